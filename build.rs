@@ -0,0 +1,28 @@
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    #[cfg(feature = "ffi")]
+    generate_ffi_header();
+}
+
+/// 根据 src/ffi.rs 生成 C 头文件
+///
+/// 头文件写入 `OUT_DIR`；设置 `RAT_QUICKDB_UPDATE_HEADER=1` 时同时更新仓库中的
+/// include/rat_quickdb.h，普通构建不会改动源码目录
+#[cfg(feature = "ffi")]
+fn generate_ffi_header() {
+    println!("cargo:rerun-if-changed=src/ffi.rs");
+    println!("cargo:rerun-if-env-changed=RAT_QUICKDB_UPDATE_HEADER");
+    let crate_dir = std::env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR");
+    let out_dir = std::env::var("OUT_DIR").expect("OUT_DIR");
+    let bindings = cbindgen::Builder::new()
+        .with_src(format!("{}/src/ffi.rs", crate_dir))
+        .with_language(cbindgen::Language::C)
+        .with_include_guard("RAT_QUICKDB_H")
+        .with_header("/* 由 cbindgen 根据 src/ffi.rs 生成，请勿手动编辑 */")
+        .generate()
+        .expect("生成 FFI 头文件失败");
+    bindings.write_to_file(format!("{}/rat_quickdb.h", out_dir));
+    if std::env::var("RAT_QUICKDB_UPDATE_HEADER").is_ok_and(|value| value == "1") {
+        bindings.write_to_file(format!("{}/include/rat_quickdb.h", crate_dir));
+    }
+}
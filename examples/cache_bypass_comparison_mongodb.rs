@@ -3,13 +3,12 @@
 //! 本示例对比使用缓存和强制跳过缓存的MongoDB数据库操作性能差异
 //! 使用 MongoDB 数据库进行测试，支持 TLS、认证和 ZSTD 压缩
 
-use rat_logger::{LoggerBuilder, debug, handler::term::TermConfig};
+use rat_logger::{LoggerBuilder, handler::term::TermConfig};
 use rat_quickdb::manager::shutdown;
 use rat_quickdb::types::*;
 use rat_quickdb::*;
-use rat_quickdb::{ModelOperations, datetime_field, float_field, integer_field, string_field, uuid_field};
+use rat_quickdb::{ModelOperations, datetime_field, integer_field, string_field, uuid_field};
 use std::collections::HashMap;
-use std::path::PathBuf;
 use std::time::{Duration, Instant};
 use tokio::time::sleep;
 
@@ -88,7 +87,7 @@ impl MongoCacheBypassTest {
         // 初始化日志系统
         LoggerBuilder::new()
             .add_terminal_with_config(TermConfig::default())
-            .init()
+            .init_global_logger()
             .expect("日志初始化失败");
 
         // 创建带缓存的数据库配置（L1 + L2）
@@ -149,6 +148,7 @@ impl MongoCacheBypassTest {
             default_ttl_secs: 1800,   // 默认30分钟
             max_ttl_secs: 7200,       // 最大2小时
             check_interval_secs: 120, // 检查间隔2分钟
+            negative_ttl_secs: None,
         };
 
         // 压缩配置
@@ -167,6 +167,7 @@ impl MongoCacheBypassTest {
             ttl_config,
             compression_config,
             version: "v1".to_string(),
+            write_mode: CacheWriteMode::WriteAround,
         };
 
         // 构建MongoDB连接配置
@@ -199,6 +200,10 @@ impl MongoCacheBypassTest {
             alias: "cached_mongodb".to_string(),
             cache: Some(cache_config),
             id_strategy: IdStrategy::Uuid,
+            version_storage_path: None,
+            enable_versioning: None,
+            on_connect_statements: Vec::new(),
+            table_naming: None,
         }
     }
 
@@ -229,13 +234,11 @@ impl MongoCacheBypassTest {
         let _ = drop_table("cached_mongodb", "users").await;
 
         // 创建测试用户数据
-        let users = vec![
-            self.create_cached_user("user1", "张三", "zhangsan@example.com", 25),
+        let users = [self.create_cached_user("user1", "张三", "zhangsan@example.com", 25),
             self.create_cached_user("user2", "李四", "lisi@example.com", 30),
             self.create_cached_user("user3", "王五", "wangwu@example.com", 28),
             self.create_cached_user("user4", "赵六", "zhaoliu@example.com", 35),
-            self.create_cached_user("user5", "钱七", "qianqi@example.com", 22),
-        ];
+            self.create_cached_user("user5", "钱七", "qianqi@example.com", 22)];
 
         // 批量用户数据
         let batch_users: Vec<CachedUser> = (6..=25)
@@ -253,7 +256,7 @@ impl MongoCacheBypassTest {
         println!("  创建测试数据到数据库...");
         set_default_alias("cached_mongodb").await?;
         for user in users.iter().chain(batch_users.iter()) {
-            let mut user_clone = user.clone();
+            let user_clone = user.clone();
             user_clone.save().await?;
         }
 
@@ -3,15 +3,13 @@
 //! 本示例对比启用缓存和未启用缓存的MySQL数据库操作性能差异
 //! 使用 MySQL 数据库进行测试，支持SSL连接
 
-use rat_logger::{LoggerBuilder, debug, handler::term::TermConfig};
+use rat_logger::{LoggerBuilder, handler::term::TermConfig};
 use rat_quickdb::manager::shutdown;
 use rat_quickdb::types::*;
 use rat_quickdb::*;
-use rat_quickdb::{ModelOperations, datetime_field, float_field, integer_field, string_field};
+use rat_quickdb::{ModelOperations, datetime_field, integer_field, string_field};
 use std::collections::HashMap;
-use std::path::PathBuf;
 use std::time::{Duration, Instant};
-use tokio::time::sleep;
 
 // 定义缓存数据库用户模型
 define_model! {
@@ -92,7 +90,7 @@ impl CachePerformanceTest {
         // 初始化日志系统
         LoggerBuilder::new()
             .add_terminal_with_config(TermConfig::default())
-            .init()
+            .init_global_logger()
             .expect("日志初始化失败");
 
         // 创建带缓存的数据库配置（L1 + L2）
@@ -141,6 +139,7 @@ impl CachePerformanceTest {
             default_ttl_secs: 1800,   // 默认30分钟
             max_ttl_secs: 7200,       // 最大2小时
             check_interval_secs: 120, // 检查间隔2分钟
+            negative_ttl_secs: None,
         };
 
         // 压缩配置
@@ -159,6 +158,7 @@ impl CachePerformanceTest {
             ttl_config,
             compression_config,
             version: "v1".to_string(),
+            write_mode: CacheWriteMode::WriteAround,
         };
 
         DatabaseConfig {
@@ -171,6 +171,9 @@ impl CachePerformanceTest {
                 password: "testdb123456".to_string(),
                 ssl_opts,
                 tls_config: None,
+                socket: None,
+                charset: None,
+                collation: None,
             },
             pool: PoolConfig::builder()
                 .min_connections(1)
@@ -187,6 +190,10 @@ impl CachePerformanceTest {
             alias: "cached_mysql".to_string(),
             cache: Some(cache_config),
             id_strategy: IdStrategy::Uuid,
+            version_storage_path: None,
+            enable_versioning: None,
+            on_connect_statements: Vec::new(),
+            table_naming: None,
         }
     }
 
@@ -217,13 +224,11 @@ impl CachePerformanceTest {
         let _ = drop_table("cached_mysql", "users").await;
 
         // 创建测试用户数据
-        let test_users = vec![
-            self.create_user("user1", "zhangsan@example.com", 25, "北京"),
+        let test_users = [self.create_user("user1", "zhangsan@example.com", 25, "北京"),
             self.create_user("user2", "lisi@example.com", 30, "上海"),
             self.create_user("user3", "wangwu@example.com", 28, "广州"),
             self.create_user("user4", "zhaoliu@example.com", 35, "深圳"),
-            self.create_user("user5", "qianqi@example.com", 22, "杭州"),
-        ];
+            self.create_user("user5", "qianqi@example.com", 22, "杭州")];
 
         // 批量用户数据
         let batch_users: Vec<CachedUser> = (6..=25)
@@ -232,7 +237,7 @@ impl CachePerformanceTest {
                     &format!("batch_user_{}", i),
                     &format!("batch{}@example.com", i),
                     20 + (i % 30),
-                    &["北京", "上海", "广州", "深圳", "杭州"][i as usize % 5],
+                    ["北京", "上海", "广州", "深圳", "杭州"][i as usize % 5],
                 )
             })
             .collect();
@@ -241,7 +246,7 @@ impl CachePerformanceTest {
         println!("  创建测试数据到数据库...");
         set_default_alias("cached_mysql").await?;
         for user in test_users.iter().chain(batch_users.iter()) {
-            let mut user_clone = user.clone();
+            let user_clone = user.clone();
             user_clone.save().await?;
         }
 
@@ -264,24 +269,6 @@ impl CachePerformanceTest {
         }
     }
 
-    /// 创建非缓存用户数据
-    fn create_bypass_cache_user(
-        &self,
-        name: &str,
-        email: &str,
-        age: i32,
-        city: &str,
-    ) -> CachedUser {
-        CachedUser {
-            id: String::new(), // 框架会自动生成UUID
-            name: name.to_string(),
-            email: email.to_string(),
-            age,
-            city: city.to_string(),
-            created_at: chrono::Utc::now(),
-        }
-    }
-
     /// 缓存预热
     async fn warmup_cache(&mut self) -> QuickDbResult<()> {
         println!("\n🔥 缓存预热...");
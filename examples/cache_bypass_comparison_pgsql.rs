@@ -3,11 +3,11 @@
 //! 本示例对比启用缓存和未启用缓存的PostgreSQL数据库操作性能差异
 //! 使用 PostgreSQL 数据库进行测试，支持 TLS 和 SSL 连接
 
-use rat_logger::{LoggerBuilder, debug, handler::term::TermConfig};
+use rat_logger::{LoggerBuilder, handler::term::TermConfig};
 use rat_quickdb::manager::shutdown;
 use rat_quickdb::types::*;
 use rat_quickdb::*;
-use rat_quickdb::{ModelOperations, datetime_field, integer_field, string_field};
+use rat_quickdb::{datetime_field, integer_field, string_field};
 use std::collections::HashMap;
 use std::time::{Duration, Instant};
 
@@ -87,7 +87,7 @@ impl PgCacheBypassTest {
         // 初始化日志系统
         LoggerBuilder::new()
             .add_terminal_with_config(TermConfig::default())
-            .init()
+            .init_global_logger()
             .expect("日志初始化失败");
 
         // 创建带缓存的数据库配置（L1 + L2）
@@ -129,6 +129,7 @@ impl PgCacheBypassTest {
             default_ttl_secs: 1800,   // 默认30分钟
             max_ttl_secs: 7200,       // 最大2小时
             check_interval_secs: 120, // 检查间隔2分钟
+            negative_ttl_secs: None,
         };
 
         // 压缩配置
@@ -147,10 +148,12 @@ impl PgCacheBypassTest {
             ttl_config,
             compression_config,
             version: "v1".to_string(),
+            write_mode: CacheWriteMode::WriteAround,
         };
 
         println!("=== DEBUG: 创建cached_db DatabaseConfig ===");
-        let db_config = DatabaseConfig {
+
+        DatabaseConfig {
             db_type: DatabaseType::PostgreSQL,
             connection: ConnectionConfig::PostgreSQL {
                 host: "172.16.0.96".to_string(),
@@ -169,6 +172,8 @@ impl PgCacheBypassTest {
                     min_tls_version: None,
                     cipher_suites: None,
                 }),
+                socket_path: None,
+                pgbouncer_mode: false,
             },
             pool: PoolConfig::builder()
                 .min_connections(1)
@@ -185,9 +190,11 @@ impl PgCacheBypassTest {
             alias: "cached_db".to_string(),
             cache: Some(cache_config),
             id_strategy: IdStrategy::Uuid,
-        };
-
-        db_config
+            version_storage_path: None,
+            enable_versioning: None,
+            on_connect_statements: Vec::new(),
+            table_naming: None,
+        }
     }
 
     
@@ -217,13 +224,11 @@ impl PgCacheBypassTest {
         let _ = drop_table("cached_db", "users").await;
 
         // 用户数据
-        let users = vec![
-            self.create_user("张三", "zhangsan@example.com", 25),
+        let users = [self.create_user("张三", "zhangsan@example.com", 25),
             self.create_user("李四", "lisi@example.com", 30),
             self.create_user("王五", "wangwu@example.com", 28),
             self.create_user("赵六", "zhaoliu@example.com", 35),
-            self.create_user("钱七", "qianqi@example.com", 22),
-        ];
+            self.create_user("钱七", "qianqi@example.com", 22)];
 
         // 批量用户数据 - 自动生成ID
         let batch_users: Vec<CachedUser> = (6..=25)
@@ -231,7 +236,7 @@ impl PgCacheBypassTest {
                 self.create_user(
                     &format!("批量用户{}", i),
                     &format!("batch{}@example.com", i),
-                    (20 + (i % 30)) as i32,
+                    20 + (i % 30),
                 )
             })
             .collect();
@@ -240,7 +245,7 @@ impl PgCacheBypassTest {
         println!("  创建测试数据到数据库...");
         set_default_alias("cached_db").await?;
         for user in users.iter().chain(batch_users.iter()) {
-            let mut user_clone = user.clone();
+            let user_clone = user.clone();
             user_clone.save().await?;
         }
 
@@ -598,6 +603,7 @@ impl PgCacheBypassTest {
 }
 
 /// 清理测试文件
+#[allow(dead_code)]
 async fn cleanup_test_files() {
     // 清理缓存目录
     let cache_dir = "./cache/pgsql_cache_test";
@@ -610,7 +616,7 @@ async fn cleanup_test_files() {
     }
 
     // 尝试清理测试目录（如果为空）
-    if let Err(_) = tokio::fs::remove_dir("./cache").await {
+    if tokio::fs::remove_dir("./cache").await.is_err() {
         // 目录不为空或不存在，忽略错误
     }
 
@@ -619,9 +625,6 @@ async fn cleanup_test_files() {
 
 #[tokio::main]
 async fn main() -> QuickDbResult<()> {
-    // 初始化日志系统（默认级别）
-    rat_logger::init();
-
     println!("🚀 RatQuickDB PostgreSQL缓存绕过测试");
     println!("=====================================\n");
 
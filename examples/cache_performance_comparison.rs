@@ -3,13 +3,12 @@
 //! 本示例对比启用缓存和未启用缓存的数据库操作性能差异
 //! 使用 SQLite 数据库进行测试，并正确配置L1和L2缓存
 
-use rat_logger::{LoggerBuilder, debug, handler::term::TermConfig};
+use rat_logger::{LoggerBuilder, handler::term::TermConfig};
 use rat_quickdb::manager::shutdown;
 use rat_quickdb::types::*;
 use rat_quickdb::*;
-use rat_quickdb::{ModelOperations, datetime_field, float_field, integer_field, string_field};
+use rat_quickdb::{ModelOperations, datetime_field, integer_field, string_field};
 use std::collections::HashMap;
-use std::path::PathBuf;
 use std::time::{Duration, Instant};
 use tokio::time::sleep;
 
@@ -114,7 +113,7 @@ impl CachePerformanceTest {
         // 初始化日志系统
         LoggerBuilder::new()
             .add_terminal_with_config(TermConfig::default())
-            .init()
+            .init_global_logger()
             .expect("日志初始化失败");
 
         // 创建带缓存的数据库配置（L1 + L2）
@@ -160,6 +159,7 @@ impl CachePerformanceTest {
             default_ttl_secs: 1800,   // 默认30分钟
             max_ttl_secs: 7200,       // 最大2小时
             check_interval_secs: 120, // 检查间隔2分钟
+            negative_ttl_secs: None,
         };
 
         // 压缩配置
@@ -178,6 +178,7 @@ impl CachePerformanceTest {
             ttl_config,
             compression_config,
             version: "v1".to_string(),
+            write_mode: CacheWriteMode::WriteAround,
         };
 
         DatabaseConfig {
@@ -185,6 +186,8 @@ impl CachePerformanceTest {
             connection: ConnectionConfig::SQLite {
                 path: "./test_data/cache_performance_cached.db".to_string(),
                 create_if_missing: true,
+                pragmas: None,
+                encryption_key: None,
             },
             pool: PoolConfig::builder()
                 .max_connections(10)
@@ -201,6 +204,10 @@ impl CachePerformanceTest {
             alias: "cached_db".to_string(),
             cache: Some(cache_config),
             id_strategy: IdStrategy::Uuid,
+            version_storage_path: None,
+            enable_versioning: None,
+            on_connect_statements: Vec::new(),
+            table_naming: None,
         }
     }
 
@@ -211,6 +218,8 @@ impl CachePerformanceTest {
             connection: ConnectionConfig::SQLite {
                 path: "./test_data/cache_performance_non_cached.db".to_string(),
                 create_if_missing: true,
+                pragmas: None,
+                encryption_key: None,
             },
             pool: PoolConfig::builder()
                 .max_connections(10)
@@ -227,6 +236,10 @@ impl CachePerformanceTest {
             alias: "non_cached_db".to_string(),
             cache: None, // 明确禁用缓存
             id_strategy: IdStrategy::Uuid,
+            version_storage_path: None,
+            enable_versioning: None,
+            on_connect_statements: Vec::new(),
+            table_naming: None,
         }
     }
 
@@ -257,21 +270,17 @@ impl CachePerformanceTest {
         let _ = drop_table("non_cached_db", "users").await;
 
         // 创建测试用户数据
-        let test_cached_users = vec![
-            self.create_cached_user("user1", "张三", "zhangsan@example.com", 25),
+        let test_cached_users = [self.create_cached_user("user1", "张三", "zhangsan@example.com", 25),
             self.create_cached_user("user2", "李四", "lisi@example.com", 30),
             self.create_cached_user("user3", "王五", "wangwu@example.com", 28),
             self.create_cached_user("user4", "赵六", "zhaoliu@example.com", 35),
-            self.create_cached_user("user5", "钱七", "qianqi@example.com", 22),
-        ];
+            self.create_cached_user("user5", "钱七", "qianqi@example.com", 22)];
 
-        let test_non_cached_users = vec![
-            self.create_non_cached_user("user1", "张三", "zhangsan@example.com", 25),
+        let test_non_cached_users = [self.create_non_cached_user("user1", "张三", "zhangsan@example.com", 25),
             self.create_non_cached_user("user2", "李四", "lisi@example.com", 30),
             self.create_non_cached_user("user3", "王五", "wangwu@example.com", 28),
             self.create_non_cached_user("user4", "赵六", "zhaoliu@example.com", 35),
-            self.create_non_cached_user("user5", "钱七", "qianqi@example.com", 22),
-        ];
+            self.create_non_cached_user("user5", "钱七", "qianqi@example.com", 22)];
 
         // 批量用户数据
         let batch_cached_users: Vec<CachedUser> = (6..=25)
@@ -299,7 +308,7 @@ impl CachePerformanceTest {
         // 创建测试数据到两个数据库
         println!("  创建测试数据到缓存数据库...");
         for user in test_cached_users.iter().chain(batch_cached_users.iter()) {
-            let mut user_clone = user.clone();
+            let user_clone = user.clone();
             user_clone.save().await?;
         }
 
@@ -308,7 +317,7 @@ impl CachePerformanceTest {
             .iter()
             .chain(batch_non_cached_users.iter())
         {
-            let mut user_clone = user.clone();
+            let user_clone = user.clone();
             user_clone.save().await?;
         }
 
@@ -662,7 +671,7 @@ async fn cleanup_test_files() {
     }
 
     // 尝试清理测试目录（如果为空）
-    if let Err(_) = tokio::fs::remove_dir("./test_data").await {
+    if tokio::fs::remove_dir("./test_data").await.is_err() {
         // 目录不为空或不存在，忽略错误
     }
 
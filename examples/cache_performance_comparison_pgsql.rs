@@ -3,11 +3,11 @@
 //! 本示例对比启用缓存和未启用缓存的PostgreSQL数据库操作性能差异
 //! 使用 PostgreSQL 数据库进行测试，支持 TLS 和 SSL 连接
 
-use rat_logger::{LoggerBuilder, debug, handler::term::TermConfig};
+use rat_logger::{LoggerBuilder, handler::term::TermConfig};
 use rat_quickdb::manager::shutdown;
 use rat_quickdb::types::*;
 use rat_quickdb::*;
-use rat_quickdb::{ModelOperations, datetime_field, integer_field, string_field};
+use rat_quickdb::{datetime_field, integer_field, string_field};
 use std::collections::HashMap;
 use std::time::{Duration, Instant};
 
@@ -112,7 +112,7 @@ impl PgCachePerformanceTest {
         // 初始化日志系统
         LoggerBuilder::new()
             .add_terminal_with_config(TermConfig::default())
-            .init()
+            .init_global_logger()
             .expect("日志初始化失败");
 
         // 创建带缓存的数据库配置（L1 + L2）
@@ -160,6 +160,7 @@ impl PgCachePerformanceTest {
             default_ttl_secs: 1800,   // 默认30分钟
             max_ttl_secs: 7200,       // 最大2小时
             check_interval_secs: 120, // 检查间隔2分钟
+            negative_ttl_secs: None,
         };
 
         // 压缩配置
@@ -178,10 +179,12 @@ impl PgCachePerformanceTest {
             ttl_config,
             compression_config,
             version: "v1".to_string(),
+            write_mode: CacheWriteMode::WriteAround,
         };
 
         println!("=== DEBUG: 创建cached_db DatabaseConfig ===");
-        let db_config = DatabaseConfig {
+
+        DatabaseConfig {
             db_type: DatabaseType::PostgreSQL,
             connection: ConnectionConfig::PostgreSQL {
                 host: "172.16.0.96".to_string(),
@@ -200,6 +203,8 @@ impl PgCachePerformanceTest {
                     min_tls_version: None,
                     cipher_suites: None,
                 }),
+                socket_path: None,
+                pgbouncer_mode: false,
             },
             pool: PoolConfig::builder()
                 .min_connections(1)
@@ -216,9 +221,11 @@ impl PgCachePerformanceTest {
             alias: "cached_db".to_string(),
             cache: Some(cache_config),
             id_strategy: IdStrategy::Uuid,
-        };
-
-        db_config
+            version_storage_path: None,
+            enable_versioning: None,
+            on_connect_statements: Vec::new(),
+            table_naming: None,
+        }
     }
 
     /// 创建不带缓存的数据库配置
@@ -242,6 +249,8 @@ impl PgCachePerformanceTest {
                     min_tls_version: None,
                     cipher_suites: None,
                 }),
+                socket_path: None,
+                pgbouncer_mode: false,
             },
             pool: PoolConfig::builder()
                 .min_connections(1)
@@ -258,6 +267,10 @@ impl PgCachePerformanceTest {
             alias: "non_cached_db".to_string(),
             cache: None, // 明确禁用缓存
             id_strategy: IdStrategy::Uuid,
+            version_storage_path: None,
+            enable_versioning: None,
+            on_connect_statements: Vec::new(),
+            table_naming: None,
         }
     }
 
@@ -288,13 +301,11 @@ impl PgCachePerformanceTest {
         let _ = drop_table("non_cached_db", "users").await;
 
         // 缓存数据库的用户数据
-        let cached_users = vec![
-            self.create_user("张三", "zhangsan_cached@example.com", 25),
+        let cached_users = [self.create_user("张三", "zhangsan_cached@example.com", 25),
             self.create_user("李四", "lisi_cached@example.com", 30),
             self.create_user("王五", "wangwu_cached@example.com", 28),
             self.create_user("赵六", "zhaoliu_cached@example.com", 35),
-            self.create_user("钱七", "qianqi_cached@example.com", 22),
-        ];
+            self.create_user("钱七", "qianqi_cached@example.com", 22)];
 
         // 批量用户数据 - 缓存数据库 - 自动生成ID
         let batch_cached_users: Vec<CachedUser> = (6..=25)
@@ -302,19 +313,17 @@ impl PgCachePerformanceTest {
                 self.create_user(
                     &format!("批量用户{}", i),
                     &format!("batch{}_cached@example.com", i),
-                    (20 + (i % 30)) as i32,
+                    20 + (i % 30),
                 )
             })
             .collect();
 
         // 非缓存数据库的用户数据（相同数据，用于性能对比）
-        let non_cached_users = vec![
-            self.create_non_cached_user("张三", "zhangsan_non_cached@example.com", 25),
+        let non_cached_users = [self.create_non_cached_user("张三", "zhangsan_non_cached@example.com", 25),
             self.create_non_cached_user("李四", "lisi_non_cached@example.com", 30),
             self.create_non_cached_user("王五", "wangwu_non_cached@example.com", 28),
             self.create_non_cached_user("赵六", "zhaoliu_non_cached@example.com", 35),
-            self.create_non_cached_user("钱七", "qianqi_non_cached@example.com", 22),
-        ];
+            self.create_non_cached_user("钱七", "qianqi_non_cached@example.com", 22)];
 
         // 批量用户数据 - 非缓存数据库 - 自动生成ID
         let batch_non_cached_users: Vec<NonCachedUser> = (26..=45)
@@ -322,7 +331,7 @@ impl PgCachePerformanceTest {
                 self.create_non_cached_user(
                     &format!("批量用户{}", i),
                     &format!("batch{}_non_cached@example.com", i),
-                    (20 + (i % 30)) as i32,
+                    20 + (i % 30),
                 )
             })
             .collect();
@@ -331,18 +340,18 @@ impl PgCachePerformanceTest {
         println!("  创建测试数据到缓存数据库...");
         set_default_alias("cached_db").await?;
         for user in cached_users.iter().chain(batch_cached_users.iter()) {
-            let mut user_clone = user.clone();
+            let user_clone = user.clone();
             user_clone.save().await?;
         }
 
         println!("  创建测试数据到非缓存数据库...");
         set_default_alias("non_cached_db").await?;
         for user in non_cached_users.iter() {
-            let mut user_clone = user.clone();
+            let user_clone = user.clone();
             user_clone.save().await?;
         }
         for user in batch_non_cached_users.iter() {
-            let mut user_clone = user.clone();
+            let user_clone = user.clone();
             user_clone.save().await?;
         }
 
@@ -714,6 +723,7 @@ impl PgCachePerformanceTest {
 }
 
 /// 清理测试文件
+#[allow(dead_code)]
 async fn cleanup_test_files() {
     // 清理缓存目录
     let cache_dir = "./cache/pgsql_cache_test";
@@ -726,7 +736,7 @@ async fn cleanup_test_files() {
     }
 
     // 尝试清理测试目录（如果为空）
-    if let Err(_) = tokio::fs::remove_dir("./cache").await {
+    if tokio::fs::remove_dir("./cache").await.is_err() {
         // 目录不为空或不存在，忽略错误
     }
 
@@ -735,9 +745,6 @@ async fn cleanup_test_files() {
 
 #[tokio::main]
 async fn main() -> QuickDbResult<()> {
-    // 初始化日志系统（默认级别）
-    rat_logger::init();
-
     println!("🚀 RatQuickDB PostgreSQL缓存性能对比测试");
     println!("=====================================\n");
 
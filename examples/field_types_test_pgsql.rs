@@ -40,7 +40,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     LoggerBuilder::new()
         .with_level(LevelFilter::Info)
         .add_terminal_with_config(TermConfig::default())
-        .init()?;
+        .init_global_logger()?;
 
     // 初始化数据库 - PostgreSQL
     let db_config = DatabaseConfig {
@@ -53,6 +53,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             password: "testdb".to_string(),
             ssl_mode: Some("prefer".to_string()),
             tls_config: None,
+            socket_path: None,
+            pgbouncer_mode: false,
         },
         pool: PoolConfig::builder()
             .min_connections(1)
@@ -68,6 +70,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         alias: "main".to_string(),
         cache: None,
         id_strategy: IdStrategy::Uuid,
+        version_storage_path: None,
+        enable_versioning: None,
+        on_connect_statements: Vec::new(),
+        table_naming: None,
     };
 
     add_database(db_config).await?;
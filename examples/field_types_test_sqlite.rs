@@ -40,7 +40,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     LoggerBuilder::new()
         .with_level(LevelFilter::Info)
         .add_terminal_with_config(TermConfig::default())
-        .init()?;
+        .init_global_logger()?;
 
     // 初始化数据库 - SQLite
     let db_config = DatabaseConfig {
@@ -48,6 +48,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         connection: ConnectionConfig::SQLite {
             path: "field_types_test.db".to_string(),
             create_if_missing: true,
+            pragmas: None,
+            encryption_key: None,
         },
         pool: PoolConfig::builder()
             .min_connections(1)
@@ -63,6 +65,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         alias: "main".to_string(),
         cache: None,
         id_strategy: IdStrategy::Uuid,
+        version_storage_path: None,
+        enable_versioning: None,
+        on_connect_statements: Vec::new(),
+        table_naming: None,
     };
 
     add_database(db_config).await?;
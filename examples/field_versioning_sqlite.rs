@@ -30,6 +30,8 @@ fn create_user_model_v1() -> ModelMeta {
             description: Some("用户ID".to_string()),
             validator: None,
             sqlite_compatibility: false,
+            rename: None,
+            computed: false,
         },
     );
 
@@ -48,6 +50,8 @@ fn create_user_model_v1() -> ModelMeta {
             description: Some("用户名".to_string()),
             validator: None,
             sqlite_compatibility: false,
+            rename: None,
+            computed: false,
         },
     );
 
@@ -62,6 +66,8 @@ fn create_user_model_v1() -> ModelMeta {
             description: Some("创建时间".to_string()),
             validator: None,
             sqlite_compatibility: false,
+            rename: None,
+            computed: false,
         },
     );
 
@@ -72,6 +78,17 @@ fn create_user_model_v1() -> ModelMeta {
         indexes: vec![],
         description: Some("用户表".to_string()),
         version: Some(1),
+        primary_key: vec![],
+        expires_after: None,
+        rename_all: None,
+        field_names: HashMap::new(),
+        discriminator: None,
+        validation_rules: Vec::new(),
+        cache_policy: None,
+        audit: false,
+        schema: None,
+        shard_key: None,
+        scopes: Default::default(),
     }
 }
 
@@ -95,6 +112,8 @@ fn create_user_model_v2() -> ModelMeta {
             description: Some("用户ID".to_string()),
             validator: None,
             sqlite_compatibility: false,
+            rename: None,
+            computed: false,
         },
     );
 
@@ -113,6 +132,8 @@ fn create_user_model_v2() -> ModelMeta {
             description: Some("用户名".to_string()),
             validator: None,
             sqlite_compatibility: false,
+            rename: None,
+            computed: false,
         },
     );
 
@@ -127,6 +148,8 @@ fn create_user_model_v2() -> ModelMeta {
             description: Some("创建时间".to_string()),
             validator: None,
             sqlite_compatibility: false,
+            rename: None,
+            computed: false,
         },
     );
 
@@ -146,6 +169,8 @@ fn create_user_model_v2() -> ModelMeta {
             description: Some("邮箱地址".to_string()),
             validator: None,
             sqlite_compatibility: false,
+            rename: None,
+            computed: false,
         },
     );
 
@@ -163,6 +188,8 @@ fn create_user_model_v2() -> ModelMeta {
             description: Some("年龄".to_string()),
             validator: None,
             sqlite_compatibility: false,
+            rename: None,
+            computed: false,
         },
     );
 
@@ -173,6 +200,17 @@ fn create_user_model_v2() -> ModelMeta {
         indexes: vec![],
         description: Some("用户表".to_string()),
         version: Some(2),
+        primary_key: vec![],
+        expires_after: None,
+        rename_all: None,
+        field_names: HashMap::new(),
+        discriminator: None,
+        validation_rules: Vec::new(),
+        cache_policy: None,
+        audit: false,
+        schema: None,
+        shard_key: None,
+        scopes: Default::default(),
     }
 }
 
@@ -192,6 +230,8 @@ fn create_user_model_v3() -> ModelMeta {
             description: Some("是否激活".to_string()),
             validator: None,
             sqlite_compatibility: false,
+            rename: None,
+            computed: false,
         },
     );
 
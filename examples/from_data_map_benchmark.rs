@@ -0,0 +1,121 @@
+//! 模型解码性能对比示例
+//!
+//! 对比三种从 HashMap<String, DataValue> 构造模型实例的方式：
+//! 1. JSON 往返：先序列化为 JSON 字符串，再用 serde_json 反序列化
+//! 2. serde 反序列化器：通过 DataValueDeserializer 驱动 serde
+//! 3. 直接解码：define_model! 生成的 FromDataMap 实现，按字段移动 DataValue
+//!
+//! 不需要数据库连接，请使用 `--release` 运行以获得有意义的数据
+
+use rat_quickdb::model::create_model_from_data_map;
+use rat_quickdb::types::*;
+use rat_quickdb::*;
+use rat_quickdb::{array_field, boolean_field, datetime_field, float_field, integer_field, string_field};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+define_model! {
+    /// 订单模型
+    struct Order {
+        id: String,
+        customer: String,
+        amount: f64,
+        quantity: i32,
+        paid: bool,
+        created_at: chrono::DateTime<chrono::Utc>,
+        note: Option<String>,
+        tags: Vec<String>,
+    }
+    collection = "orders",
+    fields = {
+        id: string_field(None, None, None).required(),
+        customer: string_field(None, None, None).required(),
+        amount: float_field(None, None),
+        quantity: integer_field(None, None),
+        paid: boolean_field(),
+        created_at: datetime_field(),
+        note: string_field(None, None, None),
+        tags: array_field(field_types!(string), None, None),
+    }
+}
+
+const ITERATIONS: u32 = 100_000;
+
+fn sample_row(index: u32) -> HashMap<String, DataValue> {
+    let mut row = HashMap::new();
+    row.insert("id".to_string(), DataValue::String(format!("order-{}", index)));
+    row.insert("customer".to_string(), DataValue::String("张三".to_string()));
+    row.insert("amount".to_string(), DataValue::Float(199.5));
+    row.insert("quantity".to_string(), DataValue::Int(3));
+    row.insert("paid".to_string(), DataValue::Bool(true));
+    row.insert(
+        "created_at".to_string(),
+        DataValue::DateTimeUTC(chrono::Utc::now()),
+    );
+    row.insert("note".to_string(), DataValue::Null);
+    row.insert(
+        "tags".to_string(),
+        DataValue::Array(vec![
+            DataValue::String("vip".to_string()),
+            DataValue::String("express".to_string()),
+        ]),
+    );
+    row
+}
+
+fn json_round_trip(row: HashMap<String, DataValue>) -> Order {
+    let json: serde_json::Map<String, serde_json::Value> = row
+        .into_iter()
+        .map(|(key, value)| (key, value.to_json_value()))
+        .collect();
+    let json_str = serde_json::to_string(&json).expect("序列化失败");
+    serde_json::from_str(&json_str).expect("反序列化失败")
+}
+
+fn measure(name: &str, rows: &[HashMap<String, DataValue>], decode: impl Fn(HashMap<String, DataValue>) -> Order) -> Duration {
+    let start = Instant::now();
+    for row in rows {
+        let order = decode(row.clone());
+        std::hint::black_box(order);
+    }
+    let elapsed = start.elapsed();
+    println!(
+        "  {:<16} 总耗时 {:>10.2?}  平均 {:>8.0} ns/行",
+        name,
+        elapsed,
+        elapsed.as_nanos() as f64 / rows.len() as f64
+    );
+    elapsed
+}
+
+fn main() -> QuickDbResult<()> {
+    println!("🚀 RatQuickDB 模型解码性能对比");
+    println!("=====================================\n");
+
+    let rows: Vec<_> = (0..ITERATIONS).map(sample_row).collect();
+
+    // 先校验三种方式结果一致
+    let direct = Order::from_data_map_direct(rows[0].clone())?;
+    let via_serde: Order = create_model_from_data_map(&rows[0])?;
+    let via_json = json_round_trip(rows[0].clone());
+    assert_eq!(serde_json::to_value(&direct)?, serde_json::to_value(&via_serde)?);
+    assert_eq!(serde_json::to_value(&direct)?, serde_json::to_value(&via_json)?);
+
+    println!("📊 解码 {} 行（包含每行克隆开销）:", ITERATIONS);
+    let json = measure("JSON 往返", &rows, json_round_trip);
+    let serde = measure("serde 反序列化器", &rows, |row| {
+        create_model_from_data_map(&row).expect("解码失败")
+    });
+    let direct = measure("直接解码", &rows, |row| {
+        Order::from_data_map_direct(row).expect("解码失败")
+    });
+
+    println!();
+    println!(
+        "⚡ 直接解码相对 JSON 往返提升 {:.2}x，相对 serde 反序列化器提升 {:.2}x",
+        json.as_secs_f64() / direct.as_secs_f64(),
+        serde.as_secs_f64() / direct.as_secs_f64()
+    );
+
+    Ok(())
+}
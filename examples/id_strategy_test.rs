@@ -7,12 +7,10 @@
 //! - ObjectId (MongoDB风格)
 
 use chrono::{DateTime, Utc};
-use rat_logger::{LoggerBuilder, debug, handler::term::TermConfig};
+use rat_logger::{LoggerBuilder, handler::term::TermConfig};
 use rat_quickdb::types::{ConnectionConfig, DatabaseType, IdStrategy, PoolConfig};
 use rat_quickdb::*;
-use rat_quickdb::{ModelManager, ModelOperations, datetime_field, integer_field, string_field};
-use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use rat_quickdb::{datetime_field, string_field};
 
 // 定义测试模型
 define_model! {
@@ -76,6 +74,8 @@ async fn test_auto_increment() -> QuickDbResult<()> {
         connection: ConnectionConfig::SQLite {
             path: "./id_strategy_test.db".to_string(),
             create_if_missing: true,
+            pragmas: None,
+            encryption_key: None,
         },
         pool: PoolConfig::builder()
             .max_connections(10)
@@ -91,6 +91,10 @@ async fn test_auto_increment() -> QuickDbResult<()> {
             .unwrap(),
         id_strategy: IdStrategy::AutoIncrement,
         cache: None,
+        version_storage_path: None,
+        enable_versioning: None,
+        on_connect_statements: Vec::new(),
+        table_naming: None,
     };
 
     add_database(db_config).await?;
@@ -99,11 +103,9 @@ async fn test_auto_increment() -> QuickDbResult<()> {
     rat_quickdb::set_default_alias("auto_increment_db").await?;
 
     // 创建测试用户
-    let users = vec![
-        TestUser::new("user1", "user1@test.com"),
+    let users = [TestUser::new("user1", "user1@test.com"),
         TestUser::new("user2", "user2@test.com"),
-        TestUser::new("user3", "user3@test.com"),
-    ];
+        TestUser::new("user3", "user3@test.com")];
 
     println!("创建3个用户，测试AutoIncrement策略自动生成ID...");
     let mut created_ids = Vec::new();
@@ -133,7 +135,7 @@ async fn test_auto_increment() -> QuickDbResult<()> {
     }
 
     // 清理数据
-    let _ = rat_quickdb::delete("test_users", vec![], Some("auto_increment_db")).await;
+    let _ = rat_quickdb::odm::delete("test_users", vec![], Some("auto_increment_db")).await;
     println!("✅ AutoIncrement ID 测试完成\n");
 
     Ok(())
@@ -151,6 +153,8 @@ async fn test_uuid() -> QuickDbResult<()> {
         connection: ConnectionConfig::SQLite {
             path: "./id_strategy_test.db".to_string(),
             create_if_missing: true,
+            pragmas: None,
+            encryption_key: None,
         },
         pool: PoolConfig::builder()
             .max_connections(10)
@@ -166,6 +170,10 @@ async fn test_uuid() -> QuickDbResult<()> {
             .unwrap(),
         id_strategy: IdStrategy::Uuid,
         cache: None,
+        version_storage_path: None,
+        enable_versioning: None,
+        on_connect_statements: Vec::new(),
+        table_naming: None,
     };
 
     add_database(db_config).await?;
@@ -174,11 +182,9 @@ async fn test_uuid() -> QuickDbResult<()> {
     rat_quickdb::set_default_alias("uuid_db").await?;
 
     // 创建测试用户
-    let users = vec![
-        TestUser::new("uuid_user1", "uuid1@test.com"),
+    let users = [TestUser::new("uuid_user1", "uuid1@test.com"),
         TestUser::new("uuid_user2", "uuid2@test.com"),
-        TestUser::new("uuid_user3", "uuid3@test.com"),
-    ];
+        TestUser::new("uuid_user3", "uuid3@test.com")];
 
     println!("创建3个用户，测试UUID自动生成...");
     let mut created_ids = Vec::new();
@@ -213,7 +219,7 @@ async fn test_uuid() -> QuickDbResult<()> {
     }
 
     // 清理数据
-    let _ = rat_quickdb::delete("test_users", vec![], Some("uuid_db")).await;
+    let _ = rat_quickdb::odm::delete("test_users", vec![], Some("uuid_db")).await;
     println!("✅ UUID ID 测试完成\n");
 
     Ok(())
@@ -231,6 +237,8 @@ async fn test_snowflake() -> QuickDbResult<()> {
         connection: ConnectionConfig::SQLite {
             path: "./id_strategy_test.db".to_string(),
             create_if_missing: true,
+            pragmas: None,
+            encryption_key: None,
         },
         pool: PoolConfig::builder()
             .max_connections(10)
@@ -246,6 +254,10 @@ async fn test_snowflake() -> QuickDbResult<()> {
             .unwrap(),
         id_strategy: IdStrategy::snowflake(1, 1),
         cache: None,
+        version_storage_path: None,
+        enable_versioning: None,
+        on_connect_statements: Vec::new(),
+        table_naming: None,
     };
 
     add_database(db_config).await?;
@@ -254,11 +266,9 @@ async fn test_snowflake() -> QuickDbResult<()> {
     rat_quickdb::set_default_alias("snowflake_db").await?;
 
     // 创建测试用户
-    let users = vec![
-        TestUser::new("snowflake_user1", "snowflake1@test.com"),
+    let users = [TestUser::new("snowflake_user1", "snowflake1@test.com"),
         TestUser::new("snowflake_user2", "snowflake2@test.com"),
-        TestUser::new("snowflake_user3", "snowflake3@test.com"),
-    ];
+        TestUser::new("snowflake_user3", "snowflake3@test.com")];
 
     println!("创建3个用户，测试雪花算法ID生成...");
     let mut created_ids = Vec::new();
@@ -316,7 +326,7 @@ async fn test_snowflake() -> QuickDbResult<()> {
     }
 
     // 清理数据
-    let _ = rat_quickdb::delete("test_users", vec![], Some("snowflake_db")).await;
+    let _ = rat_quickdb::odm::delete("test_users", vec![], Some("snowflake_db")).await;
     println!("✅ Snowflake ID 测试完成\n");
 
     Ok(())
@@ -327,7 +337,7 @@ async fn main() -> QuickDbResult<()> {
     // 初始化日志
     LoggerBuilder::new()
         .add_terminal_with_config(TermConfig::default())
-        .init()
+        .init_global_logger()
         .expect("日志初始化失败");
 
     println!("🧪 RatQuickDB ID策略测试");
@@ -338,8 +348,7 @@ async fn main() -> QuickDbResult<()> {
 
     let test_choice = if args.len() == 1 {
         // 没有参数，随机选择一个策略避免污染
-        use std::collections::HashMap;
-        let strategies = vec!["auto-increment", "uuid", "snowflake"];
+        let strategies = ["auto-increment", "uuid", "snowflake"];
         let random_index = (std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
             .unwrap()
@@ -7,11 +7,10 @@
 //! - ObjectId (MongoDB风格)
 
 use chrono::{DateTime, Utc};
-use rat_logger::{LoggerBuilder, debug, handler::term::TermConfig};
+use rat_logger::{LoggerBuilder, handler::term::TermConfig};
 use rat_quickdb::types::{ConnectionConfig, DatabaseType, IdStrategy, PoolConfig};
 use rat_quickdb::*;
-use rat_quickdb::{ModelManager, ModelOperations, datetime_field, integer_field, string_field};
-use serde::{Deserialize, Serialize};
+use rat_quickdb::{datetime_field, string_field};
 use std::collections::HashMap;
 
 // 定义测试模型
@@ -84,6 +83,9 @@ async fn test_auto_increment() -> QuickDbResult<()> {
                 Some(opts)
             },
             tls_config: None,
+            socket: None,
+            charset: None,
+            collation: None,
         },
         pool: PoolConfig::builder()
             .min_connections(1)
@@ -99,6 +101,10 @@ async fn test_auto_increment() -> QuickDbResult<()> {
         alias: "auto_increment_db".to_string(),
         cache: None,
         id_strategy: IdStrategy::AutoIncrement,
+        version_storage_path: None,
+        enable_versioning: None,
+        on_connect_statements: Vec::new(),
+        table_naming: None,
     };
 
     add_database(db_config).await?;
@@ -110,11 +116,9 @@ async fn test_auto_increment() -> QuickDbResult<()> {
     let _ = rat_quickdb::drop_table("auto_increment_db", "test_users").await;
 
     // 创建测试用户
-    let users = vec![
-        TestUser::new("user1", "user1@test.com"),
+    let users = [TestUser::new("user1", "user1@test.com"),
         TestUser::new("user2", "user2@test.com"),
-        TestUser::new("user3", "user3@test.com"),
-    ];
+        TestUser::new("user3", "user3@test.com")];
 
     println!("创建3个用户，测试AutoIncrement策略自动生成ID...");
     let mut created_ids = Vec::new();
@@ -182,6 +186,9 @@ async fn test_uuid() -> QuickDbResult<()> {
                 Some(opts)
             },
             tls_config: None,
+            socket: None,
+            charset: None,
+            collation: None,
         },
         pool: PoolConfig::builder()
             .min_connections(1)
@@ -197,6 +204,10 @@ async fn test_uuid() -> QuickDbResult<()> {
         alias: "uuid_db".to_string(),
         cache: None,
         id_strategy: IdStrategy::Uuid,
+        version_storage_path: None,
+        enable_versioning: None,
+        on_connect_statements: Vec::new(),
+        table_naming: None,
     };
 
     add_database(db_config).await?;
@@ -208,11 +219,9 @@ async fn test_uuid() -> QuickDbResult<()> {
     let _ = rat_quickdb::drop_table("uuid_db", "test_users").await;
 
     // 创建测试用户
-    let users = vec![
-        TestUser::new("uuid_user1", "uuid1@test.com"),
+    let users = [TestUser::new("uuid_user1", "uuid1@test.com"),
         TestUser::new("uuid_user2", "uuid2@test.com"),
-        TestUser::new("uuid_user3", "uuid3@test.com"),
-    ];
+        TestUser::new("uuid_user3", "uuid3@test.com")];
 
     println!("创建3个用户，测试UUID自动生成...");
     let mut created_ids = Vec::new();
@@ -247,7 +256,7 @@ async fn test_uuid() -> QuickDbResult<()> {
     }
 
     // 清理数据
-    let _ = rat_quickdb::delete("test_users", vec![], Some("uuid_db")).await;
+    let _ = rat_quickdb::odm::delete("test_users", vec![], Some("uuid_db")).await;
     println!("✅ UUID ID 测试完成\n");
 
     Ok(())
@@ -273,6 +282,9 @@ async fn test_snowflake() -> QuickDbResult<()> {
                 Some(opts)
             },
             tls_config: None,
+            socket: None,
+            charset: None,
+            collation: None,
         },
         pool: PoolConfig::builder()
             .min_connections(1)
@@ -288,6 +300,10 @@ async fn test_snowflake() -> QuickDbResult<()> {
         alias: "snowflake_db".to_string(),
         cache: None,
         id_strategy: IdStrategy::snowflake(1, 1),
+        version_storage_path: None,
+        enable_versioning: None,
+        on_connect_statements: Vec::new(),
+        table_naming: None,
     };
 
     add_database(db_config).await?;
@@ -299,11 +315,9 @@ async fn test_snowflake() -> QuickDbResult<()> {
     let _ = rat_quickdb::drop_table("snowflake_db", "test_users").await;
 
     // 创建测试用户
-    let users = vec![
-        TestUser::new("snowflake_user1", "snowflake1@test.com"),
+    let users = [TestUser::new("snowflake_user1", "snowflake1@test.com"),
         TestUser::new("snowflake_user2", "snowflake2@test.com"),
-        TestUser::new("snowflake_user3", "snowflake3@test.com"),
-    ];
+        TestUser::new("snowflake_user3", "snowflake3@test.com")];
 
     println!("创建3个用户，测试雪花算法ID生成...");
     let mut created_ids = Vec::new();
@@ -361,7 +375,7 @@ async fn test_snowflake() -> QuickDbResult<()> {
     }
 
     // 清理数据
-    let _ = rat_quickdb::delete("test_users", vec![], Some("snowflake_db")).await;
+    let _ = rat_quickdb::odm::delete("test_users", vec![], Some("snowflake_db")).await;
     println!("✅ Snowflake ID 测试完成\n");
 
     Ok(())
@@ -372,7 +386,7 @@ async fn main() -> QuickDbResult<()> {
     // 初始化日志
     LoggerBuilder::new()
         .add_terminal_with_config(TermConfig::default())
-        .init()
+        .init_global_logger()
         .expect("日志初始化失败");
 
     println!("🧪 RatQuickDB ID策略测试");
@@ -383,8 +397,7 @@ async fn main() -> QuickDbResult<()> {
 
     let test_choice = if args.len() == 1 {
         // 没有参数，随机选择一个策略避免污染
-        use std::collections::HashMap;
-        let strategies = vec!["auto-increment", "uuid", "snowflake"];
+        let strategies = ["auto-increment", "uuid", "snowflake"];
         let random_index = (std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
             .unwrap()
@@ -1,9 +1,6 @@
 //! join宏数据库别名功能演示
 //! 展示如何为虚拟表指定数据库别名
 
-#[cfg(debug_assertions)]
-use rat_logger::debug;
-use rat_quickdb::join_macro::*;
 use rat_quickdb::join_macro::{JoinDefinition, JoinType};
 use rat_quickdb::*;
 
@@ -108,11 +105,17 @@ async fn setup_database() -> QuickDbResult<()> {
         connection: ConnectionConfig::SQLite {
             path: "join_demo.db".to_string(),
             create_if_missing: true,
+            pragmas: None,
+            encryption_key: None,
         },
         pool: PoolConfig::default(),
         alias: "main_db".to_string(),
         cache: None,
         id_strategy: IdStrategy::Uuid,
+        version_storage_path: None,
+        enable_versioning: None,
+        on_connect_statements: Vec::new(),
+        table_naming: None,
     };
 
     add_database(config).await?;
@@ -222,7 +222,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     LoggerBuilder::new()
         .with_level(LevelFilter::Info)
         .add_terminal_with_config(TermConfig::default())
-        .init()?;
+        .init_global_logger()?;
 
     // 初始化数据库
     let tls_config = rat_quickdb::types::TlsConfig {
@@ -255,6 +255,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             options: None,
             tls_config: Some(tls_config),
             zstd_config: Some(zstd_config),
+            cluster_config: None,
         })
         .pool(
             PoolConfig::builder()
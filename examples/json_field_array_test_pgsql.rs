@@ -222,7 +222,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     LoggerBuilder::new()
         .with_level(LevelFilter::Info)
         .add_terminal_with_config(TermConfig::default())
-        .init()?;
+        .init_global_logger()?;
 
     // 初始化数据库 - 使用PostgreSQL连接信息
     let db_config = DatabaseConfig::builder()
@@ -244,6 +244,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 min_tls_version: None,
                 cipher_suites: None,
             }),
+            socket_path: None,
+            pgbouncer_mode: false,
         })
         .pool(
             PoolConfig::builder()
@@ -44,7 +44,7 @@ async fn main() -> QuickDbResult<()> {
     LoggerBuilder::new()
         .with_level(LevelFilter::Warn)
         .add_terminal_with_config(TermConfig::default())
-        .init()
+        .init_global_logger()
         .expect("日志初始化失败");
 
     println!("=== PostgreSQL JSON 字段数值类型查询测试 ===\n");
@@ -69,6 +69,8 @@ async fn main() -> QuickDbResult<()> {
                 min_tls_version: None,
                 cipher_suites: None,
             }),
+            socket_path: None,
+            pgbouncer_mode: false,
         })
         .pool(
             PoolConfig::builder()
@@ -8,12 +8,10 @@
 //!
 //! 注意：这个示例使用模型宏定义表结构，然后演示手动管理操作
 
-use rat_logger::{LoggerBuilder, debug, handler::term::TermConfig, info, warn};
+use rat_logger::{LoggerBuilder, handler::term::TermConfig};
 use rat_quickdb::manager::{drop_table, shutdown, table_exists};
-use rat_quickdb::types::{DataValue, QueryCondition, QueryOperator};
 use rat_quickdb::*;
-use rat_quickdb::{ModelOperations, boolean_field, datetime_field, integer_field, string_field};
-use std::collections::HashMap;
+use rat_quickdb::{boolean_field, datetime_field, integer_field, string_field};
 
 // 定义测试模型 - 遵循框架的模型宏设计原则
 define_model! {
@@ -47,7 +45,7 @@ async fn main() -> QuickDbResult<()> {
     // 初始化日志系统
     LoggerBuilder::new()
         .add_terminal_with_config(TermConfig::default())
-        .init()
+        .init_global_logger()
         .expect("日志初始化失败");
 
     println!("=== PostgreSQL 手动表管理示例 ===");
@@ -63,6 +61,8 @@ async fn main() -> QuickDbResult<()> {
             password: "testdb123456".to_string(),
             ssl_mode: Some("prefer".to_string()),
             tls_config: None,
+            socket_path: None,
+            pgbouncer_mode: false,
         },
         pool: PoolConfig::builder()
             .max_connections(10)
@@ -79,6 +79,10 @@ async fn main() -> QuickDbResult<()> {
         alias: "default".to_string(),
         cache: None,
         id_strategy: IdStrategy::Uuid,
+        version_storage_path: None,
+        enable_versioning: None,
+        on_connect_statements: Vec::new(),
+        table_naming: None,
     };
 
     // 初始化数据库
@@ -12,14 +12,13 @@ use rat_quickdb::types::*;
 use rat_quickdb::*;
 use rat_quickdb::{
     ModelManager, ModelOperations, array_field, boolean_field, datetime_field, float_field,
-    integer_field, json_field, string_field, uuid_field,
+    integer_field, string_field, uuid_field,
 };
 
 // 数据库别名常量
 const DATABASE_ALIAS: &str = "main";
 use std::collections::HashMap;
 use std::time::Instant;
-use tokio::join;
 
 // 用户模型
 define_model! {
@@ -357,7 +356,7 @@ async fn demonstrate_pagination() -> Result<SimpleStats, Box<dyn std::error::Err
                 for (i, emp) in employees.iter().take(3).enumerate() {
                     println!(
                         "  {}. {} - {} - ${:.0}",
-                        (page as u64 * page_size as u64 + i as u64 + 1),
+                        (page * page_size + i as u64 + 1),
                         emp.name,
                         emp.department,
                         emp.salary
@@ -455,7 +454,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     LoggerBuilder::new()
         .with_level(LevelFilter::Warn) // 减少输出
         .add_terminal_with_config(TermConfig::default())
-        .init()?;
+        .init_global_logger()?;
 
     // 初始化数据库
     // TLS配置
@@ -490,6 +489,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             options: None,
             tls_config: Some(tls_config),
             zstd_config: Some(zstd_config),
+            cluster_config: None,
         })
         .pool(
             PoolConfig::builder()
@@ -546,8 +546,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // 健康检查
     println!("\n=== 健康检查 ===");
     let health = health_check().await;
-    for (alias, is_healthy) in health {
-        let status = if is_healthy { "✅" } else { "❌" };
+    for (alias, report) in health {
+        let status = if report.reachable { "✅" } else { "❌" };
         println!("{}: {}", alias, status);
     }
 
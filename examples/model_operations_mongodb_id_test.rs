@@ -10,15 +10,13 @@ use rat_logger::{LevelFilter, LoggerBuilder, handler::term::TermConfig};
 use rat_quickdb::types::*;
 use rat_quickdb::*;
 use rat_quickdb::{
-    ModelManager, ModelOperations, array_field, boolean_field, datetime_field, float_field,
-    integer_field, json_field, string_field, uuid_field,
+    ModelManager, ModelOperations, array_field, boolean_field, datetime_field,
+    integer_field, string_field, uuid_field,
 };
 
 // 数据库别名常量
 const DATABASE_ALIAS: &str = "main";
-use std::collections::HashMap;
 use std::time::Instant;
-use tokio::join;
 
 // 用户模型
 define_model! {
@@ -334,7 +332,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     LoggerBuilder::new()
         .with_level(LevelFilter::Info)
         .add_terminal_with_config(TermConfig::default())
-        .init()?;
+        .init_global_logger()?;
 
     // 初始化数据库
     // TLS配置
@@ -369,6 +367,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             options: None,
             tls_config: Some(tls_config),
             zstd_config: Some(zstd_config),
+            cluster_config: None,
         })
         .pool(
             PoolConfig::builder()
@@ -400,8 +399,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // 健康检查
     println!("\n=== 健康检查 ===");
     let health = health_check().await;
-    for (alias, is_healthy) in health {
-        let status = if is_healthy { "✅" } else { "❌" };
+    for (alias, report) in health {
+        let status = if report.reachable { "✅" } else { "❌" };
         println!("{}: {}", alias, status);
     }
 
@@ -12,11 +12,10 @@ use rat_quickdb::types::*;
 use rat_quickdb::*;
 use rat_quickdb::{
     ModelManager, ModelOperations, array_field, boolean_field, datetime_field, float_field,
-    integer_field, json_field, string_field, uuid_field,
+    integer_field, string_field, uuid_field,
 };
 use std::collections::HashMap;
 use std::time::Instant;
-use tokio::join;
 
 // 用户模型
 define_model! {
@@ -352,7 +351,7 @@ async fn demonstrate_pagination() -> Result<SimpleStats, Box<dyn std::error::Err
                 for (i, emp) in employees.iter().take(3).enumerate() {
                     println!(
                         "  {}. {} - {} - ${:.0}",
-                        (page as u64 * page_size as u64 + i as u64 + 1),
+                        (page * page_size + i as u64 + 1),
                         emp.name,
                         emp.department,
                         emp.salary
@@ -450,7 +449,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     LoggerBuilder::new()
         .with_level(LevelFilter::Warn) // 减少输出
         .add_terminal_with_config(TermConfig::default())
-        .init()?;
+        .init_global_logger()?;
 
     // 初始化数据库
     let db_config = DatabaseConfig::builder()
@@ -463,6 +462,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             password: "testdb123456".to_string(),
             ssl_opts: Default::default(),
             tls_config: None,
+            socket: None,
+            charset: None,
+            collation: None,
         })
         .pool(
             PoolConfig::builder()
@@ -519,8 +521,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // 健康检查
     println!("\n=== 健康检查 ===");
     let health = health_check().await;
-    for (alias, is_healthy) in health {
-        let status = if is_healthy { "✅" } else { "❌" };
+    for (alias, report) in health {
+        let status = if report.reachable { "✅" } else { "❌" };
         println!("{}: {}", alias, status);
     }
 
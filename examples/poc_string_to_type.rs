@@ -1,18 +1,18 @@
 // POC: 字符串转类型并调用的不同方案验证
 
-use std::collections::HashMap;
-use std::future::Future;
-use std::pin::Pin;
 
 // 定义一些示例结构体
+#[allow(dead_code)]
 struct User {
     name: &'static str,
 }
 
+#[allow(dead_code)]
 struct Order {
     id: u32,
 }
 
+#[allow(dead_code)]
 struct Product {
     price: f64,
 }
@@ -1,13 +1,12 @@
 //! PostgreSQL UUID自动转换功能测试
 
-use rat_logger::{LevelFilter, LoggerBuilder, debug, handler::term::TermConfig};
+use rat_logger::{LevelFilter, LoggerBuilder, handler::term::TermConfig};
 use rat_quickdb::manager::add_database;
 use rat_quickdb::types::{
     ConnectionConfig, DataValue, DatabaseType, PoolConfig, QueryCondition, QueryOperator,
 };
 use rat_quickdb::*;
-use rat_quickdb::{ModelManager, ModelOperations};
-use std::collections::HashMap;
+use rat_quickdb::ModelManager;
 
 // 定义测试模型
 define_model! {
@@ -33,7 +32,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     LoggerBuilder::new()
         .with_level(LevelFilter::Debug)
         .add_terminal_with_config(TermConfig::default())
-        .init()
+        .init_global_logger()
         .expect("Failed to initialize logger");
 
     println!("=== PostgreSQL UUID自动转换功能测试 ===");
@@ -49,6 +48,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             password: "testdb123456".to_string(),
             ssl_mode: Some("prefer".to_string()),
             tls_config: None,
+            socket_path: None,
+            pgbouncer_mode: false,
         },
         pool: PoolConfig::builder()
             .min_connections(2)
@@ -64,6 +65,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         alias: "test".to_string(),
         id_strategy: IdStrategy::Uuid,
         cache: None,
+        version_storage_path: None,
+        enable_versioning: None,
+        on_connect_statements: Vec::new(),
+        table_naming: None,
     };
 
     // 添加数据库连接
@@ -24,9 +24,8 @@ use rat_quickdb::{
     ModelManager, ModelOperations, boolean_field, datetime_field, float_field, integer_field,
     string_field, uuid_field,
 };
-use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::time::{Instant, SystemTime};
+use std::time::Instant;
 
 // 用户模型 - 用于批量操作演示
 define_model! {
@@ -263,7 +262,7 @@ async fn demonstrate_batch_operations() -> Result<QueryStats, Box<dyn std::error
     println!("\n=== 批量操作演示 ===");
 
     let mut stats = QueryStats::new();
-    let user_ids = create_test_data(100).await?;
+    let _user_ids = create_test_data(100).await?;
 
     // 批量更新操作
     println!("\n执行批量更新...");
@@ -643,7 +642,7 @@ async fn demonstrate_complex_queries() -> Result<QueryStats, Box<dyn std::error:
     );
 
     // 验证结果
-    if case_insensitive_result.len() > 0 {
+    if !case_insensitive_result.is_empty() {
         println!("   ✅ 大小写不敏感查询成功");
         println!("   找到的产品: {}", case_insensitive_result[0].name);
     } else {
@@ -811,7 +810,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     LoggerBuilder::new()
         .with_level(LevelFilter::Warn)
         .add_terminal_with_config(TermConfig::default())
-        .init()?;
+        .init_global_logger()?;
 
     // 初始化数据库
     let db_config = DatabaseConfig::builder()
@@ -833,6 +832,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 min_tls_version: None,
                 cipher_suites: None,
             }),
+            socket_path: None,
+            pgbouncer_mode: false,
         })
         .pool(
             PoolConfig::builder()
@@ -873,8 +874,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // 健康检查
     println!("\n=== 健康检查 ===");
     let health = health_check().await;
-    for (alias, is_healthy) in health {
-        let status = if is_healthy { "✅" } else { "❌" };
+    for (alias, report) in health {
+        let status = if report.reachable { "✅" } else { "❌" };
         println!("{}: {}", alias, status);
     }
 
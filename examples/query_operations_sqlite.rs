@@ -24,9 +24,8 @@ use rat_quickdb::{
     ModelManager, ModelOperations, boolean_field, datetime_field, float_field, integer_field,
     string_field, uuid_field,
 };
-use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::time::{Instant, SystemTime};
+use std::time::Instant;
 
 // 用户模型 - 用于批量操作演示
 define_model! {
@@ -262,7 +261,7 @@ async fn demonstrate_batch_operations() -> Result<QueryStats, Box<dyn std::error
     println!("\n=== 批量操作演示 ===");
 
     let mut stats = QueryStats::new();
-    let user_ids = create_test_data(100).await?;
+    let _user_ids = create_test_data(100).await?;
 
     // 批量更新操作
     println!("\n执行批量更新...");
@@ -639,7 +638,7 @@ async fn demonstrate_complex_queries() -> Result<QueryStats, Box<dyn std::error:
     );
 
     // 验证结果
-    if case_insensitive_result.len() > 0 {
+    if !case_insensitive_result.is_empty() {
         println!("   ✅ 大小写不敏感查询成功");
         println!("   找到的产品: {}", case_insensitive_result[0].name);
     } else {
@@ -807,7 +806,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     LoggerBuilder::new()
         .with_level(LevelFilter::Warn)
         .add_terminal_with_config(TermConfig::default())
-        .init()?;
+        .init_global_logger()?;
 
     // 先删除旧的数据库文件
     if std::path::Path::new("./query_operations_sqlite.db").exists() {
@@ -820,6 +819,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .connection(ConnectionConfig::SQLite {
             path: "./query_operations_sqlite.db".to_string(),
             create_if_missing: true,
+            pragmas: None,
+            encryption_key: None,
         })
         .pool(
             PoolConfig::builder()
@@ -860,8 +861,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // 健康检查
     println!("\n=== 健康检查 ===");
     let health = health_check().await;
-    for (alias, is_healthy) in health {
-        let status = if is_healthy { "✅" } else { "❌" };
+    for (alias, report) in health {
+        let status = if report.reachable { "✅" } else { "❌" };
         println!("{}: {}", alias, status);
     }
 
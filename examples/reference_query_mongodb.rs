@@ -19,10 +19,9 @@ use rat_logger::{LevelFilter, LoggerBuilder, handler::term::TermConfig};
 use rat_quickdb::types::*;
 use rat_quickdb::*;
 use rat_quickdb::{
-    ModelManager, ModelOperations, boolean_field, datetime_field, float_field, integer_field,
+    ModelManager, boolean_field, datetime_field, float_field, integer_field,
     string_field, uuid_field,
 };
-use serde::{Deserialize, Serialize};
 
 // 部门模型 - 被关联的模型
 define_model! {
@@ -295,7 +294,7 @@ async fn demo_not_equal_reference_query() -> Result<(), Box<dyn std::error::Erro
     let mut dept_groups: std::collections::HashMap<String, Vec<&Employee>> = std::collections::HashMap::new();
     for emp in &employees {
         dept_groups.entry(emp.department_id.clone())
-            .or_insert_with(Vec::new)
+            .or_default()
             .push(emp);
     }
 
@@ -330,7 +329,7 @@ async fn demo_in_reference_query() -> Result<(), Box<dyn std::error::Error>> {
     // 选择前3个部门
     let target_depts: Vec<&Department> = departments.iter().take(3).collect();
     let target_dept_ids: Vec<String> = target_depts.iter().map(|d| d.id.clone()).collect();
-    let target_dept_names: Vec<&str> = target_depts.iter().map(|d| d.name.as_str()).collect();
+    let _target_dept_names: Vec<&str> = target_depts.iter().map(|d| d.name.as_str()).collect();
 
     println!("📌 查询条件: department_id IN [...]");
     println!("   （查询属于以下部门的员工）");
@@ -354,7 +353,7 @@ async fn demo_in_reference_query() -> Result<(), Box<dyn std::error::Error>> {
     let mut dept_groups: std::collections::HashMap<String, Vec<&Employee>> = std::collections::HashMap::new();
     for emp in &employees {
         dept_groups.entry(emp.department_id.clone())
-            .or_insert_with(Vec::new)
+            .or_default()
             .push(emp);
     }
 
@@ -390,7 +389,7 @@ async fn demo_not_in_reference_query() -> Result<(), Box<dyn std::error::Error>>
     // 排除前2个部门
     let exclude_depts: Vec<&Department> = departments.iter().take(2).collect();
     let exclude_dept_ids: Vec<String> = exclude_depts.iter().map(|d| d.id.clone()).collect();
-    let exclude_dept_names: Vec<&str> = exclude_depts.iter().map(|d| d.name.as_str()).collect();
+    let _exclude_dept_names: Vec<&str> = exclude_depts.iter().map(|d| d.name.as_str()).collect();
 
     println!("📌 查询条件: department_id NOT IN [...]");
     println!("   （查询不属于以下部门的员工）");
@@ -417,7 +416,7 @@ async fn demo_not_in_reference_query() -> Result<(), Box<dyn std::error::Error>>
     let mut dept_groups: std::collections::HashMap<String, Vec<&Employee>> = std::collections::HashMap::new();
     for emp in &employees {
         dept_groups.entry(emp.department_id.clone())
-            .or_insert_with(Vec::new)
+            .or_default()
             .push(emp);
     }
 
@@ -486,7 +485,7 @@ async fn demo_is_not_null_reference_query() -> Result<(), Box<dyn std::error::Er
     println!("📊 查询结果: 找到 {} 个已关联项目的任务", tasks.len());
 
     for (i, task) in tasks.iter().take(5).enumerate() {
-        let project_id = task.project_id.as_ref().map(|id| id.as_str()).unwrap_or("无");
+        let project_id = task.project_id.as_deref().unwrap_or("无");
         println!("   {}. {} - 项目ID: {} - 状态: {}",
             i + 1, task.title, project_id, task.status);
     }
@@ -659,7 +658,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     LoggerBuilder::new()
         .with_level(LevelFilter::Warn)
         .add_terminal_with_config(TermConfig::default())
-        .init()?;
+        .init_global_logger()?;
 
     // 初始化数据库
     println!("\n📡 连接数据库...");
@@ -676,6 +675,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             tls_config: None,
             zstd_config: None,
             options: None,
+            cluster_config: None,
         })
         .pool(
             PoolConfig::builder()
@@ -11,14 +11,12 @@
 //! cargo run --example string_fuzzy_search_mongodb --features mongodb-support
 //! ```
 
-use chrono::Utc;
 use rat_logger::{LevelFilter, LoggerBuilder, handler::term::TermConfig};
 use rat_quickdb::types::*;
 use rat_quickdb::*;
-use rat_quickdb::{ModelManager, ModelOperations, string_field, uuid_field};
-use serde::{Deserialize, Serialize};
+use rat_quickdb::{ModelManager, string_field, uuid_field};
 
-/// 用户模型 - 用于字符串模糊搜索演示
+// 用户模型 - 用于字符串模糊搜索演示
 define_model! {
     struct StringSearchUser {
         id: String,
@@ -308,7 +306,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     LoggerBuilder::new()
         .with_level(LevelFilter::Info)
         .add_terminal_with_config(TermConfig::default())
-        .init()?;
+        .init_global_logger()?;
 
     // 初始化数据库（使用与 query_operations_mongodb.rs 相同的连接配置）
     let db_config = DatabaseConfig::builder()
@@ -328,6 +326,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 compression_threshold: Some(1024),
             }),
             options: None,
+            cluster_config: None,
         })
         .pool(
             PoolConfig::builder()
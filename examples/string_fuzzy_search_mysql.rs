@@ -11,14 +11,12 @@
 //! cargo run --example string_fuzzy_search_mysql --features mysql-support
 //! ```
 
-use chrono::Utc;
 use rat_logger::{LevelFilter, LoggerBuilder, handler::term::TermConfig};
 use rat_quickdb::types::*;
 use rat_quickdb::*;
-use rat_quickdb::{ModelManager, ModelOperations, string_field, uuid_field};
-use serde::{Deserialize, Serialize};
+use rat_quickdb::{ModelManager, string_field, uuid_field};
 
-/// 用户模型 - 用于字符串模糊搜索演示
+// 用户模型 - 用于字符串模糊搜索演示
 define_model! {
     struct StringSearchUser {
         id: String,
@@ -306,7 +304,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     LoggerBuilder::new()
         .with_level(LevelFilter::Info)
         .add_terminal_with_config(TermConfig::default())
-        .init()?;
+        .init_global_logger()?;
 
     // 初始化数据库（使用与 query_operations_mysql.rs 相同的连接配置）
     let db_config = DatabaseConfig::builder()
@@ -319,6 +317,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             password: "testdb123456".to_string(),
             ssl_opts: Default::default(),
             tls_config: None,
+            socket: None,
+            charset: None,
+            collation: None,
         })
         .pool(
             PoolConfig::builder()
@@ -11,14 +11,12 @@
 //! cargo run --example string_fuzzy_search_pgsql --features postgres-support
 //! ```
 
-use chrono::Utc;
 use rat_logger::{LevelFilter, LoggerBuilder, handler::term::TermConfig};
 use rat_quickdb::types::*;
 use rat_quickdb::*;
-use rat_quickdb::{ModelManager, ModelOperations, string_field, uuid_field};
-use serde::{Deserialize, Serialize};
+use rat_quickdb::{ModelManager, string_field, uuid_field};
 
-/// 用户模型 - 用于字符串模糊搜索演示
+// 用户模型 - 用于字符串模糊搜索演示
 define_model! {
     struct StringSearchUser {
         id: String,
@@ -306,7 +304,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     LoggerBuilder::new()
         .with_level(LevelFilter::Info)
         .add_terminal_with_config(TermConfig::default())
-        .init()?;
+        .init_global_logger()?;
 
     // 初始化数据库（使用与 query_operations_pgsql.rs 相同的连接配置）
     let db_config = DatabaseConfig::builder()
@@ -328,6 +326,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 min_tls_version: None,
                 cipher_suites: None,
             }),
+            socket_path: None,
+            pgbouncer_mode: false,
         })
         .pool(
             PoolConfig::builder()
@@ -11,14 +11,12 @@
 //! cargo run --example string_fuzzy_search_sqlite --features sqlite-support
 //! ```
 
-use chrono::Utc;
 use rat_logger::{LevelFilter, LoggerBuilder, handler::term::TermConfig};
 use rat_quickdb::types::*;
 use rat_quickdb::*;
-use rat_quickdb::{ModelManager, ModelOperations, string_field, uuid_field};
-use serde::{Deserialize, Serialize};
+use rat_quickdb::{ModelManager, string_field, uuid_field};
 
-/// 用户模型 - 用于字符串模糊搜索演示
+// 用户模型 - 用于字符串模糊搜索演示
 define_model! {
     struct StringSearchUser {
         id: String,
@@ -306,7 +304,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     LoggerBuilder::new()
         .with_level(LevelFilter::Info)
         .add_terminal_with_config(TermConfig::default())
-        .init()?;
+        .init_global_logger()?;
 
     // 先删除旧的数据库文件
     if std::path::Path::new("./string_fuzzy_search_sqlite.db").exists() {
@@ -319,6 +317,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .connection(ConnectionConfig::SQLite {
             path: "./string_fuzzy_search_sqlite.db".to_string(),
             create_if_missing: true,
+            pragmas: None,
+            encryption_key: None,
         })
         .pool(
             PoolConfig::builder()
@@ -2,12 +2,9 @@
 //!
 //! 测试 Array 字段的存储、查询和类型转换功能
 
-use rat_logger::{LevelFilter, LoggerBuilder, handler::term::TermConfig};
-use rat_quickdb::manager::health_check;
-use rat_quickdb::model::FieldType;
+use rat_logger::{LoggerBuilder, handler::term::TermConfig};
 use rat_quickdb::types::{
-    ConnectionConfig, DatabaseType, LogicalOperator, PoolConfig, QueryConditionGroup, QueryOptions,
-    SortConfig, SortDirection,
+    ConnectionConfig, DatabaseType, LogicalOperator, PoolConfig, QueryConditionGroup,
 };
 use rat_quickdb::*;
 use rat_quickdb::{
@@ -56,7 +53,7 @@ async fn main() -> QuickDbResult<()> {
     // 初始化日志
     LoggerBuilder::new()
         .add_terminal_with_config(TermConfig::default())
-        .init()
+        .init_global_logger()
         .expect("日志初始化失败");
 
     println!("🚀 测试 MySQL Array 字段 IN 查询功能");
@@ -79,6 +76,9 @@ async fn main() -> QuickDbResult<()> {
                 Some(opts)
             },
             tls_config: None,
+            socket: None,
+            charset: None,
+            collation: None,
         },
         pool: PoolConfig::builder()
             .max_connections(10)
@@ -94,6 +94,10 @@ async fn main() -> QuickDbResult<()> {
             .unwrap(),
         id_strategy: IdStrategy::ObjectId,
         cache: None,
+        version_storage_path: None,
+        enable_versioning: None,
+        on_connect_statements: Vec::new(),
+        table_naming: None,
     };
 
     // 添加数据库配置
@@ -109,8 +113,7 @@ async fn main() -> QuickDbResult<()> {
 
     // 2. 创建测试数据
     println!("\n2. 创建测试数据...");
-    let test_data = vec![
-        ArrayTestModel {
+    let test_data = [ArrayTestModel {
             id: generate_object_id(),
             name: "iPhone 15".to_string(),
             tags: vec![
@@ -153,8 +156,7 @@ async fn main() -> QuickDbResult<()> {
             ],
             category_ids: vec![2, 5, 13],
             ratings: vec![4.2, 4.4, 4.3],
-        },
-    ];
+        }];
 
     for (i, item) in test_data.iter().enumerate() {
         match item.save().await {
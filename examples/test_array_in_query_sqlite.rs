@@ -2,12 +2,9 @@
 //!
 //! 测试 Array 字段的存储、查询和类型转换功能
 
-use rat_logger::{LevelFilter, LoggerBuilder, handler::term::TermConfig};
-use rat_quickdb::manager::health_check;
-use rat_quickdb::model::FieldType;
+use rat_logger::{LoggerBuilder, handler::term::TermConfig};
 use rat_quickdb::types::{
-    ConnectionConfig, DatabaseType, LogicalOperator, PoolConfig, QueryConditionGroup, QueryOptions,
-    SortConfig, SortDirection,
+    ConnectionConfig, DatabaseType, LogicalOperator, PoolConfig, QueryConditionGroup,
 };
 use rat_quickdb::*;
 use rat_quickdb::{
@@ -56,7 +53,7 @@ async fn main() -> QuickDbResult<()> {
     // 初始化日志
     LoggerBuilder::new()
         .add_terminal_with_config(TermConfig::default())
-        .init()
+        .init_global_logger()
         .expect("日志初始化失败");
 
     println!("🚀 测试 SQLite Array 字段 IN 查询功能");
@@ -73,6 +70,8 @@ async fn main() -> QuickDbResult<()> {
         connection: ConnectionConfig::SQLite {
             path: "./array_test.db".to_string(),
             create_if_missing: true,
+            pragmas: None,
+            encryption_key: None,
         },
         pool: PoolConfig::builder()
             .max_connections(10)
@@ -88,6 +87,10 @@ async fn main() -> QuickDbResult<()> {
             .unwrap(),
         id_strategy: IdStrategy::ObjectId,
         cache: None,
+        version_storage_path: None,
+        enable_versioning: None,
+        on_connect_statements: Vec::new(),
+        table_naming: None,
     };
 
     // 添加数据库配置
@@ -96,8 +99,7 @@ async fn main() -> QuickDbResult<()> {
 
     // 2. 创建测试数据
     println!("\n2. 创建测试数据...");
-    let test_data = vec![
-        ArrayTestModel {
+    let test_data = [ArrayTestModel {
             id: generate_object_id(),
             name: "iPhone 15".to_string(),
             tags: vec![
@@ -140,8 +142,7 @@ async fn main() -> QuickDbResult<()> {
             ],
             category_ids: vec![2, 5, 13],
             ratings: vec![4.2, 4.4, 4.3],
-        },
-    ];
+        }];
 
     for (i, item) in test_data.iter().enumerate() {
         match item.save().await {
@@ -1,6 +1,5 @@
 // 测试 Array 字段序列化和查询模式
 
-use serde_json;
 
 fn main() {
     println!("=== Array 字段序列化测试 ===\n");
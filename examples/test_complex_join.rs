@@ -1,7 +1,6 @@
 //! 复杂JOIN查询测试示例
 //! 验证多表混合JOIN类型的虚拟表格宏功能
 
-use rat_quickdb::adapter::OrderClause;
 use rat_quickdb::join_macro::{JoinDefinition, JoinType};
 use rat_quickdb::types::query::SortDirection;
 use rat_quickdb::types::*;
@@ -17,37 +16,43 @@ define_join_table! {
             JoinDefinition {
                 table: "users".to_string(),
                 on_condition: "orders.user_id = users.id".to_string(),
-                join_type: JoinType::Inner
+                join_type: JoinType::Inner,
+                database: None,
             },
             // 左连接订单项表（订单可能有多个项目）
             JoinDefinition {
                 table: "order_items".to_string(),
                 on_condition: "orders.id = order_items.order_id".to_string(),
-                join_type: JoinType::Left
+                join_type: JoinType::Left,
+                database: None,
             },
             // 左连接商品表（订单项必须有商品，但为了测试Left Join）
             JoinDefinition {
                 table: "products".to_string(),
                 on_condition: "order_items.product_id = products.id".to_string(),
-                join_type: JoinType::Left
+                join_type: JoinType::Left,
+                database: None,
             },
             // 左连接商品分类表（商品可能没有分类）
             JoinDefinition {
                 table: "categories".to_string(),
                 on_condition: "products.category_id = categories.id".to_string(),
-                join_type: JoinType::Left
+                join_type: JoinType::Left,
+                database: None,
             },
             // 右连接供应商表（测试Right Join，商品必须有供应商）
             JoinDefinition {
                 table: "suppliers".to_string(),
                 on_condition: "products.supplier_id = suppliers.id".to_string(),
-                join_type: JoinType::Right
+                join_type: JoinType::Right,
+                database: None,
             },
             // 全连接配送表（测试Full Outer Join）
             JoinDefinition {
                 table: "shipments".to_string(),
                 on_condition: "orders.id = shipments.order_id".to_string(),
-                join_type: JoinType::Full
+                join_type: JoinType::Full,
+                database: None,
             }
         ],
         fields: {
@@ -101,31 +106,36 @@ define_join_table! {
             JoinDefinition {
                 table: "users".to_string(),
                 on_condition: "posts.user_id = users.id".to_string(),
-                join_type: JoinType::Inner
+                join_type: JoinType::Inner,
+                database: None,
             },
             // 左连接评论（帖子可能没有评论）
             JoinDefinition {
                 table: "comments".to_string(),
                 on_condition: "posts.id = comments.post_id".to_string(),
-                join_type: JoinType::Left
+                join_type: JoinType::Left,
+                database: None,
             },
             // 右连接点赞（测试Right Join，点赞必须有帖子）
             JoinDefinition {
                 table: "likes".to_string(),
                 on_condition: "posts.id = likes.post_id".to_string(),
-                join_type: JoinType::Right
+                join_type: JoinType::Right,
+                database: None,
             },
             // 全连接标签（测试Full Outer Join，标签可能没有帖子）
             JoinDefinition {
                 table: "tags".to_string(),
                 on_condition: "posts.id = tags.post_id".to_string(),
-                join_type: JoinType::Full
+                join_type: JoinType::Full,
+                database: None,
             },
             // 左连接媒体文件（帖子可能没有媒体）
             JoinDefinition {
                 table: "media".to_string(),
                 on_condition: "posts.id = media.post_id".to_string(),
-                join_type: JoinType::Left
+                join_type: JoinType::Left,
+                database: None,
             }
         ],
         fields: {
@@ -1,6 +1,6 @@
 //! 数据库类型安全验证测试
 
-use rat_logger::{LevelFilter, LoggerBuilder, debug, handler::term};
+use rat_logger::{LevelFilter, LoggerBuilder, handler::term};
 use rat_quickdb::*;
 
 #[cfg(feature = "sqlite-support")]
@@ -25,7 +25,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     LoggerBuilder::new()
         .with_level(LevelFilter::Info)
         .add_terminal_with_config(term::TermConfig::default())
-        .init()?;
+        .init_global_logger()?;
 
     // 创建SQLite数据库配置
     let pool_config = PoolConfig::builder()
@@ -41,6 +41,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .connection(ConnectionConfig::SQLite {
             path: ":memory:".to_string(),
             create_if_missing: true,
+            pragmas: None,
+            encryption_key: None,
         })
         .pool(pool_config)
         .alias("sqlite_test")
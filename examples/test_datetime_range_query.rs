@@ -2,13 +2,13 @@
 //!
 //! 验证带时区的DateTime字段范围查询是否正常工作
 
-use chrono::{DateTime, Duration, Utc};
-use rat_logger::{LevelFilter, LoggerBuilder, handler::term::TermConfig};
+use chrono::{Duration, Utc};
+use rat_logger::{LoggerBuilder, handler::term::TermConfig};
 use rat_quickdb::manager::health_check;
 use rat_quickdb::types::{ConnectionConfig, DatabaseType, PoolConfig};
 use rat_quickdb::types::{QueryCondition, QueryOperator};
 use rat_quickdb::*;
-use rat_quickdb::{ModelManager, ModelOperations, datetime_with_tz_field};
+use rat_quickdb::ModelManager;
 
 // 定义测试模型
 define_model! {
@@ -31,7 +31,7 @@ async fn main() -> QuickDbResult<()> {
     // 初始化日志
     LoggerBuilder::new()
         .add_terminal_with_config(TermConfig::default())
-        .init()
+        .init_global_logger()
         .expect("日志初始化失败");
 
     println!("🕒 测试DateTime范围查询功能");
@@ -48,6 +48,8 @@ async fn main() -> QuickDbResult<()> {
         connection: ConnectionConfig::SQLite {
             path: "./time_range_test.db".to_string(),
             create_if_missing: true,
+            pragmas: None,
+            encryption_key: None,
         },
         pool: PoolConfig::builder()
             .max_connections(10)
@@ -63,11 +65,15 @@ async fn main() -> QuickDbResult<()> {
             .unwrap(),
         cache: None,
         id_strategy: Default::default(),
+        version_storage_path: None,
+        enable_versioning: None,
+        on_connect_statements: Vec::new(),
+        table_naming: None,
     };
 
     add_database(db_config).await?;
     let health_status = health_check().await;
-    if !health_status.get("main").unwrap_or(&false) {
+    if !health_status.get("main").is_some_and(|report| report.reachable) {
         return Err(QuickDbError::ConnectionError {
             message: "数据库连接失败".to_string(),
         });
@@ -109,12 +115,12 @@ async fn main() -> QuickDbResult<()> {
         QueryCondition {
             field: "event_time".to_string(),
             operator: QueryOperator::Gte,
-            value: rat_quickdb::types::DataValue::DateTime(start_time),
+            value: rat_quickdb::types::DataValue::DateTimeUTC(start_time),
         },
         QueryCondition {
             field: "event_time".to_string(),
             operator: QueryOperator::Lte,
-            value: rat_quickdb::types::DataValue::DateTime(end_time),
+            value: rat_quickdb::types::DataValue::DateTimeUTC(end_time),
         },
     ];
 
@@ -141,7 +147,7 @@ async fn main() -> QuickDbResult<()> {
     let gt_condition = vec![QueryCondition {
         field: "event_time".to_string(),
         operator: QueryOperator::Gt,
-        value: rat_quickdb::types::DataValue::DateTime(after_time),
+        value: rat_quickdb::types::DataValue::DateTimeUTC(after_time),
     }];
 
     match ModelManager::<TimeRangeTestModel>::find(gt_condition, None).await {
@@ -2,7 +2,6 @@
 
 use rat_quickdb::types::{ConnectionConfig, DatabaseType};
 use rat_quickdb::*;
-use rat_quickdb::{ModelManager, ModelOperations};
 
 // 定义测试模型
 define_model! {
@@ -29,20 +28,27 @@ async fn main() -> QuickDbResult<()> {
         connection: ConnectionConfig::SQLite {
             path: "./string_input_test.db".to_string(),
             create_if_missing: true,
+            pragmas: None,
+            encryption_key: None,
         },
         pool: Default::default(),
         cache: None,
         id_strategy: Default::default(),
+        version_storage_path: None,
+        enable_versioning: None,
+        on_connect_statements: Vec::new(),
+        table_naming: None,
     };
 
     add_database(db_config).await?;
 
     // 测试1: 尝试传入String类型的DateTime字段
-    println!("测试1: 传入String类型到DateTime字段");
+    println!("测试1: 将String解析后传入DateTime字段");
     let model = StringInputTestModel {
         id: String::new(),
         name: "测试String输入".to_string(),
-        event_time: "2025-11-28T12:00:00Z".to_string(), // 这里传入String而不是DateTime<Utc>
+        // DateTime 字段不接受 String，需先解析为 DateTime<Utc>
+        event_time: "2025-11-28T12:00:00Z".parse().expect("无效的时间字符串"),
     };
 
     match model.save().await {
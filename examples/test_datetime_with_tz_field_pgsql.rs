@@ -2,8 +2,8 @@
 //!
 //! 验证 datetime_with_tz_field 函数的功能
 
-use chrono::{DateTime, Timelike, Utc};
-use rat_logger::{LevelFilter, LoggerBuilder, handler::term::TermConfig};
+use chrono::{Timelike, Utc};
+use rat_logger::{LoggerBuilder, handler::term::TermConfig};
 use rat_quickdb::manager::health_check;
 use rat_quickdb::types::{ConnectionConfig, DatabaseType, PoolConfig};
 use rat_quickdb::*;
@@ -36,7 +36,7 @@ async fn main() -> QuickDbResult<()> {
     // 初始化日志
     LoggerBuilder::new()
         .add_terminal_with_config(TermConfig::default())
-        .init()
+        .init_global_logger()
         .expect("日志初始化失败");
 
     println!("🚀 测试带时区的DateTime字段");
@@ -55,6 +55,8 @@ async fn main() -> QuickDbResult<()> {
             password: "testdb".to_string(),
             ssl_mode: Some("prefer".to_string()),
             tls_config: None,
+            socket_path: None,
+            pgbouncer_mode: false,
         },
         pool: PoolConfig::builder()
             .max_connections(10)
@@ -70,6 +72,10 @@ async fn main() -> QuickDbResult<()> {
             .unwrap(),
         id_strategy: IdStrategy::Uuid,
         cache: None,
+        version_storage_path: None,
+        enable_versioning: None,
+        on_connect_statements: Vec::new(),
+        table_naming: None,
     };
 
     add_database(db_config).await?;
@@ -81,7 +87,7 @@ async fn main() -> QuickDbResult<()> {
     // 2. 健康检查
     println!("2. 数据库健康检查...");
     let health_results = health_check().await;
-    if let Some(&is_healthy) = health_results.get("main") {
+    if let Some(is_healthy) = health_results.get("main").map(|report| report.reachable) {
         if is_healthy {
             println!("✅ 数据库连接正常");
         } else {
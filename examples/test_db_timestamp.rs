@@ -1,9 +1,6 @@
 //! 测试读取数据库中的时间戳数据
 
-use chrono::{DateTime, Utc};
-use rat_quickdb::ModelOperations;
 use rat_quickdb::manager::shutdown;
-use rat_quickdb::types::QueryCondition;
 use rat_quickdb::*;
 
 #[tokio::main]
@@ -14,6 +11,8 @@ async fn main() -> QuickDbResult<()> {
         .connection(ConnectionConfig::SQLite {
             path: "./timezone_range_demo.db".to_string(),
             create_if_missing: false, // 使用已有的数据库
+            pragmas: None,
+            encryption_key: None,
         })
         .pool(
             PoolConfig::builder()
@@ -75,10 +74,10 @@ define_model! {
         username: string_field(Some(100), Some(1), None).required(),
         activity_type: string_field(Some(50), Some(1), None).required(),
         description: string_field(Some(500), Some(1), None).required(),
-        beijing_time: datetime_field(Some("+08:00".to_string())).required(),
+        beijing_time: datetime_with_tz_field("+08:00").required(),
         duration_minutes: integer_field(Some(0), Some(1440)).required(),
         ip_address: string_field(Some(45), Some(1), None).required(),
-        created_at: datetime_field(Some("+00:00".to_string())).required(),
+        created_at: datetime_field().required(),
     }
     indexes = [
         { fields: ["user_id"], unique: false, name: "idx_user_id" },
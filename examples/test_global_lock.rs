@@ -41,11 +41,17 @@ async fn test_normal_database_addition() -> QuickDbResult<()> {
         connection: ConnectionConfig::SQLite {
             path: "test_global_lock.db".to_string(),
             create_if_missing: true,
+            pragmas: None,
+            encryption_key: None,
         },
         pool: PoolConfig::default(),
         alias: "test_normal".to_string(),
         cache: None,
         id_strategy: IdStrategy::Uuid,
+        version_storage_path: None,
+        enable_versioning: None,
+        on_connect_statements: Vec::new(),
+        table_naming: None,
     };
 
     // 在查询操作开始前添加数据库应该成功
@@ -65,16 +71,16 @@ async fn test_database_addition_after_queries() -> QuickDbResult<()> {
     println!("执行查询操作以触发全局锁...");
 
     // 创建一个简单的查询条件
-    let conditions = vec![QueryCondition {
+    let conditions = [QueryCondition {
         field: "id".to_string(),
         operator: QueryOperator::Eq,
         value: DataValue::String("test".to_string()),
     }];
 
     // 执行查询（应该触发全局锁）
-    match find(
+    match odm::find(
         "test_collection",
-        conditions.clone(),
+        conditions.iter().cloned().map(Into::into).collect(),
         None,
         Some("test_normal"),
     )
@@ -93,11 +99,17 @@ async fn test_database_addition_after_queries() -> QuickDbResult<()> {
         connection: ConnectionConfig::SQLite {
             path: "test_should_fail.db".to_string(),
             create_if_missing: true,
+            pragmas: None,
+            encryption_key: None,
         },
         pool: PoolConfig::default(),
         alias: "should_fail".to_string(),
         cache: None,
         id_strategy: IdStrategy::Uuid,
+        version_storage_path: None,
+        enable_versioning: None,
+        on_connect_statements: Vec::new(),
+        table_naming: None,
     };
 
     match add_database(new_config).await {
@@ -137,11 +149,17 @@ async fn test_database_addition_after_table_ops() -> QuickDbResult<()> {
         connection: ConnectionConfig::SQLite {
             path: "test_should_also_fail.db".to_string(),
             create_if_missing: true,
+            pragmas: None,
+            encryption_key: None,
         },
         pool: PoolConfig::default(),
         alias: "should_also_fail".to_string(),
         cache: None,
         id_strategy: IdStrategy::Uuid,
+        version_storage_path: None,
+        enable_versioning: None,
+        on_connect_statements: Vec::new(),
+        table_naming: None,
     };
 
     match add_database(another_config).await {
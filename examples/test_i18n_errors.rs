@@ -1,6 +1,5 @@
 //! 多语言错误消息系统测试示例
 
-use rat_quickdb::*;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("=== 多语言错误消息系统测试 ===\n");
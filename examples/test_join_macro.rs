@@ -1,7 +1,6 @@
 //! 虚拟表格宏测试示例
 //! 验证define_join_table宏的功能和SQL生成
 
-use rat_quickdb::adapter::OrderClause;
 use rat_quickdb::join_macro::{JoinDefinition, JoinType};
 use rat_quickdb::types::query::SortDirection;
 use rat_quickdb::types::*;
@@ -16,7 +15,8 @@ define_join_table! {
             JoinDefinition {
                 table: "profiles".to_string(),
                 on_condition: "users.id = profiles.user_id".to_string(),
-                join_type: JoinType::Left
+                join_type: JoinType::Left,
+                database: None,
             }
         ],
         fields: {
@@ -40,12 +40,14 @@ define_join_table! {
             JoinDefinition {
                 table: "users".to_string(),
                 on_condition: "articles.author_id = users.id".to_string(),
-                join_type: JoinType::Inner
+                join_type: JoinType::Inner,
+                database: None,
             },
             JoinDefinition {
                 table: "categories".to_string(),
                 on_condition: "articles.category_id = categories.id".to_string(),
-                join_type: JoinType::Inner
+                join_type: JoinType::Inner,
+                database: None,
             }
         ],
         fields: {
@@ -2,16 +2,13 @@
 //!
 //! 测试 JSON 字段的存储和 JsonContains 查询功能
 
-use rat_logger::{LevelFilter, LoggerBuilder, handler::term::TermConfig};
-use rat_quickdb::manager::health_check;
-use rat_quickdb::model::FieldType;
+use rat_logger::{LoggerBuilder, handler::term::TermConfig};
 use rat_quickdb::types::{
-    ConnectionConfig, DatabaseType, LogicalOperator, PoolConfig, QueryConditionGroup, QueryOptions,
-    SortConfig, SortDirection,
+    ConnectionConfig, DatabaseType, LogicalOperator, PoolConfig, QueryConditionGroup,
 };
 use rat_quickdb::*;
 use rat_quickdb::{
-    DataValue, ModelManager, ModelOperations, QueryCondition, QueryOperator, field_types,
+    DataValue, ModelManager, ModelOperations, QueryCondition, QueryOperator,
     json_field,
 };
 use serde_json::json;
@@ -54,7 +51,7 @@ async fn main() -> QuickDbResult<()> {
     // 初始化日志
     LoggerBuilder::new()
         .add_terminal_with_config(TermConfig::default())
-        .init()
+        .init_global_logger()
         .expect("日志初始化失败");
 
     println!("🚀 测试 PostgreSQL JSON 字段 JsonContains 查询功能");
@@ -73,6 +70,8 @@ async fn main() -> QuickDbResult<()> {
             password: "testdb".to_string(),
             ssl_mode: Some("prefer".to_string()),
             tls_config: None,
+            socket_path: None,
+            pgbouncer_mode: false,
         },
         pool: PoolConfig::builder()
             .max_connections(10)
@@ -88,6 +87,10 @@ async fn main() -> QuickDbResult<()> {
             .unwrap(),
         id_strategy: IdStrategy::ObjectId,
         cache: None,
+        version_storage_path: None,
+        enable_versioning: None,
+        on_connect_statements: Vec::new(),
+        table_naming: None,
     };
 
     // 添加数据库配置
@@ -103,8 +106,7 @@ async fn main() -> QuickDbResult<()> {
 
     // 2. 创建测试数据
     println!("\n2. 创建测试数据...");
-    let test_data = vec![
-        JsonTestModel {
+    let test_data = [JsonTestModel {
             id: generate_object_id(),
             name: "张三".to_string(),
             profile: json!({
@@ -211,8 +213,7 @@ async fn main() -> QuickDbResult<()> {
                     "analytics": false
                 }
             }),
-        },
-    ];
+        }];
 
     for (i, item) in test_data.iter().enumerate() {
         match item.save().await {
@@ -7,7 +7,6 @@ use rat_logger::{LevelFilter, LoggerBuilder, handler::term::TermConfig};
 use rat_quickdb::types::*;
 use rat_quickdb::*;
 use rat_quickdb::{ModelManager, ModelOperations, datetime_field, float_field, string_field, uuid_field};
-use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 define_model! {
@@ -46,7 +45,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     LoggerBuilder::new()
         .with_level(LevelFilter::Warn)
         .add_terminal_with_config(TermConfig::default())
-        .init()?;
+        .init_global_logger()?;
 
     let db_config = DatabaseConfig::builder()
         .db_type(DatabaseType::MySQL)
@@ -58,6 +57,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             password: "testdb123456".to_string(),
             ssl_opts: Default::default(),
             tls_config: None,
+            socket: None,
+            charset: None,
+            collation: None,
         })
         .pool(
             PoolConfig::builder()
@@ -96,7 +98,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         created_at: Utc::now(),
     };
     let id1 = o1.save().await?;
-    println!("[PASS] INSERT: order={}, group={}, user={}, select={}", "ORD-001", "A组", "张三", "标准配送");
+    println!("[PASS] INSERT: order=ORD-001, group=A组, user=张三, select=标准配送");
 
     for (ord, grp, usr, amt, st) in [
         ("ORD-002", "B组", "李四", 199.0, "completed"),
@@ -206,7 +208,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             field: "status".to_string(),
             operator: QueryOperator::Eq,
             value: DataValue::String("completed".to_string()),
-        }.into()],
+        }],
     ).await?;
     println!("[PASS] DELETE status='completed': 删除 {} 条", deleted);
 
@@ -8,7 +8,6 @@ use rat_logger::{LevelFilter, LoggerBuilder, handler::term::TermConfig};
 use rat_quickdb::types::*;
 use rat_quickdb::*;
 use rat_quickdb::{ModelManager, ModelOperations, datetime_field, float_field, string_field, uuid_field};
-use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 // 使用 SQL 关键词 "order" 作为表名，关键词作为字段名
@@ -50,7 +49,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     LoggerBuilder::new()
         .with_level(LevelFilter::Warn)
         .add_terminal_with_config(TermConfig::default())
-        .init()?;
+        .init_global_logger()?;
 
     // 数据库配置
     let db_config = DatabaseConfig::builder()
@@ -72,6 +71,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 min_tls_version: None,
                 cipher_suites: None,
             }),
+            socket_path: None,
+            pgbouncer_mode: false,
         })
         .pool(
             PoolConfig::builder()
@@ -110,7 +111,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         created_at: Utc::now(),
     };
     let id1 = o1.save().await?;
-    println!("[PASS] INSERT: order={}, group={}, user={}, select={}", "ORD-001", "A组", "张三", "标准配送");
+    println!("[PASS] INSERT: order=ORD-001, group=A组, user=张三, select=标准配送");
 
     // 批量创建
     for (ord, grp, usr, amt, st) in [
@@ -221,7 +222,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             field: "status".to_string(),
             operator: QueryOperator::Eq,
             value: DataValue::String("completed".to_string()),
-        }.into()],
+        }],
     ).await?;
     println!("[PASS] DELETE status='completed': 删除 {} 条", deleted);
 
@@ -2,8 +2,6 @@
 //! 验证跨库操作的正确性
 
 use chrono::{DateTime, Utc};
-#[cfg(debug_assertions)]
-use rat_logger::debug;
 use rat_quickdb::types::*;
 use rat_quickdb::*;
 use rat_quickdb::{ModelManager, ModelOperations, add_database, set_default_alias};
@@ -110,11 +108,17 @@ async fn setup_test_databases() -> QuickDbResult<()> {
         connection: ConnectionConfig::SQLite {
             path: "test_main.db".to_string(),
             create_if_missing: true,
+            pragmas: None,
+            encryption_key: None,
         },
         pool: PoolConfig::default(),
         alias: "main_db".to_string(),
         cache: None,
         id_strategy: IdStrategy::Uuid,
+        version_storage_path: None,
+        enable_versioning: None,
+        on_connect_statements: Vec::new(),
+        table_naming: None,
     };
 
     // 创建归档数据库配置
@@ -123,11 +127,17 @@ async fn setup_test_databases() -> QuickDbResult<()> {
         connection: ConnectionConfig::SQLite {
             path: "test_archive.db".to_string(),
             create_if_missing: true,
+            pragmas: None,
+            encryption_key: None,
         },
         pool: PoolConfig::default(),
         alias: "archive_db".to_string(),
         cache: None,
         id_strategy: IdStrategy::Uuid,
+        version_storage_path: None,
+        enable_versioning: None,
+        on_connect_statements: Vec::new(),
+        table_naming: None,
     };
 
     // 创建默认数据库配置
@@ -136,11 +146,17 @@ async fn setup_test_databases() -> QuickDbResult<()> {
         connection: ConnectionConfig::SQLite {
             path: "test_default.db".to_string(),
             create_if_missing: true,
+            pragmas: None,
+            encryption_key: None,
         },
         pool: PoolConfig::default(),
         alias: "default".to_string(),
         cache: None,
         id_strategy: IdStrategy::Uuid,
+        version_storage_path: None,
+        enable_versioning: None,
+        on_connect_statements: Vec::new(),
+        table_naming: None,
     };
 
     // 添加数据库
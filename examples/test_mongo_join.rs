@@ -41,6 +41,7 @@ macro_rules! define_mongo_join_table {
     ) => {
         // 生成字段结构体
         #[derive(Debug, Clone)]
+        #[allow(dead_code)]
         $vis struct $struct_name {
             $(
                 $field: DataValue,
@@ -190,6 +191,10 @@ macro_rules! define_mongo_join_table {
                     QueryOperator::Exists => "$exists",
                     QueryOperator::IsNull => "$eq",
                     QueryOperator::IsNotNull => "$ne",
+                    QueryOperator::JsonContains => "$all",
+                    QueryOperator::Between | QueryOperator::NotBetween => {
+                        panic!("示例不支持区间操作符: {:?}", condition.operator)
+                    }
                 };
 
                 let value = match &condition.value {
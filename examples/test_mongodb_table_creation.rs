@@ -1,6 +1,5 @@
 //! 测试MongoDB集合创建行为和TableNotExistError
 
-use chrono::Utc;
 use rat_logger::{LevelFilter, LoggerBuilder, handler::term::TermConfig};
 use rat_quickdb::types::*;
 use rat_quickdb::*;
@@ -99,7 +98,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     LoggerBuilder::new()
         .with_level(LevelFilter::Warn)
         .add_terminal_with_config(TermConfig::default())
-        .init()?;
+        .init_global_logger()?;
 
     // 初始化数据库
     // TLS配置
@@ -134,6 +133,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             options: None,
             tls_config: Some(tls_config),
             zstd_config: Some(zstd_config),
+            cluster_config: None,
         })
         .pool(
             PoolConfig::builder()
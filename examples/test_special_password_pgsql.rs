@@ -8,7 +8,6 @@ use rat_logger::{LevelFilter, LoggerBuilder, handler::term::TermConfig};
 use rat_quickdb::types::*;
 use rat_quickdb::*;
 use rat_quickdb::{ModelManager, ModelOperations, datetime_field, float_field, string_field, uuid_field};
-use serde::{Deserialize, Serialize};
 
 define_model! {
     struct TestRecord {
@@ -34,7 +33,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     LoggerBuilder::new()
         .with_level(LevelFilter::Debug)
         .add_terminal_with_config(TermConfig::default())
-        .init()?;
+        .init_global_logger()?;
 
     // 密码包含特殊字符: p@ss#w0rd!2024$%test
     let special_password = "p@ss#w0rd!2024$%test";
@@ -51,6 +50,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             password: special_password.to_string(),
             ssl_mode: Some("prefer".to_string()),
             tls_config: None,
+            socket_path: None,
+            pgbouncer_mode: false,
         })
         .pool(
             PoolConfig::builder()
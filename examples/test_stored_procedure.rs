@@ -1,7 +1,7 @@
 //! 存储过程创建测试
 
 #[cfg(feature = "sqlite-support")]
-use rat_logger::{LevelFilter, LoggerBuilder, debug, handler::term};
+use rat_logger::{LevelFilter, LoggerBuilder, handler::term};
 #[cfg(feature = "sqlite-support")]
 use rat_quickdb::*;
 
@@ -9,7 +9,7 @@ use rat_quickdb::*;
 #[cfg(feature = "sqlite-support")]
 define_model! {
     struct User {
-        id: i32,
+        id: String,
         name: String,
         email: String,
         created_at: chrono::DateTime<chrono::Utc>,
@@ -34,7 +34,7 @@ define_model! {
 #[cfg(feature = "sqlite-support")]
 define_model! {
     struct Order {
-        id: i32,
+        id: String,
         user_id: i32,
         total: f64,
         order_date: chrono::DateTime<chrono::Utc>,
@@ -62,7 +62,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     LoggerBuilder::new()
         .with_level(LevelFilter::Info)
         .add_terminal_with_config(term::TermConfig::default())
-        .init()?;
+        .init_global_logger()?;
 
     let pool_config = PoolConfig::builder()
         .max_connections(10)
@@ -81,6 +81,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .connection(ConnectionConfig::SQLite {
             path: ":memory:".to_string(),
             create_if_missing: true,
+            pragmas: None,
+            encryption_key: None,
         })
         .pool(pool_config)
         .alias("test_db")
@@ -114,21 +116,21 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // 创建一些测试用户
     let test_users = vec![
         User {
-            id: 1,
+            id: "1".to_string(),
             name: "张三".to_string(),
             email: "zhangsan@example.com".to_string(),
             created_at: two_days_ago,
             updated_at: yesterday,
         },
         User {
-            id: 2,
+            id: "2".to_string(),
             name: "李四".to_string(),
             email: "lisi@example.com".to_string(),
             created_at: yesterday,
             updated_at: now,
         },
         User {
-            id: 3,
+            id: "3".to_string(),
             name: "王五".to_string(),
             email: "wangwu@example.com".to_string(),
             created_at: now,
@@ -139,21 +141,21 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // 创建一些测试订单
     let test_orders = vec![
         Order {
-            id: 1,
+            id: "1".to_string(),
             user_id: 1,
             total: 100.50,
             order_date: yesterday,
             created_at: yesterday,
         },
         Order {
-            id: 2,
+            id: "2".to_string(),
             user_id: 1,
             total: 200.75,
             order_date: now,
             created_at: now,
         },
         Order {
-            id: 3,
+            id: "3".to_string(),
             user_id: 2,
             total: 150.00,
             order_date: two_days_ago,
@@ -164,7 +166,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // 插入测试用户
     for user in &test_users {
         let user_instance = User {
-            id: user.id,
+            id: user.id.clone(),
             name: user.name.clone(),
             email: user.email.clone(),
             created_at: user.created_at,
@@ -184,7 +186,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // 插入测试订单
     for order in &test_orders {
         let order_instance = Order {
-            id: order.id,
+            id: order.id.clone(),
             user_id: order.user_id,
             total: order.total,
             order_date: order.order_date,
@@ -284,9 +286,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     println!(
                         "  {}. {} - {} ({})",
                         i + 1,
-                        user_name.to_string(),
-                        user_email.to_string(),
-                        user_id.to_string()
+                        user_name,
+                        user_email,
+                        user_id
                     );
                     println!(
                         "     创建时间: {}, 更新时间: {}",
@@ -334,8 +336,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     println!(
                         "  {}. {} (ID: {}) - 创建时间: {}",
                         i + 1,
-                        user_name.to_string(),
-                        user_id.to_string(),
+                        user_name,
+                        user_id,
                         created_at_str
                     );
                 }
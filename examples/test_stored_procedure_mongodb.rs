@@ -1,7 +1,7 @@
 //! MongoDB存储过程创建测试
 
 #[cfg(feature = "mongodb-support")]
-use rat_logger::{LevelFilter, LoggerBuilder, debug, handler::term};
+use rat_logger::{LevelFilter, LoggerBuilder, handler::term};
 #[cfg(feature = "mongodb-support")]
 use rat_quickdb::*;
 
@@ -62,7 +62,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     LoggerBuilder::new()
         .with_level(LevelFilter::Info)
         .add_terminal_with_config(term::TermConfig::default())
-        .init()?;
+        .init_global_logger()?;
 
     let pool_config = PoolConfig::builder()
         .max_connections(10)
@@ -107,6 +107,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 opts.insert("w".to_string(), "majority".to_string());
                 Some(opts)
             },
+            cluster_config: None,
         })
         .pool(pool_config)
         .alias("test_db")
@@ -299,10 +300,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 ) {
                     println!(
                         "    用户: {} - {} ({}), 订单数: {}",
-                        user_name.to_string(),
-                        user_email.to_string(),
-                        user_id.to_string(),
-                        orders_count.to_string()
+                        user_name,
+                        user_email,
+                        user_id,
+                        orders_count
                     );
                 }
             }
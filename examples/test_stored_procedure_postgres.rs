@@ -1,7 +1,7 @@
 //! 存储过程创建测试
 
 #[cfg(feature = "postgres-support")]
-use rat_logger::{LevelFilter, LoggerBuilder, debug, handler::term};
+use rat_logger::{LevelFilter, LoggerBuilder, handler::term};
 #[cfg(feature = "postgres-support")]
 use rat_quickdb::*;
 
@@ -62,7 +62,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     LoggerBuilder::new()
         .with_level(LevelFilter::Info)
         .add_terminal_with_config(term::TermConfig::default())
-        .init()?;
+        .init_global_logger()?;
 
     let pool_config = PoolConfig::builder()
         .max_connections(10)
@@ -86,6 +86,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             password: "testdb".to_string(),
             ssl_mode: Some("prefer".to_string()),
             tls_config: None,
+            socket_path: None,
+            pgbouncer_mode: false,
         })
         .pool(pool_config)
         .alias("test_db")
@@ -237,9 +239,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     println!(
                         "  {}. {} - {} ({})",
                         i + 1,
-                        user_name.to_string(),
-                        user_email.to_string(),
-                        user_id.to_string()
+                        user_name,
+                        user_email,
+                        user_id
                     );
                 }
             }
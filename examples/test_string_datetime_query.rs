@@ -3,7 +3,7 @@
 use rat_quickdb::types::{ConnectionConfig, DatabaseType};
 use rat_quickdb::types::{QueryCondition, QueryOperator};
 use rat_quickdb::*;
-use rat_quickdb::{ModelManager, ModelOperations};
+use rat_quickdb::ModelManager;
 
 // 定义测试模型
 define_model! {
@@ -36,10 +36,16 @@ async fn main() -> QuickDbResult<()> {
         connection: ConnectionConfig::SQLite {
             path: "./string_datetime_test.db".to_string(),
             create_if_missing: true,
+            pragmas: None,
+            encryption_key: None,
         },
         pool: Default::default(),
         cache: None,
         id_strategy: Default::default(),
+        version_storage_path: None,
+        enable_versioning: None,
+        on_connect_statements: Vec::new(),
+        table_naming: None,
     };
 
     add_database(db_config).await?;
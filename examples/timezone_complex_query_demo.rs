@@ -2,7 +2,7 @@
 //!
 //! 展示如何使用SQLite的datetime字段进行时区相关的复杂查询
 
-use chrono::{DateTime, Duration, TimeZone, Utc};
+use chrono::{DateTime, Duration, Utc};
 use rand::Rng;
 use rat_logger::{LoggerBuilder, debug, handler::term::TermConfig};
 use rat_quickdb::manager::shutdown;
@@ -12,7 +12,7 @@ use rat_quickdb::types::{
 };
 use rat_quickdb::*;
 use rat_quickdb::{
-    ModelOperations, boolean_field, datetime_field, float_field, integer_field, string_field,
+    ModelOperations, boolean_field, datetime_field, integer_field, string_field,
 };
 use std::collections::HashMap;
 
@@ -21,7 +21,7 @@ async fn main() -> QuickDbResult<()> {
     // 初始化日志系统
     LoggerBuilder::new()
         .add_terminal_with_config(TermConfig::default())
-        .init()
+        .init_global_logger()
         .expect("日志初始化失败");
 
     println!("=== SQLite 时区复杂查询示例 ===");
@@ -32,6 +32,8 @@ async fn main() -> QuickDbResult<()> {
         .connection(ConnectionConfig::SQLite {
             path: "/tmp/timezone_complex_query_example.db".to_string(),
             create_if_missing: true,
+            pragmas: None,
+            encryption_key: None,
         })
         .pool(
             PoolConfig::builder()
@@ -323,6 +325,7 @@ async fn main() -> QuickDbResult<()> {
         ],
         pagination: Some(PaginationConfig { limit: 5, skip: 0 }),
         fields: vec![],
+        ..Default::default()
     };
 
     let large_event_result = ModelManager::<TimeZoneEvent>::find_with_groups(
@@ -652,7 +655,7 @@ async fn insert_test_data() -> QuickDbResult<()> {
     println!("插入时区事件测试数据...");
 
     let base_time = Utc::now();
-    let test_events = vec![
+    let test_events = [
         create_timezone_event(
             "全球技术峰会",
             "技术会议",
@@ -836,6 +839,7 @@ async fn insert_test_data() -> QuickDbResult<()> {
 }
 
 /// 创建时区事件数据的辅助函数
+#[allow(clippy::too_many_arguments)]
 fn create_timezone_event(
     name: &str,
     event_type: &str,
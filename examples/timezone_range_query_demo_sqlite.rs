@@ -2,12 +2,11 @@
 //!
 //! 展示如何使用时区感知的DateTime字段进行范围查询
 
-use chrono::{DateTime, Utc};
+use chrono::Utc;
 use rat_quickdb::manager::shutdown;
-use rat_quickdb::types::{DataValue, QueryCondition, QueryOperator, QueryOptions};
+use rat_quickdb::types::{DataValue, QueryCondition, QueryOperator};
 use rat_quickdb::*;
-use rat_quickdb::{ModelOperations, datetime_field, integer_field, string_field};
-use std::collections::HashMap;
+use rat_quickdb::{datetime_field, integer_field, string_field};
 
 // 定义用户活动日志模型（使用北京时间）
 define_model! {
@@ -31,10 +30,10 @@ define_model! {
         username: string_field(Some(100), Some(1), None).required(),
         activity_type: string_field(Some(50), Some(1), None).required(),
         description: string_field(Some(500), Some(1), None).required(),
-        beijing_time: datetime_field(Some("+08:00".to_string())).required(),  // 北京时间字段
+        beijing_time: datetime_with_tz_field("+08:00").required(),  // 北京时间字段
         duration_minutes: integer_field(Some(0), Some(1440)).required(),  // 活动持续时间（分钟）
         ip_address: string_field(Some(45), Some(1), None).required(),
-        created_at: datetime_field(Some("+00:00".to_string())).required(),  // UTC时间
+        created_at: datetime_field().required(),  // UTC时间
     }
     indexes = [
         { fields: ["user_id"], unique: false, name: "idx_user_id" },
@@ -66,10 +65,10 @@ define_model! {
         component: string_field(Some(100), Some(1), None).required(),
         severity: string_field(Some(20), Some(1), None).required(),
         message: string_field(Some(1000), Some(1), None).required(),
-        utc_timestamp: datetime_field(Some("+00:00".to_string())).required(),  // UTC时间戳
+        utc_timestamp: datetime_field().required(),  // UTC时间戳
         affected_users: integer_field(Some(0), None).required(),
         resolved: boolean_field().required(),
-        resolved_at: datetime_field(Some("+00:00".to_string())),
+        resolved_at: datetime_field(),
     }
     indexes = [
         { fields: ["event_type"], unique: false, name: "idx_event_type" },
@@ -95,6 +94,8 @@ async fn main() -> QuickDbResult<()> {
         .connection(ConnectionConfig::SQLite {
             path: "./timezone_range_demo.db".to_string(),
             create_if_missing: true,
+            pragmas: None,
+            encryption_key: None,
         })
         .pool(
             PoolConfig::builder()
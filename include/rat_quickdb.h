@@ -0,0 +1,56 @@
+/* 由 cbindgen 根据 src/ffi.rs 生成，请勿手动编辑 */
+
+#ifndef RAT_QUICKDB_H
+#define RAT_QUICKDB_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * 初始化库（i18n 等子系统），可重复调用
+ */
+void rat_quickdb_init(void);
+
+/**
+ * 按 JSON 配置添加数据库
+ *
+ * `config_json` 为 [`DatabaseConfig`] 的 JSON 表示
+ *
+ * # Safety
+ *
+ * `config_json` 必须是以 NUL 结尾的有效字符串指针或空指针
+ */
+char *rat_quickdb_add_database_json(const char *config_json);
+
+/**
+ * 按 JSON 模型元数据注册模型并创建表和索引
+ *
+ * `meta_json` 为 [`ModelMeta`](crate::model::ModelMeta) 的 JSON 表示，必须包含 `database_alias`
+ *
+ * # Safety
+ *
+ * `meta_json` 必须是以 NUL 结尾的有效字符串指针或空指针
+ */
+char *rat_quickdb_register_model_json(const char *meta_json);
+
+/**
+ * 执行一个 JSON 描述的 ODM 操作
+ *
+ * # Safety
+ *
+ * `op_json` 必须是以 NUL 结尾的有效字符串指针或空指针
+ */
+char *rat_quickdb_execute_op_json(const char *op_json);
+
+/**
+ * 释放本库返回的字符串
+ *
+ * # Safety
+ *
+ * `value` 必须是本库函数返回的指针（或空指针），且只能释放一次
+ */
+void rat_quickdb_free_string(char *value);
+
+#endif  /* RAT_QUICKDB_H */
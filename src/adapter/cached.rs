@@ -2,10 +2,10 @@
 //!
 //! 提供带缓存功能的数据库适配器包装器，在适配器层实现缓存逻辑
 
-use super::DatabaseAdapter;
-use crate::cache::CacheManager;
+use super::{BatchCreate, DatabaseAdapter};
+use crate::cache::{CacheManager, CachedRecord};
 use crate::error::QuickDbResult;
-use crate::model::FieldDefinition;
+use crate::model::{FieldDefinition, IndexOptions};
 use crate::pool::DatabaseConnection;
 use crate::types::*;
 use async_trait::async_trait;
@@ -29,6 +29,87 @@ impl CachedDatabaseAdapter {
             cache_manager,
         }
     }
+
+    /// 缓存使用的记录ID（仅整数与字符串ID参与记录缓存）
+    fn cache_id(id: &DataValue) -> Option<IdType> {
+        match id {
+            DataValue::Int(n) => Some(IdType::Number(*n)),
+            DataValue::String(s) => Some(IdType::String(s.clone())),
+            _ => None,
+        }
+    }
+
+    /// 写入记录后使用的记录ID：优先取写入结果，其次取写入的数据
+    fn written_record_id(data: &HashMap<String, DataValue>, result: &DataValue) -> Option<IdType> {
+        match result {
+            DataValue::Object(map) => map.get("id").or_else(|| map.get("_id")),
+            scalar => Some(scalar),
+        }
+        .or_else(|| data.get("id").or_else(|| data.get("_id")))
+        .and_then(Self::cache_id)
+    }
+
+    /// 创建记录成功后的缓存处理
+    async fn after_create(
+        &self,
+        connection: &DatabaseConnection,
+        table: &str,
+        data: &HashMap<String, DataValue>,
+        created: &DataValue,
+        alias: &str,
+    ) {
+        // 创建成功后清理查询缓存，按写入模式同步记录缓存
+        if let Err(e) = self.cache_manager.clear_table_query_cache(table).await {
+            warn!("清理表查询缓存失败: {}", e);
+        }
+        debug!("已清理表查询缓存: table={}", table);
+
+        // 新记录只可能残留负缓存标记；写绕模式且未启用负缓存时无需处理
+        let needs_sync = self.cache_manager.write_mode() == CacheWriteMode::WriteThrough
+            || self.cache_manager.negative_cache_enabled();
+        if !needs_sync {
+            return;
+        }
+        if let Some(id) = Self::written_record_id(data, created) {
+            self.sync_written_record(connection, table, id, alias).await;
+        }
+    }
+
+    /// 按ID写入记录后同步记录缓存
+    ///
+    /// 写穿模式下从数据库读回最新记录写入缓存（同时覆盖负缓存标记）；
+    /// 其他情况下失效该记录的缓存
+    async fn sync_written_record(
+        &self,
+        connection: &DatabaseConnection,
+        table: &str,
+        id: IdType,
+        alias: &str,
+    ) {
+        if self.cache_manager.write_mode() == CacheWriteMode::WriteThrough
+            && self.cache_manager.caches_table(table)
+        {
+            let id_value = match &id {
+                IdType::Number(n) => DataValue::Int(*n),
+                IdType::String(s) => DataValue::String(s.clone()),
+            };
+            match self.inner.find_by_id(connection, table, &id_value, alias).await {
+                Ok(Some(record)) => match self.cache_manager.cache_record(table, &id, &record).await {
+                    Ok(()) => {
+                        debug!("已写穿记录缓存: table={}, id={:?}", table, id);
+                        return;
+                    }
+                    Err(e) => warn!("写穿记录缓存失败: {}", e),
+                },
+                Ok(None) => {}
+                Err(e) => warn!("写穿读回记录失败: {}", e),
+            }
+        }
+
+        if let Err(e) = self.cache_manager.invalidate_record(table, &id).await {
+            warn!("清理记录缓存失败: {}", e);
+        }
+    }
 }
 
 #[async_trait]
@@ -47,17 +128,31 @@ impl DatabaseAdapter for CachedDatabaseAdapter {
             .create(connection, table, data, id_strategy, alias)
             .await;
 
-        // 创建成功后只清理查询缓存，保留记录缓存
-        if result.is_ok() {
-            if let Err(e) = self.cache_manager.clear_table_query_cache(table).await {
-                warn!("清理表查询缓存失败: {}", e);
-            }
-            debug!("已清理表查询缓存: table={}", table);
+        if let Ok(created) = &result {
+            self.after_create(connection, table, data, created, alias).await;
         }
 
         result
     }
 
+    /// 批量创建记录 - 对每条成功的记录执行与单条创建相同的缓存处理
+    async fn create_batch(
+        &self,
+        connection: &DatabaseConnection,
+        items: &[BatchCreate],
+    ) -> Vec<QuickDbResult<DataValue>> {
+        let results = self.inner.create_batch(connection, items).await;
+
+        for (item, result) in items.iter().zip(&results) {
+            if let Ok(created) = result {
+                self.after_create(connection, &item.table, &item.data, created, &item.alias)
+                    .await;
+            }
+        }
+
+        results
+    }
+
     /// 根据ID查找记录 - 先检查缓存，缓存未命中时查询数据库并缓存结果
     async fn find_by_id(
         &self,
@@ -67,21 +162,21 @@ impl DatabaseAdapter for CachedDatabaseAdapter {
         alias: &str,
     ) -> QuickDbResult<Option<DataValue>> {
         // 将DataValue转换为IdType
-        let id_type = match id {
-            DataValue::Int(n) => IdType::Number(*n),
-            DataValue::String(s) => IdType::String(s.clone()),
-            _ => {
-                warn!("无法将DataValue转换为IdType: {:?}", id);
-                return self.inner.find_by_id(connection, table, id, alias).await;
-            }
+        let Some(id_type) = Self::cache_id(id) else {
+            warn!("无法将DataValue转换为IdType: {:?}", id);
+            return self.inner.find_by_id(connection, table, id, alias).await;
         };
 
         // 先检查缓存
-        match self.cache_manager.get_cached_record(table, &id_type).await {
-            Ok(Some(cached_result)) => {
+        match self.cache_manager.lookup_cached_record(table, &id_type).await {
+            Ok(Some(CachedRecord::Found(cached_result))) => {
                 debug!("缓存命中: 表={}, ID={:?}", table, id);
                 return Ok(Some(cached_result));
             }
+            Ok(Some(CachedRecord::Missing)) => {
+                debug!("负缓存命中（记录不存在）: 表={}, ID={:?}", table, id);
+                return Ok(None);
+            }
             Ok(None) => {
                 debug!("缓存未命中: 表={}, ID={:?}", table, id);
             }
@@ -93,20 +188,46 @@ impl DatabaseAdapter for CachedDatabaseAdapter {
         // 缓存未命中或查询失败，查询数据库
         let result = self.inner.find_by_id(connection, table, id, alias).await;
 
-        // 查询成功时缓存结果
-        if let Ok(Some(ref record)) = result {
-            if let Err(e) = self
-                .cache_manager
-                .cache_record(table, &id_type, record)
-                .await
-            {
-                warn!("缓存记录失败: {}", e);
+        // 查询成功时缓存结果，记录不存在时按配置写入负缓存
+        match result {
+            Ok(Some(ref record)) => {
+                if let Err(e) = self
+                    .cache_manager
+                    .cache_record(table, &id_type, record)
+                    .await
+                {
+                    warn!("缓存记录失败: {}", e);
+                }
+            }
+            Ok(None) => {
+                if let Err(e) = self.cache_manager.cache_missing_record(table, &id_type).await {
+                    warn!("缓存记录不存在标记失败: {}", e);
+                }
             }
+            Err(_) => {}
         }
 
         result
     }
 
+    /// 根据ID检查记录是否存在 - 缓存命中时直接返回，否则交由数据库判断（不写入缓存）
+    async fn exists_by_id(
+        &self,
+        connection: &DatabaseConnection,
+        table: &str,
+        id: &DataValue,
+        alias: &str,
+    ) -> QuickDbResult<bool> {
+        if let Some(id_type) = Self::cache_id(id) {
+            if let Ok(Some(cached)) = self.cache_manager.lookup_cached_record(table, &id_type).await {
+                debug!("缓存命中（存在性检查）: 表={}, ID={:?}", table, id);
+                return Ok(matches!(cached, CachedRecord::Found(_)));
+            }
+        }
+
+        self.inner.exists_by_id(connection, table, id, alias).await
+    }
+
     /// 查找记录（支持缓存控制）- 内部统一使用 find_with_groups_with_cache_control_and_config 实现
     async fn find_with_cache_control(
         &self,
@@ -307,27 +428,48 @@ impl DatabaseAdapter for CachedDatabaseAdapter {
 
         // 更新成功后精确清理相关缓存
         if let Ok(true) = result {
-            // 清理特定记录的缓存
-            let id_value = match id {
-                DataValue::Int(n) => IdType::Number(*n),
-                DataValue::String(s) => IdType::String(s.clone()),
-                _ => {
-                    warn!("无法将DataValue转换为IdType: {:?}", id);
-                    return result;
-                }
+            // 同步特定记录的缓存
+            let Some(id_value) = Self::cache_id(id) else {
+                warn!("无法将DataValue转换为IdType: {:?}", id);
+                return result;
             };
-
-            // 清理记录缓存
-            if let Err(e) = self.cache_manager.invalidate_record(table, &id_value).await {
-                warn!("清理记录缓存失败: {}", e);
-            }
+            self.sync_written_record(connection, table, id_value, alias).await;
 
             // 只清理查询缓存，不清理其他记录缓存
             if let Err(e) = self.cache_manager.clear_table_query_cache(table).await {
                 warn!("清理表查询缓存失败: {}", e);
             }
 
-            debug!("已清理记录和查询缓存: table={}, id={:?}", table, id);
+            debug!("已同步记录缓存并清理查询缓存: table={}, id={:?}", table, id);
+        }
+
+        result
+    }
+
+    /// 根据ID更新并返回记录 - 更新成功后精确清理相关缓存
+    async fn update_by_id_returning(
+        &self,
+        connection: &DatabaseConnection,
+        table: &str,
+        id: &DataValue,
+        data: &HashMap<String, DataValue>,
+        alias: &str,
+    ) -> QuickDbResult<Option<DataValue>> {
+        let result = self
+            .inner
+            .update_by_id_returning(connection, table, id, data, alias)
+            .await;
+
+        if let Ok(Some(_)) = result {
+            let Some(id_value) = Self::cache_id(id) else {
+                warn!("无法将DataValue转换为IdType: {:?}", id);
+                return result;
+            };
+            self.sync_written_record(connection, table, id_value, alias).await;
+
+            if let Err(e) = self.cache_manager.clear_table_query_cache(table).await {
+                warn!("清理表查询缓存失败: {}", e);
+            }
         }
 
         result
@@ -348,12 +490,15 @@ impl DatabaseAdapter for CachedDatabaseAdapter {
             .upsert(connection, table, data, id_strategy, conflict_columns, alias)
             .await;
 
-        // Upsert成功后清理查询缓存
-        if result.is_ok() {
+        // Upsert成功后清理查询缓存，并同步可能被覆盖的记录缓存
+        if let Ok(upserted) = &result {
             if let Err(e) = self.cache_manager.clear_table_query_cache(table).await {
                 warn!("清理表查询缓存失败: {}", e);
             }
             debug!("已清理表查询缓存(upsert): table={}", table);
+            if let Some(id) = Self::written_record_id(data, upserted) {
+                self.sync_written_record(connection, table, id, alias).await;
+            }
         }
 
         result
@@ -432,6 +577,60 @@ impl DatabaseAdapter for CachedDatabaseAdapter {
         result
     }
 
+    /// 批量写入 - 有记录被写入时清理整张表的记录与查询缓存
+    async fn bulk_write(
+        &self,
+        connection: &DatabaseConnection,
+        table: &str,
+        ops: &[BulkOp],
+        mode: BulkWriteMode,
+        id_strategy: &IdStrategy,
+        alias: &str,
+    ) -> QuickDbResult<BulkWriteReport> {
+        let result = self
+            .inner
+            .bulk_write(connection, table, ops, mode, id_strategy, alias)
+            .await;
+
+        if result.as_ref().is_ok_and(|report| report.affected_rows() > 0) {
+            if let Err(e) = self.cache_manager.clear_table_record_cache(table).await {
+                warn!("清理表记录缓存失败: {}", e);
+            }
+            if let Err(e) = self.cache_manager.clear_table_query_cache(table).await {
+                warn!("清理表查询缓存失败: {}", e);
+            }
+            debug!("批量写入后已清理表缓存: table={}", table);
+        }
+
+        result
+    }
+
+    /// 根据ID删除并返回记录 - 删除成功后精确清理相关缓存
+    async fn delete_by_id_returning(
+        &self,
+        connection: &DatabaseConnection,
+        table: &str,
+        id: &DataValue,
+        alias: &str,
+    ) -> QuickDbResult<Option<DataValue>> {
+        let result = self.inner.delete_by_id_returning(connection, table, id, alias).await;
+
+        if let Ok(Some(_)) = result {
+            let Some(id_value) = Self::cache_id(id) else {
+                warn!("无法将DataValue转换为IdType: {:?}", id);
+                return result;
+            };
+            if let Err(e) = self.cache_manager.invalidate_record(table, &id_value).await {
+                warn!("清理记录缓存失败: {}", e);
+            }
+            if let Err(e) = self.cache_manager.clear_table_query_cache(table).await {
+                warn!("清理表查询缓存失败: {}", e);
+            }
+        }
+
+        result
+    }
+
     /// 统计记录数量 - 直接调用内部适配器，不缓存统计结果
     async fn count(
         &self,
@@ -479,10 +678,11 @@ impl DatabaseAdapter for CachedDatabaseAdapter {
         index_name: &str,
         fields: &[String],
         unique: bool,
+        options: &IndexOptions,
     ) -> QuickDbResult<()> {
         // 索引操作不缓存，直接调用内部适配器
         self.inner
-            .create_index(connection, table, index_name, fields, unique)
+            .create_index(connection, table, index_name, fields, unique, options)
             .await
     }
 
@@ -495,6 +695,15 @@ impl DatabaseAdapter for CachedDatabaseAdapter {
         self.inner.table_exists(connection, table).await
     }
 
+    /// 估算记录数 - 统计信息本身已是近似值，不缓存
+    async fn count_estimate(
+        &self,
+        connection: &DatabaseConnection,
+        table: &str,
+    ) -> QuickDbResult<CountEstimate> {
+        self.inner.count_estimate(connection, table).await
+    }
+
     /// 删除表 - 删除成功后清理所有相关缓存
     async fn drop_table(&self, connection: &DatabaseConnection, table: &str) -> QuickDbResult<()> {
         let result = self.inner.drop_table(connection, table).await;
@@ -525,6 +734,15 @@ impl DatabaseAdapter for CachedDatabaseAdapter {
         self.inner.create_stored_procedure(connection, config).await
     }
 
+    /// 删除存储过程 - 直接调用内部适配器
+    async fn drop_stored_procedure(
+        &self,
+        connection: &DatabaseConnection,
+        procedure_name: &str,
+    ) -> QuickDbResult<bool> {
+        self.inner.drop_stored_procedure(connection, procedure_name).await
+    }
+
     /// 执行存储过程 - 直接调用内部适配器
     async fn execute_stored_procedure(
         &self,
@@ -539,3 +757,59 @@ impl DatabaseAdapter for CachedDatabaseAdapter {
             .await
     }
 }
+
+#[cfg(all(test, feature = "sqlite-support"))]
+mod tests {
+    use super::*;
+    use crate::manager::PoolManager;
+
+    #[tokio::test]
+    async fn write_through_populates_the_record_cache() {
+        crate::i18n::ErrorMessageI18n::init_i18n();
+        let alias = "cached_write_through";
+        let manager = PoolManager::new();
+        manager
+            .add_database(DatabaseConfig {
+                cache: Some(CacheConfig {
+                    write_mode: CacheWriteMode::WriteThrough,
+                    ..crate::cache::test_cache_config(None)
+                }),
+                ..DatabaseConfig::sqlite_memory_for_test(alias)
+            })
+            .await
+            .unwrap();
+        crate::manager::register_model(crate::model::ModelMeta {
+            collection_name: "notes".to_string(),
+            database_alias: Some(alias.to_string()),
+            fields: HashMap::from([
+                ("id".to_string(), crate::model::integer_field(None, None)),
+                ("title".to_string(), crate::model::string_field(Some(32), None, None)),
+            ]),
+            ..Default::default()
+        })
+        .unwrap();
+        let pool = manager.get_connection_pools().get(alias).unwrap().clone();
+
+        let data = HashMap::from([("title".to_string(), DataValue::String("draft".to_string()))]);
+        let id = match pool.create("notes", &data, &IdStrategy::AutoIncrement).await.unwrap() {
+            DataValue::Object(record) => record["id"].clone(),
+            other => other,
+        };
+
+        // 写入后记录已在缓存中
+        let cache = pool.cache_manager.clone().unwrap();
+        let cached = cache.get_cached_record("notes", &CachedDatabaseAdapter::cache_id(&id).unwrap()).await.unwrap();
+        assert!(matches!(cached, Some(DataValue::Object(ref record)) if record["title"] == DataValue::String("draft".to_string())));
+
+        // 绕过适配器修改数据库后，读取仍由缓存提供
+        let params = [id.clone()];
+        manager
+            .with_connection(Some(alias), |mut conn| async move {
+                conn.execute("UPDATE notes SET title = 'edited' WHERE id = ?", &params).await
+            })
+            .await
+            .unwrap();
+        let found = pool.find_by_id("notes", &id).await.unwrap();
+        assert!(matches!(found, Some(DataValue::Object(ref record)) if record["title"] == DataValue::String("draft".to_string())));
+    }
+}
@@ -0,0 +1,836 @@
+//! 模拟数据库适配器
+//!
+//! 启用 `testing` 特性后提供 [`MockAdapter`]：数据保存在内存中的 HashMap，
+//! 完整实现 `DatabaseAdapter`（条件、条件组合、排序、分页、字段选择、
+//! 唯一索引），通过 `PoolManager::add_mock_database` 注册到任意别名后，
+//! 业务代码经 ODM 与模型的所有读写都落到内存中，无需真实数据库。
+//!
+//! 比较语义参照 SQLite：字段缺失与空值不满足任何比较条件，
+//! 数字字符串可以与数值比较，升序排序时空值在前
+
+use crate::adapter::DatabaseAdapter;
+use crate::error::{QuickDbError, QuickDbResult};
+use crate::model::{FieldDefinition, IndexOptions};
+use crate::pool::DatabaseConnection;
+use crate::types::query::{between_bounds, regex_pattern};
+use crate::types::*;
+use async_trait::async_trait;
+use rat_logger::debug;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+type Record = HashMap<String, DataValue>;
+
+/// 内存中的表
+#[derive(Debug, Default)]
+struct MockTable {
+    /// 记录（按插入顺序）
+    records: Vec<Record>,
+    /// 下一个自增 ID
+    next_id: i64,
+    /// 唯一索引：索引名 → 字段列表
+    unique_indexes: HashMap<String, Vec<String>>,
+    /// 普通索引名
+    indexes: Vec<String>,
+}
+
+impl MockTable {
+    /// 检查记录是否与其他记录违反唯一索引（`skip` 为记录自身的位置）
+    fn check_unique(&self, table: &str, record: &Record, skip: Option<usize>) -> QuickDbResult<()> {
+        let id_fields = vec!["id".to_string()];
+        let constraints = std::iter::once(&id_fields).chain(self.unique_indexes.values());
+        for fields in constraints {
+            let key: Vec<&DataValue> = fields.iter().filter_map(|field| record.get(field)).collect();
+            // 与 SQL 一致，唯一键含空值时不参与唯一性检查
+            if key.len() != fields.len() || key.iter().any(|value| value.is_null()) {
+                continue;
+            }
+            let duplicated = self.records.iter().enumerate().any(|(index, other)| {
+                Some(index) != skip
+                    && fields
+                        .iter()
+                        .zip(&key)
+                        .all(|(field, value)| other.get(field).is_some_and(|other| values_equal(other, value)))
+            });
+            if duplicated {
+                return Err(QuickDbError::UniqueViolation {
+                    table: table.to_string(),
+                    fields: fields.clone(),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 内存数据库适配器
+///
+/// 克隆得到的是同一份数据的句柄，测试中可保留一份用于预置或检查数据
+#[derive(Debug, Clone, Default)]
+pub struct MockAdapter {
+    tables: Arc<Mutex<HashMap<String, MockTable>>>,
+}
+
+impl MockAdapter {
+    /// 创建空的模拟适配器
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 直接写入记录（不经过 ID 生成与唯一性检查），用于预置测试数据
+    pub fn insert_records(&self, table: &str, records: Vec<HashMap<String, DataValue>>) {
+        let mut tables = self.tables();
+        let table = tables.entry(table.to_string()).or_default();
+        for record in &records {
+            if let Some(DataValue::Int(id)) = record.get("id") {
+                table.next_id = table.next_id.max(*id);
+            }
+        }
+        table.records.extend(records);
+    }
+
+    /// 获取表中的全部记录（按插入顺序）
+    pub fn records(&self, table: &str) -> Vec<HashMap<String, DataValue>> {
+        self.tables()
+            .get(table)
+            .map(|table| table.records.clone())
+            .unwrap_or_default()
+    }
+
+    /// 清空所有表
+    pub fn clear(&self) {
+        self.tables().clear();
+    }
+
+    fn tables(&self) -> MutexGuard<'_, HashMap<String, MockTable>> {
+        // 持锁期间不会 panic，锁中毒时数据仍然可用
+        self.tables.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn insert(
+        &self,
+        table_name: &str,
+        data: &Record,
+        id_strategy: &IdStrategy,
+    ) -> QuickDbResult<DataValue> {
+        let mut tables = self.tables();
+        let table = tables.entry(table_name.to_string()).or_default();
+
+        let mut record = data.clone();
+        if record.get("id").is_none_or(DataValue::is_null) {
+            let id = match id_strategy {
+                IdStrategy::AutoIncrement => {
+                    table.next_id += 1;
+                    DataValue::Int(table.next_id)
+                }
+                _ => DataValue::String(uuid::Uuid::new_v4().to_string()),
+            };
+            record.insert("id".to_string(), id);
+        } else if let Some(DataValue::Int(id)) = record.get("id") {
+            table.next_id = table.next_id.max(*id);
+        }
+        table.check_unique(table_name, &record, None)?;
+
+        let id = record["id"].clone();
+        table.records.push(record);
+        Ok(id)
+    }
+
+    /// 按条件组筛选、排序、分页并选择字段
+    fn select(
+        &self,
+        table: &str,
+        groups: &[QueryConditionGroupWithConfig],
+        options: &QueryOptions,
+    ) -> QuickDbResult<Vec<DataValue>> {
+        let tables = self.tables();
+        let Some(table) = tables.get(table) else {
+            return Ok(Vec::new());
+        };
+
+        let mut matched = Vec::new();
+        for record in &table.records {
+            if matches_all(record, groups)? {
+                matched.push(record);
+            }
+        }
+        for sort in options.sort.iter().rev() {
+            // 稳定排序，从最后一个排序键开始排即得到多键排序结果
+            matched.sort_by(|a, b| {
+                let ordering = sort_values(a.get(&sort.field), b.get(&sort.field));
+                match sort.direction {
+                    SortDirection::Asc => ordering,
+                    SortDirection::Desc => ordering.reverse(),
+                }
+            });
+        }
+
+        let (skip, limit) = match &options.pagination {
+            Some(pagination) => (pagination.skip as usize, pagination.limit as usize),
+            None => (0, usize::MAX),
+        };
+        Ok(matched
+            .into_iter()
+            .skip(skip)
+            .take(limit)
+            .map(|record| {
+                if options.fields.is_empty() {
+                    DataValue::Object(record.clone())
+                } else {
+                    DataValue::Object(
+                        record
+                            .iter()
+                            .filter(|(field, _)| options.fields.contains(field))
+                            .map(|(field, value)| (field.clone(), value.clone()))
+                            .collect(),
+                    )
+                }
+            })
+            .collect())
+    }
+
+    /// 修改满足条件的记录，返回修改数量
+    fn modify(
+        &self,
+        table_name: &str,
+        groups: &[QueryConditionGroupWithConfig],
+        apply: impl Fn(&mut Record) -> QuickDbResult<()>,
+    ) -> QuickDbResult<u64> {
+        let mut tables = self.tables();
+        let Some(table) = tables.get_mut(table_name) else {
+            return Ok(0);
+        };
+
+        let mut positions = Vec::new();
+        for (index, record) in table.records.iter().enumerate() {
+            if matches_all(record, groups)? {
+                positions.push(index);
+            }
+        }
+        // 先在副本上修改并检查唯一性，任一失败时不写入任何修改
+        let mut updated = Vec::with_capacity(positions.len());
+        for &index in &positions {
+            let mut record = table.records[index].clone();
+            apply(&mut record)?;
+            table.check_unique(table_name, &record, Some(index))?;
+            updated.push(record);
+        }
+        for (index, record) in positions.iter().zip(updated) {
+            table.records[*index] = record;
+        }
+        Ok(positions.len() as u64)
+    }
+
+    fn remove(&self, table: &str, groups: &[QueryConditionGroupWithConfig]) -> QuickDbResult<u64> {
+        let mut tables = self.tables();
+        let Some(table) = tables.get_mut(table) else {
+            return Ok(0);
+        };
+
+        let mut matched = Vec::with_capacity(table.records.len());
+        for record in &table.records {
+            matched.push(matches_all(record, groups)?);
+        }
+        let before = table.records.len();
+        let mut matched = matched.into_iter();
+        table.records.retain(|_| !matched.next().unwrap_or(false));
+        Ok((before - table.records.len()) as u64)
+    }
+}
+
+fn single_groups(conditions: &[QueryConditionWithConfig]) -> Vec<QueryConditionGroupWithConfig> {
+    conditions
+        .iter()
+        .cloned()
+        .map(QueryConditionGroupWithConfig::Single)
+        .collect()
+}
+
+fn id_groups(id: &DataValue) -> Vec<QueryConditionGroupWithConfig> {
+    single_groups(&[QueryConditionWithConfig {
+        field: "id".to_string(),
+        operator: QueryOperator::Eq,
+        value: id.clone(),
+        case_insensitive: false,
+    }])
+}
+
+/// 记录是否满足所有条件组（条件组之间为 AND）
+fn matches_all(record: &Record, groups: &[QueryConditionGroupWithConfig]) -> QuickDbResult<bool> {
+    for group in groups {
+        if !matches_group(record, group)? {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+fn matches_group(record: &Record, group: &QueryConditionGroupWithConfig) -> QuickDbResult<bool> {
+    match group {
+        QueryConditionGroupWithConfig::Single(condition) => matches_condition(record, condition),
+        QueryConditionGroupWithConfig::GroupWithConfig { operator, conditions } => match operator {
+            LogicalOperator::And => matches_all(record, conditions),
+            LogicalOperator::Or => {
+                for condition in conditions {
+                    if matches_group(record, condition)? {
+                        return Ok(true);
+                    }
+                }
+                Ok(false)
+            }
+        },
+    }
+}
+
+fn matches_condition(record: &Record, condition: &QueryConditionWithConfig) -> QuickDbResult<bool> {
+    let value = record.get(&condition.field).filter(|value| !value.is_null());
+    let target = &condition.value;
+    let fold = |s: &str| {
+        if condition.case_insensitive {
+            s.to_lowercase()
+        } else {
+            s.to_string()
+        }
+    };
+    let text = |value: &DataValue| match value {
+        DataValue::String(s) => Some(fold(s)),
+        DataValue::Uuid(uuid) => Some(uuid.to_string()),
+        _ => None,
+    };
+    let compare = |value: &DataValue| match (text(value), text(target)) {
+        (Some(a), Some(b)) if condition.case_insensitive => Some(a.cmp(&b)),
+        _ => compare_values(value, target),
+    };
+
+    let matched = match condition.operator {
+        QueryOperator::IsNull => value.is_none(),
+        QueryOperator::IsNotNull | QueryOperator::Exists => value.is_some(),
+        _ => {
+            let Some(value) = value else {
+                return Ok(false);
+            };
+            match condition.operator {
+                QueryOperator::Eq => compare(value) == Some(Ordering::Equal),
+                QueryOperator::Ne => compare(value).is_some_and(|o| o != Ordering::Equal),
+                QueryOperator::Gt => compare(value) == Some(Ordering::Greater),
+                QueryOperator::Gte => compare(value).is_some_and(|o| o != Ordering::Less),
+                QueryOperator::Lt => compare(value) == Some(Ordering::Less),
+                QueryOperator::Lte => compare(value).is_some_and(|o| o != Ordering::Greater),
+                QueryOperator::Contains => match (value, text(value), text(target)) {
+                    (DataValue::Array(items), _, _) => items.iter().any(|item| values_equal(item, target)),
+                    (_, Some(a), Some(b)) => a.contains(&b),
+                    _ => false,
+                },
+                QueryOperator::StartsWith => matches!((text(value), text(target)), (Some(a), Some(b)) if a.starts_with(&b)),
+                QueryOperator::EndsWith => matches!((text(value), text(target)), (Some(a), Some(b)) if a.ends_with(&b)),
+                QueryOperator::JsonContains => json_contains(&value.to_json_value(), &target.to_json_value()),
+                QueryOperator::In | QueryOperator::NotIn => {
+                    let DataValue::Array(items) = target else {
+                        return Err(QuickDbError::ValidationError {
+                            field: condition.field.clone(),
+                            message: format!("{:?} 操作符需要数组值", condition.operator),
+                        });
+                    };
+                    let found = items.iter().any(|item| compare_values(value, item) == Some(Ordering::Equal));
+                    found == (condition.operator == QueryOperator::In)
+                }
+                QueryOperator::Regex => {
+                    let pattern = regex_pattern(&condition.field, target)?;
+                    let regex = regex::RegexBuilder::new(pattern)
+                        .case_insensitive(condition.case_insensitive)
+                        .build()
+                        .map_err(|e| QuickDbError::ValidationError {
+                            field: condition.field.clone(),
+                            message: e.to_string(),
+                        })?;
+                    matches!(value, DataValue::String(s) if regex.is_match(s))
+                }
+                QueryOperator::Between | QueryOperator::NotBetween => {
+                    let (lower, upper) = between_bounds(&condition.field, target)?;
+                    let within = compare_values(value, lower).is_some_and(|o| o != Ordering::Less)
+                        && compare_values(value, upper).is_some_and(|o| o != Ordering::Greater);
+                    within == (condition.operator == QueryOperator::Between)
+                }
+                QueryOperator::IsNull | QueryOperator::IsNotNull | QueryOperator::Exists => unreachable!(),
+            }
+        }
+    };
+    Ok(matched)
+}
+
+/// JSON 包含判断：对象逐键包含，数组包含全部元素，其余按值相等
+fn json_contains(value: &serde_json::Value, needle: &serde_json::Value) -> bool {
+    use serde_json::Value;
+    match (value, needle) {
+        (Value::Object(value), Value::Object(needle)) => needle
+            .iter()
+            .all(|(key, item)| value.get(key).is_some_and(|v| json_contains(v, item))),
+        (Value::Array(value), Value::Array(needle)) => {
+            needle.iter().all(|item| value.iter().any(|v| json_contains(v, item)))
+        }
+        (Value::Array(value), needle) => value.iter().any(|v| json_contains(v, needle)),
+        (value, needle) => value == needle,
+    }
+}
+
+fn as_f64(value: &DataValue) -> Option<f64> {
+    match value {
+        DataValue::Int(i) => Some(*i as f64),
+        DataValue::UInt(u) => Some(*u as f64),
+        DataValue::Float(f) => Some(*f),
+        DataValue::Bool(b) => Some(*b as i64 as f64),
+        DataValue::String(s) => s.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+fn as_utc(value: &DataValue) -> Option<chrono::DateTime<chrono::Utc>> {
+    match value {
+        DataValue::DateTime(dt) => Some(dt.with_timezone(&chrono::Utc)),
+        DataValue::DateTimeUTC(dt) => Some(*dt),
+        DataValue::String(s) => chrono::DateTime::parse_from_rfc3339(s)
+            .ok()
+            .map(|dt| dt.with_timezone(&chrono::Utc)),
+        _ => None,
+    }
+}
+
+/// 比较两个值，类型不可比较时返回 `None`
+fn compare_values(a: &DataValue, b: &DataValue) -> Option<Ordering> {
+    match (a, b) {
+        (DataValue::Null, _) | (_, DataValue::Null) => None,
+        (DataValue::Int(a), DataValue::Int(b)) => Some(a.cmp(b)),
+        (DataValue::String(a), DataValue::String(b)) => Some(a.cmp(b)),
+        (DataValue::Uuid(a), DataValue::Uuid(b)) => Some(a.cmp(b)),
+        (DataValue::Uuid(uuid), DataValue::String(s)) => Some(uuid.to_string().cmp(&s.to_lowercase())),
+        (DataValue::String(s), DataValue::Uuid(uuid)) => Some(s.to_lowercase().cmp(&uuid.to_string())),
+        (DataValue::DateTime(_) | DataValue::DateTimeUTC(_), _)
+        | (_, DataValue::DateTime(_) | DataValue::DateTimeUTC(_)) => as_utc(a)?.partial_cmp(&as_utc(b)?),
+        (DataValue::Int(_) | DataValue::UInt(_) | DataValue::Float(_) | DataValue::Bool(_), _)
+        | (_, DataValue::Int(_) | DataValue::UInt(_) | DataValue::Float(_) | DataValue::Bool(_)) => {
+            as_f64(a)?.partial_cmp(&as_f64(b)?)
+        }
+        _ => (a.to_json_value() == b.to_json_value()).then_some(Ordering::Equal),
+    }
+}
+
+fn values_equal(a: &DataValue, b: &DataValue) -> bool {
+    compare_values(a, b) == Some(Ordering::Equal)
+}
+
+/// 排序比较：空值（含缺失）最小，不可比较的值视为相等
+fn sort_values(a: Option<&DataValue>, b: Option<&DataValue>) -> Ordering {
+    let a = a.filter(|value| !value.is_null());
+    let b = b.filter(|value| !value.is_null());
+    match (a, b) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Less,
+        (Some(_), None) => Ordering::Greater,
+        (Some(a), Some(b)) => compare_values(a, b).unwrap_or(Ordering::Equal),
+    }
+}
+
+/// 按更新操作计算字段的新值
+fn apply_operation(record: &mut Record, operation: &UpdateOperation) -> QuickDbResult<()> {
+    match (&operation.operation, &operation.value) {
+        (UpdateOperator::Set, value) => {
+            record.insert(operation.field.clone(), value.clone());
+            return Ok(());
+        }
+        (UpdateOperator::Unset, _) => {
+            record.remove(&operation.field);
+            return Ok(());
+        }
+        (UpdateOperator::Rename, DataValue::String(target)) => {
+            if let Some(value) = record.remove(&operation.field) {
+                record.insert(target.clone(), value);
+            }
+            return Ok(());
+        }
+        (UpdateOperator::Rename, _) => {
+            return Err(QuickDbError::ValidationError {
+                field: operation.field.clone(),
+                message: crate::i18n::tf("model.rename_target_invalid", &[("field", &operation.field)]),
+            });
+        }
+        _ => {}
+    }
+
+    let current = record.get(&operation.field).cloned().unwrap_or(DataValue::Int(0));
+    let invalid = || QuickDbError::ValidationError {
+        field: operation.field.clone(),
+        message: format!("{:?} 操作需要数值字段和数值参数", operation.operation),
+    };
+    let numeric = |value: &DataValue| {
+        matches!(value, DataValue::Int(_) | DataValue::UInt(_) | DataValue::Float(_))
+            .then(|| as_f64(value))
+            .flatten()
+    };
+    let (Some(current_value), Some(operand)) = (numeric(&current), numeric(&operation.value)) else {
+        return Err(invalid());
+    };
+    let result = match operation.operation {
+        UpdateOperator::Increment => current_value + operand,
+        UpdateOperator::Decrement => current_value - operand,
+        UpdateOperator::Multiply => current_value * operand,
+        UpdateOperator::Divide => current_value / operand,
+        UpdateOperator::PercentIncrease => current_value * (1.0 + operand / 100.0),
+        UpdateOperator::PercentDecrease => current_value * (1.0 - operand / 100.0),
+        UpdateOperator::Set | UpdateOperator::Unset | UpdateOperator::Rename => unreachable!(),
+    };
+
+    // 整数字段在加减整数时保持整数类型
+    let integral = matches!(current, DataValue::Int(_))
+        && matches!(operation.value, DataValue::Int(_))
+        && matches!(operation.operation, UpdateOperator::Increment | UpdateOperator::Decrement);
+    let value = if integral {
+        DataValue::Int(result as i64)
+    } else {
+        DataValue::Float(result)
+    };
+    record.insert(operation.field.clone(), value);
+    Ok(())
+}
+
+#[async_trait]
+impl DatabaseAdapter for MockAdapter {
+    async fn create(
+        &self,
+        _connection: &DatabaseConnection,
+        table: &str,
+        data: &HashMap<String, DataValue>,
+        id_strategy: &IdStrategy,
+        _alias: &str,
+    ) -> QuickDbResult<DataValue> {
+        self.insert(table, data, id_strategy)
+    }
+
+    async fn find_by_id(
+        &self,
+        _connection: &DatabaseConnection,
+        table: &str,
+        id: &DataValue,
+        _alias: &str,
+    ) -> QuickDbResult<Option<DataValue>> {
+        Ok(self.select(table, &id_groups(id), &QueryOptions::default())?.pop())
+    }
+
+    async fn find_with_cache_control(
+        &self,
+        _connection: &DatabaseConnection,
+        table: &str,
+        conditions: &[QueryConditionWithConfig],
+        options: &QueryOptions,
+        _alias: &str,
+        _bypass_cache: bool,
+    ) -> QuickDbResult<Vec<DataValue>> {
+        self.select(table, &single_groups(conditions), options)
+    }
+
+    async fn find_with_groups_with_cache_control_and_config(
+        &self,
+        _connection: &DatabaseConnection,
+        table: &str,
+        condition_groups: &[QueryConditionGroupWithConfig],
+        options: &QueryOptions,
+        _alias: &str,
+        _bypass_cache: bool,
+    ) -> QuickDbResult<Vec<DataValue>> {
+        self.select(table, condition_groups, options)
+    }
+
+    async fn update(
+        &self,
+        _connection: &DatabaseConnection,
+        table: &str,
+        conditions: &[QueryConditionWithConfig],
+        data: &HashMap<String, DataValue>,
+        _alias: &str,
+    ) -> QuickDbResult<u64> {
+        self.modify(table, &single_groups(conditions), |record| {
+            record.extend(data.iter().map(|(field, value)| (field.clone(), value.clone())));
+            Ok(())
+        })
+    }
+
+    async fn update_with_operations(
+        &self,
+        _connection: &DatabaseConnection,
+        table: &str,
+        conditions: &[QueryConditionWithConfig],
+        operations: &[UpdateOperation],
+        _alias: &str,
+    ) -> QuickDbResult<u64> {
+        self.modify(table, &single_groups(conditions), |record| {
+            operations.iter().try_for_each(|operation| apply_operation(record, operation))
+        })
+    }
+
+    async fn update_by_id(
+        &self,
+        connection: &DatabaseConnection,
+        table: &str,
+        id: &DataValue,
+        data: &HashMap<String, DataValue>,
+        alias: &str,
+    ) -> QuickDbResult<bool> {
+        let conditions = [QueryConditionWithConfig {
+            field: "id".to_string(),
+            operator: QueryOperator::Eq,
+            value: id.clone(),
+            case_insensitive: false,
+        }];
+        Ok(self.update(connection, table, &conditions, data, alias).await? > 0)
+    }
+
+    async fn upsert(
+        &self,
+        _connection: &DatabaseConnection,
+        table_name: &str,
+        data: &HashMap<String, DataValue>,
+        id_strategy: &IdStrategy,
+        conflict_columns: &[String],
+        _alias: &str,
+    ) -> QuickDbResult<DataValue> {
+        let existing = {
+            let tables = self.tables();
+            tables.get(table_name).and_then(|table| {
+                table.records.iter().position(|record| {
+                    !conflict_columns.is_empty()
+                        && conflict_columns.iter().all(|column| match (record.get(column), data.get(column)) {
+                            (Some(a), Some(b)) => values_equal(a, b),
+                            _ => false,
+                        })
+                })
+            })
+        };
+        let Some(index) = existing else {
+            return self.insert(table_name, data, id_strategy);
+        };
+
+        let mut tables = self.tables();
+        let table = tables.get_mut(table_name).expect("表在查找冲突记录后不会被移除");
+        let mut record = table.records[index].clone();
+        // 冲突时更新除 ID 外的字段
+        record.extend(
+            data.iter()
+                .filter(|(field, _)| field.as_str() != "id")
+                .map(|(field, value)| (field.clone(), value.clone())),
+        );
+        table.check_unique(table_name, &record, Some(index))?;
+        let id = record.get("id").cloned().unwrap_or(DataValue::Null);
+        table.records[index] = record;
+        Ok(id)
+    }
+
+    async fn delete(
+        &self,
+        _connection: &DatabaseConnection,
+        table: &str,
+        conditions: &[QueryConditionWithConfig],
+        _alias: &str,
+    ) -> QuickDbResult<u64> {
+        self.remove(table, &single_groups(conditions))
+    }
+
+    async fn delete_by_id(
+        &self,
+        _connection: &DatabaseConnection,
+        table: &str,
+        id: &DataValue,
+        _alias: &str,
+    ) -> QuickDbResult<bool> {
+        Ok(self.remove(table, &id_groups(id))? > 0)
+    }
+
+    async fn count(
+        &self,
+        _connection: &DatabaseConnection,
+        table: &str,
+        conditions: &[QueryConditionWithConfig],
+        _alias: &str,
+    ) -> QuickDbResult<u64> {
+        Ok(self.select(table, &single_groups(conditions), &QueryOptions::default())?.len() as u64)
+    }
+
+    async fn count_with_groups(
+        &self,
+        _connection: &DatabaseConnection,
+        table: &str,
+        condition_groups: &[QueryConditionGroupWithConfig],
+        _alias: &str,
+    ) -> QuickDbResult<u64> {
+        Ok(self.select(table, condition_groups, &QueryOptions::default())?.len() as u64)
+    }
+
+    async fn create_table(
+        &self,
+        _connection: &DatabaseConnection,
+        table: &str,
+        _fields: &HashMap<String, FieldDefinition>,
+        _id_strategy: &IdStrategy,
+        _alias: &str,
+    ) -> QuickDbResult<()> {
+        self.tables().entry(table.to_string()).or_default();
+        debug!("模拟适配器创建表: {}", table);
+        Ok(())
+    }
+
+    async fn create_index(
+        &self,
+        _connection: &DatabaseConnection,
+        table: &str,
+        index_name: &str,
+        fields: &[String],
+        unique: bool,
+        _options: &IndexOptions,
+    ) -> QuickDbResult<()> {
+        let mut tables = self.tables();
+        let table = tables.entry(table.to_string()).or_default();
+        if table.indexes.iter().any(|name| name == index_name) || table.unique_indexes.contains_key(index_name) {
+            return Err(QuickDbError::IndexExistsError {
+                index: index_name.to_string(),
+                message: "索引已存在".to_string(),
+            });
+        }
+        if unique {
+            table.unique_indexes.insert(index_name.to_string(), fields.to_vec());
+        } else {
+            table.indexes.push(index_name.to_string());
+        }
+        Ok(())
+    }
+
+    async fn table_exists(&self, _connection: &DatabaseConnection, table: &str) -> QuickDbResult<bool> {
+        Ok(self.tables().contains_key(table))
+    }
+
+    /// 内存表没有统计信息，直接返回精确记录数
+    async fn count_estimate(&self, _connection: &DatabaseConnection, table: &str) -> QuickDbResult<CountEstimate> {
+        let count = self.tables().get(table).map_or(0, |table| table.records.len());
+        Ok(CountEstimate::exact(count as u64))
+    }
+
+    async fn drop_table(&self, _connection: &DatabaseConnection, table: &str) -> QuickDbResult<()> {
+        self.tables().remove(table);
+        Ok(())
+    }
+
+    async fn get_server_version(&self, _connection: &DatabaseConnection) -> QuickDbResult<String> {
+        Ok(format!("rat_quickdb mock {}", env!("CARGO_PKG_VERSION")))
+    }
+
+    async fn create_stored_procedure(
+        &self,
+        _connection: &DatabaseConnection,
+        config: &crate::stored_procedure::StoredProcedureConfig,
+    ) -> QuickDbResult<crate::stored_procedure::StoredProcedureCreateResult> {
+        Err(QuickDbError::QueryError {
+            message: format!("模拟适配器不支持存储过程: {}", config.procedure_name),
+        })
+    }
+
+    async fn drop_stored_procedure(
+        &self,
+        _connection: &DatabaseConnection,
+        procedure_name: &str,
+    ) -> QuickDbResult<bool> {
+        Err(QuickDbError::QueryError {
+            message: format!("模拟适配器不支持存储过程: {}", procedure_name),
+        })
+    }
+
+    async fn execute_stored_procedure(
+        &self,
+        _connection: &DatabaseConnection,
+        procedure_name: &str,
+        _database: &str,
+        _params: Option<HashMap<String, DataValue>>,
+    ) -> QuickDbResult<crate::stored_procedure::StoredProcedureQueryResult> {
+        Err(QuickDbError::QueryError {
+            message: format!("模拟适配器不支持存储过程: {}", procedure_name),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn condition(field: &str, operator: QueryOperator, value: DataValue) -> QueryConditionWithConfig {
+        QueryConditionWithConfig {
+            field: field.to_string(),
+            operator,
+            value,
+            case_insensitive: false,
+        }
+    }
+
+    fn user(name: &str, age: i64) -> HashMap<String, DataValue> {
+        HashMap::from([
+            ("name".to_string(), DataValue::String(name.to_string())),
+            ("age".to_string(), DataValue::Int(age)),
+        ])
+    }
+
+    #[tokio::test]
+    async fn filters_sorts_and_paginates_in_memory() {
+        crate::i18n::ErrorMessageI18n::init_i18n();
+        let adapter = MockAdapter::new();
+        let conn = &DatabaseConnection::Mock;
+        let strategy = IdStrategy::AutoIncrement;
+
+        for (name, age) in [("alice", 30), ("bob", 25), ("carol", 35), ("dave", 25)] {
+            adapter.create(conn, "users", &user(name, age), &strategy, "mock").await.unwrap();
+        }
+        assert_eq!(adapter.records("users")[3]["id"], DataValue::Int(4));
+
+        let options = QueryOptions::new()
+            .with_sort(vec![
+                SortConfig { field: "age".to_string(), direction: SortDirection::Desc },
+                SortConfig { field: "name".to_string(), direction: SortDirection::Asc },
+            ])
+            .with_pagination(PaginationConfig { skip: 1, limit: 2 })
+            .with_fields(vec!["name".to_string()]);
+        let younger = [condition("age", QueryOperator::Lt, DataValue::Int(35))];
+        let found = adapter.find(conn, "users", &younger, &options, "mock").await.unwrap();
+        let expected = |name: &str| {
+            DataValue::Object(HashMap::from([("name".to_string(), DataValue::String(name.to_string()))]))
+        };
+        assert_eq!(found, vec![expected("bob"), expected("dave")]);
+
+        let mut prefix = condition("name", QueryOperator::StartsWith, DataValue::String("A".to_string()));
+        prefix.case_insensitive = true;
+        let bounds = DataValue::Array(vec![DataValue::Int(34), DataValue::Int(40)]);
+        let either = QueryConditionGroupWithConfig::GroupWithConfig {
+            operator: LogicalOperator::Or,
+            conditions: vec![prefix.into(), condition("age", QueryOperator::Between, bounds).into()],
+        };
+        assert_eq!(adapter.count_with_groups(conn, "users", &[either], "mock").await.unwrap(), 2);
+
+        let young = [condition("age", QueryOperator::Eq, DataValue::Int(25))];
+        let increments = [UpdateOperation::increment("age", 1)];
+        assert_eq!(adapter.update_with_operations(conn, "users", &young, &increments, "mock").await.unwrap(), 2);
+        let after = [condition("age", QueryOperator::In, DataValue::Array(vec![DataValue::Int(26)]))];
+        assert_eq!(adapter.delete(conn, "users", &after, "mock").await.unwrap(), 2);
+        assert!(adapter.delete_by_id(conn, "users", &DataValue::String("1".to_string()), "mock").await.unwrap());
+        assert_eq!(adapter.records("users").len(), 1);
+
+        let fields = ["name".to_string()];
+        adapter
+            .create_index(conn, "users", "uniq_name", &fields, true, &IndexOptions::default())
+            .await
+            .unwrap();
+        assert!(matches!(
+            adapter.create(conn, "users", &user("carol", 1), &strategy, "mock").await,
+            Err(QuickDbError::UniqueViolation { fields: violated, .. }) if violated == fields
+        ));
+        let upserted = adapter
+            .upsert(conn, "users", &user("carol", 40), &strategy, &fields, "mock")
+            .await
+            .unwrap();
+        assert_eq!(upserted, DataValue::Int(3));
+        assert_eq!(adapter.records("users")[0]["age"], DataValue::Int(40));
+    }
+}
@@ -3,38 +3,61 @@
 //! 提供统一的数据库操作接口，屏蔽不同数据库的实现差异
 
 use crate::error::{QuickDbError, QuickDbResult};
-use crate::model::FieldDefinition;
+use crate::model::{FieldDefinition, IndexOptions};
 use crate::pool::DatabaseConnection;
 use crate::types::*;
 use async_trait::async_trait;
 
 use std::collections::HashMap;
 
+/// 批量创建中的一条记录
+#[derive(Debug, Clone)]
+pub struct BatchCreate {
+    pub table: String,
+    pub data: HashMap<String, DataValue>,
+    pub id_strategy: IdStrategy,
+    pub alias: String,
+}
+
 // 导入各个数据库适配器 (条件编译)
 mod cached;
+#[cfg(feature = "testing")]
+mod mock;
 #[cfg(feature = "mongodb-support")]
 mod mongodb;
 #[cfg(feature = "mysql-support")]
 mod mysql;
+mod naming;
 #[cfg(feature = "postgres-support")]
 mod postgres;
 mod postgres_utils;
+pub(crate) mod render;
+#[cfg(any(feature = "sqlite-support", feature = "postgres-support", feature = "mysql-support"))]
+pub(crate) mod session;
 #[cfg(feature = "sqlite-support")]
 mod sqlite;
+pub mod statement_cache;
+#[cfg(any(feature = "sqlite-support", feature = "postgres-support", feature = "mysql-support"))]
+pub(crate) mod transaction;
 mod utils;
 
 // 条件导出适配器
 pub use cached::CachedDatabaseAdapter;
+#[cfg(feature = "testing")]
+pub use mock::MockAdapter;
 #[cfg(feature = "mongodb-support")]
 pub use mongodb::MongoAdapter;
 #[cfg(feature = "mysql-support")]
 pub use mysql::MysqlAdapter;
+pub use naming::TableNamingAdapter;
 #[cfg(feature = "postgres-support")]
 pub use postgres::PostgresAdapter;
 pub use postgres_utils::{build_json_query_condition, convert_to_jsonb_value};
 #[cfg(feature = "sqlite-support")]
 pub use sqlite::SqliteAdapter;
+pub use statement_cache::{StatementCacheStats, get_statement_cache_stats};
 pub use utils::get_field_type;
+pub(crate) use utils::{index_key_parts, render_index_predicate};
 
 /// 数据库适配器trait，定义统一的数据库操作接口
 #[async_trait]
@@ -49,6 +72,24 @@ pub trait DatabaseAdapter: Send + Sync {
         alias: &str,
     ) -> QuickDbResult<DataValue>;
 
+    /// 批量创建记录，按输入顺序返回每条记录的结果
+    ///
+    /// 默认实现逐条调用 `create`，SQLite 适配器覆盖为单个事务内写入
+    async fn create_batch(
+        &self,
+        connection: &DatabaseConnection,
+        items: &[BatchCreate],
+    ) -> Vec<QuickDbResult<DataValue>> {
+        let mut results = Vec::with_capacity(items.len());
+        for item in items {
+            results.push(
+                self.create(connection, &item.table, &item.data, &item.id_strategy, &item.alias)
+                    .await,
+            );
+        }
+        results
+    }
+
     /// 根据ID查找记录
     async fn find_by_id(
         &self,
@@ -58,6 +99,20 @@ pub trait DatabaseAdapter: Send + Sync {
         alias: &str,
     ) -> QuickDbResult<Option<DataValue>>;
 
+    /// 根据ID检查记录是否存在
+    ///
+    /// 默认实现基于 `find_by_id`，各数据库适配器以 `SELECT 1` / `count_documents`
+    /// 覆盖实现，避免读取和反序列化整行数据
+    async fn exists_by_id(
+        &self,
+        connection: &DatabaseConnection,
+        table: &str,
+        id: &DataValue,
+        alias: &str,
+    ) -> QuickDbResult<bool> {
+        Ok(self.find_by_id(connection, table, id, alias).await?.is_some())
+    }
+
     /// 查找记录
     async fn find(
         &self,
@@ -163,6 +218,24 @@ pub trait DatabaseAdapter: Send + Sync {
         alias: &str,
     ) -> QuickDbResult<bool>;
 
+    /// 根据ID更新记录并返回更新后的记录，记录不存在时返回 `None`
+    ///
+    /// 默认实现先更新再按ID查询（两步之间不保证原子性），PostgreSQL/SQLite 使用
+    /// `RETURNING *`、MongoDB 使用 `findOneAndUpdate` 覆盖实现
+    async fn update_by_id_returning(
+        &self,
+        connection: &DatabaseConnection,
+        table: &str,
+        id: &DataValue,
+        data: &HashMap<String, DataValue>,
+        alias: &str,
+    ) -> QuickDbResult<Option<DataValue>> {
+        if !self.update_by_id(connection, table, id, data, alias).await? {
+            return Ok(None);
+        }
+        self.find_by_id(connection, table, id, alias).await
+    }
+
     /// Upsert记录 - 如果记录存在则更新，否则插入新记录
     async fn upsert(
         &self,
@@ -192,6 +265,60 @@ pub trait DatabaseAdapter: Send + Sync {
         alias: &str,
     ) -> QuickDbResult<bool>;
 
+    /// 根据ID删除记录并返回被删除的记录，记录不存在时返回 `None`
+    ///
+    /// 默认实现先按ID查询再删除（两步之间不保证原子性），PostgreSQL/SQLite 使用
+    /// `RETURNING *`、MongoDB 使用 `findOneAndDelete` 覆盖实现
+    async fn delete_by_id_returning(
+        &self,
+        connection: &DatabaseConnection,
+        table: &str,
+        id: &DataValue,
+        alias: &str,
+    ) -> QuickDbResult<Option<DataValue>> {
+        let Some(record) = self.find_by_id(connection, table, id, alias).await? else {
+            return Ok(None);
+        };
+        Ok(self.delete_by_id(connection, table, id, alias).await?.then_some(record))
+    }
+
+    /// 批量执行混合写操作，返回每个操作的结果
+    ///
+    /// 默认实现依次调用各写入方法，已执行的操作不会因后续失败而回滚；SQL 数据库在一个事务中
+    /// 执行（每个操作一个保存点），MongoDB 使用 `bulkWrite` 命令覆盖实现
+    async fn bulk_write(
+        &self,
+        connection: &DatabaseConnection,
+        table: &str,
+        ops: &[BulkOp],
+        mode: BulkWriteMode,
+        id_strategy: &IdStrategy,
+        alias: &str,
+    ) -> QuickDbResult<BulkWriteReport> {
+        let mut report = BulkWriteReport::skipped(ops.len());
+        for (outcome, op) in report.outcomes.iter_mut().zip(ops) {
+            let result = match op {
+                BulkOp::Insert(data) => self.create(connection, table, data, id_strategy, alias).await.map(|_| 1),
+                BulkOp::Update { conditions, data } => self.update(connection, table, conditions, data, alias).await,
+                BulkOp::Delete(conditions) => self.delete(connection, table, conditions, alias).await,
+                BulkOp::Upsert { data, conflict_columns } => self
+                    .upsert(connection, table, data, id_strategy, conflict_columns, alias)
+                    .await
+                    .map(|_| 1),
+            };
+            match result {
+                Ok(affected) => *outcome = BulkOpOutcome::Applied { affected },
+                Err(e) => {
+                    *outcome = BulkOpOutcome::Failed { message: e.to_string() };
+                    if mode == BulkWriteMode::Ordered {
+                        break;
+                    }
+                }
+            }
+        }
+        Ok(report)
+    }
+
     /// 统计记录数量
     async fn count(
         &self,
@@ -221,6 +348,8 @@ pub trait DatabaseAdapter: Send + Sync {
     ) -> QuickDbResult<()>;
 
     /// 创建索引
+    ///
+    /// `where_clause` 非空时创建部分索引，`expression` 为表达式索引键
     async fn create_index(
         &self,
         connection: &DatabaseConnection,
@@ -228,6 +357,7 @@ pub trait DatabaseAdapter: Send + Sync {
         index_name: &str,
         fields: &[String],
         unique: bool,
+        options: &IndexOptions,
     ) -> QuickDbResult<()>;
 
     /// 检查表是否存在
@@ -237,6 +367,15 @@ pub trait DatabaseAdapter: Send + Sync {
         table: &str,
     ) -> QuickDbResult<bool>;
 
+    /// 估算表/集合的记录数
+    ///
+    /// 读取数据库的统计信息而不扫描全表，结果可能与实际记录数有偏差
+    async fn count_estimate(
+        &self,
+        connection: &DatabaseConnection,
+        table: &str,
+    ) -> QuickDbResult<CountEstimate>;
+
     /// 删除表/集合
     async fn drop_table(&self, connection: &DatabaseConnection, table: &str) -> QuickDbResult<()>;
 
@@ -250,6 +389,13 @@ pub trait DatabaseAdapter: Send + Sync {
         config: &crate::stored_procedure::StoredProcedureConfig,
     ) -> QuickDbResult<crate::stored_procedure::StoredProcedureCreateResult>;
 
+    /// 删除存储过程，返回是否存在
+    async fn drop_stored_procedure(
+        &self,
+        connection: &DatabaseConnection,
+        procedure_name: &str,
+    ) -> QuickDbResult<bool>;
+
     /// 执行存储过程查询
     async fn execute_stored_procedure(
         &self,
@@ -278,6 +424,23 @@ pub fn create_adapter(db_type: &DatabaseType) -> QuickDbResult<Box<dyn DatabaseA
     }
 }
 
+/// 按数据库配置创建适配器
+///
+/// 配置了表名装饰时包装 [`TableNamingAdapter`]，配置了缓存时在其外再包装缓存适配器
+pub(crate) fn create_adapter_for_config(
+    db_config: &DatabaseConfig,
+    cache_manager: Option<std::sync::Arc<crate::cache::CacheManager>>,
+) -> QuickDbResult<Box<dyn DatabaseAdapter>> {
+    let mut adapter = create_adapter(&db_config.db_type)?;
+    if let Some(naming) = db_config.table_naming.as_ref().filter(|naming| !naming.is_identity()) {
+        adapter = Box::new(TableNamingAdapter::new(adapter, naming.clone()));
+    }
+    Ok(match cache_manager {
+        Some(cache_manager) => Box::new(CachedDatabaseAdapter::new(adapter, cache_manager)),
+        None => adapter,
+    })
+}
+
 /// 根据数据库类型和缓存管理器创建带缓存的适配器
 pub fn create_adapter_with_cache(
     db_type: &DatabaseType,
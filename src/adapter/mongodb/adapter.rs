@@ -16,6 +16,12 @@ pub struct MongoAdapter {
         Arc<Mutex<HashMap<String, crate::stored_procedure::StoredProcedureInfo>>>,
 }
 
+impl Default for MongoAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl MongoAdapter {
     /// 创建新的MongoDB适配器
     pub fn new() -> Self {
@@ -38,16 +44,6 @@ impl MongoAdapter {
         locks
     }
 
-    /// 释放表创建锁
-    pub(crate) async fn release_table_lock(
-        &self,
-        table: &str,
-        mut locks: tokio::sync::MutexGuard<'_, HashMap<String, ()>>,
-    ) {
-        locks.remove(table);
-        debug!("🔓 释放表 {} 的创建锁", table);
-    }
-
     /// 生成存储过程的MongoDB聚合管道（MongoDB使用聚合管道模拟存储过程逻辑）
     pub async fn generate_stored_procedure_pipeline(
         &self,
@@ -524,7 +520,7 @@ impl MongoAdapter {
             // 将JSON阶段转换为MongoDB Document
             let pipeline_docs: Result<Vec<Document>, _> = pipeline_stages
                 .iter()
-                .map(|stage| mongodb::bson::to_document(stage))
+                .map(mongodb::bson::to_document)
                 .collect();
 
             let pipeline_docs =
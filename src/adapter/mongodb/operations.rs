@@ -4,16 +4,15 @@ use crate::adapter::DatabaseAdapter;
 use crate::adapter::MongoAdapter;
 use crate::adapter::mongodb::query_builder::build_query_document;
 use crate::adapter::mongodb::utils::build_update_document;
+use crate::adapter::mongodb::utils::mongo_unique_violation;
 use crate::error::{QuickDbError, QuickDbResult};
-use crate::manager;
-use crate::model::FieldDefinition;
+use crate::model::{FieldDefinition, IndexOptions};
 use crate::pool::DatabaseConnection;
 use crate::types::*;
 use async_trait::async_trait;
 use mongodb::bson::{Bson, Document, doc};
 use mongodb::options::UpdateOptions;
 use rat_logger::debug;
-use serde_json::json;
 use std::collections::HashMap;
 
 use super::query as mongodb_query;
@@ -30,6 +29,63 @@ fn check_collection_not_exist_error(error: &mongodb::error::Error, collection: &
     error_string.contains("command failed") && error_string.contains("find")
 }
 
+/// 构建 `bulkWrite` 命令中的单个操作，集合固定为 `nsInfo` 中的第 0 项
+fn bulk_op_document(adapter: &MongoAdapter, table: &str, alias: &str, op: &BulkOp) -> QuickDbResult<Document> {
+    Ok(match op {
+        BulkOp::Insert(data) => {
+            let mut mapped_data = mongodb_utils::map_data_fields(adapter, data);
+            crate::adapter::utils::apply_field_defaults(table, alias, DatabaseType::MongoDB, &mut mapped_data)?;
+            // 空ID由MongoDB生成
+            if matches!(mapped_data.get("_id"), Some(DataValue::String(id)) if id.is_empty()) {
+                mapped_data.remove("_id");
+            }
+            let mut document = Document::new();
+            for (key, value) in &mapped_data {
+                document.insert(key, mongodb_utils::data_value_to_bson(adapter, value)?);
+            }
+            doc! { "insert": 0, "document": document }
+        }
+        BulkOp::Update { conditions, data } => doc! {
+            "update": 0,
+            "filter": build_query_document(table, alias, conditions)?,
+            "updateMods": build_update_document(adapter, data)?,
+            "multi": true,
+        },
+        BulkOp::Delete(conditions) => doc! {
+            "delete": 0,
+            "filter": build_query_document(table, alias, conditions)?,
+            "multi": true,
+        },
+        BulkOp::Upsert { data, conflict_columns } => {
+            let mapped_data = mongodb_utils::map_data_fields(adapter, data);
+            let conditions = crate::adapter::utils::conflict_conditions(data, conflict_columns)?;
+            let mut filter = Document::new();
+            for condition in &conditions {
+                let field = if condition.field == "id" { "_id" } else { condition.field.as_str() };
+                let value = mapped_data.get(field).unwrap_or(&condition.value);
+                filter.insert(field, mongodb_utils::data_value_to_bson(adapter, value)?);
+            }
+            doc! {
+                "update": 0,
+                "filter": filter,
+                "updateMods": build_update_document(adapter, data)?,
+                "upsert": true,
+                "multi": false,
+            }
+        }
+    })
+}
+
+/// 读取命令返回中的整数字段（服务器可能返回 Int32、Int64 或 Double）
+fn bson_number(document: &Document, key: &str) -> i64 {
+    match document.get(key) {
+        Some(Bson::Int32(n)) => i64::from(*n),
+        Some(Bson::Int64(n)) => *n,
+        Some(Bson::Double(n)) => *n as i64,
+        _ => 0,
+    }
+}
+
 #[async_trait]
 impl DatabaseAdapter for MongoAdapter {
     async fn create(
@@ -50,7 +106,7 @@ impl DatabaseAdapter for MongoAdapter {
                 // 双重检查：再次确认集合不存在
                 if !mongodb_schema::table_exists(self, connection, table).await? {
                     // 尝试从模型管理器获取预定义的元数据
-                    if let Some(model_meta) = crate::manager::get_model_with_alias(table, alias) {
+                    if let Some(_model_meta) = crate::manager::get_model_with_alias(table, alias) {
                         debug!("集合 {} 不存在，使用预定义模型元数据创建", table);
 
                         // MongoDB不需要预创建表结构，集合是无模式的
@@ -70,6 +126,26 @@ impl DatabaseAdapter for MongoAdapter {
 
             // 映射字段名（id -> _id）并处理ID策略
             let mut mapped_data = mongodb_utils::map_data_fields(self, data);
+            crate::adapter::utils::apply_field_defaults(
+                table,
+                alias,
+                DatabaseType::MongoDB,
+                &mut mapped_data,
+            )?;
+
+            // 序列字段未提供值时由序列生成器分配
+            for (field, sequence_name) in crate::adapter::utils::get_sequence_fields(table, alias) {
+                if matches!(mapped_data.get(&field), None | Some(DataValue::Null)) {
+                    let value = mongodb_utils::next_sequence_value(
+                        &collection,
+                        alias,
+                        &field,
+                        &sequence_name,
+                    )
+                    .await?;
+                    mapped_data.insert(field, DataValue::Int(value));
+                }
+            }
 
             // 根据ID策略处理ID字段
             if mapped_data.contains_key("_id") {
@@ -159,35 +235,28 @@ impl DatabaseAdapter for MongoAdapter {
                 }
             }
 
-            debug!("执行MongoDB插入到集合 {}: {:?}", table, doc);
+            crate::statement_log::log_mongo(table, "insertOne", &[&doc]);
 
             let result =
                 collection
                     .insert_one(doc, None)
                     .await
-                    .map_err(|e| QuickDbError::QueryError {
-                        message: crate::i18n::tf("adapter.mongo.insert_failed", &[("error", &e.to_string())]),
+                    .map_err(|e| {
+                        mongo_unique_violation(&e, table).unwrap_or_else(|| QuickDbError::QueryError {
+                            message: crate::i18n::tf("adapter.mongo.insert_failed", &[("error", &e.to_string())]),
+                        })
                     })?;
 
             let mut result_map = HashMap::new();
 
             // 检查是否有ODM层生成的ID，如果有则使用它，否则使用MongoDB生成的ID
-            if let Some(id_value) = mapped_data.get("_id") {
-                if let DataValue::String(id_str) = id_value {
-                    if !id_str.is_empty() {
-                        // 使用ODM层生成的ID
-                        result_map.insert("id".to_string(), DataValue::String(id_str.clone()));
-                        Ok(DataValue::Object(result_map))
-                    } else {
-                        // 使用MongoDB生成的ID，确保转换为纯字符串格式
-                        let id_str = match result.inserted_id {
-                            mongodb::bson::Bson::ObjectId(oid) => oid.to_hex(),
-                            _ => result.inserted_id.to_string(),
-                        };
-                        result_map.insert("id".to_string(), DataValue::String(id_str));
-                        Ok(DataValue::Object(result_map))
-                    }
-                } else {
+            match mapped_data.get("_id") {
+                Some(DataValue::String(id_str)) if !id_str.is_empty() => {
+                    // 使用ODM层生成的ID
+                    result_map.insert("id".to_string(), DataValue::String(id_str.clone()));
+                    Ok(DataValue::Object(result_map))
+                }
+                _ => {
                     // 使用MongoDB生成的ID，确保转换为纯字符串格式
                     let id_str = match result.inserted_id {
                         mongodb::bson::Bson::ObjectId(oid) => oid.to_hex(),
@@ -196,14 +265,6 @@ impl DatabaseAdapter for MongoAdapter {
                     result_map.insert("id".to_string(), DataValue::String(id_str));
                     Ok(DataValue::Object(result_map))
                 }
-            } else {
-                // 使用MongoDB生成的ID，确保转换为纯字符串格式
-                let id_str = match result.inserted_id {
-                    mongodb::bson::Bson::ObjectId(oid) => oid.to_hex(),
-                    _ => result.inserted_id.to_string(),
-                };
-                result_map.insert("id".to_string(), DataValue::String(id_str));
-                Ok(DataValue::Object(result_map))
             }
         } else {
             Err(QuickDbError::ConnectionError {
@@ -217,7 +278,7 @@ impl DatabaseAdapter for MongoAdapter {
         connection: &DatabaseConnection,
         table: &str,
         data: &HashMap<String, DataValue>,
-        id_strategy: &IdStrategy,
+        _id_strategy: &IdStrategy,
         conflict_columns: &[String],
         alias: &str,
     ) -> QuickDbResult<DataValue> {
@@ -230,7 +291,7 @@ impl DatabaseAdapter for MongoAdapter {
                 // 双重检查：再次确认集合不存在
                 if !mongodb_schema::table_exists(self, connection, table).await? {
                     // 尝试从模型管理器获取预定义的元数据
-                    if let Some(model_meta) = crate::manager::get_model_with_alias(table, alias) {
+                    if let Some(_model_meta) = crate::manager::get_model_with_alias(table, alias) {
                         debug!("集合 {} 不存在，使用预定义模型元数据创建", table);
 
                         // MongoDB不需要预创建表结构，集合是无模式的
@@ -265,10 +326,7 @@ impl DatabaseAdapter for MongoAdapter {
             // 构建更新文档：使用$set设置所有非_id字段
             let update = mongodb_utils::build_update_document(self, data)?;
 
-            debug!(
-                "执行MongoDB Upsert: 集合={}, filter={:?}, update={:?}",
-                table, filter, update
-            );
+            crate::statement_log::log_mongo(table, "updateOne", &[&filter, &update, &doc! {"upsert": true}]);
 
             let result = collection
                 .update_one(
@@ -277,8 +335,10 @@ impl DatabaseAdapter for MongoAdapter {
                     UpdateOptions::builder().upsert(true).build(),
                 )
                 .await
-                .map_err(|e| QuickDbError::QueryError {
-                    message: crate::i18n::tf("adapter.mongo.upsert_failed", &[("error", &e.to_string())]),
+                .map_err(|e| {
+                    mongo_unique_violation(&e, table).unwrap_or_else(|| QuickDbError::QueryError {
+                        message: crate::i18n::tf("adapter.mongo.upsert_failed", &[("error", &e.to_string())]),
+                    })
                 })?;
 
             let mut result_map = HashMap::new();
@@ -322,6 +382,16 @@ impl DatabaseAdapter for MongoAdapter {
         mongodb_query::find_by_id(self, connection, table, id, alias).await
     }
 
+    async fn exists_by_id(
+        &self,
+        connection: &DatabaseConnection,
+        table: &str,
+        id: &DataValue,
+        _alias: &str,
+    ) -> QuickDbResult<bool> {
+        mongodb_query::exists_by_id(self, connection, table, id).await
+    }
+
     async fn find_with_cache_control(
         &self,
         connection: &DatabaseConnection,
@@ -329,7 +399,7 @@ impl DatabaseAdapter for MongoAdapter {
         conditions: &[QueryConditionWithConfig],
         options: &QueryOptions,
         alias: &str,
-        bypass_cache: bool,
+        _bypass_cache: bool,
     ) -> QuickDbResult<Vec<DataValue>> {
         mongodb_query::find(self, connection, table, conditions, options, alias).await
     }
@@ -341,7 +411,7 @@ impl DatabaseAdapter for MongoAdapter {
         condition_groups: &[QueryConditionGroup],
         options: &QueryOptions,
         alias: &str,
-        bypass_cache: bool,
+        _bypass_cache: bool,
     ) -> QuickDbResult<Vec<DataValue>> {
         mongodb_query::find_with_groups(self, connection, table, condition_groups, options, alias)
             .await
@@ -354,7 +424,7 @@ impl DatabaseAdapter for MongoAdapter {
         condition_groups: &[QueryConditionGroupWithConfig],
         options: &QueryOptions,
         alias: &str,
-        bypass_cache: bool,
+        _bypass_cache: bool,
     ) -> QuickDbResult<Vec<DataValue>> {
         mongodb_query::find_with_groups_with_config(self, connection, table, condition_groups, options, alias)
             .await
@@ -391,21 +461,12 @@ impl DatabaseAdapter for MongoAdapter {
         alias: &str,
     ) -> QuickDbResult<u64> {
         if let DatabaseConnection::MongoDB(db) = connection {
-            let collection = mongodb_utils::get_collection(self, db, table);
-
             let query = build_query_document(table, alias, conditions)?;
             let update = mongodb_utils::build_update_document(self, data)?;
 
-            debug!("执行MongoDB更新: 查询={:?}, 更新={:?}", query, update);
+            crate::statement_log::log_mongo(table, "updateMany", &[&query, &update]);
 
-            let result = collection
-                .update_many(query, update, None)
-                .await
-                .map_err(|e| QuickDbError::QueryError {
-                    message: crate::i18n::tf("adapter.mongo.update_failed", &[("error", &e.to_string())]),
-                })?;
-
-            Ok(result.modified_count)
+            mongodb_utils::update_many(self, db, table, query, update).await
         } else {
             Err(QuickDbError::ConnectionError {
                 message: crate::i18n::t("adapter.mongo.connection_mismatch"),
@@ -434,6 +495,17 @@ impl DatabaseAdapter for MongoAdapter {
         Ok(affected > 0)
     }
 
+    async fn update_by_id_returning(
+        &self,
+        connection: &DatabaseConnection,
+        table: &str,
+        id: &DataValue,
+        data: &HashMap<String, DataValue>,
+        _alias: &str,
+    ) -> QuickDbResult<Option<DataValue>> {
+        mongodb_query::update_by_id_returning(self, connection, table, id, data).await
+    }
+
     async fn update_with_operations(
         &self,
         connection: &DatabaseConnection,
@@ -443,202 +515,12 @@ impl DatabaseAdapter for MongoAdapter {
         alias: &str,
     ) -> QuickDbResult<u64> {
         if let DatabaseConnection::MongoDB(db) = connection {
-            let collection = mongodb_utils::get_collection(self, db, table);
-
             let query = build_query_document(table, alias, conditions)?;
-            let mut update_doc = Document::new();
-
-            let mut set_doc = Document::new();
-            let mut inc_doc = Document::new();
-
-            for operation in operations {
-                match &operation.operation {
-                    crate::types::UpdateOperator::Set => {
-                        match mongodb_utils::data_value_to_bson(self, &operation.value) {
-                            Ok(bson_value) => set_doc.insert(&operation.field, bson_value),
-                            Err(e) => {
-                                return Err(QuickDbError::QueryError {
-                                    message: crate::i18n::tf("adapter.mongo.convert_to_bson_failed", &[("operation", "更新值"), ("error", &e.to_string())]),
-                                });
-                            }
-                        };
-                    }
-                    crate::types::UpdateOperator::Increment => {
-                        match mongodb_utils::data_value_to_bson(self, &operation.value) {
-                            Ok(bson_value) => inc_doc.insert(&operation.field, bson_value),
-                            Err(e) => {
-                                return Err(QuickDbError::QueryError {
-                                    message: crate::i18n::tf("adapter.mongo.convert_to_bson_failed", &[("operation", "递增值"), ("error", &e.to_string())]),
-                                });
-                            }
-                        };
-                    }
-                    crate::types::UpdateOperator::Decrement => {
-                        // 对于减少操作，使用负数的inc操作
-                        let neg_value = match &operation.value {
-                            crate::types::DataValue::Int(i) => crate::types::DataValue::Int(-i),
-                            crate::types::DataValue::Float(f) => crate::types::DataValue::Float(-f),
-                            _ => {
-                                return Err(QuickDbError::ValidationError {
-                                    field: operation.field.clone(),
-                                    message: crate::i18n::t("adapter.mongo.decrement_numeric_only"),
-                                });
-                            }
-                        };
-                        match mongodb_utils::data_value_to_bson(self, &neg_value) {
-                            Ok(bson_value) => inc_doc.insert(&operation.field, bson_value),
-                            Err(e) => {
-                                return Err(QuickDbError::QueryError {
-                                    message: crate::i18n::tf("adapter.mongo.convert_to_bson_failed", &[("operation", "递减值"), ("error", &e.to_string())]),
-                                });
-                            }
-                        };
-                    }
-                    crate::types::UpdateOperator::Multiply => {
-                        // MongoDB使用$multiply操作符
-                        match mongodb_utils::data_value_to_bson(self, &operation.value) {
-                            Ok(bson_value) => {
-                                if !set_doc.contains_key("$mul") {
-                                    set_doc.insert("$mul", Document::new());
-                                }
-                                let mul_doc = set_doc.get_mut("$mul").unwrap().as_document_mut().unwrap();
-                                mul_doc.insert(&operation.field, bson_value);
-                            }
-                            Err(e) => {
-                                return Err(QuickDbError::QueryError {
-                                    message: crate::i18n::tf("adapter.mongo.convert_to_bson_failed", &[("operation", "乘数值"), ("error", &e.to_string())]),
-                                });
-                            }
-                        };
-                    }
-                    crate::types::UpdateOperator::Divide => {
-                        // MongoDB不支持直接除法，但可以使用乘法配合小数
-                        let divisor = match &operation.value {
-                            crate::types::DataValue::Int(i) => 1.0 / *i as f64,
-                            crate::types::DataValue::Float(f) => 1.0 / f,
-                            _ => {
-                                return Err(QuickDbError::ValidationError {
-                                    field: operation.field.clone(),
-                                    message: crate::i18n::t("adapter.mongo.divide_numeric_only"),
-                                });
-                            }
-                        };
-                        match mongodb_utils::data_value_to_bson(
-                            self,
-                            &crate::types::DataValue::Float(divisor),
-                        ) {
-                            Ok(bson_value) => {
-                                if !set_doc.contains_key("$mul") {
-                                    set_doc.insert("$mul", Document::new());
-                                }
-                                let mul_doc = set_doc.get_mut("$mul").unwrap().as_document_mut().unwrap();
-                                mul_doc.insert(&operation.field, bson_value);
-                            }
-                            Err(e) => {
-                                return Err(QuickDbError::QueryError {
-                                    message: crate::i18n::tf("adapter.mongo.convert_to_bson_failed", &[("operation", "除数值"), ("error", &e.to_string())]),
-                                });
-                            }
-                        };
-                    }
-                    crate::types::UpdateOperator::PercentIncrease => {
-                        // 百分比增加：转换为乘法 (1 + percentage/100)
-                        let percentage = match &operation.value {
-                            crate::types::DataValue::Float(f) => *f,
-                            crate::types::DataValue::Int(i) => *i as f64,
-                            _ => {
-                                return Err(QuickDbError::ValidationError {
-                                    field: operation.field.clone(),
-                                    message: crate::i18n::t("adapter.mongo.percent_increase_numeric_only"),
-                                });
-                            }
-                        };
-                        let multiplier = 1.0 + percentage / 100.0;
-                        match mongodb_utils::data_value_to_bson(
-                            self,
-                            &crate::types::DataValue::Float(multiplier),
-                        ) {
-                            Ok(bson_value) => {
-                                if !set_doc.contains_key("$mul") {
-                                    set_doc.insert("$mul", Document::new());
-                                }
-                                let mul_doc = set_doc.get_mut("$mul").unwrap().as_document_mut().unwrap();
-                                mul_doc.insert(&operation.field, bson_value);
-                            }
-                            Err(e) => {
-                                return Err(QuickDbError::QueryError {
-                                    message: crate::i18n::tf("adapter.mongo.convert_to_bson_failed", &[("operation", "百分比增加值"), ("error", &e.to_string())]),
-                                });
-                            }
-                        };
-                    }
-                    crate::types::UpdateOperator::PercentDecrease => {
-                        // 百分比减少：转换为乘法 (1 - percentage/100)
-                        let percentage = match &operation.value {
-                            crate::types::DataValue::Float(f) => *f,
-                            crate::types::DataValue::Int(i) => *i as f64,
-                            _ => {
-                                return Err(QuickDbError::ValidationError {
-                                    field: operation.field.clone(),
-                                    message: crate::i18n::t("adapter.mongo.percent_decrease_numeric_only"),
-                                });
-                            }
-                        };
-                        let multiplier = 1.0 - percentage / 100.0;
-                        match mongodb_utils::data_value_to_bson(
-                            self,
-                            &crate::types::DataValue::Float(multiplier),
-                        ) {
-                            Ok(bson_value) => {
-                                if !set_doc.contains_key("$mul") {
-                                    set_doc.insert("$mul", Document::new());
-                                }
-                                let mul_doc = set_doc.get_mut("$mul").unwrap().as_document_mut().unwrap();
-                                mul_doc.insert(&operation.field, bson_value);
-                            }
-                            Err(e) => {
-                                return Err(QuickDbError::QueryError {
-                                    message: crate::i18n::tf("adapter.mongo.convert_to_bson_failed", &[("operation", "百分比减少值"), ("error", &e.to_string())]),
-                                });
-                            }
-                        };
-                    }
-                }
-            }
+            let update_doc = mongodb_utils::build_operations_update_document(self, operations)?;
 
-            if !set_doc.is_empty() {
-                // 将$mul操作从set_doc中分离出来
-                let mut mul_doc = Document::new();
-                if let Some(bson_value) = set_doc.remove("$mul") {
-                    update_doc.insert("$mul", bson_value);
-                }
-                update_doc.insert("$set", set_doc);
-            }
-
-            if !inc_doc.is_empty() {
-                update_doc.insert("$inc", inc_doc);
-            }
-
-            if update_doc.is_empty() {
-                return Err(QuickDbError::ValidationError {
-                    field: "operations".to_string(),
-                    message: crate::i18n::t("adapter.mongo.update_empty"),
-                });
-            }
-
-            debug!(
-                "执行MongoDB操作更新: query={:?}, update={:?}",
-                query, update_doc
-            );
+            crate::statement_log::log_mongo(table, "updateMany", &[&query, &update_doc]);
 
-            let result = collection
-                .update_many(query, update_doc, None)
-                .await
-                .map_err(|e| QuickDbError::QueryError {
-                    message: crate::i18n::tf("adapter.mongo.update_failed", &[("error", &e.to_string())]),
-                })?;
-
-            Ok(result.modified_count)
+            mongodb_utils::update_many(self, db, table, query, update_doc).await
         } else {
             Err(QuickDbError::ConnectionError {
                 message: crate::i18n::t("adapter.mongo.connection_mismatch"),
@@ -658,7 +540,7 @@ impl DatabaseAdapter for MongoAdapter {
 
             let query = build_query_document(table, alias, conditions)?;
 
-            debug!("执行MongoDB删除: {:?}", query);
+            crate::statement_log::log_mongo(table, "deleteMany", &[&query]);
 
             let result = collection.delete_many(query, None).await.map_err(|e| {
                 if check_collection_not_exist_error(&e, table) {
@@ -699,6 +581,101 @@ impl DatabaseAdapter for MongoAdapter {
         Ok(affected > 0)
     }
 
+    async fn delete_by_id_returning(
+        &self,
+        connection: &DatabaseConnection,
+        table: &str,
+        id: &DataValue,
+        _alias: &str,
+    ) -> QuickDbResult<Option<DataValue>> {
+        mongodb_query::delete_by_id_returning(self, connection, table, id).await
+    }
+
+    /// 使用 `bulkWrite` 命令（MongoDB 8.0+）一次提交全部操作
+    async fn bulk_write(
+        &self,
+        connection: &DatabaseConnection,
+        table: &str,
+        ops: &[BulkOp],
+        mode: BulkWriteMode,
+        _id_strategy: &IdStrategy,
+        alias: &str,
+    ) -> QuickDbResult<BulkWriteReport> {
+        let DatabaseConnection::MongoDB(db) = connection else {
+            return Err(QuickDbError::ConnectionError {
+                message: crate::i18n::t("adapter.mongo.connection_mismatch"),
+            });
+        };
+        let collection = mongodb_utils::get_collection(self, db, table);
+
+        // 无法构建的操作直接记为失败，不提交给服务器
+        let mut report = BulkWriteReport::skipped(ops.len());
+        let mut submitted = Vec::with_capacity(ops.len());
+        let mut op_documents = Vec::with_capacity(ops.len());
+        for (index, op) in ops.iter().enumerate() {
+            match bulk_op_document(self, table, alias, op) {
+                Ok(document) => {
+                    submitted.push(index);
+                    op_documents.push(document);
+                }
+                Err(e) => {
+                    report.outcomes[index] = BulkOpOutcome::Failed { message: e.to_string() };
+                    if mode == BulkWriteMode::Ordered {
+                        break;
+                    }
+                }
+            }
+        }
+        if op_documents.is_empty() {
+            return Ok(report);
+        }
+
+        let command = doc! {
+            "bulkWrite": 1,
+            "ops": op_documents,
+            "nsInfo": [{ "ns": collection.namespace().to_string() }],
+            "ordered": mode == BulkWriteMode::Ordered,
+            "errorsOnly": false,
+        };
+        crate::statement_log::log_mongo(table, "bulkWrite", &[&command]);
+
+        let admin = collection.client().database("admin");
+        let command_failed = |e: mongodb::error::Error| QuickDbError::QueryError {
+            message: crate::i18n::tf("bulk.transaction_failed", &[("error", &e.to_string())]),
+        };
+        let response = admin.run_command(command, None).await.map_err(command_failed)?;
+        let mut cursor = response.get_document("cursor").cloned().unwrap_or_default();
+        let mut batch_key = "firstBatch";
+        loop {
+            for entry in cursor.get_array(batch_key).map(|batch| batch.as_slice()).unwrap_or_default() {
+                let Some(entry) = entry.as_document() else { continue };
+                let Some(&index) = usize::try_from(bson_number(entry, "idx")).ok().and_then(|idx| submitted.get(idx)) else {
+                    continue;
+                };
+                report.outcomes[index] = if bson_number(entry, "ok") == 1 {
+                    let key = if matches!(ops[index], BulkOp::Update { .. }) { "nModified" } else { "n" };
+                    BulkOpOutcome::Applied { affected: bson_number(entry, key).max(0) as u64 }
+                } else {
+                    BulkOpOutcome::Failed {
+                        message: entry.get_str("errmsg").unwrap_or_default().to_string(),
+                    }
+                };
+            }
+
+            let cursor_id = bson_number(&cursor, "id");
+            if cursor_id == 0 {
+                break;
+            }
+            let more = admin
+                .run_command(doc! { "getMore": cursor_id, "collection": "$cmd.bulkWrite" }, None)
+                .await
+                .map_err(command_failed)?;
+            cursor = more.get_document("cursor").cloned().unwrap_or_default();
+            batch_key = "nextBatch";
+        }
+        Ok(report)
+    }
+
     async fn count(
         &self,
         connection: &DatabaseConnection,
@@ -737,8 +714,18 @@ impl DatabaseAdapter for MongoAdapter {
         index_name: &str,
         fields: &[String],
         unique: bool,
+        options: &IndexOptions,
     ) -> QuickDbResult<()> {
-        mongodb_schema::create_index(self, connection, table, index_name, fields, unique).await
+        mongodb_schema::create_index(
+            self,
+            connection,
+            table,
+            index_name,
+            fields,
+            unique,
+            options,
+        )
+        .await
     }
 
     async fn table_exists(
@@ -749,6 +736,14 @@ impl DatabaseAdapter for MongoAdapter {
         mongodb_schema::table_exists(self, connection, table).await
     }
 
+    async fn count_estimate(
+        &self,
+        connection: &DatabaseConnection,
+        table: &str,
+    ) -> QuickDbResult<CountEstimate> {
+        mongodb_schema::count_estimate(self, connection, table).await
+    }
+
     async fn drop_table(&self, connection: &DatabaseConnection, table: &str) -> QuickDbResult<()> {
         mongodb_schema::drop_table(self, connection, table).await
     }
@@ -796,7 +791,7 @@ impl DatabaseAdapter for MongoAdapter {
         }
 
         // 2. 生成MongoDB聚合管道（带占位符）
-        let pipeline_json = self.generate_stored_procedure_pipeline(&config).await?;
+        let pipeline_json = self.generate_stored_procedure_pipeline(config).await?;
         debug!("生成MongoDB存储过程聚合管道: {}", pipeline_json);
 
         // 3. 将存储过程信息存储到适配器映射表中（MongoDB不需要执行创建聚合管道）
@@ -817,17 +812,25 @@ impl DatabaseAdapter for MongoAdapter {
         })
     }
 
+    /// 删除存储过程（从适配器映射表中移除模板）
+    async fn drop_stored_procedure(
+        &self,
+        _connection: &DatabaseConnection,
+        procedure_name: &str,
+    ) -> QuickDbResult<bool> {
+        let removed = self.stored_procedures.lock().await.remove(procedure_name).is_some();
+        debug!("删除MongoDB存储过程: {}, 存在={}", procedure_name, removed);
+        Ok(removed)
+    }
+
     /// 执行存储过程查询（MongoDB使用聚合管道实现）
     async fn execute_stored_procedure(
         &self,
         connection: &DatabaseConnection,
         procedure_name: &str,
-        database: &str,
+        _database: &str,
         params: Option<std::collections::HashMap<String, crate::types::DataValue>>,
     ) -> QuickDbResult<crate::stored_procedure::StoredProcedureQueryResult> {
-        use crate::adapter::mongodb::adapter::MongoAdapter;
-        use serde_json::json;
-
         // 获取存储过程信息
         let procedures = self.stored_procedures.lock().await;
         let procedure_info = procedures.get(procedure_name).ok_or_else(|| {
@@ -909,7 +912,7 @@ impl MongoAdapter {
     async fn build_final_pipeline_from_template(
         &self,
         pipeline_template: &serde_json::Value,
-        params: Option<std::collections::HashMap<String, crate::types::DataValue>>,
+        _params: Option<std::collections::HashMap<String, crate::types::DataValue>>,
     ) -> QuickDbResult<serde_json::Value> {
         let mut final_pipeline = pipeline_template.clone();
 
@@ -18,46 +18,64 @@ fn check_collection_not_exist_error(error: &mongodb::error::Error, collection: &
     error_string.contains("command failed") && error_string.contains("find")
 }
 
+/// 构建字段投影文档（未指定字段时返回 None，即返回完整文档）
+fn projection_document(fields: &[String]) -> Option<Document> {
+    if fields.is_empty() {
+        return None;
+    }
+    let mut projection = Document::new();
+    for field in fields {
+        projection.insert(crate::adapter::mongodb::query_builder::map_field_name(field), 1);
+    }
+    Some(projection)
+}
+
+/// 构建按 `_id` 查询的过滤文档（支持 ObjectId 字符串与其他 ID 类型）
+fn id_filter(adapter: &MongoAdapter, id: &DataValue) -> QuickDbResult<Document> {
+    let query = match id {
+        DataValue::String(id_str) => {
+            // 处理ObjectId格式：ObjectId("xxx") 或直接是ObjectId字符串
+            let actual_id = if id_str.starts_with("ObjectId(\"") && id_str.ends_with("\")") {
+                // 提取ObjectId字符串部分
+                &id_str[10..id_str.len() - 2]
+            } else {
+                id_str
+            };
+
+            // 尝试解析为ObjectId，如果失败则作为字符串查询
+            if let Ok(object_id) = mongodb::bson::oid::ObjectId::parse_str(actual_id) {
+                doc! { "_id": object_id }
+            } else {
+                doc! { "_id": actual_id }
+            }
+        }
+        _ => {
+            match crate::adapter::mongodb::utils::data_value_to_bson(adapter, id) {
+                Ok(bson_id) => doc! { "_id": bson_id },
+                Err(e) => {
+                    return Err(QuickDbError::QueryError {
+                        message: crate::i18n::tf("adapter.mongo.convert_id_bson_failed", &[("error", &e.to_string())]),
+                    });
+                }
+            }
+        }
+    };
+    Ok(query)
+}
+
 pub(crate) async fn find_by_id(
     adapter: &MongoAdapter,
     connection: &DatabaseConnection,
     table: &str,
     id: &DataValue,
-    alias: &str,
+    _alias: &str,
 ) -> QuickDbResult<Option<DataValue>> {
     if let DatabaseConnection::MongoDB(db) = connection {
         let collection = crate::adapter::mongodb::utils::get_collection(adapter, db, table);
 
-        let query = match id {
-            DataValue::String(id_str) => {
-                // 处理ObjectId格式：ObjectId("xxx") 或直接是ObjectId字符串
-                let actual_id = if id_str.starts_with("ObjectId(\"") && id_str.ends_with("\")") {
-                    // 提取ObjectId字符串部分
-                    &id_str[10..id_str.len() - 2]
-                } else {
-                    id_str
-                };
-
-                // 尝试解析为ObjectId，如果失败则作为字符串查询
-                if let Ok(object_id) = mongodb::bson::oid::ObjectId::parse_str(actual_id) {
-                    doc! { "_id": object_id }
-                } else {
-                    doc! { "_id": actual_id }
-                }
-            }
-            _ => {
-                match crate::adapter::mongodb::utils::data_value_to_bson(adapter, id) {
-                    Ok(bson_id) => doc! { "_id": bson_id },
-                    Err(e) => {
-                        return Err(QuickDbError::QueryError {
-                            message: crate::i18n::tf("adapter.mongo.convert_id_bson_failed", &[("error", &e.to_string())]),
-                        });
-                    }
-                }
-            }
-        };
+        let query = id_filter(adapter, id)?;
 
-        debug!("执行MongoDB根据ID查询: {:?}", query);
+        crate::statement_log::log_mongo(table, "findOne", &[&query]);
 
         let result =
             collection
@@ -96,6 +114,98 @@ pub(crate) async fn find_by_id(
     }
 }
 
+pub(crate) async fn exists_by_id(
+    adapter: &MongoAdapter,
+    connection: &DatabaseConnection,
+    table: &str,
+    id: &DataValue,
+) -> QuickDbResult<bool> {
+    if let DatabaseConnection::MongoDB(db) = connection {
+        let collection = crate::adapter::mongodb::utils::get_collection(adapter, db, table);
+        let query = id_filter(adapter, id)?;
+
+        crate::statement_log::log_mongo(table, "findOne", &[&query]);
+
+        // 找到一条即可停止计数；集合不存在时计数为 0
+        let options = mongodb::options::CountOptions::builder().limit(1).build();
+        let count = collection.count_documents(query, options).await.map_err(|e| {
+            QuickDbError::QueryError {
+                message: crate::i18n::tf("adapter.mongo.count_failed", &[("error", &e.to_string())]),
+            }
+        })?;
+        Ok(count > 0)
+    } else {
+        Err(QuickDbError::ConnectionError {
+            message: crate::i18n::t("adapter.mongo.connection_mismatch"),
+        })
+    }
+}
+
+/// 根据ID更新并返回更新后的文档（`findOneAndUpdate`）
+pub(crate) async fn update_by_id_returning(
+    adapter: &MongoAdapter,
+    connection: &DatabaseConnection,
+    table: &str,
+    id: &DataValue,
+    data: &std::collections::HashMap<String, DataValue>,
+) -> QuickDbResult<Option<DataValue>> {
+    if let DatabaseConnection::MongoDB(db) = connection {
+        let collection = crate::adapter::mongodb::utils::get_collection(adapter, db, table);
+        let query = id_filter(adapter, id)?;
+        let update = crate::adapter::mongodb::utils::build_update_document(adapter, data)?;
+
+        crate::statement_log::log_mongo(table, "findOneAndUpdate", &[&query, &update]);
+
+        let options = mongodb::options::FindOneAndUpdateOptions::builder()
+            .return_document(mongodb::options::ReturnDocument::After)
+            .build();
+        let result = collection.find_one_and_update(query, update, options).await.map_err(|e| {
+            crate::adapter::mongodb::utils::mongo_unique_violation(&e, table).unwrap_or_else(|| {
+                QuickDbError::QueryError {
+                    message: crate::i18n::tf("adapter.mongo.update_failed", &[("error", &e.to_string())]),
+                }
+            })
+        })?;
+
+        result
+            .map(|doc| crate::adapter::mongodb::utils::document_to_data_map(adapter, &doc).map(DataValue::Object))
+            .transpose()
+    } else {
+        Err(QuickDbError::ConnectionError {
+            message: crate::i18n::t("adapter.mongo.connection_mismatch"),
+        })
+    }
+}
+
+/// 根据ID删除并返回被删除的文档（`findOneAndDelete`）
+pub(crate) async fn delete_by_id_returning(
+    adapter: &MongoAdapter,
+    connection: &DatabaseConnection,
+    table: &str,
+    id: &DataValue,
+) -> QuickDbResult<Option<DataValue>> {
+    if let DatabaseConnection::MongoDB(db) = connection {
+        let collection = crate::adapter::mongodb::utils::get_collection(adapter, db, table);
+        let query = id_filter(adapter, id)?;
+
+        crate::statement_log::log_mongo(table, "findOneAndDelete", &[&query]);
+
+        let result = collection.find_one_and_delete(query, None).await.map_err(|e| {
+            QuickDbError::QueryError {
+                message: crate::i18n::tf("adapter.mongo.delete_failed", &[("error", &e.to_string())]),
+            }
+        })?;
+
+        result
+            .map(|doc| crate::adapter::mongodb::utils::document_to_data_map(adapter, &doc).map(DataValue::Object))
+            .transpose()
+    } else {
+        Err(QuickDbError::ConnectionError {
+            message: crate::i18n::t("adapter.mongo.connection_mismatch"),
+        })
+    }
+}
+
 pub(crate) async fn find(
     adapter: &MongoAdapter,
     connection: &DatabaseConnection,
@@ -145,7 +255,7 @@ pub(crate) async fn find_with_groups(
             .where_condition_groups(condition_groups)
             .build(table, alias)?;
 
-        debug!("执行MongoDB条件组合查询: {:?}", query);
+        crate::statement_log::log_mongo(table, "find", &[&query]);
 
         let mut find_options = mongodb::options::FindOptions::default();
 
@@ -168,6 +278,16 @@ pub(crate) async fn find_with_groups(
             find_options.skip = Some(pagination.skip);
         }
 
+        // 查询超时下推为 maxTimeMS
+        find_options.max_time = options.timeout;
+
+        if options.lock.is_some() {
+            debug!("MongoDB没有读锁，忽略查询选项中的lock");
+        }
+
+        // 字段投影
+        find_options.projection = projection_document(&options.fields);
+
         let mut cursor =
             collection
                 .find(query, find_options)
@@ -217,7 +337,7 @@ pub(crate) async fn find_with_groups_with_config(
             .where_condition_groups_with_config(condition_groups)
             .build(table, alias)?;
 
-        debug!("执行MongoDB条件组合查询（完整版）: {:?}", query);
+        crate::statement_log::log_mongo(table, "find", &[&query]);
 
         let mut find_options = mongodb::options::FindOptions::default();
 
@@ -240,6 +360,16 @@ pub(crate) async fn find_with_groups_with_config(
             find_options.skip = Some(pagination.skip);
         }
 
+        // 查询超时下推为 maxTimeMS
+        find_options.max_time = options.timeout;
+
+        if options.lock.is_some() {
+            debug!("MongoDB没有读锁，忽略查询选项中的lock");
+        }
+
+        // 字段投影
+        find_options.projection = projection_document(&options.fields);
+
         let mut cursor =
             collection
                 .find(query, find_options)
@@ -286,7 +416,7 @@ pub(crate) async fn count(
         let query =
             crate::adapter::mongodb::query_builder::build_query_document(table, alias, conditions)?;
 
-        debug!("执行MongoDB计数: {:?}", query);
+        crate::statement_log::log_mongo(table, "countDocuments", &[&query]);
 
         let count = collection.count_documents(query, None).await.map_err(|e| {
             if check_collection_not_exist_error(&e, table) {
@@ -324,7 +454,7 @@ pub(crate) async fn count_with_groups(
             .where_condition_groups_with_config(condition_groups)
             .build(table, alias)?;
 
-        debug!("执行MongoDB条件组合计数: {:?}", query);
+        crate::statement_log::log_mongo(table, "countDocuments", &[&query]);
 
         let count = collection.count_documents(query, None).await.map_err(|e| {
             if check_collection_not_exist_error(&e, table) {
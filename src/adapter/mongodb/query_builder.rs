@@ -4,13 +4,14 @@
 
 use crate::adapter::utils::get_field_type;
 use crate::error::{QuickDbError, QuickDbResult};
+use crate::types::query::{between_bounds, regex_pattern};
 use crate::types::*;
 use mongodb::bson::{Bson, Document, Regex, doc};
 use rat_logger::debug;
 
 /// 映射字段名（适配MongoDB命名约定）
 /// 将通用的字段名映射为MongoDB特定的字段名
-fn map_field_name(field_name: &str) -> &str {
+pub(crate) fn map_field_name(field_name: &str) -> &str {
     // MongoDB使用_id作为主键，而不是id
     if field_name == "id" {
         "_id"
@@ -19,6 +20,19 @@ fn map_field_name(field_name: &str) -> &str {
     }
 }
 
+/// 忽略大小写的完整匹配正则（值中的正则元字符会被转义）
+fn exact_match_regex(value: &str) -> Bson {
+    Bson::RegularExpression(Regex {
+        pattern: format!("^{}$", regex::escape(value)),
+        options: "i".to_string(),
+    })
+}
+
+/// 是否为非空的字符串数组
+fn is_string_array(value: &Bson) -> bool {
+    matches!(value, Bson::Array(arr) if !arr.is_empty() && arr.iter().all(|item| matches!(item, Bson::String(_))))
+}
+
 /// MongoDB查询构建器
 pub struct MongoQueryBuilder {
     conditions: Vec<QueryConditionWithConfig>,
@@ -37,6 +51,7 @@ impl MongoQueryBuilder {
     }
 
     /// 添加WHERE条件
+    #[allow(dead_code)]
     pub fn where_condition(mut self, condition: QueryConditionWithConfig) -> Self {
         self.conditions.push(condition);
         self
@@ -285,7 +300,12 @@ impl MongoQueryBuilder {
                     doc! { field_name: bson_value }
                 }
             }
-            QueryOperator::Ne => doc! { field_name: doc! { "$ne": bson_value } },
+            QueryOperator::Ne => match &bson_value {
+                Bson::String(s) if condition.case_insensitive => {
+                    doc! { field_name: doc! { "$not": exact_match_regex(s) } }
+                }
+                _ => doc! { field_name: doc! { "$ne": bson_value } },
+            },
             QueryOperator::Gt => doc! { field_name: doc! { "$gt": bson_value } },
             QueryOperator::Gte => doc! { field_name: doc! { "$gte": bson_value } },
             QueryOperator::Lt => doc! { field_name: doc! { "$lt": bson_value } },
@@ -335,6 +355,17 @@ impl MongoQueryBuilder {
                     });
                 }
             }
+            QueryOperator::In if condition.case_insensitive && is_string_array(&bson_value) => {
+                // 大小写不敏感的IN：每个候选值转换为锚定的忽略大小写正则
+                let patterns: Vec<Bson> = bson_value
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .filter_map(Bson::as_str)
+                    .map(exact_match_regex)
+                    .collect();
+                doc! { field_name: doc! { "$in": patterns } }
+            }
             QueryOperator::In => {
                 // 验证Array字段IN操作的数据类型
                 if let Bson::Array(arr) = &bson_value {
@@ -402,14 +433,10 @@ impl MongoQueryBuilder {
                 }
             }
             QueryOperator::Regex => {
-                if let Bson::String(s) = bson_value {
-                    doc! { field_name: doc! { "$regex": s, "$options": "i" } }
-                } else {
-                    return Err(QuickDbError::ValidationError {
-                        field: condition.field.clone(),
-                        message: crate::i18n::t("adapter.mongo.regex_string_only"),
-                    });
-                }
+                // 与 SQL 后端一致，默认大小写敏感
+                let pattern = regex_pattern(&condition.field, &condition.value)?;
+                let options = if condition.case_insensitive { "i" } else { "" };
+                doc! { field_name: doc! { "$regex": pattern, "$options": options } }
             }
             QueryOperator::Exists => {
                 doc! { field_name: doc! { "$exists": true } }
@@ -420,6 +447,21 @@ impl MongoQueryBuilder {
             QueryOperator::IsNotNull => {
                 doc! { field_name: doc! { "$ne": null } }
             }
+            QueryOperator::Between => {
+                let (lower, upper) = between_bounds(&condition.field, &condition.value)?;
+                doc! { field_name: doc! {
+                    "$gte": self.data_value_to_bson(lower),
+                    "$lte": self.data_value_to_bson(upper),
+                } }
+            }
+            QueryOperator::NotBetween => {
+                // 与 SQL 的 NOT BETWEEN 一致，空值和缺失字段不匹配
+                let (lower, upper) = between_bounds(&condition.field, &condition.value)?;
+                doc! { "$or": [
+                    { field_name: { "$lt": self.data_value_to_bson(lower) } },
+                    { field_name: { "$gt": self.data_value_to_bson(upper) } },
+                ] }
+            }
         };
 
         Ok(condition_doc)
@@ -457,10 +499,10 @@ impl MongoQueryBuilder {
                     );
                     Ok(doc! { field_name: regex_doc })
                 } else {
-                    return Err(QuickDbError::ValidationError {
+                    Err(QuickDbError::ValidationError {
                         field: field_name.to_string(),
                         message: crate::i18n::t("adapter.mongo.contains_string_only"),
-                    });
+                    })
                 }
             }
             crate::model::FieldType::Array { .. } => {
@@ -482,10 +524,10 @@ impl MongoQueryBuilder {
                 }
             }
             _ => {
-                return Err(QuickDbError::ValidationError {
+                Err(QuickDbError::ValidationError {
                     field: field_name.to_string(),
                     message: crate::i18n::t("adapter.mongo.contains_supported_types"),
-                });
+                })
             }
         }
     }
@@ -513,7 +555,7 @@ impl MongoQueryBuilder {
             }
             DataValue::DateTimeUTC(dt) => {
                 // DateTime<Utc>直接转换为MongoDB BSON DateTime
-                Bson::DateTime(mongodb::bson::DateTime::from_system_time(dt.clone().into()))
+                Bson::DateTime(mongodb::bson::DateTime::from_system_time((*dt).into()))
             }
             DataValue::Uuid(uuid) => Bson::String(uuid.to_string()),
             DataValue::Json(json) => {
@@ -613,6 +655,39 @@ mod tests {
         // 这里可以添加单元测试
     }
 
+    #[test]
+    fn case_insensitive_eq_ne_in_use_anchored_regex() {
+        let ci = |field: &str, operator: crate::types::QueryOperator, value: DataValue| {
+            QueryConditionWithConfig {
+                field: field.to_string(),
+                operator,
+                value,
+                case_insensitive: true,
+            }
+        };
+        let conditions = vec![
+            ci("email", crate::types::QueryOperator::Ne, DataValue::String("A.B@X.COM".to_string())),
+            ci(
+                "role",
+                crate::types::QueryOperator::In,
+                DataValue::Array(vec![DataValue::String("Admin".to_string())]),
+            ),
+        ];
+
+        let filter = build_query_document("ci_users", "default", &conditions).unwrap();
+        let filter = filter.to_string();
+        assert!(filter.contains(r#""email": { "$not": /^A\.B@X\.COM$/i }"#), "{}", filter);
+        assert!(filter.contains(r#""role": { "$in": [/^Admin$/i] }"#), "{}", filter);
+
+        // 正则默认大小写敏感，且需要通过复杂度校验
+        let mut regex = ci("name", crate::types::QueryOperator::Regex, DataValue::String("^ali".to_string()));
+        regex.case_insensitive = false;
+        let filter = build_query_document("ci_users", "default", std::slice::from_ref(&regex)).unwrap();
+        assert_eq!(filter.to_string(), r#"{ "name": { "$regex": "^ali", "$options": "" } }"#);
+        regex.value = DataValue::String("(a+)+$".to_string());
+        assert!(build_query_document("ci_users", "default", &[regex]).is_err());
+    }
+
     #[test]
     fn test_field_name_mapping() {
         // 测试id字段映射到_id
@@ -4,9 +4,9 @@
 use crate::adapter::DatabaseConnection;
 use crate::adapter::mongodb::MongoAdapter;
 use crate::error::{QuickDbError, QuickDbResult};
-use crate::model::{FieldDefinition, FieldType};
+use crate::model::{ExpiresAfter, FieldDefinition, IndexOptions};
 use crate::types::*;
-use mongodb::bson::{Document, doc};
+use mongodb::bson::{Bson, Document, doc};
 use rat_logger::debug;
 use std::collections::HashMap;
 
@@ -37,6 +37,28 @@ pub(crate) async fn create_table(
             }
         }
 
+        // MongoDB的主键固定为 _id，复合/自定义主键通过唯一复合索引保证
+        if let Some(pk) = crate::adapter::utils::get_custom_primary_key(table, alias) {
+            let index_name = format!("pk_{}", pk.join("_"));
+            create_index(
+                adapter,
+                connection,
+                table,
+                &index_name,
+                &pk,
+                true,
+                &IndexOptions::default(),
+            )
+            .await?;
+        }
+
+        // 记录过期由MongoDB的TTL索引自动清理
+        if let Some(expires_after) = crate::manager::get_model_with_alias(table, alias)
+            .and_then(|meta| meta.expires_after)
+        {
+            create_ttl_index(adapter, db, table, &expires_after).await?;
+        }
+
         Ok(())
     } else {
         Err(QuickDbError::ConnectionError {
@@ -52,8 +74,19 @@ pub(crate) async fn create_index(
     index_name: &str,
     fields: &[String],
     unique: bool,
+    options: &IndexOptions,
 ) -> QuickDbResult<()> {
     if let DatabaseConnection::MongoDB(db) = connection {
+        if options.expression.is_some() {
+            return Err(QuickDbError::ValidationError {
+                field: "expression".to_string(),
+                message: crate::i18n::tf(
+                    "model.expression_index_unsupported",
+                    &[("database", "MongoDB")],
+                ),
+            });
+        }
+
         let collection = crate::adapter::mongodb::utils::get_collection(adapter, db, table);
 
         let mut index_doc = Document::new();
@@ -64,6 +97,10 @@ pub(crate) async fn create_index(
         let mut index_options = mongodb::options::IndexOptions::default();
         index_options.name = Some(index_name.to_string());
         index_options.unique = Some(unique);
+        if !options.where_clause.is_empty() {
+            index_options.partial_filter_expression =
+                Some(build_partial_filter(adapter, &options.where_clause)?);
+        }
 
         let index_model = mongodb::IndexModel::builder()
             .keys(index_doc)
@@ -87,20 +124,122 @@ pub(crate) async fn create_index(
     }
 }
 
-pub(crate) async fn table_exists(
+/// 将部分索引条件转换为 partialFilterExpression
+///
+/// partialFilterExpression 只支持等值、$exists、范围比较和 $in 等有限的操作符
+fn build_partial_filter(
+    adapter: &MongoAdapter,
+    conditions: &[QueryConditionWithConfig],
+) -> QuickDbResult<Document> {
+    let mut filter = Document::new();
+    for condition in conditions {
+        let value = crate::adapter::mongodb::utils::data_value_to_bson(adapter, &condition.value)?;
+        let (operator, value) = match &condition.operator {
+            QueryOperator::Eq => ("$eq", value),
+            QueryOperator::Gt => ("$gt", value),
+            QueryOperator::Gte => ("$gte", value),
+            QueryOperator::Lt => ("$lt", value),
+            QueryOperator::Lte => ("$lte", value),
+            QueryOperator::In => ("$in", value),
+            QueryOperator::Exists | QueryOperator::IsNotNull => ("$exists", Bson::Boolean(true)),
+            operator => {
+                return Err(QuickDbError::ValidationError {
+                    field: "where_clause".to_string(),
+                    message: crate::i18n::tf(
+                        "model.index_operator_unsupported",
+                        &[("operator", &format!("{:?}", operator))],
+                    ),
+                });
+            }
+        };
+
+        // 同一字段的多个条件合并到同一个子文档中
+        match filter.get_mut(&condition.field) {
+            Some(Bson::Document(field_filter)) => {
+                field_filter.insert(operator, value);
+            }
+            _ => {
+                filter.insert(condition.field.clone(), doc! { operator: value });
+            }
+        }
+    }
+    Ok(filter)
+}
+
+/// 创建TTL索引
+///
+/// MongoDB 仅对 BSON Date 类型的字段执行过期删除
+pub(crate) async fn create_ttl_index(
     adapter: &MongoAdapter,
+    db: &crate::pool::MongoConnection,
+    table: &str,
+    expires_after: &ExpiresAfter,
+) -> QuickDbResult<()> {
+    let collection = crate::adapter::mongodb::utils::get_collection(adapter, db, table);
+    let index_name = format!("ttl_{}", expires_after.field);
+
+    let mut index_options = mongodb::options::IndexOptions::default();
+    index_options.name = Some(index_name.clone());
+    index_options.expire_after = Some(std::time::Duration::from_secs(expires_after.seconds));
+
+    let index_model = mongodb::IndexModel::builder()
+        .keys(doc! { expires_after.field.as_str(): 1 })
+        .options(index_options)
+        .build();
+
+    debug!(
+        "创建MongoDB TTL索引: {} 在集合 {}，过期时长 {} 秒",
+        index_name, table, expires_after.seconds
+    );
+
+    collection
+        .create_index(index_model, None)
+        .await
+        .map_err(|e| QuickDbError::QueryError {
+            message: crate::i18n::tf("adapter.mongo.create_index_failed", &[("error", &e.to_string())]),
+        })?;
+
+    Ok(())
+}
+
+pub(crate) async fn table_exists(
+    _adapter: &MongoAdapter,
     connection: &DatabaseConnection,
     table: &str,
 ) -> QuickDbResult<bool> {
     if let DatabaseConnection::MongoDB(db) = connection {
+        let (database, collection) = super::utils::resolve_namespace(db, table);
         let collection_names =
-            db.list_collection_names(None)
+            database.list_collection_names(None)
                 .await
                 .map_err(|e| QuickDbError::QueryError {
                     message: crate::i18n::tf("adapter.mongo.check_collection_failed", &[("error", &e.to_string())]),
                 })?;
 
-        Ok(collection_names.contains(&table.to_string()))
+        Ok(collection_names.iter().any(|name| name == collection))
+    } else {
+        Err(QuickDbError::ConnectionError {
+            message: crate::i18n::t("adapter.mongo.connection_mismatch"),
+        })
+    }
+}
+
+/// 以集合元数据（`estimatedDocumentCount`）估算文档数，不扫描集合；
+/// 非正常关闭后或分片集群迁移块期间可能不准确
+pub(crate) async fn count_estimate(
+    adapter: &MongoAdapter,
+    connection: &DatabaseConnection,
+    table: &str,
+) -> QuickDbResult<CountEstimate> {
+    if let DatabaseConnection::MongoDB(db) = connection {
+        let collection = super::utils::get_collection(adapter, db, table);
+        let count = collection
+            .estimated_document_count(None)
+            .await
+            .map_err(|e| QuickDbError::QueryError {
+                message: crate::i18n::tf("adapter.mongo.count_failed", &[("error", &e.to_string())]),
+            })?;
+        Ok(CountEstimate::approximate(count))
     } else {
         Err(QuickDbError::ConnectionError {
             message: crate::i18n::t("adapter.mongo.connection_mismatch"),
@@ -116,7 +255,7 @@ pub(crate) async fn drop_table(
     if let DatabaseConnection::MongoDB(db) = connection {
         debug!("执行MongoDB删除集合: {}", table);
 
-        let collection = db.collection::<mongodb::bson::Document>(table);
+        let collection = super::utils::get_collection(adapter, db, table);
         collection
             .drop(None)
             .await
@@ -134,7 +273,7 @@ pub(crate) async fn drop_table(
 }
 
 pub(crate) async fn get_server_version(
-    adapter: &MongoAdapter,
+    _adapter: &MongoAdapter,
     connection: &DatabaseConnection,
 ) -> QuickDbResult<String> {
     if let DatabaseConnection::MongoDB(db) = connection {
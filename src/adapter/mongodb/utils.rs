@@ -4,13 +4,15 @@
 
 use crate::adapter::mongodb::MongoAdapter;
 use crate::error::{QuickDbError, QuickDbResult};
+use crate::pool::MongoConnection;
 use crate::types::*;
 use mongodb::bson::{Bson, Document, doc};
-use mongodb::{Collection, Database};
+use mongodb::Collection;
 use rat_logger::debug;
 use std::collections::HashMap;
 
 /// 将DataValue转换为BSON值
+#[allow(clippy::only_used_in_recursion)]
 pub(crate) fn data_value_to_bson(adapter: &MongoAdapter, value: &DataValue) -> QuickDbResult<Bson> {
     match value {
         DataValue::String(s) => Ok(Bson::String(s.clone())),
@@ -33,7 +35,7 @@ pub(crate) fn data_value_to_bson(adapter: &MongoAdapter, value: &DataValue) -> Q
         }
         DataValue::DateTimeUTC(dt) => {
             // DateTime<Utc>直接转换为MongoDB BSON DateTime
-            Ok(Bson::DateTime(mongodb::bson::DateTime::from_system_time(dt.clone().into())))
+            Ok(Bson::DateTime(mongodb::bson::DateTime::from_system_time((*dt).into())))
         }
         DataValue::Uuid(uuid) => Ok(Bson::String(uuid.to_string())),
         DataValue::Json(json) => {
@@ -102,10 +104,10 @@ pub(crate) fn data_value_to_bson(adapter: &MongoAdapter, value: &DataValue) -> Q
                     // Json字段不应该存储对象和数组之外的其他类型
                     // 这应该是验证阶段就拒绝的，如果到这里说明有内部错误
                     // 返回错误而不是panic，让用户知道数据有问题
-                    return Err(QuickDbError::ValidationError {
+                    Err(QuickDbError::ValidationError {
                         field: "json_field".to_string(),
                         message: crate::i18n::tf("adapter.mongo.json_invalid_data", &[("data", &format!("{:?}", json))]),
-                    });
+                    })
                 }
             }
         }
@@ -156,16 +158,8 @@ pub(crate) fn document_to_data_map(
     Ok(result)
 }
 
-/// 将Document转换为DataValue
-pub(crate) fn document_to_data_value(
-    adapter: &MongoAdapter,
-    doc: &Document,
-) -> QuickDbResult<DataValue> {
-    let map = document_to_data_map(adapter, doc)?;
-    Ok(DataValue::Object(map))
-}
-
 /// 将BSON转换为JSON Value
+#[allow(clippy::only_used_in_recursion)]
 pub(crate) fn bson_to_json_value(
     adapter: &MongoAdapter,
     bson: &Bson,
@@ -236,7 +230,7 @@ pub(crate) fn bson_to_data_value(adapter: &MongoAdapter, bson: &Bson) -> QuickDb
         Bson::Boolean(b) => Ok(DataValue::Bool(*b)),
         Bson::DateTime(dt) => {
             let utc_dt = chrono::DateTime::<chrono::Utc>::from(dt.to_system_time());
-            let fixed_dt = utc_dt.with_timezone(&chrono::FixedOffset::east(0));
+            let fixed_dt = utc_dt.with_timezone(&chrono::FixedOffset::east_opt(0).unwrap());
             Ok(DataValue::DateTime(fixed_dt))
         }
         Bson::ObjectId(oid) => Ok(DataValue::String(oid.to_hex())),
@@ -294,24 +288,290 @@ pub(crate) fn build_update_document(
     Ok(update_doc)
 }
 
+/// 将更新操作数组构建为更新文档
+pub(crate) fn build_operations_update_document(
+    adapter: &MongoAdapter,
+    operations: &[crate::types::UpdateOperation],
+) -> QuickDbResult<Document> {
+    let mut update_doc = Document::new();
+
+    let mut set_doc = Document::new();
+    let mut inc_doc = Document::new();
+    let mut unset_doc = Document::new();
+    let mut rename_doc = Document::new();
+
+    for operation in operations {
+        match &operation.operation {
+            crate::types::UpdateOperator::Set => {
+                match data_value_to_bson(adapter, &operation.value) {
+                    Ok(bson_value) => set_doc.insert(&operation.field, bson_value),
+                    Err(e) => {
+                        return Err(QuickDbError::QueryError {
+                            message: crate::i18n::tf("adapter.mongo.convert_to_bson_failed", &[("operation", "更新值"), ("error", &e.to_string())]),
+                        });
+                    }
+                };
+            }
+            crate::types::UpdateOperator::Increment => {
+                match data_value_to_bson(adapter, &operation.value) {
+                    Ok(bson_value) => inc_doc.insert(&operation.field, bson_value),
+                    Err(e) => {
+                        return Err(QuickDbError::QueryError {
+                            message: crate::i18n::tf("adapter.mongo.convert_to_bson_failed", &[("operation", "递增值"), ("error", &e.to_string())]),
+                        });
+                    }
+                };
+            }
+            crate::types::UpdateOperator::Decrement => {
+                // 对于减少操作，使用负数的inc操作
+                let neg_value = match &operation.value {
+                    crate::types::DataValue::Int(i) => crate::types::DataValue::Int(-i),
+                    crate::types::DataValue::Float(f) => crate::types::DataValue::Float(-f),
+                    _ => {
+                        return Err(QuickDbError::ValidationError {
+                            field: operation.field.clone(),
+                            message: crate::i18n::t("adapter.mongo.decrement_numeric_only"),
+                        });
+                    }
+                };
+                match data_value_to_bson(adapter, &neg_value) {
+                    Ok(bson_value) => inc_doc.insert(&operation.field, bson_value),
+                    Err(e) => {
+                        return Err(QuickDbError::QueryError {
+                            message: crate::i18n::tf("adapter.mongo.convert_to_bson_failed", &[("operation", "递减值"), ("error", &e.to_string())]),
+                        });
+                    }
+                };
+            }
+            crate::types::UpdateOperator::Multiply => {
+                // MongoDB使用$multiply操作符
+                match data_value_to_bson(adapter, &operation.value) {
+                    Ok(bson_value) => {
+                        if !set_doc.contains_key("$mul") {
+                            set_doc.insert("$mul", Document::new());
+                        }
+                        let mul_doc = set_doc.get_mut("$mul").unwrap().as_document_mut().unwrap();
+                        mul_doc.insert(&operation.field, bson_value);
+                    }
+                    Err(e) => {
+                        return Err(QuickDbError::QueryError {
+                            message: crate::i18n::tf("adapter.mongo.convert_to_bson_failed", &[("operation", "乘数值"), ("error", &e.to_string())]),
+                        });
+                    }
+                };
+            }
+            crate::types::UpdateOperator::Divide => {
+                // MongoDB不支持直接除法，但可以使用乘法配合小数
+                let divisor = match &operation.value {
+                    crate::types::DataValue::Int(i) => 1.0 / *i as f64,
+                    crate::types::DataValue::Float(f) => 1.0 / f,
+                    _ => {
+                        return Err(QuickDbError::ValidationError {
+                            field: operation.field.clone(),
+                            message: crate::i18n::t("adapter.mongo.divide_numeric_only"),
+                        });
+                    }
+                };
+                match data_value_to_bson(
+                    adapter,
+                    &crate::types::DataValue::Float(divisor),
+                ) {
+                    Ok(bson_value) => {
+                        if !set_doc.contains_key("$mul") {
+                            set_doc.insert("$mul", Document::new());
+                        }
+                        let mul_doc = set_doc.get_mut("$mul").unwrap().as_document_mut().unwrap();
+                        mul_doc.insert(&operation.field, bson_value);
+                    }
+                    Err(e) => {
+                        return Err(QuickDbError::QueryError {
+                            message: crate::i18n::tf("adapter.mongo.convert_to_bson_failed", &[("operation", "除数值"), ("error", &e.to_string())]),
+                        });
+                    }
+                };
+            }
+            crate::types::UpdateOperator::PercentIncrease => {
+                // 百分比增加：转换为乘法 (1 + percentage/100)
+                let percentage = match &operation.value {
+                    crate::types::DataValue::Float(f) => *f,
+                    crate::types::DataValue::Int(i) => *i as f64,
+                    _ => {
+                        return Err(QuickDbError::ValidationError {
+                            field: operation.field.clone(),
+                            message: crate::i18n::t("adapter.mongo.percent_increase_numeric_only"),
+                        });
+                    }
+                };
+                let multiplier = 1.0 + percentage / 100.0;
+                match data_value_to_bson(
+                    adapter,
+                    &crate::types::DataValue::Float(multiplier),
+                ) {
+                    Ok(bson_value) => {
+                        if !set_doc.contains_key("$mul") {
+                            set_doc.insert("$mul", Document::new());
+                        }
+                        let mul_doc = set_doc.get_mut("$mul").unwrap().as_document_mut().unwrap();
+                        mul_doc.insert(&operation.field, bson_value);
+                    }
+                    Err(e) => {
+                        return Err(QuickDbError::QueryError {
+                            message: crate::i18n::tf("adapter.mongo.convert_to_bson_failed", &[("operation", "百分比增加值"), ("error", &e.to_string())]),
+                        });
+                    }
+                };
+            }
+            crate::types::UpdateOperator::PercentDecrease => {
+                // 百分比减少：转换为乘法 (1 - percentage/100)
+                let percentage = match &operation.value {
+                    crate::types::DataValue::Float(f) => *f,
+                    crate::types::DataValue::Int(i) => *i as f64,
+                    _ => {
+                        return Err(QuickDbError::ValidationError {
+                            field: operation.field.clone(),
+                            message: crate::i18n::t("adapter.mongo.percent_decrease_numeric_only"),
+                        });
+                    }
+                };
+                let multiplier = 1.0 - percentage / 100.0;
+                match data_value_to_bson(
+                    adapter,
+                    &crate::types::DataValue::Float(multiplier),
+                ) {
+                    Ok(bson_value) => {
+                        if !set_doc.contains_key("$mul") {
+                            set_doc.insert("$mul", Document::new());
+                        }
+                        let mul_doc = set_doc.get_mut("$mul").unwrap().as_document_mut().unwrap();
+                        mul_doc.insert(&operation.field, bson_value);
+                    }
+                    Err(e) => {
+                        return Err(QuickDbError::QueryError {
+                            message: crate::i18n::tf("adapter.mongo.convert_to_bson_failed", &[("operation", "百分比减少值"), ("error", &e.to_string())]),
+                        });
+                    }
+                };
+            }
+            crate::types::UpdateOperator::Unset => {
+                unset_doc.insert(&operation.field, "");
+            }
+            crate::types::UpdateOperator::Rename => match &operation.value {
+                crate::types::DataValue::String(target) => {
+                    rename_doc.insert(&operation.field, target.as_str());
+                }
+                _ => {
+                    return Err(QuickDbError::ValidationError {
+                        field: operation.field.clone(),
+                        message: crate::i18n::tf("model.rename_target_invalid", &[("field", &operation.field)]),
+                    });
+                }
+            },
+        }
+    }
+
+    if !set_doc.is_empty() {
+        // 将$mul操作从set_doc中分离出来
+        if let Some(bson_value) = set_doc.remove("$mul") {
+            update_doc.insert("$mul", bson_value);
+        }
+        update_doc.insert("$set", set_doc);
+    }
+
+    if !inc_doc.is_empty() {
+        update_doc.insert("$inc", inc_doc);
+    }
+
+    if !unset_doc.is_empty() {
+        update_doc.insert("$unset", unset_doc);
+    }
+
+    if !rename_doc.is_empty() {
+        update_doc.insert("$rename", rename_doc);
+    }
+
+    if update_doc.is_empty() {
+        return Err(QuickDbError::ValidationError {
+            field: "operations".to_string(),
+            message: crate::i18n::t("adapter.mongo.update_empty"),
+        });
+    }
+
+    Ok(update_doc)
+}
+
 /// 获取MongoDB集合
+///
+/// 限定名 `数据库.集合名` 使用同一客户端上的另一个数据库
 pub(crate) fn get_collection(
-    adapter: &MongoAdapter,
-    db: &mongodb::Database,
+    _adapter: &MongoAdapter,
+    db: &MongoConnection,
     table: &str,
 ) -> Collection<Document> {
-    db.collection(table)
+    let (database, collection) = resolve_namespace(db, table);
+    database.collection(collection)
+}
+
+/// 解析限定集合名，返回集合所在的数据库与集合名
+pub(crate) fn resolve_namespace<'a>(db: &MongoConnection, table: &'a str) -> (mongodb::Database, &'a str) {
+    match crate::security::split_qualified_name(table) {
+        (Some(database), collection) => (db.client().database(database), collection),
+        (None, collection) => ((**db).clone(), collection),
+    }
 }
 
-/// 映射字段名（适配MongoDB命名约定）
-pub(crate) fn map_field_name(adapter: &MongoAdapter, field_name: &str) -> String {
-    // 这里可以实现字段名映射逻辑
-    field_name.to_string()
+/// 批量更新匹配的文档并返回修改的文档数
+///
+/// 当前操作设置了超时时改用 `update` 命令附带 `maxTimeMS`（驱动的 `UpdateOptions` 不支持
+/// 服务端超时），由服务端终止超时的更新
+pub(crate) async fn update_many(
+    adapter: &MongoAdapter,
+    db: &MongoConnection,
+    table: &str,
+    query: Document,
+    update: Document,
+) -> QuickDbResult<u64> {
+    let update_failed = |e: mongodb::error::Error| {
+        mongo_unique_violation(&e, table).unwrap_or_else(|| QuickDbError::QueryError {
+            message: crate::i18n::tf("adapter.mongo.update_failed", &[("error", &e.to_string())]),
+        })
+    };
+
+    let Some(timeout) = crate::pool::statement_timeout() else {
+        let result = get_collection(adapter, db, table)
+            .update_many(query, update, None)
+            .await
+            .map_err(update_failed)?;
+        return Ok(result.modified_count);
+    };
+
+    let (database, collection) = resolve_namespace(db, table);
+    let command = doc! {
+        "update": collection,
+        "updates": [{ "q": query, "u": update, "multi": true }],
+        "maxTimeMS": timeout.as_millis().clamp(1, i64::MAX as u128) as i64,
+    };
+    let reply = database.run_command(command, None).await.map_err(update_failed)?;
+    if let Some(Bson::Document(error)) = reply.get_array("writeErrors").ok().and_then(|errors| errors.first()) {
+        let code = error.get_i32("code").unwrap_or_default();
+        let message = error.get_str("errmsg").unwrap_or_default();
+        return Err(if code == 11000 {
+            duplicate_key_violation(message, table)
+        } else {
+            QuickDbError::QueryError {
+                message: crate::i18n::tf("adapter.mongo.update_failed", &[("error", message)]),
+            }
+        });
+    }
+    Ok(match reply.get("nModified") {
+        Some(Bson::Int32(count)) => *count as u64,
+        Some(Bson::Int64(count)) => *count as u64,
+        _ => 0,
+    })
 }
 
 /// 映射数据字段（适配MongoDB字段命名）
 pub(crate) fn map_data_fields(
-    adapter: &MongoAdapter,
+    _adapter: &MongoAdapter,
     data: &HashMap<String, DataValue>,
 ) -> HashMap<String, DataValue> {
     let mut mapped_data = HashMap::new();
@@ -349,3 +609,109 @@ pub(crate) fn map_data_fields(
 
     mapped_data
 }
+
+/// 为序列字段分配下一个值
+///
+/// 首次使用时以集合中该字段的当前最大值为起点初始化生成器，之后在内存中递增
+pub(crate) async fn next_sequence_value(
+    collection: &Collection<Document>,
+    alias: &str,
+    field: &str,
+    sequence_name: &str,
+) -> QuickDbResult<i64> {
+    let manager = crate::manager::get_global_pool_manager();
+    let generator = match manager.get_mongo_sequence_generator(alias, sequence_name) {
+        Some(generator) => generator,
+        None => {
+            let options = mongodb::options::FindOneOptions::builder()
+                .sort(doc! { field: -1 })
+                .projection(doc! { field: 1 })
+                .build();
+            let current_max = collection
+                .find_one(doc! { field: { "$type": "number" } }, options)
+                .await
+                .map_err(|e| QuickDbError::QueryError {
+                    message: format!("读取序列字段 '{}' 的最大值失败: {}", field, e),
+                })?
+                .and_then(|document| match document.get(field) {
+                    Some(Bson::Int32(v)) => Some(*v as i64),
+                    Some(Bson::Int64(v)) => Some(*v),
+                    Some(Bson::Double(v)) => Some(*v as i64),
+                    _ => None,
+                })
+                .unwrap_or(0);
+            let start = current_max.max(0) as u64 + 1;
+            manager.init_mongo_sequence_generator(alias, sequence_name, start)
+        }
+    };
+
+    let value = generator.next_id().await.map_err(|e| QuickDbError::QueryError {
+        message: format!("分配序列 '{}' 的值失败: {}", sequence_name, e),
+    })?;
+    debug!("序列 {} 分配值: {}", sequence_name, value);
+    Ok(value)
+}
+
+/// 将 MongoDB 的 E11000 重复键错误转换为唯一约束冲突错误
+pub(crate) fn mongo_unique_violation(error: &mongodb::error::Error, table: &str) -> Option<QuickDbError> {
+    use mongodb::error::{ErrorKind, WriteFailure};
+
+    let message = match error.kind.as_ref() {
+        ErrorKind::Write(WriteFailure::WriteError(write_error)) if write_error.code == 11000 => {
+            write_error.message.as_str()
+        }
+        ErrorKind::Command(command_error) if command_error.code == 11000 => {
+            command_error.message.as_str()
+        }
+        _ => return None,
+    };
+
+    Some(duplicate_key_violation(message, table))
+}
+
+/// 重复键错误消息转换为唯一约束错误
+fn duplicate_key_violation(message: &str, table: &str) -> QuickDbError {
+    let fields = message
+        .split_once("index: ")
+        .and_then(|(_, rest)| rest.split_whitespace().next())
+        .and_then(|index_name| crate::adapter::utils::resolve_index_fields(table, index_name))
+        .unwrap_or_else(|| mongo_duplicate_key_fields(message));
+
+    QuickDbError::UniqueViolation {
+        table: table.to_string(),
+        fields,
+    }
+}
+
+/// 解析重复键错误中的字段名：`dup key: { tenant_id: 1, email: "a@b.c" }`
+fn mongo_duplicate_key_fields(message: &str) -> Vec<String> {
+    let Some((_, keys)) = message.split_once("dup key: {") else {
+        return Vec::new();
+    };
+    let keys = keys.rsplit_once('}').map_or(keys, |(keys, _)| keys);
+    keys.split(", ")
+        .filter_map(|pair| pair.split_once(':'))
+        .map(|(key, _)| key.trim().trim_matches('"').to_string())
+        .filter(|key| {
+            !key.is_empty()
+                && key
+                    .chars()
+                    .all(|c| c.is_alphanumeric() || c == '_' || c == '.')
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_mongo_duplicate_key_fields() {
+        let message = "E11000 duplicate key error collection: test.users index: tenant_email dup key: { tenant_id: 1, email: \"a@b.c\" }";
+        assert_eq!(
+            mongo_duplicate_key_fields(message),
+            vec!["tenant_id".to_string(), "email".to_string()]
+        );
+        assert!(mongo_duplicate_key_fields("E11000 duplicate key error").is_empty());
+    }
+}
@@ -42,7 +42,7 @@ pub fn row_to_data_map_with_metadata(
                     match value {
                         Some(dt) => {
                                                         // MySQL存储的是UTC时间，直接返回UTC时间
-                            DataValue::DateTime(dt.with_timezone(&chrono::FixedOffset::east(0)))
+                            DataValue::DateTime(dt.with_timezone(&chrono::FixedOffset::east_opt(0).unwrap()))
                         },
                         None => DataValue::Null,
                     }
@@ -182,6 +182,6 @@ fn apply_timezone_offset_to_utc(
         });
     }
 
-    Ok(utc_dt.with_timezone(&chrono::FixedOffset::east(offset_seconds)))
+    Ok(utc_dt.with_timezone(&chrono::FixedOffset::east_opt(offset_seconds).unwrap()))
 }
 
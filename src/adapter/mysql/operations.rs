@@ -4,11 +4,12 @@
 use crate::adapter::DatabaseAdapter;
 use crate::adapter::MysqlAdapter;
 use crate::adapter::mysql::query_builder::SqlQueryBuilder;
+use crate::adapter::utils::{apply_field_defaults, get_sequence_fields, sqlx_unique_violation};
 use crate::error::{QuickDbError, QuickDbResult};
 use crate::manager;
-use crate::model::{FieldDefinition, FieldType};
+use crate::model::{FieldDefinition, IndexOptions};
 use crate::pool::DatabaseConnection;
-use crate::security::quote_identifier;
+use crate::security::{quote_identifier, quote_table_identifier};
 use crate::types::*;
 use async_trait::async_trait;
 use rat_logger::debug;
@@ -66,8 +67,18 @@ impl DatabaseAdapter for MysqlAdapter {
                 // 锁会在这里自动释放（当 _lock 超出作用域时）
             }
 
+            // 序列字段未提供值时从序列表分配
+            let mut insert_data = data.clone();
+            apply_field_defaults(table, alias, DatabaseType::MySQL, &mut insert_data)?;
+            for (field, sequence_name) in get_sequence_fields(table, alias) {
+                if matches!(insert_data.get(&field), None | Some(DataValue::Null)) {
+                    let value = mysql_schema::next_sequence_value(pool, &sequence_name).await?;
+                    insert_data.insert(field, DataValue::Int(value));
+                }
+            }
+
             let (sql, params) = SqlQueryBuilder::new()
-                .insert(data.clone())
+                .insert(insert_data)
                 .build(table, alias)?;
 
             debug!("生成的INSERT SQL: {}", sql);
@@ -106,6 +117,8 @@ impl DatabaseAdapter for MysqlAdapter {
                                 arr.iter().map(|v| v.to_json_value()).collect();
                             query.bind(serde_json::to_string(&json_values).unwrap_or_default())
                         }
+                        // 向量以JSON数组存储
+                        DataValue::Vector(vector) => query.bind(serde_json::to_string(vector).unwrap_or_default()),
                         DataValue::Object(obj) => {
                             let json_map: serde_json::Map<String, serde_json::Value> = obj
                                 .iter()
@@ -125,9 +138,11 @@ impl DatabaseAdapter for MysqlAdapter {
                     }
                     Err(e) => {
                         debug!("❌ SQL执行失败: {}", e);
-                        return Err(QuickDbError::QueryError {
-                            message: format!("执行插入失败: {}", e),
-                        });
+                        return Err(sqlx_unique_violation(&e, table).unwrap_or_else(|| {
+                            QuickDbError::QueryError {
+                                message: format!("执行插入失败: {}", e),
+                            }
+                        }));
                     }
                 }
             };
@@ -289,6 +304,8 @@ impl DatabaseAdapter for MysqlAdapter {
                                 arr.iter().map(|v| v.to_json_value()).collect();
                             query.bind(serde_json::to_string(&json_values).unwrap_or_default())
                         }
+                        // 向量以JSON数组存储
+                        DataValue::Vector(vector) => query.bind(serde_json::to_string(vector).unwrap_or_default()),
                         DataValue::Object(obj) => {
                             let json_map: serde_json::Map<String, serde_json::Value> = obj
                                 .iter()
@@ -404,7 +421,7 @@ impl DatabaseAdapter for MysqlAdapter {
                 .limit(1)
                 .build(table, alias)?;
 
-            let results = self.execute_query(pool, &sql, &params, table).await?;
+            let results = self.execute_query(pool, &sql, &params, table, Some(alias)).await?;
             Ok(results.into_iter().next())
         } else {
             Err(QuickDbError::ConnectionError {
@@ -413,6 +430,37 @@ impl DatabaseAdapter for MysqlAdapter {
         }
     }
 
+    async fn exists_by_id(
+        &self,
+        connection: &DatabaseConnection,
+        table: &str,
+        id: &DataValue,
+        alias: &str,
+    ) -> QuickDbResult<bool> {
+        if let DatabaseConnection::MySQL(pool) = connection {
+            let condition = QueryConditionWithConfig {
+                field: "id".to_string(),
+                operator: QueryOperator::Eq,
+                value: id.clone(),
+                case_insensitive: false,
+            };
+
+            // 只读取主键列，不反序列化整行
+            let (sql, params) = SqlQueryBuilder::new()
+                .select(&["id"])
+                .where_condition(condition)
+                .limit(1)
+                .build(table, alias)?;
+
+            let results = self.execute_query(pool, &sql, &params, table, Some(alias)).await?;
+            Ok(!results.is_empty())
+        } else {
+            Err(QuickDbError::ConnectionError {
+                message: "连接类型不匹配，期望MySQL连接".to_string(),
+            })
+        }
+    }
+
     async fn find_with_cache_control(
         &self,
         connection: &DatabaseConnection,
@@ -474,10 +522,24 @@ impl DatabaseAdapter for MysqlAdapter {
         let condition_groups_with_config: Vec<QueryConditionGroupWithConfig> =
             condition_groups.iter().map(convert_group).collect();
 
+        self.find_with_groups_with_cache_control_and_config(connection, table, &condition_groups_with_config, options, alias, bypass_cache).await
+    }
+
+    /// MySQL条件组合查找操作（带WithConfig支持）
+    async fn find_with_groups_with_cache_control_and_config(
+        &self,
+        connection: &DatabaseConnection,
+        table: &str,
+        condition_groups: &[QueryConditionGroupWithConfig],
+        options: &QueryOptions,
+        alias: &str,
+        _bypass_cache: bool,
+    ) -> QuickDbResult<Vec<DataValue>> {
         if let DatabaseConnection::MySQL(pool) = connection {
+            let fields = crate::adapter::utils::projection_fields(options);
             let mut builder = SqlQueryBuilder::new()
-                .select(&["*"])
-                .where_condition_groups(&condition_groups_with_config);
+                .select(&fields)
+                .where_condition_groups(condition_groups);
 
             // 添加排序
             for sort_field in &options.sort {
@@ -489,11 +551,25 @@ impl DatabaseAdapter for MysqlAdapter {
                 builder = builder.limit(pagination.limit).offset(pagination.skip);
             }
 
-            let (sql, params) = builder.build(table, alias)?;
+            // 添加行级锁
+            if let Some(lock) = options.lock {
+                builder = builder.lock(lock);
+            }
+
+            let (mut sql, params) = builder.build(table, alias)?;
+
+            // 查询超时下推为 MAX_EXECUTION_TIME 优化器提示（MariaDB 会将其视为普通注释）
+            if let Some(timeout) = options.timeout {
+                sql = sql.replacen(
+                    "SELECT ",
+                    &format!("SELECT /*+ MAX_EXECUTION_TIME({}) */ ", timeout.as_millis().max(1)),
+                    1,
+                );
+            }
 
             debug!("执行MySQL条件组合查询: {}", sql);
 
-            self.execute_query(pool, &sql, &params, table).await
+            self.execute_query(pool, &sql, &params, table, Some(alias)).await
         } else {
             Err(QuickDbError::ConnectionError {
                 message: "连接类型不匹配，期望MySQL连接".to_string(),
@@ -501,42 +577,6 @@ impl DatabaseAdapter for MysqlAdapter {
         }
     }
 
-    /// MySQL条件组合查找操作（带WithConfig支持）
-    async fn find_with_groups_with_cache_control_and_config(
-        &self,
-        connection: &DatabaseConnection,
-        table: &str,
-        condition_groups: &[QueryConditionGroupWithConfig],
-        options: &QueryOptions,
-        alias: &str,
-        bypass_cache: bool,
-    ) -> QuickDbResult<Vec<DataValue>> {
-        // 将 QueryConditionGroupWithConfig 转换为 QueryConditionGroup
-        // 暂时忽略 case_insensitive 配置（后续可以在 query_builder 中支持）
-        fn convert_group(group: &QueryConditionGroupWithConfig) -> QueryConditionGroup {
-            match group {
-                QueryConditionGroupWithConfig::Single(c) => {
-                    QueryConditionGroup::Single(QueryCondition {
-                        field: c.field.clone(),
-                        operator: c.operator.clone(),
-                        value: c.value.clone(),
-                    })
-                }
-                QueryConditionGroupWithConfig::GroupWithConfig { operator, conditions } => {
-                    QueryConditionGroup::Group {
-                        operator: operator.clone(),
-                        conditions: conditions.iter().map(convert_group).collect(),
-                    }
-                }
-            }
-        }
-
-        let simple_groups: Vec<QueryConditionGroup> =
-            condition_groups.iter().map(convert_group).collect();
-
-        self.find_with_groups_with_cache_control(connection, table, &simple_groups, options, alias, bypass_cache).await
-    }
-
     async fn find(
         &self,
         connection: &DatabaseConnection,
@@ -598,12 +638,12 @@ impl DatabaseAdapter for MysqlAdapter {
                             DataValue::String(s) => chrono::DateTime::parse_from_rfc3339(s)
                                 .map(|dt| {
                                     DataValue::DateTime(
-                                        dt.with_timezone(&chrono::FixedOffset::east(0)),
+                                        dt.with_timezone(&chrono::FixedOffset::east_opt(0).unwrap()),
                                     )
                                 })
                                 .unwrap_or(data_value.clone()),
                             DataValue::DateTimeUTC(dt) => {
-                                DataValue::DateTime(dt.with_timezone(&chrono::FixedOffset::east(0)))
+                                DataValue::DateTime(dt.with_timezone(&chrono::FixedOffset::east_opt(0).unwrap()))
                             }
                             _ => data_value.clone(),
                         };
@@ -713,6 +753,9 @@ impl DatabaseAdapter for MysqlAdapter {
                         ));
                         params.push(operation.value.clone());
                     }
+                    crate::types::UpdateOperator::Unset | crate::types::UpdateOperator::Rename => {
+                        set_clauses.extend(crate::adapter::utils::structural_set_clauses(DatabaseType::MySQL, operation)?);
+                    }
                 }
             }
 
@@ -723,12 +766,12 @@ impl DatabaseAdapter for MysqlAdapter {
                 });
             }
 
-            let safe_table = quote_identifier(table, DatabaseType::MySQL);
+            let safe_table = quote_table_identifier(table, DatabaseType::MySQL);
             let mut sql = format!("UPDATE {} SET {}", safe_table, set_clauses.join(", "));
 
             // 添加WHERE条件
             if !conditions.is_empty() {
-                let (where_clause, mut where_params) = SqlQueryBuilder::new()
+                let (where_clause, where_params) = SqlQueryBuilder::new()
                     .build_where_clause_with_offset(conditions, params.len() + 1, table, alias)?;
 
                 sql.push_str(&format!(" WHERE {}", where_clause));
@@ -765,6 +808,23 @@ impl DatabaseAdapter for MysqlAdapter {
         mysql_query::delete_by_id(self, connection, table, id, alias).await
     }
 
+    async fn bulk_write(
+        &self,
+        connection: &DatabaseConnection,
+        table: &str,
+        ops: &[BulkOp],
+        mode: BulkWriteMode,
+        _id_strategy: &IdStrategy,
+        alias: &str,
+    ) -> QuickDbResult<BulkWriteReport> {
+        let DatabaseConnection::MySQL(pool) = connection else {
+            return Err(QuickDbError::ConnectionError {
+                message: "连接类型不匹配，期望MySQL连接".to_string(),
+            });
+        };
+        crate::adapter::transaction::execute_mysql_bulk(pool, table, alias, ops, mode).await
+    }
+
     async fn count(
         &self,
         connection: &DatabaseConnection,
@@ -803,8 +863,18 @@ impl DatabaseAdapter for MysqlAdapter {
         index_name: &str,
         fields: &[String],
         unique: bool,
+        options: &IndexOptions,
     ) -> QuickDbResult<()> {
-        mysql_schema::create_index(self, connection, table, index_name, fields, unique).await
+        mysql_schema::create_index(
+            self,
+            connection,
+            table,
+            index_name,
+            fields,
+            unique,
+            options,
+        )
+        .await
     }
 
     async fn table_exists(
@@ -815,6 +885,14 @@ impl DatabaseAdapter for MysqlAdapter {
         mysql_schema::table_exists(self, connection, table).await
     }
 
+    async fn count_estimate(
+        &self,
+        connection: &DatabaseConnection,
+        table: &str,
+    ) -> QuickDbResult<CountEstimate> {
+        mysql_schema::count_estimate(self, connection, table).await
+    }
+
     async fn drop_table(&self, connection: &DatabaseConnection, table: &str) -> QuickDbResult<()> {
         mysql_schema::drop_table(self, connection, table).await
     }
@@ -862,7 +940,7 @@ impl DatabaseAdapter for MysqlAdapter {
         }
 
         // 2. 生成MySQL存储过程模板（带占位符）
-        let sql_template = self.generate_stored_procedure_sql(&config).await?;
+        let sql_template = self.generate_stored_procedure_sql(config).await?;
         debug!("生成MySQL存储过程SQL模板: {}", sql_template);
 
         // 3. 将存储过程信息存储到适配器映射表中（MySQL不需要执行创建SQL）
@@ -887,16 +965,25 @@ impl DatabaseAdapter for MysqlAdapter {
         })
     }
 
+    /// 删除存储过程（从适配器映射表中移除模板）
+    async fn drop_stored_procedure(
+        &self,
+        _connection: &DatabaseConnection,
+        procedure_name: &str,
+    ) -> QuickDbResult<bool> {
+        let removed = self.stored_procedures.lock().await.remove(procedure_name).is_some();
+        debug!("删除MySQL存储过程: {}, 存在={}", procedure_name, removed);
+        Ok(removed)
+    }
+
     /// 执行存储过程查询（MySQL使用视图实现）
     async fn execute_stored_procedure(
         &self,
         connection: &DatabaseConnection,
         procedure_name: &str,
-        database: &str,
+        _database: &str,
         params: Option<std::collections::HashMap<String, crate::types::DataValue>>,
     ) -> QuickDbResult<crate::stored_procedure::StoredProcedureQueryResult> {
-        use crate::adapter::mysql::adapter::MysqlAdapter;
-
         // 获取存储过程信息
         let procedures = self.stored_procedures.lock().await;
         let procedure_info = procedures.get(procedure_name).ok_or_else(|| {
@@ -924,7 +1011,7 @@ impl DatabaseAdapter for MysqlAdapter {
             DatabaseConnection::MySQL(pool) => pool,
             _ => {
                 return Err(QuickDbError::ConnectionError {
-                    message: "Invalid connection type for MySQL".to_string(),
+                    message: "连接类型不匹配，期望MySQL连接".to_string(),
                 });
             }
         };
@@ -1054,7 +1141,6 @@ impl MysqlAdapter {
         final_sql = final_sql
             .replace("  ", " ")
             .replace(" ,", ",")
-            .replace(", ", ", ")
             .replace(" WHERE ", "")
             // MySQL特殊处理：不清理GROUP BY子句，因为它是自动生成的
             .replace(" HAVING ", "")
@@ -1,7 +1,6 @@
 
 //! MySQL查询相关操作
 
-use crate::adapter::DatabaseAdapter;
 use crate::adapter::MysqlAdapter;
 use crate::adapter::mysql::query_builder::SqlQueryBuilder;
 use crate::error::{QuickDbError, QuickDbResult};
@@ -73,13 +72,11 @@ pub(crate) async fn count(
             .where_conditions(conditions)
             .build(table, alias)?;
 
-        let results = adapter.execute_query(pool, &sql, &params, table).await?;
+        let results = adapter.execute_query(pool, &sql, &params, table, Some(alias)).await?;
 
-        if let Some(result) = results.first() {
-            if let DataValue::Object(map) = result {
-                if let Some(DataValue::Int(count)) = map.get("count") {
-                    return Ok(*count as u64);
-                }
+        if let Some(DataValue::Object(map)) = results.first() {
+            if let Some(DataValue::Int(count)) = map.get("count") {
+                return Ok(*count as u64);
             }
         }
 
@@ -107,13 +104,11 @@ pub(crate) async fn count_with_groups(
 
         debug!("执行MySQL条件组合计数: {}", sql);
 
-        let results = adapter.execute_query(pool, &sql, &params, table).await?;
+        let results = adapter.execute_query(pool, &sql, &params, table, Some(alias)).await?;
 
-        if let Some(result) = results.first() {
-            if let DataValue::Object(map) = result {
-                if let Some(DataValue::Int(count)) = map.get("count") {
-                    return Ok(*count as u64);
-                }
+        if let Some(DataValue::Object(map)) = results.first() {
+            if let Some(DataValue::Int(count)) = map.get("count") {
+                return Ok(*count as u64);
             }
         }
 
@@ -2,8 +2,10 @@
 //!
 //! 提供安全的SQL查询构建功能，防止SQL注入攻击
 
+use crate::adapter::utils::case_insensitive_clause;
 use crate::error::{QuickDbError, QuickDbResult};
 use crate::security::DatabaseSecurityValidator;
+use crate::types::query::{between_bounds, regex_pattern};
 use crate::types::*;
 use rat_logger::debug;
 use std::collections::HashMap;
@@ -20,6 +22,7 @@ pub struct SqlQueryBuilder {
     having: Vec<QueryConditionWithConfig>,
     limit: Option<u64>,
     offset: Option<u64>,
+    lock: Option<RowLock>,
     values: HashMap<String, DataValue>,
     returning_fields: Vec<String>,
     /// Upsert冲突检测列
@@ -44,6 +47,7 @@ pub struct JoinClause {
 }
 
 #[derive(Debug, Clone)]
+#[allow(dead_code)]
 pub enum JoinType {
     Inner,
     Left,
@@ -71,6 +75,7 @@ impl SqlQueryBuilder {
             having: Vec::new(),
             limit: None,
             offset: None,
+            lock: None,
             values: HashMap::new(),
             returning_fields: Vec::new(),
             conflict_columns: Vec::new(),
@@ -118,8 +123,6 @@ impl SqlQueryBuilder {
         self
     }
 
-    /// 设置表名
-
     /// 添加WHERE条件
     pub fn where_condition(mut self, condition: QueryConditionWithConfig) -> Self {
         self.conditions.push(condition);
@@ -142,6 +145,7 @@ impl SqlQueryBuilder {
     }
 
     /// 添加JOIN子句
+    #[allow(dead_code)]
     pub fn join(mut self, join_type: JoinType, table: &str, on_condition: &str) -> Self {
         self.joins.push(JoinClause {
             join_type,
@@ -161,12 +165,14 @@ impl SqlQueryBuilder {
     }
 
     /// 添加GROUP BY子句
+    #[allow(dead_code)]
     pub fn group_by(mut self, fields: &[&str]) -> Self {
         self.group_by = fields.iter().map(|s| s.to_string()).collect();
         self
     }
 
     /// 添加HAVING条件
+    #[allow(dead_code)]
     pub fn having(mut self, condition: QueryConditionWithConfig) -> Self {
         self.having.push(condition);
         self
@@ -184,7 +190,14 @@ impl SqlQueryBuilder {
         self
     }
 
+    /// 设置行级锁（SELECT ... FOR UPDATE）
+    pub fn lock(mut self, lock: RowLock) -> Self {
+        self.lock = Some(lock);
+        self
+    }
+
     /// 设置RETURNING子句（用于INSERT/UPDATE/DELETE）
+    #[allow(dead_code)]
     pub fn returning(mut self, fields: &[&str]) -> Self {
         self.returning_fields = fields.iter().map(|s| s.to_string()).collect();
         self
@@ -192,15 +205,13 @@ impl SqlQueryBuilder {
 
     /// 构建SQL查询语句
     pub fn build(self, table: &str, alias: &str) -> QuickDbResult<(String, Vec<DataValue>)> {
-        let result = match self.query_type {
+        match self.query_type {
             QueryType::Select => self.build_select(table, alias),
             QueryType::Insert => self.build_insert(table, alias),
             QueryType::Update => self.build_update(table, alias),
             QueryType::Delete => self.build_delete(table, alias),
             QueryType::Upsert => self.build_upsert(table, alias),
-        };
-
-        result
+        }
     }
 
     /// 构建SELECT语句
@@ -307,11 +318,18 @@ impl SqlQueryBuilder {
             sql.push_str(&format!(" OFFSET {}", offset));
         }
 
+        // 添加行级锁
+        match self.lock {
+            Some(RowLock::ForUpdate) => sql.push_str(" FOR UPDATE"),
+            Some(RowLock::ForShare) => sql.push_str(" LOCK IN SHARE MODE"),
+            None => {}
+        }
+
         Ok((sql, params))
     }
 
     /// 构建INSERT语句
-    fn build_insert(&self, table: &str, alias: &str) -> QuickDbResult<(String, Vec<DataValue>)> {
+    fn build_insert(&self, table: &str, _alias: &str) -> QuickDbResult<(String, Vec<DataValue>)> {
         if table.is_empty() {
             return Err(QuickDbError::QueryError {
                 message: "表名不能为空".to_string(),
@@ -424,7 +442,7 @@ impl SqlQueryBuilder {
     }
 
     /// 构建UPSERT语句（INSERT ... ON DUPLICATE KEY UPDATE ...）
-    fn build_upsert(&self, table: &str, alias: &str) -> QuickDbResult<(String, Vec<DataValue>)> {
+    fn build_upsert(&self, table: &str, _alias: &str) -> QuickDbResult<(String, Vec<DataValue>)> {
         if table.is_empty() {
             return Err(QuickDbError::QueryError {
                 message: "表名不能为空".to_string(),
@@ -600,7 +618,7 @@ impl SqlQueryBuilder {
     ) -> QuickDbResult<(String, Vec<DataValue>, usize)> {
         match group {
             QueryConditionGroupWithConfig::Single(condition) => {
-                let (clause, mut params, new_index) =
+                let (clause, params, new_index) =
                     self.build_single_condition_clause(condition, start_index, table, alias)?;
                 Ok((clause, params, new_index))
             }
@@ -678,13 +696,20 @@ impl SqlQueryBuilder {
             condition.value.clone()
         };
 
+        if let Some((clause, params)) = case_insensitive_clause(condition, &safe_field, table, alias, |offset| {
+            self.get_placeholder(param_index + offset)
+        }) {
+            let next_index = param_index + params.len();
+            return Ok((clause, params, next_index));
+        }
+
         let (clause, params) = match condition.operator {
             QueryOperator::Eq => {
                 new_index += 1;
                 // 处理大小写不敏感的等于操作
                 if condition.case_insensitive {
                     match &query_value {
-                        DataValue::String(s) => {
+                        DataValue::String(_s) => {
                             // 使用LOWER函数实现大小写不敏感比较
                             (
                                 format!("LOWER({}) = LOWER({})", safe_field, placeholder),
@@ -745,7 +770,7 @@ impl SqlQueryBuilder {
                 new_index += 1;
 
                 // 检查字段类型
-                if let Some(field_type) = self.get_field_type(&table, &condition.field) {
+                if let Some(field_type) = self.get_field_type(table, &condition.field) {
                     match field_type {
                         crate::model::FieldType::String { .. } => {
                             // 字符串字段使用 LIKE 查询
@@ -766,7 +791,7 @@ impl SqlQueryBuilder {
                                 format!(
                                     "JSON_CONTAINS({}, '{}', '$')",
                                     safe_field,
-                                    condition.value.to_json_value().to_string()
+                                    condition.value.to_json_value()
                                 ),
                                 vec![],
                             )
@@ -989,9 +1014,24 @@ impl SqlQueryBuilder {
             }
             QueryOperator::Regex => {
                 new_index += 1;
+                let pattern = regex_pattern(&condition.field, &condition.value)?;
                 (
-                    format!("{} REGEXP {}", safe_field, placeholder),
-                    vec![condition.value.clone()],
+                    format!("REGEXP_LIKE({}, {}, '{}')", safe_field, placeholder, mysql_regex_mode(condition)),
+                    vec![DataValue::String(pattern.to_string())],
+                )
+            }
+            QueryOperator::Between | QueryOperator::NotBetween => {
+                let (lower, upper) = between_bounds(&condition.field, &query_value)?;
+                let keyword = if condition.operator == QueryOperator::Between {
+                    "BETWEEN"
+                } else {
+                    "NOT BETWEEN"
+                };
+                let upper_placeholder = self.get_placeholder(new_index + 1);
+                new_index += 2;
+                (
+                    format!("{} {} {} AND {}", safe_field, keyword, placeholder, upper_placeholder),
+                    vec![lower.clone(), upper.clone()],
                 )
             }
             QueryOperator::Exists => (format!("{} IS NOT NULL", safe_field), vec![]),
@@ -1033,6 +1073,15 @@ impl SqlQueryBuilder {
                 .security_validator
                 .get_safe_field_identifier(&condition.field)?;
 
+            if let Some((clause, values)) = case_insensitive_clause(condition, &safe_field, table, alias, |offset| {
+                self.get_placeholder(param_index + offset)
+            }) {
+                clauses.push(clause);
+                param_index += values.len();
+                params.extend(values);
+                continue;
+            }
+
             match condition.operator {
                 QueryOperator::Eq => {
                     // 处理大小写不敏感的等于操作
@@ -1087,7 +1136,7 @@ impl SqlQueryBuilder {
                 }
                 QueryOperator::Contains => {
                     // 检查字段类型
-                    if let Some(field_type) = self.get_field_type(&table, &condition.field) {
+                    if let Some(field_type) = self.get_field_type(table, &condition.field) {
                         match field_type {
                             crate::model::FieldType::String { .. } => {
                                 // 字符串字段使用 LIKE 查询
@@ -1113,7 +1162,7 @@ impl SqlQueryBuilder {
                                     "JSON_CONTAINS({}, '{}', '$')",
                                     self.security_validator
                                         .get_safe_field_identifier(&condition.field)?,
-                                    condition.value.to_json_value().to_string()
+                                    condition.value.to_json_value()
                                 ));
                             }
                             _ => {
@@ -1331,11 +1380,33 @@ impl SqlQueryBuilder {
                     });
                 }
                 QueryOperator::Regex => {
-                    // 不同数据库的正则表达式语法不同，这里使用通用的LIKE
-                    clauses.push(format!("{} REGEXP {}", safe_field, placeholder));
-                    params.push(condition.value.clone());
+                    // 显式指定匹配模式，避免大小写敏感性随列排序规则变化
+                    let pattern = regex_pattern(&condition.field, &condition.value)?;
+                    clauses.push(format!(
+                        "REGEXP_LIKE({}, {}, '{}')",
+                        safe_field,
+                        placeholder,
+                        mysql_regex_mode(condition)
+                    ));
+                    params.push(DataValue::String(pattern.to_string()));
                     param_index += 1;
                 }
+                QueryOperator::Between | QueryOperator::NotBetween => {
+                    let (lower, upper) = between_bounds(&condition.field, &condition.value)?;
+                    let keyword = if condition.operator == QueryOperator::Between {
+                        "BETWEEN"
+                    } else {
+                        "NOT BETWEEN"
+                    };
+                    let upper_placeholder = self.get_placeholder(param_index + 1);
+                    clauses.push(format!(
+                        "{} {} {} AND {}",
+                        safe_field, keyword, placeholder, upper_placeholder
+                    ));
+                    params.push(lower.clone());
+                    params.push(upper.clone());
+                    param_index += 2;
+                }
                 QueryOperator::Exists => {
                     // 检查字段是否存在（主要用于NoSQL数据库）
                     clauses.push(format!("{} IS NOT NULL", safe_field));
@@ -1371,7 +1442,7 @@ impl SqlQueryBuilder {
     }
 
     /// 获取单个占位符
-    fn get_placeholder(&self, index: usize) -> String {
+    fn get_placeholder(&self, _index: usize) -> String {
         // MySQL使用?占位符
         "?".to_string()
     }
@@ -1393,7 +1464,7 @@ impl SqlQueryBuilder {
         field_name: &str,
     ) -> Option<crate::model::FieldType> {
         // 通过全局管理器获取模型元数据
-        if let Some(model_meta) = crate::manager::get_model(table_name) {
+        if let Some(model_meta) = crate::manager::get_global_pool_manager().get_model(table_name) {
             model_meta
                 .fields
                 .get(field_name)
@@ -1404,8 +1475,99 @@ impl SqlQueryBuilder {
     }
 }
 
+/// REGEXP_LIKE 的匹配模式：`c` 大小写敏感，`i` 大小写不敏感
+fn mysql_regex_mode(condition: &QueryConditionWithConfig) -> &'static str {
+    if condition.case_insensitive { "i" } else { "c" }
+}
+
 impl Default for SqlQueryBuilder {
     fn default() -> Self {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ci(field: &str, operator: QueryOperator, value: DataValue) -> QueryConditionWithConfig {
+        QueryConditionWithConfig {
+            field: field.to_string(),
+            operator,
+            value,
+            case_insensitive: true,
+        }
+    }
+
+    #[test]
+    fn case_insensitive_eq_ne_in_use_lower() {
+        let names = DataValue::Array(vec![
+            DataValue::String("Alice".to_string()),
+            DataValue::String("BOB".to_string()),
+        ]);
+        let conditions = vec![
+            ci("name", QueryOperator::Eq, DataValue::String("Alice".to_string())),
+            ci("email", QueryOperator::Ne, DataValue::String("A@B.COM".to_string())),
+            ci("role", QueryOperator::In, names),
+            ci("age", QueryOperator::Eq, DataValue::Int(30)),
+        ];
+
+        let (sql, params) = SqlQueryBuilder::new()
+            .select(&["*"])
+            .where_conditions(&conditions)
+            .build("ci_users", "default")
+            .unwrap();
+        assert!(sql.contains("LOWER(`name`) = LOWER(?)"), "{}", sql);
+        assert!(sql.contains("LOWER(`email`) != LOWER(?)"), "{}", sql);
+        assert!(sql.contains("LOWER(`role`) IN (LOWER(?), LOWER(?))"), "{}", sql);
+        assert!(sql.contains("`age` = ?"), "{}", sql);
+        assert_eq!(params.len(), 5);
+
+        // 条件组路径与简单条件路径生成一致的比较
+        let groups = vec![QueryConditionGroupWithConfig::GroupWithConfig {
+            operator: LogicalOperator::Or,
+            conditions: conditions.into_iter().map(QueryConditionGroupWithConfig::Single).collect(),
+        }];
+        let (sql, params) = SqlQueryBuilder::new()
+            .select(&["*"])
+            .where_condition_groups(&groups)
+            .build("ci_users", "default")
+            .unwrap();
+        assert!(sql.contains("LOWER(`role`) IN (LOWER(?), LOWER(?))"), "{}", sql);
+        assert!(sql.contains("`age` = ?"), "{}", sql);
+        assert_eq!(params.len(), 5);
+    }
+
+    #[test]
+    fn regex_uses_regexp_like_with_explicit_mode() {
+        crate::i18n::ErrorMessageI18n::init_i18n();
+        let mut condition = ci("name", QueryOperator::Regex, DataValue::String("^ali".to_string()));
+        let build = |condition: &QueryConditionWithConfig| {
+            SqlQueryBuilder::new()
+                .select(&["*"])
+                .where_conditions(std::slice::from_ref(condition))
+                .build("ci_users", "default")
+        };
+        assert!(build(&condition).unwrap().0.ends_with("WHERE REGEXP_LIKE(`name`, ?, 'i')"));
+        condition.case_insensitive = false;
+        assert!(build(&condition).unwrap().0.ends_with("WHERE REGEXP_LIKE(`name`, ?, 'c')"));
+        condition.value = DataValue::Int(1);
+        assert!(build(&condition).is_err());
+    }
+
+    #[test]
+    fn row_lock_is_appended_after_pagination() {
+        let build = |lock: RowLock| {
+            SqlQueryBuilder::new()
+                .select(&["*"])
+                .where_conditions(&[ci("id", QueryOperator::Gt, DataValue::Int(1))])
+                .limit(10)
+                .lock(lock)
+                .build("accounts", "default")
+                .unwrap()
+                .0
+        };
+        assert!(build(RowLock::ForUpdate).ends_with("LIMIT 10 FOR UPDATE"));
+        assert!(build(RowLock::ForShare).ends_with("LIMIT 10 LOCK IN SHARE MODE"));
+    }
+}
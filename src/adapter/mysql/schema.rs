@@ -2,10 +2,14 @@
 //! MySQL表和索引管理操作
 
 use crate::adapter::MysqlAdapter;
+use crate::adapter::utils::{
+    SEQUENCE_TABLE, get_custom_primary_key, id_column_constraint, index_key_parts,
+    render_index_predicate, sequence_fields_of,
+};
 use crate::error::{QuickDbError, QuickDbResult};
-use crate::model::{FieldDefinition, FieldType};
+use crate::model::{FieldDefinition, FieldType, IndexOptions};
 use crate::pool::DatabaseConnection;
-use crate::security::quote_identifier;
+use crate::security::{quote_identifier, quote_table_identifier, split_qualified_name};
 use crate::types::*;
 use rat_logger::debug;
 use std::collections::HashMap;
@@ -22,14 +26,18 @@ pub(crate) async fn create_table(
     if let DatabaseConnection::MySQL(pool) = connection {
         let mut field_definitions = Vec::new();
 
+        // 复合/自定义主键时，id列不再是主键，主键约束在表级声明
+        let custom_primary_key = get_custom_primary_key(table, alias);
+        let id_constraint = id_column_constraint(custom_primary_key.as_deref());
+
         // 统一处理id字段，根据ID策略决定类型和属性
         let safe_id = quote_identifier("id", DatabaseType::MySQL);
         let id_definition = match id_strategy {
-            IdStrategy::AutoIncrement => format!("{} BIGINT AUTO_INCREMENT PRIMARY KEY", safe_id),
-            IdStrategy::ObjectId => format!("{} VARCHAR(255) PRIMARY KEY", safe_id), // ObjectId存储为字符串
-            IdStrategy::Uuid => format!("{} VARCHAR(36) PRIMARY KEY", safe_id),
-            IdStrategy::Snowflake { .. } => format!("{} BIGINT PRIMARY KEY", safe_id),
-            IdStrategy::Custom(_) => format!("{} VARCHAR(255) PRIMARY KEY", safe_id), // 自定义ID使用字符串
+            IdStrategy::AutoIncrement => format!("{} BIGINT AUTO_INCREMENT {}", safe_id, id_constraint),
+            IdStrategy::ObjectId => format!("{} VARCHAR(255) {}", safe_id, id_constraint), // ObjectId存储为字符串
+            IdStrategy::Uuid => format!("{} VARCHAR(36) {}", safe_id, id_constraint),
+            IdStrategy::Snowflake { .. } => format!("{} BIGINT {}", safe_id, id_constraint),
+            IdStrategy::Custom(_) => format!("{} VARCHAR(255) {}", safe_id, id_constraint), // 自定义ID使用字符串
         };
         field_definitions.push(id_definition);
 
@@ -68,11 +76,17 @@ pub(crate) async fn create_table(
                 FieldType::Array { .. } => "JSON".to_string(),
                 FieldType::Object { .. } => "JSON".to_string(),
                 FieldType::Reference { .. } => "VARCHAR(255)".to_string(),
+                FieldType::PolymorphicReference { .. } => "JSON".to_string(),
                 FieldType::Vector { .. } => "JSON".to_string(), // MySQL 不支持原生向量，用 JSON 存储
+                FieldType::Sequence { .. } => "BIGINT".to_string(), // 值由序列表分配
             };
 
+            // 主键字段必须为NOT NULL
+            let is_primary_key = custom_primary_key
+                .as_ref()
+                .is_some_and(|pk| pk.contains(name));
             // 添加NULL或NOT NULL约束
-            let null_constraint = if field_definition.required {
+            let null_constraint = if field_definition.required || is_primary_key {
                 "NOT NULL"
             } else {
                 "NULL"
@@ -81,11 +95,44 @@ pub(crate) async fn create_table(
             field_definitions.push(format!("{} {} {}", safe_name, sql_type, null_constraint));
         }
 
-        let safe_table = quote_identifier(table, DatabaseType::MySQL);
+        // 表级复合主键约束
+        if let Some(pk) = &custom_primary_key {
+            let safe_pk: Vec<String> = pk
+                .iter()
+                .map(|f| quote_identifier(f, DatabaseType::MySQL))
+                .collect();
+            field_definitions.push(format!("PRIMARY KEY ({})", safe_pk.join(", ")));
+
+            // AUTO_INCREMENT 列必须是某个索引的第一列
+            if matches!(id_strategy, IdStrategy::AutoIncrement)
+                && pk.first().map(String::as_str) != Some("id")
+                && pk.iter().any(|f| f == "id")
+            {
+                field_definitions.push(format!("KEY ({})", safe_id));
+            }
+        }
+
+        // 存在序列字段时确保序列表存在
+        if !sequence_fields_of(fields).is_empty() {
+            ensure_sequence_table(adapter, pool).await?;
+        }
+
+        // 表沿用连接的字符集与排序规则，避免旧服务器的默认字符集（如 latin1）拒绝 emoji 等 4 字节字符
+        let (charset, collation): (String, String) =
+            sqlx::query_as("SELECT @@character_set_connection, @@collation_connection")
+                .fetch_one(pool)
+                .await
+                .map_err(|e| QuickDbError::QueryError {
+                    message: format!("查询连接字符集失败: {}", e),
+                })?;
+
+        let safe_table = quote_table_identifier(table, DatabaseType::MySQL);
         let sql = format!(
-            "CREATE TABLE IF NOT EXISTS {} ({})",
+            "CREATE TABLE IF NOT EXISTS {} ({}) DEFAULT CHARSET={} COLLATE={}",
             safe_table,
-            field_definitions.join(", ")
+            field_definitions.join(", "),
+            charset,
+            collation
         );
 
         adapter.execute_update(pool, &sql, &[], table).await?;
@@ -98,6 +145,63 @@ pub(crate) async fn create_table(
     }
 }
 
+/// 确保序列表存在
+///
+/// MySQL 没有独立的序列对象，使用一张 (name, value) 表模拟
+pub(crate) async fn ensure_sequence_table(
+    adapter: &MysqlAdapter,
+    pool: &sqlx::MySqlPool,
+) -> QuickDbResult<()> {
+    let safe_table = quote_identifier(SEQUENCE_TABLE, DatabaseType::MySQL);
+    let sql = format!(
+        "CREATE TABLE IF NOT EXISTS {} (`name` VARCHAR(255) PRIMARY KEY, `value` BIGINT NOT NULL)",
+        safe_table
+    );
+    adapter.execute_update(pool, &sql, &[], SEQUENCE_TABLE).await?;
+    Ok(())
+}
+
+/// 获取序列的下一个值
+///
+/// 通过 `LAST_INSERT_ID(expr)` 原子地递增计数并在同一连接上读回，避免并发冲突
+pub(crate) async fn next_sequence_value(
+    pool: &sqlx::MySqlPool,
+    sequence_name: &str,
+) -> QuickDbResult<i64> {
+    use sqlx::Row;
+
+    let mut conn = pool.acquire().await.map_err(|e| QuickDbError::ConnectionError {
+        message: format!("获取MySQL连接失败: {}", e),
+    })?;
+
+    let safe_table = quote_identifier(SEQUENCE_TABLE, DatabaseType::MySQL);
+    let sql = format!(
+        "INSERT INTO {} (`name`, `value`) VALUES (?, LAST_INSERT_ID(1)) \
+         ON DUPLICATE KEY UPDATE `value` = LAST_INSERT_ID(`value` + 1)",
+        safe_table
+    );
+    sqlx::query::<sqlx::MySql>(&sql)
+        .bind(sequence_name)
+        .execute(&mut *conn)
+        .await
+        .map_err(|e| QuickDbError::QueryError {
+            message: format!("递增序列 '{}' 失败: {}", sequence_name, e),
+        })?;
+
+    let row = sqlx::query::<sqlx::MySql>("SELECT LAST_INSERT_ID()")
+        .fetch_one(&mut *conn)
+        .await
+        .map_err(|e| QuickDbError::QueryError {
+            message: format!("读取序列 '{}' 失败: {}", sequence_name, e),
+        })?;
+    let value: u64 = row.try_get(0).map_err(|e| QuickDbError::QueryError {
+        message: format!("解析序列 '{}' 的值失败: {}", sequence_name, e),
+    })?;
+
+    debug!("序列 {} 分配值: {}", sequence_name, value);
+    Ok(value as i64)
+}
+
 /// MySQL创建索引操作
 pub(crate) async fn create_index(
     adapter: &MysqlAdapter,
@@ -106,21 +210,20 @@ pub(crate) async fn create_index(
     index_name: &str,
     fields: &[String],
     unique: bool,
+    options: &IndexOptions,
 ) -> QuickDbResult<()> {
     if let DatabaseConnection::MySQL(pool) = connection {
         let unique_clause = if unique { "UNIQUE " } else { "" };
         let safe_index_name = quote_identifier(index_name, DatabaseType::MySQL);
-        let safe_table = quote_identifier(table, DatabaseType::MySQL);
-        let safe_fields: Vec<String> = fields
-            .iter()
-            .map(|f| quote_identifier(f, DatabaseType::MySQL))
-            .collect();
+        let safe_table = quote_table_identifier(table, DatabaseType::MySQL);
+        // MySQL 不支持部分索引，直接报错而不是静默创建全量索引
+        if !options.where_clause.is_empty() {
+            render_index_predicate(&options.where_clause, DatabaseType::MySQL)?;
+        }
+        let key_parts = index_key_parts(fields, options.expression.as_deref(), DatabaseType::MySQL)?;
         let sql = format!(
             "CREATE {}INDEX {} ON {} ({})",
-            unique_clause,
-            safe_index_name,
-            safe_table,
-            safe_fields.join(", ")
+            unique_clause, safe_index_name, safe_table, key_parts
         );
 
         adapter.execute_update(pool, &sql, &[], table).await?;
@@ -140,9 +243,13 @@ pub(crate) async fn table_exists(
     table: &str,
 ) -> QuickDbResult<bool> {
     if let DatabaseConnection::MySQL(pool) = connection {
-        let sql = "SELECT TABLE_NAME FROM INFORMATION_SCHEMA.TABLES WHERE TABLE_SCHEMA = DATABASE() AND TABLE_NAME = ?";
-        let params = vec![DataValue::String(table.to_string())];
-        let results = adapter.execute_query(pool, sql, &params, table).await?;
+        let (table_schema, table_name) = split_qualified_name(table);
+        let sql = "SELECT TABLE_NAME FROM INFORMATION_SCHEMA.TABLES WHERE TABLE_SCHEMA = COALESCE(?, DATABASE()) AND TABLE_NAME = ?";
+        let params = vec![
+            table_schema.map_or(DataValue::Null, |schema| DataValue::String(schema.to_string())),
+            DataValue::String(table_name.to_string()),
+        ];
+        let results = adapter.execute_query(pool, sql, &params, table, None).await?;
 
         Ok(!results.is_empty())
     } else {
@@ -152,6 +259,52 @@ pub(crate) async fn table_exists(
     }
 }
 
+/// MySQL表记录数估算
+///
+/// 读取 `information_schema.TABLES.TABLE_ROWS`：InnoDB 的值来自采样统计，误差可达数十个百分点，
+/// MySQL 8 还会按 `information_schema_stats_expiry` 缓存；视图等没有该值时回退到精确计数
+pub(crate) async fn count_estimate(
+    _adapter: &MysqlAdapter,
+    connection: &DatabaseConnection,
+    table: &str,
+) -> QuickDbResult<CountEstimate> {
+    if let DatabaseConnection::MySQL(pool) = connection {
+        let (table_schema, table_name) = split_qualified_name(table);
+        let sql = "SELECT TABLE_ROWS FROM INFORMATION_SCHEMA.TABLES WHERE TABLE_SCHEMA = COALESCE(?, DATABASE()) AND TABLE_NAME = ?";
+        let estimate: Option<Option<u64>> = sqlx::query_scalar(sql)
+            .bind(table_schema)
+            .bind(table_name)
+            .fetch_optional(pool)
+            .await
+            .map_err(|e| QuickDbError::QueryError {
+                message: format!("估算MySQL表记录数失败: {}", e),
+            })?;
+
+        match estimate {
+            None => Err(QuickDbError::TableNotExistError {
+                table: table.to_string(),
+                message: format!("MySQL表 '{}' 不存在", table),
+            }),
+            Some(Some(count)) => Ok(CountEstimate::approximate(count)),
+            Some(None) => {
+                debug!("MySQL表 {} 没有行数统计，回退到精确计数", table);
+                let sql = format!("SELECT COUNT(*) FROM {}", quote_table_identifier(table, DatabaseType::MySQL));
+                let count: i64 = sqlx::query_scalar(&sql)
+                    .fetch_one(pool)
+                    .await
+                    .map_err(|e| QuickDbError::QueryError {
+                        message: format!("统计MySQL表记录数失败: {}", e),
+                    })?;
+                Ok(CountEstimate::exact(count as u64))
+            }
+        }
+    } else {
+        Err(QuickDbError::ConnectionError {
+            message: "连接类型不匹配，期望MySQL连接".to_string(),
+        })
+    }
+}
+
 /// MySQL删除表操作
 pub(crate) async fn drop_table(
     adapter: &MysqlAdapter,
@@ -159,7 +312,7 @@ pub(crate) async fn drop_table(
     table: &str,
 ) -> QuickDbResult<()> {
     if let DatabaseConnection::MySQL(pool) = connection {
-        let safe_table = quote_identifier(table, DatabaseType::MySQL);
+        let safe_table = quote_table_identifier(table, DatabaseType::MySQL);
         let sql = format!("DROP TABLE IF EXISTS {}", safe_table);
 
         debug!("执行MySQL删除表SQL: {}", sql);
@@ -2,12 +2,14 @@
 //! MySQL适配器辅助工具方法
 
 use crate::adapter::MysqlAdapter;
-use crate::adapter::mysql::query_builder::SqlQueryBuilder;
+use crate::adapter::statement_cache::track_statement;
+use crate::adapter::utils::sqlx_unique_violation;
 use crate::error::{QuickDbError, QuickDbResult};
 use crate::types::{
-    DataValue, LogicalOperator, QueryCondition, QueryConditionGroup, QueryOperator,
+    DataValue, DatabaseType,
 };
-use async_trait::async_trait;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
 use rat_logger::{debug, error, warn};
 use serde_json::Value as JsonValue;
 use sqlx::mysql::MySqlRow;
@@ -303,12 +305,8 @@ impl MysqlAdapter {
                             Some(i) => {
                                 // 如果i64为负数，这可能是类型转换错误，尝试u64
                                 if i < 0 {
-                                    if let Ok(u_val) = row.try_get::<Option<u64>, _>(column_name) {
-                                        if let Some(u) = u_val {
-                                            DataValue::Int(u as i64)
-                                        } else {
-                                            DataValue::Null
-                                        }
+                                    if let Ok(Some(u)) = row.try_get::<Option<u64>, _>(column_name) {
+                                        DataValue::Int(u as i64)
                                     } else {
                                         DataValue::Null
                                     }
@@ -424,7 +422,7 @@ impl MysqlAdapter {
                                     Ok(s) => DataValue::String(s),
                                     Err(e) => {
                                         warn!("BLOB字段UTF-8转换失败: {}, 使用base64编码", e);
-                                        DataValue::String(base64::encode(&bytes))
+                                        DataValue::String(STANDARD.encode(&bytes))
                                     }
                                 }
                             }
@@ -444,7 +442,7 @@ impl MysqlAdapter {
                     {
                         let result = match value {
                             Some(dt) => {
-                                DataValue::DateTime(dt.with_timezone(&chrono::FixedOffset::east(0)))
+                                DataValue::DateTime(dt.with_timezone(&chrono::FixedOffset::east_opt(0).unwrap()))
                             }
                             None => DataValue::Null,
                         };
@@ -483,15 +481,22 @@ impl MysqlAdapter {
         Ok(data_map)
     }
 
-    /// 执行查询并返回结果
-    pub async fn execute_query(
+    /// 执行查询并返回结果（可在连接池或租用的连接上执行）
+    pub async fn execute_query<'c, E>(
         &self,
-        pool: &Pool<MySql>,
+        pool: E,
         sql: &str,
         params: &[DataValue],
         table: &str,
-    ) -> QuickDbResult<Vec<DataValue>> {
-        let mut query = sqlx::query::<sqlx::MySql>(sql);
+        alias: Option<&str>,
+    ) -> QuickDbResult<Vec<DataValue>>
+    where
+        E: sqlx::MySqlExecutor<'c>,
+    {
+        crate::statement_log::log_sql(DatabaseType::MySQL, sql, params);
+        // alias 为 None 时不纳入预编译语句缓存统计，沿用 sqlx 默认的持久化预编译
+        let mut query = sqlx::query::<sqlx::MySql>(sql)
+            .persistent(alias.is_none_or(|alias| track_statement(alias, sql)));
 
         // 绑定参数
         for param in params {
@@ -532,6 +537,8 @@ impl MysqlAdapter {
                         arr.iter().map(|v| v.to_json_value()).collect();
                     query.bind(serde_json::to_string(&json_values).unwrap_or_default())
                 }
+                // 向量以JSON数组存储
+                DataValue::Vector(vector) => query.bind(serde_json::to_string(vector).unwrap_or_default()),
                 DataValue::Object(obj) => {
                     // 将DataValue对象转换为原始JSON对象
                     let json_map: serde_json::Map<String, serde_json::Value> = obj
@@ -547,6 +554,9 @@ impl MysqlAdapter {
             .fetch_all(pool)
             .await
             .map_err(|e| {
+                if let Some(unique_error) = sqlx_unique_violation(&e, table) {
+                    return unique_error;
+                }
                 // 使用错误码检测表不存在，避免受语言影响
                 // 42S02 是 MySQL/MariaDB 中 "base table or view not found" 的标准错误码
                 if let Some(db_err) = e.as_database_error() {
@@ -608,14 +618,18 @@ impl MysqlAdapter {
         Ok(results)
     }
 
-    /// 执行更新操作
-    pub async fn execute_update(
+    /// 执行更新操作（可在连接池或事务上执行）
+    pub async fn execute_update<'c, E>(
         &self,
-        pool: &Pool<MySql>,
+        pool: E,
         sql: &str,
         params: &[DataValue],
         table: &str,
-    ) -> QuickDbResult<u64> {
+    ) -> QuickDbResult<u64>
+    where
+        E: sqlx::MySqlExecutor<'c>,
+    {
+        crate::statement_log::log_sql(DatabaseType::MySQL, sql, params);
         let mut query = sqlx::query(sql);
 
         // 绑定参数
@@ -657,6 +671,8 @@ impl MysqlAdapter {
                         arr.iter().map(|v| v.to_json_value()).collect();
                     query.bind(serde_json::to_string(&json_values).unwrap_or_default())
                 }
+                // 向量以JSON数组存储
+                DataValue::Vector(vector) => query.bind(serde_json::to_string(vector).unwrap_or_default()),
                 DataValue::Object(obj) => {
                     // 将DataValue对象转换为原始JSON对象
                     let json_map: serde_json::Map<String, serde_json::Value> = obj
@@ -672,6 +688,9 @@ impl MysqlAdapter {
             .execute(pool)
             .await
             .map_err(|e| {
+                if let Some(unique_error) = sqlx_unique_violation(&e, table) {
+                    return unique_error;
+                }
                 // 使用错误码检测表不存在，避免受语言影响
                 // 42S02 是 MySQL/MariaDB 中 "base table or view not found" 的标准错误码
                 if let Some(db_err) = e.as_database_error() {
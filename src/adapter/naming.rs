@@ -0,0 +1,362 @@
+//! 表名装饰适配器
+//!
+//! 按别名的 [`TableNaming`] 把逻辑表名转换为物理表名后交给内部适配器，
+//! 建表、建索引与读写使用同一规则。位于缓存适配器之内，缓存仍按逻辑表名工作；
+//! 内部适配器按物理表名查找模型元数据时由模型注册表还原为逻辑表名
+
+use super::{BatchCreate, DatabaseAdapter};
+use crate::error::QuickDbResult;
+use crate::model::{FieldDefinition, IndexOptions};
+use crate::pool::DatabaseConnection;
+use crate::types::*;
+use async_trait::async_trait;
+use std::collections::HashMap;
+
+/// 表名装饰适配器包装器
+pub struct TableNamingAdapter {
+    /// 内部真实的数据库适配器
+    inner: Box<dyn DatabaseAdapter>,
+    /// 表名装饰规则
+    naming: TableNaming,
+}
+
+impl TableNamingAdapter {
+    /// 创建新的表名装饰适配器
+    pub fn new(inner: Box<dyn DatabaseAdapter>, naming: TableNaming) -> Self {
+        Self { inner, naming }
+    }
+
+    fn table(&self, table: &str) -> String {
+        self.naming.decorate(table)
+    }
+}
+
+#[async_trait]
+impl DatabaseAdapter for TableNamingAdapter {
+    async fn create(
+        &self,
+        connection: &DatabaseConnection,
+        table: &str,
+        data: &HashMap<String, DataValue>,
+        id_strategy: &IdStrategy,
+        alias: &str,
+    ) -> QuickDbResult<DataValue> {
+        self.inner.create(connection, &self.table(table), data, id_strategy, alias).await
+    }
+
+    async fn create_batch(
+        &self,
+        connection: &DatabaseConnection,
+        items: &[BatchCreate],
+    ) -> Vec<QuickDbResult<DataValue>> {
+        let items: Vec<BatchCreate> = items
+            .iter()
+            .map(|item| BatchCreate {
+                table: self.table(&item.table),
+                ..item.clone()
+            })
+            .collect();
+        self.inner.create_batch(connection, &items).await
+    }
+
+    async fn find_by_id(
+        &self,
+        connection: &DatabaseConnection,
+        table: &str,
+        id: &DataValue,
+        alias: &str,
+    ) -> QuickDbResult<Option<DataValue>> {
+        self.inner.find_by_id(connection, &self.table(table), id, alias).await
+    }
+
+    async fn exists_by_id(
+        &self,
+        connection: &DatabaseConnection,
+        table: &str,
+        id: &DataValue,
+        alias: &str,
+    ) -> QuickDbResult<bool> {
+        self.inner.exists_by_id(connection, &self.table(table), id, alias).await
+    }
+
+    async fn find_with_cache_control(
+        &self,
+        connection: &DatabaseConnection,
+        table: &str,
+        conditions: &[QueryConditionWithConfig],
+        options: &QueryOptions,
+        alias: &str,
+        bypass_cache: bool,
+    ) -> QuickDbResult<Vec<DataValue>> {
+        self.inner
+            .find_with_cache_control(connection, &self.table(table), conditions, options, alias, bypass_cache)
+            .await
+    }
+
+    async fn find_with_groups_with_cache_control_and_config(
+        &self,
+        connection: &DatabaseConnection,
+        table: &str,
+        condition_groups: &[QueryConditionGroupWithConfig],
+        options: &QueryOptions,
+        alias: &str,
+        bypass_cache: bool,
+    ) -> QuickDbResult<Vec<DataValue>> {
+        self.inner
+            .find_with_groups_with_cache_control_and_config(
+                connection,
+                &self.table(table),
+                condition_groups,
+                options,
+                alias,
+                bypass_cache,
+            )
+            .await
+    }
+
+    async fn update(
+        &self,
+        connection: &DatabaseConnection,
+        table: &str,
+        conditions: &[QueryConditionWithConfig],
+        data: &HashMap<String, DataValue>,
+        alias: &str,
+    ) -> QuickDbResult<u64> {
+        self.inner.update(connection, &self.table(table), conditions, data, alias).await
+    }
+
+    async fn update_with_operations(
+        &self,
+        connection: &DatabaseConnection,
+        table: &str,
+        conditions: &[QueryConditionWithConfig],
+        operations: &[crate::types::UpdateOperation],
+        alias: &str,
+    ) -> QuickDbResult<u64> {
+        self.inner
+            .update_with_operations(connection, &self.table(table), conditions, operations, alias)
+            .await
+    }
+
+    async fn update_by_id(
+        &self,
+        connection: &DatabaseConnection,
+        table: &str,
+        id: &DataValue,
+        data: &HashMap<String, DataValue>,
+        alias: &str,
+    ) -> QuickDbResult<bool> {
+        self.inner.update_by_id(connection, &self.table(table), id, data, alias).await
+    }
+
+    async fn update_by_id_returning(
+        &self,
+        connection: &DatabaseConnection,
+        table: &str,
+        id: &DataValue,
+        data: &HashMap<String, DataValue>,
+        alias: &str,
+    ) -> QuickDbResult<Option<DataValue>> {
+        self.inner.update_by_id_returning(connection, &self.table(table), id, data, alias).await
+    }
+
+    async fn upsert(
+        &self,
+        connection: &DatabaseConnection,
+        table: &str,
+        data: &HashMap<String, DataValue>,
+        id_strategy: &IdStrategy,
+        conflict_columns: &[String],
+        alias: &str,
+    ) -> QuickDbResult<DataValue> {
+        self.inner
+            .upsert(connection, &self.table(table), data, id_strategy, conflict_columns, alias)
+            .await
+    }
+
+    async fn delete(
+        &self,
+        connection: &DatabaseConnection,
+        table: &str,
+        conditions: &[QueryConditionWithConfig],
+        alias: &str,
+    ) -> QuickDbResult<u64> {
+        self.inner.delete(connection, &self.table(table), conditions, alias).await
+    }
+
+    async fn delete_by_id(
+        &self,
+        connection: &DatabaseConnection,
+        table: &str,
+        id: &DataValue,
+        alias: &str,
+    ) -> QuickDbResult<bool> {
+        self.inner.delete_by_id(connection, &self.table(table), id, alias).await
+    }
+
+    async fn delete_by_id_returning(
+        &self,
+        connection: &DatabaseConnection,
+        table: &str,
+        id: &DataValue,
+        alias: &str,
+    ) -> QuickDbResult<Option<DataValue>> {
+        self.inner.delete_by_id_returning(connection, &self.table(table), id, alias).await
+    }
+
+    async fn bulk_write(
+        &self,
+        connection: &DatabaseConnection,
+        table: &str,
+        ops: &[BulkOp],
+        mode: BulkWriteMode,
+        id_strategy: &IdStrategy,
+        alias: &str,
+    ) -> QuickDbResult<BulkWriteReport> {
+        self.inner
+            .bulk_write(connection, &self.table(table), ops, mode, id_strategy, alias)
+            .await
+    }
+
+    async fn count(
+        &self,
+        connection: &DatabaseConnection,
+        table: &str,
+        conditions: &[QueryConditionWithConfig],
+        alias: &str,
+    ) -> QuickDbResult<u64> {
+        self.inner.count(connection, &self.table(table), conditions, alias).await
+    }
+
+    async fn count_with_groups(
+        &self,
+        connection: &DatabaseConnection,
+        table: &str,
+        condition_groups: &[QueryConditionGroupWithConfig],
+        alias: &str,
+    ) -> QuickDbResult<u64> {
+        self.inner
+            .count_with_groups(connection, &self.table(table), condition_groups, alias)
+            .await
+    }
+
+    async fn create_table(
+        &self,
+        connection: &DatabaseConnection,
+        table: &str,
+        fields: &HashMap<String, FieldDefinition>,
+        id_strategy: &IdStrategy,
+        alias: &str,
+    ) -> QuickDbResult<()> {
+        self.inner.create_table(connection, &self.table(table), fields, id_strategy, alias).await
+    }
+
+    /// 索引名同样装饰：PostgreSQL 的索引名在模式内唯一，多套部署共用模式时不能重名
+    async fn create_index(
+        &self,
+        connection: &DatabaseConnection,
+        table: &str,
+        index_name: &str,
+        fields: &[String],
+        unique: bool,
+        options: &IndexOptions,
+    ) -> QuickDbResult<()> {
+        self.inner
+            .create_index(connection, &self.table(table), &self.naming.decorate(index_name), fields, unique, options)
+            .await
+    }
+
+    async fn table_exists(&self, connection: &DatabaseConnection, table: &str) -> QuickDbResult<bool> {
+        self.inner.table_exists(connection, &self.table(table)).await
+    }
+
+    async fn count_estimate(&self, connection: &DatabaseConnection, table: &str) -> QuickDbResult<CountEstimate> {
+        self.inner.count_estimate(connection, &self.table(table)).await
+    }
+
+    async fn drop_table(&self, connection: &DatabaseConnection, table: &str) -> QuickDbResult<()> {
+        self.inner.drop_table(connection, &self.table(table)).await
+    }
+
+    async fn get_server_version(&self, connection: &DatabaseConnection) -> QuickDbResult<String> {
+        self.inner.get_server_version(connection).await
+    }
+
+    /// 存储过程按配置中的表名原样创建，不做装饰
+    async fn create_stored_procedure(
+        &self,
+        connection: &DatabaseConnection,
+        config: &crate::stored_procedure::StoredProcedureConfig,
+    ) -> QuickDbResult<crate::stored_procedure::StoredProcedureCreateResult> {
+        self.inner.create_stored_procedure(connection, config).await
+    }
+
+    async fn drop_stored_procedure(
+        &self,
+        connection: &DatabaseConnection,
+        procedure_name: &str,
+    ) -> QuickDbResult<bool> {
+        self.inner.drop_stored_procedure(connection, procedure_name).await
+    }
+
+    async fn execute_stored_procedure(
+        &self,
+        connection: &DatabaseConnection,
+        procedure_name: &str,
+        database: &str,
+        params: Option<HashMap<String, DataValue>>,
+    ) -> QuickDbResult<crate::stored_procedure::StoredProcedureQueryResult> {
+        self.inner
+            .execute_stored_procedure(connection, procedure_name, database, params)
+            .await
+    }
+}
+
+#[cfg(all(test, feature = "sqlite-support"))]
+mod tests {
+    use super::*;
+    use crate::types::id_types::IdStrategy;
+
+    #[tokio::test]
+    async fn models_use_decorated_physical_tables() {
+        crate::i18n::ErrorMessageI18n::init_i18n();
+        let alias = "table_naming_suite".to_string();
+        let manager = crate::manager::get_global_pool_manager();
+        manager
+            .add_database(DatabaseConfig {
+                table_naming: Some(TableNaming::prefix("app_").with_suffix("_staging")),
+                ..DatabaseConfig::sqlite_memory_for_test(&alias)
+            })
+            .await
+            .unwrap();
+
+        manager
+            .register_model(crate::model::ModelMeta {
+                collection_name: "events".to_string(),
+                database_alias: Some(alias.clone()),
+                fields: HashMap::from([
+                    ("id".to_string(), crate::model::integer_field(None, None)),
+                    ("kind".to_string(), crate::model::string_field(Some(32), None, None)),
+                ]),
+                ..Default::default()
+            })
+            .unwrap();
+
+        let data = HashMap::from([("kind".to_string(), DataValue::String("click".to_string()))]);
+        let pool = manager.get_connection_pools().get(&alias).unwrap().clone();
+        pool.create("events", &data, &IdStrategy::AutoIncrement).await.unwrap();
+        assert_eq!(pool.find("events", &[], &QueryOptions::default()).await.unwrap().len(), 1);
+
+        let tables = manager
+            .with_connection(Some(&alias), |mut conn| async move {
+                conn.query("SELECT name FROM sqlite_master WHERE type = 'table' AND name LIKE '%events%'", &[])
+                    .await
+            })
+            .await
+            .unwrap();
+        assert_eq!(tables, vec![DataValue::Object(HashMap::from([(
+            "name".to_string(),
+            DataValue::String("app_events_staging".to_string()),
+        )]))]);
+    }
+}
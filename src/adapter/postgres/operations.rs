@@ -3,14 +3,14 @@
 use crate::adapter::DatabaseAdapter;
 use crate::adapter::PostgresAdapter;
 use crate::adapter::postgres::query_builder::SqlQueryBuilder;
-use crate::adapter::postgres::utils::row_to_data_map;
+use crate::adapter::postgres::utils::{pg_error_message, row_to_data_map};
 use crate::error::{QuickDbError, QuickDbResult};
-use crate::manager;
-use crate::model::{FieldDefinition, FieldType};
+use crate::model::{FieldDefinition, IndexOptions};
 use crate::pool::DatabaseConnection;
-use crate::security::quote_identifier;
+use crate::security::{quote_identifier, quote_table_identifier, split_qualified_name};
 use crate::types::*;
 use async_trait::async_trait;
+use chrono::Offset;
 use rat_logger::debug;
 use sqlx::Row;
 use std::collections::HashMap;
@@ -19,6 +19,60 @@ use super::query as postgres_query;
 use super::schema as postgres_schema;
 
 
+/// 按模型元数据验证更新字段，并将 DateTimeWithTz 字段转换为 DateTime
+fn validate_update_data(
+    table: &str,
+    data: &HashMap<String, DataValue>,
+    alias: &str,
+) -> QuickDbResult<HashMap<String, DataValue>> {
+    // 获取字段元数据进行验证和转换
+    let model_meta =
+        crate::manager::get_model_with_alias(table, alias).ok_or_else(|| {
+            QuickDbError::ValidationError {
+                field: "model".to_string(),
+                message: format!("模型 '{}' 不存在", table),
+            }
+        })?;
+
+    // 验证字段存在性，并处理DateTimeWithTz字段转换
+    let field_map: std::collections::HashMap<String, crate::model::FieldDefinition> =
+        model_meta
+            .fields
+            .iter()
+            .map(|(name, f)| (name.clone(), f.clone()))
+            .collect();
+
+    let utc_offset = chrono::Utc.fix();
+    let mut validated_data = HashMap::new();
+    for (field_name, data_value) in data {
+        if let Some(field_def) = field_map.get(field_name) {
+            if matches!(
+                field_def.field_type,
+                crate::model::FieldType::DateTimeWithTz { .. }
+            ) {
+                // DateTimeWithTz字段：将String转换为DateTime
+                let converted = match data_value {
+                    DataValue::String(s) => chrono::DateTime::parse_from_rfc3339(s)
+                        .map(|dt| DataValue::DateTime(dt.with_timezone(&utc_offset)))
+                        .unwrap_or(data_value.clone()),
+                    DataValue::DateTimeUTC(dt) => DataValue::DateTime(dt.with_timezone(&utc_offset)),
+                    _ => data_value.clone(),
+                };
+                validated_data.insert(field_name.clone(), converted);
+            } else {
+                validated_data.insert(field_name.clone(), data_value.clone());
+            }
+        } else {
+            return Err(QuickDbError::ValidationError {
+                field: field_name.clone(),
+                message: format!("字段 '{}' 在模型中不存在", field_name),
+            });
+        }
+    }
+
+    Ok(validated_data)
+}
+
 #[async_trait]
 impl DatabaseAdapter for PostgresAdapter {
     async fn create(
@@ -73,9 +127,11 @@ impl DatabaseAdapter for PostgresAdapter {
 
             // 表已存在，检查是否有SERIAL类型的id字段
             let mut has_auto_increment_id = false;
-            let check_serial_sql = "SELECT column_default FROM information_schema.columns WHERE table_name = $1 AND column_name = 'id'";
+            let (table_schema, table_name) = split_qualified_name(table);
+            let check_serial_sql = "SELECT column_default FROM information_schema.columns WHERE table_schema = COALESCE($1, table_schema) AND table_name = $2 AND column_name = 'id'";
             let rows = sqlx::query(check_serial_sql)
-                .bind(table)
+                .bind(table_schema)
+                .bind(table_name)
                 .fetch_all(pool)
                 .await
                 .map_err(|e| QuickDbError::QueryError {
@@ -94,8 +150,21 @@ impl DatabaseAdapter for PostgresAdapter {
             // 如果数据中有id字段但表使用SERIAL自增，也要移除id字段让PostgreSQL自动生成
             let mut insert_data = data.clone();
             let data_has_id = insert_data.contains_key("id");
+            crate::adapter::utils::apply_field_defaults(
+                table,
+                alias,
+                DatabaseType::PostgreSQL,
+                &mut insert_data,
+            )?;
+
+            // 未提供值的序列字段由列默认值 nextval() 分配
+            for (field_name, _) in crate::adapter::utils::get_sequence_fields(table, alias) {
+                if matches!(insert_data.get(&field_name), Some(DataValue::Null)) {
+                    insert_data.remove(&field_name);
+                }
+            }
 
-            if !data_has_id || (data_has_id && has_auto_increment_id) {
+            if !data_has_id || has_auto_increment_id {
                 insert_data.remove("id");
                 debug!("使用PostgreSQL SERIAL自增，不在INSERT中包含id字段");
             } else if data_has_id {
@@ -103,24 +172,20 @@ impl DatabaseAdapter for PostgresAdapter {
                 match id_strategy {
                     IdStrategy::Snowflake { .. } => {
                         // 雪花ID需要转换为整数
-                        if let Some(id_value) = insert_data.get("id").cloned() {
-                            if let DataValue::String(s) = id_value {
-                                if let Ok(num) = s.parse::<i64>() {
-                                    insert_data.insert("id".to_string(), DataValue::Int(num));
-                                    debug!("将雪花ID从字符串转换为整数: {} -> {}", s, num);
-                                }
-                            }
+                        if let Some(DataValue::String(s)) = insert_data.get("id").cloned() {
+                        if let Ok(num) = s.parse::<i64>() {
+                            insert_data.insert("id".to_string(), DataValue::Int(num));
+                            debug!("将雪花ID从字符串转换为整数: {} -> {}", s, num);
+                        }
                         }
                     }
                     IdStrategy::Uuid => {
                         // UUID需要转换为UUID类型
-                        if let Some(id_value) = insert_data.get("id").cloned() {
-                            if let DataValue::String(s) = id_value {
-                                if let Ok(uuid) = s.parse::<uuid::Uuid>() {
-                                    insert_data.insert("id".to_string(), DataValue::Uuid(uuid));
-                                    debug!("将UUID从字符串转换为UUID类型: {}", s);
-                                }
-                            }
+                        if let Some(DataValue::String(s)) = insert_data.get("id").cloned() {
+                        if let Ok(uuid) = s.parse::<uuid::Uuid>() {
+                            insert_data.insert("id".to_string(), DataValue::Uuid(uuid));
+                            debug!("将UUID从字符串转换为UUID类型: {}", s);
+                        }
                         }
                     }
                     _ => {} // 其他策略不需要转换
@@ -134,7 +199,7 @@ impl DatabaseAdapter for PostgresAdapter {
 
             debug!("执行PostgreSQL插入: {}", sql);
 
-            let results = super::utils::execute_query(self, pool, &sql, &params, table).await?;
+            let results = super::utils::execute_query(self, pool, &sql, &params, table, Some(alias)).await?;
 
             if let Some(result) = results.first() {
                 Ok(result.clone())
@@ -204,9 +269,11 @@ impl DatabaseAdapter for PostgresAdapter {
 
             // 表已存在，检查是否有SERIAL类型的id字段
             let mut has_auto_increment_id = false;
-            let check_serial_sql = "SELECT column_default FROM information_schema.columns WHERE table_name = $1 AND column_name = 'id'";
+            let (table_schema, table_name) = split_qualified_name(table);
+            let check_serial_sql = "SELECT column_default FROM information_schema.columns WHERE table_schema = COALESCE($1, table_schema) AND table_name = $2 AND column_name = 'id'";
             let rows = sqlx::query(check_serial_sql)
-                .bind(table)
+                .bind(table_schema)
+                .bind(table_name)
                 .fetch_all(pool)
                 .await
                 .map_err(|e| QuickDbError::QueryError {
@@ -226,7 +293,14 @@ impl DatabaseAdapter for PostgresAdapter {
             let mut insert_data = data.clone();
             let data_has_id = insert_data.contains_key("id");
 
-            if !data_has_id || (data_has_id && has_auto_increment_id) {
+            // 未提供值的序列字段由列默认值 nextval() 分配
+            for (field_name, _) in crate::adapter::utils::get_sequence_fields(table, alias) {
+                if matches!(insert_data.get(&field_name), Some(DataValue::Null)) {
+                    insert_data.remove(&field_name);
+                }
+            }
+
+            if !data_has_id || has_auto_increment_id {
                 insert_data.remove("id");
                 debug!("使用PostgreSQL SERIAL自增，不在INSERT中包含id字段");
             } else if data_has_id {
@@ -234,24 +308,20 @@ impl DatabaseAdapter for PostgresAdapter {
                 match id_strategy {
                     IdStrategy::Snowflake { .. } => {
                         // 雪花ID需要转换为整数
-                        if let Some(id_value) = insert_data.get("id").cloned() {
-                            if let DataValue::String(s) = id_value {
-                                if let Ok(num) = s.parse::<i64>() {
-                                    insert_data.insert("id".to_string(), DataValue::Int(num));
-                                    debug!("将雪花ID从字符串转换为整数: {} -> {}", s, num);
-                                }
-                            }
+                        if let Some(DataValue::String(s)) = insert_data.get("id").cloned() {
+                        if let Ok(num) = s.parse::<i64>() {
+                            insert_data.insert("id".to_string(), DataValue::Int(num));
+                            debug!("将雪花ID从字符串转换为整数: {} -> {}", s, num);
+                        }
                         }
                     }
                     IdStrategy::Uuid => {
                         // UUID需要转换为UUID类型
-                        if let Some(id_value) = insert_data.get("id").cloned() {
-                            if let DataValue::String(s) = id_value {
-                                if let Ok(uuid) = s.parse::<uuid::Uuid>() {
-                                    insert_data.insert("id".to_string(), DataValue::Uuid(uuid));
-                                    debug!("将UUID从字符串转换为UUID类型: {}", s);
-                                }
-                            }
+                        if let Some(DataValue::String(s)) = insert_data.get("id").cloned() {
+                        if let Ok(uuid) = s.parse::<uuid::Uuid>() {
+                            insert_data.insert("id".to_string(), DataValue::Uuid(uuid));
+                            debug!("将UUID从字符串转换为UUID类型: {}", s);
+                        }
                         }
                     }
                     _ => {} // 其他策略不需要转换
@@ -268,7 +338,7 @@ impl DatabaseAdapter for PostgresAdapter {
 
             debug!("执行PostgreSQL Upsert: {}", sql);
 
-            let results = super::utils::execute_query(self, pool, &sql, &params, table).await?;
+            let results = super::utils::execute_query(self, pool, &sql, &params, table, Some(alias)).await?;
 
             if let Some(result) = results.first() {
                 Ok(result.clone())
@@ -308,7 +378,7 @@ impl DatabaseAdapter for PostgresAdapter {
 
             debug!("执行PostgreSQL根据ID查询: {}", sql);
 
-            let results = super::utils::execute_query(self, pool, &sql, &params, table).await?;
+            let results = super::utils::execute_query(self, pool, &sql, &params, table, Some(alias)).await?;
             Ok(results.into_iter().next())
         } else {
             Err(QuickDbError::ConnectionError {
@@ -317,6 +387,39 @@ impl DatabaseAdapter for PostgresAdapter {
         }
     }
 
+    async fn exists_by_id(
+        &self,
+        connection: &DatabaseConnection,
+        table: &str,
+        id: &DataValue,
+        alias: &str,
+    ) -> QuickDbResult<bool> {
+        if let DatabaseConnection::PostgreSQL(pool) = connection {
+            let condition = QueryConditionWithConfig {
+                field: "id".to_string(),
+                operator: QueryOperator::Eq,
+                value: id.clone(),
+                case_insensitive: false,
+            };
+
+            // 只读取主键列，不反序列化整行
+            let (sql, params) = SqlQueryBuilder::new()
+                .select(&["id"])
+                .where_condition(condition)
+                .limit(1)
+                .build(table, alias)?;
+
+            debug!("执行PostgreSQL存在性查询: {}", sql);
+
+            let results = super::utils::execute_query(self, pool, &sql, &params, table, Some(alias)).await?;
+            Ok(!results.is_empty())
+        } else {
+            Err(QuickDbError::ConnectionError {
+                message: "连接类型不匹配，期望PostgreSQL连接".to_string(),
+            })
+        }
+    }
+
     async fn find_with_cache_control(
         &self,
         connection: &DatabaseConnection,
@@ -352,11 +455,12 @@ impl DatabaseAdapter for PostgresAdapter {
         condition_groups: &[QueryConditionGroupWithConfig],
         options: &QueryOptions,
         alias: &str,
-        bypass_cache: bool,
+        _bypass_cache: bool,
     ) -> QuickDbResult<Vec<DataValue>> {
         if let DatabaseConnection::PostgreSQL(pool) = connection {
+            let fields = crate::adapter::utils::projection_fields(options);
             let mut builder = SqlQueryBuilder::new()
-                .select(&["*"])
+                .select(&fields)
                 .where_condition_groups(condition_groups);
 
             // 添加向量排序（优先于普通排序）
@@ -381,11 +485,26 @@ impl DatabaseAdapter for PostgresAdapter {
                 builder = builder.limit(pagination.limit).offset(pagination.skip);
             }
 
+            // 添加行级锁
+            if let Some(lock) = options.lock {
+                builder = builder.lock(lock);
+            }
+
             let (sql, params) = builder.build(table, alias)?;
 
             debug!("执行PostgreSQL条件组查询: {}", sql);
 
-            super::utils::execute_query(self, pool, &sql, &params, table).await
+            let Some(timeout) = options.timeout else {
+                return super::utils::execute_query(self, pool, &sql, &params, table, Some(alias)).await;
+            };
+
+            // 设置了查询超时：在事务内使用 SET LOCAL，只影响本次查询
+            let mut tx = super::utils::begin_with_statement_timeout(pool, timeout).await?;
+            let results = super::utils::execute_query(self, &mut *tx, &sql, &params, table, Some(alias)).await?;
+            tx.commit().await.map_err(|e| QuickDbError::QueryError {
+                message: format!("提交PostgreSQL事务失败: {}", pg_error_message(&e)),
+            })?;
+            Ok(results)
         } else {
             Err(QuickDbError::ConnectionError {
                 message: "连接类型不匹配，期望PostgreSQL连接".to_string(),
@@ -467,55 +586,7 @@ impl DatabaseAdapter for PostgresAdapter {
         alias: &str,
     ) -> QuickDbResult<u64> {
         if let DatabaseConnection::PostgreSQL(pool) = connection {
-            // 获取字段元数据进行验证和转换
-            let model_meta =
-                crate::manager::get_model_with_alias(table, alias).ok_or_else(|| {
-                    QuickDbError::ValidationError {
-                        field: "model".to_string(),
-                        message: format!("模型 '{}' 不存在", table),
-                    }
-                })?;
-
-            // 验证字段存在性，并处理DateTimeWithTz字段转换
-            let field_map: std::collections::HashMap<String, crate::model::FieldDefinition> =
-                model_meta
-                    .fields
-                    .iter()
-                    .map(|(name, f)| (name.clone(), f.clone()))
-                    .collect();
-
-            let mut validated_data = HashMap::new();
-            for (field_name, data_value) in data {
-                if let Some(field_def) = field_map.get(field_name) {
-                    if matches!(
-                        field_def.field_type,
-                        crate::model::FieldType::DateTimeWithTz { .. }
-                    ) {
-                        // DateTimeWithTz字段：将String转换为DateTime
-                        let converted = match data_value {
-                            DataValue::String(s) => chrono::DateTime::parse_from_rfc3339(s)
-                                .map(|dt| {
-                                    DataValue::DateTime(
-                                        dt.with_timezone(&chrono::FixedOffset::east(0)),
-                                    )
-                                })
-                                .unwrap_or(data_value.clone()),
-                            DataValue::DateTimeUTC(dt) => {
-                                DataValue::DateTime(dt.with_timezone(&chrono::FixedOffset::east(0)))
-                            }
-                            _ => data_value.clone(),
-                        };
-                        validated_data.insert(field_name.clone(), converted);
-                    } else {
-                        validated_data.insert(field_name.clone(), data_value.clone());
-                    }
-                } else {
-                    return Err(QuickDbError::ValidationError {
-                        field: field_name.clone(),
-                        message: format!("字段 '{}' 在模型中不存在", field_name),
-                    });
-                }
-            }
+            let validated_data = validate_update_data(table, data, alias)?;
 
             let (sql, params) = SqlQueryBuilder::new()
                 .update(validated_data)
@@ -524,7 +595,7 @@ impl DatabaseAdapter for PostgresAdapter {
 
             debug!("执行PostgreSQL更新: {}", sql);
 
-            super::utils::execute_update(self, pool, &sql, &params, table).await
+            super::utils::execute_update_with_timeout(self, pool, &sql, &params, table).await
         } else {
             Err(QuickDbError::ConnectionError {
                 message: "连接类型不匹配，期望PostgreSQL连接".to_string(),
@@ -553,6 +624,40 @@ impl DatabaseAdapter for PostgresAdapter {
         Ok(affected > 0)
     }
 
+    async fn update_by_id_returning(
+        &self,
+        connection: &DatabaseConnection,
+        table: &str,
+        id: &DataValue,
+        data: &HashMap<String, DataValue>,
+        alias: &str,
+    ) -> QuickDbResult<Option<DataValue>> {
+        if let DatabaseConnection::PostgreSQL(pool) = connection {
+            let validated_data = validate_update_data(table, data, alias)?;
+            let conditions = vec![QueryConditionWithConfig {
+                field: "id".to_string(),
+                operator: QueryOperator::Eq,
+                value: id.clone(),
+                case_insensitive: false,
+            }];
+
+            let (sql, params) = SqlQueryBuilder::new()
+                .update(validated_data)
+                .where_conditions(&conditions)
+                .build(table, alias)?;
+            let sql = format!("{} RETURNING *", sql);
+
+            debug!("执行PostgreSQL更新并返回记录: {}", sql);
+
+            let rows = super::utils::execute_query(self, pool, &sql, &params, table, Some(alias)).await?;
+            Ok(rows.into_iter().next())
+        } else {
+            Err(QuickDbError::ConnectionError {
+                message: "连接类型不匹配，期望PostgreSQL连接".to_string(),
+            })
+        }
+    }
+
     async fn update_with_operations(
         &self,
         connection: &DatabaseConnection,
@@ -626,6 +731,9 @@ impl DatabaseAdapter for PostgresAdapter {
                         ));
                         params.push(operation.value.clone());
                     }
+                    crate::types::UpdateOperator::Unset | crate::types::UpdateOperator::Rename => {
+                        set_clauses.extend(crate::adapter::utils::structural_set_clauses(DatabaseType::PostgreSQL, operation)?);
+                    }
                 }
             }
 
@@ -636,12 +744,12 @@ impl DatabaseAdapter for PostgresAdapter {
                 });
             }
 
-            let safe_table = quote_identifier(table, DatabaseType::PostgreSQL);
+            let safe_table = quote_table_identifier(table, DatabaseType::PostgreSQL);
             let mut sql = format!("UPDATE {} SET {}", safe_table, set_clauses.join(", "));
 
             // 添加WHERE条件
             if !conditions.is_empty() {
-                let (where_clause, mut where_params) = SqlQueryBuilder::new()
+                let (where_clause, where_params) = SqlQueryBuilder::new()
                     .build_where_clause_with_offset(conditions, params.len() + 1, table, alias)?;
 
                 sql.push_str(&format!(" WHERE {}", where_clause));
@@ -650,7 +758,7 @@ impl DatabaseAdapter for PostgresAdapter {
 
             debug!("执行PostgreSQL操作更新: {}", sql);
 
-            super::utils::execute_update(self, pool, &sql, &params, table).await
+            super::utils::execute_update_with_timeout(self, pool, &sql, &params, table).await
         } else {
             Err(QuickDbError::ConnectionError {
                 message: "连接类型不匹配，期望PostgreSQL连接".to_string(),
@@ -678,6 +786,33 @@ impl DatabaseAdapter for PostgresAdapter {
         postgres_query::delete_by_id(self, connection, table, id, alias).await
     }
 
+    async fn delete_by_id_returning(
+        &self,
+        connection: &DatabaseConnection,
+        table: &str,
+        id: &DataValue,
+        alias: &str,
+    ) -> QuickDbResult<Option<DataValue>> {
+        postgres_query::delete_by_id_returning(self, connection, table, id, alias).await
+    }
+
+    async fn bulk_write(
+        &self,
+        connection: &DatabaseConnection,
+        table: &str,
+        ops: &[BulkOp],
+        mode: BulkWriteMode,
+        _id_strategy: &IdStrategy,
+        alias: &str,
+    ) -> QuickDbResult<BulkWriteReport> {
+        let DatabaseConnection::PostgreSQL(pool) = connection else {
+            return Err(QuickDbError::ConnectionError {
+                message: "连接类型不匹配，期望PostgreSQL连接".to_string(),
+            });
+        };
+        crate::adapter::transaction::execute_postgres_bulk(pool, table, alias, ops, mode).await
+    }
+
     async fn count(
         &self,
         connection: &DatabaseConnection,
@@ -706,122 +841,7 @@ impl DatabaseAdapter for PostgresAdapter {
         id_strategy: &IdStrategy,
         alias: &str,
     ) -> QuickDbResult<()> {
-        if let DatabaseConnection::PostgreSQL(pool) = connection {
-            let mut field_definitions = Vec::new();
-
-            // 根据ID策略创建ID字段
-            if !fields.contains_key("id") {
-                let id_definition = match id_strategy {
-                    IdStrategy::AutoIncrement => "id SERIAL PRIMARY KEY".to_string(),
-                    IdStrategy::Uuid => "id UUID PRIMARY KEY".to_string(), // 使用原生UUID类型，返回时转换为字符串
-                    IdStrategy::Snowflake { .. } => "id BIGINT PRIMARY KEY".to_string(),
-                    IdStrategy::ObjectId => "id TEXT PRIMARY KEY".to_string(),
-                    IdStrategy::Custom(_) => "id TEXT PRIMARY KEY".to_string(), // 自定义策略使用TEXT
-                };
-                field_definitions.push(id_definition);
-            }
-
-            for (name, field_definition) in fields {
-                let sql_type = match &field_definition.field_type {
-                    FieldType::String { max_length, .. } => {
-                        if let Some(max_len) = max_length {
-                            format!("VARCHAR({})", max_len)
-                        } else {
-                            "TEXT".to_string()
-                        }
-                    }
-                    FieldType::Integer { .. } => "INTEGER".to_string(),
-                    FieldType::BigInteger => "BIGINT".to_string(),
-                    FieldType::Float { .. } => "REAL".to_string(),
-                    FieldType::Double => "DOUBLE PRECISION".to_string(),
-                    FieldType::Text => "TEXT".to_string(),
-                    FieldType::Boolean => "BOOLEAN".to_string(),
-                    FieldType::DateTime => {
-                        debug!(
-                            "🔍 字段 {} 类型为 DateTime，required: {}",
-                            name, field_definition.required
-                        );
-                        "TIMESTAMPTZ".to_string()
-                    }
-                    FieldType::DateTimeWithTz { .. } => {
-                        debug!(
-                            "🔍 字段 {} 类型为 DateTimeWithTz，required: {}",
-                            name, field_definition.required
-                        );
-                        "TIMESTAMPTZ".to_string()
-                    }
-                    FieldType::Date => "DATE".to_string(),
-                    FieldType::Time => "TIME".to_string(),
-                    FieldType::Uuid => "UUID".to_string(),
-                    FieldType::Json => "JSONB".to_string(),
-                    FieldType::Binary => "BYTEA".to_string(),
-                    FieldType::Decimal { precision, scale } => {
-                        format!("DECIMAL({},{})", precision, scale)
-                    }
-                    FieldType::Array {
-                        item_type,
-                        max_items: _,
-                        min_items: _,
-                    } => {
-                        // 根据元素类型映射到 PostgreSQL 原生数组类型
-                        match **item_type {
-                            FieldType::String { .. } | FieldType::Text | FieldType::Uuid => "TEXT[]".to_string(),
-                            FieldType::Integer { .. } => "INTEGER[]".to_string(),
-                            FieldType::BigInteger => "BIGINT[]".to_string(),
-                            FieldType::Float { .. } => "REAL[]".to_string(),
-                            FieldType::Double => "DOUBLE PRECISION[]".to_string(),
-                            FieldType::Boolean => "BOOLEAN[]".to_string(),
-                            _ => "JSONB".to_string(),
-                        }
-                    }
-                    FieldType::Object { .. } => "JSONB".to_string(),
-                    FieldType::Reference {
-                        target_collection: _,
-                    } => "TEXT".to_string(),
-                    FieldType::Vector { dimension } => {
-                        format!("vector({})", dimension)
-                    }
-                };
-
-                // 如果是id字段，根据ID策略创建正确的字段类型
-                if name == "id" {
-                    let id_definition = match id_strategy {
-                        IdStrategy::AutoIncrement => "id SERIAL PRIMARY KEY".to_string(),
-                        IdStrategy::Uuid => "id UUID PRIMARY KEY".to_string(), // 使用原生UUID类型
-                        IdStrategy::Snowflake { .. } => "id BIGINT PRIMARY KEY".to_string(),
-                        IdStrategy::ObjectId => "id TEXT PRIMARY KEY".to_string(),
-                        IdStrategy::Custom(_) => "id TEXT PRIMARY KEY".to_string(), // 自定义策略使用TEXT
-                    };
-                    field_definitions.push(id_definition);
-                } else {
-                    // 添加NULL或NOT NULL约束
-                    let null_constraint = if field_definition.required {
-                        "NOT NULL"
-                    } else {
-                        "NULL"
-                    };
-                    debug!("🔍 字段 {} 定义: {} {}", name, sql_type, null_constraint);
-                    field_definitions.push(format!("{} {} {}", quote_identifier(name, DatabaseType::PostgreSQL), sql_type, null_constraint));
-                }
-            }
-
-            let sql = format!(
-                "CREATE TABLE IF NOT EXISTS {} ({})",
-                quote_identifier(table, DatabaseType::PostgreSQL),
-                field_definitions.join(", ")
-            );
-
-            debug!("🔍 执行PostgreSQL建表SQL: {}", sql);
-            debug!("🔍 字段定义详情: {:?}", field_definitions);
-
-            super::utils::execute_update(self, pool, &sql, &[], table).await?;
-
-            Ok(())
-        } else {
-            Err(QuickDbError::ConnectionError {
-                message: "连接类型不匹配，期望PostgreSQL连接".to_string(),
-            })
-        }
+        postgres_schema::create_table(self, connection, table, fields, id_strategy, alias).await
     }
 
     async fn create_index(
@@ -831,31 +851,18 @@ impl DatabaseAdapter for PostgresAdapter {
         index_name: &str,
         fields: &[String],
         unique: bool,
+        options: &IndexOptions,
     ) -> QuickDbResult<()> {
-        if let DatabaseConnection::PostgreSQL(pool) = connection {
-            let unique_clause = if unique { "UNIQUE " } else { "" };
-            let safe_fields: Vec<String> = fields
-                .iter()
-                .map(|f| quote_identifier(f, DatabaseType::PostgreSQL))
-                .collect();
-            let sql = format!(
-                "CREATE {}INDEX IF NOT EXISTS {} ON {} ({})",
-                unique_clause,
-                quote_identifier(index_name, DatabaseType::PostgreSQL),
-                quote_identifier(table, DatabaseType::PostgreSQL),
-                safe_fields.join(", ")
-            );
-
-            debug!("执行PostgreSQL索引创建: {}", sql);
-
-            super::utils::execute_update(self, pool, &sql, &[], table).await?;
-
-            Ok(())
-        } else {
-            Err(QuickDbError::ConnectionError {
-                message: "连接类型不匹配，期望PostgreSQL连接".to_string(),
-            })
-        }
+        postgres_schema::create_index(
+            self,
+            connection,
+            table,
+            index_name,
+            fields,
+            unique,
+            options,
+        )
+        .await
     }
 
     async fn table_exists(
@@ -864,10 +871,12 @@ impl DatabaseAdapter for PostgresAdapter {
         table: &str,
     ) -> QuickDbResult<bool> {
         if let DatabaseConnection::PostgreSQL(pool) = connection {
-            let sql = "SELECT table_name FROM information_schema.tables WHERE table_schema = 'public' AND table_name = $1";
+            let (table_schema, table_name) = split_qualified_name(table);
+            let sql = "SELECT table_name FROM information_schema.tables WHERE table_schema = COALESCE($1, 'public') AND table_name = $2";
 
             let rows = sqlx::query(sql)
-                .bind(table)
+                .bind(table_schema)
+                .bind(table_name)
                 .fetch_all(pool)
                 .await
                 .map_err(|e| QuickDbError::QueryError {
@@ -884,9 +893,17 @@ impl DatabaseAdapter for PostgresAdapter {
         }
     }
 
+    async fn count_estimate(
+        &self,
+        connection: &DatabaseConnection,
+        table: &str,
+    ) -> QuickDbResult<CountEstimate> {
+        postgres_schema::count_estimate(self, connection, table).await
+    }
+
     async fn drop_table(&self, connection: &DatabaseConnection, table: &str) -> QuickDbResult<()> {
         if let DatabaseConnection::PostgreSQL(pool) = connection {
-            let sql = format!("DROP TABLE IF EXISTS {} CASCADE", quote_identifier(table, DatabaseType::PostgreSQL));
+            let sql = format!("DROP TABLE IF EXISTS {} CASCADE", quote_table_identifier(table, DatabaseType::PostgreSQL));
 
             debug!("执行PostgreSQL删除表SQL: {}", sql);
 
@@ -898,9 +915,11 @@ impl DatabaseAdapter for PostgresAdapter {
                 })?;
 
             // 验证表是否真的被删除了
-            let check_sql = "SELECT table_name FROM information_schema.tables WHERE table_schema = 'public' AND table_name = $1";
+            let (table_schema, table_name) = split_qualified_name(table);
+            let check_sql = "SELECT table_name FROM information_schema.tables WHERE table_schema = COALESCE($1, 'public') AND table_name = $2";
             let check_rows = sqlx::query(check_sql)
-                .bind(table)
+                .bind(table_schema)
+                .bind(table_name)
                 .fetch_all(pool)
                 .await
                 .map_err(|e| QuickDbError::QueryError {
@@ -985,7 +1004,7 @@ impl DatabaseAdapter for PostgresAdapter {
         }
 
         // 2. 生成PostgreSQL存储过程模板（带占位符）
-        let sql_template = self.generate_stored_procedure_sql(&config).await?;
+        let sql_template = self.generate_stored_procedure_sql(config).await?;
         debug!("生成PostgreSQL存储过程SQL模板: {}", sql_template);
 
         // 3. 将存储过程信息存储到适配器映射表中（PostgreSQL不需要执行创建SQL）
@@ -1010,16 +1029,25 @@ impl DatabaseAdapter for PostgresAdapter {
         })
     }
 
+    /// 删除存储过程（从适配器映射表中移除模板）
+    async fn drop_stored_procedure(
+        &self,
+        _connection: &DatabaseConnection,
+        procedure_name: &str,
+    ) -> QuickDbResult<bool> {
+        let removed = self.stored_procedures.lock().await.remove(procedure_name).is_some();
+        debug!("删除PostgreSQL存储过程: {}, 存在={}", procedure_name, removed);
+        Ok(removed)
+    }
+
     /// 执行存储过程查询（PostgreSQL使用视图实现）
     async fn execute_stored_procedure(
         &self,
         connection: &DatabaseConnection,
         procedure_name: &str,
-        database: &str,
+        _database: &str,
         params: Option<std::collections::HashMap<String, crate::types::DataValue>>,
     ) -> QuickDbResult<crate::stored_procedure::StoredProcedureQueryResult> {
-        use crate::adapter::postgres::adapter::PostgresAdapter;
-
         // 获取存储过程信息
         let procedures = self.stored_procedures.lock().await;
         let procedure_info = procedures.get(procedure_name).ok_or_else(|| {
@@ -1047,7 +1075,7 @@ impl DatabaseAdapter for PostgresAdapter {
             DatabaseConnection::PostgreSQL(pool) => pool,
             _ => {
                 return Err(QuickDbError::ConnectionError {
-                    message: "Invalid connection type for PostgreSQL".to_string(),
+                    message: "连接类型不匹配，期望PostgreSQL连接".to_string(),
                 });
             }
         };
@@ -1056,7 +1084,7 @@ impl DatabaseAdapter for PostgresAdapter {
 
         let rows = sqlx::query(&final_sql).fetch_all(pool).await.map_err(|e| {
             QuickDbError::QueryError {
-                message: format!("执行存储过程查询失败: {}", e),
+                message: format!("执行存储过程查询失败: {}", pg_error_message(&e)),
             }
         })?;
 
@@ -1115,12 +1143,7 @@ impl PostgresAdapter {
                 };
                 final_sql = final_sql.replace("{GROUP_BY}", &format!(" GROUP BY {}", group_by_str));
             } else {
-                // PostgreSQL特殊处理：如果SQL模板已经包含GROUP BY，则不替换为空字符串
-                if final_sql.contains(" GROUP BY") {
-                    final_sql = final_sql.replace("{GROUP_BY}", "");
-                } else {
-                    final_sql = final_sql.replace("{GROUP_BY}", "");
-                }
+                final_sql = final_sql.replace("{GROUP_BY}", "");
             }
 
             // HAVING替换
@@ -1182,7 +1205,6 @@ impl PostgresAdapter {
         final_sql = final_sql
             .replace("  ", " ")
             .replace(" ,", ",")
-            .replace(", ", ", ")
             .replace(" WHERE ", "")
             // 不删除GROUP BY，因为PostgreSQL需要它
             .replace(" HAVING ", "")
@@ -1,6 +1,5 @@
 //! PostgreSQL查询相关操作
 
-use crate::adapter::DatabaseAdapter;
 use crate::adapter::postgres::PostgresAdapter;
 use crate::adapter::postgres::query_builder::SqlQueryBuilder;
 use crate::error::{QuickDbError, QuickDbResult};
@@ -52,6 +51,38 @@ pub(crate) async fn delete_by_id(
     Ok(affected > 0)
 }
 
+/// PostgreSQL根据ID删除并返回被删除的记录（`DELETE ... RETURNING *`）
+pub(crate) async fn delete_by_id_returning(
+    adapter: &PostgresAdapter,
+    connection: &DatabaseConnection,
+    table: &str,
+    id: &DataValue,
+    alias: &str,
+) -> QuickDbResult<Option<DataValue>> {
+    if let DatabaseConnection::PostgreSQL(pool) = connection {
+        let conditions = vec![QueryConditionWithConfig {
+            field: "id".to_string(),
+            operator: QueryOperator::Eq,
+            value: id.clone(),
+            case_insensitive: false,
+        }];
+        let (sql, params) = SqlQueryBuilder::new()
+            .delete()
+            .where_conditions(&conditions)
+            .build(table, alias)?;
+        let sql = format!("{} RETURNING *", sql);
+
+        debug!("执行PostgreSQL删除并返回记录: {}", sql);
+
+        let rows = super::utils::execute_query(adapter, pool, &sql, &params, table, Some(alias)).await?;
+        Ok(rows.into_iter().next())
+    } else {
+        Err(QuickDbError::ConnectionError {
+            message: "连接类型不匹配，期望PostgreSQL连接".to_string(),
+        })
+    }
+}
+
 /// PostgreSQL计数操作
 pub(crate) async fn count(
     adapter: &PostgresAdapter,
@@ -68,12 +99,10 @@ pub(crate) async fn count(
 
         debug!("执行PostgreSQL计数: {}", sql);
 
-        let results = super::utils::execute_query(adapter, pool, &sql, &params, table).await?;
-        if let Some(result) = results.first() {
-            if let DataValue::Object(obj) = result {
-                if let Some(DataValue::Int(count)) = obj.get("count") {
-                    return Ok(*count as u64);
-                }
+        let results = super::utils::execute_query(adapter, pool, &sql, &params, table, Some(alias)).await?;
+        if let Some(DataValue::Object(obj)) = results.first() {
+            if let Some(DataValue::Int(count)) = obj.get("count") {
+                return Ok(*count as u64);
             }
         }
 
@@ -101,12 +130,10 @@ pub(crate) async fn count_with_groups(
 
         debug!("执行PostgreSQL条件组合计数: {}", sql);
 
-        let results = super::utils::execute_query(adapter, pool, &sql, &params, table).await?;
-        if let Some(result) = results.first() {
-            if let DataValue::Object(obj) = result {
-                if let Some(DataValue::Int(count)) = obj.get("count") {
-                    return Ok(*count as u64);
-                }
+        let results = super::utils::execute_query(adapter, pool, &sql, &params, table, Some(alias)).await?;
+        if let Some(DataValue::Object(obj)) = results.first() {
+            if let Some(DataValue::Int(count)) = obj.get("count") {
+                return Ok(*count as u64);
             }
         }
 
@@ -2,9 +2,10 @@
 //!
 //! 提供安全的SQL查询构建功能，防止SQL注入攻击
 
-use crate::adapter::utils::get_field_type;
+use crate::adapter::utils::{case_insensitive_clause, get_field_type};
 use crate::error::{QuickDbError, QuickDbResult};
 use crate::security::DatabaseSecurityValidator;
+use crate::types::query::{between_bounds, regex_pattern};
 use crate::types::*;
 use std::collections::HashMap;
 
@@ -20,6 +21,7 @@ pub struct SqlQueryBuilder {
     having: Vec<QueryConditionWithConfig>,
     limit: Option<u64>,
     offset: Option<u64>,
+    lock: Option<RowLock>,
     values: HashMap<String, DataValue>,
     returning_fields: Vec<String>,
     /// Upsert冲突检测列
@@ -48,6 +50,7 @@ pub struct JoinClause {
 }
 
 #[derive(Debug, Clone)]
+#[allow(dead_code)]
 pub enum JoinType {
     Inner,
     Left,
@@ -75,6 +78,7 @@ impl SqlQueryBuilder {
             having: Vec::new(),
             limit: None,
             offset: None,
+            lock: None,
             values: HashMap::new(),
             returning_fields: Vec::new(),
             conflict_columns: Vec::new(),
@@ -146,6 +150,7 @@ impl SqlQueryBuilder {
     }
 
     /// 添加JOIN子句
+    #[allow(dead_code)]
     pub fn join(mut self, join_type: JoinType, table: &str, on_condition: &str) -> Self {
         self.joins.push(JoinClause {
             join_type,
@@ -177,12 +182,14 @@ impl SqlQueryBuilder {
     }
 
     /// 添加GROUP BY子句
+    #[allow(dead_code)]
     pub fn group_by(mut self, fields: &[&str]) -> Self {
         self.group_by = fields.iter().map(|s| s.to_string()).collect();
         self
     }
 
     /// 添加HAVING条件
+    #[allow(dead_code)]
     pub fn having(mut self, condition: QueryConditionWithConfig) -> Self {
         self.having.push(condition);
         self
@@ -200,6 +207,12 @@ impl SqlQueryBuilder {
         self
     }
 
+    /// 设置行级锁（SELECT ... FOR UPDATE）
+    pub fn lock(mut self, lock: RowLock) -> Self {
+        self.lock = Some(lock);
+        self
+    }
+
     /// 设置RETURNING子句（用于INSERT/UPDATE/DELETE）
     pub fn returning(mut self, fields: &[&str]) -> Self {
         self.returning_fields = fields.iter().map(|s| s.to_string()).collect();
@@ -208,15 +221,13 @@ impl SqlQueryBuilder {
 
     /// 构建SQL查询语句
     pub fn build(self, table: &str, alias: &str) -> QuickDbResult<(String, Vec<DataValue>)> {
-        let result = match self.query_type {
+        match self.query_type {
             QueryType::Select => self.build_select(table, alias),
             QueryType::Insert => self.build_insert(table, alias),
             QueryType::Update => self.build_update(table, alias),
             QueryType::Delete => self.build_delete(table, alias),
             QueryType::Upsert => self.build_upsert(table, alias),
-        };
-
-        result
+        }
     }
 
     /// 构建SELECT语句
@@ -371,6 +382,13 @@ impl SqlQueryBuilder {
             sql.push_str(&format!(" OFFSET {}", offset));
         }
 
+        // 添加行级锁
+        match self.lock {
+            Some(RowLock::ForUpdate) => sql.push_str(" FOR UPDATE"),
+            Some(RowLock::ForShare) => sql.push_str(" FOR SHARE"),
+            None => {}
+        }
+
         Ok((sql, params))
     }
 
@@ -499,7 +517,7 @@ impl SqlQueryBuilder {
     }
 
     /// 构建UPSERT语句（INSERT ... ON CONFLICT ... DO UPDATE SET ...）
-    fn build_upsert(&self, table: &str, alias: &str) -> QuickDbResult<(String, Vec<DataValue>)> {
+    fn build_upsert(&self, table: &str, _alias: &str) -> QuickDbResult<(String, Vec<DataValue>)> {
         if table.is_empty() {
             return Err(QuickDbError::QueryError {
                 message: "表名不能为空".to_string(),
@@ -670,7 +688,7 @@ impl SqlQueryBuilder {
     ) -> QuickDbResult<(String, Vec<DataValue>, usize)> {
         match group {
             QueryConditionGroupWithConfig::Single(condition) => {
-                let (clause, mut params, new_index) =
+                let (clause, params, new_index) =
                     self.build_single_condition_clause(condition, start_index, table, alias)?;
                 Ok((clause, params, new_index))
             }
@@ -730,6 +748,13 @@ impl SqlQueryBuilder {
         let safe_field = self
             .security_validator
             .get_safe_field_identifier(&condition.field)?;
+        if let Some((clause, params)) = case_insensitive_clause(condition, &safe_field, table, alias, |offset| {
+            self.get_placeholder(param_index + offset)
+        }) {
+            let next_index = param_index + params.len();
+            return Ok((clause, params, next_index));
+        }
+
         let (clause, params) = match condition.operator {
             QueryOperator::Eq => {
                 new_index += 1;
@@ -1048,9 +1073,25 @@ impl SqlQueryBuilder {
             }
             QueryOperator::Regex => {
                 new_index += 1;
+                let pattern = regex_pattern(&condition.field, &condition.value)?;
+                let operator = if condition.case_insensitive { "~*" } else { "~" };
                 (
-                    format!("{} ~ {}", safe_field, placeholder),
-                    vec![condition.value.clone()],
+                    format!("{} {} {}", safe_field, operator, placeholder),
+                    vec![DataValue::String(pattern.to_string())],
+                )
+            }
+            QueryOperator::Between | QueryOperator::NotBetween => {
+                let (lower, upper) = between_bounds(&condition.field, &condition.value)?;
+                let keyword = if condition.operator == QueryOperator::Between {
+                    "BETWEEN"
+                } else {
+                    "NOT BETWEEN"
+                };
+                let upper_placeholder = self.get_placeholder(new_index + 1);
+                new_index += 2;
+                (
+                    format!("{} {} {} AND {}", safe_field, keyword, placeholder, upper_placeholder),
+                    vec![self.convert_uuid_value_for_postgres(table, &condition.field, lower, alias)?, self.convert_uuid_value_for_postgres(table, &condition.field, upper, alias)?],
                 )
             }
             QueryOperator::Exists => (format!("{} IS NOT NULL", safe_field), vec![]),
@@ -1083,6 +1124,15 @@ impl SqlQueryBuilder {
                 .security_validator
                 .get_safe_field_identifier(&condition.field)?;
 
+            if let Some((clause, values)) = case_insensitive_clause(condition, &safe_field, table, alias, |offset| {
+                self.get_placeholder(param_index + offset)
+            }) {
+                clauses.push(clause);
+                param_index += values.len();
+                params.extend(values);
+                continue;
+            }
+
             match condition.operator {
                 QueryOperator::Eq => {
                     // 处理大小写不敏感的等于操作
@@ -1394,11 +1444,29 @@ impl SqlQueryBuilder {
                     param_index += 1;
                 }
                 QueryOperator::Regex => {
-                    // PostgreSQL 使用 ~ 操作符进行正则表达式匹配
-                    clauses.push(format!("{} ~ {}", safe_field, placeholder));
-                    params.push(condition.value.clone());
+                    // PostgreSQL 使用 ~ / ~*（大小写不敏感）操作符进行正则表达式匹配
+                    let pattern = regex_pattern(&condition.field, &condition.value)?;
+                    let operator = if condition.case_insensitive { "~*" } else { "~" };
+                    clauses.push(format!("{} {} {}", safe_field, operator, placeholder));
+                    params.push(DataValue::String(pattern.to_string()));
                     param_index += 1;
                 }
+                QueryOperator::Between | QueryOperator::NotBetween => {
+                    let (lower, upper) = between_bounds(&condition.field, &condition.value)?;
+                    let keyword = if condition.operator == QueryOperator::Between {
+                        "BETWEEN"
+                    } else {
+                        "NOT BETWEEN"
+                    };
+                    let upper_placeholder = self.get_placeholder(param_index + 1);
+                    clauses.push(format!(
+                        "{} {} {} AND {}",
+                        safe_field, keyword, placeholder, upper_placeholder
+                    ));
+                    params.push(self.convert_uuid_value_for_postgres(table, &condition.field, lower, alias)?);
+                    params.push(self.convert_uuid_value_for_postgres(table, &condition.field, upper, alias)?);
+                    param_index += 2;
+                }
                 QueryOperator::Exists => {
                     // 检查字段是否存在（主要用于NoSQL数据库）
                     clauses.push(format!("{} IS NOT NULL", safe_field));
@@ -1430,10 +1498,6 @@ impl SqlQueryBuilder {
         format!("${}", index)
     }
 
-    /// 获取字段类型信息
-    ///
-    /// 通过查询模型元数据来确定指定字段的类型
-
     /// PostgreSQL UUID字段值转换
     ///
     /// 仅在PostgreSQL数据库且字段类型为UUID时使用
@@ -1489,3 +1553,89 @@ impl Default for SqlQueryBuilder {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ci(field: &str, operator: QueryOperator, value: DataValue) -> QueryConditionWithConfig {
+        QueryConditionWithConfig {
+            field: field.to_string(),
+            operator,
+            value,
+            case_insensitive: true,
+        }
+    }
+
+    #[test]
+    fn case_insensitive_eq_ne_in_use_lower() {
+        let names = DataValue::Array(vec![
+            DataValue::String("Alice".to_string()),
+            DataValue::String("BOB".to_string()),
+        ]);
+        let conditions = vec![
+            ci("name", QueryOperator::Eq, DataValue::String("Alice".to_string())),
+            ci("email", QueryOperator::Ne, DataValue::String("A@B.COM".to_string())),
+            ci("role", QueryOperator::In, names),
+            ci("age", QueryOperator::Eq, DataValue::Int(30)),
+        ];
+
+        let (sql, params) = SqlQueryBuilder::new()
+            .select(&["*"])
+            .where_conditions(&conditions)
+            .build("ci_users", "default")
+            .unwrap();
+        assert!(sql.contains(r#"LOWER("name") = LOWER($1)"#), "{}", sql);
+        assert!(sql.contains(r#"LOWER("email") != LOWER($2)"#), "{}", sql);
+        assert!(sql.contains(r#"LOWER("role") IN (LOWER($3), LOWER($4))"#), "{}", sql);
+        assert!(sql.contains(r#""age" = $5"#), "{}", sql);
+        assert_eq!(params.len(), 5);
+
+        // 条件组路径与简单条件路径生成一致的比较
+        let groups = vec![QueryConditionGroupWithConfig::GroupWithConfig {
+            operator: LogicalOperator::Or,
+            conditions: conditions.into_iter().map(QueryConditionGroupWithConfig::Single).collect(),
+        }];
+        let (sql, params) = SqlQueryBuilder::new()
+            .select(&["*"])
+            .where_condition_groups(&groups)
+            .build("ci_users", "default")
+            .unwrap();
+        assert!(sql.contains(r#"LOWER("role") IN (LOWER($3), LOWER($4))"#), "{}", sql);
+        assert!(sql.contains(r#""age" = $5"#), "{}", sql);
+        assert_eq!(params.len(), 5);
+    }
+
+    #[test]
+    fn regex_uses_tilde_operators() {
+        crate::i18n::ErrorMessageI18n::init_i18n();
+        let mut condition = ci("name", QueryOperator::Regex, DataValue::String("^ali".to_string()));
+        let build = |condition: &QueryConditionWithConfig| {
+            SqlQueryBuilder::new()
+                .select(&["*"])
+                .where_conditions(std::slice::from_ref(condition))
+                .build("ci_users", "default")
+        };
+        assert!(build(&condition).unwrap().0.ends_with(r#"WHERE "name" ~* $1"#));
+        condition.case_insensitive = false;
+        assert!(build(&condition).unwrap().0.ends_with(r#"WHERE "name" ~ $1"#));
+        condition.value = DataValue::String("(a*)*".to_string());
+        assert!(build(&condition).is_err());
+    }
+
+    #[test]
+    fn row_lock_is_appended_after_pagination() {
+        let build = |lock: RowLock| {
+            SqlQueryBuilder::new()
+                .select(&["*"])
+                .where_conditions(&[ci("id", QueryOperator::Gt, DataValue::Int(1))])
+                .limit(10)
+                .lock(lock)
+                .build("accounts", "default")
+                .unwrap()
+                .0
+        };
+        assert!(build(RowLock::ForUpdate).ends_with("LIMIT 10 FOR UPDATE"));
+        assert!(build(RowLock::ForShare).ends_with("LIMIT 10 FOR SHARE"));
+    }
+}
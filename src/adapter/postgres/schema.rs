@@ -1,13 +1,16 @@
 //! PostgreSQL表和索引管理操作
 
 use crate::adapter::postgres::PostgresAdapter;
+use crate::adapter::utils::{
+    get_custom_primary_key, id_column_constraint, index_key_parts, render_index_predicate,
+    sequence_fields_of,
+};
 use crate::error::{QuickDbError, QuickDbResult};
-use crate::model::{FieldDefinition, FieldType};
+use crate::model::{FieldDefinition, FieldType, IndexOptions};
 use crate::pool::DatabaseConnection;
-use crate::security::quote_identifier;
+use crate::security::{DatabaseSecurityValidator, quote_identifier, quote_table_identifier, split_qualified_name};
 use crate::types::*;
 use rat_logger::debug;
-use sqlx::Row;
 use std::collections::HashMap;
 
 /// PostgreSQL创建表操作
@@ -22,15 +25,24 @@ pub(crate) async fn create_table(
     if let DatabaseConnection::PostgreSQL(pool) = connection {
         let mut field_definitions = Vec::new();
 
+        // 序列字段依赖的序列需要先于表创建
+        for (_, sequence_name) in sequence_fields_of(fields) {
+            create_sequence(adapter, pool, &sequence_name, table).await?;
+        }
+
+        // 复合/自定义主键时，id列不再是主键，主键约束在表级声明
+        let custom_primary_key = get_custom_primary_key(table, alias);
+        let id_constraint = id_column_constraint(custom_primary_key.as_deref());
+
         // 根据ID策略创建ID字段
         if !fields.contains_key("id") {
             let safe_id = quote_identifier("id", DatabaseType::PostgreSQL);
             let id_definition = match id_strategy {
-                IdStrategy::AutoIncrement => format!("{} SERIAL PRIMARY KEY", safe_id),
-                IdStrategy::Uuid => format!("{} UUID PRIMARY KEY", safe_id), // 使用原生UUID类型，返回时转换为字符串
-                IdStrategy::Snowflake { .. } => format!("{} BIGINT PRIMARY KEY", safe_id),
-                IdStrategy::ObjectId => format!("{} TEXT PRIMARY KEY", safe_id),
-                IdStrategy::Custom(_) => format!("{} TEXT PRIMARY KEY", safe_id), // 自定义策略使用TEXT
+                IdStrategy::AutoIncrement => format!("{} SERIAL {}", safe_id, id_constraint),
+                IdStrategy::Uuid => format!("{} UUID {}", safe_id, id_constraint), // 使用原生UUID类型，返回时转换为字符串
+                IdStrategy::Snowflake { .. } => format!("{} BIGINT {}", safe_id, id_constraint),
+                IdStrategy::ObjectId => format!("{} TEXT {}", safe_id, id_constraint),
+                IdStrategy::Custom(_) => format!("{} TEXT {}", safe_id, id_constraint), // 自定义策略使用TEXT
             };
             field_definitions.push(id_definition);
         }
@@ -92,26 +104,38 @@ pub(crate) async fn create_table(
                 FieldType::Reference {
                     target_collection: _,
                 } => "TEXT".to_string(),
+                FieldType::PolymorphicReference { .. } => "JSONB".to_string(),
                 FieldType::Vector { dimension } => {
                     format!("vector({})", dimension)
                 }
+                FieldType::Sequence { name: sequence_name } => {
+                    // 使用原生序列，插入时未提供值则由默认值分配
+                    format!(
+                        "BIGINT DEFAULT nextval('{}')",
+                        quote_identifier(sequence_name, DatabaseType::PostgreSQL)
+                    )
+                }
             };
 
             // 如果是id字段，根据ID策略创建正确的字段类型
             if name == "id" {
                 let safe_id = quote_identifier("id", DatabaseType::PostgreSQL);
                 let id_definition = match id_strategy {
-                    IdStrategy::AutoIncrement => format!("{} SERIAL PRIMARY KEY", safe_id),
-                    IdStrategy::Uuid => format!("{} UUID PRIMARY KEY", safe_id), // 使用原生UUID类型
-                    IdStrategy::Snowflake { .. } => format!("{} BIGINT PRIMARY KEY", safe_id),
-                    IdStrategy::ObjectId => format!("{} TEXT PRIMARY KEY", safe_id),
-                    IdStrategy::Custom(_) => format!("{} TEXT PRIMARY KEY", safe_id), // 自定义策略使用TEXT
+                    IdStrategy::AutoIncrement => format!("{} SERIAL {}", safe_id, id_constraint),
+                    IdStrategy::Uuid => format!("{} UUID {}", safe_id, id_constraint), // 使用原生UUID类型
+                    IdStrategy::Snowflake { .. } => format!("{} BIGINT {}", safe_id, id_constraint),
+                    IdStrategy::ObjectId => format!("{} TEXT {}", safe_id, id_constraint),
+                    IdStrategy::Custom(_) => format!("{} TEXT {}", safe_id, id_constraint), // 自定义策略使用TEXT
                 };
                 field_definitions.push(id_definition);
             } else {
                 let safe_name = quote_identifier(name, DatabaseType::PostgreSQL);
+                // 主键字段必须为NOT NULL
+                let is_primary_key = custom_primary_key
+                    .as_ref()
+                    .is_some_and(|pk| pk.contains(name));
                 // 添加NULL或NOT NULL约束
-                let null_constraint = if field_definition.required {
+                let null_constraint = if field_definition.required || is_primary_key {
                     "NOT NULL"
                 } else {
                     "NULL"
@@ -121,7 +145,16 @@ pub(crate) async fn create_table(
             }
         }
 
-        let safe_table = quote_identifier(table, DatabaseType::PostgreSQL);
+        // 表级复合主键约束
+        if let Some(pk) = &custom_primary_key {
+            let safe_pk: Vec<String> = pk
+                .iter()
+                .map(|f| quote_identifier(f, DatabaseType::PostgreSQL))
+                .collect();
+            field_definitions.push(format!("PRIMARY KEY ({})", safe_pk.join(", ")));
+        }
+
+        let safe_table = quote_table_identifier(table, DatabaseType::PostgreSQL);
         let sql = format!(
             "CREATE TABLE IF NOT EXISTS {} ({})",
             safe_table,
@@ -141,6 +174,27 @@ pub(crate) async fn create_table(
     }
 }
 
+/// PostgreSQL创建序列（已存在时跳过）
+pub(crate) async fn create_sequence(
+    adapter: &PostgresAdapter,
+    pool: &sqlx::PgPool,
+    sequence_name: &str,
+    table: &str,
+) -> QuickDbResult<()> {
+    DatabaseSecurityValidator::new(DatabaseType::PostgreSQL).validate_table_name(sequence_name)?;
+
+    let sql = format!(
+        "CREATE SEQUENCE IF NOT EXISTS {}",
+        quote_identifier(sequence_name, DatabaseType::PostgreSQL)
+    );
+
+    debug!("执行PostgreSQL序列创建: {}", sql);
+
+    super::utils::execute_update(adapter, pool, &sql, &[], table).await?;
+
+    Ok(())
+}
+
 /// PostgreSQL创建索引操作
 pub(crate) async fn create_index(
     adapter: &PostgresAdapter,
@@ -149,22 +203,21 @@ pub(crate) async fn create_index(
     index_name: &str,
     fields: &[String],
     unique: bool,
+    options: &IndexOptions,
 ) -> QuickDbResult<()> {
     if let DatabaseConnection::PostgreSQL(pool) = connection {
         let unique_clause = if unique { "UNIQUE " } else { "" };
         let safe_index_name = quote_identifier(index_name, DatabaseType::PostgreSQL);
-        let safe_table = quote_identifier(table, DatabaseType::PostgreSQL);
-        let safe_fields: Vec<String> = fields
-            .iter()
-            .map(|f| quote_identifier(f, DatabaseType::PostgreSQL))
-            .collect();
-        let sql = format!(
+        let safe_table = quote_table_identifier(table, DatabaseType::PostgreSQL);
+        let key_parts = index_key_parts(fields, options.expression.as_deref(), DatabaseType::PostgreSQL)?;
+        let mut sql = format!(
             "CREATE {}INDEX IF NOT EXISTS {} ON {} ({})",
-            unique_clause,
-            safe_index_name,
-            safe_table,
-            safe_fields.join(", ")
+            unique_clause, safe_index_name, safe_table, key_parts
         );
+        if !options.where_clause.is_empty() {
+            let predicate = render_index_predicate(&options.where_clause, DatabaseType::PostgreSQL)?;
+            sql.push_str(&format!(" WHERE {}", predicate));
+        }
 
         debug!("执行PostgreSQL索引创建: {}", sql);
 
@@ -180,15 +233,17 @@ pub(crate) async fn create_index(
 
 /// PostgreSQL表存在检查操作
 pub(crate) async fn table_exists(
-    adapter: &PostgresAdapter,
+    _adapter: &PostgresAdapter,
     connection: &DatabaseConnection,
     table: &str,
 ) -> QuickDbResult<bool> {
     if let DatabaseConnection::PostgreSQL(pool) = connection {
-        let sql = "SELECT table_name FROM information_schema.tables WHERE table_schema = 'public' AND table_name = $1";
+        let (table_schema, table_name) = split_qualified_name(table);
+        let sql = "SELECT table_name FROM information_schema.tables WHERE table_schema = COALESCE($1, 'public') AND table_name = $2";
 
         let rows = sqlx::query(sql)
-            .bind(table)
+            .bind(table_schema)
+            .bind(table_name)
             .fetch_all(pool)
             .await
             .map_err(|e| QuickDbError::QueryError {
@@ -205,73 +260,57 @@ pub(crate) async fn table_exists(
     }
 }
 
-/// PostgreSQL删除表操作
-pub(crate) async fn drop_table(
-    adapter: &PostgresAdapter,
+/// PostgreSQL表记录数估算
+///
+/// 读取 `pg_class.reltuples`（由 VACUUM / ANALYZE 更新）；表从未分析过时为 -1，回退到精确计数。
+/// 分区表的父表本身没有统计信息，累加各分区的估算值
+pub(crate) async fn count_estimate(
+    _adapter: &PostgresAdapter,
     connection: &DatabaseConnection,
     table: &str,
-) -> QuickDbResult<()> {
+) -> QuickDbResult<CountEstimate> {
     if let DatabaseConnection::PostgreSQL(pool) = connection {
-        let safe_table = quote_identifier(table, DatabaseType::PostgreSQL);
-        let sql = format!("DROP TABLE IF EXISTS {} CASCADE", safe_table);
+        let safe_table = quote_table_identifier(table, DatabaseType::PostgreSQL);
+        let sql = "SELECT CASE WHEN c.relkind = 'p' THEN \
+                       (SELECT SUM(GREATEST(child.reltuples, 0))::bigint FROM pg_inherits i \
+                        JOIN pg_class child ON child.oid = i.inhrelid WHERE i.inhparent = c.oid) \
+                   ELSE c.reltuples::bigint END \
+                   FROM pg_class c WHERE c.oid = to_regclass($1)";
 
-        debug!("执行PostgreSQL删除表SQL: {}", sql);
-
-        sqlx::query(&sql)
-            .execute(pool)
+        let estimate: Option<Option<i64>> = sqlx::query_scalar(sql)
+            .bind(&safe_table)
+            .fetch_optional(pool)
             .await
             .map_err(|e| QuickDbError::QueryError {
-                message: format!("删除PostgreSQL表失败: {}", e),
+                message: format!("估算PostgreSQL表记录数失败: {}", e),
             })?;
 
-        // 验证表是否真的被删除了
-        let check_sql = "SELECT table_name FROM information_schema.tables WHERE table_schema = 'public' AND table_name = $1";
-        let check_rows = sqlx::query(check_sql)
-            .bind(table)
-            .fetch_all(pool)
-            .await
-            .map_err(|e| QuickDbError::QueryError {
-                message: format!("验证表删除失败: {}", e),
-            })?;
-
-        let still_exists = !check_rows.is_empty();
-        debug!("🔍 删除后验证表 {} 是否存在: {}", table, still_exists);
-
-        debug!("成功删除PostgreSQL表: {}", table);
-        Ok(())
-    } else {
-        Err(QuickDbError::ConnectionError {
-            message: "连接类型不匹配，期望PostgreSQL连接".to_string(),
-        })
-    }
-}
-
-/// PostgreSQL获取服务器版本操作
-pub(crate) async fn get_server_version(
-    adapter: &PostgresAdapter,
-    connection: &DatabaseConnection,
-) -> QuickDbResult<String> {
-    if let DatabaseConnection::PostgreSQL(pool) = connection {
-        let sql = "SELECT version()";
-
-        debug!("执行PostgreSQL版本查询SQL: {}", sql);
+        let estimate = match estimate {
+            Some(estimate) => estimate,
+            None => {
+                return Err(QuickDbError::TableNotExistError {
+                    table: table.to_string(),
+                    message: format!("PostgreSQL表 '{}' 不存在", table),
+                });
+            }
+        };
+        if let Some(count) = estimate.filter(|count| *count >= 0) {
+            return Ok(CountEstimate::approximate(count as u64));
+        }
 
-        let row = sqlx::query(sql)
+        debug!("PostgreSQL表 {} 尚无统计信息，回退到精确计数", table);
+        let sql = format!("SELECT COUNT(*) FROM {}", safe_table);
+        let count: i64 = sqlx::query_scalar(&sql)
             .fetch_one(pool)
             .await
             .map_err(|e| QuickDbError::QueryError {
-                message: format!("查询PostgreSQL版本失败: {}", e),
+                message: format!("统计PostgreSQL表记录数失败: {}", e),
             })?;
-
-        let version: String = row.try_get(0).map_err(|e| QuickDbError::QueryError {
-            message: format!("解析PostgreSQL版本结果失败: {}", e),
-        })?;
-
-        debug!("成功获取PostgreSQL版本: {}", version);
-        Ok(version)
+        Ok(CountEstimate::exact(count as u64))
     } else {
         Err(QuickDbError::ConnectionError {
             message: "连接类型不匹配，期望PostgreSQL连接".to_string(),
         })
     }
 }
+
@@ -1,16 +1,25 @@
 //! PostgreSQL适配器辅助工具函数
 
 use crate::adapter::postgres::PostgresAdapter;
+use crate::adapter::statement_cache::track_statement;
+use crate::adapter::utils::sqlx_unique_violation;
 use crate::error::{QuickDbError, QuickDbResult};
-use crate::types::DataValue;
+use crate::types::{DataValue, DatabaseType};
 use rat_logger::debug;
-use serde_json::Value;
 use sqlx::{Column, Row, TypeInfo};
 use std::collections::HashMap;
 
+/// 格式化 sqlx 错误，数据库错误附加 SQLSTATE，供重试策略按错误码识别瞬时错误
+pub(crate) fn pg_error_message(error: &sqlx::Error) -> String {
+    match error.as_database_error().and_then(|db_err| db_err.code()) {
+        Some(code) => format!("{} (SQLSTATE {})", error, code),
+        None => error.to_string(),
+    }
+}
+
 /// 将PostgreSQL行转换为DataValue映射
 pub(crate) fn row_to_data_map(
-    adapter: &PostgresAdapter,
+    _adapter: &PostgresAdapter,
     row: &sqlx::postgres::PgRow,
 ) -> QuickDbResult<HashMap<String, DataValue>> {
     let mut map = HashMap::new();
@@ -154,7 +163,7 @@ pub(crate) fn row_to_data_map(
                 {
                     match val {
                         Some(dt) => {
-                            DataValue::DateTime(dt.with_timezone(&chrono::FixedOffset::east(0)))
+                            DataValue::DateTime(dt.with_timezone(&chrono::FixedOffset::east_opt(0).unwrap()))
                         }
                         None => DataValue::Null,
                     }
@@ -164,7 +173,7 @@ pub(crate) fn row_to_data_map(
                         Some(ndt) => {
                             // 将NaiveDateTime转换为UTC时间
                             let utc_dt = ndt.and_utc();
-                            DataValue::DateTime(utc_dt.with_timezone(&chrono::FixedOffset::east(0)))
+                            DataValue::DateTime(utc_dt.with_timezone(&chrono::FixedOffset::east_opt(0).unwrap()))
                         }
                         None => DataValue::Null,
                     }
@@ -211,30 +220,22 @@ pub(crate) fn row_to_data_map(
     Ok(map)
 }
 
-/// 将PostgreSQL行转换为JSON值（保留用于向后兼容）
-pub(crate) fn row_to_json(
-    adapter: &PostgresAdapter,
-    row: &sqlx::postgres::PgRow,
-) -> QuickDbResult<Value> {
-    let data_map = row_to_data_map(adapter, row)?;
-    let mut json_map = serde_json::Map::new();
-
-    for (key, value) in data_map {
-        json_map.insert(key, value.to_json_value());
-    }
-
-    Ok(Value::Object(json_map))
-}
-
-/// 执行查询并返回结果
-pub(crate) async fn execute_query(
+/// 执行查询并返回结果（可在连接池或事务上执行）
+pub(crate) async fn execute_query<'c, E>(
     adapter: &PostgresAdapter,
-    pool: &sqlx::Pool<sqlx::Postgres>,
+    pool: E,
     sql: &str,
     params: &[DataValue],
     table: &str,
-) -> QuickDbResult<Vec<DataValue>> {
-    let mut query = sqlx::query(sql);
+    alias: Option<&str>,
+) -> QuickDbResult<Vec<DataValue>>
+where
+    E: sqlx::PgExecutor<'c>,
+{
+    crate::statement_log::log_sql(DatabaseType::PostgreSQL, sql, params);
+    // alias 为 None 时不纳入预编译语句缓存统计，沿用 sqlx 默认的持久化预编译
+    let mut query =
+        sqlx::query(sql).persistent(alias.is_none_or(|alias| track_statement(alias, sql)));
 
     // 绑定参数
     for param in params {
@@ -257,7 +258,7 @@ pub(crate) async fn execute_query(
             DataValue::Bool(b) => query.bind(*b),
             DataValue::DateTime(dt) => query.bind(*dt),
             DataValue::DateTimeUTC(dt) => {
-                query.bind(dt.with_timezone(&chrono::FixedOffset::east(0)))
+                query.bind(dt.with_timezone(&chrono::FixedOffset::east_opt(0).unwrap()))
             }
             DataValue::Uuid(uuid) => query.bind(*uuid),
             DataValue::Json(json) => query.bind(json),
@@ -305,6 +306,9 @@ pub(crate) async fn execute_query(
         .fetch_all(pool)
         .await
         .map_err(|e| {
+            if let Some(unique_error) = sqlx_unique_violation(&e, table) {
+                return unique_error;
+            }
             // 使用错误码检测表不存在，避免受语言影响
             // 42P01 是 PostgreSQL 中 "table does not exist" 的标准错误码
             if let Some(db_err) = e.as_database_error() {
@@ -325,7 +329,7 @@ pub(crate) async fn execute_query(
                 }
             }
             QuickDbError::QueryError {
-                message: format!("执行PostgreSQL查询失败: {}", e),
+                message: format!("执行PostgreSQL查询失败: {}", pg_error_message(&e)),
             }
         })?;
 
@@ -338,15 +342,55 @@ pub(crate) async fn execute_query(
     Ok(results)
 }
 
-/// 执行更新操作
-pub(crate) async fn execute_update(
+/// 开启事务并以 `SET LOCAL statement_timeout` 设置本事务内的语句超时
+pub(crate) async fn begin_with_statement_timeout(
+    pool: &sqlx::PgPool,
+    timeout: std::time::Duration,
+) -> QuickDbResult<sqlx::Transaction<'static, sqlx::Postgres>> {
+    let mut tx = pool.begin().await.map_err(|e| QuickDbError::QueryError {
+        message: format!("开启PostgreSQL事务失败: {}", pg_error_message(&e)),
+    })?;
+    sqlx::query(&format!("SET LOCAL statement_timeout = {}", timeout.as_millis().max(1)))
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| QuickDbError::QueryError {
+            message: format!("设置PostgreSQL语句超时失败: {}", pg_error_message(&e)),
+        })?;
+    Ok(tx)
+}
+
+/// 执行更新语句，当前操作设置了超时时由服务端按语句超时终止
+pub(crate) async fn execute_update_with_timeout(
     adapter: &PostgresAdapter,
-    pool: &sqlx::Pool<sqlx::Postgres>,
+    pool: &sqlx::PgPool,
     sql: &str,
     params: &[DataValue],
     table: &str,
 ) -> QuickDbResult<u64> {
-    rat_logger::debug!("🔍 PostgreSQL execute_update: SQL={}", sql);
+    let Some(timeout) = crate::pool::statement_timeout() else {
+        return execute_update(adapter, pool, sql, params, table).await;
+    };
+
+    let mut tx = begin_with_statement_timeout(pool, timeout).await?;
+    let affected = execute_update(adapter, &mut *tx, sql, params, table).await?;
+    tx.commit().await.map_err(|e| QuickDbError::QueryError {
+        message: format!("提交PostgreSQL事务失败: {}", pg_error_message(&e)),
+    })?;
+    Ok(affected)
+}
+
+/// 执行更新操作（可在连接池或事务上执行）
+pub(crate) async fn execute_update<'c, E>(
+    _adapter: &PostgresAdapter,
+    pool: E,
+    sql: &str,
+    params: &[DataValue],
+    table: &str,
+) -> QuickDbResult<u64>
+where
+    E: sqlx::PgExecutor<'c>,
+{
+    crate::statement_log::log_sql(DatabaseType::PostgreSQL, sql, params);
     let mut query = sqlx::query(sql);
 
     // 绑定参数
@@ -371,7 +415,7 @@ pub(crate) async fn execute_update(
             DataValue::Bool(b) => query.bind(*b),
             DataValue::DateTime(dt) => query.bind(*dt),
             DataValue::DateTimeUTC(dt) => {
-                query.bind(dt.with_timezone(&chrono::FixedOffset::east(0)))
+                query.bind(dt.with_timezone(&chrono::FixedOffset::east_opt(0).unwrap()))
             }
             DataValue::Uuid(uuid) => query.bind(*uuid),
             DataValue::Json(json) => query.bind(json),
@@ -400,7 +444,8 @@ pub(crate) async fn execute_update(
                     }
                 }
             }
-            DataValue::Object(obj) => query.bind(serde_json::to_value(obj).unwrap_or_default()),
+            // 使用 to_json_value() 避免序列化时包含类型标签
+            DataValue::Object(obj) => query.bind(DataValue::Object(obj.clone()).to_json_value()),
             DataValue::Vector(vec) => {
                 // pgvector 格式字符串: "[1.0,2.0,3.0]"
                 let pg_str = format!("[{}]", vec.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(","));
@@ -413,6 +458,9 @@ pub(crate) async fn execute_update(
         .execute(pool)
         .await
         .map_err(|e| {
+            if let Some(unique_error) = sqlx_unique_violation(&e, table) {
+                return unique_error;
+            }
             // 使用错误码检测表不存在，避免受语言影响
             // 42P01 是 PostgreSQL 中 "table does not exist" 的标准错误码
             if let Some(db_err) = e.as_database_error() {
@@ -433,7 +481,7 @@ pub(crate) async fn execute_update(
                 }
             }
             QuickDbError::QueryError {
-                message: format!("执行PostgreSQL更新失败: {}", e),
+                message: format!("执行PostgreSQL更新失败: {}", pg_error_message(&e)),
             }
         })?;
 
@@ -4,7 +4,8 @@
 
 use crate::error::{QuickDbError, QuickDbResult};
 use crate::types::DataValue;
-use std::collections::HashMap;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
 
 /// PostgreSQL JSON字段查询策略选择和SQL生成
 /// 根据查询值的类型和内容，选择合适的JSON查询策略
@@ -334,7 +335,7 @@ pub fn convert_to_jsonb_value(value: &DataValue) -> QuickDbResult<DataValue> {
                 });
             }
             // 转换为base64字符串
-            Ok(DataValue::String(format!("\"{}\"", base64::encode(bytes))))
+            Ok(DataValue::String(format!("\"{}\"", STANDARD.encode(bytes))))
         }
         // 向量数据：序列化为JSON数组字符串
         DataValue::Vector(vec) => {
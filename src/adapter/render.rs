@@ -0,0 +1,145 @@
+//! 写操作语句渲染
+//!
+//! 生成更新与删除的语句文本与绑定参数而不执行，SQL 与 MongoDB 文档的构建沿用各适配器的实现
+
+use crate::error::QuickDbResult;
+use crate::types::*;
+use std::collections::HashMap;
+
+/// 待渲染的写操作
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(
+    not(any(
+        feature = "sqlite-support",
+        feature = "postgres-support",
+        feature = "mysql-support",
+        feature = "mongodb-support"
+    )),
+    allow(dead_code)
+)]
+pub(crate) enum WriteStatement<'a> {
+    Update(&'a HashMap<String, DataValue>),
+    UpdateOperations(&'a [UpdateOperation]),
+    Delete,
+}
+
+/// 生成 SQL 的分支对三种 SQL 构建器相同，操作数组更新的 SET 子句与各适配器一致
+#[cfg(any(feature = "sqlite-support", feature = "postgres-support", feature = "mysql-support"))]
+macro_rules! render_sql {
+    ($builder:ty, $db_type:expr, $table:expr, $alias:expr, $conditions:expr, $write:expr) => {
+        match $write {
+            WriteStatement::Update(data) => <$builder>::new()
+                .update(super::transaction::TransactionWrite::process_data(data, $table, $alias))
+                .where_conditions($conditions)
+                .build($table, $alias),
+            WriteStatement::Delete => <$builder>::new().delete().where_conditions($conditions).build($table, $alias),
+            WriteStatement::UpdateOperations(operations) => {
+                let (mut sql, mut params) = operations_set_clause($db_type, $table, operations)?;
+                if !$conditions.is_empty() {
+                    let (where_clause, where_params) =
+                        <$builder>::new().build_where_clause_with_offset($conditions, params.len() + 1, $table, $alias)?;
+                    sql.push_str(&format!(" WHERE {}", where_clause));
+                    params.extend(where_params);
+                }
+                Ok((sql, params))
+            }
+        }
+    };
+}
+
+/// 渲染写操作，返回语句文本与绑定参数（MongoDB 的参数已内联到文档中）
+#[cfg_attr(
+    not(any(
+        feature = "sqlite-support",
+        feature = "postgres-support",
+        feature = "mysql-support",
+        feature = "mongodb-support"
+    )),
+    allow(unused_variables)
+)]
+pub(crate) fn render_write(
+    db_type: &DatabaseType,
+    table: &str,
+    alias: &str,
+    conditions: &[QueryConditionWithConfig],
+    write: WriteStatement<'_>,
+) -> QuickDbResult<(String, Vec<DataValue>)> {
+    match db_type {
+        #[cfg(feature = "sqlite-support")]
+        DatabaseType::SQLite => render_sql!(super::sqlite::SqlQueryBuilder, db_type, table, alias, conditions, write),
+        #[cfg(feature = "postgres-support")]
+        DatabaseType::PostgreSQL => {
+            render_sql!(super::postgres::query_builder::SqlQueryBuilder, db_type, table, alias, conditions, write)
+        }
+        #[cfg(feature = "mysql-support")]
+        DatabaseType::MySQL => render_sql!(super::mysql::query_builder::SqlQueryBuilder, db_type, table, alias, conditions, write),
+        #[cfg(feature = "mongodb-support")]
+        DatabaseType::MongoDB => render_mongo(table, alias, conditions, write),
+        #[allow(unreachable_patterns)]
+        _ => Err(crate::quick_error!(unsupported_db, format!("{:?}", db_type))),
+    }
+}
+
+/// 操作数组更新的 `UPDATE ... SET` 部分
+#[cfg(any(feature = "sqlite-support", feature = "postgres-support", feature = "mysql-support"))]
+fn operations_set_clause(db_type: &DatabaseType, table: &str, operations: &[UpdateOperation]) -> QuickDbResult<(String, Vec<DataValue>)> {
+    use crate::security::{quote_identifier, quote_table_identifier};
+
+    let mut set_clauses = Vec::with_capacity(operations.len());
+    let mut params = Vec::with_capacity(operations.len());
+    for operation in operations {
+        if matches!(operation.operation, UpdateOperator::Unset | UpdateOperator::Rename) {
+            set_clauses.extend(super::utils::structural_set_clauses(*db_type, operation)?);
+            continue;
+        }
+        let field = quote_identifier(&operation.field, *db_type);
+        params.push(operation.value.clone());
+        let value = match db_type {
+            DatabaseType::PostgreSQL => format!("${}", params.len()),
+            _ => "?".to_string(),
+        };
+        set_clauses.push(match operation.operation {
+            UpdateOperator::Set => format!("{} = {}", field, value),
+            UpdateOperator::Increment => format!("{} = {} + {}", field, field, value),
+            UpdateOperator::Decrement => format!("{} = {} - {}", field, field, value),
+            UpdateOperator::Multiply => format!("{} = {} * {}", field, field, value),
+            UpdateOperator::Divide => format!("{} = {} / {}", field, field, value),
+            UpdateOperator::PercentIncrease => format!("{} = {} * (1.0 + {}/100.0)", field, field, value),
+            UpdateOperator::PercentDecrease => format!("{} = {} * (1.0 - {}/100.0)", field, field, value),
+            UpdateOperator::Unset | UpdateOperator::Rename => unreachable!(),
+        });
+    }
+    let sql = format!(
+        "UPDATE {} SET {}",
+        quote_table_identifier(table, *db_type),
+        set_clauses.join(", ")
+    );
+    Ok((sql, params))
+}
+
+/// 渲染为 mongo shell 形式的命令
+#[cfg(feature = "mongodb-support")]
+fn render_mongo(
+    table: &str,
+    alias: &str,
+    conditions: &[QueryConditionWithConfig],
+    write: WriteStatement<'_>,
+) -> QuickDbResult<(String, Vec<DataValue>)> {
+    use super::mongodb::{query_builder::build_query_document, utils};
+
+    let adapter = super::MongoAdapter::new();
+    let filter = build_query_document(table, alias, conditions)?;
+    let command = match write {
+        WriteStatement::Update(data) => {
+            format!("db.{}.updateMany({}, {})", table, filter, utils::build_update_document(&adapter, data)?)
+        }
+        WriteStatement::UpdateOperations(operations) => format!(
+            "db.{}.updateMany({}, {})",
+            table,
+            filter,
+            utils::build_operations_update_document(&adapter, operations)?
+        ),
+        WriteStatement::Delete => format!("db.{}.deleteMany({})", table, filter),
+    };
+    Ok((command, Vec::new()))
+}
@@ -0,0 +1,129 @@
+//! 租用连接上的语句执行
+//!
+//! 在调用方独占的连接上执行原生 SQL，参数绑定与行转换沿用各适配器的实现
+
+use crate::error::QuickDbResult;
+use crate::types::*;
+
+/// 在 SQLite 连接上执行语句，返回受影响行数
+#[cfg(feature = "sqlite-support")]
+pub(crate) async fn execute_sqlite(
+    connection: &mut sqlx::SqliteConnection,
+    sql: &str,
+    params: &[DataValue],
+) -> QuickDbResult<u64> {
+    super::SqliteAdapter::new().execute_update(connection, "", sql, params).await
+}
+
+/// 在 SQLite 连接上执行查询
+#[cfg(feature = "sqlite-support")]
+pub(crate) async fn query_sqlite(
+    connection: &mut sqlx::SqliteConnection,
+    sql: &str,
+    params: &[DataValue],
+) -> QuickDbResult<Vec<DataValue>> {
+    super::SqliteAdapter::new().execute_query(connection, sql, params).await
+}
+
+/// 在 PostgreSQL 连接上执行语句，返回受影响行数
+#[cfg(feature = "postgres-support")]
+pub(crate) async fn execute_postgres(
+    connection: &mut sqlx::PgConnection,
+    sql: &str,
+    params: &[DataValue],
+) -> QuickDbResult<u64> {
+    super::postgres::utils::execute_update(&super::PostgresAdapter::new(), connection, sql, params, "").await
+}
+
+/// 在 PostgreSQL 连接上执行查询
+#[cfg(feature = "postgres-support")]
+pub(crate) async fn query_postgres(
+    connection: &mut sqlx::PgConnection,
+    sql: &str,
+    params: &[DataValue],
+) -> QuickDbResult<Vec<DataValue>> {
+    super::postgres::utils::execute_query(&super::PostgresAdapter::new(), connection, sql, params, "", None).await
+}
+
+/// 在 MySQL 连接上执行语句，返回受影响行数
+#[cfg(feature = "mysql-support")]
+pub(crate) async fn execute_mysql(
+    connection: &mut sqlx::MySqlConnection,
+    sql: &str,
+    params: &[DataValue],
+) -> QuickDbResult<u64> {
+    super::MysqlAdapter::new().execute_update(connection, sql, params, "").await
+}
+
+/// 在 MySQL 连接上执行查询
+#[cfg(feature = "mysql-support")]
+pub(crate) async fn query_mysql(
+    connection: &mut sqlx::MySqlConnection,
+    sql: &str,
+    params: &[DataValue],
+) -> QuickDbResult<Vec<DataValue>> {
+    super::MysqlAdapter::new().execute_query(connection, sql, params, "", None).await
+}
+
+/// 生成条件查询的 SELECT（三种 SQL 构建器相同，行级锁由支持的数据库追加）
+macro_rules! select_builder {
+    ($builder:ty, $conditions:expr, $options:expr) => {{
+        let fields = super::utils::projection_fields($options);
+        let mut builder = <$builder>::new().select(&fields).where_conditions($conditions);
+        for sort in &$options.sort {
+            builder = builder.order_by(&sort.field, sort.direction.clone());
+        }
+        if let Some(pagination) = &$options.pagination {
+            builder = builder.limit(pagination.limit).offset(pagination.skip);
+        }
+        builder
+    }};
+}
+
+/// 在 SQLite 连接上按条件查询（SQLite 没有行级锁，忽略 `options.lock`）
+#[cfg(feature = "sqlite-support")]
+pub(crate) async fn find_sqlite(
+    connection: &mut sqlx::SqliteConnection,
+    table: &str,
+    alias: &str,
+    conditions: &[QueryConditionWithConfig],
+    options: &QueryOptions,
+) -> QuickDbResult<Vec<DataValue>> {
+    let (sql, params) = select_builder!(super::sqlite::SqlQueryBuilder, conditions, options).build(table, alias)?;
+    super::SqliteAdapter::new().execute_query(connection, &sql, &params).await
+}
+
+/// 在 PostgreSQL 连接上按条件查询，`options.lock` 生成 `FOR UPDATE` / `FOR SHARE`
+#[cfg(feature = "postgres-support")]
+pub(crate) async fn find_postgres(
+    connection: &mut sqlx::PgConnection,
+    table: &str,
+    alias: &str,
+    conditions: &[QueryConditionWithConfig],
+    options: &QueryOptions,
+) -> QuickDbResult<Vec<DataValue>> {
+    let mut builder = select_builder!(super::postgres::query_builder::SqlQueryBuilder, conditions, options);
+    if let Some(lock) = options.lock {
+        builder = builder.lock(lock);
+    }
+    let (sql, params) = builder.build(table, alias)?;
+    super::postgres::utils::execute_query(&super::PostgresAdapter::new(), connection, &sql, &params, table, Some(alias))
+        .await
+}
+
+/// 在 MySQL 连接上按条件查询，`options.lock` 生成 `FOR UPDATE` / `LOCK IN SHARE MODE`
+#[cfg(feature = "mysql-support")]
+pub(crate) async fn find_mysql(
+    connection: &mut sqlx::MySqlConnection,
+    table: &str,
+    alias: &str,
+    conditions: &[QueryConditionWithConfig],
+    options: &QueryOptions,
+) -> QuickDbResult<Vec<DataValue>> {
+    let mut builder = select_builder!(super::mysql::query_builder::SqlQueryBuilder, conditions, options);
+    if let Some(lock) = options.lock {
+        builder = builder.lock(lock);
+    }
+    let (sql, params) = builder.build(table, alias)?;
+    super::MysqlAdapter::new().execute_query(connection, &sql, &params, table, Some(alias)).await
+}
@@ -16,6 +16,12 @@ pub struct SqliteAdapter {
         Arc<Mutex<HashMap<String, crate::stored_procedure::StoredProcedureInfo>>>,
 }
 
+impl Default for SqliteAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl SqliteAdapter {
     /// 创建新的SQLite适配器
     pub fn new() -> Self {
@@ -38,22 +44,12 @@ impl SqliteAdapter {
         locks
     }
 
-    /// 释放表创建锁
-    pub(crate) async fn release_table_lock(
-        &self,
-        table: &str,
-        mut locks: tokio::sync::MutexGuard<'_, HashMap<String, ()>>,
-    ) {
-        locks.remove(table);
-        debug!("🔓 释放表 {} 的创建锁", table);
-    }
-
     /// 生成存储过程的SQL语句（SQLite使用视图实现）
     pub async fn generate_stored_procedure_sql(
         &self,
         config: &crate::stored_procedure::StoredProcedureConfig,
     ) -> crate::error::QuickDbResult<String> {
-        use crate::security::{quote_dotted_identifier, quote_identifier};
+        use crate::security::{quote_dotted_identifier, quote_identifier, quote_table_identifier};
         use crate::stored_procedure::JoinType;
         let db_type = crate::types::DatabaseType::SQLite;
 
@@ -98,7 +94,7 @@ impl SqliteAdapter {
             joins.push(format!(
                 " {} {} ON {} = {}",
                 join_str,
-                quote_identifier(&join.table, db_type),
+                quote_table_identifier(&join.table, db_type),
                 quote_dotted_identifier(&join.local_field, db_type),
                 quote_dotted_identifier(&join.foreign_field, db_type),
             ));
@@ -108,7 +104,7 @@ impl SqliteAdapter {
         let sql_template = format!(
             "SELECT {SELECT_FIELDS} FROM {BASE_TABLE}{JOINS}{WHERE}{GROUP_BY}{HAVING}{ORDER_BY}{LIMIT}{OFFSET}",
             SELECT_FIELDS = fields.join(", "),
-            BASE_TABLE = quote_identifier(base_table, db_type),
+            BASE_TABLE = quote_table_identifier(base_table, db_type),
             JOINS = if joins.is_empty() {
                 "".to_string()
             } else {
@@ -218,56 +218,6 @@ pub fn row_to_data_map_with_metadata(
     Ok(map)
 }
 
-/// 将UTC时间戳转换为本地时间字符串
-///
-/// # 参数
-/// - `local_datetime_str`: 本地时间字符串，格式 "YYYY-MM-DD HH:MM:SS"
-/// - `timezone_offset`: 时区偏移，格式 "+08:00", "-05:00"
-///
-/// # 返回
-/// Unix时间戳
-pub fn convert_local_to_timestamp(
-    local_datetime_str: &str,
-    timezone_offset: &str,
-) -> QuickDbResult<i64> {
-    // 解析本地时间
-    let naive_dt = chrono::NaiveDateTime::parse_from_str(local_datetime_str, "%Y-%m-%d %H:%M:%S")
-        .map_err(|e| QuickDbError::ValidationError {
-        field: "datetime".to_string(),
-        message: format!("无效的日期时间格式 '{}': {}", local_datetime_str, e),
-    })?;
-
-    // 将本地时间视为UTC时间（应用时区偏移前的状态）
-    let utc_dt = naive_dt.and_utc();
-
-    // 解析时区偏移
-    let offset_seconds = crate::utils::timezone::parse_timezone_offset_to_seconds(timezone_offset)?;
-
-    // 本地时间 = UTC时间 + 时区偏移
-    // UTC时间 = 本地时间 - 时区偏移
-    let adjusted_dt = utc_dt - chrono::Duration::seconds(offset_seconds as i64);
-
-    Ok(adjusted_dt.timestamp())
-}
-
-/// 将RFC3339格式字符串转换为本地时间字符串
-///
-/// # 参数
-/// - `rfc3339_str`: RFC3339格式字符串，如 "2024-06-15T12:00:00+08:00"
-///
-/// # 返回
-/// 本地时间字符串，格式 "YYYY-MM-DD HH:MM:SS"
-pub fn convert_rfc3339_to_local(rfc3339_str: &str) -> QuickDbResult<String> {
-    let dt = chrono::DateTime::parse_from_rfc3339(rfc3339_str).map_err(|e| {
-        QuickDbError::ValidationError {
-            field: "datetime".to_string(),
-            message: format!("无效的RFC3339格式 '{}': {}", rfc3339_str, e),
-        }
-    })?;
-
-    Ok(dt.format("%Y-%m-%d %H:%M:%S").to_string())
-}
-
 /// 对UTC时间应用时区偏移，返回本地时间
 ///
 /// # 参数
@@ -283,7 +233,7 @@ fn apply_timezone_offset_to_utc(
     let offset_seconds = crate::utils::timezone::parse_timezone_offset_to_seconds(timezone_offset)?;
 
     // 检查时区偏移是否在有效范围内（-23:59 到 +23:59）
-    if offset_seconds < -86399 || offset_seconds > 86399 {
+    if !(-86399..=86399).contains(&offset_seconds) {
         return Err(QuickDbError::ValidationError {
             field: "timezone_offset".to_string(),
             message: format!(
@@ -293,5 +243,5 @@ fn apply_timezone_offset_to_utc(
         });
     }
 
-    Ok(utc_dt.with_timezone(&chrono::FixedOffset::east(offset_seconds)))
+    Ok(utc_dt.with_timezone(&chrono::FixedOffset::east_opt(offset_seconds).unwrap()))
 }
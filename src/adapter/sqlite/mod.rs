@@ -13,5 +13,4 @@ pub mod utils;
 
 // 重新导出主要的公共类型和结构体
 pub use adapter::SqliteAdapter;
-pub use data_conversion::{convert_local_to_timestamp, convert_rfc3339_to_local};
 pub use query_builder::SqlQueryBuilder;
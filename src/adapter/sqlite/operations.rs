@@ -1,13 +1,15 @@
 use super::SqlQueryBuilder;
-use crate::adapter::DatabaseAdapter;
+use crate::adapter::{BatchCreate, DatabaseAdapter};
+use crate::adapter::statement_cache::track_statement;
+use crate::adapter::utils::{apply_field_defaults, get_sequence_fields, sqlx_unique_violation};
 use crate::error::{QuickDbError, QuickDbResult};
-use crate::model::{FieldDefinition, FieldType};
+use crate::model::{FieldDefinition, IndexOptions};
 use crate::pool::DatabaseConnection;
-use crate::security::quote_identifier;
+use crate::security::{quote_identifier, quote_table_identifier};
 use crate::types::*;
 use async_trait::async_trait;
-use rat_logger::{debug, info, warn};
-use sqlx::{Column, Row, sqlite::SqliteRow};
+use rat_logger::{debug, info};
+use sqlx::Row;
 use std::collections::HashMap;
 
 use super::adapter::SqliteAdapter;
@@ -31,25 +33,17 @@ fn check_table_not_exist_error(error: &sqlx::Error, _table: &str) -> bool {
     false
 }
 
-#[async_trait]
-impl DatabaseAdapter for SqliteAdapter {
-    async fn create(
+impl SqliteAdapter {
+    /// 准备插入语句：必要时自动建表，填充字段默认值与序列值，生成 SQL 与参数
+    async fn prepare_insert(
         &self,
         connection: &DatabaseConnection,
+        pool: &sqlx::SqlitePool,
         table: &str,
         data: &HashMap<String, DataValue>,
         id_strategy: &IdStrategy,
         alias: &str,
-    ) -> QuickDbResult<DataValue> {
-        let pool = match connection {
-            DatabaseConnection::SQLite(pool) => pool,
-            _ => {
-                return Err(QuickDbError::ConnectionError {
-                    message: "Invalid connection type for SQLite".to_string(),
-                });
-            }
-        };
-
+    ) -> QuickDbResult<(String, Vec<DataValue>)> {
         // 自动建表逻辑：检查表是否存在，如果不存在则创建
         if !self.table_exists(connection, table).await? {
             // 获取表创建锁，防止重复创建
@@ -81,108 +75,228 @@ impl DatabaseAdapter for SqliteAdapter {
             // 锁会在这里自动释放（当 _lock 超出作用域时）
         }
 
-        let (sql, params) = SqlQueryBuilder::new()
-            .insert(data.clone())
-            .build(table, alias)?;
+        // 序列字段未提供值时从计数器表分配
+        let mut insert_data = data.clone();
+        apply_field_defaults(table, alias, DatabaseType::SQLite, &mut insert_data)?;
+        for (field, sequence_name) in get_sequence_fields(table, alias) {
+            if matches!(insert_data.get(&field), None | Some(DataValue::Null)) {
+                let value = sqlite_schema::next_sequence_value(pool, &sequence_name).await?;
+                insert_data.insert(field, DataValue::Int(value));
+            }
+        }
 
-        // 构建参数化查询，使用正确的参数顺序
-        let mut query = sqlx::query(&sql);
-        for param in &params {
-            match param {
-                DataValue::String(s) => {
-                    query = query.bind(s);
-                }
-                DataValue::Int(i) => {
-                    query = query.bind(i);
-                }
-                DataValue::UInt(u) => {
-                    // SQLite 不支持 u64 编码，转换为 i64 或字符串
-                    if *u <= i64::MAX as u64 {
-                        query = query.bind(*u as i64);
-                    } else {
-                        query = query.bind(u.to_string());
-                    }
-                }
-                DataValue::Float(f) => {
-                    query = query.bind(f);
-                }
-                DataValue::Bool(b) => {
-                    query = query.bind(b);
-                }
-                DataValue::Bytes(bytes) => {
-                    query = query.bind(bytes);
-                }
-                DataValue::DateTime(dt) => {
-                    query = query.bind(dt.timestamp());
-                }
-                DataValue::DateTimeUTC(dt) => {
-                    query = query.bind(dt.timestamp());
-                }
-                DataValue::Uuid(uuid) => {
-                    query = query.bind(uuid.to_string());
-                }
-                DataValue::Json(json) => {
-                    query = query.bind(json.to_string());
-                }
-                DataValue::Array(arr) => {
-                    // Array字段统一转为字符串数组存储
-                    let string_array: Result<Vec<String>, QuickDbError> = arr.iter().map(|item| {
-                            Ok(match item {
-                                DataValue::String(s) => s.clone(),
-                                DataValue::Int(i) => i.to_string(),
-                                DataValue::Float(f) => f.to_string(),
-                                DataValue::Uuid(uuid) => uuid.to_string(),
-                                _ => {
-                                    return Err(QuickDbError::ValidationError {
-                                        field: "array_field".to_string(),
-                                        message: format!("Array字段不支持该类型: {:?}，只支持String、Int、Float、Uuid类型", item),
-                                    });
-                                }
-                            })
-                        }).collect();
-                    let string_array = string_array?;
-                    let json = serde_json::to_string(&string_array).unwrap_or_default();
-                    query = query.bind(json);
-                }
-                DataValue::Object(_) => {
-                    let json = param.to_json_value().to_string();
-                    query = query.bind(json);
-                }
-                DataValue::Null => {
-                    query = query.bind(Option::<String>::None);
+        SqlQueryBuilder::new().insert(insert_data).build(table, alias)
+    }
+}
+
+/// 按参数类型绑定插入语句的参数
+fn bind_insert_params<'q>(
+    mut query: sqlx::query::Query<'q, sqlx::Sqlite, sqlx::sqlite::SqliteArguments<'q>>,
+    params: &'q [DataValue],
+) -> QuickDbResult<sqlx::query::Query<'q, sqlx::Sqlite, sqlx::sqlite::SqliteArguments<'q>>> {
+    for param in params {
+        match param {
+            DataValue::String(s) => {
+                query = query.bind(s);
+            }
+            DataValue::Int(i) => {
+                query = query.bind(i);
+            }
+            DataValue::UInt(u) => {
+                // SQLite 不支持 u64 编码，转换为 i64 或字符串
+                if *u <= i64::MAX as u64 {
+                    query = query.bind(*u as i64);
+                } else {
+                    query = query.bind(u.to_string());
                 }
             }
+            DataValue::Float(f) => {
+                query = query.bind(f);
+            }
+            DataValue::Bool(b) => {
+                query = query.bind(b);
+            }
+            DataValue::Bytes(bytes) => {
+                query = query.bind(bytes);
+            }
+            DataValue::DateTime(dt) => {
+                query = query.bind(dt.timestamp());
+            }
+            DataValue::DateTimeUTC(dt) => {
+                query = query.bind(dt.timestamp());
+            }
+            DataValue::Uuid(uuid) => {
+                query = query.bind(uuid.to_string());
+            }
+            DataValue::Json(json) => {
+                query = query.bind(json.to_string());
+            }
+            DataValue::Array(arr) => {
+                // Array字段统一转为字符串数组存储
+                let string_array: Result<Vec<String>, QuickDbError> = arr.iter().map(|item| {
+                        Ok(match item {
+                            DataValue::String(s) => s.clone(),
+                            DataValue::Int(i) => i.to_string(),
+                            DataValue::Float(f) => f.to_string(),
+                            DataValue::Uuid(uuid) => uuid.to_string(),
+                            _ => {
+                                return Err(QuickDbError::ValidationError {
+                                    field: "array_field".to_string(),
+                                    message: format!("Array字段不支持该类型: {:?}，只支持String、Int、Float、Uuid类型", item),
+                                });
+                            }
+                        })
+                    }).collect();
+                let string_array = string_array?;
+                let json = serde_json::to_string(&string_array).unwrap_or_default();
+                query = query.bind(json);
+            }
+            DataValue::Vector(vector) => {
+                // 向量以JSON数组存储
+                query = query.bind(serde_json::to_string(vector).unwrap_or_default());
+            }
+            DataValue::Object(_) => {
+                let json = param.to_json_value().to_string();
+                query = query.bind(json);
+            }
+            DataValue::Null => {
+                query = query.bind(Option::<String>::None);
+            }
         }
+    }
 
-        let result = query
+    Ok(query)
+}
+
+/// 插入失败的错误：唯一约束冲突单独识别
+fn insert_error(error: sqlx::Error, table: &str) -> QuickDbError {
+    sqlx_unique_violation(&error, table).unwrap_or_else(|| QuickDbError::QueryError {
+        message: format!("执行SQLite插入失败: {}", error),
+    })
+}
+
+/// 插入结果：优先返回数据中的ID字段，如果没有则使用SQLite的rowid
+fn insert_result(data: &HashMap<String, DataValue>, last_insert_rowid: i64, rows_affected: u64) -> DataValue {
+    // 根据插入的数据返回相应的ID
+    // 优先返回数据中的ID字段，如果没有则使用SQLite的rowid
+    if let Some(id_value) = data.get("id") {
+        id_value.clone()
+    } else if let Some(id_value) = data.get("_id") {
+        id_value.clone()
+    } else {
+        // 如果数据中没有ID字段，返回SQLite的自增ID
+        let id = last_insert_rowid;
+        if id > 0 {
+            DataValue::Int(id)
+        } else {
+            // 如果没有自增ID，返回包含详细信息的对象
+            let mut result_map = HashMap::new();
+            result_map.insert("id".to_string(), DataValue::Int(id));
+            result_map.insert(
+                "affected_rows".to_string(),
+                DataValue::Int(rows_affected as i64),
+            );
+            DataValue::Object(result_map)
+        }
+    }
+}
+
+#[async_trait]
+impl DatabaseAdapter for SqliteAdapter {
+    async fn create(
+        &self,
+        connection: &DatabaseConnection,
+        table: &str,
+        data: &HashMap<String, DataValue>,
+        id_strategy: &IdStrategy,
+        alias: &str,
+    ) -> QuickDbResult<DataValue> {
+        let pool = match connection {
+            DatabaseConnection::SQLite(pool) => pool,
+            _ => {
+                return Err(QuickDbError::ConnectionError {
+                    message: "Invalid connection type for SQLite".to_string(),
+                });
+            }
+        };
+
+        let (sql, params) = self.prepare_insert(connection, pool, table, data, id_strategy, alias).await?;
+
+        // 构建参数化查询，使用正确的参数顺序
+        crate::statement_log::log_sql(DatabaseType::SQLite, &sql, &params);
+        let result = bind_insert_params(sqlx::query(&sql), &params)?
             .execute(pool)
             .await
-            .map_err(|e| QuickDbError::QueryError {
-                message: format!("执行SQLite插入失败: {}", e),
-            })?;
+            .map_err(|e| insert_error(e, table))?;
 
-        // 根据插入的数据返回相应的ID
-        // 优先返回数据中的ID字段，如果没有则使用SQLite的rowid
-        if let Some(id_value) = data.get("id") {
-            Ok(id_value.clone())
-        } else if let Some(id_value) = data.get("_id") {
-            Ok(id_value.clone())
-        } else {
-            // 如果数据中没有ID字段，返回SQLite的自增ID
-            let id = result.last_insert_rowid();
-            if id > 0 {
-                Ok(DataValue::Int(id))
-            } else {
-                // 如果没有自增ID，返回包含详细信息的对象
-                let mut result_map = HashMap::new();
-                result_map.insert("id".to_string(), DataValue::Int(id));
-                result_map.insert(
-                    "affected_rows".to_string(),
-                    DataValue::Int(result.rows_affected() as i64),
-                );
-                Ok(DataValue::Object(result_map))
+        Ok(insert_result(data, result.last_insert_rowid(), result.rows_affected()))
+    }
+
+    /// 在一个事务中依次插入，单条插入失败只影响该条记录；提交失败时所有记录均返回错误
+    async fn create_batch(
+        &self,
+        connection: &DatabaseConnection,
+        items: &[BatchCreate],
+    ) -> Vec<QuickDbResult<DataValue>> {
+        let DatabaseConnection::SQLite(pool) = connection else {
+            return items
+                .iter()
+                .map(|_| {
+                    Err(QuickDbError::ConnectionError {
+                        message: "Invalid connection type for SQLite".to_string(),
+                    })
+                })
+                .collect();
+        };
+
+        // 建表与序列分配使用连接池中的其他连接，必须在开启写事务之前完成
+        let mut prepared = Vec::with_capacity(items.len());
+        for item in items {
+            prepared.push(
+                self.prepare_insert(connection, pool, &item.table, &item.data, &item.id_strategy, &item.alias)
+                    .await,
+            );
+        }
+
+        let mut tx = match pool.begin().await {
+            Ok(tx) => tx,
+            Err(e) => {
+                let message = format!("开启SQLite批量写入事务失败: {}", e);
+                return items
+                    .iter()
+                    .map(|_| Err(QuickDbError::QueryError { message: message.clone() }))
+                    .collect();
             }
+        };
+
+        let mut results = Vec::with_capacity(items.len());
+        for (item, statement) in items.iter().zip(prepared) {
+            let result = match statement {
+                Ok((sql, params)) => {
+                    crate::statement_log::log_sql(DatabaseType::SQLite, &sql, &params);
+                    match bind_insert_params(sqlx::query(&sql), &params) {
+                        Ok(query) => query
+                            .execute(&mut *tx)
+                            .await
+                            .map(|done| insert_result(&item.data, done.last_insert_rowid(), done.rows_affected()))
+                            .map_err(|e| insert_error(e, &item.table)),
+                        Err(e) => Err(e),
+                    }
+                }
+                Err(e) => Err(e),
+            };
+            results.push(result);
         }
+
+        if let Err(e) = tx.commit().await {
+            let message = format!("提交SQLite批量写入事务失败: {}", e);
+            return results
+                .into_iter()
+                .map(|result| result.and(Err(QuickDbError::QueryError { message: message.clone() })))
+                .collect();
+        }
+        debug!("SQLite批量写入完成: 记录数={}", items.len());
+        results
     }
 
     async fn upsert(
@@ -245,6 +359,7 @@ impl DatabaseAdapter for SqliteAdapter {
             .build(table, alias)?;
 
         // 构建参数化查询，使用正确的参数顺序
+        crate::statement_log::log_sql(DatabaseType::SQLite, &sql, &params);
         let mut query = sqlx::query(&sql);
         for param in &params {
             match param {
@@ -303,6 +418,10 @@ impl DatabaseAdapter for SqliteAdapter {
                     let json = serde_json::to_string(&string_array).unwrap_or_default();
                     query = query.bind(json);
                 }
+                DataValue::Vector(vector) => {
+                    // 向量以JSON数组存储
+                    query = query.bind(serde_json::to_string(vector).unwrap_or_default());
+                }
                 DataValue::Object(_) => {
                     let json = param.to_json_value().to_string();
                     query = query.bind(json);
@@ -316,8 +435,10 @@ impl DatabaseAdapter for SqliteAdapter {
         let row = query
             .fetch_one(pool)
             .await
-            .map_err(|e| QuickDbError::QueryError {
-                message: format!("执行SQLite upsert失败: {}", e),
+            .map_err(|e| {
+                sqlx_unique_violation(&e, table).unwrap_or_else(|| QuickDbError::QueryError {
+                    message: format!("执行SQLite upsert失败: {}", e),
+                })
             })?;
 
         // 根据插入的数据返回相应的ID
@@ -374,11 +495,12 @@ impl DatabaseAdapter for SqliteAdapter {
             }
         };
         {
-            let safe_table = quote_identifier(table, DatabaseType::SQLite);
+            let safe_table = quote_table_identifier(table, DatabaseType::SQLite);
             let safe_id = quote_identifier("id", DatabaseType::SQLite);
             let sql = format!("SELECT * FROM {} WHERE {} = ? LIMIT 1", safe_table, safe_id);
 
-            let mut query = sqlx::query(&sql);
+            crate::statement_log::log_sql(DatabaseType::SQLite, &sql, std::slice::from_ref(id));
+            let mut query = sqlx::query(&sql).persistent(track_statement(alias, &sql));
             match id {
                 DataValue::String(s) => {
                     query = query.bind(s);
@@ -428,6 +550,55 @@ impl DatabaseAdapter for SqliteAdapter {
         }
     }
 
+    async fn exists_by_id(
+        &self,
+        connection: &DatabaseConnection,
+        table: &str,
+        id: &DataValue,
+        alias: &str,
+    ) -> QuickDbResult<bool> {
+        let pool = match connection {
+            DatabaseConnection::SQLite(pool) => pool,
+            _ => {
+                return Err(QuickDbError::ConnectionError {
+                    message: "Invalid connection type for SQLite".to_string(),
+                });
+            }
+        };
+
+        let safe_table = quote_table_identifier(table, DatabaseType::SQLite);
+        let safe_id = quote_identifier("id", DatabaseType::SQLite);
+        let sql = format!("SELECT 1 FROM {} WHERE {} = ? LIMIT 1", safe_table, safe_id);
+
+        crate::statement_log::log_sql(DatabaseType::SQLite, &sql, std::slice::from_ref(id));
+        let mut query = sqlx::query(&sql).persistent(track_statement(alias, &sql));
+        match id {
+            DataValue::String(s) => {
+                query = query.bind(s);
+            }
+            DataValue::Int(i) => {
+                query = query.bind(i);
+            }
+            _ => {
+                query = query.bind(id.to_string());
+            }
+        }
+
+        let row = query.fetch_optional(pool).await.map_err(|e| {
+            if check_table_not_exist_error(&e, table) {
+                QuickDbError::TableNotExistError {
+                    table: table.to_string(),
+                    message: format!("SQLite表 '{}' 不存在", table),
+                }
+            } else {
+                QuickDbError::QueryError {
+                    message: format!("执行SQLite存在性查询失败: {}", e),
+                }
+            }
+        })?;
+        Ok(row.is_some())
+    }
+
     async fn find_with_cache_control(
         &self,
         connection: &DatabaseConnection,
@@ -463,6 +634,20 @@ impl DatabaseAdapter for SqliteAdapter {
         options: &QueryOptions,
         alias: &str,
         bypass_cache: bool,
+    ) -> QuickDbResult<Vec<DataValue>> {
+        let condition_groups: Vec<QueryConditionGroupWithConfig> =
+            condition_groups.iter().cloned().map(Into::into).collect();
+        self.find_with_groups_with_cache_control_and_config(connection, table, &condition_groups, options, alias, bypass_cache).await
+    }
+
+    async fn find_with_groups_with_cache_control_and_config(
+        &self,
+        connection: &DatabaseConnection,
+        table: &str,
+        condition_groups: &[QueryConditionGroupWithConfig],
+        options: &QueryOptions,
+        alias: &str,
+        _bypass_cache: bool,
     ) -> QuickDbResult<Vec<DataValue>> {
         let pool = match connection {
             DatabaseConnection::SQLite(pool) => pool,
@@ -474,8 +659,9 @@ impl DatabaseAdapter for SqliteAdapter {
         };
         {
             let (sql, params) = {
+                let fields = crate::adapter::utils::projection_fields(options);
                 let mut builder = SqlQueryBuilder::new()
-                    .select(&["*"])
+                    .select(&fields)
                     .where_condition_groups(condition_groups)
                     .limit(options.pagination.as_ref().map(|p| p.limit).unwrap_or(1000))
                     .offset(options.pagination.as_ref().map(|p| p.skip).unwrap_or(0));
@@ -485,9 +671,13 @@ impl DatabaseAdapter for SqliteAdapter {
                 builder.build(table, alias)?
             };
 
+            if options.lock.is_some() {
+                debug!("SQLite没有行级锁，忽略查询选项中的lock");
+            }
             debug!("执行SQLite条件组合查询: {}", sql);
 
-            let mut query = sqlx::query(&sql);
+            crate::statement_log::log_sql(DatabaseType::SQLite, &sql, &params);
+            let mut query = sqlx::query(&sql).persistent(track_statement(alias, &sql));
             for param in &params {
                 match param {
                     DataValue::String(s) => {
@@ -556,40 +746,6 @@ impl DatabaseAdapter for SqliteAdapter {
         }
     }
 
-    async fn find_with_groups_with_cache_control_and_config(
-        &self,
-        connection: &DatabaseConnection,
-        table: &str,
-        condition_groups: &[QueryConditionGroupWithConfig],
-        options: &QueryOptions,
-        alias: &str,
-        bypass_cache: bool,
-    ) -> QuickDbResult<Vec<DataValue>> {
-        // SQLite 不支持 case_insensitive，将 QueryConditionGroupWithConfig 转换回 QueryConditionGroup
-        fn convert_group(group: &QueryConditionGroupWithConfig) -> QueryConditionGroup {
-            match group {
-                QueryConditionGroupWithConfig::Single(c) => {
-                    QueryConditionGroup::Single(QueryCondition {
-                        field: c.field.clone(),
-                        operator: c.operator.clone(),
-                        value: c.value.clone(),
-                    })
-                }
-                QueryConditionGroupWithConfig::GroupWithConfig { operator, conditions } => {
-                    QueryConditionGroup::Group {
-                        operator: operator.clone(),
-                        conditions: conditions.iter().map(convert_group).collect(),
-                    }
-                }
-            }
-        }
-
-        let simple_groups: Vec<QueryConditionGroup> =
-            condition_groups.iter().map(convert_group).collect();
-
-        self.find_with_groups_with_cache_control(connection, table, &simple_groups, options, alias, bypass_cache).await
-    }
-
     async fn find(
         &self,
         connection: &DatabaseConnection,
@@ -653,30 +809,7 @@ impl DatabaseAdapter for SqliteAdapter {
                 .where_conditions(conditions)
                 .build(table, alias)?;
 
-            // 构建包含实际参数的完整SQL用于调试
-            let mut complete_sql = sql.clone();
-            for param in &params {
-                let param_value = match param {
-                    DataValue::String(s) => format!("'{}'", s.replace('\'', "''")),
-                    DataValue::Int(i) => i.to_string(),
-                    DataValue::Float(f) => f.to_string(),
-                    DataValue::Bool(b) => {
-                        if *b {
-                            "1".to_string()
-                        } else {
-                            "0".to_string()
-                        }
-                    }
-                    DataValue::DateTime(dt) => dt.timestamp().to_string(),
-                    DataValue::DateTimeUTC(dt) => dt.timestamp().to_string(),
-                    DataValue::Null => "NULL".to_string(),
-                    _ => format!("'{}'", param.to_string()),
-                };
-                complete_sql = complete_sql.replacen('?', &param_value, 1);
-            }
-
-            debug!("[SQLite] Complete SQL: {}", complete_sql);
-
+            crate::statement_log::log_sql(DatabaseType::SQLite, &sql, &params);
             let mut query = sqlx::query(&sql);
             for param in &params {
                 match param {
@@ -713,6 +846,8 @@ impl DatabaseAdapter for SqliteAdapter {
                             table: table.to_string(),
                             message: format!("SQLite表 '{}' 不存在", table),
                         }
+                    } else if let Some(unique_error) = sqlx_unique_violation(&e, table) {
+                        unique_error
                     } else {
                         QuickDbError::QueryError {
                             message: format!("执行SQLite更新失败: {}", e),
@@ -745,6 +880,17 @@ impl DatabaseAdapter for SqliteAdapter {
         Ok(affected_rows > 0)
     }
 
+    async fn update_by_id_returning(
+        &self,
+        connection: &DatabaseConnection,
+        table: &str,
+        id: &DataValue,
+        data: &HashMap<String, DataValue>,
+        alias: &str,
+    ) -> QuickDbResult<Option<DataValue>> {
+        sqlite_query::update_by_id_returning(self, connection, table, id, data, alias).await
+    }
+
     async fn update_with_operations(
         &self,
         connection: &DatabaseConnection,
@@ -829,6 +975,9 @@ impl DatabaseAdapter for SqliteAdapter {
                     ));
                     params.push(operation.value.clone());
                 }
+                crate::types::UpdateOperator::Unset | crate::types::UpdateOperator::Rename => {
+                    set_clauses.extend(crate::adapter::utils::structural_set_clauses(DatabaseType::SQLite, operation)?);
+                }
             }
         }
 
@@ -839,12 +988,12 @@ impl DatabaseAdapter for SqliteAdapter {
             });
         }
 
-        let safe_table = quote_identifier(table, DatabaseType::SQLite);
+        let safe_table = quote_table_identifier(table, DatabaseType::SQLite);
         let mut sql = format!("UPDATE {} SET {}", safe_table, set_clauses.join(", "));
 
         // 添加WHERE条件
         if !conditions.is_empty() {
-            let (where_clause, mut where_params) = SqlQueryBuilder::new()
+            let (where_clause, where_params) = SqlQueryBuilder::new()
                 .build_where_clause_with_offset(conditions, params.len() + 1, table, alias)?;
 
             sql.push_str(&format!(" WHERE {}", where_clause));
@@ -853,7 +1002,7 @@ impl DatabaseAdapter for SqliteAdapter {
 
         debug!("执行SQLite操作更新: {}", sql);
 
-        self.execute_update(pool, &sql, &params).await
+        self.execute_update(pool, table, &sql, &params).await
     }
 
     async fn delete(
@@ -863,7 +1012,6 @@ impl DatabaseAdapter for SqliteAdapter {
         conditions: &[QueryConditionWithConfig],
         alias: &str,
     ) -> QuickDbResult<u64> {
-        // 将 QueryConditionWithConfig 转换回 QueryCondition（忽略 case_insensitive）
         sqlite_query::delete(self, connection, table, conditions, alias).await
     }
 
@@ -877,6 +1025,33 @@ impl DatabaseAdapter for SqliteAdapter {
         sqlite_query::delete_by_id(self, connection, table, id, alias).await
     }
 
+    async fn delete_by_id_returning(
+        &self,
+        connection: &DatabaseConnection,
+        table: &str,
+        id: &DataValue,
+        alias: &str,
+    ) -> QuickDbResult<Option<DataValue>> {
+        sqlite_query::delete_by_id_returning(self, connection, table, id, alias).await
+    }
+
+    async fn bulk_write(
+        &self,
+        connection: &DatabaseConnection,
+        table: &str,
+        ops: &[BulkOp],
+        mode: BulkWriteMode,
+        _id_strategy: &IdStrategy,
+        alias: &str,
+    ) -> QuickDbResult<BulkWriteReport> {
+        let DatabaseConnection::SQLite(pool) = connection else {
+            return Err(QuickDbError::ConnectionError {
+                message: "Invalid connection type for SQLite".to_string(),
+            });
+        };
+        crate::adapter::transaction::execute_sqlite_bulk(pool, table, alias, ops, mode).await
+    }
+
     async fn count(
         &self,
         connection: &DatabaseConnection,
@@ -905,7 +1080,7 @@ impl DatabaseAdapter for SqliteAdapter {
         id_strategy: &IdStrategy,
         alias: &str,
     ) -> QuickDbResult<()> {
-        sqlite_schema::create_table(self, connection, table, fields, id_strategy).await
+        sqlite_schema::create_table(self, connection, table, fields, id_strategy, alias).await
     }
 
     async fn create_index(
@@ -915,8 +1090,18 @@ impl DatabaseAdapter for SqliteAdapter {
         index_name: &str,
         fields: &[String],
         unique: bool,
+        options: &IndexOptions,
     ) -> QuickDbResult<()> {
-        sqlite_schema::create_index(self, connection, table, index_name, fields, unique).await
+        sqlite_schema::create_index(
+            self,
+            connection,
+            table,
+            index_name,
+            fields,
+            unique,
+            options,
+        )
+        .await
     }
 
     async fn table_exists(
@@ -927,6 +1112,14 @@ impl DatabaseAdapter for SqliteAdapter {
         sqlite_schema::table_exists(self, connection, table).await
     }
 
+    async fn count_estimate(
+        &self,
+        connection: &DatabaseConnection,
+        table: &str,
+    ) -> QuickDbResult<CountEstimate> {
+        sqlite_schema::count_estimate(self, connection, table).await
+    }
+
     async fn drop_table(&self, connection: &DatabaseConnection, table: &str) -> QuickDbResult<()> {
         sqlite_schema::drop_table(self, connection, table).await
     }
@@ -940,7 +1133,7 @@ impl DatabaseAdapter for SqliteAdapter {
         connection: &DatabaseConnection,
         config: &crate::stored_procedure::StoredProcedureConfig,
     ) -> QuickDbResult<crate::stored_procedure::StoredProcedureCreateResult> {
-        use crate::stored_procedure::{JoinType, StoredProcedureCreateResult};
+        use crate::stored_procedure::StoredProcedureCreateResult;
 
         debug!("开始创建SQLite存储过程: {}", config.procedure_name);
 
@@ -952,7 +1145,7 @@ impl DatabaseAdapter for SqliteAdapter {
                 message: format!("存储过程配置验证失败: {}", e),
             })?;
 
-        let pool = match connection {
+        let _pool = match connection {
             DatabaseConnection::SQLite(pool) => pool,
             _ => {
                 return Err(crate::error::QuickDbError::ConnectionError {
@@ -982,7 +1175,7 @@ impl DatabaseAdapter for SqliteAdapter {
         }
 
         // 2. 生成SQL存储过程模板（带占位符）
-        let sql_template = self.generate_stored_procedure_sql(&config).await?;
+        let sql_template = self.generate_stored_procedure_sql(config).await?;
         debug!("生成存储过程SQL模板: {}", sql_template);
 
         // 3. 将存储过程信息存储到适配器映射表中（SQLite不需要执行创建SQL）
@@ -1007,16 +1200,25 @@ impl DatabaseAdapter for SqliteAdapter {
         })
     }
 
+    /// 删除存储过程（从适配器映射表中移除模板）
+    async fn drop_stored_procedure(
+        &self,
+        _connection: &DatabaseConnection,
+        procedure_name: &str,
+    ) -> QuickDbResult<bool> {
+        let removed = self.stored_procedures.lock().await.remove(procedure_name).is_some();
+        debug!("删除SQLite存储过程: {}, 存在={}", procedure_name, removed);
+        Ok(removed)
+    }
+
     /// 执行存储过程查询（SQLite使用视图实现）
     async fn execute_stored_procedure(
         &self,
         connection: &DatabaseConnection,
         procedure_name: &str,
-        database: &str,
+        _database: &str,
         params: Option<std::collections::HashMap<String, crate::types::DataValue>>,
     ) -> QuickDbResult<crate::stored_procedure::StoredProcedureQueryResult> {
-        use crate::adapter::sqlite::adapter::SqliteAdapter;
-
         // 获取存储过程信息
         let procedures = self.stored_procedures.lock().await;
         let procedure_info = procedures.get(procedure_name).ok_or_else(|| {
@@ -1178,8 +1380,7 @@ impl SqliteAdapter {
             .replace("{LIMIT}", "")
             .replace("{OFFSET}", "")
             .replace("  ", " ")
-            .replace(" ,", ",")
-            .replace(", ", ", ");
+            .replace(" ,", ",");
 
         // 移除连续空格和多余的空格
         final_sql = final_sql.trim().to_string();
@@ -1,10 +1,12 @@
 use super::SqlQueryBuilder;
-use crate::adapter::{DatabaseAdapter, SqliteAdapter};
+use crate::adapter::statement_cache::track_statement;
+use crate::adapter::SqliteAdapter;
 use crate::error::{QuickDbError, QuickDbResult};
 use crate::pool::DatabaseConnection;
 use crate::types::*;
 use rat_logger::debug;
-use sqlx::{Column, Row, sqlite::SqliteRow};
+use sqlx::Row;
+use std::collections::HashMap;
 
 /// 检查SQLite错误是否为表不存在错误
 /// SQLite 错误码 1 是 SQLITE_ERROR（通用错误），需要结合错误消息判断
@@ -25,7 +27,7 @@ fn check_table_not_exist_error(error: &sqlx::Error, _table: &str) -> bool {
 
 /// SQLite删除操作
 pub(crate) async fn delete(
-    adapter: &SqliteAdapter,
+    _adapter: &SqliteAdapter,
     connection: &DatabaseConnection,
     table: &str,
     conditions: &[QueryConditionWithConfig],
@@ -45,6 +47,7 @@ pub(crate) async fn delete(
             .where_conditions(conditions)
             .build(table, alias)?;
 
+        crate::statement_log::log_sql(DatabaseType::SQLite, &sql, &params);
         let mut query = sqlx::query(&sql);
         for param in &params {
             match param {
@@ -105,9 +108,83 @@ pub(crate) async fn delete_by_id(
     Ok(affected_rows > 0)
 }
 
+/// SQLite根据ID更新并返回更新后的记录（`UPDATE ... RETURNING *`）
+pub(crate) async fn update_by_id_returning(
+    adapter: &SqliteAdapter,
+    connection: &DatabaseConnection,
+    table: &str,
+    id: &DataValue,
+    data: &HashMap<String, DataValue>,
+    alias: &str,
+) -> QuickDbResult<Option<DataValue>> {
+    let pool = match connection {
+        DatabaseConnection::SQLite(pool) => pool,
+        _ => {
+            return Err(QuickDbError::ConnectionError {
+                message: "Invalid connection type for SQLite".to_string(),
+            });
+        }
+    };
+
+    // 与 update 一致：按字段元数据验证和转换
+    let model_meta = crate::manager::get_model_with_alias(table, alias).ok_or_else(|| {
+        QuickDbError::ValidationError {
+            field: "model".to_string(),
+            message: format!("模型 '{}' 不存在", table),
+        }
+    })?;
+    let validated_data =
+        crate::utils::timezone::process_data_fields_from_metadata(data.clone(), &model_meta.fields);
+
+    let (sql, params) = SqlQueryBuilder::new()
+        .update(validated_data)
+        .where_conditions(&[id_condition(id)])
+        .build(table, alias)?;
+    let sql = format!("{} RETURNING *", sql);
+
+    debug!("执行SQLite更新并返回记录: {}", sql);
+    adapter.execute_returning(pool, table, &sql, &params, alias).await
+}
+
+/// SQLite根据ID删除并返回被删除的记录（`DELETE ... RETURNING *`）
+pub(crate) async fn delete_by_id_returning(
+    adapter: &SqliteAdapter,
+    connection: &DatabaseConnection,
+    table: &str,
+    id: &DataValue,
+    alias: &str,
+) -> QuickDbResult<Option<DataValue>> {
+    let pool = match connection {
+        DatabaseConnection::SQLite(pool) => pool,
+        _ => {
+            return Err(QuickDbError::ConnectionError {
+                message: "Invalid connection type for SQLite".to_string(),
+            });
+        }
+    };
+
+    let (sql, params) = SqlQueryBuilder::new()
+        .delete()
+        .where_conditions(&[id_condition(id)])
+        .build(table, alias)?;
+    let sql = format!("{} RETURNING *", sql);
+
+    debug!("执行SQLite删除并返回记录: {}", sql);
+    adapter.execute_returning(pool, table, &sql, &params, alias).await
+}
+
+fn id_condition(id: &DataValue) -> QueryConditionWithConfig {
+    QueryConditionWithConfig {
+        field: "id".to_string(),
+        operator: QueryOperator::Eq,
+        value: id.clone(),
+        case_insensitive: false,
+    }
+}
+
 /// SQLite统计操作
 pub(crate) async fn count(
-    adapter: &SqliteAdapter,
+    _adapter: &SqliteAdapter,
     connection: &DatabaseConnection,
     table: &str,
     conditions: &[QueryConditionWithConfig],
@@ -127,7 +204,8 @@ pub(crate) async fn count(
             .where_conditions(conditions)
             .build(table, alias)?;
 
-        let mut query = sqlx::query(&sql);
+        crate::statement_log::log_sql(DatabaseType::SQLite, &sql, &params);
+        let mut query = sqlx::query(&sql).persistent(track_statement(alias, &sql));
         for param in &params {
             match param {
                 DataValue::String(s) => {
@@ -174,7 +252,7 @@ pub(crate) async fn count(
 
 /// SQLite条件组合统计操作
 pub(crate) async fn count_with_groups(
-    adapter: &SqliteAdapter,
+    _adapter: &SqliteAdapter,
     connection: &DatabaseConnection,
     table: &str,
     condition_groups: &[QueryConditionGroupWithConfig],
@@ -189,36 +267,15 @@ pub(crate) async fn count_with_groups(
         }
     };
 
-    // SQLite 不支持 case_insensitive，将 QueryConditionGroupWithConfig 转换为 QueryConditionGroup
-    fn convert_group(group: &QueryConditionGroupWithConfig) -> QueryConditionGroup {
-        match group {
-            QueryConditionGroupWithConfig::Single(c) => {
-                QueryConditionGroup::Single(QueryCondition {
-                    field: c.field.clone(),
-                    operator: c.operator.clone(),
-                    value: c.value.clone(),
-                })
-            }
-            QueryConditionGroupWithConfig::GroupWithConfig { operator, conditions } => {
-                QueryConditionGroup::Group {
-                    operator: operator.clone(),
-                    conditions: conditions.iter().map(convert_group).collect(),
-                }
-            }
-        }
-    }
-
-    let simple_groups: Vec<QueryConditionGroup> =
-        condition_groups.iter().map(convert_group).collect();
-
     let (sql, params) = SqlQueryBuilder::new()
         .select(&["COUNT(*) as count"])
-        .where_condition_groups(&simple_groups)
+        .where_condition_groups(condition_groups)
         .build(table, alias)?;
 
     debug!("执行SQLite条件组合统计: {}", sql);
 
-    let mut query = sqlx::query(&sql);
+    crate::statement_log::log_sql(DatabaseType::SQLite, &sql, &params);
+    let mut query = sqlx::query(&sql).persistent(track_statement(alias, &sql));
     for param in &params {
         match param {
             DataValue::String(s) => {
@@ -261,3 +318,48 @@ pub(crate) async fn count_with_groups(
 
     Ok(count as u64)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::types::*;
+
+    #[tokio::test]
+    async fn returning_variants_yield_written_records() {
+        crate::i18n::ErrorMessageI18n::init_i18n();
+        let alias = "returning_suite";
+        let manager = crate::manager::get_global_pool_manager();
+        manager
+            .add_database(DatabaseConfig::sqlite_memory_for_test(alias))
+            .await
+            .unwrap();
+        manager
+            .register_model(crate::model::ModelMeta {
+                collection_name: "tickets".to_string(),
+                database_alias: Some(alias.to_string()),
+                fields: HashMap::from([
+                    ("id".to_string(), crate::model::integer_field(None, None)),
+                    ("status".to_string(), crate::model::string_field(Some(16), None, None)),
+                ]),
+                ..Default::default()
+            })
+            .unwrap();
+        let pool = manager.get_connection_pools().get(alias).unwrap().clone();
+        let status = |value: &str| HashMap::from([("status".to_string(), DataValue::String(value.to_string()))]);
+        let id = match pool.create("tickets", &status("open"), &IdStrategy::AutoIncrement).await.unwrap() {
+            DataValue::Object(record) => record["id"].clone(),
+            other => other,
+        };
+
+        let updated = pool.update_by_id_returning("tickets", &id, &status("closed")).await.unwrap();
+        let Some(DataValue::Object(updated)) = updated else { panic!("更新后应返回记录: {:?}", updated) };
+        assert_eq!(updated["status"], DataValue::String("closed".to_string()));
+
+        let deleted = pool.delete_by_id_returning("tickets", &id, alias).await.unwrap();
+        let Some(DataValue::Object(deleted)) = deleted else { panic!("删除后应返回记录: {:?}", deleted) };
+        assert_eq!(deleted["id"], updated["id"]);
+        assert_eq!(pool.delete_by_id_returning("tickets", &id, alias).await.unwrap(), None);
+        assert_eq!(pool.update_by_id_returning("tickets", &id, &status("open")).await.unwrap(), None);
+    }
+}
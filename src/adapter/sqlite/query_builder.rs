@@ -3,8 +3,10 @@
 //! 提供安全的SQL查询构建功能，防止SQL注入攻击
 
 use crate::adapter::get_field_type;
+use crate::adapter::utils::case_insensitive_clause;
 use crate::error::{QuickDbError, QuickDbResult};
 use crate::security::DatabaseSecurityValidator;
+use crate::types::query::{between_bounds, regex_pattern};
 use crate::types::*;
 use rat_logger::debug;
 use std::collections::HashMap;
@@ -14,7 +16,7 @@ pub struct SqlQueryBuilder {
     query_type: QueryType,
     fields: Vec<String>,
     conditions: Vec<QueryConditionWithConfig>,
-    condition_groups: Vec<QueryConditionGroup>,
+    condition_groups: Vec<QueryConditionGroupWithConfig>,
     joins: Vec<JoinClause>,
     order_by: Vec<OrderClause>,
     group_by: Vec<String>,
@@ -45,6 +47,7 @@ pub struct JoinClause {
 }
 
 #[derive(Debug, Clone)]
+#[allow(dead_code)]
 pub enum JoinType {
     Inner,
     Left,
@@ -120,6 +123,7 @@ impl SqlQueryBuilder {
     }
 
     /// 添加WHERE条件
+    #[allow(dead_code)]
     pub fn where_condition(mut self, condition: QueryConditionWithConfig) -> Self {
         self.conditions.push(condition);
         self
@@ -132,7 +136,7 @@ impl SqlQueryBuilder {
     }
 
     /// 添加条件组合（支持OR逻辑）
-    pub fn where_condition_groups(mut self, groups: &[QueryConditionGroup]) -> Self {
+    pub fn where_condition_groups(mut self, groups: &[QueryConditionGroupWithConfig]) -> Self {
         // 存储条件组合
         self.condition_groups.extend_from_slice(groups);
         // 清空简单条件，因为条件组合会覆盖简单条件
@@ -141,6 +145,7 @@ impl SqlQueryBuilder {
     }
 
     /// 添加JOIN子句
+    #[allow(dead_code)]
     pub fn join(mut self, join_type: JoinType, table: &str, on_condition: &str) -> Self {
         self.joins.push(JoinClause {
             join_type,
@@ -160,12 +165,14 @@ impl SqlQueryBuilder {
     }
 
     /// 添加GROUP BY子句
+    #[allow(dead_code)]
     pub fn group_by(mut self, fields: &[&str]) -> Self {
         self.group_by = fields.iter().map(|s| s.to_string()).collect();
         self
     }
 
     /// 添加HAVING条件
+    #[allow(dead_code)]
     pub fn having(mut self, condition: QueryConditionWithConfig) -> Self {
         self.having.push(condition);
         self
@@ -191,15 +198,13 @@ impl SqlQueryBuilder {
 
     /// 构建SQL查询语句
     pub fn build(self, table: &str, alias: &str) -> QuickDbResult<(String, Vec<DataValue>)> {
-        let result = match self.query_type {
+        match self.query_type {
             QueryType::Select => self.build_select(table, alias),
             QueryType::Insert => self.build_insert(table, alias),
             QueryType::Update => self.build_update(table, alias),
             QueryType::Delete => self.build_delete(table, alias),
             QueryType::Upsert => self.build_upsert(table, alias),
-        };
-
-        result
+        }
     }
 
     /// 构建SELECT语句
@@ -224,9 +229,10 @@ impl SqlQueryBuilder {
                     if f == "*" {
                         "*".to_string()
                     } else {
+                        // 非法标识符按表达式原样输出（如 COUNT(*) as count），与 PostgreSQL 一致
                         self.security_validator
                             .get_safe_field_identifier(f)
-                            .unwrap_or_else(|_| format!("\"{}\"", f))
+                            .unwrap_or_else(|_| f.clone())
                     }
                 })
                 .collect::<Vec<_>>()
@@ -253,7 +259,7 @@ impl SqlQueryBuilder {
         // 添加WHERE条件（优先使用条件组合）
         if !self.condition_groups.is_empty() {
             let (where_clause, where_params) =
-                self.build_where_clause_from_groups(&self.condition_groups, table, alias)?;
+                self.build_where_clause_from_groups_with_config_offset(&self.condition_groups, 1, table, alias)?;
             sql.push_str(&format!(" WHERE {}", where_clause));
             params.extend(where_params);
         } else if !self.conditions.is_empty() {
@@ -317,7 +323,7 @@ impl SqlQueryBuilder {
     }
 
     /// 构建INSERT语句
-    fn build_insert(&self, table: &str, alias: &str) -> QuickDbResult<(String, Vec<DataValue>)> {
+    fn build_insert(&self, table: &str, _alias: &str) -> QuickDbResult<(String, Vec<DataValue>)> {
         if table.is_empty() {
             return Err(QuickDbError::QueryError {
                 message: "表名不能为空".to_string(),
@@ -463,7 +469,7 @@ impl SqlQueryBuilder {
     }
 
     /// 构建UPSERT语句（INSERT ... ON CONFLICT ... DO UPDATE SET ...）
-    fn build_upsert(&self, table: &str, alias: &str) -> QuickDbResult<(String, Vec<DataValue>)> {
+    fn build_upsert(&self, table: &str, _alias: &str) -> QuickDbResult<(String, Vec<DataValue>)> {
         if table.is_empty() {
             return Err(QuickDbError::QueryError {
                 message: "表名不能为空".to_string(),
@@ -619,43 +625,6 @@ impl SqlQueryBuilder {
         self.build_where_clause_with_offset(conditions, 1, table, alias)
     }
 
-    /// 构建WHERE子句（支持条件组合）
-    pub fn build_where_clause_from_groups(
-        &self,
-        groups: &[QueryConditionGroup],
-        table: &str,
-        alias: &str,
-    ) -> QuickDbResult<(String, Vec<DataValue>)> {
-        self.build_where_clause_from_groups_with_offset(groups, 1, table, alias)
-    }
-
-    /// 构建WHERE子句（支持条件组合），从指定的参数索引开始
-    fn build_where_clause_from_groups_with_offset(
-        &self,
-        groups: &[QueryConditionGroup],
-        start_index: usize,
-        table: &str,
-        alias: &str,
-    ) -> QuickDbResult<(String, Vec<DataValue>)> {
-        if groups.is_empty() {
-            return Ok((String::new(), Vec::new()));
-        }
-
-        let mut clauses = Vec::new();
-        let mut params = Vec::new();
-        let mut param_index = start_index;
-
-        for group in groups {
-            let (clause, group_params, new_index) =
-                self.build_condition_group_clause(group, param_index, table, alias)?;
-            clauses.push(clause);
-            params.extend(group_params);
-            param_index = new_index;
-        }
-
-        Ok((clauses.join(" AND "), params))
-    }
-
     /// 构建WHERE子句（支持条件组合 WithConfig），从指定的参数索引开始
     fn build_where_clause_from_groups_with_config_offset(
         &self,
@@ -693,7 +662,7 @@ impl SqlQueryBuilder {
     ) -> QuickDbResult<(String, Vec<DataValue>, usize)> {
         match group {
             QueryConditionGroupWithConfig::Single(condition) => {
-                let (clause, mut params, new_index) =
+                let (clause, params, new_index) =
                     self.build_single_condition_clause(condition, start_index, table, alias)?;
                 Ok((clause, params, new_index))
             }
@@ -733,68 +702,6 @@ impl SqlQueryBuilder {
         }
     }
 
-    /// 构建单个条件组合的子句
-    fn build_condition_group_clause(
-        &self,
-        group: &QueryConditionGroup,
-        start_index: usize,
-        table: &str,
-        alias: &str,
-    ) -> QuickDbResult<(String, Vec<DataValue>, usize)> {
-        match group {
-            QueryConditionGroup::Single(condition) => {
-                let config = QueryConditionWithConfig {
-                    field: condition.field.clone(),
-                    operator: condition.operator.clone(),
-                    value: condition.value.clone(),
-                    case_insensitive: false,
-                };
-                let (clause, mut params, new_index) =
-                    self.build_single_condition_clause(&config, start_index, table, alias)?;
-                Ok((clause, params, new_index))
-            }
-            QueryConditionGroup::Group {
-                operator,
-                conditions,
-            } => {
-                if conditions.is_empty() {
-                    return Ok((String::new(), Vec::new(), start_index));
-                }
-
-                let mut clauses = Vec::new();
-                let mut params = Vec::new();
-                let mut param_index = start_index;
-
-                for condition in conditions {
-                    let (clause, condition_params, new_index) =
-                        self.build_condition_group_clause(condition, param_index, table, alias)?;
-                    if !clause.is_empty() {
-                        clauses.push(clause);
-                        params.extend(condition_params);
-                        param_index = new_index;
-                    }
-                }
-
-                if clauses.is_empty() {
-                    return Ok((String::new(), Vec::new(), param_index));
-                }
-
-                let logical_op = match operator {
-                    LogicalOperator::And => " AND ",
-                    LogicalOperator::Or => " OR ",
-                };
-
-                let combined_clause = if clauses.len() == 1 {
-                    clauses[0].clone()
-                } else {
-                    format!("({})", clauses.join(logical_op))
-                };
-
-                Ok((combined_clause, params, param_index))
-            }
-        }
-    }
-
     /// 构建单个条件的子句
     fn build_single_condition_clause(
         &self,
@@ -827,6 +734,13 @@ impl SqlQueryBuilder {
             condition.value.clone()
         };
 
+        if let Some((clause, params)) = case_insensitive_clause(condition, &safe_field, table, alias, |offset| {
+            self.get_placeholder(param_index + offset)
+        }) {
+            let next_index = param_index + params.len();
+            return Ok((clause, params, next_index));
+        }
+
         let (clause, params) = match condition.operator {
             QueryOperator::Eq => {
                 new_index += 1;
@@ -1119,7 +1033,21 @@ impl SqlQueryBuilder {
                 new_index += 1;
                 (
                     format!("{} REGEXP {}", safe_field, placeholder),
-                    vec![condition.value.clone()],
+                    vec![sqlite_regex_param(condition)?],
+                )
+            }
+            QueryOperator::Between | QueryOperator::NotBetween => {
+                let (lower, upper) = between_bounds(&condition.field, &query_value)?;
+                let keyword = if condition.operator == QueryOperator::Between {
+                    "BETWEEN"
+                } else {
+                    "NOT BETWEEN"
+                };
+                let upper_placeholder = self.get_placeholder(new_index + 1);
+                new_index += 2;
+                (
+                    format!("{} {} {} AND {}", safe_field, keyword, placeholder, upper_placeholder),
+                    vec![process_range_query_value(table, alias, &condition.field, lower)?, process_range_query_value(table, alias, &condition.field, upper)?],
                 )
             }
             QueryOperator::Exists => (format!("{} IS NOT NULL", safe_field), vec![]),
@@ -1152,9 +1080,18 @@ impl SqlQueryBuilder {
                 .security_validator
                 .get_safe_field_identifier(&condition.field)?;
 
+            if let Some((clause, values)) = case_insensitive_clause(condition, &safe_field, table, alias, |offset| {
+                self.get_placeholder(param_index + offset)
+            }) {
+                clauses.push(clause);
+                param_index += values.len();
+                params.extend(values);
+                continue;
+            }
+
             match condition.operator {
                 QueryOperator::Eq => {
-                    // SQLite 不支持大小写不敏感，直接使用正常比较
+                    // 大小写不敏感的字符串比较已由 case_insensitive_clause 处理
                     clauses.push(format!("{} = {}", safe_field, placeholder));
                     params.push(condition.value.clone());
                     param_index += 1;
@@ -1434,11 +1371,27 @@ impl SqlQueryBuilder {
                     });
                 }
                 QueryOperator::Regex => {
-                    // 不同数据库的正则表达式语法不同，这里使用通用的LIKE
+                    // REGEXP 由连接上注册的 regexp() 函数（regex crate）求值
                     clauses.push(format!("{} REGEXP {}", safe_field, placeholder));
-                    params.push(condition.value.clone());
+                    params.push(sqlite_regex_param(condition)?);
                     param_index += 1;
                 }
+                QueryOperator::Between | QueryOperator::NotBetween => {
+                    let (lower, upper) = between_bounds(&condition.field, &condition.value)?;
+                    let keyword = if condition.operator == QueryOperator::Between {
+                        "BETWEEN"
+                    } else {
+                        "NOT BETWEEN"
+                    };
+                    let upper_placeholder = self.get_placeholder(param_index + 1);
+                    clauses.push(format!(
+                        "{} {} {} AND {}",
+                        safe_field, keyword, placeholder, upper_placeholder
+                    ));
+                    params.push(process_range_query_value(table, alias, &condition.field, lower)?);
+                    params.push(process_range_query_value(table, alias, &condition.field, upper)?);
+                    param_index += 2;
+                }
                 QueryOperator::Exists => {
                     // 检查字段是否存在（主要用于NoSQL数据库）
                     clauses.push(format!("{} IS NOT NULL", safe_field));
@@ -1500,21 +1453,21 @@ fn process_range_query_value(
                             return Ok(DataValue::Int(naive_dt.and_utc().timestamp()));
                         }
                         // 转换失败，直接报错
-                        return Err(QuickDbError::ValidationError {
+                        Err(QuickDbError::ValidationError {
                             field: field_name.to_string(),
                             message: format!(
                                 "无法解析日期时间字符串 '{}'。支持的格式：RFC3339 (如 2024-01-15T14:30:00+08:00) 或本地时间 (如 2024-01-15 14:30:00)",
                                 s
                             ),
-                        });
+                        })
                     }
                     DataValue::DateTime(dt) => {
                         // DateTime转换为timestamp
-                        return Ok(DataValue::Int(dt.timestamp()));
+                        Ok(DataValue::Int(dt.timestamp()))
                     }
                     DataValue::DateTimeUTC(dt) => {
                         // DateTimeUTC转换为timestamp
-                        return Ok(DataValue::Int(dt.timestamp()));
+                        Ok(DataValue::Int(dt.timestamp()))
                     }
                     _ => Ok(value.clone()),
                 }
@@ -1528,19 +1481,19 @@ fn process_range_query_value(
                         if let Ok(i) = s.parse::<i64>() {
                             return Ok(DataValue::Int(i));
                         }
-                        return Err(QuickDbError::ValidationError {
+                        Err(QuickDbError::ValidationError {
                             field: field_name.to_string(),
                             message: format!("Integer字段无法将字符串 '{}' 转换为整数", s),
-                        });
+                        })
                     }
                     _ => {
-                        return Err(QuickDbError::ValidationError {
+                        Err(QuickDbError::ValidationError {
                             field: field_name.to_string(),
                             message: format!(
                                 "Integer字段不支持的数据类型: {:?}，期望Int或String形式的数字",
                                 std::any::type_name_of_val(value)
                             ),
-                        });
+                        })
                     }
                 }
             }
@@ -1554,44 +1507,132 @@ fn process_range_query_value(
                         if let Ok(f) = s.parse::<f64>() {
                             return Ok(DataValue::Float(f));
                         }
-                        return Err(QuickDbError::ValidationError {
+                        Err(QuickDbError::ValidationError {
                             field: field_name.to_string(),
                             message: format!("Float字段无法将字符串 '{}' 转换为浮点数", s),
-                        });
+                        })
                     }
                     _ => {
-                        return Err(QuickDbError::ValidationError {
+                        Err(QuickDbError::ValidationError {
                             field: field_name.to_string(),
                             message: format!(
                                 "Float字段不支持的数据类型: {:?}，期望Float、Int或String形式的数字",
                                 std::any::type_name_of_val(value)
                             ),
-                        });
+                        })
                     }
                 }
             }
             // 其他字段类型不支持范围查询操作符
             _ => {
-                return Err(QuickDbError::ValidationError {
+                Err(QuickDbError::ValidationError {
                     field: field_name.to_string(),
                     message: format!(
                         "字段类型 {:?} 不支持范围查询操作符 (Gt/Gte/Lt/Lte)",
                         field_type
                     ),
-                });
+                })
             }
         }
     } else {
         // 无法获取字段类型，报错
-        return Err(QuickDbError::ValidationError {
+        Err(QuickDbError::ValidationError {
             field: field_name.to_string(),
             message: "无法获取字段类型信息，请确保模型已正确注册".to_string(),
-        });
+        })
     }
 }
 
+/// 校验正则条件并生成 REGEXP 参数（大小写不敏感时添加 `(?i)` 标志）
+fn sqlite_regex_param(condition: &QueryConditionWithConfig) -> QuickDbResult<DataValue> {
+    let pattern = regex_pattern(&condition.field, &condition.value)?;
+    Ok(DataValue::String(if condition.case_insensitive {
+        format!("(?i){}", pattern)
+    } else {
+        pattern.to_string()
+    }))
+}
+
 impl Default for SqlQueryBuilder {
     fn default() -> Self {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ci(field: &str, operator: QueryOperator, value: DataValue) -> QueryConditionWithConfig {
+        QueryConditionWithConfig {
+            field: field.to_string(),
+            operator,
+            value,
+            case_insensitive: true,
+        }
+    }
+
+    #[test]
+    fn case_insensitive_eq_ne_in_use_lower() {
+        let names = DataValue::Array(vec![
+            DataValue::String("Alice".to_string()),
+            DataValue::String("BOB".to_string()),
+        ]);
+        let conditions = vec![
+            ci("name", QueryOperator::Eq, DataValue::String("Alice".to_string())),
+            ci("email", QueryOperator::Ne, DataValue::String("A@B.COM".to_string())),
+            ci("role", QueryOperator::In, names),
+            ci("age", QueryOperator::Eq, DataValue::Int(30)),
+        ];
+
+        let (sql, params) = SqlQueryBuilder::new()
+            .select(&["*"])
+            .where_conditions(&conditions)
+            .build("ci_users", "default")
+            .unwrap();
+        assert!(sql.contains(r#"LOWER("name") = LOWER(?)"#), "{}", sql);
+        assert!(sql.contains(r#"LOWER("email") != LOWER(?)"#), "{}", sql);
+        assert!(sql.contains(r#"LOWER("role") IN (LOWER(?), LOWER(?))"#), "{}", sql);
+        assert!(sql.contains(r#""age" = ?"#), "{}", sql);
+        assert_eq!(params.len(), 5);
+
+        // 条件组路径与简单条件路径生成一致的比较
+        let groups = vec![QueryConditionGroupWithConfig::GroupWithConfig {
+            operator: LogicalOperator::Or,
+            conditions: conditions.into_iter().map(QueryConditionGroupWithConfig::Single).collect(),
+        }];
+        let (sql, params) = SqlQueryBuilder::new()
+            .select(&["*"])
+            .where_condition_groups(&groups)
+            .build("ci_users", "default")
+            .unwrap();
+        assert!(sql.contains(r#"LOWER("role") IN (LOWER(?), LOWER(?))"#), "{}", sql);
+        assert!(sql.contains(r#""age" = ?"#), "{}", sql);
+        assert_eq!(params.len(), 5);
+    }
+
+    #[tokio::test]
+    async fn regex_uses_registered_regexp_function() {
+        crate::i18n::ErrorMessageI18n::init_i18n();
+        let mut condition = ci("name", QueryOperator::Regex, DataValue::String("^ali".to_string()));
+        let (sql, params) = SqlQueryBuilder::new()
+            .select(&["*"])
+            .where_conditions(std::slice::from_ref(&condition))
+            .build("ci_users", "default")
+            .unwrap();
+        assert!(sql.ends_with(r#"WHERE "name" REGEXP ?"#), "{}", sql);
+        assert_eq!(params, vec![DataValue::String("(?i)^ali".to_string())]);
+
+        let options = "sqlite::memory:".parse::<sqlx::sqlite::SqliteConnectOptions>().unwrap().with_regexp();
+        let pool = sqlx::SqlitePool::connect_with(options).await.unwrap();
+        let matched: bool = sqlx::query_scalar("SELECT 'Alice' REGEXP ?")
+            .bind("(?i)^ali")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert!(matched);
+
+        condition.value = DataValue::String("(a+)+$".to_string());
+        assert!(SqlQueryBuilder::new().where_conditions(&[condition]).build("ci_users", "default").is_err());
+    }
+}
@@ -1,22 +1,25 @@
-use super::SqlQueryBuilder;
-use crate::adapter::{DatabaseAdapter, SqliteAdapter};
+use crate::adapter::utils::{
+    SEQUENCE_TABLE, get_custom_primary_key, id_column_constraint, index_key_parts,
+    render_index_predicate, sequence_fields_of,
+};
+use crate::adapter::SqliteAdapter;
 use crate::error::{QuickDbError, QuickDbResult};
-use crate::model::{FieldDefinition, FieldType};
+use crate::model::{FieldDefinition, FieldType, IndexOptions};
 use crate::pool::DatabaseConnection;
-use crate::security::quote_identifier;
+use crate::security::{quote_identifier, quote_table_identifier, split_qualified_name};
 use crate::types::*;
-use async_trait::async_trait;
 use rat_logger::debug;
-use sqlx::{Column, Row, sqlite::SqliteRow};
+use sqlx::Row;
 use std::collections::HashMap;
 
 /// SQLite创建表操作
 pub(crate) async fn create_table(
-    adapter: &SqliteAdapter,
+    _adapter: &SqliteAdapter,
     connection: &DatabaseConnection,
     table: &str,
     fields: &HashMap<String, FieldDefinition>,
-    id_strategy: &IdStrategy,
+    _id_strategy: &IdStrategy,
+    alias: &str,
 ) -> QuickDbResult<()> {
     let pool = match connection {
         DatabaseConnection::SQLite(pool) => pool,
@@ -27,14 +30,22 @@ pub(crate) async fn create_table(
         }
     };
     {
-        let safe_table = quote_identifier(table, DatabaseType::SQLite);
+        let safe_table = quote_table_identifier(table, DatabaseType::SQLite);
         let mut sql = format!("CREATE TABLE IF NOT EXISTS {} (", safe_table);
         let mut has_fields = false;
 
+        // 复合/自定义主键时，id列不再是主键（也不再是rowid别名），主键约束在表级声明
+        let custom_primary_key = get_custom_primary_key(table, alias);
+        let id_constraint = id_column_constraint(custom_primary_key.as_deref());
+
         // 检查是否已经有id字段，如果没有则添加默认的id主键
         if !fields.contains_key("id") {
             let safe_id = quote_identifier("id", DatabaseType::SQLite);
-            sql.push_str(&format!("{} INTEGER PRIMARY KEY AUTOINCREMENT", safe_id));
+            if custom_primary_key.is_some() {
+                sql.push_str(&format!("{} INTEGER {}", safe_id, id_constraint));
+            } else {
+                sql.push_str(&format!("{} INTEGER PRIMARY KEY AUTOINCREMENT", safe_id));
+            }
             has_fields = true;
         }
 
@@ -71,12 +82,17 @@ pub(crate) async fn create_table(
                 FieldType::Array { .. } => "TEXT".to_string(), // 存储为JSON
                 FieldType::Object { .. } => "TEXT".to_string(), // 存储为JSON
                 FieldType::Reference { .. } => "TEXT".to_string(), // 存储引用ID
+                FieldType::PolymorphicReference { .. } => "TEXT".to_string(), // 存储为JSON
                 FieldType::Vector { .. } => "BLOB".to_string(), // SQLite 不支持原生向量，用 BLOB 存储
+                FieldType::Sequence { .. } => "INTEGER".to_string(), // 值由计数器表分配
             };
 
             // 如果是id字段，添加主键约束
-            // 添加NULL或NOT NULL约束
-            let null_constraint = if field_definition.required {
+            // 添加NULL或NOT NULL约束（主键字段必须为NOT NULL）
+            let is_primary_key = custom_primary_key
+                .as_ref()
+                .is_some_and(|pk| pk.contains(field_name));
+            let null_constraint = if field_definition.required || is_primary_key {
                 "NOT NULL"
             } else {
                 ""
@@ -84,15 +100,29 @@ pub(crate) async fn create_table(
 
             let safe_field_name = quote_identifier(field_name, DatabaseType::SQLite);
             if field_name == "id" {
-                sql.push_str(&format!("{} {} PRIMARY KEY", safe_field_name, sql_type));
+                sql.push_str(&format!("{} {} {}", safe_field_name, sql_type, id_constraint));
             } else {
                 sql.push_str(&format!("{} {} {}", safe_field_name, sql_type, null_constraint));
             }
             has_fields = true;
         }
 
+        // 表级复合主键约束
+        if let Some(pk) = &custom_primary_key {
+            let safe_pk: Vec<String> = pk
+                .iter()
+                .map(|f| quote_identifier(f, DatabaseType::SQLite))
+                .collect();
+            sql.push_str(&format!(", PRIMARY KEY ({})", safe_pk.join(", ")));
+        }
+
         sql.push(')');
 
+        // 存在序列字段时确保计数器表存在
+        if !sequence_fields_of(fields).is_empty() {
+            ensure_sequence_table(pool).await?;
+        }
+
         sqlx::query(&sql)
             .execute(pool)
             .await
@@ -100,18 +130,89 @@ pub(crate) async fn create_table(
                 message: format!("创建SQLite表失败: {}", e),
             })?;
 
+        // 自定义主键不含id时，id列不是rowid别名，插入时由触发器以rowid补齐id，
+        // 使插入结果返回的 last_insert_rowid 与存储的id一致
+        if custom_primary_key.is_some() && id_constraint == "UNIQUE" {
+            let (schema, bare_table) = split_qualified_name(table);
+            let trigger = quote_table_identifier(
+                &match schema {
+                    Some(schema) => format!("{}.{}_assign_id", schema, bare_table),
+                    None => format!("{}_assign_id", bare_table),
+                },
+                DatabaseType::SQLite,
+            );
+            let safe_bare_table = quote_identifier(bare_table, DatabaseType::SQLite);
+            let safe_id = quote_identifier("id", DatabaseType::SQLite);
+            let trigger_sql = format!(
+                "CREATE TRIGGER IF NOT EXISTS {} AFTER INSERT ON {} FOR EACH ROW WHEN NEW.{} IS NULL \
+                 BEGIN UPDATE {} SET {} = NEW.rowid WHERE rowid = NEW.rowid; END",
+                trigger, safe_table, safe_id, safe_bare_table, safe_id
+            );
+            sqlx::query(&trigger_sql)
+                .execute(pool)
+                .await
+                .map_err(|e| QuickDbError::QueryError {
+                    message: format!("创建SQLite id触发器失败: {}", e),
+                })?;
+        }
+
         Ok(())
     }
 }
 
+/// 确保序列计数器表存在
+pub(crate) async fn ensure_sequence_table(pool: &sqlx::SqlitePool) -> QuickDbResult<()> {
+    let safe_table = quote_identifier(SEQUENCE_TABLE, DatabaseType::SQLite);
+    let sql = format!(
+        "CREATE TABLE IF NOT EXISTS {} (\"name\" TEXT PRIMARY KEY, \"value\" INTEGER NOT NULL)",
+        safe_table
+    );
+    sqlx::query(&sql)
+        .execute(pool)
+        .await
+        .map_err(|e| QuickDbError::QueryError {
+            message: format!("创建SQLite序列表失败: {}", e),
+        })?;
+    Ok(())
+}
+
+/// 获取序列的下一个值
+///
+/// 单条 UPSERT ... RETURNING 语句完成递增与读取，SQLite 写锁保证原子性
+pub(crate) async fn next_sequence_value(
+    pool: &sqlx::SqlitePool,
+    sequence_name: &str,
+) -> QuickDbResult<i64> {
+    let safe_table = quote_identifier(SEQUENCE_TABLE, DatabaseType::SQLite);
+    let sql = format!(
+        "INSERT INTO {} (\"name\", \"value\") VALUES (?, 1) \
+         ON CONFLICT(\"name\") DO UPDATE SET \"value\" = \"value\" + 1 RETURNING \"value\"",
+        safe_table
+    );
+    let row = sqlx::query(&sql)
+        .bind(sequence_name)
+        .fetch_one(pool)
+        .await
+        .map_err(|e| QuickDbError::QueryError {
+            message: format!("递增序列 '{}' 失败: {}", sequence_name, e),
+        })?;
+    let value: i64 = row.try_get(0).map_err(|e| QuickDbError::QueryError {
+        message: format!("解析序列 '{}' 的值失败: {}", sequence_name, e),
+    })?;
+
+    debug!("序列 {} 分配值: {}", sequence_name, value);
+    Ok(value)
+}
+
 /// SQLite创建索引操作
 pub(crate) async fn create_index(
-    adapter: &SqliteAdapter,
+    _adapter: &SqliteAdapter,
     connection: &DatabaseConnection,
     table: &str,
     index_name: &str,
     fields: &[String],
     unique: bool,
+    options: &IndexOptions,
 ) -> QuickDbResult<()> {
     let pool = match connection {
         DatabaseConnection::SQLite(pool) => pool,
@@ -124,16 +225,16 @@ pub(crate) async fn create_index(
     {
         let unique_keyword = if unique { "UNIQUE " } else { "" };
         let safe_index_name = quote_identifier(index_name, DatabaseType::SQLite);
-        let safe_table = quote_identifier(table, DatabaseType::SQLite);
-        let safe_fields: Vec<String> = fields
-            .iter()
-            .map(|f| quote_identifier(f, DatabaseType::SQLite))
-            .collect();
-        let fields_str = safe_fields.join(", ");
-        let sql = format!(
+        let safe_table = quote_table_identifier(table, DatabaseType::SQLite);
+        let key_parts = index_key_parts(fields, options.expression.as_deref(), DatabaseType::SQLite)?;
+        let mut sql = format!(
             "CREATE {}INDEX IF NOT EXISTS {} ON {} ({})",
-            unique_keyword, safe_index_name, safe_table, fields_str
+            unique_keyword, safe_index_name, safe_table, key_parts
         );
+        if !options.where_clause.is_empty() {
+            let predicate = render_index_predicate(&options.where_clause, DatabaseType::SQLite)?;
+            sql.push_str(&format!(" WHERE {}", predicate));
+        }
 
         sqlx::query(&sql)
             .execute(pool)
@@ -148,7 +249,7 @@ pub(crate) async fn create_index(
 
 /// SQLite表存在检查操作
 pub(crate) async fn table_exists(
-    adapter: &SqliteAdapter,
+    _adapter: &SqliteAdapter,
     connection: &DatabaseConnection,
     table: &str,
 ) -> QuickDbResult<bool> {
@@ -161,9 +262,15 @@ pub(crate) async fn table_exists(
         }
     };
     {
-        let sql = "SELECT name FROM sqlite_master WHERE type='table' AND name=?";
-        let row = sqlx::query(sql)
-            .bind(table)
+        // 限定表名在附加数据库（ATTACH）的 sqlite_master 中查找
+        let (schema, table_name) = split_qualified_name(table);
+        let master = match schema {
+            Some(schema) => format!("{}.sqlite_master", quote_identifier(schema, DatabaseType::SQLite)),
+            None => "sqlite_master".to_string(),
+        };
+        let sql = format!("SELECT name FROM {} WHERE type='table' AND name=?", master);
+        let row = sqlx::query(&sql)
+            .bind(table_name)
             .fetch_optional(pool)
             .await
             .map_err(|e| QuickDbError::QueryError {
@@ -174,9 +281,44 @@ pub(crate) async fn table_exists(
     }
 }
 
+/// SQLite表记录数估算
+///
+/// 以 `MAX(rowid)` 估算：删除过记录的表会偏大；WITHOUT ROWID 表没有 rowid，回退到精确计数
+pub(crate) async fn count_estimate(
+    _adapter: &SqliteAdapter,
+    connection: &DatabaseConnection,
+    table: &str,
+) -> QuickDbResult<CountEstimate> {
+    let pool = match connection {
+        DatabaseConnection::SQLite(pool) => pool,
+        _ => {
+            return Err(QuickDbError::ConnectionError {
+                message: "Invalid connection type for SQLite".to_string(),
+            });
+        }
+    };
+
+    let safe_table = quote_table_identifier(table, DatabaseType::SQLite);
+    let sql = format!("SELECT MAX(rowid) FROM {}", safe_table);
+    if let Ok(row) = sqlx::query(&sql).fetch_one(pool).await {
+        let max_rowid: Option<i64> = row.try_get(0).unwrap_or(None);
+        return Ok(CountEstimate::approximate(max_rowid.unwrap_or(0).max(0) as u64));
+    }
+
+    debug!("SQLite表 {} 没有 rowid，回退到精确计数", table);
+    let sql = format!("SELECT COUNT(*) FROM {}", safe_table);
+    let count: i64 = sqlx::query_scalar(&sql)
+        .fetch_one(pool)
+        .await
+        .map_err(|e| QuickDbError::QueryError {
+            message: format!("估算SQLite表记录数失败: {}", e),
+        })?;
+    Ok(CountEstimate::exact(count as u64))
+}
+
 /// SQLite删除表操作
 pub(crate) async fn drop_table(
-    adapter: &SqliteAdapter,
+    _adapter: &SqliteAdapter,
     connection: &DatabaseConnection,
     table: &str,
 ) -> QuickDbResult<()> {
@@ -189,7 +331,7 @@ pub(crate) async fn drop_table(
         }
     };
 
-    let safe_table = quote_identifier(table, DatabaseType::SQLite);
+    let safe_table = quote_table_identifier(table, DatabaseType::SQLite);
     let sql = format!("DROP TABLE IF EXISTS {}", safe_table);
 
     debug!("执行SQLite删除表SQL: {}", sql);
@@ -207,7 +349,7 @@ pub(crate) async fn drop_table(
 
 /// SQLite获取服务器版本操作
 pub(crate) async fn get_server_version(
-    adapter: &SqliteAdapter,
+    _adapter: &SqliteAdapter,
     connection: &DatabaseConnection,
 ) -> QuickDbResult<String> {
     let pool = match connection {
@@ -237,3 +379,32 @@ pub(crate) async fn get_server_version(
     debug!("成功获取SQLite版本: {}", version);
     Ok(version)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn count_estimate_reads_max_rowid_and_falls_back_without_rowid() {
+        crate::i18n::ErrorMessageI18n::init_i18n();
+        let pool = sqlx::SqlitePool::connect("sqlite::memory:").await.unwrap();
+        for sql in [
+            "CREATE TABLE events (id INTEGER PRIMARY KEY, kind TEXT)",
+            "INSERT INTO events (kind) VALUES ('a'), ('b'), ('c')",
+            "DELETE FROM events WHERE id = 2",
+            "CREATE TABLE tags (name TEXT PRIMARY KEY) WITHOUT ROWID",
+            "INSERT INTO tags VALUES ('x'), ('y')",
+        ] {
+            sqlx::query(sql).execute(&pool).await.unwrap();
+        }
+        let adapter = SqliteAdapter::new();
+        let connection = DatabaseConnection::SQLite(pool);
+
+        // 删除留下的空洞使估算值偏大
+        let events = count_estimate(&adapter, &connection, "events").await.unwrap();
+        assert_eq!(events, CountEstimate::approximate(3));
+        let tags = count_estimate(&adapter, &connection, "tags").await.unwrap();
+        assert_eq!(tags, CountEstimate::exact(2));
+        assert!(count_estimate(&adapter, &connection, "missing").await.is_err());
+    }
+}
@@ -2,8 +2,8 @@
 //! SQLite适配器辅助方法模块
 
 use crate::adapter::SqliteAdapter;
+use crate::adapter::utils::sqlx_unique_violation;
 use crate::error::{QuickDbError, QuickDbResult};
-use crate::pool::DatabaseConnection;
 use crate::types::*;
 use rat_logger::debug;
 use sqlx::{Column, Row, sqlite::SqliteRow};
@@ -78,63 +78,19 @@ impl SqliteAdapter {
         Ok(map)
     }
 
-    /// 执行更新操作
-    pub(crate) async fn execute_update(
+    /// 执行更新操作（可在连接池或事务上执行）
+    pub(crate) async fn execute_update<'c, E>(
         &self,
-        pool: &sqlx::Pool<sqlx::Sqlite>,
+        pool: E,
+        table: &str,
         sql: &str,
         params: &[DataValue],
-    ) -> QuickDbResult<u64> {
-        let mut query = sqlx::query(sql);
-
-        // 绑定参数
-        for param in params {
-            query = match param {
-                DataValue::String(s) => {
-                    // SQLite中字符串直接绑定
-                    query.bind(s)
-                }
-                DataValue::Int(i) => query.bind(*i),
-                DataValue::UInt(u) => {
-                    // SQLite 不支持 u64 编码，转换为 i64 或字符串
-                    if *u <= i64::MAX as u64 {
-                        query.bind(*u as i64)
-                    } else {
-                        query.bind(u.to_string())
-                    }
-                }
-                DataValue::Float(f) => query.bind(*f),
-                DataValue::Bool(b) => query.bind(i32::from(*b)), // SQLite使用整数表示布尔值
-                DataValue::DateTime(dt) => query.bind(dt.timestamp()),
-                DataValue::DateTimeUTC(dt) => query.bind(dt.timestamp()),
-                DataValue::Uuid(uuid) => query.bind(uuid.to_string()),
-                DataValue::Json(json) => query.bind(json.to_string()),
-                DataValue::Bytes(bytes) => query.bind(bytes.as_slice()),
-                DataValue::Null => query.bind(Option::<String>::None),
-                DataValue::Array(arr) => {
-                    // Array字段只支持简单类型：String、Int、Float、Uuid
-                    let string_array: Result<Vec<String>, QuickDbError> = arr.iter().map(|item| {
-                        Ok(match item {
-                            DataValue::String(s) => s.clone(),
-                            DataValue::Int(i) => i.to_string(),
-                            DataValue::Float(f) => f.to_string(),
-                            DataValue::Uuid(uuid) => uuid.to_string(),
-                            _ => {
-                                return Err(QuickDbError::ValidationError {
-                                    field: "array_field".to_string(),
-                                    message: format!("Array字段不支持该类型: {:?}，只支持String、Int、Float、Uuid类型", item),
-                                });
-                            }
-                        })
-                    }).collect();
-                    let string_array = string_array?;
-                    query.bind(serde_json::to_string(&string_array).unwrap_or_default())
-                }
-                DataValue::Object(obj) => {
-                    query.bind(serde_json::to_string(obj).unwrap_or_default())
-                }
-            };
-        }
+    ) -> QuickDbResult<u64>
+    where
+        E: sqlx::SqliteExecutor<'c>,
+    {
+        crate::statement_log::log_sql(DatabaseType::SQLite, sql, params);
+        let query = bind_params(sqlx::query(sql), params)?;
 
         debug!("执行SQLite更新SQL: {}", sql);
 
@@ -142,6 +98,9 @@ impl SqliteAdapter {
             .execute(pool)
             .await
             .map_err(|e| {
+                if let Some(unique_error) = sqlx_unique_violation(&e, table) {
+                    return unique_error;
+                }
                 // SQLite 错误码检测
                 if let Some(db_err) = e.as_database_error() {
                     if let Some(code) = db_err.code() {
@@ -165,4 +124,129 @@ impl SqliteAdapter {
 
         Ok(result.rows_affected())
     }
+    /// 执行查询并返回结果（可在连接池或租用的连接上执行）
+    pub(crate) async fn execute_query<'c, E>(
+        &self,
+        pool: E,
+        sql: &str,
+        params: &[DataValue],
+    ) -> QuickDbResult<Vec<DataValue>>
+    where
+        E: sqlx::SqliteExecutor<'c>,
+    {
+        crate::statement_log::log_sql(DatabaseType::SQLite, sql, params);
+        let rows = bind_params(sqlx::query(sql), params)?
+            .fetch_all(pool)
+            .await
+            .map_err(|e| QuickDbError::QueryError {
+                message: format!("执行SQLite查询失败: {}", e),
+            })?;
+
+        rows.iter()
+            .map(|row| self.row_to_data_map(row).map(DataValue::Object))
+            .collect()
+    }
+
+    /// 执行带 `RETURNING *` 的写入语句，返回被写入的记录
+    ///
+    /// 已注册模型时按字段元数据转换返回的行
+    pub(crate) async fn execute_returning<'c, E>(
+        &self,
+        pool: E,
+        table: &str,
+        sql: &str,
+        params: &[DataValue],
+        alias: &str,
+    ) -> QuickDbResult<Option<DataValue>>
+    where
+        E: sqlx::SqliteExecutor<'c>,
+    {
+        crate::statement_log::log_sql(DatabaseType::SQLite, sql, params);
+        let row = bind_params(sqlx::query(sql), params)?
+            .fetch_optional(pool)
+            .await
+            .map_err(|e| {
+                if let Some(unique_error) = sqlx_unique_violation(&e, table) {
+                    return unique_error;
+                }
+                if e.to_string().to_lowercase().contains("no such table") {
+                    return QuickDbError::TableNotExistError {
+                        table: table.to_string(),
+                        message: format!("SQLite表 '{}' 不存在", table),
+                    };
+                }
+                QuickDbError::QueryError {
+                    message: format!("执行SQLite写入失败: {}", e),
+                }
+            })?;
+
+        let Some(row) = row else {
+            return Ok(None);
+        };
+        let data_map = match crate::manager::get_model_with_alias(table, alias) {
+            Some(model_meta) => super::data_conversion::row_to_data_map_with_metadata(&row, &model_meta.fields)?,
+            None => self.row_to_data_map(&row)?,
+        };
+        Ok(Some(DataValue::Object(data_map)))
+    }
+}
+
+/// 绑定语句参数，布尔值按 SQLite 的整数表示绑定
+fn bind_params<'q>(
+    mut query: sqlx::query::Query<'q, sqlx::Sqlite, sqlx::sqlite::SqliteArguments<'q>>,
+    params: &'q [DataValue],
+) -> QuickDbResult<sqlx::query::Query<'q, sqlx::Sqlite, sqlx::sqlite::SqliteArguments<'q>>> {
+    // 绑定参数
+    for param in params {
+        query = match param {
+            DataValue::String(s) => {
+                // SQLite中字符串直接绑定
+                query.bind(s)
+            }
+            DataValue::Int(i) => query.bind(*i),
+            DataValue::UInt(u) => {
+                // SQLite 不支持 u64 编码，转换为 i64 或字符串
+                if *u <= i64::MAX as u64 {
+                    query.bind(*u as i64)
+                } else {
+                    query.bind(u.to_string())
+                }
+            }
+            DataValue::Float(f) => query.bind(*f),
+            DataValue::Bool(b) => query.bind(i32::from(*b)), // SQLite使用整数表示布尔值
+            DataValue::DateTime(dt) => query.bind(dt.timestamp()),
+            DataValue::DateTimeUTC(dt) => query.bind(dt.timestamp()),
+            DataValue::Uuid(uuid) => query.bind(uuid.to_string()),
+            DataValue::Json(json) => query.bind(json.to_string()),
+            DataValue::Bytes(bytes) => query.bind(bytes.as_slice()),
+            DataValue::Null => query.bind(Option::<String>::None),
+            DataValue::Array(arr) => {
+                // Array字段只支持简单类型：String、Int、Float、Uuid
+                let string_array: Result<Vec<String>, QuickDbError> = arr.iter().map(|item| {
+                    Ok(match item {
+                        DataValue::String(s) => s.clone(),
+                        DataValue::Int(i) => i.to_string(),
+                        DataValue::Float(f) => f.to_string(),
+                        DataValue::Uuid(uuid) => uuid.to_string(),
+                        _ => {
+                            return Err(QuickDbError::ValidationError {
+                                field: "array_field".to_string(),
+                                message: format!("Array字段不支持该类型: {:?}，只支持String、Int、Float、Uuid类型", item),
+                            });
+                        }
+                    })
+                }).collect();
+                let string_array = string_array?;
+                query.bind(serde_json::to_string(&string_array).unwrap_or_default())
+            }
+            // 向量以JSON数组存储
+            DataValue::Vector(vector) => query.bind(serde_json::to_string(vector).unwrap_or_default()),
+            DataValue::Object(obj) => {
+                // 使用 to_json_value() 避免序列化时包含类型标签
+                query.bind(DataValue::Object(obj.clone()).to_json_value().to_string())
+            }
+        };
+    }
+
+    Ok(query)
 }
@@ -0,0 +1,177 @@
+//! SQL 预编译语句缓存模块
+//!
+//! 预编译语句由 sqlx 在每个连接上按 `statement_cache_capacity` 缓存并 LRU 淘汰。
+//! 本模块按数据库别名登记缓存容量，决定查询是否以持久化预编译语句执行
+//! （容量为 0，或 PgBouncer 事务模式时每次都重新预编译），并按 (别名, SQL 形状)
+//! 统计命中率。SQL 形状即占位符形式的 SQL 文本，参数单独绑定
+//!
+//! 统计只使用分片集合与原子计数器，不持有全局锁；已记录的形状数量达到容量后不再
+//! 登记新形状，因此命中率是别名级别的近似值，而非各连接缓存的精确值
+
+use dashmap::{DashMap, DashSet};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+
+/// 全局预编译语句缓存注册表 (别名 -> 缓存)
+static STATEMENT_CACHES: Lazy<DashMap<String, Arc<StatementCache>>> = Lazy::new(DashMap::new);
+
+/// 预编译语句缓存统计
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StatementCacheStats {
+    /// 缓存容量（0 表示禁用）
+    pub capacity: usize,
+    /// 已记录的 SQL 形状数量
+    pub entries: usize,
+    /// 命中次数
+    pub hits: u64,
+    /// 未命中次数
+    pub misses: u64,
+    /// 命中率
+    pub hit_rate: f64,
+}
+
+/// 单个数据库别名的预编译语句缓存统计
+#[derive(Debug)]
+pub struct StatementCache {
+    capacity: usize,
+    /// 已记录的 SQL 形状
+    statements: DashSet<String>,
+    entries: AtomicUsize,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl StatementCache {
+    /// 创建缓存，容量为 0 时禁用
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            statements: DashSet::new(),
+            entries: AtomicUsize::new(0),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// 记录一次 SQL 执行，返回是否应以持久化预编译语句执行
+    pub fn track(&self, sql: &str) -> bool {
+        if self.capacity == 0 {
+            self.misses.fetch_add(1, Ordering::Relaxed);
+            return false;
+        }
+
+        if self.statements.contains(sql) {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            return true;
+        }
+
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        if self.entries.load(Ordering::Relaxed) < self.capacity
+            && self.statements.insert(sql.to_string())
+        {
+            self.entries.fetch_add(1, Ordering::Relaxed);
+        }
+        true
+    }
+
+    /// 获取统计信息
+    pub fn stats(&self) -> StatementCacheStats {
+        let hits = self.hits.load(Ordering::Relaxed);
+        let misses = self.misses.load(Ordering::Relaxed);
+        let total = hits + misses;
+        StatementCacheStats {
+            capacity: self.capacity,
+            entries: self.entries.load(Ordering::Relaxed),
+            hits,
+            misses,
+            hit_rate: if total > 0 {
+                hits as f64 / total as f64
+            } else {
+                0.0
+            },
+        }
+    }
+}
+
+/// 为数据库别名注册预编译语句缓存（重复注册时替换旧缓存）
+pub(crate) fn register_statement_cache(alias: &str, capacity: usize) {
+    STATEMENT_CACHES.insert(alias.to_string(), Arc::new(StatementCache::new(capacity)));
+}
+
+/// 移除数据库别名的预编译语句缓存
+pub(crate) fn remove_statement_cache(alias: &str) {
+    STATEMENT_CACHES.remove(alias);
+}
+
+/// 记录一次 SQL 执行，返回是否应以持久化预编译语句执行
+///
+/// 未注册的别名沿用 sqlx 默认行为（持久化预编译）
+#[cfg(any(
+    feature = "sqlite-support",
+    feature = "postgres-support",
+    feature = "mysql-support"
+))]
+pub(crate) fn track_statement(alias: &str, sql: &str) -> bool {
+    let cache = STATEMENT_CACHES.get(alias).map(|cache| cache.clone());
+    match cache {
+        Some(cache) => cache.track(sql),
+        None => true,
+    }
+}
+
+/// 获取数据库别名的预编译语句缓存统计
+pub fn get_statement_cache_stats(alias: &str) -> Option<StatementCacheStats> {
+    STATEMENT_CACHES.get(alias).map(|cache| cache.stats())
+}
+
+#[cfg(all(
+    test,
+    any(
+        feature = "sqlite-support",
+        feature = "postgres-support",
+        feature = "mysql-support"
+    )
+))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zero_capacity_disables_persistent_statements() {
+        let alias = "statement_cache_zero_capacity";
+        assert!(track_statement(alias, "SELECT 1"));
+
+        register_statement_cache(alias, 0);
+        assert!(!track_statement(alias, "SELECT 1"));
+        let stats = get_statement_cache_stats(alias).unwrap();
+        assert_eq!((stats.hits, stats.misses), (0, 1));
+
+        register_statement_cache(alias, 100);
+        assert!(track_statement(alias, "SELECT 1"));
+
+        remove_statement_cache(alias);
+        assert!(track_statement(alias, "SELECT 1"));
+        assert!(get_statement_cache_stats(alias).is_none());
+    }
+
+    #[test]
+    fn repeated_shapes_count_as_hits() {
+        let alias = "statement_cache_hits";
+        register_statement_cache(alias, 2);
+
+        assert!(track_statement(alias, "SELECT * FROM t WHERE id = ?"));
+        assert!(track_statement(alias, "SELECT * FROM t WHERE id = ?"));
+        assert!(track_statement(alias, "SELECT * FROM t WHERE name = ?"));
+        // 容量已满，新形状仍以持久化方式执行但不再登记
+        assert!(track_statement(alias, "SELECT * FROM t WHERE age = ?"));
+        assert!(track_statement(alias, "SELECT * FROM t WHERE age = ?"));
+
+        let stats = get_statement_cache_stats(alias).unwrap();
+        assert_eq!(stats.capacity, 2);
+        assert_eq!(stats.entries, 2);
+        assert_eq!((stats.hits, stats.misses), (1, 4));
+        assert!((stats.hit_rate - 0.2).abs() < f64::EPSILON);
+        remove_statement_cache(alias);
+    }
+}
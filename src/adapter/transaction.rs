@@ -0,0 +1,187 @@
+//! 事务内写操作
+//!
+//! 在调用方持有的事务连接上执行插入、更新与删除，SQL 生成与参数绑定沿用各适配器的实现
+
+use crate::error::QuickDbResult;
+use crate::types::*;
+use std::collections::HashMap;
+
+/// 事务内的写操作
+#[derive(Debug, Clone)]
+pub(crate) enum TransactionWrite {
+    Insert(HashMap<String, DataValue>),
+    Update {
+        conditions: Vec<QueryConditionWithConfig>,
+        data: HashMap<String, DataValue>,
+    },
+    Delete(Vec<QueryConditionWithConfig>),
+}
+
+impl TransactionWrite {
+    /// 已注册模型的写入数据按字段元数据转换，与适配器的普通写入一致
+    pub(crate) fn process_data(data: &HashMap<String, DataValue>, table: &str, alias: &str) -> HashMap<String, DataValue> {
+        match crate::manager::get_model_with_alias(table, alias) {
+            Some(model_meta) => {
+                let fields: HashMap<String, crate::model::FieldDefinition> = model_meta.fields.into_iter().collect();
+                crate::utils::timezone::process_data_fields_from_metadata(data.clone(), &fields)
+            }
+            None => data.clone(),
+        }
+    }
+}
+
+/// 生成 SQL 的分支对三种 SQL 构建器相同
+macro_rules! build_write {
+    ($builder:ty, $write:expr, $table:expr, $alias:expr) => {
+        match $write {
+            TransactionWrite::Insert(data) => <$builder>::new()
+                .insert(TransactionWrite::process_data(data, $table, $alias))
+                .build($table, $alias),
+            TransactionWrite::Update { conditions, data } => <$builder>::new()
+                .update(TransactionWrite::process_data(data, $table, $alias))
+                .where_conditions(conditions)
+                .build($table, $alias),
+            TransactionWrite::Delete(conditions) => <$builder>::new().delete().where_conditions(conditions).build($table, $alias),
+        }
+    };
+}
+
+/// 在 SQLite 事务连接上执行写操作，返回受影响行数
+#[cfg(feature = "sqlite-support")]
+pub(crate) async fn execute_sqlite_write(
+    connection: &mut sqlx::SqliteConnection,
+    table: &str,
+    alias: &str,
+    write: &TransactionWrite,
+) -> QuickDbResult<u64> {
+    let (sql, params) = build_write!(super::sqlite::SqlQueryBuilder, write, table, alias)?;
+    super::SqliteAdapter::new().execute_update(connection, table, &sql, &params).await
+}
+
+/// 在 PostgreSQL 事务连接上执行写操作，返回受影响行数
+#[cfg(feature = "postgres-support")]
+pub(crate) async fn execute_postgres_write(
+    connection: &mut sqlx::PgConnection,
+    table: &str,
+    alias: &str,
+    write: &TransactionWrite,
+) -> QuickDbResult<u64> {
+    let (sql, params) = build_write!(super::postgres::query_builder::SqlQueryBuilder, write, table, alias)?;
+    super::postgres::utils::execute_update(&super::PostgresAdapter::new(), connection, &sql, &params, table).await
+}
+
+/// 在 MySQL 事务连接上执行写操作，返回受影响行数
+#[cfg(feature = "mysql-support")]
+pub(crate) async fn execute_mysql_write(
+    connection: &mut sqlx::MySqlConnection,
+    table: &str,
+    alias: &str,
+    write: &TransactionWrite,
+) -> QuickDbResult<u64> {
+    let (sql, params) = build_write!(super::mysql::query_builder::SqlQueryBuilder, write, table, alias)?;
+    super::MysqlAdapter::new().execute_update(connection, &sql, &params, table).await
+}
+
+/// 批量写入的 SQL 实现对三种数据库相同：一个事务，每个操作一个保存点，
+/// 失败的操作只回滚自己的保存点
+macro_rules! execute_bulk {
+    ($pool:expr, $db_type:expr, $execute:path, $table:expr, $alias:expr, $ops:expr, $mode:expr) => {{
+        // 插入的数据补齐字段默认值，与适配器的普通插入一致
+        let insert = |data: &HashMap<String, DataValue>| -> QuickDbResult<TransactionWrite> {
+            let mut data = data.clone();
+            super::utils::apply_field_defaults($table, $alias, $db_type, &mut data)?;
+            Ok(TransactionWrite::Insert(data))
+        };
+        let failed = |e: sqlx::Error| crate::error::QuickDbError::QueryError {
+            message: crate::i18n::tf("bulk.transaction_failed", &[("error", &e.to_string())]),
+        };
+        let mut tx = $pool.begin().await.map_err(failed)?;
+        let mut report = BulkWriteReport::skipped($ops.len());
+        for (outcome, op) in report.outcomes.iter_mut().zip($ops) {
+            let mut savepoint = sqlx::Connection::begin(&mut *tx).await.map_err(failed)?;
+            let result = match op {
+                BulkOp::Insert(data) => match insert(data) {
+                    Ok(write) => $execute(&mut savepoint, $table, $alias, &write).await,
+                    Err(e) => Err(e),
+                },
+                BulkOp::Update { conditions, data } => {
+                    let write = TransactionWrite::Update {
+                        conditions: conditions.clone(),
+                        data: data.clone(),
+                    };
+                    $execute(&mut savepoint, $table, $alias, &write).await
+                }
+                BulkOp::Delete(conditions) => {
+                    $execute(&mut savepoint, $table, $alias, &TransactionWrite::Delete(conditions.clone())).await
+                }
+                BulkOp::Upsert { data, conflict_columns } => match super::utils::conflict_conditions(data, conflict_columns) {
+                    Ok(conditions) => {
+                        let update = TransactionWrite::Update {
+                            conditions,
+                            data: data.clone(),
+                        };
+                        match $execute(&mut savepoint, $table, $alias, &update).await {
+                            Ok(0) => match insert(data) {
+                                Ok(write) => $execute(&mut savepoint, $table, $alias, &write).await,
+                                Err(e) => Err(e),
+                            },
+                            other => other,
+                        }
+                    }
+                    Err(e) => Err(e),
+                },
+            };
+            match result {
+                Ok(affected) => {
+                    savepoint.commit().await.map_err(failed)?;
+                    *outcome = BulkOpOutcome::Applied { affected };
+                }
+                Err(e) => {
+                    savepoint.rollback().await.map_err(failed)?;
+                    *outcome = BulkOpOutcome::Failed { message: e.to_string() };
+                    if $mode == BulkWriteMode::Ordered {
+                        break;
+                    }
+                }
+            }
+        }
+        tx.commit().await.map_err(failed)?;
+        Ok(report)
+    }};
+}
+
+/// 在 SQLite 事务中批量执行写操作
+#[cfg(feature = "sqlite-support")]
+pub(crate) async fn execute_sqlite_bulk(
+    pool: &sqlx::SqlitePool,
+    table: &str,
+    alias: &str,
+    ops: &[BulkOp],
+    mode: BulkWriteMode,
+) -> QuickDbResult<BulkWriteReport> {
+    execute_bulk!(pool, DatabaseType::SQLite, execute_sqlite_write, table, alias, ops, mode)
+}
+
+/// 在 PostgreSQL 事务中批量执行写操作
+#[cfg(feature = "postgres-support")]
+pub(crate) async fn execute_postgres_bulk(
+    pool: &sqlx::PgPool,
+    table: &str,
+    alias: &str,
+    ops: &[BulkOp],
+    mode: BulkWriteMode,
+) -> QuickDbResult<BulkWriteReport> {
+    execute_bulk!(pool, DatabaseType::PostgreSQL, execute_postgres_write, table, alias, ops, mode)
+}
+
+/// 在 MySQL 事务中批量执行写操作
+#[cfg(feature = "mysql-support")]
+pub(crate) async fn execute_mysql_bulk(
+    pool: &sqlx::MySqlPool,
+    table: &str,
+    alias: &str,
+    ops: &[BulkOp],
+    mode: BulkWriteMode,
+) -> QuickDbResult<BulkWriteReport> {
+    execute_bulk!(pool, DatabaseType::MySQL, execute_mysql_write, table, alias, ops, mode)
+}
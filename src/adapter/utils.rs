@@ -1,5 +1,9 @@
 //! 数据库适配器通用工具模块
 
+use crate::error::{QuickDbError, QuickDbResult};
+use crate::security::quote_identifier;
+use crate::types::{DataValue, DatabaseType, QueryConditionWithConfig, QueryOperator};
+
 /// 获取字段的类型定义
 ///
 /// # 参数
@@ -25,3 +29,631 @@ pub fn get_field_type(
         None
     }
 }
+
+/// 获取模型声明的复合/自定义主键字段
+///
+/// 仅当模型通过 `primary_key` 声明了非默认的主键（不是单一的 `id`）时返回 `Some`
+#[cfg(any(
+    feature = "sqlite-support",
+    feature = "postgres-support",
+    feature = "mysql-support",
+    feature = "mongodb-support"
+))]
+pub(crate) fn get_custom_primary_key(table_name: &str, alias: &str) -> Option<Vec<String>> {
+    crate::manager::get_model_with_alias(table_name, alias)
+        .filter(|model_meta| model_meta.has_custom_primary_key())
+        .map(|model_meta| model_meta.primary_key)
+}
+
+/// 获取查询选项中的投影字段，未指定时选择所有字段
+#[cfg(any(
+    feature = "sqlite-support",
+    feature = "postgres-support",
+    feature = "mysql-support"
+))]
+pub(crate) fn projection_fields(options: &crate::types::QueryOptions) -> Vec<&str> {
+    if options.fields.is_empty() {
+        vec!["*"]
+    } else {
+        options.fields.iter().map(String::as_str).collect()
+    }
+}
+
+/// 构建大小写不敏感的比较子句（SQL 适配器共用）
+///
+/// 适用于开启 `case_insensitive` 的 Eq / Ne（字符串值）与 In（全部为字符串的数组），
+/// 两侧统一使用 `LOWER()` 比较，与大小写不敏感的表达式索引保持一致。
+/// 数组、JSON、UUID 等非文本字段以及其他操作符返回 `None`，由调用方按原逻辑处理
+#[cfg(any(
+    feature = "sqlite-support",
+    feature = "postgres-support",
+    feature = "mysql-support"
+))]
+pub(crate) fn case_insensitive_clause(
+    condition: &QueryConditionWithConfig,
+    safe_field: &str,
+    table: &str,
+    alias: &str,
+    placeholder: impl Fn(usize) -> String,
+) -> Option<(String, Vec<DataValue>)> {
+    use crate::model::FieldType;
+
+    if !condition.case_insensitive {
+        return None;
+    }
+    let text_field = match get_field_type(table, alias, &condition.field) {
+        None => true,
+        Some(field_type) => matches!(field_type, FieldType::String { .. } | FieldType::Text),
+    };
+    if !text_field {
+        return None;
+    }
+
+    let column = format!("LOWER({})", safe_field);
+    match (&condition.operator, &condition.value) {
+        (QueryOperator::Eq, DataValue::String(_)) => Some((
+            format!("{} = LOWER({})", column, placeholder(0)),
+            vec![condition.value.clone()],
+        )),
+        (QueryOperator::Ne, DataValue::String(_)) => Some((
+            format!("{} != LOWER({})", column, placeholder(0)),
+            vec![condition.value.clone()],
+        )),
+        (QueryOperator::In, DataValue::Array(values))
+            if !values.is_empty() && values.iter().all(|value| matches!(value, DataValue::String(_))) =>
+        {
+            let placeholders: Vec<String> = (0..values.len())
+                .map(|index| format!("LOWER({})", placeholder(index)))
+                .collect();
+            Some((format!("{} IN ({})", column, placeholders.join(", ")), values.clone()))
+        }
+        _ => None,
+    }
+}
+
+/// 根据主键声明决定建表时id列的约束
+///
+/// - 默认主键：`PRIMARY KEY`
+/// - id 属于复合主键的一部分：`NOT NULL`（主键约束在表级声明）
+/// - id 不属于自定义主键：`UNIQUE`（保持按ID访问的能力）
+#[cfg(any(
+    feature = "sqlite-support",
+    feature = "postgres-support",
+    feature = "mysql-support"
+))]
+pub(crate) fn id_column_constraint(custom_primary_key: Option<&[String]>) -> &'static str {
+    match custom_primary_key {
+        None => "PRIMARY KEY",
+        Some(pk) if pk.iter().any(|f| f == "id") => "NOT NULL",
+        Some(_) => "UNIQUE",
+    }
+}
+
+/// 序列计数器表名（MySQL/SQLite 没有原生序列，使用计数器表模拟）
+#[cfg(any(feature = "sqlite-support", feature = "mysql-support"))]
+pub(crate) const SEQUENCE_TABLE: &str = "_quickdb_sequences";
+
+/// 获取模型中的序列字段
+///
+/// 返回 `(字段名, 序列名)` 列表，表没有注册模型元数据时返回空列表
+#[cfg(any(
+    feature = "sqlite-support",
+    feature = "postgres-support",
+    feature = "mysql-support",
+    feature = "mongodb-support"
+))]
+pub(crate) fn get_sequence_fields(table_name: &str, alias: &str) -> Vec<(String, String)> {
+    crate::manager::get_model_with_alias(table_name, alias)
+        .map(|model_meta| sequence_fields_of(&model_meta.fields))
+        .unwrap_or_default()
+}
+
+/// Upsert 的冲突列条件，冲突列必须出现在写入数据中
+#[cfg(any(
+    feature = "sqlite-support",
+    feature = "postgres-support",
+    feature = "mysql-support",
+    feature = "mongodb-support"
+))]
+pub(crate) fn conflict_conditions(
+    data: &std::collections::HashMap<String, DataValue>,
+    conflict_columns: &[String],
+) -> QuickDbResult<Vec<QueryConditionWithConfig>> {
+    if conflict_columns.is_empty() {
+        return Err(QuickDbError::ValidationError {
+            field: "conflict_columns".to_string(),
+            message: crate::i18n::t("bulk.conflict_columns_empty"),
+        });
+    }
+    conflict_columns
+        .iter()
+        .map(|column| match data.get(column) {
+            Some(value) => Ok(QueryConditionWithConfig {
+                field: column.clone(),
+                operator: QueryOperator::Eq,
+                value: value.clone(),
+                case_insensitive: false,
+            }),
+            None => Err(QuickDbError::ValidationError {
+                field: column.clone(),
+                message: crate::i18n::tf("bulk.conflict_value_missing", &[("column", column)]),
+            }),
+        })
+        .collect()
+}
+
+/// 为插入数据填充字段默认值（表没有注册模型元数据时不做处理）
+#[cfg(any(
+    feature = "sqlite-support",
+    feature = "postgres-support",
+    feature = "mysql-support",
+    feature = "mongodb-support"
+))]
+pub(crate) fn apply_field_defaults(
+    table_name: &str,
+    alias: &str,
+    db_type: DatabaseType,
+    data: &mut std::collections::HashMap<String, crate::types::DataValue>,
+) -> QuickDbResult<()> {
+    match crate::manager::get_model_with_alias(table_name, alias) {
+        Some(model_meta) => crate::model::defaults::apply_defaults(&model_meta.fields, db_type, data),
+        None => Ok(()),
+    }
+}
+
+/// 从字段定义中提取序列字段
+#[cfg(any(
+    feature = "sqlite-support",
+    feature = "postgres-support",
+    feature = "mysql-support",
+    feature = "mongodb-support"
+))]
+pub(crate) fn sequence_fields_of(
+    fields: &std::collections::HashMap<String, crate::model::FieldDefinition>,
+) -> Vec<(String, String)> {
+    let mut sequence_fields: Vec<(String, String)> = fields
+        .iter()
+        .filter_map(|(field_name, field_def)| match &field_def.field_type {
+            crate::model::FieldType::Sequence { name } => Some((field_name.clone(), name.clone())),
+            _ => None,
+        })
+        .collect();
+    // 保证分配顺序稳定
+    sequence_fields.sort();
+    sequence_fields
+}
+
+/// 生成索引键列表
+///
+/// 字段在前，表达式作为函数索引键追加在后（SQLite/PostgreSQL/MySQL 均接受 `(expr)` 形式）
+pub(crate) fn index_key_parts(
+    fields: &[String],
+    expression: Option<&str>,
+    db_type: DatabaseType,
+) -> QuickDbResult<String> {
+    let mut parts: Vec<String> = fields
+        .iter()
+        .map(|f| quote_identifier(f, db_type))
+        .collect();
+
+    if let Some(expression) = expression {
+        // 表达式不能参数化，禁止语句分隔符和注释以免拼接出额外语句
+        let expression = expression.trim();
+        if expression.is_empty()
+            || expression.contains(';')
+            || expression.contains("--")
+            || expression.contains("/*")
+        {
+            return Err(QuickDbError::ValidationError {
+                field: "expression".to_string(),
+                message: crate::i18n::tf(
+                    "model.index_expression_invalid",
+                    &[("expression", expression)],
+                ),
+            });
+        }
+        parts.push(format!("({})", expression));
+    }
+
+    Ok(parts.join(", "))
+}
+
+/// 将部分索引条件渲染为 SQL 谓词（多个条件以 AND 连接）
+///
+/// CREATE INDEX 不支持参数绑定，条件值以转义后的字面量内联
+pub(crate) fn render_index_predicate(
+    conditions: &[QueryConditionWithConfig],
+    db_type: DatabaseType,
+) -> QuickDbResult<String> {
+    if matches!(db_type, DatabaseType::MySQL | DatabaseType::MongoDB) {
+        return Err(QuickDbError::ValidationError {
+            field: "where_clause".to_string(),
+            message: crate::i18n::tf(
+                "model.partial_index_unsupported",
+                &[("database", &format!("{:?}", db_type))],
+            ),
+        });
+    }
+
+    let mut predicates = Vec::with_capacity(conditions.len());
+    for condition in conditions {
+        let column = quote_identifier(&condition.field, db_type);
+        let lower = condition.case_insensitive && matches!(condition.value, DataValue::String(_));
+        let column = if lower { format!("LOWER({})", column) } else { column };
+        let literal = |value: &DataValue| -> QuickDbResult<String> {
+            let literal = index_literal(&condition.field, value, db_type)?;
+            Ok(if lower { format!("LOWER({})", literal) } else { literal })
+        };
+
+        let predicate = match (&condition.operator, &condition.value) {
+            (QueryOperator::Eq, DataValue::Null) | (QueryOperator::IsNull, _) => {
+                format!("{} IS NULL", column)
+            }
+            (QueryOperator::Ne, DataValue::Null) | (QueryOperator::IsNotNull, _) => {
+                format!("{} IS NOT NULL", column)
+            }
+            (QueryOperator::Eq, value) => format!("{} = {}", column, literal(value)?),
+            (QueryOperator::Ne, value) => format!("{} <> {}", column, literal(value)?),
+            (QueryOperator::Gt, value) => format!("{} > {}", column, literal(value)?),
+            (QueryOperator::Gte, value) => format!("{} >= {}", column, literal(value)?),
+            (QueryOperator::Lt, value) => format!("{} < {}", column, literal(value)?),
+            (QueryOperator::Lte, value) => format!("{} <= {}", column, literal(value)?),
+            (QueryOperator::In, DataValue::Array(values))
+            | (QueryOperator::NotIn, DataValue::Array(values)) => {
+                let values = values.iter().map(literal).collect::<QuickDbResult<Vec<_>>>()?;
+                let keyword = if condition.operator == QueryOperator::In { "IN" } else { "NOT IN" };
+                format!("{} {} ({})", column, keyword, values.join(", "))
+            }
+            (QueryOperator::Between, value) | (QueryOperator::NotBetween, value) => {
+                let (lower_bound, upper_bound) = crate::types::query::between_bounds(&condition.field, value)?;
+                let keyword = if condition.operator == QueryOperator::Between { "BETWEEN" } else { "NOT BETWEEN" };
+                format!("{} {} {} AND {}", column, keyword, literal(lower_bound)?, literal(upper_bound)?)
+            }
+            (operator, _) => {
+                return Err(QuickDbError::ValidationError {
+                    field: "where_clause".to_string(),
+                    message: crate::i18n::tf(
+                        "model.index_operator_unsupported",
+                        &[("operator", &format!("{:?}", operator))],
+                    ),
+                });
+            }
+        };
+        predicates.push(predicate);
+    }
+
+    Ok(predicates.join(" AND "))
+}
+
+/// 将条件值转换为 SQL 字面量
+fn index_literal(field: &str, value: &DataValue, db_type: DatabaseType) -> QuickDbResult<String> {
+    let quote = |s: &str| format!("'{}'", s.replace('\'', "''"));
+    match value {
+        DataValue::String(s) => Ok(quote(s)),
+        DataValue::Int(i) => Ok(i.to_string()),
+        DataValue::UInt(u) => Ok(u.to_string()),
+        DataValue::Float(f) if f.is_finite() => Ok(f.to_string()),
+        DataValue::Bool(b) => Ok(match db_type {
+            DatabaseType::PostgreSQL => if *b { "TRUE" } else { "FALSE" }.to_string(),
+            _ => if *b { "1" } else { "0" }.to_string(),
+        }),
+        DataValue::DateTime(dt) => Ok(quote(&dt.to_rfc3339())),
+        DataValue::DateTimeUTC(dt) => Ok(quote(&dt.to_rfc3339())),
+        DataValue::Uuid(uuid) => Ok(quote(&uuid.to_string())),
+        other => Err(QuickDbError::ValidationError {
+            field: "where_clause".to_string(),
+            message: crate::i18n::tf(
+                "model.index_value_unsupported",
+                &[("field", field), ("value_type", other.type_name())],
+            ),
+        }),
+    }
+}
+
+/// 移除字段（`Unset`）与重命名字段（`Rename`）的 `SET` 子句
+///
+/// 字段名不含 `.` 时作用于整列：`Unset` 置为 NULL，`Rename` 把值移到目标列并清空原列
+/// （目标列在前，MySQL 按顺序求值时也能读到原值）；`列.键.子键` 形式作用于 JSON 列内部，
+/// 重命名的源和目标必须位于同一列。路径直接写入 SQL，因此只允许字母、数字和下划线。
+/// 每个操作生成独立的赋值，同一次更新中不能对同一 JSON 列执行多个操作
+#[cfg(any(
+    feature = "sqlite-support",
+    feature = "postgres-support",
+    feature = "mysql-support"
+))]
+pub(crate) fn structural_set_clauses(
+    db_type: DatabaseType,
+    operation: &crate::types::UpdateOperation,
+) -> QuickDbResult<Vec<String>> {
+    use crate::types::UpdateOperator;
+
+    let (column, path) = split_update_path(&operation.field)?;
+    let safe_column = quote_identifier(column, db_type);
+    let json_path = |path: &[&str]| match db_type {
+        DatabaseType::PostgreSQL => format!("'{{{}}}'", path.join(",")),
+        _ => format!("'$.{}'", path.join(".")),
+    };
+
+    if operation.operation == UpdateOperator::Unset {
+        if path.is_empty() {
+            return Ok(vec![format!("{} = NULL", safe_column)]);
+        }
+        let path = json_path(&path);
+        return Ok(vec![match db_type {
+            DatabaseType::PostgreSQL => format!("{} = {} #- {}", safe_column, safe_column, path),
+            DatabaseType::MySQL => format!("{} = JSON_REMOVE({}, {})", safe_column, safe_column, path),
+            _ => format!("{} = json_remove({}, {})", safe_column, safe_column, path),
+        }]);
+    }
+
+    let invalid_target = || QuickDbError::ValidationError {
+        field: operation.field.clone(),
+        message: crate::i18n::tf("model.rename_target_invalid", &[("field", &operation.field)]),
+    };
+    let DataValue::String(target) = &operation.value else {
+        return Err(invalid_target());
+    };
+    let (target_column, target_path) = split_update_path(target)?;
+    if path.is_empty() != target_path.is_empty() || (!path.is_empty() && target_column != column) {
+        return Err(invalid_target());
+    }
+    if path.is_empty() {
+        return Ok(vec![
+            format!("{} = {}", quote_identifier(target_column, db_type), safe_column),
+            format!("{} = NULL", safe_column),
+        ]);
+    }
+
+    let (from, to) = (json_path(&path), json_path(&target_path));
+    let c = &safe_column;
+    Ok(vec![match db_type {
+        DatabaseType::PostgreSQL => format!(
+            "{c} = CASE WHEN {c} #> {from} IS NULL THEN {c} ELSE jsonb_set({c} #- {from}, {to}, {c} #> {from}) END"
+        ),
+        DatabaseType::MySQL => format!(
+            "{c} = IF(JSON_CONTAINS_PATH({c}, 'one', {from}), JSON_SET(JSON_REMOVE({c}, {from}), {to}, JSON_EXTRACT({c}, {from})), {c})"
+        ),
+        _ => format!(
+            "{c} = CASE WHEN json_type({c}, {from}) IS NULL THEN {c} ELSE json_set(json_remove({c}, {from}), {to}, json({c} -> {from})) END"
+        ),
+    }])
+}
+
+/// 拆分更新字段路径为列名和 JSON 键路径
+#[cfg(any(
+    feature = "sqlite-support",
+    feature = "postgres-support",
+    feature = "mysql-support"
+))]
+fn split_update_path(field: &str) -> QuickDbResult<(&str, Vec<&str>)> {
+    let mut segments = field.split('.');
+    let column = segments.next().unwrap_or_default();
+    let path: Vec<&str> = segments.collect();
+    let valid = |segment: &&str| !segment.is_empty() && segment.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if column.is_empty() || !path.iter().all(valid) {
+        return Err(QuickDbError::ValidationError {
+            field: field.to_string(),
+            message: crate::i18n::tf("model.update_path_invalid", &[("field", field)]),
+        });
+    }
+    Ok((column, path))
+}
+
+/// 将 sqlx 的唯一约束冲突错误转换为 `QuickDbError::UniqueViolation`
+///
+/// 驱动错误码由 sqlx 统一识别（PostgreSQL 23505、MySQL 1062、SQLite 2067/1555），
+/// 冲突字段从各数据库的错误信息中解析，非唯一约束错误返回 None
+#[cfg(any(
+    feature = "sqlite-support",
+    feature = "postgres-support",
+    feature = "mysql-support"
+))]
+pub(crate) fn sqlx_unique_violation(error: &sqlx::Error, table: &str) -> Option<QuickDbError> {
+    let db_err = error.as_database_error()?;
+    if !db_err.is_unique_violation() {
+        return None;
+    }
+
+    let message = db_err.message();
+    let mut fields = sqlite_unique_violation_fields(message);
+
+    #[cfg(feature = "postgres-support")]
+    if fields.is_empty() {
+        if let Some(detail) = db_err
+            .try_downcast_ref::<sqlx::postgres::PgDatabaseError>()
+            .and_then(|pg_err| pg_err.detail())
+        {
+            fields = postgres_unique_violation_fields(detail);
+        }
+    }
+
+    if fields.is_empty() {
+        let constraint = db_err
+            .constraint()
+            .map(str::to_string)
+            .or_else(|| mysql_duplicate_key_name(message));
+        if let Some(constraint) = constraint {
+            fields = resolve_index_fields(table, &constraint).unwrap_or_else(|| vec![constraint]);
+        }
+    }
+
+    Some(QuickDbError::UniqueViolation {
+        table: table.to_string(),
+        fields,
+    })
+}
+
+/// 通过注册的模型元数据将约束/索引名解析为字段列表
+#[cfg(any(
+    feature = "sqlite-support",
+    feature = "postgres-support",
+    feature = "mysql-support",
+    feature = "mongodb-support"
+))]
+pub(crate) fn resolve_index_fields(table: &str, index_name: &str) -> Option<Vec<String>> {
+    let manager = crate::manager::get_global_pool_manager();
+    // 配置了表名装饰的别名下，表名与索引名都是装饰后的物理名称
+    let physical = |meta: &crate::model::ModelMeta, name: &str| match meta.database_alias.as_deref() {
+        Some(alias) => manager.physical_table_name(name, alias),
+        None => name.to_string(),
+    };
+    let meta = manager
+        .model_registry
+        .iter()
+        .find(|entry| physical(entry.value(), &entry.value().qualified_name()) == table)
+        .map(|entry| entry.value().clone())?;
+
+    if index_name == "PRIMARY" || index_name == format!("{}_pkey", table) {
+        return Some(meta.primary_key_fields());
+    }
+
+    meta.indexes
+        .iter()
+        .find(|index| {
+            let default_name = format!("idx_{}", index.fields.join("_"));
+            physical(&meta, index.name.as_deref().unwrap_or(&default_name)) == index_name
+        })
+        .map(|index| index.fields.clone())
+}
+
+/// 解析 SQLite 的冲突字段：`UNIQUE constraint failed: users.tenant_id, users.email`
+#[cfg(any(
+    feature = "sqlite-support",
+    feature = "postgres-support",
+    feature = "mysql-support"
+))]
+fn sqlite_unique_violation_fields(message: &str) -> Vec<String> {
+    let Some((_, columns)) = message.split_once("constraint failed:") else {
+        return Vec::new();
+    };
+    columns
+        .split(',')
+        .map(|column| {
+            let column = column.trim();
+            column.rsplit_once('.').map_or(column, |(_, name)| name).to_string()
+        })
+        .filter(|column| !column.is_empty())
+        .collect()
+}
+
+/// 解析 PostgreSQL 的冲突字段：`Key (tenant_id, email)=(1, a@b.c) already exists.`
+#[cfg(feature = "postgres-support")]
+fn postgres_unique_violation_fields(detail: &str) -> Vec<String> {
+    let Some(columns) = detail
+        .strip_prefix("Key (")
+        .and_then(|rest| rest.split_once(")=").map(|(columns, _)| columns))
+    else {
+        return Vec::new();
+    };
+    columns
+        .split(',')
+        .map(|column| column.trim().trim_matches('"').to_string())
+        .filter(|column| !column.is_empty())
+        .collect()
+}
+
+/// 解析 MySQL 冲突的索引名：`Duplicate entry 'x' for key 'users.idx_email'`
+#[cfg(any(
+    feature = "sqlite-support",
+    feature = "postgres-support",
+    feature = "mysql-support"
+))]
+fn mysql_duplicate_key_name(message: &str) -> Option<String> {
+    let (_, key) = message.rsplit_once("for key '")?;
+    let key = key.strip_suffix('\'').unwrap_or(key);
+    // MySQL 8 会带上表名前缀
+    Some(key.rsplit_once('.').map_or(key, |(_, name)| name).to_string())
+}
+
+#[cfg(all(
+    test,
+    any(
+        feature = "sqlite-support",
+        feature = "postgres-support",
+        feature = "mysql-support"
+    )
+))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_sqlite_unique_violation_fields() {
+        assert_eq!(
+            sqlite_unique_violation_fields("UNIQUE constraint failed: users.tenant_id, users.email"),
+            vec!["tenant_id".to_string(), "email".to_string()]
+        );
+        assert!(sqlite_unique_violation_fields("database is locked").is_empty());
+    }
+
+    #[test]
+    fn parse_mysql_duplicate_key_name() {
+        assert_eq!(
+            mysql_duplicate_key_name("Duplicate entry 'a@b.c' for key 'users.idx_email'"),
+            Some("idx_email".to_string())
+        );
+        assert_eq!(
+            mysql_duplicate_key_name("Duplicate entry '1' for key 'PRIMARY'"),
+            Some("PRIMARY".to_string())
+        );
+    }
+
+    #[cfg(feature = "postgres-support")]
+    #[test]
+    fn parse_postgres_unique_violation_fields() {
+        assert_eq!(
+            postgres_unique_violation_fields("Key (tenant_id, email)=(1, a@b.c) already exists."),
+            vec!["tenant_id".to_string(), "email".to_string()]
+        );
+    }
+
+    #[test]
+    fn structural_operations_render_per_database() {
+        use crate::types::UpdateOperation;
+
+        crate::i18n::ErrorMessageI18n::init_i18n();
+        let rename = UpdateOperation::rename("profile.nick", "profile.display_name");
+        assert_eq!(
+            structural_set_clauses(DatabaseType::PostgreSQL, &rename).unwrap(),
+            vec![concat!(
+                r#""profile" = CASE WHEN "profile" #> '{nick}' IS NULL THEN "profile" "#,
+                r#"ELSE jsonb_set("profile" #- '{nick}', '{display_name}', "profile" #> '{nick}') END"#
+            )]
+        );
+        assert_eq!(
+            structural_set_clauses(DatabaseType::MySQL, &UpdateOperation::unset("profile.nick")).unwrap(),
+            vec!["`profile` = JSON_REMOVE(`profile`, '$.nick')"]
+        );
+        assert_eq!(
+            structural_set_clauses(DatabaseType::SQLite, &UpdateOperation::rename("nick", "display_name")).unwrap(),
+            vec![r#""display_name" = "nick""#, r#""nick" = NULL"#]
+        );
+        // 路径直接拼入 SQL，非法字符与跨列重命名都会被拒绝
+        assert!(structural_set_clauses(DatabaseType::SQLite, &UpdateOperation::unset("profile.a'b")).is_err());
+        assert!(structural_set_clauses(DatabaseType::SQLite, &UpdateOperation::rename("a.x", "b.x")).is_err());
+    }
+
+    #[cfg(feature = "sqlite-support")]
+    #[tokio::test]
+    async fn sqlite_unsets_and_renames_json_keys() {
+        use crate::types::UpdateOperation;
+
+        let pool = sqlx::SqlitePool::connect("sqlite::memory:").await.unwrap();
+        sqlx::query(r#"CREATE TABLE users (profile TEXT)"#).execute(&pool).await.unwrap();
+        sqlx::query(r#"INSERT INTO users VALUES ('{"nick":{"first":"ada"},"age":36,"tmp":1}'), ('{"age":1}')"#)
+            .execute(&pool)
+            .await
+            .unwrap();
+        let mut clauses = structural_set_clauses(DatabaseType::SQLite, &UpdateOperation::unset("profile.tmp")).unwrap();
+        let rename = UpdateOperation::rename("profile.nick", "profile.name");
+        clauses.extend(structural_set_clauses(DatabaseType::SQLite, &rename).unwrap());
+        for clause in clauses {
+            sqlx::query(&format!("UPDATE users SET {}", clause)).execute(&pool).await.unwrap();
+        }
+
+        let profiles: Vec<String> = sqlx::query_scalar("SELECT profile FROM users ORDER BY rowid")
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+        assert_eq!(profiles, [r#"{"age":36,"name":{"first":"ada"}}"#, r#"{"age":1}"#]);
+    }
+}
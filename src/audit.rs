@@ -0,0 +1,392 @@
+//! # 审计日志模块
+//!
+//! 声明 `audit = true` 的模型在每次创建、更新、删除后，把变更前后的记录快照、
+//! 操作者与时间写入 `_rqdb_audit` 表/集合，供 [`get_audit_trail`] 查询。
+//!
+//! 操作者来自任务局部上下文，未设置时取 [`crate::context`] 中的用户 ID：
+//!
+//! ```ignore
+//! audit::with_actor("user:42", async {
+//!     User::update_many(conditions, updates).await
+//! })
+//! .await?;
+//!
+//! let trail = audit::get_audit_trail("users", &user_id, None).await?;
+//! ```
+//!
+//! 快照在调用方任务中通过 ODM 读取，按条件更新或删除多条记录时每条记录写一条审计。
+//! 审计写入失败只记录错误日志，不影响已完成的业务写入
+
+use crate::error::{QuickDbError, QuickDbResult};
+use crate::manager::get_global_pool_manager;
+use crate::model::{FieldDefinition, FieldType, IndexDefinition, IndexOptions, ModelMeta, bigint_field, integer_field, string_field};
+use crate::odm::{AsyncOdmManager, OdmOperations};
+use crate::types::*;
+use chrono::{DateTime, Utc};
+use rat_logger::{debug, error};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::atomic::{AtomicI64, Ordering};
+
+/// 审计表名
+pub const AUDIT_TABLE: &str = "_rqdb_audit";
+
+tokio::task_local! {
+    static ACTOR: String;
+}
+
+/// 在指定操作者上下文中执行 `future`，期间的审计记录以该操作者署名
+pub async fn with_actor<F: Future>(actor: impl Into<String>, future: F) -> F::Output {
+    ACTOR.scope(actor.into(), future).await
+}
+
+/// 当前任务的操作者，未通过 [`with_actor`] 设置时为操作上下文的用户 ID
+pub fn current_actor() -> Option<String> {
+    ACTOR
+        .try_with(Clone::clone)
+        .ok()
+        .or_else(|| crate::context::current_context().and_then(|context| context.user_id))
+}
+
+/// 审计动作
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditAction {
+    Create,
+    Update,
+    Upsert,
+    Delete,
+}
+
+impl AuditAction {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Create => "create",
+            Self::Update => "update",
+            Self::Upsert => "upsert",
+            Self::Delete => "delete",
+        }
+    }
+
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "create" => Some(Self::Create),
+            "update" => Some(Self::Update),
+            "upsert" => Some(Self::Upsert),
+            "delete" => Some(Self::Delete),
+            _ => None,
+        }
+    }
+}
+
+/// 一条审计记录
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditEntry {
+    /// 被审计的表名
+    pub table: String,
+    /// 记录 ID（复合主键为主键值的 JSON 对象字符串）
+    pub record_id: String,
+    /// 审计动作
+    pub action: AuditAction,
+    /// 操作者，未通过 [`with_actor`] 或操作上下文设置时为空
+    pub actor: Option<String>,
+    /// 发起变更的请求 ID（来自操作上下文）
+    #[serde(default)]
+    pub request_id: Option<String>,
+    /// 变更前的记录（创建时为空）
+    pub before: Option<serde_json::Value>,
+    /// 变更后的记录（删除时为空）
+    pub after: Option<serde_json::Value>,
+    /// 变更时间
+    pub changed_at: DateTime<Utc>,
+}
+
+/// 查询记录的审计轨迹，按变更时间先后排序
+pub async fn get_audit_trail(table: &str, id: &str, alias: Option<&str>) -> QuickDbResult<Vec<AuditEntry>> {
+    let alias = resolve_alias(alias).await;
+    if get_global_pool_manager().get_model_with_alias(AUDIT_TABLE, &alias).is_none() {
+        return Ok(Vec::new());
+    }
+
+    let conditions = vec![equals("table_name", table), equals("record_id", id)];
+    let options = QueryOptions::new().with_sort(vec![SortConfig {
+        field: "sequence".to_string(),
+        direction: SortDirection::Asc,
+    }]);
+    let odm = crate::odm::get_odm_manager().await;
+    let records = match odm.find(AUDIT_TABLE, conditions, Some(options), Some(&alias)).await {
+        Ok(records) => records,
+        // 尚未写入过审计记录
+        Err(QuickDbError::TableNotExistError { .. }) => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+
+    let mut entries = Vec::with_capacity(records.len());
+    for record in records {
+        let record = record.expect_object()?;
+        // 空值在部分数据库中按空字符串读回
+        let text = |field: &str| match record.get(field) {
+            Some(DataValue::String(value)) if !value.is_empty() => Some(value.clone()),
+            _ => None,
+        };
+        let Some(action) = text("action").as_deref().and_then(AuditAction::parse) else {
+            continue;
+        };
+        let snapshot = |field: &str| text(field).map(|json| serde_json::from_str(&json).unwrap_or(serde_json::Value::String(json)));
+        let sequence = match record.get("sequence") {
+            Some(DataValue::Int(value)) => *value,
+            _ => 0,
+        };
+        entries.push(AuditEntry {
+            table: table.to_string(),
+            record_id: id.to_string(),
+            action,
+            actor: text("actor"),
+            request_id: text("request_id"),
+            before: snapshot("before"),
+            after: snapshot("after"),
+            changed_at: DateTime::from_timestamp_micros(sequence).unwrap_or_default(),
+        });
+    }
+    Ok(entries)
+}
+
+/// 一次写操作的审计上下文，在调用方任务中创建以捕获操作者
+pub(crate) struct AuditContext {
+    table: String,
+    alias: String,
+    meta: ModelMeta,
+    actor: Option<String>,
+    request_id: Option<String>,
+}
+
+type Record = HashMap<String, DataValue>;
+
+impl AuditContext {
+    /// 模型声明了审计时返回上下文
+    pub(crate) async fn resolve(table: &str, alias: Option<&str>) -> Option<Self> {
+        if table == AUDIT_TABLE {
+            return None;
+        }
+        let alias = resolve_alias(alias).await;
+        let meta = get_global_pool_manager().get_model_with_alias(table, &alias)?;
+        meta.audit.then(|| Self {
+            table: table.to_string(),
+            alias,
+            meta,
+            actor: current_actor(),
+            request_id: crate::context::current_context().and_then(|context| context.request_id),
+        })
+    }
+
+    /// 读取满足条件的记录作为变更前快照
+    pub(crate) async fn snapshot(&self, odm: &AsyncOdmManager, conditions: &[QueryConditionWithConfig]) -> Vec<Record> {
+        match odm.find(&self.table, conditions.to_vec(), None, Some(&self.alias)).await {
+            Ok(records) => records.into_iter().filter_map(into_record).collect(),
+            Err(e) => {
+                error!("读取审计快照失败: 表={}, 错误={}", self.table, e);
+                Vec::new()
+            }
+        }
+    }
+
+    /// 按 ID 读取记录快照
+    pub(crate) async fn snapshot_by_id(&self, odm: &AsyncOdmManager, id: &str) -> Option<Record> {
+        match odm.find_by_id(&self.table, id, Some(&self.alias)).await {
+            Ok(record) => record.and_then(into_record),
+            Err(e) => {
+                error!("读取审计快照失败: 表={}, ID={}, 错误={}", self.table, id, e);
+                None
+            }
+        }
+    }
+
+    /// 记录创建，`id` 为创建操作返回的记录 ID
+    pub(crate) async fn record_create(&self, odm: &AsyncOdmManager, id: &DataValue) {
+        let after = self.snapshot_by_id(odm, &id_string(id)).await;
+        let record_id = after
+            .as_ref()
+            .map_or_else(|| id_string(id), |record| self.record_id(record));
+        self.write(odm, AuditAction::Create, record_id, None, after.as_ref()).await;
+    }
+
+    /// 记录更新或插入，按 ID 重新读取每条变更前记录作为变更后快照
+    pub(crate) async fn record_updates(&self, odm: &AsyncOdmManager, action: AuditAction, before: Vec<Record>) {
+        for record in before {
+            let record_id = self.record_id(&record);
+            let after = self.snapshot_by_id(odm, &record_id).await;
+            self.write(odm, action, record_id, Some(&record), after.as_ref()).await;
+        }
+    }
+
+    /// 记录删除
+    pub(crate) async fn record_deletes(&self, odm: &AsyncOdmManager, before: Vec<Record>) {
+        for record in before {
+            let record_id = self.record_id(&record);
+            self.write(odm, AuditAction::Delete, record_id, Some(&record), None).await;
+        }
+    }
+
+    /// 写入一条审计记录
+    pub(crate) async fn write(
+        &self,
+        odm: &AsyncOdmManager,
+        action: AuditAction,
+        record_id: String,
+        before: Option<&Record>,
+        after: Option<&Record>,
+    ) {
+        if let Err(e) = self.try_write(odm, action, &record_id, before, after).await {
+            error!("写入审计记录失败: 表={}, ID={}, 动作={}, 错误={}", self.table, record_id, action.as_str(), e);
+        }
+    }
+
+    async fn try_write(
+        &self,
+        odm: &AsyncOdmManager,
+        action: AuditAction,
+        record_id: &str,
+        before: Option<&Record>,
+        after: Option<&Record>,
+    ) -> QuickDbResult<()> {
+        let manager = get_global_pool_manager();
+        if manager.get_model_with_alias(AUDIT_TABLE, &self.alias).is_none() {
+            let id_strategy = manager.get_id_strategy(&self.alias)?;
+            manager.register_model(audit_table_meta(&self.alias, &id_strategy))?;
+        }
+
+        let snapshot = |record: Option<&Record>| {
+            record.map_or(DataValue::Null, |record| {
+                DataValue::String(DataValue::Object(record.clone()).to_json_value().to_string())
+            })
+        };
+        let data = HashMap::from([
+            ("table_name".to_string(), DataValue::String(self.table.clone())),
+            ("record_id".to_string(), DataValue::String(record_id.to_string())),
+            ("action".to_string(), DataValue::String(action.as_str().to_string())),
+            ("actor".to_string(), self.actor.clone().map_or(DataValue::Null, DataValue::String)),
+            ("request_id".to_string(), self.request_id.clone().map_or(DataValue::Null, DataValue::String)),
+            ("before".to_string(), snapshot(before)),
+            ("after".to_string(), snapshot(after)),
+            ("sequence".to_string(), DataValue::Int(next_sequence())),
+        ]);
+        odm.create(AUDIT_TABLE, data, Some(&self.alias)).await?;
+        debug!("写入审计记录: 表={}, ID={}, 动作={}", self.table, record_id, action.as_str());
+        Ok(())
+    }
+
+    /// 记录的审计 ID：默认主键取 `id`（MongoDB 为 `_id`），复合主键为主键值的 JSON 对象
+    pub(crate) fn record_id(&self, record: &Record) -> String {
+        if self.meta.has_custom_primary_key() {
+            return self
+                .meta
+                .extract_primary_key(record)
+                .map(|key| DataValue::Object(key).to_json_value().to_string())
+                .unwrap_or_default();
+        }
+        record
+            .get("id")
+            .or_else(|| record.get("_id"))
+            .map(id_string)
+            .unwrap_or_default()
+    }
+}
+
+fn into_record(value: DataValue) -> Option<Record> {
+    match value {
+        DataValue::Object(record) => Some(record),
+        _ => None,
+    }
+}
+
+fn id_string(id: &DataValue) -> String {
+    match id {
+        DataValue::String(value) => value.clone(),
+        other => match other.to_json_value() {
+            serde_json::Value::String(value) => value,
+            value => value.to_string(),
+        },
+    }
+}
+
+fn equals(field: &str, value: &str) -> QueryConditionWithConfig {
+    QueryConditionWithConfig {
+        field: field.to_string(),
+        operator: QueryOperator::Eq,
+        value: DataValue::String(value.to_string()),
+        case_insensitive: false,
+    }
+}
+
+async fn resolve_alias(alias: Option<&str>) -> String {
+    match alias {
+        Some(alias) => alias.to_string(),
+        None => get_global_pool_manager()
+            .get_default_alias()
+            .await
+            .unwrap_or_else(|| "default".to_string()),
+    }
+}
+
+/// 审计序号：微秒时间戳，同一进程内严格递增，用于排序与还原变更时间
+fn next_sequence() -> i64 {
+    static LAST: AtomicI64 = AtomicI64::new(0);
+    let now = Utc::now().timestamp_micros();
+    let previous = LAST.fetch_max(now, Ordering::AcqRel);
+    if previous >= now { LAST.fetch_add(1, Ordering::AcqRel) + 1 } else { now }
+}
+
+/// 审计表的模型元数据（ID 字段类型跟随别名的 ID 策略）
+fn audit_table_meta(alias: &str, id_strategy: &IdStrategy) -> ModelMeta {
+    let id_field = match id_strategy {
+        IdStrategy::AutoIncrement => integer_field(None, None),
+        _ => string_field(None, None, None),
+    };
+    let fields: HashMap<String, FieldDefinition> = HashMap::from([
+        ("id".to_string(), id_field),
+        ("table_name".to_string(), string_field(Some(255), None, None).required()),
+        ("record_id".to_string(), string_field(Some(255), None, None).required()),
+        ("action".to_string(), string_field(Some(16), None, None).required()),
+        ("actor".to_string(), string_field(Some(255), None, None)),
+        ("request_id".to_string(), string_field(Some(255), None, None)),
+        ("before".to_string(), FieldDefinition::new(FieldType::Text)),
+        ("after".to_string(), FieldDefinition::new(FieldType::Text)),
+        ("sequence".to_string(), bigint_field().required()),
+    ]);
+    ModelMeta {
+        collection_name: AUDIT_TABLE.to_string(),
+        database_alias: Some(alias.to_string()),
+        fields,
+        indexes: vec![IndexDefinition {
+            fields: vec!["table_name".to_string(), "record_id".to_string()],
+            unique: false,
+            name: Some(format!("idx{}_record", AUDIT_TABLE)),
+            options: IndexOptions::default(),
+        }],
+        description: Some("模型变更审计记录".to_string()),
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn actor_is_scoped_to_the_task_and_sequence_increases() {
+        assert_eq!(current_actor(), None);
+        let actor = with_actor("user:42", async { current_actor() }).await;
+        assert_eq!(actor.as_deref(), Some("user:42"));
+        assert_eq!(current_actor(), None);
+
+        let context = crate::context::OperationContext::new().user_id("user:7");
+        let actor = crate::context::with_context(context, async { current_actor() }).await;
+        assert_eq!(actor.as_deref(), Some("user:7"));
+
+        let first = next_sequence();
+        assert!(next_sequence() > first);
+        assert_eq!(AuditAction::parse(AuditAction::Upsert.as_str()), Some(AuditAction::Upsert));
+        assert_eq!(id_string(&DataValue::Int(7)), "7");
+    }
+}
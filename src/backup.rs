@@ -0,0 +1,246 @@
+//! # 逻辑备份模块
+//!
+//! 把一个别名下所有已注册模型的表结构与数据导出到备份目录，并可在任意别名恢复，
+//! 适用于应用层的定时备份。备份目录结构：
+//!
+//! ```text
+//! backup-20240101/
+//! ├── manifest.json      # 格式版本、来源别名、创建时间与各表的模型元数据
+//! └── data/
+//!     ├── users.ndjson   # 每表一个 NDJSON 文件
+//!     └── orders.ndjson
+//! ```
+//!
+//! `manifest.json` 在所有数据文件写完后才写入，没有清单的目录视为不完整的备份。
+//!
+//! ```ignore
+//! let manifest = backup::dump("default", "backups/2024-01-01").await?;
+//! let report = backup::restore("staging", "backups/2024-01-01").await?;
+//! ```
+//!
+//! 恢复时会删除并按备份中的模型重建同名表；目标别名使用自增 ID 策略时记录的 ID
+//! 由数据库重新分配
+
+use crate::error::{QuickDbError, QuickDbResult};
+use crate::manager::get_global_pool_manager;
+use crate::model::ModelMeta;
+use crate::serializer::{ImportReport, NdjsonOptions, export_ndjson, import_ndjson};
+use rat_logger::{debug, info};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use tokio::io::{BufReader, BufWriter};
+
+/// 当前备份格式版本
+pub const BACKUP_FORMAT_VERSION: u32 = 1;
+
+/// 备份清单文件名
+pub const MANIFEST_FILE: &str = "manifest.json";
+
+/// 数据文件所在的子目录
+const DATA_DIR: &str = "data";
+
+/// 备份清单
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupManifest {
+    /// 备份格式版本
+    pub version: u32,
+    /// 来源数据库别名
+    pub alias: String,
+    /// 创建时间（RFC 3339）
+    pub created_at: String,
+    /// 备份的表（按表名排序）
+    pub tables: Vec<BackupTable>,
+}
+
+/// 清单中的单表条目
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupTable {
+    /// 表名
+    pub name: String,
+    /// 数据文件（相对备份目录）
+    pub file: String,
+    /// 导出的记录数
+    pub records: usize,
+    /// 表的模型元数据
+    pub meta: ModelMeta,
+}
+
+impl BackupManifest {
+    /// 读取并校验备份目录中的清单
+    pub fn load(path: impl AsRef<Path>) -> QuickDbResult<Self> {
+        let content = std::fs::read_to_string(path.as_ref().join(MANIFEST_FILE)).map_err(QuickDbError::IoError)?;
+        Self::parse(&content)
+    }
+
+    fn parse(content: &str) -> QuickDbResult<Self> {
+        let manifest: Self = serde_json::from_str(content).map_err(|e| QuickDbError::SerializationError {
+            message: crate::i18n::tf("backup.manifest_invalid", &[("message", &e.to_string())]),
+        })?;
+        if manifest.version > BACKUP_FORMAT_VERSION {
+            return Err(crate::quick_error!(
+                validation,
+                "version",
+                crate::i18n::tf(
+                    "backup.version_unsupported",
+                    &[
+                        ("version", &manifest.version.to_string()),
+                        ("supported", &BACKUP_FORMAT_VERSION.to_string()),
+                    ]
+                )
+            ));
+        }
+        Ok(manifest)
+    }
+}
+
+/// 恢复结果
+#[derive(Debug, Clone, Default)]
+pub struct RestoreReport {
+    /// 各表的导入结果（按清单顺序）
+    pub tables: Vec<(String, ImportReport)>,
+}
+
+impl RestoreReport {
+    /// 写入的总行数
+    pub fn inserted(&self) -> usize {
+        self.tables.iter().map(|(_, report)| report.inserted).sum()
+    }
+
+    /// 是否所有行都恢复成功
+    pub fn is_complete(&self) -> bool {
+        self.tables.iter().all(|(_, report)| report.errors.is_empty())
+    }
+}
+
+/// 把别名下所有已注册模型的表导出到备份目录
+///
+/// 目录不存在时自动创建；已有同名数据文件会被覆盖
+pub async fn dump(alias: &str, path: impl AsRef<Path>) -> QuickDbResult<BackupManifest> {
+    let path = path.as_ref();
+    tokio::fs::create_dir_all(path.join(DATA_DIR))
+        .await
+        .map_err(QuickDbError::IoError)?;
+
+    let mut metas: Vec<ModelMeta> = get_global_pool_manager()
+        .get_registered_models()
+        .into_iter()
+        .filter(|(key, _)| key.split_once(':').is_some_and(|(key_alias, _)| key_alias == alias))
+        .map(|(_, meta)| meta)
+        .collect();
+    metas.sort_by_key(|meta| meta.qualified_name());
+
+    let mut tables = Vec::with_capacity(metas.len());
+    for meta in metas {
+        let table = meta.qualified_name();
+        let file = format!("{}/{}.ndjson", DATA_DIR, table);
+        let writer = tokio::fs::File::create(path.join(&file))
+            .await
+            .map_err(QuickDbError::IoError)?;
+        let records = export_ndjson(alias, &table, BufWriter::new(writer), &NdjsonOptions::new()).await?;
+        debug!("备份表完成: 表={}, 记录数={}", table, records);
+        tables.push(BackupTable {
+            name: table,
+            file,
+            records,
+            meta,
+        });
+    }
+
+    let manifest = BackupManifest {
+        version: BACKUP_FORMAT_VERSION,
+        alias: alias.to_string(),
+        created_at: chrono::Utc::now().to_rfc3339(),
+        tables,
+    };
+    let content = serde_json::to_string_pretty(&manifest).map_err(|e| QuickDbError::SerializationError {
+        message: crate::i18n::tf("serializer.serialize_to_json_failed", &[("message", &e.to_string())]),
+    })?;
+    tokio::fs::write(path.join(MANIFEST_FILE), content)
+        .await
+        .map_err(QuickDbError::IoError)?;
+
+    info!(
+        "备份完成: 别名={}, 目录={}, 表数={}",
+        alias,
+        path.display(),
+        manifest.tables.len()
+    );
+    Ok(manifest)
+}
+
+/// 从备份目录恢复到指定别名
+///
+/// 按清单顺序注册模型、删除并重建表后导入数据；单行导入失败记录在对应表的
+/// [`ImportReport::errors`] 中，不会中断恢复
+pub async fn restore(alias: &str, path: impl AsRef<Path>) -> QuickDbResult<RestoreReport> {
+    let path = path.as_ref();
+    let manifest = BackupManifest::load(path)?;
+    let manager = get_global_pool_manager();
+
+    let mut report = RestoreReport::default();
+    for table in manifest.tables {
+        let mut meta = table.meta;
+        meta.database_alias = Some(alias.to_string());
+        manager.register_model(meta)?;
+        crate::manager::drop_table(alias, &table.name).await?;
+        manager.ensure_table_and_indexes(&table.name, alias).await?;
+
+        let reader = tokio::fs::File::open(path.join(&table.file))
+            .await
+            .map_err(QuickDbError::IoError)?;
+        let imported = import_ndjson(alias, &table.name, BufReader::new(reader), &NdjsonOptions::new()).await?;
+        debug!(
+            "恢复表完成: 表={}, 插入={}, 失败={}",
+            table.name,
+            imported.inserted,
+            imported.errors.len()
+        );
+        report.tables.push((table.name, imported));
+    }
+
+    info!(
+        "恢复完成: 别名={}, 来源别名={}, 表数={}, 插入={}",
+        alias,
+        manifest.alias,
+        report.tables.len(),
+        report.inserted()
+    );
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::string_field;
+    use std::collections::HashMap;
+
+    #[test]
+    fn manifest_round_trips_and_rejects_newer_versions() {
+        crate::i18n::ErrorMessageI18n::init_i18n();
+        let manifest = BackupManifest {
+            version: BACKUP_FORMAT_VERSION,
+            alias: "default".to_string(),
+            created_at: "2024-01-01T00:00:00+00:00".to_string(),
+            tables: vec![BackupTable {
+                name: "users".to_string(),
+                file: "data/users.ndjson".to_string(),
+                records: 2,
+                meta: ModelMeta {
+                    collection_name: "users".to_string(),
+                    database_alias: Some("default".to_string()),
+                    fields: HashMap::from([("name".to_string(), string_field(Some(64), None, None))]),
+                    ..Default::default()
+                },
+            }],
+        };
+
+        let content = serde_json::to_string(&manifest).unwrap();
+        let parsed = BackupManifest::parse(&content).unwrap();
+        assert_eq!(parsed.tables[0].name, "users");
+        assert_eq!(parsed.tables[0].meta.fields, manifest.tables[0].meta.fields);
+
+        let newer = content.replacen("\"version\":1", "\"version\":99", 1);
+        assert!(BackupManifest::parse(&newer).is_err());
+        assert!(BackupManifest::parse("{}").is_err());
+    }
+}
@@ -2,11 +2,11 @@
 //!
 //! 提供CacheManager的结构定义和构造函数
 
-use super::stats::CachePerformanceStats;
-use crate::types::{CacheConfig, CacheStrategy, CompressionAlgorithm};
+use super::stats::{CachePerformanceStats, TableCacheStats};
+use crate::types::{CacheConfig, CacheStrategy, TableCachePolicy};
+use dashmap::DashMap;
 use anyhow::{Result, anyhow};
 use rat_logger::{debug, info};
-use rat_memcache::config::{L1Config, L2Config, LoggingConfig, PerformanceConfig, TtlConfig};
 use rat_memcache::types::EvictionStrategy;
 use rat_memcache::{RatMemCache, RatMemCacheBuilder};
 use std::{collections::HashMap, path::PathBuf, sync::Arc, sync::atomic::AtomicU64};
@@ -31,6 +31,10 @@ pub struct CacheManager {
     pub(crate) misses_counter: Arc<AtomicU64>,
     pub(crate) writes_counter: Arc<AtomicU64>,
     pub(crate) deletes_counter: Arc<AtomicU64>,
+    /// 表级缓存策略
+    pub(crate) table_policies: Arc<DashMap<String, TableCachePolicy>>,
+    /// 按表统计
+    pub(crate) table_stats: Arc<DashMap<String, TableCacheStats>>,
 }
 
 impl CacheManager {
@@ -155,6 +159,8 @@ impl CacheManager {
             misses_counter: Arc::new(AtomicU64::new(0)),
             writes_counter: Arc::new(AtomicU64::new(0)),
             deletes_counter: Arc::new(AtomicU64::new(0)),
+            table_policies: Arc::new(DashMap::new()),
+            table_stats: Arc::new(DashMap::new()),
         })
     }
 }
@@ -4,7 +4,7 @@
 //! 提供各种缓存键的生成策略和实现
 
 use crate::types::{
-    CacheConfig, DataValue, IdType, QueryCondition, QueryConditionGroup, QueryConditionGroupWithConfig, QueryConditionWithConfig, QueryOptions,
+    DataValue, IdType, QueryConditionGroup, QueryConditionGroupWithConfig, QueryConditionWithConfig, QueryOptions,
     SortDirection,
 };
 use rat_logger::debug;
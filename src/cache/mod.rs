@@ -7,10 +7,45 @@
 pub mod cache_manager;
 pub mod key_generator;
 pub mod operations;
+pub mod policy;
 pub mod query_cache;
 pub mod record_cache;
+pub mod snapshot;
 pub mod stats;
+pub mod warm;
 
 // 重新导出主要的公共类型和结构体
 pub use cache_manager::CacheManager;
-pub use stats::{CachePerformanceStats, CacheStats};
+pub use record_cache::CachedRecord;
+pub use stats::{CachePerformanceStats, CacheStats, TableCacheStats};
+pub use warm::{CacheWarmOutcome, CacheWarmProgress, CacheWarmQuery, CacheWarmReport, CacheWarmSpec};
+
+/// 测试用的纯内存缓存配置
+#[cfg(test)]
+pub(crate) fn test_cache_config(negative_ttl_secs: Option<u64>) -> crate::types::CacheConfig {
+    use crate::types::*;
+
+    CacheConfig {
+        enabled: true,
+        strategy: CacheStrategy::Lru,
+        l1_config: L1CacheConfig {
+            max_capacity: 100,
+            max_memory_mb: 16,
+            enable_stats: false,
+        },
+        l2_config: None,
+        ttl_config: TtlConfig {
+            default_ttl_secs: 60,
+            max_ttl_secs: 60,
+            check_interval_secs: 60,
+            negative_ttl_secs,
+        },
+        compression_config: CompressionConfig {
+            enabled: false,
+            algorithm: CompressionAlgorithm::Lz4,
+            threshold_bytes: 1024,
+        },
+        version: "v1".to_string(),
+        write_mode: CacheWriteMode::WriteAround,
+    }
+}
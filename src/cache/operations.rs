@@ -3,17 +3,16 @@
 //!
 //! 提供缓存的清理、失效、批量操作等维护功能
 
-use crate::types::{CacheConfig, DataValue, IdType};
+use crate::types::{CacheWriteMode, DataValue, IdType};
 use anyhow::{Result, anyhow};
 use rat_logger::{debug, info, warn};
-use rat_memcache::{CacheOptions, RatMemCache};
 use std::collections::HashMap;
 use std::sync::atomic::Ordering;
 
 // 从 cache_manager.rs 中引入 CACHE_KEY_PREFIX 和 CacheManager
 use super::cache_manager::{CACHE_KEY_PREFIX, CacheManager};
 // 从 stats.rs 中引入统计类型
-use super::stats::{CachePerformanceStats, CacheStats};
+use super::stats::{CachePerformanceStats, CacheStats, TableCacheStats};
 
 impl CacheManager {
     /// 删除单个记录的缓存
@@ -29,6 +28,7 @@ impl CacheManager {
         } else {
             // 更新删除统计
             self.deletes_counter.fetch_add(1, Ordering::Relaxed);
+            self.record_table_evictions(table, 1);
             {
                 let mut stats = self.stats.write().await;
                 stats.deletes += 1;
@@ -46,7 +46,9 @@ impl CacheManager {
         }
 
         let pattern = format!("{}:{}:*", CACHE_KEY_PREFIX, table);
-        self.clear_by_pattern(&pattern).await.map(|_| ())
+        let cleared_count = self.clear_by_pattern(&pattern).await?;
+        self.record_table_evictions(table, cleared_count);
+        Ok(())
     }
 
     /// 清理所有缓存
@@ -149,6 +151,8 @@ impl CacheManager {
             }
         }
 
+        self.record_table_evictions(table, cleared_count);
+
         info!(
             "批量清理记录缓存完成: table={}, total={}, cleared={}",
             table,
@@ -170,7 +174,7 @@ impl CacheManager {
         let mut expired_count = 0;
         let mut table_keys = self.table_keys.write().await;
 
-        for (table_name, keys) in table_keys.iter_mut() {
+        for (_table_name, keys) in table_keys.iter_mut() {
             let mut valid_keys = Vec::new();
 
             for key in keys.iter() {
@@ -307,6 +311,7 @@ impl CacheManager {
 
         let pattern = format!("{}:{}:query:*", CACHE_KEY_PREFIX, table);
         let cleared_count = self.clear_by_pattern(&pattern).await?;
+        self.record_table_evictions(table, cleared_count);
 
         debug!(
             "清理表查询缓存完成: table={}, cleared_count={}",
@@ -325,6 +330,7 @@ impl CacheManager {
 
         let pattern = format!("{}:{}:record:*", CACHE_KEY_PREFIX, table);
         let cleared_count = self.clear_by_pattern(&pattern).await?;
+        self.record_table_evictions(table, cleared_count);
 
         info!(
             "清理表记录缓存完成: table={}, cleared_count={}",
@@ -396,10 +402,10 @@ impl CacheManager {
         // 从原子计数器和详细统计中获取数据
         let hits = self.hits_counter.load(Ordering::Relaxed);
         let misses = self.misses_counter.load(Ordering::Relaxed);
-        let writes = self.writes_counter.load(Ordering::Relaxed);
-        let deletes = self.deletes_counter.load(Ordering::Relaxed);
+        let _writes = self.writes_counter.load(Ordering::Relaxed);
+        let _deletes = self.deletes_counter.load(Ordering::Relaxed);
 
-        let stats = self.stats.read().await;
+        let _stats = self.stats.read().await;
         let hit_rate = if hits + misses > 0 {
             hits as f64 / (hits + misses) as f64
         } else {
@@ -417,6 +423,7 @@ impl CacheManager {
             entries,
             memory_usage_bytes: entries * 1024, // 粗略估算每个条目1KB
             disk_usage_bytes: 0,                // rat_memcache 主要是内存缓存
+            tables: self.get_table_stats(),
         })
     }
 
@@ -430,6 +437,14 @@ impl CacheManager {
         Ok(stats.clone())
     }
 
+    /// 获取按表划分的缓存统计
+    pub fn get_table_stats(&self) -> HashMap<String, TableCacheStats> {
+        self.table_stats
+            .iter()
+            .map(|entry| (entry.key().clone(), entry.value().clone()))
+            .collect()
+    }
+
     /// 重置统计信息
     pub async fn reset_stats(&self) -> Result<()> {
         if !self.config.enabled {
@@ -445,6 +460,7 @@ impl CacheManager {
             let mut stats = self.stats.write().await;
             *stats = CachePerformanceStats::new();
         }
+        self.table_stats.clear();
 
         info!("缓存统计信息已重置");
         Ok(())
@@ -464,6 +480,11 @@ impl CacheManager {
         self.config.enabled
     }
 
+    /// 写操作的缓存处理方式
+    pub fn write_mode(&self) -> CacheWriteMode {
+        self.config.write_mode
+    }
+
     /// 批量缓存记录 - 优化批量操作
     ///
     /// # 参数
@@ -0,0 +1,72 @@
+//! 表级缓存策略模块
+//!
+//! 为单个表覆盖是否缓存及 TTL，未设置策略的表沿用数据库别名级的缓存配置
+
+use crate::types::TableCachePolicy;
+use rat_logger::debug;
+
+use super::cache_manager::CacheManager;
+
+impl CacheManager {
+    /// 设置表级缓存策略
+    pub fn set_table_policy(&self, table: &str, policy: TableCachePolicy) {
+        debug!("设置表级缓存策略: table={}, policy={:?}", table, policy);
+        self.table_policies.insert(table.to_string(), policy);
+    }
+
+    /// 移除表级缓存策略，恢复使用数据库别名级的缓存配置
+    pub fn remove_table_policy(&self, table: &str) -> Option<TableCachePolicy> {
+        self.table_policies.remove(table).map(|(_, policy)| policy)
+    }
+
+    /// 获取表级缓存策略
+    pub fn table_policy(&self, table: &str) -> Option<TableCachePolicy> {
+        self.table_policies.get(table).map(|policy| policy.clone())
+    }
+
+    /// 是否缓存该表
+    pub fn caches_table(&self, table: &str) -> bool {
+        self.config.enabled
+            && self
+                .table_policies
+                .get(table)
+                .is_none_or(|policy| policy.enabled)
+    }
+
+    /// 该表缓存条目的 TTL（秒）
+    pub fn table_ttl_secs(&self, table: &str) -> u64 {
+        self.table_policies
+            .get(table)
+            .and_then(|policy| policy.ttl_secs)
+            .unwrap_or(self.config.ttl_config.default_ttl_secs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{DataValue, IdType};
+
+    #[tokio::test]
+    async fn table_policy_overrides_alias_config() {
+        let cache = CacheManager::new(crate::cache::test_cache_config(None)).await.unwrap();
+        let id = IdType::Number(1);
+        let record = DataValue::String("cached".to_string());
+
+        cache.set_table_policy("events", TableCachePolicy::disabled());
+        cache.set_table_policy("countries", TableCachePolicy::new().with_ttl_secs(3600));
+        assert!(!cache.caches_table("events"));
+        assert_eq!(cache.table_ttl_secs("countries"), 3600);
+        assert_eq!(cache.table_ttl_secs("users"), 60);
+
+        for table in ["events", "countries", "users"] {
+            cache.cache_record(table, &id, &record).await.unwrap();
+        }
+        assert_eq!(cache.get_cached_record("events", &id).await.unwrap(), None);
+        assert_eq!(cache.get_cached_record("countries", &id).await.unwrap(), Some(record.clone()));
+        assert_eq!(cache.get_cached_record("users", &id).await.unwrap(), Some(record));
+
+        assert_eq!(cache.remove_table_policy("events"), Some(TableCachePolicy::disabled()));
+        assert!(cache.caches_table("events"));
+    }
+}
@@ -3,11 +3,13 @@
 //!
 //! 提供查询结果和条件组合查询结果的缓存功能
 
-use crate::types::{DataValue, QueryCondition, QueryConditionGroup, QueryConditionGroupWithConfig, QueryOptions};
+use crate::types::{DataValue, QueryConditionGroup, QueryConditionGroupWithConfig, QueryOptions};
 use anyhow::{Result, anyhow};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
 use bytes::Bytes;
 use rat_logger::{debug, warn};
-use rat_memcache::{CacheOptions, RatMemCache};
+use rat_memcache::CacheOptions;
 use serde_json;
 use std::sync::atomic::Ordering;
 use std::time::Instant;
@@ -22,7 +24,7 @@ impl CacheManager {
         options: &QueryOptions,
         results: &[DataValue],
     ) -> Result<()> {
-        if !self.config.enabled {
+        if !self.caches_table(table) {
             return Ok(());
         }
 
@@ -64,7 +66,7 @@ impl CacheManager {
                     DataValue::DateTime(dt) => serde_json::Value::String(dt.to_rfc3339()),
                     DataValue::DateTimeUTC(dt) => serde_json::Value::String(dt.to_rfc3339()),
                     DataValue::Null => serde_json::Value::Null,
-                    DataValue::Bytes(bytes) => serde_json::Value::String(base64::encode(bytes)),
+                    DataValue::Bytes(bytes) => serde_json::Value::String(STANDARD.encode(bytes)),
                     DataValue::Uuid(uuid) => serde_json::Value::String(uuid.to_string()),
                     DataValue::Array(arr) => {
                         let json_array: Vec<serde_json::Value> = arr.iter().map(|item| {
@@ -79,7 +81,7 @@ impl CacheManager {
                                 DataValue::DateTime(dt) => serde_json::Value::String(dt.to_rfc3339()),
                                 DataValue::DateTimeUTC(dt) => serde_json::Value::String(dt.to_rfc3339()),
                                 DataValue::Null => serde_json::Value::Null,
-                                DataValue::Bytes(bytes) => serde_json::Value::String(base64::encode(bytes)),
+                                DataValue::Bytes(bytes) => serde_json::Value::String(STANDARD.encode(bytes)),
                                 DataValue::Uuid(uuid) => serde_json::Value::String(uuid.to_string()),
                                 _ => serde_json::Value::String(format!("{:?}", item)), // 其他复杂类型转为字符串
                             }
@@ -111,7 +113,7 @@ impl CacheManager {
                                 }
                                 DataValue::Null => serde_json::Value::Null,
                                 DataValue::Bytes(bytes) => {
-                                    serde_json::Value::String(base64::encode(bytes))
+                                    serde_json::Value::String(STANDARD.encode(bytes))
                                 }
                                 DataValue::Uuid(uuid) => {
                                     serde_json::Value::String(uuid.to_string())
@@ -130,10 +132,12 @@ impl CacheManager {
             .map_err(|e| anyhow!("Failed to serialize query results: {}", e))?;
 
         let cache_options = CacheOptions {
-            ttl_seconds: Some(self.config.ttl_config.default_ttl_secs),
+            ttl_seconds: Some(self.table_ttl_secs(table)),
             ..Default::default()
         };
 
+        let written_bytes = serialized.len();
+
         self.cache
             .set_with_options(key.clone(), Bytes::from(serialized), &cache_options)
             .await
@@ -145,6 +149,7 @@ impl CacheManager {
         // 更新统计信息
         let elapsed = start_time.elapsed();
         self.writes_counter.fetch_add(1, Ordering::Relaxed);
+        self.record_table_write(table, written_bytes);
         {
             let mut stats = self.stats.write().await;
             stats.writes += 1;
@@ -169,7 +174,7 @@ impl CacheManager {
         options: &QueryOptions,
         results: &[DataValue],
     ) -> Result<()> {
-        if !self.config.enabled {
+        if !self.caches_table(table) {
             return Ok(());
         }
 
@@ -203,7 +208,7 @@ impl CacheManager {
                     DataValue::DateTime(dt) => serde_json::Value::String(dt.to_rfc3339()),
                     DataValue::DateTimeUTC(dt) => serde_json::Value::String(dt.to_rfc3339()),
                     DataValue::Null => serde_json::Value::Null,
-                    DataValue::Bytes(bytes) => serde_json::Value::String(base64::encode(bytes)),
+                    DataValue::Bytes(bytes) => serde_json::Value::String(STANDARD.encode(bytes)),
                     DataValue::Uuid(uuid) => serde_json::Value::String(uuid.to_string()),
                     DataValue::Array(arr) => {
                         let json_array: Vec<serde_json::Value> = arr.iter().map(|item| {
@@ -217,7 +222,7 @@ impl CacheManager {
                                 DataValue::DateTime(dt) => serde_json::Value::String(dt.to_rfc3339()),
                                 DataValue::DateTimeUTC(dt) => serde_json::Value::String(dt.to_rfc3339()),
                                 DataValue::Null => serde_json::Value::Null,
-                                DataValue::Bytes(bytes) => serde_json::Value::String(base64::encode(bytes)),
+                                DataValue::Bytes(bytes) => serde_json::Value::String(STANDARD.encode(bytes)),
                                 DataValue::Uuid(uuid) => serde_json::Value::String(uuid.to_string()),
                                 _ => serde_json::Value::String(format!("{:?}", item)), // 其他复杂类型转为字符串
                             }
@@ -249,7 +254,7 @@ impl CacheManager {
                                 }
                                 DataValue::Null => serde_json::Value::Null,
                                 DataValue::Bytes(bytes) => {
-                                    serde_json::Value::String(base64::encode(bytes))
+                                    serde_json::Value::String(STANDARD.encode(bytes))
                                 }
                                 DataValue::Uuid(uuid) => {
                                     serde_json::Value::String(uuid.to_string())
@@ -268,10 +273,12 @@ impl CacheManager {
             .map_err(|e| anyhow!("Failed to serialize condition groups query results: {}", e))?;
 
         let cache_options = CacheOptions {
-            ttl_seconds: Some(self.config.ttl_config.default_ttl_secs),
+            ttl_seconds: Some(self.table_ttl_secs(table)),
             ..Default::default()
         };
 
+        let written_bytes = serialized.len();
+
         self.cache
             .set_with_options(key.clone(), Bytes::from(serialized), &cache_options)
             .await
@@ -283,6 +290,7 @@ impl CacheManager {
         // 更新统计信息
         let elapsed = start_time.elapsed();
         self.writes_counter.fetch_add(1, Ordering::Relaxed);
+        self.record_table_write(table, written_bytes);
         {
             let mut stats = self.stats.write().await;
             stats.writes += 1;
@@ -305,7 +313,7 @@ impl CacheManager {
         table: &str,
         options: &QueryOptions,
     ) -> Result<Option<Vec<DataValue>>> {
-        if !self.config.enabled {
+        if !self.caches_table(table) {
             return Ok(None);
         }
 
@@ -327,7 +335,7 @@ impl CacheManager {
         condition_groups: &[QueryConditionGroup],
         options: &QueryOptions,
     ) -> Result<Option<Vec<DataValue>>> {
-        if !self.config.enabled {
+        if !self.caches_table(table) {
             return Ok(None);
         }
 
@@ -349,7 +357,7 @@ impl CacheManager {
         condition_groups: &[QueryConditionGroupWithConfig],
         options: &QueryOptions,
     ) -> Result<Option<Vec<DataValue>>> {
-        if !self.config.enabled {
+        if !self.caches_table(table) {
             return Ok(None);
         }
 
@@ -372,7 +380,7 @@ impl CacheManager {
         options: &QueryOptions,
         results: &[DataValue],
     ) -> Result<()> {
-        if !self.config.enabled {
+        if !self.caches_table(table) {
             return Ok(());
         }
 
@@ -406,7 +414,7 @@ impl CacheManager {
                     DataValue::DateTime(dt) => serde_json::Value::String(dt.to_rfc3339()),
                     DataValue::DateTimeUTC(dt) => serde_json::Value::String(dt.to_rfc3339()),
                     DataValue::Null => serde_json::Value::Null,
-                    DataValue::Bytes(bytes) => serde_json::Value::String(base64::encode(bytes)),
+                    DataValue::Bytes(bytes) => serde_json::Value::String(STANDARD.encode(bytes)),
                     DataValue::Uuid(uuid) => serde_json::Value::String(uuid.to_string()),
                     DataValue::Array(arr) => {
                         let json_array: Vec<serde_json::Value> = arr.iter().map(|item| {
@@ -420,7 +428,7 @@ impl CacheManager {
                                 DataValue::DateTime(dt) => serde_json::Value::String(dt.to_rfc3339()),
                                 DataValue::DateTimeUTC(dt) => serde_json::Value::String(dt.to_rfc3339()),
                                 DataValue::Null => serde_json::Value::Null,
-                                DataValue::Bytes(bytes) => serde_json::Value::String(base64::encode(bytes)),
+                                DataValue::Bytes(bytes) => serde_json::Value::String(STANDARD.encode(bytes)),
                                 DataValue::Uuid(uuid) => serde_json::Value::String(uuid.to_string()),
                                 _ => serde_json::Value::String(format!("{:?}", item)),
                             }
@@ -447,7 +455,7 @@ impl CacheManager {
                                 DataValue::DateTime(dt) => serde_json::Value::String(dt.to_rfc3339()),
                                 DataValue::DateTimeUTC(dt) => serde_json::Value::String(dt.to_rfc3339()),
                                 DataValue::Null => serde_json::Value::Null,
-                                DataValue::Bytes(bytes) => serde_json::Value::String(base64::encode(bytes)),
+                                DataValue::Bytes(bytes) => serde_json::Value::String(STANDARD.encode(bytes)),
                                 DataValue::Uuid(uuid) => serde_json::Value::String(uuid.to_string()),
                                 _ => serde_json::Value::String(format!("{:?}", value)),
                             };
@@ -463,10 +471,12 @@ impl CacheManager {
             .map_err(|e| anyhow!("Failed to serialize condition groups query results: {}", e))?;
 
         let cache_options = CacheOptions {
-            ttl_seconds: Some(self.config.ttl_config.default_ttl_secs),
+            ttl_seconds: Some(self.table_ttl_secs(table)),
             ..Default::default()
         };
 
+        let written_bytes = serialized.len();
+
         self.cache
             .set_with_options(key.clone(), Bytes::from(serialized), &cache_options)
             .await
@@ -478,6 +488,7 @@ impl CacheManager {
         // 更新统计信息
         let elapsed = start_time.elapsed();
         self.writes_counter.fetch_add(1, Ordering::Relaxed);
+        self.record_table_write(table, written_bytes);
         {
             let mut stats = self.stats.write().await;
             stats.writes += 1;
@@ -501,7 +512,7 @@ impl CacheManager {
         table: &str,
         start_time: Instant,
     ) -> Result<Option<Vec<DataValue>>> {
-        match self.cache.get(&key).await {
+        match self.cache.get(key).await {
             Ok(Some(data)) => {
                 // 修复：正确反序列化为对应的DataValue类型
                 let json_results: Vec<serde_json::Value> = serde_json::from_slice(&data)
@@ -519,7 +530,7 @@ impl CacheManager {
                                 // 尝试解析为DateTime
                                 else if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(&s) {
                                     DataValue::DateTime(
-                                        dt.with_timezone(&chrono::FixedOffset::east(0)),
+                                        dt.with_timezone(&chrono::FixedOffset::east_opt(0).unwrap()),
                                     )
                                 }
                                 // 尝试解析为base64编码的字节数据
@@ -532,7 +543,7 @@ impl CacheManager {
                                                 || c == '='
                                         }))
                                 {
-                                    if let Ok(bytes) = base64::decode(&s) {
+                                    if let Ok(bytes) = STANDARD.decode(&s) {
                                         DataValue::Bytes(bytes)
                                     } else {
                                         DataValue::String(s)
@@ -605,6 +616,7 @@ impl CacheManager {
                 // 更新命中统计
                 let elapsed = start_time.elapsed();
                 self.hits_counter.fetch_add(1, Ordering::Relaxed);
+                self.record_table_hit(table, data.len());
                 {
                     let mut stats = self.stats.write().await;
                     stats.hits += 1;
@@ -624,6 +636,7 @@ impl CacheManager {
                 // 更新未命中统计
                 let elapsed = start_time.elapsed();
                 self.misses_counter.fetch_add(1, Ordering::Relaxed);
+                self.record_table_miss(table);
                 {
                     let mut stats = self.stats.write().await;
                     stats.misses += 1;
@@ -638,6 +651,7 @@ impl CacheManager {
                 // 错误也算作未命中
                 let elapsed = start_time.elapsed();
                 self.misses_counter.fetch_add(1, Ordering::Relaxed);
+                self.record_table_miss(table);
                 {
                     let mut stats = self.stats.write().await;
                     stats.misses += 1;
@@ -3,22 +3,33 @@
 //!
 //! 提供单个记录的缓存存储和读取功能
 
-use crate::types::{CacheConfig, DataValue, IdType};
+use crate::types::{DataValue, IdType};
 use anyhow::{Result, anyhow};
 use bytes::Bytes;
 use rat_logger::{debug, warn};
-use rat_memcache::{CacheOptions, RatMemCache};
+use rat_memcache::CacheOptions;
 use serde_json;
-use std::collections::HashMap;
 use std::sync::atomic::Ordering;
 use std::time::Instant;
 
 // 从 cache_manager.rs 中引入 CacheManager
 use super::cache_manager::CacheManager;
 
+/// “记录不存在”占位标记（不是合法的 JSON，不会与序列化后的记录冲突）
+const MISSING_RECORD_SENTINEL: &[u8] = b"\0rat_quickdb:missing";
+
+/// 记录缓存的查询结果
+#[derive(Debug, Clone, PartialEq)]
+pub enum CachedRecord {
+    /// 缓存中有该记录
+    Found(DataValue),
+    /// 缓存记录了该 ID 不存在（负缓存）
+    Missing,
+}
+
 impl CacheManager {
     pub async fn cache_record(&self, table: &str, id: &IdType, data: &DataValue) -> Result<()> {
-        if !self.config.enabled {
+        if !self.caches_table(table) {
             return Ok(());
         }
 
@@ -30,10 +41,12 @@ impl CacheManager {
             serde_json::to_vec(data).map_err(|e| anyhow!("Failed to serialize data: {}", e))?;
 
         let options = CacheOptions {
-            ttl_seconds: Some(self.config.ttl_config.default_ttl_secs),
+            ttl_seconds: Some(self.table_ttl_secs(table)),
             ..Default::default()
         };
 
+        let written_bytes = serialized.len();
+
         self.cache
             .set_with_options(key.clone(), Bytes::from(serialized), &options)
             .await
@@ -45,6 +58,7 @@ impl CacheManager {
         // 更新统计信息
         let elapsed = start_time.elapsed();
         self.writes_counter.fetch_add(1, Ordering::Relaxed);
+        self.record_table_write(table, written_bytes);
         {
             let mut stats = self.stats.write().await;
             stats.writes += 1;
@@ -56,9 +70,45 @@ impl CacheManager {
         Ok(())
     }
 
-    /// 获取缓存的记录
+    /// 是否启用了负缓存
+    pub fn negative_cache_enabled(&self) -> bool {
+        self.config.enabled && self.config.ttl_config.negative_ttl_secs.is_some()
+    }
+
+    /// 缓存“记录不存在”的结果（未启用负缓存时不做任何事）
+    pub async fn cache_missing_record(&self, table: &str, id: &IdType) -> Result<()> {
+        let Some(ttl) = self.config.ttl_config.negative_ttl_secs.filter(|_| self.caches_table(table)) else {
+            return Ok(());
+        };
+
+        let key = self.generate_cache_key(table, id, "record");
+        let options = CacheOptions {
+            ttl_seconds: Some(ttl),
+            ..Default::default()
+        };
+
+        self.cache
+            .set_with_options(key.clone(), Bytes::from_static(MISSING_RECORD_SENTINEL), &options)
+            .await
+            .map_err(|e| anyhow!("Failed to cache missing record: {}", e))?;
+        self.track_cache_key(table, key).await;
+        self.record_table_write(table, MISSING_RECORD_SENTINEL.len());
+
+        debug!("已缓存记录不存在标记: table={}, id={:?}, ttl={}s", table, id, ttl);
+        Ok(())
+    }
+
+    /// 获取缓存的记录（负缓存标记视为未缓存）
     pub async fn get_cached_record(&self, table: &str, id: &IdType) -> Result<Option<DataValue>> {
-        if !self.config.enabled {
+        Ok(match self.lookup_cached_record(table, id).await? {
+            Some(CachedRecord::Found(data)) => Some(data),
+            _ => None,
+        })
+    }
+
+    /// 查询记录缓存，区分“缓存了记录”与“缓存了不存在”
+    pub async fn lookup_cached_record(&self, table: &str, id: &IdType) -> Result<Option<CachedRecord>> {
+        if !self.caches_table(table) {
             return Ok(None);
         }
 
@@ -66,12 +116,19 @@ impl CacheManager {
         let key = self.generate_cache_key(table, id, "record");
         match self.cache.get(&key).await {
             Ok(Some(data)) => {
-                let deserialized: DataValue = serde_json::from_slice(&data)
-                    .map_err(|e| anyhow!("Failed to deserialize cached data: {}", e))?;
+                let cached = if data.as_ref() == MISSING_RECORD_SENTINEL {
+                    CachedRecord::Missing
+                } else {
+                    CachedRecord::Found(
+                        serde_json::from_slice(&data)
+                            .map_err(|e| anyhow!("Failed to deserialize cached data: {}", e))?,
+                    )
+                };
 
                 // 更新命中统计
                 let elapsed = start_time.elapsed();
                 self.hits_counter.fetch_add(1, Ordering::Relaxed);
+                self.record_table_hit(table, data.len());
                 {
                     let mut stats = self.stats.write().await;
                     stats.hits += 1;
@@ -80,12 +137,13 @@ impl CacheManager {
                 }
 
                 debug!("缓存命中: table={}, id={:?}", table, id);
-                Ok(Some(deserialized))
+                Ok(Some(cached))
             }
             Ok(None) => {
                 // 更新未命中统计
                 let elapsed = start_time.elapsed();
                 self.misses_counter.fetch_add(1, Ordering::Relaxed);
+                self.record_table_miss(table);
                 {
                     let mut stats = self.stats.write().await;
                     stats.misses += 1;
@@ -100,6 +158,7 @@ impl CacheManager {
                 // 错误也算作未命中
                 let elapsed = start_time.elapsed();
                 self.misses_counter.fetch_add(1, Ordering::Relaxed);
+                self.record_table_miss(table);
                 {
                     let mut stats = self.stats.write().await;
                     stats.misses += 1;
@@ -113,3 +172,26 @@ impl CacheManager {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn missing_records_are_cached_only_when_enabled() {
+        let id = IdType::String("ghost".to_string());
+
+        let cache = CacheManager::new(crate::cache::test_cache_config(Some(5))).await.unwrap();
+        cache.cache_missing_record("users", &id).await.unwrap();
+        assert_eq!(cache.lookup_cached_record("users", &id).await.unwrap(), Some(CachedRecord::Missing));
+        assert_eq!(cache.get_cached_record("users", &id).await.unwrap(), None);
+
+        // 写入记录时清除标记
+        cache.invalidate_record("users", &id).await.unwrap();
+        assert_eq!(cache.lookup_cached_record("users", &id).await.unwrap(), None);
+
+        let cache = CacheManager::new(crate::cache::test_cache_config(None)).await.unwrap();
+        cache.cache_missing_record("users", &id).await.unwrap();
+        assert_eq!(cache.lookup_cached_record("users", &id).await.unwrap(), None);
+    }
+}
@@ -0,0 +1,222 @@
+//! 缓存快照模块
+//!
+//! 把缓存内容导出到快照文件，服务重启后再导入，避免冷启动。
+//! 快照记录格式版本与缓存版本（`CacheConfig::version`），版本不一致时拒绝导入，
+//! 防止旧格式或旧版本的数据进入缓存
+
+use anyhow::{Result, anyhow};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use bytes::Bytes;
+use rat_logger::info;
+use rat_memcache::CacheOptions;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use super::cache_manager::{CACHE_KEY_PREFIX, CacheManager};
+
+/// 快照文件格式版本
+pub const CACHE_SNAPSHOT_FORMAT_VERSION: u32 = 1;
+
+/// 快照文件内容
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CacheSnapshot {
+    /// 快照文件格式版本
+    format_version: u32,
+    /// 导出时的缓存版本
+    cache_version: String,
+    /// 导出时间（Unix 秒）
+    created_at: u64,
+    /// 缓存条目
+    entries: Vec<CacheSnapshotEntry>,
+}
+
+/// 快照中的单个缓存条目
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CacheSnapshotEntry {
+    /// 所属表
+    table: String,
+    /// 缓存键
+    key: String,
+    /// 缓存值（Base64）
+    value: String,
+    /// 过期时间（Unix 秒），为空表示导出时没有剩余 TTL 信息
+    #[serde(default)]
+    expires_at: Option<u64>,
+}
+
+fn unix_now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// 从缓存键中解析表名（`rat_quickdb:{table}:...`）
+fn table_of_key(key: &str) -> Option<&str> {
+    let rest = key.strip_prefix(CACHE_KEY_PREFIX)?.strip_prefix(':')?;
+    rest.split(':').next().filter(|table| !table.is_empty())
+}
+
+impl CacheManager {
+    /// 导出缓存快照
+    ///
+    /// 导出 L1/L2 中所有未过期的缓存条目及其剩余 TTL，返回导出的条目数。
+    /// 先写入临时文件再重命名，导出中断不会破坏已有快照
+    pub async fn export_snapshot(&self, path: impl AsRef<Path>) -> Result<usize> {
+        let path = path.as_ref();
+        if !self.config.enabled {
+            return Err(anyhow!("缓存未启用，无法导出快照"));
+        }
+
+        let now = unix_now();
+        let mut entries = Vec::new();
+        let keys = self
+            .cache
+            .keys()
+            .await
+            .map_err(|e| anyhow!("读取缓存键失败: {}", e))?;
+
+        for key in keys {
+            let Some(table) = table_of_key(&key) else {
+                continue;
+            };
+            let Ok(Some(value)) = self.cache.get(&key).await else {
+                continue;
+            };
+            let expires_at = match self.cache.get_ttl(&key).await {
+                Some(0) => continue,
+                Some(ttl) => Some(now + ttl),
+                None => None,
+            };
+            entries.push(CacheSnapshotEntry {
+                table: table.to_string(),
+                value: STANDARD.encode(&value),
+                key,
+                expires_at,
+            });
+        }
+
+        let snapshot = CacheSnapshot {
+            format_version: CACHE_SNAPSHOT_FORMAT_VERSION,
+            cache_version: self.config.version.clone(),
+            created_at: now,
+            entries,
+        };
+        let content =
+            serde_json::to_vec(&snapshot).map_err(|e| anyhow!("序列化缓存快照失败: {}", e))?;
+
+        let tmp_path = path.with_extension("tmp");
+        tokio::fs::write(&tmp_path, &content)
+            .await
+            .map_err(|e| anyhow!("写入缓存快照失败: path={}, error={}", tmp_path.display(), e))?;
+        tokio::fs::rename(&tmp_path, path)
+            .await
+            .map_err(|e| anyhow!("写入缓存快照失败: path={}, error={}", path.display(), e))?;
+
+        info!(
+            "已导出缓存快照: path={}, entries={}",
+            path.display(),
+            snapshot.entries.len()
+        );
+        Ok(snapshot.entries.len())
+    }
+
+    /// 导入缓存快照
+    ///
+    /// 格式版本或缓存版本与当前配置不一致时返回错误；已过期的条目和
+    /// 当前未启用缓存的表会被跳过。没有 TTL 信息的条目使用表的默认 TTL。
+    /// 返回导入的条目数，导入不计入写入统计
+    pub async fn import_snapshot(&self, path: impl AsRef<Path>) -> Result<usize> {
+        let path = path.as_ref();
+        if !self.config.enabled {
+            return Err(anyhow!("缓存未启用，无法导入快照"));
+        }
+
+        let content = tokio::fs::read(path)
+            .await
+            .map_err(|e| anyhow!("读取缓存快照失败: path={}, error={}", path.display(), e))?;
+        let snapshot: CacheSnapshot =
+            serde_json::from_slice(&content).map_err(|e| anyhow!("解析缓存快照失败: {}", e))?;
+
+        if snapshot.format_version != CACHE_SNAPSHOT_FORMAT_VERSION {
+            return Err(anyhow!(
+                "缓存快照格式版本不兼容: expected={}, actual={}",
+                CACHE_SNAPSHOT_FORMAT_VERSION,
+                snapshot.format_version
+            ));
+        }
+        if snapshot.cache_version != self.config.version {
+            return Err(anyhow!(
+                "缓存快照版本不匹配: expected={}, actual={}",
+                self.config.version,
+                snapshot.cache_version
+            ));
+        }
+
+        let now = unix_now();
+        let mut imported = 0;
+        for entry in snapshot.entries {
+            if !self.caches_table(&entry.table) {
+                continue;
+            }
+            let ttl_seconds = match entry.expires_at {
+                Some(expires_at) if expires_at <= now => continue,
+                Some(expires_at) => expires_at - now,
+                None => self.table_ttl_secs(&entry.table),
+            };
+            let value = STANDARD
+                .decode(&entry.value)
+                .map_err(|e| anyhow!("解析缓存快照失败: key={}, error={}", entry.key, e))?;
+
+            let options = CacheOptions {
+                ttl_seconds: Some(ttl_seconds),
+                ..Default::default()
+            };
+            self.cache
+                .set_with_options(entry.key.clone(), Bytes::from(value), &options)
+                .await
+                .map_err(|e| anyhow!("导入缓存快照失败: key={}, error={}", entry.key, e))?;
+            self.track_cache_key(&entry.table, entry.key).await;
+            imported += 1;
+        }
+
+        info!(
+            "已导入缓存快照: path={}, created_at={}, entries={}",
+            path.display(),
+            snapshot.created_at,
+            imported
+        );
+        Ok(imported)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::CachedRecord;
+    use crate::types::{DataValue, IdType};
+
+    #[tokio::test]
+    async fn snapshot_round_trips_and_rejects_other_versions() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("cache.snapshot");
+        let record = DataValue::String("alice".to_string());
+
+        let source = CacheManager::new(crate::cache::test_cache_config(None)).await.unwrap();
+        source.cache_record("snap_users", &IdType::Number(1), &record).await.unwrap();
+        assert_eq!(source.export_snapshot(&path).await.unwrap(), 1);
+
+        let target = CacheManager::new(crate::cache::test_cache_config(None)).await.unwrap();
+        assert_eq!(target.import_snapshot(&path).await.unwrap(), 1);
+        assert_eq!(
+            target.lookup_cached_record("snap_users", &IdType::Number(1)).await.unwrap(),
+            Some(CachedRecord::Found(record))
+        );
+
+        let mut config = crate::cache::test_cache_config(None);
+        config.version = "v2".to_string();
+        let other_version = CacheManager::new(config).await.unwrap();
+        assert!(other_version.import_snapshot(&path).await.is_err());
+    }
+}
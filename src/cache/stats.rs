@@ -3,6 +3,7 @@
 //! 提供缓存性能统计和信息收集功能
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 // 从 cache_manager.rs 中引入 CacheManager
 use super::cache_manager::CacheManager;
@@ -28,6 +29,12 @@ pub struct CachePerformanceStats {
     pub write_count: u64,
 }
 
+impl Default for CachePerformanceStats {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl CachePerformanceStats {
     pub fn new() -> Self {
         Self {
@@ -85,6 +92,9 @@ pub struct CacheStats {
     pub memory_usage_bytes: usize,
     /// 磁盘使用量（字节）
     pub disk_usage_bytes: usize,
+    /// 按表划分的统计
+    #[serde(default)]
+    pub tables: HashMap<String, TableCacheStats>,
 }
 
 impl Default for CacheStats {
@@ -96,6 +106,91 @@ impl Default for CacheStats {
             entries: 0,
             memory_usage_bytes: 0,
             disk_usage_bytes: 0,
+            tables: HashMap::new(),
+        }
+    }
+}
+
+/// 单表缓存统计
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TableCacheStats {
+    /// 缓存命中次数
+    pub hits: u64,
+    /// 缓存未命中次数
+    pub misses: u64,
+    /// 缓存写入次数
+    pub writes: u64,
+    /// 被清除的缓存条目数（写操作触发的失效与手动清理；TTL 过期与容量淘汰无法单独统计）
+    pub evictions: u64,
+    /// 累计写入缓存的字节数
+    pub bytes_written: u64,
+    /// 命中时累计读出的字节数
+    pub bytes_read: u64,
+}
+
+impl TableCacheStats {
+    /// 计算命中率
+    pub fn hit_rate(&self) -> f64 {
+        let total = self.hits + self.misses;
+        if total == 0 {
+            0.0
+        } else {
+            self.hits as f64 / total as f64
+        }
+    }
+}
+
+impl CacheManager {
+    /// 记录单表命中
+    pub(crate) fn record_table_hit(&self, table: &str, bytes: usize) {
+        let mut stats = self.table_stats.entry(table.to_string()).or_default();
+        stats.hits += 1;
+        stats.bytes_read += bytes as u64;
+    }
+
+    /// 记录单表未命中
+    pub(crate) fn record_table_miss(&self, table: &str) {
+        self.table_stats.entry(table.to_string()).or_default().misses += 1;
+    }
+
+    /// 记录单表写入
+    pub(crate) fn record_table_write(&self, table: &str, bytes: usize) {
+        let mut stats = self.table_stats.entry(table.to_string()).or_default();
+        stats.writes += 1;
+        stats.bytes_written += bytes as u64;
+    }
+
+    /// 记录单表被清除的条目数
+    pub(crate) fn record_table_evictions(&self, table: &str, count: usize) {
+        if count > 0 {
+            self.table_stats.entry(table.to_string()).or_default().evictions += count as u64;
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{DataValue, IdType};
+
+    #[tokio::test]
+    async fn tracks_hits_misses_and_evictions_per_table() {
+        let cache = CacheManager::new(crate::cache::test_cache_config(None)).await.unwrap();
+        let id = IdType::Number(7);
+
+        cache.cache_record("users", &id, &DataValue::Int(1)).await.unwrap();
+        cache.get_cached_record("users", &id).await.unwrap();
+        cache.get_cached_record("orders", &id).await.unwrap();
+        cache.invalidate_record("users", &id).await.unwrap();
+
+        let stats = cache.get_table_stats();
+        let users = &stats["users"];
+        assert_eq!((users.hits, users.misses, users.writes, users.evictions), (1, 0, 1, 1));
+        assert!(users.bytes_written > 0 && users.bytes_read == users.bytes_written);
+        assert_eq!(stats["orders"].misses, 1);
+        assert_eq!(stats["orders"].hit_rate(), 0.0);
+
+        cache.reset_stats().await.unwrap();
+        assert!(cache.get_table_stats().is_empty());
+    }
+}
@@ -0,0 +1,180 @@
+//! 缓存预热模块
+//!
+//! 在流量到达之前预先执行配置好的查询，把结果写入 L1/L2 缓存。
+//! 预热写入的缓存键与正常查询完全一致，预热后的首次查询即可命中
+
+use crate::error::QuickDbResult;
+use crate::types::{DataValue, QueryConditionGroupWithConfig, QueryOptions};
+use anyhow::{Result, anyhow};
+use rat_logger::debug;
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+
+use super::cache_manager::CacheManager;
+
+/// 预热查询
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CacheWarmQuery {
+    /// 表名
+    pub table: String,
+    /// 查询条件组
+    #[serde(default)]
+    pub condition_groups: Vec<QueryConditionGroupWithConfig>,
+    /// 查询选项（排序、分页、投影等，需与实际查询保持一致才能命中）
+    #[serde(default)]
+    pub options: QueryOptions,
+}
+
+/// 预热配置
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CacheWarmSpec {
+    /// 按顺序执行的预热查询
+    pub queries: Vec<CacheWarmQuery>,
+}
+
+impl CacheWarmSpec {
+    /// 创建空的预热配置
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 追加预热查询
+    pub fn query(
+        mut self,
+        table: &str,
+        condition_groups: Vec<QueryConditionGroupWithConfig>,
+        options: QueryOptions,
+    ) -> Self {
+        self.queries.push(CacheWarmQuery {
+            table: table.to_string(),
+            condition_groups,
+            options,
+        });
+        self
+    }
+}
+
+/// 单个预热查询的结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CacheWarmOutcome {
+    /// 已从数据库加载并写入缓存
+    Loaded { rows: usize },
+    /// 缓存中已存在，未访问数据库
+    AlreadyCached,
+    /// 缓存未启用
+    Disabled,
+    /// 查询失败
+    Failed { message: String },
+}
+
+/// 预热进度（每完成一个查询回调一次）
+#[derive(Debug, Clone)]
+pub struct CacheWarmProgress {
+    /// 已完成的查询数
+    pub completed: usize,
+    /// 查询总数
+    pub total: usize,
+    /// 当前完成的查询对应的表
+    pub table: String,
+    /// 当前查询的结果
+    pub outcome: CacheWarmOutcome,
+}
+
+/// 预热汇总
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CacheWarmReport {
+    /// 查询总数
+    pub total: usize,
+    /// 从数据库加载并写入缓存的查询数
+    pub loaded: usize,
+    /// 已在缓存中的查询数
+    pub already_cached: usize,
+    /// 失败的查询数
+    pub failed: usize,
+}
+
+impl CacheWarmReport {
+    /// 记录单个查询的结果
+    pub fn record(&mut self, outcome: &CacheWarmOutcome) {
+        match outcome {
+            CacheWarmOutcome::Loaded { .. } => self.loaded += 1,
+            CacheWarmOutcome::AlreadyCached => self.already_cached += 1,
+            CacheWarmOutcome::Failed { .. } => self.failed += 1,
+            CacheWarmOutcome::Disabled => {}
+        }
+    }
+}
+
+impl CacheManager {
+    /// 预热单个查询
+    ///
+    /// 缓存中不存在该查询的结果时调用 `load` 从数据库加载并写入缓存；
+    /// 已存在时直接返回，不调用 `load`，也不计入命中/未命中统计
+    pub async fn warm<F, Fut>(
+        &self,
+        table: &str,
+        condition_groups: &[QueryConditionGroupWithConfig],
+        options: &QueryOptions,
+        load: F,
+    ) -> Result<CacheWarmOutcome>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = QuickDbResult<Vec<DataValue>>>,
+    {
+        if !self.caches_table(table) {
+            return Ok(CacheWarmOutcome::Disabled);
+        }
+
+        let key = self.generate_condition_groups_with_config_cache_key(table, condition_groups, options);
+        if let Ok(Some(_)) = self.cache.get(&key).await {
+            debug!("预热跳过，缓存已存在: table={}, key={}", table, key);
+            return Ok(CacheWarmOutcome::AlreadyCached);
+        }
+
+        let results = load()
+            .await
+            .map_err(|e| anyhow!("预热查询失败: table={}, error={}", table, e))?;
+        self.cache_condition_groups_with_config_result(table, condition_groups, options, &results)
+            .await?;
+
+        debug!("预热完成: table={}, key={}, rows={}", table, key, results.len());
+        Ok(CacheWarmOutcome::Loaded { rows: results.len() })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::*;
+
+    #[tokio::test]
+    async fn warm_loads_once_and_serves_regular_lookups() {
+        let cache = CacheManager::new(crate::cache::test_cache_config(None)).await.unwrap();
+        let groups = vec![QueryConditionGroupWithConfig::Single(QueryConditionWithConfig {
+            field: "status".to_string(),
+            operator: QueryOperator::Eq,
+            value: DataValue::String("active".to_string()),
+            case_insensitive: false,
+        })];
+        let options = QueryOptions::default();
+        let rows = vec![DataValue::Int(1), DataValue::Int(2)];
+
+        let outcome = cache
+            .warm("warm_users", &groups, &options, || async { Ok(rows.clone()) })
+            .await
+            .unwrap();
+        assert_eq!(outcome, CacheWarmOutcome::Loaded { rows: 2 });
+
+        let outcome = cache
+            .warm("warm_users", &groups, &options, || async { panic!("不应再次访问数据库") })
+            .await
+            .unwrap();
+        assert_eq!(outcome, CacheWarmOutcome::AlreadyCached);
+
+        let cached = cache
+            .get_cached_condition_groups_with_config_result("warm_users", &groups, &options)
+            .await
+            .unwrap();
+        assert_eq!(cached, Some(rows));
+    }
+}
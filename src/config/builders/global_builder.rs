@@ -2,12 +2,11 @@
 //!
 //! 提供全局配置的构建器实现，支持链式调用和严格验证
 
-use crate::config::core::{AppConfig, Environment, GlobalConfig, LogLevel, LoggingConfig};
+use crate::config::core::{AppConfig, GlobalConfig, LoggingConfig, QueryGuardrails};
 use crate::error::QuickDbError;
 use crate::types::*;
 use rat_logger::info;
 use std::collections::HashMap;
-use std::path::PathBuf;
 
 /// 全局配置构建器
 ///
@@ -18,6 +17,7 @@ pub struct GlobalConfigBuilder {
     default_database: Option<String>,
     app: Option<AppConfig>,
     logging: Option<LoggingConfig>,
+    guardrails: HashMap<String, QueryGuardrails>,
 }
 impl GlobalConfigBuilder {
     /// 创建新的构建器
@@ -27,6 +27,7 @@ impl GlobalConfigBuilder {
             default_database: None,
             app: None,
             logging: None,
+            guardrails: HashMap::new(),
         }
     }
 
@@ -71,6 +72,17 @@ impl GlobalConfigBuilder {
         self
     }
 
+    /// 设置数据库的查询防护配置
+    ///
+    /// # 参数
+    ///
+    /// * `alias` - 数据库别名
+    /// * `guardrails` - 查询防护配置
+    pub fn guardrails<S: Into<String>>(mut self, alias: S, guardrails: QueryGuardrails) -> Self {
+        self.guardrails.insert(alias.into(), guardrails);
+        self
+    }
+
     /// 构建全局配置
     ///
     /// # 错误
@@ -110,6 +122,7 @@ impl GlobalConfigBuilder {
             default_database: self.default_database,
             app,
             logging,
+            guardrails: self.guardrails,
         })
     }
 }
@@ -4,8 +4,11 @@
 
 use crate::error::QuickDbError;
 use crate::types::*;
+use crate::types::database_config::{
+    DEFAULT_CIRCUIT_BREAKER_RESET_MS, DEFAULT_CIRCUIT_BREAKER_THRESHOLD,
+    DEFAULT_MAX_RECONNECT_BACKOFF_MS, DEFAULT_STATEMENT_CACHE_CAPACITY,
+};
 use rat_logger::info;
-use std::path::PathBuf;
 
 /// 连接池配置构建器
 ///
@@ -21,6 +24,14 @@ pub struct PoolConfigBuilder {
     retry_interval_ms: Option<u64>,
     keepalive_interval_sec: Option<u64>,
     health_check_timeout_sec: Option<u64>,
+    circuit_breaker_threshold: Option<u32>,
+    circuit_breaker_reset_ms: Option<u64>,
+    connect_mode: Option<ConnectMode>,
+    statement_cache_capacity: Option<usize>,
+    max_reconnect_backoff_ms: Option<u64>,
+    queue_capacity: Option<usize>,
+    overflow_policy: Option<OverflowPolicy>,
+    write_batch: Option<WriteBatchConfig>,
 }
 impl PoolConfig {
     /// 创建连接池配置构建器
@@ -42,6 +53,14 @@ impl PoolConfigBuilder {
             retry_interval_ms: None,
             keepalive_interval_sec: None,
             health_check_timeout_sec: None,
+            circuit_breaker_threshold: None,
+            circuit_breaker_reset_ms: None,
+            connect_mode: None,
+            statement_cache_capacity: None,
+            max_reconnect_backoff_ms: None,
+            queue_capacity: None,
+            overflow_policy: None,
+            write_batch: None,
         }
     }
 
@@ -135,6 +154,88 @@ impl PoolConfigBuilder {
         self
     }
 
+    /// 设置熔断阈值（可选，默认5次，0 表示禁用熔断）
+    ///
+    /// # 参数
+    ///
+    /// * `threshold` - 连续失败多少次后打开熔断器
+    pub fn circuit_breaker_threshold(mut self, threshold: u32) -> Self {
+        self.circuit_breaker_threshold = Some(threshold);
+        self
+    }
+
+    /// 设置熔断恢复等待时间（可选，默认30000毫秒）
+    ///
+    /// # 参数
+    ///
+    /// * `reset_ms` - 熔断器打开后进入半开探测前的等待时间（毫秒）
+    pub fn circuit_breaker_reset_ms(mut self, reset_ms: u64) -> Self {
+        self.circuit_breaker_reset_ms = Some(reset_ms);
+        self
+    }
+
+    /// 设置连接模式（可选，默认延迟连接）
+    ///
+    /// # 参数
+    ///
+    /// * `mode` - `ConnectMode::Eager` 在添加数据库时建立并验证最小连接数，
+    ///   连接失败（如凭据错误）立即返回错误；`ConnectMode::Lazy` 在后台建立连接
+    pub fn connect_mode(mut self, mode: ConnectMode) -> Self {
+        self.connect_mode = Some(mode);
+        self
+    }
+
+    /// 设置预编译语句缓存容量（可选，默认100，0 表示禁用）
+    ///
+    /// # 参数
+    ///
+    /// * `capacity` - 每个连接缓存的预编译语句数量
+    pub fn statement_cache_capacity(mut self, capacity: usize) -> Self {
+        self.statement_cache_capacity = Some(capacity);
+        self
+    }
+
+    /// 设置重连退避上限（可选，默认30000毫秒）
+    ///
+    /// # 参数
+    ///
+    /// * `backoff_ms` - 连接断开后重连的最长等待时间（毫秒），退避从重试间隔开始逐次加倍
+    pub fn max_reconnect_backoff_ms(mut self, backoff_ms: u64) -> Self {
+        self.max_reconnect_backoff_ms = Some(backoff_ms);
+        self
+    }
+
+    /// 设置操作队列容量（可选，默认不限制）
+    ///
+    /// # 参数
+    ///
+    /// * `capacity` - 已提交但尚未开始执行的操作数上限，队列满时按溢出策略处理
+    pub fn queue_capacity(mut self, capacity: usize) -> Self {
+        self.queue_capacity = Some(capacity);
+        self
+    }
+
+    /// 设置操作队列溢出策略（可选，默认阻塞等待5000毫秒）
+    ///
+    /// # 参数
+    ///
+    /// * `policy` - 队列已满时阻塞等待（带超时）或立即拒绝
+    pub fn overflow_policy(mut self, policy: OverflowPolicy) -> Self {
+        self.overflow_policy = Some(policy);
+        self
+    }
+
+    /// 启用 SQLite 写入合并（可选，默认逐条执行）
+    ///
+    /// # 参数
+    ///
+    /// * `max_size` - 单个事务最多合并的创建操作数
+    /// * `window_ms` - 收到第一个创建操作后等待后续创建操作的最长时间（毫秒）
+    pub fn write_batch(mut self, max_size: usize, window_ms: u64) -> Self {
+        self.write_batch = Some(WriteBatchConfig { max_size, window_ms });
+        self
+    }
+
     /// 构建连接池配置
     ///
     /// # 错误
@@ -194,6 +295,14 @@ impl PoolConfigBuilder {
             return Err(crate::quick_error!(config, crate::i18n::t("config.max_lifetime_zero")));
         }
 
+        if self.queue_capacity == Some(0) {
+            return Err(crate::quick_error!(config, crate::i18n::t("config.queue_capacity_zero")));
+        }
+
+        if self.write_batch.is_some_and(|batch| batch.max_size == 0) {
+            return Err(crate::quick_error!(config, crate::i18n::t("config.write_batch_size_zero")));
+        }
+
         info!(
             "创建连接池配置: 最小连接数={}, 最大连接数={}, 连接超时={}s",
             min_connections, max_connections, connection_timeout
@@ -209,6 +318,22 @@ impl PoolConfigBuilder {
             retry_interval_ms,
             keepalive_interval_sec,
             health_check_timeout_sec,
+            circuit_breaker_threshold: self
+                .circuit_breaker_threshold
+                .unwrap_or(DEFAULT_CIRCUIT_BREAKER_THRESHOLD),
+            circuit_breaker_reset_ms: self
+                .circuit_breaker_reset_ms
+                .unwrap_or(DEFAULT_CIRCUIT_BREAKER_RESET_MS),
+            connect_mode: self.connect_mode.unwrap_or_default(),
+            statement_cache_capacity: self
+                .statement_cache_capacity
+                .unwrap_or(DEFAULT_STATEMENT_CACHE_CAPACITY),
+            max_reconnect_backoff_ms: self
+                .max_reconnect_backoff_ms
+                .unwrap_or(DEFAULT_MAX_RECONNECT_BACKOFF_MS),
+            queue_capacity: self.queue_capacity,
+            overflow_policy: self.overflow_policy.unwrap_or_default(),
+            write_batch: self.write_batch,
         })
     }
 }
@@ -223,11 +348,11 @@ mod tests {
     use super::*;
     use crate::config::builders::DatabaseConfigBuilder;
 
-    /// NOTE: These tests require `--test-threads=1` because i18n language state is global.
-    ///
-    /// ```bash
-    /// cargo test --lib config::builders::pool_builder::tests -- --test-threads=1
-    /// ```
+    // NOTE: These tests require `--test-threads=1` because i18n language state is global.
+    //
+    // ```bash
+    // cargo test --lib config::builders::pool_builder::tests -- --test-threads=1
+    // ```
 
     fn setup_i18n(lang: &str) {
         crate::i18n::ErrorMessageI18n::init_i18n();
@@ -641,6 +766,8 @@ mod tests {
             .connection(ConnectionConfig::SQLite {
                 path: "/tmp/test.db".into(),
                 create_if_missing: true,
+                pragmas: None,
+                encryption_key: None,
             })
             .pool(pool)
             .alias("test")
@@ -672,6 +799,8 @@ mod tests {
             .connection(ConnectionConfig::SQLite {
                 path: "/tmp/test.db".into(),
                 create_if_missing: true,
+                pragmas: None,
+                encryption_key: None,
             })
             .alias("test")
             .id_strategy(IdStrategy::AutoIncrement)
@@ -689,6 +818,8 @@ mod tests {
             .connection(ConnectionConfig::SQLite {
                 path: "/tmp/test.db".into(),
                 create_if_missing: true,
+                pragmas: None,
+                encryption_key: None,
             })
             .pool(pool)
             .id_strategy(IdStrategy::AutoIncrement)
@@ -706,6 +837,8 @@ mod tests {
             .connection(ConnectionConfig::SQLite {
                 path: "/tmp/test.db".into(),
                 create_if_missing: true,
+                pragmas: None,
+                encryption_key: None,
             })
             .pool(pool)
             .alias("test")
@@ -728,6 +861,8 @@ mod tests {
                 password: "pass".into(),
                 ssl_mode: Some("prefer".into()),
                 tls_config: None,
+                socket_path: None,
+                pgbouncer_mode: false,
             })
             .pool(pool)
             .alias("test")
@@ -748,6 +883,8 @@ mod tests {
             .connection(ConnectionConfig::SQLite {
                 path: "/tmp/test.db".into(),
                 create_if_missing: true,
+                pragmas: None,
+                encryption_key: None,
             })
             .pool(pool)
             .alias("test")
@@ -771,6 +908,8 @@ mod tests {
                 password: "pass".into(),
                 ssl_mode: Some("prefer".into()),
                 tls_config: None,
+                socket_path: None,
+                pgbouncer_mode: false,
             })
             .pool(pool)
             .alias("test")
@@ -791,6 +930,8 @@ mod tests {
             .connection(ConnectionConfig::SQLite {
                 path: "/tmp/test.db".into(),
                 create_if_missing: true,
+                pragmas: None,
+                encryption_key: None,
             })
             .pool(pool)
             .alias("test")
@@ -800,6 +941,36 @@ mod tests {
         assert_eq!(config_message(&err), "データベースタイプは必須です");
     }
 
+    #[test]
+    fn test_pool_connect_mode_defaults_to_lazy() {
+        let pool = valid_pool_config();
+        assert_eq!(pool.connect_mode, ConnectMode::Lazy);
+
+        let json = serde_json::to_value(&pool).unwrap();
+        assert_eq!(json["connect_mode"], "lazy");
+
+        // 旧配置缺少 connect_mode 时按延迟连接处理
+        let mut legacy = json.clone();
+        legacy.as_object_mut().unwrap().remove("connect_mode");
+        let restored: PoolConfig = serde_json::from_str(&legacy.to_string()).unwrap();
+        assert_eq!(restored.connect_mode, ConnectMode::Lazy);
+
+        let eager = PoolConfigBuilder::new()
+            .min_connections(2)
+            .max_connections(10)
+            .connection_timeout(30)
+            .idle_timeout(600)
+            .max_lifetime(1800)
+            .max_retries(3)
+            .retry_interval_ms(1000)
+            .keepalive_interval_sec(60)
+            .health_check_timeout_sec(5)
+            .connect_mode(ConnectMode::Eager)
+            .build()
+            .unwrap();
+        assert_eq!(eager.connect_mode, ConnectMode::Eager);
+    }
+
     // ===== helper =====
 
     fn valid_pool_config() -> PoolConfig {
@@ -2,7 +2,6 @@
 //!
 //! 提供常用数据库配置的便利函数，简化配置过程
 
-use crate::config::builders::*;
 use crate::error::QuickDbError;
 use crate::types::*;
 
@@ -32,6 +31,8 @@ pub fn sqlite_config<S: Into<String>, P: Into<String>>(
         .connection(ConnectionConfig::SQLite {
             path: path.into(),
             create_if_missing: true,
+            pragmas: None,
+            encryption_key: None,
         })
         .pool(pool_config)
         .alias(alias)
@@ -58,6 +59,7 @@ pub fn sqlite_config<S: Into<String>, P: Into<String>>(
     since = "0.3.2",
     note = "将在v0.4.0版本中移除，请使用DatabaseConfig::builder()模式"
 )]
+#[allow(clippy::too_many_arguments)]
 pub fn postgres_config<S: Into<String>>(
     alias: S,
     host: S,
@@ -78,6 +80,8 @@ pub fn postgres_config<S: Into<String>>(
             password: password.into(),
             ssl_mode: Some("prefer".to_string()),
             tls_config: None,
+            socket_path: None,
+            pgbouncer_mode: false,
         })
         .pool(pool_config)
         .alias(alias)
@@ -104,6 +108,7 @@ pub fn postgres_config<S: Into<String>>(
     since = "0.3.2",
     note = "将在v0.4.0版本中移除，请使用DatabaseConfig::builder()模式"
 )]
+#[allow(clippy::too_many_arguments)]
 pub fn mysql_config<S: Into<String>>(
     alias: S,
     host: S,
@@ -124,6 +129,9 @@ pub fn mysql_config<S: Into<String>>(
             password: password.into(),
             ssl_opts: None,
             tls_config: None,
+            socket: None,
+            charset: None,
+            collation: None,
         })
         .pool(pool_config)
         .alias(alias)
@@ -150,6 +158,7 @@ pub fn mysql_config<S: Into<String>>(
     since = "0.3.2",
     note = "将在v0.4.0版本中移除，请使用DatabaseConfig::builder()模式"
 )]
+#[allow(clippy::too_many_arguments)]
 pub fn mongodb_config<S: Into<String>>(
     alias: S,
     host: S,
@@ -23,6 +23,8 @@ pub struct GlobalConfig {
     pub app: AppConfig,
     /// 日志配置
     pub logging: LoggingConfig,
+    /// 查询防护配置 (别名 -> 防护配置)
+    pub guardrails: HashMap<String, QueryGuardrails>,
 }
 
 /// 应用配置
@@ -70,6 +72,50 @@ pub struct LoggingConfig {
     pub structured: bool,
 }
 
+/// 查询防护配置，防止无分页查询返回过多记录或误删整表
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QueryGuardrails {
+    /// 无分页查询允许返回的最大记录数（未设置时不限制）
+    #[serde(default)]
+    pub max_result_rows: Option<u64>,
+    /// 超过最大记录数时的处理方式
+    #[serde(default)]
+    pub on_row_limit: RowLimitAction,
+    /// 是否禁止以空条件列表执行删除
+    #[serde(default)]
+    pub require_conditions_for_delete: bool,
+}
+
+impl QueryGuardrails {
+    /// 创建不做任何限制的防护配置
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 设置无分页查询的最大记录数与超限处理方式
+    pub fn max_result_rows(mut self, max_rows: u64, action: RowLimitAction) -> Self {
+        self.max_result_rows = Some(max_rows);
+        self.on_row_limit = action;
+        self
+    }
+
+    /// 设置是否禁止以空条件列表执行删除
+    pub fn require_conditions_for_delete(mut self, require: bool) -> Self {
+        self.require_conditions_for_delete = require;
+        self
+    }
+}
+
+/// 无分页查询超过最大记录数时的处理方式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RowLimitAction {
+    /// 返回错误
+    #[default]
+    Reject,
+    /// 只返回前 `max_result_rows` 条记录
+    Truncate,
+}
+
 /// 日志级别
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum LogLevel {
@@ -98,7 +144,7 @@ impl GlobalConfig {
     /// * `config_path` - 配置文件路径
     pub fn from_file<P: AsRef<std::path::Path>>(config_path: P) -> Result<Self, QuickDbError> {
         let content =
-            std::fs::read_to_string(config_path.as_ref()).map_err(|e| QuickDbError::IoError(e))?;
+            std::fs::read_to_string(config_path.as_ref()).map_err(QuickDbError::IoError)?;
 
         let config: GlobalConfig = if config_path.as_ref().extension().and_then(|s| s.to_str())
             == Some("toml")
@@ -131,7 +177,7 @@ impl GlobalConfig {
                 .map_err(|e| crate::quick_error!(config, crate::i18n::tf("config.serialize_json_failed", &[("message", &e.to_string())])))?
         };
 
-        std::fs::write(config_path.as_ref(), content).map_err(|e| QuickDbError::IoError(e))?;
+        std::fs::write(config_path.as_ref(), content).map_err(QuickDbError::IoError)?;
 
         info!("保存配置到文件: {:?}", config_path.as_ref());
         Ok(())
@@ -159,6 +205,13 @@ impl GlobalConfig {
             .get(alias)
             .ok_or_else(|| crate::quick_error!(config, crate::i18n::tf("config.database_not_found", &[("alias", alias)])))
     }
+
+    /// 将各别名的查询防护配置应用到全局连接池管理器
+    pub fn apply_guardrails(&self) {
+        for (alias, guardrails) in &self.guardrails {
+            crate::manager::set_query_guardrails(alias, guardrails.clone());
+        }
+    }
 }
 
 impl Serialize for GlobalConfig {
@@ -167,11 +220,12 @@ impl Serialize for GlobalConfig {
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let mut state = serializer.serialize_struct("GlobalConfig", 4)?;
+        let mut state = serializer.serialize_struct("GlobalConfig", 5)?;
         state.serialize_field("databases", &self.databases)?;
         state.serialize_field("default_database", &self.default_database)?;
         state.serialize_field("app", &self.app)?;
         state.serialize_field("logging", &self.logging)?;
+        state.serialize_field("guardrails", &self.guardrails)?;
         state.end()
     }
 }
@@ -201,6 +255,7 @@ impl<'de> Deserialize<'de> for GlobalConfig {
                 let mut default_database = None;
                 let mut app = None;
                 let mut logging = None;
+                let mut guardrails = None;
 
                 while let Some(key) = map.next_key()? {
                     match key {
@@ -228,6 +283,12 @@ impl<'de> Deserialize<'de> for GlobalConfig {
                             }
                             logging = Some(map.next_value()?);
                         }
+                        "guardrails" => {
+                            if guardrails.is_some() {
+                                return Err(de::Error::duplicate_field("guardrails"));
+                            }
+                            guardrails = Some(map.next_value()?);
+                        }
                         _ => {
                             let _ = map.next_value::<serde_json::Value>()?;
                         }
@@ -243,12 +304,13 @@ impl<'de> Deserialize<'de> for GlobalConfig {
                     default_database,
                     app,
                     logging,
+                    guardrails: guardrails.unwrap_or_default(),
                 })
             }
         }
 
-        const FIELDS: &'static [&'static str] =
-            &["databases", "default_database", "app", "logging"];
+        const FIELDS: &[&str] =
+            &["databases", "default_database", "app", "logging", "guardrails"];
         deserializer.deserialize_struct("GlobalConfig", FIELDS, GlobalConfigVisitor)
     }
 }
@@ -0,0 +1,321 @@
+//! # 配置管理模块 - 配置文件加载
+//!
+//! 从 TOML / YAML / JSON 文件加载数据库配置（含连接池与缓存配置），
+//! 字段结构与 `DatabaseConfig` 的序列化格式一致，所有必填项仍需在文件中显式给出。
+//! YAML 需要启用 `yaml` 特性。
+//!
+//! 文件内容在解析前会展开环境变量：
+//! - `${NAME}`：替换为环境变量 `NAME` 的值，变量不存在时报错
+//! - `${NAME:-default}`：变量不存在或为空时使用 `default`
+//! - `$${`：输出字面量 `${`
+//!
+//! ```toml
+//! default_database = "main"
+//!
+//! [[databases]]
+//! alias = "main"
+//! db_type = "PostgreSQL"
+//! id_strategy = "AutoIncrement"
+//!
+//! [databases.connection.PostgreSQL]
+//! host = "${DB_HOST:-localhost}"
+//! port = 5432
+//! database = "app"
+//! username = "app"
+//! password = "${DB_PASSWORD}"
+//!
+//! [databases.pool]
+//! min_connections = 1
+//! max_connections = 10
+//! connection_timeout = 30000  # 毫秒（与 PoolConfigBuilder 构建后的存储单位一致）
+//! idle_timeout = 600
+//! max_lifetime = 3600
+//! max_retries = 3
+//! retry_interval_ms = 1000
+//! keepalive_interval_sec = 60
+//! health_check_timeout_sec = 10
+//! ```
+
+use crate::config::builders::DatabaseConfigBuilder;
+use crate::error::QuickDbError;
+use crate::types::*;
+use rat_logger::info;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::Path;
+
+/// 配置文件格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigFileFormat {
+    /// TOML
+    Toml,
+    /// YAML（需要 `yaml` 特性）
+    #[cfg(feature = "yaml")]
+    Yaml,
+    /// JSON
+    Json,
+}
+
+impl ConfigFileFormat {
+    /// 根据文件扩展名判断格式（`.toml`、`.yaml`/`.yml`、`.json`）
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension().and_then(|s| s.to_str())?.to_ascii_lowercase().as_str() {
+            "toml" => Some(Self::Toml),
+            #[cfg(feature = "yaml")]
+            "yaml" | "yml" => Some(Self::Yaml),
+            "json" => Some(Self::Json),
+            _ => None,
+        }
+    }
+}
+
+/// 配置文件内容
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DatabaseFileConfig {
+    /// 数据库配置列表（按文件中的顺序添加）
+    pub databases: Vec<DatabaseConfig>,
+    /// 默认数据库别名（可选，未设置时第一个添加的数据库为默认）
+    #[serde(default)]
+    pub default_database: Option<String>,
+}
+
+/// 从配置文件加载数据库配置
+///
+/// # 参数
+///
+/// * `path` - 配置文件路径，格式由扩展名决定
+pub fn load_from_file<P: AsRef<Path>>(path: P) -> Result<DatabaseFileConfig, QuickDbError> {
+    let path = path.as_ref();
+    let format = ConfigFileFormat::from_path(path).ok_or_else(|| {
+        crate::quick_error!(
+            config,
+            crate::i18n::tf("config.file_format_unsupported", &[("path", &path.display().to_string())])
+        )
+    })?;
+    let content = std::fs::read_to_string(path).map_err(QuickDbError::IoError)?;
+
+    let config = load_from_str(&content, format)?;
+    info!("从文件加载数据库配置: {:?}, 数据库数量={}", path, config.databases.len());
+    Ok(config)
+}
+
+/// 从字符串加载数据库配置
+///
+/// # 参数
+///
+/// * `content` - 配置内容
+/// * `format` - 配置格式
+pub fn load_from_str(content: &str, format: ConfigFileFormat) -> Result<DatabaseFileConfig, QuickDbError> {
+    let content = interpolate_env(content)?;
+
+    // 先统一转换为 JSON，再按 JSON 反序列化，保证各格式的字段规则完全一致
+    let value: serde_json::Value = match format {
+        ConfigFileFormat::Toml => toml::from_str(&content).map_err(|e| {
+            crate::quick_error!(config, crate::i18n::tf("config.parse_toml_failed", &[("message", &e.to_string())]))
+        })?,
+        #[cfg(feature = "yaml")]
+        ConfigFileFormat::Yaml => serde_norway::from_str(&content).map_err(|e| {
+            crate::quick_error!(config, crate::i18n::tf("config.parse_yaml_failed", &[("message", &e.to_string())]))
+        })?,
+        ConfigFileFormat::Json => serde_json::from_str(&content).map_err(|e| {
+            crate::quick_error!(config, crate::i18n::tf("config.parse_json_failed", &[("message", &e.to_string())]))
+        })?,
+    };
+    let config: DatabaseFileConfig = serde_json::from_str(&value.to_string()).map_err(|e| {
+        crate::quick_error!(config, crate::i18n::tf("config.invalid_file_config", &[("message", &e.to_string())]))
+    })?;
+
+    config.validate()?;
+    Ok(config)
+}
+
+impl DatabaseFileConfig {
+    /// 验证配置：至少一个数据库、别名不重复、类型与连接配置一致、默认别名存在
+    pub fn validate(&self) -> Result<(), QuickDbError> {
+        if self.databases.is_empty() {
+            return Err(crate::quick_error!(config, crate::i18n::t("config.at_least_one_database_required")));
+        }
+
+        let mut aliases = HashSet::new();
+        for database in &self.databases {
+            if !aliases.insert(database.alias.as_str()) {
+                return Err(crate::quick_error!(
+                    config,
+                    crate::i18n::tf("config.duplicate_database_alias", &[("alias", &database.alias)])
+                ));
+            }
+            DatabaseConfigBuilder::validate_config(&database.db_type, &database.connection)?;
+            DatabaseConfigBuilder::validate_on_connect_statements(&database.db_type, &database.on_connect_statements)?;
+            if let Some(naming) = &database.table_naming {
+                naming.validate()?;
+            }
+        }
+
+        if let Some(alias) = self.default_database.as_ref().filter(|alias| !aliases.contains(alias.as_str())) {
+            return Err(crate::quick_error!(
+                config,
+                crate::i18n::tf("config.default_database_not_exist", &[("alias", alias)])
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// 展开配置内容中的环境变量引用
+fn interpolate_env(content: &str) -> Result<String, QuickDbError> {
+    let mut output = String::with_capacity(content.len());
+    let mut rest = content;
+
+    while let Some(start) = rest.find('$') {
+        output.push_str(&rest[..start]);
+        let tail = &rest[start..];
+
+        if let Some(escaped) = tail.strip_prefix("$${") {
+            output.push_str("${");
+            rest = escaped;
+            continue;
+        }
+        let Some(body) = tail.strip_prefix("${") else {
+            output.push('$');
+            rest = &tail[1..];
+            continue;
+        };
+        let Some(end) = body.find('}') else {
+            return Err(crate::quick_error!(
+                config,
+                crate::i18n::tf("config.env_var_unterminated", &[("text", tail.lines().next().unwrap_or(tail))])
+            ));
+        };
+
+        let expr = &body[..end];
+        let (name, default) = match expr.split_once(":-") {
+            Some((name, default)) => (name, Some(default)),
+            None => (expr, None),
+        };
+        let value = match (std::env::var(name), default) {
+            (Ok(value), Some(default)) if value.is_empty() => default.to_string(),
+            (Ok(value), _) => value,
+            (Err(_), Some(default)) => default.to_string(),
+            (Err(_), None) => {
+                return Err(crate::quick_error!(
+                    config,
+                    crate::i18n::tf("config.env_var_missing", &[("name", name)])
+                ));
+            }
+        };
+        output.push_str(&value);
+        rest = &body[end + 1..];
+    }
+
+    output.push_str(rest);
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const POOL_TOML: &str = r#"
+[databases.pool]
+min_connections = 1
+max_connections = 5
+connection_timeout = 30000
+idle_timeout = 600
+max_lifetime = 3600
+max_retries = 3
+retry_interval_ms = 1000
+keepalive_interval_sec = 60
+health_check_timeout_sec = 10
+"#;
+
+    #[test]
+    fn loads_toml_and_yaml_with_env_interpolation() {
+        crate::i18n::ErrorMessageI18n::init_i18n();
+        // SAFETY: 测试使用专用变量名，不与其他测试共享
+        unsafe { std::env::set_var("RAT_QUICKDB_TEST_SQLITE_PATH", "/tmp/app.db") };
+
+        let toml = format!(
+            r#"
+default_database = "main"
+
+[[databases]]
+alias = "main"
+db_type = "SQLite"
+id_strategy = "AutoIncrement"
+
+[databases.connection.SQLite]
+path = "${{RAT_QUICKDB_TEST_SQLITE_PATH}}"
+create_if_missing = true
+{POOL_TOML}
+[databases.cache]
+enabled = true
+strategy = "Lru"
+version = "v1"
+l1_config = {{ max_capacity = 100, max_memory_mb = 16, enable_stats = false }}
+ttl_config = {{ default_ttl_secs = ${{RAT_QUICKDB_TEST_UNSET_TTL:-120}}, max_ttl_secs = 600, check_interval_secs = 60 }}
+compression_config = {{ enabled = false, algorithm = "Lz4", threshold_bytes = 1024 }}
+"#
+        );
+        let config = load_from_str(&toml, ConfigFileFormat::Toml).unwrap();
+        assert_eq!(config.default_database.as_deref(), Some("main"));
+        let database = &config.databases[0];
+        assert!(matches!(&database.connection, ConnectionConfig::SQLite { path, .. } if path == "/tmp/app.db"));
+        assert_eq!(database.pool.max_connections, 5);
+        let cache = database.cache.as_ref().unwrap();
+        assert_eq!(cache.ttl_config.default_ttl_secs, 120);
+        assert_eq!(cache.write_mode, CacheWriteMode::WriteAround);
+
+        #[cfg(feature = "yaml")]
+        {
+            let yaml = r#"
+databases:
+  - alias: side
+    db_type: SQLite
+    id_strategy: Uuid
+    connection:
+      SQLite:
+        path: "${RAT_QUICKDB_TEST_SQLITE_PATH}"
+        create_if_missing: false
+    pool:
+      min_connections: 1
+      max_connections: 2
+      connection_timeout: 30000
+      idle_timeout: 600
+      max_lifetime: 3600
+      max_retries: 3
+      retry_interval_ms: 1000
+      keepalive_interval_sec: 60
+      health_check_timeout_sec: 10
+"#;
+            let config = load_from_str(yaml, ConfigFileFormat::Yaml).unwrap();
+            assert_eq!(config.databases[0].alias, "side");
+            assert!(config.databases[0].cache.is_none());
+        }
+    }
+
+    #[test]
+    fn rejects_missing_env_vars_and_inconsistent_configs() {
+        crate::i18n::ErrorMessageI18n::init_i18n();
+
+        assert!(interpolate_env("password = \"${RAT_QUICKDB_TEST_UNSET_PASSWORD}\"").is_err());
+        assert_eq!(interpolate_env("a = \"$${x} $5\"").unwrap(), "a = \"${x} $5\"");
+
+        let mismatched = format!(
+            r#"
+[[databases]]
+alias = "main"
+db_type = "MySQL"
+id_strategy = "AutoIncrement"
+
+[databases.connection.SQLite]
+path = "app.db"
+create_if_missing = true
+{POOL_TOML}"#
+        );
+        assert!(load_from_str(&mismatched, ConfigFileFormat::Toml).is_err());
+
+        let unknown_default = format!("default_database = \"other\"\n{}", mismatched.replace("MySQL", "SQLite"));
+        assert!(load_from_str(&unknown_default, ConfigFileFormat::Toml).is_err());
+        assert!(ConfigFileFormat::from_path(Path::new("db.ini")).is_none());
+    }
+}
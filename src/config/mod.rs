@@ -6,11 +6,17 @@
 pub mod builders;
 pub mod convenience;
 pub mod core;
+pub mod env;
+pub mod file;
+pub(crate) mod url;
 
 // 重新导出所有公共类型以保持API兼容性
 pub use builders::{
     AppConfigBuilder, DatabaseConfigBuilder, GlobalConfigBuilder, LoggingConfigBuilder,
     PoolConfigBuilder,
 };
+#[allow(deprecated)]
 pub use convenience::{mongodb_config, mysql_config, postgres_config, sqlite_config};
-pub use core::{AppConfig, Environment, GlobalConfig, LogLevel, LoggingConfig};
+pub use core::{AppConfig, Environment, GlobalConfig, LogLevel, LoggingConfig, QueryGuardrails, RowLimitAction};
+pub use env::from_env;
+pub use file::{ConfigFileFormat, DatabaseFileConfig, load_from_file, load_from_str};
@@ -0,0 +1,294 @@
+//! # 操作上下文模块
+//!
+//! 调用方在每个请求入口设置一次 [`OperationContext`]，期间经 ODM 执行的数据库操作都带有该上下文：
+//! 语句日志标注请求 ID 与用户 ID，审计记录以用户 ID 作为默认操作者并记录请求 ID，
+//! 发件箱事件携带上下文交给发布回调，从而把数据库活动与应用请求关联起来：
+//!
+//! ```ignore
+//! let context = OperationContext::new().request_id("req-7f3a").user_id("user:42");
+//! context::with_context(context, async {
+//!     User::update_many(conditions, updates).await
+//! })
+//! .await?;
+//! ```
+//!
+//! ODM 请求与连接池操作在后台任务中执行，上下文随请求一并发送，在后台任务中恢复。
+//!
+//! 上下文中的 [`Priority`] 决定请求进入哪条队列：交互请求优先处理，批量导出、缓存预热等
+//! 后台请求只在交互队列空闲或交互请求连续处理一定数量后才被取出，不会阻塞用户查询：
+//!
+//! ```ignore
+//! context::with_priority(Priority::Background, async {
+//!     export_ndjson("main", "orders", file, &NdjsonOptions::default()).await
+//! })
+//! .await?;
+//! ```
+
+use crate::statement_log::is_statement_logging_enabled;
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use tokio::sync::mpsc;
+
+tokio::task_local! {
+    static CONTEXT: Arc<OperationContext>;
+}
+
+/// 交互请求连续处理多少个后让出一次给等待中的后台请求
+const INTERACTIVE_BURST: u32 = 8;
+
+/// 请求优先级
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Priority {
+    /// 对延迟敏感的用户请求
+    #[default]
+    Interactive,
+    /// 批量导出、缓存预热等后台请求
+    Background,
+}
+
+/// 一次应用请求的操作上下文
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OperationContext {
+    /// 请求 ID
+    #[serde(default)]
+    pub request_id: Option<String>,
+    /// 发起请求的用户 ID
+    #[serde(default)]
+    pub user_id: Option<String>,
+    /// 请求优先级
+    #[serde(default)]
+    pub priority: Priority,
+}
+
+impl OperationContext {
+    /// 创建空上下文
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 设置请求 ID
+    pub fn request_id(mut self, request_id: impl Into<String>) -> Self {
+        self.request_id = Some(request_id.into());
+        self
+    }
+
+    /// 设置用户 ID
+    pub fn user_id(mut self, user_id: impl Into<String>) -> Self {
+        self.user_id = Some(user_id.into());
+        self
+    }
+
+    /// 设置请求优先级
+    pub fn priority(mut self, priority: Priority) -> Self {
+        self.priority = priority;
+        self
+    }
+}
+
+/// 在指定上下文中执行 `future`
+pub async fn with_context<F: Future>(context: OperationContext, future: F) -> F::Output {
+    CONTEXT.scope(Arc::new(context), future).await
+}
+
+/// 以指定优先级执行 `future`，沿用当前上下文的其余字段
+pub async fn with_priority<F: Future>(priority: Priority, future: F) -> F::Output {
+    with_context(current_context().unwrap_or_default().priority(priority), future).await
+}
+
+/// 当前任务的操作上下文
+pub fn current_context() -> Option<OperationContext> {
+    CONTEXT.try_with(|context| OperationContext::clone(context)).ok()
+}
+
+/// 当前任务的请求优先级
+pub fn current_priority() -> Priority {
+    CONTEXT.try_with(|context| context.priority).unwrap_or_default()
+}
+
+/// 发送请求时携带的上下文：沿用调用方的上下文，启用语句日志时为缺少请求 ID 的请求自动分配
+fn outgoing_context() -> Option<Arc<OperationContext>> {
+    static NEXT: AtomicU64 = AtomicU64::new(1);
+    let context = CONTEXT.try_with(Arc::clone).ok();
+    if !is_statement_logging_enabled() || context.as_ref().is_some_and(|context| context.request_id.is_some()) {
+        return context;
+    }
+    let context = context.map_or_else(OperationContext::new, |context| OperationContext::clone(&context));
+    Some(Arc::new(context.request_id(format!("odm-{}", NEXT.fetch_add(1, Ordering::Relaxed)))))
+}
+
+/// 在上下文作用域中执行 `future`（跨任务传递上下文时使用）
+pub(crate) async fn in_context_scope<F: Future>(context: Option<Arc<OperationContext>>, future: F) -> F::Output {
+    match context {
+        Some(context) => CONTEXT.scope(context, future).await,
+        None => future.await,
+    }
+}
+
+/// 携带操作上下文的通道消息
+pub(crate) type Scoped<T> = (T, Option<Arc<OperationContext>>);
+
+/// 通道消息的优先级
+pub(crate) fn priority_of<T>((_, context): &Scoped<T>) -> Priority {
+    context.as_ref().map(|context| context.priority).unwrap_or_default()
+}
+
+/// 创建携带操作上下文的无界通道，交互与后台请求各占一条队列
+pub(crate) fn request_channel<T>() -> (RequestSender<T>, RequestReceiver<T>) {
+    let (interactive, interactive_receiver) = mpsc::unbounded_channel();
+    let (background, background_receiver) = mpsc::unbounded_channel();
+    (
+        RequestSender { interactive, background },
+        RequestReceiver {
+            interactive: interactive_receiver,
+            background: background_receiver,
+            lanes: LaneSelector::default(),
+        },
+    )
+}
+
+/// 携带操作上下文的通道发送端，发送时附带当前任务的上下文，使后台任务中的日志可关联到调用方，
+/// 并按上下文的优先级选择队列
+pub struct RequestSender<T> {
+    interactive: mpsc::UnboundedSender<Scoped<T>>,
+    background: mpsc::UnboundedSender<Scoped<T>>,
+}
+
+impl<T> RequestSender<T> {
+    /// 发送消息
+    pub fn send(&self, message: T) -> Result<(), mpsc::error::SendError<T>> {
+        let scoped = (message, outgoing_context());
+        let lane = match priority_of(&scoped) {
+            Priority::Interactive => &self.interactive,
+            Priority::Background => &self.background,
+        };
+        lane.send(scoped)
+            .map_err(|mpsc::error::SendError((message, _))| mpsc::error::SendError(message))
+    }
+}
+
+impl<T> Clone for RequestSender<T> {
+    fn clone(&self) -> Self {
+        Self {
+            interactive: self.interactive.clone(),
+            background: self.background.clone(),
+        }
+    }
+}
+
+impl<T> std::fmt::Debug for RequestSender<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RequestSender")
+            .field("interactive", &self.interactive)
+            .field("background", &self.background)
+            .finish()
+    }
+}
+
+/// 携带操作上下文的通道接收端
+pub(crate) struct RequestReceiver<T> {
+    interactive: mpsc::UnboundedReceiver<Scoped<T>>,
+    background: mpsc::UnboundedReceiver<Scoped<T>>,
+    lanes: LaneSelector,
+}
+
+impl<T> RequestReceiver<T> {
+    /// 接收下一条消息：优先交互队列，交互请求连续处理 [`INTERACTIVE_BURST`] 个后让出一次给后台队列
+    pub(crate) async fn recv(&mut self) -> Option<Scoped<T>> {
+        if self.lanes.background_due() {
+            if let Ok(message) = self.background.try_recv() {
+                self.lanes.served(Priority::Background);
+                return Some(message);
+            }
+        }
+        let message = tokio::select! {
+            biased;
+            Some(message) = self.interactive.recv() => message,
+            Some(message) = self.background.recv() => message,
+            else => return None,
+        };
+        self.lanes.served(priority_of(&message));
+        Some(message)
+    }
+
+    /// 两条队列中等待的消息总数
+    #[cfg_attr(not(feature = "sqlite-support"), allow(dead_code))]
+    pub(crate) fn len(&self) -> usize {
+        self.interactive.len() + self.background.len()
+    }
+}
+
+/// 两条队列之间的轮换状态
+#[derive(Debug, Default)]
+pub(crate) struct LaneSelector {
+    interactive_streak: u32,
+}
+
+impl LaneSelector {
+    /// 是否应先处理等待中的后台请求
+    pub(crate) fn background_due(&self) -> bool {
+        self.interactive_streak >= INTERACTIVE_BURST
+    }
+
+    /// 记录处理了一个指定优先级的请求（连续交互计数到达让出阈值后不再增长）
+    pub(crate) fn served(&mut self, priority: Priority) {
+        match priority {
+            Priority::Interactive => self.interactive_streak = (self.interactive_streak + 1).min(INTERACTIVE_BURST),
+            Priority::Background => self.interactive_streak = 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn context_travels_with_channel_messages() {
+        assert_eq!(current_context(), None);
+        let (sender, mut receiver) = request_channel();
+        let context = OperationContext::new().request_id("req-1").user_id("user:42");
+        with_context(context.clone(), async { sender.send("find").unwrap() }).await;
+
+        let (message, scoped) = receiver.recv().await.unwrap();
+        assert_eq!(message, "find");
+        let inside = in_context_scope(scoped, async { current_context() }).await;
+        assert_eq!(inside, Some(context));
+        assert_eq!(current_context(), None);
+    }
+
+    #[tokio::test]
+    async fn interactive_lane_is_served_first_without_starving_background() {
+        let (sender, mut receiver) = request_channel();
+        with_priority(Priority::Background, async {
+            sender.send("export-1").unwrap();
+            sender.send("export-2").unwrap();
+        })
+        .await;
+        for _ in 0..INTERACTIVE_BURST + 1 {
+            sender.send("query").unwrap();
+        }
+
+        let mut order = Vec::new();
+        while receiver.len() > 0 {
+            order.push(receiver.recv().await.unwrap().0);
+        }
+        let burst = INTERACTIVE_BURST as usize;
+        assert!(order[..burst].iter().all(|message| *message == "query"));
+        assert_eq!(order[burst..], ["export-1", "query", "export-2"]);
+    }
+
+    #[test]
+    fn interactive_streak_stops_at_the_burst_limit() {
+        let mut lanes = LaneSelector::default();
+        for _ in 0..INTERACTIVE_BURST * 4 {
+            lanes.served(Priority::Interactive);
+        }
+        assert_eq!(lanes.interactive_streak, INTERACTIVE_BURST);
+        assert!(lanes.background_due());
+        lanes.served(Priority::Background);
+        assert!(!lanes.background_due());
+    }
+}
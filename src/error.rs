@@ -29,7 +29,7 @@ pub enum QuickDbError {
     /// 不支持的数据库类型
     UnsupportedDatabase { db_type: String },
 
-    /// 事务操作错误（虽然不支持事务，但保留用于未来扩展）
+    /// 事务操作错误（开启、提交或回滚事务失败）
     TransactionError { message: String },
 
     /// 任务执行错误
@@ -58,6 +58,18 @@ pub enum QuickDbError {
 
     /// 数据未找到错误
     NotFound { message: String },
+
+    /// 唯一约束冲突错误（fields 为冲突的字段；无法解析字段时为约束/索引名）
+    UniqueViolation { table: String, fields: Vec<String> },
+
+    /// 熔断器打开错误（后端连续失败，请求被快速拒绝）
+    CircuitOpen { alias: String, retry_after_ms: u64 },
+
+    /// 操作队列已满错误（请求被拒绝或等待入队超时）
+    Overloaded { alias: String, queued: usize },
+
+    /// 条件更新的前置条件不满足（记录存在，但字段当前值与期望值不符）
+    PreconditionFailed { table: String, id: String, fields: Vec<String> },
 }
 
 impl std::fmt::Display for QuickDbError {
@@ -153,6 +165,38 @@ impl std::fmt::Display for QuickDbError {
                 "{}",
                 crate::i18n::tf("error.not_found", &[("message", message)])
             ),
+            Self::UniqueViolation { table, fields } => write!(
+                f,
+                "{}",
+                crate::i18n::tf(
+                    "error.unique_violation",
+                    &[("table", table), ("fields", &fields.join(", "))]
+                )
+            ),
+            Self::CircuitOpen {
+                alias,
+                retry_after_ms,
+            } => write!(
+                f,
+                "{}",
+                crate::i18n::tf(
+                    "error.circuit_open",
+                    &[("alias", alias), ("retry_after_ms", &retry_after_ms.to_string())]
+                )
+            ),
+            Self::Overloaded { alias, queued } => write!(
+                f,
+                "{}",
+                crate::i18n::tf("error.overloaded", &[("alias", alias), ("queued", &queued.to_string())])
+            ),
+            Self::PreconditionFailed { table, id, fields } => write!(
+                f,
+                "{}",
+                crate::i18n::tf(
+                    "error.precondition_failed",
+                    &[("table", table), ("id", id), ("fields", &fields.join(", "))]
+                )
+            ),
         }
     }
 }
@@ -280,6 +324,30 @@ impl ErrorBuilder {
             message: message.into(),
         }
     }
+
+    /// 创建唯一约束冲突错误
+    pub fn unique_violation(table: impl Into<String>, fields: Vec<String>) -> QuickDbError {
+        QuickDbError::UniqueViolation {
+            table: table.into(),
+            fields,
+        }
+    }
+
+    /// 创建熔断器打开错误
+    pub fn circuit_open(alias: impl Into<String>, retry_after_ms: u64) -> QuickDbError {
+        QuickDbError::CircuitOpen {
+            alias: alias.into(),
+            retry_after_ms,
+        }
+    }
+
+    /// 创建操作队列已满错误
+    pub fn overloaded(alias: impl Into<String>, queued: usize) -> QuickDbError {
+        QuickDbError::Overloaded {
+            alias: alias.into(),
+            queued,
+        }
+    }
 }
 
 /// 便捷宏 - 快速创建错误
@@ -318,6 +386,15 @@ macro_rules! quick_error {
     (index_exists, $index:expr, $msg:expr) => {
         $crate::error::ErrorBuilder::index_exists_error($index, $msg)
     };
+    (unique_violation, $table:expr, $fields:expr) => {
+        $crate::error::ErrorBuilder::unique_violation($table, $fields)
+    };
+    (circuit_open, $alias:expr, $retry_after_ms:expr) => {
+        $crate::error::ErrorBuilder::circuit_open($alias, $retry_after_ms)
+    };
+    (overloaded, $alias:expr, $queued:expr) => {
+        $crate::error::ErrorBuilder::overloaded($alias, $queued)
+    };
 }
 
 #[cfg(test)]
@@ -521,6 +598,31 @@ mod tests {
         assert_eq!(err.to_string(), "数据未找到: 记录不存在");
     }
 
+    #[test]
+    fn test_i18n_zh_cn_unique_violation_error() {
+        setup_i18n("zh-CN");
+        let fields = vec!["tenant_id".to_string(), "email".to_string()];
+        let err = quick_error!(unique_violation, "users", fields);
+        assert!(matches!(err, QuickDbError::UniqueViolation { .. }));
+        assert_eq!(err.to_string(), "表 'users' 的唯一约束冲突: tenant_id, email");
+    }
+
+    #[test]
+    fn test_i18n_zh_cn_circuit_open_error() {
+        setup_i18n("zh-CN");
+        let err = quick_error!(circuit_open, "main", 1500);
+        assert!(matches!(err, QuickDbError::CircuitOpen { .. }));
+        assert_eq!(err.to_string(), "数据库 'main' 熔断中，请在 1500ms 后重试");
+    }
+
+    #[test]
+    fn test_i18n_zh_cn_overloaded_error() {
+        setup_i18n("zh-CN");
+        let err = quick_error!(overloaded, "main", 64);
+        assert!(matches!(err, QuickDbError::Overloaded { .. }));
+        assert_eq!(err.to_string(), "数据库 'main' 的操作队列已满（64 个操作排队中）");
+    }
+
     // =========================================================================
     // i18n Display 测试 - en-US（全部 17 个变体）
     // =========================================================================
@@ -679,6 +781,29 @@ mod tests {
         assert_eq!(err.to_string(), "Data not found: record not found");
     }
 
+    #[test]
+    fn test_i18n_en_us_unique_violation_error() {
+        setup_i18n("en-US");
+        let err = QuickDbError::UniqueViolation {
+            table: "users".to_string(),
+            fields: vec!["email".to_string()],
+        };
+        assert_eq!(err.to_string(), "Unique constraint violation on table 'users': email");
+    }
+
+    #[test]
+    fn test_i18n_en_us_circuit_open_error() {
+        setup_i18n("en-US");
+        let err = QuickDbError::CircuitOpen {
+            alias: "main".to_string(),
+            retry_after_ms: 1500,
+        };
+        assert_eq!(
+            err.to_string(),
+            "Circuit breaker open for database 'main', retry after 1500ms"
+        );
+    }
+
     // =========================================================================
     // i18n Display 测试 - ja-JP（全部 17 个变体）
     // =========================================================================
@@ -846,6 +971,29 @@ mod tests {
         assert_eq!(err.to_string(), "データが見つかりません: レコードが見つかりません");
     }
 
+    #[test]
+    fn test_i18n_ja_jp_unique_violation_error() {
+        setup_i18n("ja-JP");
+        let err = QuickDbError::UniqueViolation {
+            table: "users".to_string(),
+            fields: vec!["email".to_string()],
+        };
+        assert_eq!(err.to_string(), "テーブル 'users' の一意制約違反: email");
+    }
+
+    #[test]
+    fn test_i18n_ja_jp_circuit_open_error() {
+        setup_i18n("ja-JP");
+        let err = QuickDbError::CircuitOpen {
+            alias: "main".to_string(),
+            retry_after_ms: 1500,
+        };
+        assert_eq!(
+            err.to_string(),
+            "データベース 'main' のサーキットブレーカーが開いています。1500ms 後に再試行してください"
+        );
+    }
+
     // =========================================================================
     // Error trait source() 测试 - IoError, JsonError, Other
     // =========================================================================
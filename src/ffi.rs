@@ -0,0 +1,175 @@
+//! # C FFI 模块
+//!
+//! 以 `extern "C"` 函数暴露基本数据库操作，供 C++、Swift 等原生应用嵌入。
+//! 所有参数与返回值都是 UTF-8 的 JSON 字符串，返回值统一为：
+//!
+//! ```json
+//! {"success": true, "data": ...}
+//! {"success": false, "error": "..."}
+//! ```
+//!
+//! 返回的字符串由本库分配，调用方必须用 [`rat_quickdb_free_string`] 释放。
+//! 头文件由 `ffi` 特性下的构建脚本通过 cbindgen 生成到 `OUT_DIR`，仓库中的
+//! `include/rat_quickdb.h` 只在设置 `RAT_QUICKDB_UPDATE_HEADER=1`（或 `just ffi-header`）时更新。
+//! 默认只构建 rlib，需要动态库或静态库时：
+//!
+//! ```text
+//! cargo rustc --release --features ffi,sqlite-support --crate-type cdylib
+//! ```
+//!
+//! 操作请求格式与 `server` 特性相同，例如：
+//!
+//! ```json
+//! {"op": "find", "table": "users", "alias": "default",
+//!  "conditions": [{"field": "age", "operator": "Gte", "value": 18}],
+//!  "options": {"sort": [{"field": "age", "direction": "Desc"}], "skip": 0, "limit": 10}}
+//! ```
+//!
+//! 支持的 `op`：create、find、find_by_id、exists_by_id、update、update_by_id、
+//! delete、delete_by_id、count
+
+use crate::error::{QuickDbError, QuickDbResult};
+use crate::json_request::{execute_op, parse_json};
+use crate::types::*;
+use serde_json::Value;
+use std::ffi::{CStr, CString, c_char};
+use std::sync::OnceLock;
+
+/// FFI 调用共享的 tokio runtime
+static RUNTIME: OnceLock<tokio::runtime::Runtime> = OnceLock::new();
+
+fn runtime() -> QuickDbResult<&'static tokio::runtime::Runtime> {
+    if let Some(runtime) = RUNTIME.get() {
+        return Ok(runtime);
+    }
+    let runtime = tokio::runtime::Runtime::new().map_err(QuickDbError::IoError)?;
+    // 并发初始化时以先写入者为准
+    Ok(RUNTIME.get_or_init(|| runtime))
+}
+
+/// 初始化库（i18n 等子系统），可重复调用
+#[unsafe(no_mangle)]
+pub extern "C" fn rat_quickdb_init() {
+    crate::init();
+}
+
+/// 按 JSON 配置添加数据库
+///
+/// `config_json` 为 [`DatabaseConfig`] 的 JSON 表示
+///
+/// # Safety
+///
+/// `config_json` 必须是以 NUL 结尾的有效字符串指针或空指针
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rat_quickdb_add_database_json(config_json: *const c_char) -> *mut c_char {
+    respond(|| {
+        let config: DatabaseConfig = parse_json(unsafe { read_str(config_json) }?)?;
+        runtime()?.block_on(crate::manager::add_database(config))?;
+        Ok(Value::Null)
+    })
+}
+
+/// 按 JSON 模型元数据注册模型并创建表和索引
+///
+/// `meta_json` 为 [`ModelMeta`](crate::model::ModelMeta) 的 JSON 表示，必须包含 `database_alias`
+///
+/// # Safety
+///
+/// `meta_json` 必须是以 NUL 结尾的有效字符串指针或空指针
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rat_quickdb_register_model_json(meta_json: *const c_char) -> *mut c_char {
+    respond(|| {
+        let meta: crate::model::ModelMeta = parse_json(unsafe { read_str(meta_json) }?)?;
+        let table = meta.qualified_name();
+        let alias = meta.database_alias.clone().ok_or_else(|| {
+            crate::quick_error!(
+                validation,
+                "database_alias",
+                crate::i18n::tf("request.missing_field", &[("field", "database_alias")])
+            )
+        })?;
+        let manager = crate::manager::get_global_pool_manager();
+        manager.register_model(meta)?;
+        runtime()?.block_on(manager.ensure_table_and_indexes(&table, &alias))?;
+        Ok(Value::Null)
+    })
+}
+
+/// 执行一个 JSON 描述的 ODM 操作
+///
+/// # Safety
+///
+/// `op_json` 必须是以 NUL 结尾的有效字符串指针或空指针
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rat_quickdb_execute_op_json(op_json: *const c_char) -> *mut c_char {
+    respond(|| {
+        let request: Value = parse_json(unsafe { read_str(op_json) }?)?;
+        runtime()?.block_on(execute_op(&request))
+    })
+}
+
+/// 释放本库返回的字符串
+///
+/// # Safety
+///
+/// `value` 必须是本库函数返回的指针（或空指针），且只能释放一次
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rat_quickdb_free_string(value: *mut c_char) {
+    if !value.is_null() {
+        drop(unsafe { CString::from_raw(value) });
+    }
+}
+
+/// 执行操作，panic 与错误都转换为 JSON 响应，不会跨越 FFI 边界展开
+fn respond(f: impl FnOnce() -> QuickDbResult<Value>) -> *mut c_char {
+    let response = match std::panic::catch_unwind(std::panic::AssertUnwindSafe(f)) {
+        Ok(Ok(data)) => serde_json::json!({ "success": true, "data": data }),
+        Ok(Err(e)) => serde_json::json!({ "success": false, "error": e.to_string() }),
+        Err(_) => serde_json::json!({ "success": false, "error": crate::i18n::t("ffi.panicked") }),
+    };
+    // JSON 序列化结果不含 NUL 字节
+    CString::new(response.to_string()).unwrap_or_default().into_raw()
+}
+
+/// 读取调用方传入的 C 字符串
+///
+/// # Safety
+///
+/// 同调用方函数的指针约定
+unsafe fn read_str<'a>(value: *const c_char) -> QuickDbResult<&'a str> {
+    if value.is_null() {
+        return Err(crate::quick_error!(validation, "ptr", crate::i18n::t("ffi.null_pointer")));
+    }
+    unsafe { CStr::from_ptr(value) }
+        .to_str()
+        .map_err(|_| crate::quick_error!(validation, "ptr", crate::i18n::t("ffi.invalid_utf8")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn call(f: impl FnOnce() -> *mut c_char) -> Value {
+        let ptr = f();
+        let response = unsafe { CStr::from_ptr(ptr) }.to_str().unwrap().to_string();
+        unsafe { rat_quickdb_free_string(ptr) };
+        serde_json::from_str(&response).unwrap()
+    }
+
+    #[test]
+    fn invalid_requests_return_error_responses() {
+        rat_quickdb_init();
+
+        let response = call(|| unsafe { rat_quickdb_execute_op_json(std::ptr::null()) });
+        assert_eq!(response["success"], false);
+
+        let bad = CString::new("{oops").unwrap();
+        let response = call(|| unsafe { rat_quickdb_execute_op_json(bad.as_ptr()) });
+        assert_eq!(response["success"], false);
+
+        let unknown = CString::new(r#"{"op": "explode", "table": "users"}"#).unwrap();
+        let response = call(|| unsafe { rat_quickdb_execute_op_json(unknown.as_ptr()) });
+        assert_eq!(response["success"], false);
+        assert!(response["error"].as_str().unwrap().contains("explode"));
+    }
+}
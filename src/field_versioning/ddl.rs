@@ -1,7 +1,8 @@
 //! DDL 生成器
 
+use crate::adapter::{index_key_parts, render_index_predicate};
 use crate::model::field_types::{FieldDefinition, FieldType, IndexDefinition, ModelMeta};
-use crate::security::quote_identifier;
+use crate::security::{quote_identifier, quote_table_identifier};
 use crate::types::DatabaseType;
 
 /// 生成模型 DDL
@@ -22,7 +23,7 @@ fn generate_sqlite_ddl(model: &ModelMeta) -> String {
     let db_type = DatabaseType::SQLite;
     let mut ddl = format!(
         "CREATE TABLE IF NOT EXISTS {} (\n",
-        quote_identifier(&model.collection_name, db_type)
+        quote_table_identifier(&model.qualified_name(), db_type)
     );
 
     let fields: Vec<_> = model.fields.iter().collect();
@@ -48,34 +49,24 @@ fn generate_sqlite_ddl(model: &ModelMeta) -> String {
     }
 
     // 添加主键
-    if let Some(id_field) = model.fields.get("id") {
-        if matches!(id_field.field_type, FieldType::String { .. }) {
-            ddl.push_str(&format!(
-                "    ,PRIMARY KEY ({})\n",
-                quote_identifier("id", db_type)
-            ));
-        }
+    if model.has_custom_primary_key() {
+        ddl.push_str(&primary_key_clause(model, db_type));
+    } else if model
+        .fields
+        .get("id")
+        .is_some_and(|id_field| matches!(id_field.field_type, FieldType::String { .. }))
+    {
+        ddl.push_str(&format!(
+            "    ,PRIMARY KEY ({})\n",
+            quote_identifier("id", db_type)
+        ));
     }
 
     ddl.push_str(");\n");
 
     // 添加索引
     for index in &model.indexes {
-        let unique_str = if index.unique { " UNIQUE" } else { "" };
-        let default_name = format!("idx_{}_{}", model.collection_name, index.fields.join("_"));
-        let index_name = index.name.as_ref().unwrap_or(&default_name);
-        let quoted_index_fields: Vec<String> = index
-            .fields
-            .iter()
-            .map(|f| quote_identifier(f, db_type))
-            .collect();
-        ddl.push_str(&format!(
-            "CREATE{} INDEX IF NOT EXISTS {} ON {} ({});\n",
-            unique_str,
-            quote_identifier(index_name, db_type),
-            quote_identifier(&model.collection_name, db_type),
-            quoted_index_fields.join(", ")
-        ));
+        ddl.push_str(&index_statement(model, index, db_type));
     }
 
     ddl
@@ -84,10 +75,22 @@ fn generate_sqlite_ddl(model: &ModelMeta) -> String {
 /// 生成 PostgreSQL DDL
 fn generate_postgres_ddl(model: &ModelMeta) -> String {
     let db_type = DatabaseType::PostgreSQL;
-    let mut ddl = format!(
+    let mut ddl = String::new();
+
+    // 序列字段依赖的序列需要先于表创建
+    for def in model.fields.values() {
+        if let FieldType::Sequence { name } = &def.field_type {
+            ddl.push_str(&format!(
+                "CREATE SEQUENCE IF NOT EXISTS {};\n",
+                quote_identifier(name, db_type)
+            ));
+        }
+    }
+
+    ddl.push_str(&format!(
         "CREATE TABLE IF NOT EXISTS {} (\n",
-        quote_identifier(&model.collection_name, db_type)
-    );
+        quote_table_identifier(&model.qualified_name(), db_type)
+    ));
 
     let fields: Vec<_> = model.fields.iter().collect();
     for (i, (name, def)) in fields.iter().enumerate() {
@@ -111,25 +114,16 @@ fn generate_postgres_ddl(model: &ModelMeta) -> String {
         ddl.push('\n');
     }
 
+    // 添加复合/自定义主键
+    if model.has_custom_primary_key() {
+        ddl.push_str(&primary_key_clause(model, db_type));
+    }
+
     ddl.push_str(");\n");
 
     // 添加索引
     for index in &model.indexes {
-        let unique_str = if index.unique { " UNIQUE" } else { "" };
-        let default_name = format!("idx_{}_{}", model.collection_name, index.fields.join("_"));
-        let index_name = index.name.as_ref().unwrap_or(&default_name);
-        let quoted_index_fields: Vec<String> = index
-            .fields
-            .iter()
-            .map(|f| quote_identifier(f, db_type))
-            .collect();
-        ddl.push_str(&format!(
-            "CREATE{} INDEX IF NOT EXISTS {} ON {} ({});\n",
-            unique_str,
-            quote_identifier(index_name, db_type),
-            quote_identifier(&model.collection_name, db_type),
-            quoted_index_fields.join(", ")
-        ));
+        ddl.push_str(&index_statement(model, index, db_type));
     }
 
     ddl
@@ -140,7 +134,7 @@ fn generate_mysql_ddl(model: &ModelMeta) -> String {
     let db_type = DatabaseType::MySQL;
     let mut ddl = format!(
         "CREATE TABLE IF NOT EXISTS {} (\n",
-        quote_identifier(&model.collection_name, db_type)
+        quote_table_identifier(&model.qualified_name(), db_type)
     );
 
     let fields: Vec<_> = model.fields.iter().collect();
@@ -165,33 +159,67 @@ fn generate_mysql_ddl(model: &ModelMeta) -> String {
         ddl.push('\n');
     }
 
+    // 添加复合/自定义主键
+    if model.has_custom_primary_key() {
+        ddl.push_str(&primary_key_clause(model, db_type));
+    }
+
     ddl.push_str(") ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;\n");
 
     // 添加索引
     for index in &model.indexes {
-        let unique_str = if index.unique { " UNIQUE" } else { "" };
-        let default_name = format!("idx_{}_{}", model.collection_name, index.fields.join("_"));
-        let index_name = index.name.as_ref().unwrap_or(&default_name);
-        let quoted_index_fields: Vec<String> = index
-            .fields
-            .iter()
-            .map(|f| quote_identifier(f, db_type))
-            .collect();
-        ddl.push_str(&format!(
-            "CREATE{} INDEX {} ON {} ({});\n",
-            unique_str,
-            quote_identifier(index_name, db_type),
-            quote_identifier(&model.collection_name, db_type),
-            quoted_index_fields.join(", ")
-        ));
+        ddl.push_str(&index_statement(model, index, db_type));
     }
 
     ddl
 }
 
+/// 生成单个索引的创建语句
+///
+/// 部分索引条件或表达式无法生成时输出注释说明原因，而不是生成语义不同的索引
+fn index_statement(model: &ModelMeta, index: &IndexDefinition, db_type: DatabaseType) -> String {
+    let unique_str = if index.unique { " UNIQUE" } else { "" };
+    let if_not_exists = if matches!(db_type, DatabaseType::MySQL) { "" } else { " IF NOT EXISTS" };
+    let default_name = format!("idx_{}_{}", model.collection_name, index.fields.join("_"));
+    let index_name = index.name.as_ref().unwrap_or(&default_name);
+
+    let statement = index_key_parts(&index.fields, index.options.expression.as_deref(), db_type).and_then(
+        |key_parts| {
+            let mut statement = format!(
+                "CREATE{} INDEX{} {} ON {} ({})",
+                unique_str,
+                if_not_exists,
+                quote_identifier(index_name, db_type),
+                quote_table_identifier(&model.qualified_name(), db_type),
+                key_parts
+            );
+            if !index.options.where_clause.is_empty() {
+                let predicate = render_index_predicate(&index.options.where_clause, db_type)?;
+                statement.push_str(&format!(" WHERE {}", predicate));
+            }
+            Ok(statement)
+        },
+    );
+
+    match statement {
+        Ok(statement) => format!("{};\n", statement),
+        Err(e) => format!("-- {}: {}\n", index_name, e),
+    }
+}
+
+/// 生成表级主键约束子句（用于复合/自定义主键）
+fn primary_key_clause(model: &ModelMeta, db_type: DatabaseType) -> String {
+    let quoted_pk_fields: Vec<String> = model
+        .primary_key
+        .iter()
+        .map(|f| quote_identifier(f, db_type))
+        .collect();
+    format!("    ,PRIMARY KEY ({})\n", quoted_pk_fields.join(", "))
+}
+
 /// 生成 MongoDB DDL（集合创建）
 fn generate_mongodb_ddl(model: &ModelMeta) -> String {
-    let mut ddl = format!("// MongoDB 集合: {}\n", model.collection_name);
+    let mut ddl = format!("// MongoDB 集合: {}\n", model.qualified_name());
     ddl.push_str("// MongoDB 使用灵活的 schema，不需要预定义结构\n");
     ddl.push_str("// 以下是建议的索引定义：\n\n");
 
@@ -237,7 +265,9 @@ fn field_type_to_sqlite(def: &FieldDefinition) -> String {
         FieldType::Array { .. } => "TEXT".to_string(), // SQLite 用 JSON 文本存储数组
         FieldType::Object { .. } => "TEXT".to_string(), // SQLite 用 JSON 文本存储对象
         FieldType::Reference { .. } => "TEXT".to_string(),
+        FieldType::PolymorphicReference { .. } => "TEXT".to_string(),
         FieldType::Vector { .. } => "BLOB".to_string(), // SQLite 不支持向量，用 BLOB
+        FieldType::Sequence { .. } => "INTEGER".to_string(), // 序列值由计数器表分配
     }
 }
 
@@ -274,7 +304,12 @@ fn field_type_to_postgres(def: &FieldDefinition) -> String {
         FieldType::Reference { target_collection } => {
             format!("VARCHAR(255)  -- 引用: {}", target_collection)
         }
+        FieldType::PolymorphicReference { .. } => "JSONB".to_string(),
         FieldType::Vector { dimension } => format!("vector({})", dimension),
+        FieldType::Sequence { name } => format!(
+            "BIGINT DEFAULT nextval('{}')",
+            quote_identifier(name, DatabaseType::PostgreSQL)
+        ),
     }
 }
 
@@ -307,7 +342,9 @@ fn field_type_to_mysql(def: &FieldDefinition) -> String {
         FieldType::Array { .. } => "JSON".to_string(),
         FieldType::Object { .. } => "JSON".to_string(),
         FieldType::Reference { .. } => "VARCHAR(255)".to_string(),
+        FieldType::PolymorphicReference { .. } => "JSON".to_string(),
         FieldType::Vector { .. } => "JSON".to_string(), // MySQL 不支持向量，用 JSON
+        FieldType::Sequence { .. } => "BIGINT".to_string(), // 序列值由计数器表分配
     }
 }
 
@@ -352,7 +389,7 @@ pub fn generate_diff_ddl(
             ddl.push_str(&format!(
                 "-- 新增字段: {}\nALTER TABLE {} ADD COLUMN {} {};\n",
                 name,
-                quote_identifier(&new_model.collection_name, db_type),
+                quote_table_identifier(&new_model.qualified_name(), db_type),
                 quote_identifier(name, db_type),
                 type_str
             ));
@@ -376,7 +413,7 @@ pub fn generate_downgrade_ddl(
             ddl.push_str(&format!(
                 "-- 删除字段: {}\nALTER TABLE {} DROP COLUMN {};\n",
                 name,
-                quote_identifier(&new_model.collection_name, db_type),
+                quote_table_identifier(&new_model.qualified_name(), db_type),
                 quote_identifier(name, db_type)
             ));
         }
@@ -408,6 +445,8 @@ mod tests {
                 indexed: false,
                 validator: None,
                 sqlite_compatibility: false,
+                rename: None,
+                computed: false,
             },
         );
         fields.insert(
@@ -425,6 +464,8 @@ mod tests {
                 indexed: false,
                 validator: None,
                 sqlite_compatibility: false,
+                rename: None,
+                computed: false,
             },
         );
 
@@ -432,9 +473,8 @@ mod tests {
             collection_name: "users".to_string(),
             database_alias: Some("default".to_string()),
             fields,
-            indexes: vec![],
-            description: None,
             version: Some(1),
+            ..Default::default()
         };
 
         let ddl = generate_sqlite_ddl(&model);
@@ -442,4 +482,113 @@ mod tests {
         assert!(ddl.contains("VARCHAR(36)"));
         assert!(ddl.contains("VARCHAR(100)"));
     }
+
+    #[test]
+    fn test_generate_ddl_with_composite_primary_key() {
+        let mut fields = HashMap::new();
+        for name in ["tenant_id", "order_no"] {
+            fields.insert(
+                name.to_string(),
+                FieldDefinition::new(FieldType::String {
+                    max_length: Some(64),
+                    min_length: None,
+                    regex: None,
+                })
+                .required(),
+            );
+        }
+
+        let model = ModelMeta {
+            collection_name: "orders".to_string(),
+            database_alias: Some("default".to_string()),
+            fields,
+            version: Some(1),
+            primary_key: vec!["tenant_id".to_string(), "order_no".to_string()],
+            ..Default::default()
+        };
+
+        let sqlite_ddl = generate_ddl(&model, DatabaseType::SQLite);
+        assert!(sqlite_ddl.contains("PRIMARY KEY (\"tenant_id\", \"order_no\")"));
+
+        let postgres_ddl = generate_ddl(&model, DatabaseType::PostgreSQL);
+        assert!(postgres_ddl.contains("PRIMARY KEY (\"tenant_id\", \"order_no\")"));
+
+        let mysql_ddl = generate_ddl(&model, DatabaseType::MySQL);
+        assert!(mysql_ddl.contains("PRIMARY KEY (`tenant_id`, `order_no`)"));
+    }
+
+    #[test]
+    fn test_generate_ddl_with_sequence_field() {
+        let mut fields = HashMap::new();
+        fields.insert(
+            "invoice_no".to_string(),
+            FieldDefinition::new(FieldType::Sequence {
+                name: "invoice_no_seq".to_string(),
+            }),
+        );
+
+        let model = ModelMeta {
+            collection_name: "invoices".to_string(),
+            database_alias: Some("default".to_string()),
+            fields,
+            version: Some(1),
+            ..Default::default()
+        };
+
+        let postgres_ddl = generate_ddl(&model, DatabaseType::PostgreSQL);
+        assert!(postgres_ddl.starts_with("CREATE SEQUENCE IF NOT EXISTS \"invoice_no_seq\""));
+        assert!(postgres_ddl.contains("BIGINT DEFAULT nextval('\"invoice_no_seq\"')"));
+
+        let mysql_ddl = generate_ddl(&model, DatabaseType::MySQL);
+        assert!(mysql_ddl.contains("`invoice_no` BIGINT"));
+    }
+
+    #[test]
+    fn test_generate_ddl_with_partial_and_expression_index() {
+        use crate::model::field_types::IndexOptions;
+        use crate::types::{DataValue, QueryConditionWithConfig, QueryOperator};
+
+        let model = ModelMeta {
+            collection_name: "users".to_string(),
+            database_alias: Some("default".to_string()),
+            indexes: vec![
+                IndexDefinition {
+                    fields: vec!["email".to_string()],
+                    unique: true,
+                    name: Some("idx_active_email".to_string()),
+                    options: IndexOptions {
+                        where_clause: vec![QueryConditionWithConfig {
+                            field: "status".to_string(),
+                            operator: QueryOperator::Eq,
+                            value: DataValue::String("it's active".to_string()),
+                            case_insensitive: false,
+                        }],
+                        expression: None,
+                    },
+                },
+                IndexDefinition {
+                    fields: vec![],
+                    unique: false,
+                    name: Some("idx_lower_name".to_string()),
+                    options: IndexOptions {
+                        where_clause: vec![],
+                        expression: Some("lower(name)".to_string()),
+                    },
+                },
+            ],
+            version: Some(1),
+            ..Default::default()
+        };
+
+        let postgres_ddl = generate_ddl(&model, DatabaseType::PostgreSQL);
+        assert!(postgres_ddl.contains(
+            "CREATE UNIQUE INDEX IF NOT EXISTS \"idx_active_email\" ON \"users\" (\"email\") WHERE \"status\" = 'it''s active';"
+        ));
+        assert!(postgres_ddl.contains("ON \"users\" ((lower(name)));"));
+
+        // MySQL 不支持部分索引，只输出说明注释
+        let mysql_ddl = generate_ddl(&model, DatabaseType::MySQL);
+        assert!(mysql_ddl.contains("-- idx_active_email:"));
+        assert!(mysql_ddl.contains("CREATE INDEX `idx_lower_name` ON `users` ((lower(name)));"));
+    }
 }
@@ -34,11 +34,6 @@ impl ErrorMessageI18n {
         });
     }
 
-    /// 确保i18n已初始化（内部辅助函数）
-    #[inline]
-    fn ensure_initialized() {
-        Self::init_i18n();
-    }
     /// 注册所有错误消息翻译
     pub(crate) fn register_all_translations() {
         let mut translations = HashMap::new();
@@ -636,9 +631,37 @@ impl ErrorMessageI18n {
         not_found_errors.insert("ja-JP".to_string(), "データが見つかりません: {message}".to_string());
         translations.insert("error.not_found".to_string(), not_found_errors);
 
+        // 唯一约束冲突错误
+        let mut unique_violation_errors = HashMap::new();
+        unique_violation_errors.insert("zh-CN".to_string(), "表 '{table}' 的唯一约束冲突: {fields}".to_string());
+        unique_violation_errors.insert("en-US".to_string(), "Unique constraint violation on table '{table}': {fields}".to_string());
+        unique_violation_errors.insert("ja-JP".to_string(), "テーブル '{table}' の一意制約違反: {fields}".to_string());
+        translations.insert("error.unique_violation".to_string(), unique_violation_errors);
+
+        // 熔断器打开错误
+        let mut circuit_open_errors = HashMap::new();
+        circuit_open_errors.insert("zh-CN".to_string(), "数据库 '{alias}' 熔断中，请在 {retry_after_ms}ms 后重试".to_string());
+        circuit_open_errors.insert("en-US".to_string(), "Circuit breaker open for database '{alias}', retry after {retry_after_ms}ms".to_string());
+        circuit_open_errors.insert("ja-JP".to_string(), "データベース '{alias}' のサーキットブレーカーが開いています。{retry_after_ms}ms 後に再試行してください".to_string());
+        translations.insert("error.circuit_open".to_string(), circuit_open_errors);
+
+        // 操作队列已满错误
+        let mut overloaded_errors = HashMap::new();
+        overloaded_errors.insert("zh-CN".to_string(), "数据库 '{alias}' 的操作队列已满（{queued} 个操作排队中）".to_string());
+        overloaded_errors.insert("en-US".to_string(), "Operation queue for database '{alias}' is full ({queued} operations queued)".to_string());
+        overloaded_errors.insert("ja-JP".to_string(), "データベース '{alias}' の操作キューが満杯です（{queued} 件の操作が待機中）".to_string());
+        translations.insert("error.overloaded".to_string(), overloaded_errors);
+
+        // 条件更新前置条件失败错误
+        let mut precondition_failed_errors = HashMap::new();
+        precondition_failed_errors.insert("zh-CN".to_string(), "表 '{table}' 中记录 {id} 的当前值不满足更新条件: {fields}".to_string());
+        precondition_failed_errors.insert("en-US".to_string(), "Current values of record {id} in table '{table}' do not match the update preconditions: {fields}".to_string());
+        precondition_failed_errors.insert("ja-JP".to_string(), "テーブル '{table}' のレコード {id} の現在値が更新条件を満たしていません: {fields}".to_string());
+        translations.insert("error.precondition_failed".to_string(), precondition_failed_errors);
+
         // ===== 字段验证消息 =====
 
-        let mut v = |map: &mut HashMap<String, HashMap<String, String>>, key: &str, zh: &str, en: &str, ja: &str| {
+        let v = |map: &mut HashMap<String, HashMap<String, String>>, key: &str, zh: &str, en: &str, ja: &str| {
             let mut m = HashMap::new();
             m.insert("zh-CN".to_string(), zh.to_string());
             m.insert("en-US".to_string(), en.to_string());
@@ -712,6 +735,36 @@ impl ErrorMessageI18n {
             "字段类型不匹配，期望对象类型", "Type mismatch, expected object type", "フィールドタイプが一致しません、オブジェクト型を期待");
         v(&mut translations, "validation.type_reference",
             "引用字段必须是字符串ID", "Reference field must be a string ID", "参照フィールドは文字列IDである必要があります");
+        v(&mut translations, "validation.rule_compare",
+            "字段 '{left}' 必须满足 {left} {operator} {right}", "Field '{left}' must satisfy {left} {operator} {right}", "フィールド '{left}' は {left} {operator} {right} を満たす必要があります");
+        v(&mut translations, "validation.rule_incomparable",
+            "字段 '{left}' 与 '{right}' 的值无法比较", "Values of fields '{left}' and '{right}' cannot be compared", "フィールド '{left}' と '{right}' の値は比較できません");
+        v(&mut translations, "validation.rule_exactly_one_of",
+            "字段 {fields} 中必须恰好填写一个", "Exactly one of fields {fields} must be set", "フィールド {fields} のうち正確に1つを設定する必要があります");
+        v(&mut translations, "validation.rule_at_least_one_of",
+            "字段 {fields} 中至少需要填写一个", "At least one of fields {fields} must be set", "フィールド {fields} のうち少なくとも1つを設定する必要があります");
+        v(&mut translations, "validation.rule_at_most_one_of",
+            "字段 {fields} 中至多只能填写一个", "At most one of fields {fields} may be set", "フィールド {fields} のうち設定できるのは1つまでです");
+        v(&mut translations, "validation.rule_required_with",
+            "设置字段 '{field}' 时必须同时设置字段 '{required}'", "Field '{required}' is required when '{field}' is set", "'{field}' を設定する場合は '{required}' も必須です");
+        v(&mut translations, "validation.rule_not_registered",
+            "校验规则未注册: {name}", "Validation rule is not registered: {name}", "検証ルールが登録されていません: {name}");
+        v(&mut translations, "validation.default_generator_not_registered",
+            "默认值生成器未注册: {name}", "Default value generator is not registered: {name}", "デフォルト値ジェネレーターが登録されていません: {name}");
+        v(&mut translations, "validation.validator_not_registered",
+            "验证器未注册: {name}", "Validator is not registered: {name}", "バリデーターが登録されていません: {name}");
+        v(&mut translations, "sharding.resolver_not_registered",
+            "分片解析器未注册: {name}", "Shard resolver is not registered: {name}", "シャードリゾルバーが登録されていません: {name}");
+        v(&mut translations, "sharding.key_missing",
+            "分片模型 '{collection}' 的写入数据缺少分片键 '{field}'", "Write to sharded model '{collection}' is missing shard key '{field}'", "シャードモデル '{collection}' への書き込みにシャードキー '{field}' がありません");
+        v(&mut translations, "sharding.no_shards",
+            "分片解析器没有配置任何分片", "Shard resolver has no shards configured", "シャードリゾルバーにシャードが設定されていません");
+        v(&mut translations, "validation.type_polymorphic_reference",
+            "多态引用字段必须是包含 collection 与 id 的对象", "Polymorphic reference field must be an object with collection and id", "ポリモーフィック参照フィールドは collection と id を含むオブジェクトである必要があります");
+        v(&mut translations, "validation.polymorphic_reference_missing_id",
+            "多态引用缺少目标ID", "Polymorphic reference is missing the target id", "ポリモーフィック参照にターゲットIDがありません");
+        v(&mut translations, "validation.polymorphic_reference_target",
+            "多态引用目标集合 '{collection}' 不在允许列表中: {allowed}", "Polymorphic reference target collection '{collection}' is not allowed: {allowed}", "ポリモーフィック参照のターゲットコレクション '{collection}' は許可されていません: {allowed}");
         v(&mut translations, "validation.type_biginteger",
             "字段类型不匹配，期望大整数类型", "Type mismatch, expected big integer type", "フィールドタイプが一致しません、BigInteger型を期待");
         v(&mut translations, "validation.type_double",
@@ -737,6 +790,8 @@ impl ErrorMessageI18n {
             "表名不能为空", "Table name cannot be empty", "テーブル名は空にできません");
         v(&mut translations, "security.table_too_long",
             "表名长度不能超过64个字符", "Table name length cannot exceed 64 characters", "テーブル名の長さは64文字を超えることはできません");
+        v(&mut translations, "security.schema_invalid",
+            "无效的模式/数据库名: '{name}'", "Invalid schema/database name: '{name}'", "無効なスキーマ/データベース名: '{name}'");
         v(&mut translations, "security.sql_field_start_digit",
             "SQL字段名不能以数字开头", "SQL field name cannot start with a digit", "SQLフィールド名は数字で始めることはできません");
         v(&mut translations, "security.sql_field_invalid_char",
@@ -778,6 +833,142 @@ impl ErrorMessageI18n {
             "解析TOML配置文件失败: {message}", "Failed to parse TOML config file: {message}", "TOML設定ファイルの解析に失敗しました: {message}");
         v(&mut translations, "config.parse_json_failed",
             "解析JSON配置文件失败: {message}", "Failed to parse JSON config file: {message}", "JSON設定ファイルの解析に失敗しました: {message}");
+        v(&mut translations, "config.parse_yaml_failed",
+            "解析YAML配置文件失败: {message}", "Failed to parse YAML config file: {message}", "YAML設定ファイルの解析に失敗しました: {message}");
+        v(&mut translations, "config.file_format_unsupported",
+            "不支持的配置文件格式（支持 .toml/.yaml/.yml/.json）: {path}", "Unsupported config file format (expected .toml/.yaml/.yml/.json): {path}", "サポートされていない設定ファイル形式です（.toml/.yaml/.yml/.json のみ）: {path}");
+        v(&mut translations, "config.invalid_file_config",
+            "配置文件内容无效: {message}", "Invalid config file content: {message}", "設定ファイルの内容が無効です: {message}");
+        v(&mut translations, "config.duplicate_database_alias",
+            "配置文件中的数据库别名重复: {alias}", "Duplicate database alias in config file: {alias}", "設定ファイル内のデータベースエイリアスが重複しています: {alias}");
+        v(&mut translations, "config.env_var_missing",
+            "配置引用的环境变量不存在: {name}", "Environment variable referenced by config is not set: {name}", "設定で参照されている環境変数が存在しません: {name}");
+        v(&mut translations, "config.env_var_unterminated",
+            "环境变量引用缺少结束括号: {text}", "Environment variable reference is missing its closing brace: {text}", "環境変数の参照に閉じ括弧がありません: {text}");
+        v(&mut translations, "config.env_url_required",
+            "新增数据库需要设置连接 URL 环境变量: {name}", "Environment variable {name} is required to add a database", "データベースを追加するには環境変数 {name} が必要です");
+        v(&mut translations, "config.env_value_invalid",
+            "环境变量 {name} 的值无效: {value}", "Invalid value for environment variable {name}: {value}", "環境変数 {name} の値が無効です: {value}");
+        v(&mut translations, "config.invalid_connection_url",
+            "无效的连接 URL {url}: {message}", "Invalid connection URL {url}: {message}", "無効な接続URL {url}: {message}");
+        v(&mut translations, "config.url_scheme_unsupported",
+            "不支持的连接 URL 协议: {scheme}", "Unsupported connection URL scheme: {scheme}", "サポートされていない接続URLスキームです: {scheme}");
+        v(&mut translations, "config.mongodb_srv_conflict",
+            "MongoDB SRV 模式不能同时指定额外种子节点或直连", "MongoDB SRV mode cannot be combined with extra seed hosts or direct connection", "MongoDB SRVモードでは追加のシードノードや直接接続を指定できません");
+        v(&mut translations, "config.mysql_collation_mismatch",
+            "MySQL 排序规则 {collation} 与字符集 {charset} 不匹配", "MySQL collation {collation} does not match charset {charset}", "MySQLの照合順序 {collation} が文字セット {charset} と一致しません");
+        v(&mut translations, "config.table_naming_invalid",
+            "表名前缀/后缀只能包含字母、数字与下划线: '{value}'", "Table name prefix/suffix may only contain letters, digits and underscores: '{value}'", "テーブル名のプレフィックス/サフィックスには英数字とアンダースコアのみ使用できます: '{value}'");
+        v(&mut translations, "config.sqlite_pragma_invalid",
+            "无效的 SQLite PRAGMA {name}: {value}", "Invalid SQLite PRAGMA {name}: {value}", "無効なSQLite PRAGMA {name}: {value}");
+        v(&mut translations, "config.on_connect_statement_empty",
+            "连接初始化语句不能为空", "On-connect statements cannot be empty", "接続初期化ステートメントは空にできません");
+        v(&mut translations, "config.on_connect_mongo_command_invalid",
+            "MongoDB连接初始化语句必须是JSON对象形式的命令文档", "MongoDB on-connect statements must be JSON command documents", "MongoDBの接続初期化ステートメントはJSONオブジェクト形式のコマンドドキュメントである必要があります");
+        v(&mut translations, "config.sqlcipher_feature_required",
+            "SQLite 加密密钥需要启用 sqlcipher 特性", "SQLite encryption key requires the sqlcipher feature", "SQLite暗号化キーにはsqlcipher機能の有効化が必要です");
+        v(&mut translations, "testing.fixture_parse_failed",
+            "解析夹具失败: {message}", "Failed to parse fixtures: {message}", "フィクスチャの解析に失敗しました: {message}");
+        v(&mut translations, "testing.fixture_record_invalid",
+            "夹具记录必须是对象: 表={table}, 序号={index}", "Fixture record must be an object: table={table}, index={index}", "フィクスチャのレコードはオブジェクトである必要があります: テーブル={table}, 番号={index}");
+        v(&mut translations, "seed.parse_failed",
+            "解析种子失败: 种子={name}, {message}", "Failed to parse seed: seed={name}, {message}", "シードの解析に失敗しました: シード={name}, {message}");
+        v(&mut translations, "seed.record_invalid",
+            "种子记录必须是对象: 种子={name}, 表={table}, 序号={index}", "Seed record must be an object: seed={name}, table={table}, index={index}", "シードのレコードはオブジェクトである必要があります: シード={name}, テーブル={table}, 番号={index}");
+        v(&mut translations, "serializer.csv_column_not_found",
+            "CSV表头中不存在映射的列: {column}", "Mapped column not found in CSV header: {column}", "CSVヘッダーにマッピングされた列がありません: {column}");
+        v(&mut translations, "serializer.csv_column_missing",
+            "CSV行缺少列: 字段={column}, 实际列数={count}", "CSV row is missing a column: field={column}, columns={count}", "CSV行に列がありません: フィールド={column}, 列数={count}");
+        v(&mut translations, "serializer.csv_value_invalid",
+            "CSV单元格无法转换为字段类型: 字段={column}, 值={value}", "CSV cell cannot be converted to the field type: field={column}, value={value}", "CSVセルをフィールド型に変換できません: フィールド={column}, 値={value}");
+        v(&mut translations, "serializer.csv_unterminated_quote",
+            "CSV引号未闭合", "Unterminated quote in CSV", "CSVの引用符が閉じられていません");
+        v(&mut translations, "serializer.ndjson_line_invalid",
+            "NDJSON行不是有效的JSON: {message}", "NDJSON line is not valid JSON: {message}", "NDJSON行が有効なJSONではありません: {message}");
+        v(&mut translations, "serializer.ndjson_value_invalid",
+            "NDJSON字段值无法转换为字段类型: 字段={field}", "NDJSON value cannot be converted to the field type: field={field}", "NDJSONの値をフィールド型に変換できません: フィールド={field}");
+        v(&mut translations, "serializer.parquet_failed",
+            "写入Parquet失败: {message}", "Failed to write Parquet: {message}", "Parquetの書き込みに失敗しました: {message}");
+        v(&mut translations, "serializer.parquet_model_missing",
+            "未指定列映射且表没有注册模型: {table}", "No column mapping given and no model registered for table: {table}", "列マッピングが指定されておらず、テーブルにモデルが登録されていません: {table}");
+        v(&mut translations, "serializer.parquet_value_mismatch",
+            "字段值与Parquet列类型不匹配: 字段={field}, 列类型={expected}", "Value does not match the Parquet column type: field={field}, column type={expected}", "値がParquetの列型と一致しません: フィールド={field}, 列型={expected}");
+        v(&mut translations, "sync.resume_token_invalid",
+            "续传令牌无效: {message}", "Invalid resume token: {message}", "再開トークンが無効です: {message}");
+        v(&mut translations, "sync.upsert_requires_ids",
+            "按 ID 插入或更新时必须保留源记录的 ID", "Upsert mode requires preserving source IDs", "アップサートモードではソースIDを保持する必要があります");
+        v(&mut translations, "sync.upsert_auto_increment",
+            "目标别名 {alias} 使用自增 ID，无法保留源记录的 ID 进行插入或更新", "Target alias {alias} uses auto-increment IDs, so source IDs cannot be preserved for upsert", "ターゲットエイリアス {alias} は自動採番IDを使用するため、アップサートでソースIDを保持できません");
+        v(&mut translations, "backup.manifest_invalid",
+            "备份清单无效: {message}", "Invalid backup manifest: {message}", "バックアップマニフェストが無効です: {message}");
+        v(&mut translations, "backup.version_unsupported",
+            "不支持的备份格式版本 {version}（最高支持 {supported}）", "Unsupported backup format version {version} (up to {supported} is supported)", "サポートされていないバックアップ形式バージョン {version}（{supported} までサポート）");
+        v(&mut translations, "serializer.decode_failed",
+            "解码 {format} 数据失败: {message}", "Failed to decode {format} data: {message}", "{format} データのデコードに失敗しました: {message}");
+        v(&mut translations, "serializer.wire_invalid",
+            "线上编码数据无效: {message}", "Invalid wire-encoded data: {message}", "ワイヤーエンコードデータが無効です: {message}");
+        v(&mut translations, "serializer.wire_value_mismatch",
+            "类型 {type} 的值无效: {value}", "Invalid value for type {type}: {value}", "型 {type} の値が無効です: {value}");
+        v(&mut translations, "serializer.wire_version_unsupported",
+            "不支持的线上编码版本 {version}（最高支持 {supported}）", "Unsupported wire encoding version {version} (up to {supported} is supported)", "サポートされていないワイヤーエンコードバージョン {version}（{supported} までサポート）");
+        v(&mut translations, "ffi.null_pointer",
+            "传入了空指针", "Null pointer passed", "NULL ポインタが渡されました");
+        v(&mut translations, "ffi.invalid_utf8",
+            "字符串不是有效的 UTF-8", "String is not valid UTF-8", "文字列が有効な UTF-8 ではありません");
+        v(&mut translations, "request.invalid_json",
+            "JSON 无效: {message}", "Invalid JSON: {message}", "JSON が無効です: {message}");
+        v(&mut translations, "request.missing_field",
+            "请求缺少字段: {field}", "Request is missing field: {field}", "リクエストにフィールドがありません: {field}");
+        v(&mut translations, "request.unknown_op",
+            "不支持的操作: {op}", "Unsupported operation: {op}", "サポートされていない操作です: {op}");
+        v(&mut translations, "ffi.panicked",
+            "操作执行时发生 panic", "Operation panicked", "操作の実行中にパニックが発生しました");
+        v(&mut translations, "server.unauthorized",
+            "未认证或令牌无效", "Missing or invalid credentials", "認証されていないか、トークンが無効です");
+        v(&mut translations, "server.alias_not_allowed",
+            "不允许访问数据库别名: {alias}", "Access to database alias is not allowed: {alias}", "データベースエイリアスへのアクセスは許可されていません: {alias}");
+        v(&mut translations, "server.read_only",
+            "只读服务不允许操作: {op}", "Operation not allowed on a read-only service: {op}", "読み取り専用サービスでは操作が許可されていません: {op}");
+        v(&mut translations, "server.body_not_object",
+            "请求体必须是 JSON 对象", "Request body must be a JSON object", "リクエストボディは JSON オブジェクトである必要があります");
+        v(&mut translations, "sp.output_row_missing",
+            "存储过程没有返回输出参数行", "Stored procedure returned no output parameter row", "ストアドプロシージャが出力パラメータ行を返しませんでした");
+        v(&mut translations, "sp.output_param_missing",
+            "输出参数 {name} 缺失或为空", "Output parameter {name} is missing or null", "出力パラメータ {name} がないか null です");
+        v(&mut translations, "sp.output_param_type_mismatch",
+            "输出参数 {name} 的值 {value} 无法转换为 {type}", "Output parameter {name} value {value} cannot be converted to {type}", "出力パラメータ {name} の値 {value} を {type} に変換できません");
+        v(&mut translations, "sp.output_param_invalid",
+            "输出参数名为空或重复: {name}", "Output parameter name is empty or duplicated: {name}", "出力パラメータ名が空または重複しています: {name}");
+        v(&mut translations, "sp.result_set_invalid",
+            "附加结果集的存储过程名称不能为空或引用自身", "Additional result set procedure names must be non-empty and must not reference the procedure itself", "追加結果セットのプロシージャ名は空にできず、自身を参照できません");
+        v(&mut translations, "sp.not_found",
+            "存储过程 '{name}' 不存在", "Stored procedure '{name}' does not exist", "ストアドプロシージャ '{name}' が存在しません");
+        v(&mut translations, "lock.timeout",
+            "等待咨询锁超时: 键={key}, 超时={timeout_ms}ms", "Timed out waiting for advisory lock: key={key}, timeout={timeout_ms}ms", "アドバイザリロックの待機がタイムアウトしました: キー={key}, タイムアウト={timeout_ms}ms");
+        v(&mut translations, "lock.unsupported",
+            "该连接不支持咨询锁: {db_type}", "Advisory locks are not supported on this connection: {db_type}", "この接続はアドバイザリロックをサポートしていません: {db_type}");
+        v(&mut translations, "lock.query_failed",
+            "咨询锁操作失败: {message}", "Advisory lock operation failed: {message}", "アドバイザリロック操作に失敗しました: {message}");
+        v(&mut translations, "outbox.unsupported",
+            "该连接不支持发件箱: {db_type}", "Outbox is not supported on this connection: {db_type}", "この接続はアウトボックスをサポートしていません: {db_type}");
+        v(&mut translations, "outbox.query_failed",
+            "发件箱操作失败: {message}", "Outbox operation failed: {message}", "アウトボックス操作に失敗しました: {message}");
+        v(&mut translations, "lease.unsupported",
+            "该连接不支持连接租用: {db_type}", "Connection leasing is not supported on this connection: {db_type}", "この接続は接続のリースをサポートしていません: {db_type}");
+        v(&mut translations, "odm.row_lock_requires_lease",
+            "ODM 查询不支持行级锁（锁会随自动提交的语句立即释放），请在租用连接的事务内使用 LeasedConnection::find", "ODM queries do not support row locks (the lock would be released as soon as the autocommit statement ends); use LeasedConnection::find inside a transaction on a leased connection", "ODM クエリは行ロックをサポートしていません（自動コミットの文が終わるとすぐにロックが解放されます）。リースした接続のトランザクション内で LeasedConnection::find を使用してください");
+        v(&mut translations, "lease.acquire_failed",
+            "租用数据库连接失败: {message}", "Failed to lease a database connection: {message}", "データベース接続のリースに失敗しました: {message}");
+        v(&mut translations, "lease.transaction_already_started",
+            "租用的连接已在事务中", "The leased connection is already in a transaction", "リースした接続はすでにトランザクション中です");
+        v(&mut translations, "lease.transaction_not_started",
+            "租用的连接没有进行中的事务", "The leased connection has no active transaction", "リースした接続に進行中のトランザクションがありません");
+        v(&mut translations, "lease.transaction_failed",
+            "执行事务语句失败: {statement}: {message}", "Failed to execute transaction statement: {statement}: {message}", "トランザクション文の実行に失敗しました: {statement}: {message}");
+        v(&mut translations, "guardrail.row_limit_exceeded",
+            "无分页查询返回的记录数超过上限: 表={table}, 上限={max_rows}，请添加分页", "Unpaginated query exceeded the row limit: table={table}, limit={max_rows}; add pagination", "ページングなしのクエリが行数上限を超えました: テーブル={table}, 上限={max_rows}。ページングを指定してください");
+        v(&mut translations, "guardrail.delete_requires_conditions",
+            "已禁止无条件删除: 表={table}", "Deleting without conditions is not allowed: table={table}", "条件なしの削除は許可されていません: テーブル={table}");
         v(&mut translations, "config.serialize_toml_failed",
             "序列化TOML配置失败: {message}", "Failed to serialize TOML config: {message}", "TOML設定のシリアライズに失敗しました: {message}");
         v(&mut translations, "config.serialize_json_failed",
@@ -834,6 +1025,10 @@ impl ErrorMessageI18n {
             "空闲连接超时时间不能为零", "Idle timeout cannot be zero", "アイドルタイムアウトはゼロにできません");
         v(&mut translations, "config.max_lifetime_zero",
             "连接最大生存时间不能为零", "Max lifetime cannot be zero", "最大寿命はゼロにできません");
+        v(&mut translations, "config.queue_capacity_zero",
+            "操作队列容量不能为零", "Queue capacity cannot be zero", "操作キューの容量はゼロにできません");
+        v(&mut translations, "config.write_batch_size_zero",
+            "写入合并批次大小不能为零", "Write batch size cannot be zero", "書き込みバッチサイズはゼロにできません");
         v(&mut translations, "config.app_name_required",
             "应用名称必须设置", "App name is required", "アプリ名は必須です");
         v(&mut translations, "config.app_version_required",
@@ -880,12 +1075,16 @@ impl ErrorMessageI18n {
             "不支持的数据库类型（可能需要启用相应的feature）",
             "Unsupported database type (may need to enable the corresponding feature)",
             "サポートされていないデータベースタイプ（対応するフィーチャを有効にしてください）");
-        v(&mut translations, "pool.operation_not_implemented",
-            "操作发送未实现", "Operation send not implemented", "操作送信が実装されていません");
         v(&mut translations, "pool.send_operation_failed",
             "发送操作失败", "Failed to send operation", "操作の送信に失敗しました");
         v(&mut translations, "pool.receive_response_failed",
             "接收响应失败", "Failed to receive response", "応答の受信に失敗しました");
+        v(&mut translations, "pool.operation_timeout",
+            "操作超时（{timeout_ms}ms）", "Operation timed out after {timeout_ms}ms", "操作がタイムアウトしました（{timeout_ms}ms）");
+        v(&mut translations, "pool.on_connect_failed",
+            "执行连接初始化语句失败: {statement}: {message}", "Failed to run on-connect statement: {statement}: {message}", "接続初期化ステートメントの実行に失敗しました: {statement}: {message}");
+        v(&mut translations, "pool.warm_up_failed",
+            "数据库 '{alias}' 连接预热失败: {error}", "Failed to warm up connections for database '{alias}': {error}", "データベース '{alias}' の接続ウォームアップに失敗しました: {error}");
 
         // ===== MongoDB 适配器层消息 =====
 
@@ -1064,6 +1263,8 @@ impl ErrorMessageI18n {
         v(&mut translations, "table.version_missing_rollback",
             "版本 {version} 缺少回滚脚本", "Version {version} is missing rollback script", "バージョン {version} にロールバックスクリプトがありません");
         // manager.rs
+        v(&mut translations, "table.partition_not_declared",
+            "表 '{name}' 未声明分区", "Table '{name}' does not declare partitioning", "テーブル '{name}' はパーティションを宣言していません");
         v(&mut translations, "table.cannot_get_default_pool",
             "无法获取默认连接池", "Cannot get default connection pool", "デフォルト接続プールを取得できません");
         v(&mut translations, "table.initial_version",
@@ -1121,6 +1322,57 @@ impl ErrorMessageI18n {
             "数据值不存在", "Data value does not exist", "データ値が存在しません");
         v(&mut translations, "model.key_access_error",
             "键访问错误", "Key access error", "キーアクセスエラー");
+        // from_data_value.rs
+        v(&mut translations, "model.data_value_type_mismatch",
+            "无法将 {actual} 类型的数据解码为 {expected}",
+            "Cannot decode {actual} value into {expected}",
+            "{actual} 型のデータを {expected} にデコードできません");
+        v(&mut translations, "model.field_decode_failed",
+            "字段 '{field}' 解码失败: {message}",
+            "Failed to decode field '{field}': {message}",
+            "フィールド '{field}' のデコードに失敗しました: {message}");
+        // field_types.rs
+        v(&mut translations, "model.primary_key_field_missing",
+            "缺少主键字段 '{field}' 的值", "Missing value for primary key field '{field}'", "主キーフィールド '{field}' の値がありません");
+        v(&mut translations, "model.primary_key_field_unknown",
+            "字段 '{field}' 不是主键字段", "Field '{field}' is not a primary key field", "フィールド '{field}' は主キーフィールドではありません");
+        v(&mut translations, "model.composite_key_requires_map",
+            "模型 {collection} 使用复合主键，请使用按主键映射的方法", "Model {collection} has a composite primary key, use the key map methods", "モデル {collection} は複合主キーを使用しています。主キーマップのメソッドを使用してください");
+        v(&mut translations, "model.index_operator_unsupported",
+            "部分索引条件不支持操作符 {operator}", "Partial index condition does not support operator {operator}", "部分インデックス条件は演算子 {operator} をサポートしていません");
+        v(&mut translations, "model.index_value_unsupported",
+            "部分索引条件不支持字段 '{field}' 的值类型 {value_type}", "Partial index condition does not support value type {value_type} for field '{field}'", "部分インデックス条件はフィールド '{field}' の値型 {value_type} をサポートしていません");
+        v(&mut translations, "model.index_expression_invalid",
+            "索引表达式包含非法内容: {expression}", "Index expression contains illegal content: {expression}", "インデックス式に不正な内容が含まれています: {expression}");
+        v(&mut translations, "model.update_path_invalid",
+            "字段路径 '{field}' 不合法，只能包含字母、数字和下划线，以 '.' 分隔", "Field path '{field}' is invalid; segments may only contain letters, digits and underscores separated by '.'", "フィールドパス '{field}' が不正です。'.' で区切られた英数字とアンダースコアのみ使用できます");
+        v(&mut translations, "model.rename_target_invalid",
+            "重命名字段 '{field}' 的目标必须是同一列内的字段名或 JSON 路径", "Rename target of field '{field}' must be a field name or a JSON path within the same column", "フィールド '{field}' のリネーム先は同じ列内のフィールド名または JSON パスである必要があります");
+        v(&mut translations, "model.partial_index_unsupported",
+            "{database} 不支持部分索引", "{database} does not support partial indexes", "{database} は部分インデックスをサポートしていません");
+        v(&mut translations, "model.expression_index_unsupported",
+            "{database} 不支持表达式索引", "{database} does not support expression indexes", "{database} は式インデックスをサポートしていません");
+        // manager.rs
+        v(&mut translations, "bulk.conflict_columns_empty",
+            "Upsert 操作必须指定冲突列", "Upsert operation must specify conflict columns", "Upsert 操作には競合列を指定する必要があります");
+        v(&mut translations, "bulk.conflict_value_missing",
+            "Upsert 数据中缺少冲突列 '{column}' 的值", "Upsert data is missing a value for conflict column '{column}'", "Upsert データに競合列 '{column}' の値がありません");
+        v(&mut translations, "bulk.sharded_alias_required",
+            "集合 {collection} 已分片，批量写入需要显式指定别名", "Collection {collection} is sharded; bulk write requires an explicit alias", "コレクション {collection} はシャーディングされているため、一括書き込みにはエイリアスの明示的な指定が必要です");
+        v(&mut translations, "bulk.transaction_failed",
+            "批量写入失败: {error}", "Bulk write failed: {error}", "一括書き込みに失敗しました: {error}");
+        v(&mut translations, "odm.update_if_not_found",
+            "条件更新失败：集合 {collection} 中不存在记录 {id}", "Conditional update failed: record {id} does not exist in collection {collection}", "条件付き更新に失敗しました：コレクション {collection} にレコード {id} が存在しません");
+        v(&mut translations, "model.reload_not_found",
+            "重新加载失败：集合 {collection} 中的记录已不存在",
+            "Reload failed: record no longer exists in collection {collection}",
+            "再読み込みに失敗しました：コレクション {collection} のレコードは存在しません");
+        v(&mut translations, "model.scope_not_found",
+            "模型未声明查询作用域 {scope}", "Query scope {scope} is not declared on the model", "モデルにクエリスコープ {scope} が宣言されていません");
+        v(&mut translations, "model.schema_model_not_found",
+            "导出 JSON Schema 失败：集合 {collection} 未注册模型", "Failed to export JSON Schema: no model registered for collection {collection}", "JSON Schema のエクスポートに失敗しました：コレクション {collection} にモデルが登録されていません");
+        v(&mut translations, "model.batch_size_zero",
+            "分批处理的批次大小不能为零", "Batch size cannot be zero", "バッチサイズはゼロにできません");
 
         // ===== P10: 管理器层 manager/ =====
         v(&mut translations, "manager.no_cache_manager",
@@ -1207,6 +1459,30 @@ impl ErrorMessageI18n {
             "期望JSON对象或JSON字符串",
             "Expected JSON object or JSON string",
             "JSONオブジェクトまたはJSON文字列を期待しました");
+        v(&mut translations, "query.between_requires_two_bounds",
+            "BETWEEN 条件需要 [下界, 上界] 两个元素的数组，但收到: {value_type}",
+            "BETWEEN condition requires a two-element [lower, upper] array, but received: {value_type}",
+            "BETWEEN 条件には [下限, 上限] の2要素の配列が必要ですが、受信しました: {value_type}");
+        v(&mut translations, "query.regex_string_only",
+            "正则表达式条件只支持字符串模式，但收到: {value_type}",
+            "Regex condition only supports string patterns, but received: {value_type}",
+            "正規表現条件は文字列パターンのみサポートしますが、受信しました: {value_type}");
+        v(&mut translations, "query.regex_too_long",
+            "正则表达式模式过长，最多 {max} 个字符",
+            "Regex pattern is too long, at most {max} characters allowed",
+            "正規表現パターンが長すぎます。最大 {max} 文字です");
+        v(&mut translations, "query.regex_invalid",
+            "无效的正则表达式模式: {message}",
+            "Invalid regex pattern: {message}",
+            "無効な正規表現パターン: {message}");
+        v(&mut translations, "query.regex_nested_quantifier",
+            "正则表达式包含嵌套量词，可能导致灾难性回溯: {pattern}",
+            "Regex pattern contains nested quantifiers that may cause catastrophic backtracking: {pattern}",
+            "正規表現にネストした量指定子が含まれており、壊滅的なバックトラックを引き起こす可能性があります: {pattern}");
+        v(&mut translations, "query.between_bound_type_mismatch",
+            "BETWEEN 条件的上下界类型不支持或不一致: {lower} 与 {upper}",
+            "BETWEEN bounds are unsupported or of mismatched types: {lower} and {upper}",
+            "BETWEEN 条件の境界値の型がサポートされていないか一致しません: {lower} と {upper}");
 
         // 注册所有翻译
         register_translations(translations);
@@ -4,7 +4,6 @@
 
 use crate::types::{IdStrategy, IdType};
 use anyhow::{Result, anyhow};
-use rat_logger::{error, info, warn};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::{SystemTime, UNIX_EPOCH};
@@ -201,6 +200,7 @@ impl SnowflakeGenerator {
 #[derive(Debug)]
 pub struct MongoAutoIncrementGenerator {
     /// 集合名称
+    #[allow(dead_code)]
     collection_name: String,
     /// 当前计数器值
     counter: Arc<AtomicU64>,
@@ -2,12 +2,6 @@
 //! 用于简化复杂JOIN查询和缓存管理
 //! 支持SQL和MongoDB双模式
 
-use crate::adapter::DatabaseAdapter;
-use crate::pool::DatabaseConnection;
-use crate::types::*;
-use async_trait::async_trait;
-use serde_json::Value as JsonValue;
-use std::collections::HashMap;
 
 /// JOIN子句定义
 #[derive(Debug, Clone)]
@@ -126,10 +120,10 @@ macro_rules! define_join_table {
                 let mut join_clauses = Vec::new();
                 $(
                     let join_str = match $join.join_type {
-                        crate::join_macro::JoinType::Inner => "INNER JOIN",
-                        crate::join_macro::JoinType::Left => "LEFT JOIN",
-                        crate::join_macro::JoinType::Right => "RIGHT JOIN",
-                        crate::join_macro::JoinType::Full => "FULL OUTER JOIN",
+                        $crate::join_macro::JoinType::Inner => "INNER JOIN",
+                        $crate::join_macro::JoinType::Left => "LEFT JOIN",
+                        $crate::join_macro::JoinType::Right => "RIGHT JOIN",
+                        $crate::join_macro::JoinType::Full => "FULL OUTER JOIN",
                     };
                     join_clauses.push(format!(" {} {} ON {}", join_str, $join.table, $join.on_condition));
                 )*
@@ -141,25 +135,31 @@ macro_rules! define_join_table {
                     for condition in conditions {
                         let placeholder = format!("${}", params.len() + 1);
                         let op_str = match condition.operator {
-                            crate::types::query::QueryOperator::Eq => "=",
-                            crate::types::query::QueryOperator::Ne => "!=",
-                            crate::types::query::QueryOperator::Gt => ">",
-                            crate::types::query::QueryOperator::Gte => ">=",
-                            crate::types::query::QueryOperator::Lt => "<",
-                            crate::types::query::QueryOperator::Lte => "<=",
-                            crate::types::query::QueryOperator::Contains => "LIKE",
-                            crate::types::query::QueryOperator::StartsWith => "LIKE",
-                            crate::types::query::QueryOperator::EndsWith => "LIKE",
-                            crate::types::query::QueryOperator::In => "IN",
-                            crate::types::query::QueryOperator::NotIn => "NOT IN",
-                            crate::types::query::QueryOperator::Regex => "REGEX",
-                            crate::types::query::QueryOperator::Exists => "IS NOT NULL",
-                            crate::types::query::QueryOperator::IsNull => "IS NULL",
-                            crate::types::query::QueryOperator::IsNotNull => "IS NOT NULL",
+                            $crate::types::query::QueryOperator::Eq => "=",
+                            $crate::types::query::QueryOperator::Ne => "!=",
+                            $crate::types::query::QueryOperator::Gt => ">",
+                            $crate::types::query::QueryOperator::Gte => ">=",
+                            $crate::types::query::QueryOperator::Lt => "<",
+                            $crate::types::query::QueryOperator::Lte => "<=",
+                            $crate::types::query::QueryOperator::Contains => "LIKE",
+                            $crate::types::query::QueryOperator::StartsWith => "LIKE",
+                            $crate::types::query::QueryOperator::EndsWith => "LIKE",
+                            $crate::types::query::QueryOperator::In => "IN",
+                            $crate::types::query::QueryOperator::NotIn => "NOT IN",
+                            $crate::types::query::QueryOperator::Regex => "REGEX",
+                            $crate::types::query::QueryOperator::Exists => "IS NOT NULL",
+                            $crate::types::query::QueryOperator::IsNull => "IS NULL",
+                            $crate::types::query::QueryOperator::IsNotNull => "IS NOT NULL",
+                            $crate::types::query::QueryOperator::Between => "BETWEEN",
+                            $crate::types::query::QueryOperator::NotBetween => "NOT BETWEEN",
+                            $crate::types::query::QueryOperator::JsonContains => "@>",
                         };
 
-                        if matches!(condition.operator, crate::types::query::QueryOperator::IsNull | crate::types::query::QueryOperator::IsNotNull | crate::types::query::QueryOperator::Exists) {
+                        if matches!(condition.operator, $crate::types::query::QueryOperator::IsNull | $crate::types::query::QueryOperator::IsNotNull | $crate::types::query::QueryOperator::Exists) {
                             clause_parts.push(format!("{} {}", condition.field, op_str));
+                        } else if let ($crate::types::query::QueryOperator::Between | $crate::types::query::QueryOperator::NotBetween, $crate::types::DataValue::Array(bounds)) = (&condition.operator, &condition.value) {
+                            clause_parts.push(format!("{} {} {} AND ${}", condition.field, op_str, placeholder, params.len() + 2));
+                            params.extend(bounds.iter().cloned());
                         } else {
                             clause_parts.push(format!("{} {} {}", condition.field, op_str, placeholder));
                             params.push(condition.value.clone());
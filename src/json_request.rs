@@ -0,0 +1,157 @@
+//! JSON 操作请求
+//!
+//! `ffi` 与 `server` 特性共用的 JSON 请求解析与执行。请求格式（`alias` 可省略，
+//! 使用默认别名）：
+//!
+//! ```json
+//! {"op": "find", "table": "users", "alias": "default",
+//!  "conditions": [{"field": "age", "operator": "Gte", "value": 18}],
+//!  "options": {"sort": [{"field": "age", "direction": "Desc"}], "skip": 0, "limit": 10}}
+//! ```
+//!
+//! 记录与条件值使用普通 JSON，按 [`json_value_to_data_value`] 转换。
+//! 支持的 `op`：create、find、find_by_id、exists_by_id、update、update_by_id、
+//! delete、delete_by_id、count
+
+use crate::error::{QuickDbError, QuickDbResult};
+use crate::odm::OdmOperations;
+use crate::types::data_value::json_value_to_data_value;
+use crate::types::*;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// 通过 ODM 执行一个 JSON 描述的操作，返回 JSON 结果
+pub(crate) async fn execute_op(request: &Value) -> QuickDbResult<Value> {
+    let op = str_field(request, "op")?;
+    let table = str_field(request, "table")?;
+    let alias = request.get("alias").and_then(Value::as_str);
+    let odm = crate::odm::get_odm_manager().await;
+
+    let result = match op {
+        "create" => odm.create(table, records(request, "data")?, alias).await?.to_json_value(),
+        "find" => {
+            let found = odm.find(table, conditions(request)?, options(request)?, alias).await?;
+            Value::Array(found.iter().map(DataValue::to_json_value).collect())
+        }
+        "find_by_id" => odm
+            .find_by_id(table, str_field(request, "id")?, alias)
+            .await?
+            .map_or(Value::Null, |record| record.to_json_value()),
+        "exists_by_id" => Value::Bool(odm.exists_by_id(table, str_field(request, "id")?, alias).await?),
+        "update" => Value::from(odm.update(table, conditions(request)?, records(request, "data")?, alias).await?),
+        "update_by_id" => {
+            let id = str_field(request, "id")?;
+            Value::Bool(odm.update_by_id(table, id, records(request, "data")?, alias).await?)
+        }
+        "delete" => Value::from(odm.delete(table, conditions(request)?, alias).await?),
+        "delete_by_id" => Value::Bool(odm.delete_by_id(table, str_field(request, "id")?, alias).await?),
+        "count" => Value::from(odm.count(table, conditions(request)?, alias).await?),
+        other => {
+            return Err(crate::quick_error!(
+                validation,
+                "op",
+                crate::i18n::tf("request.unknown_op", &[("op", other)])
+            ));
+        }
+    };
+    Ok(result)
+}
+
+pub(crate) fn str_field<'a>(request: &'a Value, field: &str) -> QuickDbResult<&'a str> {
+    request.get(field).and_then(Value::as_str).ok_or_else(|| {
+        crate::quick_error!(validation, field, crate::i18n::tf("request.missing_field", &[("field", field)]))
+    })
+}
+
+fn records(request: &Value, field: &str) -> QuickDbResult<HashMap<String, DataValue>> {
+    let Some(Value::Object(object)) = request.get(field) else {
+        return Err(crate::quick_error!(
+            validation,
+            field,
+            crate::i18n::tf("request.missing_field", &[("field", field)])
+        ));
+    };
+    Ok(object
+        .iter()
+        .map(|(key, value)| (key.clone(), json_value_to_data_value(value.clone())))
+        .collect())
+}
+
+/// 解析 `conditions` 数组；`operator` 使用 [`QueryOperator`] 的变体名（如 `Gte`）
+fn conditions(request: &Value) -> QuickDbResult<Vec<QueryConditionWithConfig>> {
+    let Some(items) = request.get("conditions").and_then(Value::as_array) else {
+        return Ok(Vec::new());
+    };
+    items
+        .iter()
+        .map(|item| {
+            Ok(QueryConditionWithConfig {
+                field: str_field(item, "field")?.to_string(),
+                operator: parse_json_value(item.get("operator").cloned().unwrap_or(Value::Null))?,
+                value: json_value_to_data_value(item.get("value").cloned().unwrap_or(Value::Null)),
+                case_insensitive: item.get("case_insensitive").and_then(Value::as_bool).unwrap_or(false),
+            })
+        })
+        .collect()
+}
+
+/// 解析 `options`：`sort`、`skip`、`limit` 与 `fields`
+fn options(request: &Value) -> QuickDbResult<Option<QueryOptions>> {
+    let Some(options) = request.get("options").filter(|options| !options.is_null()) else {
+        return Ok(None);
+    };
+    let mut query = QueryOptions::new();
+    if let Some(sort) = options.get("sort") {
+        query = query.with_sort(parse_json_value(sort.clone())?);
+    }
+    let skip = options.get("skip").and_then(Value::as_u64);
+    let limit = options.get("limit").and_then(Value::as_u64);
+    if skip.is_some() || limit.is_some() {
+        query = query.with_pagination(PaginationConfig {
+            skip: skip.unwrap_or(0),
+            limit: limit.unwrap_or(u64::MAX),
+        });
+    }
+    if let Some(fields) = options.get("fields") {
+        query = query.with_fields(parse_json_value(fields.clone())?);
+    }
+    Ok(Some(query))
+}
+
+pub(crate) fn parse_json<T: serde::de::DeserializeOwned>(content: impl AsRef<[u8]>) -> QuickDbResult<T> {
+    serde_json::from_slice(content.as_ref()).map_err(invalid_json)
+}
+
+fn parse_json_value<T: serde::de::DeserializeOwned>(value: Value) -> QuickDbResult<T> {
+    serde_json::from_value(value).map_err(invalid_json)
+}
+
+fn invalid_json(e: serde_json::Error) -> QuickDbError {
+    QuickDbError::SerializationError {
+        message: crate::i18n::tf("request.invalid_json", &[("message", &e.to_string())]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_conditions_and_options() {
+        crate::i18n::ErrorMessageI18n::init_i18n();
+        let request = serde_json::json!({
+            "conditions": [{"field": "age", "operator": "Gte", "value": 18}],
+            "options": {"sort": [{"field": "age", "direction": "Desc"}], "limit": 10},
+        });
+        let parsed = conditions(&request).unwrap();
+        assert_eq!(parsed[0].operator, QueryOperator::Gte);
+        assert_eq!(parsed[0].value, DataValue::Int(18));
+        let query = options(&request).unwrap().unwrap();
+        assert_eq!(query.pagination.unwrap().limit, 10);
+        assert_eq!(query.sort[0].field, "age");
+
+        let bad = serde_json::json!({"conditions": [{"field": "age", "operator": "Around"}]});
+        assert!(conditions(&bad).is_err());
+        assert!(str_field(&request, "op").is_err());
+    }
+}
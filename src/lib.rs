@@ -10,7 +10,10 @@ static GLOBAL_OPERATION_LOCK: AtomicBool = AtomicBool::new(false);
 
 // 导出所有公共模块
 pub mod adapter;
+pub mod audit;
+pub mod backup;
 pub mod config;
+pub mod context;
 pub mod error;
 pub mod manager;
 pub mod model;
@@ -22,6 +25,10 @@ pub mod utils;
 // pub mod task_queue;
 pub mod i18n;
 pub mod security;
+pub mod seed;
+pub mod sharding;
+pub mod statement_log;
+pub mod sync;
 pub mod table;
 
 // 条件编译的模块
@@ -29,53 +36,102 @@ pub mod cache;
 pub mod field_versioning;
 pub mod id_generator;
 pub mod join_macro;
+#[cfg(any(
+    feature = "sqlite-support",
+    feature = "postgres-support",
+    feature = "mysql-support",
+    feature = "mongodb-support"
+))]
+pub mod lock;
+#[cfg(any(feature = "sqlite-support", feature = "postgres-support", feature = "mysql-support"))]
+pub mod outbox;
+pub mod query_macro;
+#[cfg(any(
+    feature = "sqlite-support",
+    feature = "postgres-support",
+    feature = "mysql-support",
+    feature = "mongodb-support"
+))]
+pub mod scheduler;
 pub mod stored_procedure;
-
-// 任务队列模块（仅在启用 python-bindings 特性时编译）
-// #[cfg(feature = "python-bindings")]
-// // pub mod task_queue;
+#[cfg(feature = "testing")]
+pub mod testing;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(any(feature = "ffi", feature = "server"))]
+mod json_request;
+#[cfg(feature = "server")]
+pub mod server;
 
 // Python API 模块（仅在启用 python-bindings 特性时编译）
-// #[cfg(feature = "python-bindings")]
-// pub mod python_api;
+#[cfg(feature = "python-bindings")]
+pub mod python_api;
 
 // 重新导出常用类型和函数
 pub use error::{QuickDbError, QuickDbResult};
 pub use manager::{
-    add_database, drop_table, get_aliases, health_check, register_model, set_default_alias,
-    table_exists,
+    add_database, add_databases_from_config, count_estimate, drop_table, get_aliases, health_check, register_model,
+    set_default_alias, table_exists,
 };
+pub use manager::{HealthCheckHandle, HealthReport, get_pool_status, get_pools_status, start_periodic_health_checks};
 pub use pool::DatabaseConnection;
+pub use pool::PoolStatus;
 pub use types::*;
 
-pub use manager::{clear_all_caches, clear_cache, get_cache_manager, get_cache_stats};
+pub use manager::{
+    clear_all_caches, clear_cache, get_cache_manager, get_cache_stats, get_cache_stats_by_table,
+    warm_cache, warm_cache_with_progress,
+};
 pub use model::{
-    FieldDefinition, FieldType, IndexDefinition, Model, ModelManager, ModelMeta, ModelOperations,
-    array_field, bigint_field, boolean_field, datetime_field, datetime_with_tz_field, dict_field,
-    float_field, integer_field, json_field, list_field, reference_field, string_field, uuid_field, vector_field,
+    ExpiresAfter, FieldDefinition, FieldType, IndexDefinition, IndexOptions, Model, ModelManager,
+    ModelMeta, ModelOperations,
+    array_field, bigint_field, boolean_field, datetime_field, datetime_with_tz_field,
+    embedded_field, float_field, integer_field, json_field, list_field, polymorphic_reference_field, reference_field, string_field, uuid_field, vector_field,
 };
+#[allow(deprecated)]
+pub use model::dict_field;
 pub use odm::{AsyncOdmManager, OdmOperations, get_odm_manager, get_odm_manager_mut};
 
 // 导出DateTime转换工具
 pub use adapter::{DatabaseAdapter, create_adapter};
 pub use config::{
-    AppConfig, AppConfigBuilder, DatabaseConfigBuilder, Environment, GlobalConfig,
-    GlobalConfigBuilder, LogLevel, LoggingConfig, LoggingConfigBuilder, PoolConfigBuilder,
-    mongodb_config, mysql_config, postgres_config, sqlite_config,
+    AppConfig, AppConfigBuilder, ConfigFileFormat, DatabaseConfigBuilder, DatabaseFileConfig,
+    Environment, GlobalConfig, GlobalConfigBuilder, LogLevel, LoggingConfig, LoggingConfigBuilder,
+    PoolConfigBuilder, QueryGuardrails, RowLimitAction,
 };
+#[allow(deprecated)]
+pub use config::{mongodb_config, mysql_config, postgres_config, sqlite_config};
 pub use model::conversion::database_aware::convert_datetime_with_tz_aware;
 pub use model::conversion::datetime_conversion::convert_string_to_datetime_with_tz;
-pub use serializer::{DataSerializer, OutputFormat, SerializationResult, SerializerConfig};
+pub use serializer::{
+    CsvMapping, CsvOptions, CsvWriter, DataSerializer, ImportReport, ImportRowError, NdjsonOptions, OutputFormat,
+    SerializationResult, SerializerConfig, export_ndjson, export_table_csv, import_csv, import_ndjson, records_to_csv,
+};
+#[cfg(feature = "bson-output")]
+pub use serializer::{decode_bson, decode_bson_records};
+#[cfg(feature = "cbor")]
+pub use serializer::{decode_cbor, decode_cbor_records};
+#[cfg(feature = "msgpack")]
+pub use serializer::{decode_msgpack, decode_msgpack_records};
+#[cfg(feature = "parquet")]
+pub use serializer::{ParquetColumnType, ParquetSchemaMapping, export_parquet};
 // 任务队列导出（仅在启用 python-bindings 特性时编译）
 // #[cfg(feature = "python-bindings")]
 // pub use task_queue::{
 //     TaskQueueManager, get_global_task_queue, initialize_global_task_queue,
 //     shutdown_global_task_queue
 // };
+pub use backup::{BackupManifest, BackupTable, RestoreReport};
+pub use seed::{SeedFile, SeedReport, Seeder};
+pub use sharding::{HashShardResolver, ShardKey, ShardResolver, register_shard_resolver};
+pub use sync::{CopyMode, CopyOptions, CopyProgress, CopyReport, ResumeToken};
 pub use table::{ColumnDefinition, ColumnType, IndexType, TableManager, TableSchema};
 
 // 条件导出缓存相关类型
-pub use cache::{CacheManager, CacheStats};
+pub use cache::{
+    CacheManager, CacheStats, CacheWarmOutcome, CacheWarmProgress, CacheWarmQuery, CacheWarmReport, CacheWarmSpec,
+    TableCacheStats,
+};
 
 // 导出ID生成器相关类型
 pub use id_generator::{IdGenerator, MongoAutoIncrementGenerator};
@@ -87,19 +143,15 @@ pub use stored_procedure::*;
 pub use field_versioning::{FieldVersionManager, ModelVersionMeta, VersionChange, VersionUpgradeResult};
 
 // ODM 操作函数改为内部公开，仅用于框架内部使用
-pub(crate) use odm::{
-    count, create, delete, delete_by_id, find, find_by_id, find_with_groups, update, update_by_id,
-};
-pub(crate) use odm::{create_stored_procedure, execute_stored_procedure};
 
 // 保留有用的工具函数公开导出
 pub use odm::get_server_version;
 
 // Python API 导出（仅在启用 python-bindings 特性时）
-// 注意：Python绑定相关的导出已移至专门的Python绑定库中
+#[cfg(feature = "python-bindings")]
+pub use python_api::{PyDbQueueBridge, register_python_module};
 
 // 日志系统导入
-use rat_logger::info;
 
 // 条件编译调试宏 - 只有在 debug 模式下才输出调试信息
 #[cfg(debug_assertions)]
@@ -190,7 +242,7 @@ fn apply_timezone_offset_to_datetime(
     let offset_seconds = crate::utils::timezone::parse_timezone_offset_to_seconds(timezone_offset)?;
 
     // 检查时区偏移是否在有效范围内（-23:59 到 +23:59）
-    if offset_seconds < -86399 || offset_seconds > 86399 {
+    if !(-86399..=86399).contains(&offset_seconds) {
         return Err(QuickDbError::ValidationError {
             field: "timezone_offset".to_string(),
             message: format!(
@@ -202,7 +254,7 @@ fn apply_timezone_offset_to_datetime(
 
     // 将UTC时间转换为本地时间
     let utc_chrono = utc_dt.with_timezone(&chrono::Utc);
-    let local_dt = utc_chrono.with_timezone(&chrono::FixedOffset::east(offset_seconds));
+    let local_dt = utc_chrono.with_timezone(&chrono::FixedOffset::east_opt(offset_seconds).unwrap());
 
     Ok(local_dt)
 }
@@ -350,9 +402,3 @@ pub(crate) fn lock_global_operations() {
 pub(crate) fn is_global_operations_locked() -> bool {
     GLOBAL_OPERATION_LOCK.load(Ordering::SeqCst)
 }
-
-/// 检查是否可以添加数据库
-#[doc(hidden)]
-pub(crate) fn can_add_database() -> bool {
-    !is_global_operations_locked()
-}
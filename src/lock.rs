@@ -0,0 +1,494 @@
+//! # 咨询锁模块
+//!
+//! 基于数据库的跨进程互斥锁，供分布式任务通过同一连接池协调：
+//!
+//! - PostgreSQL：`pg_try_advisory_lock`，锁键为键名的 64 位 FNV-1a 哈希
+//! - MySQL：`GET_LOCK`，超过 64 个字符的键名使用哈希
+//! - SQLite：`_rat_quickdb_locks` 锁表中的租约行
+//! - MongoDB：`_rat_quickdb_locks` 集合中的租约文档
+//!
+//! PostgreSQL 与 MySQL 的锁属于会话，守卫持有从连接池借出的独占连接直到释放；
+//! SQLite 与 MongoDB 使用租约，持有期间后台定期续期，进程崩溃后租约到期自动失效。
+//!
+//! ```ignore
+//! let guard = lock::advisory_lock("default", "nightly-report", Duration::from_secs(5)).await?;
+//! run_report().await?;
+//! guard.release().await?;
+//! ```
+//!
+//! 守卫被丢弃时在后台释放；没有 tokio 运行时时会话锁随连接关闭释放，租约锁等待到期
+
+use crate::error::{QuickDbError, QuickDbResult};
+use crate::pool::DatabaseConnection;
+use rat_logger::{debug, warn};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tokio::time::Instant;
+
+/// 租约锁的租期
+pub const LEASE_DURATION: Duration = Duration::from_secs(30);
+
+/// 租约锁使用的表 / 集合名
+pub const LOCK_TABLE: &str = "_rat_quickdb_locks";
+
+/// 等待锁时的最长轮询间隔
+const MAX_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// 获取咨询锁，最多等待 `timeout`
+///
+/// 超时未获得锁时返回错误；`timeout` 为零时只尝试一次
+pub async fn advisory_lock(alias: &str, key: &str, timeout: Duration) -> QuickDbResult<AdvisoryLockGuard> {
+    let deadline = Instant::now() + timeout;
+    let mut session = open_session(alias, key).await?;
+    let mut interval = Duration::from_millis(10);
+    loop {
+        if session.attempt().await? {
+            return Ok(AdvisoryLockGuard::new(alias, key, session));
+        }
+        let now = Instant::now();
+        if now >= deadline {
+            session.abandon();
+            return Err(QuickDbError::QueryError {
+                message: crate::i18n::tf(
+                    "lock.timeout",
+                    &[("key", key), ("timeout_ms", &timeout.as_millis().to_string())],
+                ),
+            });
+        }
+        tokio::time::sleep(interval.min(deadline - now)).await;
+        interval = (interval * 2).min(MAX_POLL_INTERVAL);
+    }
+}
+
+/// 尝试获取咨询锁，锁被占用时立即返回 `None`
+pub async fn try_advisory_lock(alias: &str, key: &str) -> QuickDbResult<Option<AdvisoryLockGuard>> {
+    let mut session = open_session(alias, key).await?;
+    if session.attempt().await? {
+        Ok(Some(AdvisoryLockGuard::new(alias, key, session)))
+    } else {
+        session.abandon();
+        Ok(None)
+    }
+}
+
+/// 咨询锁守卫
+///
+/// 调用 [`release`](Self::release) 释放锁；直接丢弃时在后台释放
+pub struct AdvisoryLockGuard {
+    alias: String,
+    key: String,
+    session: Option<LockSession>,
+    heartbeat: Option<JoinHandle<()>>,
+    lost: Arc<AtomicBool>,
+}
+
+impl std::fmt::Debug for AdvisoryLockGuard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AdvisoryLockGuard")
+            .field("alias", &self.alias)
+            .field("key", &self.key)
+            .finish_non_exhaustive()
+    }
+}
+
+impl AdvisoryLockGuard {
+    fn new(alias: &str, key: &str, session: LockSession) -> Self {
+        debug!("获得咨询锁: 别名={}, 键={}", alias, key);
+        let lost = Arc::new(AtomicBool::new(false));
+        let heartbeat = session.spawn_heartbeat(lost.clone());
+        Self {
+            alias: alias.to_string(),
+            key: key.to_string(),
+            session: Some(session),
+            heartbeat,
+            lost,
+        }
+    }
+
+    /// 数据库别名
+    pub fn alias(&self) -> &str {
+        &self.alias
+    }
+
+    /// 锁键
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 锁是否仍被持有
+    ///
+    /// 租约锁续期失败（如数据库长时间不可用导致租约被他人接管）后返回 false
+    pub fn is_held(&self) -> bool {
+        !self.lost.load(Ordering::Acquire)
+    }
+
+    /// 释放锁
+    pub async fn release(mut self) -> QuickDbResult<()> {
+        if let Some(heartbeat) = self.heartbeat.take() {
+            heartbeat.abort();
+        }
+        match self.session.take() {
+            Some(session) => {
+                debug!("释放咨询锁: 别名={}, 键={}", self.alias, self.key);
+                session.release().await
+            }
+            None => Ok(()),
+        }
+    }
+}
+
+impl Drop for AdvisoryLockGuard {
+    fn drop(&mut self) {
+        if let Some(heartbeat) = self.heartbeat.take() {
+            heartbeat.abort();
+        }
+        let Some(session) = self.session.take() else {
+            return;
+        };
+        match tokio::runtime::Handle::try_current() {
+            Ok(handle) => {
+                let key = self.key.clone();
+                handle.spawn(async move {
+                    if let Err(e) = session.release().await {
+                        warn!("后台释放咨询锁失败: 键={}, 错误={}", key, e);
+                    }
+                });
+            }
+            Err(_) => session.abandon(),
+        }
+    }
+}
+
+/// 已建立的加锁会话
+enum LockSession {
+    #[cfg(feature = "postgres-support")]
+    Postgres {
+        connection: sqlx::pool::PoolConnection<sqlx::Postgres>,
+        id: i64,
+    },
+    #[cfg(feature = "mysql-support")]
+    MySql {
+        connection: sqlx::pool::PoolConnection<sqlx::MySql>,
+        name: String,
+    },
+    #[cfg(feature = "sqlite-support")]
+    Sqlite {
+        pool: sqlx::SqlitePool,
+        key: String,
+        owner: String,
+    },
+    #[cfg(feature = "mongodb-support")]
+    Mongo {
+        database: crate::pool::MongoConnection,
+        key: String,
+        owner: String,
+    },
+}
+
+async fn open_session(alias: &str, key: &str) -> QuickDbResult<LockSession> {
+    let pool = crate::manager::get_global_pool_manager()
+        .get_connection_pools()
+        .get(alias)
+        .map(|pool| pool.clone())
+        .ok_or_else(|| QuickDbError::AliasNotFound { alias: alias.to_string() })?;
+    let connection = pool.database_connection().await?;
+
+    match connection {
+        #[cfg(feature = "postgres-support")]
+        DatabaseConnection::PostgreSQL(pool) => Ok(LockSession::Postgres {
+            connection: pool.acquire().await.map_err(query_failed)?,
+            id: fnv1a(key) as i64,
+        }),
+        #[cfg(feature = "mysql-support")]
+        DatabaseConnection::MySQL(pool) => Ok(LockSession::MySql {
+            connection: pool.acquire().await.map_err(query_failed)?,
+            name: if key.chars().count() <= 64 {
+                key.to_string()
+            } else {
+                format!("rat_quickdb:{:016x}", fnv1a(key))
+            },
+        }),
+        #[cfg(feature = "sqlite-support")]
+        DatabaseConnection::SQLite(pool) => {
+            let sql = format!(
+                "CREATE TABLE IF NOT EXISTS {} (lock_key TEXT PRIMARY KEY, owner TEXT NOT NULL, expires_at INTEGER NOT NULL)",
+                LOCK_TABLE
+            );
+            sqlx::query(&sql).execute(&pool).await.map_err(query_failed)?;
+            Ok(LockSession::Sqlite { pool, key: key.to_string(), owner: lease_owner() })
+        }
+        #[cfg(feature = "mongodb-support")]
+        DatabaseConnection::MongoDB(database) => Ok(LockSession::Mongo {
+            database,
+            key: key.to_string(),
+            owner: lease_owner(),
+        }),
+        #[allow(unreachable_patterns)]
+        _ => Err(QuickDbError::UnsupportedDatabase {
+            db_type: crate::i18n::tf("lock.unsupported", &[("db_type", &format!("{:?}", pool.db_type))]),
+        }),
+    }
+}
+
+impl LockSession {
+    /// 尝试加锁一次
+    async fn attempt(&mut self) -> QuickDbResult<bool> {
+        match self {
+            #[cfg(feature = "postgres-support")]
+            Self::Postgres { connection, id } => sqlx::query_scalar::<_, bool>("SELECT pg_try_advisory_lock($1)")
+                .bind(*id)
+                .fetch_one(&mut **connection)
+                .await
+                .map_err(query_failed),
+            #[cfg(feature = "mysql-support")]
+            Self::MySql { connection, name } => {
+                let acquired = sqlx::query_scalar::<_, Option<i64>>("SELECT GET_LOCK(?, 0)")
+                    .bind(name.as_str())
+                    .fetch_one(&mut **connection)
+                    .await
+                    .map_err(query_failed)?;
+                Ok(acquired == Some(1))
+            }
+            #[cfg(feature = "sqlite-support")]
+            Self::Sqlite { pool, key, owner } => {
+                let now = unix_millis();
+                sqlx::query(&format!("DELETE FROM {} WHERE lock_key = ? AND expires_at < ?", LOCK_TABLE))
+                    .bind(key.as_str())
+                    .bind(now)
+                    .execute(&*pool)
+                    .await
+                    .map_err(query_failed)?;
+                let inserted = sqlx::query(&format!(
+                    "INSERT OR IGNORE INTO {} (lock_key, owner, expires_at) VALUES (?, ?, ?)",
+                    LOCK_TABLE
+                ))
+                .bind(key.as_str())
+                .bind(owner.as_str())
+                .bind(now + LEASE_DURATION.as_millis() as i64)
+                .execute(&*pool)
+                .await
+                .map_err(query_failed)?;
+                Ok(inserted.rows_affected() == 1)
+            }
+            #[cfg(feature = "mongodb-support")]
+            Self::Mongo { database, key, owner } => {
+                use mongodb::bson::{DateTime, Document, doc};
+                use mongodb::error::{ErrorKind, WriteFailure};
+
+                let collection = database.collection::<Document>(LOCK_TABLE);
+                let now = unix_millis();
+                collection
+                    .delete_one(doc! { "_id": key.as_str(), "expires_at": { "$lt": DateTime::from_millis(now) } }, None)
+                    .await
+                    .map_err(query_failed)?;
+                let lease = doc! {
+                    "_id": key.as_str(),
+                    "owner": owner.as_str(),
+                    "expires_at": DateTime::from_millis(now + LEASE_DURATION.as_millis() as i64),
+                };
+                match collection.insert_one(lease, None).await {
+                    Ok(_) => Ok(true),
+                    Err(e) if matches!(&*e.kind, ErrorKind::Write(WriteFailure::WriteError(w)) if w.code == 11000) => {
+                        Ok(false)
+                    }
+                    Err(e) => Err(query_failed(e)),
+                }
+            }
+        }
+    }
+
+    /// 释放锁
+    async fn release(self) -> QuickDbResult<()> {
+        match self {
+            #[cfg(feature = "postgres-support")]
+            Self::Postgres { mut connection, id } => {
+                let result = sqlx::query("SELECT pg_advisory_unlock($1)")
+                    .bind(id)
+                    .execute(&mut *connection)
+                    .await;
+                if let Err(e) = result {
+                    // 解锁失败时关闭连接，会话结束后锁随之释放
+                    drop(connection.detach());
+                    return Err(query_failed(e));
+                }
+                Ok(())
+            }
+            #[cfg(feature = "mysql-support")]
+            Self::MySql { mut connection, name } => {
+                let result = sqlx::query("SELECT RELEASE_LOCK(?)")
+                    .bind(name.as_str())
+                    .execute(&mut *connection)
+                    .await;
+                if let Err(e) = result {
+                    drop(connection.detach());
+                    return Err(query_failed(e));
+                }
+                Ok(())
+            }
+            #[cfg(feature = "sqlite-support")]
+            Self::Sqlite { pool, key, owner } => {
+                sqlx::query(&format!("DELETE FROM {} WHERE lock_key = ? AND owner = ?", LOCK_TABLE))
+                    .bind(key)
+                    .bind(owner)
+                    .execute(&pool)
+                    .await
+                    .map_err(query_failed)?;
+                Ok(())
+            }
+            #[cfg(feature = "mongodb-support")]
+            Self::Mongo { database, key, owner } => {
+                use mongodb::bson::{Document, doc};
+
+                database
+                    .collection::<Document>(LOCK_TABLE)
+                    .delete_one(doc! { "_id": key, "owner": owner }, None)
+                    .await
+                    .map_err(query_failed)?;
+                Ok(())
+            }
+        }
+    }
+
+    /// 放弃会话而不执行解锁：会话锁关闭连接，租约锁等待到期
+    fn abandon(self) {
+        match self {
+            #[cfg(feature = "postgres-support")]
+            Self::Postgres { connection, .. } => drop(connection.detach()),
+            #[cfg(feature = "mysql-support")]
+            Self::MySql { connection, .. } => drop(connection.detach()),
+            #[cfg(feature = "sqlite-support")]
+            Self::Sqlite { .. } => {}
+            #[cfg(feature = "mongodb-support")]
+            Self::Mongo { .. } => {}
+        }
+    }
+
+    /// 为租约锁启动续期任务，续期失败（租约已被他人接管）时停止
+    #[cfg_attr(not(any(feature = "sqlite-support", feature = "mongodb-support")), allow(unused_variables))]
+    fn spawn_heartbeat(&self, lost: Arc<AtomicBool>) -> Option<JoinHandle<()>> {
+        let renew_every = LEASE_DURATION / 3;
+        match self {
+            #[cfg(feature = "postgres-support")]
+            Self::Postgres { .. } => None,
+            #[cfg(feature = "mysql-support")]
+            Self::MySql { .. } => None,
+            #[cfg(feature = "sqlite-support")]
+            Self::Sqlite { pool, key, owner } => {
+                let (pool, key, owner) = (pool.clone(), key.clone(), owner.clone());
+                Some(tokio::spawn(async move {
+                    let sql = format!("UPDATE {} SET expires_at = ? WHERE lock_key = ? AND owner = ?", LOCK_TABLE);
+                    loop {
+                        tokio::time::sleep(renew_every).await;
+                        let renewed = sqlx::query(&sql)
+                            .bind(unix_millis() + LEASE_DURATION.as_millis() as i64)
+                            .bind(key.as_str())
+                            .bind(owner.as_str())
+                            .execute(&pool)
+                            .await;
+                        if !matches!(renewed, Ok(ref result) if result.rows_affected() == 1) {
+                            warn!("咨询锁续期失败，停止续期: 键={}", key);
+                            lost.store(true, Ordering::Release);
+                            return;
+                        }
+                    }
+                }))
+            }
+            #[cfg(feature = "mongodb-support")]
+            Self::Mongo { database, key, owner } => {
+                let (database, key, owner) = (database.clone(), key.clone(), owner.clone());
+                Some(tokio::spawn(async move {
+                    use mongodb::bson::{DateTime, Document, doc};
+
+                    let collection = database.collection::<Document>(LOCK_TABLE);
+                    loop {
+                        tokio::time::sleep(renew_every).await;
+                        let expires_at = DateTime::from_millis(unix_millis() + LEASE_DURATION.as_millis() as i64);
+                        let renewed = collection
+                            .update_one(
+                                doc! { "_id": key.as_str(), "owner": owner.as_str() },
+                                doc! { "$set": { "expires_at": expires_at } },
+                                None,
+                            )
+                            .await;
+                        if !matches!(renewed, Ok(ref result) if result.matched_count == 1) {
+                            warn!("咨询锁续期失败，停止续期: 键={}", key);
+                            lost.store(true, Ordering::Release);
+                            return;
+                        }
+                    }
+                }))
+            }
+        }
+    }
+}
+
+fn query_failed(e: impl std::fmt::Display) -> QuickDbError {
+    QuickDbError::QueryError {
+        message: crate::i18n::tf("lock.query_failed", &[("message", &e.to_string())]),
+    }
+}
+
+/// 租约持有者标识，续期与释放只作用于自己的租约
+#[cfg(any(feature = "sqlite-support", feature = "mongodb-support"))]
+fn lease_owner() -> String {
+    uuid::Uuid::new_v4().to_string()
+}
+
+#[cfg(any(feature = "sqlite-support", feature = "mongodb-support"))]
+fn unix_millis() -> i64 {
+    chrono::Utc::now().timestamp_millis()
+}
+
+/// 64 位 FNV-1a 哈希，跨进程与版本稳定
+#[cfg_attr(not(any(feature = "postgres-support", feature = "mysql-support")), allow(dead_code))]
+fn fnv1a(key: &str) -> u64 {
+    key.bytes()
+        .fold(0xcbf2_9ce4_8422_2325, |hash, byte| (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lock_keys_hash_stably() {
+        assert_eq!(fnv1a(""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(fnv1a("a"), 0xaf63_dc4c_8601_ec8c);
+        assert_ne!(fnv1a("job:a"), fnv1a("job:b"));
+    }
+
+    #[cfg(feature = "sqlite-support")]
+    #[tokio::test]
+    async fn sqlite_leases_exclude_release_and_expire() {
+        crate::i18n::ErrorMessageI18n::init_i18n();
+        let alias = "lock_suite";
+        let manager = crate::manager::get_global_pool_manager();
+        manager
+            .add_database(crate::types::DatabaseConfig::sqlite_memory_for_test(alias))
+            .await
+            .unwrap();
+
+        // 持有期间同一键的第二次尝试立即返回 None，其他键不受影响
+        let first = try_advisory_lock(alias, "nightly").await.unwrap().unwrap();
+        assert!(try_advisory_lock(alias, "nightly").await.unwrap().is_none());
+        let other = try_advisory_lock(alias, "hourly").await.unwrap().unwrap();
+        other.release().await.unwrap();
+
+        first.release().await.unwrap();
+        let second = try_advisory_lock(alias, "nightly").await.unwrap().unwrap();
+
+        // 租约到期后可被接管，原持有者释放时不会删除接管者的租约
+        manager
+            .with_connection(Some(alias), |mut conn| async move {
+                conn.execute(&format!("UPDATE {} SET expires_at = 0", LOCK_TABLE), &[]).await
+            })
+            .await
+            .unwrap();
+        let takeover = try_advisory_lock(alias, "nightly").await.unwrap().unwrap();
+        second.release().await.unwrap();
+        assert!(try_advisory_lock(alias, "nightly").await.unwrap().is_none());
+        takeover.release().await.unwrap();
+    }
+}
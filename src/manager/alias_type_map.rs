@@ -32,6 +32,7 @@ pub fn get_database_type_by_alias(alias: &str) -> Option<DatabaseType> {
 }
 
 /// 获取所有已注册的数据库别名
+#[cfg(test)]
 pub fn get_all_registered_aliases() -> Vec<String> {
     if let Some(mapping) = DATABASE_TYPE_MAPPING.get() {
         if let Ok(map) = mapping.read() {
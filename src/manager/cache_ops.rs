@@ -1,17 +1,12 @@
 
 //! 缓存操作相关方法
 
-use crate::cache::{CacheManager, CacheStats};
+use crate::cache::{CacheManager, CacheStats, TableCacheStats, CacheWarmOutcome, CacheWarmProgress, CacheWarmReport, CacheWarmSpec};
+use crate::context::{Priority, with_priority};
 use crate::error::{QuickDbError, QuickDbResult};
-use crate::id_generator::{IdGenerator, MongoAutoIncrementGenerator};
-use crate::model::ModelMeta;
-use crate::pool::{ConnectionPool, ExtendedPoolConfig, PooledConnection};
-use crate::types::{DatabaseConfig, DatabaseType, IdType};
-use dashmap::DashMap;
-use rat_logger::{debug, error, info, warn};
+use rat_logger::{debug, info, warn};
 use std::collections::HashMap;
 use std::sync::Arc;
-use tokio::sync::RwLock;
 use tokio::time::{Duration, interval};
 
 use super::PoolManager;
@@ -35,10 +30,15 @@ impl PoolManager {
         Ok(cache_manager.get_stats().await?)
     }
 
+    /// 获取按表划分的缓存统计信息
+    pub fn get_cache_stats_by_table(&self, alias: &str) -> QuickDbResult<HashMap<String, TableCacheStats>> {
+        Ok(self.get_cache_manager(alias)?.get_table_stats())
+    }
+
     /// 清理指定数据库的缓存
     pub async fn clear_cache(&self, alias: &str) -> QuickDbResult<()> {
         let cache_manager = self.get_cache_manager(alias)?;
-        cache_manager.clear_all().await;
+        cache_manager.clear_all().await?;
         info!("已清理数据库 {} 的缓存", alias);
         Ok(())
     }
@@ -48,12 +48,81 @@ impl PoolManager {
         for entry in self.cache_managers.iter() {
             let alias = entry.key();
             let cache_manager = entry.value();
-            cache_manager.clear_all().await;
+            cache_manager.clear_all().await?;
             info!("已清理数据库 {} 的缓存", alias);
         }
         Ok(())
     }
 
+    /// 预热指定数据库的缓存
+    ///
+    /// 按顺序执行预热配置中的查询并写入 L1/L2 缓存，通常在 `add_database`
+    /// 之后、开始处理请求之前调用。预热查询以后台优先级执行，不阻塞同时到达的用户查询。
+    /// 单个查询失败不会中断预热，计入汇总的 `failed`
+    pub async fn warm_cache(&self, alias: &str, spec: &CacheWarmSpec) -> QuickDbResult<CacheWarmReport> {
+        self.warm_cache_with_progress(alias, spec, |_| {}).await
+    }
+
+    /// 预热指定数据库的缓存，每完成一个查询回调一次进度
+    pub async fn warm_cache_with_progress<F>(
+        &self,
+        alias: &str,
+        spec: &CacheWarmSpec,
+        mut on_progress: F,
+    ) -> QuickDbResult<CacheWarmReport>
+    where
+        F: FnMut(&CacheWarmProgress),
+    {
+        let cache_manager = self.get_cache_manager(alias)?;
+        let pool = self
+            .pools
+            .get(alias)
+            .map(|pool| pool.clone())
+            .ok_or_else(|| QuickDbError::AliasNotFound {
+                alias: alias.to_string(),
+            })?;
+
+        let mut report = CacheWarmReport {
+            total: spec.queries.len(),
+            ..Default::default()
+        };
+
+        for (index, query) in spec.queries.iter().enumerate() {
+            // 与正常查询一致地改写空值条件，保证缓存键相同
+            let mut condition_groups = query.condition_groups.clone();
+            query.options.null_semantics.normalize_groups(&mut condition_groups);
+
+            let outcome = cache_manager
+                .warm(&query.table, &condition_groups, &query.options, || {
+                    with_priority(
+                        Priority::Background,
+                        pool.find_with_groups(&query.table, &condition_groups, &query.options, true),
+                    )
+                })
+                .await
+                .unwrap_or_else(|e| {
+                    warn!("缓存预热查询失败: 别名={}, 表={}, 错误={}", alias, query.table, e);
+                    CacheWarmOutcome::Failed {
+                        message: e.to_string(),
+                    }
+                });
+
+            report.record(&outcome);
+            on_progress(&CacheWarmProgress {
+                completed: index + 1,
+                total: report.total,
+                table: query.table.clone(),
+                outcome,
+            });
+        }
+
+        info!(
+            "数据库 {} 缓存预热完成: 共{}个查询, 加载{}, 已缓存{}, 失败{}",
+            alias, report.total, report.loaded, report.already_cached, report.failed
+        );
+        Ok(report)
+    }
+
     /// 启动清理任务
     pub(crate) async fn start_cleanup_task(&self) {
         let mut cleanup_handle = self.cleanup_handle.write().await;
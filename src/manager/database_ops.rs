@@ -1,19 +1,16 @@
 
 //! 数据库操作相关方法
 
-use crate::cache::{CacheManager, CacheStats};
-use crate::error::{QuickDbError, QuickDbResult};
+use crate::cache::CacheManager;
+use crate::config::DatabaseFileConfig;
+use crate::error::QuickDbResult;
 use crate::id_generator::{IdGenerator, MongoAutoIncrementGenerator};
-use crate::model::ModelMeta;
 use crate::pool::{ConnectionPool, ExtendedPoolConfig, PooledConnection};
 use crate::types::id_types::IdStrategy;
-use crate::types::{DatabaseConfig, DatabaseType, IdType};
+use crate::types::{DatabaseConfig, DatabaseType};
 use dashmap::DashMap;
 use rat_logger::{debug, error, info, warn};
-use std::collections::HashMap;
 use std::sync::Arc;
-use tokio::sync::RwLock;
-use tokio::time::{Duration, interval};
 
 use super::PoolManager;
 
@@ -37,6 +34,16 @@ impl PoolManager {
                 e
             })?;
             let cache_manager_arc = Arc::new(cache_manager);
+            // 先于数据库注册的模型，补充其表级缓存策略
+            let model_prefix = format!("{}:", alias);
+            for entry in self.model_registry.iter() {
+                if !entry.key().starts_with(&model_prefix) {
+                    continue;
+                }
+                if let Some(policy) = &entry.value().cache_policy {
+                    cache_manager_arc.set_table_policy(&entry.value().qualified_name(), policy.clone());
+                }
+            }
             // 保存到管理器中
             self.cache_managers
                 .insert(alias.clone(), cache_manager_arc.clone());
@@ -56,6 +63,42 @@ impl PoolManager {
                     e
                 })?;
 
+        self.install_pool(config, pool).await
+    }
+
+    /// 以模拟适配器注册数据库（`testing` 特性）
+    ///
+    /// 该别名下的所有操作由 `MockAdapter` 在内存中完成，不建立任何数据库连接。
+    /// 别名按 SQLite 语义（`id` 主键、自增 ID）处理，已存在的同名数据库会被替换
+    #[cfg(feature = "testing")]
+    pub async fn add_mock_database(&self, alias: &str, adapter: crate::adapter::MockAdapter) -> QuickDbResult<()> {
+        info!("添加模拟数据库: 别名={}", alias);
+        if self.pools.contains_key(alias) {
+            warn!("数据库别名已存在，将替换现有配置: {}", alias);
+            self.remove_database(alias).await?;
+        }
+
+        let config = DatabaseConfig {
+            db_type: DatabaseType::SQLite,
+            connection: crate::types::ConnectionConfig::sqlite_memory(alias),
+            pool: crate::types::PoolConfig::default(),
+            alias: alias.to_string(),
+            cache: None,
+            id_strategy: IdStrategy::AutoIncrement,
+            version_storage_path: None,
+            enable_versioning: None,
+            on_connect_statements: Vec::new(),
+            table_naming: None,
+        };
+        let pool_config = ExtendedPoolConfig::from_pool_config(config.pool.clone());
+        let pool = ConnectionPool::with_mock_adapter(config.clone(), pool_config, adapter);
+        self.install_pool(config, pool).await
+    }
+
+    /// 登记已创建的连接池：初始化ID生成器、默认别名与别名类型映射
+    async fn install_pool(&self, config: DatabaseConfig, pool: ConnectionPool) -> QuickDbResult<()> {
+        let alias = config.alias.clone();
+
         // 添加到管理器
         self.pools.insert(alias.clone(), Arc::new(pool));
 
@@ -89,7 +132,7 @@ impl PoolManager {
         }
 
         // 注册到全局别名类型映射
-        crate::manager::register_database_alias(alias.clone(), config.db_type.clone())?;
+        crate::manager::register_database_alias(alias.clone(), config.db_type)?;
 
         // 启动清理任务（如果还没有启动）
         self.start_cleanup_task().await;
@@ -97,17 +140,49 @@ impl PoolManager {
         info!("数据库添加成功: 别名={}", alias);
         Ok(())
     }
+    /// 按配置文件内容批量添加数据库
+    ///
+    /// 按文件中的顺序逐个添加，任一数据库添加失败即返回错误（已添加的保留）；
+    /// 配置了 `default_database` 时在全部添加完成后设置为默认别名。
+    /// 返回添加成功的别名列表
+    pub async fn add_databases_from_config(&self, config: DatabaseFileConfig) -> QuickDbResult<Vec<String>> {
+        config.validate()?;
+
+        let mut aliases = Vec::with_capacity(config.databases.len());
+        for database in config.databases {
+            let alias = database.alias.clone();
+            self.add_database(database).await?;
+            aliases.push(alias);
+        }
+
+        if let Some(alias) = &config.default_database {
+            self.set_default_alias(alias).await?;
+        }
+
+        info!("已从配置添加数据库: {:?}", aliases);
+        Ok(aliases)
+    }
+
     /// 移除数据库配置
     pub async fn remove_database(&self, alias: &str) -> QuickDbResult<()> {
         info!("移除数据库配置: 别名={}", alias);
 
         if let Some((_, _pool)) = self.pools.remove(alias) {
+            crate::adapter::statement_cache::remove_statement_cache(alias);
+            #[cfg(any(feature = "sqlite-support", feature = "postgres-support", feature = "mysql-support"))]
+            crate::outbox::forget_alias(alias);
+
             // 清理ID生成器
             self.id_generators.remove(alias);
             self.mongo_auto_increment_generators.remove(alias);
+            let sequence_prefix = format!("{}:", alias);
+            self.mongo_sequence_generators
+                .retain(|key, _| !key.starts_with(&sequence_prefix));
+            self.stored_procedures
+                .retain(|key, _| !key.starts_with(&sequence_prefix));
 
             // 清理缓存管理器
-            if let Some((_, cache_manager)) = self.cache_managers.remove(alias) {
+            if let Some((_, _cache_manager)) = self.cache_managers.remove(alias) {
                 // 这里可以添加缓存清理逻辑
                 info!("清理数据库 {} 的缓存管理器", alias);
             }
@@ -135,19 +210,7 @@ impl PoolManager {
 
     /// 获取数据库连接
     pub async fn get_connection(&self, alias: Option<&str>) -> QuickDbResult<PooledConnection> {
-        let target_alias = match alias {
-            Some(a) => a.to_string(),
-            None => {
-                // 使用默认别名
-                let default_alias = self.default_alias.read().await;
-                match default_alias.as_ref() {
-                    Some(a) => a.clone(),
-                    None => {
-                        return Err(crate::quick_error!(config, crate::i18n::t("manager.no_default_alias")));
-                    }
-                }
-            }
-        };
+        let target_alias = self.resolve_alias(alias).await?;
 
         if let Some(pool) = self.pools.get(&target_alias) {
             pool.get_connection().await
@@ -156,6 +219,19 @@ impl PoolManager {
         }
     }
 
+    /// 解析数据库别名，`None` 时使用默认别名
+    pub(crate) async fn resolve_alias(&self, alias: Option<&str>) -> QuickDbResult<String> {
+        match alias {
+            Some(a) => Ok(a.to_string()),
+            None => self
+                .default_alias
+                .read()
+                .await
+                .clone()
+                .ok_or_else(|| crate::quick_error!(config, crate::i18n::t("manager.no_default_alias"))),
+        }
+    }
+
     /// 释放连接
     pub async fn release_connection(&self, connection: &PooledConnection) -> QuickDbResult<()> {
         debug!(
@@ -196,7 +272,7 @@ impl PoolManager {
     /// 获取数据库类型
     pub fn get_database_type(&self, alias: &str) -> QuickDbResult<DatabaseType> {
         if let Some(pool) = self.pools.get(alias) {
-            Ok(pool.get_database_type().clone())
+            Ok(*pool.get_database_type())
         } else {
             Err(crate::quick_error!(alias_not_found, alias))
         }
@@ -238,6 +314,39 @@ impl PoolManager {
         }
     }
 
+    /// 获取MongoDB序列字段生成器（尚未初始化时返回None）
+    #[cfg(feature = "mongodb-support")]
+    pub(crate) fn get_mongo_sequence_generator(
+        &self,
+        alias: &str,
+        sequence_name: &str,
+    ) -> Option<Arc<MongoAutoIncrementGenerator>> {
+        self.mongo_sequence_generators
+            .get(&format!("{}:{}", alias, sequence_name))
+            .map(|generator| generator.clone())
+    }
+
+    /// 初始化MongoDB序列字段生成器
+    ///
+    /// 并发初始化时以先插入者为准，`start` 为首个分配的值
+    #[cfg(feature = "mongodb-support")]
+    pub(crate) fn init_mongo_sequence_generator(
+        &self,
+        alias: &str,
+        sequence_name: &str,
+        start: u64,
+    ) -> Arc<MongoAutoIncrementGenerator> {
+        self.mongo_sequence_generators
+            .entry(format!("{}:{}", alias, sequence_name))
+            .or_insert_with(|| {
+                let generator = MongoAutoIncrementGenerator::new(sequence_name.to_string());
+                generator.set_start_value(start);
+                debug!("为MongoDB数据库 {} 创建序列生成器 {}，起始值 {}", alias, sequence_name, start);
+                Arc::new(generator)
+            })
+            .clone()
+    }
+
     /// 获取连接池映射的引用
     pub fn get_connection_pools(&self) -> Arc<DashMap<String, Arc<ConnectionPool>>> {
         self.pools.clone()
@@ -0,0 +1,133 @@
+//! 查询防护配置相关方法
+
+use crate::config::{QueryGuardrails, RowLimitAction};
+use crate::error::QuickDbResult;
+use crate::types::{PaginationConfig, QueryOptions};
+use rat_logger::{debug, warn};
+
+use super::PoolManager;
+
+impl PoolManager {
+    /// 设置数据库的查询防护配置，同一别名的配置将被替换
+    pub fn set_query_guardrails(&self, alias: &str, guardrails: QueryGuardrails) {
+        debug!("设置查询防护配置: 别名={}, 配置={:?}", alias, guardrails);
+        self.query_guardrails.insert(alias.to_string(), guardrails);
+    }
+
+    /// 获取数据库的查询防护配置
+    pub fn get_query_guardrails(&self, alias: &str) -> Option<QueryGuardrails> {
+        self.query_guardrails.get(alias).map(|guardrails| guardrails.clone())
+    }
+
+    /// 获取请求所用别名（未指定时为默认别名）的查询防护配置
+    async fn resolve_query_guardrails(&self, alias: Option<&str>) -> Option<QueryGuardrails> {
+        if self.query_guardrails.is_empty() {
+            return None;
+        }
+        match alias {
+            Some(alias) => self.get_query_guardrails(alias),
+            None => self.get_query_guardrails(&self.get_default_alias().await?),
+        }
+    }
+
+    /// 对无分页查询应用最大记录数限制
+    ///
+    /// 截断模式直接下推为分页；拒绝模式多取一条记录，返回需校验的上限
+    pub(crate) async fn apply_row_limit(
+        &self,
+        alias: Option<&str>,
+        options: Option<QueryOptions>,
+    ) -> (Option<QueryOptions>, Option<u64>) {
+        if options.as_ref().is_some_and(|options| options.pagination.is_some()) {
+            return (options, None);
+        }
+        let Some(max_rows) = self
+            .resolve_query_guardrails(alias)
+            .await
+            .and_then(|guardrails| guardrails.max_result_rows.map(|max_rows| (max_rows, guardrails.on_row_limit)))
+        else {
+            return (options, None);
+        };
+
+        let (limit, reject_above) = match max_rows {
+            (max_rows, RowLimitAction::Truncate) => (max_rows, None),
+            (max_rows, RowLimitAction::Reject) => (max_rows.saturating_add(1), Some(max_rows)),
+        };
+        let mut options = options.unwrap_or_default();
+        options.pagination = Some(PaginationConfig { skip: 0, limit });
+        (Some(options), reject_above)
+    }
+
+    /// 校验拒绝模式下的查询结果未超过最大记录数
+    pub(crate) fn check_row_limit(collection: &str, rows: usize, reject_above: Option<u64>) -> QuickDbResult<()> {
+        match reject_above {
+            Some(max_rows) if rows as u64 > max_rows => {
+                warn!("无分页查询超过最大记录数: 表={}, 上限={}", collection, max_rows);
+                Err(crate::quick_error!(
+                    query,
+                    crate::i18n::tf(
+                        "guardrail.row_limit_exceeded",
+                        &[("table", collection), ("max_rows", &max_rows.to_string())]
+                    )
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// 校验删除条件：启用 `require_conditions_for_delete` 时禁止空条件删除
+    pub(crate) async fn check_delete_conditions(
+        &self,
+        collection: &str,
+        alias: Option<&str>,
+        has_conditions: bool,
+    ) -> QuickDbResult<()> {
+        if has_conditions {
+            return Ok(());
+        }
+        match self.resolve_query_guardrails(alias).await {
+            Some(guardrails) if guardrails.require_conditions_for_delete => Err(crate::quick_error!(
+                validation,
+                "conditions",
+                crate::i18n::tf("guardrail.delete_requires_conditions", &[("table", collection)])
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn row_limit_only_applies_to_unpaginated_queries() {
+        let manager = PoolManager::new();
+        manager.set_query_guardrails("guarded", QueryGuardrails::new().max_result_rows(10, RowLimitAction::Reject));
+        manager.set_query_guardrails(
+            "truncated",
+            QueryGuardrails::new()
+                .max_result_rows(5, RowLimitAction::Truncate)
+                .require_conditions_for_delete(true),
+        );
+
+        let (options, reject_above) = manager.apply_row_limit(Some("guarded"), None).await;
+        assert_eq!(options.and_then(|options| options.pagination).map(|p| p.limit), Some(11));
+        assert_eq!(reject_above, Some(10));
+        assert!(PoolManager::check_row_limit("users", 10, reject_above).is_ok());
+        assert!(PoolManager::check_row_limit("users", 11, reject_above).is_err());
+
+        let (options, reject_above) = manager.apply_row_limit(Some("truncated"), None).await;
+        assert_eq!(options.and_then(|options| options.pagination).map(|p| p.limit), Some(5));
+        assert_eq!(reject_above, None);
+
+        let paginated = QueryOptions::new().with_pagination(PaginationConfig { skip: 0, limit: 100 });
+        let (options, reject_above) = manager.apply_row_limit(Some("guarded"), Some(paginated)).await;
+        assert_eq!(options.and_then(|options| options.pagination).map(|p| p.limit), Some(100));
+        assert_eq!(reject_above, None);
+
+        assert!(manager.check_delete_conditions("users", Some("truncated"), false).await.is_err());
+        assert!(manager.check_delete_conditions("users", Some("truncated"), true).await.is_ok());
+        assert!(manager.check_delete_conditions("users", Some("guarded"), false).await.is_ok());
+    }
+}
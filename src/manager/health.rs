@@ -0,0 +1,130 @@
+//! 健康检查相关方法
+
+use crate::pool::{ConnectionPool, PoolStatus, with_timeout};
+use futures::future::join_all;
+use rat_logger::{debug, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+
+use super::PoolManager;
+
+/// 单个数据库的健康检查结果
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HealthReport {
+    /// 探测是否在超时时间内成功
+    pub reachable: bool,
+    /// 探测耗时（毫秒）
+    pub latency_ms: f64,
+    /// 数据库服务器版本，探测失败时为空
+    pub version: Option<String>,
+    /// 连接池运行时状态
+    pub pool: PoolStatus,
+    /// 探测失败的错误信息，探测成功时为空
+    pub last_error: Option<String>,
+}
+
+impl PoolManager {
+    /// 并发探测所有数据库：查询服务器版本并计时，超时时间取连接池的 `health_check_timeout_sec`
+    pub async fn health_check(&self) -> HashMap<String, HealthReport> {
+        let pools: Vec<(String, Arc<ConnectionPool>)> = self
+            .pools
+            .iter()
+            .map(|entry| (entry.key().clone(), entry.value().clone()))
+            .collect();
+
+        join_all(pools.into_iter().map(|(alias, pool)| async move {
+            let report = probe(&pool).await;
+            match &report.last_error {
+                Some(error) => warn!("健康检查失败: 别名={}, 错误={}", alias, error),
+                None => debug!("健康检查通过: 别名={}, 耗时={:.2}毫秒", alias, report.latency_ms),
+            }
+            (alias, report)
+        }))
+        .await
+        .into_iter()
+        .collect()
+    }
+}
+
+/// 探测一个连接池
+async fn probe(pool: &ConnectionPool) -> HealthReport {
+    let timeout = Duration::from_secs(pool.config.health_check_timeout_sec.max(1));
+    let started = Instant::now();
+    let result = with_timeout(Some(timeout), pool.server_version()).await;
+    let latency_ms = started.elapsed().as_secs_f64() * 1000.0;
+    let (version, last_error) = match result {
+        Ok(version) => (Some(version), None),
+        Err(e) => (None, Some(e.to_string())),
+    };
+    HealthReport {
+        reachable: last_error.is_none(),
+        latency_ms,
+        version,
+        pool: pool.status(),
+        last_error,
+    }
+}
+
+/// 周期健康检查任务的句柄，丢弃句柄时任务随之停止
+#[derive(Debug)]
+pub struct HealthCheckHandle {
+    shutdown: watch::Sender<bool>,
+    task: JoinHandle<()>,
+}
+
+impl HealthCheckHandle {
+    /// 停止周期健康检查并等待当前一轮结束
+    pub async fn shutdown(self) {
+        let _ = self.shutdown.send(true);
+        let _ = self.task.await;
+    }
+}
+
+/// 每隔 `interval` 检查一次所有数据库，并把结果交给 `callback`
+pub fn start_periodic_health_checks<F>(interval: Duration, callback: F) -> HealthCheckHandle
+where
+    F: Fn(HashMap<String, HealthReport>) + Send + Sync + 'static,
+{
+    let (shutdown, mut shutdown_rx) = watch::channel(false);
+    let task = tokio::spawn(async move {
+        debug!("启动周期健康检查: 间隔={:?}", interval);
+        loop {
+            callback(super::get_global_pool_manager().health_check().await);
+            tokio::select! {
+                changed = shutdown_rx.changed() => if changed.is_err() || *shutdown_rx.borrow() {
+                    break;
+                },
+                _ = tokio::time::sleep(interval) => {}
+            }
+        }
+        debug!("周期健康检查已停止");
+    });
+    HealthCheckHandle { shutdown, task }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[tokio::test]
+    async fn periodic_checks_report_until_shutdown() {
+        let runs = Arc::new(AtomicUsize::new(0));
+        let counter = runs.clone();
+        let handle = start_periodic_health_checks(Duration::from_millis(10), move |reports| {
+            assert!(reports.values().all(|report| report.reachable || report.last_error.is_some()));
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        handle.shutdown().await;
+
+        let stopped_at = runs.load(Ordering::SeqCst);
+        assert!(stopped_at >= 2);
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        assert_eq!(runs.load(Ordering::SeqCst), stopped_at);
+    }
+}
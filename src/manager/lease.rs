@@ -0,0 +1,507 @@
+//! 连接租用相关方法
+//!
+//! 在闭包执行期间独占连接池中的一个数据库连接，闭包内的语句在同一数据库会话上执行，
+//! 临时表、会话变量与事务在整个闭包内可见：
+//!
+//! ```ignore
+//! let total = manager::with_connection(Some("default"), |mut conn| async move {
+//!     conn.execute("CREATE TEMP TABLE picked (id INTEGER)", &[]).await?;
+//!     conn.execute("INSERT INTO picked SELECT id FROM orders WHERE status = ?", &[DataValue::from("paid")]).await?;
+//!     conn.query("SELECT COUNT(*) AS total FROM picked", &[]).await
+//! })
+//! .await?;
+//! ```
+//!
+//! 租用内的语句直接执行 SQL，不经过 ODM 的校验与缓存，写入涉及的表需要调用方自行清理缓存。
+//! 连接在闭包返回（或被取消）时归还连接池。支持 SQLite、PostgreSQL 与 MySQL
+//!
+//! ## 事务
+//!
+//! [`LeasedConnection::begin`] 在租用的连接上开启事务，[`PoolManager::begin_transaction`]
+//! 租用连接并直接开启事务，返回的连接可以跨越闭包持有。行级锁只在事务内有意义：
+//! [`LeasedConnection::find`] 的 `options.lock` 锁定读取的行，直到事务提交或回滚：
+//!
+//! ```ignore
+//! let mut tx = manager::begin_transaction(Some("default")).await?;
+//! let options = QueryOptions::new().with_lock(RowLock::ForUpdate);
+//! let accounts = tx.find("accounts", &conditions, &options).await?;
+//! tx.update("accounts", conditions, balance_data).await?;
+//! tx.commit().await?;
+//! ```
+//!
+//! 事务内的 `create` / `update` / `delete` 与发件箱事务一样直接写入，提交后清理涉及表的缓存。
+//! 连接在事务未结束时被丢弃，不会归还连接池，而是直接关闭，由数据库回滚未提交的事务
+
+use crate::adapter::session;
+use crate::adapter::transaction::TransactionWrite;
+use crate::cache::CacheManager;
+use crate::error::{QuickDbError, QuickDbResult};
+use crate::pool::DatabaseConnection;
+use crate::types::*;
+use rat_logger::debug;
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::sync::Arc;
+
+use super::PoolManager;
+
+/// 租用的数据库连接，在 [`PoolManager::with_connection`] 的闭包内使用，
+/// 或由 [`PoolManager::begin_transaction`] 返回
+pub struct LeasedConnection {
+    alias: String,
+    session: LeasedSession,
+    cache_manager: Option<Arc<CacheManager>>,
+    in_transaction: bool,
+    /// 事务内写入过的表，提交后清理缓存
+    touched_tables: HashSet<String>,
+    /// 别名的表名装饰规则，读写前把逻辑表名转换为物理表名
+    table_naming: Option<TableNaming>,
+}
+
+/// 从连接池借出的独占连接
+enum LeasedSession {
+    #[cfg(feature = "sqlite-support")]
+    Sqlite(sqlx::pool::PoolConnection<sqlx::Sqlite>),
+    #[cfg(feature = "postgres-support")]
+    Postgres(sqlx::pool::PoolConnection<sqlx::Postgres>),
+    #[cfg(feature = "mysql-support")]
+    MySql(sqlx::pool::PoolConnection<sqlx::MySql>),
+}
+
+impl std::fmt::Debug for LeasedConnection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LeasedConnection")
+            .field("alias", &self.alias)
+            .field("db_type", &self.db_type())
+            .field("in_transaction", &self.in_transaction)
+            .finish_non_exhaustive()
+    }
+}
+
+impl LeasedConnection {
+    /// 数据库别名
+    pub fn alias(&self) -> &str {
+        &self.alias
+    }
+
+    /// 数据库类型
+    pub fn db_type(&self) -> DatabaseType {
+        match &self.session {
+            #[cfg(feature = "sqlite-support")]
+            LeasedSession::Sqlite(_) => DatabaseType::SQLite,
+            #[cfg(feature = "postgres-support")]
+            LeasedSession::Postgres(_) => DatabaseType::PostgreSQL,
+            #[cfg(feature = "mysql-support")]
+            LeasedSession::MySql(_) => DatabaseType::MySQL,
+        }
+    }
+
+    /// 执行语句，返回受影响行数；参数占位符使用数据库自身的语法（`?` 或 `$1`）
+    pub async fn execute(&mut self, sql: &str, params: &[DataValue]) -> QuickDbResult<u64> {
+        match &mut self.session {
+            #[cfg(feature = "sqlite-support")]
+            LeasedSession::Sqlite(connection) => session::execute_sqlite(connection, sql, params).await,
+            #[cfg(feature = "postgres-support")]
+            LeasedSession::Postgres(connection) => session::execute_postgres(connection, sql, params).await,
+            #[cfg(feature = "mysql-support")]
+            LeasedSession::MySql(connection) => session::execute_mysql(connection, sql, params).await,
+        }
+    }
+
+    /// 执行查询，每行结果以 `DataValue::Object` 返回
+    pub async fn query(&mut self, sql: &str, params: &[DataValue]) -> QuickDbResult<Vec<DataValue>> {
+        match &mut self.session {
+            #[cfg(feature = "sqlite-support")]
+            LeasedSession::Sqlite(connection) => session::query_sqlite(connection, sql, params).await,
+            #[cfg(feature = "postgres-support")]
+            LeasedSession::Postgres(connection) => session::query_postgres(connection, sql, params).await,
+            #[cfg(feature = "mysql-support")]
+            LeasedSession::MySql(connection) => session::query_mysql(connection, sql, params).await,
+        }
+    }
+
+    /// 按条件查询表中的记录，每行结果以 `DataValue::Object` 返回
+    ///
+    /// 与 ODM 查询不同，`options.lock` 在这里生效：PostgreSQL 与 MySQL 追加行级锁子句，
+    /// 锁持续到 [`Self::begin`] 开启的事务结束。表名与写入一样按别名的表名装饰规则转换；
+    /// 查询不展开模型作用域，也不经过缓存；SQLite 没有行级锁，忽略该选项
+    pub async fn find(
+        &mut self,
+        table: &str,
+        conditions: &[QueryConditionWithConfig],
+        options: &QueryOptions,
+    ) -> QuickDbResult<Vec<DataValue>> {
+        let mut conditions = conditions.to_vec();
+        options.null_semantics.normalize_conditions(&mut conditions);
+        let physical = self.physical_table_name(table);
+        match &mut self.session {
+            #[cfg(feature = "sqlite-support")]
+            LeasedSession::Sqlite(connection) => {
+                session::find_sqlite(connection, &physical, &self.alias, &conditions, options).await
+            }
+            #[cfg(feature = "postgres-support")]
+            LeasedSession::Postgres(connection) => {
+                session::find_postgres(connection, &physical, &self.alias, &conditions, options).await
+            }
+            #[cfg(feature = "mysql-support")]
+            LeasedSession::MySql(connection) => {
+                session::find_mysql(connection, &physical, &self.alias, &conditions, options).await
+            }
+        }
+    }
+
+    /// 插入记录，返回受影响行数（数据原样写入，不经过 ODM 的 ID 生成与校验）
+    pub async fn create(&mut self, table: &str, data: HashMap<String, DataValue>) -> QuickDbResult<u64> {
+        self.write(table, TransactionWrite::Insert(data)).await
+    }
+
+    /// 按条件更新记录，返回受影响行数
+    pub async fn update(
+        &mut self,
+        table: &str,
+        conditions: Vec<QueryConditionWithConfig>,
+        data: HashMap<String, DataValue>,
+    ) -> QuickDbResult<u64> {
+        self.write(table, TransactionWrite::Update { conditions, data }).await
+    }
+
+    /// 按条件删除记录，返回受影响行数
+    pub async fn delete(&mut self, table: &str, conditions: Vec<QueryConditionWithConfig>) -> QuickDbResult<u64> {
+        self.write(table, TransactionWrite::Delete(conditions)).await
+    }
+
+    /// 是否处于事务中
+    pub fn in_transaction(&self) -> bool {
+        self.in_transaction
+    }
+
+    /// 在租用的连接上开启事务，已在事务中时返回事务错误
+    pub async fn begin(&mut self) -> QuickDbResult<()> {
+        if self.in_transaction {
+            return Err(QuickDbError::TransactionError {
+                message: crate::i18n::t("lease.transaction_already_started"),
+            });
+        }
+        self.transaction_statement("BEGIN").await?;
+        self.in_transaction = true;
+        debug!("开启租用连接事务: 别名={}", self.alias);
+        Ok(())
+    }
+
+    /// 提交事务，并清理事务内写入过的表的缓存
+    pub async fn commit(&mut self) -> QuickDbResult<()> {
+        self.ensure_transaction()?;
+        self.transaction_statement("COMMIT").await?;
+        self.in_transaction = false;
+        debug!("提交租用连接事务: 别名={}", self.alias);
+        let tables = std::mem::take(&mut self.touched_tables);
+        self.clear_cache(&tables).await;
+        Ok(())
+    }
+
+    /// 回滚事务
+    pub async fn rollback(&mut self) -> QuickDbResult<()> {
+        self.ensure_transaction()?;
+        self.transaction_statement("ROLLBACK").await?;
+        self.in_transaction = false;
+        self.touched_tables.clear();
+        debug!("回滚租用连接事务: 别名={}", self.alias);
+        Ok(())
+    }
+
+    fn ensure_transaction(&self) -> QuickDbResult<()> {
+        if self.in_transaction {
+            Ok(())
+        } else {
+            Err(QuickDbError::TransactionError {
+                message: crate::i18n::t("lease.transaction_not_started"),
+            })
+        }
+    }
+
+    /// 以文本协议执行事务控制语句
+    async fn transaction_statement(&mut self, statement: &'static str) -> QuickDbResult<()> {
+        let result = match &mut self.session {
+            #[cfg(feature = "sqlite-support")]
+            LeasedSession::Sqlite(connection) => sqlx::Executor::execute(&mut **connection, statement).await.map(drop),
+            #[cfg(feature = "postgres-support")]
+            LeasedSession::Postgres(connection) => {
+                sqlx::Executor::execute(&mut **connection, statement).await.map(drop)
+            }
+            #[cfg(feature = "mysql-support")]
+            LeasedSession::MySql(connection) => sqlx::Executor::execute(&mut **connection, statement).await.map(drop),
+        };
+        result.map_err(|e| QuickDbError::TransactionError {
+            message: crate::i18n::tf(
+                "lease.transaction_failed",
+                &[("statement", statement), ("message", &e.to_string())],
+            ),
+        })
+    }
+
+    /// 按别名的表名装饰规则转换逻辑表名
+    fn physical_table_name(&self, table: &str) -> String {
+        match &self.table_naming {
+            Some(naming) => naming.decorate(table),
+            None => table.to_string(),
+        }
+    }
+
+    async fn write(&mut self, table: &str, write: TransactionWrite) -> QuickDbResult<u64> {
+        let physical = self.physical_table_name(table);
+        let affected = match &mut self.session {
+            #[cfg(feature = "sqlite-support")]
+            LeasedSession::Sqlite(connection) => {
+                crate::adapter::transaction::execute_sqlite_write(connection, &physical, &self.alias, &write).await?
+            }
+            #[cfg(feature = "postgres-support")]
+            LeasedSession::Postgres(connection) => {
+                crate::adapter::transaction::execute_postgres_write(connection, &physical, &self.alias, &write).await?
+            }
+            #[cfg(feature = "mysql-support")]
+            LeasedSession::MySql(connection) => {
+                crate::adapter::transaction::execute_mysql_write(connection, &physical, &self.alias, &write).await?
+            }
+        };
+        // 事务外的写入立即生效，直接清理缓存
+        if self.in_transaction {
+            self.touched_tables.insert(table.to_string());
+        } else {
+            self.clear_cache(&HashSet::from([table.to_string()])).await;
+        }
+        Ok(affected)
+    }
+
+    /// 写入绕过了ODM层，需要同步清理涉及表的缓存
+    async fn clear_cache(&self, tables: &HashSet<String>) {
+        if let Some(cache_manager) = &self.cache_manager {
+            for table in tables {
+                let _ = cache_manager.clear_table_record_cache(table).await;
+                let _ = cache_manager.clear_table_query_cache(table).await;
+            }
+        }
+    }
+}
+
+impl Drop for LeasedConnection {
+    fn drop(&mut self) {
+        if !self.in_transaction {
+            return;
+        }
+        // 事务未结束的连接不能归还连接池，关闭连接由数据库回滚事务
+        debug!("租用连接在事务中被丢弃，关闭连接: 别名={}", self.alias);
+        match &mut self.session {
+            #[cfg(feature = "sqlite-support")]
+            LeasedSession::Sqlite(connection) => connection.close_on_drop(),
+            #[cfg(feature = "postgres-support")]
+            LeasedSession::Postgres(connection) => connection.close_on_drop(),
+            #[cfg(feature = "mysql-support")]
+            LeasedSession::MySql(connection) => connection.close_on_drop(),
+        }
+    }
+}
+
+impl PoolManager {
+    /// 租用一个数据库连接执行闭包，闭包内的语句在同一数据库会话上执行
+    ///
+    /// 连接在闭包返回后归还连接池；`alias` 为 `None` 时使用默认别名
+    pub async fn with_connection<F, Fut, T>(&self, alias: Option<&str>, operation: F) -> QuickDbResult<T>
+    where
+        F: FnOnce(LeasedConnection) -> Fut,
+        Fut: Future<Output = QuickDbResult<T>>,
+    {
+        let connection = self.lease(alias).await?;
+        let alias = connection.alias.clone();
+        debug!("租用数据库连接: 别名={}", alias);
+        let result = operation(connection).await;
+        debug!("归还租用的数据库连接: 别名={}", alias);
+        result
+    }
+
+    /// 租用一个数据库连接并开启事务
+    ///
+    /// 返回的连接需要调用 [`LeasedConnection::commit`] 提交，未提交就丢弃时事务回滚
+    pub async fn begin_transaction(&self, alias: Option<&str>) -> QuickDbResult<LeasedConnection> {
+        let mut connection = self.lease(alias).await?;
+        connection.begin().await?;
+        Ok(connection)
+    }
+
+    async fn lease(&self, alias: Option<&str>) -> QuickDbResult<LeasedConnection> {
+        let alias = self.resolve_alias(alias).await?;
+        let pool = self
+            .pools
+            .get(&alias)
+            .map(|pool| pool.clone())
+            .ok_or_else(|| crate::quick_error!(alias_not_found, &alias))?;
+
+        let session = match pool.database_connection().await? {
+            #[cfg(feature = "sqlite-support")]
+            DatabaseConnection::SQLite(pool) => LeasedSession::Sqlite(pool.acquire().await.map_err(lease_failed)?),
+            #[cfg(feature = "postgres-support")]
+            DatabaseConnection::PostgreSQL(pool) => {
+                LeasedSession::Postgres(pool.acquire().await.map_err(lease_failed)?)
+            }
+            #[cfg(feature = "mysql-support")]
+            DatabaseConnection::MySQL(pool) => LeasedSession::MySql(pool.acquire().await.map_err(lease_failed)?),
+            #[allow(unreachable_patterns)]
+            _ => {
+                return Err(QuickDbError::UnsupportedDatabase {
+                    db_type: crate::i18n::tf("lease.unsupported", &[("db_type", &format!("{:?}", pool.db_type))]),
+                });
+            }
+        };
+
+        Ok(LeasedConnection {
+            cache_manager: self.cache_managers.get(&alias).map(|cm| cm.clone()),
+            alias,
+            session,
+            in_transaction: false,
+            touched_tables: HashSet::new(),
+            table_naming: pool.db_config.table_naming.clone(),
+        })
+    }
+}
+
+fn lease_failed(e: sqlx::Error) -> QuickDbError {
+    QuickDbError::ConnectionError {
+        message: crate::i18n::tf("lease.acquire_failed", &[("message", &e.to_string())]),
+    }
+}
+
+#[cfg(all(test, feature = "sqlite-support"))]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn temp_table_is_visible_for_the_whole_lease() {
+        crate::i18n::ErrorMessageI18n::init_i18n();
+        let manager = PoolManager::new();
+        let alias = "lease_suite".to_string();
+        manager
+            .add_database(DatabaseConfig::sqlite_memory_for_test(&alias))
+            .await
+            .unwrap();
+
+        let rows = manager
+            .with_connection(Some(&alias), |mut conn| async move {
+                conn.execute("CREATE TEMP TABLE picked (id INTEGER)", &[]).await?;
+                conn.execute("INSERT INTO picked VALUES (?), (?)", &[DataValue::Int(1), DataValue::Int(2)])
+                    .await?;
+                conn.query("SELECT COUNT(*) AS total FROM picked", &[]).await
+            })
+            .await
+            .unwrap();
+        let DataValue::Object(row) = &rows[0] else {
+            panic!("查询结果应为对象: {:?}", rows);
+        };
+        assert_eq!(row.get("total"), Some(&DataValue::Int(2)));
+    }
+
+    #[tokio::test]
+    async fn row_locks_are_read_on_the_lease_and_rejected_by_odm() {
+        crate::i18n::ErrorMessageI18n::init_i18n();
+        let manager = PoolManager::new();
+        let alias = "lease_lock_suite".to_string();
+        manager
+            .add_database(DatabaseConfig::sqlite_memory_for_test(&alias))
+            .await
+            .unwrap();
+        let locked = QueryOptions::new().with_lock(RowLock::ForUpdate);
+
+        let options = locked.clone();
+        let rows = manager
+            .with_connection(Some(&alias), |mut conn| async move {
+                conn.execute("CREATE TABLE accounts (id INTEGER PRIMARY KEY, balance INTEGER)", &[]).await?;
+                conn.execute("INSERT INTO accounts VALUES (1, 10), (2, 20)", &[]).await?;
+                let condition = QueryConditionWithConfig {
+                    field: "id".to_string(),
+                    operator: QueryOperator::Eq,
+                    value: DataValue::Int(2),
+                    case_insensitive: false,
+                };
+                conn.find("accounts", &[condition], &options).await
+            })
+            .await
+            .unwrap();
+        assert_eq!(rows.len(), 1);
+
+        let result = crate::odm::AsyncOdmManager::handle_find("accounts", vec![], Some(locked), Some(alias)).await;
+        assert!(matches!(result, Err(QuickDbError::ValidationError { field, .. }) if field == "lock"));
+    }
+
+    #[tokio::test]
+    async fn leased_reads_and_writes_use_the_decorated_table() {
+        crate::i18n::ErrorMessageI18n::init_i18n();
+        let manager = PoolManager::new();
+        let alias = "lease_naming_suite".to_string();
+        let mut config = DatabaseConfig::sqlite_memory_for_test(&alias);
+        config.table_naming = Some(TableNaming::prefix("app_"));
+        manager.add_database(config).await.unwrap();
+
+        let rows = manager
+            .with_connection(Some(&alias), |mut conn| async move {
+                conn.execute("CREATE TABLE app_accounts (id INTEGER PRIMARY KEY, balance INTEGER)", &[]).await?;
+                let account = HashMap::from([
+                    ("id".to_string(), DataValue::Int(1)),
+                    ("balance".to_string(), DataValue::Int(10)),
+                ]);
+                conn.begin().await?;
+                conn.create("accounts", account).await?;
+                let options = QueryOptions::new().with_lock(RowLock::ForUpdate);
+                let rows = conn.find("accounts", &[], &options).await?;
+                conn.commit().await?;
+                Ok(rows)
+            })
+            .await
+            .unwrap();
+        assert_eq!(rows.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn transactions_commit_roll_back_and_discard_dropped_connections() {
+        crate::i18n::ErrorMessageI18n::init_i18n();
+        let manager = PoolManager::new();
+        let alias = "lease_tx_suite".to_string();
+        manager
+            .add_database(DatabaseConfig::sqlite_memory_for_test(&alias))
+            .await
+            .unwrap();
+        manager
+            .with_connection(Some(&alias), |mut conn| async move {
+                conn.execute("CREATE TABLE ledger (id INTEGER PRIMARY KEY, amount INTEGER)", &[]).await
+            })
+            .await
+            .unwrap();
+        // 内存数据库在最后一个连接关闭时销毁，保持一个连接直到测试结束
+        let _keeper = manager.lease(Some(&alias)).await.unwrap();
+        let entry = |id: i64| HashMap::from([("id".to_string(), DataValue::Int(id)), ("amount".to_string(), DataValue::Int(10))]);
+
+        let mut tx = manager.begin_transaction(Some(&alias)).await.unwrap();
+        tx.create("ledger", entry(1)).await.unwrap();
+        assert!(matches!(tx.begin().await, Err(QuickDbError::TransactionError { .. })));
+        tx.commit().await.unwrap();
+        assert!(!tx.in_transaction());
+        assert!(matches!(tx.commit().await, Err(QuickDbError::TransactionError { .. })));
+        drop(tx);
+
+        let mut tx = manager.begin_transaction(Some(&alias)).await.unwrap();
+        tx.create("ledger", entry(2)).await.unwrap();
+        tx.rollback().await.unwrap();
+        drop(tx);
+
+        let mut tx = manager.begin_transaction(Some(&alias)).await.unwrap();
+        tx.create("ledger", entry(3)).await.unwrap();
+        drop(tx);
+        // 关闭连接在后台完成，等待数据库回滚被丢弃的事务
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+
+        let rows = manager
+            .with_connection(Some(&alias), |mut conn| async move {
+                conn.query("SELECT id FROM ledger ORDER BY id", &[]).await
+            })
+            .await
+            .unwrap();
+        assert_eq!(rows.len(), 1);
+    }
+}
@@ -1,45 +1,162 @@
 
 //! 维护操作相关方法
 
-use crate::cache::{CacheManager, CacheStats};
-use crate::error::{QuickDbError, QuickDbResult};
-use crate::id_generator::{IdGenerator, MongoAutoIncrementGenerator};
-use crate::model::ModelMeta;
-use crate::pool::{ConnectionPool, ExtendedPoolConfig, PooledConnection};
-use crate::types::{DatabaseConfig, DatabaseType, IdType};
+use crate::cache::CacheManager;
+use crate::error::QuickDbResult;
+use crate::model::{ExpiresAfter, ModelMeta};
+use crate::pool::ConnectionPool;
+use crate::pool::PoolStatus;
+use crate::types::{DataValue, DatabaseType, QueryConditionWithConfig, QueryOperator};
 use dashmap::DashMap;
-use rat_logger::{debug, error, info, warn};
+use rat_logger::{debug, info, warn};
 use serde_json;
 use std::collections::HashMap;
 use std::sync::Arc;
-use tokio::sync::RwLock;
 use tokio::time::{Duration, interval};
 
 use super::PoolManager;
 
+/// 过期记录清理任务的执行间隔（秒）
+const TTL_SWEEP_INTERVAL_SECS: u64 = 60;
+
 impl PoolManager {
-    /// 检查连接池健康状态
-    pub async fn health_check(&self) -> std::collections::HashMap<String, bool> {
-        let mut health_status = std::collections::HashMap::new();
+    /// 启动过期记录清理任务
+    ///
+    /// SQL 数据库定期删除过期记录；MongoDB 由 TTL 索引自动清理，任务检测到后即退出。
+    /// 重复注册同一模型时会替换旧任务。
+    pub(crate) fn start_ttl_sweeper(&self, alias: &str, model_meta: &ModelMeta) {
+        let Some(expires_after) = model_meta.expires_after.clone() else {
+            return;
+        };
 
-        for entry in self.pools.iter() {
-            let alias = entry.key().clone();
-            let pool = entry.value();
+        let runtime = match tokio::runtime::Handle::try_current() {
+            Ok(runtime) => runtime,
+            Err(_) => {
+                warn!(
+                    "当前不在tokio运行时中，无法启动过期记录清理任务: {}:{}",
+                    alias, model_meta.qualified_name()
+                );
+                return;
+            }
+        };
 
-            // 尝试获取连接来检查健康状态
-            let is_healthy = match pool.get_connection().await {
-                Ok(conn) => {
-                    // 立即释放连接
-                    let _ = pool.release_connection(&conn.id).await;
-                    true
+        let pools = self.pools.clone();
+        let cache_managers = self.cache_managers.clone();
+        let scheduled_ttl_sweeps = self.scheduled_ttl_sweeps.clone();
+        let alias = alias.to_string();
+        let table = model_meta.qualified_name();
+        let registry_key = format!("{}:{}", alias, table);
+
+        let handle = runtime.spawn(async move {
+            let mut interval = interval(Duration::from_secs(TTL_SWEEP_INTERVAL_SECS));
+
+            debug!(
+                "启动过期记录清理任务: 别名={}, 表={}, 字段={}, 过期时长={}秒",
+                alias, table, expires_after.field, expires_after.seconds
+            );
+
+            loop {
+                interval.tick().await;
+
+                // 该数据库的清理已交给调度器的主节点执行
+                if scheduled_ttl_sweeps.contains_key(&alias) {
+                    continue;
                 }
-                Err(_) => false,
-            };
 
-            health_status.insert(alias, is_healthy);
+                // 数据库尚未添加时跳过本轮
+                let Some(pool) = pools.get(&alias).map(|pool| pool.clone()) else {
+                    continue;
+                };
+
+                if matches!(pool.get_database_type(), DatabaseType::MongoDB) {
+                    debug!("MongoDB由TTL索引清理过期记录，退出清理任务: {}", table);
+                    break;
+                }
+
+                if !pool.table_exists(&table).await.unwrap_or(false) {
+                    continue;
+                }
+
+                if let Err(e) =
+                    sweep_expired_table(&pool, &cache_managers, &alias, &table, &expires_after).await
+                {
+                    warn!("清理过期记录失败: 表={}, 错误={}", table, e);
+                }
+            }
+        });
+
+        if let Some(previous) = self.ttl_sweeper_handles.insert(registry_key, handle) {
+            previous.abort();
         }
+    }
 
-        health_status
+    /// 立即清理指定数据库中所有声明了过期配置的模型的过期记录，返回删除总数
+    ///
+    /// MongoDB 由 TTL 索引自动清理，直接返回 0
+    pub async fn sweep_expired_records(&self, alias: &str) -> QuickDbResult<u64> {
+        let pool = self
+            .pools
+            .get(alias)
+            .map(|pool| pool.clone())
+            .ok_or_else(|| crate::quick_error!(alias_not_found, alias))?;
+        if matches!(pool.get_database_type(), DatabaseType::MongoDB) {
+            return Ok(0);
+        }
+
+        let prefix = format!("{}:", alias);
+        let models: Vec<(String, ExpiresAfter)> = self
+            .model_registry
+            .iter()
+            .filter(|entry| entry.key().starts_with(&prefix))
+            .filter_map(|entry| {
+                let meta = entry.value();
+                meta.expires_after
+                    .clone()
+                    .map(|expires_after| (meta.qualified_name(), expires_after))
+            })
+            .collect();
+
+        let mut total = 0;
+        for (table, expires_after) in models {
+            if pool.table_exists(&table).await? {
+                total += sweep_expired_table(&pool, &self.cache_managers, alias, &table, &expires_after).await?;
+            }
+        }
+        Ok(total)
+    }
+
+    /// 设置指定数据库的过期清理是否交给调度器执行
+    ///
+    /// 交出后本实例的后台清理任务跳过该数据库，避免多实例重复清理
+    #[cfg(any(
+        feature = "sqlite-support",
+        feature = "postgres-support",
+        feature = "mysql-support",
+        feature = "mongodb-support"
+    ))]
+    pub(crate) fn set_ttl_sweeps_scheduled(&self, alias: &str, scheduled: bool) {
+        if scheduled {
+            self.scheduled_ttl_sweeps.insert(alias.to_string(), ());
+        } else {
+            self.scheduled_ttl_sweeps.remove(alias);
+        }
+    }
+
+    /// 获取指定数据库别名的连接池运行时状态
+    pub fn get_pool_status(&self, alias: &str) -> QuickDbResult<PoolStatus> {
+        if let Some(pool) = self.pools.get(alias) {
+            Ok(pool.status())
+        } else {
+            Err(crate::quick_error!(alias_not_found, alias))
+        }
+    }
+
+    /// 获取所有连接池的运行时状态 (别名 -> 状态)
+    pub fn get_pools_status(&self) -> HashMap<String, PoolStatus> {
+        self.pools
+            .iter()
+            .map(|entry| (entry.key().clone(), entry.value().status()))
+            .collect()
     }
 
     /// 获取所有活跃连接池的详细状态信息
@@ -114,9 +231,14 @@ impl PoolManager {
                     "max_retries": pool.config.max_retries,
                     "retry_interval_ms": pool.config.retry_interval_ms,
                     "keepalive_interval_sec": pool.config.keepalive_interval_sec,
-                    "health_check_timeout_sec": pool.config.health_check_timeout_sec
+                    "health_check_timeout_sec": pool.config.health_check_timeout_sec,
+                    "statement_cache_capacity": pool.config.base.statement_cache_capacity
                 },
                 "cache": cache_info,
+                "status": serde_json::to_value(pool.status()).unwrap_or_default(),
+                "sizing": serde_json::to_value(pool.sizing_metrics()).unwrap_or_default(),
+                "statement_cache": crate::adapter::get_statement_cache_stats(&alias)
+                    .and_then(|stats| serde_json::to_value(stats).ok()),
                 "has_id_generator": self.id_generators.contains_key(&alias),
                 "has_mongo_auto_increment": self.mongo_auto_increment_generators.contains_key(&alias)
             });
@@ -148,6 +270,14 @@ impl PoolManager {
         // 清空ID生成器
         self.id_generators.clear();
         self.mongo_auto_increment_generators.clear();
+        self.mongo_sequence_generators.clear();
+
+        // 停止过期记录清理任务
+        for entry in self.ttl_sweeper_handles.iter() {
+            entry.value().abort();
+        }
+        self.ttl_sweeper_handles.clear();
+        self.scheduled_ttl_sweeps.clear();
 
         // 清空缓存管理器
         self.cache_managers.clear();
@@ -171,3 +301,32 @@ impl PoolManager {
         Ok(())
     }
 }
+
+/// 删除一张表中的过期记录，返回删除数量
+async fn sweep_expired_table(
+    pool: &ConnectionPool,
+    cache_managers: &DashMap<String, Arc<CacheManager>>,
+    alias: &str,
+    table: &str,
+    expires_after: &ExpiresAfter,
+) -> QuickDbResult<u64> {
+    let cutoff = expires_after.cutoff(chrono::Utc::now());
+    let conditions = vec![QueryConditionWithConfig {
+        field: expires_after.field.clone(),
+        operator: QueryOperator::Lt,
+        value: DataValue::from(cutoff),
+        case_insensitive: false,
+    }];
+
+    let deleted = pool.delete(table, &conditions, alias).await?;
+    if deleted > 0 {
+        debug!("清理过期记录: 表={}, 删除数量={}", table, deleted);
+        // 直接删除绕过了ODM层，需要同步清理该表缓存
+        let cache_manager = cache_managers.get(alias).map(|cm| cm.clone());
+        if let Some(cache_manager) = cache_manager {
+            let _ = cache_manager.clear_table_record_cache(table).await;
+            let _ = cache_manager.clear_table_query_cache(table).await;
+        }
+    }
+    Ok(deleted)
+}
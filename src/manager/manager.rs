@@ -1,17 +1,14 @@
 //! 连接池管理器核心定义
 
-use crate::cache::{CacheManager, CacheStats};
-use crate::error::{QuickDbError, QuickDbResult};
+use crate::cache::CacheManager;
 use crate::id_generator::{IdGenerator, MongoAutoIncrementGenerator};
 use crate::model::ModelMeta;
-use crate::pool::{ConnectionPool, ExtendedPoolConfig, PooledConnection};
-use crate::types::{DatabaseConfig, DatabaseType, IdType};
+use crate::pool::ConnectionPool;
 use dashmap::DashMap;
-use rat_logger::{debug, error, info, warn};
+use rat_logger::info;
 use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::RwLock;
-use tokio::time::{Duration, interval};
 
 /// 连接池管理器 - 管理多个数据库连接池
 #[derive(Debug)]
@@ -27,14 +24,30 @@ pub struct PoolManager {
     /// MongoDB自增ID生成器映射 (别名 -> 自增生成器)
     pub(crate) mongo_auto_increment_generators:
         Arc<DashMap<String, Arc<MongoAutoIncrementGenerator>>>,
+    /// MongoDB序列字段生成器映射 ("别名:序列名" -> 自增生成器)
+    pub(crate) mongo_sequence_generators: Arc<DashMap<String, Arc<MongoAutoIncrementGenerator>>>,
     /// 缓存管理器映射 (别名 -> 缓存管理器)
     pub(crate) cache_managers: Arc<DashMap<String, Arc<CacheManager>>>,
     /// 模型元数据注册表 (集合名 -> 模型元数据)
     pub(crate) model_registry: Arc<DashMap<String, ModelMeta>>,
+    /// 存储过程配置注册表 ("别名:过程名" -> 配置)
+    pub(crate) stored_procedures: Arc<DashMap<String, crate::stored_procedure::StoredProcedureConfig>>,
+    /// 过期记录清理任务句柄 ("别名:集合名" -> 任务句柄)
+    pub(crate) ttl_sweeper_handles: Arc<DashMap<String, tokio::task::JoinHandle<()>>>,
+    /// 过期清理已交给调度器的数据库别名
+    pub(crate) scheduled_ttl_sweeps: Arc<DashMap<String, ()>>,
+    /// 查询防护配置 (别名 -> 防护配置)
+    pub(crate) query_guardrails: Arc<DashMap<String, crate::config::QueryGuardrails>>,
     /// 索引创建锁，防止并发创建同一个索引 (表名 -> 索引名 -> ())
     pub(crate) index_creation_locks: Arc<tokio::sync::Mutex<HashMap<String, HashMap<String, ()>>>>,
 }
 
+impl Default for PoolManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl PoolManager {
     /// 创建新的连接池管理器
     pub fn new() -> Self {
@@ -46,8 +59,13 @@ impl PoolManager {
             cleanup_handle: Arc::new(RwLock::new(None)),
             id_generators: Arc::new(DashMap::new()),
             mongo_auto_increment_generators: Arc::new(DashMap::new()),
+            mongo_sequence_generators: Arc::new(DashMap::new()),
             cache_managers: Arc::new(DashMap::new()),
             model_registry: Arc::new(DashMap::new()),
+            stored_procedures: Arc::new(DashMap::new()),
+            ttl_sweeper_handles: Arc::new(DashMap::new()),
+            scheduled_ttl_sweeps: Arc::new(DashMap::new()),
+            query_guardrails: Arc::new(DashMap::new()),
             index_creation_locks: Arc::new(tokio::sync::Mutex::new(HashMap::new())),
         }
     }
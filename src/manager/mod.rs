@@ -5,30 +5,36 @@
 mod alias_type_map;
 mod cache_ops;
 mod database_ops;
+mod guardrail_ops;
+mod health;
+#[cfg(any(feature = "sqlite-support", feature = "postgres-support", feature = "mysql-support"))]
+mod lease;
 mod maintenance;
+#[allow(clippy::module_inception)]
 mod manager;
 mod model_ops;
+mod stored_procedure_ops;
 
 // 重新导出主要类型
 pub use alias_type_map::{get_database_type_by_alias, register_database_alias};
+pub use health::{HealthCheckHandle, HealthReport, start_periodic_health_checks};
+#[cfg(any(feature = "sqlite-support", feature = "postgres-support", feature = "mysql-support"))]
+pub use lease::LeasedConnection;
 pub use manager::PoolManager;
 
 // 全局便捷函数（从原manager.rs的第631行开始）
-use dashmap::DashMap;
 use std::sync::Arc;
-use tokio::sync::RwLock;
 
-use crate::cache::{CacheManager, CacheStats};
+use crate::cache::{CacheManager, CacheStats, TableCacheStats, CacheWarmProgress, CacheWarmReport, CacheWarmSpec};
 use crate::error::{QuickDbError, QuickDbResult};
-use crate::id_generator::{IdGenerator, MongoAutoIncrementGenerator};
 use crate::model::ModelMeta;
-use crate::pool::{ConnectionPool, PooledConnection};
+use crate::pool::PooledConnection;
 use crate::types::id_types::IdStrategy;
-use crate::types::{DatabaseConfig, IdType};
+use crate::types::{CountEstimate, DatabaseConfig, IdType};
 use once_cell::sync::Lazy;
 
 /// 全局连接池管理器实例
-pub static GLOBAL_POOL_MANAGER: Lazy<PoolManager> = Lazy::new(|| PoolManager::new());
+pub static GLOBAL_POOL_MANAGER: Lazy<PoolManager> = Lazy::new(PoolManager::new);
 
 /// 获取全局连接池管理器
 pub(crate) fn get_global_pool_manager() -> &'static PoolManager {
@@ -45,6 +51,16 @@ pub async fn add_database(config: DatabaseConfig) -> QuickDbResult<()> {
     get_global_pool_manager().add_database(config).await
 }
 
+/// 便捷函数 - 按配置文件内容批量添加数据库
+pub async fn add_databases_from_config(config: crate::config::DatabaseFileConfig) -> QuickDbResult<Vec<String>> {
+    // 检查全局操作锁状态，如果已锁定则惊恐退出
+    if crate::is_global_operations_locked() {
+        panic!("{}", crate::i18n::t("manager.global_ops_locked"));
+    }
+
+    get_global_pool_manager().add_databases_from_config(config).await
+}
+
 /// 便捷函数 - 获取连接
 pub async fn get_connection(alias: Option<&str>) -> QuickDbResult<PooledConnection> {
     // 锁定全局操作
@@ -63,6 +79,28 @@ pub async fn release_connection(connection: &PooledConnection) -> QuickDbResult<
         .await
 }
 
+/// 便捷函数 - 租用一个数据库连接执行闭包，闭包内的语句在同一数据库会话上执行
+#[cfg(any(feature = "sqlite-support", feature = "postgres-support", feature = "mysql-support"))]
+pub async fn with_connection<F, Fut, T>(alias: Option<&str>, operation: F) -> QuickDbResult<T>
+where
+    F: FnOnce(LeasedConnection) -> Fut,
+    Fut: std::future::Future<Output = QuickDbResult<T>>,
+{
+    // 锁定全局操作
+    crate::lock_global_operations();
+
+    get_global_pool_manager().with_connection(alias, operation).await
+}
+
+/// 便捷函数 - 租用一个数据库连接并开启事务，提交前丢弃连接时事务回滚
+#[cfg(any(feature = "sqlite-support", feature = "postgres-support", feature = "mysql-support"))]
+pub async fn begin_transaction(alias: Option<&str>) -> QuickDbResult<LeasedConnection> {
+    // 锁定全局操作
+    crate::lock_global_operations();
+
+    get_global_pool_manager().begin_transaction(alias).await
+}
+
 /// 便捷函数 - 获取所有别名
 pub fn get_aliases() -> Vec<String> {
     get_global_pool_manager().get_aliases()
@@ -74,7 +112,7 @@ pub async fn set_default_alias(alias: &str) -> QuickDbResult<()> {
 }
 
 /// 便捷函数 - 健康检查
-pub async fn health_check() -> std::collections::HashMap<String, bool> {
+pub async fn health_check() -> std::collections::HashMap<String, HealthReport> {
     // 锁定全局操作
     crate::lock_global_operations();
 
@@ -89,13 +127,33 @@ pub async fn get_active_pools_status() -> std::collections::HashMap<String, serd
     get_global_pool_manager().get_active_pools_status().await
 }
 
+/// 便捷函数 - 获取指定数据库别名的连接池运行时状态
+pub fn get_pool_status(alias: &str) -> QuickDbResult<crate::pool::PoolStatus> {
+    get_global_pool_manager().get_pool_status(alias)
+}
+
+/// 便捷函数 - 获取所有连接池的运行时状态
+pub fn get_pools_status() -> std::collections::HashMap<String, crate::pool::PoolStatus> {
+    get_global_pool_manager().get_pools_status()
+}
+
 #[cfg(feature = "python-bindings")]
 #[doc(hidden)]
 /// 便捷函数 - 获取连接池映射（仅用于Python绑定，不推荐直接使用）
-pub fn get_connection_pools() -> Arc<DashMap<String, Arc<ConnectionPool>>> {
+pub fn get_connection_pools() -> Arc<dashmap::DashMap<String, Arc<crate::pool::ConnectionPool>>> {
     get_global_pool_manager().get_connection_pools()
 }
 
+/// 便捷函数 - 设置数据库的查询防护配置
+pub fn set_query_guardrails(alias: &str, guardrails: crate::config::QueryGuardrails) {
+    get_global_pool_manager().set_query_guardrails(alias, guardrails)
+}
+
+/// 便捷函数 - 获取数据库的查询防护配置
+pub fn get_query_guardrails(alias: &str) -> Option<crate::config::QueryGuardrails> {
+    get_global_pool_manager().get_query_guardrails(alias)
+}
+
 /// 便捷函数 - 注册模型元数据
 ///
 /// # Python API专用
@@ -142,6 +200,13 @@ pub async fn get_cache_stats(alias: &str) -> QuickDbResult<CacheStats> {
     get_global_pool_manager().get_cache_stats(alias).await
 }
 
+/// 便捷函数 - 获取按表划分的缓存统计信息
+pub fn get_cache_stats_by_table(
+    alias: &str,
+) -> QuickDbResult<std::collections::HashMap<String, TableCacheStats>> {
+    get_global_pool_manager().get_cache_stats_by_table(alias)
+}
+
 /// 便捷函数 - 清理指定数据库的缓存
 pub async fn clear_cache(alias: &str) -> QuickDbResult<()> {
     // 锁定全局操作
@@ -158,6 +223,25 @@ pub async fn clear_all_caches() -> QuickDbResult<()> {
     get_global_pool_manager().clear_all_caches().await
 }
 
+/// 便捷函数 - 预热指定数据库的缓存
+pub async fn warm_cache(alias: &str, spec: &CacheWarmSpec) -> QuickDbResult<CacheWarmReport> {
+    get_global_pool_manager().warm_cache(alias, spec).await
+}
+
+/// 便捷函数 - 预热指定数据库的缓存并回调进度
+pub async fn warm_cache_with_progress<F>(
+    alias: &str,
+    spec: &CacheWarmSpec,
+    on_progress: F,
+) -> QuickDbResult<CacheWarmReport>
+where
+    F: FnMut(&CacheWarmProgress),
+{
+    get_global_pool_manager()
+        .warm_cache_with_progress(alias, spec, on_progress)
+        .await
+}
+
 /// 便捷函数 - 按模式清理缓存
 ///
 /// # 参数
@@ -333,6 +417,31 @@ pub async fn table_exists(alias: &str, table: &str) -> QuickDbResult<bool> {
     pool.table_exists(table).await
 }
 
+/// 便捷函数 - 估算表/集合的记录数
+///
+/// 精确的 `count()` 在亿级大表上需要全表扫描，本函数改为读取数据库的统计信息：
+/// PostgreSQL 使用 `pg_class.reltuples`，MySQL 使用 `information_schema.TABLES.TABLE_ROWS`，
+/// SQLite 使用 `MAX(rowid)`，MongoDB 使用 `estimatedDocumentCount`。
+/// 结果只是近似值，适合分页总数、监控等不要求精确的场景
+///
+/// # 参数
+/// * `alias` - 数据库别名
+/// * `table` - 表名或集合名
+///
+/// # 返回值
+/// 返回 [`CountEstimate`]，`approximate` 为 false 表示没有统计信息而回退到了精确计数
+pub async fn count_estimate(alias: &str, table: &str) -> QuickDbResult<CountEstimate> {
+    let pool = get_global_pool_manager()
+        .pools
+        .get(alias)
+        .map(|pool| pool.clone())
+        .ok_or_else(|| QuickDbError::AliasNotFound {
+            alias: alias.to_string(),
+        })?;
+
+    pool.count_estimate(table).await
+}
+
 /// 便捷函数 - 删除表/集合
 ///
 /// 如果表不存在则直接返回成功，存在则执行删除操作
@@ -1,25 +1,19 @@
 
 //! 模型操作相关方法
 
-use crate::cache::{CacheManager, CacheStats};
 use crate::error::{QuickDbError, QuickDbResult};
-use crate::id_generator::{IdGenerator, MongoAutoIncrementGenerator};
-use crate::model::ModelMeta;
-use crate::pool::{ConnectionPool, ExtendedPoolConfig, PooledConnection};
-use crate::types::{DatabaseConfig, DatabaseType, IdType};
-use dashmap::DashMap;
-use rat_logger::{debug, error, info, warn};
+use crate::model::{ModelMeta, QueryScopes};
+use rat_logger::{debug, warn};
 use std::collections::HashMap;
-use std::sync::Arc;
-use tokio::sync::RwLock;
-use tokio::time::{Duration, interval};
 
 use super::PoolManager;
 
 impl PoolManager {
     /// 注册模型元数据
     pub fn register_model(&self, model_meta: ModelMeta) -> QuickDbResult<()> {
-        let collection_name = model_meta.collection_name.clone();
+        // 计算字段不存储，注册表中的元数据用于建表和写入，不包含计算字段
+        let mut model_meta = model_meta.without_computed_fields();
+        let collection_name = model_meta.qualified_name();
         let database_alias = model_meta
             .database_alias
             .clone()
@@ -27,8 +21,10 @@ impl PoolManager {
         let registry_key = format!("{}:{}", database_alias, collection_name);
 
         // 检查是否已注册
-        if self.model_registry.contains_key(&registry_key) {
+        if let Some(existing) = self.model_registry.get(&registry_key) {
             debug!("模型已存在，将更新元数据: {}", registry_key);
+            // 单表继承的模型共享同一张表，合并各模型的字段与索引
+            model_meta.merge_inherited(&existing);
         }
 
         self.model_registry
@@ -40,6 +36,23 @@ impl PoolManager {
             model_meta.indexes.len()
         );
 
+        // 声明了表级缓存策略的模型同步到该数据库的缓存管理器
+        if let (Some(policy), Some(cache_manager)) =
+            (&model_meta.cache_policy, self.cache_managers.get(&database_alias))
+        {
+            cache_manager.set_table_policy(&collection_name, policy.clone());
+        }
+
+        // 声明了分片键的模型由 ODM 按分片解析器路由
+        if model_meta.shard_key.is_some() {
+            crate::sharding::register_sharded_model(&model_meta);
+        }
+
+        // 声明了过期配置的模型启动后台清理任务
+        if model_meta.expires_after.is_some() {
+            self.start_ttl_sweeper(&database_alias, &model_meta);
+        }
+
         Ok(())
     }
 
@@ -51,13 +64,48 @@ impl PoolManager {
     }
 
     /// 获取指定数据库的模型元数据
+    ///
+    /// 适配器以物理表名查找时，按别名的表名装饰规则还原为逻辑表名
     pub fn get_model_with_alias(&self, collection_name: &str, alias: &str) -> Option<ModelMeta> {
         let registry_key = format!("{}:{}", alias, collection_name);
+        if let Some(meta) = self.model_registry.get(&registry_key) {
+            return Some(meta.clone());
+        }
+        let logical = self.logical_table_name(collection_name, alias)?;
         self.model_registry
-            .get(&registry_key)
+            .get(&format!("{}:{}", alias, logical))
             .map(|meta| meta.clone())
     }
 
+    /// 获取指定数据库的模型查询作用域（只复制作用域，不复制整个元数据）
+    pub(crate) fn get_model_scopes(&self, collection_name: &str, alias: &str) -> Option<QueryScopes> {
+        let scopes = |key: String| self.model_registry.get(&key).map(|meta| meta.scopes.clone());
+        scopes(format!("{}:{}", alias, collection_name)).or_else(|| {
+            let logical = self.logical_table_name(collection_name, alias)?;
+            scopes(format!("{}:{}", alias, logical))
+        })
+    }
+
+    /// 按别名的表名装饰规则把物理表名还原为逻辑表名，未配置装饰规则时返回 `None`
+    pub(crate) fn logical_table_name(&self, table: &str, alias: &str) -> Option<String> {
+        let pool = self.pools.get(alias)?;
+        pool.db_config.table_naming.as_ref()?.undecorate(table)
+    }
+
+    /// 按别名的表名装饰规则把逻辑表名转换为物理表名
+    #[cfg(any(
+        feature = "sqlite-support",
+        feature = "postgres-support",
+        feature = "mysql-support",
+        feature = "mongodb-support"
+    ))]
+    pub(crate) fn physical_table_name(&self, table: &str, alias: &str) -> String {
+        self.pools
+            .get(alias)
+            .and_then(|pool| pool.db_config.table_naming.as_ref().map(|naming| naming.decorate(table)))
+            .unwrap_or_else(|| table.to_string())
+    }
+
     /// 检查模型是否已注册
     pub fn has_model(&self, collection_name: &str) -> bool {
         self.model_registry.contains_key(collection_name)
@@ -89,22 +137,6 @@ impl PoolManager {
         locks
     }
 
-    /// 释放索引创建锁
-    fn release_index_lock(
-        &self,
-        table: &str,
-        index: &str,
-        mut locks: tokio::sync::MutexGuard<'_, HashMap<String, HashMap<String, ()>>>,
-    ) {
-        if let Some(table_locks) = locks.get_mut(table) {
-            table_locks.remove(index);
-            if table_locks.is_empty() {
-                locks.remove(table);
-            }
-        }
-        debug!("🔓 释放表 {} 索引 {} 的创建锁", table, index);
-    }
-
     /// 创建表和索引（基于注册的模型元数据）
     pub async fn ensure_table_and_indexes(
         &self,
@@ -124,10 +156,10 @@ impl PoolManager {
                     .collect();
 
                 // 检查表是否存在
-                let table_exists = pool.table_exists(&collection_name).await?;
+                let table_exists = pool.table_exists(collection_name).await?;
                 if !table_exists {
                     debug!("表 {} 不存在，正在创建", collection_name);
-                    pool.create_table(&collection_name, &fields, &pool.db_config.id_strategy)
+                    pool.create_table(collection_name, &fields, &pool.db_config.id_strategy)
                         .await?;
                 }
 
@@ -141,12 +173,18 @@ impl PoolManager {
                     );
 
                     // 获取索引创建锁，防止并发创建同一个索引
-                    let _lock = self.acquire_index_lock(&collection_name, index_name).await;
+                    let _lock = self.acquire_index_lock(collection_name, index_name).await;
 
                     // 双重检查：再次检查索引是否可能已存在
                     // 这里我们直接尝试创建，因为数据库层面会报错，我们捕获错误即可
                     if let Err(e) = pool
-                        .create_index(&collection_name, index_name, &index.fields, index.unique)
+                        .create_index(
+                            collection_name,
+                            index_name,
+                            &index.fields,
+                            index.unique,
+                            &index.options,
+                        )
                         .await
                     {
                         // 使用错误码检测的 IndexExistsError 替代字符串匹配
@@ -158,8 +196,7 @@ impl PoolManager {
                                 warn!("创建索引失败: {} (错误: {})", index_name, e);
                             }
                         }
-                    } else {
-                    }
+                    } 
                 }
             } else {
                 return Err(QuickDbError::AliasNotFound {
@@ -0,0 +1,67 @@
+//! 存储过程注册表相关方法
+
+use crate::stored_procedure::StoredProcedureConfig;
+use rat_logger::debug;
+
+use super::PoolManager;
+
+impl PoolManager {
+    /// 登记已创建的存储过程配置，同名配置将被替换
+    pub fn register_stored_procedure(&self, config: StoredProcedureConfig) {
+        let registry_key = format!("{}:{}", config.database, config.procedure_name);
+        debug!("登记存储过程配置: {}", registry_key);
+        self.stored_procedures.insert(registry_key, config);
+    }
+
+    /// 获取指定数据库的存储过程配置
+    pub fn get_stored_procedure(&self, alias: &str, procedure_name: &str) -> Option<StoredProcedureConfig> {
+        self.stored_procedures
+            .get(&format!("{}:{}", alias, procedure_name))
+            .map(|config| config.clone())
+    }
+
+    /// 移除存储过程配置，返回是否存在
+    pub fn remove_stored_procedure(&self, alias: &str, procedure_name: &str) -> bool {
+        self.stored_procedures
+            .remove(&format!("{}:{}", alias, procedure_name))
+            .is_some()
+    }
+
+    /// 指定数据库已登记的存储过程配置（按名称排序）
+    pub fn get_stored_procedures(&self, alias: &str) -> Vec<StoredProcedureConfig> {
+        let prefix = format!("{}:", alias);
+        let mut configs: Vec<StoredProcedureConfig> = self
+            .stored_procedures
+            .iter()
+            .filter(|entry| entry.key().starts_with(&prefix))
+            .map(|entry| entry.value().clone())
+            .collect();
+        configs.sort_by(|a, b| a.procedure_name.cmp(&b.procedure_name));
+        configs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registry_is_scoped_per_alias() {
+        let manager = PoolManager::new();
+        for (alias, name) in [("main", "b"), ("main", "a"), ("other", "a")] {
+            manager.register_stored_procedure(StoredProcedureConfig::builder(name, alias).with_version(1).build());
+        }
+        manager.register_stored_procedure(StoredProcedureConfig::builder("a", "main").with_version(2).build());
+
+        let names: Vec<_> = manager
+            .get_stored_procedures("main")
+            .into_iter()
+            .map(|config| (config.procedure_name, config.version))
+            .collect();
+        assert_eq!(names, vec![("a".to_string(), 2), ("b".to_string(), 1)]);
+
+        assert!(manager.remove_stored_procedure("main", "a"));
+        assert!(!manager.remove_stored_procedure("main", "a"));
+        assert_eq!(manager.get_stored_procedure("other", "a").map(|config| config.version), Some(1));
+    }
+}
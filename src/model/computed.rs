@@ -0,0 +1,140 @@
+//! 计算字段模块
+//!
+//! 计算字段（虚拟字段）由其他字段推导得出，例如由 first_name 与 last_name 组成的
+//! full_name、由 birthdate 推算的 age。计算字段不会写入数据库，也不会出现在建表
+//! 语句中；模型加载后由 `Model::compute_fields()` 填充。
+//!
+//! 解码时计算字段没有存储值：非必填字段（通常声明为 `Option<T>`）解码为空值，
+//! 必填字段以字段类型的零值占位，随后由 `compute_fields()` 覆盖
+
+use crate::model::field_types::{FieldType, ModelMeta};
+use crate::types::DataValue;
+use std::collections::HashMap;
+
+impl ModelMeta {
+    /// 是否声明了计算字段
+    pub fn has_computed_fields(&self) -> bool {
+        self.fields.values().any(|field| field.computed)
+    }
+
+    /// 去除计算字段后的元数据（用于注册表、建表等存储相关场景）
+    pub fn without_computed_fields(mut self) -> Self {
+        self.fields.retain(|_, field| !field.computed);
+        self
+    }
+
+    /// 从待写入的数据中移除计算字段
+    pub fn strip_computed_fields(&self, data: &mut HashMap<String, DataValue>) {
+        for (name, field) in &self.fields {
+            if field.computed {
+                data.remove(name);
+            }
+        }
+    }
+
+    /// 为解码前的数据补充计算字段的占位值
+    pub fn fill_computed_placeholders(&self, data: &mut HashMap<String, DataValue>) {
+        for (name, field) in &self.fields {
+            if !field.computed || data.contains_key(name) {
+                continue;
+            }
+            let placeholder = if field.required {
+                zero_value(&field.field_type)
+            } else {
+                DataValue::Null
+            };
+            data.insert(name.clone(), placeholder);
+        }
+    }
+}
+
+/// 字段类型的零值
+fn zero_value(field_type: &FieldType) -> DataValue {
+    match field_type {
+        FieldType::String { .. } | FieldType::Text | FieldType::Reference { .. } => {
+            DataValue::String(String::new())
+        }
+        FieldType::Integer { .. } | FieldType::BigInteger | FieldType::Sequence { .. } => {
+            DataValue::Int(0)
+        }
+        FieldType::Float { .. } | FieldType::Double | FieldType::Decimal { .. } => {
+            DataValue::Float(0.0)
+        }
+        FieldType::Boolean => DataValue::Bool(false),
+        FieldType::DateTime
+        | FieldType::DateTimeWithTz { .. }
+        | FieldType::Date
+        | FieldType::Time => DataValue::DateTime(chrono::DateTime::UNIX_EPOCH.fixed_offset()),
+        FieldType::Uuid => DataValue::Uuid(uuid::Uuid::nil()),
+        FieldType::Json => DataValue::Json(serde_json::Value::Null),
+        FieldType::Binary => DataValue::Bytes(Vec::new()),
+        FieldType::Array { .. } | FieldType::Vector { .. } => DataValue::Array(Vec::new()),
+        FieldType::Object { .. } => DataValue::Object(HashMap::new()),
+        FieldType::PolymorphicReference { .. } => DataValue::Null,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::traits::Model;
+    use crate::types::DataValue;
+    use std::collections::HashMap;
+
+    mod model {
+        use crate::model::*;
+        use crate::{define_model, integer_field, string_field};
+
+        define_model! {
+            struct Person {
+                id: String,
+                first_name: String,
+                last_name: String,
+                birth_year: i32,
+                full_name: String,
+                age: Option<i32>,
+            }
+            collection = "computed_people",
+            compute_fields = compute_person_fields,
+            fields = {
+                id: string_field(None, None, None).required(),
+                first_name: string_field(None, None, None).required(),
+                last_name: string_field(None, None, None).required(),
+                birth_year: integer_field(None, None).required(),
+                full_name: string_field(None, None, None).required().computed(),
+                age: integer_field(None, None).computed(),
+            }
+        }
+
+        fn compute_person_fields(person: &mut Person) {
+            person.full_name = format!("{} {}", person.first_name, person.last_name);
+            person.age = Some(2026 - person.birth_year);
+        }
+    }
+
+    #[test]
+    fn computed_fields_are_populated_after_load_and_never_stored() {
+        use model::Person;
+
+        let mut row = HashMap::new();
+        row.insert("id".to_string(), DataValue::String("p1".to_string()));
+        row.insert("first_name".to_string(), DataValue::String("Ada".to_string()));
+        row.insert("last_name".to_string(), DataValue::String("Lovelace".to_string()));
+        row.insert("birth_year".to_string(), DataValue::Int(1990));
+
+        let person = Person::from_data_map(row).unwrap();
+        assert_eq!(person.full_name, "Ada Lovelace");
+        assert_eq!(person.age, Some(36));
+
+        let data = person.to_data_map().unwrap();
+        assert!(!data.contains_key("full_name"));
+        assert!(!data.contains_key("age"));
+        assert!(person.validate().is_ok());
+
+        // 注册表与建表使用的元数据不包含计算字段
+        let meta = Person::meta();
+        assert!(meta.has_computed_fields());
+        let storage = meta.without_computed_fields();
+        assert!(!storage.fields.contains_key("full_name"));
+        assert!(storage.fields.contains_key("first_name"));
+    }
+}
@@ -3,6 +3,7 @@
 //! 提供创建各种字段类型的便捷函数
 
 use crate::model::field_types::{FieldDefinition, FieldType};
+use crate::model::traits::Model;
 use std::collections::HashMap;
 
 /// 便捷函数：创建数组字段
@@ -95,6 +96,16 @@ pub fn json_field() -> FieldDefinition {
     FieldDefinition::new(FieldType::Json)
 }
 
+/// 便捷函数：创建嵌入文档字段
+///
+/// 复用嵌入模型（`define_model! { embedded struct ... }`）的字段元数据进行验证，
+/// 在 SQL 数据库中以 JSON/JSONB 存储，在 MongoDB 中存储为子文档
+pub fn embedded_field<T: Model>() -> FieldDefinition {
+    FieldDefinition::new(FieldType::Object {
+        fields: T::meta().fields,
+    })
+}
+
 /// 创建向量字段定义（用于 pgvector 向量搜索）
 ///
 /// # 参数
@@ -103,6 +114,16 @@ pub fn vector_field(dimension: usize) -> FieldDefinition {
     FieldDefinition::new(FieldType::Vector { dimension })
 }
 
+/// 创建序列字段定义（主键之外的单调递增整数，如发票号）
+///
+/// # 参数
+/// * `name` - 序列名称，同名序列在多个表之间共享计数
+pub fn sequence_field(name: &str) -> FieldDefinition {
+    FieldDefinition::new(FieldType::Sequence {
+        name: name.to_string(),
+    })
+}
+
 /// 便捷函数：创建字典字段（基于Object类型）
 ///
 /// # 废弃说明
@@ -131,3 +152,8 @@ pub fn dict_field(fields: HashMap<String, FieldDefinition>) -> FieldDefinition {
 pub fn reference_field(target_collection: String) -> FieldDefinition {
     FieldDefinition::new(FieldType::Reference { target_collection })
 }
+
+/// 便捷函数：创建多态引用字段（同时存储目标集合名与ID）
+pub fn polymorphic_reference_field(target_collections: Vec<String>) -> FieldDefinition {
+    FieldDefinition::new(FieldType::PolymorphicReference { target_collections })
+}
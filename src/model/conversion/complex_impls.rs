@@ -2,9 +2,6 @@
 //!
 //! 为更复杂的类型实现 ToDataValue，包括嵌套结构等
 
-use crate::model::conversion::ToDataValue;
-use crate::types::DataValue;
-use serde_json::Value as JsonValue;
 
 // 为其他复杂类型提供 ToDataValue 实现的占位符
 // 这里可以根据需要添加更多复杂类型的实现
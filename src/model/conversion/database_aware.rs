@@ -2,8 +2,8 @@
 //!
 //! 根据不同数据库类型实现差异化的字段转换逻辑
 
-use crate::error::{QuickDbError, QuickDbResult};
-use crate::model::{FieldType, conversion::ToDataValue};
+use crate::error::QuickDbResult;
+use crate::model::conversion::ToDataValue;
 use crate::types::{DataValue, DatabaseType};
 
 /// 数据库感知的DateTimeWithTz字段转换
@@ -61,7 +61,7 @@ fn convert_datetime_with_tz_general<T: std::fmt::Debug + ToDataValue>(
             // DateTime输入：直接存储为UTC，不做时区转换
             Ok(DataValue::DateTime(dt))
         }
-        DataValue::String(s) => {
+        DataValue::String(_s) => {
             // String输入：使用时区转换逻辑
             crate::convert_string_to_datetime_with_tz(value, timezone_offset)
         }
@@ -102,6 +102,6 @@ mod tests {
     fn test_database_aware_conversion_panic_on_none() {
         let dt = Utc::now();
         // 这个调用应该panic，因为数据库类型未知
-        convert_datetime_with_tz_aware(&dt, "+00:00", None);
+        let _ = convert_datetime_with_tz_aware(&dt, "+00:00", None);
     }
 }
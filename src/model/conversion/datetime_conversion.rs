@@ -54,7 +54,7 @@ fn apply_timezone_to_datetime(
 
     // 解析目标时区偏移
     let target_offset_seconds = tz_offset;
-    let target_tz = chrono::FixedOffset::east(target_offset_seconds);
+    let target_tz = chrono::FixedOffset::east_opt(target_offset_seconds).unwrap();
 
     // 转换为目标时区
     let target_dt = utc_dt.with_timezone(&target_tz);
@@ -70,7 +70,7 @@ fn parse_string_to_datetime_with_tz(
     if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(datetime_str) {
         // 保持FixedOffset格式
         return Ok(DataValue::DateTime(
-            dt.with_timezone(&chrono::FixedOffset::east(0)),
+            dt.with_timezone(&chrono::FixedOffset::east_opt(0).unwrap()),
         ));
     }
 
@@ -106,7 +106,7 @@ fn parse_string_to_datetime_with_tz(
 
                 // 保持FixedOffset格式
                 return Ok(DataValue::DateTime(
-                    aware_dt.with_timezone(&chrono::FixedOffset::east(0)),
+                    aware_dt.with_timezone(&chrono::FixedOffset::east_opt(0).unwrap()),
                 ));
             } else {
                 return Err(QuickDbError::ValidationError {
@@ -0,0 +1,444 @@
+//! FromDataValue / FromDataMap trait 定义
+//!
+//! ToDataValue 的反向转换：直接把 DataValue 解码为 Rust 类型，不经过 serde 和 JSON 中转。
+//! 解码时按值接收 DataValue，字符串、数组和向量直接移动到目标字段，避免额外复制
+
+use crate::error::{QuickDbError, QuickDbResult};
+use crate::types::DataValue;
+use std::collections::HashMap;
+
+/// 支持直接从 DataValue 解码的 trait
+pub trait FromDataValue: Sized {
+    fn from_data_value(value: DataValue) -> QuickDbResult<Self>;
+}
+
+/// 支持直接从数据映射解码的 trait
+///
+/// `define_model!` 宏会自动实现；普通结构体可使用 `impl_from_data_map!` 宏实现
+pub trait FromDataMap: Sized {
+    fn decode_data_map(data: HashMap<String, DataValue>) -> QuickDbResult<Self>;
+}
+
+/// 构造类型不匹配错误
+pub fn type_mismatch<T>(value: &DataValue) -> QuickDbError {
+    QuickDbError::SerializationError {
+        message: crate::i18n::tf(
+            "model.data_value_type_mismatch",
+            &[
+                ("expected", std::any::type_name::<T>()),
+                ("actual", value.type_name()),
+            ],
+        ),
+    }
+}
+
+/// 从数据映射中取出并解码字段，字段缺失时按 Null 处理
+pub fn decode_field<T: FromDataValue>(
+    data: &mut HashMap<String, DataValue>,
+    field: &str,
+) -> QuickDbResult<T> {
+    let value = data.remove(field).unwrap_or(DataValue::Null);
+    T::from_data_value(value).map_err(|e| QuickDbError::SerializationError {
+        message: crate::i18n::tf(
+            "model.field_decode_failed",
+            &[("field", field), ("message", &e.to_string())],
+        ),
+    })
+}
+
+/// 取出嵌入文档的数据映射
+///
+/// SQL 数据库以 JSON/JSONB 存储嵌入文档，读取结果可能是对象、JSON 值或 JSON 字符串
+pub fn embedded_data_map<T>(value: DataValue) -> QuickDbResult<HashMap<String, DataValue>> {
+    match value {
+        DataValue::Object(map) => Ok(map),
+        DataValue::Json(json @ serde_json::Value::Object(_)) => {
+            crate::types::data_value::json_value_to_data_value(json).expect_object()
+        }
+        DataValue::String(ref json_str) => match serde_json::from_str(json_str) {
+            Ok(json @ serde_json::Value::Object(_)) => {
+                crate::types::data_value::json_value_to_data_value(json).expect_object()
+            }
+            _ => Err(type_mismatch::<T>(&value)),
+        },
+        other => Err(type_mismatch::<T>(&other)),
+    }
+}
+
+/// 为结构体实现 FromDataMap
+///
+/// ```ignore
+/// impl_from_data_map!(Profile { nickname, age });
+/// ```
+#[macro_export]
+macro_rules! impl_from_data_map {
+    ($name:ident { $($field:ident),* $(,)? }) => {
+        impl $crate::model::conversion::FromDataMap for $name {
+            #[allow(unused_mut, unused_variables)]
+            fn decode_data_map(
+                mut data: std::collections::HashMap<String, $crate::types::DataValue>,
+            ) -> $crate::error::QuickDbResult<Self> {
+                Ok(Self {
+                    $(
+                        $field: $crate::model::conversion::decode_field(&mut data, stringify!($field))?,
+                    )*
+                })
+            }
+        }
+    };
+}
+
+// 字符串类型实现
+impl FromDataValue for String {
+    fn from_data_value(value: DataValue) -> QuickDbResult<Self> {
+        match value {
+            DataValue::String(s) => Ok(s),
+            // 自增ID等场景下数据库返回整数
+            DataValue::Int(i) => Ok(i.to_string()),
+            DataValue::UInt(u) => Ok(u.to_string()),
+            DataValue::Uuid(u) => Ok(u.to_string()),
+            DataValue::DateTime(dt) => Ok(dt.to_rfc3339()),
+            DataValue::DateTimeUTC(dt) => Ok(dt.to_rfc3339()),
+            DataValue::Bytes(bytes) => {
+                use base64::Engine;
+                Ok(base64::engine::general_purpose::STANDARD.encode(bytes))
+            }
+            // 与 serde 路径保持一致：JSON 值以字符串形式交给调用方
+            DataValue::Json(json) => Ok(json.to_string()),
+            other => Err(type_mismatch::<Self>(&other)),
+        }
+    }
+}
+
+// 整数类型实现（超出目标类型范围时报错）
+macro_rules! impl_from_data_value_for_int {
+    ($($int:ty),*) => {
+        $(
+            impl FromDataValue for $int {
+                fn from_data_value(value: DataValue) -> QuickDbResult<Self> {
+                    let converted = match &value {
+                        DataValue::Int(i) => <$int>::try_from(*i).ok(),
+                        DataValue::UInt(u) => <$int>::try_from(*u).ok(),
+                        _ => None,
+                    };
+                    converted.ok_or_else(|| type_mismatch::<Self>(&value))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_data_value_for_int!(i32, i64, u8, u16, u32, u64, usize);
+
+// 浮点类型实现
+impl FromDataValue for f64 {
+    fn from_data_value(value: DataValue) -> QuickDbResult<Self> {
+        match value {
+            DataValue::Float(f) => Ok(f),
+            DataValue::Int(i) => Ok(i as f64),
+            DataValue::UInt(u) => Ok(u as f64),
+            other => Err(type_mismatch::<Self>(&other)),
+        }
+    }
+}
+
+impl FromDataValue for f32 {
+    fn from_data_value(value: DataValue) -> QuickDbResult<Self> {
+        f64::from_data_value(value).map(|f| f as f32)
+    }
+}
+
+// 布尔类型实现（兼容 SQLite 等以 0/1 存储布尔值的数据库）
+impl FromDataValue for bool {
+    fn from_data_value(value: DataValue) -> QuickDbResult<Self> {
+        match value {
+            DataValue::Bool(b) => Ok(b),
+            DataValue::Int(0) | DataValue::UInt(0) => Ok(false),
+            DataValue::Int(1) | DataValue::UInt(1) => Ok(true),
+            other => Err(type_mismatch::<Self>(&other)),
+        }
+    }
+}
+
+// DateTime类型实现
+impl FromDataValue for chrono::DateTime<chrono::Utc> {
+    fn from_data_value(value: DataValue) -> QuickDbResult<Self> {
+        match value {
+            DataValue::DateTimeUTC(dt) => Ok(dt),
+            DataValue::DateTime(dt) => Ok(dt.with_timezone(&chrono::Utc)),
+            DataValue::String(ref s) => chrono::DateTime::parse_from_rfc3339(s)
+                .map(|dt| dt.with_timezone(&chrono::Utc))
+                .map_err(|_| type_mismatch::<Self>(&value)),
+            other => Err(type_mismatch::<Self>(&other)),
+        }
+    }
+}
+
+impl FromDataValue for chrono::DateTime<chrono::FixedOffset> {
+    fn from_data_value(value: DataValue) -> QuickDbResult<Self> {
+        match value {
+            DataValue::DateTime(dt) => Ok(dt),
+            DataValue::DateTimeUTC(dt) => Ok(dt.fixed_offset()),
+            DataValue::String(ref s) => {
+                chrono::DateTime::parse_from_rfc3339(s).map_err(|_| type_mismatch::<Self>(&value))
+            }
+            other => Err(type_mismatch::<Self>(&other)),
+        }
+    }
+}
+
+// UUID类型实现
+impl FromDataValue for uuid::Uuid {
+    fn from_data_value(value: DataValue) -> QuickDbResult<Self> {
+        match value {
+            DataValue::Uuid(u) => Ok(u),
+            DataValue::String(ref s) => {
+                uuid::Uuid::parse_str(s).map_err(|_| type_mismatch::<Self>(&value))
+            }
+            other => Err(type_mismatch::<Self>(&other)),
+        }
+    }
+}
+
+// JsonValue类型实现
+impl FromDataValue for serde_json::Value {
+    fn from_data_value(value: DataValue) -> QuickDbResult<Self> {
+        match value {
+            DataValue::Json(json) => Ok(json),
+            other => Ok(other.to_json_value()),
+        }
+    }
+}
+
+// Option类型实现
+impl<T> FromDataValue for Option<T>
+where
+    T: FromDataValue,
+{
+    fn from_data_value(value: DataValue) -> QuickDbResult<Self> {
+        match value {
+            DataValue::Null => Ok(None),
+            other => T::from_data_value(other).map(Some),
+        }
+    }
+}
+
+// Vec类型实现
+impl<T> FromDataValue for Vec<T>
+where
+    T: FromDataValue,
+{
+    fn from_data_value(value: DataValue) -> QuickDbResult<Self> {
+        match value {
+            DataValue::Array(items) => items.into_iter().map(T::from_data_value).collect(),
+            DataValue::Vector(items) => items
+                .into_iter()
+                .map(|f| T::from_data_value(DataValue::Float(f as f64)))
+                .collect(),
+            DataValue::Json(json @ serde_json::Value::Array(_)) => {
+                Self::from_data_value(crate::types::data_value::json_value_to_data_value(json))
+            }
+            other => Err(type_mismatch::<Self>(&other)),
+        }
+    }
+}
+
+// HashMap<String, DataValue> 实现
+impl FromDataValue for HashMap<String, DataValue> {
+    fn from_data_value(value: DataValue) -> QuickDbResult<Self> {
+        match value {
+            DataValue::Object(map) => Ok(map),
+            DataValue::Json(json @ serde_json::Value::Object(_)) => {
+                crate::types::data_value::json_value_to_data_value(json).expect_object()
+            }
+            other => Err(type_mismatch::<Self>(&other)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Profile {
+        nickname: String,
+        age: i32,
+        score: Option<f64>,
+        tags: Vec<String>,
+        active: bool,
+    }
+
+    impl_from_data_map!(Profile { nickname, age, score, tags, active });
+
+    #[test]
+    fn decodes_struct_without_serde() {
+        let mut data = HashMap::new();
+        data.insert("nickname".to_string(), DataValue::String("alice".to_string()));
+        data.insert("age".to_string(), DataValue::Int(30));
+        data.insert(
+            "tags".to_string(),
+            DataValue::Array(vec![DataValue::String("rust".to_string())]),
+        );
+        data.insert("active".to_string(), DataValue::Int(1));
+
+        let profile = Profile::decode_data_map(data).unwrap();
+        assert_eq!(
+            profile,
+            Profile {
+                nickname: "alice".to_string(),
+                age: 30,
+                score: None,
+                tags: vec!["rust".to_string()],
+                active: true,
+            }
+        );
+    }
+
+    mod model {
+        use crate::model::*;
+
+        crate::define_model! {
+            struct Account {
+                id: String,
+                name: String,
+                balance: f64,
+                is_active: bool,
+                created_at: chrono::DateTime<chrono::Utc>,
+                tags: Option<Vec<String>>,
+                profile: serde_json::Value,
+            }
+            collection = "accounts",
+            fields = {
+                id: string_field(None, None, None).required(),
+                name: string_field(None, None, None).required(),
+                balance: float_field(None, None),
+                is_active: boolean_field(),
+                created_at: datetime_field(),
+                tags: array_field(crate::field_types!(string), None, None),
+                profile: json_field(),
+            }
+        }
+    }
+
+    #[test]
+    fn define_model_decodes_like_serde_path() {
+        use crate::model::Model;
+        use model::Account;
+
+        let now = chrono::Utc::now();
+        let mut data = HashMap::new();
+        data.insert("id".to_string(), DataValue::Int(7));
+        data.insert("name".to_string(), DataValue::String("alice".to_string()));
+        data.insert("balance".to_string(), DataValue::Int(12));
+        data.insert("is_active".to_string(), DataValue::Bool(true));
+        data.insert("created_at".to_string(), DataValue::DateTimeUTC(now));
+        data.insert(
+            "tags".to_string(),
+            DataValue::Array(vec![DataValue::String("vip".to_string())]),
+        );
+        data.insert(
+            "profile".to_string(),
+            DataValue::Json(serde_json::json!({ "level": 3 })),
+        );
+
+        let account = Account::from_data_map_direct(data.clone()).unwrap();
+        assert_eq!(account.id, "7");
+        assert_eq!(account.balance, 12.0);
+        assert_eq!(account.created_at, now);
+        assert_eq!(account.tags, Some(vec!["vip".to_string()]));
+        assert_eq!(account.profile, serde_json::json!({ "level": 3 }));
+
+        // 与 serde 反序列化路径结果一致（serde 路径无法处理整数ID和JSON对象字段）
+        data.insert("id".to_string(), DataValue::String("7".to_string()));
+        data.remove("profile");
+        data.insert("profile".to_string(), DataValue::Null);
+        let via_serde: Account =
+            crate::model::data_conversion::create_model_from_data_map(&data).unwrap();
+        let direct = Account::from_data_map_direct(data).unwrap();
+        assert_eq!(
+            serde_json::to_value(&via_serde).unwrap(),
+            serde_json::to_value(&direct).unwrap()
+        );
+    }
+
+    mod embedded {
+        use crate::model::*;
+
+        crate::define_model! {
+            embedded struct Address {
+                city: String,
+                zip_code: Option<String>,
+            }
+            rename_all = "camelCase",
+            fields = {
+                city: string_field(None, None, None).required(),
+                zip_code: string_field(None, None, None),
+            }
+        }
+
+        crate::define_model! {
+            struct Customer {
+                id: String,
+                address: Address,
+                billing_address: Option<Address>,
+            }
+            collection = "customers",
+            fields = {
+                id: string_field(None, None, None),
+                address: embedded_field::<Address>().required(),
+                billing_address: embedded_field::<Address>(),
+            }
+        }
+    }
+
+    #[test]
+    fn embedded_models_round_trip_as_objects() {
+        use crate::model::Model;
+        use embedded::{Address, Customer};
+
+        let customer = Customer {
+            id: "c1".to_string(),
+            address: Address {
+                city: "上海".to_string(),
+                zip_code: Some("200000".to_string()),
+            },
+            billing_address: None,
+        };
+        customer.validate().unwrap();
+
+        let mut data = customer.to_data_map().unwrap();
+        let address = match data.get("address") {
+            Some(DataValue::Object(address)) => address.clone(),
+            other => panic!("unexpected address: {:?}", other),
+        };
+        assert_eq!(address.get("zipCode"), Some(&DataValue::String("200000".to_string())));
+
+        // SQL 数据库读取到的是 JSON 文本
+        let json = DataValue::Object(address).to_json_value().to_string();
+        data.insert("address".to_string(), DataValue::String(json));
+        let decoded = Customer::from_data_map(data).unwrap();
+        assert_eq!(decoded.address.city, "上海");
+        assert_eq!(decoded.address.zip_code.as_deref(), Some("200000"));
+        assert!(decoded.billing_address.is_none());
+
+        // 嵌入模型的必填字段参与验证
+        let mut address = HashMap::new();
+        address.insert("zipCode".to_string(), DataValue::String("200000".to_string()));
+        let billing_address = &Customer::meta().fields["billing_address"];
+        assert!(billing_address.validate(&DataValue::Object(address)).is_err());
+    }
+
+    #[test]
+    fn reports_field_on_type_mismatch() {
+        crate::i18n::ErrorMessageI18n::init_i18n();
+        let mut data = HashMap::new();
+        data.insert("nickname".to_string(), DataValue::String("bob".to_string()));
+        data.insert("age".to_string(), DataValue::Int(i64::MAX));
+
+        match Profile::decode_data_map(data).unwrap_err() {
+            QuickDbError::SerializationError { message } => assert!(message.contains("'age'")),
+            other => panic!("unexpected error: {:?}", other),
+        }
+    }
+}
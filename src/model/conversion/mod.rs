@@ -1,13 +1,18 @@
 //! 数据转换模块
 //!
-//! 提供 ToDataValue trait 及其实现，用于将各种类型转换为 DataValue
+//! 提供 ToDataValue trait 及其实现，用于将各种类型转换为 DataValue，
+//! 以及反向的 FromDataValue / FromDataMap 直接解码
 
 pub mod collection_impls;
 pub mod complex_impls;
 pub mod database_aware;
 pub mod datetime_conversion;
+pub mod from_data_value;
 pub mod primitive_impls;
 pub mod to_data_value;
 
 // 重新导出核心 trait
+pub use from_data_value::{
+    FromDataMap, FromDataValue, decode_field, embedded_data_map, type_mismatch,
+};
 pub use to_data_value::ToDataValue;
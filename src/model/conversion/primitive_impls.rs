@@ -85,7 +85,7 @@ impl ToDataValue for bool {
 // DateTime类型实现
 impl ToDataValue for chrono::DateTime<chrono::Utc> {
     fn to_data_value(&self) -> DataValue {
-        let fixed_dt = self.with_timezone(&chrono::FixedOffset::east(0));
+        let fixed_dt = self.with_timezone(&chrono::FixedOffset::east_opt(0).unwrap());
         DataValue::DateTime(fixed_dt)
     }
 }
@@ -2,9 +2,10 @@
 //!
 //! 提供从DataValue映射到模型实例的直接转换功能
 
-use crate::debug_log;
 use crate::error::{QuickDbError, QuickDbResult};
 use crate::types::DataValue;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
 use std::collections::HashMap;
 
 /// 从DataValue映射直接创建模型实例
@@ -32,9 +33,7 @@ pub fn create_model_from_data_map_with_debug<T>(
 where
     T: serde::de::DeserializeOwned,
 {
-    let result = create_model_from_data_map::<T>(data_map);
-
-    result
+    create_model_from_data_map::<T>(data_map)
 }
 
 /// DataValue反序列化器
@@ -42,22 +41,11 @@ where
 /// 实现serde::de::Deserializer trait，直接从DataValue读取数据
 struct DataValueDeserializer<'a> {
     data_map: &'a HashMap<String, DataValue>,
-    current_key: Option<String>,
 }
 
 impl<'a> DataValueDeserializer<'a> {
     fn new(data_map: &'a HashMap<String, DataValue>) -> Self {
-        Self {
-            data_map,
-            current_key: None,
-        }
-    }
-
-    fn get_current_value(&self) -> Option<&'a DataValue> {
-        match &self.current_key {
-            Some(key) => self.data_map.get(key),
-            None => None,
-        }
+        Self { data_map }
     }
 }
 
@@ -65,7 +53,7 @@ impl<'a, 'de> serde::de::Deserializer<'de> for DataValueDeserializer<'a> {
     type Error = serde_json::Error;
 
     fn deserialize_struct<V>(
-        mut self,
+        self,
         _name: &'static str,
         fields: &'static [&'static str],
         visitor: V,
@@ -74,21 +62,21 @@ impl<'a, 'de> serde::de::Deserializer<'de> for DataValueDeserializer<'a> {
         V: serde::de::Visitor<'de>,
     {
         // 为结构体创建字段访问器
-        visitor.visit_map(DataValueStructDeserializer::new(&self.data_map, fields))
+        visitor.visit_map(DataValueStructDeserializer::new(self.data_map, fields))
     }
 
     fn deserialize_map<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: serde::de::Visitor<'de>,
     {
-        visitor.visit_map(DataValueMapDeserializer::new(&self.data_map))
+        visitor.visit_map(DataValueMapDeserializer::new(self.data_map))
     }
 
     fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: serde::de::Visitor<'de>,
     {
-        visitor.visit_map(DataValueMapDeserializer::new(&self.data_map))
+        visitor.visit_map(DataValueMapDeserializer::new(self.data_map))
     }
 
     fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
@@ -253,7 +241,7 @@ impl<'a, 'de> serde::de::Deserializer<'de> for DataValueSingleDeserializer<'a> {
                 visitor.visit_map(deserializer)
             }
             DataValue::Bytes(bytes) => {
-                let base64_str = base64::encode(bytes);
+                let base64_str = STANDARD.encode(bytes);
                 visitor.visit_str(&base64_str)
             }
             DataValue::DateTime(dt) => visitor.visit_str(&dt.to_rfc3339()),
@@ -349,7 +337,7 @@ mod tests {
         assert_eq!(model.id, "test-123");
         assert_eq!(model.name, "测试");
         assert_eq!(model.age, 25);
-        assert_eq!(model.active, true);
+        assert!(model.active);
     }
 
     // ===== i18n 测试 =====
@@ -0,0 +1,220 @@
+//! 字段默认值模块
+//!
+//! 默认值分为静态值与生成器两类：静态值直接写入，生成器（当前时间、UUID、ULID
+//! 或通过 [`register_generator`] 注册的闭包）在适配器插入记录时求值。
+//! 仅在字段缺失或为空值时填充，更新和 upsert 不会写入默认值
+
+use crate::error::{QuickDbError, QuickDbResult};
+use crate::model::field_types::{FieldDefinition, FieldType};
+use crate::types::{DataValue, DatabaseType};
+use dashmap::DashMap;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// 自定义默认值生成函数
+pub type GeneratorFn = Arc<dyn Fn() -> DataValue + Send + Sync>;
+
+/// 全局默认值生成器注册表 (名称 -> 生成函数)
+static GENERATORS: Lazy<DashMap<String, GeneratorFn>> = Lazy::new(DashMap::new);
+
+/// 注册自定义默认值生成器，同名生成器会被替换
+pub fn register_generator<F>(name: &str, generator: F)
+where
+    F: Fn() -> DataValue + Send + Sync + 'static,
+{
+    GENERATORS.insert(name.to_string(), Arc::new(generator));
+}
+
+/// 移除已注册的默认值生成器
+pub fn unregister_generator(name: &str) -> bool {
+    GENERATORS.remove(name).is_some()
+}
+
+/// 默认值生成器
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DefaultGenerator {
+    /// 插入时的当前时间
+    Now,
+    /// 随机 UUID（v4）
+    UuidV4,
+    /// ULID（按时间排序的唯一标识，26 位 Crockford Base32 字符串）
+    Ulid,
+    /// 通过 `register_generator` 注册的自定义生成器
+    Custom(String),
+}
+
+/// 字段默认值
+///
+/// 序列化时不带类型标签，旧版本只包含静态 `DataValue` 的元数据可以直接读取
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum DefaultValue {
+    /// 静态默认值
+    Static(DataValue),
+    /// 插入时生成的默认值
+    Generated(DefaultGenerator),
+}
+
+impl From<DataValue> for DefaultValue {
+    fn from(value: DataValue) -> Self {
+        DefaultValue::Static(value)
+    }
+}
+
+impl From<DefaultGenerator> for DefaultValue {
+    fn from(generator: DefaultGenerator) -> Self {
+        DefaultValue::Generated(generator)
+    }
+}
+
+impl DefaultValue {
+    /// 按字段类型与数据库类型求出默认值
+    pub fn resolve(&self, field_type: &FieldType, db_type: DatabaseType) -> QuickDbResult<DataValue> {
+        match self {
+            DefaultValue::Static(value) => Ok(value.clone()),
+            DefaultValue::Generated(DefaultGenerator::Now) => {
+                let now = chrono::Utc::now();
+                match field_type {
+                    FieldType::DateTimeWithTz { timezone_offset } => {
+                        crate::convert_datetime_with_tz_aware(&now, timezone_offset, Some(db_type))
+                    }
+                    FieldType::Integer { .. } | FieldType::BigInteger => {
+                        Ok(DataValue::Int(now.timestamp()))
+                    }
+                    _ => Ok(DataValue::DateTime(now.fixed_offset())),
+                }
+            }
+            DefaultValue::Generated(DefaultGenerator::UuidV4) => {
+                let uuid = uuid::Uuid::new_v4();
+                match field_type {
+                    FieldType::Uuid => Ok(DataValue::Uuid(uuid)),
+                    _ => Ok(DataValue::String(uuid.to_string())),
+                }
+            }
+            DefaultValue::Generated(DefaultGenerator::Ulid) => Ok(DataValue::String(generate_ulid())),
+            DefaultValue::Generated(DefaultGenerator::Custom(name)) => {
+                let generator = GENERATORS
+                    .get(name)
+                    .map(|entry| entry.value().clone())
+                    .ok_or_else(|| QuickDbError::ValidationError {
+                        field: "default".to_string(),
+                        message: crate::i18n::tf(
+                            "validation.default_generator_not_registered",
+                            &[("name", name)],
+                        ),
+                    })?;
+                Ok(generator())
+            }
+        }
+    }
+}
+
+/// 为缺失或为空值的字段填充默认值（ID 字段由 ID 策略处理，不在此填充）
+pub fn apply_defaults(
+    fields: &HashMap<String, FieldDefinition>,
+    db_type: DatabaseType,
+    data: &mut HashMap<String, DataValue>,
+) -> QuickDbResult<()> {
+    for (field_name, field_def) in fields {
+        let Some(default) = &field_def.default else {
+            continue;
+        };
+        if field_name == "id" || field_name == "_id" {
+            continue;
+        }
+        if matches!(data.get(field_name), None | Some(DataValue::Null)) {
+            let value = default.resolve(&field_def.field_type, db_type)?;
+            data.insert(field_name.clone(), value);
+        }
+    }
+    Ok(())
+}
+
+/// 生成 ULID：48 位毫秒时间戳 + 80 位随机数，Crockford Base32 编码
+pub fn generate_ulid() -> String {
+    const ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+    let timestamp = chrono::Utc::now().timestamp_millis().max(0) as u128 & ((1 << 48) - 1);
+    let randomness = rand::random::<u128>() & ((1 << 80) - 1);
+    let mut value = (timestamp << 80) | randomness;
+
+    let mut encoded = [0u8; 26];
+    for slot in encoded.iter_mut().rev() {
+        *slot = ALPHABET[(value & 0x1f) as usize];
+        value >>= 5;
+    }
+    String::from_utf8_lossy(&encoded).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::convenience::{integer_field, string_field, uuid_field};
+
+    #[test]
+    fn fills_missing_and_null_fields_only() {
+        let mut fields = HashMap::new();
+        fields.insert(
+            "status".to_string(),
+            string_field(None, None, None).default_value(DataValue::String("draft".to_string())),
+        );
+        fields.insert(
+            "token".to_string(),
+            uuid_field().default_generated(DefaultGenerator::UuidV4),
+        );
+        fields.insert(
+            "created_at".to_string(),
+            FieldDefinition::new(FieldType::DateTime).default_generated(DefaultGenerator::Now),
+        );
+        fields.insert(
+            "created_ts".to_string(),
+            integer_field(None, None).default_generated(DefaultGenerator::Now),
+        );
+        fields.insert(
+            "id".to_string(),
+            string_field(None, None, None).default_generated(DefaultGenerator::Ulid),
+        );
+
+        let mut data = HashMap::new();
+        data.insert("status".to_string(), DataValue::String("published".to_string()));
+        data.insert("token".to_string(), DataValue::Null);
+        apply_defaults(&fields, DatabaseType::PostgreSQL, &mut data).unwrap();
+
+        assert_eq!(data["status"], DataValue::String("published".to_string()));
+        assert!(matches!(data["token"], DataValue::Uuid(_)));
+        assert!(matches!(data["created_at"], DataValue::DateTime(_)));
+        assert!(matches!(data["created_ts"], DataValue::Int(ts) if ts > 0));
+        assert!(!data.contains_key("id"));
+    }
+
+    #[test]
+    fn generates_sortable_ulids_and_custom_values() {
+        let first = generate_ulid();
+        assert_eq!(first.len(), 26);
+        assert!(first.bytes().all(|b| b"0123456789ABCDEFGHJKMNPQRSTVWXYZ".contains(&b)));
+        std::thread::sleep(std::time::Duration::from_millis(2));
+        assert!(generate_ulid()[..10] > first[..10]);
+
+        register_generator("test_region", || DataValue::String("cn-north".to_string()));
+        let default = DefaultValue::Generated(DefaultGenerator::Custom("test_region".to_string()));
+        assert_eq!(
+            default.resolve(&FieldType::Text, DatabaseType::SQLite).unwrap(),
+            DataValue::String("cn-north".to_string())
+        );
+        assert!(unregister_generator("test_region"));
+        assert!(default.resolve(&FieldType::Text, DatabaseType::SQLite).is_err());
+    }
+
+    #[test]
+    fn reads_legacy_static_defaults() {
+        let legacy = serde_json::json!({"String": "draft"});
+        let default: DefaultValue = serde_json::from_value(legacy).unwrap();
+        assert_eq!(default, DefaultValue::Static(DataValue::String("draft".to_string())));
+
+        let generated = serde_json::to_value(DefaultValue::Generated(DefaultGenerator::Now)).unwrap();
+        let parsed: DefaultValue = serde_json::from_value(generated).unwrap();
+        assert_eq!(parsed, DefaultValue::Generated(DefaultGenerator::Now));
+    }
+}
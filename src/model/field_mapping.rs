@@ -0,0 +1,410 @@
+//! 字段名映射模块
+//!
+//! 让 Rust 的 snake_case 字段映射到数据库中既有的字段/列名：
+//! 字段级 `rename` 优先，其次是模型级 `rename_all` 命名规则。
+//! 映射只发生在模型层边界，`ModelMeta.fields` 等元数据均以存储字段名为键，
+//! 适配器无需感知 Rust 字段名
+
+use crate::model::field_types::ModelMeta;
+use crate::types::{
+    DataValue, QueryConditionGroup, QueryConditionGroupWithConfig, QueryConditionWithConfig, QueryOptions,
+    UpdateOperation, UpdateOperator,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// 字段命名规则（命名与 serde 的 `rename_all` 保持一致）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RenameRule {
+    /// `lowercase`
+    #[serde(rename = "lowercase")]
+    LowerCase,
+    /// `UPPERCASE`
+    #[serde(rename = "UPPERCASE")]
+    UpperCase,
+    /// `PascalCase`
+    #[serde(rename = "PascalCase")]
+    PascalCase,
+    /// `camelCase`
+    #[serde(rename = "camelCase")]
+    CamelCase,
+    /// `snake_case`
+    #[serde(rename = "snake_case")]
+    SnakeCase,
+    /// `SCREAMING_SNAKE_CASE`
+    #[serde(rename = "SCREAMING_SNAKE_CASE")]
+    ScreamingSnakeCase,
+    /// `kebab-case`
+    #[serde(rename = "kebab-case")]
+    KebabCase,
+    /// `SCREAMING-KEBAB-CASE`
+    #[serde(rename = "SCREAMING-KEBAB-CASE")]
+    ScreamingKebabCase,
+}
+
+impl RenameRule {
+    /// 根据规则名称解析，例如 `"camelCase"`
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "lowercase" => Some(Self::LowerCase),
+            "UPPERCASE" => Some(Self::UpperCase),
+            "PascalCase" => Some(Self::PascalCase),
+            "camelCase" => Some(Self::CamelCase),
+            "snake_case" => Some(Self::SnakeCase),
+            "SCREAMING_SNAKE_CASE" => Some(Self::ScreamingSnakeCase),
+            "kebab-case" => Some(Self::KebabCase),
+            "SCREAMING-KEBAB-CASE" => Some(Self::ScreamingKebabCase),
+            _ => None,
+        }
+    }
+
+    /// 将 snake_case 字段名转换为目标命名
+    pub fn apply(&self, field: &str) -> String {
+        match self {
+            Self::LowerCase | Self::SnakeCase => field.to_string(),
+            Self::UpperCase => field.to_ascii_uppercase(),
+            Self::PascalCase => field
+                .split('_')
+                .map(|word| {
+                    let mut chars = word.chars();
+                    match chars.next() {
+                        Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                        None => String::new(),
+                    }
+                })
+                .collect(),
+            Self::CamelCase => {
+                let pascal = Self::PascalCase.apply(field);
+                let mut chars = pascal.chars();
+                match chars.next() {
+                    Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+                    None => String::new(),
+                }
+            }
+            Self::ScreamingSnakeCase => field.to_ascii_uppercase(),
+            Self::KebabCase => field.replace('_', "-"),
+            Self::ScreamingKebabCase => field.replace('_', "-").to_ascii_uppercase(),
+        }
+    }
+}
+
+impl ModelMeta {
+    /// 应用字段级 `rename` 和模型级 `rename_all`，将元数据改为以存储字段名为键
+    ///
+    /// 同时改写索引、主键和过期配置中引用的字段名，并记录 Rust 字段名到存储字段名的映射
+    pub fn with_field_names(mut self) -> Self {
+        let fields = std::mem::take(&mut self.fields);
+        for (field, definition) in fields {
+            let storage = match (&definition.rename, self.rename_all) {
+                (Some(rename), _) => rename.clone(),
+                (None, Some(rule)) => rule.apply(&field),
+                (None, None) => field.clone(),
+            };
+            if storage != field {
+                self.field_names.insert(field, storage.clone());
+            }
+            self.fields.insert(storage, definition);
+        }
+
+        if self.field_names.is_empty() {
+            return self;
+        }
+
+        let field_names = &self.field_names;
+        let rename = |field: &mut String| {
+            if let Some(storage) = field_names.get(field.as_str()) {
+                *field = storage.clone();
+            }
+        };
+        for index in &mut self.indexes {
+            index.fields.iter_mut().for_each(rename);
+        }
+        self.primary_key.iter_mut().for_each(rename);
+        if let Some(expires_after) = &mut self.expires_after {
+            rename(&mut expires_after.field);
+        }
+        for rule in &mut self.validation_rules {
+            rule.fields_mut().into_iter().for_each(rename);
+        }
+        let scopes = &mut self.scopes;
+        for scope in scopes.default.iter_mut().chain(scopes.named.values_mut()) {
+            scope.fields_mut().for_each(rename);
+        }
+        self
+    }
+
+    /// 获取 Rust 字段名对应的存储字段名（未重命名或未知字段原样返回）
+    pub fn storage_field_name<'a>(&'a self, field: &'a str) -> &'a str {
+        self.field_names.get(field).map(String::as_str).unwrap_or(field)
+    }
+
+    /// 获取存储字段名对应的 Rust 字段名（未重命名或未知字段原样返回）
+    pub fn rust_field_name<'a>(&'a self, storage: &'a str) -> &'a str {
+        self.field_names
+            .iter()
+            .find(|(_, name)| name.as_str() == storage)
+            .map(|(field, _)| field.as_str())
+            .unwrap_or(storage)
+    }
+
+    /// 将数据映射的键从 Rust 字段名改写为存储字段名
+    pub fn map_data_fields(&self, data: HashMap<String, DataValue>) -> HashMap<String, DataValue> {
+        if self.field_names.is_empty() {
+            return data;
+        }
+        data.into_iter()
+            .map(|(field, value)| (self.storage_field_name(&field).to_string(), value))
+            .collect()
+    }
+
+    /// 将数据映射的键从存储字段名改写回 Rust 字段名
+    pub fn unmap_data_fields(&self, data: HashMap<String, DataValue>) -> HashMap<String, DataValue> {
+        if self.field_names.is_empty() {
+            return data;
+        }
+        data.into_iter()
+            .map(|(field, value)| (self.rust_field_name(&field).to_string(), value))
+            .collect()
+    }
+
+    /// 将查询条件中的字段名改写为存储字段名
+    pub fn map_conditions(
+        &self,
+        mut conditions: Vec<QueryConditionWithConfig>,
+    ) -> Vec<QueryConditionWithConfig> {
+        for condition in &mut conditions {
+            self.rename_field(&mut condition.field);
+        }
+        conditions
+    }
+
+    /// 将条件组中的字段名改写为存储字段名
+    pub fn map_condition_groups(&self, groups: Vec<QueryConditionGroup>) -> Vec<QueryConditionGroup> {
+        groups
+            .into_iter()
+            .map(|group| match group {
+                QueryConditionGroup::Single(mut condition) => {
+                    self.rename_field(&mut condition.field);
+                    QueryConditionGroup::Single(condition)
+                }
+                QueryConditionGroup::Group { operator, conditions } => QueryConditionGroup::Group {
+                    operator,
+                    conditions: self.map_condition_groups(conditions),
+                },
+            })
+            .collect()
+    }
+
+    /// 将条件组（带配置）中的字段名改写为存储字段名
+    pub fn map_condition_groups_with_config(
+        &self,
+        groups: Vec<QueryConditionGroupWithConfig>,
+    ) -> Vec<QueryConditionGroupWithConfig> {
+        groups
+            .into_iter()
+            .map(|group| match group {
+                QueryConditionGroupWithConfig::Single(mut condition) => {
+                    self.rename_field(&mut condition.field);
+                    QueryConditionGroupWithConfig::Single(condition)
+                }
+                QueryConditionGroupWithConfig::GroupWithConfig { operator, conditions } => {
+                    QueryConditionGroupWithConfig::GroupWithConfig {
+                        operator,
+                        conditions: self.map_condition_groups_with_config(conditions),
+                    }
+                }
+            })
+            .collect()
+    }
+
+    /// 将查询选项中的条件、排序和投影字段名改写为存储字段名
+    pub fn map_query_options(&self, options: Option<QueryOptions>) -> Option<QueryOptions> {
+        let mut options = options?;
+        if self.field_names.is_empty() {
+            return Some(options);
+        }
+        options.conditions = self.map_conditions(options.conditions);
+        for sort in &mut options.sort {
+            self.rename_field(&mut sort.field);
+        }
+        for field in &mut options.fields {
+            self.rename_field(field);
+        }
+        Some(options)
+    }
+
+    /// 将更新操作中的字段名改写为存储字段名
+    pub fn map_update_operations(&self, mut operations: Vec<UpdateOperation>) -> Vec<UpdateOperation> {
+        for operation in &mut operations {
+            self.rename_field(&mut operation.field);
+            if operation.operation != UpdateOperator::Rename {
+                continue;
+            }
+            if let DataValue::String(target) = &mut operation.value {
+                self.rename_field(target);
+            }
+        }
+        operations
+    }
+
+    fn rename_field(&self, field: &mut String) {
+        if let Some(storage) = self.field_names.get(field.as_str()) {
+            *field = storage.clone();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::convenience::{datetime_field, string_field};
+    use crate::model::field_types::{ExpiresAfter, IndexDefinition, IndexOptions};
+    use crate::types::{QueryCondition, QueryOperator, SortConfig, SortDirection};
+
+    #[test]
+    fn rename_rules_follow_serde_names() {
+        let cases = [
+            ("camelCase", "createdAt"),
+            ("PascalCase", "CreatedAt"),
+            ("snake_case", "created_at"),
+            ("SCREAMING_SNAKE_CASE", "CREATED_AT"),
+            ("kebab-case", "created-at"),
+            ("SCREAMING-KEBAB-CASE", "CREATED-AT"),
+        ];
+        for (name, expected) in cases {
+            let rule = RenameRule::from_name(name).unwrap();
+            assert_eq!(rule.apply("created_at"), expected);
+        }
+        assert!(RenameRule::from_name("camel").is_none());
+    }
+
+    fn user_meta() -> ModelMeta {
+        let mut fields = HashMap::new();
+        fields.insert("id".to_string(), string_field(None, None, None));
+        fields.insert("user_name".to_string(), string_field(None, None, None));
+        fields.insert(
+            "created_at".to_string(),
+            datetime_field().rename("creation_time"),
+        );
+
+        ModelMeta {
+            collection_name: "users".to_string(),
+            fields,
+            indexes: vec![IndexDefinition {
+                fields: vec!["user_name".to_string()],
+                unique: true,
+                name: None,
+                options: IndexOptions::default(),
+            }],
+            expires_after: Some(ExpiresAfter::new("created_at", 60)),
+            rename_all: Some(RenameRule::CamelCase),
+            ..Default::default()
+        }
+        .with_field_names()
+    }
+
+    mod model {
+        use crate::model::*;
+
+        crate::define_model! {
+            struct LegacyUser {
+                id: String,
+                user_name: String,
+                login_count: i64,
+            }
+            collection = "legacy_users",
+            rename_all = "camelCase",
+            fields = {
+                id: string_field(None, None, None).required(),
+                user_name: string_field(None, None, None).required(),
+                login_count: integer_field(None, None).rename("logins"),
+            }
+            indexes = [
+                { fields: ["user_name"], unique: true },
+            ],
+        }
+    }
+
+    #[test]
+    fn define_model_round_trips_through_storage_names() {
+        use crate::model::Model;
+        use model::LegacyUser;
+
+        let user = LegacyUser {
+            id: "u1".to_string(),
+            user_name: "alice".to_string(),
+            login_count: 3,
+        };
+        let data = user.to_data_map().unwrap();
+        assert_eq!(data.get("userName"), Some(&DataValue::String("alice".to_string())));
+        assert_eq!(data.get("logins"), Some(&DataValue::Int(3)));
+        assert!(!data.contains_key("user_name"));
+
+        let meta = LegacyUser::meta();
+        assert_eq!(meta.rename_all, Some(RenameRule::CamelCase));
+        assert!(meta.fields.contains_key("userName"));
+        assert_eq!(meta.indexes[0].fields, ["userName"]);
+
+        let decoded = LegacyUser::from_data_map(data).unwrap();
+        assert_eq!(decoded.user_name, "alice");
+        assert_eq!(decoded.login_count, 3);
+    }
+
+    #[test]
+    fn metadata_is_keyed_by_storage_names() {
+        let meta = user_meta();
+        let mut storage: Vec<_> = meta.fields.keys().cloned().collect();
+        storage.sort();
+        // 字段级 rename 优先于 rename_all
+        assert_eq!(storage, ["creation_time", "id", "userName"]);
+        assert_eq!(meta.indexes[0].fields, ["userName"]);
+        assert_eq!(meta.expires_after.as_ref().unwrap().field, "creation_time");
+        assert_eq!(meta.storage_field_name("user_name"), "userName");
+        assert_eq!(meta.rust_field_name("creation_time"), "created_at");
+        assert_eq!(meta.storage_field_name("unknown"), "unknown");
+    }
+
+    #[test]
+    fn maps_conditions_options_and_updates() {
+        let meta = user_meta();
+        let condition = QueryCondition {
+            field: "user_name".to_string(),
+            operator: QueryOperator::Eq,
+            value: DataValue::String("alice".to_string()),
+        };
+
+        let conditions = meta.map_conditions(vec![condition.clone().into()]);
+        assert_eq!(conditions[0].field, "userName");
+
+        let groups = meta.map_condition_groups(vec![QueryConditionGroup::Group {
+            operator: crate::types::LogicalOperator::Or,
+            conditions: vec![condition.into()],
+        }]);
+        match &groups[0] {
+            QueryConditionGroup::Group { conditions, .. } => match &conditions[0] {
+                QueryConditionGroup::Single(c) => assert_eq!(c.field, "userName"),
+                other => panic!("unexpected group: {:?}", other),
+            },
+            other => panic!("unexpected group: {:?}", other),
+        }
+
+        let mut options = QueryOptions::new();
+        options.sort.push(SortConfig {
+            field: "created_at".to_string(),
+            direction: SortDirection::Desc,
+        });
+        options.fields.push("user_name".to_string());
+        let options = meta.map_query_options(Some(options)).unwrap();
+        assert_eq!(options.sort[0].field, "creation_time");
+        assert_eq!(options.fields, ["userName"]);
+
+        let mut updates = HashMap::new();
+        updates.insert("user_name".to_string(), DataValue::String("bob".to_string()));
+        let stored = meta.map_data_fields(updates);
+        assert!(stored.contains_key("userName"));
+        assert!(meta.unmap_data_fields(stored).contains_key("user_name"));
+
+        let operations = meta.map_update_operations(vec![UpdateOperation::set("user_name", "bob")]);
+        assert_eq!(operations[0].field, "userName");
+    }
+}
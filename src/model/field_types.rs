@@ -65,8 +65,18 @@ pub enum FieldType {
     },
     /// 引用类型（外键）
     Reference { target_collection: String },
+    /// 多态引用类型（同时存储目标集合名与ID）
+    ///
+    /// 值以 `{"collection": ..., "id": ...}` 对象存储，`target_collections`
+    /// 限定允许引用的集合
+    PolymorphicReference { target_collections: Vec<String> },
     /// 向量类型（用于 pgvector 向量搜索）
     Vector { dimension: usize },
+    /// 序列类型（主键之外的单调递增整数，如发票号）
+    ///
+    /// 插入时若未提供值，则从名为 `name` 的序列中分配下一个值。
+    /// 同名序列可在多个表之间共享。
+    Sequence { name: String },
 }
 
 /// 字段定义
@@ -76,18 +86,24 @@ pub struct FieldDefinition {
     pub field_type: FieldType,
     /// 是否必填
     pub required: bool,
-    /// 默认值
-    pub default: Option<DataValue>,
+    /// 默认值（静态值或插入时求值的生成器）
+    pub default: Option<crate::model::defaults::DefaultValue>,
     /// 是否唯一
     pub unique: bool,
     /// 是否建立索引
     pub indexed: bool,
     /// 字段描述
     pub description: Option<String>,
-    /// 自定义验证函数名
+    /// 自定义验证函数名（通过 `model::validators::register` 注册）
     pub validator: Option<String>,
     /// SQLite 布尔值兼容性
     pub sqlite_compatibility: bool,
+    /// 存储字段名（数据库中的字段/列名，优先于模型级 `rename_all`）
+    #[serde(default)]
+    pub rename: Option<String>,
+    /// 是否为计算字段（由其他字段推导，不写入数据库，加载后由 `compute_fields()` 填充）
+    #[serde(default)]
+    pub computed: bool,
 }
 
 impl FieldDefinition {
@@ -102,6 +118,8 @@ impl FieldDefinition {
             description: None,
             validator: None,
             sqlite_compatibility: false,
+            rename: None,
+            computed: false,
         }
     }
 
@@ -113,7 +131,13 @@ impl FieldDefinition {
 
     /// 设置默认值
     pub fn default_value(mut self, value: DataValue) -> Self {
-        self.default = Some(value);
+        self.default = Some(value.into());
+        self
+    }
+
+    /// 设置插入时生成的默认值（当前时间、UUID、ULID 或自定义生成器）
+    pub fn default_generated(mut self, generator: crate::model::defaults::DefaultGenerator) -> Self {
+        self.default = Some(generator.into());
         self
     }
 
@@ -135,12 +159,24 @@ impl FieldDefinition {
         self
     }
 
-    /// 设置验证函数
+    /// 设置验证函数（名称对应 `model::validators::register` 注册的异步验证器）
     pub fn validator(mut self, validator_name: &str) -> Self {
         self.validator = Some(validator_name.to_string());
         self
     }
 
+    /// 设置为计算字段（不写入数据库，加载后由 `Model::compute_fields()` 填充）
+    pub fn computed(mut self) -> Self {
+        self.computed = true;
+        self
+    }
+
+    /// 设置存储字段名，用于映射到数据库中既有的字段/列名
+    pub fn rename(mut self, name: &str) -> Self {
+        self.rename = Some(name.to_string());
+        self
+    }
+
     /// 设置 SQLite 兼容性
     pub fn with_sqlite_compatibility(mut self, compatible: bool) -> Self {
         self.sqlite_compatibility = compatible;
@@ -149,7 +185,7 @@ impl FieldDefinition {
 
     /// 设置默认值（别名方法，提供更直观的API）
     pub fn with_default(mut self, value: DataValue) -> Self {
-        self.default = Some(value);
+        self.default = Some(value.into());
         self
     }
 
@@ -163,8 +199,15 @@ impl FieldDefinition {
         value: &DataValue,
         field_name: &str,
     ) -> QuickDbResult<()> {
-        // 检查必填字段
-        if self.required && matches!(value, DataValue::Null) {
+        // 序列字段的空值会在插入时自动分配，因此不视为缺失
+        if matches!(self.field_type, FieldType::Sequence { .. })
+            && matches!(value, DataValue::Null)
+        {
+            return Ok(());
+        }
+
+        // 检查必填字段（声明了默认值的字段在插入时填充，不视为缺失）
+        if self.required && self.default.is_none() && matches!(value, DataValue::Null) {
             return Err(QuickDbError::ValidationError {
                 field: field_name.to_string(),
                 message: crate::i18n::t("validation.required_empty"),
@@ -521,6 +564,18 @@ impl FieldDefinition {
                     });
                 }
             }
+            FieldType::PolymorphicReference { target_collections } => {
+                let reference = <crate::model::PolymorphicRef as crate::model::FromDataValue>::from_data_value(
+                    value.clone(),
+                )
+                .map_err(|_| {
+                        QuickDbError::ValidationError {
+                            field: "polymorphic_reference".to_string(),
+                            message: crate::i18n::t("validation.type_polymorphic_reference"),
+                        }
+                    })?;
+                reference.validate_target(target_collections)?;
+            }
             FieldType::BigInteger => {
                 if !matches!(value, DataValue::Int(_)) {
                     return Err(QuickDbError::ValidationError {
@@ -605,6 +660,14 @@ impl FieldDefinition {
                     }
                 }
             }
+            FieldType::Sequence { .. } => {
+                if !matches!(value, DataValue::Int(_)) {
+                    return Err(QuickDbError::ValidationError {
+                        field: "type_mismatch".to_string(),
+                        message: crate::i18n::t("validation.type_integer"),
+                    });
+                }
+            }
         }
 
         Ok(())
@@ -612,7 +675,7 @@ impl FieldDefinition {
 }
 
 /// 模型元数据
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ModelMeta {
     /// 集合/表名
     pub collection_name: String,
@@ -626,6 +689,120 @@ pub struct ModelMeta {
     pub description: Option<String>,
     /// 模型版本号（用于字段版本控制）
     pub version: Option<u32>,
+    /// 主键字段列表（为空时使用默认的单一 `id` 主键，多个字段表示复合主键）
+    #[serde(default)]
+    pub primary_key: Vec<String>,
+    /// 记录过期配置（MongoDB 使用 TTL 索引，SQL 数据库由后台任务定期清理）
+    #[serde(default)]
+    pub expires_after: Option<ExpiresAfter>,
+    /// 模型级字段命名规则（未设置 `rename` 的字段按此规则映射存储字段名）
+    #[serde(default)]
+    pub rename_all: Option<crate::model::field_mapping::RenameRule>,
+    /// Rust 字段名到存储字段名的映射（仅包含被重命名的字段，由 `with_field_names` 生成）
+    #[serde(default)]
+    pub field_names: HashMap<String, String>,
+    /// 单表继承鉴别器（多个模型共享同一张表/集合时用于区分记录所属模型）
+    #[serde(default)]
+    pub discriminator: Option<Discriminator>,
+    /// 模型级跨字段校验规则（保存和更新时执行）
+    #[serde(default)]
+    pub validation_rules: Vec<crate::model::rules::ValidationRule>,
+    /// 表级缓存策略（覆盖数据库别名级的缓存配置）
+    #[serde(default)]
+    pub cache_policy: Option<crate::types::TableCachePolicy>,
+    /// 是否记录审计日志（写入前后快照与操作者，见 [`crate::audit`]）
+    #[serde(default)]
+    pub audit: bool,
+    /// 表所在的模式（PostgreSQL 的 schema、MySQL 的数据库、MongoDB 中同一连接上的其他数据库），为空时使用连接的默认模式
+    #[serde(default)]
+    pub schema: Option<String>,
+    /// 分片键（按分片解析器把记录路由到不同的数据库别名，见 [`crate::sharding`]）
+    #[serde(default)]
+    pub shard_key: Option<crate::sharding::ShardKey>,
+    /// 查询作用域（默认作用域与命名作用域，见 [`crate::model::scopes`]）
+    #[serde(default)]
+    pub scopes: crate::model::scopes::QueryScopes,
+}
+
+impl ModelMeta {
+    /// 限定表名：声明了模式时为 `模式.表名`，否则为表名
+    pub fn qualified_name(&self) -> String {
+        match &self.schema {
+            Some(schema) => format!("{}.{}", schema, self.collection_name),
+            None => self.collection_name.clone(),
+        }
+    }
+
+    /// 是否声明了自定义主键（非默认的单一 `id` 主键）
+    pub fn has_custom_primary_key(&self) -> bool {
+        !self.primary_key.is_empty() && self.primary_key != ["id"]
+    }
+
+    /// 获取主键字段列表，未声明时返回默认的 `["id"]`
+    pub fn primary_key_fields(&self) -> Vec<String> {
+        if self.primary_key.is_empty() {
+            vec!["id".to_string()]
+        } else {
+            self.primary_key.clone()
+        }
+    }
+
+    /// 从记录数据中提取主键值
+    ///
+    /// 主键字段缺失或为 `Null` 时返回验证错误
+    pub fn extract_primary_key(
+        &self,
+        data: &HashMap<String, DataValue>,
+    ) -> QuickDbResult<HashMap<String, DataValue>> {
+        let mut key = HashMap::new();
+        for field in self.primary_key_fields() {
+            match data.get(&field) {
+                Some(DataValue::Null) | None => {
+                    return Err(QuickDbError::ValidationError {
+                        field: field.clone(),
+                        message: crate::i18n::tf("model.primary_key_field_missing", &[("field", &field)]),
+                    });
+                }
+                Some(value) => {
+                    key.insert(field, value.clone());
+                }
+            }
+        }
+        Ok(key)
+    }
+
+    /// 将主键值映射转换为查询条件
+    ///
+    /// 映射必须恰好包含所有主键字段，缺失或多余的字段都会返回验证错误
+    pub fn primary_key_conditions(
+        &self,
+        key: &HashMap<String, DataValue>,
+    ) -> QuickDbResult<Vec<crate::types::QueryConditionWithConfig>> {
+        let pk_fields = self.primary_key_fields();
+        // 主键映射可以使用 Rust 字段名
+        let key = &self.map_data_fields(key.clone());
+
+        if let Some(unknown) = key.keys().find(|k| !pk_fields.contains(k)) {
+            return Err(QuickDbError::ValidationError {
+                field: unknown.clone(),
+                message: crate::i18n::tf("model.primary_key_field_unknown", &[("field", unknown)]),
+            });
+        }
+
+        let key = self.extract_primary_key(key)?;
+        Ok(pk_fields
+            .into_iter()
+            .map(|field| {
+                let value = key[&field].clone();
+                crate::types::QueryConditionWithConfig {
+                    field,
+                    operator: crate::types::QueryOperator::Eq,
+                    value,
+                    case_insensitive: false,
+                }
+            })
+            .collect())
+    }
 }
 
 /// 索引定义
@@ -637,6 +814,105 @@ pub struct IndexDefinition {
     pub unique: bool,
     /// 索引名称
     pub name: Option<String>,
+    /// 索引附加选项（部分索引、表达式索引）
+    #[serde(flatten)]
+    pub options: IndexOptions,
+}
+
+/// 索引附加选项
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct IndexOptions {
+    /// 部分索引条件（仅索引满足全部条件的记录；SQL 生成 `WHERE`，MongoDB 生成 partialFilterExpression）
+    #[serde(default)]
+    pub where_clause: Vec<crate::types::QueryConditionWithConfig>,
+    /// 表达式索引（如 `lower(email)`，作为函数索引键追加在字段之后，仅 SQL 数据库支持）
+    #[serde(default)]
+    pub expression: Option<String>,
+}
+
+/// 记录过期配置
+///
+/// 当 `field` 字段的时间早于当前时间减去 `seconds` 秒时，记录视为过期
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExpiresAfter {
+    /// 作为过期基准的时间字段
+    pub field: String,
+    /// 过期时长（秒）
+    pub seconds: u64,
+}
+
+impl ExpiresAfter {
+    /// 创建过期配置
+    pub fn new(field: &str, seconds: u64) -> Self {
+        Self {
+            field: field.to_string(),
+            seconds,
+        }
+    }
+
+    /// 计算过期截止时间，早于该时间的记录已过期
+    pub fn cutoff(&self, now: chrono::DateTime<chrono::Utc>) -> chrono::DateTime<chrono::Utc> {
+        i64::try_from(self.seconds)
+            .ok()
+            .and_then(chrono::Duration::try_seconds)
+            .and_then(|duration| now.checked_sub_signed(duration))
+            .unwrap_or(chrono::DateTime::<chrono::Utc>::MIN_UTC)
+    }
+}
+
+/// 单表继承鉴别器配置
+///
+/// 保存时将 `field` 字段写为 `value`，查询时自动追加 `field = value` 条件
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Discriminator {
+    /// 鉴别器字段名（存储字段名）
+    pub field: String,
+    /// 当前模型的鉴别器取值
+    pub value: String,
+}
+
+impl Discriminator {
+    /// 创建鉴别器配置
+    pub fn new(field: &str, value: &str) -> Self {
+        Self {
+            field: field.to_string(),
+            value: value.to_string(),
+        }
+    }
+}
+
+/// 验证时区偏移格式是否有效
+///
+/// 有效格式：+00:00, +08:00, -05:00 等
+fn is_valid_timezone_offset(offset: &str) -> bool {
+    // 正则表达式匹配时区偏移格式
+    // 格式：+或-，后跟两位数的小时，冒号，两位数的分钟
+    use regex::Regex;
+
+    if let Ok(re) = Regex::new(r"^[+-]\d{2}:\d{2}$") {
+        if !re.is_match(offset) {
+            return false;
+        }
+
+        // 解析小时和分钟，验证范围
+        let parts: Vec<&str> = offset[1..].split(':').collect();
+        if parts.len() != 2 {
+            return false;
+        }
+
+        if let (Ok(hours), Ok(minutes)) = (parts[0].parse::<i32>(), parts[1].parse::<i32>()) {
+            // 小时范围：0-23，分钟范围：0-59
+            if hours > 23 || minutes > 59 {
+                return false;
+            }
+        } else {
+            return false;
+        }
+
+        true
+    } else {
+        false
+    }
 }
 
 /// 验证时区偏移格式是否有效
@@ -1925,38 +2201,159 @@ mod tests {
             .validate_with_field_name(&DataValue::String("".into()), "id")
             .is_ok());
     }
-}
 
-/// 验证时区偏移格式是否有效
-///
-/// 有效格式：+00:00, +08:00, -05:00 等
-fn is_valid_timezone_offset(offset: &str) -> bool {
-    // 正则表达式匹配时区偏移格式
-    // 格式：+或-，后跟两位数的小时，冒号，两位数的分钟
-    use regex::Regex;
+    // ---- ModelMeta primary key ----
 
-    if let Ok(re) = Regex::new(r"^[+-]\d{2}:\d{2}$") {
-        if !re.is_match(offset) {
-            return false;
+    fn composite_key_meta() -> ModelMeta {
+        ModelMeta {
+            collection_name: "orders".to_string(),
+            primary_key: vec!["tenant_id".to_string(), "order_no".to_string()],
+            ..Default::default()
         }
+    }
 
-        // 解析小时和分钟，验证范围
-        let parts: Vec<&str> = offset[1..].split(':').collect();
-        if parts.len() != 2 {
-            return false;
-        }
+    #[cfg(feature = "sqlite-support")]
+    #[tokio::test]
+    async fn qualified_model_targets_attached_schema() {
+        use crate::types::*;
+        crate::i18n::ErrorMessageI18n::init_i18n();
+        let alias = "qualified_schema_suite".to_string();
+        let manager = crate::manager::get_global_pool_manager();
+        manager
+            .add_database(DatabaseConfig {
+                on_connect_statements: vec![format!(
+                    "ATTACH DATABASE 'file:{}_analytics?mode=memory&cache=shared' AS analytics",
+                    alias
+                )],
+                ..DatabaseConfig::sqlite_memory_for_test(&alias)
+            })
+            .await
+            .unwrap();
+
+        let mut meta = composite_key_meta();
+        meta.collection_name = "events".to_string();
+        meta.database_alias = Some(alias.clone());
+        meta.primary_key = Vec::new();
+        meta.fields = HashMap::from([
+            ("id".to_string(), crate::model::integer_field(None, None)),
+            ("kind".to_string(), crate::model::string_field(Some(32), None, None)),
+        ]);
+        meta.schema = Some("analytics".to_string());
+        assert_eq!(meta.qualified_name(), "analytics.events");
+        manager.register_model(meta).unwrap();
+
+        // 直接调用 ODM 处理函数，不经过绑定在其他测试运行时上的全局 ODM 后台任务
+        crate::odm::AsyncOdmManager::handle_create(
+            "analytics.events",
+            HashMap::from([("kind".to_string(), DataValue::String("click".to_string()))]),
+            Some(alias.clone()),
+        )
+        .await
+        .unwrap();
+        let records = crate::odm::AsyncOdmManager::handle_find("analytics.events", vec![], None, Some(alias.clone()))
+            .await
+            .unwrap();
+        assert_eq!(records.len(), 1);
+
+        // 表建在附加的 analytics 库中，主库中没有同名表
+        let (attached, main) = manager
+            .with_connection(Some(&alias), |mut conn| async move {
+                let attached = conn.query("SELECT kind FROM analytics.events", &[]).await?;
+                let main = conn.query("SELECT name FROM main.sqlite_master WHERE name = 'events'", &[]).await?;
+                Ok((attached, main))
+            })
+            .await
+            .unwrap();
+        assert_eq!(attached.len(), 1);
+        assert!(main.is_empty());
+    }
+
+    #[test]
+    fn expires_after_cutoff() {
+        let expires_after = ExpiresAfter::new("created_at", 3600);
+        let now = chrono::Utc::now();
+        assert_eq!(now - expires_after.cutoff(now), chrono::Duration::seconds(3600));
+
+        // 超大过期时长不会溢出
+        let forever = ExpiresAfter::new("created_at", u64::MAX);
+        assert!(forever.cutoff(now) < now);
+    }
+
+    #[test]
+    fn primary_key_defaults_to_id() {
+        let mut meta = composite_key_meta();
+        meta.primary_key = vec![];
+        assert!(!meta.has_custom_primary_key());
+        assert_eq!(meta.primary_key_fields(), vec!["id".to_string()]);
+
+        meta.primary_key = vec!["id".to_string()];
+        assert!(!meta.has_custom_primary_key());
+    }
+
+    #[test]
+    fn primary_key_conditions_composite() {
+        let meta = composite_key_meta();
+        assert!(meta.has_custom_primary_key());
+
+        let mut key = HashMap::new();
+        key.insert("order_no".to_string(), DataValue::String("A-1".into()));
+        key.insert("tenant_id".to_string(), DataValue::Int(7));
+
+        let conditions = meta.primary_key_conditions(&key).unwrap();
+        assert_eq!(conditions.len(), 2);
+        assert_eq!(conditions[0].field, "tenant_id");
+        assert_eq!(conditions[0].value, DataValue::Int(7));
+        assert_eq!(conditions[1].field, "order_no");
+        assert!(conditions
+            .iter()
+            .all(|c| c.operator == crate::types::QueryOperator::Eq));
+    }
+
+    #[test]
+    fn primary_key_conditions_missing_field() {
+        setup_i18n("en-US");
+        let meta = composite_key_meta();
+        let mut key = HashMap::new();
+        key.insert("tenant_id".to_string(), DataValue::Int(7));
 
-        if let (Ok(hours), Ok(minutes)) = (parts[0].parse::<i32>(), parts[1].parse::<i32>()) {
-            // 小时范围：0-23，分钟范围：0-59
-            if hours > 23 || minutes > 59 {
-                return false;
-            }
-        } else {
-            return false;
-        }
+        let err = meta.primary_key_conditions(&key).unwrap_err();
+        assert_eq!(
+            validation_message(&err),
+            "Missing value for primary key field 'order_no'"
+        );
+    }
 
-        true
-    } else {
-        false
+    #[test]
+    fn primary_key_conditions_unknown_field() {
+        setup_i18n("en-US");
+        let meta = composite_key_meta();
+        let mut key = HashMap::new();
+        key.insert("tenant_id".to_string(), DataValue::Int(7));
+        key.insert("order_no".to_string(), DataValue::String("A-1".into()));
+        key.insert("id".to_string(), DataValue::Int(1));
+
+        let err = meta.primary_key_conditions(&key).unwrap_err();
+        assert_eq!(
+            validation_message(&err),
+            "Field 'id' is not a primary key field"
+        );
+    }
+
+    #[test]
+    fn extract_primary_key_rejects_null() {
+        setup_i18n("zh-CN");
+        let meta = composite_key_meta();
+        let mut data = HashMap::new();
+        data.insert("tenant_id".to_string(), DataValue::Int(7));
+        data.insert("order_no".to_string(), DataValue::Null);
+        data.insert("amount".to_string(), DataValue::Float(1.5));
+
+        let err = meta.extract_primary_key(&data).unwrap_err();
+        assert_eq!(validation_message(&err), "缺少主键字段 'order_no' 的值");
+
+        data.insert("order_no".to_string(), DataValue::String("A-1".into()));
+        let key = meta.extract_primary_key(&data).unwrap();
+        assert_eq!(key.len(), 2);
+        assert!(!key.contains_key("amount"));
     }
 }
@@ -0,0 +1,254 @@
+//! 单表继承模块
+//!
+//! 参考 mongoengine 的继承设计：多个模型共享同一张表/集合，通过鉴别器字段区分
+//! 记录所属的模型。保存时自动写入鉴别器取值，查询、统计、批量更新和删除时
+//! 自动追加鉴别器条件，使每个模型只能看到属于自己的记录。
+//!
+//! 共享表的各模型注册时会合并字段定义，SQL 数据库自动建表前应先调用各模型的
+//! `meta()` 完成注册，以免表结构缺少其他模型的列
+
+use crate::model::convenience::string_field;
+use crate::model::field_types::{IndexDefinition, IndexOptions, ModelMeta};
+use crate::types::{
+    DataValue, LogicalOperator, QueryConditionGroupWithConfig, QueryConditionWithConfig,
+    QueryOperator,
+};
+use std::collections::HashMap;
+
+impl ModelMeta {
+    /// 补全鉴别器字段定义与索引
+    ///
+    /// 模型未显式定义鉴别器字段时自动添加必填字符串字段，并为其创建普通索引
+    pub fn with_discriminator(mut self) -> Self {
+        let Some(discriminator) = self.discriminator.clone() else {
+            return self;
+        };
+
+        self.fields
+            .entry(discriminator.field.clone())
+            .or_insert_with(|| string_field(None, None, None).required());
+
+        let indexed = self
+            .indexes
+            .iter()
+            .any(|index| index.fields.first() == Some(&discriminator.field));
+        if !indexed {
+            self.indexes.push(IndexDefinition {
+                fields: vec![discriminator.field.clone()],
+                unique: false,
+                name: None,
+                options: IndexOptions::default(),
+            });
+        }
+        self
+    }
+
+    /// 获取鉴别器查询条件
+    pub fn discriminator_condition(&self) -> Option<QueryConditionWithConfig> {
+        self.discriminator
+            .as_ref()
+            .map(|discriminator| QueryConditionWithConfig {
+                field: discriminator.field.clone(),
+                operator: QueryOperator::Eq,
+                value: DataValue::String(discriminator.value.clone()),
+                case_insensitive: false,
+            })
+    }
+
+    /// 为查询条件追加鉴别器条件
+    pub fn scope_conditions(
+        &self,
+        mut conditions: Vec<QueryConditionWithConfig>,
+    ) -> Vec<QueryConditionWithConfig> {
+        if let Some(condition) = self.discriminator_condition() {
+            conditions.push(condition);
+        }
+        conditions
+    }
+
+    /// 为条件组追加鉴别器条件（与原有条件组以 AND 组合）
+    pub fn scope_condition_groups(
+        &self,
+        groups: Vec<QueryConditionGroupWithConfig>,
+    ) -> Vec<QueryConditionGroupWithConfig> {
+        let Some(condition) = self.discriminator_condition() else {
+            return groups;
+        };
+
+        if groups.is_empty() {
+            return vec![QueryConditionGroupWithConfig::Single(condition)];
+        }
+
+        let mut conditions = groups;
+        conditions.push(QueryConditionGroupWithConfig::Single(condition));
+        vec![QueryConditionGroupWithConfig::GroupWithConfig {
+            operator: LogicalOperator::And,
+            conditions,
+        }]
+    }
+
+    /// 在待保存的数据中写入鉴别器取值
+    pub fn stamp_discriminator(&self, data: &mut HashMap<String, DataValue>) {
+        if let Some(discriminator) = &self.discriminator {
+            data.insert(
+                discriminator.field.clone(),
+                DataValue::String(discriminator.value.clone()),
+            );
+        }
+    }
+
+    /// 判断记录是否属于当前模型（未声明鉴别器的模型接受所有记录）
+    pub fn matches_discriminator(&self, data: &HashMap<String, DataValue>) -> bool {
+        match &self.discriminator {
+            Some(discriminator) => matches!(
+                data.get(&discriminator.field),
+                Some(DataValue::String(value)) if *value == discriminator.value
+            ),
+            None => true,
+        }
+    }
+
+    /// 合并共享同一张表的其他模型的字段与索引
+    ///
+    /// 单表继承的各模型注册到同一集合时，表结构需要包含所有模型的字段。
+    /// 仅当两者声明了相同的鉴别器字段时合并，当前模型的定义优先
+    pub fn merge_inherited(&mut self, other: &ModelMeta) {
+        let same_hierarchy = matches!(
+            (&self.discriminator, &other.discriminator),
+            (Some(current), Some(existing)) if current.field == existing.field
+        );
+        if !same_hierarchy {
+            return;
+        }
+
+        for (name, definition) in &other.fields {
+            if !self.fields.contains_key(name) {
+                // 其他模型的字段在当前模型的记录中不存在，不能为必填
+                let mut definition = definition.clone();
+                definition.required = false;
+                self.fields.insert(name.clone(), definition);
+            }
+        }
+        for index in &other.indexes {
+            if !self.indexes.iter().any(|existing| existing.fields == index.fields) {
+                self.indexes.push(index.clone());
+            }
+        }
+        // 共享表中任一模型声明审计即审计整张表
+        self.audit |= other.audit;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::traits::Model;
+
+    mod model {
+        use crate::model::*;
+        use crate::{define_model, integer_field, string_field};
+
+        define_model! {
+            struct Dog {
+                id: String,
+                name: String,
+                bark_volume: i32,
+            }
+            collection = "inheritance_animals",
+            discriminator = ("kind", "dog"),
+            fields = {
+                id: string_field(None, None, None).required(),
+                name: string_field(None, None, None).required(),
+                bark_volume: integer_field(None, None),
+            }
+        }
+
+        define_model! {
+            struct Cat {
+                id: String,
+                name: String,
+                lives: i32,
+            }
+            collection = "inheritance_animals",
+            discriminator = ("kind", "cat"),
+            fields = {
+                id: string_field(None, None, None).required(),
+                name: string_field(None, None, None).required(),
+                lives: integer_field(None, None).required(),
+            }
+        }
+    }
+
+    #[test]
+    fn define_model_adds_discriminator_field_and_stamps_value() {
+        use model::Dog;
+
+        let meta = Dog::meta();
+        assert!(meta.fields["kind"].required);
+        assert!(meta.indexes.iter().any(|index| index.fields == ["kind"]));
+
+        let dog = Dog {
+            id: "d1".to_string(),
+            name: "旺财".to_string(),
+            bark_volume: 7,
+        };
+        assert!(dog.validate().is_ok());
+        let data = dog.to_data_map().unwrap();
+        assert_eq!(data["kind"], DataValue::String("dog".to_string()));
+        assert!(meta.matches_discriminator(&data));
+
+        // 鉴别器字段不影响解码
+        let decoded = Dog::from_data_map(data).unwrap();
+        assert_eq!(decoded.name, "旺财");
+    }
+
+    #[test]
+    fn scopes_conditions_and_groups() {
+        use model::{Cat, Dog};
+
+        let condition = QueryConditionWithConfig {
+            field: "name".to_string(),
+            operator: QueryOperator::Eq,
+            value: DataValue::String("咪咪".to_string()),
+            case_insensitive: false,
+        };
+        let scoped = Cat::meta().scope_conditions(vec![condition.clone()]);
+        assert_eq!(scoped.len(), 2);
+        assert_eq!(scoped[1].field, "kind");
+        assert_eq!(scoped[1].value, DataValue::String("cat".to_string()));
+
+        let groups = Dog::meta().scope_condition_groups(vec![
+            QueryConditionGroupWithConfig::Single(condition),
+        ]);
+        match groups.as_slice() {
+            [QueryConditionGroupWithConfig::GroupWithConfig { operator, conditions }] => {
+                assert_eq!(*operator, LogicalOperator::And);
+                assert_eq!(conditions.len(), 2);
+            }
+            other => panic!("unexpected groups: {:?}", other),
+        }
+        assert_eq!(Dog::meta().scope_condition_groups(Vec::new()).len(), 1);
+
+        let mut cat_data = HashMap::new();
+        Cat::meta().stamp_discriminator(&mut cat_data);
+        assert!(!Dog::meta().matches_discriminator(&cat_data));
+    }
+
+    #[test]
+    fn merges_sibling_fields_as_optional() {
+        use model::{Cat, Dog};
+
+        let mut meta = Dog::meta();
+        meta.merge_inherited(&Cat::meta());
+        assert!(meta.fields.contains_key("bark_volume"));
+        assert!(!meta.fields["lives"].required);
+        assert!(meta.fields["name"].required);
+        assert_eq!(meta.discriminator.as_ref().unwrap().value, "dog");
+
+        // 未声明鉴别器的模型不合并
+        let mut plain = Dog::meta();
+        plain.discriminator = None;
+        plain.merge_inherited(&Cat::meta());
+        assert!(!plain.fields.contains_key("lives"));
+    }
+}
@@ -0,0 +1,273 @@
+//! JSON Schema 导出模块
+//!
+//! 把模型元数据转换为 JSON Schema（兼容 OpenAPI 3.1 的 Schema 对象），HTTP 层可以用
+//! 同一份字段定义校验请求体：
+//!
+//! ```rust,ignore
+//! let schema = model::export_json_schema("users")?;
+//! let components = model::export_all_openapi_components();
+//! openapi["components"] = components;
+//! ```
+//!
+//! 属性名使用 Rust 字段名（与模型的 JSON 序列化一致）。必填字段列入 `required`，
+//! 非必填字段允许 `null`；字符串长度、数值范围、数组长度与正则映射为对应的校验关键字，
+//! 鉴别器字段与多态引用的集合名以 `enum` 限定取值。唯一约束与跨字段校验规则需要数据库
+//! 或服务端校验，不在 Schema 中体现
+
+use crate::error::{QuickDbError, QuickDbResult};
+use crate::model::defaults::DefaultValue;
+use crate::model::field_types::{FieldDefinition, FieldType, ModelMeta};
+use serde_json::{Map, Value as JsonValue, json};
+use std::collections::HashMap;
+
+impl FieldDefinition {
+    /// 转换为 JSON Schema（不含可空性，可空性由所在对象的必填列表决定）
+    pub fn to_json_schema(&self) -> JsonValue {
+        let mut schema = field_type_schema(&self.field_type);
+        if let Some(description) = &self.description {
+            schema["description"] = json!(description);
+        }
+        if let Some(DefaultValue::Static(value)) = &self.default {
+            schema["default"] = value.to_json_value();
+        }
+        if self.computed {
+            schema["readOnly"] = json!(true);
+        }
+        schema
+    }
+}
+
+impl ModelMeta {
+    /// 转换为对象类型的 JSON Schema
+    pub fn to_json_schema(&self) -> JsonValue {
+        let fields = self
+            .fields
+            .iter()
+            .map(|(storage, definition)| (self.rust_field_name(storage).to_string(), definition))
+            .collect::<HashMap<_, _>>();
+        let mut schema = object_schema(&fields);
+        schema["title"] = json!(self.collection_name);
+        if let Some(description) = &self.description {
+            schema["description"] = json!(description);
+        }
+        if let Some(discriminator) = &self.discriminator {
+            let field = self.rust_field_name(&discriminator.field);
+            schema["properties"][field]["enum"] = json!([discriminator.value]);
+        }
+        schema
+    }
+}
+
+/// 导出已注册模型的 JSON Schema
+///
+/// 同一集合注册在多个数据库别名下时使用默认别名的元数据，集合未注册时返回 `NotFound` 错误
+pub fn export_json_schema(collection: &str) -> QuickDbResult<JsonValue> {
+    let manager = crate::manager::get_global_pool_manager();
+    let mut metas: Vec<(String, ModelMeta)> = manager
+        .model_registry
+        .iter()
+        .filter(|entry| entry.value().collection_name == collection || entry.value().qualified_name() == collection)
+        .map(|entry| (entry.key().clone(), entry.value().clone()))
+        .collect();
+    metas.sort_by_key(|(key, _)| !key.starts_with("default:"));
+    metas
+        .into_iter()
+        .next()
+        .map(|(_, meta)| meta.to_json_schema())
+        .ok_or_else(|| QuickDbError::NotFound {
+            message: crate::i18n::tf("model.schema_model_not_found", &[("collection", collection)]),
+        })
+}
+
+/// 导出全部已注册模型的 OpenAPI 组件（`{"schemas": {集合名: Schema}}`）
+pub fn export_all_openapi_components() -> JsonValue {
+    let manager = crate::manager::get_global_pool_manager();
+    let mut schemas = Map::new();
+    for entry in manager.model_registry.iter() {
+        let name = entry.value().qualified_name();
+        // 多个别名下注册的同一集合只导出一次，优先使用默认别名的元数据
+        if !schemas.contains_key(&name) || entry.key().starts_with("default:") {
+            schemas.insert(name, entry.value().to_json_schema());
+        }
+    }
+    json!({ "schemas": schemas })
+}
+
+/// 字段集合转换为对象 Schema
+fn object_schema(fields: &HashMap<String, &FieldDefinition>) -> JsonValue {
+    let mut properties = Map::new();
+    let mut required = Vec::new();
+    for (name, definition) in fields {
+        let mut schema = definition.to_json_schema();
+        if definition.required {
+            required.push(name.clone());
+        } else {
+            allow_null(&mut schema);
+        }
+        properties.insert(name.clone(), schema);
+    }
+    required.sort();
+    json!({
+        "type": "object",
+        "properties": properties,
+        "required": required,
+    })
+}
+
+/// 非必填字段的值可以为 `null`（未声明 `type` 的 Schema 本身已接受 `null`，保持不变）
+fn allow_null(schema: &mut JsonValue) {
+    match schema.get_mut("type") {
+        Some(JsonValue::String(kind)) => {
+            let kind = std::mem::take(kind);
+            schema["type"] = json!([kind, "null"]);
+        }
+        Some(JsonValue::Array(kinds)) if !kinds.iter().any(|kind| kind == "null") => kinds.push(json!("null")),
+        _ => {}
+    }
+}
+
+/// 字段类型转换为 JSON Schema
+fn field_type_schema(field_type: &FieldType) -> JsonValue {
+    let id_schema = || json!({ "type": ["string", "integer"] });
+    match field_type {
+        FieldType::String {
+            max_length,
+            min_length,
+            regex,
+        } => {
+            let mut schema = json!({ "type": "string" });
+            if let Some(max_length) = max_length {
+                schema["maxLength"] = json!(max_length);
+            }
+            if let Some(min_length) = min_length {
+                schema["minLength"] = json!(min_length);
+            }
+            if let Some(regex) = regex {
+                schema["pattern"] = json!(regex);
+            }
+            schema
+        }
+        FieldType::Integer { min_value, max_value } => {
+            let mut schema = json!({ "type": "integer" });
+            if let Some(min_value) = min_value {
+                schema["minimum"] = json!(min_value);
+            }
+            if let Some(max_value) = max_value {
+                schema["maximum"] = json!(max_value);
+            }
+            schema
+        }
+        FieldType::BigInteger => json!({ "type": "integer", "format": "int64" }),
+        FieldType::Float { min_value, max_value } => {
+            let mut schema = json!({ "type": "number", "format": "float" });
+            if let Some(min_value) = min_value {
+                schema["minimum"] = json!(min_value);
+            }
+            if let Some(max_value) = max_value {
+                schema["maximum"] = json!(max_value);
+            }
+            schema
+        }
+        FieldType::Double => json!({ "type": "number", "format": "double" }),
+        FieldType::Decimal { .. } => json!({ "type": "number" }),
+        FieldType::Text => json!({ "type": "string" }),
+        FieldType::Boolean => json!({ "type": "boolean" }),
+        FieldType::DateTime | FieldType::DateTimeWithTz { .. } => json!({ "type": "string", "format": "date-time" }),
+        FieldType::Date => json!({ "type": "string", "format": "date" }),
+        FieldType::Time => json!({ "type": "string", "format": "time" }),
+        FieldType::Uuid => json!({ "type": "string", "format": "uuid" }),
+        FieldType::Json => json!({}),
+        FieldType::Binary => json!({ "type": "string", "format": "byte" }),
+        FieldType::Array {
+            item_type,
+            max_items,
+            min_items,
+        } => {
+            let mut schema = json!({ "type": "array", "items": field_type_schema(item_type) });
+            if let Some(max_items) = max_items {
+                schema["maxItems"] = json!(max_items);
+            }
+            if let Some(min_items) = min_items {
+                schema["minItems"] = json!(min_items);
+            }
+            schema
+        }
+        FieldType::Object { fields } => {
+            object_schema(&fields.iter().map(|(name, definition)| (name.clone(), definition)).collect())
+        }
+        FieldType::Reference { target_collection } => {
+            let mut schema = id_schema();
+            schema["x-reference"] = json!(target_collection);
+            schema
+        }
+        FieldType::PolymorphicReference { target_collections } => json!({
+            "type": "object",
+            "properties": {
+                "collection": { "type": "string", "enum": target_collections },
+                "id": id_schema(),
+            },
+            "required": ["collection", "id"],
+        }),
+        FieldType::Vector { dimension } => json!({
+            "type": "array",
+            "items": { "type": "number" },
+            "minItems": dimension,
+            "maxItems": dimension,
+        }),
+        FieldType::Sequence { .. } => json!({ "type": "integer" }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{Discriminator, array_field, integer_field, string_field};
+    use crate::types::DataValue;
+
+    #[test]
+    fn model_meta_maps_to_json_schema() {
+        let mut meta = ModelMeta {
+            collection_name: "accounts".to_string(),
+            fields: HashMap::from([
+                ("email".to_string(), string_field(Some(64), Some(3), None).required()),
+                ("age".to_string(), integer_field(Some(0), Some(150))),
+                (
+                    "tags".to_string(),
+                    array_field(FieldType::Text, Some(5), None).default_value(DataValue::Array(vec![])),
+                ),
+            ]),
+            description: Some("账户".to_string()),
+            discriminator: Some(Discriminator::new("kind", "admin")),
+            ..Default::default()
+        }
+        .with_discriminator();
+        meta.fields.insert("display_name".to_string(), string_field(None, None, None).rename("name"));
+        let meta = meta.with_field_names();
+
+        let schema = meta.to_json_schema();
+        assert_eq!(schema["title"], "accounts");
+        assert_eq!(schema["required"], json!(["email", "kind"]));
+        assert_eq!(schema["properties"]["email"], json!({ "type": "string", "maxLength": 64, "minLength": 3 }));
+        assert_eq!(
+            schema["properties"]["age"],
+            json!({ "type": ["integer", "null"], "minimum": 0, "maximum": 150 })
+        );
+        assert_eq!(schema["properties"]["tags"]["maxItems"], 5);
+        assert_eq!(schema["properties"]["tags"]["default"], json!([]));
+        assert_eq!(schema["properties"]["kind"]["enum"], json!(["admin"]));
+        assert!(schema["properties"].get("display_name").is_some());
+    }
+
+    #[test]
+    fn allow_null_extends_type_arrays_and_skips_untyped() {
+        let mut reference = json!({ "type": ["string", "integer"] });
+        allow_null(&mut reference);
+        assert_eq!(reference["type"], json!(["string", "integer", "null"]));
+        allow_null(&mut reference);
+        assert_eq!(reference["type"], json!(["string", "integer", "null"]));
+
+        let mut any = json!({});
+        allow_null(&mut any);
+        assert_eq!(any, json!({}));
+    }
+}
@@ -87,6 +87,11 @@ macro_rules! field_types {
             target_collection: $target.to_string(),
         }
     };
+    (polymorphic_reference, [$($target:expr),* $(,)?]) => {
+        $crate::model::field_types::FieldType::PolymorphicReference {
+            target_collections: vec![$($target.to_string()),*],
+        }
+    };
 }
 
 /// 便捷宏：定义模型
@@ -105,9 +110,45 @@ macro_rules! define_model {
         $(
             database = $database:expr,
         )?
+        $(
+            schema = $schema:expr,
+        )?
         $(
             version = $version:expr,
         )?
+        $(
+            primary_key = [$($pk_field:expr),* $(,)?],
+        )?
+        $(
+            expires_after = ($ttl_field:expr, $ttl_seconds:expr),
+        )?
+        $(
+            rename_all = $rename_all:expr,
+        )?
+        $(
+            discriminator = ($disc_field:expr, $disc_value:expr),
+        )?
+        $(
+            validation_rules = [$($rule:expr),* $(,)?],
+        )?
+        $(
+            cache_policy = $cache_policy:expr,
+        )?
+        $(
+            audit = $audit:expr,
+        )?
+        $(
+            shard_key = ($shard_field:expr, $shard_resolver:expr),
+        )?
+        $(
+            default_scope = $default_scope:expr,
+        )?
+        $(
+            scopes = [$(($scope_name:expr, $scope:expr)),* $(,)?],
+        )?
+        $(
+            compute_fields = $compute_fn:path,
+        )?
         fields = {
             $(
                 $field_name:ident: $field_def:expr,
@@ -116,7 +157,13 @@ macro_rules! define_model {
         $(
             indexes = [
                 $(
-                    { fields: [$($index_field:expr),*], unique: $unique:expr $(, name: $index_name:expr)? },
+                    {
+                        fields: [$($index_field:expr),*],
+                        unique: $unique:expr
+                        $(, name: $index_name:expr)?
+                        $(, where_clause: [$($index_condition:expr),* $(,)?])?
+                        $(, expression: $index_expression:expr)?
+                    },
                 )*
             ],
         )?
@@ -137,16 +184,19 @@ macro_rules! define_model {
                     fields.insert(stringify!($field_name).to_string(), $field_def);
                 )*
 
-                let mut indexes = Vec::new();
-                $(
-                    $(
-                        indexes.push($crate::model::field_types::IndexDefinition {
-                            fields: vec![$($index_field.to_string()),*],
-                            unique: $unique,
-                            name: None $(.or(Some($index_name.to_string())))?,
-                        });
-                    )*
-                )?
+                let indexes: Vec<$crate::model::field_types::IndexDefinition> = vec![$($(
+                    $crate::model::field_types::IndexDefinition {
+                        fields: vec![$($index_field.to_string()),*],
+                        unique: $unique,
+                        name: None $(.or(Some($index_name.to_string())))?,
+                        options: $crate::model::field_types::IndexOptions {
+                            where_clause: vec![$($($index_condition.into()),*)?],
+                            expression: None $(.or(Some($index_expression.to_string())))?,
+                        },
+                    }
+                ),*)?];
+
+                let primary_key: Vec<String> = vec![$($($pk_field.to_string()),*)?];
 
                 let model_meta = $crate::model::field_types::ModelMeta {
                     collection_name: $collection.to_string(),
@@ -155,7 +205,23 @@ macro_rules! define_model {
                     indexes,
                     description: None,
                     version: None $(.or(Some($version)))?,
-                };
+                    primary_key,
+                    expires_after: None $(.or(Some($crate::model::field_types::ExpiresAfter::new($ttl_field, $ttl_seconds))))?,
+                    rename_all: None $(.or(Some($crate::model::RenameRule::from_name($rename_all).expect("❌ 不支持的 rename_all 命名规则"))))?,
+                    field_names: std::collections::HashMap::new(),
+                    discriminator: None $(.or(Some($crate::model::field_types::Discriminator::new($disc_field, $disc_value))))?,
+                    validation_rules: vec![$($($rule),*)?],
+                    cache_policy: None $(.or(Some($cache_policy)))?,
+                    audit: false $(|| $audit)?,
+                    schema: None $(.or(Some($schema.to_string())))?,
+                    shard_key: None $(.or(Some($crate::sharding::ShardKey::new($shard_field, $shard_resolver))))?,
+                    scopes: $crate::model::scopes::QueryScopes {
+                        default: None $(.or(Some($default_scope)))?,
+                        named: std::collections::HashMap::from([$($(($scope_name.to_string(), $scope)),*)?]),
+                    },
+                }
+                .with_field_names()
+                .with_discriminator();
 
                 // 自动注册模型元数据（仅在首次调用时注册）
                 static ONCE: std::sync::Once = std::sync::Once::new();
@@ -163,7 +229,7 @@ macro_rules! define_model {
                     if let Err(e) = $crate::manager::register_model(model_meta.clone()) {
                         panic!("❌ 模型注册失败: {}", e);
                     } else {
-                        $crate::debug_log!("✅ 模型自动注册成功: {}", model_meta.collection_name);
+                        $crate::debug_log!("✅ 模型自动注册成功: {}", model_meta.qualified_name());
                     }
                 });
 
@@ -183,35 +249,36 @@ macro_rules! define_model {
                 $(
                     $crate::debug_log!("🔍 转换字段 {}: {:?}", stringify!($field), self.$field);
 
-                    // 根据字段类型进行智能转换
-                    let field_name = stringify!($field).to_string();
+                    // 根据字段类型进行智能转换（键为存储字段名）
+                    let field_name = meta.storage_field_name(stringify!($field)).to_string();
                     let field_def = meta.fields.get(&field_name);
 
-                    let data_value = if let Some(field_type) = field_def.map(|f| &f.field_type) {
-                        // 有字段类型定义，进行元数据感知的转换
-                        match field_type {
-                            $crate::model::field_types::FieldType::DateTimeWithTz { timezone_offset } => {
-                                // 获取数据库别名，如果为None则是严重框架错误，立即panic
-                                let alias = Self::database_alias().expect("严重错误：模型没有数据库别名！这表明框架内部存在严重问题！");
-                                let db_type = $crate::manager::get_database_type_by_alias(&alias);
-
-                                // 使用数据库感知的转换函数
-                                $crate::convert_datetime_with_tz_aware(&self.$field, timezone_offset, db_type)?
-                            },
-                            _ => {
-                                // 其他字段类型使用默认转换
-                                self.$field.to_data_value()
-                            }
+                    let data_value = match field_def.map(|f| &f.field_type) {
+                        // 带时区的时间字段，进行元数据感知的转换
+                        Some($crate::model::field_types::FieldType::DateTimeWithTz { timezone_offset }) => {
+                            // 获取数据库别名，如果为None则是严重框架错误，立即panic
+                            let alias = Self::database_alias().expect("严重错误：模型没有数据库别名！这表明框架内部存在严重问题！");
+                            let db_type = $crate::manager::get_database_type_by_alias(&alias);
+
+                            // 使用数据库感知的转换函数
+                            $crate::convert_datetime_with_tz_aware(&self.$field, timezone_offset, db_type)?
+                        },
+                        _ => {
+                            // 其他字段类型或没有字段类型定义，使用默认转换
+                            self.$field.to_data_value()
                         }
-                    } else {
-                        // 没有字段类型定义，使用默认转换
-                        self.$field.to_data_value()
                     };
 
                     $crate::debug_log!("🔍 字段 {} 转换为: {:?}", stringify!($field), data_value);
                     data_map.insert(field_name, data_value);
                 )*
 
+                // 单表继承模型写入鉴别器取值
+                meta.stamp_discriminator(&mut data_map);
+
+                // 计算字段不写入数据库
+                meta.strip_computed_fields(&mut data_map);
+
                 // 移除为None的id字段，让数据库自动生成ID
                 if let Some(id_value) = data_map.get("id") {
                     if matches!(id_value, $crate::types::DataValue::Null) {
@@ -229,8 +296,21 @@ macro_rules! define_model {
                 $crate::debug_log!("🔍 to_data_map_direct 转换完成");
                 Ok(data_map)
             }
+
+            /// 高性能直接解码实现，按字段移动 DataValue，不经过 serde
+            fn from_data_map_direct(data: std::collections::HashMap<String, $crate::types::DataValue>) -> $crate::error::QuickDbResult<Self> {
+                <Self as $crate::model::conversion::FromDataMap>::decode_data_map(data)
+            }
+
+            /// 填充计算字段
+            fn compute_fields(&mut self) {
+                $($compute_fn(self);)?
+            }
         }
 
+        $crate::define_model!(@from_data_map $name { $($field),* });
+
+        #[allow(dead_code)]
         impl $name {
             /// 保存模型到数据库
             pub async fn save(&self) -> $crate::error::QuickDbResult<String> {
@@ -297,10 +377,35 @@ macro_rules! define_model {
                 Ok(id)
             }
 
+            /// 从数据库重新加载模型，覆盖内存中的字段
+            ///
+            /// 见 `ModelManager::reload`
+            pub async fn reload(&mut self) -> $crate::error::QuickDbResult<()> {
+                $crate::model::ModelManager::<Self>::reload(self).await
+            }
+
             /// 更新模型
             pub async fn update(&self, updates: std::collections::HashMap<String, $crate::types::DataValue>) -> $crate::error::QuickDbResult<bool> {
-                // 尝试从模型中获取ID字段，兼容 MongoDB 的 _id 和 SQL 的 id
                 let data_map = self.to_data_map()?;
+
+                // 声明了复合/自定义主键的模型按主键条件更新
+                let meta = Self::meta();
+                let updates = meta.map_data_fields(updates);
+
+                // 以合并更新字段后的完整记录执行跨字段校验规则
+                if !meta.validation_rules.is_empty() {
+                    let mut merged = data_map.clone();
+                    merged.extend(updates.iter().map(|(field, value)| (field.clone(), value.clone())));
+                    meta.validate_rules(&merged)?;
+                }
+
+                if meta.has_custom_primary_key() {
+                    let conditions = meta.primary_key_conditions(&meta.extract_primary_key(&data_map)?)?;
+                    let affected = $crate::odm::update(&Self::collection_name(), conditions, updates, Self::database_alias().as_deref()).await?;
+                    return Ok(affected > 0);
+                }
+
+                // 尝试从模型中获取ID字段，兼容 MongoDB 的 _id 和 SQL 的 id
                 let (id_field_name, id_value) = data_map.get("_id")
                     .map(|v| ("_id", v))
                     .or_else(|| data_map.get("id").map(|v| ("id", v)))
@@ -341,6 +446,11 @@ macro_rules! define_model {
             pub async fn upsert(&self, conflict_columns: Vec<String>) -> $crate::error::QuickDbResult<String> {
                 self.validate()?;
                 let data = self.to_data_map()?;
+                let meta = Self::meta();
+                let conflict_columns = conflict_columns
+                    .iter()
+                    .map(|column| meta.storage_field_name(column).to_string())
+                    .collect();
                 let collection_name = Self::collection_name();
                 let database_alias = Self::database_alias();
 
@@ -401,8 +511,17 @@ macro_rules! define_model {
 
             /// 删除模型
             pub async fn delete(&self) -> $crate::error::QuickDbResult<bool> {
-                // 尝试从模型中获取ID字段，兼容 MongoDB 的 _id 和 SQL 的 id
                 let data_map = self.to_data_map()?;
+
+                // 声明了复合/自定义主键的模型按主键条件删除
+                let meta = Self::meta();
+                if meta.has_custom_primary_key() {
+                    let conditions = meta.primary_key_conditions(&meta.extract_primary_key(&data_map)?)?;
+                    let affected = $crate::odm::delete(&Self::collection_name(), conditions, Self::database_alias().as_deref()).await?;
+                    return Ok(affected > 0);
+                }
+
+                // 尝试从模型中获取ID字段，兼容 MongoDB 的 _id 和 SQL 的 id
                 let (id_field_name, id_value) = data_map.get("_id")
                     .map(|v| ("_id", v))
                     .or_else(|| data_map.get("id").map(|v| ("id", v)))
@@ -449,15 +568,7 @@ macro_rules! define_model {
                     .into_iter()
                     .map(|c| c.into())
                     .collect();
-                let collection_name = Self::collection_name();
-                let database_alias = Self::database_alias();
-
-                $crate::odm::update(
-                    &collection_name,
-                    conditions_with_config,
-                    updates,
-                    database_alias.as_deref(),
-                ).await
+                Self::update_many_with_config(conditions_with_config, updates).await
             }
 
             /// 使用操作数组批量更新模型（简化方法）
@@ -468,15 +579,7 @@ macro_rules! define_model {
                     .into_iter()
                     .map(|c| c.into())
                     .collect();
-                let collection_name = Self::collection_name();
-                let database_alias = Self::database_alias();
-
-                $crate::odm::update_with_operations(
-                    &collection_name,
-                    conditions_with_config,
-                    operations,
-                    database_alias.as_deref(),
-                ).await
+                Self::update_many_with_operations_and_config(conditions_with_config, operations).await
             }
 
             /// 批量删除模型（简化方法）
@@ -487,14 +590,7 @@ macro_rules! define_model {
                     .into_iter()
                     .map(|c| c.into())
                     .collect();
-                let collection_name = Self::collection_name();
-                let database_alias = Self::database_alias();
-
-                $crate::odm::delete(
-                    &collection_name,
-                    conditions_with_config,
-                    database_alias.as_deref(),
-                ).await
+                Self::delete_many_with_config(conditions_with_config).await
             }
 
             // ========== 完整方法：接受 QueryConditionWithConfig ==========
@@ -503,6 +599,9 @@ macro_rules! define_model {
             ///
             /// 接受 `Vec<QueryConditionWithConfig>`，支持大小写不敏感等高级配置
             pub async fn update_many_with_config(conditions: Vec<$crate::types::QueryConditionWithConfig>, updates: std::collections::HashMap<String, $crate::types::DataValue>) -> $crate::error::QuickDbResult<u64> {
+                let meta = Self::meta();
+                let conditions = meta.scope_conditions(meta.map_conditions(conditions));
+                let updates = meta.map_data_fields(updates);
                 let collection_name = Self::collection_name();
                 let database_alias = Self::database_alias();
 
@@ -518,6 +617,9 @@ macro_rules! define_model {
             ///
             /// 接受 `Vec<QueryConditionWithConfig>`，支持大小写不敏感等高级配置
             pub async fn update_many_with_operations_and_config(conditions: Vec<$crate::types::QueryConditionWithConfig>, operations: Vec<$crate::types::UpdateOperation>) -> $crate::error::QuickDbResult<u64> {
+                let meta = Self::meta();
+                let conditions = meta.scope_conditions(meta.map_conditions(conditions));
+                let operations = meta.map_update_operations(operations);
                 let collection_name = Self::collection_name();
                 let database_alias = Self::database_alias();
 
@@ -533,6 +635,8 @@ macro_rules! define_model {
             ///
             /// 接受 `Vec<QueryConditionWithConfig>`，支持大小写不敏感等高级配置
             pub async fn delete_many_with_config(conditions: Vec<$crate::types::QueryConditionWithConfig>) -> $crate::error::QuickDbResult<u64> {
+                let meta = Self::meta();
+                let conditions = meta.scope_conditions(meta.map_conditions(conditions));
                 let collection_name = Self::collection_name();
                 let database_alias = Self::database_alias();
 
@@ -544,4 +648,117 @@ macro_rules! define_model {
             }
         }
     };
+
+    // 内部规则：生成按存储字段名解码的 FromDataMap 实现
+    (@from_data_map $name:ident { $($field:ident),* }) => {
+        impl $crate::model::conversion::FromDataMap for $name {
+            fn decode_data_map(
+                mut data: std::collections::HashMap<String, $crate::types::DataValue>,
+            ) -> $crate::error::QuickDbResult<Self> {
+                // 存储字段名按结构体字段顺序缓存，避免每行都重建模型元数据
+                static STORAGE_NAMES: std::sync::OnceLock<Vec<String>> = std::sync::OnceLock::new();
+                let storage_names = STORAGE_NAMES.get_or_init(|| {
+                    let meta = <Self as $crate::model::traits::Model>::meta();
+                    vec![$(meta.storage_field_name(stringify!($field)).to_string()),*]
+                });
+                let mut storage_names = storage_names.iter();
+
+                Ok(Self {
+                    $(
+                        $field: $crate::model::conversion::decode_field(
+                            &mut data,
+                            storage_names.next().map(String::as_str).unwrap_or(stringify!($field)),
+                        )?,
+                    )*
+                })
+            }
+        }
+    };
+
+    // 嵌入式模型：作为其他模型的 `embedded_field::<T>()` 字段使用，
+    // 不对应独立的集合/表，因此不注册到模型管理器，也不生成保存、更新等方法
+    (
+        $(#[$meta:meta])*
+        embedded struct $name:ident {
+            $(
+                $(#[$field_meta:meta])*
+                $field:ident: $field_type:ty,
+            )*
+        }
+
+        $(
+            rename_all = $rename_all:expr,
+        )?
+        fields = {
+            $(
+                $field_name:ident: $field_def:expr,
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+        pub struct $name {
+            $(
+                $(#[$field_meta])*
+                pub $field: $field_type,
+            )*
+        }
+
+        impl $crate::model::traits::Model for $name {
+            fn meta() -> $crate::model::field_types::ModelMeta {
+                let mut fields = std::collections::HashMap::new();
+                $(
+                    fields.insert(stringify!($field_name).to_string(), $field_def);
+                )*
+
+                $crate::model::field_types::ModelMeta {
+                    collection_name: stringify!($name).to_string(),
+                    fields,
+                    rename_all: None $(.or(Some($crate::model::RenameRule::from_name($rename_all).expect("❌ 不支持的 rename_all 命名规则"))))?,
+                    ..Default::default()
+                }
+                .with_field_names()
+            }
+
+            /// 高性能直接转换实现，嵌入文档中的字段统一使用默认转换
+            fn to_data_map_direct(&self) -> $crate::error::QuickDbResult<std::collections::HashMap<String, $crate::types::DataValue>> {
+                use $crate::model::conversion::ToDataValue;
+                let meta = Self::meta();
+                let mut data_map = std::collections::HashMap::new();
+                $(
+                    data_map.insert(
+                        meta.storage_field_name(stringify!($field)).to_string(),
+                        self.$field.to_data_value(),
+                    );
+                )*
+                Ok(data_map)
+            }
+
+            /// 高性能直接解码实现，按字段移动 DataValue，不经过 serde
+            fn from_data_map_direct(data: std::collections::HashMap<String, $crate::types::DataValue>) -> $crate::error::QuickDbResult<Self> {
+                <Self as $crate::model::conversion::FromDataMap>::decode_data_map(data)
+            }
+        }
+
+        $crate::define_model!(@from_data_map $name { $($field),* });
+
+        impl $crate::model::conversion::ToDataValue for $name {
+            fn to_data_value(&self) -> $crate::types::DataValue {
+                match <Self as $crate::model::traits::Model>::to_data_map_direct(self) {
+                    Ok(data_map) => $crate::types::DataValue::Object(data_map),
+                    Err(e) => {
+                        $crate::debug_log!("❌ 嵌入文档转换失败: {}", e);
+                        $crate::types::DataValue::Null
+                    }
+                }
+            }
+        }
+
+        impl $crate::model::conversion::FromDataValue for $name {
+            fn from_data_value(value: $crate::types::DataValue) -> $crate::error::QuickDbResult<Self> {
+                let data_map = $crate::model::conversion::embedded_data_map::<Self>(value)?;
+                <Self as $crate::model::traits::Model>::from_data_map(data_map)
+            }
+        }
+    };
 }
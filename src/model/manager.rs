@@ -18,6 +18,12 @@ pub struct ModelManager<T: Model> {
     _phantom: PhantomData<T>,
 }
 
+impl<T: Model> Default for ModelManager<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T: Model> ModelManager<T> {
     /// 创建新的模型管理器
     pub fn new() -> Self {
@@ -65,6 +71,120 @@ impl<T: Model> ModelManager<T> {
         Self::delete_many_with_config(conditions_with_config).await
     }
 
+    /// 使用操作数组批量更新模型（简化方法）
+    ///
+    /// 接受 `Vec<QueryCondition>` 并自动转换为 `Vec<QueryConditionWithConfig>`，
+    /// 增减、乘除等操作由数据库原子执行，返回受影响的行数
+    pub async fn update_with_ops(
+        conditions: Vec<QueryCondition>,
+        operations: Vec<UpdateOperation>,
+    ) -> QuickDbResult<u64> {
+        let conditions_with_config: Vec<QueryConditionWithConfig> = conditions
+            .into_iter()
+            .map(|c| c.into())
+            .collect();
+        <Self as ModelOperations<T>>::update_many_with_operations(conditions_with_config, operations).await
+    }
+
+    /// 批量更新模型（支持超时等更新选项）
+    ///
+    /// 与 [`ModelOperations::update_many`] 相同，`options.timeout` 超时后返回错误，
+    /// 各数据库对超时的处理见 [`UpdateOptions::timeout`]
+    pub async fn update_many_with_options(
+        conditions: Vec<QueryConditionWithConfig>,
+        updates: HashMap<String, DataValue>,
+        options: UpdateOptions,
+    ) -> QuickDbResult<u64> {
+        let collection_name = T::collection_name();
+        let database_alias = T::database_alias();
+
+        debug!(
+            "批量更新模型: collection={}, 条件数量={}",
+            collection_name,
+            conditions.len()
+        );
+
+        let meta = T::meta();
+        let conditions = meta.scope_conditions(meta.map_conditions(conditions));
+        let updates = meta.map_data_fields(updates);
+        odm::update_with_options(
+            &collection_name,
+            conditions,
+            updates,
+            options,
+            database_alias.as_deref(),
+        )
+        .await
+    }
+
+    /// 使用操作数组批量更新模型（支持超时等更新选项）
+    ///
+    /// 与 [`ModelOperations::update_many_with_operations`] 相同，`options.timeout` 超时后返回错误
+    pub async fn update_many_with_operations_and_options(
+        conditions: Vec<QueryConditionWithConfig>,
+        operations: Vec<UpdateOperation>,
+        options: UpdateOptions,
+    ) -> QuickDbResult<u64> {
+        let collection_name = T::collection_name();
+        let database_alias = T::database_alias();
+
+        debug!(
+            "使用操作数组批量更新模型: collection={}, 条件数量={}, 操作数量={}",
+            collection_name,
+            conditions.len(),
+            operations.len()
+        );
+
+        let meta = T::meta();
+        let conditions = meta.scope_conditions(meta.map_conditions(conditions));
+        let operations = meta.map_update_operations(operations);
+        odm::update_with_operations_and_options(
+            &collection_name,
+            conditions,
+            operations,
+            options,
+            database_alias.as_deref(),
+        )
+        .await
+    }
+
+    /// 根据ID原子性增加字段值
+    ///
+    /// 在数据库中执行 `field = field + amount`，不需要先读取记录，并发更新不会丢失。
+    /// 返回记录是否存在并已更新
+    pub async fn increment(id: &str, field: &str, amount: impl Into<DataValue>) -> QuickDbResult<bool> {
+        Self::update_by_id_with_ops(id, vec![UpdateOperation::increment(field, amount)]).await
+    }
+
+    /// 根据ID原子性减少字段值
+    ///
+    /// 在数据库中执行 `field = field - amount`，返回记录是否存在并已更新
+    pub async fn decrement(id: &str, field: &str, amount: impl Into<DataValue>) -> QuickDbResult<bool> {
+        Self::update_by_id_with_ops(id, vec![UpdateOperation::decrement(field, amount)]).await
+    }
+
+    async fn update_by_id_with_ops(id: &str, operations: Vec<UpdateOperation>) -> QuickDbResult<bool> {
+        debug!("根据ID原子更新模型: collection={}, id={}", T::collection_name(), id);
+
+        let conditions = T::meta().primary_key_conditions(&Self::id_key(id)?)?;
+        let affected = <Self as ModelOperations<T>>::update_many_with_operations(conditions, operations).await?;
+        Ok(affected > 0)
+    }
+
+    /// 把ID字符串转换为主键值映射（模型声明了单字段自定义主键时使用该字段）
+    ///
+    /// 复合主键无法用单个ID表示，返回验证错误
+    fn id_key(id: &str) -> QuickDbResult<HashMap<String, DataValue>> {
+        let meta = T::meta();
+        match meta.primary_key_fields().as_slice() {
+            [field] => Ok(HashMap::from([(field.clone(), DataValue::String(id.to_string()))])),
+            _ => Err(QuickDbError::ValidationError {
+                field: "primary_key".to_string(),
+                message: crate::i18n::tf("model.composite_key_requires_map", &[("collection", &meta.collection_name)]),
+            }),
+        }
+    }
+
     /// 查找模型（简化方法，支持缓存控制）
     ///
     /// 接受 `Vec<QueryCondition>` 并自动转换为 `Vec<QueryConditionWithConfig>`
@@ -80,8 +200,66 @@ impl<T: Model> ModelManager<T> {
         <Self as ModelOperations<T>>::find_with_cache_control(conditions_with_config, options, bypass_cache).await
     }
 
+    /// 部分字段查询（简化方法）
+    ///
+    /// 接受 `Vec<QueryCondition>` 并自动转换为 `Vec<QueryConditionWithConfig>`
+    pub async fn find_partial(
+        conditions: Vec<QueryCondition>,
+        fields: &[&str],
+        options: Option<QueryOptions>,
+    ) -> QuickDbResult<Vec<HashMap<String, DataValue>>> {
+        let conditions_with_config: Vec<QueryConditionWithConfig> = conditions
+            .into_iter()
+            .map(|c| c.into())
+            .collect();
+        Self::find_partial_with_config(conditions_with_config, fields, options).await
+    }
+
     // ========== 完整方法：接受 QueryConditionWithConfig ==========
 
+    /// 部分字段查询（带配置）
+    ///
+    /// 只查询 `fields` 指定的字段（覆盖 `options.fields`），结果不反序列化为模型，
+    /// 而是以 Rust 字段名为键的数据映射返回，未查询的字段不会出现在结果中。
+    /// 字段值按模型元数据完成类型转换（JSON 字符串、布尔整数等）
+    pub async fn find_partial_with_config(
+        conditions: Vec<QueryConditionWithConfig>,
+        fields: &[&str],
+        options: Option<QueryOptions>,
+    ) -> QuickDbResult<Vec<HashMap<String, DataValue>>> {
+        let collection_name = T::collection_name();
+        let database_alias = T::database_alias();
+
+        debug!("部分字段查询: collection={}, fields={:?}", collection_name, fields);
+
+        let meta = T::meta();
+        let conditions = meta.scope_conditions(meta.map_conditions(conditions));
+        let options = options
+            .unwrap_or_default()
+            .with_fields(fields.iter().map(|field| field.to_string()).collect());
+        let options = meta.map_query_options(Some(options));
+
+        let result = odm::find_with_cache_control(
+            &collection_name,
+            conditions,
+            options,
+            database_alias.as_deref(),
+            false,
+        )
+        .await?;
+
+        let mut rows = Vec::with_capacity(result.len());
+        for data_value in result {
+            let data_map = match data_value {
+                DataValue::Object(data_map) => data_map,
+                other => other.deserialize_to()?,
+            };
+            let data_map = crate::process_data_fields_from_metadata(data_map, &meta.fields);
+            rows.push(meta.unmap_data_fields(data_map));
+        }
+        Ok(rows)
+    }
+
     /// 查找模型（带配置）
     ///
     /// 接受 `Vec<QueryConditionWithConfig>`，支持大小写不敏感等高级配置
@@ -124,6 +302,122 @@ impl<T: Model> ModelManager<T> {
         <Self as ModelOperations<T>>::delete_many(conditions).await
     }
 
+    /// 根据ID检查模型是否存在
+    ///
+    /// 适配器使用 `SELECT 1` / `count_documents` 判断，不读取和反序列化整行数据。
+    /// 单表继承模型需要校验鉴别器，改为按 ID 与鉴别器条件计数
+    pub async fn exists_by_id(id: &str) -> QuickDbResult<bool> {
+        let collection_name = T::collection_name();
+
+        debug!("根据ID检查模型是否存在: collection={}, id={}", collection_name, id);
+
+        if T::meta().discriminator.is_some() {
+            let condition = QueryConditionWithConfig {
+                field: "id".to_string(),
+                operator: QueryOperator::Eq,
+                value: DataValue::String(id.to_string()),
+                case_insensitive: false,
+            };
+            let count = <Self as ModelOperations<T>>::count_with_config(vec![condition]).await?;
+            return Ok(count > 0);
+        }
+
+        odm::exists_by_id(&collection_name, id, T::database_alias().as_deref()).await
+    }
+
+    /// 从数据库重新加载模型
+    ///
+    /// 按主键重新查询记录（绕过缓存）并覆盖内存中的全部字段，适用于触发器或并发
+    /// 更新修改了已存储记录的场景。记录已不存在时返回 `NotFound` 错误
+    pub async fn reload(model: &mut T) -> QuickDbResult<()> {
+        let meta = T::meta();
+        let key = meta.extract_primary_key(&model.to_data_map()?)?;
+        let conditions = meta.primary_key_conditions(&key)?;
+
+        debug!("重新加载模型: collection={}, key={:?}", T::collection_name(), key);
+
+        let options = QueryOptions::new()
+            .with_pagination(PaginationConfig { skip: 0, limit: 1 })
+            .unscoped();
+        let mut models =
+            <Self as ModelOperations<T>>::find_with_cache_control(conditions, Some(options), true).await?;
+        if models.is_empty() {
+            return Err(QuickDbError::NotFound {
+                message: crate::i18n::tf(
+                    "model.reload_not_found",
+                    &[("collection", &T::collection_name())],
+                ),
+            });
+        }
+        *model = models.remove(0);
+        Ok(())
+    }
+
+    /// 按主键分批遍历满足条件的模型
+    ///
+    /// 内部按主键升序进行键集分页（`WHERE 主键 > 上一批最后的主键`），每批最多
+    /// `batch_size` 条记录交给回调处理，不受偏移量增大的影响，适合遍历大表的维护任务。
+    /// 查询绕过缓存，回调返回错误时立即停止。返回处理的记录总数
+    pub async fn for_each_batch<F, Fut>(
+        conditions: Vec<QueryCondition>,
+        batch_size: u64,
+        mut f: F,
+    ) -> QuickDbResult<u64>
+    where
+        F: FnMut(Vec<T>) -> Fut,
+        Fut: std::future::Future<Output = QuickDbResult<()>>,
+    {
+        if batch_size == 0 {
+            return Err(QuickDbError::ValidationError {
+                field: "batch_size".to_string(),
+                message: crate::i18n::t("model.batch_size_zero"),
+            });
+        }
+
+        let meta = T::meta();
+        let pk_fields = meta.primary_key_fields();
+        let sort = pk_fields
+            .iter()
+            .map(|field| SortConfig {
+                field: field.clone(),
+                direction: SortDirection::Asc,
+            })
+            .collect::<Vec<_>>();
+        let conditions: Vec<QueryConditionGroupWithConfig> = conditions.into_iter().map(|c| c.into()).collect();
+
+        debug!("分批遍历模型: collection={}, batch_size={}", T::collection_name(), batch_size);
+
+        let mut last_key: Option<HashMap<String, DataValue>> = None;
+        let mut processed = 0;
+        loop {
+            let mut groups = conditions.clone();
+            if let Some(key) = &last_key {
+                groups.push(keyset_after(&pk_fields, key));
+            }
+            let options = QueryOptions::new()
+                .with_sort(sort.clone())
+                .with_pagination(PaginationConfig { skip: 0, limit: batch_size });
+            let batch = <Self as ModelOperations<T>>::find_with_groups_with_cache_control_and_config(
+                groups,
+                Some(options),
+                true,
+            )
+            .await?;
+
+            let len = batch.len() as u64;
+            match batch.last() {
+                Some(model) => last_key = Some(meta.extract_primary_key(&model.to_data_map()?)?),
+                None => break,
+            }
+            f(batch).await?;
+            processed += len;
+            if len < batch_size {
+                break;
+            }
+        }
+        Ok(processed)
+    }
+
     /// 创建表（静态便利方法）
     ///
     /// 使用模型的元数据直接创建表，无需插入数据
@@ -149,6 +443,11 @@ impl<T: Model> ModelOperations<T> for ModelManager<T> {
     }
 
     async fn find_by_id(id: &str) -> QuickDbResult<Option<T>> {
+        // 自定义主键的模型按主键映射查询
+        if T::meta().has_custom_primary_key() {
+            return <Self as ModelOperations<T>>::find_by_key(Self::id_key(id)?).await;
+        }
+
         let collection_name = T::collection_name();
         let database_alias = T::database_alias();
 
@@ -161,6 +460,10 @@ impl<T: Model> ModelOperations<T> for ModelManager<T> {
             match data_value {
                 DataValue::Object(data_map) => {
                     debug!("从数据库收到的数据: {:?}", data_map);
+                    // 单表继承：记录属于同表的其他模型时视为不存在
+                    if !T::meta().matches_discriminator(&data_map) {
+                        return Ok(None);
+                    }
                     let model: T = match T::from_data_map(data_map.clone()) {
                         Ok(model) => model,
                         Err(e) => {
@@ -182,6 +485,27 @@ impl<T: Model> ModelOperations<T> for ModelManager<T> {
         }
     }
 
+    async fn find_by_key(key: HashMap<String, DataValue>) -> QuickDbResult<Option<T>> {
+        let meta = T::meta();
+        let mut conditions = meta.primary_key_conditions(&key)?;
+
+        // 默认 `id` 主键走按ID查询的路径（ID类型转换与按ID缓存）
+        if !meta.has_custom_primary_key() {
+            let id = conditions.remove(0).value.to_string();
+            return <Self as ModelOperations<T>>::find_by_id(&id).await;
+        }
+
+        debug!("根据主键查找模型: collection={}, key={:?}", T::collection_name(), key);
+
+        // 与按ID查询一致，不应用查询作用域
+        let options = QueryOptions::new()
+            .unscoped()
+            .with_pagination(PaginationConfig { skip: 0, limit: 1 });
+        let mut models =
+            <Self as ModelOperations<T>>::find_with_cache_control(conditions, Some(options), false).await?;
+        Ok(if models.is_empty() { None } else { Some(models.remove(0)) })
+    }
+
     async fn find_with_cache_control(
         conditions: Vec<QueryConditionWithConfig>,
         options: Option<QueryOptions>,
@@ -192,6 +516,10 @@ impl<T: Model> ModelOperations<T> for ModelManager<T> {
 
         debug!("查找模型（bypass_cache={}）: collection={}", bypass_cache, collection_name);
 
+        let meta = T::meta();
+        let conditions = meta.scope_conditions(meta.map_conditions(conditions));
+        let options = meta.map_query_options(options);
+
         let result = odm::find_with_cache_control(
             &collection_name,
             conditions,
@@ -244,6 +572,18 @@ impl<T: Model> ModelOperations<T> for ModelManager<T> {
         })
     }
 
+    async fn update_by_key(
+        key: HashMap<String, DataValue>,
+        updates: HashMap<String, DataValue>,
+    ) -> QuickDbResult<bool> {
+        let conditions = T::meta().primary_key_conditions(&key)?;
+
+        debug!("根据主键更新模型: collection={}, key={:?}", T::collection_name(), key);
+
+        let affected = <Self as ModelOperations<T>>::update_many(conditions, updates).await?;
+        Ok(affected > 0)
+    }
+
     async fn delete(&self) -> QuickDbResult<bool> {
         // 这个方法需要模型实例，应该在具体的模型实现中调用
         Err(QuickDbError::ValidationError {
@@ -252,6 +592,15 @@ impl<T: Model> ModelOperations<T> for ModelManager<T> {
         })
     }
 
+    async fn delete_by_key(key: HashMap<String, DataValue>) -> QuickDbResult<bool> {
+        let conditions = T::meta().primary_key_conditions(&key)?;
+
+        debug!("根据主键删除模型: collection={}, key={:?}", T::collection_name(), key);
+
+        let affected = <Self as ModelOperations<T>>::delete_many(conditions).await?;
+        Ok(affected > 0)
+    }
+
     async fn count(conditions: Vec<QueryCondition>) -> QuickDbResult<u64> {
         let conditions_with_config: Vec<QueryConditionWithConfig> = conditions
             .into_iter()
@@ -266,6 +615,8 @@ impl<T: Model> ModelOperations<T> for ModelManager<T> {
 
         debug!("统计模型数量: collection={}", collection_name);
 
+        let meta = T::meta();
+        let conditions = meta.scope_conditions(meta.map_conditions(conditions));
         odm::count(&collection_name, conditions, database_alias.as_deref()).await
     }
 
@@ -277,6 +628,9 @@ impl<T: Model> ModelOperations<T> for ModelManager<T> {
 
         debug!("使用条件组统计模型数量: collection={}", collection_name);
 
+        let meta = T::meta();
+        let condition_groups =
+            meta.scope_condition_groups(meta.map_condition_groups_with_config(condition_groups));
         odm::count_with_groups(&collection_name, condition_groups, database_alias.as_deref()).await
     }
 
@@ -291,10 +645,14 @@ impl<T: Model> ModelOperations<T> for ModelManager<T> {
         debug!("使用条件组查找模型（bypass_cache={}）: collection={}", bypass_cache, collection_name);
 
         // 转换为完整版
-        let condition_groups_with_config: Vec<QueryConditionGroupWithConfig> = condition_groups
+        let meta = T::meta();
+        let condition_groups_with_config: Vec<QueryConditionGroupWithConfig> = meta
+            .map_condition_groups(condition_groups)
             .into_iter()
             .map(|g| g.into())
             .collect();
+        let condition_groups_with_config = meta.scope_condition_groups(condition_groups_with_config);
+        let options = meta.map_query_options(options);
 
         let result = odm::find_with_groups_with_cache_control(
             &collection_name,
@@ -338,6 +696,11 @@ impl<T: Model> ModelOperations<T> for ModelManager<T> {
 
         debug!("使用条件组查找模型（bypass_cache={}）: collection={}", bypass_cache, collection_name);
 
+        let meta = T::meta();
+        let condition_groups =
+            meta.scope_condition_groups(meta.map_condition_groups_with_config(condition_groups));
+        let options = meta.map_query_options(options);
+
         let result = odm::find_with_groups_with_cache_control(
             &collection_name,
             condition_groups,
@@ -363,22 +726,7 @@ impl<T: Model> ModelOperations<T> for ModelManager<T> {
         conditions: Vec<QueryConditionWithConfig>,
         updates: HashMap<String, DataValue>,
     ) -> QuickDbResult<u64> {
-        let collection_name = T::collection_name();
-        let database_alias = T::database_alias();
-
-        debug!(
-            "批量更新模型: collection={}, 条件数量={}",
-            collection_name,
-            conditions.len()
-        );
-
-        odm::update(
-            &collection_name,
-            conditions,
-            updates,
-            database_alias.as_deref(),
-        )
-        .await
+        Self::update_many_with_options(conditions, updates, UpdateOptions::default()).await
     }
 
     /// 使用操作数组批量更新模型
@@ -388,23 +736,7 @@ impl<T: Model> ModelOperations<T> for ModelManager<T> {
         conditions: Vec<QueryConditionWithConfig>,
         operations: Vec<crate::types::UpdateOperation>,
     ) -> QuickDbResult<u64> {
-        let collection_name = T::collection_name();
-        let database_alias = T::database_alias();
-
-        debug!(
-            "使用操作数组批量更新模型: collection={}, 条件数量={}, 操作数量={}",
-            collection_name,
-            conditions.len(),
-            operations.len()
-        );
-
-        odm::update_with_operations(
-            &collection_name,
-            conditions,
-            operations,
-            database_alias.as_deref(),
-        )
-        .await
+        Self::update_many_with_operations_and_options(conditions, operations, UpdateOptions::default()).await
     }
 
     /// 批量删除模型
@@ -420,6 +752,8 @@ impl<T: Model> ModelOperations<T> for ModelManager<T> {
             conditions.len()
         );
 
+        let meta = T::meta();
+        let conditions = meta.scope_conditions(meta.map_conditions(conditions));
         odm::delete(&collection_name, conditions, database_alias.as_deref()).await
     }
 
@@ -467,4 +801,375 @@ impl<T: Model> ModelOperations<T> for ModelManager<T> {
             .execute_stored_procedure(procedure_name, database_alias.as_deref(), params)
             .await
     }
+
+    /// 执行存储过程并返回带类型的结果（多结果集与输出参数）
+    async fn execute_stored_procedure_typed(
+        procedure_name: &str,
+        params: Option<std::collections::HashMap<String, crate::types::DataValue>>,
+    ) -> QuickDbResult<crate::stored_procedure::StoredProcedureOutput> {
+        debug!("通过模型管理器执行带类型的存储过程: {}", procedure_name);
+        let database_alias = T::database_alias().or_else(|| Some("default".to_string()));
+        odm::execute_stored_procedure_typed(procedure_name, database_alias.as_deref(), params).await
+    }
+}
+
+/// 构造键集分页条件：主键按字典序大于 `key`
+///
+/// 复合主键 `(a, b)` 展开为 `a > ? OR (a = ? AND b > ?)`
+fn keyset_after(pk_fields: &[String], key: &HashMap<String, DataValue>) -> QueryConditionGroupWithConfig {
+    let condition = |field: &String, operator: QueryOperator| {
+        QueryConditionGroupWithConfig::Single(QueryConditionWithConfig {
+            field: field.clone(),
+            operator,
+            value: key.get(field).cloned().unwrap_or(DataValue::Null),
+            case_insensitive: false,
+        })
+    };
+    let mut branches: Vec<QueryConditionGroupWithConfig> = (0..pk_fields.len())
+        .map(|i| {
+            let mut parts: Vec<_> = pk_fields[..i].iter().map(|field| condition(field, QueryOperator::Eq)).collect();
+            parts.push(condition(&pk_fields[i], QueryOperator::Gt));
+            if parts.len() == 1 {
+                parts.remove(0)
+            } else {
+                QueryConditionGroupWithConfig::GroupWithConfig {
+                    operator: LogicalOperator::And,
+                    conditions: parts,
+                }
+            }
+        })
+        .collect();
+    if branches.len() == 1 {
+        branches.remove(0)
+    } else {
+        QueryConditionGroupWithConfig::GroupWithConfig {
+            operator: LogicalOperator::Or,
+            conditions: branches,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod model {
+        use crate::model::*;
+        use crate::{define_model, float_field, integer_field, string_field};
+
+        pub const SUITE_ALIAS: &str = "manager_suite";
+        pub const AUTO_INCREMENT_ALIAS: &str = "manager_auto_increment";
+
+        define_model! {
+            struct Counter {
+                id: String,
+                name: String,
+                hits: i32,
+                score: f64,
+            }
+            collection = "manager_counters",
+            database = SUITE_ALIAS,
+            fields = {
+                id: string_field(None, None, None),
+                name: string_field(Some(32), None, None).required(),
+                hits: integer_field(None, None),
+                score: float_field(None, None),
+            }
+        }
+
+        define_model! {
+            struct Coupon {
+                id: String,
+                code: String,
+                uses: i32,
+            }
+            collection = "manager_coupons",
+            primary_key = ["code"],
+            fields = {
+                id: string_field(None, None, None),
+                code: string_field(None, None, None).required(),
+                uses: integer_field(None, None),
+            }
+        }
+
+        define_model! {
+            struct LineItem {
+                id: String,
+                order_id: i32,
+                line: i32,
+            }
+            collection = "manager_line_items",
+            primary_key = ["order_id", "line"],
+            fields = {
+                id: string_field(None, None, None),
+                order_id: integer_field(None, None).required(),
+                line: integer_field(None, None).required(),
+            }
+        }
+
+        define_model! {
+            struct Shipment {
+                id: String,
+                carrier: String,
+                tracking: String,
+            }
+            collection = "manager_shipments",
+            database = AUTO_INCREMENT_ALIAS,
+            primary_key = ["carrier", "tracking"],
+            fields = {
+                id: string_field(None, None, None),
+                carrier: string_field(None, None, None).required(),
+                tracking: string_field(None, None, None).required(),
+            }
+        }
+    }
+
+    /// 添加各测试共用的 SQLite 数据库（只添加一次）
+    #[cfg(feature = "sqlite-support")]
+    async fn setup_suite() {
+        static SETUP: tokio::sync::OnceCell<()> = tokio::sync::OnceCell::const_new();
+        SETUP
+            .get_or_init(|| async {
+                crate::i18n::ErrorMessageI18n::init_i18n();
+                crate::manager::get_global_pool_manager()
+                    .add_database(DatabaseConfig {
+                        id_strategy: IdStrategy::Uuid,
+                        ..DatabaseConfig::sqlite_memory_for_test(model::SUITE_ALIAS)
+                    })
+                    .await
+                    .unwrap();
+                model::Counter::meta();
+            })
+            .await;
+    }
+
+    #[cfg(feature = "sqlite-support")]
+    async fn create_counter(name: &str) -> String {
+        let data = HashMap::from([
+            ("name".to_string(), DataValue::String(name.to_string())),
+            ("hits".to_string(), DataValue::Int(0)),
+            ("score".to_string(), DataValue::Float(1.5)),
+        ]);
+        match odm::create("manager_counters", data, Some(model::SUITE_ALIAS)).await.unwrap() {
+            DataValue::Object(record) => record["id"].to_string(),
+            other => other.to_string(),
+        }
+    }
+
+    #[cfg(feature = "sqlite-support")]
+    async fn find_counter(id: &str) -> model::Counter {
+        let condition = QueryCondition {
+            field: "id".to_string(),
+            operator: QueryOperator::Eq,
+            value: DataValue::String(id.to_string()),
+        };
+        ModelManager::<model::Counter>::find(vec![condition], None).await.unwrap().remove(0)
+    }
+
+    #[cfg(feature = "sqlite-support")]
+    #[test]
+    fn concurrent_increments_are_not_lost() {
+        odm::global::block_on_shared(async {
+            setup_suite().await;
+            let id = create_counter("concurrent").await;
+
+            let tasks: Vec<_> = (0..20)
+                .map(|_| {
+                    let id = id.clone();
+                    tokio::spawn(async move { ModelManager::<model::Counter>::increment(&id, "hits", 1).await })
+                })
+                .collect();
+            for task in tasks {
+                assert!(task.await.unwrap().unwrap());
+            }
+            assert!(ModelManager::<model::Counter>::decrement(&id, "hits", 5).await.unwrap());
+            assert_eq!(find_counter(&id).await.hits, 15);
+
+            // 记录不存在时返回 false
+            assert!(!ModelManager::<model::Counter>::increment("9999", "hits", 1).await.unwrap());
+        })
+    }
+
+    #[cfg(feature = "sqlite-support")]
+    #[test]
+    fn update_with_ops_applies_mixed_operations() {
+        odm::global::block_on_shared(async {
+            setup_suite().await;
+            let id = create_counter("mixed").await;
+            create_counter("untouched").await;
+
+            let condition = QueryCondition {
+                field: "name".to_string(),
+                operator: QueryOperator::Eq,
+                value: DataValue::String("mixed".to_string()),
+            };
+            let affected = ModelManager::<model::Counter>::update_with_ops(
+                vec![condition],
+                vec![
+                    UpdateOperation::increment("hits", 3),
+                    UpdateOperation::multiply("score", 2.0),
+                    UpdateOperation::set("name", "renamed"),
+                ],
+            )
+            .await
+            .unwrap();
+            assert_eq!(affected, 1);
+
+            let counter = find_counter(&id).await;
+            assert_eq!((counter.name.as_str(), counter.hits, counter.score), ("renamed", 3, 3.0));
+        })
+    }
+
+    #[cfg(feature = "sqlite-support")]
+    #[test]
+    fn reload_picks_up_external_updates() {
+        odm::global::block_on_shared(async {
+            setup_suite().await;
+            let id = create_counter("reloaded").await;
+            let mut counter = find_counter(&id).await;
+
+            // 绕过模型直接修改已存储的记录
+            let params = [DataValue::String(id.clone())];
+            crate::manager::get_global_pool_manager()
+                .with_connection(Some(model::SUITE_ALIAS), |mut conn| async move {
+                    conn.execute("UPDATE manager_counters SET hits = 42 WHERE id = ?", &params).await
+                })
+                .await
+                .unwrap();
+            assert_eq!(counter.hits, 0);
+            ModelManager::<model::Counter>::reload(&mut counter).await.unwrap();
+            assert_eq!((counter.id.as_str(), counter.hits), (id.as_str(), 42));
+
+            odm::delete_by_id("manager_counters", &id, Some(model::SUITE_ALIAS)).await.unwrap();
+            assert!(matches!(
+                ModelManager::<model::Counter>::reload(&mut counter).await,
+                Err(QuickDbError::NotFound { .. })
+            ));
+        })
+    }
+
+    #[cfg(feature = "sqlite-support")]
+    #[test]
+    fn exists_by_id_reports_stored_records() {
+        odm::global::block_on_shared(async {
+            setup_suite().await;
+            let id = create_counter("exists").await;
+
+            assert!(ModelManager::<model::Counter>::exists_by_id(&id).await.unwrap());
+            assert!(!ModelManager::<model::Counter>::exists_by_id("missing").await.unwrap());
+        })
+    }
+
+    #[cfg(feature = "sqlite-support")]
+    #[test]
+    fn find_partial_returns_only_projected_fields() {
+        odm::global::block_on_shared(async {
+            setup_suite().await;
+            create_counter("partial").await;
+
+            let condition = QueryCondition {
+                field: "name".to_string(),
+                operator: QueryOperator::Eq,
+                value: DataValue::String("partial".to_string()),
+            };
+            let rows = ModelManager::<model::Counter>::find_partial(vec![condition], &["name", "score"], None)
+                .await
+                .unwrap();
+            assert_eq!(
+                rows,
+                vec![HashMap::from([
+                    ("name".to_string(), DataValue::String("partial".to_string())),
+                    ("score".to_string(), DataValue::Float(1.5)),
+                ])]
+            );
+        })
+    }
+
+    #[cfg(feature = "sqlite-support")]
+    #[test]
+    fn composite_key_records_get_an_auto_increment_id() {
+        odm::global::block_on_shared(async {
+            crate::i18n::ErrorMessageI18n::init_i18n();
+            crate::manager::get_global_pool_manager()
+                .add_database(DatabaseConfig {
+                    id_strategy: IdStrategy::AutoIncrement,
+                    ..DatabaseConfig::sqlite_memory_for_test(model::AUTO_INCREMENT_ALIAS)
+                })
+                .await
+                .unwrap();
+            model::Shipment::meta();
+
+            let mut ids = Vec::new();
+            for tracking in ["T-1", "T-2"] {
+                let shipment = model::Shipment {
+                    id: String::new(),
+                    carrier: "acme".to_string(),
+                    tracking: tracking.to_string(),
+                };
+                ids.push(shipment.save().await.unwrap());
+            }
+            assert_ne!(ids[0], ids[1]);
+
+            let key = HashMap::from([
+                ("carrier".to_string(), DataValue::String("acme".to_string())),
+                ("tracking".to_string(), DataValue::String("T-2".to_string())),
+            ]);
+            let found = ModelManager::<model::Shipment>::find_by_key(key).await.unwrap().unwrap();
+            assert_eq!(found.id, ids[1]);
+
+            let record = odm::find_by_id("manager_shipments", &ids[1], Some(model::AUTO_INCREMENT_ALIAS))
+                .await
+                .unwrap();
+            assert!(matches!(record, Some(DataValue::Object(record)) if record["tracking"].to_string() == "T-2"));
+        })
+    }
+
+    #[tokio::test]
+    async fn increment_requires_a_single_field_primary_key() {
+        crate::i18n::ErrorMessageI18n::init_i18n();
+        // 复合主键无法由单个ID定位，在访问数据库前返回验证错误
+        assert!(matches!(
+            ModelManager::<model::LineItem>::increment("1", "line", 1).await,
+            Err(QuickDbError::ValidationError { .. })
+        ));
+    }
+
+    #[test]
+    fn id_key_follows_primary_key() {
+        crate::i18n::ErrorMessageI18n::init_i18n();
+        use model::{Coupon, LineItem};
+
+        let key = ModelManager::<Coupon>::id_key("SPRING").unwrap();
+        assert_eq!(key, HashMap::from([("code".to_string(), DataValue::String("SPRING".to_string()))]));
+        assert!(matches!(
+            ModelManager::<LineItem>::id_key("1"),
+            Err(QuickDbError::ValidationError { .. })
+        ));
+    }
+
+    #[test]
+    fn keyset_after_expands_composite_key() {
+        let key = HashMap::from([
+            ("tenant".to_string(), DataValue::Int(1)),
+            ("seq".to_string(), DataValue::Int(7)),
+        ]);
+        let single = keyset_after(&["tenant".to_string()], &key);
+        assert!(matches!(
+            single,
+            QueryConditionGroupWithConfig::Single(QueryConditionWithConfig { operator: QueryOperator::Gt, .. })
+        ));
+
+        let QueryConditionGroupWithConfig::GroupWithConfig { operator, conditions } =
+            keyset_after(&["tenant".to_string(), "seq".to_string()], &key)
+        else {
+            panic!("复合主键应展开为条件组");
+        };
+        assert_eq!(operator, LogicalOperator::Or);
+        assert!(matches!(
+            &conditions[1],
+            QueryConditionGroupWithConfig::GroupWithConfig { operator: LogicalOperator::And, conditions }
+                if matches!(&conditions[1], QueryConditionGroupWithConfig::Single(c)
+                    if c.field == "seq" && c.operator == QueryOperator::Gt && c.value == DataValue::Int(7))
+        ));
+    }
 }
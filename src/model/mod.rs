@@ -3,19 +3,35 @@
 //! 参考mongoengine的设计，支持通过结构体定义数据表结构
 //! 提供字段类型、验证、索引等功能
 
+pub mod computed;
 pub mod convenience;
 pub mod conversion;
 pub mod data_conversion;
+pub mod defaults;
+pub mod field_mapping;
 pub mod field_types;
+pub mod inheritance;
+pub mod json_schema;
 pub mod macros;
 pub mod manager;
+pub mod polymorphic;
+pub mod rules;
+pub mod scopes;
 pub mod traits;
+pub mod validators;
 
 // 重新导出核心类型（保持向后兼容）
 pub use convenience::*;
-pub use conversion::ToDataValue;
+pub use conversion::{FromDataMap, FromDataValue, ToDataValue};
 pub use data_conversion::{create_model_from_data_map, create_model_from_data_map_with_debug};
-pub use field_types::{FieldDefinition, FieldType, IndexDefinition, ModelMeta};
-pub use macros::*;
+pub use defaults::{DefaultGenerator, DefaultValue};
+pub use field_mapping::RenameRule;
+pub use field_types::{
+    Discriminator, ExpiresAfter, FieldDefinition, FieldType, IndexDefinition, IndexOptions, ModelMeta,
+};
+pub use json_schema::{export_all_openapi_components, export_json_schema};
 pub use manager::ModelManager;
+pub use polymorphic::{PolymorphicRef, populate_polymorphic};
+pub use rules::{CompareOperator, ValidationRule};
+pub use scopes::{QueryScope, QueryScopes};
 pub use traits::{Model, ModelOperations};
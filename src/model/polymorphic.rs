@@ -0,0 +1,219 @@
+//! 多态引用模块
+//!
+//! 多态引用同时记录目标集合名与目标ID，使评论等模型可以指向文章或视频等
+//! 不同集合，而无需为每个目标集合单独设置可空的引用列。
+//! 值以 `{"collection": ..., "id": ...}` 对象存储：SQL 数据库中为 JSON 列，
+//! MongoDB 中为子文档
+
+use crate::error::{QuickDbError, QuickDbResult};
+use crate::model::conversion::{FromDataValue, ToDataValue, embedded_data_map};
+use crate::model::traits::{Model, ModelOperations};
+use crate::model::ModelManager;
+use crate::odm;
+use crate::types::DataValue;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// 多态引用值
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PolymorphicRef {
+    /// 目标集合名
+    pub collection: String,
+    /// 目标记录ID
+    pub id: String,
+}
+
+impl PolymorphicRef {
+    /// 创建指向指定集合记录的引用
+    pub fn new(collection: impl Into<String>, id: impl Into<String>) -> Self {
+        Self {
+            collection: collection.into(),
+            id: id.into(),
+        }
+    }
+
+    /// 创建指向模型 `T` 所在集合记录的引用
+    pub fn to<T: Model>(id: impl Into<String>) -> Self {
+        Self::new(T::collection_name(), id)
+    }
+
+    /// 判断引用是否指向模型 `T` 所在的集合
+    pub fn points_to<T: Model>(&self) -> bool {
+        self.collection == T::collection_name()
+    }
+
+    /// 加载引用的目标模型
+    ///
+    /// 引用指向其他集合时返回 `None`，便于依次尝试多个候选模型：
+    /// `if let Some(article) = r.populate::<Article>().await? { ... }`
+    pub async fn populate<T: Model>(&self) -> QuickDbResult<Option<T>> {
+        if !self.points_to::<T>() {
+            return Ok(None);
+        }
+        ModelManager::<T>::find_by_id(&self.id).await
+    }
+
+    /// 以原始数据形式加载引用的目标记录（目标模型在编译期未知时使用）
+    pub async fn populate_raw(&self, alias: Option<&str>) -> QuickDbResult<Option<DataValue>> {
+        odm::find_by_id(&self.collection, &self.id, alias).await
+    }
+
+    /// 校验引用目标是否在允许的集合列表中
+    pub fn validate_target(&self, target_collections: &[String]) -> QuickDbResult<()> {
+        if self.id.is_empty() {
+            return Err(QuickDbError::ValidationError {
+                field: "polymorphic_reference".to_string(),
+                message: crate::i18n::t("validation.polymorphic_reference_missing_id"),
+            });
+        }
+        if !target_collections.is_empty() && !target_collections.contains(&self.collection) {
+            return Err(QuickDbError::ValidationError {
+                field: "polymorphic_reference".to_string(),
+                message: crate::i18n::tf(
+                    "validation.polymorphic_reference_target",
+                    &[
+                        ("collection", &self.collection),
+                        ("allowed", &target_collections.join(", ")),
+                    ],
+                ),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl ToDataValue for PolymorphicRef {
+    fn to_data_value(&self) -> DataValue {
+        let mut map = HashMap::new();
+        map.insert(
+            "collection".to_string(),
+            DataValue::String(self.collection.clone()),
+        );
+        map.insert("id".to_string(), DataValue::String(self.id.clone()));
+        DataValue::Object(map)
+    }
+}
+
+impl FromDataValue for PolymorphicRef {
+    fn from_data_value(value: DataValue) -> QuickDbResult<Self> {
+        let mut map = embedded_data_map::<Self>(value)?;
+        Ok(Self {
+            collection: crate::model::conversion::decode_field(&mut map, "collection")?,
+            id: crate::model::conversion::decode_field(&mut map, "id")?,
+        })
+    }
+}
+
+/// 加载一组多态引用的目标记录，相同引用只查询一次
+///
+/// 返回结果与输入顺序一致，目标不存在时对应位置为 `None`
+pub async fn populate_polymorphic(
+    refs: &[PolymorphicRef],
+    alias: Option<&str>,
+) -> QuickDbResult<Vec<Option<DataValue>>> {
+    let mut loaded: HashMap<&PolymorphicRef, Option<DataValue>> = HashMap::new();
+    for reference in refs {
+        if !loaded.contains_key(reference) {
+            let value = reference.populate_raw(alias).await?;
+            loaded.insert(reference, value);
+        }
+    }
+    Ok(refs
+        .iter()
+        .map(|reference| loaded.get(reference).cloned().flatten())
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::field_types::FieldType;
+
+    mod model {
+        use crate::model::*;
+        use crate::{define_model, polymorphic_reference_field, string_field};
+
+        define_model! {
+            struct Comment {
+                id: String,
+                body: String,
+                target: PolymorphicRef,
+            }
+            collection = "poly_comments",
+            fields = {
+                id: string_field(None, None, None).required(),
+                body: string_field(None, None, None),
+                target: polymorphic_reference_field(vec!["articles".to_string(), "videos".to_string()]).required(),
+            }
+        }
+
+        define_model! {
+            struct Article {
+                id: String,
+                title: String,
+            }
+            collection = "articles",
+            fields = {
+                id: string_field(None, None, None).required(),
+                title: string_field(None, None, None),
+            }
+        }
+    }
+
+    #[test]
+    fn round_trips_through_object_and_json() {
+        let reference = PolymorphicRef::new("videos", "v1");
+        let value = reference.to_data_value();
+        assert!(matches!(value, DataValue::Object(_)));
+        assert_eq!(PolymorphicRef::from_data_value(value.clone()).unwrap(), reference);
+
+        // SQL 数据库读回的 JSON 文本同样可以解码
+        let from_text = PolymorphicRef::from_data_value(DataValue::String(
+            serde_json::json!({"collection": "videos", "id": "v1"}).to_string(),
+        ))
+        .unwrap();
+        assert_eq!(from_text, reference);
+    }
+
+    #[test]
+    fn validates_allowed_targets() {
+        crate::i18n::ErrorMessageI18n::init_i18n();
+        let field = crate::polymorphic_reference_field(vec![
+            "articles".to_string(),
+            "videos".to_string(),
+        ]);
+        assert!(matches!(
+            field.field_type,
+            FieldType::PolymorphicReference { ref target_collections } if target_collections.len() == 2
+        ));
+
+        assert!(field
+            .validate(&PolymorphicRef::new("articles", "a1").to_data_value())
+            .is_ok());
+        assert!(field
+            .validate(&PolymorphicRef::new("users", "u1").to_data_value())
+            .is_err());
+        assert!(field
+            .validate(&PolymorphicRef::new("videos", "").to_data_value())
+            .is_err());
+        assert!(field.validate(&DataValue::String("a1".to_string())).is_err());
+    }
+
+    #[test]
+    fn define_model_stores_reference_as_object() {
+        use model::{Article, Comment};
+
+        assert!(PolymorphicRef::to::<Article>("a1").points_to::<Article>());
+        assert!(!PolymorphicRef::new("videos", "v1").points_to::<Article>());
+
+        let comment = Comment {
+            id: "c1".to_string(),
+            body: "好文".to_string(),
+            target: PolymorphicRef::to::<Article>("a1"),
+        };
+        let data = comment.to_data_map_direct().unwrap();
+        assert_eq!(data["target"], PolymorphicRef::new("articles", "a1").to_data_value());
+        let decoded = Comment::from_data_map(data).unwrap();
+        assert_eq!(decoded.target, comment.target);
+    }
+}
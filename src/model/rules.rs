@@ -0,0 +1,409 @@
+//! 模型级跨字段校验规则模块
+//!
+//! 单字段校验由 `FieldDefinition::validate` 完成，本模块处理涉及多个字段的规则，
+//! 例如 `ends_at > starts_at`、"email 与 phone 恰好填写一个"。规则以枚举声明在
+//! `ModelMeta::validation_rules` 上，无法用枚举表达的规则可通过 [`register_rule`]
+//! 注册为闭包并以 [`ValidationRule::Custom`] 引用。
+//!
+//! 规则在 `Model::validate()`（保存、upsert）以及模型实例的 `update()` 中执行，
+//! 更新时使用合并了更新字段后的完整记录校验
+
+use crate::error::{QuickDbError, QuickDbResult};
+use crate::model::field_types::ModelMeta;
+use crate::types::DataValue;
+use chrono::{DateTime, FixedOffset};
+use dashmap::DashMap;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// 自定义校验规则（接收存储字段名为键的完整记录）
+pub type RuleFn = Arc<dyn Fn(&HashMap<String, DataValue>) -> QuickDbResult<()> + Send + Sync>;
+
+/// 全局自定义规则注册表 (名称 -> 规则)
+static RULES: Lazy<DashMap<String, RuleFn>> = Lazy::new(DashMap::new);
+
+/// 注册自定义校验规则，同名规则会被替换
+pub fn register_rule<F>(name: &str, rule: F)
+where
+    F: Fn(&HashMap<String, DataValue>) -> QuickDbResult<()> + Send + Sync + 'static,
+{
+    RULES.insert(name.to_string(), Arc::new(rule));
+}
+
+/// 移除已注册的自定义校验规则
+pub fn unregister_rule(name: &str) -> bool {
+    RULES.remove(name).is_some()
+}
+
+/// 字段比较操作符
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CompareOperator {
+    /// 大于
+    Gt,
+    /// 大于等于
+    Gte,
+    /// 小于
+    Lt,
+    /// 小于等于
+    Lte,
+    /// 等于
+    Eq,
+    /// 不等于
+    Ne,
+}
+
+impl CompareOperator {
+    /// 操作符符号（用于错误消息）
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            CompareOperator::Gt => ">",
+            CompareOperator::Gte => ">=",
+            CompareOperator::Lt => "<",
+            CompareOperator::Lte => "<=",
+            CompareOperator::Eq => "==",
+            CompareOperator::Ne => "!=",
+        }
+    }
+
+    fn accepts(&self, ordering: Ordering) -> bool {
+        match self {
+            CompareOperator::Gt => ordering == Ordering::Greater,
+            CompareOperator::Gte => ordering != Ordering::Less,
+            CompareOperator::Lt => ordering == Ordering::Less,
+            CompareOperator::Lte => ordering != Ordering::Greater,
+            CompareOperator::Eq => ordering == Ordering::Equal,
+            CompareOperator::Ne => ordering != Ordering::Equal,
+        }
+    }
+}
+
+/// 模型级校验规则
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ValidationRule {
+    /// 两个字段比较，如 `ends_at > starts_at`（任一字段为空时跳过）
+    Compare {
+        left: String,
+        operator: CompareOperator,
+        right: String,
+    },
+    /// 恰好一个字段非空
+    ExactlyOneOf { fields: Vec<String> },
+    /// 至少一个字段非空
+    AtLeastOneOf { fields: Vec<String> },
+    /// 至多一个字段非空
+    AtMostOneOf { fields: Vec<String> },
+    /// `field` 非空时 `required` 中的字段也必须非空
+    RequiredWith { field: String, required: Vec<String> },
+    /// 通过 `register_rule` 注册的自定义规则
+    Custom { name: String },
+}
+
+impl ValidationRule {
+    /// 创建字段比较规则
+    pub fn compare(left: &str, operator: CompareOperator, right: &str) -> Self {
+        Self::Compare {
+            left: left.to_string(),
+            operator,
+            right: right.to_string(),
+        }
+    }
+
+    /// 创建"恰好一个非空"规则
+    pub fn exactly_one_of(fields: &[&str]) -> Self {
+        Self::ExactlyOneOf {
+            fields: fields.iter().map(|f| f.to_string()).collect(),
+        }
+    }
+
+    /// 创建"至少一个非空"规则
+    pub fn at_least_one_of(fields: &[&str]) -> Self {
+        Self::AtLeastOneOf {
+            fields: fields.iter().map(|f| f.to_string()).collect(),
+        }
+    }
+
+    /// 创建"至多一个非空"规则
+    pub fn at_most_one_of(fields: &[&str]) -> Self {
+        Self::AtMostOneOf {
+            fields: fields.iter().map(|f| f.to_string()).collect(),
+        }
+    }
+
+    /// 创建"字段联动必填"规则
+    pub fn required_with(field: &str, required: &[&str]) -> Self {
+        Self::RequiredWith {
+            field: field.to_string(),
+            required: required.iter().map(|f| f.to_string()).collect(),
+        }
+    }
+
+    /// 引用已注册的自定义规则
+    pub fn custom(name: &str) -> Self {
+        Self::Custom {
+            name: name.to_string(),
+        }
+    }
+
+    /// 规则涉及的字段名（用于字段重命名映射）
+    pub(crate) fn fields_mut(&mut self) -> Vec<&mut String> {
+        match self {
+            ValidationRule::Compare { left, right, .. } => vec![left, right],
+            ValidationRule::ExactlyOneOf { fields }
+            | ValidationRule::AtLeastOneOf { fields }
+            | ValidationRule::AtMostOneOf { fields } => fields.iter_mut().collect(),
+            ValidationRule::RequiredWith { field, required } => {
+                let mut fields = vec![field];
+                fields.extend(required.iter_mut());
+                fields
+            }
+            ValidationRule::Custom { .. } => Vec::new(),
+        }
+    }
+
+    /// 对记录执行校验
+    pub fn check(&self, data: &HashMap<String, DataValue>) -> QuickDbResult<()> {
+        match self {
+            ValidationRule::Compare {
+                left,
+                operator,
+                right,
+            } => {
+                let (Some(left_value), Some(right_value)) =
+                    (present(data, left), present(data, right))
+                else {
+                    return Ok(());
+                };
+                let args = [
+                    ("left", left.as_str()),
+                    ("operator", operator.symbol()),
+                    ("right", right.as_str()),
+                ];
+                match compare_values(left_value, right_value) {
+                    Some(ordering) if operator.accepts(ordering) => Ok(()),
+                    Some(_) => Err(rule_error(left, "validation.rule_compare", &args)),
+                    None => Err(rule_error(left, "validation.rule_incomparable", &args)),
+                }
+            }
+            ValidationRule::ExactlyOneOf { fields } => {
+                check_count(data, fields, |count| count == 1, "validation.rule_exactly_one_of")
+            }
+            ValidationRule::AtLeastOneOf { fields } => {
+                check_count(data, fields, |count| count >= 1, "validation.rule_at_least_one_of")
+            }
+            ValidationRule::AtMostOneOf { fields } => {
+                check_count(data, fields, |count| count <= 1, "validation.rule_at_most_one_of")
+            }
+            ValidationRule::RequiredWith { field, required } => {
+                if present(data, field).is_none() {
+                    return Ok(());
+                }
+                match required.iter().find(|name| present(data, name).is_none()) {
+                    Some(missing) => Err(rule_error(
+                        missing,
+                        "validation.rule_required_with",
+                        &[("field", field.as_str()), ("required", missing.as_str())],
+                    )),
+                    None => Ok(()),
+                }
+            }
+            ValidationRule::Custom { name } => {
+                // 先克隆出规则，避免执行期间持有注册表的锁
+                let rule = RULES
+                    .get(name)
+                    .map(|entry| entry.value().clone())
+                    .ok_or_else(|| {
+                        rule_error(name, "validation.rule_not_registered", &[("name", name.as_str())])
+                    })?;
+                rule(data)
+            }
+        }
+    }
+}
+
+impl ModelMeta {
+    /// 对记录执行模型级校验规则（键为存储字段名）
+    pub fn validate_rules(&self, data: &HashMap<String, DataValue>) -> QuickDbResult<()> {
+        for rule in &self.validation_rules {
+            rule.check(data)?;
+        }
+        Ok(())
+    }
+}
+
+/// 获取非空字段值
+fn present<'a>(data: &'a HashMap<String, DataValue>, field: &str) -> Option<&'a DataValue> {
+    data.get(field).filter(|value| !matches!(value, DataValue::Null))
+}
+
+fn check_count(
+    data: &HashMap<String, DataValue>,
+    fields: &[String],
+    accepts: impl Fn(usize) -> bool,
+    key: &str,
+) -> QuickDbResult<()> {
+    let count = fields
+        .iter()
+        .filter(|field| present(data, field).is_some())
+        .count();
+    if accepts(count) {
+        return Ok(());
+    }
+    let joined = fields.join(", ");
+    Err(rule_error(&joined, key, &[("fields", joined.as_str())]))
+}
+
+fn rule_error(field: &str, key: &str, args: &[(&str, &str)]) -> QuickDbError {
+    QuickDbError::ValidationError {
+        field: field.to_string(),
+        message: crate::i18n::tf(key, args),
+    }
+}
+
+fn as_datetime(value: &DataValue) -> Option<DateTime<FixedOffset>> {
+    match value {
+        DataValue::DateTime(dt) => Some(*dt),
+        DataValue::DateTimeUTC(dt) => Some(dt.fixed_offset()),
+        DataValue::String(s) => DateTime::parse_from_rfc3339(s).ok(),
+        _ => None,
+    }
+}
+
+fn as_number(value: &DataValue) -> Option<f64> {
+    match value {
+        DataValue::Int(i) => Some(*i as f64),
+        DataValue::UInt(u) => Some(*u as f64),
+        DataValue::Float(f) => Some(*f),
+        _ => None,
+    }
+}
+
+/// 比较两个字段值，类型不可比较时返回 None
+pub(crate) fn compare_values(left: &DataValue, right: &DataValue) -> Option<Ordering> {
+    match (left, right) {
+        (DataValue::Int(a), DataValue::Int(b)) => Some(a.cmp(b)),
+        (DataValue::UInt(a), DataValue::UInt(b)) => Some(a.cmp(b)),
+        (DataValue::Bool(a), DataValue::Bool(b)) => Some(a.cmp(b)),
+        (DataValue::Uuid(a), DataValue::Uuid(b)) => Some(a.cmp(b)),
+        (DataValue::String(a), DataValue::String(b)) => {
+            // 两侧均为 RFC3339 时间字符串时按时间比较
+            match (DateTime::parse_from_rfc3339(a), DateTime::parse_from_rfc3339(b)) {
+                (Ok(a), Ok(b)) => Some(a.cmp(&b)),
+                _ => Some(a.cmp(b)),
+            }
+        }
+        _ => {
+            if let (Some(a), Some(b)) = (as_number(left), as_number(right)) {
+                return a.partial_cmp(&b);
+            }
+            match (as_datetime(left), as_datetime(right)) {
+                (Some(a), Some(b)) => Some(a.cmp(&b)),
+                _ => None,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::traits::Model;
+
+    mod model {
+        use crate::model::*;
+        use crate::{define_model, string_field};
+
+        define_model! {
+            struct Event {
+                id: String,
+                starts_at: chrono::DateTime<chrono::Utc>,
+                ends_at: chrono::DateTime<chrono::Utc>,
+                email: Option<String>,
+                phone: Option<String>,
+            }
+            collection = "rule_events",
+            rename_all = "camelCase",
+            validation_rules = [
+                ValidationRule::compare("ends_at", CompareOperator::Gt, "starts_at"),
+                ValidationRule::exactly_one_of(&["email", "phone"]),
+            ],
+            fields = {
+                id: string_field(None, None, None).required(),
+                starts_at: FieldDefinition::new(FieldType::DateTime),
+                ends_at: FieldDefinition::new(FieldType::DateTime),
+                email: string_field(None, None, None),
+                phone: string_field(None, None, None),
+            }
+        }
+    }
+
+    fn event(hours: i64, email: Option<&str>, phone: Option<&str>) -> model::Event {
+        let starts_at = chrono::Utc::now();
+        model::Event {
+            id: "e1".to_string(),
+            starts_at,
+            ends_at: starts_at + chrono::Duration::hours(hours),
+            email: email.map(str::to_string),
+            phone: phone.map(str::to_string),
+        }
+    }
+
+    fn rejected_message(result: QuickDbResult<()>) -> String {
+        match result {
+            Err(QuickDbError::ValidationError { message, .. }) => message,
+            other => panic!("expected validation error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn define_model_runs_rules_on_storage_names() {
+        crate::i18n::ErrorMessageI18n::init_i18n();
+        assert_eq!(
+            model::Event::meta().validation_rules[0],
+            ValidationRule::compare("endsAt", CompareOperator::Gt, "startsAt")
+        );
+
+        assert!(event(2, Some("a@b.c"), None).validate().is_ok());
+
+        let message = rejected_message(event(-2, None, Some("123")).validate());
+        assert!(message.contains("endsAt") && message.contains(">"));
+
+        let message = rejected_message(event(2, Some("a@b.c"), Some("123")).validate());
+        assert!(message.contains("email, phone"));
+        assert!(event(2, None, None).validate().is_err());
+    }
+
+    #[test]
+    fn checks_counts_dependencies_and_custom_rules() {
+        crate::i18n::ErrorMessageI18n::init_i18n();
+        let mut data = HashMap::new();
+        data.insert("min".to_string(), DataValue::Int(3));
+        data.insert("max".to_string(), DataValue::Float(2.5));
+        data.insert("street".to_string(), DataValue::String("长安街".to_string()));
+        data.insert("city".to_string(), DataValue::Null);
+
+        assert!(ValidationRule::compare("max", CompareOperator::Gte, "min").check(&data).is_err());
+        assert!(ValidationRule::compare("min", CompareOperator::Ne, "max").check(&data).is_ok());
+        // 缺失字段交由必填校验处理
+        assert!(ValidationRule::compare("min", CompareOperator::Lt, "missing").check(&data).is_ok());
+        assert!(ValidationRule::compare("street", CompareOperator::Gt, "min").check(&data).is_err());
+
+        assert!(ValidationRule::at_most_one_of(&["street", "city"]).check(&data).is_ok());
+        assert!(ValidationRule::at_least_one_of(&["city", "zip"]).check(&data).is_err());
+        let message = rejected_message(ValidationRule::required_with("street", &["city"]).check(&data));
+        assert!(message.contains("city"));
+
+        register_rule("test_min_positive", |data| match data.get("min") {
+            Some(DataValue::Int(min)) if *min > 0 => Ok(()),
+            _ => Err(QuickDbError::ValidationError {
+                field: "min".to_string(),
+                message: "min 必须为正数".to_string(),
+            }),
+        });
+        assert!(ValidationRule::custom("test_min_positive").check(&data).is_ok());
+        assert!(unregister_rule("test_min_positive"));
+        assert!(ValidationRule::custom("test_min_positive").check(&data).is_err());
+    }
+}
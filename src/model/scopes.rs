@@ -0,0 +1,236 @@
+//! 查询作用域模块
+//!
+//! 模型可以声明命名作用域（常用的过滤条件与排序）和默认作用域，使公共过滤条件只在
+//! 模型定义中维护一处：
+//!
+//! ```rust,ignore
+//! define_model! {
+//!     struct Article { ... }
+//!     collection = "articles",
+//!     default_scope = QueryScope::new().sort("created_at", SortDirection::Desc),
+//!     scopes = [
+//!         ("published", QueryScope::new().filter("status", QueryOperator::Eq, "published")),
+//!     ],
+//!     fields = { ... }
+//! }
+//!
+//! let options = QueryOptions::new().with_scope("published");
+//! let articles = ModelManager::<Article>::find(vec![], Some(options)).await?;
+//! ```
+//!
+//! 作用域由 ODM 层按注册的模型元数据展开，模型方法、`odm::find` 与 Python 桥接的查询
+//! 行为一致。默认作用域作用于所有条件查询，使用 [`QueryOptions::unscoped`] 跳过；
+//! 作用域的排序仅在查询未指定排序时生效。统计、按ID查询与批量更新、删除不受作用域影响
+
+use crate::error::{QuickDbError, QuickDbResult};
+use crate::types::{
+    DataValue, LogicalOperator, QueryConditionGroupWithConfig, QueryConditionWithConfig, QueryOperator,
+    QueryOptions, SortConfig, SortDirection,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// 查询作用域：一组过滤条件与默认排序
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct QueryScope {
+    /// 追加到查询中的条件（与查询条件以 AND 组合）
+    #[serde(default)]
+    pub conditions: Vec<QueryConditionWithConfig>,
+    /// 查询未指定排序时使用的排序
+    #[serde(default)]
+    pub sort: Vec<SortConfig>,
+}
+
+impl QueryScope {
+    /// 创建空的作用域
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 追加过滤条件
+    pub fn filter(mut self, field: impl Into<String>, operator: QueryOperator, value: impl Into<DataValue>) -> Self {
+        self.conditions.push(QueryConditionWithConfig {
+            field: field.into(),
+            operator,
+            value: value.into(),
+            case_insensitive: false,
+        });
+        self
+    }
+
+    /// 追加排序字段
+    pub fn sort(mut self, field: impl Into<String>, direction: SortDirection) -> Self {
+        self.sort.push(SortConfig {
+            field: field.into(),
+            direction,
+        });
+        self
+    }
+
+    /// 把作用域中的字段名改写为存储字段名
+    pub(crate) fn fields_mut(&mut self) -> impl Iterator<Item = &mut String> {
+        self.conditions
+            .iter_mut()
+            .map(|condition| &mut condition.field)
+            .chain(self.sort.iter_mut().map(|sort| &mut sort.field))
+    }
+}
+
+/// 模型声明的全部作用域
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct QueryScopes {
+    /// 默认作用域（除非查询声明 `unscoped`，否则作用于所有条件查询）
+    #[serde(default)]
+    pub default: Option<QueryScope>,
+    /// 命名作用域（通过 [`QueryOptions::with_scope`] 按名称启用）
+    #[serde(default)]
+    pub named: HashMap<String, QueryScope>,
+}
+
+impl QueryScopes {
+    /// 是否未声明任何作用域
+    pub fn is_empty(&self) -> bool {
+        self.default.is_none() && self.named.is_empty()
+    }
+
+    /// 按查询选项展开作用域，返回需要追加的条件，并在查询未指定排序时写入作用域的排序
+    ///
+    /// 命名作用域按启用顺序排在默认作用域之前，排序取第一个声明了排序的作用域。
+    /// 启用了未声明的命名作用域时返回验证错误
+    pub fn resolve(&self, options: &mut QueryOptions) -> QuickDbResult<Vec<QueryConditionWithConfig>> {
+        let mut scopes = Vec::with_capacity(options.scopes.len() + 1);
+        for name in &options.scopes {
+            let scope = self.named.get(name).ok_or_else(|| QuickDbError::ValidationError {
+                field: "scopes".to_string(),
+                message: crate::i18n::tf("model.scope_not_found", &[("scope", name)]),
+            })?;
+            scopes.push(scope);
+        }
+        if !options.unscoped {
+            scopes.extend(&self.default);
+        }
+
+        if options.sort.is_empty() && options.vector_sort.is_none() {
+            if let Some(scope) = scopes.iter().find(|scope| !scope.sort.is_empty()) {
+                options.sort = scope.sort.clone();
+            }
+        }
+        Ok(scopes.into_iter().flat_map(|scope| scope.conditions.clone()).collect())
+    }
+}
+
+/// 把条件以 AND 方式追加到条件组
+pub(crate) fn and_condition_groups(
+    groups: Vec<QueryConditionGroupWithConfig>,
+    conditions: Vec<QueryConditionWithConfig>,
+) -> Vec<QueryConditionGroupWithConfig> {
+    if conditions.is_empty() {
+        return groups;
+    }
+    let singles = conditions.into_iter().map(QueryConditionGroupWithConfig::Single);
+    if groups.is_empty() {
+        return singles.collect();
+    }
+
+    let mut conditions = groups;
+    conditions.extend(singles);
+    vec![QueryConditionGroupWithConfig::GroupWithConfig {
+        operator: LogicalOperator::And,
+        conditions,
+    }]
+}
+
+/// 展开已注册模型的作用域（未注册模型的集合只能使用空作用域）
+pub(crate) fn resolve_registered(
+    collection: &str,
+    alias: &str,
+    options: &mut QueryOptions,
+) -> QuickDbResult<Vec<QueryConditionWithConfig>> {
+    let manager = crate::manager::get_global_pool_manager();
+    let scopes = manager
+        .get_model_scopes(collection, alias)
+        .or_else(|| manager.get_model_scopes(collection, "default"))
+        .unwrap_or_default();
+    scopes.resolve(options)
+}
+
+#[cfg(all(test, feature = "sqlite-support"))]
+mod tests {
+    use super::*;
+    use crate::odm::AsyncOdmManager;
+    use crate::types::*;
+
+    #[tokio::test]
+    async fn default_and_named_scopes_filter_finds() {
+        crate::i18n::ErrorMessageI18n::init_i18n();
+        let alias = "scopes_suite";
+        let manager = crate::manager::get_global_pool_manager();
+        manager
+            .add_database(DatabaseConfig::sqlite_memory_for_test(alias))
+            .await
+            .unwrap();
+        manager
+            .register_model(crate::model::ModelMeta {
+                collection_name: "articles".to_string(),
+                database_alias: Some(alias.to_string()),
+                fields: HashMap::from([
+                    ("id".to_string(), crate::model::integer_field(None, None)),
+                    ("title".to_string(), crate::model::string_field(Some(32), None, None)),
+                    ("status".to_string(), crate::model::string_field(Some(16), None, None)),
+                    ("deleted".to_string(), crate::model::boolean_field()),
+                ]),
+                scopes: QueryScopes {
+                    default: Some(
+                        QueryScope::new()
+                            .filter("deleted", QueryOperator::Eq, false)
+                            .sort("title", SortDirection::Desc),
+                    ),
+                    named: HashMap::from([(
+                        "published".to_string(),
+                        QueryScope::new().filter("status", QueryOperator::Eq, "published"),
+                    )]),
+                },
+                ..Default::default()
+            })
+            .unwrap();
+        let pool = manager.get_connection_pools().get(alias).unwrap().clone();
+        for (title, status, deleted) in [("a", "draft", false), ("b", "published", false), ("c", "published", true)] {
+            let data = HashMap::from([
+                ("title".to_string(), DataValue::String(title.to_string())),
+                ("status".to_string(), DataValue::String(status.to_string())),
+                ("deleted".to_string(), DataValue::Bool(deleted)),
+            ]);
+            pool.create("articles", &data, &IdStrategy::AutoIncrement).await.unwrap();
+        }
+
+        let titles = |options: QueryOptions| async move {
+            let records = AsyncOdmManager::handle_find(
+                "articles",
+                vec![],
+                Some(options),
+                Some(alias.to_string()),
+            )
+            .await?;
+            Ok::<_, QuickDbError>(
+                records
+                    .iter()
+                    .map(|record| match record {
+                        DataValue::Object(map) => map["title"].to_string(),
+                        other => other.to_string(),
+                    })
+                    .collect::<Vec<_>>(),
+            )
+        };
+        assert_eq!(titles(QueryOptions::new()).await.unwrap(), ["b", "a"]);
+        assert_eq!(titles(QueryOptions::new().with_scope("published")).await.unwrap(), ["b"]);
+        let unscoped = QueryOptions::new().unscoped().with_sort(vec![SortConfig {
+            field: "title".to_string(),
+            direction: SortDirection::Asc,
+        }]);
+        assert_eq!(titles(unscoped).await.unwrap(), ["a", "b", "c"]);
+        assert!(matches!(
+            titles(QueryOptions::new().with_scope("archived")).await,
+            Err(QuickDbError::ValidationError { .. })
+        ));
+    }
+}
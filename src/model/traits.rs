@@ -3,16 +3,13 @@
 //! 定义模型的核心接口和操作特征
 
 use crate::error::{QuickDbError, QuickDbResult};
-use crate::model::conversion::ToDataValue;
-use crate::model::field_types::{FieldDefinition, FieldType, ModelMeta};
+use crate::model::field_types::ModelMeta;
 use crate::types::*;
 use async_trait::async_trait;
-use base64;
-use rat_logger::{debug, error, info, warn};
+use rat_logger::{debug, warn};
 use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 use std::collections::HashMap;
-use std::marker::PhantomData;
 
 /// 模型特征
 ///
@@ -21,9 +18,9 @@ pub trait Model: Serialize + for<'de> Deserialize<'de> + Send + Sync {
     /// 获取模型元数据
     fn meta() -> ModelMeta;
 
-    /// 获取集合/表名
+    /// 获取集合/表名（声明了模式时为 `模式.表名`）
     fn collection_name() -> String {
-        Self::meta().collection_name
+        Self::meta().qualified_name()
     }
 
     /// 获取数据库别名
@@ -32,6 +29,11 @@ pub trait Model: Serialize + for<'de> Deserialize<'de> + Send + Sync {
     }
 
     /// 验证模型数据
+    ///
+    /// # ⚠️ 注意
+    /// 此方法只检查字段类型约束与模型级跨字段规则，**不执行**通过 `validator(...)`
+    /// 注册的异步自定义验证器（它们需要 await，无法在同步方法中运行）。
+    /// 需要完整校验时使用 [`Model::validate_async`]；经由 ODM 创建、更新记录时会自动执行验证器
     fn validate(&self) -> QuickDbResult<()> {
         let meta = Self::meta();
         let data = self.to_data_map()?;
@@ -40,14 +42,32 @@ pub trait Model: Serialize + for<'de> Deserialize<'de> + Send + Sync {
         debug!("🔍 验证数据映射: {:?}", data);
 
         for (field_name, field_def) in &meta.fields {
+            // 计算字段不写入数据库，无需校验
+            if field_def.computed {
+                continue;
+            }
             let field_value = data.get(field_name).unwrap_or(&DataValue::Null);
             debug!("🔍 验证字段 {}: {:?}", field_name, field_value);
             field_def.validate_with_field_name(field_value, field_name)?;
         }
 
+        // 模型级跨字段校验规则
+        meta.validate_rules(&data)?;
+
         Ok(())
     }
 
+    /// 验证模型数据，并执行字段声明的异步自定义验证器
+    ///
+    /// 通过 ODM 创建、更新记录时会自动执行验证器，此方法用于保存前提前校验
+    fn validate_async(&self) -> impl std::future::Future<Output = QuickDbResult<()>> + Send {
+        async move {
+            self.validate()?;
+            let data = self.to_data_map()?;
+            crate::model::validators::validate_data(&Self::meta(), &data).await
+        }
+    }
+
     /// 转换为数据映射（直接转换，避免 JSON 序列化开销）
     /// 子类应该重写此方法以提供高性能的直接转换
     fn to_data_map_direct(&self) -> QuickDbResult<HashMap<String, DataValue>> {
@@ -89,7 +109,7 @@ pub trait Model: Serialize + for<'de> Deserialize<'de> + Send + Sync {
     }
 
     /// 将模型转换为带类型信息的数据映射（专门用于 PyO3 兼容序列化）
-    /// 对于 None 值，会根据字段类型生成带类型标签的 DataValue
+    /// 每个字段为 [`crate::serializer::wire`] 带标签模式编码的 JSON，None 值按字段类型标注
     fn to_data_map_with_types(&self) -> QuickDbResult<HashMap<String, DataValue>> {
         let json_map = self.to_data_map_with_types_json()?;
         // 将 HashMap<String, JsonValue> 转换为 HashMap<String, DataValue>
@@ -101,218 +121,27 @@ pub trait Model: Serialize + for<'de> Deserialize<'de> + Send + Sync {
     }
 
     /// 将模型转换为带类型信息的 JSON 映射（专门用于 PyO3 兼容序列化）
-    /// 对于 None 值，会根据字段类型生成带类型标签的 JsonValue
-    /// 这个方法直接返回 JsonValue，避免 DataValue 的额外嵌套
+    ///
+    /// 使用 [`crate::serializer::wire`] 的带标签模式：`{"$t": 类型, "$v": 值}`，
+    /// 对于 None 值生成 `{"$t": 字段类型, "$v": null}`
     fn to_data_map_with_types_json(&self) -> QuickDbResult<HashMap<String, JsonValue>> {
+        use crate::serializer::wire::{WireMode, encode_field};
+
         let meta = Self::meta();
         let mut data_map = HashMap::new();
-
-        // 遍历模型的所有字段
-        let json_str =
-            serde_json::to_string(self).map_err(|e| QuickDbError::SerializationError {
-                message: crate::i18n::tf("serializer.serialize_failed", &[("message", &e.to_string())]),
-            })?;
-
-        debug!("🔍 to_data_map_with_types_json 序列化的JSON: {}", json_str);
-
-        let json_value: JsonValue =
-            serde_json::from_str(&json_str).map_err(|e| QuickDbError::SerializationError {
-                message: crate::i18n::tf("model.parse_json_failed", &[("message", &e.to_string())]),
-            })?;
-
-        debug!(
-            "🔍 to_data_map_with_types_json 解析后的JSON: {:?}",
-            json_value
-        );
-
-        if let JsonValue::Object(obj) = json_value {
-            for (key, value) in obj {
-                // 检查字段是否在元数据中定义
-                if let Some(field_def) = meta.fields.get(&key) {
-                    // 对所有字段都生成带类型标签的 JsonValue
-                    let type_name = match &field_def.field_type {
-                        FieldType::String { .. } => "String",
-                        FieldType::Integer { .. } => "Int",
-                        FieldType::Float { .. } => "Float",
-                        FieldType::BigInteger => "Int",
-                        FieldType::Double => "Float",
-                        FieldType::Text => "String",
-                        FieldType::Boolean => "Bool",
-                        FieldType::DateTime => "DateTime",
-                        FieldType::DateTimeWithTz { .. } => "DateTime", // 带时区的DateTime
-                        FieldType::Date => "DateTime",
-                        FieldType::Time => "DateTime",
-                        FieldType::Uuid => "Uuid",
-                        FieldType::Json => "Json",
-                        FieldType::Binary => "Bytes",
-                        FieldType::Decimal { .. } => "Float",
-                        FieldType::Array { .. } => "Array",
-                        FieldType::Object { .. } => "Object",
-                        FieldType::Reference { .. } => "String",
-                        FieldType::Vector { .. } => "Vector",
-                    };
-
-                    // 直接创建带类型标签的 JsonValue，避免嵌套
-                    let typed_json = match value {
-                        JsonValue::Null => {
-                            // 对于 null 值，创建 {类型名: null}
-                            let mut type_obj = serde_json::Map::new();
-                            type_obj.insert(type_name.to_string(), JsonValue::Null);
-                            JsonValue::Object(type_obj)
-                        }
-                        JsonValue::String(s) => {
-                            // 对于字符串值，创建 {类型名: "value"}
-                            let mut type_obj = serde_json::Map::new();
-                            type_obj.insert(type_name.to_string(), JsonValue::String(s));
-                            JsonValue::Object(type_obj)
-                        }
-                        JsonValue::Number(n) => {
-                            // 对于数字值，根据类型包装
-                            let mut type_obj = serde_json::Map::new();
-                            type_obj.insert(type_name.to_string(), JsonValue::Number(n));
-                            JsonValue::Object(type_obj)
-                        }
-                        JsonValue::Bool(b) => {
-                            // 对于布尔值，创建 {类型名: boolean}
-                            let mut type_obj = serde_json::Map::new();
-                            type_obj.insert(type_name.to_string(), JsonValue::Bool(b));
-                            JsonValue::Object(type_obj)
-                        }
-                        JsonValue::Array(arr) => {
-                            // 对于数组，需要根据字段类型为每个元素添加类型标记
-                            if let FieldType::Array { item_type, .. } = &field_def.field_type {
-                                let item_type_name = match &**item_type {
-                                    FieldType::String { .. } => "String",
-                                    FieldType::Integer { .. } => "Int",
-                                    FieldType::Float { .. } => "Float",
-                                    FieldType::BigInteger => "Int",
-                                    FieldType::Double => "Float",
-                                    FieldType::Text => "String",
-                                    FieldType::Boolean => "Bool",
-                                    FieldType::DateTime => "DateTime",
-                                    FieldType::DateTimeWithTz { .. } => "DateTime", // 带时区的DateTime
-                                    FieldType::Date => "DateTime",
-                                    FieldType::Time => "DateTime",
-                                    FieldType::Uuid => "Uuid",
-                                    FieldType::Json => "Json",
-                                    FieldType::Binary => "Bytes",
-                                    FieldType::Decimal { .. } => "Float",
-                                    FieldType::Array { .. } => "Array",
-                                    FieldType::Object { .. } => "Object",
-                                    FieldType::Reference { .. } => "String",
-                                    FieldType::Vector { .. } => "Vector",
-                        FieldType::Vector { .. } => "Vector",
-                                };
-
-                                let processed_array: Vec<JsonValue> = arr
-                                    .into_iter()
-                                    .map(|v| {
-                                        // 为每个数组元素添加类型标记
-                                        let mut item_type_obj = serde_json::Map::new();
-                                        match v {
-                                            JsonValue::String(s) => {
-                                                item_type_obj.insert(
-                                                    item_type_name.to_string(),
-                                                    JsonValue::String(s),
-                                                );
-                                            }
-                                            JsonValue::Number(n) => {
-                                                item_type_obj.insert(
-                                                    item_type_name.to_string(),
-                                                    JsonValue::Number(n),
-                                                );
-                                            }
-                                            JsonValue::Bool(b) => {
-                                                item_type_obj.insert(
-                                                    item_type_name.to_string(),
-                                                    JsonValue::Bool(b),
-                                                );
-                                            }
-                                            JsonValue::Null => {
-                                                item_type_obj.insert(
-                                                    item_type_name.to_string(),
-                                                    JsonValue::Null,
-                                                );
-                                            }
-                                            JsonValue::Array(nested_arr) => {
-                                                // 嵌套数组暂时保持原样，实际使用中应该递归处理
-                                                item_type_obj.insert(
-                                                    item_type_name.to_string(),
-                                                    JsonValue::Array(nested_arr),
-                                                );
-                                            }
-                                            JsonValue::Object(nested_obj) => {
-                                                // 嵌套对象暂时保持原样，实际使用中应该递归处理
-                                                item_type_obj.insert(
-                                                    item_type_name.to_string(),
-                                                    JsonValue::Object(nested_obj),
-                                                );
-                                            }
-                                        }
-                                        JsonValue::Object(item_type_obj)
-                                    })
-                                    .collect();
-                                let mut type_obj = serde_json::Map::new();
-                                type_obj.insert(
-                                    type_name.to_string(),
-                                    JsonValue::Array(processed_array),
-                                );
-                                JsonValue::Object(type_obj)
-                            } else {
-                                // 如果不是数组类型，保持原有逻辑
-                                let processed_array: Vec<JsonValue> = arr
-                                    .into_iter()
-                                    .map(|v| match v {
-                                        JsonValue::String(s) => JsonValue::String(s),
-                                        JsonValue::Number(n) => JsonValue::Number(n),
-                                        JsonValue::Bool(b) => JsonValue::Bool(b),
-                                        JsonValue::Null => JsonValue::Null,
-                                        JsonValue::Array(_) => v,
-                                        JsonValue::Object(_) => v,
-                                    })
-                                    .collect();
-                                let mut type_obj = serde_json::Map::new();
-                                type_obj.insert(
-                                    type_name.to_string(),
-                                    JsonValue::Array(processed_array),
-                                );
-                                JsonValue::Object(type_obj)
-                            }
-                        }
-                        JsonValue::Object(obj) => {
-                            // 对于对象，递归处理每个字段，然后包装类型
-                            let processed_obj: serde_json::Map<String, JsonValue> = obj
-                                .into_iter()
-                                .map(|(k, v)| {
-                                    let processed_value = match v {
-                                        JsonValue::String(s) => JsonValue::String(s),
-                                        JsonValue::Number(n) => JsonValue::Number(n),
-                                        JsonValue::Bool(b) => JsonValue::Bool(b),
-                                        JsonValue::Null => JsonValue::Null,
-                                        JsonValue::Array(_) => v,
-                                        JsonValue::Object(_) => v,
-                                    };
-                                    (k, processed_value)
-                                })
-                                .collect();
-                            let mut type_obj = serde_json::Map::new();
-                            type_obj
-                                .insert(type_name.to_string(), JsonValue::Object(processed_obj));
-                            JsonValue::Object(type_obj)
-                        }
-                    };
-
-                    data_map.insert(key, typed_json);
-                } else {
-                    // 字段不在元数据中 - 这在 v0.3.0 中不应该发生，报错退出
-                    return Err(QuickDbError::ValidationError {
-                        field: key.clone(),
-                        message: crate::i18n::tf("model.field_not_in_metadata", &[("field", &key)]),
-                    });
-                }
-            }
+        for (key, value) in self.to_data_map()? {
+            // 字段不在元数据中 - 这在 v0.3.0 中不应该发生，报错退出
+            let Some(field_def) = meta.fields.get(&key) else {
+                return Err(QuickDbError::ValidationError {
+                    field: key.clone(),
+                    message: crate::i18n::tf("model.field_not_in_metadata", &[("field", &key)]),
+                });
+            };
+            let typed_json = encode_field(&value, &field_def.field_type, WireMode::Tagged);
+            data_map.insert(key, typed_json);
         }
 
+        debug!("🔍 to_data_map_with_types_json 编码后的字段数: {}", data_map.len());
         Ok(data_map)
     }
 
@@ -320,10 +149,24 @@ pub trait Model: Serialize + for<'de> Deserialize<'de> + Send + Sync {
     fn from_data_map(data: HashMap<String, DataValue>) -> QuickDbResult<Self> {
         // 使用模型元数据后处理数据字段，修复复杂类型字段反序列化问题
         let meta = Self::meta();
-        let processed_data = crate::process_data_fields_from_metadata(data, &meta.fields);
+        let mut processed_data = crate::process_data_fields_from_metadata(data, &meta.fields);
+        meta.fill_computed_placeholders(&mut processed_data);
 
-        // 直接从HashMap<String, DataValue>转换为模型实例，避免JSON中转
-        crate::model::data_conversion::create_model_from_data_map::<Self>(&processed_data)
+        let mut model = Self::from_data_map_direct(processed_data)?;
+        model.compute_fields();
+        Ok(model)
+    }
+
+    /// 填充计算字段，模型从数据映射加载后自动调用
+    ///
+    /// `define_model!` 通过 `compute_fields = 函数名,` 指定填充函数
+    fn compute_fields(&mut self) {}
+
+    /// 从数据映射直接解码模型实例（不经过 serde）
+    /// 子类应该重写此方法以提供高性能的直接解码，`define_model!` 宏会自动重写
+    fn from_data_map_direct(data: HashMap<String, DataValue>) -> QuickDbResult<Self> {
+        // 默认回退到 serde 反序列化器，从HashMap<String, DataValue>转换为模型实例
+        crate::model::data_conversion::create_model_from_data_map::<Self>(&data)
     }
 }
 
@@ -356,8 +199,17 @@ pub trait ModelOperations<T: Model> {
     async fn save(&self) -> QuickDbResult<String>;
 
     /// 根据ID查找记录
+    ///
+    /// 声明了单字段自定义主键的模型按该主键字段查询（与 `find_by_key` 同一路径），
+    /// 复合主键的模型返回验证错误，请使用 `find_by_key`
     async fn find_by_id(id: &str) -> QuickDbResult<Option<T>>;
 
+    /// 根据主键值映射查找记录（支持复合主键）
+    ///
+    /// `key` 必须恰好包含模型 `primary_key` 声明的全部字段，
+    /// 未声明 `primary_key` 的模型使用 `{"id": ...}`
+    async fn find_by_key(key: HashMap<String, DataValue>) -> QuickDbResult<Option<T>>;
+
     /// 查找多条记录（简化版）
     async fn find(
         conditions: Vec<QueryCondition>,
@@ -415,9 +267,22 @@ pub trait ModelOperations<T: Model> {
     /// 返回记录的ID字符串
     async fn upsert(&self, conflict_columns: Vec<String>) -> QuickDbResult<String>;
 
+    /// 根据主键值映射更新记录（支持复合主键）
+    ///
+    /// 返回是否有记录被更新
+    async fn update_by_key(
+        key: HashMap<String, DataValue>,
+        updates: HashMap<String, DataValue>,
+    ) -> QuickDbResult<bool>;
+
     /// 删除记录
     async fn delete(&self) -> QuickDbResult<bool>;
 
+    /// 根据主键值映射删除记录（支持复合主键）
+    ///
+    /// 返回是否有记录被删除
+    async fn delete_by_key(key: HashMap<String, DataValue>) -> QuickDbResult<bool>;
+
     /// 统计记录数量（简化版）
     async fn count(conditions: Vec<QueryCondition>) -> QuickDbResult<u64> {
         let conditions_with_config: Vec<QueryConditionWithConfig> = conditions
@@ -534,4 +399,10 @@ pub trait ModelOperations<T: Model> {
         procedure_name: &str,
         params: Option<std::collections::HashMap<String, crate::types::DataValue>>,
     ) -> QuickDbResult<crate::stored_procedure::StoredProcedureQueryResult>;
+
+    /// 执行存储过程并返回带类型的结果（多结果集与输出参数）
+    async fn execute_stored_procedure_typed(
+        procedure_name: &str,
+        params: Option<std::collections::HashMap<String, crate::types::DataValue>>,
+    ) -> QuickDbResult<crate::stored_procedure::StoredProcedureOutput>;
 }
@@ -0,0 +1,202 @@
+//! 自定义验证器注册表模块
+//!
+//! `FieldDefinition::validator` 记录验证器名称，实际的验证逻辑通过 [`register`]
+//! 按名称注册为异步函数。ODM 在创建、更新（包括操作数组更新中的 `Set` 操作）记录前
+//! 对注册了模型元数据的集合执行字段声明的验证器，`Model::validate_async` 可在保存前
+//! 手动执行同样的校验。同步的 `Model::validate` 不执行这些验证器
+
+use crate::error::{QuickDbError, QuickDbResult};
+use crate::model::field_types::ModelMeta;
+use crate::types::DataValue;
+use dashmap::DashMap;
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+/// 验证器返回的 Future
+pub type ValidatorFuture = Pin<Box<dyn Future<Output = QuickDbResult<()>> + Send>>;
+
+/// 已注册的验证器（值按所有权传入，便于在 Future 中跨 await 使用）
+pub type Validator = Arc<dyn Fn(DataValue) -> ValidatorFuture + Send + Sync>;
+
+/// 全局验证器注册表 (名称 -> 验证器)
+static VALIDATORS: Lazy<DashMap<String, Validator>> = Lazy::new(DashMap::new);
+
+/// 注册异步验证器，同名验证器会被替换
+///
+/// ```rust,ignore
+/// validators::register("unique_email", |value| async move {
+///     // 查询数据库或调用外部服务
+///     Ok(())
+/// });
+/// ```
+pub fn register<F, Fut>(name: &str, validator: F)
+where
+    F: Fn(DataValue) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = QuickDbResult<()>> + Send + 'static,
+{
+    let validator: Validator = Arc::new(move |value| Box::pin(validator(value)));
+    VALIDATORS.insert(name.to_string(), validator);
+}
+
+/// 移除已注册的验证器
+pub fn unregister(name: &str) -> bool {
+    VALIDATORS.remove(name).is_some()
+}
+
+/// 检查验证器是否已注册
+pub fn is_registered(name: &str) -> bool {
+    VALIDATORS.contains_key(name)
+}
+
+/// 使用指定名称的验证器校验字段值
+pub async fn run(name: &str, field: &str, value: DataValue) -> QuickDbResult<()> {
+    // 先克隆出验证器，避免跨 await 持有注册表的锁
+    let validator = VALIDATORS
+        .get(name)
+        .map(|entry| entry.value().clone())
+        .ok_or_else(|| QuickDbError::ValidationError {
+            field: field.to_string(),
+            message: crate::i18n::tf("validation.validator_not_registered", &[("name", name)]),
+        })?;
+    validator(value).await
+}
+
+/// 对数据执行模型字段声明的验证器
+///
+/// 只校验数据中出现的非空字段（空值交由必填校验处理），因此同样适用于部分更新
+pub async fn validate_data(meta: &ModelMeta, data: &HashMap<String, DataValue>) -> QuickDbResult<()> {
+    for (field_name, field_def) in &meta.fields {
+        let Some(name) = &field_def.validator else {
+            continue;
+        };
+        let value = match data.get(field_name) {
+            Some(DataValue::Null) | None => continue,
+            Some(value) => value.clone(),
+        };
+        run(name, field_name, value).await?;
+    }
+    Ok(())
+}
+
+/// 对已注册模型元数据的集合执行字段验证器（未注册模型的集合直接通过）
+pub(crate) async fn validate_registered(
+    collection: &str,
+    alias: &str,
+    data: &HashMap<String, DataValue>,
+) -> QuickDbResult<()> {
+    let manager = crate::manager::get_global_pool_manager();
+    let meta = manager
+        .get_model_with_alias(collection, alias)
+        .or_else(|| manager.get_model_with_alias(collection, "default"));
+    match meta {
+        Some(meta) => validate_data(&meta, data).await,
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::convenience::string_field;
+
+    fn meta_with_validator(validator: &str) -> ModelMeta {
+        let mut fields = HashMap::new();
+        fields.insert(
+            "email".to_string(),
+            string_field(None, None, None).validator(validator),
+        );
+        fields.insert("name".to_string(), string_field(None, None, None));
+        ModelMeta {
+            collection_name: "validator_users".to_string(),
+            fields,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn runs_registered_async_validators() {
+        register("test_email_domain", |value| async move {
+            tokio::task::yield_now().await;
+            match value {
+                DataValue::String(email) if email.ends_with("@example.com") => Ok(()),
+                _ => Err(QuickDbError::ValidationError {
+                    field: "email".to_string(),
+                    message: "邮箱域名不合法".to_string(),
+                }),
+            }
+        });
+        let meta = meta_with_validator("test_email_domain");
+
+        let mut data = HashMap::new();
+        data.insert(
+            "email".to_string(),
+            DataValue::String("a@example.com".to_string()),
+        );
+        assert!(validate_data(&meta, &data).await.is_ok());
+
+        data.insert("email".to_string(), DataValue::String("a@other.com".to_string()));
+        assert!(validate_data(&meta, &data).await.is_err());
+
+        // 部分更新未涉及验证字段时不执行验证器
+        let mut updates = HashMap::new();
+        updates.insert("name".to_string(), DataValue::String("张三".to_string()));
+        assert!(validate_data(&meta, &updates).await.is_ok());
+
+        assert!(unregister("test_email_domain"));
+        assert!(!is_registered("test_email_domain"));
+    }
+
+    #[tokio::test]
+    async fn runs_validators_for_set_operations() {
+        crate::i18n::ErrorMessageI18n::init_i18n();
+        register("test_ops_email", |value| async move {
+            match value {
+                DataValue::String(email) if email.contains('@') => Ok(()),
+                _ => Err(QuickDbError::ValidationError {
+                    field: "email".to_string(),
+                    message: "邮箱格式不合法".to_string(),
+                }),
+            }
+        });
+        let meta = ModelMeta {
+            database_alias: Some("validator_ops_suite".to_string()),
+            ..meta_with_validator("test_ops_email")
+        };
+        crate::manager::get_global_pool_manager().register_model(meta).unwrap();
+
+        // 验证在查找连接池之前执行，未配置数据库的别名也能检验验证器是否生效
+        let update = |operations| {
+            crate::odm::AsyncOdmManager::handle_update_with_operations(
+                "validator_users",
+                vec![],
+                operations,
+                crate::types::UpdateOptions::default(),
+                Some("validator_ops_suite".to_string()),
+            )
+        };
+        let rejected = update(vec![crate::types::UpdateOperation::set("email", "invalid")]).await;
+        assert!(matches!(rejected, Err(QuickDbError::ValidationError { .. })));
+        let accepted = update(vec![crate::types::UpdateOperation::set("email", "a@b.c")]).await;
+        assert!(matches!(accepted, Err(QuickDbError::AliasNotFound { .. })));
+        unregister("test_ops_email");
+    }
+
+    #[tokio::test]
+    async fn reports_unregistered_validator() {
+        crate::i18n::ErrorMessageI18n::init_i18n();
+        let meta = meta_with_validator("test_missing_validator");
+        let mut data = HashMap::new();
+        data.insert("email".to_string(), DataValue::String("a@b.c".to_string()));
+
+        match validate_data(&meta, &data).await {
+            Err(QuickDbError::ValidationError { field, message }) => {
+                assert_eq!(field, "email");
+                assert!(message.contains("test_missing_validator"));
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,205 @@
+//! # 批量混合写入
+//!
+//! [`bulk_write`] 把插入、更新、删除与 Upsert 一次提交给数据库，并返回每个操作的结果：
+//!
+//! ```ignore
+//! let ops = vec![
+//!     BulkOp::Insert(HashMap::from([("sku".to_string(), DataValue::String("A-1".into()))])),
+//!     BulkOp::Update { conditions: vec![sku_eq("B-2")], data: HashMap::from([("stock".to_string(), DataValue::Int(0))]) },
+//!     BulkOp::Delete(vec![sku_eq("C-3")]),
+//! ];
+//! let report = odm::bulk_write("products", ops, BulkWriteMode::Unordered, None).await?;
+//! for (index, message) in report.failures() {
+//!     warn!("第 {} 个操作失败: {}", index, message);
+//! }
+//! ```
+//!
+//! - SQL 数据库：在一个事务中执行，每个操作一个保存点，失败的操作只回滚自身，其余操作一起提交
+//! - MongoDB：使用 `bulkWrite` 命令（需要 MongoDB 8.0+）
+//!
+//! 顺序模式（[`BulkWriteMode::Ordered`]）遇到第一个失败后停止，之后的操作记为跳过；
+//! 无序模式执行全部操作。插入按别名的ID策略生成ID，插入与更新数据执行模型声明的校验器，
+//! 校验失败的操作不提交给数据库，按执行失败处理。演练模式下不执行任何操作，所有操作记为跳过。
+//! 分片集合需要显式指定别名
+
+use crate::error::{QuickDbError, QuickDbResult};
+use crate::manager::get_global_pool_manager;
+use crate::odm::{AsyncOdmManager, is_dry_run};
+use crate::sharding::{self, ShardRoute};
+use crate::types::*;
+
+/// 批量执行混合写操作
+pub async fn bulk_write(
+    collection: &str,
+    ops: Vec<BulkOp>,
+    mode: BulkWriteMode,
+    alias: Option<&str>,
+) -> QuickDbResult<BulkWriteReport> {
+    crate::lock_global_operations();
+
+    if is_dry_run() {
+        return Ok(BulkWriteReport::skipped(ops.len()));
+    }
+
+    let alias = match sharding::route_all(collection, alias)? {
+        ShardRoute::Alias(Some(alias)) => alias,
+        ShardRoute::Alias(None) => get_global_pool_manager()
+            .get_default_alias()
+            .await
+            .unwrap_or_else(|| "default".to_string()),
+        ShardRoute::FanOut(_) => {
+            return Err(QuickDbError::ValidationError {
+                field: "alias".to_string(),
+                message: crate::i18n::tf("bulk.sharded_alias_required", &[("collection", collection)]),
+            });
+        }
+    };
+    bulk_write_on(collection, ops, mode, &alias).await
+}
+
+/// 在指定别名上执行批量写入
+async fn bulk_write_on(
+    collection: &str,
+    ops: Vec<BulkOp>,
+    mode: BulkWriteMode,
+    alias: &str,
+) -> QuickDbResult<BulkWriteReport> {
+    let manager = get_global_pool_manager();
+    let pool = manager
+        .get_connection_pools()
+        .get(alias)
+        .map(|pool| pool.clone())
+        .ok_or_else(|| QuickDbError::AliasNotFound {
+            alias: alias.to_string(),
+        })?;
+    if let Err(e) = manager.ensure_table_and_indexes(collection, alias).await {
+        rat_logger::debug!("自动创建表和索引失败: {}", e);
+    }
+
+    // 校验失败的操作不提交，记录其在原始列表中的位置
+    let mut report = BulkWriteReport::skipped(ops.len());
+    let mut submitted = Vec::with_capacity(ops.len());
+    let mut prepared = Vec::with_capacity(ops.len());
+    for (index, op) in ops.into_iter().enumerate() {
+        match prepare(collection, alias, &pool.db_config.db_type, op).await {
+            Ok(op) => {
+                submitted.push(index);
+                prepared.push(op);
+            }
+            Err(e) => {
+                report.outcomes[index] = BulkOpOutcome::Failed { message: e.to_string() };
+                if mode == BulkWriteMode::Ordered {
+                    break;
+                }
+            }
+        }
+    }
+    if prepared.is_empty() {
+        return Ok(report);
+    }
+
+    let executed = pool.bulk_write(collection, prepared, mode).await?;
+    for (index, outcome) in submitted.into_iter().zip(executed.outcomes) {
+        report.outcomes[index] = outcome;
+    }
+    Ok(report)
+}
+
+/// 执行模型校验器，并为插入的数据分配ID
+async fn prepare(collection: &str, alias: &str, db_type: &DatabaseType, op: BulkOp) -> QuickDbResult<BulkOp> {
+    match op {
+        BulkOp::Insert(mut data) => {
+            crate::model::validators::validate_registered(collection, alias, &data).await?;
+            AsyncOdmManager::assign_create_id(alias, db_type, &mut data).await?;
+            Ok(BulkOp::Insert(data))
+        }
+        BulkOp::Update { conditions, data } => {
+            crate::model::validators::validate_registered(collection, alias, &data).await?;
+            Ok(BulkOp::Update { conditions, data })
+        }
+        BulkOp::Upsert { data, conflict_columns } => {
+            crate::model::validators::validate_registered(collection, alias, &data).await?;
+            Ok(BulkOp::Upsert { data, conflict_columns })
+        }
+        delete => Ok(delete),
+    }
+}
+
+#[cfg(all(test, feature = "sqlite-support"))]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[tokio::test]
+    async fn ordered_stops_and_unordered_continues_after_failure() {
+        crate::i18n::ErrorMessageI18n::init_i18n();
+        let alias = "bulk_suite";
+        let manager = get_global_pool_manager();
+        manager
+            .add_database(DatabaseConfig::sqlite_memory_for_test(alias))
+            .await
+            .unwrap();
+        manager
+            .register_model(crate::model::ModelMeta {
+                collection_name: "products".to_string(),
+                database_alias: Some(alias.to_string()),
+                fields: HashMap::from([
+                    ("id".to_string(), crate::model::integer_field(None, None)),
+                    ("sku".to_string(), crate::model::string_field(Some(16), None, None)),
+                    ("stock".to_string(), crate::model::integer_field(None, None)),
+                ]),
+                indexes: vec![crate::model::IndexDefinition {
+                    fields: vec!["sku".to_string()],
+                    unique: true,
+                    name: None,
+                    options: Default::default(),
+                }],
+                ..Default::default()
+            })
+            .unwrap();
+
+        let product = |sku: &str, stock: i64| {
+            HashMap::from([
+                ("sku".to_string(), DataValue::String(sku.to_string())),
+                ("stock".to_string(), DataValue::Int(stock)),
+            ])
+        };
+        let sku_eq = |sku: &str| QueryConditionWithConfig {
+            field: "sku".to_string(),
+            operator: QueryOperator::Eq,
+            value: DataValue::String(sku.to_string()),
+            case_insensitive: false,
+        };
+        let ops = |first: &str| {
+            vec![
+                BulkOp::Insert(product(first, 1)),
+                BulkOp::Insert(product("dup", 1)),
+                BulkOp::Upsert {
+                    data: product("dup", 5),
+                    conflict_columns: vec!["sku".to_string()],
+                },
+                BulkOp::Delete(vec![sku_eq(first)]),
+            ]
+        };
+
+        let first = bulk_write_on("products", ops("a"), BulkWriteMode::Ordered, alias).await.unwrap();
+        assert!(first.is_success(), "{:?}", first);
+        assert_eq!(first.affected_rows(), 4);
+
+        // 第二个插入违反唯一约束：顺序模式停止，无序模式继续执行后续操作
+        let ordered = bulk_write_on("products", ops("b"), BulkWriteMode::Ordered, alias).await.unwrap();
+        assert!(matches!(ordered.outcomes[1], BulkOpOutcome::Failed { .. }));
+        assert_eq!(ordered.outcomes[2..], [BulkOpOutcome::Skipped, BulkOpOutcome::Skipped]);
+        let unordered = bulk_write_on("products", ops("c"), BulkWriteMode::Unordered, alias).await.unwrap();
+        assert_eq!(unordered.failures().map(|(index, _)| index).collect::<Vec<_>>(), [1]);
+        assert_eq!(unordered.outcomes[3], BulkOpOutcome::Applied { affected: 1 });
+
+        let pool = manager.get_connection_pools().get(alias).unwrap().clone();
+        let count = |sku: &str| {
+            let (pool, condition) = (pool.clone(), sku_eq(sku));
+            async move { pool.count("products", &[condition], alias).await.unwrap() }
+        };
+        // 顺序模式中失败前的插入已提交，无序模式插入后又被删除
+        assert_eq!((count("b").await, count("c").await, count("dup").await), (1, 0, 1));
+    }
+}
@@ -0,0 +1,143 @@
+//! # 条件更新（比较并设置）
+//!
+//! [`update_if`] 只在记录的字段当前值与期望值一致时执行更新，判断与写入在同一条
+//! `UPDATE ... WHERE id = ? AND 字段 = 期望值` 中完成，无需加锁即可实现状态机迁移：
+//!
+//! ```ignore
+//! let expected = HashMap::from([("status".to_string(), DataValue::String("pending".into()))]);
+//! let updates = HashMap::from([("status".to_string(), DataValue::String("paid".into()))]);
+//! match odm::update_if("orders", &order_id, expected, updates, None).await {
+//!     Ok(()) => {}
+//!     Err(QuickDbError::PreconditionFailed { .. }) => { /* 已被其他请求迁移 */ }
+//!     Err(e) => return Err(e),
+//! }
+//! ```
+//!
+//! 期望值为 `DataValue::Null` 时按 `IS NULL` 匹配。没有记录被更新时再按 ID 查询一次，
+//! 记录不存在返回 `NotFound`，存在则返回 `PreconditionFailed`
+
+use crate::error::{QuickDbError, QuickDbResult};
+use crate::odm::{AsyncOdmManager, OdmOperations, get_odm_manager};
+use crate::sharding::{self, ShardRoute};
+use crate::types::*;
+use std::collections::HashMap;
+
+/// 字段当前值与期望值一致时更新记录
+pub async fn update_if(
+    collection: &str,
+    id: &str,
+    expectations: HashMap<String, DataValue>,
+    updates: HashMap<String, DataValue>,
+    alias: Option<&str>,
+) -> QuickDbResult<()> {
+    crate::lock_global_operations();
+
+    let manager = get_odm_manager().await;
+    update_if_with(&manager, collection, id, expectations, updates, alias).await
+}
+
+/// 使用指定的 ODM 管理器执行条件更新
+pub(crate) async fn update_if_with(
+    odm: &AsyncOdmManager,
+    collection: &str,
+    id: &str,
+    expectations: HashMap<String, DataValue>,
+    updates: HashMap<String, DataValue>,
+    alias: Option<&str>,
+) -> QuickDbResult<()> {
+    let mut fields: Vec<String> = expectations.keys().cloned().collect();
+    fields.sort();
+
+    let mut conditions = vec![QueryConditionWithConfig {
+        field: "id".to_string(),
+        operator: QueryOperator::Eq,
+        value: DataValue::String(id.to_string()),
+        case_insensitive: false,
+    }];
+    for (field, expected) in expectations {
+        let operator = if expected.is_null() { QueryOperator::IsNull } else { QueryOperator::Eq };
+        conditions.push(QueryConditionWithConfig {
+            field,
+            operator,
+            value: expected,
+            case_insensitive: false,
+        });
+    }
+
+    let targets = match sharding::route_id(collection, id, alias)? {
+        ShardRoute::Alias(alias) => vec![alias],
+        ShardRoute::FanOut(shards) => shards.into_iter().map(Some).collect(),
+    };
+    let updated = futures::future::try_join_all(targets.iter().map(|target| {
+        let (conditions, updates) = (conditions.clone(), updates.clone());
+        async move { odm.update(collection, conditions, updates, target.as_deref()).await }
+    }))
+    .await?;
+    if updated.iter().sum::<u64>() > 0 {
+        return Ok(());
+    }
+
+    let exists = futures::future::try_join_all(
+        targets.iter().map(|target| odm.exists_by_id(collection, id, target.as_deref())),
+    )
+    .await?;
+    if exists.into_iter().any(|exists| exists) {
+        Err(QuickDbError::PreconditionFailed {
+            table: collection.to_string(),
+            id: id.to_string(),
+            fields,
+        })
+    } else {
+        Err(QuickDbError::NotFound {
+            message: crate::i18n::tf("odm.update_if_not_found", &[("collection", collection), ("id", id)]),
+        })
+    }
+}
+
+#[cfg(all(test, feature = "sqlite-support"))]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn compare_and_set_transitions_state_once() {
+        crate::i18n::ErrorMessageI18n::init_i18n();
+        let alias = "conditional_suite";
+        let manager = crate::manager::get_global_pool_manager();
+        manager
+            .add_database(DatabaseConfig::sqlite_memory_for_test(alias))
+            .await
+            .unwrap();
+        manager
+            .register_model(crate::model::ModelMeta {
+                collection_name: "orders".to_string(),
+                database_alias: Some(alias.to_string()),
+                fields: HashMap::from([
+                    ("id".to_string(), crate::model::integer_field(None, None)),
+                    ("status".to_string(), crate::model::string_field(Some(16), None, None)),
+                    ("note".to_string(), crate::model::string_field(Some(64), None, None)),
+                ]),
+                ..Default::default()
+            })
+            .unwrap();
+        let pool = manager.get_connection_pools().get(alias).unwrap().clone();
+        let data = HashMap::from([
+            ("status".to_string(), DataValue::String("pending".to_string())),
+            ("note".to_string(), DataValue::Null),
+        ]);
+        let id = match pool.create("orders", &data, &IdStrategy::AutoIncrement).await.unwrap() {
+            DataValue::Object(record) => record["id"].to_string(),
+            other => other.to_string(),
+        };
+
+        let odm = AsyncOdmManager::new();
+        let status = |value: &str| HashMap::from([("status".to_string(), DataValue::String(value.to_string()))]);
+        let mut expected = status("pending");
+        expected.insert("note".to_string(), DataValue::Null);
+        update_if_with(&odm, "orders", &id, expected, status("paid"), Some(alias)).await.unwrap();
+
+        let again = update_if_with(&odm, "orders", &id, status("pending"), status("paid"), Some(alias)).await;
+        assert!(matches!(again, Err(QuickDbError::PreconditionFailed { fields, .. }) if fields == ["status"]));
+        let missing = update_if_with(&odm, "orders", "9999", status("paid"), status("shipped"), Some(alias)).await;
+        assert!(matches!(missing, Err(QuickDbError::NotFound { .. })));
+    }
+}
@@ -0,0 +1,214 @@
+//! # 并发查询去重
+//!
+//! 请求扇出场景中同一个查询常在同一时刻被执行多次。启用去重后，别名、表与查询
+//! （条件与查询选项）都相同的并发查询共享同一次数据库调用，后到的请求直接等待
+//! 正在执行的查询结果：
+//!
+//! ```ignore
+//! odm::set_query_dedup(true);
+//! let (a, b) = tokio::join!(
+//!     odm::find("products", conditions.clone(), None, None),
+//!     odm::find("products", conditions, None, None),
+//! );
+//! ```
+//!
+//! 去重只覆盖正在执行的查询，查询完成后立即失效，与缓存层相互独立。加入已在执行的
+//! 查询可能读不到在它开始之后才提交的写入，因此默认关闭；带行级锁的查询从不合并
+
+use crate::error::{QuickDbError, QuickDbResult};
+use crate::types::*;
+use dashmap::DashMap;
+use dashmap::mapref::entry::Entry;
+use futures::FutureExt;
+use futures::future::{BoxFuture, Shared, WeakShared};
+use once_cell::sync::Lazy;
+use std::future::Future;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+
+type FindFuture = BoxFuture<'static, Arc<QuickDbResult<Vec<DataValue>>>>;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static NEXT_QUERY_ID: AtomicU64 = AtomicU64::new(0);
+/// 正在执行的查询，只保存弱引用，所有等待者都放弃时查询随之释放
+static IN_FLIGHT: Lazy<DashMap<String, (u64, WeakShared<FindFuture>)>> = Lazy::new(DashMap::new);
+
+/// 启用或关闭并发查询去重
+pub fn set_query_dedup(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// 并发查询去重是否已启用
+pub fn is_query_dedup_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// 生成条件列表查询的去重键，条件按内容排序，顺序不同的同一组条件视为同一查询。
+/// 带行级锁的查询不参与去重，返回 `None`
+pub(crate) fn conditions_key(
+    alias: &str,
+    table: &str,
+    conditions: &[QueryConditionWithConfig],
+    options: &QueryOptions,
+    bypass_cache: bool,
+) -> Option<String> {
+    if options.lock.is_some() {
+        return None;
+    }
+    let mut parts: Vec<String> = conditions.iter().map(|condition| format!("{:?}", condition)).collect();
+    parts.sort();
+    Some(format!("{}\0{}\0find\0{}\0{:?}\0{}", alias, table, parts.join("\0"), options, bypass_cache))
+}
+
+/// 生成条件组查询的去重键
+pub(crate) fn groups_key(
+    alias: &str,
+    table: &str,
+    condition_groups: &[QueryConditionGroupWithConfig],
+    options: &QueryOptions,
+    bypass_cache: bool,
+) -> Option<String> {
+    if options.lock.is_some() {
+        return None;
+    }
+    let mut parts: Vec<String> = condition_groups.iter().map(|group| format!("{:?}", group)).collect();
+    parts.sort();
+    Some(format!("{}\0{}\0groups\0{}\0{:?}\0{}", alias, table, parts.join("\0"), options, bypass_cache))
+}
+
+/// 已启用去重且查询可以去重时，与同键的并发查询共享 `run` 的结果，否则直接执行
+pub(crate) async fn dedup_find<F>(key: Option<String>, run: F) -> QuickDbResult<Vec<DataValue>>
+where
+    F: Future<Output = QuickDbResult<Vec<DataValue>>> + Send + 'static,
+{
+    match key {
+        Some(key) if is_query_dedup_enabled() => share(key, run).await,
+        _ => run.await,
+    }
+}
+
+/// 加入同键的正在执行的查询，没有时由当前请求发起
+async fn share<F>(key: String, run: F) -> QuickDbResult<Vec<DataValue>>
+where
+    F: Future<Output = QuickDbResult<Vec<DataValue>>> + Send + 'static,
+{
+    let (shared, leader) = match IN_FLIGHT.entry(key.clone()) {
+        Entry::Occupied(entry) => match entry.get().1.upgrade() {
+            Some(shared) => (shared, false),
+            // 登记的查询已被所有等待者放弃，由当前请求重新发起
+            None => (lead(Entry::Occupied(entry), key, run), true),
+        },
+        entry => (lead(entry, key, run), true),
+    };
+
+    let result = shared.await;
+    // 发起者是最后一个持有者时可以直接取走结果，避免复制
+    let result = if leader { Arc::try_unwrap(result) } else { Err(result) };
+    match result {
+        Ok(result) => result,
+        Err(result) => match &*result {
+            Ok(records) => Ok(records.clone()),
+            Err(e) => Err(share_error(e)),
+        },
+    }
+}
+
+/// 由当前请求发起查询，并把它登记到同键的条目上
+fn lead<F>(entry: Entry<'_, String, (u64, WeakShared<FindFuture>)>, key: String, run: F) -> Shared<FindFuture>
+where
+    F: Future<Output = QuickDbResult<Vec<DataValue>>> + Send + 'static,
+{
+    // 守卫随共享查询一起释放：查询完成、panic 或所有等待者都放弃等待时都会移除登记
+    let id = NEXT_QUERY_ID.fetch_add(1, Ordering::Relaxed);
+    let guard = InFlightGuard { key, id };
+    let shared = async move {
+        let _guard = guard;
+        Arc::new(run.await)
+    }
+    .boxed()
+    .shared();
+    let weak = shared.downgrade().expect("未完成的共享查询可以降级");
+    entry.insert_entry((id, weak));
+    shared
+}
+
+/// 释放时移除正在执行的查询的登记（同键已被后续查询替换时保留新的登记）
+struct InFlightGuard {
+    key: String,
+    id: u64,
+}
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        IN_FLIGHT.remove_if(&self.key, |_, (id, _)| *id == self.id);
+    }
+}
+
+/// 复制共享查询的错误，无法复制的错误转换为查询错误
+fn share_error(e: &QuickDbError) -> QuickDbError {
+    match e {
+        QuickDbError::ConnectionError { message } => QuickDbError::ConnectionError { message: message.clone() },
+        QuickDbError::PoolError { message } => QuickDbError::PoolError { message: message.clone() },
+        QuickDbError::ValidationError { field, message } => QuickDbError::ValidationError {
+            field: field.clone(),
+            message: message.clone(),
+        },
+        QuickDbError::AliasNotFound { alias } => QuickDbError::AliasNotFound { alias: alias.clone() },
+        QuickDbError::TableNotExistError { table, message } => QuickDbError::TableNotExistError {
+            table: table.clone(),
+            message: message.clone(),
+        },
+        QuickDbError::CircuitOpen { alias, retry_after_ms } => QuickDbError::CircuitOpen {
+            alias: alias.clone(),
+            retry_after_ms: *retry_after_ms,
+        },
+        QuickDbError::Overloaded { alias, queued } => QuickDbError::Overloaded {
+            alias: alias.clone(),
+            queued: *queued,
+        },
+        other => QuickDbError::QueryError { message: other.to_string() },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn concurrent_identical_queries_share_one_call() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let run = |calls: Arc<AtomicUsize>| async move {
+            calls.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            Ok(vec![DataValue::Int(1)])
+        };
+
+        let key = conditions_key("dedup_suite", "products", &[], &QueryOptions::default(), false).unwrap();
+        let results = futures::future::join_all((0..8).map(|_| share(key.clone(), run(calls.clone())))).await;
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert!(results.iter().all(|result| matches!(result.as_deref(), Ok([DataValue::Int(1)]))));
+        assert!(!IN_FLIGHT.contains_key(&key));
+
+        // 查询完成后再次发起会重新执行
+        share(key, run(calls.clone())).await.unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+        let locked = QueryOptions::default().with_lock(RowLock::ForUpdate);
+        assert!(conditions_key("dedup_suite", "products", &[], &locked, false).is_none());
+    }
+
+    #[tokio::test]
+    async fn abandoned_or_panicking_leader_releases_key() {
+        let key = conditions_key("dedup_suite", "orders", &[], &QueryOptions::default(), false).unwrap();
+
+        let pending = share(key.clone(), futures::future::pending());
+        assert!(tokio::time::timeout(Duration::from_millis(10), pending).await.is_err());
+        assert!(!IN_FLIGHT.contains_key(&key));
+
+        let panicking = tokio::spawn(share(key.clone(), async { panic!("查询失败") }));
+        assert!(panicking.await.is_err());
+        assert!(!IN_FLIGHT.contains_key(&key));
+    }
+}
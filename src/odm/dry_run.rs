@@ -0,0 +1,143 @@
+//! # 写操作演练
+//!
+//! 演练模式下更新与删除不会执行，只统计满足条件的记录数并渲染将要执行的语句，
+//! 适用于管理工具预览与迁移彩排：
+//!
+//! ```ignore
+//! let (deleted, reports) = odm::with_dry_run(async {
+//!     odm.delete("users", conditions, None).await
+//! })
+//! .await;
+//! for report in reports {
+//!     println!("{} 条记录将受影响: {}", report.affected_rows, report.statement);
+//! }
+//! ```
+//!
+//! 单次更新也可通过 [`UpdateOptions::with_dry_run`] 演练，报告写入当前演练作用域（若有）并记录日志
+
+use crate::adapter::render::{WriteStatement, render_write};
+use crate::error::{QuickDbError, QuickDbResult};
+use crate::manager::{get_database_type_by_alias, get_global_pool_manager};
+use crate::odm::{AsyncOdmManager, OdmOperations};
+use crate::types::*;
+use rat_logger::info;
+use serde::Serialize;
+use std::future::Future;
+use std::sync::{Arc, Mutex, PoisonError};
+
+tokio::task_local! {
+    static DRY_RUN: Arc<Mutex<Vec<DryRunReport>>>;
+}
+
+/// 在演练作用域中执行 `future`，期间的更新与删除只生成报告，返回 `future` 的输出与全部报告
+pub async fn with_dry_run<F: Future>(future: F) -> (F::Output, Vec<DryRunReport>) {
+    let reports = Arc::new(Mutex::new(Vec::new()));
+    let output = DRY_RUN.scope(reports.clone(), future).await;
+    let reports = std::mem::take(&mut *reports.lock().unwrap_or_else(PoisonError::into_inner));
+    (output, reports)
+}
+
+/// 当前任务是否处于演练作用域
+pub fn is_dry_run() -> bool {
+    DRY_RUN.try_with(|_| ()).is_ok()
+}
+
+/// 演练的写操作类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DryRunOperation {
+    Update,
+    Delete,
+}
+
+/// 一次演练的结果
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DryRunReport {
+    /// 表名/集合名
+    pub collection: String,
+    /// 写操作类型
+    pub operation: DryRunOperation,
+    /// 满足条件、将受影响的记录数
+    pub affected_rows: u64,
+    /// 将要执行的语句（SQL 或 mongo shell 命令）
+    pub statement: String,
+    /// 语句的绑定参数（MongoDB 的参数已内联到命令中）
+    pub params: Vec<DataValue>,
+}
+
+/// 演练一次写操作：统计受影响记录数、渲染语句并写入报告
+pub(crate) async fn rehearse(
+    odm: &AsyncOdmManager,
+    collection: &str,
+    alias: Option<&str>,
+    conditions: Vec<QueryConditionWithConfig>,
+    write: WriteStatement<'_>,
+) -> QuickDbResult<u64> {
+    let alias = match alias {
+        Some(alias) => alias.to_string(),
+        None => get_global_pool_manager()
+            .get_default_alias()
+            .await
+            .unwrap_or_else(|| "default".to_string()),
+    };
+    let db_type = get_database_type_by_alias(&alias).ok_or_else(|| QuickDbError::AliasNotFound {
+        alias: alias.clone(),
+    })?;
+
+    let (statement, params) = render_write(&db_type, collection, &alias, &conditions, write)?;
+    let operation = match write {
+        WriteStatement::Delete => DryRunOperation::Delete,
+        _ => DryRunOperation::Update,
+    };
+    let affected_rows = odm.count(collection, conditions, Some(&alias)).await?;
+    info!(
+        "演练写操作: 表={}, 操作={:?}, 受影响记录数={}, 语句={}",
+        collection, operation, affected_rows, statement
+    );
+
+    let report = DryRunReport {
+        collection: collection.to_string(),
+        operation,
+        affected_rows,
+        statement,
+        params,
+    };
+    let _ = DRY_RUN.try_with(|reports| reports.lock().unwrap_or_else(PoisonError::into_inner).push(report));
+    Ok(affected_rows)
+}
+
+/// 按 ID 定位记录的条件
+pub(crate) fn id_condition(id: &str) -> QueryConditionWithConfig {
+    QueryConditionWithConfig {
+        field: "id".to_string(),
+        operator: QueryOperator::Eq,
+        value: DataValue::String(id.to_string()),
+        case_insensitive: false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn scope_collects_reports_and_ends_with_the_future() {
+        assert!(!is_dry_run());
+        let (inside, reports) = with_dry_run(async {
+            let report = DryRunReport {
+                collection: "users".to_string(),
+                operation: DryRunOperation::Delete,
+                affected_rows: 3,
+                statement: "DELETE FROM \"users\"".to_string(),
+                params: Vec::new(),
+            };
+            DRY_RUN.with(|reports| reports.lock().unwrap().push(report));
+            is_dry_run()
+        })
+        .await;
+        assert!(inside);
+        assert!(!is_dry_run());
+        assert_eq!(reports.len(), 1);
+        assert_eq!(reports[0].affected_rows, 3);
+    }
+}
@@ -0,0 +1,110 @@
+//! # 跨数据库联合查询
+//!
+//! 同一张表分布在多个数据库别名中（例如每个区域一个数据库）时，
+//! [`find_multi`] 在各别名上并发执行同一查询，合并结果后按查询选项重新排序并分页：
+//!
+//! ```ignore
+//! let options = QueryOptions::new()
+//!     .with_sort(vec![SortConfig { field: "created_at".into(), direction: SortDirection::Desc }])
+//!     .with_pagination(PaginationConfig { skip: 0, limit: 20 });
+//! let latest = odm::find_multi(&["eu", "us", "ap"], "orders", conditions, Some(options)).await?;
+//! ```
+//!
+//! 每个别名只取前 `skip + limit` 条记录，合并后再统一跳过与截取；任一别名查询失败时返回错误
+
+use crate::error::QuickDbResult;
+use crate::odm::{AsyncOdmManager, OdmOperations, get_odm_manager};
+use crate::sharding;
+use crate::types::*;
+
+/// 在多个数据库别名上执行同一查询并合并结果
+pub async fn find_multi(
+    aliases: &[&str],
+    collection: &str,
+    conditions: Vec<QueryConditionWithConfig>,
+    options: Option<QueryOptions>,
+) -> QuickDbResult<Vec<DataValue>> {
+    crate::lock_global_operations();
+
+    let manager = get_odm_manager().await;
+    find_multi_with(&manager, aliases, collection, conditions, options).await
+}
+
+/// 使用指定的 ODM 管理器执行联合查询
+pub(crate) async fn find_multi_with(
+    odm: &AsyncOdmManager,
+    aliases: &[&str],
+    collection: &str,
+    conditions: Vec<QueryConditionWithConfig>,
+    options: Option<QueryOptions>,
+) -> QuickDbResult<Vec<DataValue>> {
+    let mut targets: Vec<String> = Vec::with_capacity(aliases.len());
+    for alias in aliases {
+        if !targets.iter().any(|target| target == alias) {
+            targets.push(alias.to_string());
+        }
+    }
+
+    let alias_options = sharding::shard_options(options.as_ref());
+    let results = sharding::fan_out(targets, |alias| {
+        let (conditions, alias_options) = (conditions.clone(), alias_options.clone());
+        async move { odm.find(collection, conditions, alias_options, Some(&alias)).await }
+    })
+    .await?;
+    Ok(sharding::merge_results(results, options.as_ref()))
+}
+
+#[cfg(all(test, feature = "sqlite-support"))]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[tokio::test]
+    async fn merges_sorted_pages_across_aliases() {
+        crate::i18n::ErrorMessageI18n::init_i18n();
+        let manager = crate::manager::get_global_pool_manager();
+        let aliases = ["federated_suite_eu", "federated_suite_us"];
+        for (alias, scores) in aliases.iter().zip([[5, 1], [9, 3]]) {
+            manager
+                .add_database(DatabaseConfig::sqlite_memory_for_test(alias))
+                .await
+                .unwrap();
+            manager
+                .register_model(crate::model::ModelMeta {
+                    collection_name: "orders".to_string(),
+                    database_alias: Some(alias.to_string()),
+                    fields: HashMap::from([
+                        ("id".to_string(), crate::model::integer_field(None, None)),
+                        ("score".to_string(), crate::model::integer_field(None, None)),
+                    ]),
+                    ..Default::default()
+                })
+                .unwrap();
+            let pool = manager.get_connection_pools().get(*alias).unwrap().clone();
+            for score in scores {
+                let data = HashMap::from([("score".to_string(), DataValue::Int(score))]);
+                pool.create("orders", &data, &IdStrategy::AutoIncrement).await.unwrap();
+            }
+        }
+
+        let options = QueryOptions::new()
+            .with_sort(vec![SortConfig {
+                field: "score".to_string(),
+                direction: SortDirection::Desc,
+            }])
+            .with_pagination(PaginationConfig { skip: 1, limit: 2 });
+        let odm = AsyncOdmManager::new();
+        let records = find_multi_with(&odm, &aliases, "orders", vec![], Some(options)).await.unwrap();
+        let scores: Vec<i64> = records
+            .iter()
+            .filter_map(|record| match record {
+                DataValue::Object(map) => map.get("score").and_then(|score| match score {
+                    DataValue::Int(score) => Some(*score),
+                    _ => None,
+                }),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(scores, [5, 3]);
+    }
+}
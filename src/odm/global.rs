@@ -3,6 +3,7 @@
 use crate::error::QuickDbResult;
 use crate::odm::manager_core::AsyncOdmManager;
 use crate::odm::traits::OdmOperations;
+use crate::sharding::{self, ShardRoute};
 use crate::types::*;
 use std::collections::HashMap;
 
@@ -20,6 +21,21 @@ pub async fn get_odm_manager_mut() -> tokio::sync::RwLockWriteGuard<'static, Asy
     ASYNC_ODM_MANAGER.write().await
 }
 
+/// 在测试共享的运行时中执行 future
+///
+/// 全局ODM管理器的后台任务启动在首次访问它的运行时上，`#[tokio::test]` 的运行时随
+/// 单个测试结束，经过全局ODM的测试都应在此运行时中执行
+#[cfg(test)]
+pub(crate) fn block_on_shared<F: std::future::Future>(future: F) -> F::Output {
+    static RUNTIME: once_cell::sync::Lazy<tokio::runtime::Runtime> = once_cell::sync::Lazy::new(|| {
+        tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .expect("创建测试运行时失败")
+    });
+    RUNTIME.block_on(future)
+}
+
 /// 便捷函数：创建记录
 ///
 /// 【注意】这是一个内部函数，建议通过ModelManager或模型的save方法进行操作
@@ -33,8 +49,9 @@ pub async fn create(
     // 锁定全局操作
     crate::lock_global_operations();
 
+    let alias = sharding::route_data(collection, &data, alias)?;
     let manager = get_odm_manager().await;
-    manager.create(collection, data, alias).await
+    manager.create(collection, data, alias.as_deref()).await
 }
 
 /// 便捷函数：根据ID查询记录
@@ -51,7 +68,44 @@ pub async fn find_by_id(
     crate::lock_global_operations();
 
     let manager = get_odm_manager().await;
-    manager.find_by_id(collection, id, alias).await
+    match sharding::route_id(collection, id, alias)? {
+        ShardRoute::Alias(alias) => manager.find_by_id(collection, id, alias.as_deref()).await,
+        ShardRoute::FanOut(shards) => {
+            let found = sharding::fan_out(shards, |shard| {
+                let manager = &manager;
+                async move { manager.find_by_id(collection, id, Some(&shard)).await }
+            })
+            .await?;
+            Ok(found.into_iter().flatten().next())
+        }
+    }
+}
+
+/// 便捷函数：根据ID检查记录是否存在
+///
+/// 【注意】这是一个内部函数，建议通过ModelManager的exists_by_id方法进行操作
+/// 除非您明确知道自己在做什么，否则不要直接调用此函数
+#[doc(hidden)]
+pub async fn exists_by_id(
+    collection: &str,
+    id: &str,
+    alias: Option<&str>,
+) -> QuickDbResult<bool> {
+    // 锁定全局操作
+    crate::lock_global_operations();
+
+    let manager = get_odm_manager().await;
+    match sharding::route_id(collection, id, alias)? {
+        ShardRoute::Alias(alias) => manager.exists_by_id(collection, id, alias.as_deref()).await,
+        ShardRoute::FanOut(shards) => {
+            let exists = sharding::fan_out(shards, |shard| {
+                let manager = &manager;
+                async move { manager.exists_by_id(collection, id, Some(&shard)).await }
+            })
+            .await?;
+            Ok(exists.into_iter().any(|exists| exists))
+        }
+    }
 }
 
 /// 便捷函数：查询记录（支持缓存控制）
@@ -70,7 +124,26 @@ pub async fn find_with_cache_control(
     crate::lock_global_operations();
 
     let manager = get_odm_manager().await;
-    manager.find_with_cache_control(collection, conditions, options, alias, bypass_cache).await
+    match sharding::route_conditions(collection, &conditions, alias)? {
+        ShardRoute::Alias(alias) => {
+            manager
+                .find_with_cache_control(collection, conditions, options, alias.as_deref(), bypass_cache)
+                .await
+        }
+        ShardRoute::FanOut(shards) => {
+            let shard_options = sharding::shard_options(options.as_ref());
+            let results = sharding::fan_out(shards, |shard| {
+                let (manager, conditions, shard_options) = (&manager, conditions.clone(), shard_options.clone());
+                async move {
+                    manager
+                        .find_with_cache_control(collection, conditions, shard_options, Some(&shard), bypass_cache)
+                        .await
+                }
+            })
+            .await?;
+            Ok(sharding::merge_results(results, options.as_ref()))
+        }
+    }
 }
 
 /// 便捷函数：查询记录
@@ -103,9 +176,39 @@ pub async fn find_with_groups_with_cache_control(
     crate::lock_global_operations();
 
     let manager = get_odm_manager().await;
-    manager
-        .find_with_groups_with_cache_control_and_config(collection, condition_groups, options, alias, bypass_cache)
-        .await
+    match sharding::route_all(collection, alias)? {
+        ShardRoute::Alias(alias) => {
+            manager
+                .find_with_groups_with_cache_control_and_config(
+                    collection,
+                    condition_groups,
+                    options,
+                    alias.as_deref(),
+                    bypass_cache,
+                )
+                .await
+        }
+        ShardRoute::FanOut(shards) => {
+            let shard_options = sharding::shard_options(options.as_ref());
+            let results = sharding::fan_out(shards, |shard| {
+                let (manager, condition_groups, shard_options) =
+                    (&manager, condition_groups.clone(), shard_options.clone());
+                async move {
+                    manager
+                        .find_with_groups_with_cache_control_and_config(
+                            collection,
+                            condition_groups,
+                            shard_options,
+                            Some(&shard),
+                            bypass_cache,
+                        )
+                        .await
+                }
+            })
+            .await?;
+            Ok(sharding::merge_results(results, options.as_ref()))
+        }
+    }
 }
 
 /// 分组查询便捷函数
@@ -140,8 +243,44 @@ pub async fn update(
     // 锁定全局操作
     crate::lock_global_operations();
 
+    update_with_options(collection, conditions, updates, UpdateOptions::default(), alias).await
+}
+
+/// 便捷函数：更新记录（支持超时等更新选项）
+///
+/// 【注意】这是一个内部函数，建议通过ModelManager或模型的update方法进行操作
+/// 除非您明确知道自己在做什么，否则不要直接调用此函数
+#[doc(hidden)]
+pub async fn update_with_options(
+    collection: &str,
+    conditions: Vec<QueryConditionWithConfig>,
+    updates: HashMap<String, DataValue>,
+    options: UpdateOptions,
+    alias: Option<&str>,
+) -> QuickDbResult<u64> {
+    // 锁定全局操作
+    crate::lock_global_operations();
+
     let manager = get_odm_manager().await;
-    manager.update(collection, conditions, updates, alias).await
+    match sharding::route_conditions(collection, &conditions, alias)? {
+        ShardRoute::Alias(alias) => {
+            manager
+                .update_with_options(collection, conditions, updates, options, alias.as_deref())
+                .await
+        }
+        ShardRoute::FanOut(shards) => {
+            let affected = sharding::fan_out(shards, |shard| {
+                let (manager, conditions, updates, options) = (&manager, conditions.clone(), updates.clone(), options.clone());
+                async move {
+                    manager
+                        .update_with_options(collection, conditions, updates, options, Some(&shard))
+                        .await
+                }
+            })
+            .await?;
+            Ok(affected.into_iter().sum())
+        }
+    }
 }
 
 /// 便捷函数：根据ID更新记录
@@ -159,7 +298,44 @@ pub async fn update_by_id(
     crate::lock_global_operations();
 
     let manager = get_odm_manager().await;
-    manager.update_by_id(collection, id, updates, alias).await
+    match sharding::route_id(collection, id, alias)? {
+        ShardRoute::Alias(alias) => manager.update_by_id(collection, id, updates, alias.as_deref()).await,
+        ShardRoute::FanOut(shards) => {
+            let updated = sharding::fan_out(shards, |shard| {
+                let (manager, updates) = (&manager, updates.clone());
+                async move { manager.update_by_id(collection, id, updates, Some(&shard)).await }
+            })
+            .await?;
+            Ok(updated.into_iter().any(|updated| updated))
+        }
+    }
+}
+
+/// 便捷函数：根据ID更新记录并返回更新后的记录
+///
+/// PostgreSQL/SQLite 使用 `UPDATE ... RETURNING *`，MongoDB 使用 `findOneAndUpdate`，
+/// MySQL 不支持 `RETURNING`，先更新再按ID读回（两步之间不保证原子性）。记录不存在时返回 `None`
+pub async fn update_by_id_returning(
+    collection: &str,
+    id: &str,
+    updates: HashMap<String, DataValue>,
+    alias: Option<&str>,
+) -> QuickDbResult<Option<DataValue>> {
+    // 锁定全局操作
+    crate::lock_global_operations();
+
+    let manager = get_odm_manager().await;
+    match sharding::route_id(collection, id, alias)? {
+        ShardRoute::Alias(alias) => manager.update_by_id_returning(collection, id, updates, alias.as_deref()).await,
+        ShardRoute::FanOut(shards) => {
+            let updated = sharding::fan_out(shards, |shard| {
+                let (manager, updates) = (&manager, updates.clone());
+                async move { manager.update_by_id_returning(collection, id, updates, Some(&shard)).await }
+            })
+            .await?;
+            Ok(updated.into_iter().flatten().next())
+        }
+    }
 }
 
 /// 便捷函数：Upsert记录 - 如果记录存在则更新，否则插入新记录
@@ -176,9 +352,10 @@ pub async fn upsert(
     // 锁定全局操作
     crate::lock_global_operations();
 
+    let alias = sharding::route_data(collection, &data, alias)?;
     let manager = get_odm_manager().await;
     manager
-        .upsert(collection, data, conflict_columns, alias)
+        .upsert(collection, data, conflict_columns, alias.as_deref())
         .await
 }
 
@@ -196,10 +373,45 @@ pub async fn update_with_operations(
     // 锁定全局操作
     crate::lock_global_operations();
 
+    update_with_operations_and_options(collection, conditions, operations, UpdateOptions::default(), alias).await
+}
+
+/// 便捷函数：使用操作数组更新记录（支持超时等更新选项）
+///
+/// 【注意】这是一个内部函数，建议通过ModelManager或模型的update_many_with_operations方法进行操作
+/// 除非您明确知道自己在做什么，否则不要直接调用此函数
+#[doc(hidden)]
+pub async fn update_with_operations_and_options(
+    collection: &str,
+    conditions: Vec<QueryConditionWithConfig>,
+    operations: Vec<crate::types::UpdateOperation>,
+    options: UpdateOptions,
+    alias: Option<&str>,
+) -> QuickDbResult<u64> {
+    // 锁定全局操作
+    crate::lock_global_operations();
+
     let manager = get_odm_manager().await;
-    manager
-        .update_with_operations(collection, conditions, operations, alias)
-        .await
+    match sharding::route_conditions(collection, &conditions, alias)? {
+        ShardRoute::Alias(alias) => {
+            manager
+                .update_with_operations_and_options(collection, conditions, operations, options, alias.as_deref())
+                .await
+        }
+        ShardRoute::FanOut(shards) => {
+            let affected = sharding::fan_out(shards, |shard| {
+                let (manager, conditions, operations, options) =
+                    (&manager, conditions.clone(), operations.clone(), options.clone());
+                async move {
+                    manager
+                        .update_with_operations_and_options(collection, conditions, operations, options, Some(&shard))
+                        .await
+                }
+            })
+            .await?;
+            Ok(affected.into_iter().sum())
+        }
+    }
 }
 
 /// 便捷函数：删除记录
@@ -216,7 +428,17 @@ pub async fn delete(
     crate::lock_global_operations();
 
     let manager = get_odm_manager().await;
-    manager.delete(collection, conditions, alias).await
+    match sharding::route_conditions(collection, &conditions, alias)? {
+        ShardRoute::Alias(alias) => manager.delete(collection, conditions, alias.as_deref()).await,
+        ShardRoute::FanOut(shards) => {
+            let affected = sharding::fan_out(shards, |shard| {
+                let (manager, conditions) = (&manager, conditions.clone());
+                async move { manager.delete(collection, conditions, Some(&shard)).await }
+            })
+            .await?;
+            Ok(affected.into_iter().sum())
+        }
+    }
 }
 
 /// 便捷函数：根据ID删除记录
@@ -229,7 +451,39 @@ pub async fn delete_by_id(collection: &str, id: &str, alias: Option<&str>) -> Qu
     crate::lock_global_operations();
 
     let manager = get_odm_manager().await;
-    manager.delete_by_id(collection, id, alias).await
+    match sharding::route_id(collection, id, alias)? {
+        ShardRoute::Alias(alias) => manager.delete_by_id(collection, id, alias.as_deref()).await,
+        ShardRoute::FanOut(shards) => {
+            let deleted = sharding::fan_out(shards, |shard| {
+                let manager = &manager;
+                async move { manager.delete_by_id(collection, id, Some(&shard)).await }
+            })
+            .await?;
+            Ok(deleted.into_iter().any(|deleted| deleted))
+        }
+    }
+}
+
+/// 便捷函数：根据ID删除记录并返回被删除的记录
+///
+/// PostgreSQL/SQLite 使用 `DELETE ... RETURNING *`，MongoDB 使用 `findOneAndDelete`，
+/// MySQL 不支持 `RETURNING`，先按ID读取再删除（两步之间不保证原子性）。记录不存在时返回 `None`
+pub async fn delete_by_id_returning(collection: &str, id: &str, alias: Option<&str>) -> QuickDbResult<Option<DataValue>> {
+    // 锁定全局操作
+    crate::lock_global_operations();
+
+    let manager = get_odm_manager().await;
+    match sharding::route_id(collection, id, alias)? {
+        ShardRoute::Alias(alias) => manager.delete_by_id_returning(collection, id, alias.as_deref()).await,
+        ShardRoute::FanOut(shards) => {
+            let deleted = sharding::fan_out(shards, |shard| {
+                let manager = &manager;
+                async move { manager.delete_by_id_returning(collection, id, Some(&shard)).await }
+            })
+            .await?;
+            Ok(deleted.into_iter().flatten().next())
+        }
+    }
 }
 
 /// 便捷函数：统计记录数量
@@ -246,7 +500,17 @@ pub async fn count(
     crate::lock_global_operations();
 
     let manager = get_odm_manager().await;
-    manager.count(collection, conditions, alias).await
+    match sharding::route_conditions(collection, &conditions, alias)? {
+        ShardRoute::Alias(alias) => manager.count(collection, conditions, alias.as_deref()).await,
+        ShardRoute::FanOut(shards) => {
+            let counts = sharding::fan_out(shards, |shard| {
+                let (manager, conditions) = (&manager, conditions.clone());
+                async move { manager.count(collection, conditions, Some(&shard)).await }
+            })
+            .await?;
+            Ok(counts.into_iter().sum())
+        }
+    }
 }
 
 /// 便捷函数：使用条件组统计记录数量
@@ -263,7 +527,25 @@ pub async fn count_with_groups(
     crate::lock_global_operations();
 
     let manager = get_odm_manager().await;
-    manager.count_with_groups_with_config(collection, condition_groups, alias).await
+    match sharding::route_all(collection, alias)? {
+        ShardRoute::Alias(alias) => {
+            manager
+                .count_with_groups_with_config(collection, condition_groups, alias.as_deref())
+                .await
+        }
+        ShardRoute::FanOut(shards) => {
+            let counts = sharding::fan_out(shards, |shard| {
+                let (manager, condition_groups) = (&manager, condition_groups.clone());
+                async move {
+                    manager
+                        .count_with_groups_with_config(collection, condition_groups, Some(&shard))
+                        .await
+                }
+            })
+            .await?;
+            Ok(counts.into_iter().sum())
+        }
+    }
 }
 
 /// 获取数据库服务器版本信息
@@ -294,3 +576,100 @@ pub async fn execute_stored_procedure(
         .execute_stored_procedure(procedure_name, database_alias, params)
         .await
 }
+
+/// 修改已创建的存储过程，以新配置替换原定义
+pub async fn alter_stored_procedure(
+    config: crate::stored_procedure::StoredProcedureConfig,
+) -> QuickDbResult<crate::stored_procedure::StoredProcedureCreateResult> {
+    let pool_manager = crate::manager::get_global_pool_manager();
+    if pool_manager
+        .get_stored_procedure(&config.database, &config.procedure_name)
+        .is_none()
+    {
+        return Err(crate::quick_error!(
+            validation,
+            "procedure_name",
+            crate::i18n::tf("sp.not_found", &[("name", &config.procedure_name)])
+        ));
+    }
+    let manager = get_odm_manager().await;
+    manager.create_stored_procedure(config).await
+}
+
+/// 删除存储过程，返回是否存在
+pub async fn drop_stored_procedure(procedure_name: &str, database_alias: Option<&str>) -> QuickDbResult<bool> {
+    let manager = get_odm_manager().await;
+    manager.drop_stored_procedure(procedure_name, database_alias).await
+}
+
+/// 按版本幂等部署存储过程
+///
+/// 未创建时创建；已部署的版本低于 `config.version` 时替换；已部署相同或更新的版本时
+/// 不做修改，便于多个实例或重复部署时安全调用。版本登记在进程内，与存储过程定义一致
+pub async fn deploy_stored_procedure(
+    config: crate::stored_procedure::StoredProcedureConfig,
+) -> QuickDbResult<crate::stored_procedure::StoredProcedureDeployment> {
+    use crate::stored_procedure::StoredProcedureDeployment;
+
+    let pool_manager = crate::manager::get_global_pool_manager();
+    let deployment = match pool_manager.get_stored_procedure(&config.database, &config.procedure_name) {
+        None => StoredProcedureDeployment::Created,
+        Some(current) if current.version < config.version => {
+            StoredProcedureDeployment::Upgraded { from: current.version }
+        }
+        Some(current) => {
+            if current.version > config.version {
+                rat_logger::warn!(
+                    "存储过程 {} 已部署版本 {}，忽略旧版本 {}",
+                    config.procedure_name,
+                    current.version,
+                    config.version
+                );
+            }
+            return Ok(StoredProcedureDeployment::Unchanged { current: current.version });
+        }
+    };
+
+    let manager = get_odm_manager().await;
+    let result = manager.create_stored_procedure(config).await?;
+    if !result.success {
+        return Err(crate::error::QuickDbError::QueryError {
+            message: result.error.unwrap_or_else(|| result.procedure_name.clone()),
+        });
+    }
+    Ok(deployment)
+}
+
+/// 执行存储过程并返回带类型的结果
+///
+/// 依次执行该过程及其配置的附加结果集，按声明的输出参数读取并转换输出值；
+/// 未通过ODM创建（没有登记配置）的过程按单结果集处理
+pub async fn execute_stored_procedure_typed(
+    procedure_name: &str,
+    database_alias: Option<&str>,
+    params: Option<std::collections::HashMap<String, crate::types::DataValue>>,
+) -> QuickDbResult<crate::stored_procedure::StoredProcedureOutput> {
+    let pool_manager = crate::manager::get_global_pool_manager();
+    let alias = match database_alias {
+        Some(alias) => alias.to_string(),
+        None => pool_manager
+            .get_default_alias()
+            .await
+            .unwrap_or_else(|| "default".to_string()),
+    };
+    let config = pool_manager.get_stored_procedure(&alias, procedure_name);
+    let (result_sets, output_params) = config
+        .map(|config| (config.result_sets, config.output_params))
+        .unwrap_or_default();
+
+    let manager = get_odm_manager().await;
+    let mut rows = Vec::with_capacity(result_sets.len() + 1);
+    for name in std::iter::once(procedure_name).chain(result_sets.iter().map(String::as_str)) {
+        rows.push(
+            manager
+                .execute_stored_procedure(name, Some(&alias), params.clone())
+                .await?,
+        );
+    }
+    crate::stored_procedure::StoredProcedureOutput::from_result_sets(rows, &output_params)
+}
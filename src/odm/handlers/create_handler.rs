@@ -5,7 +5,7 @@ use crate::error::{QuickDbError, QuickDbResult};
 use crate::manager::get_global_pool_manager;
 use crate::odm::manager_core::AsyncOdmManager;
 use crate::types::*;
-use rat_logger::{debug, error, info, warn};
+use rat_logger::{debug, warn};
 use std::collections::HashMap;
 use tokio::sync::oneshot;
 
@@ -33,6 +33,9 @@ impl AsyncOdmManager {
             collection, actual_alias
         );
 
+        // 执行模型字段声明的自定义验证器
+        crate::model::validators::validate_registered(collection, &actual_alias, &data).await?;
+
         // 确保表和索引存在（基于注册的模型元数据）
         if let Err(e) = manager
             .ensure_table_and_indexes(collection, &actual_alias)
@@ -56,8 +59,58 @@ impl AsyncOdmManager {
 
         // 根据ID策略处理ID字段
         let mut processed_data = data.clone();
+        Self::assign_create_id(&actual_alias, &connection_pool.db_config.db_type, &mut processed_data).await?;
+
+        // 创建oneshot通道用于接收响应
+        let (response_tx, response_rx) = oneshot::channel();
+
+        // 发送DatabaseOperation::Create请求到连接池
+        let operation = crate::pool::DatabaseOperation::Create {
+            table: collection.to_string(),
+            data: processed_data,
+            id_strategy,
+            alias: actual_alias.clone(),
+            response: response_tx,
+        };
+
+        connection_pool
+            .operation_sender
+            .send(operation)
+            .await?;
+
+        // 等待响应
+        let result = response_rx
+            .await
+            .map_err(|_| QuickDbError::ConnectionError {
+                message: crate::i18n::t("odm.response_timeout"),
+            })??;
+
+        // 从返回的Object中提取id字段
+        match result {
+            DataValue::Object(map) => {
+                // 优先查找"id"字段（SQL数据库），如果没有则查找"_id"字段（MongoDB）
+                if let Some(id_value) = map.get("id") {
+                    Ok(id_value.clone())
+                } else if let Some(id_value) = map.get("_id") {
+                    Ok(id_value.clone())
+                } else {
+                    Err(QuickDbError::QueryError {
+                        message: crate::i18n::t("odm.create_missing_id"),
+                    })
+                }
+            }
+            // 如果返回的不是Object，可能是其他数据库的直接ID值，直接返回
+            other => Ok(other),
+        }
+    }
 
-        if let Ok(id_generator) = manager.get_id_generator(&actual_alias) {
+    /// 按别名的ID策略处理待创建数据的ID字段：自增策略移除传入的ID，其他策略缺少有效ID时生成
+    pub(crate) async fn assign_create_id(
+        alias: &str,
+        db_type: &DatabaseType,
+        processed_data: &mut HashMap<String, DataValue>,
+    ) -> QuickDbResult<()> {
+        if let Ok(id_generator) = get_global_pool_manager().get_id_generator(alias) {
             match id_generator.strategy() {
                 crate::types::IdStrategy::AutoIncrement => {
                     // AutoIncrement策略：移除用户传入的id字段，让数据库自动生成
@@ -94,7 +147,7 @@ impl AsyncOdmManager {
                                     crate::types::IdType::String(s) => DataValue::String(s.clone()),
                                 };
                                 // 根据数据库类型决定使用"id"还是"_id"字段
-                                match connection_pool.db_config.db_type {
+                                match db_type {
                                     crate::types::DatabaseType::MongoDB => {
                                         debug!("为MongoDB生成_id字段");
                                         processed_data.insert("_id".to_string(), id_value);
@@ -115,49 +168,6 @@ impl AsyncOdmManager {
         } else {
             warn!("获取IdGenerator失败，使用原始数据");
         }
-
-        // 创建oneshot通道用于接收响应
-        let (response_tx, response_rx) = oneshot::channel();
-
-        // 发送DatabaseOperation::Create请求到连接池
-        let operation = crate::pool::DatabaseOperation::Create {
-            table: collection.to_string(),
-            data: processed_data,
-            id_strategy,
-            alias: actual_alias.clone(),
-            response: response_tx,
-        };
-
-        connection_pool
-            .operation_sender
-            .send(operation)
-            .map_err(|_| QuickDbError::ConnectionError {
-                message: crate::i18n::t("odm.channel_closed"),
-            })?;
-
-        // 等待响应
-        let result = response_rx
-            .await
-            .map_err(|_| QuickDbError::ConnectionError {
-                message: crate::i18n::t("odm.response_timeout"),
-            })??;
-
-        // 从返回的Object中提取id字段
-        match result {
-            DataValue::Object(map) => {
-                // 优先查找"id"字段（SQL数据库），如果没有则查找"_id"字段（MongoDB）
-                if let Some(id_value) = map.get("id") {
-                    Ok(id_value.clone())
-                } else if let Some(id_value) = map.get("_id") {
-                    Ok(id_value.clone())
-                } else {
-                    Err(QuickDbError::QueryError {
-                        message: crate::i18n::t("odm.create_missing_id"),
-                    })
-                }
-            }
-            // 如果返回的不是Object，可能是其他数据库的直接ID值，直接返回
-            other => Ok(other),
-        }
+        Ok(())
     }
 }
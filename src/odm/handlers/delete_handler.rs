@@ -6,7 +6,7 @@ use crate::manager::get_global_pool_manager;
 use crate::odm::manager_core::AsyncOdmManager;
 use crate::pool::DatabaseOperation;
 use crate::types::*;
-use rat_logger::{debug, info, warn};
+use rat_logger::debug;
 use tokio::sync::oneshot;
 
 impl AsyncOdmManager {
@@ -53,9 +53,7 @@ impl AsyncOdmManager {
         connection_pool
             .operation_sender
             .send(operation)
-            .map_err(|_| QuickDbError::ConnectionError {
-                message: crate::i18n::t("odm.channel_closed"),
-            })?;
+            .await?;
 
         // 等待响应
         let affected_rows = response_rx
@@ -110,9 +108,7 @@ impl AsyncOdmManager {
         connection_pool
             .operation_sender
             .send(operation)
-            .map_err(|_| QuickDbError::ConnectionError {
-                message: crate::i18n::t("odm.channel_closed"),
-            })?;
+            .await?;
 
         // 等待响应
         let result = response_rx
@@ -124,6 +120,55 @@ impl AsyncOdmManager {
         Ok(result)
     }
 
+    /// 处理根据ID删除并返回记录请求
+    #[doc(hidden)]
+    pub async fn handle_delete_by_id_returning(
+        collection: &str,
+        id: &str,
+        alias: Option<String>,
+    ) -> QuickDbResult<Option<DataValue>> {
+        let manager = get_global_pool_manager();
+        let actual_alias = match alias {
+            Some(a) => a,
+            None => manager
+                .get_default_alias()
+                .await
+                .unwrap_or_else(|| "default".to_string()),
+        };
+        debug!(
+            "处理根据ID删除并返回记录请求: collection={}, id={}, alias={}",
+            collection, id, actual_alias
+        );
+
+        let connection_pools = manager.get_connection_pools();
+        let connection_pool =
+            connection_pools
+                .get(&actual_alias)
+                .ok_or_else(|| QuickDbError::AliasNotFound {
+                    alias: actual_alias.clone(),
+                })?;
+
+        let (response_tx, response_rx) = oneshot::channel();
+
+        let operation = DatabaseOperation::DeleteByIdReturning {
+            table: collection.to_string(),
+            id: DataValue::String(id.to_string()),
+            alias: actual_alias.clone(),
+            response: response_tx,
+        };
+
+        connection_pool
+            .operation_sender
+            .send(operation)
+            .await?;
+
+        response_rx
+            .await
+            .map_err(|_| QuickDbError::ConnectionError {
+                message: crate::i18n::t("odm.response_timeout"),
+            })?
+    }
+
     /// 处理计数请求
     #[doc(hidden)]
     pub async fn handle_count(
@@ -167,9 +212,7 @@ impl AsyncOdmManager {
         connection_pool
             .operation_sender
             .send(operation)
-            .map_err(|_| QuickDbError::ConnectionError {
-                message: crate::i18n::t("odm.channel_closed"),
-            })?;
+            .await?;
 
         // 等待响应
         let count = response_rx
@@ -224,9 +267,7 @@ impl AsyncOdmManager {
         connection_pool
             .operation_sender
             .send(operation)
-            .map_err(|_| QuickDbError::ConnectionError {
-                message: crate::i18n::t("odm.channel_closed"),
-            })?;
+            .await?;
 
         // 等待响应
         let count = response_rx
@@ -269,9 +310,7 @@ impl AsyncOdmManager {
         connection_pool
             .operation_sender
             .send(operation)
-            .map_err(|_| QuickDbError::ConnectionError {
-                message: crate::i18n::t("odm.channel_closed"),
-            })?;
+            .await?;
 
         let result = response_rx
             .await
@@ -10,9 +10,4 @@ pub mod update_handler;
 pub mod upsert_handler;
 
 // 重新导出所有处理器以保持API兼容性
-pub use create_handler::*;
-pub use delete_handler::*;
-pub use read_handler::*;
 pub use stored_procedure_handler::*;
-pub use update_handler::*;
-pub use upsert_handler::*;
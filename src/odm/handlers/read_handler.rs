@@ -3,10 +3,12 @@
 
 use crate::error::{QuickDbError, QuickDbResult};
 use crate::manager::get_global_pool_manager;
+use crate::model::scopes;
+use crate::odm::dedup;
 use crate::odm::manager_core::AsyncOdmManager;
 use crate::pool::DatabaseOperation;
 use crate::types::*;
-use rat_logger::{debug, info, warn};
+use rat_logger::debug;
 use tokio::sync::oneshot;
 
 impl AsyncOdmManager {
@@ -53,9 +55,7 @@ impl AsyncOdmManager {
         connection_pool
             .operation_sender
             .send(operation)
-            .map_err(|_| QuickDbError::ConnectionError {
-                message: crate::i18n::t("odm.channel_closed"),
-            })?;
+            .await?;
 
         // 等待响应
         response_rx
@@ -65,15 +65,13 @@ impl AsyncOdmManager {
             })?
     }
 
-    /// 处理查询请求（支持缓存控制）
+    /// 处理根据ID检查记录是否存在请求
     #[doc(hidden)]
-    pub async fn handle_find_with_cache_control(
+    pub async fn handle_exists_by_id(
         collection: &str,
-        conditions: Vec<QueryConditionWithConfig>,
-        options: Option<QueryOptions>,
+        id: &str,
         alias: Option<String>,
-        bypass_cache: bool,
-    ) -> QuickDbResult<Vec<DataValue>> {
+    ) -> QuickDbResult<bool> {
         let manager = get_global_pool_manager();
         let actual_alias = match alias {
             Some(a) => a,
@@ -83,11 +81,10 @@ impl AsyncOdmManager {
                 .unwrap_or_else(|| "default".to_string()),
         };
         debug!(
-            "处理查询请求（bypass_cache={}）: collection={}, alias={}",
-            bypass_cache, collection, actual_alias
+            "处理根据ID检查存在请求: collection={}, id={}, alias={}",
+            collection, id, actual_alias
         );
 
-        let manager = get_global_pool_manager();
         let connection_pools = manager.get_connection_pools();
         let connection_pool =
             connection_pools
@@ -96,27 +93,20 @@ impl AsyncOdmManager {
                     alias: actual_alias.clone(),
                 })?;
 
-        // 创建oneshot通道用于接收响应
         let (response_tx, response_rx) = oneshot::channel();
 
-        // 发送DatabaseOperation::FindWithBypassCache请求到连接池
-        let operation = DatabaseOperation::FindWithBypassCache {
+        let operation = DatabaseOperation::ExistsById {
             table: collection.to_string(),
-            conditions,
-            options: options.unwrap_or_default(),
+            id: DataValue::String(id.to_string()),
             alias: actual_alias.clone(),
-            bypass_cache,
             response: response_tx,
         };
 
         connection_pool
             .operation_sender
             .send(operation)
-            .map_err(|_| QuickDbError::ConnectionError {
-                message: crate::i18n::t("odm.channel_closed"),
-            })?;
+            .await?;
 
-        // 等待响应
         response_rx
             .await
             .map_err(|_| QuickDbError::ConnectionError {
@@ -124,6 +114,78 @@ impl AsyncOdmManager {
             })?
     }
 
+    /// 处理查询请求（支持缓存控制）
+    #[doc(hidden)]
+    pub async fn handle_find_with_cache_control(
+        collection: &str,
+        conditions: Vec<QueryConditionWithConfig>,
+        options: Option<QueryOptions>,
+        alias: Option<String>,
+        bypass_cache: bool,
+    ) -> QuickDbResult<Vec<DataValue>> {
+        reject_row_lock(options.as_ref())?;
+        let manager = get_global_pool_manager();
+        let actual_alias = match alias {
+            Some(a) => a,
+            None => manager
+                .get_default_alias()
+                .await
+                .unwrap_or_else(|| "default".to_string()),
+        };
+        debug!(
+            "处理查询请求（bypass_cache={}）: collection={}, alias={}",
+            bypass_cache, collection, actual_alias
+        );
+
+        let manager = get_global_pool_manager();
+        let connection_pools = manager.get_connection_pools();
+        let connection_pool =
+            connection_pools
+                .get(&actual_alias)
+                .ok_or_else(|| QuickDbError::AliasNotFound {
+                    alias: actual_alias.clone(),
+                })?;
+
+        // 创建oneshot通道用于接收响应
+        let (response_tx, response_rx) = oneshot::channel();
+
+        // 按查询选项的空值语义改写条件，保证各数据库行为一致
+        let mut options = options.unwrap_or_default();
+        let mut conditions = conditions;
+        // 展开模型的默认作用域与命名作用域
+        conditions.extend(scopes::resolve_registered(collection, &actual_alias, &mut options)?);
+        options.null_semantics.normalize_conditions(&mut conditions);
+
+        // 相同的并发查询共享同一次数据库调用
+        let key = dedup::conditions_key(&actual_alias, collection, &conditions, &options, bypass_cache);
+        let connection_pool = connection_pool.clone();
+        let table = collection.to_string();
+        dedup::dedup_find(key, async move {
+            // 发送DatabaseOperation::FindWithBypassCache请求到连接池
+            let operation = DatabaseOperation::FindWithBypassCache {
+                table,
+                conditions,
+                options,
+                alias: actual_alias,
+                bypass_cache,
+                response: response_tx,
+            };
+
+            connection_pool
+                .operation_sender
+                .send(operation)
+                .await?;
+
+            // 等待响应
+            response_rx
+                .await
+                .map_err(|_| QuickDbError::ConnectionError {
+                    message: crate::i18n::t("odm.response_timeout"),
+                })?
+        })
+        .await
+    }
+
     /// 处理查询请求
     #[doc(hidden)]
     pub async fn handle_find(
@@ -144,6 +206,7 @@ impl AsyncOdmManager {
         alias: Option<String>,
         bypass_cache: bool,
     ) -> QuickDbResult<Vec<DataValue>> {
+        reject_row_lock(options.as_ref())?;
         let manager = get_global_pool_manager();
         let actual_alias = match alias {
             Some(a) => a,
@@ -169,29 +232,41 @@ impl AsyncOdmManager {
         // 创建oneshot通道用于接收响应
         let (response_tx, response_rx) = oneshot::channel();
 
-        // 发送DatabaseOperation::FindWithGroupsWithBypassCache请求到连接池
-        let operation = DatabaseOperation::FindWithGroupsWithBypassCache {
-            table: collection.to_string(),
-            condition_groups,
-            options: options.unwrap_or_default(),
-            alias: actual_alias.clone(),
-            bypass_cache,
-            response: response_tx,
-        };
+        // 按查询选项的空值语义改写条件，保证各数据库行为一致
+        let mut options = options.unwrap_or_default();
+        // 展开模型的默认作用域与命名作用域
+        let scope_conditions = scopes::resolve_registered(collection, &actual_alias, &mut options)?;
+        let mut condition_groups = scopes::and_condition_groups(condition_groups, scope_conditions);
+        options.null_semantics.normalize_groups(&mut condition_groups);
 
-        connection_pool
-            .operation_sender
-            .send(operation)
-            .map_err(|_| QuickDbError::ConnectionError {
-                message: crate::i18n::t("odm.channel_closed"),
-            })?;
+        // 相同的并发查询共享同一次数据库调用
+        let key = dedup::groups_key(&actual_alias, collection, &condition_groups, &options, bypass_cache);
+        let connection_pool = connection_pool.clone();
+        let table = collection.to_string();
+        dedup::dedup_find(key, async move {
+            // 发送DatabaseOperation::FindWithGroupsWithBypassCache请求到连接池
+            let operation = DatabaseOperation::FindWithGroupsWithBypassCache {
+                table,
+                condition_groups,
+                options,
+                alias: actual_alias,
+                bypass_cache,
+                response: response_tx,
+            };
 
-        // 等待响应
-        response_rx
-            .await
-            .map_err(|_| QuickDbError::ConnectionError {
-                message: crate::i18n::t("odm.response_timeout"),
-            })?
+            connection_pool
+                .operation_sender
+                .send(operation)
+                .await?;
+
+            // 等待响应
+            response_rx
+                .await
+                .map_err(|_| QuickDbError::ConnectionError {
+                    message: crate::i18n::t("odm.response_timeout"),
+                })?
+        })
+        .await
     }
 
     /// 处理分组查询请求
@@ -205,3 +280,15 @@ impl AsyncOdmManager {
         Self::handle_find_with_groups_with_cache_control(collection, condition_groups, options, alias, false).await
     }
 }
+
+/// ODM 查询在连接池的自动提交连接上执行，行级锁随语句结束立即释放，不会产生预期的效果，
+/// 因此直接拒绝；需要加锁读取时使用租用连接（[`crate::manager::LeasedConnection::find`]）
+fn reject_row_lock(options: Option<&QueryOptions>) -> QuickDbResult<()> {
+    if options.is_some_and(|options| options.lock.is_some()) {
+        return Err(QuickDbError::ValidationError {
+            field: "lock".to_string(),
+            message: crate::i18n::t("odm.row_lock_requires_lease"),
+        });
+    }
+    Ok(())
+}
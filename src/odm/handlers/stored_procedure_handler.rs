@@ -2,7 +2,7 @@
 //!
 //! 处理存储过程相关的ODM请求
 
-use crate::error::{QuickDbError, QuickDbResult};
+use crate::error::QuickDbError;
 use crate::odm::manager_core::AsyncOdmManager;
 use crate::odm::types::OdmRequest;
 use rat_logger::{debug, error};
@@ -6,7 +6,7 @@ use crate::manager::get_global_pool_manager;
 use crate::odm::manager_core::AsyncOdmManager;
 use crate::pool::DatabaseOperation;
 use crate::types::*;
-use rat_logger::{debug, info, warn};
+use rat_logger::debug;
 use std::collections::HashMap;
 use tokio::sync::oneshot;
 
@@ -17,6 +17,7 @@ impl AsyncOdmManager {
         collection: &str,
         conditions: Vec<QueryConditionWithConfig>,
         updates: HashMap<String, DataValue>,
+        options: UpdateOptions,
         alias: Option<String>,
     ) -> QuickDbResult<u64> {
         let manager = get_global_pool_manager();
@@ -32,6 +33,9 @@ impl AsyncOdmManager {
             collection, actual_alias
         );
 
+        // 执行模型字段声明的自定义验证器（仅校验更新涉及的字段）
+        crate::model::validators::validate_registered(collection, &actual_alias, &updates).await?;
+
         let manager = get_global_pool_manager();
         let connection_pools = manager.get_connection_pools();
         let connection_pool =
@@ -49,6 +53,7 @@ impl AsyncOdmManager {
             table: collection.to_string(),
             conditions,
             data: updates,
+            options,
             alias: actual_alias.clone(),
             response: response_tx,
         };
@@ -56,9 +61,7 @@ impl AsyncOdmManager {
         connection_pool
             .operation_sender
             .send(operation)
-            .map_err(|_| QuickDbError::ConnectionError {
-                message: crate::i18n::t("odm.channel_closed"),
-            })?;
+            .await?;
 
         // 等待响应
         let affected_rows = response_rx
@@ -76,6 +79,7 @@ impl AsyncOdmManager {
         collection: &str,
         conditions: Vec<QueryConditionWithConfig>,
         operations: Vec<crate::types::UpdateOperation>,
+        options: UpdateOptions,
         alias: Option<String>,
     ) -> QuickDbResult<u64> {
         let manager = get_global_pool_manager();
@@ -91,6 +95,14 @@ impl AsyncOdmManager {
             collection, actual_alias
         );
 
+        // Set 操作直接写入字段值，同样执行字段声明的自定义验证器
+        let set_values: HashMap<String, DataValue> = operations
+            .iter()
+            .filter(|operation| matches!(operation.operation, UpdateOperator::Set))
+            .map(|operation| (operation.field.clone(), operation.value.clone()))
+            .collect();
+        crate::model::validators::validate_registered(collection, &actual_alias, &set_values).await?;
+
         let manager = get_global_pool_manager();
         let connection_pools = manager.get_connection_pools();
         let connection_pool =
@@ -108,6 +120,7 @@ impl AsyncOdmManager {
             table: collection.to_string(),
             conditions,
             operations,
+            options,
             alias: actual_alias.clone(),
             response: response_tx,
         };
@@ -115,9 +128,7 @@ impl AsyncOdmManager {
         connection_pool
             .operation_sender
             .send(operation)
-            .map_err(|_| QuickDbError::ConnectionError {
-                message: crate::i18n::t("odm.channel_closed"),
-            })?;
+            .await?;
 
         // 等待响应
         let affected_rows = response_rx
@@ -150,6 +161,9 @@ impl AsyncOdmManager {
             collection, id, actual_alias
         );
 
+        // 执行模型字段声明的自定义验证器（仅校验更新涉及的字段）
+        crate::model::validators::validate_registered(collection, &actual_alias, &updates).await?;
+
         let manager = get_global_pool_manager();
         let connection_pools = manager.get_connection_pools();
         let connection_pool =
@@ -174,9 +188,7 @@ impl AsyncOdmManager {
         connection_pool
             .operation_sender
             .send(operation)
-            .map_err(|_| QuickDbError::ConnectionError {
-                message: crate::i18n::t("odm.channel_closed"),
-            })?;
+            .await?;
 
         // 等待响应
         let result = response_rx
@@ -187,4 +199,57 @@ impl AsyncOdmManager {
 
         Ok(result)
     }
+
+    /// 处理根据ID更新并返回记录请求
+    #[doc(hidden)]
+    pub async fn handle_update_by_id_returning(
+        collection: &str,
+        id: &str,
+        updates: HashMap<String, DataValue>,
+        alias: Option<String>,
+    ) -> QuickDbResult<Option<DataValue>> {
+        let manager = get_global_pool_manager();
+        let actual_alias = match alias {
+            Some(a) => a,
+            None => manager
+                .get_default_alias()
+                .await
+                .unwrap_or_else(|| "default".to_string()),
+        };
+        debug!(
+            "处理根据ID更新并返回记录请求: collection={}, id={}, alias={}",
+            collection, id, actual_alias
+        );
+
+        crate::model::validators::validate_registered(collection, &actual_alias, &updates).await?;
+
+        let connection_pools = manager.get_connection_pools();
+        let connection_pool =
+            connection_pools
+                .get(&actual_alias)
+                .ok_or_else(|| QuickDbError::AliasNotFound {
+                    alias: actual_alias.clone(),
+                })?;
+
+        let (response_tx, response_rx) = oneshot::channel();
+
+        let operation = DatabaseOperation::UpdateByIdReturning {
+            table: collection.to_string(),
+            id: DataValue::String(id.to_string()),
+            data: updates,
+            alias: actual_alias.clone(),
+            response: response_tx,
+        };
+
+        connection_pool
+            .operation_sender
+            .send(operation)
+            .await?;
+
+        response_rx
+            .await
+            .map_err(|_| QuickDbError::ConnectionError {
+                message: crate::i18n::t("odm.response_timeout"),
+            })?
+    }
 }
@@ -5,7 +5,7 @@ use crate::error::{QuickDbError, QuickDbResult};
 use crate::manager::get_global_pool_manager;
 use crate::odm::manager_core::AsyncOdmManager;
 use crate::types::*;
-use rat_logger::{debug, error, info, warn};
+use rat_logger::{debug, warn};
 use std::collections::HashMap;
 use tokio::sync::oneshot;
 
@@ -34,6 +34,9 @@ impl AsyncOdmManager {
             collection, actual_alias, conflict_columns
         );
 
+        // 执行模型字段声明的自定义验证器
+        crate::model::validators::validate_registered(collection, &actual_alias, &data).await?;
+
         // 确保表和索引存在（基于注册的模型元数据）
         if let Err(e) = manager
             .ensure_table_and_indexes(collection, &actual_alias)
@@ -131,9 +134,7 @@ impl AsyncOdmManager {
         connection_pool
             .operation_sender
             .send(operation)
-            .map_err(|_| QuickDbError::ConnectionError {
-                message: crate::i18n::t("odm.channel_closed"),
-            })?;
+            .await?;
 
         // 等待响应
         let result = response_rx
@@ -3,24 +3,30 @@
 use crate::error::{QuickDbError, QuickDbResult};
 use crate::manager::get_global_pool_manager;
 use crate::odm::types::OdmRequest;
-use crate::types::*;
-use rat_logger::{debug, error, info, warn};
-use tokio::sync::{mpsc, oneshot};
+use crate::context::{RequestReceiver, RequestSender, in_context_scope, request_channel};
+use rat_logger::{debug, info, warn};
+use tokio::sync::oneshot;
 
 /// 异步ODM管理器 - 使用消息传递避免生命周期问题
 pub struct AsyncOdmManager {
     /// 请求发送器
-    pub(crate) request_sender: mpsc::UnboundedSender<OdmRequest>,
+    pub(crate) request_sender: RequestSender<OdmRequest>,
     /// 默认别名
     default_alias: String,
     /// 后台任务句柄（用于优雅关闭）
     _task_handle: Option<tokio::task::JoinHandle<()>>,
 }
 
+impl Default for AsyncOdmManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl AsyncOdmManager {
     /// 创建新的异步ODM管理器
     pub fn new() -> Self {
-        let (sender, receiver) = mpsc::unbounded_channel();
+        let (sender, receiver) = request_channel();
 
         // 启动后台处理任务
         let task_handle = tokio::spawn(Self::process_requests(receiver));
@@ -40,186 +46,227 @@ impl AsyncOdmManager {
         self.default_alias = alias.to_string();
     }
 
-    /// 获取实际使用的别名
-    fn get_actual_alias(&self, alias: Option<&str>) -> String {
-        alias.unwrap_or(&self.default_alias).to_string()
-    }
-
     /// 后台请求处理任务
-    async fn process_requests(mut receiver: mpsc::UnboundedReceiver<OdmRequest>) {
+    async fn process_requests(mut receiver: RequestReceiver<OdmRequest>) {
         info!("启动ODM后台处理任务");
 
-        while let Some(request) = receiver.recv().await {
-            match request {
-                OdmRequest::Create {
-                    collection,
-                    data,
-                    alias,
-                    response,
-                } => {
-                    let result = Self::handle_create(&collection, data, alias).await;
-                    let _ = response.send(result);
-                }
-                OdmRequest::FindById {
-                    collection,
-                    id,
-                    alias,
-                    response,
-                } => {
-                    let result = Self::handle_find_by_id(&collection, &id, alias).await;
-                    let _ = response.send(result);
-                }
-                OdmRequest::Find {
-                    collection,
-                    conditions,
-                    options,
-                    alias,
-                    response,
-                } => {
-                    let result = Self::handle_find_with_cache_control(&collection, conditions, options, alias, false).await;
-                    let _ = response.send(result);
-                }
-                OdmRequest::FindWithCacheControl {
-                    collection,
-                    conditions,
-                    options,
-                    alias,
-                    bypass_cache,
-                    response,
-                } => {
-                    let result = Self::handle_find_with_cache_control(&collection, conditions, options, alias, bypass_cache).await;
-                    let _ = response.send(result);
-                }
-                OdmRequest::FindWithGroups {
-                    collection,
-                    condition_groups,
-                    options,
-                    alias,
-                    response,
-                } => {
-                    let result = Self::handle_find_with_groups(&collection, condition_groups, options, alias).await;
-                    let _ = response.send(result);
-                }
-                OdmRequest::FindWithGroupsWithCacheControl {
-                    collection,
-                    condition_groups,
-                    options,
-                    alias,
-                    bypass_cache,
-                    response,
-                } => {
-                    let result = Self::handle_find_with_groups_with_cache_control(&collection, condition_groups, options, alias, bypass_cache).await;
-                    let _ = response.send(result);
-                }
-                OdmRequest::Update {
-                    collection,
-                    conditions,
-                    updates,
-                    alias,
-                    response,
-                } => {
-                    let result = Self::handle_update(&collection, conditions, updates, alias).await;
-                    let _ = response.send(result);
-                }
-                OdmRequest::UpdateWithOperations {
-                    collection,
+        while let Some((request, context)) = receiver.recv().await {
+            in_context_scope(context, Self::handle_request(request)).await;
+        }
+
+        warn!("ODM后台处理任务结束");
+    }
+
+    /// 处理单个请求并回复结果
+    async fn handle_request(request: OdmRequest) {
+        match request {
+            OdmRequest::Create {
+                collection,
+                data,
+                alias,
+                response,
+            } => {
+                let result = Self::handle_create(&collection, data, alias).await;
+                let _ = response.send(result);
+            }
+            OdmRequest::FindById {
+                collection,
+                id,
+                alias,
+                response,
+            } => {
+                let result = Self::handle_find_by_id(&collection, &id, alias).await;
+                let _ = response.send(result);
+            }
+            OdmRequest::ExistsById {
+                collection,
+                id,
+                alias,
+                response,
+            } => {
+                let result = Self::handle_exists_by_id(&collection, &id, alias).await;
+                let _ = response.send(result);
+            }
+            OdmRequest::Find {
+                collection,
+                conditions,
+                options,
+                alias,
+                response,
+            } => {
+                let result = Self::handle_find_with_cache_control(&collection, conditions, options, alias, false).await;
+                let _ = response.send(result);
+            }
+            OdmRequest::FindWithCacheControl {
+                collection,
+                conditions,
+                options,
+                alias,
+                bypass_cache,
+                response,
+            } => {
+                let result = Self::handle_find_with_cache_control(&collection, conditions, options, alias, bypass_cache).await;
+                let _ = response.send(result);
+            }
+            OdmRequest::FindWithGroups {
+                collection,
+                condition_groups,
+                options,
+                alias,
+                response,
+            } => {
+                let result = Self::handle_find_with_groups(&collection, condition_groups, options, alias).await;
+                let _ = response.send(result);
+            }
+            OdmRequest::FindWithGroupsWithCacheControl {
+                collection,
+                condition_groups,
+                options,
+                alias,
+                bypass_cache,
+                response,
+            } => {
+                let result = Self::handle_find_with_groups_with_cache_control(&collection, condition_groups, options, alias, bypass_cache).await;
+                let _ = response.send(result);
+            }
+            OdmRequest::Update {
+                collection,
+                conditions,
+                updates,
+                options,
+                alias,
+                response,
+            } => {
+                let result =
+                    Self::handle_update(&collection, conditions, updates, options, alias).await;
+                let _ = response.send(result);
+            }
+            OdmRequest::UpdateWithOperations {
+                collection,
+                conditions,
+                operations,
+                options,
+                alias,
+                response,
+            } => {
+                let result = Self::handle_update_with_operations(
+                    &collection,
                     conditions,
                     operations,
+                    options,
                     alias,
-                    response,
-                } => {
-                    let result = Self::handle_update_with_operations(
-                        &collection,
-                        conditions,
-                        operations,
-                        alias,
-                    )
-                    .await;
-                    let _ = response.send(result);
-                }
-                OdmRequest::UpdateById {
-                    collection,
-                    id,
-                    updates,
-                    alias,
-                    response,
-                } => {
-                    let result = Self::handle_update_by_id(&collection, &id, updates, alias).await;
-                    let _ = response.send(result);
-                }
-                OdmRequest::Upsert {
-                    collection,
-                    data,
-                    conflict_columns,
-                    alias,
-                    response,
-                } => {
-                    let result =
-                        Self::handle_upsert(&collection, data, conflict_columns, alias).await;
-                    let _ = response.send(result);
-                }
-                OdmRequest::Delete {
-                    collection,
-                    conditions,
-                    alias,
-                    response,
-                } => {
-                    let result = Self::handle_delete(&collection, conditions, alias).await;
-                    let _ = response.send(result);
-                }
-                OdmRequest::DeleteById {
-                    collection,
-                    id,
-                    alias,
-                    response,
-                } => {
-                    let result = Self::handle_delete_by_id(&collection, &id, alias).await;
-                    let _ = response.send(result);
-                }
-                OdmRequest::Count {
-                    collection,
-                    conditions,
-                    alias,
-                    response,
-                } => {
-                    let result = Self::handle_count(&collection, conditions, alias).await;
-                    let _ = response.send(result);
-                }
-                OdmRequest::CountWithGroups {
-                    collection,
-                    condition_groups,
-                    alias,
-                    response,
-                } => {
-                    let result = Self::handle_count_with_groups(&collection, condition_groups, alias).await;
-                    let _ = response.send(result);
-                }
-                OdmRequest::GetServerVersion { alias, response } => {
-                    let result = Self::handle_get_server_version(alias).await;
-                    let _ = response.send(result);
-                }
-                OdmRequest::CreateStoredProcedure { config, response } => {
-                    let result = Self::handle_create_stored_procedure(config).await;
-                    let _ = response.send(result);
-                }
-                OdmRequest::ExecuteStoredProcedure {
-                    procedure_name,
-                    database_alias,
+                )
+                .await;
+                let _ = response.send(result);
+            }
+            OdmRequest::UpdateById {
+                collection,
+                id,
+                updates,
+                alias,
+                response,
+            } => {
+                let result = Self::handle_update_by_id(&collection, &id, updates, alias).await;
+                let _ = response.send(result);
+            }
+            OdmRequest::UpdateByIdReturning {
+                collection,
+                id,
+                updates,
+                alias,
+                response,
+            } => {
+                let result = Self::handle_update_by_id_returning(&collection, &id, updates, alias).await;
+                let _ = response.send(result);
+            }
+            OdmRequest::Upsert {
+                collection,
+                data,
+                conflict_columns,
+                alias,
+                response,
+            } => {
+                let result =
+                    Self::handle_upsert(&collection, data, conflict_columns, alias).await;
+                let _ = response.send(result);
+            }
+            OdmRequest::Delete {
+                collection,
+                conditions,
+                alias,
+                response,
+            } => {
+                let result = Self::handle_delete(&collection, conditions, alias).await;
+                let _ = response.send(result);
+            }
+            OdmRequest::DeleteById {
+                collection,
+                id,
+                alias,
+                response,
+            } => {
+                let result = Self::handle_delete_by_id(&collection, &id, alias).await;
+                let _ = response.send(result);
+            }
+            OdmRequest::DeleteByIdReturning {
+                collection,
+                id,
+                alias,
+                response,
+            } => {
+                let result = Self::handle_delete_by_id_returning(&collection, &id, alias).await;
+                let _ = response.send(result);
+            }
+            OdmRequest::Count {
+                collection,
+                conditions,
+                alias,
+                response,
+            } => {
+                let result = Self::handle_count(&collection, conditions, alias).await;
+                let _ = response.send(result);
+            }
+            OdmRequest::CountWithGroups {
+                collection,
+                condition_groups,
+                alias,
+                response,
+            } => {
+                let result = Self::handle_count_with_groups(&collection, condition_groups, alias).await;
+                let _ = response.send(result);
+            }
+            OdmRequest::GetServerVersion { alias, response } => {
+                let result = Self::handle_get_server_version(alias).await;
+                let _ = response.send(result);
+            }
+            OdmRequest::CreateStoredProcedure { config, response } => {
+                let result = Self::handle_create_stored_procedure(config).await;
+                let _ = response.send(result);
+            }
+            OdmRequest::ExecuteStoredProcedure {
+                procedure_name,
+                database_alias,
+                params,
+                response,
+            } => {
+                let result = Self::handle_execute_stored_procedure(
+                    &procedure_name,
+                    database_alias.as_deref(),
                     params,
-                    response,
-                } => {
-                    let result = Self::handle_execute_stored_procedure(
-                        &procedure_name,
-                        database_alias.as_deref(),
-                        params,
-                    )
-                    .await;
-                    let _ = response.send(result);
-                }
+                )
+                .await;
+                let _ = response.send(result);
+            }
+            OdmRequest::DropStoredProcedure {
+                procedure_name,
+                database_alias,
+                response,
+            } => {
+                let result =
+                    Self::handle_drop_stored_procedure(&procedure_name, database_alias.as_deref()).await;
+                let _ = response.send(result);
             }
-        }
-
-        warn!("ODM后台处理任务结束");
+    }
     }
 
     /// 处理存储过程创建请求
@@ -247,7 +294,8 @@ impl AsyncOdmManager {
         // 创建oneshot通道用于接收响应
         let (response_tx, response_rx) = oneshot::channel();
 
-        // 创建适配器操作请求
+        // 创建适配器操作请求，成功后登记配置供带类型的执行使用
+        let registered = config.clone();
         let operation = crate::pool::DatabaseOperation::CreateStoredProcedure {
             config,
             response: response_tx,
@@ -257,9 +305,7 @@ impl AsyncOdmManager {
         connection_pool
             .operation_sender
             .send(operation)
-            .map_err(|_| QuickDbError::ConnectionError {
-                message: crate::i18n::t("odm.channel_closed"),
-            })?;
+            .await?;
 
         // 等待响应
         let result = response_rx
@@ -268,9 +314,56 @@ impl AsyncOdmManager {
                 message: crate::i18n::t("odm.response_timeout"),
             })??;
 
+        if result.success {
+            manager.register_stored_procedure(registered);
+        }
         Ok(result)
     }
 
+    /// 处理存储过程删除请求
+    #[doc(hidden)]
+    pub async fn handle_drop_stored_procedure(procedure_name: &str, alias: Option<&str>) -> QuickDbResult<bool> {
+        let manager = get_global_pool_manager();
+        let database_alias = match alias {
+            Some(a) => a.to_string(),
+            None => manager
+                .get_default_alias()
+                .await
+                .unwrap_or_else(|| "default".to_string()),
+        };
+
+        debug!(
+            "处理存储过程删除请求: procedure={}, database={}",
+            procedure_name, database_alias
+        );
+
+        let connection_pools = manager.get_connection_pools();
+        let connection_pool =
+            connection_pools
+                .get(&database_alias)
+                .ok_or_else(|| QuickDbError::AliasNotFound {
+                    alias: database_alias.clone(),
+                })?;
+
+        let (response_tx, response_rx) = oneshot::channel();
+        let operation = crate::pool::DatabaseOperation::DropStoredProcedure {
+            procedure_name: procedure_name.to_string(),
+            response: response_tx,
+        };
+        connection_pool
+            .operation_sender
+            .send(operation)
+            .await?;
+        let removed = response_rx
+            .await
+            .map_err(|_| QuickDbError::ConnectionError {
+                message: crate::i18n::t("odm.response_timeout"),
+            })??;
+
+        let registered = manager.remove_stored_procedure(&database_alias, procedure_name);
+        Ok(removed || registered)
+    }
+
     /// 处理存储过程执行请求
     #[doc(hidden)]
     pub async fn handle_execute_stored_procedure(
@@ -320,9 +413,7 @@ impl AsyncOdmManager {
         connection_pool
             .operation_sender
             .send(operation)
-            .map_err(|_| QuickDbError::ConnectionError {
-                message: crate::i18n::t("odm.channel_closed"),
-            })?;
+            .await?;
 
         // 等待响应
         let result = response_rx
@@ -17,7 +17,26 @@ pub mod operations;
 // 全局管理器模块
 pub mod global;
 
+// 写操作演练模块
+pub mod dry_run;
+
+// 跨数据库联合查询模块
+pub mod federated;
+
+// 条件更新模块
+pub mod conditional;
+
+pub mod bulk;
+
+// 并发查询去重模块
+pub mod dedup;
+
 // 重新导出所有公共类型以保持API兼容性
+pub use dry_run::{DryRunOperation, DryRunReport, is_dry_run, with_dry_run};
+pub use bulk::bulk_write;
+pub use conditional::update_if;
+pub use dedup::{is_query_dedup_enabled, set_query_dedup};
+pub use federated::find_multi;
 pub use global::*;
 pub use handlers::*;
 pub use manager_core::AsyncOdmManager;
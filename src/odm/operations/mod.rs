@@ -5,4 +5,3 @@
 pub mod odm_operations_impl;
 
 // 重新导出所有操作实现以保持API兼容性
-pub use odm_operations_impl::*;
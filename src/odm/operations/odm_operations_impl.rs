@@ -1,6 +1,10 @@
 //! # ODM操作接口实现
 
+use crate::adapter::render::WriteStatement;
+use crate::audit::{AuditAction, AuditContext};
 use crate::error::{QuickDbError, QuickDbResult};
+use crate::manager::{PoolManager, get_global_pool_manager};
+use crate::odm::dry_run::{self, is_dry_run};
 use crate::odm::manager_core::AsyncOdmManager;
 use crate::odm::traits::OdmOperations;
 use crate::odm::types::OdmRequest;
@@ -18,6 +22,8 @@ impl OdmOperations for AsyncOdmManager {
         data: HashMap<String, DataValue>,
         alias: Option<&str>,
     ) -> QuickDbResult<DataValue> {
+        let audit = AuditContext::resolve(collection, alias).await;
+
         let (sender, receiver) = oneshot::channel();
 
         let request = OdmRequest::Create {
@@ -33,9 +39,14 @@ impl OdmOperations for AsyncOdmManager {
                 message: crate::i18n::t("odm.task_stopped"),
             })?;
 
-        receiver.await.map_err(|_| QuickDbError::ConnectionError {
+        let result = receiver.await.map_err(|_| QuickDbError::ConnectionError {
             message: crate::i18n::t("odm.request_failed"),
-        })?
+        })??;
+
+        if let Some(audit) = audit {
+            audit.record_create(self, &result).await;
+        }
+        Ok(result)
     }
 
     async fn find_by_id(
@@ -64,6 +75,32 @@ impl OdmOperations for AsyncOdmManager {
         })?
     }
 
+    async fn exists_by_id(
+        &self,
+        collection: &str,
+        id: &str,
+        alias: Option<&str>,
+    ) -> QuickDbResult<bool> {
+        let (sender, receiver) = oneshot::channel();
+
+        let request = OdmRequest::ExistsById {
+            collection: collection.to_string(),
+            id: id.to_string(),
+            alias: alias.map(|s| s.to_string()),
+            response: sender,
+        };
+
+        self.request_sender
+            .send(request)
+            .map_err(|_| QuickDbError::ConnectionError {
+                message: crate::i18n::t("odm.task_stopped"),
+            })?;
+
+        receiver.await.map_err(|_| QuickDbError::ConnectionError {
+            message: crate::i18n::t("odm.request_failed"),
+        })?
+    }
+
     async fn find(
         &self,
         collection: &str,
@@ -82,6 +119,7 @@ impl OdmOperations for AsyncOdmManager {
         alias: Option<&str>,
         bypass_cache: bool,
     ) -> QuickDbResult<Vec<DataValue>> {
+        let (options, reject_above) = get_global_pool_manager().apply_row_limit(alias, options).await;
         let (sender, receiver) = oneshot::channel();
 
         let request = OdmRequest::FindWithCacheControl {
@@ -99,9 +137,12 @@ impl OdmOperations for AsyncOdmManager {
                 message: crate::i18n::t("odm.task_stopped"),
             })?;
 
-        receiver.await.map_err(|_| QuickDbError::ConnectionError {
+        let result = receiver.await.map_err(|_| QuickDbError::ConnectionError {
             message: crate::i18n::t("odm.request_failed"),
-        })?
+        })??;
+
+        PoolManager::check_row_limit(collection, result.len(), reject_above)?;
+        Ok(result)
     }
 
     async fn find_with_groups(
@@ -147,6 +188,7 @@ impl OdmOperations for AsyncOdmManager {
         alias: Option<&str>,
         bypass_cache: bool,
     ) -> QuickDbResult<Vec<DataValue>> {
+        let (options, reject_above) = get_global_pool_manager().apply_row_limit(alias, options).await;
         let (sender, receiver) = oneshot::channel();
 
         let request = OdmRequest::FindWithGroupsWithCacheControl {
@@ -164,24 +206,39 @@ impl OdmOperations for AsyncOdmManager {
                 message: crate::i18n::t("odm.task_stopped"),
             })?;
 
-        receiver.await.map_err(|_| QuickDbError::ConnectionError {
+        let result = receiver.await.map_err(|_| QuickDbError::ConnectionError {
             message: crate::i18n::t("odm.request_failed"),
-        })?
+        })??;
+
+        PoolManager::check_row_limit(collection, result.len(), reject_above)?;
+        Ok(result)
     }
 
-    async fn update(
+    async fn update_with_options(
         &self,
         collection: &str,
         conditions: Vec<QueryConditionWithConfig>,
         updates: HashMap<String, DataValue>,
+        options: UpdateOptions,
         alias: Option<&str>,
     ) -> QuickDbResult<u64> {
+        if options.dry_run || is_dry_run() {
+            return dry_run::rehearse(self, collection, alias, conditions, WriteStatement::Update(&updates)).await;
+        }
+
+        let audit = AuditContext::resolve(collection, alias).await;
+        let before = match &audit {
+            Some(audit) => audit.snapshot(self, &conditions).await,
+            None => Vec::new(),
+        };
+
         let (sender, receiver) = oneshot::channel();
 
         let request = OdmRequest::Update {
             collection: collection.to_string(),
             conditions,
             updates,
+            options,
             alias: alias.map(|s| s.to_string()),
             response: sender,
         };
@@ -192,24 +249,41 @@ impl OdmOperations for AsyncOdmManager {
                 message: crate::i18n::t("odm.task_stopped"),
             })?;
 
-        receiver.await.map_err(|_| QuickDbError::ConnectionError {
+        let result = receiver.await.map_err(|_| QuickDbError::ConnectionError {
             message: crate::i18n::t("odm.request_failed"),
-        })?
+        })??;
+
+        if let Some(audit) = audit {
+            audit.record_updates(self, AuditAction::Update, before).await;
+        }
+        Ok(result)
     }
 
-    async fn update_with_operations(
+    async fn update_with_operations_and_options(
         &self,
         collection: &str,
         conditions: Vec<QueryConditionWithConfig>,
         operations: Vec<crate::types::UpdateOperation>,
+        options: UpdateOptions,
         alias: Option<&str>,
     ) -> QuickDbResult<u64> {
+        if options.dry_run || is_dry_run() {
+            return dry_run::rehearse(self, collection, alias, conditions, WriteStatement::UpdateOperations(&operations)).await;
+        }
+
+        let audit = AuditContext::resolve(collection, alias).await;
+        let before = match &audit {
+            Some(audit) => audit.snapshot(self, &conditions).await,
+            None => Vec::new(),
+        };
+
         let (sender, receiver) = oneshot::channel();
 
         let request = OdmRequest::UpdateWithOperations {
             collection: collection.to_string(),
             conditions,
             operations,
+            options,
             alias: alias.map(|s| s.to_string()),
             response: sender,
         };
@@ -220,9 +294,14 @@ impl OdmOperations for AsyncOdmManager {
                 message: crate::i18n::t("odm.task_stopped"),
             })?;
 
-        receiver.await.map_err(|_| QuickDbError::ConnectionError {
+        let result = receiver.await.map_err(|_| QuickDbError::ConnectionError {
             message: crate::i18n::t("odm.request_failed"),
-        })?
+        })??;
+
+        if let Some(audit) = audit {
+            audit.record_updates(self, AuditAction::Update, before).await;
+        }
+        Ok(result)
     }
 
     async fn update_by_id(
@@ -232,6 +311,17 @@ impl OdmOperations for AsyncOdmManager {
         updates: HashMap<String, DataValue>,
         alias: Option<&str>,
     ) -> QuickDbResult<bool> {
+        if is_dry_run() {
+            let conditions = vec![dry_run::id_condition(id)];
+            return Ok(dry_run::rehearse(self, collection, alias, conditions, WriteStatement::Update(&updates)).await? > 0);
+        }
+
+        let audit = AuditContext::resolve(collection, alias).await;
+        let before = match &audit {
+            Some(audit) => audit.snapshot_by_id(self, id).await.into_iter().collect(),
+            None => Vec::new(),
+        };
+
         let (sender, receiver) = oneshot::channel();
 
         let request = OdmRequest::UpdateById {
@@ -248,9 +338,68 @@ impl OdmOperations for AsyncOdmManager {
                 message: crate::i18n::t("odm.task_stopped"),
             })?;
 
-        receiver.await.map_err(|_| QuickDbError::ConnectionError {
+        let result = receiver.await.map_err(|_| QuickDbError::ConnectionError {
             message: crate::i18n::t("odm.request_failed"),
-        })?
+        })??;
+
+        if let Some(audit) = audit {
+            audit.record_updates(self, AuditAction::Update, before).await;
+        }
+        Ok(result)
+    }
+
+    async fn update_by_id_returning(
+        &self,
+        collection: &str,
+        id: &str,
+        updates: HashMap<String, DataValue>,
+        alias: Option<&str>,
+    ) -> QuickDbResult<Option<DataValue>> {
+        if is_dry_run() {
+            // 演练不写入数据库，返回当前记录叠加更新字段后的结果
+            let conditions = vec![dry_run::id_condition(id)];
+            if dry_run::rehearse(self, collection, alias, conditions, WriteStatement::Update(&updates)).await? == 0 {
+                return Ok(None);
+            }
+            return Ok(match self.find_by_id(collection, id, alias).await? {
+                Some(DataValue::Object(mut record)) => {
+                    record.extend(updates);
+                    Some(DataValue::Object(record))
+                }
+                other => other,
+            });
+        }
+
+        let audit = AuditContext::resolve(collection, alias).await;
+        let before = match &audit {
+            Some(audit) => audit.snapshot_by_id(self, id).await.into_iter().collect(),
+            None => Vec::new(),
+        };
+
+        let (sender, receiver) = oneshot::channel();
+
+        let request = OdmRequest::UpdateByIdReturning {
+            collection: collection.to_string(),
+            id: id.to_string(),
+            updates,
+            alias: alias.map(|s| s.to_string()),
+            response: sender,
+        };
+
+        self.request_sender
+            .send(request)
+            .map_err(|_| QuickDbError::ConnectionError {
+                message: crate::i18n::t("odm.task_stopped"),
+            })?;
+
+        let result = receiver.await.map_err(|_| QuickDbError::ConnectionError {
+            message: crate::i18n::t("odm.request_failed"),
+        })??;
+
+        if let Some(audit) = audit {
+            audit.record_updates(self, AuditAction::Update, before).await;
+        }
+        Ok(result)
     }
 
     async fn upsert(
@@ -260,6 +409,24 @@ impl OdmOperations for AsyncOdmManager {
         conflict_columns: Vec<String>,
         alias: Option<&str>,
     ) -> QuickDbResult<DataValue> {
+        let audit = AuditContext::resolve(collection, alias).await;
+        // 按冲突列定位被插入或更新的记录
+        let conflict_conditions: Vec<QueryConditionWithConfig> = conflict_columns
+            .iter()
+            .filter_map(|column| {
+                data.get(column).map(|value| QueryConditionWithConfig {
+                    field: column.clone(),
+                    operator: QueryOperator::Eq,
+                    value: value.clone(),
+                    case_insensitive: false,
+                })
+            })
+            .collect();
+        let before = match &audit {
+            Some(audit) => audit.snapshot(self, &conflict_conditions).await,
+            None => Vec::new(),
+        };
+
         let (sender, receiver) = oneshot::channel();
 
         let request = OdmRequest::Upsert {
@@ -276,9 +443,21 @@ impl OdmOperations for AsyncOdmManager {
                 message: crate::i18n::t("odm.task_stopped"),
             })?;
 
-        receiver.await.map_err(|_| QuickDbError::ConnectionError {
+        let result = receiver.await.map_err(|_| QuickDbError::ConnectionError {
             message: crate::i18n::t("odm.request_failed"),
-        })?
+        })??;
+
+        if let Some(audit) = audit {
+            if before.is_empty() {
+                for record in audit.snapshot(self, &conflict_conditions).await {
+                    let record_id = audit.record_id(&record);
+                    audit.write(self, AuditAction::Upsert, record_id, None, Some(&record)).await;
+                }
+            } else {
+                audit.record_updates(self, AuditAction::Upsert, before).await;
+            }
+        }
+        Ok(result)
     }
 
     async fn delete(
@@ -287,6 +466,20 @@ impl OdmOperations for AsyncOdmManager {
         conditions: Vec<QueryConditionWithConfig>,
         alias: Option<&str>,
     ) -> QuickDbResult<u64> {
+        get_global_pool_manager()
+            .check_delete_conditions(collection, alias, !conditions.is_empty())
+            .await?;
+
+        if is_dry_run() {
+            return dry_run::rehearse(self, collection, alias, conditions, WriteStatement::Delete).await;
+        }
+
+        let audit = AuditContext::resolve(collection, alias).await;
+        let before = match &audit {
+            Some(audit) => audit.snapshot(self, &conditions).await,
+            None => Vec::new(),
+        };
+
         let (sender, receiver) = oneshot::channel();
 
         let request = OdmRequest::Delete {
@@ -302,9 +495,14 @@ impl OdmOperations for AsyncOdmManager {
                 message: crate::i18n::t("odm.task_stopped"),
             })?;
 
-        receiver.await.map_err(|_| QuickDbError::ConnectionError {
+        let result = receiver.await.map_err(|_| QuickDbError::ConnectionError {
             message: crate::i18n::t("odm.request_failed"),
-        })?
+        })??;
+
+        if let Some(audit) = audit {
+            audit.record_deletes(self, before).await;
+        }
+        Ok(result)
     }
 
     async fn delete_by_id(
@@ -313,6 +511,17 @@ impl OdmOperations for AsyncOdmManager {
         id: &str,
         alias: Option<&str>,
     ) -> QuickDbResult<bool> {
+        if is_dry_run() {
+            let conditions = vec![dry_run::id_condition(id)];
+            return Ok(dry_run::rehearse(self, collection, alias, conditions, WriteStatement::Delete).await? > 0);
+        }
+
+        let audit = AuditContext::resolve(collection, alias).await;
+        let before = match &audit {
+            Some(audit) => audit.snapshot_by_id(self, id).await.into_iter().collect(),
+            None => Vec::new(),
+        };
+
         let (sender, receiver) = oneshot::channel();
 
         let request = OdmRequest::DeleteById {
@@ -328,9 +537,60 @@ impl OdmOperations for AsyncOdmManager {
                 message: crate::i18n::t("odm.task_stopped"),
             })?;
 
-        receiver.await.map_err(|_| QuickDbError::ConnectionError {
+        let result = receiver.await.map_err(|_| QuickDbError::ConnectionError {
             message: crate::i18n::t("odm.request_failed"),
-        })?
+        })??;
+
+        if let Some(audit) = audit {
+            audit.record_deletes(self, before).await;
+        }
+        Ok(result)
+    }
+
+    async fn delete_by_id_returning(
+        &self,
+        collection: &str,
+        id: &str,
+        alias: Option<&str>,
+    ) -> QuickDbResult<Option<DataValue>> {
+        if is_dry_run() {
+            // 演练不写入数据库，返回将被删除的当前记录
+            let conditions = vec![dry_run::id_condition(id)];
+            if dry_run::rehearse(self, collection, alias, conditions, WriteStatement::Delete).await? == 0 {
+                return Ok(None);
+            }
+            return self.find_by_id(collection, id, alias).await;
+        }
+
+        let audit = AuditContext::resolve(collection, alias).await;
+        let before = match &audit {
+            Some(audit) => audit.snapshot_by_id(self, id).await.into_iter().collect(),
+            None => Vec::new(),
+        };
+
+        let (sender, receiver) = oneshot::channel();
+
+        let request = OdmRequest::DeleteByIdReturning {
+            collection: collection.to_string(),
+            id: id.to_string(),
+            alias: alias.map(|s| s.to_string()),
+            response: sender,
+        };
+
+        self.request_sender
+            .send(request)
+            .map_err(|_| QuickDbError::ConnectionError {
+                message: crate::i18n::t("odm.task_stopped"),
+            })?;
+
+        let result = receiver.await.map_err(|_| QuickDbError::ConnectionError {
+            message: crate::i18n::t("odm.request_failed"),
+        })??;
+
+        if let Some(audit) = audit {
+            audit.record_deletes(self, before).await;
+        }
+        Ok(result)
     }
 
     async fn count(
@@ -451,4 +711,28 @@ impl OdmOperations for AsyncOdmManager {
             message: crate::i18n::t("odm.request_failed"),
         })?
     }
+
+    async fn drop_stored_procedure(
+        &self,
+        procedure_name: &str,
+        database_alias: Option<&str>,
+    ) -> QuickDbResult<bool> {
+        let (sender, receiver) = oneshot::channel();
+
+        let request = OdmRequest::DropStoredProcedure {
+            procedure_name: procedure_name.to_string(),
+            database_alias: database_alias.map(|s| s.to_string()),
+            response: sender,
+        };
+
+        self.request_sender
+            .send(request)
+            .map_err(|_| QuickDbError::ConnectionError {
+                message: crate::i18n::t("odm.task_stopped"),
+            })?;
+
+        receiver.await.map_err(|_| QuickDbError::ConnectionError {
+            message: crate::i18n::t("odm.request_failed"),
+        })?
+    }
 }
@@ -4,7 +4,6 @@ use crate::error::QuickDbResult;
 use crate::types::*;
 use async_trait::async_trait;
 use std::collections::HashMap;
-use tokio::sync::oneshot;
 
 /// ODM操作接口
 #[async_trait]
@@ -25,6 +24,14 @@ pub trait OdmOperations {
         alias: Option<&str>,
     ) -> QuickDbResult<Option<DataValue>>;
 
+    /// 根据ID检查记录是否存在
+    async fn exists_by_id(
+        &self,
+        collection: &str,
+        id: &str,
+        alias: Option<&str>,
+    ) -> QuickDbResult<bool>;
+
     /// 查找记录
     async fn find(
         &self,
@@ -101,6 +108,18 @@ pub trait OdmOperations {
         conditions: Vec<QueryConditionWithConfig>,
         updates: HashMap<String, DataValue>,
         alias: Option<&str>,
+    ) -> QuickDbResult<u64> {
+        self.update_with_options(collection, conditions, updates, UpdateOptions::default(), alias).await
+    }
+
+    /// 更新记录（支持超时等更新选项）
+    async fn update_with_options(
+        &self,
+        collection: &str,
+        conditions: Vec<QueryConditionWithConfig>,
+        updates: HashMap<String, DataValue>,
+        options: UpdateOptions,
+        alias: Option<&str>,
     ) -> QuickDbResult<u64>;
 
     /// 使用操作数组更新记录
@@ -110,6 +129,18 @@ pub trait OdmOperations {
         conditions: Vec<QueryConditionWithConfig>,
         operations: Vec<crate::types::UpdateOperation>,
         alias: Option<&str>,
+    ) -> QuickDbResult<u64> {
+        self.update_with_operations_and_options(collection, conditions, operations, UpdateOptions::default(), alias).await
+    }
+
+    /// 使用操作数组更新记录（支持超时等更新选项）
+    async fn update_with_operations_and_options(
+        &self,
+        collection: &str,
+        conditions: Vec<QueryConditionWithConfig>,
+        operations: Vec<crate::types::UpdateOperation>,
+        options: UpdateOptions,
+        alias: Option<&str>,
     ) -> QuickDbResult<u64>;
 
     /// 根据ID更新记录
@@ -121,6 +152,15 @@ pub trait OdmOperations {
         alias: Option<&str>,
     ) -> QuickDbResult<bool>;
 
+    /// 根据ID更新记录并返回更新后的记录，记录不存在时返回 `None`
+    async fn update_by_id_returning(
+        &self,
+        collection: &str,
+        id: &str,
+        updates: HashMap<String, DataValue>,
+        alias: Option<&str>,
+    ) -> QuickDbResult<Option<DataValue>>;
+
     /// Upsert记录 - 如果记录存在则更新，否则插入新记录
     async fn upsert(
         &self,
@@ -146,6 +186,14 @@ pub trait OdmOperations {
         alias: Option<&str>,
     ) -> QuickDbResult<bool>;
 
+    /// 根据ID删除记录并返回被删除的记录，记录不存在时返回 `None`
+    async fn delete_by_id_returning(
+        &self,
+        collection: &str,
+        id: &str,
+        alias: Option<&str>,
+    ) -> QuickDbResult<Option<DataValue>>;
+
     /// 统计记录数量
     async fn count(
         &self,
@@ -192,6 +240,13 @@ pub trait OdmOperations {
         database_alias: Option<&str>,
         params: Option<std::collections::HashMap<String, crate::types::DataValue>>,
     ) -> QuickDbResult<crate::stored_procedure::StoredProcedureQueryResult>;
+
+    /// 删除存储过程，返回是否存在
+    async fn drop_stored_procedure(
+        &self,
+        procedure_name: &str,
+        database_alias: Option<&str>,
+    ) -> QuickDbResult<bool>;
 }
 
 // 重新导出OdmRequest类型
@@ -20,6 +20,12 @@ pub enum OdmRequest {
         alias: Option<String>,
         response: oneshot::Sender<QuickDbResult<Option<DataValue>>>,
     },
+    ExistsById {
+        collection: String,
+        id: String,
+        alias: Option<String>,
+        response: oneshot::Sender<QuickDbResult<bool>>,
+    },
     Find {
         collection: String,
         conditions: Vec<QueryConditionWithConfig>,
@@ -54,6 +60,7 @@ pub enum OdmRequest {
         collection: String,
         conditions: Vec<QueryConditionWithConfig>,
         updates: HashMap<String, DataValue>,
+        options: UpdateOptions,
         alias: Option<String>,
         response: oneshot::Sender<QuickDbResult<u64>>,
     },
@@ -61,6 +68,7 @@ pub enum OdmRequest {
         collection: String,
         conditions: Vec<QueryConditionWithConfig>,
         operations: Vec<crate::types::UpdateOperation>,
+        options: UpdateOptions,
         alias: Option<String>,
         response: oneshot::Sender<QuickDbResult<u64>>,
     },
@@ -71,6 +79,14 @@ pub enum OdmRequest {
         alias: Option<String>,
         response: oneshot::Sender<QuickDbResult<bool>>,
     },
+    /// 根据ID更新记录并返回更新后的记录
+    UpdateByIdReturning {
+        collection: String,
+        id: String,
+        updates: HashMap<String, DataValue>,
+        alias: Option<String>,
+        response: oneshot::Sender<QuickDbResult<Option<DataValue>>>,
+    },
     /// Upsert记录 - 如果记录存在则更新，否则插入
     Upsert {
         collection: String,
@@ -91,6 +107,13 @@ pub enum OdmRequest {
         alias: Option<String>,
         response: oneshot::Sender<QuickDbResult<bool>>,
     },
+    /// 根据ID删除记录并返回被删除的记录
+    DeleteByIdReturning {
+        collection: String,
+        id: String,
+        alias: Option<String>,
+        response: oneshot::Sender<QuickDbResult<Option<DataValue>>>,
+    },
     Count {
         collection: String,
         conditions: Vec<QueryConditionWithConfig>,
@@ -119,4 +142,9 @@ pub enum OdmRequest {
         response:
             oneshot::Sender<QuickDbResult<crate::stored_procedure::StoredProcedureQueryResult>>,
     },
+    DropStoredProcedure {
+        procedure_name: String,
+        database_alias: Option<String>,
+        response: oneshot::Sender<QuickDbResult<bool>>,
+    },
 }
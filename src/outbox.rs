@@ -0,0 +1,632 @@
+//! # 事务性发件箱模块
+//!
+//! 业务写入与待发布事件在同一事务中写入 `_rqdb_outbox` 表，提交成功后由中继任务轮询发件箱，
+//! 把事件交给调用方提供的发布回调，发布成功后删除事件。事件至少投递一次：
+//! 发布成功但删除失败、或多个中继同时运行时事件可能重复投递，消费端应按事件 ID 去重。
+//!
+//! ```ignore
+//! let mut tx = outbox::begin("default").await?;
+//! tx.create("orders", order_data).await?;
+//! tx.publish("order.created", serde_json::json!({ "order_id": order_id })).await?;
+//! tx.commit().await?;
+//!
+//! let relay = OutboxRelay::new("default", |event: OutboxEvent| async move {
+//!     kafka.send(&event.topic, event.payload.to_string()).await
+//! })
+//! .start();
+//! ```
+//!
+//! 事件写入时记录当前 [`crate::context::OperationContext`]，发布回调可据此关联发起请求。
+//! 支持 SQLite、PostgreSQL 与 MySQL。事务内的写入直接执行 SQL，数据原样写入，
+//! 不经过 ODM 的 ID 生成、字段默认值与校验；提交后清理涉及表的缓存。
+//! 多实例部署时可用 [`OutboxRelay::into_job`] 把中继交给 [`crate::scheduler`] 只在主节点运行
+
+use crate::adapter::transaction::TransactionWrite;
+use crate::context::{OperationContext, current_context};
+use crate::error::{QuickDbError, QuickDbResult};
+use crate::pool::DatabaseConnection;
+use crate::scheduler::{ScheduledJob, wait_for_shutdown};
+use crate::types::*;
+use chrono::{DateTime, Utc};
+use dashmap::DashSet;
+use once_cell::sync::Lazy;
+use rat_logger::{debug, info, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+
+/// 发件箱表名
+pub const OUTBOX_TABLE: &str = "_rqdb_outbox";
+
+/// 中继每轮默认读取的事件数
+pub const DEFAULT_BATCH_SIZE: u32 = 100;
+
+/// 中继默认轮询间隔
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// 已确保发件箱表存在的数据库别名
+static READY_ALIASES: Lazy<DashSet<String>> = Lazy::new(DashSet::new);
+
+/// 移除数据库别名时清除登记，重新添加同名数据库后再次检查发件箱表
+pub(crate) fn forget_alias(alias: &str) {
+    READY_ALIASES.remove(alias);
+}
+
+/// 发件箱事件
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OutboxEvent {
+    /// 事件 ID（UUID），可用于消费端去重
+    pub id: String,
+    /// 事件主题
+    pub topic: String,
+    /// 事件内容
+    pub payload: serde_json::Value,
+    /// 写入时间
+    pub created_at: DateTime<Utc>,
+    /// 此前发布失败的次数
+    pub attempts: u32,
+    /// 写入事件时的操作上下文
+    #[serde(default)]
+    pub context: OperationContext,
+}
+
+impl OutboxEvent {
+    fn from_row(id: String, topic: String, payload: String, created_at: i64, attempts: i32) -> Self {
+        Self {
+            id,
+            topic,
+            // 非 JSON 内容（如手工写入的行）按字符串交给发布回调
+            payload: serde_json::from_str(&payload).unwrap_or(serde_json::Value::String(payload)),
+            created_at: DateTime::from_timestamp_millis(created_at).unwrap_or_default(),
+            attempts: attempts.max(0) as u32,
+            context: OperationContext::default(),
+        }
+    }
+}
+
+/// 开始一个发件箱事务
+pub async fn begin(alias: &str) -> QuickDbResult<OutboxTransaction> {
+    let pool = OutboxPool::open(alias).await?;
+    let transaction = match &pool {
+        #[cfg(feature = "sqlite-support")]
+        OutboxPool::Sqlite(pool) => OutboxTx::Sqlite(pool.begin().await.map_err(query_failed)?),
+        #[cfg(feature = "postgres-support")]
+        OutboxPool::Postgres(pool) => OutboxTx::Postgres(pool.begin().await.map_err(query_failed)?),
+        #[cfg(feature = "mysql-support")]
+        OutboxPool::MySql(pool) => OutboxTx::MySql(pool.begin().await.map_err(query_failed)?),
+    };
+    Ok(OutboxTransaction {
+        alias: alias.to_string(),
+        transaction,
+        touched_tables: HashSet::new(),
+    })
+}
+
+/// 发件箱事务，丢弃而未提交时回滚
+pub struct OutboxTransaction {
+    alias: String,
+    transaction: OutboxTx,
+    touched_tables: HashSet<String>,
+}
+
+impl std::fmt::Debug for OutboxTransaction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OutboxTransaction")
+            .field("alias", &self.alias)
+            .field("touched_tables", &self.touched_tables)
+            .finish_non_exhaustive()
+    }
+}
+
+impl OutboxTransaction {
+    /// 在事务中插入记录，返回受影响行数
+    pub async fn create(&mut self, table: &str, data: HashMap<String, DataValue>) -> QuickDbResult<u64> {
+        self.write(table, TransactionWrite::Insert(data)).await
+    }
+
+    /// 在事务中按条件更新记录，返回受影响行数
+    pub async fn update(
+        &mut self,
+        table: &str,
+        conditions: Vec<QueryConditionWithConfig>,
+        data: HashMap<String, DataValue>,
+    ) -> QuickDbResult<u64> {
+        self.write(table, TransactionWrite::Update { conditions, data }).await
+    }
+
+    /// 在事务中按条件删除记录，返回受影响行数
+    pub async fn delete(&mut self, table: &str, conditions: Vec<QueryConditionWithConfig>) -> QuickDbResult<u64> {
+        self.write(table, TransactionWrite::Delete(conditions)).await
+    }
+
+    /// 在事务中写入待发布事件，返回事件 ID
+    pub async fn publish(&mut self, topic: &str, payload: serde_json::Value) -> QuickDbResult<String> {
+        let id = uuid::Uuid::new_v4().to_string();
+        let payload = payload.to_string();
+        let created_at = Utc::now().timestamp_millis();
+        let context = current_context().unwrap_or_default();
+        match &mut self.transaction {
+            #[cfg(feature = "sqlite-support")]
+            OutboxTx::Sqlite(tx) => {
+                sqlx::query(&insert_event_sql("?, ?, ?, ?, ?, ?"))
+                    .bind(&id)
+                    .bind(topic)
+                    .bind(&payload)
+                    .bind(created_at)
+                    .bind(&context.request_id)
+                    .bind(&context.user_id)
+                    .execute(&mut **tx)
+                    .await
+                    .map_err(query_failed)?;
+            }
+            #[cfg(feature = "postgres-support")]
+            OutboxTx::Postgres(tx) => {
+                sqlx::query(&insert_event_sql("$1, $2, $3, $4, $5, $6"))
+                    .bind(&id)
+                    .bind(topic)
+                    .bind(&payload)
+                    .bind(created_at)
+                    .bind(&context.request_id)
+                    .bind(&context.user_id)
+                    .execute(&mut **tx)
+                    .await
+                    .map_err(query_failed)?;
+            }
+            #[cfg(feature = "mysql-support")]
+            OutboxTx::MySql(tx) => {
+                sqlx::query(&insert_event_sql("?, ?, ?, ?, ?, ?"))
+                    .bind(&id)
+                    .bind(topic)
+                    .bind(&payload)
+                    .bind(created_at)
+                    .bind(&context.request_id)
+                    .bind(&context.user_id)
+                    .execute(&mut **tx)
+                    .await
+                    .map_err(query_failed)?;
+            }
+        }
+        debug!("写入发件箱事件: 别名={}, 主题={}, ID={}", self.alias, topic, id);
+        Ok(id)
+    }
+
+    /// 提交事务，并清理涉及表的缓存
+    pub async fn commit(self) -> QuickDbResult<()> {
+        match self.transaction {
+            #[cfg(feature = "sqlite-support")]
+            OutboxTx::Sqlite(tx) => tx.commit().await.map_err(query_failed)?,
+            #[cfg(feature = "postgres-support")]
+            OutboxTx::Postgres(tx) => tx.commit().await.map_err(query_failed)?,
+            #[cfg(feature = "mysql-support")]
+            OutboxTx::MySql(tx) => tx.commit().await.map_err(query_failed)?,
+        }
+        // 事务内的写入绕过了ODM层，需要同步清理涉及表的缓存
+        let cache_manager = crate::manager::get_global_pool_manager()
+            .cache_managers
+            .get(&self.alias)
+            .map(|cm| cm.clone());
+        if let Some(cache_manager) = cache_manager {
+            for table in &self.touched_tables {
+                let _ = cache_manager.clear_table_record_cache(table).await;
+                let _ = cache_manager.clear_table_query_cache(table).await;
+            }
+        }
+        Ok(())
+    }
+
+    /// 回滚事务
+    pub async fn rollback(self) -> QuickDbResult<()> {
+        match self.transaction {
+            #[cfg(feature = "sqlite-support")]
+            OutboxTx::Sqlite(tx) => tx.rollback().await.map_err(query_failed),
+            #[cfg(feature = "postgres-support")]
+            OutboxTx::Postgres(tx) => tx.rollback().await.map_err(query_failed),
+            #[cfg(feature = "mysql-support")]
+            OutboxTx::MySql(tx) => tx.rollback().await.map_err(query_failed),
+        }
+    }
+
+    async fn write(&mut self, table: &str, write: TransactionWrite) -> QuickDbResult<u64> {
+        let physical = crate::manager::get_global_pool_manager().physical_table_name(table, &self.alias);
+        let affected = match &mut self.transaction {
+            #[cfg(feature = "sqlite-support")]
+            OutboxTx::Sqlite(tx) => {
+                crate::adapter::transaction::execute_sqlite_write(tx, &physical, &self.alias, &write).await?
+            }
+            #[cfg(feature = "postgres-support")]
+            OutboxTx::Postgres(tx) => {
+                crate::adapter::transaction::execute_postgres_write(tx, &physical, &self.alias, &write).await?
+            }
+            #[cfg(feature = "mysql-support")]
+            OutboxTx::MySql(tx) => {
+                crate::adapter::transaction::execute_mysql_write(tx, &physical, &self.alias, &write).await?
+            }
+        };
+        self.touched_tables.insert(table.to_string());
+        Ok(affected)
+    }
+}
+
+type PublishFuture = Pin<Box<dyn Future<Output = Result<(), String>> + Send>>;
+
+/// 发件箱中继：轮询发件箱并按写入顺序发布事件
+///
+/// 发布失败时记录失败次数与错误，本轮停止，下轮从该事件重试，以保持事件顺序
+#[derive(Clone)]
+pub struct OutboxRelay {
+    alias: String,
+    publisher: Arc<dyn Fn(OutboxEvent) -> PublishFuture + Send + Sync>,
+    batch_size: u32,
+    poll_interval: Duration,
+    max_attempts: Option<u32>,
+}
+
+impl std::fmt::Debug for OutboxRelay {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OutboxRelay")
+            .field("alias", &self.alias)
+            .field("batch_size", &self.batch_size)
+            .field("poll_interval", &self.poll_interval)
+            .field("max_attempts", &self.max_attempts)
+            .finish_non_exhaustive()
+    }
+}
+
+impl OutboxRelay {
+    /// 创建中继，`publisher` 返回错误表示发布失败，事件保留待重试
+    pub fn new<F, Fut, E>(alias: impl Into<String>, publisher: F) -> Self
+    where
+        F: Fn(OutboxEvent) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), E>> + Send + 'static,
+        E: std::fmt::Display,
+    {
+        let publisher = Arc::new(publisher);
+        Self {
+            alias: alias.into(),
+            publisher: Arc::new(move |event| {
+                let future = publisher(event);
+                Box::pin(async move { future.await.map_err(|e| e.to_string()) }) as PublishFuture
+            }),
+            batch_size: DEFAULT_BATCH_SIZE,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            max_attempts: None,
+        }
+    }
+
+    /// 设置每轮读取的事件数
+    pub fn batch_size(mut self, batch_size: u32) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// 设置轮询间隔
+    pub fn poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// 设置最大发布次数，失败达到该次数的事件留在发件箱中不再发布，需人工处理
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = Some(max_attempts);
+        self
+    }
+
+    /// 执行一轮发布，返回成功发布的事件数
+    pub async fn run_once(&self) -> QuickDbResult<usize> {
+        let pool = OutboxPool::open(&self.alias).await?;
+        let events = pool.pending(self.batch_size, self.max_attempts).await?;
+        let mut published = 0;
+        for event in events {
+            let id = event.id.clone();
+            match (self.publisher)(event).await {
+                Ok(()) => {
+                    pool.remove(&id).await?;
+                    published += 1;
+                }
+                Err(message) => {
+                    warn!("发布发件箱事件失败: 别名={}, ID={}, 错误={}", self.alias, id, message);
+                    pool.record_failure(&id, &message).await?;
+                    break;
+                }
+            }
+        }
+        if published > 0 {
+            debug!("发布发件箱事件: 别名={}, 数量={}", self.alias, published);
+        }
+        Ok(published)
+    }
+
+    /// 在后台按轮询间隔持续发布，一轮发满批量时立即进入下一轮
+    pub fn start(self) -> OutboxRelayHandle {
+        let (shutdown, mut shutdown_rx) = watch::channel(false);
+        let task = tokio::spawn(async move {
+            info!("启动发件箱中继: 别名={}", self.alias);
+            loop {
+                let wait = match self.run_once().await {
+                    Ok(published) if published as u32 >= self.batch_size => Duration::ZERO,
+                    Ok(_) => self.poll_interval,
+                    Err(e) => {
+                        warn!("发件箱中继轮询失败: 别名={}, 错误={}", self.alias, e);
+                        self.poll_interval
+                    }
+                };
+                if wait_for_shutdown(&mut shutdown_rx, wait).await {
+                    break;
+                }
+            }
+            info!("发件箱中继已停止: 别名={}", self.alias);
+        });
+        OutboxRelayHandle { shutdown, task }
+    }
+
+    /// 转换为调度任务，由调度器只在主节点上每隔 `interval` 执行一轮
+    pub fn into_job(self, interval: Duration) -> ScheduledJob {
+        let name = format!("outbox_relay:{}", self.alias);
+        ScheduledJob::new(name, interval, move || {
+            let relay = self.clone();
+            async move { relay.run_once().await.map(drop) }
+        })
+    }
+}
+
+/// 运行中的中继句柄，丢弃句柄时中继随之停止
+#[derive(Debug)]
+pub struct OutboxRelayHandle {
+    shutdown: watch::Sender<bool>,
+    task: JoinHandle<()>,
+}
+
+impl OutboxRelayHandle {
+    /// 停止中继并等待当前一轮结束
+    pub async fn shutdown(self) {
+        let _ = self.shutdown.send(true);
+        let _ = self.task.await;
+    }
+}
+
+/// 发件箱所在数据库的连接池
+enum OutboxPool {
+    #[cfg(feature = "sqlite-support")]
+    Sqlite(sqlx::SqlitePool),
+    #[cfg(feature = "postgres-support")]
+    Postgres(sqlx::PgPool),
+    #[cfg(feature = "mysql-support")]
+    MySql(sqlx::MySqlPool),
+}
+
+/// 进行中的事务
+enum OutboxTx {
+    #[cfg(feature = "sqlite-support")]
+    Sqlite(sqlx::Transaction<'static, sqlx::Sqlite>),
+    #[cfg(feature = "postgres-support")]
+    Postgres(sqlx::Transaction<'static, sqlx::Postgres>),
+    #[cfg(feature = "mysql-support")]
+    MySql(sqlx::Transaction<'static, sqlx::MySql>),
+}
+
+impl OutboxPool {
+    /// 获取别名对应的连接池，每个别名首次打开时确保发件箱表存在
+    async fn open(alias: &str) -> QuickDbResult<Self> {
+        let pool = crate::manager::get_global_pool_manager()
+            .get_connection_pools()
+            .get(alias)
+            .map(|pool| pool.clone())
+            .ok_or_else(|| QuickDbError::AliasNotFound { alias: alias.to_string() })?;
+
+        let outbox = match pool.database_connection().await? {
+            #[cfg(feature = "sqlite-support")]
+            DatabaseConnection::SQLite(pool) => Self::Sqlite(pool),
+            #[cfg(feature = "postgres-support")]
+            DatabaseConnection::PostgreSQL(pool) => Self::Postgres(pool),
+            #[cfg(feature = "mysql-support")]
+            DatabaseConnection::MySQL(pool) => Self::MySql(pool),
+            #[allow(unreachable_patterns)]
+            _ => {
+                return Err(QuickDbError::UnsupportedDatabase {
+                    db_type: crate::i18n::tf("outbox.unsupported", &[("db_type", &format!("{:?}", pool.db_type))]),
+                });
+            }
+        };
+        if !READY_ALIASES.contains(alias) {
+            outbox.ensure_table().await?;
+            READY_ALIASES.insert(alias.to_string());
+        }
+        Ok(outbox)
+    }
+
+    async fn ensure_table(&self) -> QuickDbResult<()> {
+        let columns = "event_id VARCHAR(36) NOT NULL UNIQUE, topic VARCHAR(255) NOT NULL, payload TEXT NOT NULL, \
+                       created_at BIGINT NOT NULL, attempts INTEGER NOT NULL DEFAULT 0, last_error TEXT, \
+                       request_id VARCHAR(255), user_id VARCHAR(255)";
+        let result = match self {
+            #[cfg(feature = "sqlite-support")]
+            Self::Sqlite(pool) => sqlx::query(&format!(
+                "CREATE TABLE IF NOT EXISTS {} (seq INTEGER PRIMARY KEY AUTOINCREMENT, {})",
+                OUTBOX_TABLE, columns
+            ))
+            .execute(pool)
+            .await
+            .map(drop),
+            #[cfg(feature = "postgres-support")]
+            Self::Postgres(pool) => sqlx::query(&format!(
+                "CREATE TABLE IF NOT EXISTS {} (seq BIGSERIAL PRIMARY KEY, {})",
+                OUTBOX_TABLE, columns
+            ))
+            .execute(pool)
+            .await
+            .map(drop),
+            #[cfg(feature = "mysql-support")]
+            Self::MySql(pool) => sqlx::query(&format!(
+                "CREATE TABLE IF NOT EXISTS {} (seq BIGINT AUTO_INCREMENT PRIMARY KEY, {})",
+                OUTBOX_TABLE, columns
+            ))
+            .execute(pool)
+            .await
+            .map(drop),
+        };
+        result.map_err(query_failed)
+    }
+
+    /// 按写入顺序读取待发布事件
+    async fn pending(&self, limit: u32, max_attempts: Option<u32>) -> QuickDbResult<Vec<OutboxEvent>> {
+        let max_attempts = i64::from(max_attempts.unwrap_or(u32::MAX));
+        let select = |placeholders: (&str, &str)| {
+            format!(
+                "SELECT event_id, topic, payload, created_at, attempts, request_id, user_id FROM {} \
+                 WHERE attempts < {} ORDER BY seq LIMIT {}",
+                OUTBOX_TABLE, placeholders.0, placeholders.1
+            )
+        };
+        type EventRow = (String, String, String, i64, i32, Option<String>, Option<String>);
+        let rows: Vec<EventRow> = match self {
+            #[cfg(feature = "sqlite-support")]
+            Self::Sqlite(pool) => sqlx::query_as(&select(("?", "?")))
+                .bind(max_attempts)
+                .bind(i64::from(limit))
+                .fetch_all(pool)
+                .await,
+            #[cfg(feature = "postgres-support")]
+            Self::Postgres(pool) => sqlx::query_as(&select(("$1", "$2")))
+                .bind(max_attempts)
+                .bind(i64::from(limit))
+                .fetch_all(pool)
+                .await,
+            #[cfg(feature = "mysql-support")]
+            Self::MySql(pool) => sqlx::query_as(&select(("?", "?")))
+                .bind(max_attempts)
+                .bind(i64::from(limit))
+                .fetch_all(pool)
+                .await,
+        }
+        .map_err(query_failed)?;
+        Ok(rows
+            .into_iter()
+            .map(|(id, topic, payload, created_at, attempts, request_id, user_id)| OutboxEvent {
+                context: OperationContext { request_id, user_id, ..Default::default() },
+                ..OutboxEvent::from_row(id, topic, payload, created_at, attempts)
+            })
+            .collect())
+    }
+
+    async fn remove(&self, id: &str) -> QuickDbResult<()> {
+        let delete = |placeholder: &str| format!("DELETE FROM {} WHERE event_id = {}", OUTBOX_TABLE, placeholder);
+        match self {
+            #[cfg(feature = "sqlite-support")]
+            Self::Sqlite(pool) => sqlx::query(&delete("?")).bind(id).execute(pool).await.map(drop),
+            #[cfg(feature = "postgres-support")]
+            Self::Postgres(pool) => sqlx::query(&delete("$1")).bind(id).execute(pool).await.map(drop),
+            #[cfg(feature = "mysql-support")]
+            Self::MySql(pool) => sqlx::query(&delete("?")).bind(id).execute(pool).await.map(drop),
+        }
+        .map_err(query_failed)
+    }
+
+    async fn record_failure(&self, id: &str, message: &str) -> QuickDbResult<()> {
+        let update = |placeholders: (&str, &str)| {
+            format!(
+                "UPDATE {} SET attempts = attempts + 1, last_error = {} WHERE event_id = {}",
+                OUTBOX_TABLE, placeholders.0, placeholders.1
+            )
+        };
+        match self {
+            #[cfg(feature = "sqlite-support")]
+            Self::Sqlite(pool) => sqlx::query(&update(("?", "?"))).bind(message).bind(id).execute(pool).await.map(drop),
+            #[cfg(feature = "postgres-support")]
+            Self::Postgres(pool) => {
+                sqlx::query(&update(("$1", "$2"))).bind(message).bind(id).execute(pool).await.map(drop)
+            }
+            #[cfg(feature = "mysql-support")]
+            Self::MySql(pool) => sqlx::query(&update(("?", "?"))).bind(message).bind(id).execute(pool).await.map(drop),
+        }
+        .map_err(query_failed)
+    }
+}
+
+fn insert_event_sql(placeholders: &str) -> String {
+    format!(
+        "INSERT INTO {} (event_id, topic, payload, created_at, request_id, user_id) VALUES ({})",
+        OUTBOX_TABLE, placeholders
+    )
+}
+
+fn query_failed(e: impl std::fmt::Display) -> QuickDbError {
+    QuickDbError::QueryError {
+        message: crate::i18n::tf("outbox.query_failed", &[("message", &e.to_string())]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_outbox_rows() {
+        let event = OutboxEvent::from_row(
+            "e1".to_string(),
+            "order.created".to_string(),
+            r#"{"order_id":7}"#.to_string(),
+            1_700_000_000_000,
+            2,
+        );
+        assert_eq!(event.payload["order_id"], 7);
+        assert_eq!(event.created_at.timestamp(), 1_700_000_000);
+        assert_eq!(event.attempts, 2);
+
+        let raw = OutboxEvent::from_row("e2".to_string(), "t".to_string(), "not json".to_string(), 0, -1);
+        assert_eq!(raw.payload, serde_json::Value::String("not json".to_string()));
+        assert_eq!(raw.attempts, 0);
+    }
+
+    #[cfg(feature = "sqlite-support")]
+    #[tokio::test]
+    async fn relay_publishes_committed_events_in_order() {
+        crate::i18n::ErrorMessageI18n::init_i18n();
+        let alias = "outbox_suite";
+        crate::manager::get_global_pool_manager()
+            .add_database(DatabaseConfig::sqlite_memory_for_test(alias))
+            .await
+            .unwrap();
+
+        let published = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let relay = {
+            let published = published.clone();
+            OutboxRelay::new(alias, move |event: OutboxEvent| {
+                published.lock().unwrap().push((event.payload["order_id"].clone(), event.attempts));
+                async { Ok::<(), String>(()) }
+            })
+        };
+        let failing = OutboxRelay::new(alias, |_event: OutboxEvent| async { Err::<(), _>("broker down") });
+
+        // 回滚的事务不留下事件
+        let mut tx = begin(alias).await.unwrap();
+        tx.publish("order.created", serde_json::json!({ "order_id": 1 })).await.unwrap();
+        tx.rollback().await.unwrap();
+        assert!(READY_ALIASES.contains(alias));
+        assert_eq!(relay.run_once().await.unwrap(), 0);
+
+        let mut tx = begin(alias).await.unwrap();
+        for order_id in [2, 3] {
+            tx.publish("order.created", serde_json::json!({ "order_id": order_id })).await.unwrap();
+        }
+        tx.commit().await.unwrap();
+
+        // 发布失败：记录失败次数，本轮停止，事件保持原顺序
+        assert_eq!(failing.run_once().await.unwrap(), 0);
+        let pool = OutboxPool::open(alias).await.unwrap();
+        let pending = pool.pending(10, None).await.unwrap();
+        let attempts: Vec<_> = pending.iter().map(|event| (event.payload["order_id"].clone(), event.attempts)).collect();
+        assert_eq!(attempts, [(serde_json::json!(2), 1), (serde_json::json!(3), 0)]);
+
+        assert_eq!(relay.run_once().await.unwrap(), 2);
+        assert_eq!(
+            *published.lock().unwrap(),
+            [(serde_json::json!(2), 1), (serde_json::json!(3), 0)]
+        );
+        assert!(pool.pending(10, None).await.unwrap().is_empty());
+    }
+}
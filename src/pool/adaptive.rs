@@ -0,0 +1,214 @@
+//! 自适应连接池扩缩容模块
+//!
+//! 根据操作排队延迟在 `min_connections` 和 `max_connections` 之间动态调整活跃工作器数量：
+//! 排队延迟超过阈值时按积压数量扩容，持续空闲一段时间后逐个缩容
+
+use parking_lot::Mutex;
+use serde::Serialize;
+use std::time::Duration;
+
+use super::ExtendedPoolConfig;
+
+/// 扩缩容评估间隔
+pub(crate) const SIZING_INTERVAL: Duration = Duration::from_millis(100);
+/// 触发扩容的排队延迟阈值
+const GROW_LATENCY: Duration = Duration::from_millis(5);
+/// 连续空闲多少个评估周期后缩容一个工作器（约10秒）
+const SHRINK_AFTER_IDLE_TICKS: u32 = 100;
+
+/// 扩缩容决策
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SizingDecision {
+    /// 增加指定数量的工作器
+    Grow(u32),
+    /// 减少一个空闲工作器
+    Shrink,
+    /// 保持不变
+    Hold,
+}
+
+/// 自适应扩缩容运行时指标
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct PoolSizingMetrics {
+    /// 工作器数量下限
+    pub min_workers: u32,
+    /// 工作器数量上限
+    pub max_workers: u32,
+    /// 当前活跃工作器数量（空闲 + 忙碌）
+    pub active_workers: u32,
+    /// 正在执行操作的工作器数量
+    pub busy_workers: u32,
+    /// 等待分配工作器的操作数量
+    pub queue_depth: u32,
+    /// 最近一个评估周期的平均排队延迟（毫秒）
+    pub avg_queue_latency_ms: f64,
+    /// 累计扩容次数
+    pub grow_events: u64,
+    /// 累计缩容次数
+    pub shrink_events: u64,
+}
+
+#[derive(Debug, Default)]
+struct SizerState {
+    active_workers: u32,
+    busy_workers: u32,
+    queue_depth: u32,
+    window_latency: Duration,
+    window_samples: u32,
+    last_avg_latency: Duration,
+    idle_ticks: u32,
+    grow_events: u64,
+    shrink_events: u64,
+}
+
+/// 自适应扩缩容控制器（与连接池共享，用于读取指标）
+#[derive(Debug)]
+pub struct AdaptiveSizer {
+    min_workers: u32,
+    max_workers: u32,
+    state: Mutex<SizerState>,
+}
+
+impl AdaptiveSizer {
+    /// 创建控制器，工作器数量下限至少为1
+    pub fn new(min_connections: u32, max_connections: u32) -> Self {
+        let min_workers = min_connections.max(1);
+        Self {
+            min_workers,
+            max_workers: max_connections.max(min_workers),
+            state: Mutex::new(SizerState::default()),
+        }
+    }
+
+    /// 根据连接池配置创建控制器
+    pub fn from_config(config: &ExtendedPoolConfig) -> Self {
+        Self::new(config.base.min_connections, config.base.max_connections)
+    }
+
+    /// 工作器数量下限
+    pub fn min_workers(&self) -> u32 {
+        self.min_workers
+    }
+
+    /// 工作器数量上限
+    pub fn max_workers(&self) -> u32 {
+        self.max_workers
+    }
+
+    /// 记录一次操作从入队到分配工作器的等待时间
+    pub fn record_queue_latency(&self, latency: Duration) {
+        let mut state = self.state.lock();
+        state.window_latency += latency;
+        state.window_samples += 1;
+    }
+
+    /// 更新当前负载
+    pub fn set_load(&self, active_workers: u32, busy_workers: u32, queue_depth: u32) {
+        let mut state = self.state.lock();
+        state.active_workers = active_workers;
+        state.busy_workers = busy_workers;
+        state.queue_depth = queue_depth;
+    }
+
+    /// 结束当前评估周期并给出扩缩容决策
+    ///
+    /// 排队延迟取本周期已分配操作的平均等待时间与最早积压操作已等待时间中的较大值
+    pub fn evaluate(&self, oldest_pending_wait: Duration) -> SizingDecision {
+        let mut state = self.state.lock();
+        let avg_latency = if state.window_samples > 0 {
+            state.window_latency / state.window_samples
+        } else {
+            Duration::ZERO
+        };
+        state.last_avg_latency = avg_latency;
+        state.window_latency = Duration::ZERO;
+        state.window_samples = 0;
+
+        let active = state.active_workers;
+        let idle = active.saturating_sub(state.busy_workers);
+
+        if active < self.min_workers {
+            state.idle_ticks = 0;
+            return SizingDecision::Grow(self.min_workers - active);
+        }
+
+        let latency = avg_latency.max(oldest_pending_wait);
+        if latency >= GROW_LATENCY && state.queue_depth > 0 && active < self.max_workers {
+            state.idle_ticks = 0;
+            return SizingDecision::Grow(state.queue_depth.min(self.max_workers - active));
+        }
+
+        if idle > 0 && state.queue_depth == 0 && active > self.min_workers {
+            state.idle_ticks += 1;
+            if state.idle_ticks >= SHRINK_AFTER_IDLE_TICKS {
+                state.idle_ticks = 0;
+                return SizingDecision::Shrink;
+            }
+        } else {
+            state.idle_ticks = 0;
+        }
+
+        SizingDecision::Hold
+    }
+
+    /// 记录扩容
+    pub fn record_grow(&self) {
+        self.state.lock().grow_events += 1;
+    }
+
+    /// 记录缩容
+    pub fn record_shrink(&self) {
+        self.state.lock().shrink_events += 1;
+    }
+
+    /// 获取运行时指标
+    pub fn metrics(&self) -> PoolSizingMetrics {
+        let state = self.state.lock();
+        PoolSizingMetrics {
+            min_workers: self.min_workers,
+            max_workers: self.max_workers,
+            active_workers: state.active_workers,
+            busy_workers: state.busy_workers,
+            queue_depth: state.queue_depth,
+            avg_queue_latency_ms: state.last_avg_latency.as_secs_f64() * 1000.0,
+            grow_events: state.grow_events,
+            shrink_events: state.shrink_events,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grows_on_queue_latency_up_to_max() {
+        let sizer = AdaptiveSizer::new(1, 4);
+        sizer.set_load(1, 1, 10);
+        sizer.record_queue_latency(Duration::from_millis(20));
+        assert_eq!(sizer.evaluate(Duration::ZERO), SizingDecision::Grow(3));
+
+        // 已达上限时不再扩容
+        sizer.set_load(4, 4, 10);
+        assert_eq!(sizer.evaluate(Duration::from_secs(1)), SizingDecision::Hold);
+
+        // 低于下限时补足
+        sizer.set_load(0, 0, 0);
+        assert_eq!(sizer.evaluate(Duration::ZERO), SizingDecision::Grow(1));
+    }
+
+    #[test]
+    fn shrinks_after_sustained_idle_but_not_below_min() {
+        let sizer = AdaptiveSizer::new(2, 8);
+        sizer.set_load(3, 0, 0);
+        for _ in 1..SHRINK_AFTER_IDLE_TICKS {
+            assert_eq!(sizer.evaluate(Duration::ZERO), SizingDecision::Hold);
+        }
+        assert_eq!(sizer.evaluate(Duration::ZERO), SizingDecision::Shrink);
+
+        sizer.set_load(2, 0, 0);
+        for _ in 0..SHRINK_AFTER_IDLE_TICKS * 2 {
+            assert_eq!(sizer.evaluate(Duration::ZERO), SizingDecision::Hold);
+        }
+    }
+}
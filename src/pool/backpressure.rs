@@ -0,0 +1,130 @@
+//! 操作队列背压模块
+//!
+//! 连接池的操作队列可按别名限制容量：已提交但尚未开始执行的操作数达到 `queue_capacity` 时，
+//! 按 `overflow_policy` 等待空位（超时返回 `QuickDbError::Overloaded`）或立即拒绝。
+//! 每个入队的操作带有一个 [`QueueSlot`]，工作器开始执行或丢弃操作时释放
+
+use rat_logger::warn;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+use super::{DatabaseOperation, PoolStatusTracker};
+use crate::context::{RequestReceiver, RequestSender, request_channel};
+use crate::error::{QuickDbError, QuickDbResult};
+use crate::types::{OverflowPolicy, PoolConfig};
+
+/// 入队操作占用的队列位置，释放时归还容量并更新队列深度
+pub(crate) struct QueueSlot {
+    _permit: Option<OwnedSemaphorePermit>,
+    status: Arc<PoolStatusTracker>,
+}
+
+impl Drop for QueueSlot {
+    fn drop(&mut self) {
+        self.status.record_dequeue();
+    }
+}
+
+/// 队列中的操作及其占用的队列位置
+pub(crate) type QueuedOperation = (DatabaseOperation, QueueSlot);
+
+/// 操作请求发送器，按连接池配置限制队列容量
+#[derive(Debug)]
+pub struct OperationSender {
+    alias: String,
+    sender: RequestSender<QueuedOperation>,
+    permits: Option<Arc<Semaphore>>,
+    policy: OverflowPolicy,
+    status: Arc<PoolStatusTracker>,
+}
+
+/// 创建连接池的操作队列
+pub(crate) fn operation_channel(
+    alias: &str,
+    config: &PoolConfig,
+    status: Arc<PoolStatusTracker>,
+) -> (OperationSender, RequestReceiver<QueuedOperation>) {
+    let (sender, receiver) = request_channel();
+    let sender = OperationSender {
+        alias: alias.to_string(),
+        sender,
+        permits: config
+            .queue_capacity
+            .map(|capacity| Arc::new(Semaphore::new(capacity))),
+        policy: config.overflow_policy,
+        status,
+    };
+    (sender, receiver)
+}
+
+impl OperationSender {
+    /// 提交操作：队列已满时按溢出策略等待或返回 `QuickDbError::Overloaded`
+    pub async fn send(&self, operation: DatabaseOperation) -> QuickDbResult<()> {
+        let permit = match &self.permits {
+            Some(permits) => Some(self.acquire(permits.clone()).await?),
+            None => None,
+        };
+        self.status.record_enqueue();
+        let slot = QueueSlot {
+            _permit: permit,
+            status: self.status.clone(),
+        };
+        self.sender
+            .send((operation, slot))
+            .map_err(|_| QuickDbError::QueryError {
+                message: crate::i18n::t("pool.send_operation_failed"),
+            })
+    }
+
+    /// 申请一个队列位置
+    async fn acquire(&self, permits: Arc<Semaphore>) -> QuickDbResult<OwnedSemaphorePermit> {
+        let permit = match self.policy {
+            OverflowPolicy::Reject => permits.try_acquire_owned().ok(),
+            OverflowPolicy::Block { timeout_ms } => {
+                tokio::time::timeout(Duration::from_millis(timeout_ms), permits.acquire_owned())
+                    .await
+                    .ok()
+                    .and_then(Result::ok)
+            }
+        };
+        permit.ok_or_else(|| {
+            let queued = self.status.queue_depth() as usize;
+            self.status.record_rejected();
+            warn!("操作队列已满: 别名={}, 排队操作数={}", self.alias, queued);
+            crate::quick_error!(overloaded, &self.alias, queued)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::sync::oneshot;
+
+    fn get_server_version() -> DatabaseOperation {
+        let (response, _) = oneshot::channel();
+        DatabaseOperation::GetServerVersion { response }
+    }
+
+    #[tokio::test]
+    async fn full_queue_rejects_until_a_slot_is_released() {
+        let status = Arc::new(PoolStatusTracker::new());
+        let config = PoolConfig {
+            queue_capacity: Some(1),
+            overflow_policy: OverflowPolicy::Reject,
+            ..PoolConfig::default()
+        };
+        let (sender, mut receiver) = operation_channel("main", &config, status.clone());
+
+        sender.send(get_server_version()).await.unwrap();
+        let overflow = sender.send(get_server_version()).await;
+        assert!(matches!(overflow, Err(QuickDbError::Overloaded { queued: 1, .. })));
+        assert_eq!((status.snapshot().queue_depth, status.snapshot().rejected), (1, 1));
+
+        // 工作器取出操作后释放位置
+        drop(receiver.recv().await.unwrap());
+        assert_eq!(status.queue_depth(), 0);
+        sender.send(get_server_version()).await.unwrap();
+    }
+}
@@ -0,0 +1,260 @@
+//! 熔断器模块
+//!
+//! 每个数据库别名一个熔断器：连续失败达到阈值后打开，打开期间请求直接返回
+//! `QuickDbError::CircuitOpen`，等待恢复时间后进入半开状态，放行单个探测请求，
+//! 探测成功则关闭熔断器，失败则重新打开
+
+use parking_lot::Mutex;
+use rat_logger::{info, warn};
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+use super::{ExtendedPoolConfig, RetryPolicy, TransientErrorKind};
+use crate::error::{QuickDbError, QuickDbResult};
+use crate::types::{DatabaseConfig, DatabaseType};
+
+/// 熔断器状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// 关闭：正常放行请求
+    Closed,
+    /// 打开：快速拒绝请求
+    Open,
+    /// 半开：放行探测请求
+    HalfOpen,
+}
+
+#[derive(Debug)]
+struct CircuitInner {
+    state: CircuitState,
+    consecutive_failures: u32,
+    opened_at: Instant,
+    probe_started_at: Option<Instant>,
+}
+
+/// 数据库别名级别的熔断器
+#[derive(Debug)]
+pub struct CircuitBreaker {
+    alias: String,
+    db_type: DatabaseType,
+    /// 连续失败阈值（0 表示禁用）
+    threshold: u32,
+    /// 打开后进入半开前的等待时间
+    reset_timeout: Duration,
+    inner: Mutex<CircuitInner>,
+}
+
+impl CircuitBreaker {
+    /// 创建熔断器
+    pub fn new(
+        alias: impl Into<String>,
+        db_type: DatabaseType,
+        threshold: u32,
+        reset_timeout: Duration,
+    ) -> Self {
+        Self {
+            alias: alias.into(),
+            db_type,
+            threshold,
+            reset_timeout,
+            inner: Mutex::new(CircuitInner {
+                state: CircuitState::Closed,
+                consecutive_failures: 0,
+                opened_at: Instant::now(),
+                probe_started_at: None,
+            }),
+        }
+    }
+
+    /// 根据数据库配置和连接池配置创建熔断器
+    pub fn from_config(db_config: &DatabaseConfig, config: &ExtendedPoolConfig) -> Self {
+        Self::new(
+            db_config.alias.clone(),
+            db_config.db_type,
+            config.base.circuit_breaker_threshold,
+            Duration::from_millis(config.base.circuit_breaker_reset_ms),
+        )
+    }
+
+    /// 当前状态
+    pub fn state(&self) -> CircuitState {
+        self.inner.lock().state
+    }
+
+    /// 申请执行请求，熔断器打开时返回 `QuickDbError::CircuitOpen`
+    pub fn try_acquire(&self) -> QuickDbResult<()> {
+        if self.threshold == 0 {
+            return Ok(());
+        }
+
+        let mut inner = self.inner.lock();
+        match inner.state {
+            CircuitState::Closed => Ok(()),
+            CircuitState::Open => {
+                let elapsed = inner.opened_at.elapsed();
+                if elapsed >= self.reset_timeout {
+                    info!("熔断器进入半开状态，放行探测请求: 别名={}", self.alias);
+                    inner.state = CircuitState::HalfOpen;
+                    inner.probe_started_at = Some(Instant::now());
+                    Ok(())
+                } else {
+                    Err(self.open_error(self.reset_timeout - elapsed))
+                }
+            }
+            CircuitState::HalfOpen => {
+                // 同一时间只放行一个探测请求；探测超过恢复时间未返回视为丢失，允许重新探测
+                let probe_lost = inner
+                    .probe_started_at
+                    .is_none_or(|started| started.elapsed() >= self.reset_timeout);
+                if probe_lost {
+                    inner.probe_started_at = Some(Instant::now());
+                    Ok(())
+                } else {
+                    Err(self.open_error(Duration::ZERO))
+                }
+            }
+        }
+    }
+
+    /// 记录成功，关闭熔断器
+    pub fn record_success(&self) {
+        if self.threshold == 0 {
+            return;
+        }
+
+        let mut inner = self.inner.lock();
+        if inner.state != CircuitState::Closed {
+            info!("熔断器探测成功，恢复关闭状态: 别名={}", self.alias);
+        }
+        inner.state = CircuitState::Closed;
+        inner.consecutive_failures = 0;
+        inner.probe_started_at = None;
+    }
+
+    /// 记录失败，达到阈值或半开探测失败时打开熔断器
+    pub fn record_failure(&self) {
+        if self.threshold == 0 {
+            return;
+        }
+
+        let mut inner = self.inner.lock();
+        inner.consecutive_failures = inner.consecutive_failures.saturating_add(1);
+        let should_open = match inner.state {
+            CircuitState::Closed => inner.consecutive_failures >= self.threshold,
+            CircuitState::HalfOpen => true,
+            CircuitState::Open => false,
+        };
+        if should_open {
+            warn!(
+                "熔断器打开: 别名={}, 连续失败{}次, {}ms后半开探测",
+                self.alias,
+                inner.consecutive_failures,
+                self.reset_timeout.as_millis()
+            );
+            inner.state = CircuitState::Open;
+            inner.opened_at = Instant::now();
+            inner.probe_started_at = None;
+        }
+    }
+
+    /// 判断错误是否表示后端不可用（计入熔断失败次数）
+    ///
+    /// 校验错误、唯一约束冲突等业务错误说明后端可达，不计入失败
+    pub fn is_backend_failure(db_type: DatabaseType, error: &QuickDbError) -> bool {
+        match error {
+            QuickDbError::ConnectionError { .. } | QuickDbError::PoolError { .. } => true,
+            _ => matches!(
+                RetryPolicy::classify(db_type, error),
+                Some(TransientErrorKind::ConnectionLost)
+            ),
+        }
+    }
+
+    /// 在熔断器保护下执行操作
+    pub async fn call<T, Fut>(&self, operation: Fut) -> QuickDbResult<T>
+    where
+        Fut: Future<Output = QuickDbResult<T>>,
+    {
+        self.try_acquire()?;
+
+        let result = operation.await;
+        match &result {
+            Err(error) if Self::is_backend_failure(self.db_type, error) => self.record_failure(),
+            _ => self.record_success(),
+        }
+        result
+    }
+
+    fn open_error(&self, retry_after: Duration) -> QuickDbError {
+        QuickDbError::CircuitOpen {
+            alias: self.alias.clone(),
+            retry_after_ms: retry_after.as_millis() as u64,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn connection_error() -> QuickDbError {
+        QuickDbError::ConnectionError {
+            message: "connection refused".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn opens_after_threshold_and_fails_fast() {
+        let breaker = CircuitBreaker::new("main", DatabaseType::PostgreSQL, 2, Duration::from_secs(60));
+
+        for _ in 0..2 {
+            let result: QuickDbResult<()> = breaker.call(async { Err(connection_error()) }).await;
+            assert!(matches!(result, Err(QuickDbError::ConnectionError { .. })));
+        }
+        assert_eq!(breaker.state(), CircuitState::Open);
+
+        let result = breaker.call(async { Ok(1) }).await;
+        assert!(matches!(result, Err(QuickDbError::CircuitOpen { .. })));
+    }
+
+    #[tokio::test]
+    async fn business_errors_do_not_trip_the_breaker() {
+        let breaker = CircuitBreaker::new("main", DatabaseType::MySQL, 1, Duration::from_secs(60));
+        let result: QuickDbResult<()> = breaker
+            .call(async {
+                Err(QuickDbError::UniqueViolation {
+                    table: "users".to_string(),
+                    fields: vec!["email".to_string()],
+                })
+            })
+            .await;
+        assert!(result.is_err());
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+
+    #[tokio::test]
+    async fn half_open_probe_closes_or_reopens() {
+        let breaker = CircuitBreaker::new("main", DatabaseType::MongoDB, 1, Duration::ZERO);
+
+        let _: QuickDbResult<()> = breaker.call(async { Err(connection_error()) }).await;
+        assert_eq!(breaker.state(), CircuitState::Open);
+
+        // 恢复时间已过，探测失败重新打开
+        let _: QuickDbResult<()> = breaker.call(async { Err(connection_error()) }).await;
+        assert_eq!(breaker.state(), CircuitState::Open);
+
+        // 探测成功关闭熔断器
+        assert_eq!(breaker.call(async { Ok(7) }).await.unwrap(), 7);
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+
+    #[test]
+    fn zero_threshold_disables_breaker() {
+        let breaker = CircuitBreaker::new("main", DatabaseType::SQLite, 0, Duration::from_secs(60));
+        for _ in 0..10 {
+            breaker.record_failure();
+        }
+        assert!(breaker.try_acquire().is_ok());
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+}
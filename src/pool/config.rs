@@ -43,6 +43,14 @@ impl ExtendedPoolConfig {
                 retry_interval_ms: pool_config.retry_interval_ms,
                 keepalive_interval_sec: pool_config.keepalive_interval_sec,
                 health_check_timeout_sec: pool_config.health_check_timeout_sec,
+                circuit_breaker_threshold: pool_config.circuit_breaker_threshold,
+                circuit_breaker_reset_ms: pool_config.circuit_breaker_reset_ms,
+                connect_mode: pool_config.connect_mode,
+                statement_cache_capacity: pool_config.statement_cache_capacity,
+                max_reconnect_backoff_ms: pool_config.max_reconnect_backoff_ms,
+                queue_capacity: pool_config.queue_capacity,
+                overflow_policy: pool_config.overflow_policy,
+                write_batch: pool_config.write_batch,
             },
             max_retries: pool_config.max_retries,
             retry_interval_ms: pool_config.retry_interval_ms,
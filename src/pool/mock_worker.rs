@@ -0,0 +1,163 @@
+//! 模拟工作器模块
+//!
+//! 为 `MockAdapter` 消费操作请求：不经过重试与熔断，按接收顺序串行执行
+
+use rat_logger::info;
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::{DatabaseConnection, DatabaseOperation, PoolStatusTracker};
+use crate::adapter::{DatabaseAdapter, MockAdapter};
+
+/// 模拟工作器
+pub(crate) struct MockWorker {
+    /// 别名（用于日志）
+    pub(crate) alias: String,
+    /// 操作接收器
+    pub(crate) operation_receiver: crate::context::RequestReceiver<super::QueuedOperation>,
+    /// 模拟适配器
+    pub(crate) adapter: MockAdapter,
+    /// 运行时状态计数器（与连接池共享）
+    pub(crate) status: Arc<PoolStatusTracker>,
+}
+
+impl MockWorker {
+    /// 运行模拟工作器，直到连接池关闭
+    pub(crate) async fn run(mut self) {
+        info!("模拟工作器开始运行: 别名={}", self.alias);
+        self.status.set_load(0, 1, 0);
+        while let Some(((operation, slot), context)) = self.operation_receiver.recv().await {
+            drop(slot);
+            self.status.record_acquire(Duration::ZERO);
+            crate::context::in_context_scope(context, self.handle_operation(operation)).await;
+        }
+        info!("模拟工作器停止运行: 别名={}", self.alias);
+    }
+
+    async fn handle_operation(&self, operation: DatabaseOperation) {
+        let adapter = &self.adapter;
+        let connection = &DatabaseConnection::Mock;
+        let status = &self.status;
+        match operation {
+            DatabaseOperation::Create { table, data, id_strategy, alias, response } => {
+                status.respond(response, adapter.create(connection, &table, &data, &id_strategy, &alias).await)
+            }
+            DatabaseOperation::FindById { table, id, alias, response } => {
+                status.respond(response, adapter.find_by_id(connection, &table, &id, &alias).await)
+            }
+            DatabaseOperation::ExistsById { table, id, alias, response } => {
+                status.respond(response, adapter.exists_by_id(connection, &table, &id, &alias).await)
+            }
+            DatabaseOperation::Find { table, conditions, options, alias, response } => {
+                status.respond(response, adapter.find(connection, &table, &conditions, &options, &alias).await)
+            }
+            DatabaseOperation::FindWithBypassCache { table, conditions, options, alias, bypass_cache, response } => {
+                let result = adapter
+                    .find_with_cache_control(connection, &table, &conditions, &options, &alias, bypass_cache)
+                    .await;
+                status.respond(response, result)
+            }
+            DatabaseOperation::FindWithGroups { table, condition_groups, options, alias, response } => {
+                let result = adapter.find_with_groups(connection, &table, &condition_groups, &options, &alias).await;
+                status.respond(response, result)
+            }
+            DatabaseOperation::FindWithGroupsWithBypassCache {
+                table,
+                condition_groups,
+                options,
+                alias,
+                bypass_cache,
+                response,
+            } => {
+                let result = adapter
+                    .find_with_groups_with_cache_control_and_config(
+                        connection,
+                        &table,
+                        &condition_groups,
+                        &options,
+                        &alias,
+                        bypass_cache,
+                    )
+                    .await;
+                status.respond(response, result)
+            }
+            DatabaseOperation::Update { table, conditions, data, alias, response, .. } => {
+                status.respond(response, adapter.update(connection, &table, &conditions, &data, &alias).await)
+            }
+            DatabaseOperation::UpdateWithOperations { table, conditions, operations, alias, response, .. } => {
+                let result = adapter
+                    .update_with_operations(connection, &table, &conditions, &operations, &alias)
+                    .await;
+                status.respond(response, result)
+            }
+            DatabaseOperation::UpdateById { table, id, data, alias, response } => {
+                status.respond(response, adapter.update_by_id(connection, &table, &id, &data, &alias).await)
+            }
+            DatabaseOperation::UpdateByIdReturning { table, id, data, alias, response } => {
+                let result = adapter.update_by_id_returning(connection, &table, &id, &data, &alias).await;
+                status.respond(response, result)
+            }
+            DatabaseOperation::Upsert { table, data, id_strategy, conflict_columns, alias, response } => {
+                let result = adapter
+                    .upsert(connection, &table, &data, &id_strategy, &conflict_columns, &alias)
+                    .await;
+                status.respond(response, result)
+            }
+            DatabaseOperation::Delete { table, conditions, alias, response } => {
+                status.respond(response, adapter.delete(connection, &table, &conditions, &alias).await)
+            }
+            DatabaseOperation::DeleteById { table, id, alias, response } => {
+                status.respond(response, adapter.delete_by_id(connection, &table, &id, &alias).await)
+            }
+            DatabaseOperation::DeleteByIdReturning { table, id, alias, response } => {
+                status.respond(response, adapter.delete_by_id_returning(connection, &table, &id, &alias).await)
+            }
+            DatabaseOperation::BulkWrite { table, ops, mode, id_strategy, alias, response } => {
+                let result = adapter.bulk_write(connection, &table, &ops, mode, &id_strategy, &alias).await;
+                status.respond(response, result)
+            }
+            DatabaseOperation::Count { table, conditions, alias, response } => {
+                status.respond(response, adapter.count(connection, &table, &conditions, &alias).await)
+            }
+            DatabaseOperation::CountWithGroups { table, condition_groups, alias, response } => {
+                let result = adapter.count_with_groups(connection, &table, &condition_groups, &alias).await;
+                status.respond(response, result)
+            }
+            DatabaseOperation::CreateTable { table, fields, id_strategy, alias, response } => {
+                let result = adapter.create_table(connection, &table, &fields, &id_strategy, &alias).await;
+                status.respond(response, result)
+            }
+            DatabaseOperation::CreateIndex { table, index_name, fields, unique, options, response } => {
+                let result = adapter
+                    .create_index(connection, &table, &index_name, &fields, unique, &options)
+                    .await;
+                status.respond(response, result)
+            }
+            DatabaseOperation::TableExists { table, response } => {
+                status.respond(response, adapter.table_exists(connection, &table).await)
+            }
+            DatabaseOperation::CountEstimate { table, response } => {
+                status.respond(response, adapter.count_estimate(connection, &table).await)
+            }
+            DatabaseOperation::DropTable { table, response } => {
+                status.respond(response, adapter.drop_table(connection, &table).await)
+            }
+            DatabaseOperation::GetServerVersion { response } => {
+                status.respond(response, adapter.get_server_version(connection).await)
+            }
+            DatabaseOperation::CreateStoredProcedure { config, response } => {
+                status.respond(response, adapter.create_stored_procedure(connection, &config).await)
+            }
+            DatabaseOperation::GetConnection { response } => status.respond(response, Ok(connection.clone())),
+            DatabaseOperation::DropStoredProcedure { procedure_name, response } => {
+                status.respond(response, adapter.drop_stored_procedure(connection, &procedure_name).await)
+            }
+            DatabaseOperation::ExecuteStoredProcedure { procedure_name, database, params, response } => {
+                let result = adapter
+                    .execute_stored_procedure(connection, &procedure_name, &database, params)
+                    .await;
+                status.respond(response, result)
+            }
+        }
+    }
+}
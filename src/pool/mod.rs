@@ -5,16 +5,39 @@
 //! MySQL/PostgreSQL/MongoDB: 多连接长连接池，支持保活和重试
 
 // 导入所有子模块
+pub mod adaptive;
+mod backpressure;
+pub mod circuit_breaker;
 pub mod config;
+#[cfg(feature = "testing")]
+mod mock_worker;
 pub mod multi_connection_manager;
+mod on_connect;
+#[allow(clippy::module_inception)]
 pub mod pool;
+pub mod retry;
 pub mod sqlite_worker;
+pub mod status;
+mod timeout;
 pub mod types;
+mod warm_up;
 
 // 重新导出主要的公共类型和结构体
+pub use adaptive::{AdaptiveSizer, PoolSizingMetrics};
+pub use backpressure::OperationSender;
+pub(crate) use backpressure::{QueuedOperation, operation_channel};
+pub use circuit_breaker::{CircuitBreaker, CircuitState};
 pub use config::ExtendedPoolConfig;
 pub use multi_connection_manager::MultiConnectionManager;
 pub use pool::ConnectionPool;
+pub use retry::{RetryPolicy, TransientErrorKind};
+#[cfg(any(feature = "postgres-support", feature = "mongodb-support"))]
+pub(crate) use timeout::statement_timeout;
+pub(crate) use timeout::with_timeout;
 #[cfg(feature = "sqlite-support")]
 pub use sqlite_worker::SqliteWorker;
+pub use status::{PoolStatus, PoolStatusTracker};
+pub(crate) use warm_up::{ping, warm_up};
 pub use types::{ConnectionWorker, DatabaseConnection, DatabaseOperation, PooledConnection};
+#[cfg(feature = "mongodb-support")]
+pub use types::MongoConnection;
@@ -1,64 +1,202 @@
 //! 多连接管理器模块
 
-use crossbeam_queue::SegQueue;
 use rat_logger::{debug, error, info, warn};
-use std::collections::HashMap;
+use std::collections::VecDeque;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
-use tokio::sync::{mpsc, oneshot};
+use tokio::task::JoinSet;
+use tokio::time::MissedTickBehavior;
 use tokio::time::timeout;
-use uuid::Uuid;
 
-use super::{ConnectionWorker, DatabaseConnection, DatabaseOperation, ExtendedPoolConfig};
-use crate::adapter::DatabaseAdapter;
+use super::adaptive::{SIZING_INTERVAL, SizingDecision};
+use super::{
+    AdaptiveSizer, CircuitBreaker, ConnectionWorker, DatabaseConnection, DatabaseOperation,
+    ExtendedPoolConfig, PoolStatusTracker, QueuedOperation, RetryPolicy, ping, warm_up, with_timeout,
+};
 use crate::error::{QuickDbError, QuickDbResult};
+use crate::context::{
+    LaneSelector, Priority, RequestReceiver, Scoped, in_context_scope, priority_of,
+};
 use crate::types::*;
 
 /// 多连接工作器管理器（用于MySQL/PostgreSQL/MongoDB）
 pub struct MultiConnectionManager {
-    /// 工作器列表
+    /// 空闲工作器列表（执行操作期间工作器被移出，完成后归还）
     pub(crate) workers: Vec<ConnectionWorker>,
-    /// 可用工作器队列
-    pub(crate) available_workers: SegQueue<usize>,
+    /// 工作器共享的数据库连接池（扩容时复用）
+    pub(crate) shared_connection: Option<DatabaseConnection>,
+    /// 下一个工作器编号
+    pub(crate) next_worker_index: usize,
+    /// 自适应扩缩容控制器（与连接池共享）
+    pub(crate) sizer: Arc<AdaptiveSizer>,
+    /// 运行时状态计数器（与连接池共享）
+    pub(crate) status: Arc<PoolStatusTracker>,
     /// 操作接收器
-    pub(crate) operation_receiver: mpsc::UnboundedReceiver<DatabaseOperation>,
+    pub(crate) operation_receiver: RequestReceiver<QueuedOperation>,
     /// 数据库配置
     pub(crate) db_config: DatabaseConfig,
     /// 扩展配置
     pub(crate) config: ExtendedPoolConfig,
-    /// 保活任务句柄
-    pub(crate) keepalive_handle: Option<tokio::task::JoinHandle<()>>,
+    /// 瞬时错误重试策略
+    pub(crate) retry_policy: RetryPolicy,
+    /// 熔断器（与连接池共享）
+    pub(crate) circuit_breaker: Arc<CircuitBreaker>,
+    /// 后台重连进行中时为已失败的重连次数，未在重连时为空
+    pub(crate) reconnect_attempts: Option<u32>,
     /// 缓存管理器（可选）
     pub(crate) cache_manager: Option<Arc<crate::cache::CacheManager>>,
 }
+
+/// 执行中的操作任务，完成后返回工作器和执行结果
+type InFlightOperations = JoinSet<(ConnectionWorker, Outcome)>;
+
+/// 工作器上一次操作的结果
+enum Outcome {
+    /// 操作已执行
+    Done(QuickDbResult<()>),
+    /// 取出时连接检测失败，操作未执行，需要重新排队
+    Stale(Box<Scoped<QueuedOperation>>),
+}
+
+/// 等待分配工作器的操作，交互与后台操作分别排队
+#[derive(Default)]
+struct PendingOperations {
+    interactive: VecDeque<(Instant, Scoped<QueuedOperation>)>,
+    background: VecDeque<(Instant, Scoped<QueuedOperation>)>,
+    lanes: LaneSelector,
+}
+
+impl PendingOperations {
+    fn lane(&mut self, operation: &Scoped<QueuedOperation>) -> &mut VecDeque<(Instant, Scoped<QueuedOperation>)> {
+        match priority_of(operation) {
+            Priority::Interactive => &mut self.interactive,
+            Priority::Background => &mut self.background,
+        }
+    }
+
+    fn push_back(&mut self, operation: Scoped<QueuedOperation>) {
+        self.lane(&operation).push_back((Instant::now(), operation));
+    }
+
+    fn push_front(&mut self, operation: Scoped<QueuedOperation>) {
+        self.lane(&operation).push_front((Instant::now(), operation));
+    }
+
+    /// 取出下一个操作：优先交互操作，交互操作连续分配一定数量后让出一次给后台操作
+    fn pop_front(&mut self) -> Option<(Instant, Scoped<QueuedOperation>)> {
+        let next = if self.lanes.background_due() || self.interactive.is_empty() {
+            self.background.pop_front().or_else(|| self.interactive.pop_front())
+        } else {
+            self.interactive.pop_front()
+        };
+        if let Some((_, operation)) = &next {
+            self.lanes.served(priority_of(operation));
+        }
+        next
+    }
+
+    /// 最早入队的操作已等待的时间
+    fn oldest_wait(&self) -> Duration {
+        [self.interactive.front(), self.background.front()]
+            .into_iter()
+            .flatten()
+            .map(|(enqueued_at, _)| enqueued_at.elapsed())
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn len(&self) -> usize {
+        self.interactive.len() + self.background.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// 后台维护任务：保活检测与重连
+type MaintenanceTasks = JoinSet<Maintenance>;
+
+/// 后台维护任务的结果
+enum Maintenance {
+    /// 保活检测结果
+    Probe(QuickDbResult<()>),
+    /// 重连结果
+    Reconnect(QuickDbResult<DatabaseConnection>),
+}
+
+/// 第 `attempt` 次重连前的等待时间：从 `base_ms` 开始逐次加倍，不超过 `max_ms`
+fn reconnect_backoff(base_ms: u64, max_ms: u64, attempt: u32) -> Duration {
+    let backoff = base_ms.saturating_mul(1u64 << attempt.min(32));
+    Duration::from_millis(backoff.min(max_ms.max(base_ms)))
+}
+
+/// 在超时时间内 ping 连接
+async fn ping_with_timeout(connection: &DatabaseConnection, limit: Duration) -> QuickDbResult<()> {
+    timeout(limit, ping(connection))
+        .await
+        .unwrap_or_else(|_| {
+            Err(QuickDbError::ConnectionError {
+                message: format!("连接检测超时: {:?}", limit),
+            })
+        })
+}
+
 impl MultiConnectionManager {
-    /// 创建初始连接
+    /// 创建初始连接：建立数据库连接池，并按工作器数量下限创建共享该连接池的工作器
     pub async fn create_initial_connections(&mut self) -> QuickDbResult<()> {
-        // 只创建1个worker进行测试
-        let worker = self.create_connection_worker(0).await?;
-        self.workers.push(worker);
-        self.available_workers.push(0);
+        let connection = Self::create_database_connection(&self.db_config, &self.config).await?;
+        self.shared_connection = Some(connection.clone());
+        for _ in 0..self.sizer.min_workers() {
+            let worker = self.build_worker(connection.clone())?;
+            self.workers.push(worker);
+        }
+        self.sizer.set_load(self.workers.len() as u32, 0, 0);
+
+        Ok(())
+    }
 
+    /// 预热连接：创建初始连接，并建立、验证最小连接数
+    pub async fn warm_up(&mut self) -> QuickDbResult<()> {
+        if self.workers.is_empty() {
+            self.create_initial_connections().await?;
+        }
+        if let Some(connection) = &self.shared_connection {
+            warm_up(
+                &self.db_config.alias,
+                connection,
+                self.config.base.min_connections,
+            )
+            .await?;
+        }
         Ok(())
     }
 
-    /// 创建连接工作器
-    async fn create_connection_worker(&self, index: usize) -> QuickDbResult<ConnectionWorker> {
-        let connection = self.create_database_connection().await?;
+    /// 创建使用独立数据库连接池的工作器（尚无共享连接池时使用）
+    async fn create_connection_worker(&mut self) -> QuickDbResult<ConnectionWorker> {
+        let connection = Self::create_database_connection(&self.db_config, &self.config).await?;
+        self.build_worker(connection)
+    }
+
+    /// 创建共享数据库连接池的工作器（用于扩容）
+    async fn create_shared_worker(&mut self) -> QuickDbResult<ConnectionWorker> {
+        match self.shared_connection.clone() {
+            Some(connection) => self.build_worker(connection),
+            None => self.create_connection_worker().await,
+        }
+    }
 
+    /// 使用给定的数据库连接创建工作器
+    fn build_worker(&mut self, connection: DatabaseConnection) -> QuickDbResult<ConnectionWorker> {
         // 创建适配器
-        use crate::adapter::{create_adapter, create_adapter_with_cache};
-        let (adapter, adapter_type) = if let Some(cache_manager) = &self.cache_manager {
-            let adapter =
-                create_adapter_with_cache(&self.db_config.db_type, cache_manager.clone())?;
-            (adapter, "缓存适配器")
-        } else {
-            let adapter = create_adapter(&self.db_config.db_type)?;
-            (adapter, "普通适配器")
-        };
+        let adapter = crate::adapter::create_adapter_for_config(&self.db_config, self.cache_manager.clone())?;
+        let adapter_type = if self.cache_manager.is_some() { "缓存适配器" } else { "普通适配器" };
 
         debug!("数据库 '{}' 使用 {}", self.db_config.alias, adapter_type);
 
+        let index = self.next_worker_index;
+        self.next_worker_index += 1;
+
         Ok(ConnectionWorker {
             id: format!("{}-worker-{}", self.db_config.alias, index),
             connection,
@@ -66,17 +204,28 @@ impl MultiConnectionManager {
             created_at: Instant::now(),
             last_used: Instant::now(),
             retry_count: 0,
-            db_type: self.db_config.db_type.clone(),
+            db_type: self.db_config.db_type,
             adapter,
         })
     }
 
     /// 创建数据库连接
-    async fn create_database_connection(&self) -> QuickDbResult<DatabaseConnection> {
-        match &self.db_config.db_type {
+    #[cfg_attr(
+        not(any(
+            feature = "postgres-support",
+            feature = "mysql-support",
+            feature = "mongodb-support"
+        )),
+        allow(unused_variables)
+    )]
+    async fn create_database_connection(
+        db_config: &DatabaseConfig,
+        config: &ExtendedPoolConfig,
+    ) -> QuickDbResult<DatabaseConnection> {
+        match &db_config.db_type {
             #[cfg(feature = "postgres-support")]
             DatabaseType::PostgreSQL => {
-                let pg_opts = match &self.db_config.connection {
+                let pg_opts = match &db_config.connection {
                     crate::types::ConnectionConfig::PostgreSQL {
                         host,
                         port,
@@ -85,12 +234,20 @@ impl MultiConnectionManager {
                         password,
                         ssl_mode,
                         tls_config,
+                        socket_path,
+                        pgbouncer_mode: _,
                     } => {
+                        // PgBouncer 兼容模式下连接池创建时已把语句缓存容量置为 0
                         let mut opts = sqlx::postgres::PgConnectOptions::new()
                             .host(host)
                             .port(*port)
                             .username(username)
-                            .password(password);
+                            .password(password)
+                            .statement_cache_capacity(config.base.statement_cache_capacity);
+
+                        if let Some(socket_path) = socket_path {
+                            opts = opts.socket(socket_path);
+                        }
 
                         if !database.is_empty() {
                             opts = opts.database(database);
@@ -139,18 +296,19 @@ impl MultiConnectionManager {
                 };
 
                 // 使用 PgConnectOptions 创建连接池
-                let pool = sqlx::postgres::PgPoolOptions::new()
-                    .max_connections(self.config.base.max_connections)
-                    .min_connections(self.config.base.min_connections)
+                let pool_options = sqlx::postgres::PgPoolOptions::new()
+                    .max_connections(config.base.max_connections)
+                    .min_connections(config.base.min_connections)
                     .max_lifetime(std::time::Duration::from_secs(
-                        self.config.base.max_lifetime,
+                        config.base.max_lifetime,
                     ))
                     .idle_timeout(std::time::Duration::from_secs(
-                        self.config.base.idle_timeout,
+                        config.base.idle_timeout,
                     ))
                     .acquire_timeout(std::time::Duration::from_millis(
-                        self.config.base.connection_timeout,
-                    ))
+                        config.base.connection_timeout,
+                    ));
+                let pool = super::on_connect::with_on_connect(pool_options, &db_config.on_connect_statements)
                     .connect_with(pg_opts)
                     .await
                     .map_err(|e| QuickDbError::ConnectionError {
@@ -161,7 +319,7 @@ impl MultiConnectionManager {
             }
             #[cfg(feature = "mysql-support")]
             DatabaseType::MySQL => {
-                let mysql_opts = match &self.db_config.connection {
+                let mysql_opts = match &db_config.connection {
                     crate::types::ConnectionConfig::MySQL {
                         host,
                         port,
@@ -170,12 +328,28 @@ impl MultiConnectionManager {
                         password,
                         ssl_opts,
                         tls_config,
+                        socket,
+                        charset,
+                        collation,
                     } => {
                         let mut opts = sqlx::mysql::MySqlConnectOptions::new()
                             .host(host)
                             .port(*port)
                             .username(username)
-                            .password(password);
+                            .password(password)
+                            .charset(
+                                charset
+                                    .as_deref()
+                                    .unwrap_or(crate::types::database_config::DEFAULT_MYSQL_CHARSET),
+                            )
+                            .statement_cache_capacity(config.base.statement_cache_capacity);
+
+                        if let Some(collation) = collation {
+                            opts = opts.collation(collation);
+                        }
+                        if let Some(socket) = socket {
+                            opts = opts.socket(socket);
+                        }
 
                         if !database.is_empty() {
                             opts = opts.database(database);
@@ -234,18 +408,19 @@ impl MultiConnectionManager {
                 };
 
                 // 使用 MySqlConnectOptions 创建连接池
-                let mysql_pool = sqlx::mysql::MySqlPoolOptions::new()
-                    .min_connections(self.config.base.min_connections)
-                    .max_connections(self.config.base.max_connections)
+                let pool_options = sqlx::mysql::MySqlPoolOptions::new()
+                    .min_connections(config.base.min_connections)
+                    .max_connections(config.base.max_connections)
                     .acquire_timeout(std::time::Duration::from_millis(
-                        self.config.base.connection_timeout,
+                        config.base.connection_timeout,
                     ))
                     .idle_timeout(std::time::Duration::from_secs(
-                        self.config.base.idle_timeout,
+                        config.base.idle_timeout,
                     ))
                     .max_lifetime(std::time::Duration::from_millis(
-                        self.config.base.max_lifetime,
-                    ))
+                        config.base.max_lifetime,
+                    ));
+                let mysql_pool = super::on_connect::with_on_connect(pool_options, &db_config.on_connect_statements)
                     .connect_with(mysql_opts)
                     .await
                     .map_err(|e| QuickDbError::ConnectionError {
@@ -256,7 +431,7 @@ impl MultiConnectionManager {
             }
             #[cfg(feature = "mongodb-support")]
             DatabaseType::MongoDB => {
-                let connection_uri = match &self.db_config.connection {
+                let connection_uri = match &db_config.connection {
                     crate::types::ConnectionConfig::MongoDB {
                         host,
                         port,
@@ -268,6 +443,7 @@ impl MultiConnectionManager {
                         tls_config,
                         zstd_config,
                         options,
+                        cluster_config,
                     } => {
                         // 使用构建器生成连接URI
                         let mut builder = crate::types::MongoDbConnectionBuilder::new(
@@ -299,6 +475,11 @@ impl MultiConnectionManager {
                             builder = builder.with_zstd_config(zstd.clone());
                         }
 
+                        // 设置集群配置
+                        if let Some(cluster) = cluster_config {
+                            builder = builder.with_cluster_config(cluster.clone());
+                        }
+
                         // 添加自定义选项
                         if let Some(opts) = options {
                             for (key, value) in opts {
@@ -323,12 +504,13 @@ impl MultiConnectionManager {
                         message: format!("MongoDB连接失败: {}", e),
                     })?;
 
-                let database_name = match &self.db_config.connection {
+                let database_name = match &db_config.connection {
                     crate::types::ConnectionConfig::MongoDB { database, .. } => database.clone(),
                     _ => unreachable!(),
                 };
 
-                let db = client.database(&database_name);
+                let db = super::MongoConnection::new(client, &database_name);
+                super::on_connect::run_mongo_on_connect(&db, &db_config.on_connect_statements).await?;
                 Ok(DatabaseConnection::MongoDB(db))
             }
             _ => Err(QuickDbError::ConfigError {
@@ -338,66 +520,311 @@ impl MultiConnectionManager {
         }
     }
 
-    /// 启动连接保活任务
-    pub fn start_keepalive_task(&mut self) {
-        let keepalive_interval = Duration::from_secs(self.config.keepalive_interval_sec);
-        let health_check_timeout = Duration::from_secs(self.config.health_check_timeout_sec);
+    /// 保活检测：空闲期间定期 ping 共享连接池，失败时启动后台重连
+    fn start_probe(&self, maintenance: &mut MaintenanceTasks) {
+        if self.reconnect_attempts.is_some() {
+            return;
+        }
+        let Some(connection) = self.shared_connection.clone() else {
+            return;
+        };
+        let health_check_timeout = Duration::from_secs(self.config.health_check_timeout_sec.max(1));
+        maintenance.spawn(async move {
+            debug!("执行连接保活检查");
+            Maintenance::Probe(ping_with_timeout(&connection, health_check_timeout).await)
+        });
+    }
 
-        // 这里需要实现保活逻辑的占位符
-        let handle = tokio::spawn(async move {
-            let mut interval = tokio::time::interval(keepalive_interval);
+    /// 开始后台重连（已在重连时忽略）
+    fn begin_reconnect(&mut self, maintenance: &mut MaintenanceTasks, reason: &str) {
+        if self.reconnect_attempts.is_some() {
+            return;
+        }
+        warn!("连接不可用，开始后台重连: 别名={}, 原因={}", self.db_config.alias, reason);
+        self.reconnect_attempts = Some(0);
+        self.spawn_reconnect(maintenance);
+    }
 
-            loop {
-                interval.tick().await;
-                debug!("执行连接保活检查");
-                // TODO: 实现具体的保活逻辑
-            }
+    /// 按退避时间在后台重建共享连接池，并验证新连接池可用
+    fn spawn_reconnect(&self, maintenance: &mut MaintenanceTasks) {
+        let attempt = self.reconnect_attempts.unwrap_or_default();
+        let backoff = reconnect_backoff(
+            self.config.retry_interval_ms,
+            self.config.base.max_reconnect_backoff_ms,
+            attempt,
+        );
+        let db_config = self.db_config.clone();
+        let config = self.config.clone();
+        maintenance.spawn(async move {
+            tokio::time::sleep(backoff).await;
+            let health_check_timeout = Duration::from_secs(config.health_check_timeout_sec.max(1));
+            let result = match Self::create_database_connection(&db_config, &config).await {
+                Ok(connection) => ping_with_timeout(&connection, health_check_timeout)
+                    .await
+                    .map(|_| connection),
+                Err(e) => Err(e),
+            };
+            Maintenance::Reconnect(result)
         });
+    }
 
-        self.keepalive_handle = Some(handle);
+    /// 处理后台维护任务的结果
+    #[cfg_attr(
+        not(any(
+            feature = "postgres-support",
+            feature = "mysql-support",
+            feature = "mongodb-support"
+        )),
+        allow(unreachable_code, unused_variables)
+    )]
+    fn finish_maintenance(&mut self, maintenance: &mut MaintenanceTasks, result: Maintenance) {
+        match result {
+            Maintenance::Probe(Ok(())) => self.status.record_ping(true),
+            Maintenance::Probe(Err(e)) => {
+                self.status.record_ping(false);
+                self.begin_reconnect(maintenance, &e.to_string());
+            }
+            Maintenance::Reconnect(Ok(connection)) => {
+                info!(
+                    "后台重连成功: 别名={}, 失败次数={}",
+                    self.db_config.alias,
+                    self.reconnect_attempts.unwrap_or_default()
+                );
+                // 空闲工作器立即换用新连接池，执行中的工作器在归还时换用
+                for worker in &mut self.workers {
+                    worker.connection = connection.clone();
+                    worker.retry_count = 0;
+                }
+                self.shared_connection = Some(connection);
+                self.reconnect_attempts = None;
+            }
+            Maintenance::Reconnect(Err(e)) => {
+                let attempts = self.reconnect_attempts.unwrap_or_default() + 1;
+                warn!(
+                    "后台重连失败: 别名={}, 失败次数={}, 错误={}",
+                    self.db_config.alias, attempts, e
+                );
+                self.reconnect_attempts = Some(attempts);
+                self.spawn_reconnect(maintenance);
+            }
+        }
     }
 
     /// 运行多连接管理器
     pub async fn run(mut self) {
         debug!("多连接管理器开始运行: 别名={}", self.db_config.alias);
 
-        // 创建初始连接
-        if let Err(e) = self.create_initial_connections().await {
-            error!("创建初始连接失败: {:?}", e);
-            return;
+        // 创建初始连接（立即连接模式下已在启动前创建）
+        if self.workers.is_empty() {
+            if let Err(e) = self.create_initial_connections().await {
+                error!("创建初始连接失败: {:?}", e);
+                return;
+            }
         }
 
-        // 启动保活任务
-        self.start_keepalive_task();
+        let mut in_flight = InFlightOperations::new();
+        let mut maintenance = MaintenanceTasks::new();
+        let mut keepalive = tokio::time::interval(self.keepalive_interval());
+        keepalive.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        keepalive.reset();
+        let mut pending = PendingOperations::default();
+        let mut sizing = tokio::time::interval(SIZING_INTERVAL);
+        sizing.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        let mut receiver_open = true;
+
+        loop {
+            // 将积压的操作分配给空闲工作器
+            while let Some(worker) = self.workers.pop() {
+                let Some((enqueued_at, operation)) = pending.pop_front() else {
+                    self.workers.push(worker);
+                    break;
+                };
+                let wait = enqueued_at.elapsed();
+                self.sizer.record_queue_latency(wait);
+                self.status.record_acquire(wait);
+                self.dispatch(&mut in_flight, worker, operation);
+            }
+            self.sizer.set_load(
+                (self.workers.len() + in_flight.len()) as u32,
+                in_flight.len() as u32,
+                pending.len() as u32,
+            );
+            self.status.set_load(
+                in_flight.len() as u32,
+                self.workers.len() as u32,
+                pending.len() as u32,
+            );
+
+            if !receiver_open && pending.is_empty() && in_flight.is_empty() {
+                break;
+            }
 
-        while let Some(operation) = self.operation_receiver.recv().await {
-            if let Err(e) = self.handle_operation(operation).await {
-                error!("多连接操作处理失败: {}", e);
+            tokio::select! {
+                operation = self.operation_receiver.recv(), if receiver_open => match operation {
+                    Some(operation) => pending.push_back(operation),
+                    None => receiver_open = false,
+                },
+                Some(joined) = in_flight.join_next() => match joined {
+                    Ok((worker, Outcome::Done(result))) => self.finish_operation(&mut maintenance, worker, result),
+                    Ok((worker, Outcome::Stale(operation))) => {
+                        // 操作放回队首，由其他工作器或重连后的连接执行
+                        pending.push_front(*operation);
+                        self.begin_reconnect(&mut maintenance, "取出工作器时连接检测失败");
+                        self.return_worker(worker);
+                    }
+                    Err(e) => error!("多连接操作任务异常退出: {}", e),
+                },
+                Some(joined) = maintenance.join_next() => match joined {
+                    Ok(result) => self.finish_maintenance(&mut maintenance, result),
+                    Err(e) => {
+                        error!("多连接维护任务异常退出: {}", e);
+                        self.reconnect_attempts = None;
+                    }
+                },
+                _ = keepalive.tick() => self.start_probe(&mut maintenance),
+                _ = sizing.tick() => {
+                    self.adjust_pool_size(pending.oldest_wait()).await;
+                }
             }
         }
 
         debug!("多连接管理器停止运行");
     }
 
-    /// 处理数据库操作
-    async fn handle_operation(&mut self, operation: DatabaseOperation) -> QuickDbResult<()> {
-        // 获取可用工作器
-        let worker_index = match self.available_workers.pop() {
-            Some(index) => index,
-            None => {
-                // 所有工作器都在使用中，等待或创建新连接
-                return Err(QuickDbError::ConnectionError {
-                    message: "所有连接都在使用中".to_string(),
-                });
+    /// 根据扩缩容决策调整工作器数量
+    async fn adjust_pool_size(&mut self, oldest_pending_wait: Duration) {
+        match self.sizer.evaluate(oldest_pending_wait) {
+            SizingDecision::Grow(count) => {
+                for _ in 0..count {
+                    match self.create_shared_worker().await {
+                        Ok(worker) => {
+                            self.workers.push(worker);
+                            self.sizer.record_grow();
+                        }
+                        Err(e) => {
+                            error!("扩容工作器失败: 别名={}, 错误={}", self.db_config.alias, e);
+                            break;
+                        }
+                    }
+                }
+                debug!(
+                    "连接池扩容: 别名={}, 空闲工作器={}",
+                    self.db_config.alias,
+                    self.workers.len()
+                );
             }
-        };
+            SizingDecision::Shrink => {
+                // 空闲列表头部是最久未使用的工作器
+                if !self.workers.is_empty() {
+                    drop(self.workers.remove(0));
+                    self.sizer.record_shrink();
+                    debug!(
+                        "连接池缩容: 别名={}, 空闲工作器={}",
+                        self.db_config.alias,
+                        self.workers.len()
+                    );
+                }
+            }
+            SizingDecision::Hold => {}
+        }
+    }
 
-        // 获取工作器的连接
-        let worker = &mut self.workers[worker_index];
+    /// 在工作器上异步执行操作
+    fn dispatch(
+        &self,
+        in_flight: &mut InFlightOperations,
+        mut worker: ConnectionWorker,
+        ((operation, slot), context): Scoped<QueuedOperation>,
+    ) {
+        // 空闲超过保活间隔的工作器先检测连接，重连期间不再检测
+        let probe_timeout = (self.reconnect_attempts.is_none()
+            && worker.last_used.elapsed() >= self.keepalive_interval())
+        .then(|| Duration::from_secs(self.config.health_check_timeout_sec.max(1)));
         worker.last_used = Instant::now();
+        let retry_policy = self.retry_policy.clone();
+        let circuit_breaker = self.circuit_breaker.clone();
+        let status = self.status.clone();
+        let db_type = self.db_config.db_type;
+        in_flight.spawn(async move {
+            if let Some(limit) = probe_timeout {
+                let probe = ping_with_timeout(&worker.connection, limit).await;
+                status.record_ping(probe.is_ok());
+                if let Err(e) = probe {
+                    warn!("工作器 {} 连接检测失败: {}", worker.id, e);
+                    return (worker, Outcome::Stale(Box::new(((operation, slot), context))));
+                }
+            }
+            drop(slot);
+            let result = in_context_scope(
+                context,
+                Self::handle_operation(&worker, &retry_policy, &circuit_breaker, &status, db_type, operation),
+            )
+            .await;
+            (worker, Outcome::Done(result))
+        });
+    }
+
+    /// 保活检测间隔
+    fn keepalive_interval(&self) -> Duration {
+        Duration::from_secs(self.config.keepalive_interval_sec.max(1))
+    }
+
+    /// 归还工作器：执行期间共享连接池已重建时换用新连接池
+    #[cfg_attr(
+        not(any(
+            feature = "postgres-support",
+            feature = "mysql-support",
+            feature = "mongodb-support"
+        )),
+        allow(unreachable_code, unused_mut)
+    )]
+    fn return_worker(&mut self, mut worker: ConnectionWorker) {
+        if let Some(connection) = &self.shared_connection {
+            worker.connection = connection.clone();
+        }
+        self.workers.push(worker);
+    }
+
+    /// 操作完成后处理工作器：失败次数超限时启动后台重连，然后归还空闲列表
+    fn finish_operation(
+        &mut self,
+        maintenance: &mut MaintenanceTasks,
+        mut worker: ConnectionWorker,
+        result: QuickDbResult<()>,
+    ) {
+        // 处理连接错误和重试逻辑
+        if let Err(e) = result {
+            worker.retry_count += 1;
+
+            error!(
+                "工作器 {} 操作失败 ({}/{}): {}",
+                worker.id, worker.retry_count, self.config.max_retries, e
+            );
+
+            if worker.retry_count > self.config.max_retries {
+                warn!("工作器 {} 重试次数超限，后台重新创建连接", worker.id);
+                worker.retry_count = 0;
+                self.begin_reconnect(maintenance, &e.to_string());
+            }
+        } else {
+            // 操作成功，重置重试计数
+            worker.retry_count = 0;
+        }
 
+        self.return_worker(worker);
+    }
+
+    /// 在工作器上执行数据库操作并发送结果
+    async fn handle_operation(
+        worker: &ConnectionWorker,
+        retry_policy: &RetryPolicy,
+        circuit_breaker: &CircuitBreaker,
+        status: &PoolStatusTracker,
+        db_type: DatabaseType,
+        operation: DatabaseOperation,
+    ) -> QuickDbResult<()> {
         // 处理具体操作
-        let result = match operation {
+        match operation {
             DatabaseOperation::Create {
                 table,
                 data,
@@ -405,11 +832,12 @@ impl MultiConnectionManager {
                 alias,
                 response,
             } => {
-                let result = worker
-                    .adapter
-                    .create(&worker.connection, &table, &data, &id_strategy, &alias)
+                let result = circuit_breaker
+                    .call(retry_policy.execute(db_type, false, || {
+                        worker.adapter.create(&worker.connection, &table, &data, &id_strategy, &alias)
+                    }))
                     .await;
-                let _ = response.send(result);
+                status.respond(response, result);
                 Ok(())
             }
             DatabaseOperation::FindById {
@@ -418,11 +846,26 @@ impl MultiConnectionManager {
                 alias,
                 response,
             } => {
-                let result = worker
-                    .adapter
-                    .find_by_id(&worker.connection, &table, &id, &alias)
+                let result = circuit_breaker
+                    .call(retry_policy.execute(db_type, true, || {
+                        worker.adapter.find_by_id(&worker.connection, &table, &id, &alias)
+                    }))
+                    .await;
+                status.respond(response, result);
+                Ok(())
+            }
+            DatabaseOperation::ExistsById {
+                table,
+                id,
+                alias,
+                response,
+            } => {
+                let result = circuit_breaker
+                    .call(retry_policy.execute(db_type, true, || {
+                        worker.adapter.exists_by_id(&worker.connection, &table, &id, &alias)
+                    }))
                     .await;
-                let _ = response.send(result);
+                status.respond(response, result);
                 Ok(())
             }
             DatabaseOperation::Find {
@@ -433,15 +876,22 @@ impl MultiConnectionManager {
                 response,
             } => {
                 // 将 QueryCondition 转换为 QueryConditionWithConfig
-                let conditions_with_config: Vec<QueryConditionWithConfig> = conditions
-                    .iter()
-                    .map(|c| c.clone().into())
-                    .collect();
-                let result = worker
-                    .adapter
-                    .find(&worker.connection, &table, &conditions_with_config, &options, &alias)
+                let conditions_with_config: Vec<QueryConditionWithConfig> = conditions.to_vec();
+                let result = circuit_breaker
+                    .call(with_timeout(
+                        options.timeout,
+                        retry_policy.execute(db_type, true, || {
+                            worker.adapter.find(
+                                &worker.connection,
+                                &table,
+                                &conditions_with_config,
+                                &options,
+                                &alias,
+                            )
+                        }),
+                    ))
                     .await;
-                let _ = response.send(result);
+                status.respond(response, result);
                 Ok(())
             }
             DatabaseOperation::FindWithGroups {
@@ -451,17 +901,21 @@ impl MultiConnectionManager {
                 alias,
                 response,
             } => {
-                let result = worker
-                    .adapter
-                    .find_with_groups(
-                        &worker.connection,
-                        &table,
-                        &condition_groups,
-                        &options,
-                        &alias,
-                    )
+                let result = circuit_breaker
+                    .call(with_timeout(
+                        options.timeout,
+                        retry_policy.execute(db_type, true, || {
+                            worker.adapter.find_with_groups(
+                                &worker.connection,
+                                &table,
+                                &condition_groups,
+                                &options,
+                                &alias,
+                            )
+                        }),
+                    ))
                     .await;
-                let _ = response.send(result);
+                status.respond(response, result);
                 Ok(())
             }
             DatabaseOperation::FindWithBypassCache {
@@ -473,22 +927,23 @@ impl MultiConnectionManager {
                 response,
             } => {
                 // 将 QueryCondition 转换为 QueryConditionWithConfig
-                let conditions_with_config: Vec<QueryConditionWithConfig> = conditions
-                    .iter()
-                    .map(|c| c.clone().into())
-                    .collect();
-                let result = worker
-                    .adapter
-                    .find_with_cache_control(
-                        &worker.connection,
-                        &table,
-                        &conditions_with_config,
-                        &options,
-                        &alias,
-                        bypass_cache,
-                    )
+                let conditions_with_config: Vec<QueryConditionWithConfig> = conditions.to_vec();
+                let result = circuit_breaker
+                    .call(with_timeout(
+                        options.timeout,
+                        retry_policy.execute(db_type, true, || {
+                            worker.adapter.find_with_cache_control(
+                                &worker.connection,
+                                &table,
+                                &conditions_with_config,
+                                &options,
+                                &alias,
+                                bypass_cache,
+                            )
+                        }),
+                    ))
                     .await;
-                let _ = response.send(result);
+                status.respond(response, result);
                 Ok(())
             }
             DatabaseOperation::FindWithGroupsWithBypassCache {
@@ -499,62 +954,76 @@ impl MultiConnectionManager {
                 bypass_cache,
                 response,
             } => {
-                let result = worker
-                    .adapter
-                    .find_with_groups_with_cache_control_and_config(
-                        &worker.connection,
-                        &table,
-                        &condition_groups,
-                        &options,
-                        &alias,
-                        bypass_cache,
-                    )
+                let result = circuit_breaker
+                    .call(with_timeout(
+                        options.timeout,
+                        retry_policy.execute(db_type, true, || {
+                            worker.adapter.find_with_groups_with_cache_control_and_config(
+                                &worker.connection,
+                                &table,
+                                &condition_groups,
+                                &options,
+                                &alias,
+                                bypass_cache,
+                            )
+                        }),
+                    ))
                     .await;
-                let _ = response.send(result);
+                status.respond(response, result);
                 Ok(())
             }
             DatabaseOperation::Update {
                 table,
                 conditions,
                 data,
+                options,
                 alias,
                 response,
             } => {
                 // 将 QueryCondition 转换为 QueryConditionWithConfig
-                let conditions_with_config: Vec<QueryConditionWithConfig> = conditions
-                    .iter()
-                    .map(|c| c.clone().into())
-                    .collect();
-                let result = worker
-                    .adapter
-                    .update(&worker.connection, &table, &conditions_with_config, &data, &alias)
+                let conditions_with_config: Vec<QueryConditionWithConfig> = conditions.to_vec();
+                let result = circuit_breaker
+                    .call(with_timeout(
+                        options.timeout,
+                        retry_policy.execute(db_type, false, || {
+                            worker.adapter.update(
+                                &worker.connection,
+                                &table,
+                                &conditions_with_config,
+                                &data,
+                                &alias,
+                            )
+                        }),
+                    ))
                     .await;
-                let _ = response.send(result);
+                status.respond(response, result);
                 Ok(())
             }
             DatabaseOperation::UpdateWithOperations {
                 table,
                 conditions,
                 operations,
+                options,
                 alias,
                 response,
             } => {
                 // 将 QueryCondition 转换为 QueryConditionWithConfig
-                let conditions_with_config: Vec<QueryConditionWithConfig> = conditions
-                    .iter()
-                    .map(|c| c.clone().into())
-                    .collect();
-                let result = worker
-                    .adapter
-                    .update_with_operations(
-                        &worker.connection,
-                        &table,
-                        &conditions_with_config,
-                        &operations,
-                        &alias,
-                    )
+                let conditions_with_config: Vec<QueryConditionWithConfig> = conditions.to_vec();
+                let result = circuit_breaker
+                    .call(with_timeout(
+                        options.timeout,
+                        retry_policy.execute(db_type, false, || {
+                            worker.adapter.update_with_operations(
+                                &worker.connection,
+                                &table,
+                                &conditions_with_config,
+                                &operations,
+                                &alias,
+                            )
+                        }),
+                    ))
                     .await;
-                let _ = response.send(result);
+                status.respond(response, result);
                 Ok(())
             }
             DatabaseOperation::UpdateById {
@@ -564,11 +1033,27 @@ impl MultiConnectionManager {
                 alias,
                 response,
             } => {
-                let result = worker
-                    .adapter
-                    .update_by_id(&worker.connection, &table, &id, &data, &alias)
+                let result = circuit_breaker
+                    .call(retry_policy.execute(db_type, false, || {
+                        worker.adapter.update_by_id(&worker.connection, &table, &id, &data, &alias)
+                    }))
+                    .await;
+                status.respond(response, result);
+                Ok(())
+            }
+            DatabaseOperation::UpdateByIdReturning {
+                table,
+                id,
+                data,
+                alias,
+                response,
+            } => {
+                let result = circuit_breaker
+                    .call(retry_policy.execute(db_type, false, || {
+                        worker.adapter.update_by_id_returning(&worker.connection, &table, &id, &data, &alias)
+                    }))
                     .await;
-                let _ = response.send(result);
+                status.respond(response, result);
                 Ok(())
             }
             DatabaseOperation::Upsert {
@@ -579,18 +1064,19 @@ impl MultiConnectionManager {
                 alias,
                 response,
             } => {
-                let result = worker
-                    .adapter
-                    .upsert(
-                        &worker.connection,
-                        &table,
-                        &data,
-                        &id_strategy,
-                        &conflict_columns,
-                        &alias,
-                    )
+                let result = circuit_breaker
+                    .call(retry_policy.execute(db_type, false, || {
+                        worker.adapter.upsert(
+                            &worker.connection,
+                            &table,
+                            &data,
+                            &id_strategy,
+                            &conflict_columns,
+                            &alias,
+                        )
+                    }))
                     .await;
-                let _ = response.send(result);
+                status.respond(response, result);
                 Ok(())
             }
             DatabaseOperation::Delete {
@@ -600,15 +1086,18 @@ impl MultiConnectionManager {
                 response,
             } => {
                 // 将 QueryCondition 转换为 QueryConditionWithConfig
-                let conditions_with_config: Vec<QueryConditionWithConfig> = conditions
-                    .iter()
-                    .map(|c| c.clone().into())
-                    .collect();
-                let result = worker
-                    .adapter
-                    .delete(&worker.connection, &table, &conditions_with_config, &alias)
+                let conditions_with_config: Vec<QueryConditionWithConfig> = conditions.to_vec();
+                let result = circuit_breaker
+                    .call(retry_policy.execute(db_type, false, || {
+                        worker.adapter.delete(
+                            &worker.connection,
+                            &table,
+                            &conditions_with_config,
+                            &alias,
+                        )
+                    }))
                     .await;
-                let _ = response.send(result);
+                status.respond(response, result);
                 Ok(())
             }
             DatabaseOperation::DeleteById {
@@ -617,11 +1106,42 @@ impl MultiConnectionManager {
                 alias,
                 response,
             } => {
-                let result = worker
-                    .adapter
-                    .delete_by_id(&worker.connection, &table, &id, &alias)
+                let result = circuit_breaker
+                    .call(retry_policy.execute(db_type, false, || {
+                        worker.adapter.delete_by_id(&worker.connection, &table, &id, &alias)
+                    }))
                     .await;
-                let _ = response.send(result);
+                status.respond(response, result);
+                Ok(())
+            }
+            DatabaseOperation::DeleteByIdReturning {
+                table,
+                id,
+                alias,
+                response,
+            } => {
+                let result = circuit_breaker
+                    .call(retry_policy.execute(db_type, false, || {
+                        worker.adapter.delete_by_id_returning(&worker.connection, &table, &id, &alias)
+                    }))
+                    .await;
+                status.respond(response, result);
+                Ok(())
+            }
+            DatabaseOperation::BulkWrite {
+                table,
+                ops,
+                mode,
+                id_strategy,
+                alias,
+                response,
+            } => {
+                let result = circuit_breaker
+                    .call(retry_policy.execute(db_type, false, || {
+                        worker.adapter.bulk_write(&worker.connection, &table, &ops, mode, &id_strategy, &alias)
+                    }))
+                    .await;
+                status.respond(response, result);
                 Ok(())
             }
             DatabaseOperation::Count {
@@ -631,15 +1151,18 @@ impl MultiConnectionManager {
                 response,
             } => {
                 // 将 QueryCondition 转换为 QueryConditionWithConfig
-                let conditions_with_config: Vec<QueryConditionWithConfig> = conditions
-                    .iter()
-                    .map(|c| c.clone().into())
-                    .collect();
-                let result = worker
-                    .adapter
-                    .count(&worker.connection, &table, &conditions_with_config, &alias)
+                let conditions_with_config: Vec<QueryConditionWithConfig> = conditions.to_vec();
+                let result = circuit_breaker
+                    .call(retry_policy.execute(db_type, true, || {
+                        worker.adapter.count(
+                            &worker.connection,
+                            &table,
+                            &conditions_with_config,
+                            &alias,
+                        )
+                    }))
                     .await;
-                let _ = response.send(result);
+                status.respond(response, result);
                 Ok(())
             }
             DatabaseOperation::CountWithGroups {
@@ -648,11 +1171,17 @@ impl MultiConnectionManager {
                 alias,
                 response,
             } => {
-                let result = worker
-                    .adapter
-                    .count_with_groups(&worker.connection, &table, &condition_groups, &alias)
+                let result = circuit_breaker
+                    .call(retry_policy.execute(db_type, true, || {
+                        worker.adapter.count_with_groups(
+                            &worker.connection,
+                            &table,
+                            &condition_groups,
+                            &alias,
+                        )
+                    }))
                     .await;
-                let _ = response.send(result);
+                status.respond(response, result);
                 Ok(())
             }
             DatabaseOperation::CreateTable {
@@ -662,11 +1191,18 @@ impl MultiConnectionManager {
                 alias,
                 response,
             } => {
-                let result = worker
-                    .adapter
-                    .create_table(&worker.connection, &table, &fields, &id_strategy, &alias)
+                let result = circuit_breaker
+                    .call(retry_policy.execute(db_type, false, || {
+                        worker.adapter.create_table(
+                            &worker.connection,
+                            &table,
+                            &fields,
+                            &id_strategy,
+                            &alias,
+                        )
+                    }))
                     .await;
-                let _ = response.send(result);
+                status.respond(response, result);
                 Ok(())
             }
             DatabaseOperation::CreateIndex {
@@ -674,39 +1210,80 @@ impl MultiConnectionManager {
                 index_name,
                 fields,
                 unique,
+                options,
                 response,
             } => {
-                let result = worker
-                    .adapter
-                    .create_index(&worker.connection, &table, &index_name, &fields, unique)
+                let result = circuit_breaker
+                    .call(retry_policy.execute(db_type, false, || {
+                        worker.adapter.create_index(
+                            &worker.connection,
+                            &table,
+                            &index_name,
+                            &fields,
+                            unique,
+                            &options,
+                        )
+                    }))
                     .await;
-                let _ = response.send(result);
+                status.respond(response, result);
                 Ok(())
             }
             DatabaseOperation::TableExists { table, response } => {
-                let result = worker
-                    .adapter
-                    .table_exists(&worker.connection, &table)
+                let result = circuit_breaker
+                    .call(retry_policy.execute(db_type, true, || {
+                        worker.adapter.table_exists(&worker.connection, &table)
+                    }))
+                    .await;
+                status.respond(response, result);
+                Ok(())
+            }
+            DatabaseOperation::CountEstimate { table, response } => {
+                let result = circuit_breaker
+                    .call(retry_policy.execute(db_type, true, || {
+                        worker.adapter.count_estimate(&worker.connection, &table)
+                    }))
                     .await;
-                let _ = response.send(result);
+                status.respond(response, result);
                 Ok(())
             }
             DatabaseOperation::DropTable { table, response } => {
-                let result = worker.adapter.drop_table(&worker.connection, &table).await;
-                let _ = response.send(result);
+                let result = circuit_breaker
+                    .call(retry_policy.execute(db_type, false, || {
+                        worker.adapter.drop_table(&worker.connection, &table)
+                    }))
+                    .await;
+                status.respond(response, result);
                 Ok(())
             }
             DatabaseOperation::GetServerVersion { response } => {
-                let result = worker.adapter.get_server_version(&worker.connection).await;
-                let _ = response.send(result);
+                let result = circuit_breaker
+                    .call(retry_policy.execute(db_type, true, || {
+                        worker.adapter.get_server_version(&worker.connection)
+                    }))
+                    .await;
+                status.respond(response, result);
                 Ok(())
             }
             DatabaseOperation::CreateStoredProcedure { config, response } => {
-                let result = worker
-                    .adapter
-                    .create_stored_procedure(&worker.connection, &config)
+                let result = circuit_breaker
+                    .call(retry_policy.execute(db_type, false, || {
+                        worker.adapter.create_stored_procedure(&worker.connection, &config)
+                    }))
+                    .await;
+                status.respond(response, result);
+                Ok(())
+            }
+            DatabaseOperation::GetConnection { response } => {
+                status.respond(response, Ok(worker.connection.clone()));
+                Ok(())
+            }
+            DatabaseOperation::DropStoredProcedure { procedure_name, response } => {
+                let result = circuit_breaker
+                    .call(retry_policy.execute(db_type, false, || {
+                        worker.adapter.drop_stored_procedure(&worker.connection, &procedure_name)
+                    }))
                     .await;
-                let _ = response.send(result);
+                status.respond(response, result);
                 Ok(())
             }
             DatabaseOperation::ExecuteStoredProcedure {
@@ -715,65 +1292,35 @@ impl MultiConnectionManager {
                 params,
                 response,
             } => {
-                let result = worker
-                    .adapter
-                    .execute_stored_procedure(
-                        &worker.connection,
-                        &procedure_name,
-                        &database,
-                        params,
-                    )
+                let result = circuit_breaker
+                    .call(retry_policy.execute(db_type, false, || {
+                        worker.adapter.execute_stored_procedure(
+                            &worker.connection,
+                            &procedure_name,
+                            &database,
+                            params.clone(),
+                        )
+                    }))
                     .await;
-                let _ = response.send(result);
+                status.respond(response, result);
                 Ok(())
             }
-        };
-
-        // 处理连接错误和重试逻辑
-        if let Err(ref e) = result {
-            let worker_id = worker.id.clone();
-            worker.retry_count += 1;
-            let retry_count = worker.retry_count;
-
-            error!(
-                "工作器 {} 操作失败 ({}/{}): {}",
-                worker_id, retry_count, self.config.max_retries, e
-            );
-
-            if retry_count > self.config.max_retries {
-                warn!("工作器 {} 重试次数超限，尝试重新创建连接", worker_id);
-
-                // 释放对 worker 的借用，然后重新创建连接
-                drop(worker);
-
-                // 尝试重新创建连接，但不退出程序
-                match self.create_connection_worker(worker_index).await {
-                    Ok(new_worker) => {
-                        self.workers[worker_index] = new_worker;
-                        debug!("工作器 {} 连接已重新创建", worker_index);
-                    }
-                    Err(create_err) => {
-                        error!("重新创建工作器 {} 连接失败: {}", worker_index, create_err);
-                        // 重新获取 worker 引用并重置计数
-                        if let Some(worker) = self.workers.get_mut(worker_index) {
-                            worker.retry_count = 0; // 重置计数，下次再试
-                        }
-                        // 延迟一段时间再重试
-                        tokio::time::sleep(Duration::from_millis(
-                            self.config.retry_interval_ms * 2,
-                        ))
-                        .await;
-                    }
-                }
-            }
-        } else {
-            // 操作成功，重置重试计数
-            worker.retry_count = 0;
         }
+    }
+}
 
-        // 归还工作器
-        self.available_workers.push(worker_index);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-        result
+    #[test]
+    fn reconnect_backoff_doubles_up_to_cap() {
+        let waits: Vec<u64> = (0..6)
+            .map(|attempt| reconnect_backoff(100, 1_000, attempt).as_millis() as u64)
+            .collect();
+        assert_eq!(waits, vec![100, 200, 400, 800, 1_000, 1_000]);
+        assert_eq!(reconnect_backoff(100, 1_000, u32::MAX), Duration::from_millis(1_000));
+        // 上限小于初始间隔时按初始间隔等待
+        assert_eq!(reconnect_backoff(500, 100, 3), Duration::from_millis(500));
     }
 }
@@ -0,0 +1,114 @@
+//! 连接初始化语句模块
+//!
+//! 按别名配置的 `DatabaseConfig::on_connect_statements` 在每个新建立的连接上执行，
+//! 用于设置 `search_path`、时区、会话变量等，不需要在每条查询中重复设置：
+//!
+//! - SQLite / PostgreSQL / MySQL：语句按原样执行（不预编译），连接池新建连接（含重连后）时依次执行，
+//!   任一语句失败时该连接被丢弃
+//! - MongoDB：驱动自行管理连接，每条语句是一个 JSON 命令文档，在建立客户端（含重连）后执行一次；
+//!   读关注等客户端级选项需要通过连接 URI 设置
+
+#[cfg(any(feature = "sqlite-support", feature = "postgres-support", feature = "mysql-support"))]
+use std::sync::Arc;
+
+/// 在具体数据库的连接上执行原始语句
+#[cfg(any(feature = "sqlite-support", feature = "postgres-support", feature = "mysql-support"))]
+pub(crate) trait RawExecute: sqlx::Database {
+    fn raw_execute<'c>(
+        connection: &'c mut Self::Connection,
+        sql: &'c str,
+    ) -> futures::future::BoxFuture<'c, Result<(), sqlx::Error>>;
+}
+
+macro_rules! impl_raw_execute {
+    ($feature:literal, $db:ty) => {
+        #[cfg(feature = $feature)]
+        impl RawExecute for $db {
+            fn raw_execute<'c>(
+                connection: &'c mut Self::Connection,
+                sql: &'c str,
+            ) -> futures::future::BoxFuture<'c, Result<(), sqlx::Error>> {
+                Box::pin(async move { sqlx::Executor::execute(&mut *connection, sql).await.map(|_| ()) })
+            }
+        }
+    };
+}
+
+impl_raw_execute!("sqlite-support", sqlx::Sqlite);
+impl_raw_execute!("postgres-support", sqlx::Postgres);
+impl_raw_execute!("mysql-support", sqlx::MySql);
+
+/// 为连接池注册初始化语句，每个新连接建立后依次执行
+#[cfg(any(feature = "sqlite-support", feature = "postgres-support", feature = "mysql-support"))]
+pub(crate) fn with_on_connect<DB: RawExecute>(
+    options: sqlx::pool::PoolOptions<DB>,
+    statements: &[String],
+) -> sqlx::pool::PoolOptions<DB> {
+    if statements.is_empty() {
+        return options;
+    }
+    let statements: Arc<[String]> = statements.into();
+    options.after_connect(move |connection, _| {
+        let statements = statements.clone();
+        Box::pin(async move {
+            for statement in statements.iter() {
+                DB::raw_execute(connection, statement).await?;
+            }
+            Ok(())
+        })
+    })
+}
+
+/// 解析 MongoDB 初始化命令（JSON 对象）
+#[cfg(feature = "mongodb-support")]
+pub(crate) fn parse_mongo_command(statement: &str) -> Option<mongodb::bson::Document> {
+    match serde_json::from_str::<serde_json::Value>(statement) {
+        Ok(value @ serde_json::Value::Object(_)) => mongodb::bson::to_document(&value).ok(),
+        _ => None,
+    }
+}
+
+/// 在 MongoDB 数据库上依次执行初始化命令
+#[cfg(feature = "mongodb-support")]
+pub(crate) async fn run_mongo_on_connect(
+    database: &mongodb::Database,
+    statements: &[String],
+) -> crate::error::QuickDbResult<()> {
+    for statement in statements {
+        let failed = |message: String| crate::error::QuickDbError::ConnectionError {
+            message: crate::i18n::tf("pool.on_connect_failed", &[("statement", statement), ("message", &message)]),
+        };
+        let command = parse_mongo_command(statement)
+            .ok_or_else(|| failed(crate::i18n::t("config.on_connect_mongo_command_invalid")))?;
+        database
+            .run_command(command, None)
+            .await
+            .map_err(|e| failed(e.to_string()))?;
+    }
+    Ok(())
+}
+
+#[cfg(all(test, feature = "sqlite-support"))]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn statements_run_on_every_new_connection() {
+        let statements = vec!["PRAGMA user_version = 7".to_string()];
+        let pool = with_on_connect(sqlx::sqlite::SqlitePoolOptions::new().max_connections(2), &statements)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+
+        // 两个独立的内存库连接都执行过初始化语句
+        let mut first = pool.acquire().await.unwrap();
+        let mut second = pool.acquire().await.unwrap();
+        for connection in [&mut first, &mut second] {
+            let version: i64 = sqlx::query_scalar("PRAGMA user_version")
+                .fetch_one(&mut **connection)
+                .await
+                .unwrap();
+            assert_eq!(version, 7);
+        }
+    }
+}
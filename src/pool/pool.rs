@@ -1,21 +1,26 @@
 //! 连接池核心模块
 
-use crossbeam_queue::SegQueue;
-use rat_logger::{debug, error, info, warn};
+use rat_logger::{debug, info};
 use std::collections::HashMap;
 use std::sync::Arc;
-use std::time::{Duration, Instant};
-use tokio::sync::{mpsc, oneshot};
+use std::time::Instant;
+use tokio::sync::oneshot;
 
 #[cfg(feature = "sqlite-support")]
 use super::SqliteWorker;
+#[cfg(feature = "sqlite-support")]
+use super::sqlite_worker::{is_sqlite_memory_path, shared_memory_path};
+#[cfg(feature = "sqlite-support")]
+use super::warm_up;
 use super::{
     DatabaseConnection, DatabaseOperation, ExtendedPoolConfig, MultiConnectionManager,
-    PooledConnection,
+    PooledConnection, RetryPolicy,
 };
-use crate::adapter::DatabaseAdapter;
+use super::{AdaptiveSizer, CircuitBreaker, CircuitState, OperationSender, PoolSizingMetrics, QueuedOperation, operation_channel};
+use super::{PoolStatus, PoolStatusTracker};
 use crate::error::{QuickDbError, QuickDbResult};
-use crate::model::FieldDefinition;
+use crate::model::{FieldDefinition, IndexOptions};
+use crate::context::RequestReceiver;
 use crate::types::*;
 
 /// 新的连接池 - 基于生产者/消费者模式
@@ -26,11 +31,17 @@ pub struct ConnectionPool {
     /// 扩展连接池配置
     pub config: ExtendedPoolConfig,
     /// 操作请求发送器
-    pub operation_sender: mpsc::UnboundedSender<DatabaseOperation>,
+    pub operation_sender: OperationSender,
     /// 数据库类型
     pub db_type: DatabaseType,
     /// 缓存管理器（可选）
     pub cache_manager: Option<Arc<crate::cache::CacheManager>>,
+    /// 熔断器（与操作消费者共享）
+    pub circuit_breaker: Arc<CircuitBreaker>,
+    /// 自适应扩缩容控制器（与多连接管理器共享）
+    pub sizer: Arc<AdaptiveSizer>,
+    /// 运行时状态计数器（与操作消费者共享）
+    pub status: Arc<PoolStatusTracker>,
 }
 
 impl ConnectionPool {
@@ -45,19 +56,39 @@ impl ConnectionPool {
     /// 使用配置和缓存管理器创建连接池
     pub async fn with_config_and_cache(
         db_config: DatabaseConfig,
-        config: ExtendedPoolConfig,
+        mut config: ExtendedPoolConfig,
         cache_manager: Option<Arc<crate::cache::CacheManager>>,
     ) -> QuickDbResult<Self> {
-        let (operation_sender, operation_receiver) = mpsc::unbounded_channel();
+        // 事务级 PgBouncer 会在事务之间切换后端连接，连接上缓存的预编译语句不可复用
+        if matches!(db_config.connection, ConnectionConfig::PostgreSQL { pgbouncer_mode: true, .. }) {
+            config.base.statement_cache_capacity = 0;
+        }
+        let status = Arc::new(PoolStatusTracker::new());
+        let (operation_sender, operation_receiver) =
+            operation_channel(&db_config.alias, &config.base, status.clone());
+        // SQLite 使用单工作器串行执行，不参与扩缩容
+        let sizer = if db_config.db_type == DatabaseType::SQLite {
+            AdaptiveSizer::new(1, 1)
+        } else {
+            AdaptiveSizer::from_config(&config)
+        };
 
         let pool = Self {
-            db_type: db_config.db_type.clone(),
+            db_type: db_config.db_type,
             db_config: db_config.clone(),
             config: config.clone(),
             operation_sender,
             cache_manager: cache_manager.clone(),
+            circuit_breaker: Arc::new(CircuitBreaker::from_config(&db_config, &config)),
+            sizer: Arc::new(sizer),
+            status,
         };
 
+        crate::adapter::statement_cache::register_statement_cache(
+            &db_config.alias,
+            config.base.statement_cache_capacity,
+        );
+
         // 根据数据库类型启动对应的工作器
         match &db_config.db_type {
             #[cfg(feature = "sqlite-support")]
@@ -89,33 +120,76 @@ impl ConnectionPool {
         Ok(pool)
     }
 
+    /// 创建以模拟适配器处理操作的连接池（不建立任何数据库连接）
+    #[cfg(feature = "testing")]
+    pub(crate) fn with_mock_adapter(
+        db_config: DatabaseConfig,
+        config: ExtendedPoolConfig,
+        adapter: crate::adapter::MockAdapter,
+    ) -> Self {
+        let status = Arc::new(PoolStatusTracker::new());
+        let (operation_sender, operation_receiver) =
+            operation_channel(&db_config.alias, &config.base, status.clone());
+        let pool = Self {
+            db_type: db_config.db_type,
+            db_config: db_config.clone(),
+            operation_sender,
+            cache_manager: None,
+            circuit_breaker: Arc::new(CircuitBreaker::from_config(&db_config, &config)),
+            sizer: Arc::new(AdaptiveSizer::new(1, 1)),
+            status,
+            config,
+        };
+
+        let worker = super::mock_worker::MockWorker {
+            alias: db_config.alias,
+            operation_receiver,
+            adapter,
+            status: pool.status.clone(),
+        };
+        tokio::spawn(worker.run());
+        pool
+    }
+
     /// 设置缓存管理器
     pub fn set_cache_manager(&mut self, cache_manager: Arc<crate::cache::CacheManager>) {
         self.cache_manager = Some(cache_manager);
     }
 
+    /// 获取熔断器当前状态
+    pub fn circuit_state(&self) -> CircuitState {
+        self.circuit_breaker.state()
+    }
+
+    /// 获取自适应扩缩容指标
+    pub fn sizing_metrics(&self) -> PoolSizingMetrics {
+        self.sizer.metrics()
+    }
+
+    /// 获取运行时状态快照
+    pub fn status(&self) -> PoolStatus {
+        self.status.snapshot()
+    }
+
     /// 启动SQLite工作器
     #[cfg(feature = "sqlite-support")]
     async fn start_sqlite_worker(
         &self,
-        operation_receiver: mpsc::UnboundedReceiver<DatabaseOperation>,
+        operation_receiver: RequestReceiver<QueuedOperation>,
         db_config: DatabaseConfig,
         config: ExtendedPoolConfig,
     ) -> QuickDbResult<()> {
         let connection = self.create_sqlite_connection().await?;
+        if config.base.connect_mode == ConnectMode::Eager {
+            warm_up(&db_config.alias, &connection, config.base.min_connections).await?;
+        }
 
         // 创建启动同步通道
         let (startup_tx, startup_rx) = oneshot::channel();
 
         // 创建适配器
-        use crate::adapter::{create_adapter, create_adapter_with_cache};
-        let (adapter, adapter_type) = if let Some(cache_manager) = &self.cache_manager {
-            let adapter = create_adapter_with_cache(&db_config.db_type, cache_manager.clone())?;
-            (adapter, "缓存适配器")
-        } else {
-            let adapter = create_adapter(&db_config.db_type)?;
-            (adapter, "普通适配器")
-        };
+        let adapter = crate::adapter::create_adapter_for_config(&db_config, self.cache_manager.clone())?;
+        let adapter_type = if self.cache_manager.is_some() { "缓存适配器" } else { "普通适配器" };
 
         info!("数据库 '{}' 使用 {}", db_config.alias, adapter_type);
 
@@ -126,7 +200,12 @@ impl ConnectionPool {
             retry_count: 0,
             max_retries: config.max_retries,
             retry_interval_ms: config.retry_interval_ms,
-            health_check_interval_sec: config.health_check_timeout_sec, // 复用健康检查超时作为间隔
+            retry_policy: RetryPolicy::from_config(&config),
+            circuit_breaker: self.circuit_breaker.clone(),
+            status: self.status.clone(),
+            keepalive_interval_sec: config.keepalive_interval_sec,
+            health_check_timeout_sec: config.health_check_timeout_sec,
+            write_batch: config.base.write_batch,
             last_health_check: Instant::now(),
             is_healthy: true,
             cache_manager: self.cache_manager.clone(),
@@ -150,6 +229,7 @@ impl ConnectionPool {
                 ),
             })?;
 
+        self.sizer.set_load(1, 0, 0);
         info!("SQLite工作器启动完成: 别名={}", db_config.alias);
         Ok(())
     }
@@ -157,20 +237,30 @@ impl ConnectionPool {
     /// 启动多连接管理器
     async fn start_multi_connection_manager(
         &self,
-        operation_receiver: mpsc::UnboundedReceiver<DatabaseOperation>,
+        operation_receiver: RequestReceiver<QueuedOperation>,
         db_config: DatabaseConfig,
         config: ExtendedPoolConfig,
     ) -> QuickDbResult<()> {
-        let manager = MultiConnectionManager {
+        let mut manager = MultiConnectionManager {
             workers: Vec::new(),
-            available_workers: SegQueue::new(),
+            shared_connection: None,
+            next_worker_index: 0,
+            sizer: self.sizer.clone(),
+            status: self.status.clone(),
             operation_receiver,
             db_config,
+            retry_policy: RetryPolicy::from_config(&config),
+            circuit_breaker: self.circuit_breaker.clone(),
             config,
-            keepalive_handle: None,
+            reconnect_attempts: None,
             cache_manager: self.cache_manager.clone(),
         };
 
+        // 立即连接模式下在返回前建立并验证连接，连接失败直接返回错误
+        if manager.config.base.connect_mode == ConnectMode::Eager {
+            manager.warm_up().await?;
+        }
+
         // 启动管理器
         tokio::spawn(async move {
             manager.run().await;
@@ -182,11 +272,13 @@ impl ConnectionPool {
     /// 创建SQLite连接
     #[cfg(feature = "sqlite-support")]
     async fn create_sqlite_connection(&self) -> QuickDbResult<DatabaseConnection> {
-        let (path, create_if_missing) = match &self.db_config.connection {
+        let (path, create_if_missing, pragmas, encryption_key) = match &self.db_config.connection {
             crate::types::ConnectionConfig::SQLite {
                 path,
                 create_if_missing,
-            } => (path.clone(), *create_if_missing),
+                pragmas,
+                encryption_key,
+            } => (path.clone(), *create_if_missing, pragmas.clone(), encryption_key.clone()),
             _ => {
                 return Err(QuickDbError::ConfigError {
                     message: crate::i18n::t("error.sqlite_config_mismatch"),
@@ -195,9 +287,10 @@ impl ConnectionPool {
         };
 
         // 特殊处理内存数据库：直接连接，不创建文件
-        if path == ":memory:" {
-            info!("连接SQLite内存数据库: 别名={}", self.db_config.alias);
-            let pool = sqlx::SqlitePool::connect(&path).await.map_err(|e| {
+        if is_sqlite_memory_path(&path) {
+            let path = shared_memory_path(&path, &self.db_config.alias);
+            info!("连接SQLite内存数据库: 别名={}, 路径={}", self.db_config.alias, path);
+            let pool = self.connect_sqlite_pool(&path, pragmas.as_ref(), encryption_key.as_deref()).await.map_err(|e| {
                 QuickDbError::ConnectionError {
                     message: crate::i18n::tf("error.sqlite_memory", &[("message", &e.to_string())]),
                 }
@@ -240,7 +333,7 @@ impl ConnectionPool {
         }
 
         let pool =
-            sqlx::SqlitePool::connect(&path)
+            self.connect_sqlite_pool(&path, pragmas.as_ref(), encryption_key.as_deref())
                 .await
                 .map_err(|e| QuickDbError::ConnectionError {
                     message: crate::i18n::tf(
@@ -251,15 +344,17 @@ impl ConnectionPool {
         Ok(DatabaseConnection::SQLite(pool))
     }
 
-    /// 发送操作请求并等待响应
-    async fn send_operation<T>(&self, operation: DatabaseOperation) -> QuickDbResult<T>
-    where
-        T: Send + 'static,
-    {
-        // 这是一个泛型占位符，实际实现需要根据具体操作类型来处理
-        Err(QuickDbError::QueryError {
-            message: crate::i18n::t("pool.operation_not_implemented"),
-        })
+    /// 按连接池配置连接SQLite（设置预编译语句缓存容量，应用 PRAGMA 配置）
+    #[cfg(feature = "sqlite-support")]
+    async fn connect_sqlite_pool(
+        &self,
+        path: &str,
+        pragmas: Option<&SqlitePragmaConfig>,
+        encryption_key: Option<&str>,
+    ) -> Result<sqlx::SqlitePool, sqlx::Error> {
+        let options = super::sqlite_worker::sqlite_connect_options(path, pragmas, encryption_key)?
+            .statement_cache_capacity(self.config.base.statement_cache_capacity);
+        super::sqlite_worker::open_sqlite_pool(path, options, &self.db_config.on_connect_statements).await
     }
 
     /// 创建记录
@@ -281,9 +376,7 @@ impl ConnectionPool {
 
         self.operation_sender
             .send(operation)
-            .map_err(|_| QuickDbError::QueryError {
-                message: crate::i18n::t("pool.send_operation_failed"),
-            })?;
+            .await?;
 
         response_receiver
             .await
@@ -309,9 +402,29 @@ impl ConnectionPool {
 
         self.operation_sender
             .send(operation)
+            .await?;
+
+        response_receiver
+            .await
             .map_err(|_| QuickDbError::QueryError {
-                message: crate::i18n::t("pool.send_operation_failed"),
-            })?;
+                message: crate::i18n::t("pool.receive_response_failed"),
+            })?
+    }
+
+    /// 根据ID检查记录是否存在
+    pub async fn exists_by_id(&self, table: &str, id: &DataValue) -> QuickDbResult<bool> {
+        let (response_sender, response_receiver) = oneshot::channel();
+
+        let operation = DatabaseOperation::ExistsById {
+            table: table.to_string(),
+            id: id.clone(),
+            alias: self.db_config.alias.clone(),
+            response: response_sender,
+        };
+
+        self.operation_sender
+            .send(operation)
+            .await?;
 
         response_receiver
             .await
@@ -339,9 +452,37 @@ impl ConnectionPool {
 
         self.operation_sender
             .send(operation)
+            .await?;
+
+        response_receiver
+            .await
             .map_err(|_| QuickDbError::QueryError {
-                message: crate::i18n::t("pool.send_operation_failed"),
-            })?;
+                message: crate::i18n::t("pool.receive_response_failed"),
+            })?
+    }
+
+    /// 使用条件组查找记录（可跳过缓存）
+    pub async fn find_with_groups(
+        &self,
+        table: &str,
+        condition_groups: &[QueryConditionGroupWithConfig],
+        options: &QueryOptions,
+        bypass_cache: bool,
+    ) -> QuickDbResult<Vec<DataValue>> {
+        let (response_sender, response_receiver) = oneshot::channel();
+
+        let operation = DatabaseOperation::FindWithGroupsWithBypassCache {
+            table: table.to_string(),
+            condition_groups: condition_groups.to_vec(),
+            options: options.clone(),
+            alias: self.db_config.alias.clone(),
+            bypass_cache,
+            response: response_sender,
+        };
+
+        self.operation_sender
+            .send(operation)
+            .await?;
 
         response_receiver
             .await
@@ -356,6 +497,17 @@ impl ConnectionPool {
         table: &str,
         conditions: &[QueryConditionWithConfig],
         data: &HashMap<String, DataValue>,
+    ) -> QuickDbResult<u64> {
+        self.update_with_options(table, conditions, data, UpdateOptions::default()).await
+    }
+
+    /// 更新记录（支持超时等更新选项）
+    pub async fn update_with_options(
+        &self,
+        table: &str,
+        conditions: &[QueryConditionWithConfig],
+        data: &HashMap<String, DataValue>,
+        options: UpdateOptions,
     ) -> QuickDbResult<u64> {
         let (response_sender, response_receiver) = oneshot::channel();
 
@@ -363,15 +515,14 @@ impl ConnectionPool {
             table: table.to_string(),
             conditions: conditions.to_vec(),
             data: data.clone(),
+            options,
             alias: self.db_config.alias.clone(),
             response: response_sender,
         };
 
         self.operation_sender
             .send(operation)
-            .map_err(|_| QuickDbError::QueryError {
-                message: crate::i18n::t("pool.send_operation_failed"),
-            })?;
+            .await?;
 
         response_receiver
             .await
@@ -399,9 +550,35 @@ impl ConnectionPool {
 
         self.operation_sender
             .send(operation)
+            .await?;
+
+        response_receiver
+            .await
             .map_err(|_| QuickDbError::QueryError {
-                message: crate::i18n::t("pool.send_operation_failed"),
-            })?;
+                message: crate::i18n::t("pool.receive_response_failed"),
+            })?
+    }
+
+    /// 根据ID更新记录并返回更新后的记录
+    pub async fn update_by_id_returning(
+        &self,
+        table: &str,
+        id: &DataValue,
+        data: &HashMap<String, DataValue>,
+    ) -> QuickDbResult<Option<DataValue>> {
+        let (response_sender, response_receiver) = oneshot::channel();
+
+        let operation = DatabaseOperation::UpdateByIdReturning {
+            table: table.to_string(),
+            id: id.clone(),
+            data: data.clone(),
+            alias: self.db_config.alias.clone(),
+            response: response_sender,
+        };
+
+        self.operation_sender
+            .send(operation)
+            .await?;
 
         response_receiver
             .await
@@ -428,9 +605,7 @@ impl ConnectionPool {
 
         self.operation_sender
             .send(operation)
-            .map_err(|_| QuickDbError::QueryError {
-                message: crate::i18n::t("pool.send_operation_failed"),
-            })?;
+            .await?;
 
         response_receiver
             .await
@@ -457,9 +632,63 @@ impl ConnectionPool {
 
         self.operation_sender
             .send(operation)
+            .await?;
+
+        response_receiver
+            .await
             .map_err(|_| QuickDbError::QueryError {
-                message: crate::i18n::t("pool.send_operation_failed"),
-            })?;
+                message: crate::i18n::t("pool.receive_response_failed"),
+            })?
+    }
+
+    /// 根据ID删除记录并返回被删除的记录
+    pub async fn delete_by_id_returning(
+        &self,
+        table: &str,
+        id: &DataValue,
+        alias: &str,
+    ) -> QuickDbResult<Option<DataValue>> {
+        let (response_sender, response_receiver) = oneshot::channel();
+
+        let operation = DatabaseOperation::DeleteByIdReturning {
+            table: table.to_string(),
+            id: id.clone(),
+            alias: alias.to_string(),
+            response: response_sender,
+        };
+
+        self.operation_sender
+            .send(operation)
+            .await?;
+
+        response_receiver
+            .await
+            .map_err(|_| QuickDbError::QueryError {
+                message: crate::i18n::t("pool.receive_response_failed"),
+            })?
+    }
+
+    /// 批量执行混合写操作，插入使用连接池配置的ID策略
+    pub async fn bulk_write(
+        &self,
+        table: &str,
+        ops: Vec<BulkOp>,
+        mode: BulkWriteMode,
+    ) -> QuickDbResult<BulkWriteReport> {
+        let (response_sender, response_receiver) = oneshot::channel();
+
+        let operation = DatabaseOperation::BulkWrite {
+            table: table.to_string(),
+            ops,
+            mode,
+            id_strategy: self.db_config.id_strategy.clone(),
+            alias: self.db_config.alias.clone(),
+            response: response_sender,
+        };
+
+        self.operation_sender
+            .send(operation)
+            .await?;
 
         response_receiver
             .await
@@ -486,9 +715,24 @@ impl ConnectionPool {
 
         self.operation_sender
             .send(operation)
+            .await?;
+
+        response_receiver
+            .await
             .map_err(|_| QuickDbError::QueryError {
-                message: crate::i18n::t("pool.send_operation_failed"),
-            })?;
+                message: crate::i18n::t("pool.receive_response_failed"),
+            })?
+    }
+
+    /// 查询数据库服务器版本
+    pub async fn server_version(&self) -> QuickDbResult<String> {
+        let (response_sender, response_receiver) = oneshot::channel();
+
+        self.operation_sender
+            .send(DatabaseOperation::GetServerVersion {
+                response: response_sender,
+            })
+            .await?;
 
         response_receiver
             .await
@@ -516,9 +760,7 @@ impl ConnectionPool {
 
         self.operation_sender
             .send(operation)
-            .map_err(|_| QuickDbError::QueryError {
-                message: crate::i18n::t("pool.send_operation_failed"),
-            })?;
+            .await?;
 
         response_receiver
             .await
@@ -534,6 +776,7 @@ impl ConnectionPool {
         index_name: &str,
         fields: &[String],
         unique: bool,
+        options: &IndexOptions,
     ) -> QuickDbResult<()> {
         let (response_sender, response_receiver) = oneshot::channel();
 
@@ -542,14 +785,38 @@ impl ConnectionPool {
             index_name: index_name.to_string(),
             fields: fields.to_vec(),
             unique,
+            options: options.clone(),
             response: response_sender,
         };
 
         self.operation_sender
             .send(operation)
+            .await?;
+
+        response_receiver
+            .await
             .map_err(|_| QuickDbError::QueryError {
-                message: crate::i18n::t("pool.send_operation_failed"),
-            })?;
+                message: crate::i18n::t("pool.receive_response_failed"),
+            })?
+    }
+
+    /// 获取底层数据库连接句柄
+    ///
+    /// 供需要独占会话的功能（如咨询锁）从同一连接池借出连接
+    #[cfg(any(
+        feature = "sqlite-support",
+        feature = "postgres-support",
+        feature = "mysql-support",
+        feature = "mongodb-support"
+    ))]
+    pub(crate) async fn database_connection(&self) -> QuickDbResult<DatabaseConnection> {
+        let (response_sender, response_receiver) = oneshot::channel();
+
+        self.operation_sender
+            .send(DatabaseOperation::GetConnection {
+                response: response_sender,
+            })
+            .await?;
 
         response_receiver
             .await
@@ -569,9 +836,27 @@ impl ConnectionPool {
 
         self.operation_sender
             .send(operation)
+            .await?;
+
+        response_receiver
+            .await
             .map_err(|_| QuickDbError::QueryError {
-                message: crate::i18n::t("pool.send_operation_failed"),
-            })?;
+                message: crate::i18n::t("pool.receive_response_failed"),
+            })?
+    }
+
+    /// 估算表记录数（读取统计信息，不扫描全表）
+    pub async fn count_estimate(&self, table: &str) -> QuickDbResult<CountEstimate> {
+        let (response_sender, response_receiver) = oneshot::channel();
+
+        let operation = DatabaseOperation::CountEstimate {
+            table: table.to_string(),
+            response: response_sender,
+        };
+
+        self.operation_sender
+            .send(operation)
+            .await?;
 
         response_receiver
             .await
@@ -591,9 +876,7 @@ impl ConnectionPool {
 
         self.operation_sender
             .send(operation)
-            .map_err(|_| QuickDbError::QueryError {
-                message: crate::i18n::t("pool.send_operation_failed"),
-            })?;
+            .await?;
 
         response_receiver
             .await
@@ -613,7 +896,7 @@ impl ConnectionPool {
         // 这个方法主要用于兼容性，返回一个虚拟连接
         Ok(PooledConnection {
             id: format!("{}-virtual", self.db_config.alias),
-            db_type: self.db_config.db_type.clone(),
+            db_type: self.db_config.db_type,
             alias: self.db_config.alias.clone(),
         })
     }
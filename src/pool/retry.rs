@@ -0,0 +1,427 @@
+//! 瞬时错误重试策略模块
+//!
+//! 按数据库驱动错误码识别死锁、序列化失败、锁等待和连接中断等瞬时错误
+//! （错误消息中没有错误码时回退到错误文本匹配），
+//! 并以指数退避加随机抖动的方式重试操作
+
+use rand::Rng;
+use rat_logger::warn;
+use std::future::Future;
+use std::time::Duration;
+
+use super::ExtendedPoolConfig;
+use crate::error::{QuickDbError, QuickDbResult};
+use crate::types::DatabaseType;
+
+/// 单次退避的最大延迟（毫秒）
+const MAX_BACKOFF_MS: u64 = 30_000;
+
+/// 瞬时错误类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransientErrorKind {
+    /// 死锁，事务已被数据库回滚
+    Deadlock,
+    /// 序列化失败或写冲突，事务已被数据库回滚
+    SerializationFailure,
+    /// 锁等待超时或数据库忙
+    LockTimeout,
+    /// 连接中断，操作是否已生效无法确定
+    ConnectionLost,
+}
+
+impl TransientErrorKind {
+    /// 操作是否确定未生效（可安全重试非幂等操作）
+    pub fn is_rolled_back(&self) -> bool {
+        !matches!(self, TransientErrorKind::ConnectionLost)
+    }
+}
+
+/// 重试策略
+///
+/// 最大重试次数与基础间隔来自 `PoolConfig` 的 `max_retries` / `retry_interval_ms`，
+/// 第 n 次重试前等待 `base * 2^(n-1)` 毫秒（上限30秒），并叠加最多50%的随机抖动
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// 最大重试次数（不含首次执行）
+    pub max_retries: u32,
+    /// 基础重试间隔（毫秒）
+    pub base_delay_ms: u64,
+    /// 最大重试间隔（毫秒）
+    pub max_delay_ms: u64,
+}
+
+impl RetryPolicy {
+    /// 创建重试策略
+    pub fn new(max_retries: u32, base_delay_ms: u64) -> Self {
+        Self {
+            max_retries,
+            base_delay_ms,
+            max_delay_ms: MAX_BACKOFF_MS,
+        }
+    }
+
+    /// 从连接池扩展配置创建重试策略
+    pub fn from_config(config: &ExtendedPoolConfig) -> Self {
+        Self::new(config.max_retries, config.retry_interval_ms)
+    }
+
+    /// 识别瞬时错误，非瞬时错误返回 None
+    ///
+    /// 错误消息中带有驱动错误码时只按错误码判断，否则按错误文本匹配
+    pub fn classify(db_type: DatabaseType, error: &QuickDbError) -> Option<TransientErrorKind> {
+        let message = match error {
+            QuickDbError::QueryError { message } | QuickDbError::ConnectionError { message } => message,
+            _ => return None,
+        };
+
+        match Self::classify_code(db_type, message) {
+            Some(kind) => kind,
+            None => Self::classify_message(db_type, &message.to_lowercase()),
+        }
+    }
+
+    /// 按驱动错误码识别瞬时错误，消息中没有错误码时返回 None
+    ///
+    /// - PostgreSQL: SQLSTATE（适配器以 `SQLSTATE xxxxx` 附加在消息中）
+    /// - MySQL: 服务器错误号（sqlx 格式 `error returned from database: 1213 (40001): ...`）
+    /// - SQLite: 结果码（sqlx 格式 `(code: 5) ...`，扩展结果码取低8位）
+    /// - MongoDB: 错误标签与服务器错误码
+    fn classify_code(db_type: DatabaseType, message: &str) -> Option<Option<TransientErrorKind>> {
+        match db_type {
+            DatabaseType::PostgreSQL => {
+                let kind = match code_after(message, "SQLSTATE ")? {
+                    "40P01" => Some(TransientErrorKind::Deadlock),
+                    "40001" => Some(TransientErrorKind::SerializationFailure),
+                    "55P03" => Some(TransientErrorKind::LockTimeout),
+                    "57P01" | "57P03" => Some(TransientErrorKind::ConnectionLost),
+                    _ => None,
+                };
+                Some(kind)
+            }
+            DatabaseType::MySQL => {
+                let errno: u32 = code_after(message, "error returned from database: ")?.parse().ok()?;
+                let kind = match errno {
+                    1213 => Some(TransientErrorKind::Deadlock),
+                    1205 => Some(TransientErrorKind::LockTimeout),
+                    2006 | 2013 => Some(TransientErrorKind::ConnectionLost),
+                    _ => None,
+                };
+                Some(kind)
+            }
+            DatabaseType::SQLite => {
+                let code: u32 = code_after(message, "(code: ")?.parse().ok()?;
+                // SQLITE_BUSY = 5, SQLITE_LOCKED = 6
+                let kind = match code & 0xff {
+                    5 | 6 => Some(TransientErrorKind::LockTimeout),
+                    _ => None,
+                };
+                Some(kind)
+            }
+            DatabaseType::MongoDB => {
+                if message.contains("\"TransientTransactionError\"") {
+                    return Some(Some(TransientErrorKind::SerializationFailure));
+                }
+                if message.contains("\"RetryableWriteError\"") {
+                    return Some(Some(TransientErrorKind::ConnectionLost));
+                }
+                let code: i32 = code_after(message, "Error code ")
+                    .or_else(|| code_after(message, "code: "))?
+                    .parse()
+                    .ok()?;
+                let kind = match code {
+                    // WriteConflict
+                    112 => Some(TransientErrorKind::SerializationFailure),
+                    // 主节点切换、节点关闭或网络错误
+                    6 | 7 | 89 | 91 | 189 | 9001 | 10107 | 11600 | 11602 | 13435 | 13436 => {
+                        Some(TransientErrorKind::ConnectionLost)
+                    }
+                    _ => None,
+                };
+                Some(kind)
+            }
+        }
+    }
+
+    /// 按错误文本识别瞬时错误（消息需已转为小写）
+    fn classify_message(db_type: DatabaseType, message: &str) -> Option<TransientErrorKind> {
+        let patterns: &[(&str, TransientErrorKind)] = match db_type {
+            DatabaseType::PostgreSQL => &[
+                ("deadlock detected", TransientErrorKind::Deadlock),
+                ("could not serialize access", TransientErrorKind::SerializationFailure),
+                ("lock timeout", TransientErrorKind::LockTimeout),
+                ("terminating connection", TransientErrorKind::ConnectionLost),
+                ("the database system is starting up", TransientErrorKind::ConnectionLost),
+            ],
+            DatabaseType::MySQL => &[
+                ("deadlock found", TransientErrorKind::Deadlock),
+                ("lock wait timeout exceeded", TransientErrorKind::LockTimeout),
+                ("server has gone away", TransientErrorKind::ConnectionLost),
+                ("lost connection", TransientErrorKind::ConnectionLost),
+            ],
+            DatabaseType::SQLite => &[
+                ("database is locked", TransientErrorKind::LockTimeout),
+                ("database table is locked", TransientErrorKind::LockTimeout),
+                ("database is busy", TransientErrorKind::LockTimeout),
+            ],
+            DatabaseType::MongoDB => &[
+                ("writeconflict", TransientErrorKind::SerializationFailure),
+                ("write conflict", TransientErrorKind::SerializationFailure),
+                ("transienttransactionerror", TransientErrorKind::SerializationFailure),
+                ("notwritableprimary", TransientErrorKind::ConnectionLost),
+                ("not master", TransientErrorKind::ConnectionLost),
+                ("node is recovering", TransientErrorKind::ConnectionLost),
+                ("connection pool", TransientErrorKind::ConnectionLost),
+            ],
+        };
+
+        // 各数据库通用的网络层错误
+        let common: &[(&str, TransientErrorKind)] = &[
+            ("connection reset", TransientErrorKind::ConnectionLost),
+            ("connection refused", TransientErrorKind::ConnectionLost),
+            ("connection closed", TransientErrorKind::ConnectionLost),
+            ("connection aborted", TransientErrorKind::ConnectionLost),
+            ("broken pipe", TransientErrorKind::ConnectionLost),
+            ("unexpected eof", TransientErrorKind::ConnectionLost),
+            ("pool timed out", TransientErrorKind::ConnectionLost),
+        ];
+
+        patterns
+            .iter()
+            .chain(common.iter())
+            .find(|(pattern, _)| message.contains(pattern))
+            .map(|(_, kind)| *kind)
+    }
+
+    /// 计算第 attempt 次重试（从1开始）前的等待时间
+    pub fn backoff_delay(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(16);
+        let delay = self
+            .base_delay_ms
+            .saturating_mul(1 << exponent)
+            .min(self.max_delay_ms);
+        let jitter = if delay > 0 {
+            rand::thread_rng().gen_range(0..=delay / 2)
+        } else {
+            0
+        };
+        Duration::from_millis(delay + jitter)
+    }
+
+    /// 按策略执行操作
+    ///
+    /// `idempotent` 为 false 时只重试确定已回滚的错误（死锁、序列化失败、锁等待），
+    /// 连接中断可能发生在提交之后，重试会导致重复写入
+    pub async fn execute<T, F, Fut>(
+        &self,
+        db_type: DatabaseType,
+        idempotent: bool,
+        mut operation: F,
+    ) -> QuickDbResult<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = QuickDbResult<T>>,
+    {
+        let mut attempt = 0;
+        loop {
+            let error = match operation().await {
+                Ok(value) => return Ok(value),
+                Err(error) => error,
+            };
+
+            let retryable = Self::classify(db_type, &error)
+                .is_some_and(|kind| idempotent || kind.is_rolled_back());
+            if !retryable || attempt >= self.max_retries {
+                return Err(error);
+            }
+
+            attempt += 1;
+            let delay = self.backoff_delay(attempt);
+            warn!(
+                "瞬时错误，{}ms后重试 ({}/{}): {}",
+                delay.as_millis(),
+                attempt,
+                self.max_retries,
+                error
+            );
+            tokio::time::sleep(delay).await;
+        }
+    }
+}
+
+/// 提取标记之后的错误码（到第一个非字母数字字符为止）
+fn code_after<'a>(message: &'a str, marker: &str) -> Option<&'a str> {
+    let start = message.find(marker)? + marker.len();
+    let rest = &message[start..];
+    let end = rest
+        .find(|c: char| !c.is_ascii_alphanumeric())
+        .unwrap_or(rest.len());
+    (end > 0).then(|| &rest[..end])
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::from_config(&ExtendedPoolConfig::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn query_error(message: &str) -> QuickDbError {
+        QuickDbError::QueryError {
+            message: message.to_string(),
+        }
+    }
+
+    #[test]
+    fn classify_transient_errors_per_backend() {
+        assert_eq!(
+            RetryPolicy::classify(
+                DatabaseType::PostgreSQL,
+                &query_error("error returned from database: deadlock detected")
+            ),
+            Some(TransientErrorKind::Deadlock)
+        );
+        assert_eq!(
+            RetryPolicy::classify(
+                DatabaseType::MySQL,
+                &query_error("Deadlock found when trying to get lock; try restarting transaction")
+            ),
+            Some(TransientErrorKind::Deadlock)
+        );
+        assert_eq!(
+            RetryPolicy::classify(
+                DatabaseType::SQLite,
+                &query_error("执行SQLite插入失败: (code: 5) database is locked")
+            ),
+            Some(TransientErrorKind::LockTimeout)
+        );
+        // 其他数据库的错误文本不会被误判
+        assert_eq!(
+            RetryPolicy::classify(DatabaseType::SQLite, &query_error("deadlock detected")),
+            None
+        );
+        assert_eq!(
+            RetryPolicy::classify(
+                DatabaseType::MongoDB,
+                &QuickDbError::ValidationError {
+                    field: "id".to_string(),
+                    message: "connection reset".to_string(),
+                }
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn classify_on_driver_error_codes() {
+        let cases = [
+            (
+                DatabaseType::PostgreSQL,
+                "执行PostgreSQL更新失败: error returned from database: deadlock detected (SQLSTATE 40P01)",
+                Some(TransientErrorKind::Deadlock),
+            ),
+            (
+                DatabaseType::PostgreSQL,
+                "提交PostgreSQL事务失败: error returned from database: could not serialize (SQLSTATE 40001)",
+                Some(TransientErrorKind::SerializationFailure),
+            ),
+            (
+                DatabaseType::PostgreSQL,
+                "error returned from database: canceling statement (SQLSTATE 55P03)",
+                Some(TransientErrorKind::LockTimeout),
+            ),
+            (
+                DatabaseType::PostgreSQL,
+                "error returned from database: terminating connection (SQLSTATE 57P01)",
+                Some(TransientErrorKind::ConnectionLost),
+            ),
+            (
+                DatabaseType::MySQL,
+                "执行MySQL查询失败: error returned from database: 1205 (HY000): Lock wait timeout exceeded",
+                Some(TransientErrorKind::LockTimeout),
+            ),
+            (
+                DatabaseType::MySQL,
+                "error returned from database: 2013: Lost connection to MySQL server during query",
+                Some(TransientErrorKind::ConnectionLost),
+            ),
+            (
+                DatabaseType::SQLite,
+                "error returned from database: (code: 517) database is locked",
+                Some(TransientErrorKind::LockTimeout),
+            ),
+            (
+                DatabaseType::SQLite,
+                "error returned from database: (code: 6) database table is locked",
+                Some(TransientErrorKind::LockTimeout),
+            ),
+            (
+                DatabaseType::MongoDB,
+                "Kind: Command failed: Error code 251 (NoSuchTransaction): aborted, labels: {\"TransientTransactionError\"}",
+                Some(TransientErrorKind::SerializationFailure),
+            ),
+            (
+                DatabaseType::MongoDB,
+                "Kind: Command failed: Error code 11602 (InterruptedDueToReplStateChange): stepdown, labels: {}",
+                Some(TransientErrorKind::ConnectionLost),
+            ),
+            // 错误码优先于错误文本：非瞬时错误码即使文本匹配也不重试
+            (
+                DatabaseType::PostgreSQL,
+                "error returned from database: deadlock detected in trigger (SQLSTATE 23505)",
+                None,
+            ),
+            (
+                DatabaseType::MySQL,
+                "error returned from database: 1062 (23000): Duplicate entry, lost connection",
+                None,
+            ),
+        ];
+        for (db_type, message, expected) in cases {
+            assert_eq!(RetryPolicy::classify(db_type, &query_error(message)), expected, "{}", message);
+        }
+    }
+
+    #[test]
+    fn backoff_delay_grows_and_is_capped() {
+        let policy = RetryPolicy::new(5, 100);
+        for attempt in 1..=3 {
+            let base = 100u64 << (attempt - 1);
+            let delay = policy.backoff_delay(attempt).as_millis() as u64;
+            assert!(delay >= base && delay <= base + base / 2);
+        }
+        let capped = policy.backoff_delay(30).as_millis() as u64;
+        assert!(capped <= MAX_BACKOFF_MS + MAX_BACKOFF_MS / 2);
+    }
+
+    #[tokio::test]
+    async fn execute_respects_idempotency() {
+        let policy = RetryPolicy::new(3, 0);
+        let calls = AtomicU32::new(0);
+        let result = policy
+            .execute(DatabaseType::MySQL, false, || async {
+                if calls.fetch_add(1, Ordering::SeqCst) < 2 {
+                    Err(query_error("Deadlock found when trying to get lock"))
+                } else {
+                    Ok(42)
+                }
+            })
+            .await;
+        assert_eq!(result.unwrap(), 42);
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+
+        // 连接中断时非幂等操作不重试
+        let calls = AtomicU32::new(0);
+        let result: QuickDbResult<()> = policy
+            .execute(DatabaseType::MySQL, false, || async {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Err(query_error("MySQL server has gone away"))
+            })
+            .await;
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+}
@@ -1,14 +1,15 @@
 //! SQLite工作器模块
 
 use rat_logger::{debug, error, info, warn};
-use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
-use tokio::sync::{mpsc, oneshot};
 use tokio::time::timeout;
 
-use super::{DatabaseConnection, DatabaseOperation, ExtendedPoolConfig};
-use crate::adapter::DatabaseAdapter;
+use super::{DatabaseConnection, DatabaseOperation};
+#[cfg(feature = "sqlite-support")]
+use super::{CircuitBreaker, PoolStatusTracker, RetryPolicy, with_timeout};
+#[cfg(feature = "sqlite-support")]
+use crate::adapter::BatchCreate;
 use crate::error::{QuickDbError, QuickDbResult};
 use crate::types::*;
 
@@ -18,7 +19,7 @@ pub struct SqliteWorker {
     /// 数据库连接
     pub(crate) connection: DatabaseConnection,
     /// 操作接收器
-    pub(crate) operation_receiver: mpsc::UnboundedReceiver<DatabaseOperation>,
+    pub(crate) operation_receiver: crate::context::RequestReceiver<super::QueuedOperation>,
     /// 数据库配置
     pub(crate) db_config: DatabaseConfig,
     /// 重试计数
@@ -27,8 +28,18 @@ pub struct SqliteWorker {
     pub(crate) max_retries: u32,
     /// 重试间隔（毫秒）
     pub(crate) retry_interval_ms: u64,
-    /// 健康检查间隔（秒）
-    pub(crate) health_check_interval_sec: u64,
+    /// 瞬时错误重试策略
+    pub(crate) retry_policy: RetryPolicy,
+    /// 熔断器（与连接池共享）
+    pub(crate) circuit_breaker: Arc<CircuitBreaker>,
+    /// 运行时状态计数器（与连接池共享）
+    pub(crate) status: Arc<PoolStatusTracker>,
+    /// 保活检测间隔（秒）
+    pub(crate) keepalive_interval_sec: u64,
+    /// 保活检测超时（秒）
+    pub(crate) health_check_timeout_sec: u64,
+    /// 写入合并配置（`None` 表示逐条执行创建操作）
+    pub(crate) write_batch: Option<WriteBatchConfig>,
     /// 上次健康检查时间
     pub(crate) last_health_check: Instant,
     /// 连接是否健康
@@ -48,7 +59,12 @@ impl std::fmt::Debug for SqliteWorker {
             .field("retry_count", &self.retry_count)
             .field("max_retries", &self.max_retries)
             .field("retry_interval_ms", &self.retry_interval_ms)
-            .field("health_check_interval_sec", &self.health_check_interval_sec)
+            .field("retry_policy", &self.retry_policy)
+            .field("circuit_breaker", &self.circuit_breaker)
+            .field("status", &self.status)
+            .field("keepalive_interval_sec", &self.keepalive_interval_sec)
+            .field("health_check_timeout_sec", &self.health_check_timeout_sec)
+            .field("write_batch", &self.write_batch)
             .field("last_health_check", &self.last_health_check)
             .field("is_healthy", &self.is_healthy)
             .field("cache_manager", &self.cache_manager)
@@ -62,10 +78,36 @@ impl SqliteWorker {
     pub async fn run(mut self) {
         info!("SQLite工作器开始运行: 别名={}", self.db_config.alias);
 
-        // 启动健康检查任务
-        let health_check_handle = self.start_health_check_task().await;
+        // 空闲期间按保活间隔 ping 连接，失败时立即重连
+        let mut keepalive = tokio::time::interval(self.keepalive_interval());
+        keepalive.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        keepalive.reset();
 
-        while let Some(operation) = self.operation_receiver.recv().await {
+        self.status.set_load(0, 1, 0);
+        // 合并写入时收到的非创建操作，在批次执行后处理
+        let mut deferred = None;
+        loop {
+            let ((operation, slot), context) = match deferred.take() {
+                Some(received) => received,
+                None => tokio::select! {
+                    received = self.operation_receiver.recv() => match received {
+                        Some(received) => received,
+                        None => break,
+                    },
+                    _ = keepalive.tick() => {
+                        if !self.ping_connection().await {
+                            if let Err(e) = self.reconnect().await {
+                                error!("SQLite重新连接失败: {}", e);
+                            }
+                        }
+                        continue;
+                    }
+                },
+            };
+            // 单工作器串行执行，排队等待时间体现在 waiting 中，不单独统计
+            drop(slot);
+            self.status.record_acquire(Duration::ZERO);
+            self.status.set_load(1, 0, self.operation_receiver.len() as u32);
             // 检查连接健康状态
             if !self.is_healthy {
                 warn!("SQLite连接不健康，尝试重新连接");
@@ -75,7 +117,23 @@ impl SqliteWorker {
                 }
             }
 
-            match self.handle_operation(operation).await {
+            let batch = match (&self.write_batch, operation) {
+                (Some(config), operation @ DatabaseOperation::Create { .. }) => {
+                    let config = *config;
+                    let (batch, next) = self.collect_creates(operation, context.as_ref(), &config).await;
+                    deferred = next;
+                    batch
+                }
+                (_, operation) => vec![operation],
+            };
+            let result = if batch.len() > 1 {
+                crate::context::in_context_scope(context, self.handle_create_batch(batch)).await
+            } else {
+                let operation = batch.into_iter().next().expect("批次至少包含一个操作");
+                crate::context::in_context_scope(context, self.handle_operation(operation)).await
+            };
+            self.status.set_load(0, 1, self.operation_receiver.len() as u32);
+            match result {
                 Ok(_) => {
                     self.retry_count = 0; // 重置重试计数
                     self.is_healthy = true; // 标记连接健康
@@ -107,25 +165,101 @@ impl SqliteWorker {
             }
         }
 
-        // 清理健康检查任务
-        health_check_handle.abort();
         info!("SQLite工作器停止运行");
     }
 
-    /// 启动健康检查任务
-    async fn start_health_check_task(&self) -> tokio::task::JoinHandle<()> {
-        let health_check_interval = Duration::from_secs(self.health_check_interval_sec);
-        let db_config = self.db_config.clone();
+    /// 从队列中继续收集创建操作，直到达到批次上限、等待窗口结束或遇到其他操作
+    ///
+    /// 批次在一个上下文中执行，语句日志按该上下文标注请求 ID 与用户 ID，
+    /// 因此只合并与第一个操作上下文相同的创建操作，上下文不同的操作同样打断批次。
+    /// 返回收集到的创建操作，以及打断批次的操作（需在批次之后执行）
+    async fn collect_creates(
+        &mut self,
+        first: DatabaseOperation,
+        context: Option<&Arc<crate::context::OperationContext>>,
+        config: &WriteBatchConfig,
+    ) -> (
+        Vec<DatabaseOperation>,
+        Option<crate::context::Scoped<super::QueuedOperation>>,
+    ) {
+        let mut batch = vec![first];
+        let deadline = tokio::time::Instant::now() + Duration::from_millis(config.window_ms);
+        while batch.len() < config.max_size {
+            let Ok(Some(((operation, slot), next_context))) =
+                tokio::time::timeout_at(deadline, self.operation_receiver.recv()).await
+            else {
+                break;
+            };
+            if !matches!(operation, DatabaseOperation::Create { .. }) || next_context.as_ref() != context {
+                return (batch, Some(((operation, slot), next_context)));
+            }
+            drop(slot);
+            self.status.record_acquire(Duration::ZERO);
+            batch.push(operation);
+        }
+        (batch, None)
+    }
 
-        tokio::spawn(async move {
-            let mut interval = tokio::time::interval(health_check_interval);
+    /// 在一个事务中执行合并的创建操作，批次内的操作共享同一个上下文
+    ///
+    /// 合并写入不做瞬时错误重试，整个批次只申请一次熔断器
+    async fn handle_create_batch(&mut self, batch: Vec<DatabaseOperation>) -> QuickDbResult<()> {
+        self.perform_health_check().await;
 
-            loop {
-                interval.tick().await;
-                debug!("执行SQLite连接健康检查: 别名={}", db_config.alias);
-                // 健康检查逻辑在主循环中处理
+        let mut items = Vec::with_capacity(batch.len());
+        let mut responses = Vec::with_capacity(batch.len());
+        for operation in batch {
+            if let DatabaseOperation::Create {
+                table,
+                data,
+                id_strategy,
+                alias,
+                response,
+            } = operation
+            {
+                items.push(BatchCreate {
+                    table,
+                    data,
+                    id_strategy,
+                    alias,
+                });
+                responses.push(response);
             }
-        })
+        }
+
+        if let Err(QuickDbError::CircuitOpen { alias, retry_after_ms }) = self.circuit_breaker.try_acquire() {
+            for response in responses {
+                let error = QuickDbError::CircuitOpen {
+                    alias: alias.clone(),
+                    retry_after_ms,
+                };
+                self.status.respond(response, Err(error));
+            }
+            return Ok(());
+        }
+
+        debug!("SQLite合并写入: 别名={}, 记录数={}", self.db_config.alias, items.len());
+        let results = self.adapter.create_batch(&self.connection, &items).await;
+        let backend_failure = results.iter().any(|result| {
+            result
+                .as_ref()
+                .is_err_and(|e| CircuitBreaker::is_backend_failure(DatabaseType::SQLite, e))
+        });
+        if backend_failure {
+            self.circuit_breaker.record_failure();
+        } else {
+            self.circuit_breaker.record_success();
+        }
+
+        for (response, result) in responses.into_iter().zip(results) {
+            self.status.respond(response, result);
+        }
+        Ok(())
+    }
+
+    /// 保活检测间隔
+    fn keepalive_interval(&self) -> Duration {
+        Duration::from_secs(self.keepalive_interval_sec.max(1))
     }
 
     /// 重新连接数据库
@@ -144,11 +278,13 @@ impl SqliteWorker {
     /// 创建SQLite连接
     #[cfg(feature = "sqlite-support")]
     async fn create_sqlite_connection(&self) -> QuickDbResult<DatabaseConnection> {
-        let (path, create_if_missing) = match &self.db_config.connection {
+        let (path, create_if_missing, pragmas, encryption_key) = match &self.db_config.connection {
             crate::types::ConnectionConfig::SQLite {
                 path,
                 create_if_missing,
-            } => (path.clone(), *create_if_missing),
+                pragmas,
+                encryption_key,
+            } => (path.clone(), *create_if_missing, pragmas.clone(), encryption_key.clone()),
             _ => {
                 return Err(QuickDbError::ConfigError {
                     message: crate::i18n::t("error.sqlite_config_mismatch"),
@@ -157,9 +293,10 @@ impl SqliteWorker {
         };
 
         // 特殊处理内存数据库：直接连接，不创建文件
-        if path == ":memory:" {
-            info!("连接SQLite内存数据库: 别名={}", self.db_config.alias);
-            let pool = sqlx::SqlitePool::connect(&path).await.map_err(|e| {
+        if is_sqlite_memory_path(&path) {
+            let path = shared_memory_path(&path, &self.db_config.alias);
+            info!("连接SQLite内存数据库: 别名={}, 路径={}", self.db_config.alias, path);
+            let pool = self.connect_sqlite_pool(&path, pragmas.as_ref(), encryption_key.as_deref()).await.map_err(|e| {
                 QuickDbError::ConnectionError {
                     message: crate::i18n::tf("error.sqlite_memory", &[("message", &e.to_string())]),
                 }
@@ -196,7 +333,7 @@ impl SqliteWorker {
         }
 
         let pool =
-            sqlx::SqlitePool::connect(&path)
+            self.connect_sqlite_pool(&path, pragmas.as_ref(), encryption_key.as_deref())
                 .await
                 .map_err(|e| QuickDbError::ConnectionError {
                     message: crate::i18n::tf("error.sqlite_connection", &[("message", &e.to_string())]),
@@ -204,6 +341,17 @@ impl SqliteWorker {
         Ok(DatabaseConnection::SQLite(pool))
     }
 
+    /// 连接SQLite（应用 PRAGMA 配置与加密密钥）
+    async fn connect_sqlite_pool(
+        &self,
+        path: &str,
+        pragmas: Option<&SqlitePragmaConfig>,
+        encryption_key: Option<&str>,
+    ) -> Result<sqlx::SqlitePool, sqlx::Error> {
+        let options = sqlite_connect_options(path, pragmas, encryption_key)?;
+        open_sqlite_pool(path, options, &self.db_config.on_connect_statements).await
+    }
+
     /// 计算退避延迟（指数退避）
     fn calculate_backoff_delay(&self) -> u64 {
         let base_delay = self.retry_interval_ms;
@@ -212,22 +360,22 @@ impl SqliteWorker {
         exponential_delay.min(max_delay)
     }
 
-    /// 执行连接健康检查
+    /// 执行连接健康检查（距上次检查不足保活间隔时沿用上次结果）
     async fn perform_health_check(&mut self) -> bool {
-        if self.last_health_check.elapsed() < Duration::from_secs(self.health_check_interval_sec) {
+        if self.last_health_check.elapsed() < self.keepalive_interval() {
             return self.is_healthy;
         }
+        self.ping_connection().await
+    }
 
+    /// ping 连接并记录结果
+    async fn ping_connection(&mut self) -> bool {
         debug!("执行SQLite连接健康检查: 别名={}", self.db_config.alias);
 
-        // 执行简单的查询来检查连接健康状态
-        let health_check_result = match &self.connection {
-            #[cfg(feature = "sqlite-support")]
-            DatabaseConnection::SQLite(pool) => {
-                sqlx::query("SELECT 1").fetch_optional(pool).await.is_ok()
-            }
-            _ => false,
-        };
+        // 执行 SELECT 1 检查连接健康状态，超时视为失败
+        let limit = Duration::from_secs(self.health_check_timeout_sec.max(1));
+        let health_check_result = matches!(timeout(limit, super::ping(&self.connection)).await, Ok(Ok(())));
+        self.status.record_ping(health_check_result);
 
         self.last_health_check = Instant::now();
         self.is_healthy = health_check_result;
@@ -247,7 +395,8 @@ impl SqliteWorker {
         self.perform_health_check().await;
 
         // 执行数据库操作，使用 Result 来处理错误而不是 panic 捕获
-        let operation_result = match operation {
+
+        match operation {
             DatabaseOperation::Create {
                 table,
                 data,
@@ -256,10 +405,12 @@ impl SqliteWorker {
                 response,
             } => {
                 let result = self
-                    .adapter
-                    .create(&self.connection, &table, &data, &id_strategy, &alias)
+                    .circuit_breaker
+                    .call(self.retry_policy.execute(DatabaseType::SQLite, false, || {
+                        self.adapter.create(&self.connection, &table, &data, &id_strategy, &alias)
+                    }))
                     .await;
-                let _ = response.send(result);
+                self.status.respond(response, result);
                 Ok(())
             }
             DatabaseOperation::FindById {
@@ -269,10 +420,27 @@ impl SqliteWorker {
                 response,
             } => {
                 let result = self
-                    .adapter
-                    .find_by_id(&self.connection, &table, &id, &alias)
+                    .circuit_breaker
+                    .call(self.retry_policy.execute(DatabaseType::SQLite, true, || {
+                        self.adapter.find_by_id(&self.connection, &table, &id, &alias)
+                    }))
+                    .await;
+                self.status.respond(response, result);
+                Ok(())
+            }
+            DatabaseOperation::ExistsById {
+                table,
+                id,
+                alias,
+                response,
+            } => {
+                let result = self
+                    .circuit_breaker
+                    .call(self.retry_policy.execute(DatabaseType::SQLite, true, || {
+                        self.adapter.exists_by_id(&self.connection, &table, &id, &alias)
+                    }))
                     .await;
-                let _ = response.send(result);
+                self.status.respond(response, result);
                 Ok(())
             }
             DatabaseOperation::Find {
@@ -283,10 +451,15 @@ impl SqliteWorker {
                 response,
             } => {
                 let result = self
-                    .adapter
-                    .find(&self.connection, &table, &conditions, &options, &alias)
+                    .circuit_breaker
+                    .call(with_timeout(
+                        options.timeout,
+                        self.retry_policy.execute(DatabaseType::SQLite, true, || {
+                            self.adapter.find(&self.connection, &table, &conditions, &options, &alias)
+                        }),
+                    ))
                     .await;
-                let _ = response.send(result);
+                self.status.respond(response, result);
                 Ok(())
             }
             DatabaseOperation::FindWithGroups {
@@ -297,16 +470,21 @@ impl SqliteWorker {
                 response,
             } => {
                 let result = self
-                    .adapter
-                    .find_with_groups(
-                        &self.connection,
-                        &table,
-                        &condition_groups,
-                        &options,
-                        &alias,
-                    )
+                    .circuit_breaker
+                    .call(with_timeout(
+                        options.timeout,
+                        self.retry_policy.execute(DatabaseType::SQLite, true, || {
+                            self.adapter.find_with_groups(
+                                &self.connection,
+                                &table,
+                                &condition_groups,
+                                &options,
+                                &alias,
+                            )
+                        }),
+                    ))
                     .await;
-                let _ = response.send(result);
+                self.status.respond(response, result);
                 Ok(())
             }
             DatabaseOperation::FindWithBypassCache {
@@ -318,17 +496,22 @@ impl SqliteWorker {
                 response,
             } => {
                 let result = self
-                    .adapter
-                    .find_with_cache_control(
-                        &self.connection,
-                        &table,
-                        &conditions,
-                        &options,
-                        &alias,
-                        bypass_cache,
-                    )
+                    .circuit_breaker
+                    .call(with_timeout(
+                        options.timeout,
+                        self.retry_policy.execute(DatabaseType::SQLite, true, || {
+                            self.adapter.find_with_cache_control(
+                                &self.connection,
+                                &table,
+                                &conditions,
+                                &options,
+                                &alias,
+                                bypass_cache,
+                            )
+                        }),
+                    ))
                     .await;
-                let _ = response.send(result);
+                self.status.respond(response, result);
                 Ok(())
             }
             DatabaseOperation::FindWithGroupsWithBypassCache {
@@ -340,51 +523,68 @@ impl SqliteWorker {
                 response,
             } => {
                 let result = self
-                    .adapter
-                    .find_with_groups_with_cache_control_and_config(
-                        &self.connection,
-                        &table,
-                        &condition_groups,
-                        &options,
-                        &alias,
-                        bypass_cache,
-                    )
+                    .circuit_breaker
+                    .call(with_timeout(
+                        options.timeout,
+                        self.retry_policy.execute(DatabaseType::SQLite, true, || {
+                            self.adapter.find_with_groups_with_cache_control_and_config(
+                                &self.connection,
+                                &table,
+                                &condition_groups,
+                                &options,
+                                &alias,
+                                bypass_cache,
+                            )
+                        }),
+                    ))
                     .await;
-                let _ = response.send(result);
+                self.status.respond(response, result);
                 Ok(())
             }
             DatabaseOperation::Update {
                 table,
                 conditions,
                 data,
+                options,
                 alias,
                 response,
             } => {
                 let result = self
-                    .adapter
-                    .update(&self.connection, &table, &conditions, &data, &alias)
+                    .circuit_breaker
+                    .call(with_timeout(
+                        options.timeout,
+                        self.retry_policy.execute(DatabaseType::SQLite, false, || {
+                            self.adapter.update(&self.connection, &table, &conditions, &data, &alias)
+                        }),
+                    ))
                     .await;
-                let _ = response.send(result);
+                self.status.respond(response, result);
                 Ok(())
             }
             DatabaseOperation::UpdateWithOperations {
                 table,
                 conditions,
                 operations,
+                options,
                 alias,
                 response,
             } => {
                 let result = self
-                    .adapter
-                    .update_with_operations(
-                        &self.connection,
-                        &table,
-                        &conditions,
-                        &operations,
-                        &alias,
-                    )
+                    .circuit_breaker
+                    .call(with_timeout(
+                        options.timeout,
+                        self.retry_policy.execute(DatabaseType::SQLite, false, || {
+                            self.adapter.update_with_operations(
+                                &self.connection,
+                                &table,
+                                &conditions,
+                                &operations,
+                                &alias,
+                            )
+                        }),
+                    ))
                     .await;
-                let _ = response.send(result);
+                self.status.respond(response, result);
                 Ok(())
             }
             DatabaseOperation::UpdateById {
@@ -395,10 +595,28 @@ impl SqliteWorker {
                 response,
             } => {
                 let result = self
-                    .adapter
-                    .update_by_id(&self.connection, &table, &id, &data, &alias)
+                    .circuit_breaker
+                    .call(self.retry_policy.execute(DatabaseType::SQLite, false, || {
+                        self.adapter.update_by_id(&self.connection, &table, &id, &data, &alias)
+                    }))
                     .await;
-                let _ = response.send(result);
+                self.status.respond(response, result);
+                Ok(())
+            }
+            DatabaseOperation::UpdateByIdReturning {
+                table,
+                id,
+                data,
+                alias,
+                response,
+            } => {
+                let result = self
+                    .circuit_breaker
+                    .call(self.retry_policy.execute(DatabaseType::SQLite, false, || {
+                        self.adapter.update_by_id_returning(&self.connection, &table, &id, &data, &alias)
+                    }))
+                    .await;
+                self.status.respond(response, result);
                 Ok(())
             }
             DatabaseOperation::Upsert {
@@ -410,17 +628,19 @@ impl SqliteWorker {
                 response,
             } => {
                 let result = self
-                    .adapter
-                    .upsert(
-                        &self.connection,
-                        &table,
-                        &data,
-                        &id_strategy,
-                        &conflict_columns,
-                        &alias,
-                    )
+                    .circuit_breaker
+                    .call(self.retry_policy.execute(DatabaseType::SQLite, false, || {
+                        self.adapter.upsert(
+                            &self.connection,
+                            &table,
+                            &data,
+                            &id_strategy,
+                            &conflict_columns,
+                            &alias,
+                        )
+                    }))
                     .await;
-                let _ = response.send(result);
+                self.status.respond(response, result);
                 Ok(())
             }
             DatabaseOperation::Delete {
@@ -430,10 +650,12 @@ impl SqliteWorker {
                 response,
             } => {
                 let result = self
-                    .adapter
-                    .delete(&self.connection, &table, &conditions, &alias)
+                    .circuit_breaker
+                    .call(self.retry_policy.execute(DatabaseType::SQLite, false, || {
+                        self.adapter.delete(&self.connection, &table, &conditions, &alias)
+                    }))
                     .await;
-                let _ = response.send(result);
+                self.status.respond(response, result);
                 Ok(())
             }
             DatabaseOperation::DeleteById {
@@ -443,10 +665,44 @@ impl SqliteWorker {
                 response,
             } => {
                 let result = self
-                    .adapter
-                    .delete_by_id(&self.connection, &table, &id, &alias)
+                    .circuit_breaker
+                    .call(self.retry_policy.execute(DatabaseType::SQLite, false, || {
+                        self.adapter.delete_by_id(&self.connection, &table, &id, &alias)
+                    }))
                     .await;
-                let _ = response.send(result);
+                self.status.respond(response, result);
+                Ok(())
+            }
+            DatabaseOperation::DeleteByIdReturning {
+                table,
+                id,
+                alias,
+                response,
+            } => {
+                let result = self
+                    .circuit_breaker
+                    .call(self.retry_policy.execute(DatabaseType::SQLite, false, || {
+                        self.adapter.delete_by_id_returning(&self.connection, &table, &id, &alias)
+                    }))
+                    .await;
+                self.status.respond(response, result);
+                Ok(())
+            }
+            DatabaseOperation::BulkWrite {
+                table,
+                ops,
+                mode,
+                id_strategy,
+                alias,
+                response,
+            } => {
+                let result = self
+                    .circuit_breaker
+                    .call(self.retry_policy.execute(DatabaseType::SQLite, false, || {
+                        self.adapter.bulk_write(&self.connection, &table, &ops, mode, &id_strategy, &alias)
+                    }))
+                    .await;
+                self.status.respond(response, result);
                 Ok(())
             }
             DatabaseOperation::Count {
@@ -456,10 +712,12 @@ impl SqliteWorker {
                 response,
             } => {
                 let result = self
-                    .adapter
-                    .count(&self.connection, &table, &conditions, &alias)
+                    .circuit_breaker
+                    .call(self.retry_policy.execute(DatabaseType::SQLite, true, || {
+                        self.adapter.count(&self.connection, &table, &conditions, &alias)
+                    }))
                     .await;
-                let _ = response.send(result);
+                self.status.respond(response, result);
                 Ok(())
             }
             DatabaseOperation::CountWithGroups {
@@ -469,10 +727,17 @@ impl SqliteWorker {
                 response,
             } => {
                 let result = self
-                    .adapter
-                    .count_with_groups(&self.connection, &table, &condition_groups, &alias)
+                    .circuit_breaker
+                    .call(self.retry_policy.execute(DatabaseType::SQLite, true, || {
+                        self.adapter.count_with_groups(
+                            &self.connection,
+                            &table,
+                            &condition_groups,
+                            &alias,
+                        )
+                    }))
                     .await;
-                let _ = response.send(result);
+                self.status.respond(response, result);
                 Ok(())
             }
             DatabaseOperation::CreateTable {
@@ -483,10 +748,18 @@ impl SqliteWorker {
                 response,
             } => {
                 let result = self
-                    .adapter
-                    .create_table(&self.connection, &table, &fields, &id_strategy, &alias)
+                    .circuit_breaker
+                    .call(self.retry_policy.execute(DatabaseType::SQLite, false, || {
+                        self.adapter.create_table(
+                            &self.connection,
+                            &table,
+                            &fields,
+                            &id_strategy,
+                            &alias,
+                        )
+                    }))
                     .await;
-                let _ = response.send(result);
+                self.status.respond(response, result);
                 Ok(())
             }
             DatabaseOperation::CreateIndex {
@@ -494,36 +767,87 @@ impl SqliteWorker {
                 index_name,
                 fields,
                 unique,
+                options,
                 response,
             } => {
                 let result = self
-                    .adapter
-                    .create_index(&self.connection, &table, &index_name, &fields, unique)
+                    .circuit_breaker
+                    .call(self.retry_policy.execute(DatabaseType::SQLite, false, || {
+                        self.adapter.create_index(
+                            &self.connection,
+                            &table,
+                            &index_name,
+                            &fields,
+                            unique,
+                            &options,
+                        )
+                    }))
                     .await;
-                let _ = response.send(result);
+                self.status.respond(response, result);
                 Ok(())
             }
             DatabaseOperation::TableExists { table, response } => {
-                let result = self.adapter.table_exists(&self.connection, &table).await;
-                let _ = response.send(result);
+                let result = self
+                    .circuit_breaker
+                    .call(self.retry_policy.execute(DatabaseType::SQLite, true, || {
+                        self.adapter.table_exists(&self.connection, &table)
+                    }))
+                    .await;
+                self.status.respond(response, result);
+                Ok(())
+            }
+            DatabaseOperation::CountEstimate { table, response } => {
+                let result = self
+                    .circuit_breaker
+                    .call(self.retry_policy.execute(DatabaseType::SQLite, true, || {
+                        self.adapter.count_estimate(&self.connection, &table)
+                    }))
+                    .await;
+                self.status.respond(response, result);
                 Ok(())
             }
             DatabaseOperation::DropTable { table, response } => {
-                let result = self.adapter.drop_table(&self.connection, &table).await;
-                let _ = response.send(result);
+                let result = self
+                    .circuit_breaker
+                    .call(self.retry_policy.execute(DatabaseType::SQLite, false, || {
+                        self.adapter.drop_table(&self.connection, &table)
+                    }))
+                    .await;
+                self.status.respond(response, result);
                 Ok(())
             }
             DatabaseOperation::GetServerVersion { response } => {
-                let result = self.adapter.get_server_version(&self.connection).await;
-                let _ = response.send(result);
+                let result = self
+                    .circuit_breaker
+                    .call(self.retry_policy.execute(DatabaseType::SQLite, true, || {
+                        self.adapter.get_server_version(&self.connection)
+                    }))
+                    .await;
+                self.status.respond(response, result);
                 Ok(())
             }
             DatabaseOperation::CreateStoredProcedure { config, response } => {
                 let result = self
-                    .adapter
-                    .create_stored_procedure(&self.connection, &config)
+                    .circuit_breaker
+                    .call(self.retry_policy.execute(DatabaseType::SQLite, false, || {
+                        self.adapter.create_stored_procedure(&self.connection, &config)
+                    }))
                     .await;
-                let _ = response.send(result);
+                self.status.respond(response, result);
+                Ok(())
+            }
+            DatabaseOperation::GetConnection { response } => {
+                self.status.respond(response, Ok(self.connection.clone()));
+                Ok(())
+            }
+            DatabaseOperation::DropStoredProcedure { procedure_name, response } => {
+                let result = self
+                    .circuit_breaker
+                    .call(self.retry_policy.execute(DatabaseType::SQLite, false, || {
+                        self.adapter.drop_stored_procedure(&self.connection, &procedure_name)
+                    }))
+                    .await;
+                self.status.respond(response, result);
                 Ok(())
             }
             DatabaseOperation::ExecuteStoredProcedure {
@@ -533,14 +857,390 @@ impl SqliteWorker {
                 response,
             } => {
                 let result = self
-                    .adapter
-                    .execute_stored_procedure(&self.connection, &procedure_name, &database, params)
+                    .circuit_breaker
+                    .call(self.retry_policy.execute(DatabaseType::SQLite, false, || {
+                        self.adapter.execute_stored_procedure(
+                            &self.connection,
+                            &procedure_name,
+                            &database,
+                            params.clone(),
+                        )
+                    }))
                     .await;
-                let _ = response.send(result);
+                self.status.respond(response, result);
                 Ok(())
             }
+        }
+    }
+}
+
+/// 判断路径是否为内存数据库（`:memory:`、`sqlite::memory:` 或 `file:name?mode=memory` 形式的 URI）
+#[cfg(feature = "sqlite-support")]
+pub(crate) fn is_sqlite_memory_path(path: &str) -> bool {
+    matches!(path, ":memory:" | "sqlite::memory:")
+        || path
+            .split_once('?')
+            .is_some_and(|(_, query)| query.split('&').any(|pair| pair == "mode=memory"))
+}
+
+/// 内存数据库的实际连接路径
+///
+/// `:memory:` 转换为按别名命名的共享缓存内存库（`file:rat_quickdb_{alias}?mode=memory&cache=shared`），
+/// 连接池中的所有连接以及重连后的新连接池都访问同一个数据库；已命名的 URI 原样使用
+#[cfg(feature = "sqlite-support")]
+pub(crate) fn shared_memory_path(path: &str, alias: &str) -> String {
+    if matches!(path, ":memory:" | "sqlite::memory:") {
+        format!("file:rat_quickdb_{}?mode=memory&cache=shared", urlencoding::encode(alias))
+    } else {
+        path.to_string()
+    }
+}
+
+/// 打开SQLite连接池
+///
+/// 共享缓存内存库在最后一个连接关闭时销毁，因此内存库始终保留至少一个连接，且连接不因空闲或超龄回收
+#[cfg(feature = "sqlite-support")]
+pub(crate) async fn open_sqlite_pool(
+    path: &str,
+    options: sqlx::sqlite::SqliteConnectOptions,
+    on_connect_statements: &[String],
+) -> Result<sqlx::SqlitePool, sqlx::Error> {
+    let mut pool_options = sqlx::sqlite::SqlitePoolOptions::new();
+    if is_sqlite_memory_path(path) {
+        pool_options = pool_options.min_connections(1).idle_timeout(None).max_lifetime(None);
+    }
+    super::on_connect::with_on_connect(pool_options, on_connect_statements)
+        .connect_with(options)
+        .await
+}
+
+/// 构建SQLite连接选项：注册 Regex 查询使用的 regexp() 函数，并应用加密密钥与 PRAGMA 配置
+///
+/// PRAGMA 写入连接选项，连接池中的每个新连接打开时都会执行。
+/// sqlx 保证 `PRAGMA key` 先于其他 PRAGMA 执行，加密库在读取任何页之前完成解密设置
+#[cfg(feature = "sqlite-support")]
+pub(crate) fn sqlite_connect_options(
+    path: &str,
+    pragmas: Option<&SqlitePragmaConfig>,
+    encryption_key: Option<&str>,
+) -> Result<sqlx::sqlite::SqliteConnectOptions, sqlx::Error> {
+    let mut options = path.parse::<sqlx::sqlite::SqliteConnectOptions>()?.with_regexp();
+    if let Some(key) = encryption_key {
+        options = apply_encryption_key(options, key)?;
+    }
+    let Some(pragmas) = pragmas else {
+        return Ok(options);
+    };
+
+    if let Some(journal_mode) = &pragmas.journal_mode {
+        options = options.journal_mode(journal_mode.parse()?);
+    }
+    if let Some(synchronous) = &pragmas.synchronous {
+        options = options.synchronous(synchronous.parse()?);
+    }
+    if let Some(busy_timeout_ms) = pragmas.busy_timeout_ms {
+        options = options.busy_timeout(Duration::from_millis(busy_timeout_ms));
+    }
+    if let Some(foreign_keys) = pragmas.foreign_keys {
+        options = options.foreign_keys(foreign_keys);
+    }
+    if let Some(cache_size) = pragmas.cache_size {
+        options = options.pragma("cache_size", cache_size.to_string());
+    }
+    Ok(options)
+}
+
+/// 设置 SQLCipher 密钥（`x'...'` 形式的原始十六进制密钥原样传入，其余按口令字符串转义）
+#[cfg(feature = "sqlcipher")]
+fn apply_encryption_key(
+    options: sqlx::sqlite::SqliteConnectOptions,
+    key: &str,
+) -> Result<sqlx::sqlite::SqliteConnectOptions, sqlx::Error> {
+    let is_raw_key = key.len() > 3 && key.starts_with("x'") && key.ends_with('\'');
+    let value = if is_raw_key {
+        key.to_string()
+    } else {
+        format!("'{}'", key.replace('\'', "''"))
+    };
+    Ok(options.pragma("key", value))
+}
+
+/// 未启用 sqlcipher 特性时拒绝加密配置，避免以明文创建数据库
+#[cfg(all(feature = "sqlite-support", not(feature = "sqlcipher")))]
+fn apply_encryption_key(
+    _options: sqlx::sqlite::SqliteConnectOptions,
+    _key: &str,
+) -> Result<sqlx::sqlite::SqliteConnectOptions, sqlx::Error> {
+    Err(sqlx::Error::Configuration(
+        crate::i18n::t("config.sqlcipher_feature_required").into(),
+    ))
+}
+
+#[cfg(all(test, feature = "sqlite-support"))]
+mod tests {
+    use super::*;
+    use super::super::ExtendedPoolConfig;
+    use std::collections::HashMap;
+
+    /// 在给定连接池上构造工作器（不启动运行循环），返回提交操作的发送器
+    fn test_worker(
+        alias: &str,
+        pool: sqlx::SqlitePool,
+        write_batch: Option<WriteBatchConfig>,
+    ) -> (super::super::OperationSender, SqliteWorker) {
+        let db_config = DatabaseConfig::sqlite_memory_for_test(alias);
+        let config = ExtendedPoolConfig::from_pool_config(PoolConfig {
+            write_batch,
+            ..PoolConfig::default()
+        });
+        let status = Arc::new(PoolStatusTracker::new());
+        let (sender, operation_receiver) = super::super::operation_channel(alias, &config.base, status.clone());
+        let worker = SqliteWorker {
+            connection: DatabaseConnection::SQLite(pool),
+            operation_receiver,
+            retry_count: 0,
+            max_retries: config.max_retries,
+            retry_interval_ms: config.retry_interval_ms,
+            retry_policy: RetryPolicy::from_config(&config),
+            circuit_breaker: Arc::new(CircuitBreaker::from_config(&db_config, &config)),
+            status,
+            keepalive_interval_sec: config.keepalive_interval_sec,
+            health_check_timeout_sec: config.health_check_timeout_sec,
+            write_batch,
+            last_health_check: Instant::now(),
+            is_healthy: true,
+            cache_manager: None,
+            adapter: Box::new(crate::adapter::SqliteAdapter::new()),
+            db_config,
         };
+        (sender, worker)
+    }
+
+    #[tokio::test]
+    async fn applies_pragmas_to_each_connection() {
+        crate::i18n::ErrorMessageI18n::init_i18n();
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("pragmas.db");
+        let pragmas = SqlitePragmaConfig::wal()
+            .with_busy_timeout_ms(1500)
+            .with_foreign_keys(false)
+            .with_cache_size(-4000);
+
+        let options = sqlite_connect_options(path.to_str().unwrap(), Some(&pragmas), None)
+            .unwrap()
+            .create_if_missing(true);
+        let pool = sqlx::SqlitePool::connect_with(options).await.unwrap();
+        for (pragma, column, expected) in [
+            ("journal_mode", "journal_mode", "wal"),
+            ("synchronous", "synchronous", "1"),
+            ("busy_timeout", "timeout", "1500"),
+            ("foreign_keys", "foreign_keys", "0"),
+            ("cache_size", "cache_size", "-4000"),
+        ] {
+            let value: String = sqlx::query_scalar(&format!("SELECT CAST({} AS TEXT) FROM pragma_{}", column, pragma))
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+            assert_eq!(value, expected, "{}", pragma);
+        }
+
+        assert!(pragmas.validate().is_ok());
+        assert!(SqlitePragmaConfig::default().with_journal_mode("fast").validate().is_err());
+
+        let encrypted = ConnectionConfig::SQLite {
+            path: "encrypted.db".to_string(),
+            create_if_missing: true,
+            pragmas: None,
+            encryption_key: Some("secret".to_string()),
+        };
+        assert_eq!(
+            crate::config::DatabaseConfigBuilder::validate_config(&DatabaseType::SQLite, &encrypted).is_ok(),
+            cfg!(feature = "sqlcipher")
+        );
+    }
+
+    #[cfg(feature = "sqlcipher")]
+    #[tokio::test]
+    async fn encrypted_database_requires_matching_key() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("encrypted.db");
+        let path = path.to_str().unwrap();
+        let connect = |key: Option<&'static str>| async move {
+            let options = sqlite_connect_options(path, None, key)?.create_if_missing(true);
+            let pool = sqlx::SqlitePool::connect_with(options).await?;
+            sqlx::query("CREATE TABLE IF NOT EXISTS secrets (value TEXT)").execute(&pool).await?;
+            Ok::<_, sqlx::Error>(pool)
+        };
+
+        let pool = connect(Some("it's secret")).await.unwrap();
+        sqlx::query("INSERT INTO secrets VALUES ('hidden')").execute(&pool).await.unwrap();
+        pool.close().await;
+
+        assert!(connect(Some("it's secret")).await.is_ok());
+        assert!(connect(Some("wrong key")).await.is_err());
+        assert!(connect(None).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn shared_memory_database_is_visible_across_pools() {
+        assert!(is_sqlite_memory_path(":memory:"));
+        assert!(is_sqlite_memory_path("file:suite?mode=memory&cache=shared"));
+        assert!(!is_sqlite_memory_path("data/app.db"));
+        assert_eq!(
+            shared_memory_path(":memory:", "main"),
+            "file:rat_quickdb_main?mode=memory&cache=shared"
+        );
+
+        let ConnectionConfig::SQLite { path, .. } = ConnectionConfig::sqlite_memory("shared_suite") else {
+            unreachable!();
+        };
+        let open = || async { open_sqlite_pool(&path, sqlite_connect_options(&path, None, None)?, &[]).await };
+        let writer = open().await.unwrap();
+        sqlx::query("CREATE TABLE items (name TEXT)").execute(&writer).await.unwrap();
+        sqlx::query("INSERT INTO items VALUES ('a')").execute(&writer).await.unwrap();
+
+        let reader = open().await.unwrap();
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM items").fetch_one(&reader).await.unwrap();
+        assert_eq!(count, 1);
+        assert!(std::fs::metadata("file:shared_suite").is_err());
+    }
+
+    #[tokio::test]
+    async fn coalesced_creates_report_results_per_record() {
+        crate::i18n::ErrorMessageI18n::init_i18n();
+        let alias = "write_batch_suite".to_string();
+        let connection = ConnectionConfig::sqlite_memory(&alias);
+        let ConnectionConfig::SQLite { path, .. } = &connection else {
+            unreachable!();
+        };
+        // 共享内存库在最后一个连接关闭前保持存在
+        let keeper = open_sqlite_pool(path, sqlite_connect_options(path, None, None).unwrap(), &[])
+            .await
+            .unwrap();
+        sqlx::query("CREATE TABLE items (id INTEGER PRIMARY KEY AUTOINCREMENT, name TEXT)")
+            .execute(&keeper)
+            .await
+            .unwrap();
+
+        let pool_config = PoolConfig {
+            write_batch: Some(WriteBatchConfig {
+                max_size: 8,
+                window_ms: 50,
+            }),
+            ..PoolConfig::default()
+        };
+        let db_config = DatabaseConfig {
+            db_type: DatabaseType::SQLite,
+            connection,
+            pool: pool_config.clone(),
+            alias: alias.clone(),
+            cache: None,
+            id_strategy: IdStrategy::AutoIncrement,
+            version_storage_path: None,
+            enable_versioning: None,
+            on_connect_statements: Vec::new(),
+            table_naming: None,
+        };
+        let pool = super::super::ConnectionPool::with_config(
+            db_config,
+            ExtendedPoolConfig::from_pool_config(pool_config),
+        )
+        .await
+        .unwrap();
+
+        let mut receivers = Vec::new();
+        for data in [None, Some(100), None, Some(100)] {
+            let mut record = HashMap::from([("name".to_string(), DataValue::String("item".to_string()))]);
+            if let Some(id) = data {
+                record.insert("id".to_string(), DataValue::Int(id));
+            }
+            let (response, receiver) = tokio::sync::oneshot::channel();
+            pool.operation_sender
+                .send(DatabaseOperation::Create {
+                    table: "items".to_string(),
+                    data: record,
+                    id_strategy: IdStrategy::AutoIncrement,
+                    alias: alias.clone(),
+                    response,
+                })
+                .await
+                .unwrap();
+            receivers.push(receiver);
+        }
+
+        let mut results = Vec::new();
+        for receiver in receivers {
+            results.push(receiver.await.unwrap());
+        }
+        assert!(matches!(results[0], Ok(DataValue::Int(_))));
+        assert!(matches!(results[1], Ok(DataValue::Int(100))));
+        assert!(matches!(results[2], Ok(DataValue::Int(_))));
+        // 重复主键只影响该记录，同一事务中的其他记录正常提交
+        assert!(results[3].is_err());
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM items")
+            .fetch_one(&keeper)
+            .await
+            .unwrap();
+        assert_eq!(count, 3);
+    }
+
+    #[tokio::test]
+    async fn creates_from_different_contexts_are_not_coalesced() {
+        crate::i18n::ErrorMessageI18n::init_i18n();
+        let alias = "batch_context_suite";
+        let pool = sqlx::SqlitePool::connect("sqlite::memory:").await.unwrap();
+        let config = WriteBatchConfig {
+            max_size: 8,
+            window_ms: 50,
+        };
+        let (sender, mut worker) = test_worker(alias, pool, Some(config));
+
+        for (request_id, name) in [("req-1", "a"), ("req-1", "b"), ("req-2", "c")] {
+            let (response, _) = tokio::sync::oneshot::channel();
+            let create = DatabaseOperation::Create {
+                table: "items".to_string(),
+                data: HashMap::from([("name".to_string(), DataValue::String(name.to_string()))]),
+                id_strategy: IdStrategy::AutoIncrement,
+                alias: alias.to_string(),
+                response,
+            };
+            let context = crate::context::OperationContext::new().request_id(request_id);
+            crate::context::with_context(context, sender.send(create)).await.unwrap();
+        }
+
+        let ((first, _slot), context) = worker.operation_receiver.recv().await.unwrap();
+        let (batch, next) = worker.collect_creates(first, context.as_ref(), &config).await;
+        assert_eq!(batch.len(), 2);
+        let ((operation, _slot), next_context) = next.expect("上下文不同的创建操作应打断批次");
+        assert!(matches!(operation, DatabaseOperation::Create { .. }));
+        assert_eq!(next_context.and_then(|context| context.request_id.clone()), Some("req-2".to_string()));
+    }
+
+    #[tokio::test]
+    async fn missed_keepalive_ping_reconnects() {
+        crate::i18n::ErrorMessageI18n::init_i18n();
+        let alias = "keepalive_suite";
+        let pool = sqlx::SqlitePool::connect("sqlite::memory:").await.unwrap();
+        let (sender, mut worker) = test_worker(alias, pool.clone(), None);
+        worker.keepalive_interval_sec = 1;
+        let status = worker.status.clone();
+
+        // 关闭工作器持有的连接，下一次保活 ping 必然失败
+        pool.close().await;
+        let handle = tokio::spawn(worker.run());
+
+        // 第一次 ping 失败后立即重连，下一次 ping 落在新连接上并成功
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while status.snapshot().pings < 2 {
+                tokio::time::sleep(Duration::from_millis(20)).await;
+            }
+        })
+        .await
+        .expect("保活 ping 应按间隔执行");
+        let snapshot = status.snapshot();
+        assert_eq!((snapshot.pings, snapshot.missed_pings), (2, 1));
 
-        operation_result
+        drop(sender);
+        handle.await.unwrap();
     }
 }
@@ -0,0 +1,177 @@
+//! 连接池运行时状态模块
+//!
+//! 操作消费者通过原子计数器实时更新状态，连接池和管理器可随时读取快照
+
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+use std::time::Duration;
+use tokio::sync::oneshot;
+
+use crate::error::QuickDbResult;
+
+/// 连接池状态快照
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PoolStatus {
+    /// 正在执行操作的工作器数量
+    pub active: u32,
+    /// 空闲工作器数量
+    pub idle: u32,
+    /// 等待分配工作器的操作数量
+    pub waiting: u32,
+    /// 累计分配工作器的操作数量
+    pub total_acquired: u64,
+    /// 平均等待时间（毫秒）
+    pub avg_wait_ms: f64,
+    /// 累计失败的操作数量
+    pub errors: u64,
+    /// 累计保活检测次数
+    #[serde(default)]
+    pub pings: u64,
+    /// 累计失败或超时的保活检测次数
+    #[serde(default)]
+    pub missed_pings: u64,
+    /// 已提交但尚未开始执行的操作数量
+    #[serde(default)]
+    pub queue_depth: u32,
+    /// 累计因队列已满被拒绝的操作数量
+    #[serde(default)]
+    pub rejected: u64,
+}
+
+/// 连接池状态计数器（连接池与操作消费者共享）
+#[derive(Debug, Default)]
+pub struct PoolStatusTracker {
+    active: AtomicU32,
+    idle: AtomicU32,
+    waiting: AtomicU32,
+    total_acquired: AtomicU64,
+    total_wait_us: AtomicU64,
+    errors: AtomicU64,
+    pings: AtomicU64,
+    missed_pings: AtomicU64,
+    queue_depth: AtomicU32,
+    rejected: AtomicU64,
+}
+
+impl PoolStatusTracker {
+    /// 创建状态计数器
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 更新工作器负载
+    pub fn set_load(&self, active: u32, idle: u32, waiting: u32) {
+        self.active.store(active, Ordering::Relaxed);
+        self.idle.store(idle, Ordering::Relaxed);
+        self.waiting.store(waiting, Ordering::Relaxed);
+    }
+
+    /// 记录一次工作器分配及其等待时间
+    pub fn record_acquire(&self, wait: Duration) {
+        self.total_acquired.fetch_add(1, Ordering::Relaxed);
+        self.total_wait_us
+            .fetch_add(wait.as_micros() as u64, Ordering::Relaxed);
+    }
+
+    /// 记录一次失败的操作
+    pub fn record_error(&self) {
+        self.errors.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// 记录一次保活检测及其是否成功
+    pub fn record_ping(&self, success: bool) {
+        self.pings.fetch_add(1, Ordering::Relaxed);
+        if !success {
+            self.missed_pings.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// 记录操作入队
+    pub fn record_enqueue(&self) {
+        self.queue_depth.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// 记录操作出队（开始执行或被丢弃）
+    pub fn record_dequeue(&self) {
+        self.queue_depth.fetch_sub(1, Ordering::Relaxed);
+    }
+
+    /// 记录一次因队列已满被拒绝的操作
+    pub fn record_rejected(&self) {
+        self.rejected.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// 已提交但尚未开始执行的操作数量
+    pub fn queue_depth(&self) -> u32 {
+        self.queue_depth.load(Ordering::Relaxed)
+    }
+
+    /// 发送操作结果，并统计失败次数
+    pub(crate) fn respond<T>(
+        &self,
+        response: oneshot::Sender<QuickDbResult<T>>,
+        result: QuickDbResult<T>,
+    ) {
+        if result.is_err() {
+            self.record_error();
+        }
+        let _ = response.send(result);
+    }
+
+    /// 获取状态快照
+    pub fn snapshot(&self) -> PoolStatus {
+        let total_acquired = self.total_acquired.load(Ordering::Relaxed);
+        let total_wait_us = self.total_wait_us.load(Ordering::Relaxed);
+        let avg_wait_ms = if total_acquired > 0 {
+            total_wait_us as f64 / total_acquired as f64 / 1000.0
+        } else {
+            0.0
+        };
+
+        PoolStatus {
+            active: self.active.load(Ordering::Relaxed),
+            idle: self.idle.load(Ordering::Relaxed),
+            waiting: self.waiting.load(Ordering::Relaxed),
+            total_acquired,
+            avg_wait_ms,
+            errors: self.errors.load(Ordering::Relaxed),
+            pings: self.pings.load(Ordering::Relaxed),
+            missed_pings: self.missed_pings.load(Ordering::Relaxed),
+            queue_depth: self.queue_depth(),
+            rejected: self.rejected.load(Ordering::Relaxed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::QuickDbError;
+
+    #[test]
+    fn snapshot_reports_counters_and_average_wait() {
+        let tracker = PoolStatusTracker::new();
+        tracker.set_load(2, 3, 4);
+        tracker.record_acquire(Duration::from_millis(2));
+        tracker.record_acquire(Duration::from_millis(4));
+
+        let (tx, _rx) = oneshot::channel::<QuickDbResult<()>>();
+        tracker.respond(
+            tx,
+            Err(QuickDbError::QueryError {
+                message: "boom".to_string(),
+            }),
+        );
+        let (tx, _rx) = oneshot::channel::<QuickDbResult<()>>();
+        tracker.respond(tx, Ok(()));
+        tracker.record_ping(true);
+        tracker.record_ping(false);
+
+        let status = tracker.snapshot();
+        assert_eq!((status.active, status.idle, status.waiting), (2, 3, 4));
+        assert_eq!(status.total_acquired, 2);
+        assert!((status.avg_wait_ms - 3.0).abs() < f64::EPSILON);
+        assert_eq!(status.errors, 1);
+        assert_eq!((status.pings, status.missed_pings), (2, 1));
+    }
+}
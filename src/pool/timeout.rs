@@ -0,0 +1,61 @@
+//! 操作超时模块
+
+use std::future::Future;
+use std::time::Duration;
+
+use crate::error::{QuickDbError, QuickDbResult};
+
+tokio::task_local! {
+    static STATEMENT_TIMEOUT: Duration;
+}
+
+/// 当前操作的超时时间，适配器据此在服务端设置语句超时
+/// （PostgreSQL 的 `statement_timeout`、MongoDB 的 `maxTimeMS`）
+#[cfg(any(feature = "postgres-support", feature = "mongodb-support", test))]
+pub(crate) fn statement_timeout() -> Option<Duration> {
+    STATEMENT_TIMEOUT.try_with(|timeout| *timeout).ok()
+}
+
+/// 在可选的超时时间内执行操作，未设置超时时直接执行
+///
+/// 超时时间在操作执行期间可通过 [`statement_timeout`] 读取
+pub(crate) async fn with_timeout<T, Fut>(timeout: Option<Duration>, operation: Fut) -> QuickDbResult<T>
+where
+    Fut: Future<Output = QuickDbResult<T>>,
+{
+    let Some(timeout) = timeout else {
+        return operation.await;
+    };
+
+    STATEMENT_TIMEOUT
+        .scope(timeout, tokio::time::timeout(timeout, operation))
+        .await
+        .unwrap_or_else(|_| {
+            Err(QuickDbError::QueryError {
+                message: crate::i18n::tf(
+                    "pool.operation_timeout",
+                    &[("timeout_ms", &timeout.as_millis().to_string())],
+                ),
+            })
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn times_out_slow_operations() {
+        let result: QuickDbResult<()> = with_timeout(Some(Duration::from_millis(10)), async {
+            tokio::time::sleep(Duration::from_secs(5)).await;
+            Ok(())
+        })
+        .await;
+        assert!(matches!(result, Err(QuickDbError::QueryError { .. })));
+
+        let result = with_timeout(None, async { Ok(statement_timeout()) }).await;
+        assert_eq!(result.unwrap(), None);
+        let result = with_timeout(Some(Duration::from_secs(1)), async { Ok(statement_timeout()) }).await;
+        assert_eq!(result.unwrap(), Some(Duration::from_secs(1)));
+    }
+}
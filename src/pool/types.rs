@@ -1,13 +1,12 @@
 //! 连接池类型定义模块
 
 use std::collections::HashMap;
-use std::sync::Arc;
 use std::time::Instant;
 use tokio::sync::oneshot;
 
 use super::ExtendedPoolConfig;
-use crate::error::{QuickDbError, QuickDbResult};
-use crate::model::{FieldDefinition, FieldType};
+use crate::error::QuickDbResult;
+use crate::model::{FieldDefinition, IndexOptions};
 use crate::types::*;
 
 /// 池化连接 - 用于兼容旧接口
@@ -39,6 +38,13 @@ pub enum DatabaseOperation {
         alias: String,
         response: oneshot::Sender<QuickDbResult<Option<DataValue>>>,
     },
+    /// 根据ID检查记录是否存在
+    ExistsById {
+        table: String,
+        id: DataValue,
+        alias: String,
+        response: oneshot::Sender<QuickDbResult<bool>>,
+    },
     /// 查找记录（支持缓存控制）
     Find {
         table: String,
@@ -78,6 +84,7 @@ pub enum DatabaseOperation {
         table: String,
         conditions: Vec<QueryConditionWithConfig>,
         data: HashMap<String, DataValue>,
+        options: UpdateOptions,
         alias: String,
         response: oneshot::Sender<QuickDbResult<u64>>,
     },
@@ -86,6 +93,7 @@ pub enum DatabaseOperation {
         table: String,
         conditions: Vec<QueryConditionWithConfig>,
         operations: Vec<crate::types::UpdateOperation>,
+        options: UpdateOptions,
         alias: String,
         response: oneshot::Sender<QuickDbResult<u64>>,
     },
@@ -97,6 +105,14 @@ pub enum DatabaseOperation {
         alias: String,
         response: oneshot::Sender<QuickDbResult<bool>>,
     },
+    /// 根据ID更新记录并返回更新后的记录
+    UpdateByIdReturning {
+        table: String,
+        id: DataValue,
+        data: HashMap<String, DataValue>,
+        alias: String,
+        response: oneshot::Sender<QuickDbResult<Option<DataValue>>>,
+    },
     /// Upsert记录 - 如果记录存在则更新，否则插入
     Upsert {
         table: String,
@@ -120,6 +136,22 @@ pub enum DatabaseOperation {
         alias: String,
         response: oneshot::Sender<QuickDbResult<bool>>,
     },
+    /// 根据ID删除记录并返回被删除的记录
+    DeleteByIdReturning {
+        table: String,
+        id: DataValue,
+        alias: String,
+        response: oneshot::Sender<QuickDbResult<Option<DataValue>>>,
+    },
+    /// 批量执行混合写操作
+    BulkWrite {
+        table: String,
+        ops: Vec<BulkOp>,
+        mode: BulkWriteMode,
+        id_strategy: IdStrategy,
+        alias: String,
+        response: oneshot::Sender<QuickDbResult<BulkWriteReport>>,
+    },
     /// 统计记录
     Count {
         table: String,
@@ -148,6 +180,7 @@ pub enum DatabaseOperation {
         index_name: String,
         fields: Vec<String>,
         unique: bool,
+        options: IndexOptions,
         response: oneshot::Sender<QuickDbResult<()>>,
     },
     /// 检查表是否存在
@@ -155,6 +188,11 @@ pub enum DatabaseOperation {
         table: String,
         response: oneshot::Sender<QuickDbResult<bool>>,
     },
+    /// 估算表记录数
+    CountEstimate {
+        table: String,
+        response: oneshot::Sender<QuickDbResult<CountEstimate>>,
+    },
     /// 删除表
     DropTable {
         table: String,
@@ -170,6 +208,15 @@ pub enum DatabaseOperation {
         response:
             oneshot::Sender<QuickDbResult<crate::stored_procedure::StoredProcedureCreateResult>>,
     },
+    /// 获取工作器持有的数据库连接句柄（与连接池共享底层连接）
+    GetConnection {
+        response: oneshot::Sender<QuickDbResult<DatabaseConnection>>,
+    },
+    /// 删除存储过程
+    DropStoredProcedure {
+        procedure_name: String,
+        response: oneshot::Sender<QuickDbResult<bool>>,
+    },
     /// 执行存储过程
     ExecuteStoredProcedure {
         procedure_name: String,
@@ -181,7 +228,9 @@ pub enum DatabaseOperation {
 }
 
 /// 原生数据库连接枚举 - 直接持有数据库连接，不使用Arc包装
-#[derive(Debug)]
+///
+/// 克隆得到的是同一底层连接池的句柄
+#[derive(Debug, Clone)]
 pub enum DatabaseConnection {
     #[cfg(feature = "sqlite-support")]
     SQLite(sqlx::SqlitePool),
@@ -190,7 +239,43 @@ pub enum DatabaseConnection {
     #[cfg(feature = "mysql-support")]
     MySQL(sqlx::MySqlPool),
     #[cfg(feature = "mongodb-support")]
-    MongoDB(mongodb::Database),
+    MongoDB(MongoConnection),
+    /// 模拟连接（`MockAdapter` 不使用连接，数据保存在适配器内）
+    #[cfg(feature = "testing")]
+    Mock,
+}
+
+/// MongoDB 连接：数据库句柄与所属客户端
+///
+/// 解引用为 [`mongodb::Database`]；限定集合名（`数据库.集合名`）通过客户端访问同一服务器上的其他数据库
+#[cfg(feature = "mongodb-support")]
+#[derive(Debug, Clone)]
+pub struct MongoConnection {
+    client: mongodb::Client,
+    database: mongodb::Database,
+}
+
+#[cfg(feature = "mongodb-support")]
+impl MongoConnection {
+    /// 使用客户端与默认数据库名创建连接
+    pub fn new(client: mongodb::Client, database_name: &str) -> Self {
+        let database = client.database(database_name);
+        Self { client, database }
+    }
+
+    /// 所属客户端
+    pub fn client(&self) -> &mongodb::Client {
+        &self.client
+    }
+}
+
+#[cfg(feature = "mongodb-support")]
+impl std::ops::Deref for MongoConnection {
+    type Target = mongodb::Database;
+
+    fn deref(&self) -> &Self::Target {
+        &self.database
+    }
 }
 
 /// 连接工作器 - 持有数据库连接池并处理操作
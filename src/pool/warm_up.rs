@@ -0,0 +1,160 @@
+//! 连接预热模块
+//!
+//! `ConnectMode::Eager` 模式下，在添加数据库时预先建立并验证最小连接数，
+//! 让凭据错误、网络不可达等问题在启动阶段暴露，而不是等到首次查询
+
+#[cfg(any(
+    feature = "sqlite-support",
+    feature = "postgres-support",
+    feature = "mysql-support",
+    feature = "mongodb-support"
+))]
+use rat_logger::info;
+
+use super::DatabaseConnection;
+#[cfg(any(
+    feature = "sqlite-support",
+    feature = "postgres-support",
+    feature = "mysql-support",
+    feature = "mongodb-support"
+))]
+use crate::error::QuickDbError;
+use crate::error::QuickDbResult;
+
+/// 预热连接：建立并验证 `min_connections` 个连接（至少1个）
+///
+/// 对 sqlx 连接池同时持有多个连接并逐个 ping，保证建立的是不同的物理连接，
+/// 数量不超过连接池的最大连接数；MongoDB 由驱动管理连接池，执行 `ping` 命令验证可达性
+pub(crate) async fn warm_up(
+    alias: &str,
+    connection: &DatabaseConnection,
+    min_connections: u32,
+) -> QuickDbResult<()> {
+    let target = min_connections.max(1);
+
+    match connection {
+        #[cfg(feature = "sqlite-support")]
+        DatabaseConnection::SQLite(pool) => warm_up_sqlx_pool(alias, pool, target).await,
+        #[cfg(feature = "postgres-support")]
+        DatabaseConnection::PostgreSQL(pool) => warm_up_sqlx_pool(alias, pool, target).await,
+        #[cfg(feature = "mysql-support")]
+        DatabaseConnection::MySQL(pool) => warm_up_sqlx_pool(alias, pool, target).await,
+        #[cfg(feature = "mongodb-support")]
+        DatabaseConnection::MongoDB(db) => {
+            db.run_command(mongodb::bson::doc! { "ping": 1 }, None)
+                .await
+                .map_err(|e| warm_up_error(alias, e))?;
+            info!("连接预热完成: 别名={}, MongoDB ping 成功", alias);
+            Ok(())
+        }
+        #[allow(unreachable_patterns)]
+        _ => {
+            let _ = (alias, target);
+            Ok(())
+        }
+    }
+}
+
+/// 验证连接可用：SQLite 执行 `SELECT 1`，PostgreSQL/MySQL 从连接池取出一个连接发送协议级 ping，
+/// MongoDB 执行 `ping` 命令
+pub(crate) async fn ping(connection: &DatabaseConnection) -> QuickDbResult<()> {
+    match connection {
+        #[cfg(feature = "sqlite-support")]
+        DatabaseConnection::SQLite(pool) => sqlx::query("SELECT 1")
+            .execute(pool)
+            .await
+            .map(|_| ())
+            .map_err(|e| QuickDbError::ConnectionError {
+                message: format!("连接 ping 失败: {}", e),
+            }),
+        #[cfg(feature = "postgres-support")]
+        DatabaseConnection::PostgreSQL(pool) => ping_sqlx_pool(pool).await,
+        #[cfg(feature = "mysql-support")]
+        DatabaseConnection::MySQL(pool) => ping_sqlx_pool(pool).await,
+        #[cfg(feature = "mongodb-support")]
+        DatabaseConnection::MongoDB(db) => db
+            .run_command(mongodb::bson::doc! { "ping": 1 }, None)
+            .await
+            .map(|_| ())
+            .map_err(|e| QuickDbError::ConnectionError {
+                message: format!("MongoDB ping 失败: {}", e),
+            }),
+        #[allow(unreachable_patterns)]
+        _ => Ok(()),
+    }
+}
+
+#[cfg(any(feature = "postgres-support", feature = "mysql-support"))]
+async fn ping_sqlx_pool<DB>(pool: &sqlx::Pool<DB>) -> QuickDbResult<()>
+where
+    DB: sqlx::Database,
+{
+    use sqlx::Connection;
+
+    let to_error = |e: sqlx::Error| QuickDbError::ConnectionError {
+        message: format!("连接 ping 失败: {}", e),
+    };
+    let mut connection = pool.acquire().await.map_err(to_error)?;
+    connection.ping().await.map_err(to_error)
+}
+
+#[cfg(any(
+    feature = "sqlite-support",
+    feature = "postgres-support",
+    feature = "mysql-support"
+))]
+async fn warm_up_sqlx_pool<DB>(alias: &str, pool: &sqlx::Pool<DB>, target: u32) -> QuickDbResult<()>
+where
+    DB: sqlx::Database,
+{
+    use sqlx::Connection;
+
+    let target = target.min(pool.options().get_max_connections());
+    let mut connections = Vec::with_capacity(target as usize);
+    for _ in 0..target {
+        let mut connection = pool.acquire().await.map_err(|e| warm_up_error(alias, e))?;
+        connection.ping().await.map_err(|e| warm_up_error(alias, e))?;
+        connections.push(connection);
+    }
+
+    info!("连接预热完成: 别名={}, 连接数={}", alias, connections.len());
+    // 连接在此释放回连接池，作为空闲连接保留
+    Ok(())
+}
+
+#[cfg(any(
+    feature = "sqlite-support",
+    feature = "postgres-support",
+    feature = "mysql-support",
+    feature = "mongodb-support"
+))]
+fn warm_up_error(alias: &str, error: impl std::fmt::Display) -> QuickDbError {
+    QuickDbError::ConnectionError {
+        message: crate::i18n::tf(
+            "pool.warm_up_failed",
+            &[("alias", alias), ("error", &error.to_string())],
+        ),
+    }
+}
+
+#[cfg(all(test, feature = "sqlite-support"))]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn warm_up_holds_min_connections_open() {
+        let pool = sqlx::sqlite::SqlitePoolOptions::new()
+            .max_connections(4)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        let connection = DatabaseConnection::SQLite(pool.clone());
+
+        warm_up("main", &connection, 3).await.unwrap();
+        assert!(pool.size() >= 3);
+
+        // 最小连接数超过最大连接数时按最大连接数预热，不会阻塞
+        warm_up("main", &connection, 10).await.unwrap();
+        assert_eq!(pool.size(), 4);
+    }
+}
@@ -8,6 +8,7 @@ use std::collections::HashMap;
 use super::DatabaseJsonProcessor;
 
 /// PostgreSQL JSON处理器
+#[derive(Default)]
 pub struct PostgresJsonProcessor;
 
 impl PostgresJsonProcessor {
@@ -26,7 +27,7 @@ impl DatabaseJsonProcessor for PostgresJsonProcessor {
         let mut data_map = HashMap::new();
 
         // 获取模型元数据
-        let model_meta = crate::manager::get_model(table_name)
+        let model_meta = crate::manager::get_model_with_alias(table_name, db_alias)
             .ok_or_else(|| format!("未找到表'{}'的模型元数据", table_name))?;
 
         
@@ -69,10 +70,10 @@ impl PostgresJsonProcessor {
             Value::String(s) => {
                 if is_datetime {
                                         match self.parse_datetime_string(s) {
-                        Some(dt) => Ok(DataValue::DateTime(dt)),
+                        Some(dt) => Ok(DataValue::DateTimeUTC(dt)),
                         None => Err(format!("datetime字段'{}'格式错误: {}, 必须使用有效的ISO 8601格式", field_name, s))
                     }
-                } else if self.is_uuid_field(field_name, &s) {
+                } else if self.is_uuid_field(field_name, s) {
                                         match self.parse_uuid_string(s) {
                         Some(uuid) => Ok(DataValue::Uuid(uuid)),
                         None => Err(format!("UUID字段'{}'格式错误: {}", field_name, s))
@@ -102,6 +103,8 @@ impl PostgresJsonProcessor {
                         description: None,
                         validator: None,
                         sqlite_compatibility: false,
+                        rename: None,
+                        computed: false,
                     }))
                     .collect::<Result<Vec<_>, String>>()?;
                 Ok(DataValue::Array(data_array))
@@ -118,6 +121,8 @@ impl PostgresJsonProcessor {
                             description: None,
                             validator: None,
                             sqlite_compatibility: false,
+                            rename: None,
+                            computed: false,
                         }).map(|val| (k.clone(), val))
                     })
                     .collect::<Result<HashMap<String, DataValue>, String>>()?;
@@ -136,34 +141,28 @@ impl PostgresJsonProcessor {
 
         // 1. ISO 8601格式带时区
         if s.contains('T') && (s.contains('+') || s.contains('-') || s.contains('Z')) {
-            match DateTime::parse_from_rfc3339(s) {
-                Ok(dt) => return Some(dt.with_timezone(&Utc)),
-                Err(_) => {},
+            if let Ok(dt) = DateTime::parse_from_rfc3339(s) {
+                return Some(dt.with_timezone(&Utc));
             }
         }
 
         // 2. ISO 8601格式无时区 (假定为UTC)
         if s.contains('T') {
-            match NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S%.f") {
-                Ok(ndt) => return Some(DateTime::from_naive_utc_and_offset(ndt, Utc)),
-                Err(_) => {
-                    match NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S") {
-                        Ok(ndt) => return Some(DateTime::from_naive_utc_and_offset(ndt, Utc)),
-                        Err(_) => {},
-                    }
-                }
+            if let Ok(ndt) = NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S%.f")
+                .or_else(|_| NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S"))
+            {
+                return Some(DateTime::from_naive_utc_and_offset(ndt, Utc));
             }
         }
 
         // 3. MySQL格式
         if s.len() == 19 && s.contains(' ') {
-            match NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S") {
-                Ok(ndt) => return Some(DateTime::from_naive_utc_and_offset(ndt, Utc)),
-                Err(_) => {},
+            if let Ok(ndt) = NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S") {
+                return Some(DateTime::from_naive_utc_and_offset(ndt, Utc));
             }
         }
 
-                None
+        None
     }
 
     /// 检查字段是否为UUID字段
@@ -185,11 +184,6 @@ impl PostgresJsonProcessor {
             return None;
         }
 
-        match uuid::Uuid::parse_str(s) {
-            Ok(uuid) => Some(uuid),
-            Err(_) => {
-                                None
-            }
-        }
+        uuid::Uuid::parse_str(s).ok()
     }
 }
\ No newline at end of file
@@ -8,6 +8,7 @@ use std::collections::HashMap;
 use super::DatabaseJsonProcessor;
 
 /// SQLite JSON处理器
+#[derive(Default)]
 pub struct SqliteJsonProcessor;
 
 impl SqliteJsonProcessor {
@@ -26,13 +27,13 @@ impl DatabaseJsonProcessor for SqliteJsonProcessor {
         let mut data_map = HashMap::new();
 
         // 获取模型元数据
-        let model_meta = crate::manager::get_model(table_name)
+        let model_meta = crate::manager::get_model_with_alias(table_name, db_alias)
             .ok_or_else(|| format!("未找到表'{}'的模型元数据", table_name))?;
 
         
         for (field_name, json_value) in json_obj {
             // 获取字段定义
-            let field_def = model_meta.fields.get(field_name)
+            model_meta.fields.get(field_name)
                 .ok_or_else(|| format!("字段'{}'未在表'{}'的模型中定义", field_name, table_name))?;
 
             
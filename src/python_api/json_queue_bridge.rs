@@ -11,6 +11,7 @@ use tokio::sync::oneshot;
 use crate::error::{QuickDbResult, QuickDbError};
 use crate::task_queue::{get_global_task_queue, DbTask};
 use crate::types::{DataValue, QueryCondition, QueryOptions};
+use super::simple_queue_bridge::shared_runtime;
 
 /// JSON队列桥接器 - 使用JSON字符串与Python通信
 #[pyclass(name = "JsonQueueBridge")]
@@ -27,9 +28,7 @@ impl PyJsonQueueBridge {
 
     /// 创建记录
     pub fn create(&self, table: String, data_json: String) -> PyResult<String> {
-        let rt = tokio::runtime::Runtime::new().map_err(|e| {
-            pyo3::exceptions::PyRuntimeError::new_err(format!("无法创建运行时: {}", e))
-        })?;
+        let rt = shared_runtime().map_err(pyo3::exceptions::PyRuntimeError::new_err)?;
 
         rt.block_on(async {
             // 解析JSON数据
@@ -53,9 +52,7 @@ impl PyJsonQueueBridge {
 
     /// 查询记录
     pub fn find(&self, table: String, conditions_json: String, options_json: Option<String>) -> PyResult<String> {
-        let rt = tokio::runtime::Runtime::new().map_err(|e| {
-            pyo3::exceptions::PyRuntimeError::new_err(format!("无法创建运行时: {}", e))
-        })?;
+        let rt = shared_runtime().map_err(pyo3::exceptions::PyRuntimeError::new_err)?;
 
         rt.block_on(async {
             // 解析查询条件
@@ -87,9 +84,7 @@ impl PyJsonQueueBridge {
 
     /// 根据ID查询记录
     pub fn find_by_id(&self, table: String, id: String, options_json: Option<String>) -> PyResult<Option<String>> {
-        let rt = tokio::runtime::Runtime::new().map_err(|e| {
-            pyo3::exceptions::PyRuntimeError::new_err(format!("无法创建运行时: {}", e))
-        })?;
+        let rt = shared_runtime().map_err(pyo3::exceptions::PyRuntimeError::new_err)?;
 
         rt.block_on(async {
             // 解析查询选项
@@ -112,9 +107,7 @@ impl PyJsonQueueBridge {
 
     /// 更新记录
     pub fn update(&self, table: String, conditions_json: String, data_json: String, options_json: Option<String>) -> PyResult<u64> {
-        let rt = tokio::runtime::Runtime::new().map_err(|e| {
-            pyo3::exceptions::PyRuntimeError::new_err(format!("无法创建运行时: {}", e))
-        })?;
+        let rt = shared_runtime().map_err(pyo3::exceptions::PyRuntimeError::new_err)?;
 
         rt.block_on(async {
             // 解析查询条件
@@ -146,9 +139,7 @@ impl PyJsonQueueBridge {
 
     /// 根据ID更新记录
     pub fn update_by_id(&self, table: String, id: String, data_json: String, options_json: Option<String>) -> PyResult<bool> {
-        let rt = tokio::runtime::Runtime::new().map_err(|e| {
-            pyo3::exceptions::PyRuntimeError::new_err(format!("无法创建运行时: {}", e))
-        })?;
+        let rt = shared_runtime().map_err(pyo3::exceptions::PyRuntimeError::new_err)?;
 
         rt.block_on(async {
             // 解析更新数据
@@ -171,9 +162,7 @@ impl PyJsonQueueBridge {
 
     /// 删除记录
     pub fn delete(&self, table: String, conditions_json: String, options_json: Option<String>) -> PyResult<u64> {
-        let rt = tokio::runtime::Runtime::new().map_err(|e| {
-            pyo3::exceptions::PyRuntimeError::new_err(format!("无法创建运行时: {}", e))
-        })?;
+        let rt = shared_runtime().map_err(pyo3::exceptions::PyRuntimeError::new_err)?;
 
         rt.block_on(async {
             // 解析查询条件
@@ -196,9 +185,7 @@ impl PyJsonQueueBridge {
 
     /// 根据ID删除记录
     pub fn delete_by_id(&self, table: String, id: String, options_json: Option<String>) -> PyResult<bool> {
-        let rt = tokio::runtime::Runtime::new().map_err(|e| {
-            pyo3::exceptions::PyRuntimeError::new_err(format!("无法创建运行时: {}", e))
-        })?;
+        let rt = shared_runtime().map_err(pyo3::exceptions::PyRuntimeError::new_err)?;
 
         rt.block_on(async {
             // 通过全局任务队列执行
@@ -212,9 +199,7 @@ impl PyJsonQueueBridge {
 
     /// 计数记录
     pub fn count(&self, table: String, conditions_json: String, options_json: Option<String>) -> PyResult<u64> {
-        let rt = tokio::runtime::Runtime::new().map_err(|e| {
-            pyo3::exceptions::PyRuntimeError::new_err(format!("无法创建运行时: {}", e))
-        })?;
+        let rt = shared_runtime().map_err(pyo3::exceptions::PyRuntimeError::new_err)?;
 
         rt.block_on(async {
             // 解析查询条件
@@ -237,9 +222,7 @@ impl PyJsonQueueBridge {
 
     /// 检查记录是否存在
     pub fn exists(&self, table: String, conditions_json: String, options_json: Option<String>) -> PyResult<bool> {
-        let rt = tokio::runtime::Runtime::new().map_err(|e| {
-            pyo3::exceptions::PyRuntimeError::new_err(format!("无法创建运行时: {}", e))
-        })?;
+        let rt = shared_runtime().map_err(pyo3::exceptions::PyRuntimeError::new_err)?;
 
         rt.block_on(async {
             // 解析查询条件
@@ -262,9 +245,7 @@ impl PyJsonQueueBridge {
 
     /// 检查表是否存在
     pub fn check_table(&self, table: String) -> PyResult<bool> {
-        let rt = tokio::runtime::Runtime::new().map_err(|e| {
-            pyo3::exceptions::PyRuntimeError::new_err(format!("无法创建运行时: {}", e))
-        })?;
+        let rt = shared_runtime().map_err(pyo3::exceptions::PyRuntimeError::new_err)?;
 
         rt.block_on(async {
             // 通过全局任务队列执行
@@ -346,6 +327,8 @@ pub fn parse_query_condition(value: Value) -> PyResult<QueryCondition> {
         "exists" => crate::types::QueryOperator::Exists,
         "isNull" => crate::types::QueryOperator::IsNull,
         "isNotNull" => crate::types::QueryOperator::IsNotNull,
+        "between" => crate::types::QueryOperator::Between,
+        "notBetween" => crate::types::QueryOperator::NotBetween,
         _ => return Err(pyo3::exceptions::PyValueError::new_err(format!("不支持的操作符: {}", operator_str))),
     };
 
@@ -0,0 +1,552 @@
+//! pyo3 绑定
+//!
+//! `PyDbQueueBridge` 把 [`SimpleQueueBridge`] 暴露给 Python：条件、数据与查询选项都以 JSON
+//! 字符串传入，结果以 JSON 字符串返回，与 `send_request` 的请求格式一致。
+//! 以 `_async` 结尾的方法返回 awaitable，可在 asyncio 事件循环中 `await`，等待期间不占用 GIL。
+//! `begin_transaction`（别名 `transaction`）返回 [`PyTransaction`]，可以作为上下文管理器使用：
+//!
+//! ```python
+//! with bridge.transaction("default") as tx:
+//!     tx.update("accounts", conditions, updates)
+//!     tx.create("ledger", record)
+//! ```
+//!
+//! 代码块正常结束时提交，抛出异常时回滚。
+//! 扩展模块由独立的 Python 项目打包，在其 `#[pymodule]` 中调用 [`register_python_module`]
+
+// pyo3 0.20 的宏展开在 2024 edition 下会触发这两项检查
+#![allow(unsafe_op_in_unsafe_fn, non_local_definitions)]
+
+use pyo3::exceptions::PyRuntimeError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use serde_json::json;
+
+use super::simple_queue_bridge::SimpleQueueBridge;
+
+/// Python 侧的数据库队列桥接器
+#[pyclass(name = "PyDbQueueBridge")]
+pub struct PyDbQueueBridge {
+    bridge: SimpleQueueBridge,
+}
+
+impl PyDbQueueBridge {
+    /// 发送请求并等待结果，等待期间释放 GIL，其他 Python 线程可以继续执行
+    fn request(&self, py: Python<'_>, request_type: &str, request: serde_json::Value) -> PyResult<String> {
+        py.allow_threads(|| self.bridge.send_request(request_type.to_string(), request.to_string()))
+            .map_err(PyRuntimeError::new_err)
+    }
+
+    /// 发送请求并把 JSON 结果转换为 Python 字典
+    fn request_dict<'py>(&self, py: Python<'py>, request_type: &str, request: serde_json::Value) -> PyResult<&'py PyDict> {
+        let response = self.request(py, request_type, request)?;
+        let value = py.import("json")?.call_method1("loads", (response,))?;
+        Ok(value.downcast::<PyDict>()?)
+    }
+
+    /// 把请求交给共享 runtime 执行，返回 asyncio 可以 await 的对象
+    fn request_async<'py>(&self, py: Python<'py>, request_type: &str, data: String) -> PyResult<&'py PyAny> {
+        let handle = self.bridge.spawn_request(request_type.to_string(), data);
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            handle
+                .await
+                .map_err(|e| PyRuntimeError::new_err(e.to_string()))?
+                .map_err(PyRuntimeError::new_err)
+        })
+    }
+}
+
+#[pymethods]
+impl PyDbQueueBridge {
+    #[new]
+    fn new() -> PyResult<Self> {
+        let bridge = SimpleQueueBridge::new().map_err(PyRuntimeError::new_err)?;
+        Ok(Self { bridge })
+    }
+
+    /// 设置同步请求的默认等待上限（毫秒，`None` 表示不限时）
+    #[pyo3(signature = (timeout_ms=None))]
+    fn set_request_timeout(&mut self, timeout_ms: Option<u64>) {
+        self.bridge.set_request_timeout(timeout_ms);
+    }
+
+    /// 发送原始请求（`data` 为 JSON 字符串）
+    fn send_request(&self, py: Python<'_>, request_type: &str, data: String) -> PyResult<String> {
+        py.allow_threads(|| self.bridge.send_request(request_type.to_string(), data))
+            .map_err(PyRuntimeError::new_err)
+    }
+
+    /// 异步发送原始请求
+    fn send_request_async<'py>(&self, py: Python<'py>, request_type: &str, data: String) -> PyResult<&'py PyAny> {
+        self.request_async(py, request_type, data)
+    }
+
+    /// 创建记录
+    #[pyo3(signature = (table, data, alias=None))]
+    fn create(&self, py: Python<'_>, table: &str, data: &str, alias: Option<&str>) -> PyResult<String> {
+        self.request(py, "create", json!({ "table": table, "data": data, "alias": alias }))
+    }
+
+    /// 异步创建记录
+    #[pyo3(signature = (table, data, alias=None))]
+    fn create_async<'py>(&self, py: Python<'py>, table: &str, data: &str, alias: Option<&str>) -> PyResult<&'py PyAny> {
+        self.request_async(py, "create", json!({ "table": table, "data": data, "alias": alias }).to_string())
+    }
+
+    /// 按条件查询记录
+    #[pyo3(signature = (table, conditions=None, options=None, alias=None))]
+    fn find(
+        &self,
+        py: Python<'_>,
+        table: &str,
+        conditions: Option<&str>,
+        options: Option<&str>,
+        alias: Option<&str>,
+    ) -> PyResult<String> {
+        self.request(
+            py,
+            "find",
+            json!({ "table": table, "conditions": conditions, "options": options, "alias": alias }),
+        )
+    }
+
+    /// 异步按条件查询记录
+    #[pyo3(signature = (table, conditions=None, options=None, alias=None))]
+    fn find_async<'py>(
+        &self,
+        py: Python<'py>,
+        table: &str,
+        conditions: Option<&str>,
+        options: Option<&str>,
+        alias: Option<&str>,
+    ) -> PyResult<&'py PyAny> {
+        let request = json!({ "table": table, "conditions": conditions, "options": options, "alias": alias });
+        self.request_async(py, "find", request.to_string())
+    }
+
+    /// 按条件组合查询记录
+    #[pyo3(signature = (table, condition_groups, options=None, alias=None))]
+    fn find_with_groups(
+        &self,
+        py: Python<'_>,
+        table: &str,
+        condition_groups: &str,
+        options: Option<&str>,
+        alias: Option<&str>,
+    ) -> PyResult<String> {
+        self.request(
+            py,
+            "find_with_groups",
+            json!({ "table": table, "condition_groups": condition_groups, "options": options, "alias": alias }),
+        )
+    }
+
+    /// 根据ID查询记录
+    #[pyo3(signature = (table, id, alias=None))]
+    fn find_by_id(&self, py: Python<'_>, table: &str, id: &str, alias: Option<&str>) -> PyResult<String> {
+        self.request(py, "find_by_id", json!({ "table": table, "id": id, "alias": alias }))
+    }
+
+    /// 按条件更新记录，返回受影响的行数
+    #[pyo3(signature = (table, conditions, updates, alias=None))]
+    fn update(&self, py: Python<'_>, table: &str, conditions: &str, updates: &str, alias: Option<&str>) -> PyResult<String> {
+        self.request(
+            py,
+            "update",
+            json!({ "table": table, "conditions": conditions, "updates": updates, "alias": alias }),
+        )
+    }
+
+    /// 根据ID更新记录
+    #[pyo3(signature = (table, id, updates, alias=None))]
+    fn update_by_id(&self, py: Python<'_>, table: &str, id: &str, updates: &str, alias: Option<&str>) -> PyResult<String> {
+        self.request(py, "update_by_id", json!({ "table": table, "id": id, "updates": updates, "alias": alias }))
+    }
+
+    /// 按条件删除记录，返回受影响的行数
+    #[pyo3(signature = (table, conditions, alias=None))]
+    fn delete(&self, py: Python<'_>, table: &str, conditions: &str, alias: Option<&str>) -> PyResult<String> {
+        self.request(py, "delete", json!({ "table": table, "conditions": conditions, "alias": alias }))
+    }
+
+    /// 根据ID删除记录
+    #[pyo3(signature = (table, id, alias=None))]
+    fn delete_by_id(&self, py: Python<'_>, table: &str, id: &str, alias: Option<&str>) -> PyResult<String> {
+        self.request(py, "delete_by_id", json!({ "table": table, "id": id, "alias": alias }))
+    }
+
+    /// 统计记录数
+    #[pyo3(signature = (table, conditions=None, alias=None))]
+    fn count(&self, py: Python<'_>, table: &str, conditions: Option<&str>, alias: Option<&str>) -> PyResult<String> {
+        self.request(py, "count", json!({ "table": table, "conditions": conditions, "alias": alias }))
+    }
+
+    /// 检查是否存在满足条件的记录
+    #[pyo3(signature = (table, conditions, alias=None))]
+    fn exists(&self, py: Python<'_>, table: &str, conditions: &str, alias: Option<&str>) -> PyResult<String> {
+        self.request(py, "exists", json!({ "table": table, "conditions": conditions, "alias": alias }))
+    }
+
+    /// 开启事务（`alias` 为 `None` 时使用默认别名）
+    #[pyo3(signature = (alias=None))]
+    fn begin_transaction(&self, py: Python<'_>, alias: Option<&str>) -> PyResult<PyTransaction> {
+        let response = self.request(py, "begin_transaction", json!({ "alias": alias }))?;
+        let response: serde_json::Value =
+            serde_json::from_str(&response).map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
+        let transaction_id = response["data"]["transaction_id"]
+            .as_str()
+            .ok_or_else(|| PyRuntimeError::new_err("开启事务的响应缺少transaction_id"))?;
+        Ok(PyTransaction {
+            bridge: self.bridge.clone(),
+            transaction_id: transaction_id.to_string(),
+            finished: false,
+        })
+    }
+
+    /// `begin_transaction` 的别名，便于写成 `with bridge.transaction() as tx:`
+    #[pyo3(signature = (alias=None))]
+    fn transaction(&self, py: Python<'_>, alias: Option<&str>) -> PyResult<PyTransaction> {
+        self.begin_transaction(py, alias)
+    }
+
+    /// 获取连接池状态（`alias` 为 `None` 时返回全部连接池）
+    #[pyo3(signature = (alias=None))]
+    fn get_pool_status<'py>(&self, py: Python<'py>, alias: Option<&str>) -> PyResult<&'py PyDict> {
+        self.request_dict(py, "pool_status", json!({ "alias": alias }))
+    }
+
+    /// 获取指定别名的缓存统计
+    fn get_cache_stats<'py>(&self, py: Python<'py>, alias: &str) -> PyResult<&'py PyDict> {
+        self.request_dict(py, "cache_stats", json!({ "alias": alias }))
+    }
+
+    /// 清空缓存（`alias` 为 `None` 时清空全部缓存）
+    #[pyo3(signature = (alias=None))]
+    fn clear_cache<'py>(&self, py: Python<'py>, alias: Option<&str>) -> PyResult<&'py PyDict> {
+        self.request_dict(py, "clear_cache", json!({ "alias": alias }))
+    }
+}
+
+/// Python 侧的事务句柄，写入在同一个数据库连接上执行，直到提交或回滚
+///
+/// 写入前执行模型校验器，插入的数据按ID策略分配ID并补齐字段默认值；句柄未结束就被回收时事务回滚
+#[pyclass(name = "PyTransaction")]
+pub struct PyTransaction {
+    bridge: SimpleQueueBridge,
+    transaction_id: String,
+    finished: bool,
+}
+
+impl PyTransaction {
+    fn request(&self, py: Python<'_>, request_type: &str, mut request: serde_json::Value) -> PyResult<String> {
+        if self.finished {
+            return Err(PyRuntimeError::new_err(format!("事务已结束: {}", self.transaction_id)));
+        }
+        request["transaction_id"] = json!(self.transaction_id);
+        py.allow_threads(|| self.bridge.send_request(request_type.to_string(), request.to_string()))
+            .map_err(PyRuntimeError::new_err)
+    }
+
+    fn finish(&mut self, py: Python<'_>, request_type: &str) -> PyResult<()> {
+        self.request(py, request_type, json!({}))?;
+        self.finished = true;
+        Ok(())
+    }
+}
+
+#[pymethods]
+impl PyTransaction {
+    /// 事务ID
+    #[getter]
+    fn transaction_id(&self) -> &str {
+        &self.transaction_id
+    }
+
+    /// 在事务内创建记录，返回受影响的行数
+    fn create(&self, py: Python<'_>, table: &str, data: &str) -> PyResult<String> {
+        self.request(py, "create", json!({ "table": table, "data": data }))
+    }
+
+    /// 在事务内按条件更新记录，返回受影响的行数
+    fn update(&self, py: Python<'_>, table: &str, conditions: &str, updates: &str) -> PyResult<String> {
+        self.request(py, "update", json!({ "table": table, "conditions": conditions, "updates": updates }))
+    }
+
+    /// 在事务内按条件删除记录，返回受影响的行数
+    fn delete(&self, py: Python<'_>, table: &str, conditions: &str) -> PyResult<String> {
+        self.request(py, "delete", json!({ "table": table, "conditions": conditions }))
+    }
+
+    /// 提交事务
+    fn commit(&mut self, py: Python<'_>) -> PyResult<()> {
+        self.finish(py, "commit_transaction")
+    }
+
+    /// 回滚事务
+    fn rollback(&mut self, py: Python<'_>) -> PyResult<()> {
+        self.finish(py, "rollback_transaction")
+    }
+
+    fn __enter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    /// 正常退出时提交，发生异常时回滚；不吞掉异常
+    fn __exit__(
+        &mut self,
+        py: Python<'_>,
+        exc_type: Option<&PyAny>,
+        _exc_value: Option<&PyAny>,
+        _traceback: Option<&PyAny>,
+    ) -> PyResult<bool> {
+        if !self.finished {
+            match exc_type {
+                Some(_) => self.rollback(py)?,
+                None => self.commit(py)?,
+            }
+        }
+        Ok(false)
+    }
+}
+
+impl Drop for PyTransaction {
+    fn drop(&mut self) {
+        if !self.finished {
+            // 回收时可能持有 GIL，不阻塞等待回滚结果
+            let request = json!({ "transaction_id": self.transaction_id }).to_string();
+            drop(self.bridge.spawn_request("rollback_transaction".to_string(), request));
+        }
+    }
+}
+
+/// 向 Python 模块注册桥接器类
+pub fn register_python_module(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
+    m.add("__version__", env!("CARGO_PKG_VERSION"))?;
+    m.add_class::<PyDbQueueBridge>()?;
+    m.add_class::<PyTransaction>()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bridge_is_registered_and_forwards_requests() {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| -> PyResult<()> {
+            let module = PyModule::new(py, "rat_quickdb")?;
+            register_python_module(py, module)?;
+            assert_eq!(module.getattr("__version__")?.extract::<&str>()?, env!("CARGO_PKG_VERSION"));
+
+            let bridge = module.getattr("PyDbQueueBridge")?.call0()?;
+            for method in ["update", "update_by_id", "delete", "delete_by_id", "count", "exists", "find_with_groups"] {
+                assert!(bridge.hasattr(method)?, "缺少方法: {}", method);
+            }
+            // 请求在解析记录数据时失败，错误以 RuntimeError 抛出
+            let error = bridge.call_method1("create", ("users", "不是JSON")).unwrap_err();
+            assert!(error.is_instance_of::<PyRuntimeError>(py));
+            assert!(error.to_string().contains("解析记录数据失败"));
+            Ok(())
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn status_methods_return_dicts() {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| -> PyResult<()> {
+            let bridge = Py::new(py, PyDbQueueBridge::new()?)?.into_ref(py);
+            let status = bridge.call_method0("get_pool_status")?.downcast::<PyDict>()?;
+            assert!(status.get_item("success")?.unwrap().extract::<bool>()?);
+            assert!(status.get_item("data")?.unwrap().is_instance_of::<PyDict>());
+
+            let cleared = bridge.call_method0("clear_cache")?.downcast::<PyDict>()?;
+            assert!(cleared.get_item("success")?.unwrap().extract::<bool>()?);
+            let error = bridge.call_method1("get_cache_stats", ("py_bridge_missing",)).unwrap_err();
+            assert!(error.is_instance_of::<PyRuntimeError>(py));
+            Ok(())
+        })
+        .unwrap();
+    }
+
+    #[cfg(feature = "sqlite-support")]
+    #[test]
+    fn transactions_commit_on_exit_and_roll_back_on_error() {
+        crate::i18n::ErrorMessageI18n::init_i18n();
+        let alias = "py_bridge_transactions";
+        let runtime = crate::python_api::simple_queue_bridge::shared_runtime().unwrap();
+        let manager = crate::manager::get_global_pool_manager();
+        let count = || {
+            runtime.block_on(manager.with_connection(Some(alias), |mut conn| async move {
+                conn.query("SELECT COUNT(*) AS total FROM ledger", &[]).await
+            }))
+        };
+        runtime
+            .block_on(async {
+                manager.add_database(crate::types::DatabaseConfig::sqlite_memory_for_test(alias)).await?;
+                manager.with_connection(Some(alias), |mut conn| async move {
+                    conn.execute("CREATE TABLE ledger (id INTEGER PRIMARY KEY, amount INTEGER)", &[]).await
+                })
+                .await
+            })
+            .unwrap();
+
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| -> PyResult<()> {
+            let globals = PyDict::new(py);
+            globals.set_item("bridge", Py::new(py, PyDbQueueBridge::new()?)?)?;
+            globals.set_item("alias", alias)?;
+            py.run(
+                r#"
+with bridge.begin_transaction(alias) as tx:
+    tx.create("ledger", '{"amount": {"Int": 5}}')
+
+try:
+    with bridge.begin_transaction(alias) as tx:
+        tx.create("ledger", '{"amount": {"Int": 7}}')
+        raise ValueError("abort")
+except ValueError:
+    pass
+
+try:
+    tx.create("ledger", '{"amount": {"Int": 9}}')
+    finished_error = None
+except RuntimeError as error:
+    finished_error = str(error)
+"#,
+                Some(globals),
+                None,
+            )?;
+            let finished_error: String = globals.get_item("finished_error")?.unwrap().extract()?;
+            assert!(finished_error.contains("事务已结束"), "{}", finished_error);
+            Ok(())
+        })
+        .unwrap();
+
+        let rows = count().unwrap();
+        let crate::types::DataValue::Object(row) = &rows[0] else {
+            panic!("查询结果应为对象: {:?}", rows);
+        };
+        assert_eq!(row.get("total"), Some(&crate::types::DataValue::Int(1)));
+    }
+
+    #[cfg(feature = "sqlite-support")]
+    #[test]
+    fn transaction_writes_run_validators_ids_and_defaults() {
+        use crate::types::DataValue;
+        crate::i18n::ErrorMessageI18n::init_i18n();
+        let alias = "py_bridge_prepared_transactions";
+        let runtime = crate::python_api::simple_queue_bridge::shared_runtime().unwrap();
+        let manager = crate::manager::get_global_pool_manager();
+        crate::model::validators::register("py_bridge_non_empty", |value| async move {
+            match value {
+                DataValue::String(s) if !s.is_empty() => Ok(()),
+                _ => Err(crate::error::QuickDbError::ValidationError {
+                    field: "body".to_string(),
+                    message: "内容不能为空".to_string(),
+                }),
+            }
+        });
+        runtime
+            .block_on(async {
+                manager
+                    .add_database(crate::types::DatabaseConfig {
+                        id_strategy: crate::types::IdStrategy::Uuid,
+                        ..crate::types::DatabaseConfig::sqlite_memory_for_test(alias)
+                    })
+                    .await?;
+                manager.register_model(crate::model::ModelMeta {
+                    collection_name: "notes".to_string(),
+                    database_alias: Some(alias.to_string()),
+                    fields: std::collections::HashMap::from([
+                        ("id".to_string(), crate::model::string_field(None, None, None)),
+                        (
+                            "body".to_string(),
+                            crate::model::string_field(None, None, None).validator("py_bridge_non_empty"),
+                        ),
+                        (
+                            "status".to_string(),
+                            crate::model::string_field(None, None, None)
+                                .default_value(DataValue::String("draft".to_string())),
+                        ),
+                    ]),
+                    ..Default::default()
+                })?;
+                manager.with_connection(Some(alias), |mut conn| async move {
+                    conn.execute("CREATE TABLE notes (id TEXT PRIMARY KEY, body TEXT, status TEXT)", &[]).await
+                })
+                .await
+            })
+            .unwrap();
+
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| -> PyResult<()> {
+            let globals = PyDict::new(py);
+            globals.set_item("bridge", Py::new(py, PyDbQueueBridge::new()?)?)?;
+            globals.set_item("alias", alias)?;
+            py.run(
+                r#"
+with bridge.transaction(alias) as tx:
+    tx.create("notes", '{"body": {"String": "hello"}}')
+
+try:
+    with bridge.transaction(alias) as tx:
+        tx.update("notes", '[]', '{"body": ""}')
+    invalid_error = None
+except RuntimeError as error:
+    invalid_error = str(error)
+"#,
+                Some(globals),
+                None,
+            )?;
+            let invalid_error: String = globals.get_item("invalid_error")?.unwrap().extract()?;
+            assert!(invalid_error.contains("内容不能为空"), "{}", invalid_error);
+            Ok(())
+        })
+        .unwrap();
+
+        let rows = runtime
+            .block_on(manager.with_connection(Some(alias), |mut conn| async move {
+                conn.query("SELECT id, body, status FROM notes", &[]).await
+            }))
+            .unwrap();
+        let [DataValue::Object(row)] = rows.as_slice() else {
+            panic!("应只有一条记录: {:?}", rows);
+        };
+        assert!(matches!(row.get("id"), Some(DataValue::String(id)) if !id.is_empty()));
+        assert_eq!(row.get("body"), Some(&DataValue::String("hello".to_string())));
+        assert_eq!(row.get("status"), Some(&DataValue::String("draft".to_string())));
+        crate::model::validators::unregister("py_bridge_non_empty");
+    }
+
+    #[test]
+    fn async_methods_return_awaitables() {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| -> PyResult<()> {
+            let module = PyModule::new(py, "rat_quickdb")?;
+            register_python_module(py, module)?;
+            let globals = pyo3::types::PyDict::new(py);
+            globals.set_item("bridge", module.getattr("PyDbQueueBridge")?.call0()?)?;
+            py.run(
+                r#"
+import asyncio
+
+async def main():
+    try:
+        await bridge.create_async("users", "不是JSON")
+    except RuntimeError as error:
+        return str(error)
+
+message = asyncio.run(main())
+"#,
+                Some(globals),
+                None,
+            )?;
+            let message: String = globals.get_item("message")?.unwrap().extract()?;
+            assert!(message.contains("解析记录数据失败"), "{}", message);
+            Ok(())
+        })
+        .unwrap();
+    }
+}
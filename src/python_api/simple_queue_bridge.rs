@@ -1,9 +1,7 @@
 //! 简化版队列桥接器
 //!
-//! 使用 crossbeam::SegQueue 实现 Rust-Python 解耦通信
-//! 移除复杂的任务队列依赖，直接处理基本数据库操作
+//! 在共享的 tokio runtime 上直接处理基本数据库操作，请求与响应均为 JSON 字符串
 
-use crossbeam_queue::SegQueue;
 use std::sync::Arc;
 use serde_json;
 use uuid::Uuid;
@@ -11,6 +9,8 @@ use std::collections::HashMap;
 use rat_logger::{info, warn, error};
 use chrono;
 
+use super::transactions;
+
 // 导入必要的模块和类型
 use crate::types::{DataValue, DatabaseConfig, QueryOperator, QueryCondition};
 use crate::manager::{get_global_pool_manager, add_database};
@@ -43,13 +43,30 @@ pub struct PyResponseMessage {
 }
 
 /// 简化版队列桥接器
+#[derive(Clone)]
 pub struct SimpleQueueBridge {
-    /// 请求队列 - Python 向 Rust 发送请求
-    request_queue: Arc<SegQueue<PyRequestMessage>>,
-    /// 响应队列 - Rust 向 Python 返回响应
-    response_queue: Arc<SegQueue<PyResponseMessage>>,
     /// 全局tokio runtime句柄
     runtime_handle: Arc<tokio::runtime::Runtime>,
+    /// 同步请求的默认等待上限（`None` 表示不限时）
+    request_timeout: Option<std::time::Duration>,
+}
+
+/// 所有桥接器共享的tokio runtime
+static SHARED_RUNTIME: std::sync::OnceLock<Arc<tokio::runtime::Runtime>> = std::sync::OnceLock::new();
+
+/// 获取桥接器共享的tokio runtime，首次调用时创建
+///
+/// 避免每次调用都新建 runtime（开销大且会遗留线程局部状态）
+pub fn shared_runtime() -> Result<Arc<tokio::runtime::Runtime>, String> {
+    if let Some(runtime) = SHARED_RUNTIME.get() {
+        return Ok(runtime.clone());
+    }
+    let runtime = Arc::new(
+        tokio::runtime::Runtime::new()
+            .map_err(|e| format!("创建tokio runtime失败: {}", e))?
+    );
+    // 并发初始化时以先写入者为准
+    Ok(SHARED_RUNTIME.get_or_init(|| runtime).clone())
 }
 
 impl SimpleQueueBridge {
@@ -57,42 +74,98 @@ impl SimpleQueueBridge {
     pub fn new() -> Result<Self, String> {
         info!("创建简化版队列桥接器");
 
-        let request_queue = Arc::new(SegQueue::new());
-        let response_queue = Arc::new(SegQueue::new());
-
-        // 创建持久的tokio runtime
-        let runtime_handle = Arc::new(
-            tokio::runtime::Runtime::new()
-                .map_err(|e| format!("创建tokio runtime失败: {}", e))?
-        );
+        // 复用共享的tokio runtime
+        let runtime_handle = shared_runtime()?;
 
         Ok(Self {
-            request_queue,
-            response_queue,
             runtime_handle,
+            request_timeout: None,
         })
     }
 
-    /// 发送请求并等待响应
+    /// 设置同步请求的默认等待上限（毫秒，`None` 表示不限时）
+    pub fn set_request_timeout(&mut self, timeout_ms: Option<u64>) {
+        self.request_timeout = timeout_ms.map(std::time::Duration::from_millis);
+    }
+
+    /// 发送请求并等待响应（使用默认等待上限）
     pub fn send_request(&self, request_type: String, data: String) -> Result<String, String> {
-        let request_id = Uuid::new_v4().to_string();
+        self.wait_for_response(request_type, data, self.request_timeout)
+    }
 
-        info!("发送请求: {} - {}", request_type, request_id);
+    /// 发送请求并最多等待 `timeout_ms` 毫秒
+    pub fn send_request_with_timeout(&self, request_type: String, data: String, timeout_ms: u64) -> Result<String, String> {
+        self.wait_for_response(request_type, data, Some(std::time::Duration::from_millis(timeout_ms)))
+    }
 
-        // 克隆request_id以避免移动问题
-        let request_id_clone = request_id.clone();
+    /// 在共享runtime上执行请求并阻塞等待结果，超时后返回错误
+    ///
+    /// 超时只结束等待，已发往数据库的操作不会回滚
+    fn wait_for_response(
+        &self,
+        request_type: String,
+        data: String,
+        timeout: Option<std::time::Duration>,
+    ) -> Result<String, String> {
+        self.runtime_handle.block_on(self.request_with_timeout(request_type, data, timeout))
+    }
 
-        // 使用持久的runtime处理请求
-        let result = self.runtime_handle.block_on(async {
-            self.process_request_async(&request_type, &data, &request_id).await
-        });
+    /// 在共享runtime上启动请求并立即返回句柄，句柄可以在任意运行时中 await
+    ///
+    /// 请求同样遵循默认等待上限。Python 侧的异步方法通过它把请求交给持有连接池的
+    /// runtime 执行，而不是在 pyo3-asyncio 自己的 runtime 上访问数据库
+    pub fn spawn_request(&self, request_type: String, data: String) -> tokio::task::JoinHandle<Result<String, String>> {
+        let bridge = self.clone();
+        self.runtime_handle.spawn(async move {
+            bridge.request_with_timeout(request_type, data, bridge.request_timeout).await
+        })
+    }
 
-        let response = match result {
+    async fn request_with_timeout(
+        &self,
+        request_type: String,
+        data: String,
+        timeout: Option<std::time::Duration>,
+    ) -> Result<String, String> {
+        let request = self.send_request_async(request_type.clone(), data);
+        match timeout {
+            Some(timeout) => tokio::time::timeout(timeout, request).await
+                .map_err(|_| format!("请求超时: {} - {}ms", request_type, timeout.as_millis()))?,
+            None => request.await,
+        }
+    }
+
+    /// 获取连接池状态（`alias` 为 `None` 时返回全部连接池）
+    pub fn get_pool_status(&self, alias: Option<String>) -> Result<String, String> {
+        self.send_request("pool_status".to_string(), serde_json::json!({ "alias": alias }).to_string())
+    }
+
+    /// 获取指定别名的缓存统计
+    pub fn get_cache_stats(&self, alias: String) -> Result<String, String> {
+        self.send_request("cache_stats".to_string(), serde_json::json!({ "alias": alias }).to_string())
+    }
+
+    /// 清空指定别名的缓存（`alias` 为 `None` 时清空全部缓存）
+    pub fn clear_cache(&self, alias: Option<String>) -> Result<String, String> {
+        self.send_request("clear_cache".to_string(), serde_json::json!({ "alias": alias }).to_string())
+    }
+
+    /// 异步发送请求，不阻塞调用线程
+    ///
+    /// 需要在 tokio 运行时中 await，Python 侧可通过 pyo3-asyncio 的
+    /// `future_into_py` 包装为 `await bridge.create_async(...)`；桥接器可廉价克隆，
+    /// 以便移入 `'static` 的 future
+    pub async fn send_request_async(&self, request_type: String, data: String) -> Result<String, String> {
+        let request_id = Uuid::new_v4().to_string();
+
+        info!("发送请求: {} - {}", request_type, request_id);
+
+        let response = match self.process_request_async(&request_type, &data, &request_id).await {
             Ok(response) => response,
             Err(e) => {
                 error!("处理请求时发生错误: {}", e);
                 PyResponseMessage {
-                    request_id: request_id_clone,
+                    request_id,
                     success: false,
                     data: String::new(),
                     error: Some(e),
@@ -117,15 +190,26 @@ impl SimpleQueueBridge {
             "create" => self.handle_create_odm(data).await,
             "find" => self.handle_find_odm(data).await,
             "update" => self.handle_update_odm(data).await,
+            "update_with_operations" => self.handle_update_with_operations_odm(data).await,
             "delete" => self.handle_delete_odm(data).await,
             "count" => self.handle_count_odm(data).await,
             "find_by_id" => self.handle_find_by_id_odm(data).await,
+            "exists" => self.handle_exists_odm(data).await,
+            "find_with_groups" => self.handle_find_with_groups_odm(data).await,
+            "exists_by_id" => self.handle_exists_by_id_odm(data).await,
             "delete_by_id" => self.handle_delete_by_id_odm(data).await,
             "update_by_id" => self.handle_update_by_id_odm(data).await,
             "register_model" => self.handle_register_model_odm(data).await,
             "create_table" => self.handle_create_table_odm(data).await,
             "drop_table" => self.handle_drop_table_odm(data).await,
             "add_database" => self.handle_add_database_odm(data).await,
+            "pool_status" => self.handle_pool_status(data).await,
+            "cache_stats" => self.handle_cache_stats(data).await,
+            "cache_stats_by_table" => self.handle_cache_stats_by_table(data).await,
+            "clear_cache" => self.handle_clear_cache(data).await,
+            "begin_transaction" => self.handle_begin_transaction(data).await,
+            "commit_transaction" => self.handle_finish_transaction(data, true).await,
+            "rollback_transaction" => self.handle_finish_transaction(data, false).await,
             _ => Err(format!("不支持的请求类型: {}", request_type)),
         };
 
@@ -187,6 +271,11 @@ impl SimpleQueueBridge {
             return Err("record不是Object类型".to_string());
         }
 
+        if let Some(transaction_id) = Self::transaction_id(&request) {
+            let affected = transactions::create(transaction_id, table, data_map).await?;
+            return Ok(Self::affected_response(affected));
+        }
+
         // 通过ODM层执行创建操作
         use crate::odm::get_odm_manager;
         let odm_manager = get_odm_manager().await;
@@ -220,12 +309,21 @@ impl SimpleQueueBridge {
             vec![] // 空条件表示查询所有
         };
 
-        let options = None;
+        // 解析查询选项（JSON字符串或对象）
+        let options = match request.get("options") {
+            Some(serde_json::Value::String(options_str)) => {
+                let options_value: serde_json::Value = serde_json::from_str(options_str)
+                    .map_err(|e| format!("解析查询选项失败: {}", e))?;
+                Some(self.parse_query_options(options_value)?)
+            }
+            Some(serde_json::Value::Null) | None => None,
+            Some(options_value) => Some(self.parse_query_options(options_value.clone())?),
+        };
 
         // 通过ODM层执行查询操作
         use crate::odm::get_odm_manager;
         let odm_manager = get_odm_manager().await;
-        let result = odm_manager.find(table, conditions, options, alias).await
+        let result = odm_manager.find(table, conditions.into_iter().map(Into::into).collect(), options, alias).await
             .map_err(|e| format!("ODM查询操作失败: {}", e))?;
 
         info!("ODM查询记录成功: {} - {} 条记录", table, result.len());
@@ -276,9 +374,13 @@ impl SimpleQueueBridge {
             }
         } else {
             // 默认添加更新时间
-            updates.insert("updated_at".to_string(), DataValue::DateTime(
-                chrono::Utc::now()
-            ));
+            updates.insert("updated_at".to_string(), DataValue::DateTimeUTC(chrono::Utc::now()));
+        }
+
+        let conditions = conditions.into_iter().map(Into::into).collect();
+        if let Some(transaction_id) = Self::transaction_id(&request) {
+            let affected = transactions::update(transaction_id, table, conditions, updates).await?;
+            return Ok(Self::affected_response(affected));
         }
 
         // 通过ODM层执行更新操作
@@ -296,6 +398,59 @@ impl SimpleQueueBridge {
         }).to_string())
     }
 
+    /// 使用ODM层处理操作数组更新（原子性增减等）
+    ///
+    /// `operations` 为 JSON 数组字符串，元素形如 `{"field": "views", "operation": "Increment", "value": 1}`
+    async fn handle_update_with_operations_odm(&self, data: &str) -> Result<String, String> {
+        let request: serde_json::Value = serde_json::from_str(data)
+            .map_err(|e| format!("解析更新请求失败: {}", e))?;
+
+        let table = request["table"].as_str()
+            .ok_or("缺少表名")?;
+        let alias = request.get("alias").and_then(|v| v.as_str());
+
+        let conditions = if let Some(conditions_str) = request.get("conditions").and_then(|v| v.as_str()) {
+            let conditions_value: serde_json::Value = serde_json::from_str(conditions_str)
+                .map_err(|e| format!("解析更新条件失败: {}", e))?;
+            self.parse_query_conditions(conditions_value)?
+        } else {
+            vec![] // 空条件表示更新所有记录
+        };
+
+        let operations_str = request.get("operations").and_then(|v| v.as_str())
+            .ok_or("缺少更新操作")?;
+        let operations_value: serde_json::Value = serde_json::from_str(operations_str)
+            .map_err(|e| format!("解析更新操作失败: {}", e))?;
+        let mut operations = Vec::new();
+        for item in operations_value.as_array().ok_or("更新操作必须是数组")? {
+            let field = item["field"].as_str().ok_or("更新操作缺少字段名")?;
+            let operator: crate::types::UpdateOperator = serde_json::from_value(item["operation"].clone())
+                .map_err(|e| format!("解析更新操作符失败: {}", e))?;
+            let value = match self.parse_labeled_data_value(item["value"].clone()) {
+                Ok(datavalue) => datavalue,
+                Err(_) => self.json_value_to_data_value(item["value"].clone()),
+            };
+            operations.push(crate::types::UpdateOperation {
+                field: field.to_string(),
+                operation: operator,
+                value,
+            });
+        }
+
+        // 通过ODM层执行更新操作
+        use crate::odm::get_odm_manager;
+        let odm_manager = get_odm_manager().await;
+        let result = odm_manager.update_with_operations(table, conditions.into_iter().map(Into::into).collect(), operations, alias).await
+            .map_err(|e| format!("ODM操作数组更新失败: {}", e))?;
+
+        info!("ODM操作数组更新记录成功: {} - {} 条记录", table, result);
+
+        Ok(serde_json::json!({
+            "success": true,
+            "data": result
+        }).to_string())
+    }
+
     /// 使用ODM层处理删除操作
     async fn handle_delete_odm(&self, data: &str) -> Result<String, String> {
         let request: serde_json::Value = serde_json::from_str(data)
@@ -314,6 +469,12 @@ impl SimpleQueueBridge {
             vec![] // 空条件表示删除所有记录
         };
 
+        let conditions = conditions.into_iter().map(Into::into).collect();
+        if let Some(transaction_id) = Self::transaction_id(&request) {
+            let affected = transactions::delete(transaction_id, table, conditions).await?;
+            return Ok(Self::affected_response(affected));
+        }
+
         // 通过ODM层执行删除操作
         use crate::odm::get_odm_manager;
         let odm_manager = get_odm_manager().await;
@@ -350,7 +511,7 @@ impl SimpleQueueBridge {
         // 通过ODM层执行计数操作
         use crate::odm::get_odm_manager;
         let odm_manager = get_odm_manager().await;
-        let result = odm_manager.count(table, conditions, alias).await
+        let result = odm_manager.count(table, conditions.into_iter().map(Into::into).collect(), alias).await
             .map_err(|e| format!("ODM计数操作失败: {}", e))?;
 
         info!("ODM计数记录成功: {} - {} 条记录", table, result);
@@ -362,6 +523,126 @@ impl SimpleQueueBridge {
         }).to_string())
     }
 
+    /// 使用ODM层处理按条件检查记录是否存在操作
+    async fn handle_exists_odm(&self, data: &str) -> Result<String, String> {
+        let request: serde_json::Value = serde_json::from_str(data)
+            .map_err(|e| format!("解析存在性检查请求失败: {}", e))?;
+
+        let table = request["table"].as_str()
+            .ok_or("缺少表名")?;
+        let alias = request.get("alias").and_then(|v| v.as_str());
+
+        // 解析条件
+        let conditions = if let Some(conditions_str) = request.get("conditions").and_then(|v| v.as_str()) {
+            let conditions_value: serde_json::Value = serde_json::from_str(conditions_str)
+                .map_err(|e| format!("解析存在性检查条件失败: {}", e))?;
+            self.parse_query_conditions(conditions_value)?
+        } else {
+            vec![] // 空条件表示表中有任意记录即存在
+        };
+
+        // 只取一条记录，避免统计全表
+        let options = crate::types::QueryOptions::new()
+            .with_pagination(crate::types::PaginationConfig { skip: 0, limit: 1 });
+
+        use crate::odm::get_odm_manager;
+        let odm_manager = get_odm_manager().await;
+        let result = odm_manager
+            .find(table, conditions.into_iter().map(Into::into).collect(), Some(options), alias)
+            .await
+            .map_err(|e| format!("ODM存在性检查失败: {}", e))?;
+
+        let exists = !result.is_empty();
+        info!("ODM存在性检查: {} - {}", table, exists);
+        Ok(serde_json::json!({
+            "success": true,
+            "data": exists
+        }).to_string())
+    }
+
+    /// 使用ODM层处理条件组合查询操作
+    ///
+    /// `condition_groups` 为条件组数组，每项可以是单个条件
+    /// （`{"field", "operator", "value"}`），也可以是嵌套组合
+    /// （`{"operator": "and" | "or", "conditions": [...]}`），数组顶层按 AND 组合
+    async fn handle_find_with_groups_odm(&self, data: &str) -> Result<String, String> {
+        let request: serde_json::Value = serde_json::from_str(data)
+            .map_err(|e| format!("解析条件组合查询请求失败: {}", e))?;
+
+        let table = request["table"].as_str()
+            .ok_or("缺少表名")?;
+        let alias = request.get("alias").and_then(|v| v.as_str());
+
+        let groups = if let Some(groups_str) = request.get("condition_groups").and_then(|v| v.as_str()) {
+            let groups_value: serde_json::Value = serde_json::from_str(groups_str)
+                .map_err(|e| format!("解析条件组合失败: {}", e))?;
+            match groups_value {
+                serde_json::Value::Array(items) => items
+                    .into_iter()
+                    .map(|item| self.parse_condition_group(item))
+                    .collect::<Result<Vec<_>, _>>()?,
+                other => vec![self.parse_condition_group(other)?],
+            }
+        } else {
+            vec![] // 空条件表示查询所有
+        };
+
+        use crate::odm::get_odm_manager;
+        let odm_manager = get_odm_manager().await;
+        let options = match request.get("options") {
+            Some(serde_json::Value::Null) | None => None,
+            Some(options_value) => Some(self.parse_query_options(options_value.clone())?),
+        };
+        let result = odm_manager.find_with_groups(table, groups, options, alias).await
+            .map_err(|e| format!("ODM条件组合查询失败: {}", e))?;
+
+        info!("ODM条件组合查询成功: {} - {} 条记录", table, result.len());
+        Ok(serde_json::json!({
+            "success": true,
+            "data": result
+        }).to_string())
+    }
+
+    /// 使用ODM层处理根据ID检查记录是否存在操作
+    async fn handle_exists_by_id_odm(&self, data: &str) -> Result<String, String> {
+        let request: serde_json::Value = serde_json::from_str(data)
+            .map_err(|e| format!("解析ID存在性检查请求失败: {}", e))?;
+
+        let table = request["table"].as_str()
+            .ok_or("缺少表名")?;
+
+        // 解析ID - 与 find_by_id 相同，支持字符串、DataValue格式与整数
+        let id_str = if let Some(id_str) = request["id"].as_str() {
+            id_str.to_string()
+        } else if let Some(id_obj) = request["id"].as_object() {
+            if let Some(s) = id_obj.get("String").and_then(|v| v.as_str()) {
+                s.to_string()
+            } else if let Some(i) = id_obj.get("Int").and_then(|v| v.as_i64()) {
+                i.to_string()
+            } else {
+                return Err("ID格式不支持，必须是String或Int类型".to_string());
+            }
+        } else if let Some(i) = request["id"].as_i64() {
+            i.to_string()
+        } else {
+            return Err("缺少记录ID或ID格式不正确".to_string());
+        };
+
+        let alias = request.get("alias").and_then(|v| v.as_str());
+
+        // 通过ODM层执行存在性检查，不读取整行数据
+        use crate::odm::get_odm_manager;
+        let odm_manager = get_odm_manager().await;
+        let exists = odm_manager.exists_by_id(table, &id_str, alias).await
+            .map_err(|e| format!("ODM ID存在性检查失败: {}", e))?;
+
+        info!("ODM ID存在性检查: {} - {} - {}", table, id_str, exists);
+        Ok(serde_json::json!({
+            "success": true,
+            "data": exists
+        }).to_string())
+    }
+
     /// 使用ODM层处理根据ID查询操作
     async fn handle_find_by_id_odm(&self, data: &str) -> Result<String, String> {
         let request: serde_json::Value = serde_json::from_str(data)
@@ -517,12 +798,17 @@ impl SimpleQueueBridge {
 
         info!("处理模型注册请求: {}", data);
 
-        // 解析模型元数据
-        if let Some(model_meta_value) = request.get("model_meta") {
-            let model_meta: ModelMeta = serde_json::from_value(model_meta_value.clone())
-                .map_err(|e| format!("解析模型元数据失败: {}", e))?;
-
-            let collection_name = model_meta.collection_name.clone();
+        // 解析模型元数据：完整的 ModelMeta，或 Python dict/dataclass 生成的简化定义
+        let model_meta = match (request.get("model"), request.get("model_meta")) {
+            (Some(model), _) => Some(self.parse_model_definition(model)?),
+            (None, Some(model_meta_value)) => Some(
+                serde_json::from_value::<ModelMeta>(model_meta_value.clone())
+                    .map_err(|e| format!("解析模型元数据失败: {}", e))?,
+            ),
+            (None, None) => None,
+        };
+        if let Some(model_meta) = model_meta {
+            let collection_name = model_meta.qualified_name();
             let database_alias = model_meta.database_alias.clone()
                 .ok_or("模型元数据缺少数据库别名")?;
 
@@ -546,6 +832,139 @@ impl SimpleQueueBridge {
         }
     }
 
+    /// 解析 Python 侧的简化模型定义
+    ///
+    /// ```json
+    /// {
+    ///   "collection": "users",
+    ///   "alias": "default",
+    ///   "fields": {
+    ///     "name": {"type": "string", "max_length": 64, "required": true, "unique": true},
+    ///     "tags": {"type": "array", "item_type": {"type": "string"}}
+    ///   },
+    ///   "indexes": [{"fields": ["name", "age"], "unique": false, "name": "idx_name_age"}]
+    /// }
+    /// ```
+    fn parse_model_definition(&self, value: &serde_json::Value) -> Result<ModelMeta, String> {
+        let collection_name = value.get("collection").and_then(|v| v.as_str())
+            .ok_or("模型定义缺少collection字段")?.to_string();
+        let database_alias = value.get("alias").and_then(|v| v.as_str()).map(str::to_string);
+
+        let fields_value = value.get("fields").and_then(|v| v.as_object())
+            .ok_or("模型定义缺少fields对象")?;
+        let mut fields = HashMap::with_capacity(fields_value.len());
+        for (name, field_value) in fields_value {
+            let field_type = self.parse_field_type(field_value)
+                .map_err(|e| format!("字段{}定义无效: {}", name, e))?;
+            let mut field = crate::model::FieldDefinition::new(field_type);
+            let flag = |key: &str| field_value.get(key).and_then(|v| v.as_bool()).unwrap_or(false);
+            if flag("required") {
+                field = field.required();
+            }
+            if flag("unique") {
+                field = field.unique();
+            }
+            if flag("indexed") {
+                field = field.indexed();
+            }
+            if let Some(description) = field_value.get("description").and_then(|v| v.as_str()) {
+                field = field.description(description);
+            }
+            if let Some(default) = field_value.get("default") {
+                field = field.with_default(self.json_value_to_data_value(default.clone()));
+            }
+            fields.insert(name.clone(), field);
+        }
+
+        let mut indexes = Vec::new();
+        for index_value in value.get("indexes").and_then(|v| v.as_array()).into_iter().flatten() {
+            let index_fields: Vec<String> = index_value.get("fields").and_then(|v| v.as_array())
+                .ok_or("索引定义缺少fields数组")?
+                .iter()
+                .filter_map(|v| v.as_str().map(str::to_string))
+                .collect();
+            if let Some(missing) = index_fields.iter().find(|field| !fields.contains_key(*field)) {
+                return Err(format!("索引引用了未定义的字段: {}", missing));
+            }
+            indexes.push(crate::model::IndexDefinition {
+                fields: index_fields,
+                unique: index_value.get("unique").and_then(|v| v.as_bool()).unwrap_or(false),
+                name: index_value.get("name").and_then(|v| v.as_str()).map(str::to_string),
+                options: Default::default(),
+            });
+        }
+
+        Ok(ModelMeta {
+            collection_name,
+            database_alias,
+            fields,
+            indexes,
+            description: value.get("description").and_then(|v| v.as_str()).map(str::to_string),
+            version: None,
+            primary_key: vec![],
+            expires_after: None,
+            rename_all: None,
+            field_names: HashMap::new(),
+            discriminator: None,
+            validation_rules: Vec::new(),
+            cache_policy: None,
+            audit: false,
+            schema: None,
+            shard_key: None,
+            scopes: Default::default(),
+        })
+    }
+
+    /// 解析简化模型定义中的字段类型
+    fn parse_field_type(&self, value: &serde_json::Value) -> Result<crate::model::FieldType, String> {
+        use crate::model::FieldType;
+
+        let type_name = value.get("type").and_then(|v| v.as_str()).ok_or("缺少type")?;
+        let usize_of = |key: &str| value.get(key).and_then(|v| v.as_u64()).map(|v| v as usize);
+        let field_type = match type_name {
+            "string" => FieldType::String {
+                max_length: usize_of("max_length"),
+                min_length: usize_of("min_length"),
+                regex: value.get("regex").and_then(|v| v.as_str()).map(str::to_string),
+            },
+            "text" => FieldType::Text,
+            "integer" => FieldType::Integer {
+                min_value: value.get("min_value").and_then(|v| v.as_i64()),
+                max_value: value.get("max_value").and_then(|v| v.as_i64()),
+            },
+            "bigint" => FieldType::BigInteger,
+            "float" => FieldType::Float {
+                min_value: value.get("min_value").and_then(|v| v.as_f64()),
+                max_value: value.get("max_value").and_then(|v| v.as_f64()),
+            },
+            "double" => FieldType::Double,
+            "boolean" => FieldType::Boolean,
+            "datetime" => FieldType::DateTime,
+            "date" => FieldType::Date,
+            "time" => FieldType::Time,
+            "uuid" => FieldType::Uuid,
+            "json" => FieldType::Json,
+            "binary" => FieldType::Binary,
+            "array" => FieldType::Array {
+                item_type: Box::new(match value.get("item_type") {
+                    Some(item_type) => self.parse_field_type(item_type)?,
+                    None => FieldType::Json,
+                }),
+                max_items: usize_of("max_items"),
+                min_items: usize_of("min_items"),
+            },
+            "reference" => FieldType::Reference {
+                target_collection: value.get("target_collection").and_then(|v| v.as_str())
+                    .ok_or("reference字段缺少target_collection")?.to_string(),
+            },
+            "vector" => FieldType::Vector {
+                dimension: usize_of("dimension").ok_or("vector字段缺少dimension")?,
+            },
+            other => return Err(format!("不支持的字段类型: {}", other)),
+        };
+        Ok(field_type)
+    }
+
     /// 使用ODM层处理表创建操作
     async fn handle_create_table_odm(&self, data: &str) -> Result<String, String> {
         let request: serde_json::Value = serde_json::from_str(data)
@@ -592,6 +1011,135 @@ impl SimpleQueueBridge {
         }).to_string())
     }
 
+    /// 处理开启事务请求，返回事务ID
+    async fn handle_begin_transaction(&self, data: &str) -> Result<String, String> {
+        let request: serde_json::Value = serde_json::from_str(data)
+            .map_err(|e| format!("解析开启事务请求失败: {}", e))?;
+        let alias = request.get("alias").and_then(|v| v.as_str());
+
+        let transaction_id = transactions::begin(alias).await?;
+        info!("开启事务: {}", transaction_id);
+
+        Ok(serde_json::json!({
+            "success": true,
+            "data": { "transaction_id": transaction_id }
+        }).to_string())
+    }
+
+    /// 处理提交或回滚事务请求
+    async fn handle_finish_transaction(&self, data: &str, commit: bool) -> Result<String, String> {
+        let request: serde_json::Value = serde_json::from_str(data)
+            .map_err(|e| format!("解析结束事务请求失败: {}", e))?;
+        let transaction_id = Self::transaction_id(&request).ok_or("缺少transaction_id字段")?;
+
+        transactions::finish(transaction_id, commit).await?;
+        info!("{}事务: {}", if commit { "提交" } else { "回滚" }, transaction_id);
+
+        Ok(serde_json::json!({
+            "success": true,
+            "data": { "transaction_id": transaction_id }
+        }).to_string())
+    }
+
+    fn transaction_id(request: &serde_json::Value) -> Option<&str> {
+        request.get("transaction_id").and_then(|v| v.as_str())
+    }
+
+    /// 事务内写入的响应（受影响行数）
+    fn affected_response(affected: u64) -> String {
+        serde_json::json!({
+            "success": true,
+            "data": affected
+        }).to_string()
+    }
+
+    /// 处理连接池状态查询（指定 alias 时返回单个连接池状态，否则返回全部）
+    async fn handle_pool_status(&self, data: &str) -> Result<String, String> {
+        let request: serde_json::Value = if data.trim().is_empty() {
+            serde_json::Value::Null
+        } else {
+            serde_json::from_str(data)
+                .map_err(|e| format!("解析连接池状态请求失败: {}", e))?
+        };
+
+        let result = match request.get("alias").and_then(|v| v.as_str()) {
+            Some(alias) => {
+                let status = crate::manager::get_pool_status(alias)
+                    .map_err(|e| format!("获取连接池状态失败: {}", e))?;
+                serde_json::to_value(status)
+            }
+            None => serde_json::to_value(crate::manager::get_pools_status()),
+        }
+        .map_err(|e| format!("序列化连接池状态失败: {}", e))?;
+
+        Ok(serde_json::json!({
+            "success": true,
+            "data": result
+        }).to_string())
+    }
+
+    /// 处理缓存统计查询
+    async fn handle_cache_stats(&self, data: &str) -> Result<String, String> {
+        let request: serde_json::Value = serde_json::from_str(data)
+            .map_err(|e| format!("解析缓存统计请求失败: {}", e))?;
+        let alias = request
+            .get("alias")
+            .and_then(|v| v.as_str())
+            .ok_or("缺少alias字段")?;
+
+        let stats = crate::manager::get_cache_stats(alias)
+            .await
+            .map_err(|e| format!("获取缓存统计失败: {}", e))?;
+        let result = serde_json::to_value(stats)
+            .map_err(|e| format!("序列化缓存统计失败: {}", e))?;
+
+        Ok(serde_json::json!({
+            "success": true,
+            "data": result
+        }).to_string())
+    }
+
+    /// 处理清空缓存请求（指定 alias 时只清空该别名的缓存，否则清空全部）
+    async fn handle_clear_cache(&self, data: &str) -> Result<String, String> {
+        let request: serde_json::Value = if data.trim().is_empty() {
+            serde_json::Value::Null
+        } else {
+            serde_json::from_str(data)
+                .map_err(|e| format!("解析清空缓存请求失败: {}", e))?
+        };
+
+        match request.get("alias").and_then(|v| v.as_str()) {
+            Some(alias) => crate::manager::clear_cache(alias).await,
+            None => crate::manager::clear_all_caches().await,
+        }
+        .map_err(|e| format!("清空缓存失败: {}", e))?;
+
+        Ok(serde_json::json!({
+            "success": true,
+            "message": "缓存已清空"
+        }).to_string())
+    }
+
+    /// 处理按表划分的缓存统计查询
+    async fn handle_cache_stats_by_table(&self, data: &str) -> Result<String, String> {
+        let request: serde_json::Value = serde_json::from_str(data)
+            .map_err(|e| format!("解析缓存统计请求失败: {}", e))?;
+        let alias = request
+            .get("alias")
+            .and_then(|v| v.as_str())
+            .ok_or("缺少alias字段")?;
+
+        let stats = crate::manager::get_cache_stats_by_table(alias)
+            .map_err(|e| format!("获取缓存统计失败: {}", e))?;
+        let result = serde_json::to_value(stats)
+            .map_err(|e| format!("序列化缓存统计失败: {}", e))?;
+
+        Ok(serde_json::json!({
+            "success": true,
+            "data": result
+        }).to_string())
+    }
+
         /// 解析查询条件
     fn parse_query_conditions(&self, conditions_value: serde_json::Value) -> Result<Vec<crate::types::QueryCondition>, String> {
         match conditions_value {
@@ -621,6 +1169,8 @@ impl SimpleQueueBridge {
                             "not_in" => QueryOperator::NotIn,
                             "is_null" => QueryOperator::IsNull,
                             "is_not_null" => QueryOperator::IsNotNull,
+                            "between" => QueryOperator::Between,
+                            "not_between" => QueryOperator::NotBetween,
                             _ => return Err(format!("不支持的操作符: {}", operator_str)),
                         };
 
@@ -636,14 +1186,186 @@ impl SimpleQueueBridge {
                 }
                 Ok(conditions)
             },
-            serde_json::Value::Object(_) => {
+            serde_json::Value::Object(ref obj) if obj.contains_key("field") => {
                 // 单个条件对象
                 self.parse_query_conditions(serde_json::Value::Array(vec![conditions_value]))
             },
+            serde_json::Value::Object(obj) => self.parse_mongo_conditions(obj),
             _ => Err("条件必须是数组或对象格式".to_string()),
         }
     }
 
+    /// 解析 Mongo 风格的条件字典
+    ///
+    /// `{"age": {"$gte": 18}, "status": {"$in": ["a", "b"]}, "name": "x"}`，
+    /// 字段值不是操作符对象时按相等匹配，多个字段与操作符之间按 AND 组合
+    fn parse_mongo_conditions(&self, obj: serde_json::Map<String, serde_json::Value>) -> Result<Vec<QueryCondition>, String> {
+        let mut conditions = Vec::new();
+        for (field, value) in obj {
+            let operators = match value {
+                serde_json::Value::Object(ops) if !ops.is_empty() && ops.keys().all(|k| k.starts_with('$')) => ops,
+                value => {
+                    conditions.push(QueryCondition {
+                        field,
+                        operator: QueryOperator::Eq,
+                        value: self.json_value_to_data_value(value),
+                    });
+                    continue;
+                }
+            };
+
+            for (operator_str, operand) in operators {
+                let operator = match operator_str.as_str() {
+                    "$eq" => QueryOperator::Eq,
+                    "$ne" => QueryOperator::Ne,
+                    "$gt" => QueryOperator::Gt,
+                    "$gte" => QueryOperator::Gte,
+                    "$lt" => QueryOperator::Lt,
+                    "$lte" => QueryOperator::Lte,
+                    "$in" => QueryOperator::In,
+                    "$nin" => QueryOperator::NotIn,
+                    "$regex" => QueryOperator::Regex,
+                    "$contains" => QueryOperator::Contains,
+                    "$startsWith" => QueryOperator::StartsWith,
+                    "$endsWith" => QueryOperator::EndsWith,
+                    "$between" => QueryOperator::Between,
+                    "$exists" => QueryOperator::Exists,
+                    _ => return Err(format!("不支持的操作符: {}", operator_str)),
+                };
+                if matches!(operator, QueryOperator::In | QueryOperator::NotIn | QueryOperator::Between)
+                    && !operand.is_array()
+                {
+                    return Err(format!("操作符{}的值必须是数组: {}", operator_str, field));
+                }
+                conditions.push(QueryCondition {
+                    field: field.clone(),
+                    operator,
+                    value: self.json_value_to_data_value(operand),
+                });
+            }
+        }
+        Ok(conditions)
+    }
+
+    /// 解析查询选项：排序、分页与字段投影
+    ///
+    /// ```json
+    /// {"sort": {"age": -1, "name": "asc"}, "skip": 20, "limit": 10, "fields": ["id", "name"]}
+    /// ```
+    ///
+    /// `sort` 也接受 `[{"field": "age", "direction": "desc"}]`，分页也可写为
+    /// `{"pagination": {"skip", "limit"}}`，`projection` 可代替 `fields` 并接受
+    /// `{"name": 1}` 形式
+    fn parse_query_options(&self, value: serde_json::Value) -> Result<crate::types::QueryOptions, String> {
+        use crate::types::{PaginationConfig, QueryOptions, SortConfig, SortDirection};
+
+        let serde_json::Value::Object(obj) = value else {
+            return Err("查询选项必须是对象格式".to_string());
+        };
+        let mut options = QueryOptions::new();
+
+        let parse_direction = |field: &str, direction: &serde_json::Value| match direction {
+            serde_json::Value::Number(n) if n.as_i64() == Some(1) => Ok(SortDirection::Asc),
+            serde_json::Value::Number(n) if n.as_i64() == Some(-1) => Ok(SortDirection::Desc),
+            serde_json::Value::String(s) if s.eq_ignore_ascii_case("asc") => Ok(SortDirection::Asc),
+            serde_json::Value::String(s) if s.eq_ignore_ascii_case("desc") => Ok(SortDirection::Desc),
+            _ => Err(format!("不支持的排序方向: {} - {}", field, direction)),
+        };
+        match obj.get("sort") {
+            Some(serde_json::Value::Object(sort)) => {
+                let sort = sort
+                    .iter()
+                    .map(|(field, direction)| {
+                        Ok(SortConfig { field: field.clone(), direction: parse_direction(field, direction)? })
+                    })
+                    .collect::<Result<Vec<_>, String>>()?;
+                options = options.with_sort(sort);
+            }
+            Some(serde_json::Value::Array(sort)) => {
+                let sort = sort
+                    .iter()
+                    .map(|item| {
+                        let field = item.get("field").and_then(|v| v.as_str()).ok_or("排序配置缺少field字段")?;
+                        let direction = item.get("direction").cloned().unwrap_or(serde_json::json!("asc"));
+                        Ok(SortConfig { field: field.to_string(), direction: parse_direction(field, &direction)? })
+                    })
+                    .collect::<Result<Vec<_>, String>>()?;
+                options = options.with_sort(sort);
+            }
+            Some(_) => return Err("sort必须是对象或数组格式".to_string()),
+            None => {}
+        }
+
+        let pagination = obj.get("pagination").unwrap_or(&serde_json::Value::Null);
+        let skip = obj.get("skip").or_else(|| pagination.get("skip")).and_then(|v| v.as_u64());
+        let limit = obj.get("limit").or_else(|| pagination.get("limit")).and_then(|v| v.as_u64());
+        if skip.is_some() || limit.is_some() {
+            options = options.with_pagination(PaginationConfig {
+                skip: skip.unwrap_or(0),
+                limit: limit.unwrap_or(u64::MAX),
+            });
+        }
+
+        match obj.get("fields").or_else(|| obj.get("projection")) {
+            Some(serde_json::Value::Array(fields)) => {
+                let fields = fields.iter().filter_map(|v| v.as_str().map(str::to_string)).collect();
+                options = options.with_fields(fields);
+            }
+            Some(serde_json::Value::Object(projection)) => {
+                let fields = projection
+                    .iter()
+                    .filter(|(_, include)| include.as_i64() == Some(1) || include.as_bool() == Some(true))
+                    .map(|(field, _)| field.clone())
+                    .collect();
+                options = options.with_fields(fields);
+            }
+            Some(_) => return Err("fields必须是数组或对象格式".to_string()),
+            None => {}
+        }
+
+        match obj.get("scopes").or_else(|| obj.get("scope")) {
+            Some(serde_json::Value::String(scope)) => options = options.with_scope(scope.clone()),
+            Some(serde_json::Value::Array(scopes)) => {
+                for scope in scopes {
+                    let scope = scope.as_str().ok_or("scopes必须是字符串数组")?;
+                    options = options.with_scope(scope);
+                }
+            }
+            Some(_) => return Err("scopes必须是字符串或字符串数组".to_string()),
+            None => {}
+        }
+        if obj.get("unscoped").and_then(|v| v.as_bool()) == Some(true) {
+            options = options.unscoped();
+        }
+
+        Ok(options)
+    }
+
+    /// 解析单个条件组（单个条件或嵌套的 AND/OR 组合）
+    fn parse_condition_group(&self, value: serde_json::Value) -> Result<crate::types::QueryConditionGroup, String> {
+        let Some(conditions) = value.get("conditions") else {
+            let mut single = self.parse_query_conditions(value)?;
+            return single.pop()
+                .map(crate::types::QueryConditionGroup::Single)
+                .ok_or_else(|| "条件组不能为空".to_string());
+        };
+
+        let operator = match value.get("operator").and_then(|v| v.as_str()).unwrap_or("and") {
+            "and" => crate::types::LogicalOperator::And,
+            "or" => crate::types::LogicalOperator::Or,
+            other => return Err(format!("不支持的逻辑操作符: {}", other)),
+        };
+        let serde_json::Value::Array(items) = conditions else {
+            return Err("条件组的conditions必须是数组".to_string());
+        };
+        let conditions = items
+            .iter()
+            .map(|item| self.parse_condition_group(item.clone()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(crate::types::QueryConditionGroup::Group { operator, conditions })
+    }
+
     /// 获取数据库特定的JSON处理器
     /// 解析带标签的DataValue格式
     fn parse_labeled_data_value(&self, value: serde_json::Value) -> Result<DataValue, String> {
@@ -651,7 +1373,7 @@ impl SimpleQueueBridge {
             serde_json::Value::Object(obj) => {
                 if obj.len() == 1 {
                     // 带标签的DataValue格式
-                    for (tag, val) in &obj {
+                    if let Some((tag, val)) = obj.iter().next() {
                         return match tag.as_str() {
                             "String" => Ok(DataValue::String(val.as_str().unwrap_or_default().to_string())),
                             "Int" => {
@@ -679,7 +1401,7 @@ impl SimpleQueueBridge {
                                 if let Some(dt_str) = val.as_str() {
                                     // 解析ISO 8601格式的datetime字符串
                                     match chrono::DateTime::parse_from_rfc3339(dt_str) {
-                                        Ok(dt) => Ok(DataValue::DateTime(dt.with_timezone(&chrono::Utc))),
+                                        Ok(dt) => Ok(DataValue::DateTime(dt)),
                                         Err(e) => Err(format!("DateTime字段包含无效的ISO格式: {} - {}", dt_str, e))
                                     }
                                 } else {
@@ -729,20 +1451,6 @@ impl SimpleQueueBridge {
         }
     }
 
-    fn get_database_processor(&self, db_alias: Option<&str>) -> Result<Box<dyn super::database_processors::DatabaseJsonProcessor>, String> {
-        use super::database_processors::create_database_json_processor;
-
-        if let Some(alias) = db_alias {
-            // 获取数据库类型
-            let db_type = crate::manager::get_global_pool_manager().get_database_type(alias)
-                .map_err(|e| format!("无法获取数据库'{}'的类型: {}, 请检查数据库配置是否正确", alias, e))?;
-
-                        Ok(create_database_json_processor(&db_type))
-        } else {
-            Err("未指定数据库别名，无法获取数据库处理器".to_string())
-        }
-    }
-
     fn json_value_to_data_value(&self, value: serde_json::Value) -> DataValue {
         match value {
             serde_json::Value::Null => DataValue::Null,
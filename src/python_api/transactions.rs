@@ -0,0 +1,145 @@
+//! Python 桥接的事务注册表
+//!
+//! `begin_transaction` 请求通过 [`crate::manager::begin_transaction`] 租用连接并开启事务，
+//! 连接以事务ID登记在注册表中；之后携带 `transaction_id` 的 create / update / delete 请求
+//! 在同一连接上执行，直到 `commit_transaction` 或 `rollback_transaction` 结束事务并归还连接。
+//! 写入前与 ODM 批量写入一样执行模型校验器，插入的数据按ID策略分配ID并补齐字段默认值。
+//! 仅支持 SQLite、PostgreSQL 与 MySQL
+
+use crate::types::{DataValue, QueryConditionWithConfig};
+use std::collections::HashMap;
+
+#[cfg(any(feature = "sqlite-support", feature = "postgres-support", feature = "mysql-support"))]
+mod registry {
+    use super::*;
+    use crate::manager::LeasedConnection;
+    use dashmap::DashMap;
+    use std::sync::{Arc, OnceLock};
+    use tokio::sync::Mutex;
+
+    /// 所有桥接器共享的未结束事务
+    static TRANSACTIONS: OnceLock<DashMap<String, Arc<Mutex<LeasedConnection>>>> = OnceLock::new();
+
+    fn transactions() -> &'static DashMap<String, Arc<Mutex<LeasedConnection>>> {
+        TRANSACTIONS.get_or_init(DashMap::new)
+    }
+
+    fn transaction(transaction_id: &str) -> Result<Arc<Mutex<LeasedConnection>>, String> {
+        transactions()
+            .get(transaction_id)
+            .map(|entry| entry.value().clone())
+            .ok_or_else(|| format!("事务不存在或已结束: {}", transaction_id))
+    }
+
+    pub(crate) async fn begin(alias: Option<&str>) -> Result<String, String> {
+        let lease = crate::manager::begin_transaction(alias)
+            .await
+            .map_err(|e| format!("开启事务失败: {}", e))?;
+        let transaction_id = uuid::Uuid::new_v4().to_string();
+        transactions().insert(transaction_id.clone(), Arc::new(Mutex::new(lease)));
+        Ok(transaction_id)
+    }
+
+    /// 执行模型校验器，按ID策略分配ID并补齐字段默认值
+    async fn prepare_insert(
+        lease: &LeasedConnection,
+        table: &str,
+        data: &mut HashMap<String, DataValue>,
+    ) -> crate::error::QuickDbResult<()> {
+        let (alias, db_type) = (lease.alias(), lease.db_type());
+        crate::model::validators::validate_registered(table, alias, data).await?;
+        crate::odm::AsyncOdmManager::assign_create_id(alias, &db_type, data).await?;
+        match crate::manager::get_model_with_alias(table, alias) {
+            Some(meta) => crate::model::defaults::apply_defaults(&meta.fields, db_type, data),
+            None => Ok(()),
+        }
+    }
+
+    pub(crate) async fn create(transaction_id: &str, table: &str, mut data: HashMap<String, DataValue>) -> Result<u64, String> {
+        let transaction = transaction(transaction_id)?;
+        let mut lease = transaction.lock().await;
+        let affected = match prepare_insert(&lease, table, &mut data).await {
+            Ok(()) => lease.create(table, data).await,
+            Err(e) => Err(e),
+        };
+        affected.map_err(|e| format!("事务内写入失败: {}", e))
+    }
+
+    pub(crate) async fn update(
+        transaction_id: &str,
+        table: &str,
+        conditions: Vec<QueryConditionWithConfig>,
+        data: HashMap<String, DataValue>,
+    ) -> Result<u64, String> {
+        let transaction = transaction(transaction_id)?;
+        let mut lease = transaction.lock().await;
+        let affected = match crate::model::validators::validate_registered(table, lease.alias(), &data).await {
+            Ok(()) => lease.update(table, conditions, data).await,
+            Err(e) => Err(e),
+        };
+        affected.map_err(|e| format!("事务内写入失败: {}", e))
+    }
+
+    pub(crate) async fn delete(
+        transaction_id: &str,
+        table: &str,
+        conditions: Vec<QueryConditionWithConfig>,
+    ) -> Result<u64, String> {
+        let transaction = transaction(transaction_id)?;
+        let affected = transaction.lock().await.delete(table, conditions).await;
+        affected.map_err(|e| format!("事务内写入失败: {}", e))
+    }
+
+    /// 结束事务，连接在此之后归还连接池（提交失败时连接被关闭，事务由数据库回滚）
+    pub(crate) async fn finish(transaction_id: &str, commit: bool) -> Result<(), String> {
+        let (_, transaction) = transactions()
+            .remove(transaction_id)
+            .ok_or_else(|| format!("事务不存在或已结束: {}", transaction_id))?;
+        let mut lease = transaction.lock().await;
+        if commit { lease.commit().await } else { lease.rollback().await }
+            .map_err(|e| format!("结束事务失败: {}", e))
+    }
+}
+
+#[cfg(not(any(feature = "sqlite-support", feature = "postgres-support", feature = "mysql-support")))]
+mod registry {
+    use super::*;
+
+    fn unsupported() -> String {
+        crate::error::QuickDbError::TransactionError {
+            message: "当前构建未启用支持事务的数据库".to_string(),
+        }
+        .to_string()
+    }
+
+    pub(crate) async fn begin(_alias: Option<&str>) -> Result<String, String> {
+        Err(unsupported())
+    }
+
+    pub(crate) async fn create(_transaction_id: &str, _table: &str, _data: HashMap<String, DataValue>) -> Result<u64, String> {
+        Err(unsupported())
+    }
+
+    pub(crate) async fn update(
+        _transaction_id: &str,
+        _table: &str,
+        _conditions: Vec<QueryConditionWithConfig>,
+        _data: HashMap<String, DataValue>,
+    ) -> Result<u64, String> {
+        Err(unsupported())
+    }
+
+    pub(crate) async fn delete(
+        _transaction_id: &str,
+        _table: &str,
+        _conditions: Vec<QueryConditionWithConfig>,
+    ) -> Result<u64, String> {
+        Err(unsupported())
+    }
+
+    pub(crate) async fn finish(_transaction_id: &str, _commit: bool) -> Result<(), String> {
+        Err(unsupported())
+    }
+}
+
+pub(crate) use registry::{begin, create, delete, finish, update};
@@ -0,0 +1,188 @@
+//! 条件组构建宏定义
+//! 用于简化嵌套 AND/OR 条件组的构建
+//!
+//! `qand!` / `qor!` 生成 `QueryConditionGroup`，可以相互嵌套，
+//! 无需手写多层 `QueryConditionGroup::Group { .. }` 枚举字面量
+//!
+//! # 语法
+//! ```rust,ignore
+//! let groups = vec![qor!(
+//!     qand!("age" >= 18, "status" == "active"),
+//!     "role" in ["admin", "owner"],
+//!     "deleted_at" is_null,
+//! )];
+//! ```
+//!
+//! 支持的条件写法：
+//! - 比较：`==` `!=` `>` `>=` `<` `<=`
+//! - 字符串与 JSON：`contains` `starts_with` `ends_with` `regex` `json_contains`
+//! - 列表：`in [..]` `not_in [..]`
+//! - 范围：`between [下界, 上界]` `not_between [下界, 上界]`
+//! - 一元：`is_null` `is_not_null` `exists`
+//! - 嵌套：`qand!(..)` `qor!(..)`
+
+/// 构建 AND 条件组
+#[macro_export]
+macro_rules! qand {
+    ($($items:tt)*) => {
+        $crate::__qgroup!(@munch And [] $($items)*)
+    };
+}
+
+/// 构建 OR 条件组
+#[macro_export]
+macro_rules! qor {
+    ($($items:tt)*) => {
+        $crate::__qgroup!(@munch Or [] $($items)*)
+    };
+}
+
+/// `qand!` / `qor!` 的内部实现
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __qgroup {
+    // 结束：生成条件组
+    (@munch $logic:ident [$($acc:expr,)*] $(,)?) => {
+        $crate::types::QueryConditionGroup::Group {
+            operator: $crate::types::LogicalOperator::$logic,
+            conditions: vec![$($acc),*],
+        }
+    };
+
+    // 嵌套条件组
+    (@munch $logic:ident [$($acc:expr,)*] $nested:ident ! $inner:tt $(, $($rest:tt)*)?) => {
+        $crate::__qgroup!(@munch $logic [$($acc,)* $crate::$nested! $inner,] $($($rest)*)?)
+    };
+
+    // 列表条件
+    (@munch $logic:ident [$($acc:expr,)*] $field:literal in [$($value:expr),* $(,)?] $(, $($rest:tt)*)?) => {
+        $crate::__qgroup!(@munch $logic [$($acc,)*
+            $crate::__qgroup!(@list $field, In, $($value),*),] $($($rest)*)?)
+    };
+    (@munch $logic:ident [$($acc:expr,)*] $field:literal not_in [$($value:expr),* $(,)?] $(, $($rest:tt)*)?) => {
+        $crate::__qgroup!(@munch $logic [$($acc,)*
+            $crate::__qgroup!(@list $field, NotIn, $($value),*),] $($($rest)*)?)
+    };
+
+    // 范围条件
+    (@munch $logic:ident [$($acc:expr,)*] $field:literal between [$lower:expr, $upper:expr] $(, $($rest:tt)*)?) => {
+        $crate::__qgroup!(@munch $logic [$($acc,)*
+            $crate::__qgroup!(@list $field, Between, $lower, $upper),] $($($rest)*)?)
+    };
+    (@munch $logic:ident [$($acc:expr,)*] $field:literal not_between [$lower:expr, $upper:expr] $(, $($rest:tt)*)?) => {
+        $crate::__qgroup!(@munch $logic [$($acc,)*
+            $crate::__qgroup!(@list $field, NotBetween, $lower, $upper),] $($($rest)*)?)
+    };
+
+    // 一元条件
+    (@munch $logic:ident [$($acc:expr,)*] $field:literal is_null $(, $($rest:tt)*)?) => {
+        $crate::__qgroup!(@munch $logic [$($acc,)*
+            $crate::__qgroup!(@single $field, IsNull, $crate::types::DataValue::Null),] $($($rest)*)?)
+    };
+    (@munch $logic:ident [$($acc:expr,)*] $field:literal is_not_null $(, $($rest:tt)*)?) => {
+        $crate::__qgroup!(@munch $logic [$($acc,)*
+            $crate::__qgroup!(@single $field, IsNotNull, $crate::types::DataValue::Null),] $($($rest)*)?)
+    };
+    (@munch $logic:ident [$($acc:expr,)*] $field:literal exists $(, $($rest:tt)*)?) => {
+        $crate::__qgroup!(@munch $logic [$($acc,)*
+            $crate::__qgroup!(@single $field, Exists, $crate::types::DataValue::Bool(true)),] $($($rest)*)?)
+    };
+
+    // 二元条件
+    (@munch $logic:ident [$($acc:expr,)*] $field:literal $op:tt $value:expr $(, $($rest:tt)*)?) => {
+        $crate::__qgroup!(@munch $logic [$($acc,)*
+            $crate::__qgroup!(@single $field, $crate::__qgroup!(@op $op),
+                $crate::types::DataValue::from($value)),] $($($rest)*)?)
+    };
+
+    (@single $field:expr, $operator:ident, $value:expr) => {
+        $crate::__qgroup!(@single $field, $crate::types::QueryOperator::$operator, $value)
+    };
+    (@single $field:expr, $operator:expr, $value:expr) => {
+        $crate::types::QueryConditionGroup::Single($crate::types::QueryCondition {
+            field: $field.to_string(),
+            operator: $operator,
+            value: $value,
+        })
+    };
+    (@list $field:expr, $operator:ident, $($value:expr),*) => {
+        $crate::__qgroup!(@single $field, $operator,
+            $crate::types::DataValue::Array(vec![$($crate::types::DataValue::from($value)),*]))
+    };
+
+    (@op ==) => { $crate::types::QueryOperator::Eq };
+    (@op !=) => { $crate::types::QueryOperator::Ne };
+    (@op >) => { $crate::types::QueryOperator::Gt };
+    (@op >=) => { $crate::types::QueryOperator::Gte };
+    (@op <) => { $crate::types::QueryOperator::Lt };
+    (@op <=) => { $crate::types::QueryOperator::Lte };
+    (@op contains) => { $crate::types::QueryOperator::Contains };
+    (@op starts_with) => { $crate::types::QueryOperator::StartsWith };
+    (@op ends_with) => { $crate::types::QueryOperator::EndsWith };
+    (@op regex) => { $crate::types::QueryOperator::Regex };
+    (@op json_contains) => { $crate::types::QueryOperator::JsonContains };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{DataValue, LogicalOperator, QueryConditionGroup, QueryOperator};
+
+    fn single(group: &QueryConditionGroup) -> (&str, &QueryOperator, &DataValue) {
+        match group {
+            QueryConditionGroup::Single(c) => (c.field.as_str(), &c.operator, &c.value),
+            other => panic!("unexpected group: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn builds_nested_or_of_ands() {
+        let group = qor!(
+            qand!("age" >= 18, "status" == "active"),
+            "role" in ["admin", "owner"],
+            "deleted_at" is_null,
+            "name" starts_with "张",
+        );
+
+        let QueryConditionGroup::Group { operator, conditions } = &group else {
+            panic!("unexpected group: {:?}", group);
+        };
+        assert_eq!(*operator, LogicalOperator::Or);
+        assert_eq!(conditions.len(), 4);
+
+        match &conditions[0] {
+            QueryConditionGroup::Group { operator, conditions } => {
+                assert_eq!(*operator, LogicalOperator::And);
+                assert_eq!(single(&conditions[0]), ("age", &QueryOperator::Gte, &DataValue::Int(18)));
+                assert_eq!(
+                    single(&conditions[1]),
+                    ("status", &QueryOperator::Eq, &DataValue::String("active".to_string()))
+                );
+            }
+            other => panic!("unexpected group: {:?}", other),
+        }
+
+        let (field, operator, value) = single(&conditions[1]);
+        assert_eq!((field, operator), ("role", &QueryOperator::In));
+        assert!(matches!(value, DataValue::Array(values) if values.len() == 2));
+        assert_eq!(single(&conditions[2]), ("deleted_at", &QueryOperator::IsNull, &DataValue::Null));
+        assert_eq!(single(&conditions[3]).1, &QueryOperator::StartsWith);
+    }
+
+    #[test]
+    fn accepts_single_condition_and_expressions() {
+        let min_age = 21;
+        let group = qand!("age" > min_age + 1, "score" between [60, 100]);
+        let QueryConditionGroup::Group { conditions, .. } = &group else {
+            panic!("unexpected group: {:?}", group);
+        };
+        assert_eq!(single(&conditions[0]), ("age", &QueryOperator::Gt, &DataValue::Int(22)));
+        assert_eq!(
+            single(&conditions[1]),
+            (
+                "score",
+                &QueryOperator::Between,
+                &DataValue::Array(vec![DataValue::Int(60), DataValue::Int(100)])
+            )
+        );
+    }
+}
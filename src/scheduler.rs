@@ -0,0 +1,306 @@
+//! # 分布式调度模块
+//!
+//! 多实例部署时，各节点以同一调度器名竞争 [`crate::lock`] 咨询锁，获得锁的节点成为主节点
+//! 并按间隔执行注册的周期任务（缓存清理、过期记录清理等），其余节点定期重试，
+//! 主节点退出或失去租约后由其他节点接替，避免各实例重复执行维护工作。
+//!
+//! ```ignore
+//! let handle = Scheduler::new("default", "maintenance")
+//!     .job(ScheduledJob::ttl_sweep("default", Duration::from_secs(60)))
+//!     .job(ScheduledJob::cache_cleanup("default", Duration::from_secs(300)))
+//!     .start();
+//! // ...
+//! handle.shutdown().await;
+//! ```
+//!
+//! 调度器包含 [`ScheduledJob::ttl_sweep`] 时，本实例注册模型时启动的后台清理任务在调度器运行期间
+//! 跳过该数据库，清理只由主节点执行
+
+use crate::error::{QuickDbError, QuickDbResult};
+use crate::lock::{AdvisoryLockGuard, try_advisory_lock};
+use rat_logger::{debug, info, warn};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+use tokio::time::Instant;
+
+/// 非主节点重试竞选的默认间隔
+pub const DEFAULT_ACQUIRE_INTERVAL: Duration = Duration::from_secs(5);
+
+type JobFuture = Pin<Box<dyn Future<Output = QuickDbResult<()>> + Send>>;
+
+/// 周期任务
+#[derive(Clone)]
+pub struct ScheduledJob {
+    name: String,
+    interval: Duration,
+    task: Arc<dyn Fn() -> JobFuture + Send + Sync>,
+    /// 由该任务接管过期清理的数据库别名
+    ttl_sweep_alias: Option<String>,
+}
+
+impl std::fmt::Debug for ScheduledJob {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ScheduledJob")
+            .field("name", &self.name)
+            .field("interval", &self.interval)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ScheduledJob {
+    /// 创建周期任务，成为主节点后立即执行一次，之后每隔 `interval` 执行
+    pub fn new<F, Fut>(name: impl Into<String>, interval: Duration, task: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = QuickDbResult<()>> + Send + 'static,
+    {
+        Self {
+            name: name.into(),
+            interval,
+            task: Arc::new(move || Box::pin(task()) as JobFuture),
+            ttl_sweep_alias: None,
+        }
+    }
+
+    /// 清理指定数据库缓存中的过期条目，数据库未启用缓存时不做任何事
+    pub fn cache_cleanup(alias: impl Into<String>, interval: Duration) -> Self {
+        let alias = alias.into();
+        Self::new(format!("cache_cleanup:{}", alias), interval, move || {
+            let alias = alias.clone();
+            async move {
+                let cache_manager = crate::manager::get_global_pool_manager()
+                    .cache_managers
+                    .get(&alias)
+                    .map(|cm| cm.clone());
+                if let Some(cache_manager) = cache_manager {
+                    let cleaned = cache_manager
+                        .force_cleanup_expired()
+                        .await
+                        .map_err(|e| QuickDbError::CacheError { message: e.to_string() })?;
+                    debug!("调度任务清理过期缓存: 别名={}, 数量={}", alias, cleaned);
+                }
+                Ok(())
+            }
+        })
+    }
+
+    /// 清理指定数据库中所有声明了过期配置的模型的过期记录
+    pub fn ttl_sweep(alias: impl Into<String>, interval: Duration) -> Self {
+        let alias = alias.into();
+        let sweep_alias = alias.clone();
+        let mut job = Self::new(format!("ttl_sweep:{}", alias), interval, move || {
+            let alias = sweep_alias.clone();
+            async move {
+                crate::manager::get_global_pool_manager()
+                    .sweep_expired_records(&alias)
+                    .await
+                    .map(drop)
+            }
+        });
+        job.ttl_sweep_alias = Some(alias);
+        job
+    }
+
+    /// 任务名
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// 执行间隔
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+}
+
+/// 分布式调度器
+#[derive(Debug, Clone)]
+pub struct Scheduler {
+    alias: String,
+    name: String,
+    jobs: Vec<ScheduledJob>,
+    acquire_interval: Duration,
+}
+
+impl Scheduler {
+    /// 创建调度器，`alias` 为用于竞选的数据库，同名调度器的节点互相竞争
+    pub fn new(alias: impl Into<String>, name: impl Into<String>) -> Self {
+        Self {
+            alias: alias.into(),
+            name: name.into(),
+            jobs: Vec::new(),
+            acquire_interval: DEFAULT_ACQUIRE_INTERVAL,
+        }
+    }
+
+    /// 注册周期任务
+    pub fn job(mut self, job: ScheduledJob) -> Self {
+        self.jobs.push(job);
+        self
+    }
+
+    /// 设置非主节点重试竞选的间隔
+    pub fn acquire_interval(mut self, interval: Duration) -> Self {
+        self.acquire_interval = interval;
+        self
+    }
+
+    /// 在后台启动调度器
+    pub fn start(self) -> SchedulerHandle {
+        let (shutdown, shutdown_rx) = watch::channel(false);
+        let leader = Arc::new(AtomicBool::new(false));
+        let task = tokio::spawn(self.run(leader.clone(), shutdown_rx));
+        SchedulerHandle { leader, shutdown, task }
+    }
+
+    fn lock_key(&self) -> String {
+        format!("scheduler:{}", self.name)
+    }
+
+    async fn run(self, leader: Arc<AtomicBool>, mut shutdown: watch::Receiver<bool>) {
+        info!("启动调度器: 别名={}, 名称={}, 任务数={}", self.alias, self.name, self.jobs.len());
+        loop {
+            match try_advisory_lock(&self.alias, &self.lock_key()).await {
+                Ok(Some(guard)) => {
+                    info!("调度器成为主节点: {}", self.name);
+                    leader.store(true, Ordering::Release);
+                    let stopped = self.lead(&guard, &mut shutdown).await;
+                    leader.store(false, Ordering::Release);
+                    if let Err(e) = guard.release().await {
+                        warn!("调度器释放主节点锁失败: {}, 错误={}", self.name, e);
+                    }
+                    if stopped {
+                        break;
+                    }
+                }
+                Ok(None) => {}
+                Err(e) => warn!("调度器竞选主节点失败: {}, 错误={}", self.name, e),
+            }
+            if wait_for_shutdown(&mut shutdown, self.acquire_interval).await {
+                break;
+            }
+        }
+        info!("调度器已停止: {}", self.name);
+    }
+
+    /// 以主节点身份执行任务，直到收到停止信号（返回 true）或失去租约（返回 false）
+    async fn lead(&self, guard: &AdvisoryLockGuard, shutdown: &mut watch::Receiver<bool>) -> bool {
+        let manager = crate::manager::get_global_pool_manager();
+        let ttl_aliases: Vec<&str> = self.jobs.iter().filter_map(|job| job.ttl_sweep_alias.as_deref()).collect();
+        for alias in &ttl_aliases {
+            manager.set_ttl_sweeps_scheduled(alias, true);
+        }
+
+        let mut schedule = Schedule::new(&self.jobs, Instant::now());
+        let stopped = loop {
+            if !guard.is_held() {
+                warn!("调度器失去主节点租约: {}", self.name);
+                break false;
+            }
+            for index in schedule.due(Instant::now()) {
+                let job = &self.jobs[index];
+                debug!("执行调度任务: {}", job.name);
+                if let Err(e) = (job.task)().await {
+                    warn!("调度任务执行失败: {}, 错误={}", job.name, e);
+                }
+                schedule.finished(index, job.interval, Instant::now());
+            }
+            // 至少每个竞选间隔检查一次租约
+            let wait = schedule
+                .next_wake()
+                .map_or(self.acquire_interval, |wake| wake.saturating_duration_since(Instant::now()))
+                .min(self.acquire_interval);
+            if wait_for_shutdown(shutdown, wait).await {
+                break true;
+            }
+        };
+
+        for alias in &ttl_aliases {
+            manager.set_ttl_sweeps_scheduled(alias, false);
+        }
+        stopped
+    }
+}
+
+/// 等待 `duration` 或停止信号，收到停止信号（或句柄被丢弃）时返回 true
+pub(crate) async fn wait_for_shutdown(shutdown: &mut watch::Receiver<bool>, duration: Duration) -> bool {
+    if *shutdown.borrow() {
+        return true;
+    }
+    tokio::select! {
+        changed = shutdown.changed() => changed.is_err() || *shutdown.borrow(),
+        _ = tokio::time::sleep(duration) => false,
+    }
+}
+
+/// 运行中的调度器句柄，丢弃句柄时调度器随之停止
+#[derive(Debug)]
+pub struct SchedulerHandle {
+    leader: Arc<AtomicBool>,
+    shutdown: watch::Sender<bool>,
+    task: JoinHandle<()>,
+}
+
+impl SchedulerHandle {
+    /// 本节点当前是否为主节点
+    pub fn is_leader(&self) -> bool {
+        self.leader.load(Ordering::Acquire)
+    }
+
+    /// 停止调度器并等待其释放主节点锁
+    pub async fn shutdown(self) {
+        let _ = self.shutdown.send(true);
+        let _ = self.task.await;
+    }
+}
+
+/// 各任务的下次执行时间
+struct Schedule {
+    next_runs: Vec<Instant>,
+}
+
+impl Schedule {
+    fn new(jobs: &[ScheduledJob], now: Instant) -> Self {
+        Self { next_runs: vec![now; jobs.len()] }
+    }
+
+    /// 到期的任务下标
+    fn due(&self, now: Instant) -> Vec<usize> {
+        (0..self.next_runs.len()).filter(|&index| self.next_runs[index] <= now).collect()
+    }
+
+    fn finished(&mut self, index: usize, interval: Duration, now: Instant) {
+        self.next_runs[index] = now + interval;
+    }
+
+    fn next_wake(&self) -> Option<Instant> {
+        self.next_runs.iter().min().copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn schedule_runs_jobs_immediately_then_by_interval() {
+        let jobs = vec![
+            ScheduledJob::new("fast", Duration::from_secs(1), || async { Ok(()) }),
+            ScheduledJob::new("slow", Duration::from_secs(10), || async { Ok(()) }),
+        ];
+        let start = Instant::now();
+        let mut schedule = Schedule::new(&jobs, start);
+        assert_eq!(schedule.due(start), vec![0, 1]);
+
+        schedule.finished(0, jobs[0].interval(), start);
+        schedule.finished(1, jobs[1].interval(), start);
+        assert!(schedule.due(start).is_empty());
+        assert_eq!(schedule.next_wake(), Some(start + Duration::from_secs(1)));
+        assert_eq!(schedule.due(start + Duration::from_secs(2)), vec![0]);
+        assert_eq!(ScheduledJob::ttl_sweep("main", Duration::from_secs(60)).ttl_sweep_alias.as_deref(), Some("main"));
+    }
+}
@@ -64,6 +64,43 @@ impl DatabaseSecurityValidator {
     /// * `Ok(())` - 表名安全
     /// * `Err(QuickDbError)` - 表名包含非法字符
     pub fn validate_table_name(&self, table_name: &str) -> QuickDbResult<()> {
+        // 限定名（`模式.表名`，MongoDB 为 `数据库.集合名`）按段分别验证
+        let system_collection = self.db_type == DatabaseType::MongoDB && table_name.starts_with("system.");
+        if let (Some(schema), table) = split_qualified_name(table_name) {
+            if !system_collection {
+                self.validate_schema_name(schema)?;
+                return self.validate_unqualified_table_name(table);
+            }
+        }
+        self.validate_unqualified_table_name(table_name)
+    }
+
+    /// 验证模式名（MongoDB 为数据库名）的安全性
+    fn validate_schema_name(&self, schema: &str) -> QuickDbResult<()> {
+        let invalid = |message: String| QuickDbError::ValidationError {
+            field: schema.to_string(),
+            message,
+        };
+        if schema.is_empty() {
+            return Err(invalid(crate::i18n::tf("security.schema_invalid", &[("name", schema)])));
+        }
+        if schema.len() > 64 {
+            return Err(invalid(crate::i18n::t("security.table_too_long")));
+        }
+        match self.db_type {
+            DatabaseType::PostgreSQL | DatabaseType::MySQL | DatabaseType::SQLite => self.validate_sql_table_name(schema),
+            DatabaseType::MongoDB => {
+                // MongoDB 数据库名不能包含 /\. "$*<>:|? 与空字符
+                if schema.chars().any(|ch| "/\\. \"$*<>:|?\0".contains(ch)) {
+                    return Err(invalid(crate::i18n::tf("security.schema_invalid", &[("name", schema)])));
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// 验证不带模式前缀的表名
+    fn validate_unqualified_table_name(&self, table_name: &str) -> QuickDbResult<()> {
         // 表名不能为空
         if table_name.is_empty() {
             return Err(QuickDbError::ValidationError {
@@ -126,13 +163,8 @@ impl DatabaseSecurityValidator {
         // 验证表名安全性
         self.validate_table_name(table_name)?;
 
-        // 返回带引号的表标识符
-        match self.db_type {
-            DatabaseType::PostgreSQL => Ok(format!("\"{}\"", table_name)),
-            DatabaseType::MySQL => Ok(format!("`{}`", table_name)),
-            DatabaseType::SQLite => Ok(format!("\"{}\"", table_name)),
-            DatabaseType::MongoDB => Ok(table_name.to_string()), // MongoDB不需要引号
-        }
+        // 返回带引号的表标识符（限定名的每一段分别加引号）
+        Ok(quote_table_identifier(table_name, self.db_type))
     }
 
     /// 验证SQL数据库字段名的安全性
@@ -280,6 +312,28 @@ pub fn quote_identifier(name: &str, db_type: DatabaseType) -> String {
     }
 }
 
+/// 拆分限定表名：`模式.表名`（MongoDB 为 `数据库.集合名`）按第一个点号拆分为模式与表名
+///
+/// 不包含点号时模式为 `None`
+pub fn split_qualified_name(name: &str) -> (Option<&str>, &str) {
+    match name.split_once('.') {
+        Some((schema, table)) => (Some(schema), table),
+        None => (None, name),
+    }
+}
+
+/// 按数据库类型给表名加引号，限定表名的模式与表名分别加引号
+///
+/// MongoDB 原样返回，由适配器按 [`split_qualified_name`] 选择数据库
+pub fn quote_table_identifier(name: &str, db_type: DatabaseType) -> String {
+    match (db_type, split_qualified_name(name)) {
+        (DatabaseType::MongoDB, _) | (_, (None, _)) => quote_identifier(name, db_type),
+        (_, (Some(schema), table)) => {
+            format!("{}.{}", quote_identifier(schema, db_type), quote_identifier(table, db_type))
+        }
+    }
+}
+
 /// 对点分隔的标识符（如 "表名.字段名"）的每一段分别加引号
 ///
 /// 用于存储过程 SQL 中 `local_field`/`foreign_field` 等 "表名.字段名" 格式的标识符。
@@ -578,6 +632,22 @@ mod tests {
         assert!(v.validate_field_name("order").is_ok());
     }
 
+    #[test]
+    fn test_qualified_table_name() {
+        let v = DatabaseSecurityValidator::new(DatabaseType::PostgreSQL);
+        assert_eq!(v.get_safe_table_identifier("analytics.events").unwrap(), "\"analytics\".\"events\"");
+        assert_eq!(quote_table_identifier("analytics.events", DatabaseType::MySQL), "`analytics`.`events`");
+        assert_eq!(quote_table_identifier("events", DatabaseType::SQLite), "\"events\"");
+        assert!(v.validate_table_name("a.b.c").is_err());
+        assert!(v.validate_table_name(".events").is_err());
+
+        let mongo = DatabaseSecurityValidator::new(DatabaseType::MongoDB);
+        assert!(mongo.validate_table_name("analytics.fs.files").is_ok());
+        assert!(mongo.validate_table_name("bad$db.events").is_err());
+        assert_eq!(split_qualified_name("analytics.fs.files"), (Some("analytics"), "fs.files"));
+        assert_eq!(split_qualified_name("events"), (None, "events"));
+    }
+
     #[test]
     fn test_quote_identifier() {
         assert_eq!(quote_identifier("order", DatabaseType::PostgreSQL), "\"order\"");
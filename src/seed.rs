@@ -0,0 +1,422 @@
+//! # 数据填充模块
+//!
+//! 按顺序把种子文件中的数据写入指定别名的数据库，用于演示环境与 CI。
+//! 已应用的种子记录在 `_rqdb_seeds` 表中（含插入记录的 ID），重复执行
+//! [`Seeder::run_seeds`] 只会应用新增的种子，[`Seeder::reset_seeds`]
+//! 删除种子插入的数据并清除应用记录。
+//!
+//! 种子文件为 JSON / TOML / YAML（YAML 需要 `yaml` 特性），顶层键为表名，值为记录列表，
+//! 同一文件内按表的书写顺序插入；种子名默认取文件名（不含扩展名）：
+//!
+//! ```toml
+//! # seeds/001_users.toml
+//! [[users]]
+//! name = "alice"
+//!
+//! [[users]]
+//! name = "bob"
+//! ```
+//!
+//! ```ignore
+//! let seeder = Seeder::from_dir("default", "seeds")?;
+//! let report = seeder.run_seeds().await?;
+//! ```
+
+use crate::config::ConfigFileFormat;
+use crate::error::{QuickDbError, QuickDbResult};
+use crate::manager::get_global_pool_manager;
+use crate::model::{
+    FieldDefinition, FieldType, IndexDefinition, IndexOptions, ModelMeta, bigint_field, integer_field, string_field,
+};
+use crate::odm::OdmOperations;
+use crate::types::data_value::json_value_to_data_value;
+use crate::types::*;
+use rat_logger::{info, warn};
+use serde::de::{Deserializer, MapAccess, Visitor};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+
+/// 种子应用记录表名
+pub const SEED_TABLE: &str = "_rqdb_seeds";
+
+/// 按表分组的记录（保持文件中表的顺序）
+pub(crate) struct TableRecords(pub(crate) Vec<(String, Vec<serde_json::Value>)>);
+
+impl<'de> Deserialize<'de> for TableRecords {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct TableRecordsVisitor;
+
+        impl<'de> Visitor<'de> for TableRecordsVisitor {
+            type Value = TableRecords;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a map of table name to record list")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<TableRecords, A::Error> {
+                let mut tables = Vec::new();
+                while let Some(entry) = map.next_entry()? {
+                    tables.push(entry);
+                }
+                Ok(TableRecords(tables))
+            }
+        }
+
+        deserializer.deserialize_map(TableRecordsVisitor)
+    }
+}
+
+impl TableRecords {
+    /// 解析按表分组的记录，失败时返回解析器的错误信息
+    pub(crate) fn parse(content: &str, format: ConfigFileFormat) -> Result<Self, String> {
+        match format {
+            ConfigFileFormat::Json => serde_json::from_str(content).map_err(|e| e.to_string()),
+            ConfigFileFormat::Toml => toml::from_str(content).map_err(|e| e.to_string()),
+            #[cfg(feature = "yaml")]
+            ConfigFileFormat::Yaml => serde_norway::from_str(content).map_err(|e| e.to_string()),
+        }
+    }
+}
+
+/// 种子文件
+pub struct SeedFile {
+    /// 种子名（同一别名下唯一，决定是否已应用）
+    pub name: String,
+    tables: Vec<(String, Vec<HashMap<String, DataValue>>)>,
+}
+
+impl SeedFile {
+    /// 从文件加载种子，种子名取文件名（不含扩展名），格式由扩展名决定
+    pub fn from_path<P: AsRef<Path>>(path: P) -> QuickDbResult<Self> {
+        let path = path.as_ref();
+        let format = ConfigFileFormat::from_path(path).ok_or_else(|| {
+            crate::quick_error!(
+                config,
+                crate::i18n::tf("config.file_format_unsupported", &[("path", &path.display().to_string())])
+            )
+        })?;
+        let name = path.file_stem().map(|stem| stem.to_string_lossy().into_owned()).unwrap_or_default();
+        let content = std::fs::read_to_string(path).map_err(QuickDbError::IoError)?;
+        Self::from_str(name, &content, format)
+    }
+
+    /// 从字符串加载种子
+    pub fn from_str<N: Into<String>>(name: N, content: &str, format: ConfigFileFormat) -> QuickDbResult<Self> {
+        let name = name.into();
+        let records = TableRecords::parse(content, format).map_err(|message| {
+            crate::quick_error!(
+                config,
+                crate::i18n::tf("seed.parse_failed", &[("name", &name), ("message", &message)])
+            )
+        })?;
+
+        let mut tables = Vec::with_capacity(records.0.len());
+        for (table, values) in records.0 {
+            let mut rows = Vec::with_capacity(values.len());
+            for (index, value) in values.into_iter().enumerate() {
+                let DataValue::Object(row) = json_value_to_data_value(value) else {
+                    return Err(crate::quick_error!(
+                        config,
+                        crate::i18n::tf(
+                            "seed.record_invalid",
+                            &[("name", &name), ("table", &table), ("index", &index.to_string())]
+                        )
+                    ));
+                };
+                rows.push(row);
+            }
+            tables.push((table, rows));
+        }
+        Ok(Self { name, tables })
+    }
+
+    /// 记录总数
+    pub fn record_count(&self) -> usize {
+        self.tables.iter().map(|(_, rows)| rows.len()).sum()
+    }
+}
+
+/// 种子执行结果
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SeedReport {
+    /// 本次应用（或重置）的种子名（按执行顺序）
+    pub applied: Vec<String>,
+    /// 已应用过而跳过的种子名
+    pub skipped: Vec<String>,
+    /// 插入（或删除）的记录数
+    pub records: usize,
+}
+
+/// 已应用的种子
+struct AppliedSeed {
+    name: String,
+    applied_at: i64,
+    /// 表名 → 插入记录的 ID
+    inserted: Vec<(String, Vec<DataValue>)>,
+}
+
+/// 数据填充器
+///
+/// 种子按添加顺序应用，[`Seeder::from_dir`] 按文件名排序添加
+pub struct Seeder {
+    alias: String,
+    seeds: Vec<SeedFile>,
+}
+
+impl Seeder {
+    /// 为指定别名创建空的填充器
+    pub fn new<A: Into<String>>(alias: A) -> Self {
+        Self {
+            alias: alias.into(),
+            seeds: Vec::new(),
+        }
+    }
+
+    /// 加载目录下所有 JSON / TOML / YAML 种子文件（按文件名排序，不递归）
+    pub fn from_dir<A: Into<String>, P: AsRef<Path>>(alias: A, dir: P) -> QuickDbResult<Self> {
+        let mut paths = Vec::new();
+        for entry in std::fs::read_dir(dir.as_ref()).map_err(QuickDbError::IoError)? {
+            let path = entry.map_err(QuickDbError::IoError)?.path();
+            if path.is_file() && ConfigFileFormat::from_path(&path).is_some() {
+                paths.push(path);
+            }
+        }
+        paths.sort();
+
+        let mut seeder = Self::new(alias);
+        for path in paths {
+            seeder = seeder.with_seed(SeedFile::from_path(path)?);
+        }
+        Ok(seeder)
+    }
+
+    /// 添加种子
+    pub fn with_seed(mut self, seed: SeedFile) -> Self {
+        self.seeds.push(seed);
+        self
+    }
+
+    /// 种子名列表（按应用顺序）
+    pub fn seed_names(&self) -> Vec<&str> {
+        self.seeds.iter().map(|seed| seed.name.as_str()).collect()
+    }
+
+    /// 已应用的种子名（按应用顺序）
+    pub async fn applied_seeds(&self) -> QuickDbResult<Vec<String>> {
+        self.ensure_seed_table().await?;
+        Ok(self.load_applied().await?.into_iter().map(|seed| seed.name).collect())
+    }
+
+    /// 应用尚未应用的种子
+    ///
+    /// 种子内任一记录插入失败时尽力删除该种子已插入的记录并返回错误，
+    /// 之前已应用的种子保持不变
+    pub async fn run_seeds(&self) -> QuickDbResult<SeedReport> {
+        self.ensure_seed_table().await?;
+        let applied: Vec<String> = self.load_applied().await?.into_iter().map(|seed| seed.name).collect();
+        let odm = crate::odm::get_odm_manager().await;
+        let alias = Some(self.alias.as_str());
+
+        let mut report = SeedReport::default();
+        for seed in &self.seeds {
+            if applied.contains(&seed.name) {
+                report.skipped.push(seed.name.clone());
+                continue;
+            }
+
+            let mut inserted: Vec<(String, Vec<DataValue>)> = Vec::new();
+            for (table, rows) in &seed.tables {
+                let mut ids = Vec::with_capacity(rows.len());
+                for row in rows {
+                    match odm.create(table, row.clone(), alias).await {
+                        Ok(id) => ids.push(id),
+                        Err(e) => {
+                            inserted.push((table.clone(), ids));
+                            warn!("种子应用失败，回滚已插入的记录: 别名={}, 种子={}, 错误={}", self.alias, seed.name, e);
+                            delete_inserted(&odm, &inserted, alias).await;
+                            return Err(e);
+                        }
+                    }
+                }
+                inserted.push((table.clone(), ids));
+            }
+
+            let inserted_json = serde_json::Value::Array(
+                inserted
+                    .iter()
+                    .map(|(table, ids)| {
+                        serde_json::json!({
+                            "table": table,
+                            "ids": ids.iter().map(DataValue::to_json_value).collect::<Vec<_>>(),
+                        })
+                    })
+                    .collect(),
+            );
+            let record = HashMap::from([
+                ("name".to_string(), DataValue::String(seed.name.clone())),
+                ("applied_at".to_string(), DataValue::Int(chrono::Utc::now().timestamp_millis())),
+                ("inserted".to_string(), DataValue::String(inserted_json.to_string())),
+            ]);
+            odm.create(SEED_TABLE, record, alias).await?;
+
+            info!("已应用种子: 别名={}, 种子={}, 记录数={}", self.alias, seed.name, seed.record_count());
+            report.applied.push(seed.name.clone());
+            report.records += seed.record_count();
+        }
+        Ok(report)
+    }
+
+    /// 重置所有已应用的种子（包括已不在当前填充器中的种子）
+    ///
+    /// 按应用顺序的逆序删除种子插入的记录，并清除应用记录，
+    /// 之后再次调用 [`Seeder::run_seeds`] 会重新应用全部种子
+    pub async fn reset_seeds(&self) -> QuickDbResult<SeedReport> {
+        self.ensure_seed_table().await?;
+        let mut applied = self.load_applied().await?;
+        applied.reverse();
+        let odm = crate::odm::get_odm_manager().await;
+        let alias = Some(self.alias.as_str());
+
+        let mut report = SeedReport::default();
+        for seed in applied {
+            report.records += delete_inserted(&odm, &seed.inserted, alias).await;
+            let condition = QueryConditionWithConfig {
+                field: "name".to_string(),
+                operator: QueryOperator::Eq,
+                value: DataValue::String(seed.name.clone()),
+                case_insensitive: false,
+            };
+            odm.delete(SEED_TABLE, vec![condition], alias).await?;
+            info!("已重置种子: 别名={}, 种子={}", self.alias, seed.name);
+            report.applied.push(seed.name);
+        }
+        Ok(report)
+    }
+
+    /// 注册并创建种子记录表
+    async fn ensure_seed_table(&self) -> QuickDbResult<()> {
+        let manager = get_global_pool_manager();
+        if manager.get_model_with_alias(SEED_TABLE, &self.alias).is_none() {
+            let id_strategy = manager.get_id_strategy(&self.alias)?;
+            manager.register_model(seed_table_meta(&self.alias, &id_strategy))?;
+        }
+        manager.ensure_table_and_indexes(SEED_TABLE, &self.alias).await
+    }
+
+    async fn load_applied(&self) -> QuickDbResult<Vec<AppliedSeed>> {
+        let odm = crate::odm::get_odm_manager().await;
+        let records = odm.find(SEED_TABLE, vec![], None, Some(&self.alias)).await?;
+
+        let mut applied = Vec::with_capacity(records.len());
+        for record in records {
+            let record = record.expect_object()?;
+            let Some(DataValue::String(name)) = record.get("name") else {
+                continue;
+            };
+            let applied_at = match record.get("applied_at") {
+                Some(DataValue::Int(value)) => *value,
+                _ => 0,
+            };
+            let inserted = match record.get("inserted") {
+                Some(DataValue::String(text)) => serde_json::from_str(text).unwrap_or(serde_json::Value::Null),
+                Some(other) => other.to_json_value(),
+                None => serde_json::Value::Null,
+            };
+            let inserted = inserted
+                .as_array()
+                .map(|tables| {
+                    tables
+                        .iter()
+                        .filter_map(|entry| {
+                            let table = entry.get("table")?.as_str()?.to_string();
+                            let ids = entry.get("ids")?.as_array()?.iter().cloned().map(json_value_to_data_value);
+                            Some((table, ids.collect()))
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+            applied.push(AppliedSeed {
+                name: name.clone(),
+                applied_at,
+                inserted,
+            });
+        }
+        applied.sort_by(|a, b| a.applied_at.cmp(&b.applied_at).then_with(|| a.name.cmp(&b.name)));
+        Ok(applied)
+    }
+}
+
+/// 逆序删除插入的记录，返回删除数量（删除失败只记录警告）
+async fn delete_inserted(
+    odm: &crate::odm::AsyncOdmManager,
+    inserted: &[(String, Vec<DataValue>)],
+    alias: Option<&str>,
+) -> usize {
+    let mut deleted = 0;
+    for (table, ids) in inserted.iter().rev() {
+        for id in ids.iter().rev() {
+            // 按原始类型的 ID 条件删除，避免字符串 ID 与整数主键比较不匹配
+            let condition = QueryConditionWithConfig {
+                field: "id".to_string(),
+                operator: QueryOperator::Eq,
+                value: id.clone(),
+                case_insensitive: false,
+            };
+            match odm.delete(table, vec![condition], alias).await {
+                Ok(affected) => deleted += affected as usize,
+                Err(e) => warn!("删除种子记录失败: 表={}, ID={}, 错误={}", table, id, e),
+            }
+        }
+    }
+    deleted
+}
+
+/// 种子记录表的模型元数据（ID 字段类型跟随别名的 ID 策略）
+fn seed_table_meta(alias: &str, id_strategy: &IdStrategy) -> ModelMeta {
+    let id_field = match id_strategy {
+        IdStrategy::AutoIncrement => integer_field(None, None),
+        _ => string_field(None, None, None),
+    };
+    let fields: HashMap<String, FieldDefinition> = HashMap::from([
+        ("id".to_string(), id_field),
+        ("name".to_string(), string_field(Some(255), None, None).required()),
+        ("applied_at".to_string(), bigint_field().required()),
+        ("inserted".to_string(), FieldDefinition::new(FieldType::Text)),
+    ]);
+    ModelMeta {
+        collection_name: SEED_TABLE.to_string(),
+        database_alias: Some(alias.to_string()),
+        fields,
+        indexes: vec![IndexDefinition {
+            fields: vec!["name".to_string()],
+            unique: true,
+            name: Some(format!("uniq{}_name", SEED_TABLE)),
+            options: IndexOptions::default(),
+        }],
+        description: Some("已应用的数据种子".to_string()),
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seed_file_keeps_table_order_and_rejects_non_objects() {
+        crate::i18n::ErrorMessageI18n::init_i18n();
+        let content = "[[users]]\nname = \"alice\"\n\n[[users]]\nname = \"bob\"\n\n[[posts]]\ntitle = \"hello\"\n";
+        let seed = SeedFile::from_str("001_users", content, ConfigFileFormat::Toml).unwrap();
+        assert_eq!(seed.name, "001_users");
+        assert_eq!(seed.record_count(), 3);
+        let tables: Vec<&str> = seed.tables.iter().map(|(table, _)| table.as_str()).collect();
+        assert_eq!(tables, vec!["users", "posts"]);
+        assert_eq!(seed.tables[0].1[1]["name"], DataValue::String("bob".to_string()));
+
+        assert!(SeedFile::from_str("bad", r#"{"users": [1]}"#, ConfigFileFormat::Json).is_err());
+        #[cfg(feature = "yaml")]
+        assert!(SeedFile::from_str("bad", "users: [", ConfigFileFormat::Yaml).is_err());
+    }
+}
@@ -1,32 +1,71 @@
 //! JSON序列化层
 //!
 //! 提供灵活的序列化选项，支持返回JSON字符串或对象
-//! 兼容PyO3调用，可根据调用者需求选择返回格式
+//! 兼容PyO3调用，可根据调用者需求选择返回格式；支持MessagePack（`msgpack` 特性）、BSON（`bson-output` 特性）与CBOR（`cbor` 特性）二进制输出；另提供CSV与NDJSON导入导出
 
 use crate::error::{QuickDbError, QuickDbResult};
 use crate::types::DataValue;
-use rat_logger::{debug, error, info, warn};
-use serde::{Deserialize, Serialize};
+use rat_logger::debug;
 use serde_json::{Map as JsonMap, Value as JsonValue};
 use std::collections::HashMap;
 
+mod binary;
+mod csv;
+mod ndjson;
+#[cfg(feature = "parquet")]
+mod parquet;
+pub(crate) mod transfer;
+pub mod wire;
+#[cfg(feature = "bson-output")]
+pub use binary::{decode_bson, decode_bson_records};
+#[cfg(feature = "cbor")]
+pub use binary::{decode_cbor, decode_cbor_records};
+#[cfg(feature = "msgpack")]
+pub use binary::{decode_msgpack, decode_msgpack_records};
+pub use csv::{CsvColumn, CsvMapping, CsvOptions, CsvWriter, export_table_csv, import_csv, records_to_csv};
+pub use ndjson::{NdjsonOptions, export_ndjson, import_ndjson};
+#[cfg(feature = "parquet")]
+pub use self::parquet::{ParquetColumnType, ParquetSchemaMapping, export_parquet};
+pub use transfer::{ImportReport, ImportRowError};
+
 /// 序列化输出格式
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Default)]
 pub enum OutputFormat {
     /// JSON字符串格式（默认，兼容PyO3）
+    #[default]
     JsonString,
     /// JSON对象格式（Rust原生调用）
     JsonObject,
     /// 原始数据格式（内部使用）
     RawData,
+    /// MessagePack 二进制格式（结果为 [`SerializationResult::Bytes`]，需要 `msgpack` 特性）
+    #[cfg(feature = "msgpack")]
+    MessagePack,
+    /// BSON 二进制格式（结果为 [`SerializationResult::Bytes`]，需要 `bson-output` 特性）
+    #[cfg(feature = "bson-output")]
+    Bson,
+    /// CBOR 二进制格式（结果为 [`SerializationResult::Bytes`]，需要 `cbor` 特性）
+    #[cfg(feature = "cbor")]
+    Cbor,
 }
 
-impl Default for OutputFormat {
-    fn default() -> Self {
-        OutputFormat::JsonString
+impl OutputFormat {
+    /// 是否为二进制格式
+    pub fn is_binary(&self) -> bool {
+        match self {
+            #[cfg(feature = "msgpack")]
+            OutputFormat::MessagePack => true,
+            #[cfg(feature = "bson-output")]
+            OutputFormat::Bson => true,
+            #[cfg(feature = "cbor")]
+            OutputFormat::Cbor => true,
+            OutputFormat::JsonString | OutputFormat::JsonObject | OutputFormat::RawData => false,
+        }
     }
 }
 
+
 /// 序列化配置
 #[derive(Debug, Clone)]
 pub struct SerializerConfig {
@@ -133,6 +172,13 @@ pub enum SerializationResult {
     JsonObject(JsonValue),
     /// 原始数据
     RawData(HashMap<String, DataValue>),
+    /// 二进制数据
+    Bytes {
+        /// 编码格式
+        format: OutputFormat,
+        /// 编码后的数据
+        data: Vec<u8>,
+    },
 }
 
 impl SerializationResult {
@@ -151,6 +197,12 @@ impl SerializationResult {
                     message: crate::i18n::tf("serializer.serialize_to_json_failed", &[("message", &e.to_string())]),
                 })
             }
+            SerializationResult::Bytes { .. } => {
+                let json_obj = self.to_json_object()?;
+                serde_json::to_string(&json_obj).map_err(|e| QuickDbError::SerializationError {
+                    message: crate::i18n::tf("serializer.serialize_to_json_failed", &[("message", &e.to_string())]),
+                })
+            }
         }
     }
 
@@ -164,6 +216,7 @@ impl SerializationResult {
             }
             SerializationResult::JsonObject(obj) => Ok(obj.clone()),
             SerializationResult::RawData(data) => data_map_to_json_value(data),
+            SerializationResult::Bytes { format, data } => Ok(binary::decode(*format, data)?.to_json_value()),
         }
     }
 
@@ -179,6 +232,14 @@ impl SerializationResult {
             }
             SerializationResult::JsonObject(obj) => json_value_to_data_map(obj),
             SerializationResult::RawData(data) => Ok(data.clone()),
+            // 与 RawData 一致：多条记录放在 "records" 键下
+            SerializationResult::Bytes { format, data } => match binary::decode(*format, data)? {
+                DataValue::Object(map) => Ok(map),
+                records @ DataValue::Array(_) => Ok(HashMap::from([("records".to_string(), records)])),
+                _ => Err(QuickDbError::SerializationError {
+                    message: crate::i18n::t("serializer.json_not_object"),
+                }),
+            },
         }
     }
 
@@ -188,6 +249,15 @@ impl SerializationResult {
             SerializationResult::JsonString(_) => "json_string",
             SerializationResult::JsonObject(_) => "json_object",
             SerializationResult::RawData(_) => "raw_data",
+            SerializationResult::Bytes { .. } => "bytes",
+        }
+    }
+
+    /// 获取二进制数据（非二进制结果返回 `None`）
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            SerializationResult::Bytes { data, .. } => Some(data),
+            _ => None,
         }
     }
 }
@@ -205,6 +275,7 @@ impl DataSerializer {
     }
 
     /// 使用默认配置创建序列化器
+    #[allow(clippy::should_implement_trait)]
     pub fn default() -> Self {
         Self::new(SerializerConfig::default())
     }
@@ -237,6 +308,8 @@ impl DataSerializer {
                 Ok(SerializationResult::JsonObject(json_obj))
             }
             OutputFormat::RawData => Ok(SerializationResult::RawData(processed_data)),
+            #[allow(unreachable_patterns)]
+            format => self.encode_binary(format, &DataValue::Object(processed_data)),
         }
     }
 
@@ -290,12 +363,17 @@ impl DataSerializer {
                     DataValue::Array(
                         processed_records
                             .into_iter()
-                            .map(|record| DataValue::Object(record))
+                            .map(DataValue::Object)
                             .collect(),
                     ),
                 );
                 Ok(SerializationResult::RawData(result_data))
             }
+            #[allow(unreachable_patterns)]
+            format => self.encode_binary(
+                format,
+                &DataValue::Array(processed_records.into_iter().map(DataValue::Object).collect()),
+            ),
         }
     }
 
@@ -310,26 +388,12 @@ impl DataSerializer {
 
         let mut result_data = HashMap::new();
 
-        // 序列化记录
-        let records_result = self.serialize_records(records)?;
-        match records_result {
-            SerializationResult::JsonObject(JsonValue::Array(arr)) => {
-                result_data.insert(
-                    "data".to_string(),
-                    DataValue::Array(arr.into_iter().map(|v| DataValue::from_json(v)).collect()),
-                );
-            }
-            SerializationResult::RawData(raw) => {
-                if let Some(DataValue::Array(records)) = raw.get("records") {
-                    result_data.insert("data".to_string(), DataValue::Array(records.clone()));
-                }
-            }
-            _ => {
-                return Err(QuickDbError::SerializationError {
-                    message: crate::i18n::t("serializer.cannot_process_result"),
-                });
-            }
+        // 处理记录
+        let mut processed_records = Vec::with_capacity(records.len());
+        for record in records {
+            processed_records.push(DataValue::Object(self.process_data(record)?));
         }
+        result_data.insert("data".to_string(), DataValue::Array(processed_records));
 
         // 添加元数据
         if let Some(count) = total_count {
@@ -371,9 +435,19 @@ impl DataSerializer {
                 Ok(SerializationResult::JsonObject(json_obj))
             }
             OutputFormat::RawData => Ok(SerializationResult::RawData(result_data)),
+            #[allow(unreachable_patterns)]
+            format => self.encode_binary(format, &DataValue::Object(result_data)),
         }
     }
 
+    /// 编码为二进制结果
+    fn encode_binary(&self, format: OutputFormat, value: &DataValue) -> QuickDbResult<SerializationResult> {
+        Ok(SerializationResult::Bytes {
+            format,
+            data: binary::encode(format, value)?,
+        })
+    }
+
     /// 处理数据（应用配置选项）
     fn process_data(
         &self,
@@ -384,16 +458,6 @@ impl DataSerializer {
             data.retain(|_, v| !matches!(v, DataValue::Null));
         }
 
-        // 处理日期时间格式
-        if let Some(ref format) = self.config.datetime_format {
-            for (_, value) in data.iter_mut() {
-                if let DataValue::DateTime(dt) = value {
-                    // 这里可以根据需要格式化日期时间
-                    // 暂时保持原样
-                }
-            }
-        }
-
         // 处理浮点数精度
         if let Some(precision) = self.config.float_precision {
             for (_, value) in data.iter_mut() {
@@ -438,7 +502,7 @@ fn json_value_to_data_map(json: &JsonValue) -> QuickDbResult<HashMap<String, Dat
 
 /// 全局序列化器实例
 static DEFAULT_SERIALIZER: once_cell::sync::Lazy<DataSerializer> =
-    once_cell::sync::Lazy::new(|| DataSerializer::default());
+    once_cell::sync::Lazy::new(DataSerializer::default);
 
 static RUST_SERIALIZER: once_cell::sync::Lazy<DataSerializer> =
     once_cell::sync::Lazy::new(|| DataSerializer::new(SerializerConfig::for_rust()));
@@ -577,4 +641,29 @@ mod tests {
         let result = SerializationResult::RawData(data);
         assert!(result.to_raw_data().is_ok());
     }
+
+    #[cfg(feature = "msgpack")]
+    #[test]
+    fn test_message_pack_results_decode_back() {
+        let serializer = DataSerializer::new(SerializerConfig::new().format(OutputFormat::MessagePack));
+        let record = HashMap::from([
+            ("name".to_string(), DataValue::String("alice".to_string())),
+            ("avatar".to_string(), DataValue::Bytes(vec![1, 2])),
+        ]);
+
+        let result = serializer.serialize_record(record.clone()).unwrap();
+        assert_eq!(result.result_type(), "bytes");
+        assert_eq!(result.to_raw_data().unwrap(), record);
+        assert_eq!(result.to_json_object().unwrap()["avatar"], "AQI=");
+
+        let result = serializer
+            .serialize_query_result(vec![record.clone()], Some(1), Some(false))
+            .unwrap();
+        let decoded = decode_msgpack(result.as_bytes().unwrap()).unwrap();
+        let DataValue::Object(page) = decoded else {
+            panic!("查询结果应为对象");
+        };
+        assert_eq!(page["data"], DataValue::Array(vec![DataValue::Object(record)]));
+        assert_eq!(page["total_count"], DataValue::Int(1));
+    }
 }
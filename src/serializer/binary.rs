@@ -0,0 +1,220 @@
+//! 二进制输出格式的编解码
+//!
+//! 记录按普通的 map / 数组 / 标量编码（不带 `DataValue` 的类型标签），字节数组使用
+//! 格式自身的二进制类型：
+//!
+//! | 类型 | MessagePack | BSON | CBOR |
+//! |------|-------------|------|------|
+//! | 字节数组 | bin | Binary（Generic） | 字节串 |
+//! | 日期时间 | RFC 3339 字符串 | UTC datetime（毫秒精度） | 标签 0 + RFC 3339 字符串 |
+//! | UUID | 字符串 | Binary（子类型 4） | 标签 37 + 16 字节 |
+//!
+//! BSON 顶层必须是文档，记录列表编码为 `{"records": [...]}`。
+//! 三种格式分别需要启用 `msgpack`、`bson-output` 与 `cbor` 特性
+
+use super::OutputFormat;
+use crate::error::{QuickDbError, QuickDbResult};
+use crate::types::DataValue;
+#[cfg(any(feature = "msgpack", feature = "bson-output", feature = "cbor"))]
+use std::collections::HashMap;
+
+#[cfg(feature = "bson-output")]
+mod bson;
+#[cfg(feature = "cbor")]
+mod cbor;
+#[cfg(feature = "msgpack")]
+mod msgpack;
+
+/// JSON 值先转换为 DataValue 再编码（无法转换的数字按字符串编码）
+#[cfg(any(feature = "bson-output", feature = "cbor"))]
+fn plain_json(value: &DataValue) -> DataValue {
+    match crate::types::data_value::json_value_to_data_value(value.to_json_value()) {
+        DataValue::Json(json) => DataValue::String(json.to_string()),
+        other => other,
+    }
+}
+
+/// 按二进制输出格式编码（未启用任何二进制格式特性时不会被调用）
+#[cfg_attr(
+    not(any(feature = "msgpack", feature = "bson-output", feature = "cbor")),
+    allow(unused_variables, unreachable_code)
+)]
+pub(crate) fn encode(format: OutputFormat, value: &DataValue) -> QuickDbResult<Vec<u8>> {
+    let result: Result<Vec<u8>, String> = match format {
+        #[cfg(feature = "msgpack")]
+        OutputFormat::MessagePack => msgpack::encode(value),
+        #[cfg(feature = "bson-output")]
+        OutputFormat::Bson => bson::encode(value),
+        #[cfg(feature = "cbor")]
+        OutputFormat::Cbor => cbor::encode(value),
+        _ => unreachable!("{:?} 不是二进制格式", format),
+    };
+    result.map_err(|message| QuickDbError::SerializationError {
+        message: crate::i18n::tf("serializer.serialize_failed", &[("message", &message)]),
+    })
+}
+
+/// 按二进制输出格式解码
+#[cfg_attr(
+    not(any(feature = "msgpack", feature = "bson-output", feature = "cbor")),
+    allow(unused_variables, unreachable_code)
+)]
+pub(crate) fn decode(format: OutputFormat, bytes: &[u8]) -> QuickDbResult<DataValue> {
+    let result: Result<DataValue, String> = match format {
+        #[cfg(feature = "msgpack")]
+        OutputFormat::MessagePack => msgpack::decode(bytes),
+        #[cfg(feature = "bson-output")]
+        OutputFormat::Bson => bson::decode(bytes),
+        #[cfg(feature = "cbor")]
+        OutputFormat::Cbor => cbor::decode(bytes),
+        _ => unreachable!("{:?} 不是二进制格式", format),
+    };
+    result.map_err(|message| QuickDbError::SerializationError {
+            message: crate::i18n::tf(
+                "serializer.decode_failed",
+                &[("format", &format!("{:?}", format)), ("message", &message)],
+            ),
+        })
+}
+
+/// 解码 MessagePack 数据
+///
+/// 日期时间、UUID 等类型在编码时已写成字符串，解码后为 [`DataValue::String`]
+#[cfg(feature = "msgpack")]
+pub fn decode_msgpack(bytes: &[u8]) -> QuickDbResult<DataValue> {
+    decode(OutputFormat::MessagePack, bytes)
+}
+
+/// 解码 [`super::DataSerializer::serialize_records`] 输出的 MessagePack 记录列表
+#[cfg(feature = "msgpack")]
+pub fn decode_msgpack_records(bytes: &[u8]) -> QuickDbResult<Vec<HashMap<String, DataValue>>> {
+    into_records(decode_msgpack(bytes)?)
+}
+
+/// 解码 BSON 文档
+///
+/// 日期时间还原为 [`DataValue::DateTime`]（UTC），子类型 4 的 Binary 还原为 [`DataValue::Uuid`]
+#[cfg(feature = "bson-output")]
+pub fn decode_bson(bytes: &[u8]) -> QuickDbResult<DataValue> {
+    decode(OutputFormat::Bson, bytes)
+}
+
+/// 解码 [`super::DataSerializer::serialize_records`] 输出的 BSON 记录列表
+#[cfg(feature = "bson-output")]
+pub fn decode_bson_records(bytes: &[u8]) -> QuickDbResult<Vec<HashMap<String, DataValue>>> {
+    match decode_bson(bytes)? {
+        DataValue::Object(mut doc) => into_records(doc.remove(bson::RECORDS_KEY).unwrap_or(DataValue::Null)),
+        other => into_records(other),
+    }
+}
+
+/// 解码 CBOR 数据
+///
+/// 标签 0 / 1 还原为 [`DataValue::DateTime`]，标签 37 还原为 [`DataValue::Uuid`]
+#[cfg(feature = "cbor")]
+pub fn decode_cbor(bytes: &[u8]) -> QuickDbResult<DataValue> {
+    decode(OutputFormat::Cbor, bytes)
+}
+
+/// 解码 [`super::DataSerializer::serialize_records`] 输出的 CBOR 记录列表
+#[cfg(feature = "cbor")]
+pub fn decode_cbor_records(bytes: &[u8]) -> QuickDbResult<Vec<HashMap<String, DataValue>>> {
+    into_records(decode_cbor(bytes)?)
+}
+
+/// 把解码出的数组拆分为记录列表
+#[cfg(any(feature = "msgpack", feature = "bson-output", feature = "cbor"))]
+pub(crate) fn into_records(value: DataValue) -> QuickDbResult<Vec<HashMap<String, DataValue>>> {
+    let not_object = || QuickDbError::SerializationError {
+        message: crate::i18n::t("serializer.json_not_object"),
+    };
+    let DataValue::Array(items) = value else {
+        return Err(not_object());
+    };
+    items
+        .into_iter()
+        .map(|item| match item {
+            DataValue::Object(record) => Ok(record),
+            _ => Err(not_object()),
+        })
+        .collect()
+}
+
+#[cfg(all(test, any(feature = "msgpack", feature = "bson-output", feature = "cbor")))]
+mod tests {
+    use super::*;
+    #[cfg(any(feature = "bson-output", feature = "cbor"))]
+    use chrono::DateTime;
+
+    #[cfg(feature = "msgpack")]
+    #[test]
+    fn msgpack_round_trips_records_without_type_tags() {
+        crate::i18n::ErrorMessageI18n::init_i18n();
+        let record = HashMap::from([
+            ("name".to_string(), DataValue::String("alice".to_string())),
+            ("age".to_string(), DataValue::Int(30)),
+            ("score".to_string(), DataValue::Float(1.5)),
+            ("avatar".to_string(), DataValue::Bytes(vec![0, 1, 255])),
+            ("tags".to_string(), DataValue::Array(vec![DataValue::Bool(true), DataValue::Null])),
+        ]);
+        let records = DataValue::Array(vec![DataValue::Object(record.clone())]);
+        let bytes = encode(OutputFormat::MessagePack, &records).unwrap();
+
+        assert_eq!(decode_msgpack_records(&bytes).unwrap(), vec![record]);
+        // 标量不带类型标签：fixmap(1) + "n" + 正整数 7
+        let small = DataValue::Object(HashMap::from([("n".to_string(), DataValue::Int(7))]));
+        let bytes = encode(OutputFormat::MessagePack, &small).unwrap();
+        assert_eq!(bytes, vec![0x81, 0xa1, b'n', 0x07]);
+        assert!(decode_msgpack_records(&bytes).is_err());
+        assert!(decode_msgpack(&[0xc1]).is_err());
+    }
+
+    #[cfg(feature = "bson-output")]
+    #[test]
+    fn bson_keeps_datetimes_uuids_and_bytes() {
+        crate::i18n::ErrorMessageI18n::init_i18n();
+        let at = DateTime::parse_from_rfc3339("2024-01-01T08:00:00.123+08:00").unwrap();
+        let id = uuid::Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+        let record = HashMap::from([
+            ("at".to_string(), DataValue::DateTime(at)),
+            ("id".to_string(), DataValue::Uuid(id)),
+            ("blob".to_string(), DataValue::Bytes(vec![9, 8])),
+            ("n".to_string(), DataValue::Int(1)),
+        ]);
+        let records = DataValue::Array(vec![DataValue::Object(record)]);
+
+        let decoded = decode_bson_records(&encode(OutputFormat::Bson, &records).unwrap()).unwrap();
+        assert_eq!(decoded[0]["at"], DataValue::DateTime(bson::to_utc(&at).fixed_offset()));
+        assert_eq!(decoded[0]["id"], DataValue::Uuid(id));
+        assert_eq!(decoded[0]["blob"], DataValue::Bytes(vec![9, 8]));
+        assert_eq!(decoded[0]["n"], DataValue::Int(1));
+
+        // BSON 元素类型：0x09 为 UTC datetime
+        let single = DataValue::Object(HashMap::from([("at".to_string(), DataValue::DateTime(at))]));
+        let bytes = encode(OutputFormat::Bson, &single).unwrap();
+        assert_eq!(bytes[4], 0x09);
+        assert!(decode_bson(&[1, 2, 3]).is_err());
+    }
+
+    #[cfg(feature = "cbor")]
+    #[test]
+    fn cbor_tags_datetimes_and_uuids() {
+        crate::i18n::ErrorMessageI18n::init_i18n();
+        let at = DateTime::parse_from_rfc3339("2024-01-01T08:00:00.123+08:00").unwrap();
+        let id = uuid::Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+        let record = HashMap::from([
+            ("at".to_string(), DataValue::DateTime(at)),
+            ("id".to_string(), DataValue::Uuid(id)),
+            ("blob".to_string(), DataValue::Bytes(vec![9, 8])),
+            ("n".to_string(), DataValue::Int(1)),
+        ]);
+        let records = DataValue::Array(vec![DataValue::Object(record.clone())]);
+
+        let decoded = decode_cbor_records(&encode(OutputFormat::Cbor, &records).unwrap()).unwrap();
+        assert_eq!(decoded, vec![record]);
+
+        // CBOR 日期时间使用标签 0（0xc0），UUID 使用标签 37（0xd8 0x25）
+        assert_eq!(encode(OutputFormat::Cbor, &DataValue::DateTime(at)).unwrap()[0], 0xc0);
+        assert_eq!(&encode(OutputFormat::Cbor, &DataValue::Uuid(id)).unwrap()[..2], &[0xd8, 0x25]);
+    }
+}
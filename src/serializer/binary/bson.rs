@@ -0,0 +1,94 @@
+//! BSON 编解码
+//!
+//! 日期时间编码为 UTC datetime（毫秒精度），UUID 编码为子类型 4 的 Binary；
+//! 顶层必须是文档，记录列表编码为 `{"records": [...]}`
+
+use super::plain_json;
+use crate::types::DataValue;
+use crate::types::data_value::json_value_to_data_value;
+use bson::spec::BinarySubtype;
+use bson::{Binary, Bson, Document};
+use chrono::{DateTime, FixedOffset, Utc};
+use std::collections::HashMap;
+
+/// 记录列表的包装键
+pub(super) const RECORDS_KEY: &str = "records";
+
+pub(super) fn to_utc(dt: &DateTime<FixedOffset>) -> DateTime<Utc> {
+    dt.with_timezone(&Utc)
+}
+
+fn to_bson(value: &DataValue) -> Bson {
+    match value {
+        DataValue::Null => Bson::Null,
+        DataValue::Bool(b) => Bson::Boolean(*b),
+        DataValue::Int(i) => Bson::Int64(*i),
+        DataValue::UInt(u) => match i64::try_from(*u) {
+            Ok(i) => Bson::Int64(i),
+            Err(_) => Bson::String(u.to_string()),
+        },
+        DataValue::Float(f) => Bson::Double(*f),
+        DataValue::String(s) => Bson::String(s.clone()),
+        DataValue::Bytes(bytes) => Bson::Binary(Binary {
+            subtype: BinarySubtype::Generic,
+            bytes: bytes.clone(),
+        }),
+        DataValue::DateTime(dt) => Bson::DateTime(bson::DateTime::from_millis(to_utc(dt).timestamp_millis())),
+        DataValue::DateTimeUTC(dt) => Bson::DateTime(bson::DateTime::from_millis(dt.timestamp_millis())),
+        DataValue::Uuid(uuid) => Bson::Binary(Binary {
+            subtype: BinarySubtype::Uuid,
+            bytes: uuid.as_bytes().to_vec(),
+        }),
+        DataValue::Json(_) => to_bson(&plain_json(value)),
+        DataValue::Array(items) => Bson::Array(items.iter().map(to_bson).collect()),
+        DataValue::Object(map) => {
+            Bson::Document(map.iter().map(|(key, value)| (key.clone(), to_bson(value))).collect())
+        }
+        DataValue::Vector(values) => Bson::Array(values.iter().map(|v| Bson::Double(*v as f64)).collect()),
+    }
+}
+
+fn from_bson(value: Bson) -> DataValue {
+    match value {
+        Bson::Null | Bson::Undefined => DataValue::Null,
+        Bson::Boolean(b) => DataValue::Bool(b),
+        Bson::Int32(i) => DataValue::Int(i as i64),
+        Bson::Int64(i) => DataValue::Int(i),
+        Bson::Double(f) => DataValue::Float(f),
+        Bson::String(s) => DataValue::String(s),
+        Bson::Binary(binary) => match binary.subtype {
+            BinarySubtype::Uuid | BinarySubtype::UuidOld => match uuid::Uuid::from_slice(&binary.bytes) {
+                Ok(uuid) => DataValue::Uuid(uuid),
+                Err(_) => DataValue::Bytes(binary.bytes),
+            },
+            _ => DataValue::Bytes(binary.bytes),
+        },
+        Bson::DateTime(dt) => match DateTime::<Utc>::from_timestamp_millis(dt.timestamp_millis()) {
+            Some(dt) => DataValue::DateTime(dt.fixed_offset()),
+            None => DataValue::Int(dt.timestamp_millis()),
+        },
+        Bson::ObjectId(oid) => DataValue::String(oid.to_hex()),
+        Bson::Array(items) => DataValue::Array(items.into_iter().map(from_bson).collect()),
+        Bson::Document(doc) => DataValue::Object(from_document(doc)),
+        other => json_value_to_data_value(other.into_relaxed_extjson()),
+    }
+}
+
+fn from_document(doc: Document) -> HashMap<String, DataValue> {
+    doc.into_iter().map(|(key, value)| (key, from_bson(value))).collect()
+}
+
+pub(super) fn encode(value: &DataValue) -> Result<Vec<u8>, String> {
+    let doc = match to_bson(value) {
+        Bson::Document(doc) => doc,
+        other => bson::doc! { RECORDS_KEY: other },
+    };
+    let mut buffer = Vec::new();
+    doc.to_writer(&mut buffer).map(|_| buffer).map_err(|e| e.to_string())
+}
+
+pub(super) fn decode(bytes: &[u8]) -> Result<DataValue, String> {
+    Document::from_reader(bytes)
+        .map(|doc| DataValue::Object(from_document(doc)))
+        .map_err(|e| e.to_string())
+}
@@ -0,0 +1,105 @@
+//! CBOR 编解码
+//!
+//! 日期时间编码为标签 0 + RFC 3339 字符串，UUID 编码为标签 37 + 16 字节
+
+use super::plain_json;
+use crate::types::DataValue;
+use chrono::{DateTime, Utc};
+use ciborium::Value as CborValue;
+
+/// CBOR 标签：RFC 3339 日期时间字符串
+const CBOR_TAG_DATETIME: u64 = 0;
+/// CBOR 标签：Unix 时间戳（秒）
+const CBOR_TAG_EPOCH: u64 = 1;
+/// CBOR 标签：UUID
+const CBOR_TAG_UUID: u64 = 37;
+
+fn to_cbor(value: &DataValue) -> CborValue {
+    match value {
+        DataValue::Null => CborValue::Null,
+        DataValue::Bool(b) => CborValue::Bool(*b),
+        DataValue::Int(i) => CborValue::Integer((*i).into()),
+        DataValue::UInt(u) => CborValue::Integer((*u).into()),
+        DataValue::Float(f) => CborValue::Float(*f),
+        DataValue::String(s) => CborValue::Text(s.clone()),
+        DataValue::Bytes(bytes) => CborValue::Bytes(bytes.clone()),
+        DataValue::DateTime(dt) => CborValue::Tag(CBOR_TAG_DATETIME, Box::new(CborValue::Text(dt.to_rfc3339()))),
+        DataValue::DateTimeUTC(dt) => CborValue::Tag(CBOR_TAG_DATETIME, Box::new(CborValue::Text(dt.to_rfc3339()))),
+        DataValue::Uuid(uuid) => CborValue::Tag(CBOR_TAG_UUID, Box::new(CborValue::Bytes(uuid.as_bytes().to_vec()))),
+        DataValue::Json(_) => to_cbor(&plain_json(value)),
+        DataValue::Array(items) => CborValue::Array(items.iter().map(to_cbor).collect()),
+        DataValue::Object(map) => CborValue::Map(
+            map.iter()
+                .map(|(key, value)| (CborValue::Text(key.clone()), to_cbor(value)))
+                .collect(),
+        ),
+        DataValue::Vector(values) => CborValue::Array(values.iter().map(|v| CborValue::Float(*v as f64)).collect()),
+    }
+}
+
+fn from_cbor(value: CborValue) -> DataValue {
+    match value {
+        CborValue::Null => DataValue::Null,
+        CborValue::Bool(b) => DataValue::Bool(b),
+        CborValue::Integer(int) => {
+            let int = i128::from(int);
+            match (i64::try_from(int), u64::try_from(int)) {
+                (Ok(i), _) => DataValue::Int(i),
+                (_, Ok(u)) => DataValue::UInt(u),
+                _ => DataValue::String(int.to_string()),
+            }
+        }
+        CborValue::Float(f) => DataValue::Float(f),
+        CborValue::Text(s) => DataValue::String(s),
+        CborValue::Bytes(bytes) => DataValue::Bytes(bytes),
+        CborValue::Tag(tag, inner) => match (tag, *inner) {
+            (CBOR_TAG_DATETIME, CborValue::Text(text)) => match DateTime::parse_from_rfc3339(&text) {
+                Ok(dt) => DataValue::DateTime(dt),
+                Err(_) => DataValue::String(text),
+            },
+            (CBOR_TAG_EPOCH, inner) => {
+                let seconds = match &inner {
+                    CborValue::Integer(int) => Some(i128::from(*int) as f64),
+                    CborValue::Float(f) => Some(*f),
+                    _ => None,
+                };
+                match seconds.and_then(|s| DateTime::<Utc>::from_timestamp_millis((s * 1000.0) as i64)) {
+                    Some(dt) => DataValue::DateTime(dt.fixed_offset()),
+                    None => from_cbor(inner),
+                }
+            }
+            (CBOR_TAG_UUID, CborValue::Bytes(bytes)) => match uuid::Uuid::from_slice(&bytes) {
+                Ok(uuid) => DataValue::Uuid(uuid),
+                Err(_) => DataValue::Bytes(bytes),
+            },
+            (_, inner) => from_cbor(inner),
+        },
+        CborValue::Array(items) => DataValue::Array(items.into_iter().map(from_cbor).collect()),
+        CborValue::Map(entries) => DataValue::Object(
+            entries
+                .into_iter()
+                .map(|(key, value)| {
+                    let key = match key {
+                        CborValue::Text(key) => key,
+                        other => from_cbor(other).to_string(),
+                    };
+                    (key, from_cbor(value))
+                })
+                .collect(),
+        ),
+        _ => DataValue::Null,
+    }
+}
+
+pub(super) fn encode(value: &DataValue) -> Result<Vec<u8>, String> {
+    let mut buffer = Vec::new();
+    ciborium::into_writer(&to_cbor(value), &mut buffer)
+        .map(|_| buffer)
+        .map_err(|e| e.to_string())
+}
+
+pub(super) fn decode(bytes: &[u8]) -> Result<DataValue, String> {
+    ciborium::from_reader::<CborValue, _>(bytes)
+        .map(from_cbor)
+        .map_err(|e| e.to_string())
+}
@@ -0,0 +1,137 @@
+//! MessagePack 编解码
+//!
+//! 记录按普通的 map / 数组 / 标量编码，不带 `DataValue` 的类型标签
+
+use crate::types::DataValue;
+use serde::de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeMap, SerializeSeq, Serializer};
+use std::collections::HashMap;
+use std::fmt;
+
+/// 以无类型标签的形式序列化 DataValue
+struct PlainValue<'a>(&'a DataValue);
+
+impl Serialize for PlainValue<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.0 {
+            DataValue::Null => serializer.serialize_unit(),
+            DataValue::Bool(b) => serializer.serialize_bool(*b),
+            DataValue::Int(i) => serializer.serialize_i64(*i),
+            DataValue::UInt(u) => serializer.serialize_u64(*u),
+            DataValue::Float(f) => serializer.serialize_f64(*f),
+            DataValue::String(s) => serializer.serialize_str(s),
+            DataValue::Bytes(bytes) => serializer.serialize_bytes(bytes),
+            DataValue::DateTime(dt) => serializer.serialize_str(&dt.to_rfc3339()),
+            DataValue::DateTimeUTC(dt) => serializer.serialize_str(&dt.to_rfc3339()),
+            DataValue::Uuid(uuid) => serializer.serialize_str(&uuid.to_string()),
+            DataValue::Json(_) => self.0.to_json_value().serialize(serializer),
+            DataValue::Array(items) => {
+                let mut seq = serializer.serialize_seq(Some(items.len()))?;
+                for item in items {
+                    seq.serialize_element(&PlainValue(item))?;
+                }
+                seq.end()
+            }
+            DataValue::Object(map) => {
+                let mut ser_map = serializer.serialize_map(Some(map.len()))?;
+                for (key, value) in map {
+                    ser_map.serialize_entry(key, &PlainValue(value))?;
+                }
+                ser_map.end()
+            }
+            DataValue::Vector(values) => values.serialize(serializer),
+        }
+    }
+}
+
+/// 从无类型标签的二进制数据还原 DataValue
+struct DecodedValue(DataValue);
+
+impl<'de> Deserialize<'de> for DecodedValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(DecodedValueVisitor)
+    }
+}
+
+struct DecodedValueVisitor;
+
+impl<'de> Visitor<'de> for DecodedValueVisitor {
+    type Value = DecodedValue;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a scalar, array or map")
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<DecodedValue, E> {
+        Ok(DecodedValue(DataValue::Null))
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<DecodedValue, E> {
+        Ok(DecodedValue(DataValue::Null))
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<DecodedValue, D::Error> {
+        DecodedValue::deserialize(deserializer)
+    }
+
+    fn visit_bool<E: de::Error>(self, value: bool) -> Result<DecodedValue, E> {
+        Ok(DecodedValue(DataValue::Bool(value)))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<DecodedValue, E> {
+        Ok(DecodedValue(DataValue::Int(value)))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<DecodedValue, E> {
+        Ok(DecodedValue(match i64::try_from(value) {
+            Ok(value) => DataValue::Int(value),
+            Err(_) => DataValue::UInt(value),
+        }))
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<DecodedValue, E> {
+        Ok(DecodedValue(DataValue::Float(value)))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<DecodedValue, E> {
+        Ok(DecodedValue(DataValue::String(value.to_string())))
+    }
+
+    fn visit_string<E: de::Error>(self, value: String) -> Result<DecodedValue, E> {
+        Ok(DecodedValue(DataValue::String(value)))
+    }
+
+    fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<DecodedValue, E> {
+        Ok(DecodedValue(DataValue::Bytes(value.to_vec())))
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, value: Vec<u8>) -> Result<DecodedValue, E> {
+        Ok(DecodedValue(DataValue::Bytes(value)))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<DecodedValue, A::Error> {
+        let mut items = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(DecodedValue(item)) = seq.next_element()? {
+            items.push(item);
+        }
+        Ok(DecodedValue(DataValue::Array(items)))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<DecodedValue, A::Error> {
+        let mut object = HashMap::with_capacity(map.size_hint().unwrap_or(0));
+        while let Some((key, DecodedValue(value))) = map.next_entry::<String, DecodedValue>()? {
+            object.insert(key, value);
+        }
+        Ok(DecodedValue(DataValue::Object(object)))
+    }
+}
+
+pub(super) fn encode(value: &DataValue) -> Result<Vec<u8>, String> {
+    rmp_serde::to_vec_named(&PlainValue(value)).map_err(|e| e.to_string())
+}
+
+pub(super) fn decode(bytes: &[u8]) -> Result<DataValue, String> {
+    rmp_serde::from_slice::<DecodedValue>(bytes)
+        .map(|DecodedValue(value)| value)
+        .map_err(|e| e.to_string())
+}
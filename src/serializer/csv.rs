@@ -0,0 +1,601 @@
+//! CSV导入导出
+//!
+//! 导出时按表（或查询结果）逐条写出 CSV，按值类型格式化；
+//! 导入时按列映射转换每个单元格，分批插入，并收集每一行的错误而不中断导入
+
+use super::transfer::{
+    ImportReport, ImportRowError, fetch_page, insert_batch, model_meta, parse_typed_text, record_columns,
+};
+use crate::error::{QuickDbError, QuickDbResult};
+use crate::model::FieldType;
+use crate::types::*;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use rat_logger::{debug, info};
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read, Write};
+
+/// CSV 选项
+#[derive(Debug, Clone)]
+pub struct CsvOptions {
+    /// 分隔符
+    pub delimiter: char,
+    /// 是否有表头（导出时写出，导入时读取）
+    pub has_header: bool,
+    /// 空值的文本表示（导入时空单元格同样视为空值）
+    pub null_value: String,
+    /// 日期时间格式（默认 RFC 3339）
+    pub datetime_format: Option<String>,
+    /// 浮点数小数位数
+    pub float_precision: Option<usize>,
+    /// 导出的列（默认取模型字段，无模型时取首批记录的字段）
+    pub columns: Option<Vec<String>>,
+    /// 导出分页大小 / 导入每批插入的行数
+    pub batch_size: usize,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: ',',
+            has_header: true,
+            null_value: String::new(),
+            datetime_format: None,
+            float_precision: None,
+            columns: None,
+            batch_size: 500,
+        }
+    }
+}
+
+impl CsvOptions {
+    /// 创建默认 CSV 选项
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 设置分隔符
+    pub fn delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// 设置是否有表头
+    pub fn has_header(mut self, has_header: bool) -> Self {
+        self.has_header = has_header;
+        self
+    }
+
+    /// 设置空值的文本表示
+    pub fn null_value(mut self, null_value: &str) -> Self {
+        self.null_value = null_value.to_string();
+        self
+    }
+
+    /// 设置日期时间格式
+    pub fn datetime_format(mut self, format: &str) -> Self {
+        self.datetime_format = Some(format.to_string());
+        self
+    }
+
+    /// 设置浮点数精度
+    pub fn float_precision(mut self, precision: usize) -> Self {
+        self.float_precision = Some(precision);
+        self
+    }
+
+    /// 设置导出的列
+    pub fn columns(mut self, columns: Vec<String>) -> Self {
+        self.columns = Some(columns);
+        self
+    }
+
+    /// 设置分批大小（最小为 1）
+    pub fn batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+}
+
+/// CSV 写出器
+pub struct CsvWriter<W: Write> {
+    writer: W,
+    columns: Vec<String>,
+    options: CsvOptions,
+    rows: usize,
+}
+
+impl<W: Write> CsvWriter<W> {
+    /// 创建写出器，启用表头时立即写出表头
+    pub fn new(writer: W, columns: Vec<String>, options: CsvOptions) -> QuickDbResult<Self> {
+        let mut csv = Self {
+            writer,
+            columns,
+            options,
+            rows: 0,
+        };
+        if csv.options.has_header {
+            let header: Vec<String> = csv.columns.clone();
+            csv.write_row(&header)?;
+        }
+        Ok(csv)
+    }
+
+    /// 写出一条记录，缺少的列按空值写出
+    pub fn write_record(&mut self, record: &HashMap<String, DataValue>) -> QuickDbResult<()> {
+        let cells: Vec<String> = self
+            .columns
+            .iter()
+            .map(|column| match record.get(column) {
+                Some(value) => format_value(value, &self.options),
+                None => self.options.null_value.clone(),
+            })
+            .collect();
+        self.write_row(&cells)?;
+        self.rows += 1;
+        Ok(())
+    }
+
+    /// 已写出的记录数（不含表头）
+    pub fn rows_written(&self) -> usize {
+        self.rows
+    }
+
+    /// 刷新并取回底层写入器
+    pub fn into_inner(mut self) -> QuickDbResult<W> {
+        self.writer.flush().map_err(QuickDbError::IoError)?;
+        Ok(self.writer)
+    }
+
+    fn write_row(&mut self, cells: &[String]) -> QuickDbResult<()> {
+        let mut line = String::new();
+        for (index, cell) in cells.iter().enumerate() {
+            if index > 0 {
+                line.push(self.options.delimiter);
+            }
+            line.push_str(&escape_cell(cell, self.options.delimiter));
+        }
+        line.push_str("\r\n");
+        self.writer.write_all(line.as_bytes()).map_err(QuickDbError::IoError)
+    }
+}
+
+/// 把查询结果写出为 CSV，返回写出的记录数
+///
+/// 未指定列时取所有记录字段的并集（`id` 在前，其余按名称排序）
+pub fn records_to_csv<W: Write>(records: &[DataValue], writer: W, options: &CsvOptions) -> QuickDbResult<usize> {
+    let rows: Vec<&HashMap<String, DataValue>> = records
+        .iter()
+        .filter_map(|record| match record {
+            DataValue::Object(map) => Some(map),
+            _ => None,
+        })
+        .collect();
+    let columns = match &options.columns {
+        Some(columns) => columns.clone(),
+        None => record_columns(rows.iter().copied()),
+    };
+
+    let mut csv = CsvWriter::new(writer, columns, options.clone())?;
+    for row in rows {
+        csv.write_record(row)?;
+    }
+    let count = csv.rows_written();
+    csv.into_inner()?;
+    Ok(count)
+}
+
+/// 按条件分页读取表数据并写出为 CSV，返回写出的记录数
+///
+/// 按 `id` 升序分页，每页 `batch_size` 条，不会一次性把整张表读入内存
+pub async fn export_table_csv<W: Write>(
+    table: &str,
+    conditions: Vec<QueryConditionWithConfig>,
+    writer: W,
+    options: &CsvOptions,
+    alias: Option<&str>,
+) -> QuickDbResult<usize> {
+    let odm = crate::odm::get_odm_manager().await;
+    let batch_size = options.batch_size.max(1) as u64;
+    let columns = match &options.columns {
+        Some(columns) => Some(columns.clone()),
+        None => model_meta(table, alias).await.map(|meta| record_columns([&meta.fields])),
+    };
+
+    let mut skip = 0;
+    let mut page = fetch_page(&odm, table, &conditions, skip, batch_size, alias).await?;
+    let columns = columns.unwrap_or_else(|| record_columns(page.iter()));
+    let mut csv = CsvWriter::new(writer, columns, options.clone())?;
+    loop {
+        for row in &page {
+            csv.write_record(row)?;
+        }
+        debug!("导出CSV分页: 表={}, 跳过={}, 记录数={}", table, skip, page.len());
+        if (page.len() as u64) < batch_size {
+            break;
+        }
+        skip += batch_size;
+        page = fetch_page(&odm, table, &conditions, skip, batch_size, alias).await?;
+    }
+
+    let count = csv.rows_written();
+    csv.into_inner()?;
+    info!("导出CSV完成: 表={}, 记录数={}", table, count);
+    Ok(count)
+}
+
+/// CSV 列映射中的一列
+#[derive(Debug, Clone)]
+pub struct CsvColumn {
+    /// CSV 列名（无表头时忽略，按映射顺序对应 CSV 列）
+    pub source: String,
+    /// 目标字段名
+    pub field: String,
+    /// 目标字段类型（未指定时使用模型字段类型，无模型时按内容推断）
+    pub field_type: Option<FieldType>,
+}
+
+/// CSV 导入的列映射
+///
+/// 没有任何映射列时，按表头列名直接作为字段名导入
+#[derive(Debug, Clone, Default)]
+pub struct CsvMapping {
+    columns: Vec<CsvColumn>,
+}
+
+impl CsvMapping {
+    /// 创建空映射（表头列名即字段名）
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 把 CSV 列映射到字段
+    pub fn column(mut self, source: &str, field: &str) -> Self {
+        self.columns.push(CsvColumn {
+            source: source.to_string(),
+            field: field.to_string(),
+            field_type: None,
+        });
+        self
+    }
+
+    /// 把 CSV 列按指定类型映射到字段
+    pub fn typed_column(mut self, source: &str, field: &str, field_type: FieldType) -> Self {
+        self.columns.push(CsvColumn {
+            source: source.to_string(),
+            field: field.to_string(),
+            field_type: Some(field_type),
+        });
+        self
+    }
+
+    /// 映射的列
+    pub fn columns(&self) -> &[CsvColumn] {
+        &self.columns
+    }
+}
+
+/// 从 CSV 导入数据到表
+///
+/// 每 `batch_size` 行并发插入一批；单元格转换失败或插入失败的行记录到
+/// [`ImportReport::errors`] 后继续导入，只有 CSV 本身无法解析或映射列
+/// 不存在时才返回错误
+pub async fn import_csv<R: Read>(
+    table: &str,
+    reader: R,
+    mapping: &CsvMapping,
+    options: &CsvOptions,
+    alias: Option<&str>,
+) -> QuickDbResult<ImportReport> {
+    let mut records = CsvRecords::new(reader, options.delimiter);
+    let meta = model_meta(table, alias).await;
+
+    let header = if options.has_header {
+        records.next_record()?.unwrap_or_default()
+    } else {
+        Vec::new()
+    };
+    let plan = import_plan(&header, mapping, meta.as_ref(), options.has_header)?;
+
+    let odm = crate::odm::get_odm_manager().await;
+    let batch_size = options.batch_size.max(1);
+    let mut report = ImportReport::default();
+    let mut batch: Vec<(usize, HashMap<String, DataValue>)> = Vec::with_capacity(batch_size);
+    let mut row = 0;
+    while let Some(cells) = records.next_record()? {
+        row += 1;
+        match convert_row(&cells, &plan, options) {
+            Ok(data) => batch.push((row, data)),
+            Err(message) => report.errors.push(ImportRowError { row, message }),
+        }
+        if batch.len() >= batch_size {
+            insert_batch(&odm, table, std::mem::take(&mut batch), alias, &mut report).await;
+        }
+    }
+    insert_batch(&odm, table, batch, alias, &mut report).await;
+
+    report.errors.sort_by_key(|error| error.row);
+    info!(
+        "导入CSV完成: 表={}, 插入={}, 失败={}",
+        table,
+        report.inserted,
+        report.errors.len()
+    );
+    Ok(report)
+}
+
+/// 导入时一列的处理方式
+struct PlannedColumn {
+    /// CSV 列序号
+    index: usize,
+    field: String,
+    field_type: Option<FieldType>,
+}
+
+fn import_plan(
+    header: &[String],
+    mapping: &CsvMapping,
+    meta: Option<&crate::model::ModelMeta>,
+    has_header: bool,
+) -> QuickDbResult<Vec<PlannedColumn>> {
+    let meta_type = |field: &str| meta.and_then(|meta| meta.fields.get(field)).map(|def| def.field_type.clone());
+
+    if mapping.columns.is_empty() {
+        return Ok(header
+            .iter()
+            .enumerate()
+            .map(|(index, name)| PlannedColumn {
+                index,
+                field: name.clone(),
+                field_type: meta_type(name),
+            })
+            .collect());
+    }
+
+    mapping
+        .columns
+        .iter()
+        .enumerate()
+        .map(|(position, column)| {
+            let index = if has_header {
+                header.iter().position(|name| name == &column.source).ok_or_else(|| {
+                    QuickDbError::SerializationError {
+                        message: crate::i18n::tf("serializer.csv_column_not_found", &[("column", &column.source)]),
+                    }
+                })?
+            } else {
+                position
+            };
+            Ok(PlannedColumn {
+                index,
+                field: column.field.clone(),
+                field_type: column.field_type.clone().or_else(|| meta_type(&column.field)),
+            })
+        })
+        .collect()
+}
+
+fn convert_row(
+    cells: &[String],
+    plan: &[PlannedColumn],
+    options: &CsvOptions,
+) -> Result<HashMap<String, DataValue>, String> {
+    let mut data = HashMap::with_capacity(plan.len());
+    for column in plan {
+        let Some(cell) = cells.get(column.index) else {
+            return Err(crate::i18n::tf(
+                "serializer.csv_column_missing",
+                &[("column", &column.field), ("count", &cells.len().to_string())],
+            ));
+        };
+        let value = parse_cell(cell, column.field_type.as_ref(), options).ok_or_else(|| {
+            crate::i18n::tf("serializer.csv_value_invalid", &[("column", &column.field), ("value", cell)])
+        })?;
+        data.insert(column.field.clone(), value);
+    }
+    Ok(data)
+}
+
+/// 按字段类型解析单元格，无法转换时返回 `None`
+fn parse_cell(cell: &str, field_type: Option<&FieldType>, options: &CsvOptions) -> Option<DataValue> {
+    if cell.is_empty() || cell == options.null_value {
+        return Some(DataValue::Null);
+    }
+    let Some(field_type) = field_type else {
+        return Some(infer_cell(cell));
+    };
+
+    parse_typed_text(cell, field_type, options.datetime_format.as_deref())
+}
+
+/// 无类型信息时按内容推断：布尔、整数、浮点数，其余为字符串
+fn infer_cell(cell: &str) -> DataValue {
+    match cell {
+        "true" => return DataValue::Bool(true),
+        "false" => return DataValue::Bool(false),
+        _ => {}
+    }
+    if let Ok(int) = cell.parse::<i64>() {
+        return DataValue::Int(int);
+    }
+    if let Some(float) = cell.parse::<f64>().ok().filter(|float| float.is_finite()) {
+        return DataValue::Float(float);
+    }
+    DataValue::String(cell.to_string())
+}
+
+/// 按值类型格式化单元格
+fn format_value(value: &DataValue, options: &CsvOptions) -> String {
+    match value {
+        DataValue::Null => options.null_value.clone(),
+        DataValue::Bool(b) => b.to_string(),
+        DataValue::Int(i) => i.to_string(),
+        DataValue::UInt(u) => u.to_string(),
+        DataValue::Float(f) => match options.float_precision {
+            Some(precision) => format!("{:.*}", precision, f),
+            None => f.to_string(),
+        },
+        DataValue::String(s) => s.clone(),
+        DataValue::Bytes(bytes) => STANDARD.encode(bytes),
+        DataValue::DateTime(dt) => match &options.datetime_format {
+            Some(format) => dt.format(format).to_string(),
+            None => dt.to_rfc3339(),
+        },
+        DataValue::DateTimeUTC(dt) => match &options.datetime_format {
+            Some(format) => dt.format(format).to_string(),
+            None => dt.to_rfc3339(),
+        },
+        DataValue::Uuid(uuid) => uuid.to_string(),
+        DataValue::Json(json) => json.to_string(),
+        DataValue::Array(_) | DataValue::Object(_) | DataValue::Vector(_) => value.to_json_value().to_string(),
+    }
+}
+
+/// 含分隔符、引号或换行的单元格加引号，内部引号加倍
+fn escape_cell(cell: &str, delimiter: char) -> String {
+    if cell.contains(delimiter) || cell.contains('"') || cell.contains('\n') || cell.contains('\r') {
+        format!("\"{}\"", cell.replace('"', "\"\""))
+    } else {
+        cell.to_string()
+    }
+}
+
+/// 逐条读取 CSV 记录（支持引号内的分隔符与换行）
+struct CsvRecords<R: Read> {
+    reader: BufReader<R>,
+    delimiter: char,
+    line: String,
+}
+
+impl<R: Read> CsvRecords<R> {
+    fn new(reader: R, delimiter: char) -> Self {
+        Self {
+            reader: BufReader::new(reader),
+            delimiter,
+            line: String::new(),
+        }
+    }
+
+    /// 读取下一条记录，跳过空行，读完时返回 `None`
+    fn next_record(&mut self) -> QuickDbResult<Option<Vec<String>>> {
+        loop {
+            let Some(record) = self.read_record()? else {
+                return Ok(None);
+            };
+            if !(record.len() == 1 && record[0].is_empty()) {
+                return Ok(Some(record));
+            }
+        }
+    }
+
+    fn read_record(&mut self) -> QuickDbResult<Option<Vec<String>>> {
+        let mut fields = Vec::new();
+        let mut field = String::new();
+        let mut in_quotes = false;
+        let mut started = false;
+
+        loop {
+            self.line.clear();
+            if self.reader.read_line(&mut self.line).map_err(QuickDbError::IoError)? == 0 {
+                if in_quotes {
+                    return Err(QuickDbError::SerializationError {
+                        message: crate::i18n::t("serializer.csv_unterminated_quote"),
+                    });
+                }
+                if !started {
+                    return Ok(None);
+                }
+                fields.push(field);
+                return Ok(Some(fields));
+            }
+            started = true;
+
+            let mut chars = self.line.chars().peekable();
+            while let Some(c) = chars.next() {
+                if in_quotes {
+                    if c == '"' {
+                        if chars.peek() == Some(&'"') {
+                            chars.next();
+                            field.push('"');
+                        } else {
+                            in_quotes = false;
+                        }
+                    } else {
+                        field.push(c);
+                    }
+                } else if c == '"' && field.is_empty() {
+                    in_quotes = true;
+                } else if c == self.delimiter {
+                    fields.push(std::mem::take(&mut field));
+                } else if c == '\n' || (c == '\r' && chars.peek() == Some(&'\n')) {
+                    chars.next();
+                    fields.push(field);
+                    return Ok(Some(fields));
+                } else {
+                    field.push(c);
+                }
+            }
+            if !in_quotes {
+                // 文件末尾没有换行的最后一行
+                fields.push(field);
+                return Ok(Some(fields));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn writes_and_reads_back_escaped_cells() {
+        let mut record = HashMap::new();
+        record.insert("id".to_string(), DataValue::Int(1));
+        record.insert("name".to_string(), DataValue::String("a, \"b\"\nc".to_string()));
+        record.insert("score".to_string(), DataValue::Float(1.5));
+        record.insert("tags".to_string(), DataValue::Array(vec![DataValue::String("x".to_string())]));
+        let records = vec![DataValue::Object(record), DataValue::Object(HashMap::new())];
+
+        let mut output = Vec::new();
+        let options = CsvOptions::new().float_precision(2).null_value("NULL");
+        assert_eq!(records_to_csv(&records, &mut output, &options).unwrap(), 2);
+        let text = String::from_utf8(output).unwrap();
+        assert_eq!(
+            text,
+            "id,name,score,tags\r\n1,\"a, \"\"b\"\"\nc\",1.50,\"[\"\"x\"\"]\"\r\nNULL,NULL,NULL,NULL\r\n"
+        );
+
+        let mut reader = CsvRecords::new(text.as_bytes(), ',');
+        assert_eq!(reader.next_record().unwrap().unwrap(), vec!["id", "name", "score", "tags"]);
+        assert_eq!(reader.next_record().unwrap().unwrap(), vec!["1", "a, \"b\"\nc", "1.50", "[\"x\"]"]);
+        assert_eq!(reader.next_record().unwrap().unwrap(), vec!["NULL"; 4]);
+        assert!(reader.next_record().unwrap().is_none());
+        assert!(CsvRecords::new("\"open".as_bytes(), ',').next_record().is_err());
+    }
+
+    #[test]
+    fn converts_cells_by_mapping_types() {
+        crate::i18n::ErrorMessageI18n::init_i18n();
+        let header: Vec<String> = ["user", "age", "active"].iter().map(|s| s.to_string()).collect();
+        let mapping = CsvMapping::new()
+            .column("user", "name")
+            .typed_column("age", "age", FieldType::Integer { min_value: None, max_value: None })
+            .typed_column("active", "active", FieldType::Boolean);
+        let plan = import_plan(&header, &mapping, None, true).unwrap();
+        let options = CsvOptions::new();
+
+        let row = convert_row(&["alice".into(), "30".into(), "yes".into()], &plan, &options).unwrap();
+        assert_eq!(row["name"], DataValue::String("alice".to_string()));
+        assert_eq!(row["age"], DataValue::Int(30));
+        assert_eq!(row["active"], DataValue::Bool(true));
+        assert_eq!(convert_row(&["bob".into(), "".into(), "0".into()], &plan, &options).unwrap()["age"], DataValue::Null);
+        assert!(convert_row(&["carol".into(), "old".into(), "true".into()], &plan, &options).is_err());
+        assert!(convert_row(&["dave".into()], &plan, &options).is_err());
+
+        assert!(import_plan(&header, &CsvMapping::new().column("missing", "x"), None, true).is_err());
+        assert_eq!(infer_cell("12"), DataValue::Int(12));
+        assert_eq!(infer_cell("1.5"), DataValue::Float(1.5));
+        assert_eq!(infer_cell("nan"), DataValue::String("nan".to_string()));
+    }
+}
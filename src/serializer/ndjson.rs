@@ -0,0 +1,192 @@
+//! JSON Lines（NDJSON）导入导出
+//!
+//! 每行一条 JSON 对象。导出按页读取并等待写入完成后再读取下一页，
+//! 导入每批插入完成后再继续读取，内存中最多保留一页（一批）数据，
+//! 可用于在不同环境之间通过 ODM 转储与重新加载大表
+
+use super::transfer::{ImportReport, ImportRowError, coerce_value, fetch_page, insert_batch, model_meta};
+use crate::error::{QuickDbError, QuickDbResult};
+use crate::model::{FieldType, ModelMeta};
+use crate::types::data_value::json_value_to_data_value;
+use crate::types::*;
+use rat_logger::{debug, info};
+use std::collections::HashMap;
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncWrite, AsyncWriteExt};
+
+/// NDJSON 选项
+#[derive(Debug, Clone)]
+pub struct NdjsonOptions {
+    /// 导出时的过滤条件
+    pub conditions: Vec<QueryConditionWithConfig>,
+    /// 导出分页大小 / 导入每批插入的行数
+    pub batch_size: usize,
+}
+
+impl Default for NdjsonOptions {
+    fn default() -> Self {
+        Self {
+            conditions: Vec::new(),
+            batch_size: 1000,
+        }
+    }
+}
+
+impl NdjsonOptions {
+    /// 创建默认 NDJSON 选项
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 设置导出的过滤条件
+    pub fn conditions(mut self, conditions: Vec<QueryConditionWithConfig>) -> Self {
+        self.conditions = conditions;
+        self
+    }
+
+    /// 设置分批大小（最小为 1）
+    pub fn batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+}
+
+/// 把表数据导出为 NDJSON，返回写出的记录数
+///
+/// 按 `id` 升序分页读取，每页写入完成后才读取下一页
+pub async fn export_ndjson<W: AsyncWrite + Unpin>(
+    alias: &str,
+    table: &str,
+    mut writer: W,
+    options: &NdjsonOptions,
+) -> QuickDbResult<usize> {
+    let odm = crate::odm::get_odm_manager().await;
+    let batch_size = options.batch_size.max(1) as u64;
+    let mut skip = 0;
+    let mut count = 0;
+    loop {
+        let page = fetch_page(&odm, table, &options.conditions, skip, batch_size, Some(alias)).await?;
+        let mut buffer = Vec::new();
+        for record in &page {
+            let json = DataValue::Object(record.clone()).to_json_value();
+            serde_json::to_writer(&mut buffer, &json).map_err(|e| QuickDbError::SerializationError {
+                message: crate::i18n::tf("serializer.serialize_to_json_failed", &[("message", &e.to_string())]),
+            })?;
+            buffer.push(b'\n');
+        }
+        writer.write_all(&buffer).await.map_err(QuickDbError::IoError)?;
+        count += page.len();
+        debug!("导出NDJSON分页: 表={}, 跳过={}, 记录数={}", table, skip, page.len());
+
+        if (page.len() as u64) < batch_size {
+            break;
+        }
+        skip += batch_size;
+    }
+    writer.flush().await.map_err(QuickDbError::IoError)?;
+    info!("导出NDJSON完成: 别名={}, 表={}, 记录数={}", alias, table, count);
+    Ok(count)
+}
+
+/// 从 NDJSON 导入数据到表
+///
+/// 空行跳过；无法解析的行、字段值无法还原的行与插入失败的行记录到
+/// [`ImportReport::errors`]（行号为文件行号）后继续导入。表已注册模型时，
+/// 导出时写成字符串的日期时间、UUID、字节与向量按字段类型还原
+pub async fn import_ndjson<R: AsyncBufRead + Unpin>(
+    alias: &str,
+    table: &str,
+    reader: R,
+    options: &NdjsonOptions,
+) -> QuickDbResult<ImportReport> {
+    let meta = model_meta(table, Some(alias)).await;
+    let odm = crate::odm::get_odm_manager().await;
+    let batch_size = options.batch_size.max(1);
+    let mut report = ImportReport::default();
+    let mut batch: Vec<(usize, HashMap<String, DataValue>)> = Vec::with_capacity(batch_size);
+
+    let mut lines = reader.lines();
+    let mut row = 0;
+    while let Some(line) = lines.next_line().await.map_err(QuickDbError::IoError)? {
+        row += 1;
+        if line.trim().is_empty() {
+            continue;
+        }
+        match parse_line(&line, meta.as_ref()) {
+            Ok(data) => batch.push((row, data)),
+            Err(message) => report.errors.push(ImportRowError { row, message }),
+        }
+        if batch.len() >= batch_size {
+            insert_batch(&odm, table, std::mem::take(&mut batch), Some(alias), &mut report).await;
+        }
+    }
+    insert_batch(&odm, table, batch, Some(alias), &mut report).await;
+
+    report.errors.sort_by_key(|error| error.row);
+    info!(
+        "导入NDJSON完成: 别名={}, 表={}, 插入={}, 失败={}",
+        alias,
+        table,
+        report.inserted,
+        report.errors.len()
+    );
+    Ok(report)
+}
+
+/// 解析一行 NDJSON 为记录
+fn parse_line(line: &str, meta: Option<&ModelMeta>) -> Result<HashMap<String, DataValue>, String> {
+    let json: serde_json::Value = serde_json::from_str(line)
+        .map_err(|e| crate::i18n::tf("serializer.ndjson_line_invalid", &[("message", &e.to_string())]))?;
+    let serde_json::Value::Object(object) = json else {
+        return Err(crate::i18n::t("serializer.json_not_object"));
+    };
+
+    let mut data = HashMap::with_capacity(object.len());
+    for (field, value) in object {
+        let field_type = meta.and_then(|meta| meta.fields.get(&field)).map(|def| &def.field_type);
+        let value = restore_value(value, field_type).ok_or_else(|| {
+            crate::i18n::tf("serializer.ndjson_value_invalid", &[("field", &field)])
+        })?;
+        data.insert(field, value);
+    }
+    Ok(data)
+}
+
+/// 按字段类型还原导出时被写成 JSON 字符串或数组的值
+fn restore_value(value: serde_json::Value, field_type: Option<&FieldType>) -> Option<DataValue> {
+    let value = json_value_to_data_value(value);
+    match field_type {
+        Some(field_type) => coerce_value(value, field_type),
+        None => Some(value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{FieldDefinition, datetime_field, string_field};
+
+    #[test]
+    fn restores_typed_values_from_lines() {
+        crate::i18n::ErrorMessageI18n::init_i18n();
+        let meta = ModelMeta {
+            collection_name: "events".to_string(),
+            fields: HashMap::from([
+                ("name".to_string(), string_field(None, None, None)),
+                ("at".to_string(), datetime_field()),
+                ("blob".to_string(), FieldDefinition::new(FieldType::Binary)),
+            ]),
+            ..Default::default()
+        };
+
+        let line = r#"{"name":"2024-01-01T00:00:00+00:00","at":"2024-01-01T00:00:00+08:00","blob":"AQI=","n":3}"#;
+        let data = parse_line(line, Some(&meta)).unwrap();
+        assert_eq!(data["name"], DataValue::String("2024-01-01T00:00:00+00:00".to_string()));
+        assert!(matches!(data["at"], DataValue::DateTime(_)));
+        assert_eq!(data["blob"], DataValue::Bytes(vec![1, 2]));
+        assert_eq!(data["n"], DataValue::Int(3));
+
+        assert!(parse_line(r#"{"at":"yesterday"}"#, Some(&meta)).is_err());
+        assert!(parse_line("[1, 2]", None).is_err());
+        assert!(parse_line("{oops", None).is_err());
+    }
+}
@@ -0,0 +1,379 @@
+//! Parquet 导出
+//!
+//! 按页读取表数据，把 DataValue 行按列映射转换为 Arrow 记录批次后写入 Parquet 文件，
+//! 供分析工具直接读取（需要启用 `parquet` 特性）
+
+use super::transfer::fetch_page;
+use crate::error::{QuickDbError, QuickDbResult};
+use crate::manager::get_global_pool_manager;
+use crate::model::{FieldType, ModelMeta};
+use crate::types::*;
+use arrow_array::builder::{
+    BinaryBuilder, BooleanBuilder, Float64Builder, Int64Builder, StringBuilder, TimestampMillisecondBuilder,
+};
+use arrow_array::{ArrayRef, RecordBatch};
+use arrow_schema::{DataType, Field, Schema, TimeUnit};
+use parquet::arrow::ArrowWriter;
+use parquet::basic::Compression;
+use parquet::file::properties::WriterProperties;
+use rat_logger::{debug, info};
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::Path;
+use std::sync::Arc;
+
+/// Parquet 列类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParquetColumnType {
+    /// 布尔值
+    Boolean,
+    /// 64 位整数
+    Int64,
+    /// 64 位浮点数
+    Float64,
+    /// UTF-8 字符串（数组、对象等复杂值写为 JSON 文本）
+    Utf8,
+    /// 二进制
+    Binary,
+    /// UTC 毫秒时间戳
+    Timestamp,
+}
+
+impl ParquetColumnType {
+    /// 字段类型对应的列类型
+    pub fn from_field_type(field_type: &FieldType) -> Self {
+        match field_type {
+            FieldType::Integer { .. } | FieldType::BigInteger | FieldType::Sequence { .. } => Self::Int64,
+            FieldType::Float { .. } | FieldType::Double | FieldType::Decimal { .. } => Self::Float64,
+            FieldType::Boolean => Self::Boolean,
+            FieldType::DateTime | FieldType::DateTimeWithTz { .. } => Self::Timestamp,
+            FieldType::Binary => Self::Binary,
+            _ => Self::Utf8,
+        }
+    }
+
+    fn arrow_type(&self) -> DataType {
+        match self {
+            Self::Boolean => DataType::Boolean,
+            Self::Int64 => DataType::Int64,
+            Self::Float64 => DataType::Float64,
+            Self::Utf8 => DataType::Utf8,
+            Self::Binary => DataType::Binary,
+            Self::Timestamp => DataType::Timestamp(TimeUnit::Millisecond, Some("UTC".into())),
+        }
+    }
+}
+
+/// Parquet 列映射
+///
+/// 没有任何列时按表在别名下注册的模型生成（`id` 在前，其余按字段名排序）
+#[derive(Debug, Clone)]
+pub struct ParquetSchemaMapping {
+    columns: Vec<(String, ParquetColumnType)>,
+    batch_size: usize,
+    compression: Compression,
+}
+
+impl Default for ParquetSchemaMapping {
+    fn default() -> Self {
+        Self {
+            columns: Vec::new(),
+            batch_size: 10000,
+            compression: Compression::SNAPPY,
+        }
+    }
+}
+
+impl ParquetSchemaMapping {
+    /// 创建空映射（按注册的模型生成列）
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 按模型元数据生成列映射
+    pub fn from_meta(meta: &ModelMeta) -> Self {
+        let mut fields: Vec<(&String, &FieldType)> =
+            meta.fields.iter().map(|(name, def)| (name, &def.field_type)).collect();
+        fields.sort_by(|(a, _), (b, _)| (*a != "id").cmp(&(*b != "id")).then_with(|| a.cmp(b)));
+
+        let mut mapping = Self::new();
+        for (name, field_type) in fields {
+            mapping = mapping.column(name, ParquetColumnType::from_field_type(field_type));
+        }
+        mapping
+    }
+
+    /// 添加列
+    pub fn column(mut self, field: &str, column_type: ParquetColumnType) -> Self {
+        self.columns.push((field.to_string(), column_type));
+        self
+    }
+
+    /// 设置每个记录批次的行数（也是读取表数据的分页大小，最小为 1）
+    pub fn batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// 不压缩（默认 Snappy）
+    pub fn uncompressed(mut self) -> Self {
+        self.compression = Compression::UNCOMPRESSED;
+        self
+    }
+
+    /// 映射的列
+    pub fn columns(&self) -> &[(String, ParquetColumnType)] {
+        &self.columns
+    }
+
+    fn schema(&self) -> Arc<Schema> {
+        let fields: Vec<Field> = self
+            .columns
+            .iter()
+            .map(|(name, column_type)| Field::new(name, column_type.arrow_type(), true))
+            .collect();
+        Arc::new(Schema::new(fields))
+    }
+}
+
+/// 把表数据导出为 Parquet 文件，返回写出的记录数
+///
+/// 按 `id` 升序分页读取，每页转换为一个 Arrow 记录批次；值与列类型不匹配时返回错误
+pub async fn export_parquet<P: AsRef<Path>>(
+    alias: &str,
+    table: &str,
+    path: P,
+    schema_mapping: &ParquetSchemaMapping,
+) -> QuickDbResult<usize> {
+    let mapping = if schema_mapping.columns.is_empty() {
+        let meta = get_global_pool_manager().get_model_with_alias(table, alias).ok_or_else(|| {
+            QuickDbError::SerializationError {
+                message: crate::i18n::tf("serializer.parquet_model_missing", &[("table", table)]),
+            }
+        })?;
+        ParquetSchemaMapping {
+            columns: ParquetSchemaMapping::from_meta(&meta).columns,
+            ..schema_mapping.clone()
+        }
+    } else {
+        schema_mapping.clone()
+    };
+
+    let file = std::fs::File::create(path.as_ref()).map_err(QuickDbError::IoError)?;
+    let mut writer = ParquetBatchWriter::new(file, &mapping)?;
+
+    let odm = crate::odm::get_odm_manager().await;
+    let batch_size = mapping.batch_size as u64;
+    let mut skip = 0;
+    loop {
+        let page = fetch_page(&odm, table, &[], skip, batch_size, Some(alias)).await?;
+        writer.write_rows(&page)?;
+        debug!("导出Parquet分页: 表={}, 跳过={}, 记录数={}", table, skip, page.len());
+        if (page.len() as u64) < batch_size {
+            break;
+        }
+        skip += batch_size;
+    }
+
+    let count = writer.finish()?;
+    info!("导出Parquet完成: 别名={}, 表={}, 记录数={}", alias, table, count);
+    Ok(count)
+}
+
+/// 按列映射把记录批量写入 Parquet
+struct ParquetBatchWriter<'a, W: Write + Send> {
+    writer: ArrowWriter<W>,
+    schema: Arc<Schema>,
+    mapping: &'a ParquetSchemaMapping,
+    rows: usize,
+}
+
+impl<'a, W: Write + Send> ParquetBatchWriter<'a, W> {
+    fn new(output: W, mapping: &'a ParquetSchemaMapping) -> QuickDbResult<Self> {
+        let schema = mapping.schema();
+        let properties = WriterProperties::builder().set_compression(mapping.compression).build();
+        let writer = ArrowWriter::try_new(output, schema.clone(), Some(properties)).map_err(parquet_error)?;
+        Ok(Self {
+            writer,
+            schema,
+            mapping,
+            rows: 0,
+        })
+    }
+
+    fn write_rows(&mut self, rows: &[HashMap<String, DataValue>]) -> QuickDbResult<()> {
+        if rows.is_empty() {
+            return Ok(());
+        }
+        let batch = record_batch(self.schema.clone(), self.mapping, rows)?;
+        self.writer.write(&batch).map_err(parquet_error)?;
+        self.rows += rows.len();
+        Ok(())
+    }
+
+    fn finish(self) -> QuickDbResult<usize> {
+        self.writer.close().map_err(parquet_error)?;
+        Ok(self.rows)
+    }
+}
+
+/// 把一批记录转换为 Arrow 记录批次
+fn record_batch(
+    schema: Arc<Schema>,
+    mapping: &ParquetSchemaMapping,
+    rows: &[HashMap<String, DataValue>],
+) -> QuickDbResult<RecordBatch> {
+    let columns = mapping
+        .columns
+        .iter()
+        .map(|(field, column_type)| build_column(field, *column_type, rows))
+        .collect::<QuickDbResult<Vec<ArrayRef>>>()?;
+    RecordBatch::try_new(schema, columns).map_err(|e| parquet_error(e.into()))
+}
+
+fn build_column(field: &str, column_type: ParquetColumnType, rows: &[HashMap<String, DataValue>]) -> QuickDbResult<ArrayRef> {
+    let values = rows.iter().map(|row| row.get(field).unwrap_or(&DataValue::Null));
+    let mismatch = || QuickDbError::SerializationError {
+        message: crate::i18n::tf(
+            "serializer.parquet_value_mismatch",
+            &[("field", field), ("expected", &format!("{:?}", column_type))],
+        ),
+    };
+
+    let array: ArrayRef = match column_type {
+        ParquetColumnType::Boolean => {
+            let mut builder = BooleanBuilder::with_capacity(rows.len());
+            for value in values {
+                match value {
+                    DataValue::Null => builder.append_null(),
+                    DataValue::Bool(b) => builder.append_value(*b),
+                    _ => return Err(mismatch()),
+                }
+            }
+            Arc::new(builder.finish())
+        }
+        ParquetColumnType::Int64 => {
+            let mut builder = Int64Builder::with_capacity(rows.len());
+            for value in values {
+                match value {
+                    DataValue::Null => builder.append_null(),
+                    DataValue::Int(i) => builder.append_value(*i),
+                    DataValue::UInt(u) => builder.append_value(i64::try_from(*u).map_err(|_| mismatch())?),
+                    DataValue::Bool(b) => builder.append_value(*b as i64),
+                    _ => return Err(mismatch()),
+                }
+            }
+            Arc::new(builder.finish())
+        }
+        ParquetColumnType::Float64 => {
+            let mut builder = Float64Builder::with_capacity(rows.len());
+            for value in values {
+                match value {
+                    DataValue::Null => builder.append_null(),
+                    DataValue::Float(f) => builder.append_value(*f),
+                    DataValue::Int(i) => builder.append_value(*i as f64),
+                    DataValue::UInt(u) => builder.append_value(*u as f64),
+                    _ => return Err(mismatch()),
+                }
+            }
+            Arc::new(builder.finish())
+        }
+        ParquetColumnType::Utf8 => {
+            let mut builder = StringBuilder::with_capacity(rows.len(), rows.len() * 16);
+            for value in values {
+                match value {
+                    DataValue::Null => builder.append_null(),
+                    DataValue::String(s) => builder.append_value(s),
+                    DataValue::Json(json) => builder.append_value(json.to_string()),
+                    value @ (DataValue::Array(_) | DataValue::Object(_) | DataValue::Vector(_)) => {
+                        builder.append_value(value.to_json_value().to_string())
+                    }
+                    DataValue::DateTime(dt) => builder.append_value(dt.to_rfc3339()),
+                    DataValue::DateTimeUTC(dt) => builder.append_value(dt.to_rfc3339()),
+                    DataValue::Bytes(_) => return Err(mismatch()),
+                    other => builder.append_value(other.to_string()),
+                }
+            }
+            Arc::new(builder.finish())
+        }
+        ParquetColumnType::Binary => {
+            let mut builder = BinaryBuilder::with_capacity(rows.len(), rows.len() * 16);
+            for value in values {
+                match value {
+                    DataValue::Null => builder.append_null(),
+                    DataValue::Bytes(bytes) => builder.append_value(bytes),
+                    DataValue::String(s) => builder.append_value(s.as_bytes()),
+                    _ => return Err(mismatch()),
+                }
+            }
+            Arc::new(builder.finish())
+        }
+        ParquetColumnType::Timestamp => {
+            let mut builder = TimestampMillisecondBuilder::with_capacity(rows.len()).with_timezone("UTC");
+            for value in values {
+                match value {
+                    DataValue::Null => builder.append_null(),
+                    DataValue::DateTime(dt) => builder.append_value(dt.timestamp_millis()),
+                    DataValue::DateTimeUTC(dt) => builder.append_value(dt.timestamp_millis()),
+                    DataValue::Int(millis) => builder.append_value(*millis),
+                    DataValue::String(s) => {
+                        let dt = chrono::DateTime::parse_from_rfc3339(s).map_err(|_| mismatch())?;
+                        builder.append_value(dt.timestamp_millis())
+                    }
+                    _ => return Err(mismatch()),
+                }
+            }
+            Arc::new(builder.finish())
+        }
+    };
+    Ok(array)
+}
+
+fn parquet_error(error: parquet::errors::ParquetError) -> QuickDbError {
+    QuickDbError::SerializationError {
+        message: crate::i18n::tf("serializer.parquet_failed", &[("message", &error.to_string())]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::{Array, Int64Array, StringArray, TimestampMillisecondArray};
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+    #[test]
+    fn writes_rows_as_typed_arrow_columns() {
+        crate::i18n::ErrorMessageI18n::init_i18n();
+        let mapping = ParquetSchemaMapping::new()
+            .column("id", ParquetColumnType::Int64)
+            .column("name", ParquetColumnType::Utf8)
+            .column("created_at", ParquetColumnType::Timestamp);
+        let at = chrono::DateTime::parse_from_rfc3339("2024-01-01T08:00:00+08:00").unwrap();
+        let rows = vec![
+            HashMap::from([
+                ("id".to_string(), DataValue::Int(1)),
+                ("name".to_string(), DataValue::String("alice".to_string())),
+                ("created_at".to_string(), DataValue::DateTime(at)),
+            ]),
+            HashMap::from([("id".to_string(), DataValue::Int(2))]),
+        ];
+
+        let mut output = Vec::new();
+        let mut writer = ParquetBatchWriter::new(&mut output, &mapping).unwrap();
+        writer.write_rows(&rows).unwrap();
+        assert_eq!(writer.finish().unwrap(), 2);
+
+        let reader = ParquetRecordBatchReaderBuilder::try_new(bytes::Bytes::from(output)).unwrap().build().unwrap();
+        let batch = reader.into_iter().next().unwrap().unwrap();
+        let ids = batch.column(0).as_any().downcast_ref::<Int64Array>().unwrap();
+        assert_eq!(ids.values(), &[1, 2]);
+        let names = batch.column(1).as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(names.value(0), "alice");
+        assert!(names.is_null(1));
+        let times = batch.column(2).as_any().downcast_ref::<TimestampMillisecondArray>().unwrap();
+        assert_eq!(times.value(0), 1704067200000);
+
+        let bad = vec![HashMap::from([("id".to_string(), DataValue::String("x".to_string()))])];
+        assert!(record_batch(mapping.schema(), &mapping, &bad).is_err());
+    }
+}
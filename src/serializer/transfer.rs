@@ -0,0 +1,180 @@
+//! 导入导出的公共部分
+//!
+//! 分页读取、分批插入、按字段类型解析文本，以及导入结果类型
+
+use crate::context::{Priority, with_priority};
+use crate::error::QuickDbResult;
+use crate::manager::get_global_pool_manager;
+use crate::model::FieldType;
+use crate::odm::OdmOperations;
+use crate::types::data_value::json_value_to_data_value;
+use crate::types::*;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use rat_logger::debug;
+use std::collections::HashMap;
+
+/// 导入失败的行
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportRowError {
+    /// 数据行号（从 1 开始，CSV 不计表头）
+    pub row: usize,
+    /// 错误信息
+    pub message: String,
+}
+
+/// 导入结果
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ImportReport {
+    /// 成功插入的行数
+    pub inserted: usize,
+    /// 失败的行（转换失败或插入失败）
+    pub errors: Vec<ImportRowError>,
+}
+
+/// 并发插入一批记录，失败的行记入结果（以后台优先级执行）
+pub(crate) async fn insert_batch(
+    odm: &crate::odm::AsyncOdmManager,
+    table: &str,
+    batch: Vec<(usize, HashMap<String, DataValue>)>,
+    alias: Option<&str>,
+    report: &mut ImportReport,
+) {
+    if batch.is_empty() {
+        return;
+    }
+    debug!("导入批次: 表={}, 行数={}", table, batch.len());
+    let rows: Vec<usize> = batch.iter().map(|(row, _)| *row).collect();
+    let inserts = futures::future::join_all(batch.into_iter().map(|(_, data)| odm.create(table, data, alias)));
+    let results = with_priority(Priority::Background, inserts).await;
+    for (row, result) in rows.into_iter().zip(results) {
+        match result {
+            Ok(_) => report.inserted += 1,
+            Err(e) => report.errors.push(ImportRowError {
+                row,
+                message: e.to_string(),
+            }),
+        }
+    }
+}
+
+/// 按 `id` 升序读取一页记录（以后台优先级执行）
+pub(crate) async fn fetch_page(
+    odm: &crate::odm::AsyncOdmManager,
+    table: &str,
+    conditions: &[QueryConditionWithConfig],
+    skip: u64,
+    limit: u64,
+    alias: Option<&str>,
+) -> QuickDbResult<Vec<HashMap<String, DataValue>>> {
+    let query = QueryOptions::new()
+        .with_sort(vec![SortConfig {
+            field: "id".to_string(),
+            direction: SortDirection::Asc,
+        }])
+        .with_pagination(PaginationConfig { skip, limit });
+    let page = with_priority(
+        Priority::Background,
+        odm.find(table, conditions.to_vec(), Some(query), alias),
+    )
+    .await?;
+    Ok(page.into_iter().filter_map(|record| record.expect_object().ok()).collect())
+}
+
+/// 记录字段的并集：`id` 在前，其余按名称排序
+pub(crate) fn record_columns<'a, V: 'a>(records: impl IntoIterator<Item = &'a HashMap<String, V>>) -> Vec<String> {
+    let mut columns: Vec<String> = Vec::new();
+    for record in records {
+        for key in record.keys() {
+            if !columns.contains(key) {
+                columns.push(key.clone());
+            }
+        }
+    }
+    columns.sort_by(|a, b| (a != "id").cmp(&(b != "id")).then_with(|| a.cmp(b)));
+    columns
+}
+
+/// 别名下注册的模型元数据（未指定别名时使用默认别名）
+pub(crate) async fn model_meta(table: &str, alias: Option<&str>) -> Option<crate::model::ModelMeta> {
+    let manager = get_global_pool_manager();
+    let alias = match alias {
+        Some(alias) => alias.to_string(),
+        None => manager.get_default_alias().await?,
+    };
+    manager.get_model_with_alias(table, &alias)
+}
+
+/// 按字段类型解析文本，无法转换时返回 `None`
+pub(crate) fn parse_typed_text(
+    cell: &str,
+    field_type: &FieldType,
+    datetime_format: Option<&str>,
+) -> Option<DataValue> {
+    match field_type {
+        FieldType::Integer { .. } | FieldType::BigInteger | FieldType::Sequence { .. } => {
+            cell.trim().parse().ok().map(DataValue::Int)
+        }
+        FieldType::Float { .. } | FieldType::Double | FieldType::Decimal { .. } => {
+            cell.trim().parse().ok().map(DataValue::Float)
+        }
+        FieldType::Boolean => match cell.trim().to_ascii_lowercase().as_str() {
+            "true" | "1" | "yes" => Some(DataValue::Bool(true)),
+            "false" | "0" | "no" => Some(DataValue::Bool(false)),
+            _ => None,
+        },
+        FieldType::DateTime | FieldType::DateTimeWithTz { .. } => parse_datetime(cell.trim(), datetime_format),
+        FieldType::Uuid => uuid::Uuid::parse_str(cell.trim()).ok().map(DataValue::Uuid),
+        FieldType::Json
+        | FieldType::Array { .. }
+        | FieldType::Object { .. }
+        | FieldType::PolymorphicReference { .. } => {
+            serde_json::from_str(cell).ok().map(json_value_to_data_value)
+        }
+        FieldType::Vector { .. } => serde_json::from_str(cell).ok().map(DataValue::Vector),
+        FieldType::Binary => STANDARD.decode(cell.trim()).ok().map(DataValue::Bytes),
+        FieldType::String { .. }
+        | FieldType::Text
+        | FieldType::Date
+        | FieldType::Time
+        | FieldType::Reference { .. } => Some(DataValue::String(cell.to_string())),
+    }
+}
+
+/// 按字段类型转换其他数据源读出的值（例如 SQLite 以字符串返回的日期时间），无法转换时返回 `None`
+pub(crate) fn coerce_value(value: DataValue, field_type: &FieldType) -> Option<DataValue> {
+    match (value, field_type) {
+        (
+            DataValue::String(text),
+            FieldType::DateTime | FieldType::DateTimeWithTz { .. } | FieldType::Uuid | FieldType::Binary,
+        ) => parse_typed_text(&text, field_type, None),
+        (DataValue::Array(items), FieldType::Vector { .. }) => items
+            .into_iter()
+            .map(|item| match item {
+                DataValue::Float(f) => Some(f as f32),
+                DataValue::Int(i) => Some(i as f32),
+                _ => None,
+            })
+            .collect::<Option<Vec<f32>>>()
+            .map(DataValue::Vector),
+        (DataValue::Int(i), FieldType::Boolean) => Some(DataValue::Bool(i != 0)),
+        (value, _) => Some(value),
+    }
+}
+
+fn parse_datetime(cell: &str, datetime_format: Option<&str>) -> Option<DataValue> {
+    if let Some(format) = datetime_format {
+        if let Ok(datetime) = chrono::DateTime::parse_from_str(cell, format) {
+            return Some(DataValue::DateTime(datetime));
+        }
+        if let Ok(naive) = chrono::NaiveDateTime::parse_from_str(cell, format) {
+            return Some(DataValue::DateTimeUTC(naive.and_utc()));
+        }
+    }
+    if let Ok(datetime) = chrono::DateTime::parse_from_rfc3339(cell) {
+        return Some(DataValue::DateTime(datetime));
+    }
+    chrono::NaiveDateTime::parse_from_str(cell, "%Y-%m-%d %H:%M:%S")
+        .ok()
+        .map(|naive| DataValue::DateTimeUTC(naive.and_utc()))
+}
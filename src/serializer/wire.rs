@@ -0,0 +1,317 @@
+//! DataValue 的稳定线上编码
+//!
+//! 取代以往的单键类型标签对象（`{"String": ...}`），提供两种模式：
+//!
+//! - [`WireMode::Tagged`]：每个值编码为 `{"$t": 类型, "$v": 值}`，嵌套的数组与对象内部同样带标签，
+//!   不会与恰好只有一个键的业务对象混淆，可以无损解码：
+//!   `{"$t": "datetime", "$v": "2024-01-01T00:00:00+00:00"}`
+//! - [`WireMode::Untagged`]：普通 JSON（字节数组为 base64，日期时间为 RFC 3339 字符串），
+//!   解码时按 JSON 的自然类型还原
+//!
+//! 类型名与 [`DataValue::type_name`] 一致。文档级编码 [`encode_document`] 带有格式版本
+//! [`WIRE_VERSION`]，解码时拒绝更高的版本：
+//!
+//! ```json
+//! {"wire_version": 1, "mode": "tagged", "data": {"name": {"$t": "string", "$v": "alice"}}}
+//! ```
+
+use crate::error::{QuickDbError, QuickDbResult};
+use crate::model::FieldType;
+use crate::types::DataValue;
+use crate::types::data_value::json_value_to_data_value;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map as JsonMap, Value as JsonValue};
+use std::collections::HashMap;
+
+/// 当前线上编码版本
+pub const WIRE_VERSION: u32 = 1;
+
+/// 类型标签键
+pub const TYPE_KEY: &str = "$t";
+/// 值键
+pub const VALUE_KEY: &str = "$v";
+
+/// 线上编码模式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WireMode {
+    /// 带类型标签（无损）
+    #[default]
+    Tagged,
+    /// 普通 JSON
+    Untagged,
+}
+
+/// 字段类型对应的线上类型名（用于为空值标注类型）
+pub fn field_type_name(field_type: &FieldType) -> &'static str {
+    match field_type {
+        FieldType::String { .. } | FieldType::Text | FieldType::Reference { .. } => "string",
+        FieldType::Integer { .. } | FieldType::BigInteger | FieldType::Sequence { .. } => "integer",
+        FieldType::Float { .. } | FieldType::Double | FieldType::Decimal { .. } => "float",
+        FieldType::Boolean => "boolean",
+        FieldType::DateTime | FieldType::DateTimeWithTz { .. } | FieldType::Date | FieldType::Time => "datetime",
+        FieldType::Uuid => "uuid",
+        FieldType::Json => "json",
+        FieldType::Binary => "bytes",
+        FieldType::Array { .. } => "array",
+        FieldType::Object { .. } | FieldType::PolymorphicReference { .. } => "object",
+        FieldType::Vector { .. } => "vector",
+    }
+}
+
+/// 编码单个值
+pub fn encode(value: &DataValue, mode: WireMode) -> JsonValue {
+    match mode {
+        WireMode::Tagged => encode_tagged(value, value.type_name()),
+        WireMode::Untagged => encode_untagged(value),
+    }
+}
+
+/// 按字段类型编码单个值
+///
+/// 带标签模式下空值标注字段类型（`{"$t": "datetime", "$v": null}`），
+/// 以字符串形式存储的日期时间、UUID、字节数组按字段类型标注
+pub fn encode_field(value: &DataValue, field_type: &FieldType, mode: WireMode) -> JsonValue {
+    let value = super::transfer::coerce_value(value.clone(), field_type).unwrap_or_else(|| value.clone());
+    match (mode, &value) {
+        (WireMode::Tagged, DataValue::Null) => encode_tagged(&value, field_type_name(field_type)),
+        _ => encode(&value, mode),
+    }
+}
+
+/// 解码单个值
+pub fn decode(json: &JsonValue, mode: WireMode) -> QuickDbResult<DataValue> {
+    match mode {
+        WireMode::Tagged => decode_tagged(json),
+        WireMode::Untagged => Ok(json_value_to_data_value(json.clone())),
+    }
+}
+
+/// 编码记录
+pub fn encode_map(data: &HashMap<String, DataValue>, mode: WireMode) -> JsonValue {
+    JsonValue::Object(data.iter().map(|(key, value)| (key.clone(), encode(value, mode))).collect())
+}
+
+/// 解码记录
+pub fn decode_map(json: &JsonValue, mode: WireMode) -> QuickDbResult<HashMap<String, DataValue>> {
+    let JsonValue::Object(object) = json else {
+        return Err(QuickDbError::SerializationError {
+            message: crate::i18n::t("serializer.json_not_object"),
+        });
+    };
+    object
+        .iter()
+        .map(|(key, value)| Ok((key.clone(), decode(value, mode)?)))
+        .collect()
+}
+
+/// 带版本号的线上文档
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct WireDocument {
+    wire_version: u32,
+    mode: WireMode,
+    data: JsonValue,
+}
+
+/// 编码为带版本号的文档
+pub fn encode_document(data: &HashMap<String, DataValue>, mode: WireMode) -> JsonValue {
+    serde_json::json!({
+        "wire_version": WIRE_VERSION,
+        "mode": mode,
+        "data": encode_map(data, mode),
+    })
+}
+
+/// 解码 [`encode_document`] 生成的文档（按文档声明的模式解码）
+pub fn decode_document(json: &JsonValue) -> QuickDbResult<HashMap<String, DataValue>> {
+    let document = WireDocument::deserialize(json).map_err(|e| invalid(&e.to_string()))?;
+    if document.wire_version > WIRE_VERSION {
+        return Err(invalid(&crate::i18n::tf(
+            "serializer.wire_version_unsupported",
+            &[
+                ("version", &document.wire_version.to_string()),
+                ("supported", &WIRE_VERSION.to_string()),
+            ],
+        )));
+    }
+    decode_map(&document.data, document.mode)
+}
+
+fn encode_tagged(value: &DataValue, type_name: &str) -> JsonValue {
+    let payload = match value {
+        DataValue::Array(items) => JsonValue::Array(items.iter().map(|item| encode(item, WireMode::Tagged)).collect()),
+        DataValue::Object(map) => encode_map(map, WireMode::Tagged),
+        DataValue::Json(json) => json.clone(),
+        other => encode_untagged(other),
+    };
+    let mut object = JsonMap::with_capacity(2);
+    object.insert(TYPE_KEY.to_string(), JsonValue::String(type_name.to_string()));
+    object.insert(VALUE_KEY.to_string(), payload);
+    JsonValue::Object(object)
+}
+
+fn encode_untagged(value: &DataValue) -> JsonValue {
+    match value {
+        DataValue::Null => JsonValue::Null,
+        DataValue::Bool(b) => JsonValue::Bool(*b),
+        DataValue::Int(i) => JsonValue::from(*i),
+        DataValue::UInt(u) => JsonValue::from(*u),
+        DataValue::Float(f) => serde_json::Number::from_f64(*f).map(JsonValue::Number).unwrap_or(JsonValue::Null),
+        DataValue::String(s) => JsonValue::String(s.clone()),
+        DataValue::Bytes(bytes) => JsonValue::String(STANDARD.encode(bytes)),
+        DataValue::DateTime(dt) => JsonValue::String(dt.to_rfc3339()),
+        DataValue::DateTimeUTC(dt) => JsonValue::String(dt.to_rfc3339()),
+        DataValue::Uuid(uuid) => JsonValue::String(uuid.to_string()),
+        DataValue::Json(json) => json.clone(),
+        DataValue::Array(items) => JsonValue::Array(items.iter().map(encode_untagged).collect()),
+        DataValue::Object(map) => {
+            JsonValue::Object(map.iter().map(|(key, value)| (key.clone(), encode_untagged(value))).collect())
+        }
+        DataValue::Vector(values) => JsonValue::Array(values.iter().map(|v| JsonValue::from(*v as f64)).collect()),
+    }
+}
+
+fn decode_tagged(json: &JsonValue) -> QuickDbResult<DataValue> {
+    let (type_name, payload) = match json {
+        JsonValue::Object(object) if object.len() == 2 => {
+            match (object.get(TYPE_KEY).and_then(JsonValue::as_str), object.get(VALUE_KEY)) {
+                (Some(type_name), Some(payload)) => (type_name, payload),
+                _ => return Err(invalid(&json.to_string())),
+            }
+        }
+        _ => return Err(invalid(&json.to_string())),
+    };
+    if payload.is_null() {
+        return Ok(DataValue::Null);
+    }
+
+    let mismatch = || {
+        invalid(&crate::i18n::tf(
+            "serializer.wire_value_mismatch",
+            &[("type", type_name), ("value", &payload.to_string())],
+        ))
+    };
+    let value = match type_name {
+        "boolean" => DataValue::Bool(payload.as_bool().ok_or_else(mismatch)?),
+        "integer" => DataValue::Int(payload.as_i64().ok_or_else(mismatch)?),
+        "unsigned_integer" => DataValue::UInt(payload.as_u64().ok_or_else(mismatch)?),
+        "float" => DataValue::Float(payload.as_f64().ok_or_else(mismatch)?),
+        "string" => DataValue::String(payload.as_str().ok_or_else(mismatch)?.to_string()),
+        "bytes" => {
+            let text = payload.as_str().ok_or_else(mismatch)?;
+            DataValue::Bytes(STANDARD.decode(text).map_err(|_| mismatch())?)
+        }
+        "datetime" => {
+            let text = payload.as_str().ok_or_else(mismatch)?;
+            DataValue::DateTime(chrono::DateTime::parse_from_rfc3339(text).map_err(|_| mismatch())?)
+        }
+        "uuid" => {
+            let text = payload.as_str().ok_or_else(mismatch)?;
+            DataValue::Uuid(uuid::Uuid::parse_str(text).map_err(|_| mismatch())?)
+        }
+        "json" => DataValue::Json(payload.clone()),
+        "array" => DataValue::Array(
+            payload
+                .as_array()
+                .ok_or_else(mismatch)?
+                .iter()
+                .map(decode_tagged)
+                .collect::<QuickDbResult<_>>()?,
+        ),
+        "object" => DataValue::Object(decode_map(payload, WireMode::Tagged)?),
+        "vector" => DataValue::Vector(
+            payload
+                .as_array()
+                .ok_or_else(mismatch)?
+                .iter()
+                .map(|v| v.as_f64().map(|f| f as f32))
+                .collect::<Option<_>>()
+                .ok_or_else(mismatch)?,
+        ),
+        _ => return Err(mismatch()),
+    };
+    Ok(value)
+}
+
+fn invalid(message: &str) -> QuickDbError {
+    QuickDbError::SerializationError {
+        message: crate::i18n::tf("serializer.wire_invalid", &[("message", message)]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::datetime_field;
+    use crate::model::traits::Model;
+
+    mod model {
+        use crate::model::*;
+        use crate::{define_model, integer_field, string_field};
+
+        define_model! {
+            struct Event {
+                id: String,
+                title: String,
+                attendees: Option<i32>,
+            }
+            collection = "wire_events",
+            fields = {
+                id: string_field(None, None, None).required(),
+                title: string_field(None, None, None).required(),
+                attendees: integer_field(None, None),
+            }
+        }
+    }
+
+    #[test]
+    fn tagged_values_round_trip_and_stay_unambiguous() {
+        crate::i18n::ErrorMessageI18n::init_i18n();
+        let at = chrono::DateTime::parse_from_rfc3339("2024-01-01T08:00:00+08:00").unwrap();
+        let record = HashMap::from([
+            ("at".to_string(), DataValue::DateTime(at)),
+            ("blob".to_string(), DataValue::Bytes(vec![1, 2])),
+            ("big".to_string(), DataValue::UInt(u64::MAX)),
+            // 业务对象恰好只有一个名为 "String" 的键
+            (
+                "meta".to_string(),
+                DataValue::Object(HashMap::from([("String".to_string(), DataValue::Int(1))])),
+            ),
+            ("tags".to_string(), DataValue::Array(vec![DataValue::String("a".to_string())])),
+        ]);
+
+        let document = encode_document(&record, WireMode::Tagged);
+        assert_eq!(document["wire_version"], WIRE_VERSION);
+        assert_eq!(document["data"]["at"], serde_json::json!({"$t": "datetime", "$v": "2024-01-01T08:00:00+08:00"}));
+        assert_eq!(document["data"]["meta"]["$v"]["String"], serde_json::json!({"$t": "integer", "$v": 1}));
+        assert_eq!(decode_document(&document).unwrap(), record);
+
+        let untagged = encode_map(&record, WireMode::Untagged);
+        assert_eq!(untagged["blob"], "AQI=");
+        assert_eq!(untagged["meta"], serde_json::json!({"String": 1}));
+
+        assert_eq!(
+            encode_field(&DataValue::Null, &datetime_field().field_type, WireMode::Tagged),
+            serde_json::json!({"$t": "datetime", "$v": null})
+        );
+        assert!(decode(&serde_json::json!({"$t": "integer", "$v": "x"}), WireMode::Tagged).is_err());
+        assert!(decode(&serde_json::json!({"String": "x"}), WireMode::Tagged).is_err());
+        let mut newer = document.clone();
+        newer["wire_version"] = JsonValue::from(WIRE_VERSION + 1);
+        assert!(decode_document(&newer).is_err());
+    }
+
+    #[test]
+    fn model_typed_map_uses_wire_tags() {
+        let event = model::Event {
+            id: "e1".to_string(),
+            title: "launch".to_string(),
+            attendees: None,
+        };
+        let typed = event.to_data_map_with_types_json().unwrap();
+        assert_eq!(typed["title"], serde_json::json!({"$t": "string", "$v": "launch"}));
+        assert_eq!(typed["attendees"], serde_json::json!({"$t": "integer", "$v": null}));
+    }
+}
@@ -0,0 +1,277 @@
+//! # HTTP 数据服务模块
+//!
+//! 启用 `server` 特性后，[`DataService`] 把 ODM 操作暴露为 axum HTTP 接口，
+//! 请求按路径中的别名路由到对应数据库，使 rat_quickdb 可以作为轻量的数据 API 网关。
+//!
+//! ```text
+//! POST /{alias}/{table}/{op}   执行 ODM 操作，请求体格式同 FFI 接口（省略 op/table/alias）
+//! GET  /health                 健康检查
+//! ```
+//!
+//! ```ignore
+//! let service = DataService::new()
+//!     .auth(BearerTokenAuth::new("secret"))
+//!     .allow_aliases(["default"]);
+//! let listener = tokio::net::TcpListener::bind("0.0.0.0:8080").await?;
+//! service.serve(listener).await?;
+//! ```
+//!
+//! 响应体为 `{"success": true, "data": ...}` 或 `{"success": false, "error": "..."}`。
+//! 状态码：请求无效 400，未认证 401，拒绝访问 403，别名或表不存在 404，
+//! 唯一约束冲突 409，其他错误 500
+
+use crate::error::{QuickDbError, QuickDbResult};
+use crate::json_request::{execute_op, parse_json};
+use async_trait::async_trait;
+use axum::Router;
+use axum::body::Bytes;
+use axum::extract::{Path, State};
+use axum::http::{HeaderMap, StatusCode, header};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use rat_logger::{debug, info};
+use serde_json::Value;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// 会修改数据的操作，只读模式下拒绝
+const WRITE_OPERATIONS: &[&str] = &["create", "update", "update_by_id", "delete", "delete_by_id"];
+
+/// 认证钩子收到的请求信息
+#[derive(Debug)]
+pub struct AuthContext<'a> {
+    /// 请求头
+    pub headers: &'a HeaderMap,
+    /// 目标数据库别名
+    pub alias: &'a str,
+    /// 目标表名
+    pub table: &'a str,
+    /// 操作名
+    pub op: &'a str,
+}
+
+/// 认证失败
+#[derive(Debug, Clone)]
+pub enum AuthError {
+    /// 未认证（401）
+    Unauthorized(String),
+    /// 已认证但无权访问（403）
+    Forbidden(String),
+}
+
+/// 认证钩子，在执行每个操作前调用
+#[async_trait]
+pub trait AuthHook: Send + Sync {
+    async fn authorize(&self, context: &AuthContext<'_>) -> Result<(), AuthError>;
+}
+
+/// 校验 `Authorization: Bearer <token>` 请求头
+#[derive(Debug, Clone)]
+pub struct BearerTokenAuth {
+    token: String,
+}
+
+impl BearerTokenAuth {
+    pub fn new(token: impl Into<String>) -> Self {
+        Self { token: token.into() }
+    }
+}
+
+#[async_trait]
+impl AuthHook for BearerTokenAuth {
+    async fn authorize(&self, context: &AuthContext<'_>) -> Result<(), AuthError> {
+        let provided = context
+            .headers
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "));
+        match provided {
+            Some(token) if constant_time_eq(token.as_bytes(), self.token.as_bytes()) => Ok(()),
+            _ => Err(AuthError::Unauthorized(crate::i18n::t("server.unauthorized"))),
+        }
+    }
+}
+
+/// 比较令牌，耗时与不匹配的位置无关
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+/// HTTP 数据服务
+///
+/// 默认不认证、允许所有别名且可读写
+#[derive(Clone, Default)]
+pub struct DataService {
+    auth: Option<Arc<dyn AuthHook>>,
+    aliases: Option<Arc<HashSet<String>>>,
+    read_only: bool,
+}
+
+impl std::fmt::Debug for DataService {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DataService")
+            .field("auth", &self.auth.is_some())
+            .field("aliases", &self.aliases)
+            .field("read_only", &self.read_only)
+            .finish()
+    }
+}
+
+impl DataService {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 设置认证钩子
+    pub fn auth(mut self, hook: impl AuthHook + 'static) -> Self {
+        self.auth = Some(Arc::new(hook));
+        self
+    }
+
+    /// 只允许访问指定别名
+    pub fn allow_aliases<I, S>(mut self, aliases: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.aliases = Some(Arc::new(aliases.into_iter().map(Into::into).collect()));
+        self
+    }
+
+    /// 只读模式：拒绝写操作
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    /// 构建 axum 路由，可嵌入已有应用
+    pub fn router(self) -> Router {
+        Router::new()
+            .route("/health", get(|| async { "ok" }))
+            .route("/{alias}/{table}/{op}", post(handle_op))
+            .with_state(self)
+    }
+
+    /// 在监听器上运行服务，直到服务出错
+    pub async fn serve(self, listener: tokio::net::TcpListener) -> QuickDbResult<()> {
+        if let Ok(address) = listener.local_addr() {
+            info!("数据服务启动: 地址={}, 配置={:?}", address, self);
+        }
+        axum::serve(listener, self.router()).await.map_err(QuickDbError::IoError)
+    }
+
+    /// 检查别名、只读限制并调用认证钩子
+    async fn check_access(&self, context: &AuthContext<'_>, writes: bool) -> Result<(), (StatusCode, String)> {
+        if self.aliases.as_ref().is_some_and(|a| !a.contains(context.alias)) {
+            return Err((
+                StatusCode::FORBIDDEN,
+                crate::i18n::tf("server.alias_not_allowed", &[("alias", context.alias)]),
+            ));
+        }
+        if self.read_only && writes {
+            return Err((
+                StatusCode::FORBIDDEN,
+                crate::i18n::tf("server.read_only", &[("op", context.op)]),
+            ));
+        }
+        if let Some(auth) = &self.auth {
+            auth.authorize(context).await.map_err(|e| match e {
+                AuthError::Unauthorized(message) => (StatusCode::UNAUTHORIZED, message),
+                AuthError::Forbidden(message) => (StatusCode::FORBIDDEN, message),
+            })?;
+        }
+        Ok(())
+    }
+
+    /// 执行一个 ODM 操作请求
+    async fn dispatch(&self, headers: &HeaderMap, alias: &str, table: &str, op: &str, body: &[u8]) -> (StatusCode, Value) {
+        let context = AuthContext { headers, alias, table, op };
+        if let Err((status, message)) = self.check_access(&context, WRITE_OPERATIONS.contains(&op)).await {
+            debug!("数据服务拒绝请求: 别名={}, 表={}, 操作={}, 状态={}", alias, table, op, status);
+            return (status, failure(message));
+        }
+
+        let result = async {
+            let mut request = if body.is_empty() {
+                serde_json::Map::new()
+            } else {
+                match parse_json(body)? {
+                    Value::Object(object) => object,
+                    _ => {
+                        return Err(crate::quick_error!(
+                            validation,
+                            "body",
+                            crate::i18n::t("server.body_not_object")
+                        ));
+                    }
+                }
+            };
+            request.insert("op".to_string(), Value::from(op));
+            request.insert("table".to_string(), Value::from(table));
+            request.insert("alias".to_string(), Value::from(alias));
+            execute_op(&Value::Object(request)).await
+        }
+        .await;
+
+        match result {
+            Ok(data) => (StatusCode::OK, serde_json::json!({ "success": true, "data": data })),
+            Err(e) => (status_for(&e), failure(e.to_string())),
+        }
+    }
+}
+
+async fn handle_op(
+    State(service): State<DataService>,
+    Path((alias, table, op)): Path<(String, String, String)>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Response {
+    let (status, body) = service.dispatch(&headers, &alias, &table, &op, &body).await;
+    (status, axum::Json(body)).into_response()
+}
+
+fn failure(message: String) -> Value {
+    serde_json::json!({ "success": false, "error": message })
+}
+
+/// 错误对应的 HTTP 状态码
+fn status_for(error: &QuickDbError) -> StatusCode {
+    match error {
+        QuickDbError::ValidationError { .. } | QuickDbError::SerializationError { .. } => StatusCode::BAD_REQUEST,
+        QuickDbError::AliasNotFound { .. } | QuickDbError::TableNotExistError { .. } => StatusCode::NOT_FOUND,
+        QuickDbError::UniqueViolation { .. } => StatusCode::CONFLICT,
+        QuickDbError::PreconditionFailed { .. } => StatusCode::PRECONDITION_FAILED,
+        QuickDbError::Overloaded { .. } => StatusCode::SERVICE_UNAVAILABLE,
+        _ => StatusCode::INTERNAL_SERVER_ERROR,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn rejects_requests_before_touching_the_database() {
+        crate::i18n::ErrorMessageI18n::init_i18n();
+        let service = DataService::new()
+            .auth(BearerTokenAuth::new("secret"))
+            .allow_aliases(["main"])
+            .read_only(true);
+        let mut headers = HeaderMap::new();
+
+        let (status, _) = service.dispatch(&headers, "main", "users", "find", b"").await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+        headers.insert(header::AUTHORIZATION, "Bearer secret".parse().unwrap());
+        let (status, body) = service.dispatch(&headers, "other", "users", "find", b"").await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert!(body["error"].as_str().unwrap().contains("other"));
+
+        let (status, _) = service.dispatch(&headers, "main", "users", "delete", b"").await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        let (status, body) = service.dispatch(&headers, "main", "users", "find", b"[1]").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["success"], false);
+    }
+}
@@ -0,0 +1,406 @@
+//! # 水平分片模块
+//!
+//! 模型通过 `shard_key = ("tenant_id", "tenants")` 声明分片键与分片解析器名称，
+//! 解析器通过 [`register_shard_resolver`] 按名称注册，负责把分片键值映射为数据库别名：
+//!
+//! ```ignore
+//! sharding::register_shard_resolver(
+//!     "tenants",
+//!     HashShardResolver::new(vec!["shard_0".to_string(), "shard_1".to_string()]),
+//! );
+//! ```
+//!
+//! 未显式指定别名时，ODM 按以下规则路由分片模型的操作：
+//!
+//! - 创建与 upsert：按数据中的分片键值路由，缺少分片键时返回验证错误
+//! - 查询、统计、按条件更新与删除：条件中含分片键的等值条件时只访问对应分片，
+//!   含 `In` 条件时只访问涉及的分片，否则向全部分片扇出；查询结果在内存中按排序合并后再分页
+//! - 按 ID 操作：分片键为 `id` 时按 ID 路由，否则向全部分片扇出
+//!
+//! 显式指定别名的操作不经过路由，可用于直接访问某个分片。更新分片键的值不会迁移记录
+
+use crate::error::{QuickDbError, QuickDbResult};
+use crate::manager::get_global_pool_manager;
+use crate::model::ModelMeta;
+use crate::types::*;
+use dashmap::DashMap;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Arc;
+
+/// 模型声明的分片键
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShardKey {
+    /// 分片键字段
+    pub field: String,
+    /// 分片解析器名称
+    pub resolver: String,
+}
+
+impl ShardKey {
+    /// 创建分片键声明
+    pub fn new(field: impl Into<String>, resolver: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            resolver: resolver.into(),
+        }
+    }
+}
+
+/// 分片解析器：把分片键值映射为数据库别名
+pub trait ShardResolver: Send + Sync {
+    /// 分片键值所在分片的数据库别名
+    fn resolve(&self, key: &DataValue) -> QuickDbResult<String>;
+
+    /// 全部分片的数据库别名（无法按分片键路由的操作向这些别名扇出）
+    fn shards(&self) -> Vec<String>;
+}
+
+/// 哈希分片解析器：按分片键值的稳定哈希（FNV-1a）对分片数取模
+#[derive(Debug, Clone)]
+pub struct HashShardResolver {
+    shards: Vec<String>,
+}
+
+impl HashShardResolver {
+    /// 创建哈希分片解析器，分片顺序决定键值到分片的映射，扩容时需要迁移数据
+    pub fn new(shards: Vec<String>) -> Self {
+        Self { shards }
+    }
+}
+
+impl ShardResolver for HashShardResolver {
+    fn resolve(&self, key: &DataValue) -> QuickDbResult<String> {
+        if self.shards.is_empty() {
+            return Err(QuickDbError::ConfigError {
+                message: crate::i18n::t("sharding.no_shards"),
+            });
+        }
+        let hash = key_text(key)
+            .bytes()
+            .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3));
+        Ok(self.shards[(hash % self.shards.len() as u64) as usize].clone())
+    }
+
+    fn shards(&self) -> Vec<String> {
+        self.shards.clone()
+    }
+}
+
+/// 分片键值的规范文本，保证同一键值的字符串与数字等表示得到同一分片
+fn key_text(key: &DataValue) -> String {
+    match key {
+        DataValue::String(s) => s.clone(),
+        DataValue::Int(i) => i.to_string(),
+        DataValue::UInt(u) => u.to_string(),
+        DataValue::Uuid(uuid) => uuid.to_string(),
+        other => other.to_json_value().to_string(),
+    }
+}
+
+/// 全局分片解析器注册表 (名称 -> 解析器)
+static RESOLVERS: Lazy<DashMap<String, Arc<dyn ShardResolver>>> = Lazy::new(DashMap::new);
+
+/// 声明了分片键的模型 (集合名 -> 模型元数据)
+static SHARDED_MODELS: Lazy<DashMap<String, ModelMeta>> = Lazy::new(DashMap::new);
+
+/// 注册分片解析器，同名解析器会被替换
+pub fn register_shard_resolver(name: &str, resolver: impl ShardResolver + 'static) {
+    RESOLVERS.insert(name.to_string(), Arc::new(resolver));
+}
+
+/// 移除已注册的分片解析器
+pub fn unregister_shard_resolver(name: &str) -> bool {
+    RESOLVERS.remove(name).is_some()
+}
+
+/// 记录声明了分片键的模型，由模型注册时调用
+pub(crate) fn register_sharded_model(meta: &ModelMeta) {
+    SHARDED_MODELS.entry(meta.qualified_name()).or_insert_with(|| meta.clone());
+}
+
+/// 分片路由结果
+pub(crate) enum ShardRoute {
+    /// 在单个别名上执行（未分片的集合沿用调用方的别名）
+    Alias(Option<String>),
+    /// 向多个分片扇出后合并结果
+    FanOut(Vec<String>),
+}
+
+/// 已路由的分片模型：解析器与模型元数据
+struct ShardedCollection {
+    meta: ModelMeta,
+    key: ShardKey,
+    resolver: Arc<dyn ShardResolver>,
+}
+
+impl ShardedCollection {
+    /// 显式指定别名或集合未分片时返回 `None`
+    fn lookup(collection: &str, alias: Option<&str>) -> QuickDbResult<Option<Self>> {
+        if alias.is_some() {
+            return Ok(None);
+        }
+        let Some(meta) = SHARDED_MODELS.get(collection).map(|entry| entry.value().clone()) else {
+            return Ok(None);
+        };
+        let Some(key) = meta.shard_key.clone() else {
+            return Ok(None);
+        };
+        let resolver = RESOLVERS
+            .get(&key.resolver)
+            .map(|entry| entry.value().clone())
+            .ok_or_else(|| QuickDbError::ConfigError {
+                message: crate::i18n::tf("sharding.resolver_not_registered", &[("name", &key.resolver)]),
+            })?;
+        Ok(Some(Self { meta, key, resolver }))
+    }
+
+    /// 解析分片别名，并确保模型元数据已注册到该别名下（建表和校验按别名查找元数据）
+    fn resolve(&self, value: &DataValue) -> QuickDbResult<String> {
+        let alias = self.resolver.resolve(value)?;
+        self.ensure_registered(&alias)?;
+        Ok(alias)
+    }
+
+    fn all_shards(&self) -> QuickDbResult<ShardRoute> {
+        let shards = self.resolver.shards();
+        for alias in &shards {
+            self.ensure_registered(alias)?;
+        }
+        Ok(ShardRoute::FanOut(shards))
+    }
+
+    fn ensure_registered(&self, alias: &str) -> QuickDbResult<()> {
+        let manager = get_global_pool_manager();
+        if manager
+            .model_registry
+            .contains_key(&format!("{}:{}", alias, self.meta.qualified_name()))
+        {
+            return Ok(());
+        }
+        let mut meta = self.meta.clone();
+        meta.database_alias = Some(alias.to_string());
+        manager.register_model(meta)
+    }
+}
+
+/// 按写入数据中的分片键路由创建与 upsert
+pub(crate) fn route_data(
+    collection: &str,
+    data: &HashMap<String, DataValue>,
+    alias: Option<&str>,
+) -> QuickDbResult<Option<String>> {
+    let Some(sharded) = ShardedCollection::lookup(collection, alias)? else {
+        return Ok(alias.map(str::to_string));
+    };
+    match data.get(&sharded.key.field) {
+        Some(value) if !value.is_null() => sharded.resolve(value).map(Some),
+        _ => Err(QuickDbError::ValidationError {
+            field: sharded.key.field.clone(),
+            message: crate::i18n::tf(
+                "sharding.key_missing",
+                &[("collection", collection), ("field", &sharded.key.field)],
+            ),
+        }),
+    }
+}
+
+/// 按查询条件中的分片键路由，条件不含分片键时向全部分片扇出
+pub(crate) fn route_conditions(
+    collection: &str,
+    conditions: &[QueryConditionWithConfig],
+    alias: Option<&str>,
+) -> QuickDbResult<ShardRoute> {
+    let Some(sharded) = ShardedCollection::lookup(collection, alias)? else {
+        return Ok(ShardRoute::Alias(alias.map(str::to_string)));
+    };
+    for condition in conditions.iter().filter(|condition| condition.field == sharded.key.field) {
+        match (&condition.operator, &condition.value) {
+            (QueryOperator::Eq, value) => return Ok(ShardRoute::Alias(Some(sharded.resolve(value)?))),
+            (QueryOperator::In, DataValue::Array(values)) => {
+                let mut shards: Vec<String> = Vec::new();
+                for value in values {
+                    let alias = sharded.resolve(value)?;
+                    if !shards.contains(&alias) {
+                        shards.push(alias);
+                    }
+                }
+                return Ok(ShardRoute::FanOut(shards));
+            }
+            _ => {}
+        }
+    }
+    sharded.all_shards()
+}
+
+/// 路由条件组查询：分片模型总是向全部分片扇出
+pub(crate) fn route_all(collection: &str, alias: Option<&str>) -> QuickDbResult<ShardRoute> {
+    match ShardedCollection::lookup(collection, alias)? {
+        Some(sharded) => sharded.all_shards(),
+        None => Ok(ShardRoute::Alias(alias.map(str::to_string))),
+    }
+}
+
+/// 路由按 ID 的操作：分片键为 ID 时按 ID 路由，否则向全部分片扇出
+pub(crate) fn route_id(collection: &str, id: &str, alias: Option<&str>) -> QuickDbResult<ShardRoute> {
+    let Some(sharded) = ShardedCollection::lookup(collection, alias)? else {
+        return Ok(ShardRoute::Alias(alias.map(str::to_string)));
+    };
+    if sharded.key.field == "id" || sharded.key.field == "_id" {
+        return Ok(ShardRoute::Alias(Some(sharded.resolve(&DataValue::String(id.to_string()))?)));
+    }
+    sharded.all_shards()
+}
+
+/// 在每个分片上并发执行操作，任一分片失败时返回错误
+pub(crate) async fn fan_out<T, F, Fut>(shards: Vec<String>, operation: F) -> QuickDbResult<Vec<T>>
+where
+    F: Fn(String) -> Fut,
+    Fut: Future<Output = QuickDbResult<T>>,
+{
+    futures::future::try_join_all(shards.into_iter().map(operation)).await
+}
+
+/// 扇出查询时每个分片使用的选项：分片各自返回前 `skip + limit` 条，合并后再统一分页
+pub(crate) fn shard_options(options: Option<&QueryOptions>) -> Option<QueryOptions> {
+    let mut options = options.cloned()?;
+    if let Some(pagination) = &mut options.pagination {
+        pagination.limit = pagination.skip.saturating_add(pagination.limit);
+        pagination.skip = 0;
+    }
+    Some(options)
+}
+
+/// 合并各分片的查询结果：按排序配置稳定排序后应用原始分页
+pub(crate) fn merge_results(results: Vec<Vec<DataValue>>, options: Option<&QueryOptions>) -> Vec<DataValue> {
+    let mut records: Vec<DataValue> = results.into_iter().flatten().collect();
+    let Some(options) = options else {
+        return records;
+    };
+    if !options.sort.is_empty() {
+        records.sort_by(|a, b| {
+            options
+                .sort
+                .iter()
+                .map(|sort| {
+                    let ordering = compare_field(a, b, &sort.field);
+                    match sort.direction {
+                        SortDirection::Asc => ordering,
+                        SortDirection::Desc => ordering.reverse(),
+                    }
+                })
+                .find(|ordering| *ordering != Ordering::Equal)
+                .unwrap_or(Ordering::Equal)
+        });
+    }
+    match &options.pagination {
+        Some(pagination) => records
+            .into_iter()
+            .skip(pagination.skip as usize)
+            .take(pagination.limit as usize)
+            .collect(),
+        None => records,
+    }
+}
+
+/// 按字段比较两条记录：空值（含缺失）最小，不可比较的值视为相等
+fn compare_field(a: &DataValue, b: &DataValue, field: &str) -> Ordering {
+    let value = |record: &DataValue| match record {
+        DataValue::Object(map) => map.get(field).filter(|value| !value.is_null()).cloned(),
+        _ => None,
+    };
+    match (value(a), value(b)) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Less,
+        (Some(_), None) => Ordering::Greater,
+        (Some(a), Some(b)) => crate::model::rules::compare_values(&a, &b).unwrap_or(Ordering::Equal),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TenantResolver;
+
+    impl ShardResolver for TenantResolver {
+        fn resolve(&self, key: &DataValue) -> QuickDbResult<String> {
+            match key {
+                DataValue::String(tenant) if tenant.starts_with('a') => Ok("shard_suite_a".to_string()),
+                _ => Ok("shard_suite_b".to_string()),
+            }
+        }
+
+        fn shards(&self) -> Vec<String> {
+            vec!["shard_suite_a".to_string(), "shard_suite_b".to_string()]
+        }
+    }
+
+    fn record(tenant: &str, score: i64) -> DataValue {
+        DataValue::Object(HashMap::from([
+            ("tenant".to_string(), DataValue::String(tenant.to_string())),
+            ("score".to_string(), DataValue::Int(score)),
+        ]))
+    }
+
+    #[test]
+    fn routes_keyed_operations_and_merges_fan_out() {
+        crate::i18n::ErrorMessageI18n::init_i18n();
+        register_shard_resolver("shard_suite", TenantResolver);
+        register_sharded_model(&ModelMeta {
+            collection_name: "shard_suite_orders".to_string(),
+            shard_key: Some(ShardKey::new("tenant", "shard_suite")),
+            ..Default::default()
+        });
+
+        let data = HashMap::from([("tenant".to_string(), DataValue::String("acme".to_string()))]);
+        assert_eq!(
+            route_data("shard_suite_orders", &data, None).unwrap().as_deref(),
+            Some("shard_suite_a")
+        );
+        assert!(route_data("shard_suite_orders", &HashMap::new(), None).is_err());
+        // 显式别名不经过路由
+        assert_eq!(
+            route_data("shard_suite_orders", &HashMap::new(), Some("manual")).unwrap().as_deref(),
+            Some("manual")
+        );
+        // 元数据已注册到路由到的分片别名下
+        assert!(
+            get_global_pool_manager()
+                .get_model_with_alias("shard_suite_orders", "shard_suite_a")
+                .is_some()
+        );
+
+        let keyed = vec![QueryConditionWithConfig {
+            field: "tenant".to_string(),
+            operator: QueryOperator::Eq,
+            value: DataValue::String("bolt".to_string()),
+            case_insensitive: false,
+        }];
+        assert!(matches!(
+            route_conditions("shard_suite_orders", &keyed, None).unwrap(),
+            ShardRoute::Alias(Some(alias)) if alias == "shard_suite_b"
+        ));
+        assert!(matches!(
+            route_conditions("shard_suite_orders", &[], None).unwrap(),
+            ShardRoute::FanOut(shards) if shards.len() == 2
+        ));
+
+        let options = QueryOptions::new()
+            .with_sort(vec![SortConfig {
+                field: "score".to_string(),
+                direction: SortDirection::Desc,
+            }])
+            .with_pagination(PaginationConfig { skip: 1, limit: 2 });
+        let per_shard = shard_options(Some(&options)).unwrap().pagination.unwrap();
+        assert_eq!((per_shard.skip, per_shard.limit), (0, 3));
+        let merged = merge_results(
+            vec![vec![record("a1", 5), record("a2", 1)], vec![record("b1", 9), record("b2", 3)]],
+            Some(&options),
+        );
+        assert_eq!(merged, vec![record("a1", 5), record("b2", 3)]);
+    }
+}
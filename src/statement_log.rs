@@ -0,0 +1,372 @@
+//! # 语句日志模块
+//!
+//! 启用后以 debug 级别输出渲染后的 SQL 语句与 MongoDB 命令，绑定参数内联到语句中，
+//! 敏感字段（密码、令牌等）的值以 `***` 代替。每条日志带有 [`crate::context`] 中的请求 ID 与用户 ID，
+//! 同一次 ODM 调用产生的全部语句共享同一个请求 ID：
+//!
+//! ```ignore
+//! statement_log::enable_statement_logging(StatementLogConfig::new().redact_field("id_card"));
+//!
+//! statement_log::with_request_id("req-7f3a", async {
+//!     User::find(conditions, None).await
+//! })
+//! .await?;
+//! // [语句] 请求=req-7f3a, 数据库=SQLite, 语句=SELECT * FROM "users" WHERE "password" = '***'
+//! ```
+//!
+//! 未通过 [`with_request_id`] 设置请求 ID 时，每次 ODM 调用自动分配一个
+
+#![cfg_attr(
+    not(any(
+        feature = "sqlite-support",
+        feature = "postgres-support",
+        feature = "mysql-support",
+        feature = "mongodb-support"
+    )),
+    allow(dead_code)
+)]
+
+use crate::context::{current_context, with_context};
+use crate::types::{DataValue, DatabaseType};
+use rat_logger::debug;
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, PoisonError, RwLock};
+
+/// 敏感值的替代文本
+const REDACTED: &str = "***";
+
+/// 默认脱敏的字段名片段
+const DEFAULT_REDACT_FIELDS: &[&str] = &["password", "passwd", "secret", "token", "api_key", "credential"];
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static CONFIG: RwLock<Option<Arc<StatementLogConfig>>> = RwLock::new(None);
+
+/// 语句日志配置
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatementLogConfig {
+    /// 需要脱敏的字段名片段（不区分大小写，字段名包含任一片段即脱敏）
+    pub redact_fields: Vec<String>,
+    /// 单个参数值输出的最大字符数，超出部分截断
+    pub max_value_len: usize,
+}
+
+impl Default for StatementLogConfig {
+    fn default() -> Self {
+        Self {
+            redact_fields: DEFAULT_REDACT_FIELDS.iter().map(|field| field.to_string()).collect(),
+            max_value_len: 256,
+        }
+    }
+}
+
+impl StatementLogConfig {
+    /// 创建默认配置（脱敏常见的密码、令牌类字段）
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 追加需要脱敏的字段名片段
+    pub fn redact_field(mut self, field: impl Into<String>) -> Self {
+        self.redact_fields.push(field.into().to_lowercase());
+        self
+    }
+
+    /// 设置单个参数值输出的最大字符数
+    pub fn max_value_len(mut self, max_value_len: usize) -> Self {
+        self.max_value_len = max_value_len;
+        self
+    }
+
+    fn is_sensitive(&self, field: &str) -> bool {
+        let field = field.to_lowercase();
+        self.redact_fields.iter().any(|pattern| field.contains(pattern.as_str()))
+    }
+}
+
+/// 启用语句日志
+pub fn enable_statement_logging(config: StatementLogConfig) {
+    *CONFIG.write().unwrap_or_else(PoisonError::into_inner) = Some(Arc::new(config));
+    ENABLED.store(true, Ordering::Release);
+}
+
+/// 关闭语句日志
+pub fn disable_statement_logging() {
+    ENABLED.store(false, Ordering::Release);
+    *CONFIG.write().unwrap_or_else(PoisonError::into_inner) = None;
+}
+
+/// 语句日志是否已启用
+pub fn is_statement_logging_enabled() -> bool {
+    ENABLED.load(Ordering::Acquire)
+}
+
+fn config() -> Option<Arc<StatementLogConfig>> {
+    if !is_statement_logging_enabled() {
+        return None;
+    }
+    CONFIG.read().unwrap_or_else(PoisonError::into_inner).clone()
+}
+
+/// 以指定请求 ID 执行 `future`，期间产生的语句日志带有该 ID（保留当前上下文中的用户 ID）
+pub async fn with_request_id<F: Future>(request_id: impl Into<String>, future: F) -> F::Output {
+    let context = current_context().unwrap_or_default().request_id(request_id);
+    with_context(context, future).await
+}
+
+/// 当前任务的请求 ID
+pub fn current_request_id() -> Option<String> {
+    current_context().and_then(|context| context.request_id)
+}
+
+/// 当前上下文的日志标注
+fn context_label() -> String {
+    let context = current_context().unwrap_or_default();
+    match context.user_id {
+        Some(user_id) => format!("请求={}, 用户={}", context.request_id.as_deref().unwrap_or("-"), user_id),
+        None => format!("请求={}", context.request_id.as_deref().unwrap_or("-")),
+    }
+}
+
+/// 记录一条 SQL 语句
+pub(crate) fn log_sql(db_type: DatabaseType, sql: &str, params: &[DataValue]) {
+    let Some(config) = config() else {
+        return;
+    };
+    let statement = render_sql(&config, db_type, sql, params);
+    debug!("[语句] {}, 数据库={:?}, 语句={}", context_label(), db_type, statement);
+}
+
+/// 记录一条 MongoDB 命令，如 `log_mongo("users", "find", &[&filter])`
+#[cfg(feature = "mongodb-support")]
+pub(crate) fn log_mongo(collection: &str, command: &str, documents: &[&mongodb::bson::Document]) {
+    let Some(config) = config() else {
+        return;
+    };
+    let arguments: Vec<String> = documents
+        .iter()
+        .map(|document| redact_document(&config, document).to_string())
+        .collect();
+    debug!(
+        "[语句] {}, 数据库=MongoDB, 语句=db.{}.{}({})",
+        context_label(),
+        collection,
+        command,
+        arguments.join(", ")
+    );
+}
+
+/// 将参数内联到 SQL 中，敏感字段的参数以 `***` 代替
+///
+/// 参数对应的字段按占位符之前最近的带引号标识符推断；INSERT 的 VALUES 部分按列顺序对应
+fn render_sql(config: &StatementLogConfig, db_type: DatabaseType, sql: &str, params: &[DataValue]) -> String {
+    let insert_columns = insert_columns(sql);
+    let mut output = String::with_capacity(sql.len());
+    let mut last_identifier = String::new();
+    let mut sequential = 0;
+    let mut values_index = 0;
+    let mut in_values = false;
+    let mut chars = sql.char_indices().peekable();
+
+    while let Some((offset, ch)) = chars.next() {
+        match ch {
+            '\'' => {
+                // 字符串字面量原样输出
+                output.push(ch);
+                while let Some((_, next)) = chars.next() {
+                    output.push(next);
+                    if next == '\'' {
+                        if chars.peek().is_some_and(|(_, c)| *c == '\'') {
+                            output.push(chars.next().map_or('\'', |(_, c)| c));
+                            continue;
+                        }
+                        break;
+                    }
+                }
+            }
+            '"' | '`' => {
+                let mut identifier = String::new();
+                output.push(ch);
+                for (_, next) in chars.by_ref() {
+                    output.push(next);
+                    if next == ch {
+                        break;
+                    }
+                    identifier.push(next);
+                }
+                last_identifier = identifier;
+            }
+            '?' if db_type != DatabaseType::PostgreSQL => {
+                let field = placeholder_field(&insert_columns, in_values, &mut values_index, &last_identifier);
+                output.push_str(&render_param(config, &field, params.get(sequential)));
+                sequential += 1;
+            }
+            '$' if db_type == DatabaseType::PostgreSQL && chars.peek().is_some_and(|(_, c)| c.is_ascii_digit()) => {
+                let mut digits = String::new();
+                while let Some((_, next)) = chars.peek().copied().filter(|(_, c)| c.is_ascii_digit()) {
+                    digits.push(next);
+                    chars.next();
+                }
+                let index = digits.parse::<usize>().unwrap_or(0);
+                let field = placeholder_field(&insert_columns, in_values, &mut values_index, &last_identifier);
+                output.push_str(&render_param(config, &field, index.checked_sub(1).and_then(|i| params.get(i))));
+            }
+            _ => {
+                output.push(ch);
+                if insert_columns.is_some() && ch.is_ascii_alphabetic() {
+                    let rest = &sql[offset..];
+                    if starts_with_keyword(rest, "VALUES") {
+                        in_values = true;
+                    } else if in_values
+                        && (starts_with_keyword(rest, "ON") || starts_with_keyword(rest, "RETURNING"))
+                    {
+                        in_values = false;
+                    }
+                }
+            }
+        }
+    }
+    output
+}
+
+fn placeholder_field(
+    insert_columns: &Option<Vec<String>>,
+    in_values: bool,
+    values_index: &mut usize,
+    last_identifier: &str,
+) -> String {
+    match insert_columns {
+        Some(columns) if in_values && !columns.is_empty() => {
+            let field = columns[*values_index % columns.len()].clone();
+            *values_index += 1;
+            field
+        }
+        _ => last_identifier.to_string(),
+    }
+}
+
+/// INSERT 语句的列名列表
+fn insert_columns(sql: &str) -> Option<Vec<String>> {
+    let trimmed = sql.trim_start();
+    if !starts_with_keyword(trimmed, "INSERT") {
+        return None;
+    }
+    let open = trimmed.find('(')?;
+    let close = open + trimmed[open..].find(')')?;
+    Some(
+        trimmed[open + 1..close]
+            .split(',')
+            .map(|column| column.trim().trim_matches(|c| c == '"' || c == '`').to_string())
+            .collect(),
+    )
+}
+
+fn starts_with_keyword(text: &str, keyword: &str) -> bool {
+    text.get(..keyword.len()).is_some_and(|prefix| prefix.eq_ignore_ascii_case(keyword))
+        && !text[keyword.len()..].starts_with(|c: char| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn render_param(config: &StatementLogConfig, field: &str, value: Option<&DataValue>) -> String {
+    let Some(value) = value else {
+        return "?".to_string();
+    };
+    if config.is_sensitive(field) {
+        return format!("'{}'", REDACTED);
+    }
+    let text = match value {
+        DataValue::Null => return "NULL".to_string(),
+        DataValue::Bool(value) => return value.to_string(),
+        DataValue::Int(value) => return value.to_string(),
+        DataValue::UInt(value) => return value.to_string(),
+        DataValue::Float(value) => return value.to_string(),
+        DataValue::String(value) => value.clone(),
+        DataValue::Object(_) | DataValue::Json(_) => {
+            let mut json = value.to_json_value();
+            redact_json(config, &mut json);
+            json.to_string()
+        }
+        other => match other.to_json_value() {
+            serde_json::Value::String(value) => value,
+            value => value.to_string(),
+        },
+    };
+    let text = if text.chars().count() > config.max_value_len {
+        format!("{}…", text.chars().take(config.max_value_len).collect::<String>())
+    } else {
+        text
+    };
+    format!("'{}'", text.replace('\'', "''"))
+}
+
+/// JSON 对象中敏感键的值以 `***` 代替
+fn redact_json(config: &StatementLogConfig, value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                if config.is_sensitive(key) {
+                    *value = serde_json::Value::String(REDACTED.to_string());
+                } else {
+                    redact_json(config, value);
+                }
+            }
+        }
+        serde_json::Value::Array(items) => items.iter_mut().for_each(|item| redact_json(config, item)),
+        _ => {}
+    }
+}
+
+#[cfg(feature = "mongodb-support")]
+fn redact_document(config: &StatementLogConfig, document: &mongodb::bson::Document) -> mongodb::bson::Document {
+    use mongodb::bson::Bson;
+
+    fn redact(config: &StatementLogConfig, value: &Bson) -> Bson {
+        match value {
+            Bson::Document(document) => Bson::Document(redact_document(config, document)),
+            Bson::Array(items) => Bson::Array(items.iter().map(|item| redact(config, item)).collect()),
+            other => other.clone(),
+        }
+    }
+
+    document
+        .iter()
+        .map(|(key, value)| {
+            let value = if config.is_sensitive(key) {
+                Bson::String(REDACTED.to_string())
+            } else {
+                redact(config, value)
+            };
+            (key.clone(), value)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_params_and_redacts_sensitive_fields() {
+        let config = StatementLogConfig::new().redact_field("ssn");
+        let params = vec![
+            DataValue::String("alice".to_string()),
+            DataValue::String("hunter2".to_string()),
+            DataValue::Int(30),
+        ];
+
+        let sql = r#"INSERT INTO "users" ("name", "password_hash", "age") VALUES (?, ?, ?)"#;
+        assert_eq!(
+            render_sql(&config, DatabaseType::SQLite, sql, &params),
+            r#"INSERT INTO "users" ("name", "password_hash", "age") VALUES ('alice', '***', 30)"#
+        );
+
+        let sql = r#"UPDATE "users" SET "ssn" = $1 WHERE "name" = $2 AND "note" = 'what''s ?'"#;
+        assert_eq!(
+            render_sql(&config, DatabaseType::PostgreSQL, sql, &params),
+            r#"UPDATE "users" SET "ssn" = '***' WHERE "name" = 'hunter2' AND "note" = 'what''s ?'"#
+        );
+
+        let mut profile = serde_json::json!({"name": "bob", "auth": {"api_key": "k"}});
+        redact_json(&config, &mut profile);
+        assert_eq!(profile, serde_json::json!({"name": "bob", "auth": {"api_key": "***"}}));
+    }
+}
@@ -20,6 +20,9 @@ impl StoredProcedureBuilder {
                 fields: HashMap::new(),
                 procedure_name: name.to_string(),
                 mongo_pipeline: None,
+                output_params: Vec::new(),
+                result_sets: Vec::new(),
+                version: 0,
             },
         }
     }
@@ -57,6 +60,24 @@ impl StoredProcedureBuilder {
         self
     }
 
+    /// 设置定义版本
+    pub fn with_version(mut self, version: u32) -> Self {
+        self.config.version = version;
+        self
+    }
+
+    /// 声明输出参数
+    pub fn with_output_param(mut self, param: OutputParam) -> Self {
+        self.config.output_params.push(param);
+        self
+    }
+
+    /// 追加结果集：执行时在本过程之后依次执行指定的存储过程
+    pub fn with_result_set(mut self, procedure_name: &str) -> Self {
+        self.config.result_sets.push(procedure_name.to_string());
+        self
+    }
+
     /// MongoDB专用：添加聚合管道操作
     pub fn with_mongo_pipeline(
         mut self,
@@ -425,6 +446,27 @@ impl StoredProcedureConfig {
             });
         }
 
+        let mut output_names = std::collections::HashSet::new();
+        for param in &self.output_params {
+            if param.name.is_empty() || !output_names.insert(param.name.as_str()) {
+                return Err(crate::error::QuickDbError::ValidationError {
+                    field: "output_params".to_string(),
+                    message: crate::i18n::tf("sp.output_param_invalid", &[("name", &param.name)]),
+                });
+            }
+        }
+
+        if self
+            .result_sets
+            .iter()
+            .any(|name| name.is_empty() || *name == self.procedure_name)
+        {
+            return Err(crate::error::QuickDbError::ValidationError {
+                field: "result_sets".to_string(),
+                message: crate::i18n::t("sp.result_set_invalid"),
+            });
+        }
+
         // 验证JOIN关系中的字段是否存在
         for join in &self.joins {
             if join.local_field.is_empty() || join.foreign_field.is_empty() {
@@ -41,6 +41,49 @@ pub struct StoredProcedureConfig {
     pub procedure_name: String,
     /// MongoDB聚合管道操作（可选）
     pub mongo_pipeline: Option<Vec<MongoAggregationOperation>>,
+    /// 输出参数声明，从最后一个结果集的首行读取并按声明的类型转换
+    #[serde(default)]
+    pub output_params: Vec<OutputParam>,
+    /// 附加结果集：依次执行的其他存储过程名称，结果排在本过程的结果集之后
+    #[serde(default)]
+    pub result_sets: Vec<String>,
+    /// 定义版本，`deploy_stored_procedure` 据此判断是否需要升级
+    #[serde(default)]
+    pub version: u32,
+}
+
+/// 存储过程部署结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StoredProcedureDeployment {
+    /// 首次创建
+    Created,
+    /// 从旧版本升级
+    Upgraded { from: u32 },
+    /// 已部署相同或更新的版本，未做修改
+    Unchanged { current: u32 },
+}
+
+/// 输出参数类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OutputParamType {
+    String,
+    Int,
+    Float,
+    Bool,
+    DateTime,
+    Json,
+}
+
+/// 输出参数声明
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OutputParam {
+    /// 参数名（对应输出行中的列名）
+    pub name: String,
+    /// 参数类型
+    pub param_type: OutputParamType,
+    /// 是否允许为空
+    #[serde(default)]
+    pub nullable: bool,
 }
 
 /// 存储过程信息（存储在适配器中）
@@ -71,6 +114,127 @@ pub struct StoredProcedureCreateResult {
 pub type StoredProcedureQueryResult =
     Vec<std::collections::HashMap<String, crate::types::DataValue>>;
 
+/// 带类型的存储过程执行结果
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StoredProcedureOutput {
+    /// 各结果集的行（每行为 `DataValue::Object`），按执行顺序排列
+    pub result_sets: Vec<Vec<DataValue>>,
+    /// 按声明类型转换后的输出参数
+    pub output_params: HashMap<String, DataValue>,
+}
+
+impl StoredProcedureOutput {
+    /// 由各结果集组装执行结果
+    ///
+    /// 声明了输出参数时，最后一个结果集视为输出行，从中读取参数后不再作为结果集返回
+    pub fn from_result_sets(
+        result_sets: Vec<StoredProcedureQueryResult>,
+        output_params: &[OutputParam],
+    ) -> crate::error::QuickDbResult<Self> {
+        let mut result_sets: Vec<Vec<DataValue>> = result_sets
+            .into_iter()
+            .map(|rows| rows.into_iter().map(DataValue::Object).collect())
+            .collect();
+        if output_params.is_empty() {
+            return Ok(Self { result_sets, output_params: HashMap::new() });
+        }
+
+        let output_row = match result_sets.pop().and_then(|rows| rows.into_iter().next()) {
+            Some(DataValue::Object(row)) => row,
+            _ => {
+                return Err(crate::quick_error!(
+                    validation,
+                    "output_params",
+                    crate::i18n::t("sp.output_row_missing")
+                ));
+            }
+        };
+        let mut params = HashMap::with_capacity(output_params.len());
+        for param in output_params {
+            let value = param.coerce(output_row.get(&param.name).cloned().unwrap_or(DataValue::Null))?;
+            params.insert(param.name.clone(), value);
+        }
+        Ok(Self { result_sets, output_params: params })
+    }
+
+    /// 第一个结果集
+    pub fn first(&self) -> &[DataValue] {
+        self.result_sets.first().map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// 输出参数值
+    pub fn output(&self, name: &str) -> Option<&DataValue> {
+        self.output_params.get(name)
+    }
+}
+
+impl OutputParam {
+    pub fn new(name: &str, param_type: OutputParamType) -> Self {
+        Self { name: name.to_string(), param_type, nullable: false }
+    }
+
+    /// 允许为空
+    pub fn nullable(mut self) -> Self {
+        self.nullable = true;
+        self
+    }
+
+    /// 把输出值转换为声明的类型
+    fn coerce(&self, value: DataValue) -> crate::error::QuickDbResult<DataValue> {
+        if matches!(value, DataValue::Null) {
+            return if self.nullable {
+                Ok(DataValue::Null)
+            } else {
+                Err(crate::quick_error!(
+                    validation,
+                    &self.name,
+                    crate::i18n::tf("sp.output_param_missing", &[("name", &self.name)])
+                ))
+            };
+        }
+        let converted = match (self.param_type, &value) {
+            (OutputParamType::Json, _) => Some(value.clone()),
+            (OutputParamType::String, DataValue::String(_)) => Some(value.clone()),
+            (OutputParamType::String, _) => Some(DataValue::String(value.to_string())),
+            (OutputParamType::Int, DataValue::Int(_)) => Some(value.clone()),
+            (OutputParamType::Int, DataValue::UInt(v)) => i64::try_from(*v).ok().map(DataValue::Int),
+            (OutputParamType::Int, DataValue::Float(v)) if v.fract() == 0.0 => Some(DataValue::Int(*v as i64)),
+            (OutputParamType::Int, DataValue::Bool(v)) => Some(DataValue::Int(i64::from(*v))),
+            (OutputParamType::Int, DataValue::String(v)) => v.trim().parse().ok().map(DataValue::Int),
+            (OutputParamType::Float, DataValue::Float(_)) => Some(value.clone()),
+            (OutputParamType::Float, DataValue::Int(v)) => Some(DataValue::Float(*v as f64)),
+            (OutputParamType::Float, DataValue::UInt(v)) => Some(DataValue::Float(*v as f64)),
+            (OutputParamType::Float, DataValue::String(v)) => v.trim().parse().ok().map(DataValue::Float),
+            (OutputParamType::Bool, DataValue::Bool(_)) => Some(value.clone()),
+            (OutputParamType::Bool, DataValue::Int(v)) if *v == 0 || *v == 1 => Some(DataValue::Bool(*v == 1)),
+            (OutputParamType::Bool, DataValue::String(v)) => match v.trim() {
+                "true" | "1" => Some(DataValue::Bool(true)),
+                "false" | "0" => Some(DataValue::Bool(false)),
+                _ => None,
+            },
+            (OutputParamType::DateTime, DataValue::DateTime(_) | DataValue::DateTimeUTC(_)) => Some(value.clone()),
+            (OutputParamType::DateTime, DataValue::String(v)) => {
+                chrono::DateTime::parse_from_rfc3339(v).ok().map(DataValue::DateTime)
+            }
+            _ => None,
+        };
+        converted.ok_or_else(|| {
+            crate::quick_error!(
+                validation,
+                &self.name,
+                crate::i18n::tf(
+                    "sp.output_param_type_mismatch",
+                    &[
+                        ("name", &self.name),
+                        ("type", &format!("{:?}", self.param_type)),
+                        ("value", &value.to_string()),
+                    ]
+                )
+            )
+        })
+    }
+}
+
 /// MongoDB聚合管道操作类型
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum MongoAggregationOperation {
@@ -213,3 +377,41 @@ pub enum MongoCondition {
     /// 正则表达式匹配
     Regex { field: String, pattern: String },
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(pairs: &[(&str, DataValue)]) -> HashMap<String, DataValue> {
+        pairs.iter().map(|(k, v)| (k.to_string(), v.clone())).collect()
+    }
+
+    #[test]
+    fn output_params_are_read_from_the_last_result_set_and_coerced() {
+        crate::i18n::ErrorMessageI18n::init_i18n();
+        let sets = vec![
+            vec![row(&[("name", DataValue::String("a".into()))])],
+            vec![row(&[("total", DataValue::String("42".into())), ("avg", DataValue::Int(3))])],
+        ];
+        let params = [
+            OutputParam::new("total", OutputParamType::Int),
+            OutputParam::new("avg", OutputParamType::Float),
+            OutputParam::new("note", OutputParamType::String).nullable(),
+        ];
+        let output = StoredProcedureOutput::from_result_sets(sets.clone(), &params).unwrap();
+        assert_eq!(output.result_sets.len(), 1);
+        assert!(matches!(output.first()[0], DataValue::Object(_)));
+        assert_eq!(output.output("total"), Some(&DataValue::Int(42)));
+        assert_eq!(output.output("avg"), Some(&DataValue::Float(3.0)));
+        assert_eq!(output.output("note"), Some(&DataValue::Null));
+
+        let untyped = StoredProcedureOutput::from_result_sets(sets.clone(), &[]).unwrap();
+        assert_eq!(untyped.result_sets.len(), 2);
+
+        let wrong = [OutputParam::new("total", OutputParamType::Bool)];
+        assert!(StoredProcedureOutput::from_result_sets(sets.clone(), &wrong).is_err());
+        let missing = [OutputParam::new("count", OutputParamType::Int)];
+        assert!(StoredProcedureOutput::from_result_sets(sets, &missing).is_err());
+        assert!(StoredProcedureOutput::from_result_sets(vec![vec![]], &missing).is_err());
+    }
+}
@@ -0,0 +1,370 @@
+//! # 跨数据库复制模块
+//!
+//! 把一张表的数据从一个别名流式复制到另一个别名（例如 SQLite → PostgreSQL）。
+//! 按 `id` 升序分批读取（`id > 上一批最后一个 id`），每批写入完成后回调进度并给出
+//! 续传令牌；中断后把令牌传回 [`CopyOptions::resume_from`] 即可从断点继续：
+//!
+//! ```ignore
+//! let report = sync::copy_table_with_progress("sqlite", "postgres", "users", &CopyOptions::new(), |progress| {
+//!     save_token(&progress.resume_token);
+//! })
+//! .await?;
+//! ```
+//!
+//! 目标别名注册了模型时按字段类型转换源数据（如 SQLite 以字符串返回的日期时间）；
+//! 未注册时使用源别名的模型在目标别名建表。目标别名使用自增 ID 策略时，ID 由目标库重新生成，
+//! 不支持 [`CopyMode::Upsert`]
+
+use crate::error::QuickDbResult;
+use crate::manager::get_global_pool_manager;
+use crate::model::ModelMeta;
+use crate::odm::OdmOperations;
+use crate::serializer::ImportRowError;
+use crate::serializer::transfer::coerce_value;
+use crate::types::data_value::json_value_to_data_value;
+use crate::types::*;
+use rat_logger::{debug, info};
+use std::collections::HashMap;
+
+/// 续传令牌（上一批最后一条记录的 ID，可持久化为字符串）
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResumeToken(String);
+
+impl ResumeToken {
+    fn from_id(id: &DataValue) -> Self {
+        Self(id.to_json_value().to_string())
+    }
+
+    fn last_id(&self) -> QuickDbResult<DataValue> {
+        serde_json::from_str(&self.0).map(json_value_to_data_value).map_err(|e| {
+            crate::quick_error!(
+                validation,
+                "resume_from",
+                crate::i18n::tf("sync.resume_token_invalid", &[("message", &e.to_string())])
+            )
+        })
+    }
+
+    /// 令牌的字符串形式
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for ResumeToken {
+    fn from(token: String) -> Self {
+        Self(token)
+    }
+}
+
+impl std::fmt::Display for ResumeToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// 写入目标表的方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CopyMode {
+    /// 直接插入（ID 冲突的行记为失败）
+    #[default]
+    Insert,
+    /// 按 ID 插入或更新（可重复执行，用于同步）
+    Upsert,
+}
+
+/// 复制选项
+#[derive(Debug, Clone)]
+pub struct CopyOptions {
+    /// 每批读取与写入的行数
+    pub batch_size: usize,
+    /// 源表的过滤条件
+    pub conditions: Vec<QueryConditionWithConfig>,
+    /// 从续传令牌之后继续复制
+    pub resume_from: Option<ResumeToken>,
+    /// 写入方式
+    pub mode: CopyMode,
+    /// 保留源记录的 ID（关闭或目标使用自增策略时由目标库生成，此时不支持 Upsert）
+    pub preserve_ids: bool,
+}
+
+impl Default for CopyOptions {
+    fn default() -> Self {
+        Self {
+            batch_size: 500,
+            conditions: Vec::new(),
+            resume_from: None,
+            mode: CopyMode::Insert,
+            preserve_ids: true,
+        }
+    }
+}
+
+impl CopyOptions {
+    /// 创建默认复制选项
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 设置每批行数（最小为 1）
+    pub fn batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// 设置源表的过滤条件
+    pub fn conditions(mut self, conditions: Vec<QueryConditionWithConfig>) -> Self {
+        self.conditions = conditions;
+        self
+    }
+
+    /// 从续传令牌之后继续复制
+    pub fn resume_from(mut self, token: ResumeToken) -> Self {
+        self.resume_from = Some(token);
+        self
+    }
+
+    /// 设置写入方式
+    pub fn mode(mut self, mode: CopyMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// 设置是否保留源记录的 ID
+    pub fn preserve_ids(mut self, preserve_ids: bool) -> Self {
+        self.preserve_ids = preserve_ids;
+        self
+    }
+}
+
+/// 复制进度（每写完一批回调一次）
+#[derive(Debug, Clone)]
+pub struct CopyProgress {
+    /// 表名
+    pub table: String,
+    /// 已写入的行数（本次调用）
+    pub copied: usize,
+    /// 写入失败的行数（本次调用）
+    pub failed: usize,
+    /// 从此令牌继续即可跳过已处理的行
+    pub resume_token: ResumeToken,
+}
+
+/// 复制结果
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CopyReport {
+    /// 写入的行数
+    pub copied: usize,
+    /// 写入失败的行（行号为本次复制中的序号，从 1 开始）
+    pub errors: Vec<ImportRowError>,
+    /// 最后处理的记录对应的续传令牌（没有处理任何记录时为 `None`）
+    pub resume_token: Option<ResumeToken>,
+}
+
+/// 把表数据从源别名复制到目标别名
+pub async fn copy_table(src_alias: &str, dst_alias: &str, table: &str, options: &CopyOptions) -> QuickDbResult<CopyReport> {
+    copy_table_with_progress(src_alias, dst_alias, table, options, |_| {}).await
+}
+
+/// 把表数据从源别名复制到目标别名，每写完一批回调一次进度
+///
+/// 单行写入失败不会中断复制，计入 [`CopyReport::errors`]；读取源表失败时返回错误，
+/// 此前回调的进度令牌仍可用于续传
+pub async fn copy_table_with_progress<F>(
+    src_alias: &str,
+    dst_alias: &str,
+    table: &str,
+    options: &CopyOptions,
+    mut on_progress: F,
+) -> QuickDbResult<CopyReport>
+where
+    F: FnMut(&CopyProgress),
+{
+    if options.mode == CopyMode::Upsert && !options.preserve_ids {
+        return Err(crate::quick_error!(
+            validation,
+            "mode",
+            crate::i18n::t("sync.upsert_requires_ids")
+        ));
+    }
+    if options.mode == CopyMode::Upsert
+        && matches!(get_global_pool_manager().get_id_strategy(dst_alias)?, IdStrategy::AutoIncrement)
+    {
+        return Err(crate::quick_error!(
+            validation,
+            "mode",
+            crate::i18n::tf("sync.upsert_auto_increment", &[("alias", dst_alias)])
+        ));
+    }
+    let dst_meta = ensure_target_table(src_alias, dst_alias, table).await?;
+    let odm = crate::odm::get_odm_manager().await;
+    let batch_size = options.batch_size.max(1) as u64;
+
+    let mut report = CopyReport {
+        resume_token: options.resume_from.clone(),
+        ..Default::default()
+    };
+    let mut last_id = match &options.resume_from {
+        Some(token) => Some(token.last_id()?),
+        None => None,
+    };
+    let mut row = 0;
+    loop {
+        let mut conditions = options.conditions.clone();
+        if let Some(last_id) = &last_id {
+            conditions.push(QueryConditionWithConfig {
+                field: "id".to_string(),
+                operator: QueryOperator::Gt,
+                value: last_id.clone(),
+                case_insensitive: false,
+            });
+        }
+        let query = QueryOptions::new()
+            .with_sort(vec![SortConfig {
+                field: "id".to_string(),
+                direction: SortDirection::Asc,
+            }])
+            .with_pagination(PaginationConfig {
+                skip: 0,
+                limit: batch_size,
+            });
+        let page = odm.find(table, conditions, Some(query), Some(src_alias)).await?;
+        let page_len = page.len() as u64;
+        let Some(page_last_id) = page.last().and_then(record_id) else {
+            break;
+        };
+
+        let writes = page.into_iter().map(|record| {
+            row += 1;
+            let data = convert_record(record, dst_meta.as_ref(), options.preserve_ids);
+            let odm = &odm;
+            async move {
+                let result = match options.mode {
+                    CopyMode::Insert => odm.create(table, data, Some(dst_alias)).await,
+                    CopyMode::Upsert => odm.upsert(table, data, vec!["id".to_string()], Some(dst_alias)).await,
+                };
+                (row, result)
+            }
+        });
+        let mut failed = 0;
+        for (row, result) in futures::future::join_all(writes.collect::<Vec<_>>()).await {
+            match result {
+                Ok(_) => report.copied += 1,
+                Err(e) => {
+                    failed += 1;
+                    report.errors.push(ImportRowError {
+                        row,
+                        message: e.to_string(),
+                    });
+                }
+            }
+        }
+
+        let token = ResumeToken::from_id(&page_last_id);
+        debug!("复制批次完成: 表={}, 令牌={}, 失败={}", table, token, failed);
+        report.resume_token = Some(token.clone());
+        on_progress(&CopyProgress {
+            table: table.to_string(),
+            copied: report.copied,
+            failed: report.errors.len(),
+            resume_token: token,
+        });
+
+        if page_len < batch_size {
+            break;
+        }
+        last_id = Some(page_last_id);
+    }
+
+    info!(
+        "复制表完成: 表={}, {} → {}, 写入={}, 失败={}",
+        table,
+        src_alias,
+        dst_alias,
+        report.copied,
+        report.errors.len()
+    );
+    Ok(report)
+}
+
+/// 确保目标表存在，返回目标别名下的模型元数据
+///
+/// 目标别名未注册该表的模型时，复制源别名的模型注册到目标别名后建表
+async fn ensure_target_table(src_alias: &str, dst_alias: &str, table: &str) -> QuickDbResult<Option<ModelMeta>> {
+    let manager = get_global_pool_manager();
+    if let Some(meta) = manager.get_model_with_alias(table, dst_alias) {
+        manager.ensure_table_and_indexes(table, dst_alias).await?;
+        return Ok(Some(meta));
+    }
+    let Some(mut meta) = manager.get_model_with_alias(table, src_alias) else {
+        return Ok(None);
+    };
+    meta.database_alias = Some(dst_alias.to_string());
+    manager.register_model(meta.clone())?;
+    manager.ensure_table_and_indexes(table, dst_alias).await?;
+    Ok(Some(meta))
+}
+
+fn record_id(record: &DataValue) -> Option<DataValue> {
+    match record {
+        DataValue::Object(map) => map.get("id").or_else(|| map.get("_id")).cloned(),
+        _ => None,
+    }
+}
+
+/// 按目标模型的字段类型转换记录，无法转换的值原样保留由目标库报错
+fn convert_record(record: DataValue, meta: Option<&ModelMeta>, preserve_ids: bool) -> HashMap<String, DataValue> {
+    let DataValue::Object(mut data) = record else {
+        return HashMap::new();
+    };
+    if !preserve_ids {
+        data.remove("id");
+        data.remove("_id");
+    }
+    let Some(meta) = meta else {
+        return data;
+    };
+    data.into_iter()
+        .map(|(field, value)| {
+            let value = match meta.fields.get(&field) {
+                Some(def) => coerce_value(value.clone(), &def.field_type).unwrap_or(value),
+                None => value,
+            };
+            (field, value)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{boolean_field, datetime_field};
+
+    #[test]
+    fn converts_records_for_target_types_and_round_trips_tokens() {
+        let meta = ModelMeta {
+            collection_name: "events".to_string(),
+            fields: HashMap::from([
+                ("at".to_string(), datetime_field()),
+                ("active".to_string(), boolean_field()),
+            ]),
+            ..Default::default()
+        };
+        let record = DataValue::Object(HashMap::from([
+            ("id".to_string(), DataValue::Int(7)),
+            ("at".to_string(), DataValue::String("2024-01-01T00:00:00+00:00".to_string())),
+            ("active".to_string(), DataValue::Int(1)),
+        ]));
+
+        let data = convert_record(record.clone(), Some(&meta), true);
+        assert!(matches!(data["at"], DataValue::DateTime(_)));
+        assert_eq!(data["active"], DataValue::Bool(true));
+        assert_eq!(data["id"], DataValue::Int(7));
+        assert!(!convert_record(record.clone(), None, false).contains_key("id"));
+
+        let token = ResumeToken::from_id(&record_id(&record).unwrap());
+        let restored = ResumeToken::from(token.to_string());
+        assert_eq!(restored.last_id().unwrap(), DataValue::Int(7));
+        assert!(ResumeToken::from("{bad".to_string()).last_id().is_err());
+    }
+}
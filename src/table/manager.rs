@@ -2,15 +2,32 @@
 //!
 //! 提供表的创建、检查、迁移等管理功能
 
-use super::schema::{ColumnDefinition, ColumnType, TableSchema};
-use super::version::{MigrationScriptType, SchemaVersion, VersionManager};
-use crate::adapter::DatabaseAdapter;
+use super::partition::PartitionSpec;
+#[cfg(feature = "mysql-support")]
+use super::partition::{MYSQL_MAX_PARTITION, PartitionRange};
+use super::schema::{ColumnDefinition, TableSchema};
+use super::version::{MigrationScriptType, VersionManager};
 use crate::error::{QuickDbError, QuickDbResult};
 use crate::manager::PoolManager;
-use rat_logger::info;
+use crate::pool::ConnectionPool;
+use crate::security::DatabaseSecurityValidator;
+#[cfg(any(feature = "postgres-support", feature = "mysql-support"))]
+use crate::security::{quote_identifier, quote_table_identifier};
+#[cfg(feature = "mysql-support")]
+use crate::security::split_qualified_name;
+#[cfg(feature = "mysql-support")]
+use crate::types::DataValue;
+#[cfg(any(feature = "postgres-support", feature = "mysql-support"))]
+use crate::types::DatabaseType;
+use chrono::NaiveDate;
+#[cfg(any(feature = "postgres-support", feature = "mysql-support"))]
+use rat_logger::debug;
+use rat_logger::{error, info};
 use std::collections::HashMap;
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use std::time::Duration;
+use tokio::sync::{RwLock, watch};
+use tokio::task::JoinHandle;
 
 /// 表管理器
 #[derive(Debug)]
@@ -77,6 +94,21 @@ impl Default for TableCreateOptions {
     }
 }
 
+/// 分区维护任务句柄
+#[derive(Debug)]
+pub struct PartitionMaintenanceHandle {
+    shutdown: watch::Sender<bool>,
+    task: JoinHandle<()>,
+}
+
+impl PartitionMaintenanceHandle {
+    /// 停止分区维护并等待当前一轮结束
+    pub async fn shutdown(self) {
+        let _ = self.shutdown.send(true);
+        let _ = self.task.await;
+    }
+}
+
 /// 表检查结果
 #[derive(Debug, Clone)]
 pub struct TableCheckResult {
@@ -133,7 +165,7 @@ impl TableManager {
         // 从数据库检查
         let connection = self.pool_manager.get_connection(Some("default")).await?;
         let db_type = self.pool_manager.get_database_type("default")?;
-        let adapter = crate::adapter::create_adapter(&db_type)?;
+        let _adapter = crate::adapter::create_adapter(&db_type)?;
 
         // TODO: 实现 table_exists 方法
         // 这需要在 DatabaseAdapter trait 中添加 table_exists 方法
@@ -160,9 +192,9 @@ impl TableManager {
 
         // 检查表是否已存在
         if options.if_not_exists {
-            let exists = self.check_table_exists(&schema.name).await?;
+            let exists = self.check_table_exists(&schema.qualified_name()).await?;
             if exists {
-                info!("表 {} 已存在，跳过创建", schema.name);
+                info!("表 {} 已存在，跳过创建", schema.qualified_name());
                 return Ok(());
             }
         }
@@ -176,107 +208,250 @@ impl TableManager {
             })?
             .clone();
 
-        // 将TableSchema转换为HashMap<String, FieldDefinition>
-        let mut fields = std::collections::HashMap::new();
-        for column in &schema.columns {
-            // 将ColumnType转换为FieldType
-            let field_type = match &column.column_type {
-                crate::table::schema::ColumnType::Integer => crate::model::FieldType::Integer {
-                    min_value: None,
-                    max_value: None,
-                },
-                crate::table::schema::ColumnType::BigInteger => crate::model::FieldType::BigInteger,
-                crate::table::schema::ColumnType::Float => crate::model::FieldType::Float {
-                    min_value: None,
-                    max_value: None,
-                },
-                crate::table::schema::ColumnType::Double => crate::model::FieldType::Double,
-                crate::table::schema::ColumnType::String { length } => {
-                    crate::model::FieldType::String {
-                        max_length: length.map(|l| l as usize),
-                        min_length: None,
-                        regex: None,
-                    }
-                }
-                crate::table::schema::ColumnType::Text => crate::model::FieldType::Text,
-                crate::table::schema::ColumnType::Boolean => crate::model::FieldType::Boolean,
-                crate::table::schema::ColumnType::DateTime => crate::model::FieldType::DateTime,
-                crate::table::schema::ColumnType::Date => crate::model::FieldType::Date,
-                crate::table::schema::ColumnType::Time => crate::model::FieldType::Time,
-                crate::table::schema::ColumnType::Json => crate::model::FieldType::Json,
-                crate::table::schema::ColumnType::Binary { length: _ } => {
-                    crate::model::FieldType::Binary
-                }
-                crate::table::schema::ColumnType::Uuid => crate::model::FieldType::Uuid,
-                crate::table::schema::ColumnType::Decimal { precision, scale } => {
-                    crate::model::FieldType::Decimal {
-                        precision: *precision as u8,
-                        scale: *scale as u8,
-                    }
-                }
-                crate::table::schema::ColumnType::SmallInteger => {
-                    crate::model::FieldType::Integer {
-                        min_value: None,
-                        max_value: None,
-                    }
-                }
-                crate::table::schema::ColumnType::LongText => crate::model::FieldType::Text,
-                crate::table::schema::ColumnType::Timestamp => crate::model::FieldType::DateTime,
-                crate::table::schema::ColumnType::Blob => crate::model::FieldType::Binary,
-                crate::table::schema::ColumnType::Enum { values: _ } => {
-                    crate::model::FieldType::String {
-                        max_length: None,
-                        min_length: None,
-                        regex: None,
-                    }
-                }
-                crate::table::schema::ColumnType::Custom { type_name: _ } => {
-                    crate::model::FieldType::String {
-                        max_length: None,
-                        min_length: None,
-                        regex: None,
-                    }
-                }
-            };
-            fields.insert(
-                column.name.clone(),
-                crate::model::FieldDefinition::new(field_type),
-            );
-        }
+        let fields = schema_fields(schema);
 
-        // 使用ConnectionPool的create_table方法
-        let result = pool
-            .create_table(&schema.name, &fields, &pool.db_config.id_strategy)
-            .await;
+        // 使用ConnectionPool的create_table方法，声明了分区时创建分区表
+        let result = match &schema.partition {
+            Some(partition) => self.create_partitioned_table(&pool, schema, partition, &fields).await,
+            None => {
+                pool.create_table(&schema.qualified_name(), &fields, &pool.db_config.id_strategy)
+                    .await
+            }
+        };
 
         if result.is_ok() {
             // 更新缓存
             {
                 let mut cache = self.existence_cache.write().await;
-                cache.insert(schema.name.clone(), true);
+                cache.insert(schema.qualified_name(), true);
             }
 
             {
                 let mut cache = self.schema_cache.write().await;
-                cache.insert(schema.name.clone(), schema.clone());
+                cache.insert(schema.qualified_name(), schema.clone());
             }
 
             // 注册版本
             {
                 let mut version_manager = self.version_manager.write().await;
                 version_manager.register_version(
-                    schema.name.clone(),
+                    schema.qualified_name(),
                     schema.clone(),
                     Some(crate::i18n::t("table.initial_version")),
                 )?;
             }
 
-            info!("成功创建表: {}", schema.name);
+            info!("成功创建表: {}", schema.qualified_name());
         }
 
         result
     }
 
+    /// 创建分区表：PostgreSQL 以模板表 `表名_template` 的列定义创建分区父表，
+    /// MySQL 先创建普通表并在补建分区时转换为分区表，SQLite 与 MongoDB 不创建父表
+    #[cfg_attr(not(feature = "postgres-support"), allow(unused_variables))]
+    async fn create_partitioned_table(
+        &self,
+        pool: &ConnectionPool,
+        schema: &TableSchema,
+        partition: &PartitionSpec,
+        fields: &HashMap<String, crate::model::FieldDefinition>,
+    ) -> QuickDbResult<()> {
+        let table = schema.qualified_name();
+        match pool.db_config.db_type {
+            #[cfg(feature = "postgres-support")]
+            DatabaseType::PostgreSQL => {
+                // 父表的列与默认值取自模板表，模板表保留（自增序列归属于它），不写入数据
+                let template = format!("{}_template", table);
+                pool.create_table(&template, fields, &pool.db_config.id_strategy).await?;
+                let alias = &pool.db_config.alias;
+                let sql = format!(
+                    "CREATE TABLE IF NOT EXISTS {} (LIKE {} INCLUDING DEFAULTS) PARTITION BY RANGE ({})",
+                    quote_table_identifier(&self.pool_manager.physical_table_name(&table, alias), DatabaseType::PostgreSQL),
+                    quote_table_identifier(&self.pool_manager.physical_table_name(&template, alias), DatabaseType::PostgreSQL),
+                    quote_identifier(&partition.column, DatabaseType::PostgreSQL),
+                );
+                self.execute_ddl(vec![sql]).await?;
+            }
+            #[cfg(feature = "mysql-support")]
+            DatabaseType::MySQL => {
+                pool.create_table(&table, fields, &pool.db_config.id_strategy).await?;
+            }
+            _ => {}
+        }
+        self.ensure_partitions(schema, chrono::Utc::now().date_naive()).await?;
+        Ok(())
+    }
+
+    /// 创建 `today` 所在周期及之后 `premake` 个周期中尚不存在的分区，返回这些周期的分区名
+    pub async fn ensure_partitions(&self, schema: &TableSchema, today: NaiveDate) -> QuickDbResult<Vec<String>> {
+        let table = schema.qualified_name();
+        let partition = schema.partition.as_ref().ok_or_else(|| QuickDbError::ValidationError {
+            field: "partition".to_string(),
+            message: crate::i18n::tf("table.partition_not_declared", &[("name", &table)]),
+        })?;
+        let pools = self.pool_manager.get_connection_pools();
+        let pool = pools
+            .get("default")
+            .ok_or_else(|| QuickDbError::ConfigError {
+                message: crate::i18n::t("table.cannot_get_default_pool"),
+            })?
+            .clone();
+        let db_type = pool.db_config.db_type;
+        let validator = DatabaseSecurityValidator::new(db_type);
+        validator.validate_table_name(&table)?;
+        validator.validate_field_name(&partition.column)?;
+
+        let ranges = partition.upcoming(&table, today);
+        match db_type {
+            #[cfg(feature = "postgres-support")]
+            DatabaseType::PostgreSQL => {
+                let alias = &pool.db_config.alias;
+                let parent = quote_table_identifier(&self.pool_manager.physical_table_name(&table, alias), db_type);
+                let statements = ranges
+                    .iter()
+                    .map(|range| {
+                        format!(
+                            "CREATE TABLE IF NOT EXISTS {} PARTITION OF {} FOR VALUES FROM ('{}') TO ('{}')",
+                            quote_table_identifier(&self.pool_manager.physical_table_name(&range.name, alias), db_type),
+                            parent,
+                            range.from,
+                            range.to
+                        )
+                    })
+                    .collect();
+                self.execute_ddl(statements).await?;
+            }
+            #[cfg(feature = "mysql-support")]
+            DatabaseType::MySQL => {
+                let physical = self.pool_manager.physical_table_name(&table, &pool.db_config.alias);
+                self.ensure_mysql_partitions(&physical, &partition.column, &ranges).await?;
+            }
+            _ => {
+                // 每个周期一张后缀表/集合
+                let fields = schema_fields(schema);
+                for range in &ranges {
+                    pool.create_table(&range.name, &fields, &pool.db_config.id_strategy).await?;
+                }
+            }
+        }
+
+        info!("已确保表 {} 的分区: {} 个周期", table, ranges.len());
+        Ok(ranges.into_iter().map(|range| range.name).collect())
+    }
+
+    /// MySQL 补建分区：未分区的表转换为按分区列划分的分区表（主键补充分区列），
+    /// 已分区的表从兜底分区中拆分出缺少的周期
+    #[cfg(feature = "mysql-support")]
+    async fn ensure_mysql_partitions(
+        &self,
+        table: &str,
+        column: &str,
+        ranges: &[PartitionRange],
+    ) -> QuickDbResult<()> {
+        let (schema, name) = split_qualified_name(table);
+        let params = vec![
+            schema.map_or(DataValue::Null, |schema| DataValue::String(schema.to_string())),
+            DataValue::String(name.to_string()),
+        ];
+        let existing: Vec<String> = self
+            .pool_manager
+            .with_connection(Some("default"), |mut conn| async move {
+                conn.query(
+                    "SELECT PARTITION_NAME AS name FROM information_schema.PARTITIONS \
+                     WHERE TABLE_SCHEMA = COALESCE(?, DATABASE()) AND TABLE_NAME = ? AND PARTITION_NAME IS NOT NULL",
+                    &params,
+                )
+                .await
+            })
+            .await?
+            .into_iter()
+            .filter_map(|row| match row {
+                DataValue::Object(mut row) => match row.remove("name") {
+                    Some(DataValue::String(name)) => Some(name),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect();
+
+        let safe_table = quote_table_identifier(table, DatabaseType::MySQL);
+        let safe_column = quote_identifier(column, DatabaseType::MySQL);
+        let mut definitions: Vec<String> = ranges
+            .iter()
+            .filter(|range| !existing.contains(&range.mysql_name))
+            .map(|range| format!("PARTITION {} VALUES LESS THAN ('{}')", range.mysql_name, range.to))
+            .collect();
+        if definitions.is_empty() {
+            return Ok(());
+        }
+        definitions.push(format!("PARTITION {} VALUES LESS THAN (MAXVALUE)", MYSQL_MAX_PARTITION));
+
+        let statements = if existing.is_empty() {
+            vec![
+                // 分区表的主键必须包含分区列
+                format!(
+                    "ALTER TABLE {} DROP PRIMARY KEY, ADD PRIMARY KEY ({}, {})",
+                    safe_table,
+                    quote_identifier("id", DatabaseType::MySQL),
+                    safe_column
+                ),
+                format!(
+                    "ALTER TABLE {} PARTITION BY RANGE COLUMNS({}) ({})",
+                    safe_table,
+                    safe_column,
+                    definitions.join(", ")
+                ),
+            ]
+        } else {
+            vec![format!(
+                "ALTER TABLE {} REORGANIZE PARTITION {} INTO ({})",
+                safe_table,
+                MYSQL_MAX_PARTITION,
+                definitions.join(", ")
+            )]
+        };
+        self.execute_ddl(statements).await
+    }
+
+    /// 在默认数据库的同一连接上依次执行 DDL 语句
+    #[cfg(any(feature = "postgres-support", feature = "mysql-support"))]
+    async fn execute_ddl(&self, statements: Vec<String>) -> QuickDbResult<()> {
+        self.pool_manager
+            .with_connection(Some("default"), |mut conn| async move {
+                for sql in &statements {
+                    debug!("执行分区DDL: {}", sql);
+                    conn.execute(sql, &[]).await?;
+                }
+                Ok(())
+            })
+            .await
+    }
+
+    /// 启动分区维护任务，每隔 `interval` 为声明了分区的表补建之后的周期
+    pub fn start_partition_maintenance(
+        self: &Arc<Self>,
+        schema: TableSchema,
+        interval: Duration,
+    ) -> PartitionMaintenanceHandle {
+        let (shutdown, mut shutdown_rx) = watch::channel(false);
+        let manager = self.clone();
+        let task = tokio::spawn(async move {
+            loop {
+                if let Err(e) = manager.ensure_partitions(&schema, chrono::Utc::now().date_naive()).await {
+                    error!("补建表 {} 的分区失败: {}", schema.qualified_name(), e);
+                }
+                tokio::select! {
+                    changed = shutdown_rx.changed() => if changed.is_err() || *shutdown_rx.borrow() {
+                        break;
+                    },
+                    _ = tokio::time::sleep(interval) => {}
+                }
+            }
+        });
+        PartitionMaintenanceHandle { shutdown, task }
+    }
+
     /// 删除表
     pub async fn drop_table(&self, table_name: &str) -> QuickDbResult<()> {
         // 获取默认连接池
@@ -326,7 +501,7 @@ impl TableManager {
         schema: &TableSchema,
         options: Option<TableCreateOptions>,
     ) -> QuickDbResult<()> {
-        let table_name = &schema.name;
+        let table_name = &schema.qualified_name();
 
         info!("开始删除并重建表: {}", table_name);
 
@@ -362,7 +537,7 @@ impl TableManager {
         // 从数据库获取
         let connection = self.pool_manager.get_connection(Some("default")).await?;
         let db_type = self.pool_manager.get_database_type("default")?;
-        let adapter = crate::adapter::create_adapter(&db_type)?;
+        let _adapter = crate::adapter::create_adapter(&db_type)?;
 
         // TODO: 实现 get_table_schema 方法
         // 这需要在 DatabaseAdapter trait 中添加 get_table_schema 方法
@@ -381,9 +556,9 @@ impl TableManager {
 
     /// 列出所有表
     pub async fn list_tables(&self) -> QuickDbResult<Vec<String>> {
-        let mut connection = self.pool_manager.get_connection(Some("default")).await?;
+        let connection = self.pool_manager.get_connection(Some("default")).await?;
         let db_type = self.pool_manager.get_database_type("default")?;
-        let adapter = crate::adapter::create_adapter(&db_type)?;
+        let _adapter = crate::adapter::create_adapter(&db_type)?;
 
         // TODO: 实现 list_tables 方法
         // 这需要在 DatabaseAdapter trait 中添加 list_tables 方法
@@ -504,17 +679,17 @@ impl TableManager {
             return Ok(());
         }
 
-        let exists = self.check_table_exists(&schema.name).await?;
+        let exists = self.check_table_exists(&schema.qualified_name()).await?;
 
         if !exists {
-            info!("表 {} 不存在，自动创建", schema.name);
+            info!("表 {} 不存在，自动创建", schema.qualified_name());
             self.create_table(schema, None).await?;
         } else if self.auto_migrate {
             // 检查是否需要迁移
-            let status = self.check_table_status(&schema.name).await?;
+            let status = self.check_table_status(&schema.qualified_name()).await?;
             if status.needs_migration {
-                info!("表 {} 需要迁移", schema.name);
-                self.migrate_table(&schema.name, None).await?;
+                info!("表 {} 需要迁移", schema.qualified_name());
+                self.migrate_table(&schema.qualified_name(), None).await?;
             }
         }
 
@@ -529,12 +704,12 @@ impl TableManager {
     ) -> QuickDbResult<u32> {
         let mut version_manager = self.version_manager.write().await;
         let version =
-            version_manager.register_version(schema.name.clone(), schema.clone(), description)?;
+            version_manager.register_version(schema.qualified_name(), schema.clone(), description)?;
 
         // 更新缓存
         {
             let mut cache = self.schema_cache.write().await;
-            cache.insert(schema.name.clone(), schema);
+            cache.insert(schema.qualified_name(), schema);
         }
 
         Ok(version)
@@ -614,3 +789,74 @@ impl TableManager {
         stats
     }
 }
+
+/// 将TableSchema转换为HashMap<String, FieldDefinition>
+fn schema_fields(schema: &TableSchema) -> HashMap<String, crate::model::FieldDefinition> {
+    let mut fields = HashMap::new();
+    for column in &schema.columns {
+        // 将ColumnType转换为FieldType
+        let field_type = match &column.column_type {
+            crate::table::schema::ColumnType::Integer => crate::model::FieldType::Integer {
+                min_value: None,
+                max_value: None,
+            },
+            crate::table::schema::ColumnType::BigInteger => crate::model::FieldType::BigInteger,
+            crate::table::schema::ColumnType::Float => crate::model::FieldType::Float {
+                min_value: None,
+                max_value: None,
+            },
+            crate::table::schema::ColumnType::Double => crate::model::FieldType::Double,
+            crate::table::schema::ColumnType::String { length } => {
+                crate::model::FieldType::String {
+                    max_length: length.map(|l| l as usize),
+                    min_length: None,
+                    regex: None,
+                }
+            }
+            crate::table::schema::ColumnType::Text => crate::model::FieldType::Text,
+            crate::table::schema::ColumnType::Boolean => crate::model::FieldType::Boolean,
+            crate::table::schema::ColumnType::DateTime => crate::model::FieldType::DateTime,
+            crate::table::schema::ColumnType::Date => crate::model::FieldType::Date,
+            crate::table::schema::ColumnType::Time => crate::model::FieldType::Time,
+            crate::table::schema::ColumnType::Json => crate::model::FieldType::Json,
+            crate::table::schema::ColumnType::Binary { length: _ } => {
+                crate::model::FieldType::Binary
+            }
+            crate::table::schema::ColumnType::Uuid => crate::model::FieldType::Uuid,
+            crate::table::schema::ColumnType::Decimal { precision, scale } => {
+                crate::model::FieldType::Decimal {
+                    precision: *precision as u8,
+                    scale: *scale as u8,
+                }
+            }
+            crate::table::schema::ColumnType::SmallInteger => {
+                crate::model::FieldType::Integer {
+                    min_value: None,
+                    max_value: None,
+                }
+            }
+            crate::table::schema::ColumnType::LongText => crate::model::FieldType::Text,
+            crate::table::schema::ColumnType::Timestamp => crate::model::FieldType::DateTime,
+            crate::table::schema::ColumnType::Blob => crate::model::FieldType::Binary,
+            crate::table::schema::ColumnType::Enum { values: _ } => {
+                crate::model::FieldType::String {
+                    max_length: None,
+                    min_length: None,
+                    regex: None,
+                }
+            }
+            crate::table::schema::ColumnType::Custom { type_name: _ } => {
+                crate::model::FieldType::String {
+                    max_length: None,
+                    min_length: None,
+                    regex: None,
+                }
+            }
+        };
+        fields.insert(
+            column.name.clone(),
+            crate::model::FieldDefinition::new(field_type),
+        );
+    }
+    fields
+}
@@ -0,0 +1,227 @@
+//! 按时间分区
+//!
+//! 通过 [`TableSchema::with_partition`](super::TableSchema::with_partition) 声明按日或按月分区，
+//! [`TableManager`](super::TableManager) 建表时按数据库类型选择分区方式：
+//!
+//! - PostgreSQL：原生声明式分区（`PARTITION BY RANGE`），每个周期一个子表 `表名_2026_10`
+//! - MySQL：`PARTITION BY RANGE COLUMNS` 分区表，每个周期一个分区 `p2026_10`，另有兜底分区 `p_max`
+//! - SQLite / MongoDB：每个周期一张后缀表/集合 `表名_2026_10`，写入时通过
+//!   [`PartitionSpec::table_for`] 取得记录所属的表名
+//!
+//! 建表时创建当前周期和之后 `premake` 个周期的分区，后续周期由
+//! [`TableManager::ensure_partitions`](super::TableManager::ensure_partitions) 或周期维护任务补建
+
+use crate::security::split_qualified_name;
+use crate::types::DatabaseType;
+use chrono::{Datelike, Duration, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+/// MySQL 兜底分区名（容纳尚未建立分区的周期）
+#[cfg(feature = "mysql-support")]
+pub(crate) const MYSQL_MAX_PARTITION: &str = "p_max";
+
+/// 分区周期
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PartitionInterval {
+    /// 按日分区
+    Daily,
+    /// 按月分区
+    Monthly,
+}
+
+impl PartitionInterval {
+    /// 日期所在周期的起始日
+    pub fn period_start(self, date: NaiveDate) -> NaiveDate {
+        match self {
+            Self::Daily => date,
+            Self::Monthly => date.with_day(1).unwrap_or(date),
+        }
+    }
+
+    /// 下一个周期的起始日
+    pub fn next_period(self, start: NaiveDate) -> NaiveDate {
+        match self {
+            Self::Daily => start + Duration::days(1),
+            Self::Monthly => {
+                let (year, month) = if start.month() == 12 {
+                    (start.year() + 1, 1)
+                } else {
+                    (start.year(), start.month() + 1)
+                };
+                NaiveDate::from_ymd_opt(year, month, 1).unwrap_or(start)
+            }
+        }
+    }
+
+    /// 周期的名称后缀，如 `2026_10` 或 `2026_10_14`
+    fn suffix(self, start: NaiveDate) -> String {
+        match self {
+            Self::Daily => start.format("%Y_%m_%d").to_string(),
+            Self::Monthly => start.format("%Y_%m").to_string(),
+        }
+    }
+}
+
+/// 时间分区声明
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartitionSpec {
+    /// 分区依据的日期/时间列
+    pub column: String,
+    /// 分区周期
+    pub interval: PartitionInterval,
+    /// 除当前周期外预先创建的周期数
+    #[serde(default = "default_premake")]
+    pub premake: u32,
+}
+
+fn default_premake() -> u32 {
+    2
+}
+
+impl PartitionSpec {
+    /// 按月分区
+    pub fn monthly(column: impl Into<String>) -> Self {
+        Self {
+            column: column.into(),
+            interval: PartitionInterval::Monthly,
+            premake: default_premake(),
+        }
+    }
+
+    /// 按日分区
+    pub fn daily(column: impl Into<String>) -> Self {
+        Self {
+            column: column.into(),
+            interval: PartitionInterval::Daily,
+            premake: default_premake(),
+        }
+    }
+
+    /// 设置预先创建的周期数
+    pub fn with_premake(mut self, premake: u32) -> Self {
+        self.premake = premake;
+        self
+    }
+
+    /// 日期所在周期的分区
+    pub fn partition_for(&self, table: &str, date: NaiveDate) -> PartitionRange {
+        let from = self.interval.period_start(date);
+        let to = self.interval.next_period(from);
+        let suffix = self.interval.suffix(from);
+        let name = match split_qualified_name(table) {
+            (Some(schema), table) => format!("{}.{}_{}", schema, table, suffix),
+            (None, table) => format!("{}_{}", table, suffix),
+        };
+        PartitionRange {
+            name,
+            mysql_name: format!("p{}", suffix),
+            from,
+            to,
+        }
+    }
+
+    /// 当前周期及之后 `premake` 个周期的分区
+    pub fn upcoming(&self, table: &str, today: NaiveDate) -> Vec<PartitionRange> {
+        let mut start = self.interval.period_start(today);
+        let mut partitions = Vec::with_capacity(self.premake as usize + 1);
+        for _ in 0..=self.premake {
+            partitions.push(self.partition_for(table, start));
+            start = self.interval.next_period(start);
+        }
+        partitions
+    }
+
+    /// 写入日期为 `date` 的记录时使用的表名
+    ///
+    /// PostgreSQL 与 MySQL 由数据库按分区列路由，始终写入父表；SQLite 与 MongoDB 写入周期后缀表
+    pub fn table_for(&self, table: &str, db_type: &DatabaseType, date: NaiveDate) -> String {
+        match db_type {
+            DatabaseType::PostgreSQL | DatabaseType::MySQL => table.to_string(),
+            _ => self.partition_for(table, date).name,
+        }
+    }
+}
+
+/// 一个周期的分区
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartitionRange {
+    /// 子表/后缀表名（可带模式前缀）
+    pub name: String,
+    /// MySQL 分区名
+    pub mysql_name: String,
+    /// 周期起始日（包含）
+    pub from: NaiveDate,
+    /// 周期结束日（不包含）
+    pub to: NaiveDate,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn monthly_partitions_roll_over_year_end() {
+        let spec = PartitionSpec::monthly("created_at").with_premake(2);
+        let today = NaiveDate::from_ymd_opt(2026, 11, 14).unwrap();
+        let partitions = spec.upcoming("analytics.events", today);
+        let names: Vec<&str> = partitions.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, ["analytics.events_2026_11", "analytics.events_2026_12", "analytics.events_2027_01"]);
+        assert_eq!(partitions[1].mysql_name, "p2026_12");
+        assert_eq!(partitions[1].to, NaiveDate::from_ymd_opt(2027, 1, 1).unwrap());
+        assert_eq!(spec.table_for("events", &DatabaseType::PostgreSQL, today), "events");
+        assert_eq!(
+            PartitionSpec::daily("created_at").table_for("events", &DatabaseType::SQLite, today),
+            "events_2026_11_14"
+        );
+    }
+
+    #[cfg(feature = "sqlite-support")]
+    #[tokio::test]
+    async fn sqlite_creates_suffix_table_per_period() {
+        use crate::table::{ColumnDefinition, ColumnType, TableManager, TableSchema};
+        use crate::types::*;
+        use std::sync::Arc;
+
+        crate::i18n::ErrorMessageI18n::init_i18n();
+        let pool_manager = Arc::new(crate::manager::PoolManager::new());
+        pool_manager
+            .add_database(DatabaseConfig {
+                alias: "default".to_string(),
+                ..DatabaseConfig::sqlite_memory_for_test("partition_suite")
+            })
+            .await
+            .unwrap();
+        let manager = TableManager::new(pool_manager.clone(), Default::default());
+        let schema = TableSchema::new("events".to_string())
+            .add_column(ColumnDefinition::new("created_at".to_string(), ColumnType::DateTime))
+            .with_partition(PartitionSpec::monthly("created_at").with_premake(1));
+        manager.create_table(&schema, None).await.unwrap();
+
+        let ensured = manager
+            .ensure_partitions(&schema, NaiveDate::from_ymd_opt(2030, 12, 3).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(ensured, ["events_2030_12", "events_2031_01"]);
+
+        let tables: Vec<DataValue> = pool_manager
+            .with_connection(Some("default"), |mut conn| async move {
+                conn.query("SELECT name FROM sqlite_master WHERE type = 'table' AND name LIKE 'events%'", &[])
+                    .await
+            })
+            .await
+            .unwrap()
+            .into_iter()
+            .filter_map(|row| match row {
+                DataValue::Object(mut row) => row.remove("name"),
+                _ => None,
+            })
+            .collect();
+        // 建表时创建了当前周期的后缀表，没有父表
+        let current = PartitionSpec::monthly("created_at").partition_for("events", chrono::Utc::now().date_naive());
+        for name in [current.name.as_str(), "events_2030_12", "events_2031_01"] {
+            assert!(tables.contains(&DataValue::String(name.to_string())), "{} 未创建", name);
+        }
+        assert!(!tables.contains(&DataValue::String("events".to_string())));
+    }
+}
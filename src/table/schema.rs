@@ -3,7 +3,6 @@
 //! 定义表结构、列类型、索引和约束
 
 use crate::types::DataValue;
-use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -26,6 +25,12 @@ pub struct TableSchema {
     pub created_at: Option<chrono::DateTime<chrono::Utc>>,
     /// 最后修改时间
     pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// 表所在的模式（为空时使用连接的默认模式）
+    #[serde(default)]
+    pub schema: Option<String>,
+    /// 按时间分区声明（见 [`super::partition`]）
+    #[serde(default)]
+    pub partition: Option<super::partition::PartitionSpec>,
 }
 
 /// 列定义
@@ -226,6 +231,30 @@ impl TableSchema {
             version: 1,
             created_at: Some(chrono::Utc::now()),
             updated_at: Some(chrono::Utc::now()),
+            schema: None,
+            partition: None,
+        }
+    }
+
+    /// 设置表所在的模式
+    pub fn with_schema(mut self, schema: impl Into<String>) -> Self {
+        self.schema = Some(schema.into());
+        self.updated_at = Some(chrono::Utc::now());
+        self
+    }
+
+    /// 按时间分区
+    pub fn with_partition(mut self, partition: super::partition::PartitionSpec) -> Self {
+        self.partition = Some(partition);
+        self.updated_at = Some(chrono::Utc::now());
+        self
+    }
+
+    /// 限定表名：设置了模式时为 `模式.表名`，否则为表名
+    pub fn qualified_name(&self) -> String {
+        match &self.schema {
+            Some(schema) => format!("{}.{}", schema, self.name),
+            None => self.name.clone(),
         }
     }
 
@@ -404,6 +433,8 @@ impl TableSchema {
             version: 1,
             created_at: Some(chrono::Utc::now()),
             updated_at: Some(chrono::Utc::now()),
+            schema: None,
+            partition: None,
         }
     }
 
@@ -635,6 +666,8 @@ mod tests {
             version: 1,
             created_at: None,
             updated_at: None,
+            schema: None,
+            partition: None,
         }
     }
 
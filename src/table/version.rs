@@ -130,7 +130,7 @@ impl VersionManager {
         let versions = self
             .versions
             .entry(table_name.clone())
-            .or_insert_with(Vec::new);
+            .or_default();
 
         // 计算新版本号
         let new_version = versions.iter().map(|v| v.version).max().unwrap_or(0) + 1;
@@ -178,7 +178,7 @@ impl VersionManager {
     pub fn get_version_history(&self, table_name: &str) -> Vec<&SchemaVersion> {
         if let Some(versions) = self.versions.get(table_name) {
             let mut sorted_versions: Vec<&SchemaVersion> = versions.iter().collect();
-            sorted_versions.sort_by(|a, b| a.version.cmp(&b.version));
+            sorted_versions.sort_by_key(|a| a.version);
             sorted_versions
         } else {
             Vec::new()
@@ -216,7 +216,7 @@ impl VersionManager {
             from_version,
             to_version,
             chrono::Utc::now().timestamp(),
-            uuid::Uuid::new_v4().to_string()[..8].to_string()
+            &uuid::Uuid::new_v4().to_string()[..8]
         );
 
         let migration = MigrationScript {
@@ -264,7 +264,7 @@ impl VersionManager {
                 });
             }
 
-            let start_time = chrono::Utc::now();
+            let _start_time = chrono::Utc::now();
             migration.status = MigrationStatus::Running;
 
             (migration.from_version, migration.to_version)
@@ -317,7 +317,7 @@ impl VersionManager {
         migration_id: &str,
     ) -> QuickDbResult<()> {
         // 先获取迁移信息，避免借用冲突
-        let (from_version, to_version, down_script) = {
+        let (from_version, to_version, _down_script) = {
             let migration = self
                 .find_migration_mut(table_name, migration_id)
                 .ok_or_else(|| QuickDbError::ValidationError {
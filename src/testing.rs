@@ -0,0 +1,301 @@
+//! # 测试辅助模块
+//!
+//! 启用 `testing` 特性后提供临时数据库、按模型建表、夹具加载与清理，
+//! 下游项目编写集成测试时无需自行搭建数据库环境：
+//!
+//! ```ignore
+//! let db = TestDb::sqlite_memory().await?;
+//! db.create_table_for::<User>().await?;
+//! db.load_fixtures("tests/fixtures/users.yaml").await?;
+//! // ... 通过 db.alias() 访问数据库
+//! db.teardown().await?;
+//! ```
+//!
+//! 不需要真实数据库时，可用 [`TestDb::mock`] 把别名注册到内存中的 [`MockAdapter`]，
+//! 测试结束后通过保留的适配器句柄检查写入的数据。
+//!
+//! 夹具文件为 JSON 或 YAML（YAML 需要 `yaml` 特性），顶层键为表名，值为记录列表，按文件中的顺序插入：
+//!
+//! ```yaml
+//! users:
+//!   - { id: 1, name: alice }
+//!   - { id: 2, name: bob }
+//! posts:
+//!   - { id: 1, user_id: 1, title: hello }
+//! ```
+
+pub use crate::adapter::MockAdapter;
+use crate::config::ConfigFileFormat;
+use crate::error::{QuickDbError, QuickDbResult};
+use crate::manager::get_global_pool_manager;
+use crate::model::{Model, ModelMeta};
+use crate::odm::OdmOperations;
+use crate::seed::TableRecords;
+use crate::types::data_value::json_value_to_data_value;
+use crate::types::*;
+use rat_logger::{info, warn};
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// 临时数据库序号（保证同一进程内别名唯一）
+static TEST_DB_SEQ: AtomicUsize = AtomicUsize::new(0);
+
+/// 夹具文件格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FixtureFormat {
+    /// JSON
+    Json,
+    /// YAML（需要 `yaml` 特性）
+    #[cfg(feature = "yaml")]
+    Yaml,
+}
+
+impl FixtureFormat {
+    /// 根据文件扩展名判断格式（`.json`、`.yaml`/`.yml`）
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension().and_then(|s| s.to_str())?.to_ascii_lowercase().as_str() {
+            "json" => Some(Self::Json),
+            #[cfg(feature = "yaml")]
+            "yaml" | "yml" => Some(Self::Yaml),
+            _ => None,
+        }
+    }
+}
+
+/// 测试用临时数据库
+///
+/// 每个实例使用唯一别名注册到全局连接池管理器，测试结束时调用 [`TestDb::teardown`]
+/// 移除（未调用时在析构时尽力移除）。创建时不受全局操作锁限制，
+/// 同一测试进程中的多个测试可以在执行查询后继续创建各自的数据库。
+///
+/// ODM 后台任务运行在首次使用它的 tokio 运行时上，经 ODM 访问数据库的测试
+/// 应在同一个运行时中执行（例如共用一个 `#[tokio::test]` 或自建运行时）
+pub struct TestDb {
+    alias: String,
+    torn_down: bool,
+}
+
+impl TestDb {
+    /// 创建共享缓存的 SQLite 内存数据库，别名自动生成
+    pub async fn sqlite_memory() -> QuickDbResult<Self> {
+        let seq = TEST_DB_SEQ.fetch_add(1, Ordering::Relaxed);
+        Self::sqlite_memory_with_alias(format!("test_db_{}_{}", std::process::id(), seq)).await
+    }
+
+    /// 以指定别名创建共享缓存的 SQLite 内存数据库
+    ///
+    /// 模型声明了固定别名时可用此方法让模型操作直接落到临时数据库
+    pub async fn sqlite_memory_with_alias<A: Into<String>>(alias: A) -> QuickDbResult<Self> {
+        let alias = alias.into();
+        let config = DatabaseConfig {
+            db_type: DatabaseType::SQLite,
+            connection: ConnectionConfig::sqlite_memory(&alias),
+            pool: PoolConfig {
+                connection_timeout: 5000,
+                ..PoolConfig::default()
+            },
+            alias: alias.clone(),
+            cache: None,
+            id_strategy: IdStrategy::AutoIncrement,
+            version_storage_path: None,
+            enable_versioning: None,
+            on_connect_statements: Vec::new(),
+            table_naming: None,
+        };
+        get_global_pool_manager().add_database(config).await?;
+
+        info!("创建测试数据库: 别名={}", alias);
+        Ok(Self {
+            alias,
+            torn_down: false,
+        })
+    }
+
+    /// 创建由模拟适配器处理的数据库，别名自动生成
+    pub async fn mock(adapter: MockAdapter) -> QuickDbResult<Self> {
+        let seq = TEST_DB_SEQ.fetch_add(1, Ordering::Relaxed);
+        Self::mock_with_alias(format!("mock_db_{}_{}", std::process::id(), seq), adapter).await
+    }
+
+    /// 以指定别名创建由模拟适配器处理的数据库
+    pub async fn mock_with_alias<A: Into<String>>(alias: A, adapter: MockAdapter) -> QuickDbResult<Self> {
+        let alias = alias.into();
+        get_global_pool_manager().add_mock_database(&alias, adapter).await?;
+        Ok(Self {
+            alias,
+            torn_down: false,
+        })
+    }
+
+    /// 数据库别名
+    pub fn alias(&self) -> &str {
+        &self.alias
+    }
+
+    /// 按模型元数据建表（含索引），元数据以当前别名注册
+    pub async fn create_tables(&self, metas: &[ModelMeta]) -> QuickDbResult<()> {
+        let manager = get_global_pool_manager();
+        for meta in metas {
+            let mut meta = meta.clone();
+            meta.database_alias = Some(self.alias.clone());
+            let collection_name = meta.qualified_name();
+            manager.register_model(meta)?;
+            manager.ensure_table_and_indexes(&collection_name, &self.alias).await?;
+        }
+        Ok(())
+    }
+
+    /// 按模型类型建表
+    pub async fn create_table_for<M: Model>(&self) -> QuickDbResult<()> {
+        self.create_tables(&[M::meta()]).await
+    }
+
+    /// 为所有已注册的模型建表（不论模型注册在哪个别名下），返回建表数量
+    pub async fn create_registered_tables(&self) -> QuickDbResult<usize> {
+        let mut metas: Vec<ModelMeta> = Vec::new();
+        for (_, meta) in get_global_pool_manager().get_registered_models() {
+            if !metas.iter().any(|existing| existing.qualified_name() == meta.qualified_name()) {
+                metas.push(meta);
+            }
+        }
+        self.create_tables(&metas).await?;
+        Ok(metas.len())
+    }
+
+    /// 从夹具文件加载数据，返回每张表插入的记录数（按文件顺序）
+    pub async fn load_fixtures<P: AsRef<Path>>(&self, path: P) -> QuickDbResult<Vec<(String, usize)>> {
+        let path = path.as_ref();
+        let format = FixtureFormat::from_path(path).ok_or_else(|| {
+            crate::quick_error!(
+                config,
+                crate::i18n::tf("config.file_format_unsupported", &[("path", &path.display().to_string())])
+            )
+        })?;
+        let content = std::fs::read_to_string(path).map_err(QuickDbError::IoError)?;
+        self.load_fixtures_str(&content, format).await
+    }
+
+    /// 从字符串加载夹具数据，返回每张表插入的记录数（按文件顺序）
+    pub async fn load_fixtures_str(
+        &self,
+        content: &str,
+        format: FixtureFormat,
+    ) -> QuickDbResult<Vec<(String, usize)>> {
+        let format = match format {
+            FixtureFormat::Json => ConfigFileFormat::Json,
+            #[cfg(feature = "yaml")]
+            FixtureFormat::Yaml => ConfigFileFormat::Yaml,
+        };
+        let fixtures = TableRecords::parse(content, format).map_err(|message| {
+            crate::quick_error!(config, crate::i18n::tf("testing.fixture_parse_failed", &[("message", &message)]))
+        })?;
+
+        let odm = crate::odm::get_odm_manager().await;
+        let mut loaded = Vec::with_capacity(fixtures.0.len());
+        for (table, records) in fixtures.0 {
+            let count = records.len();
+            for (index, record) in records.into_iter().enumerate() {
+                let DataValue::Object(data) = json_value_to_data_value(record) else {
+                    return Err(crate::quick_error!(
+                        config,
+                        crate::i18n::tf(
+                            "testing.fixture_record_invalid",
+                            &[("table", &table), ("index", &index.to_string())]
+                        )
+                    ));
+                };
+                odm.create(&table, data, Some(&self.alias)).await?;
+            }
+            info!("加载夹具: 别名={}, 表={}, 记录数={}", self.alias, table, count);
+            loaded.push((table, count));
+        }
+        Ok(loaded)
+    }
+
+    /// 移除测试数据库（内存数据库随连接池关闭释放）
+    pub async fn teardown(mut self) -> QuickDbResult<()> {
+        self.torn_down = true;
+        get_global_pool_manager().remove_database(&self.alias).await
+    }
+}
+
+impl Drop for TestDb {
+    fn drop(&mut self) {
+        if self.torn_down {
+            return;
+        }
+        let alias = std::mem::take(&mut self.alias);
+        match tokio::runtime::Handle::try_current() {
+            Ok(handle) => {
+                handle.spawn(async move {
+                    let _ = get_global_pool_manager().remove_database(&alias).await;
+                });
+            }
+            Err(_) => warn!("测试数据库未调用 teardown 且不在 tokio 运行时中，无法移除: 别名={}", alias),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod model {
+        use crate::model::*;
+
+        crate::define_model! {
+            struct FixtureUser {
+                id: String,
+                name: String,
+            }
+            collection = "fixture_users",
+            fields = {
+                id: string_field(None, None, None),
+                name: string_field(Some(64), None, None).required(),
+            }
+        }
+    }
+
+    #[test]
+    fn creates_isolated_databases_and_loads_fixtures() {
+        crate::odm::global::block_on_shared(async {
+            crate::i18n::ErrorMessageI18n::init_i18n();
+            let first = TestDb::sqlite_memory().await.unwrap();
+            let second = TestDb::sqlite_memory().await.unwrap();
+            assert_ne!(first.alias(), second.alias());
+
+            first.create_table_for::<model::FixtureUser>().await.unwrap();
+            second.create_table_for::<model::FixtureUser>().await.unwrap();
+            #[cfg(feature = "yaml")]
+            let (fixtures, format) = ("fixture_users:\n  - { name: alice }\n  - { name: bob }\n", FixtureFormat::Yaml);
+            #[cfg(not(feature = "yaml"))]
+            let (fixtures, format) = (r#"{"fixture_users": [{"name": "alice"}, {"name": "bob"}]}"#, FixtureFormat::Json);
+            let loaded = first.load_fixtures_str(fixtures, format).await.unwrap();
+            assert_eq!(loaded, vec![("fixture_users".to_string(), 2)]);
+
+            let odm = crate::odm::get_odm_manager().await;
+            assert_eq!(odm.find("fixture_users", vec![], None, Some(first.alias())).await.unwrap().len(), 2);
+            assert!(odm.find("fixture_users", vec![], None, Some(second.alias())).await.unwrap().is_empty());
+            drop(odm);
+
+            // 模拟数据库：ODM 操作落到适配器的内存数据中
+            let adapter = MockAdapter::new();
+            let mock = TestDb::mock(adapter.clone()).await.unwrap();
+            mock.create_table_for::<model::FixtureUser>().await.unwrap();
+            mock.load_fixtures_str(r#"{"fixture_users": [{"name": "carol"}]}"#, FixtureFormat::Json)
+                .await
+                .unwrap();
+            assert_eq!(adapter.records("fixture_users")[0]["name"], DataValue::String("carol".to_string()));
+            let odm = crate::odm::get_odm_manager().await;
+            assert_eq!(odm.count("fixture_users", vec![], Some(mock.alias())).await.unwrap(), 1);
+            drop(odm);
+            mock.teardown().await.unwrap();
+
+            assert!(first.load_fixtures_str(r#"{"fixture_users": [1]}"#, FixtureFormat::Json).await.is_err());
+            let alias = first.alias().to_string();
+            first.teardown().await.unwrap();
+            assert!(!get_global_pool_manager().get_aliases().contains(&alias));
+            second.teardown().await.unwrap();
+        });
+    }
+}
@@ -0,0 +1,94 @@
+//! 批量混合写入
+//!
+//! 一次提交多种写操作（插入、更新、删除、Upsert），按执行模式逐条执行并返回每个操作的结果
+
+use super::{DataValue, QueryConditionWithConfig};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// 批量写入中的一个操作
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BulkOp {
+    /// 插入一条记录
+    Insert(HashMap<String, DataValue>),
+    /// 按条件更新记录
+    Update {
+        conditions: Vec<QueryConditionWithConfig>,
+        data: HashMap<String, DataValue>,
+    },
+    /// 按条件删除记录
+    Delete(Vec<QueryConditionWithConfig>),
+    /// 按冲突列定位记录，存在则更新，否则插入
+    Upsert {
+        data: HashMap<String, DataValue>,
+        conflict_columns: Vec<String>,
+    },
+}
+
+/// 批量写入的执行模式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BulkWriteMode {
+    /// 按顺序执行，遇到第一个失败的操作后停止，之后的操作不再执行
+    #[default]
+    Ordered,
+    /// 执行全部操作，单个操作失败不影响其他操作
+    Unordered,
+}
+
+/// 单个操作的执行结果
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BulkOpOutcome {
+    /// 已执行，`affected` 为受影响的记录数
+    Applied { affected: u64 },
+    /// 执行失败
+    Failed { message: String },
+    /// 顺序模式下前面的操作失败，未执行
+    Skipped,
+}
+
+/// 批量写入报告，`outcomes` 与提交的操作一一对应
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BulkWriteReport {
+    pub outcomes: Vec<BulkOpOutcome>,
+}
+
+impl BulkWriteReport {
+    /// 所有操作均为跳过状态的报告
+    pub fn skipped(len: usize) -> Self {
+        Self {
+            outcomes: vec![BulkOpOutcome::Skipped; len],
+        }
+    }
+
+    /// 全部操作都已执行
+    pub fn is_success(&self) -> bool {
+        self.outcomes
+            .iter()
+            .all(|outcome| matches!(outcome, BulkOpOutcome::Applied { .. }))
+    }
+
+    /// 已执行操作的受影响记录总数
+    pub fn affected_rows(&self) -> u64 {
+        self.outcomes
+            .iter()
+            .map(|outcome| match outcome {
+                BulkOpOutcome::Applied { affected } => *affected,
+                _ => 0,
+            })
+            .sum()
+    }
+
+    /// 失败的操作（序号与错误信息）
+    pub fn failures(&self) -> impl Iterator<Item = (usize, &str)> {
+        self.outcomes
+            .iter()
+            .enumerate()
+            .filter_map(|(index, outcome)| match outcome {
+                BulkOpOutcome::Failed { message } => Some((index, message.as_str())),
+                _ => None,
+            })
+    }
+}
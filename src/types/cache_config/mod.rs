@@ -18,6 +18,9 @@ pub struct CacheConfig {
     /// 缓存版本标识，变更此值可清理所有缓存
     #[serde(default = "default_cache_version")]
     pub version: String,
+    /// 写操作的缓存处理方式
+    #[serde(default)]
+    pub write_mode: CacheWriteMode,
 }
 
 /// 默认缓存版本
@@ -25,6 +28,17 @@ fn default_cache_version() -> String {
     "v1".to_string()
 }
 
+/// 写操作的缓存处理方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum CacheWriteMode {
+    /// 写入后只失效相关缓存，下次读取时再从数据库加载
+    #[default]
+    WriteAround,
+    /// 按ID写入（create/upsert/update_by_id）后立即从数据库读回最新记录写入缓存，
+    /// 以一次额外的读取换取写入后的首次读取命中；按条件的批量更新仍然只做失效
+    WriteThrough,
+}
+
 /// 缓存策略
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum CacheStrategy {
@@ -73,6 +87,12 @@ pub struct TtlConfig {
     pub max_ttl_secs: u64,
     /// TTL 检查间隔（秒）
     pub check_interval_secs: u64,
+    /// “记录不存在”结果的缓存时间（秒），None 表示不缓存
+    ///
+    /// 开启后按 ID 查询不存在的记录会在缓存中留下短期的占位标记，
+    /// 期间重复查询直接返回“不存在”；该 ID 的记录写入后标记会被清除
+    #[serde(default)]
+    pub negative_ttl_secs: Option<u64>,
 }
 
 /// 压缩配置
@@ -133,3 +153,45 @@ impl L2CacheConfig {
         self
     }
 }
+
+/// 表级缓存策略，覆盖数据库别名级的缓存配置
+///
+/// 淘汰策略（LRU/LFU/FIFO）由同一数据库别名下的所有表共享，不支持按表覆盖
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TableCachePolicy {
+    /// 是否缓存该表（关闭后读写均绕过缓存）
+    pub enabled: bool,
+    /// 该表缓存条目的 TTL（秒），None 表示沿用默认 TTL
+    #[serde(default)]
+    pub ttl_secs: Option<u64>,
+}
+
+impl TableCachePolicy {
+    /// 使用默认 TTL 缓存该表
+    pub fn new() -> Self {
+        Self {
+            enabled: true,
+            ttl_secs: None,
+        }
+    }
+
+    /// 不缓存该表（适用于频繁变更的表）
+    pub fn disabled() -> Self {
+        Self {
+            enabled: false,
+            ttl_secs: None,
+        }
+    }
+
+    /// 设置该表的 TTL（秒）
+    pub fn with_ttl_secs(mut self, ttl_secs: u64) -> Self {
+        self.ttl_secs = Some(ttl_secs);
+        self
+    }
+}
+
+impl Default for TableCachePolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
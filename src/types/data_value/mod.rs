@@ -1,3 +1,5 @@
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
 use chrono::{DateTime, FixedOffset, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -127,7 +129,7 @@ impl DataValue {
             DataValue::String(s) => serde_json::Value::String(s.clone()),
             DataValue::Bytes(b) => {
                 // 将字节数组转换为 base64 字符串
-                serde_json::Value::String(base64::encode(b))
+                serde_json::Value::String(STANDARD.encode(b))
             }
             DataValue::DateTime(dt) => serde_json::Value::String(dt.to_rfc3339()),
             DataValue::DateTimeUTC(dt) => serde_json::Value::String(dt.to_rfc3339()),
@@ -178,7 +180,7 @@ impl DataValue {
                             },
                             DataValue::Bool(b) => serde_json::Value::Bool(*b),
                             DataValue::Null => serde_json::Value::Null,
-                            DataValue::Bytes(b) => serde_json::Value::String(base64::encode(b)),
+                            DataValue::Bytes(b) => serde_json::Value::String(STANDARD.encode(b)),
                             DataValue::DateTime(dt) => serde_json::Value::String(dt.to_rfc3339()),
                             DataValue::DateTimeUTC(dt) => {
                                 serde_json::Value::String(dt.to_rfc3339())
@@ -301,7 +303,7 @@ impl From<Vec<u8>> for DataValue {
 
 impl From<DateTime<Utc>> for DataValue {
     fn from(value: DateTime<Utc>) -> Self {
-        DataValue::DateTime(value.with_timezone(&FixedOffset::east(0)))
+        DataValue::DateTime(value.with_timezone(&FixedOffset::east_opt(0).unwrap()))
     }
 }
 
@@ -571,7 +573,7 @@ pub fn convert_to_postgresql_jsonb_value(
                 ));
             }
             // 转换为base64字符串
-            Ok(DataValue::String(format!("\"{}\"", base64::encode(bytes))))
+            Ok(DataValue::String(format!("\"{}\"", STANDARD.encode(bytes))))
         }
         // 向量数据：序列化为JSON数组字符串
         DataValue::Vector(vec) => {
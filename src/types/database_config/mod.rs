@@ -28,6 +28,7 @@ impl DatabaseType {
     }
 
     /// 从字符串解析数据库类型
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(s: &str) -> Result<Self, crate::error::QuickDbError> {
         match s.to_lowercase().as_str() {
             "sqlite" => Ok(DatabaseType::SQLite),
@@ -58,6 +59,31 @@ pub struct DatabaseConfig {
     pub version_storage_path: Option<String>,
     /// 是否启用版本管理（默认 false）
     pub enable_versioning: Option<bool>,
+    /// 每个新连接建立后执行的初始化语句（如 `SET search_path TO app,public`）；
+    /// MongoDB 为 JSON 命令文档，建立客户端后执行一次
+    pub on_connect_statements: Vec<String>,
+    /// 表名装饰规则（前缀/后缀），同一数据库承载多套部署时使用
+    pub table_naming: Option<TableNaming>,
+}
+
+#[cfg(all(test, feature = "sqlite-support"))]
+impl DatabaseConfig {
+    /// 测试用的 SQLite 共享缓存内存数据库配置（自增ID，不启用缓存），
+    /// 测试中用结构体更新语法覆盖需要的字段
+    pub(crate) fn sqlite_memory_for_test(alias: &str) -> Self {
+        Self {
+            db_type: DatabaseType::SQLite,
+            connection: ConnectionConfig::sqlite_memory(alias),
+            pool: PoolConfig::default(),
+            alias: alias.to_string(),
+            cache: None,
+            id_strategy: IdStrategy::AutoIncrement,
+            version_storage_path: None,
+            enable_versioning: None,
+            on_connect_statements: Vec::new(),
+            table_naming: None,
+        }
+    }
 }
 
 // 手动实现序列化，以支持 PoolConfig 字段私有化
@@ -67,7 +93,7 @@ impl Serialize for DatabaseConfig {
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let mut state = serializer.serialize_struct("DatabaseConfig", 8)?;
+        let mut state = serializer.serialize_struct("DatabaseConfig", 10)?;
         state.serialize_field("db_type", &self.db_type)?;
         state.serialize_field("connection", &self.connection)?;
         state.serialize_field("pool", &self.pool)?;
@@ -76,6 +102,8 @@ impl Serialize for DatabaseConfig {
         state.serialize_field("id_strategy", &self.id_strategy)?;
         state.serialize_field("version_storage_path", &self.version_storage_path)?;
         state.serialize_field("enable_versioning", &self.enable_versioning)?;
+        state.serialize_field("on_connect_statements", &self.on_connect_statements)?;
+        state.serialize_field("table_naming", &self.table_naming)?;
         state.end()
     }
 }
@@ -109,6 +137,8 @@ impl<'de> Deserialize<'de> for DatabaseConfig {
                 let mut id_strategy = None;
                 let mut version_storage_path = None;
                 let mut enable_versioning = None;
+                let mut on_connect_statements = None;
+                let mut table_naming = None;
 
                 while let Some(key) = map.next_key()? {
                     match key {
@@ -160,6 +190,18 @@ impl<'de> Deserialize<'de> for DatabaseConfig {
                             }
                             enable_versioning = Some(map.next_value()?);
                         }
+                        "on_connect_statements" => {
+                            if on_connect_statements.is_some() {
+                                return Err(de::Error::duplicate_field("on_connect_statements"));
+                            }
+                            on_connect_statements = Some(map.next_value()?);
+                        }
+                        "table_naming" => {
+                            if table_naming.is_some() {
+                                return Err(de::Error::duplicate_field("table_naming"));
+                            }
+                            table_naming = Some(map.next_value()?);
+                        }
                         _ => {
                             let _ = map.next_value::<serde_json::Value>()?;
                         }
@@ -181,11 +223,13 @@ impl<'de> Deserialize<'de> for DatabaseConfig {
                     id_strategy,
                     version_storage_path,
                     enable_versioning,
+                    on_connect_statements: on_connect_statements.unwrap_or_default(),
+                    table_naming: table_naming.flatten(),
                 })
             }
         }
 
-        const FIELDS: &'static [&'static str] = &[
+        const FIELDS: &[&str] = &[
             "db_type",
             "connection",
             "pool",
@@ -194,6 +238,8 @@ impl<'de> Deserialize<'de> for DatabaseConfig {
             "id_strategy",
             "version_storage_path",
             "enable_versioning",
+            "on_connect_statements",
+            "table_naming",
         ];
         deserializer.deserialize_struct("DatabaseConfig", FIELDS, DatabaseConfigVisitor)
     }
@@ -208,6 +254,12 @@ pub enum ConnectionConfig {
         path: String,
         /// 是否创建数据库文件（如果不存在）
         create_if_missing: bool,
+        /// PRAGMA 配置（日志模式、同步级别等），每个连接打开时应用
+        #[serde(default)]
+        pragmas: Option<SqlitePragmaConfig>,
+        /// SQLCipher 加密密钥（需要启用 `sqlcipher` 特性），每个连接打开时执行 `PRAGMA key`
+        #[serde(default)]
+        encryption_key: Option<String>,
     },
     /// PostgreSQL 连接配置
     PostgreSQL {
@@ -225,6 +277,12 @@ pub enum ConnectionConfig {
         ssl_mode: Option<String>,
         /// TLS 配置选项
         tls_config: Option<TlsConfig>,
+        /// Unix 套接字目录（如 `/var/run/postgresql`），设置后通过套接字连接，忽略 `host`
+        #[serde(default)]
+        socket_path: Option<String>,
+        /// PgBouncer 兼容模式：禁用连接上的预编译语句缓存，适配事务级连接池
+        #[serde(default)]
+        pgbouncer_mode: bool,
     },
     /// MySQL 连接配置
     MySQL {
@@ -242,6 +300,15 @@ pub enum ConnectionConfig {
         ssl_opts: Option<HashMap<String, String>>,
         /// TLS 配置选项
         tls_config: Option<TlsConfig>,
+        /// Unix 套接字路径（如 `/var/run/mysqld/mysqld.sock`），设置后通过套接字连接，忽略 `host`/`port`
+        #[serde(default)]
+        socket: Option<String>,
+        /// 连接字符集（未设置时为 `utf8mb4`，可存储 emoji 等 4 字节字符）
+        #[serde(default)]
+        charset: Option<String>,
+        /// 连接排序规则（未设置时使用字符集的默认排序规则），需与字符集匹配
+        #[serde(default)]
+        collation: Option<String>,
     },
     /// MongoDB 连接配置
     MongoDB {
@@ -265,6 +332,9 @@ pub enum ConnectionConfig {
         zstd_config: Option<ZstdConfig>,
         /// 其他连接选项
         options: Option<HashMap<String, String>>,
+        /// 集群配置（多种子节点、SRV、副本集、读偏好与写关注）
+        #[serde(default)]
+        cluster_config: Option<MongoClusterConfig>,
     },
 }
 
@@ -398,6 +468,279 @@ impl ZstdConfig {
     }
 }
 
+impl ConnectionConfig {
+    /// 命名的共享缓存 SQLite 内存数据库（`file:{name}?mode=memory&cache=shared`）
+    ///
+    /// 同一进程内名称相同的连接访问同一个内存数据库，适合测试中快速建立彼此隔离的数据库
+    pub fn sqlite_memory<N: AsRef<str>>(name: N) -> Self {
+        Self::SQLite {
+            path: format!("file:{}?mode=memory&cache=shared", urlencoding::encode(name.as_ref())),
+            create_if_missing: true,
+            pragmas: None,
+            encryption_key: None,
+        }
+    }
+}
+
+/// SQLite PRAGMA 配置（未设置的项使用 sqlx 默认值，其中外键约束默认启用）
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SqlitePragmaConfig {
+    /// 日志模式（delete、truncate、persist、memory、wal、off）
+    pub journal_mode: Option<String>,
+    /// 同步级别（off、normal、full、extra），WAL 模式下通常使用 normal
+    pub synchronous: Option<String>,
+    /// 数据库被锁定时的等待时间（毫秒）
+    pub busy_timeout_ms: Option<u64>,
+    /// 是否启用外键约束
+    pub foreign_keys: Option<bool>,
+    /// 页缓存大小（正数为页数，负数为 KiB）
+    pub cache_size: Option<i64>,
+}
+
+impl SqlitePragmaConfig {
+    /// WAL 模式常用配置：`journal_mode=wal`、`synchronous=normal`
+    pub fn wal() -> Self {
+        Self {
+            journal_mode: Some("wal".to_string()),
+            synchronous: Some("normal".to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// 设置日志模式
+    pub fn with_journal_mode<J: Into<String>>(mut self, journal_mode: J) -> Self {
+        self.journal_mode = Some(journal_mode.into());
+        self
+    }
+
+    /// 设置同步级别
+    pub fn with_synchronous<S: Into<String>>(mut self, synchronous: S) -> Self {
+        self.synchronous = Some(synchronous.into());
+        self
+    }
+
+    /// 设置锁等待时间（毫秒）
+    pub fn with_busy_timeout_ms(mut self, busy_timeout_ms: u64) -> Self {
+        self.busy_timeout_ms = Some(busy_timeout_ms);
+        self
+    }
+
+    /// 设置是否启用外键约束
+    pub fn with_foreign_keys(mut self, enabled: bool) -> Self {
+        self.foreign_keys = Some(enabled);
+        self
+    }
+
+    /// 设置页缓存大小
+    pub fn with_cache_size(mut self, cache_size: i64) -> Self {
+        self.cache_size = Some(cache_size);
+        self
+    }
+
+    /// 检查日志模式与同步级别是否为 SQLite 支持的取值
+    pub(crate) fn validate(&self) -> Result<(), crate::error::QuickDbError> {
+        const JOURNAL_MODES: &[&str] = &["delete", "truncate", "persist", "memory", "wal", "off"];
+        const SYNCHRONOUS: &[&str] = &["off", "normal", "full", "extra"];
+
+        for (name, value, allowed) in [
+            ("journal_mode", &self.journal_mode, JOURNAL_MODES),
+            ("synchronous", &self.synchronous, SYNCHRONOUS),
+        ] {
+            if let Some(value) = value.as_ref().filter(|value| !allowed.contains(&value.to_ascii_lowercase().as_str())) {
+                return Err(crate::quick_error!(
+                    config,
+                    crate::i18n::tf("config.sqlite_pragma_invalid", &[("name", name), ("value", value)])
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 表名装饰规则：为别名下的所有物理表名统一添加前缀/后缀
+///
+/// 同一个数据库承载同一组模型的多套部署时使用（如前缀 `app_`、环境后缀 `_staging`）。
+/// 模型与 ODM 调用仍使用逻辑表名，适配器在建表、建索引与读写时转换为物理表名；
+/// 限定表名（`模式.表名`）只装饰表名部分
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TableNaming {
+    /// 表名前缀
+    #[serde(default)]
+    pub prefix: String,
+    /// 表名后缀
+    #[serde(default)]
+    pub suffix: String,
+}
+
+impl TableNaming {
+    /// 使用前缀创建装饰规则
+    pub fn prefix<P: Into<String>>(prefix: P) -> Self {
+        Self {
+            prefix: prefix.into(),
+            suffix: String::new(),
+        }
+    }
+
+    /// 使用后缀创建装饰规则
+    pub fn suffix<S: Into<String>>(suffix: S) -> Self {
+        Self {
+            prefix: String::new(),
+            suffix: suffix.into(),
+        }
+    }
+
+    /// 设置后缀
+    pub fn with_suffix<S: Into<String>>(mut self, suffix: S) -> Self {
+        self.suffix = suffix.into();
+        self
+    }
+
+    /// 是否不改变表名
+    pub fn is_identity(&self) -> bool {
+        self.prefix.is_empty() && self.suffix.is_empty()
+    }
+
+    /// 逻辑表名转换为物理表名
+    pub fn decorate(&self, table: &str) -> String {
+        match crate::security::split_qualified_name(table) {
+            (Some(schema), name) => format!("{}.{}{}{}", schema, self.prefix, name, self.suffix),
+            (None, name) => format!("{}{}{}", self.prefix, name, self.suffix),
+        }
+    }
+
+    /// 物理表名还原为逻辑表名，不符合装饰规则时返回 `None`
+    pub fn undecorate(&self, table: &str) -> Option<String> {
+        let (schema, name) = crate::security::split_qualified_name(table);
+        let name = name.strip_prefix(self.prefix.as_str())?.strip_suffix(self.suffix.as_str())?;
+        if name.is_empty() {
+            return None;
+        }
+        Some(match schema {
+            Some(schema) => format!("{}.{}", schema, name),
+            None => name.to_string(),
+        })
+    }
+
+    /// 前缀与后缀只能包含字母、数字与下划线
+    pub(crate) fn validate(&self) -> Result<(), crate::error::QuickDbError> {
+        for value in [&self.prefix, &self.suffix] {
+            if !value.chars().all(|ch| ch.is_ascii_alphanumeric() || ch == '_') {
+                return Err(crate::quick_error!(
+                    config,
+                    crate::i18n::tf("config.table_naming_invalid", &[("value", value)])
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// MongoDB 集群配置
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MongoClusterConfig {
+    /// 额外的种子节点（`host:port`），与 `host`/`port` 一起组成种子列表
+    #[serde(default)]
+    pub hosts: Vec<String>,
+    /// 是否通过 DNS SRV 记录发现节点（`mongodb+srv`），此时 `host` 为 SRV 域名，
+    /// 端口与额外种子节点不可用
+    #[serde(default)]
+    pub srv: bool,
+    /// 副本集名称
+    pub replica_set: Option<String>,
+    /// 读偏好（primary、primaryPreferred、secondary、secondaryPreferred、nearest）
+    pub read_preference: Option<String>,
+    /// 写关注（如 `majority`、`1`）
+    pub write_concern: Option<String>,
+}
+
+impl MongoClusterConfig {
+    /// 创建使用 DNS SRV 发现节点的配置
+    pub fn srv() -> Self {
+        Self {
+            srv: true,
+            ..Default::default()
+        }
+    }
+
+    /// 添加种子节点（`host:port`）
+    pub fn with_host<H: Into<String>>(mut self, host: H) -> Self {
+        self.hosts.push(host.into());
+        self
+    }
+
+    /// 设置副本集名称
+    pub fn with_replica_set<R: Into<String>>(mut self, replica_set: R) -> Self {
+        self.replica_set = Some(replica_set.into());
+        self
+    }
+
+    /// 设置读偏好
+    pub fn with_read_preference<R: Into<String>>(mut self, read_preference: R) -> Self {
+        self.read_preference = Some(read_preference.into());
+        self
+    }
+
+    /// 设置写关注
+    pub fn with_write_concern<W: Into<String>>(mut self, write_concern: W) -> Self {
+        self.write_concern = Some(write_concern.into());
+        self
+    }
+}
+
+/// 连接池建立连接的时机
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConnectMode {
+    /// 立即连接：`add_database` 时建立并验证 `min_connections` 个连接，失败则直接返回错误
+    Eager,
+    /// 延迟连接：后台建立连接，连接问题在首次查询时暴露
+    #[default]
+    Lazy,
+}
+
+/// 操作队列已满时的处理方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "mode", rename_all = "lowercase")]
+pub enum OverflowPolicy {
+    /// 等待队列出现空位，超过 `timeout_ms` 毫秒仍未入队时返回 `QuickDbError::Overloaded`
+    Block { timeout_ms: u64 },
+    /// 立即返回 `QuickDbError::Overloaded`
+    Reject,
+}
+
+impl Default for OverflowPolicy {
+    fn default() -> Self {
+        Self::Block {
+            timeout_ms: DEFAULT_QUEUE_BLOCK_TIMEOUT_MS,
+        }
+    }
+}
+
+/// SQLite 写入合并配置：将相邻的创建操作合并到一个事务中执行
+///
+/// 只合并操作上下文（请求 ID、用户 ID 与优先级）相同的创建操作。启用语句日志时，
+/// 未设置请求 ID 的调用各自分配请求 ID，此时每个创建操作单独执行
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WriteBatchConfig {
+    /// 单个批次最多合并的创建操作数
+    pub max_size: usize,
+    /// 收到第一个创建操作后等待后续操作的最长时间（毫秒）
+    pub window_ms: u64,
+}
+
+/// 默认熔断阈值（连续失败次数）
+pub(crate) const DEFAULT_CIRCUIT_BREAKER_THRESHOLD: u32 = 5;
+/// 默认熔断恢复等待时间（毫秒）
+pub(crate) const DEFAULT_CIRCUIT_BREAKER_RESET_MS: u64 = 30_000;
+/// MySQL 默认连接字符集
+pub(crate) const DEFAULT_MYSQL_CHARSET: &str = "utf8mb4";
+/// 默认预编译语句缓存容量（与 sqlx 每个连接的默认缓存容量一致）
+pub(crate) const DEFAULT_STATEMENT_CACHE_CAPACITY: usize = 100;
+/// 默认重连退避上限（毫秒）
+pub(crate) const DEFAULT_MAX_RECONNECT_BACKOFF_MS: u64 = 30_000;
+/// 操作队列已满时默认的最长等待时间（毫秒）
+pub(crate) const DEFAULT_QUEUE_BLOCK_TIMEOUT_MS: u64 = 5_000;
+
 /// 连接池配置
 ///
 /// ⚠️ **重要**：所有字段仅在 crate 内可见，外部代码**必须通过 `PoolConfig::builder()` 创建**
@@ -422,6 +765,22 @@ pub struct PoolConfig {
     pub(crate) keepalive_interval_sec: u64,
     /// 连接健康检查超时（秒）
     pub(crate) health_check_timeout_sec: u64,
+    /// 熔断阈值：连续失败多少次后打开熔断器（0 表示禁用熔断）
+    pub(crate) circuit_breaker_threshold: u32,
+    /// 熔断器打开后进入半开探测前的等待时间（毫秒）
+    pub(crate) circuit_breaker_reset_ms: u64,
+    /// 连接模式（立即连接或延迟连接）
+    pub(crate) connect_mode: ConnectMode,
+    /// 每个连接的预编译语句缓存容量（0 表示禁用预编译语句缓存）
+    pub(crate) statement_cache_capacity: usize,
+    /// 连接断开后重连的退避上限（毫秒），退避从 `retry_interval_ms` 开始逐次加倍
+    pub(crate) max_reconnect_backoff_ms: u64,
+    /// 操作队列容量：已提交但尚未开始执行的操作数上限（`None` 表示不限制）
+    pub(crate) queue_capacity: Option<usize>,
+    /// 操作队列已满时的处理方式
+    pub(crate) overflow_policy: OverflowPolicy,
+    /// SQLite 写入合并配置（`None` 表示逐条执行创建操作）
+    pub(crate) write_batch: Option<WriteBatchConfig>,
 }
 
 // 手动实现序列化，以支持字段私有化
@@ -431,7 +790,7 @@ impl Serialize for PoolConfig {
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let mut state = serializer.serialize_struct("PoolConfig", 9)?;
+        let mut state = serializer.serialize_struct("PoolConfig", 17)?;
         state.serialize_field("min_connections", &self.min_connections)?;
         state.serialize_field("max_connections", &self.max_connections)?;
         state.serialize_field("connection_timeout", &self.connection_timeout)?;
@@ -441,6 +800,14 @@ impl Serialize for PoolConfig {
         state.serialize_field("retry_interval_ms", &self.retry_interval_ms)?;
         state.serialize_field("keepalive_interval_sec", &self.keepalive_interval_sec)?;
         state.serialize_field("health_check_timeout_sec", &self.health_check_timeout_sec)?;
+        state.serialize_field("circuit_breaker_threshold", &self.circuit_breaker_threshold)?;
+        state.serialize_field("circuit_breaker_reset_ms", &self.circuit_breaker_reset_ms)?;
+        state.serialize_field("connect_mode", &self.connect_mode)?;
+        state.serialize_field("statement_cache_capacity", &self.statement_cache_capacity)?;
+        state.serialize_field("max_reconnect_backoff_ms", &self.max_reconnect_backoff_ms)?;
+        state.serialize_field("queue_capacity", &self.queue_capacity)?;
+        state.serialize_field("overflow_policy", &self.overflow_policy)?;
+        state.serialize_field("write_batch", &self.write_batch)?;
         state.end()
     }
 }
@@ -475,6 +842,14 @@ impl<'de> Deserialize<'de> for PoolConfig {
                 let mut retry_interval_ms = None;
                 let mut keepalive_interval_sec = None;
                 let mut health_check_timeout_sec = None;
+                let mut circuit_breaker_threshold = None;
+                let mut circuit_breaker_reset_ms = None;
+                let mut connect_mode = None;
+                let mut statement_cache_capacity = None;
+                let mut max_reconnect_backoff_ms = None;
+                let mut queue_capacity = None;
+                let mut overflow_policy = None;
+                let mut write_batch = None;
 
                 while let Some(key) = map.next_key()? {
                     match key {
@@ -532,6 +907,54 @@ impl<'de> Deserialize<'de> for PoolConfig {
                             }
                             health_check_timeout_sec = Some(map.next_value()?);
                         }
+                        "circuit_breaker_threshold" => {
+                            if circuit_breaker_threshold.is_some() {
+                                return Err(de::Error::duplicate_field("circuit_breaker_threshold"));
+                            }
+                            circuit_breaker_threshold = Some(map.next_value()?);
+                        }
+                        "circuit_breaker_reset_ms" => {
+                            if circuit_breaker_reset_ms.is_some() {
+                                return Err(de::Error::duplicate_field("circuit_breaker_reset_ms"));
+                            }
+                            circuit_breaker_reset_ms = Some(map.next_value()?);
+                        }
+                        "connect_mode" => {
+                            if connect_mode.is_some() {
+                                return Err(de::Error::duplicate_field("connect_mode"));
+                            }
+                            connect_mode = Some(map.next_value()?);
+                        }
+                        "statement_cache_capacity" => {
+                            if statement_cache_capacity.is_some() {
+                                return Err(de::Error::duplicate_field("statement_cache_capacity"));
+                            }
+                            statement_cache_capacity = Some(map.next_value()?);
+                        }
+                        "max_reconnect_backoff_ms" => {
+                            if max_reconnect_backoff_ms.is_some() {
+                                return Err(de::Error::duplicate_field("max_reconnect_backoff_ms"));
+                            }
+                            max_reconnect_backoff_ms = Some(map.next_value()?);
+                        }
+                        "queue_capacity" => {
+                            if queue_capacity.is_some() {
+                                return Err(de::Error::duplicate_field("queue_capacity"));
+                            }
+                            queue_capacity = Some(map.next_value()?);
+                        }
+                        "overflow_policy" => {
+                            if overflow_policy.is_some() {
+                                return Err(de::Error::duplicate_field("overflow_policy"));
+                            }
+                            overflow_policy = Some(map.next_value()?);
+                        }
+                        "write_batch" => {
+                            if write_batch.is_some() {
+                                return Err(de::Error::duplicate_field("write_batch"));
+                            }
+                            write_batch = Some(map.next_value()?);
+                        }
                         _ => {
                             let _ = map.next_value::<serde_json::Value>()?;
                         }
@@ -547,6 +970,17 @@ impl<'de> Deserialize<'de> for PoolConfig {
                 let retry_interval_ms = retry_interval_ms.ok_or_else(|| de::Error::missing_field("retry_interval_ms"))?;
                 let keepalive_interval_sec = keepalive_interval_sec.ok_or_else(|| de::Error::missing_field("keepalive_interval_sec"))?;
                 let health_check_timeout_sec = health_check_timeout_sec.ok_or_else(|| de::Error::missing_field("health_check_timeout_sec"))?;
+                // 熔断配置为后续新增字段，缺省时使用默认值以兼容旧配置
+                let circuit_breaker_threshold = circuit_breaker_threshold.unwrap_or(DEFAULT_CIRCUIT_BREAKER_THRESHOLD);
+                let circuit_breaker_reset_ms = circuit_breaker_reset_ms.unwrap_or(DEFAULT_CIRCUIT_BREAKER_RESET_MS);
+                let connect_mode = connect_mode.unwrap_or_default();
+                let statement_cache_capacity =
+                    statement_cache_capacity.unwrap_or(DEFAULT_STATEMENT_CACHE_CAPACITY);
+                let max_reconnect_backoff_ms =
+                    max_reconnect_backoff_ms.unwrap_or(DEFAULT_MAX_RECONNECT_BACKOFF_MS);
+                let queue_capacity = queue_capacity.flatten();
+                let overflow_policy = overflow_policy.unwrap_or_default();
+                let write_batch = write_batch.flatten();
 
                 Ok(PoolConfig {
                     min_connections,
@@ -558,11 +992,19 @@ impl<'de> Deserialize<'de> for PoolConfig {
                     retry_interval_ms,
                     keepalive_interval_sec,
                     health_check_timeout_sec,
+                    circuit_breaker_threshold,
+                    circuit_breaker_reset_ms,
+                    connect_mode,
+                    statement_cache_capacity,
+                    max_reconnect_backoff_ms,
+                    queue_capacity,
+                    overflow_policy,
+                    write_batch,
                 })
             }
         }
 
-        const FIELDS: &'static [&'static str] = &[
+        const FIELDS: &[&str] = &[
             "min_connections",
             "max_connections",
             "connection_timeout",
@@ -572,6 +1014,14 @@ impl<'de> Deserialize<'de> for PoolConfig {
             "retry_interval_ms",
             "keepalive_interval_sec",
             "health_check_timeout_sec",
+            "circuit_breaker_threshold",
+            "circuit_breaker_reset_ms",
+            "connect_mode",
+            "statement_cache_capacity",
+            "max_reconnect_backoff_ms",
+            "queue_capacity",
+            "overflow_policy",
+            "write_batch",
         ];
         deserializer.deserialize_struct("PoolConfig", FIELDS, PoolConfigVisitor)
     }
@@ -589,6 +1039,14 @@ impl Default for PoolConfig {
             retry_interval_ms: 1000,
             keepalive_interval_sec: 30,
             health_check_timeout_sec: 5,
+            circuit_breaker_threshold: DEFAULT_CIRCUIT_BREAKER_THRESHOLD,
+            circuit_breaker_reset_ms: DEFAULT_CIRCUIT_BREAKER_RESET_MS,
+            connect_mode: ConnectMode::default(),
+            statement_cache_capacity: DEFAULT_STATEMENT_CACHE_CAPACITY,
+            max_reconnect_backoff_ms: DEFAULT_MAX_RECONNECT_BACKOFF_MS,
+            queue_capacity: None,
+            overflow_policy: OverflowPolicy::default(),
+            write_batch: None,
         }
     }
 }
@@ -2,8 +2,10 @@ use serde::{Deserialize, Serialize};
 
 /// ID 生成策略
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Default)]
 pub enum IdStrategy {
     /// 数据库自增 ID（数字）
+    #[default]
     AutoIncrement,
     /// UUID v4（字符串）
     Uuid,
@@ -38,11 +40,6 @@ impl std::fmt::Display for IdType {
     }
 }
 
-impl Default for IdStrategy {
-    fn default() -> Self {
-        Self::AutoIncrement
-    }
-}
 
 impl IdStrategy {
     /// 创建 UUID 策略
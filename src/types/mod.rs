@@ -2,6 +2,7 @@
 //!
 //! 定义支持的数据库类型、连接配置和通用数据类型
 
+pub mod bulk;
 pub mod cache_config;
 pub mod data_value;
 pub mod database_config;
@@ -12,19 +13,21 @@ pub mod serde_helpers;
 pub mod update_operations;
 
 // 重新导出所有公共类型以保持API兼容性
+pub use bulk::{BulkOp, BulkOpOutcome, BulkWriteMode, BulkWriteReport};
 pub use cache_config::{
-    CacheConfig, CacheStrategy, CompressionAlgorithm, CompressionConfig, L1CacheConfig,
-    L2CacheConfig, TtlConfig,
+    CacheConfig, CacheStrategy, CacheWriteMode, CompressionAlgorithm, CompressionConfig,
+    L1CacheConfig, L2CacheConfig, TableCachePolicy, TtlConfig,
 };
 pub use data_value::DataValue;
 pub use database_config::{
-    ConnectionConfig, DatabaseConfig, DatabaseType, PoolConfig, TlsConfig, ZstdConfig,
+    ConnectMode, ConnectionConfig, DatabaseConfig, DatabaseType, MongoClusterConfig, OverflowPolicy, PoolConfig, SqlitePragmaConfig,
+    TableNaming, TlsConfig, WriteBatchConfig, ZstdConfig,
 };
 pub use id_types::{IdStrategy, IdType};
 pub use mongo_builder::MongoDbConnectionBuilder;
 pub use query::{
-    FullTextSearchConfig, LogicalOperator, PaginationConfig, QueryCondition, QueryConditionGroup, QueryConditionGroupWithConfig,
-    QueryConditionWithConfig, QueryOperator,
-    QueryOptions, SortConfig, SortDirection,
+    Column, CountEstimate, Expr, Query, col, FullTextSearchConfig, LogicalOperator, PaginationConfig, QueryCondition, QueryConditionGroup, QueryConditionGroupWithConfig,
+    QueryConditionWithConfig, QueryOperator, NullSemantics, RowLock,
+    QueryOptions, SortConfig, SortDirection, UpdateOptions,
 };
 pub use update_operations::{UpdateOperation, UpdateOperator};
@@ -1,4 +1,4 @@
-use crate::types::database_config::{ConnectionConfig, TlsConfig, ZstdConfig};
+use crate::types::database_config::{ConnectionConfig, MongoClusterConfig, TlsConfig, ZstdConfig};
 use std::collections::HashMap;
 
 /// MongoDB 连接构建器
@@ -13,6 +13,7 @@ pub struct MongoDbConnectionBuilder {
     tls_config: Option<TlsConfig>,
     zstd_config: Option<ZstdConfig>,
     options: HashMap<String, String>,
+    cluster_config: Option<MongoClusterConfig>,
 }
 
 impl MongoDbConnectionBuilder {
@@ -29,6 +30,7 @@ impl MongoDbConnectionBuilder {
             tls_config: None,
             zstd_config: None,
             options: HashMap::new(),
+            cluster_config: None,
         }
     }
 
@@ -63,6 +65,12 @@ impl MongoDbConnectionBuilder {
         self
     }
 
+    /// 设置集群配置（多种子节点、SRV、副本集等）
+    pub fn with_cluster_config(mut self, cluster_config: MongoClusterConfig) -> Self {
+        self.cluster_config = Some(cluster_config);
+        self
+    }
+
     /// 添加自定义选项
     pub fn with_option<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> Self {
         self.options.insert(key.into(), value.into());
@@ -86,13 +94,15 @@ impl MongoDbConnectionBuilder {
             } else {
                 Some(self.options)
             },
+            cluster_config: self.cluster_config,
         }
     }
 
     /// 生成MongoDB连接URI（用于内部使用）
     #[doc(hidden)]
     pub fn build_uri(&self) -> String {
-        let mut uri = String::from("mongodb://");
+        let srv = self.cluster_config.as_ref().is_some_and(|c| c.srv);
+        let mut uri = String::from(if srv { "mongodb+srv://" } else { "mongodb://" });
 
         // 添加认证信息
         if let (Some(username), Some(password)) = (&self.username, &self.password) {
@@ -102,10 +112,18 @@ impl MongoDbConnectionBuilder {
             uri.push('@');
         }
 
-        // 添加主机和端口
+        // 添加主机和端口（SRV 模式下只有域名，节点与端口由 DNS 记录给出）
         uri.push_str(&self.host);
-        uri.push(':');
-        uri.push_str(&self.port.to_string());
+        if !srv {
+            uri.push(':');
+            uri.push_str(&self.port.to_string());
+            if let Some(cluster_config) = &self.cluster_config {
+                for host in &cluster_config.hosts {
+                    uri.push(',');
+                    uri.push_str(host);
+                }
+            }
+        }
 
         // 添加数据库
         uri.push('/');
@@ -122,6 +140,18 @@ impl MongoDbConnectionBuilder {
             params.push("directConnection=true".to_string());
         }
 
+        if let Some(cluster_config) = &self.cluster_config {
+            if let Some(replica_set) = &cluster_config.replica_set {
+                params.push(format!("replicaSet={}", urlencoding::encode(replica_set)));
+            }
+            if let Some(read_preference) = &cluster_config.read_preference {
+                params.push(format!("readPreference={}", urlencoding::encode(read_preference)));
+            }
+            if let Some(write_concern) = &cluster_config.write_concern {
+                params.push(format!("w={}", urlencoding::encode(write_concern)));
+            }
+        }
+
         if let Some(tls_config) = &self.tls_config {
             if tls_config.enabled {
                 params.push("tls=true".to_string());
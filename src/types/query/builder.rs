@@ -0,0 +1,381 @@
+//! 链式查询构建器
+//!
+//! 以链式调用代替手动构造 `Vec<QueryCondition>`，最终编译为现有的条件组：
+//!
+//! ```rust,ignore
+//! let users = Query::<User>::new()
+//!     .filter(col("age").gte(18).and(col("is_active").eq(true)))
+//!     .sort_desc("created_at")
+//!     .limit(20)
+//!     .find()
+//!     .await?;
+//! ```
+
+use crate::error::QuickDbResult;
+use crate::types::data_value::DataValue;
+use crate::types::query::{
+    LogicalOperator, NullSemantics, PaginationConfig, QueryConditionGroupWithConfig, QueryConditionWithConfig,
+    QueryOperator, QueryOptions, RowLock, SortConfig, SortDirection,
+};
+use std::marker::PhantomData;
+use std::time::Duration;
+
+/// 创建字段引用，作为条件表达式的起点
+pub fn col(name: &str) -> Column {
+    Column {
+        name: name.to_string(),
+    }
+}
+
+/// 字段引用
+#[derive(Debug, Clone, PartialEq)]
+pub struct Column {
+    name: String,
+}
+
+impl Column {
+    fn condition(self, operator: QueryOperator, value: DataValue, case_insensitive: bool) -> Expr {
+        Expr(QueryConditionGroupWithConfig::Single(QueryConditionWithConfig {
+            field: self.name,
+            operator,
+            value,
+            case_insensitive,
+        }))
+    }
+
+    /// 等于
+    pub fn eq(self, value: impl Into<DataValue>) -> Expr {
+        self.condition(QueryOperator::Eq, value.into(), false)
+    }
+
+    /// 等于（大小写不敏感）
+    pub fn ieq(self, value: impl Into<DataValue>) -> Expr {
+        self.condition(QueryOperator::Eq, value.into(), true)
+    }
+
+    /// 不等于
+    pub fn ne(self, value: impl Into<DataValue>) -> Expr {
+        self.condition(QueryOperator::Ne, value.into(), false)
+    }
+
+    /// 大于
+    pub fn gt(self, value: impl Into<DataValue>) -> Expr {
+        self.condition(QueryOperator::Gt, value.into(), false)
+    }
+
+    /// 大于等于
+    pub fn gte(self, value: impl Into<DataValue>) -> Expr {
+        self.condition(QueryOperator::Gte, value.into(), false)
+    }
+
+    /// 小于
+    pub fn lt(self, value: impl Into<DataValue>) -> Expr {
+        self.condition(QueryOperator::Lt, value.into(), false)
+    }
+
+    /// 小于等于
+    pub fn lte(self, value: impl Into<DataValue>) -> Expr {
+        self.condition(QueryOperator::Lte, value.into(), false)
+    }
+
+    /// 包含（字符串）
+    pub fn contains(self, value: &str) -> Expr {
+        self.condition(QueryOperator::Contains, value.into(), false)
+    }
+
+    /// 开始于（字符串）
+    pub fn starts_with(self, value: &str) -> Expr {
+        self.condition(QueryOperator::StartsWith, value.into(), false)
+    }
+
+    /// 结束于（字符串）
+    pub fn ends_with(self, value: &str) -> Expr {
+        self.condition(QueryOperator::EndsWith, value.into(), false)
+    }
+
+    /// 在列表中
+    pub fn is_in<V: Into<DataValue>>(self, values: impl IntoIterator<Item = V>) -> Expr {
+        let values = values.into_iter().map(Into::into).collect();
+        self.condition(QueryOperator::In, DataValue::Array(values), false)
+    }
+
+    /// 不在列表中
+    pub fn not_in<V: Into<DataValue>>(self, values: impl IntoIterator<Item = V>) -> Expr {
+        let values = values.into_iter().map(Into::into).collect();
+        self.condition(QueryOperator::NotIn, DataValue::Array(values), false)
+    }
+
+    /// 在闭区间 `[lower, upper]` 内
+    pub fn between(self, lower: impl Into<DataValue>, upper: impl Into<DataValue>) -> Expr {
+        let bounds = DataValue::Array(vec![lower.into(), upper.into()]);
+        self.condition(QueryOperator::Between, bounds, false)
+    }
+
+    /// 不在闭区间 `[lower, upper]` 内
+    pub fn not_between(self, lower: impl Into<DataValue>, upper: impl Into<DataValue>) -> Expr {
+        let bounds = DataValue::Array(vec![lower.into(), upper.into()]);
+        self.condition(QueryOperator::NotBetween, bounds, false)
+    }
+
+    /// 正则表达式匹配
+    pub fn regex(self, pattern: &str) -> Expr {
+        self.condition(QueryOperator::Regex, pattern.into(), false)
+    }
+
+    /// 字段存在
+    pub fn exists(self) -> Expr {
+        self.condition(QueryOperator::Exists, DataValue::Bool(true), false)
+    }
+
+    /// 为空
+    pub fn is_null(self) -> Expr {
+        self.condition(QueryOperator::IsNull, DataValue::Null, false)
+    }
+
+    /// 不为空
+    pub fn is_not_null(self) -> Expr {
+        self.condition(QueryOperator::IsNotNull, DataValue::Null, false)
+    }
+}
+
+/// 条件表达式，可通过 `and` / `or` 组合
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expr(QueryConditionGroupWithConfig);
+
+impl Expr {
+    /// 与另一个表达式以 AND 组合
+    pub fn and(self, other: Expr) -> Expr {
+        self.combine(LogicalOperator::And, other)
+    }
+
+    /// 与另一个表达式以 OR 组合
+    pub fn or(self, other: Expr) -> Expr {
+        self.combine(LogicalOperator::Or, other)
+    }
+
+    /// 连续的同类逻辑组合会被展平，`a.and(b).and(c)` 生成单个包含三个条件的 AND 组
+    fn combine(self, operator: LogicalOperator, other: Expr) -> Expr {
+        let mut conditions = match self.0 {
+            QueryConditionGroupWithConfig::GroupWithConfig {
+                operator: existing,
+                conditions,
+            } if existing == operator => conditions,
+            group => vec![group],
+        };
+        match other.0 {
+            QueryConditionGroupWithConfig::GroupWithConfig {
+                operator: existing,
+                conditions: other_conditions,
+            } if existing == operator => conditions.extend(other_conditions),
+            group => conditions.push(group),
+        }
+        Expr(QueryConditionGroupWithConfig::GroupWithConfig {
+            operator,
+            conditions,
+        })
+    }
+
+    /// 转换为条件组
+    pub fn into_group(self) -> QueryConditionGroupWithConfig {
+        self.0
+    }
+}
+
+impl From<Expr> for QueryConditionGroupWithConfig {
+    fn from(expr: Expr) -> Self {
+        expr.0
+    }
+}
+
+/// 链式查询构建器
+///
+/// 多次调用 `filter` 的条件以 AND 组合。`T` 为查询的模型类型，
+/// 模型类型实现 `Model` 时可直接调用 `find` / `first` / `count` 执行查询
+#[derive(Debug, Clone)]
+pub struct Query<T> {
+    filters: Vec<QueryConditionGroupWithConfig>,
+    options: QueryOptions,
+    _model: PhantomData<T>,
+}
+
+impl<T> Default for Query<T> {
+    fn default() -> Self {
+        Self {
+            filters: Vec::new(),
+            options: QueryOptions::default(),
+            _model: PhantomData,
+        }
+    }
+}
+
+impl<T> Query<T> {
+    /// 创建空查询（匹配所有记录）
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 追加过滤条件
+    pub fn filter(mut self, expr: Expr) -> Self {
+        self.filters.push(expr.0);
+        self
+    }
+
+    /// 按字段升序排序
+    pub fn sort_asc(self, field: &str) -> Self {
+        self.sort(field, SortDirection::Asc)
+    }
+
+    /// 按字段降序排序
+    pub fn sort_desc(self, field: &str) -> Self {
+        self.sort(field, SortDirection::Desc)
+    }
+
+    fn sort(mut self, field: &str, direction: SortDirection) -> Self {
+        self.options.sort.push(SortConfig {
+            field: field.to_string(),
+            direction,
+        });
+        self
+    }
+
+    /// 限制返回的记录数
+    pub fn limit(mut self, limit: u64) -> Self {
+        let skip = self.options.pagination.as_ref().map_or(0, |p| p.skip);
+        self.options.pagination = Some(PaginationConfig { skip, limit });
+        self
+    }
+
+    /// 跳过的记录数（未设置 limit 时不限制返回数量）
+    pub fn skip(mut self, skip: u64) -> Self {
+        let limit = self.options.pagination.as_ref().map_or(i64::MAX as u64, |p| p.limit);
+        self.options.pagination = Some(PaginationConfig { skip, limit });
+        self
+    }
+
+    /// 只查询指定字段
+    pub fn select(mut self, fields: &[&str]) -> Self {
+        self.options.fields = fields.iter().map(|field| field.to_string()).collect();
+        self
+    }
+
+    /// 设置查询超时
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.options.timeout = Some(timeout);
+        self
+    }
+
+    /// 设置与空值比较时的语义
+    pub fn null_semantics(mut self, null_semantics: NullSemantics) -> Self {
+        self.options.null_semantics = null_semantics;
+        self
+    }
+
+    /// 锁定查询到的行（SELECT ... FOR UPDATE，仅用于租用连接的查询，见 [`RowLock`]）
+    pub fn for_update(mut self) -> Self {
+        self.options.lock = Some(RowLock::ForUpdate);
+        self
+    }
+
+    /// 以共享锁锁定查询到的行（SELECT ... FOR SHARE，仅用于租用连接的查询，见 [`RowLock`]）
+    pub fn for_share(mut self) -> Self {
+        self.options.lock = Some(RowLock::ForShare);
+        self
+    }
+
+    /// 编译后的条件组（多个过滤条件以 AND 组合为单个条件组）
+    pub fn condition_groups(&self) -> Vec<QueryConditionGroupWithConfig> {
+        match self.filters.len() {
+            0 | 1 => self.filters.clone(),
+            _ => vec![QueryConditionGroupWithConfig::GroupWithConfig {
+                operator: LogicalOperator::And,
+                conditions: self.filters.clone(),
+            }],
+        }
+    }
+
+    /// 查询选项（排序、分页、投影、超时）
+    pub fn options(&self) -> &QueryOptions {
+        &self.options
+    }
+
+    /// 拆分为条件组与查询选项
+    pub fn build(self) -> (Vec<QueryConditionGroupWithConfig>, QueryOptions) {
+        (self.condition_groups(), self.options)
+    }
+}
+
+impl<T: crate::model::Model> Query<T> {
+    /// 执行查询，返回所有匹配的模型
+    pub async fn find(self) -> QuickDbResult<Vec<T>> {
+        use crate::model::ModelOperations;
+        let (groups, options) = self.build();
+        crate::model::ModelManager::<T>::find_with_groups_with_config(groups, Some(options)).await
+    }
+
+    /// 执行查询，返回第一条匹配的模型
+    pub async fn first(self) -> QuickDbResult<Option<T>> {
+        Ok(self.limit(1).find().await?.into_iter().next())
+    }
+
+    /// 统计匹配的记录数（忽略排序与分页）
+    pub async fn count(self) -> QuickDbResult<u64> {
+        crate::model::ModelManager::<T>::count_with_groups_with_config(self.condition_groups()).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compiles_fluent_filters_to_condition_groups() {
+        let query = Query::<()>::new()
+            .filter(col("age").gte(18).and(col("is_active").eq(true)))
+            .sort_desc("created_at")
+            .limit(20);
+
+        let (groups, options) = query.build();
+        match groups.as_slice() {
+            [QueryConditionGroupWithConfig::GroupWithConfig { operator, conditions }] => {
+                assert_eq!(*operator, LogicalOperator::And);
+                assert_eq!(conditions.len(), 2);
+            }
+            other => panic!("unexpected groups: {:?}", other),
+        }
+        assert_eq!(options.sort[0].field, "created_at");
+        assert_eq!(options.sort[0].direction, SortDirection::Desc);
+        assert_eq!(options.pagination.as_ref().unwrap().limit, 20);
+    }
+
+    #[test]
+    fn flattens_same_operator_and_nests_mixed_operators() {
+        let expr = col("a").eq(1).and(col("b").eq(2)).and(col("c").eq(3));
+        match expr.clone().into_group() {
+            QueryConditionGroupWithConfig::GroupWithConfig { conditions, .. } => {
+                assert_eq!(conditions.len(), 3)
+            }
+            other => panic!("unexpected group: {:?}", other),
+        }
+
+        let mixed = expr.or(col("name").ieq("admin"));
+        match mixed.into_group() {
+            QueryConditionGroupWithConfig::GroupWithConfig { operator, conditions } => {
+                assert_eq!(operator, LogicalOperator::Or);
+                assert!(matches!(
+                    &conditions[0],
+                    QueryConditionGroupWithConfig::GroupWithConfig { operator: LogicalOperator::And, .. }
+                ));
+                assert!(matches!(
+                    &conditions[1],
+                    QueryConditionGroupWithConfig::Single(c) if c.case_insensitive
+                ));
+            }
+            other => panic!("unexpected group: {:?}", other),
+        }
+
+        // 多次 filter 以 AND 组合
+        let query = Query::<()>::new().filter(col("a").is_in([1, 2])).filter(col("b").is_null()).skip(5);
+        assert_eq!(query.condition_groups().len(), 1);
+        assert_eq!(query.options().pagination.as_ref().unwrap().skip, 5);
+    }
+}
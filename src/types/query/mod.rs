@@ -1,5 +1,13 @@
+use crate::error::{QuickDbError, QuickDbResult};
 use crate::types::data_value::DataValue;
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+mod builder;
+mod pattern;
+
+pub use builder::{Column, Expr, Query, col};
+pub use pattern::{MAX_REGEX_PATTERN_LEN, regex_pattern};
 
 /// 查询条件（简化版）
 ///
@@ -148,7 +156,7 @@ pub enum QueryOperator {
     In,
     /// 不在列表中
     NotIn,
-    /// 正则表达式匹配
+    /// 正则表达式匹配（模式需通过 `regex_pattern` 校验，默认大小写敏感）
     Regex,
     /// 存在（字段存在）
     Exists,
@@ -156,6 +164,52 @@ pub enum QueryOperator {
     IsNull,
     /// 不为空
     IsNotNull,
+    /// 在范围内（闭区间，值为 `[下界, 上界]` 两个元素的数组）
+    Between,
+    /// 不在范围内（值为 `[下界, 上界]` 两个元素的数组）
+    NotBetween,
+}
+
+/// 解析 `Between` / `NotBetween` 条件的上下界
+///
+/// 值必须是恰好两个元素的数组，且上下界属于同一类可比较的类型：
+/// 数值（Int、UInt、Float）、字符串、日期时间或 UUID
+pub fn between_bounds<'a>(
+    field: &str,
+    value: &'a DataValue,
+) -> QuickDbResult<(&'a DataValue, &'a DataValue)> {
+    let (lower, upper) = match value {
+        DataValue::Array(bounds) if bounds.len() == 2 => (&bounds[0], &bounds[1]),
+        _ => {
+            return Err(QuickDbError::ValidationError {
+                field: field.to_string(),
+                message: crate::i18n::tf(
+                    "query.between_requires_two_bounds",
+                    &[("value_type", value.type_name())],
+                ),
+            });
+        }
+    };
+
+    let category = |bound: &DataValue| match bound {
+        DataValue::Int(_) | DataValue::UInt(_) | DataValue::Float(_) => Some(0),
+        DataValue::String(_) => Some(1),
+        DataValue::DateTime(_) | DataValue::DateTimeUTC(_) => Some(2),
+        DataValue::Uuid(_) => Some(3),
+        _ => None,
+    };
+    match (category(lower), category(upper)) {
+        (Some(lower_category), Some(upper_category)) if lower_category == upper_category => {
+            Ok((lower, upper))
+        }
+        _ => Err(QuickDbError::ValidationError {
+            field: field.to_string(),
+            message: crate::i18n::tf(
+                "query.between_bound_type_mismatch",
+                &[("lower", lower.type_name()), ("upper", upper.type_name())],
+            ),
+        }),
+    }
 }
 
 /// 排序配置
@@ -238,6 +292,72 @@ pub struct FullTextSearchConfig {
     pub query_text: String,
 }
 
+/// 与空值（`DataValue::Null`）比较时的语义
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum NullSemantics {
+    /// 按数据库原生语义处理（默认）：SQL 数据库中 `= NULL` 不匹配任何记录，
+    /// MongoDB 中匹配空值和缺失字段
+    #[default]
+    Strict,
+    /// 与空值的 `Eq` / `Ne` 分别按 `IsNull` / `IsNotNull` 处理，各数据库行为一致
+    TreatAsIsNull,
+}
+
+impl NullSemantics {
+    /// 按空值语义改写条件列表
+    pub fn normalize_conditions(self, conditions: &mut [QueryConditionWithConfig]) {
+        if self == NullSemantics::Strict {
+            return;
+        }
+        for condition in conditions {
+            if condition.value != DataValue::Null {
+                continue;
+            }
+            match condition.operator {
+                QueryOperator::Eq => condition.operator = QueryOperator::IsNull,
+                QueryOperator::Ne => condition.operator = QueryOperator::IsNotNull,
+                _ => {}
+            }
+        }
+    }
+
+    /// 按空值语义改写条件组（递归处理嵌套条件组）
+    pub fn normalize_groups(self, groups: &mut [QueryConditionGroupWithConfig]) {
+        if self == NullSemantics::Strict {
+            return;
+        }
+        for group in groups {
+            match group {
+                QueryConditionGroupWithConfig::Single(condition) => {
+                    self.normalize_conditions(std::slice::from_mut(condition))
+                }
+                QueryConditionGroupWithConfig::GroupWithConfig { conditions, .. } => {
+                    self.normalize_groups(conditions)
+                }
+            }
+        }
+    }
+}
+
+/// 行级锁（悲观锁）
+///
+/// SQL 后端在 SELECT 末尾追加锁定子句，锁在所处事务结束时释放。行级锁只能通过
+/// 租用连接的 [`crate::manager::LeasedConnection::find`] 使用；ODM 查询在自动提交的连接上执行，
+/// 设置了该选项时返回验证错误。
+///
+/// - PostgreSQL：`FOR UPDATE` / `FOR SHARE`
+/// - MySQL：`FOR UPDATE` / `LOCK IN SHARE MODE`（兼容 MySQL 5.7 与 MariaDB）
+/// - SQLite：没有行级锁，写事务本身即独占整个数据库，忽略该选项
+/// - MongoDB：**不支持**。MongoDB 没有行级读锁，也不支持连接租用；需要“读取并修改”的
+///   原子性时，应使用带条件的更新（[`crate::odm::update_if`]，服务端原子执行）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RowLock {
+    /// 排他锁，阻止其他事务修改或加锁
+    ForUpdate,
+    /// 共享锁，允许其他事务读取与加共享锁，但阻止修改
+    ForShare,
+}
+
 /// 查询选项
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct QueryOptions {
@@ -253,6 +373,21 @@ pub struct QueryOptions {
     pub vector_sort: Option<VectorSortConfig>,
     /// 全文搜索配置（用于 PostgreSQL tsvector 全文搜索）
     pub fulltext_search: Option<FullTextSearchConfig>,
+    /// 查询超时（超时后返回错误，并尽量下推为数据库端的语句超时）
+    #[serde(default)]
+    pub timeout: Option<Duration>,
+    /// 与空值比较时的语义
+    #[serde(default)]
+    pub null_semantics: NullSemantics,
+    /// 行级锁（SELECT ... FOR UPDATE / FOR SHARE）
+    #[serde(default)]
+    pub lock: Option<RowLock>,
+    /// 启用的模型命名作用域（见 [`crate::model::scopes`]）
+    #[serde(default)]
+    pub scopes: Vec<String>,
+    /// 跳过模型的默认作用域
+    #[serde(default)]
+    pub unscoped: bool,
 }
 
 impl QueryOptions {
@@ -296,4 +431,158 @@ impl QueryOptions {
         self.fulltext_search = Some(fulltext_search);
         self
     }
+
+    /// 设置查询超时
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// 设置与空值比较时的语义
+    pub fn with_null_semantics(mut self, null_semantics: NullSemantics) -> Self {
+        self.null_semantics = null_semantics;
+        self
+    }
+
+    /// 设置行级锁
+    pub fn with_lock(mut self, lock: RowLock) -> Self {
+        self.lock = Some(lock);
+        self
+    }
+
+    /// 启用模型的命名作用域，可多次调用组合多个作用域
+    pub fn with_scope(mut self, scope: impl Into<String>) -> Self {
+        self.scopes.push(scope.into());
+        self
+    }
+
+    /// 跳过模型的默认作用域
+    pub fn unscoped(mut self) -> Self {
+        self.unscoped = true;
+        self
+    }
+}
+
+/// 更新选项
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct UpdateOptions {
+    /// 更新超时（超时后返回错误）
+    ///
+    /// PostgreSQL 在事务内以 `SET LOCAL statement_timeout`、MongoDB 以 `maxTimeMS` 在服务端
+    /// 终止超时的更新。MySQL 的 `max_execution_time` 只作用于 SELECT，SQLite 没有语句超时，
+    /// 这两种数据库只在客户端等待超时后返回错误，已发出的更新语句可能仍会执行完成
+    #[serde(default)]
+    pub timeout: Option<Duration>,
+    /// 演练模式：不执行更新，只返回受影响记录数（见 [`crate::odm::with_dry_run`]）
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+impl UpdateOptions {
+    /// 创建新的更新选项
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 设置更新超时
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// 启用演练模式
+    pub fn with_dry_run(mut self) -> Self {
+        self.dry_run = true;
+        self
+    }
+}
+
+/// 表记录数估算结果（见 [`crate::manager::count_estimate`]）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CountEstimate {
+    /// 记录数
+    pub count: u64,
+    /// 是否为近似值；数据库没有可用的统计信息而回退到精确计数时为 false
+    pub approximate: bool,
+}
+
+impl CountEstimate {
+    /// 来自统计信息的近似值
+    pub fn approximate(count: u64) -> Self {
+        Self { count, approximate: true }
+    }
+
+    /// 精确计数
+    pub fn exact(count: u64) -> Self {
+        Self { count, approximate: false }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validates_between_bounds() {
+        crate::i18n::ErrorMessageI18n::init_i18n();
+
+        let numeric = DataValue::Array(vec![DataValue::Int(1), DataValue::Float(9.5)]);
+        assert_eq!(
+            between_bounds("score", &numeric).unwrap(),
+            (&DataValue::Int(1), &DataValue::Float(9.5))
+        );
+        let dates = DataValue::Array(vec![
+            DataValue::DateTimeUTC(chrono::Utc::now()),
+            DataValue::DateTime(chrono::Utc::now().fixed_offset()),
+        ]);
+        assert!(between_bounds("created_at", &dates).is_ok());
+
+        let single = DataValue::Array(vec![DataValue::Int(1)]);
+        let mixed = DataValue::Array(vec![DataValue::Int(1), DataValue::String("9".to_string())]);
+        let nulls = DataValue::Array(vec![DataValue::Null, DataValue::Int(9)]);
+        for invalid in [single, mixed, nulls, DataValue::Int(1)] {
+            assert!(matches!(
+                between_bounds("score", &invalid),
+                Err(QuickDbError::ValidationError { field, .. }) if field == "score"
+            ));
+        }
+    }
+    #[test]
+    fn treat_as_is_null_rewrites_null_comparisons() {
+        let condition = |operator: QueryOperator, value: DataValue| QueryConditionWithConfig {
+            field: "deleted_at".to_string(),
+            operator,
+            value,
+            case_insensitive: false,
+        };
+        let mut groups = vec![
+            QueryConditionGroupWithConfig::Single(condition(QueryOperator::Eq, DataValue::Null)),
+            QueryConditionGroupWithConfig::GroupWithConfig {
+                operator: LogicalOperator::Or,
+                conditions: vec![
+                    QueryConditionGroupWithConfig::Single(condition(QueryOperator::Ne, DataValue::Null)),
+                    QueryConditionGroupWithConfig::Single(condition(QueryOperator::Eq, DataValue::Int(1))),
+                ],
+            },
+        ];
+
+        let original = groups.clone();
+        NullSemantics::Strict.normalize_groups(&mut groups);
+        assert_eq!(groups, original);
+
+        NullSemantics::TreatAsIsNull.normalize_groups(&mut groups);
+        let operators: Vec<QueryOperator> = match groups.as_slice() {
+            [
+                QueryConditionGroupWithConfig::Single(first),
+                QueryConditionGroupWithConfig::GroupWithConfig { conditions, .. },
+            ] => std::iter::once(first.operator.clone())
+                .chain(conditions.iter().map(|group| match group {
+                    QueryConditionGroupWithConfig::Single(c) => c.operator.clone(),
+                    other => panic!("unexpected group: {:?}", other),
+                }))
+                .collect(),
+            other => panic!("unexpected groups: {:?}", other),
+        };
+        assert_eq!(operators, vec![QueryOperator::IsNull, QueryOperator::IsNotNull, QueryOperator::Eq]);
+    }
 }
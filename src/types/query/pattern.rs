@@ -0,0 +1,141 @@
+//! 正则表达式条件的校验
+//!
+//! `QueryOperator::Regex` 的模式在交给数据库之前统一校验，保证各后端行为一致并防止 ReDoS：
+//!
+//! - 模式长度不超过 [`MAX_REGEX_PATTERN_LEN`]
+//! - 必须能被 `regex` crate 解析（各后端共同支持的子集，不含反向引用与环视），
+//!   编译后的状态机大小受限
+//! - 拒绝嵌套量词（如 `(a+)+`、`(a|b*)*`），这类模式在回溯型引擎
+//!   （PostgreSQL、MySQL ICU、MongoDB PCRE）上可能产生指数级回溯
+
+use crate::error::{QuickDbError, QuickDbResult};
+use crate::types::data_value::DataValue;
+
+/// 正则表达式模式的最大长度（字符数）
+pub const MAX_REGEX_PATTERN_LEN: usize = 512;
+
+/// 编译后正则状态机的大小上限（字节）
+const MAX_COMPILED_SIZE: usize = 1 << 20;
+
+/// 校验 `Regex` 条件的值，返回可以安全下发的模式字符串
+pub fn regex_pattern<'a>(field: &str, value: &'a DataValue) -> QuickDbResult<&'a str> {
+    let invalid = |message: String| QuickDbError::ValidationError {
+        field: field.to_string(),
+        message,
+    };
+
+    let DataValue::String(pattern) = value else {
+        return Err(invalid(crate::i18n::tf(
+            "query.regex_string_only",
+            &[("value_type", value.type_name())],
+        )));
+    };
+
+    if pattern.chars().count() > MAX_REGEX_PATTERN_LEN {
+        return Err(invalid(crate::i18n::tf(
+            "query.regex_too_long",
+            &[("max", &MAX_REGEX_PATTERN_LEN.to_string())],
+        )));
+    }
+
+    regex::RegexBuilder::new(pattern)
+        .size_limit(MAX_COMPILED_SIZE)
+        .build()
+        .map_err(|e| invalid(crate::i18n::tf("query.regex_invalid", &[("message", &e.to_string())])))?;
+
+    if has_nested_quantifier(pattern) {
+        return Err(invalid(crate::i18n::tf(
+            "query.regex_nested_quantifier",
+            &[("pattern", pattern)],
+        )));
+    }
+
+    Ok(pattern)
+}
+
+/// 是否存在被重复量词（`*`、`+`、`{n,m}`）修饰、且内部已含量词的分组
+fn has_nested_quantifier(pattern: &str) -> bool {
+    let chars: Vec<char> = pattern.chars().collect();
+    // 每层分组内是否出现过量词
+    let mut groups: Vec<bool> = Vec::new();
+    let mut in_class = false;
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        if c == '\\' {
+            i += 2;
+            continue;
+        }
+        if in_class {
+            if c == ']' {
+                in_class = false;
+            }
+            i += 1;
+            continue;
+        }
+        match c {
+            '[' => {
+                in_class = true;
+                // 字符类开头的 `]` 或 `^]` 是普通字符
+                if chars.get(i + 1) == Some(&'^') {
+                    i += 1;
+                }
+                if chars.get(i + 1) == Some(&']') {
+                    i += 1;
+                }
+            }
+            '(' => {
+                groups.push(false);
+                // `(?:`、`(?i)`、`(?P<name>` 中的 `?` 是分组修饰而非量词
+                if chars.get(i + 1) == Some(&'?') {
+                    i += 1;
+                }
+            }
+            ')' => {
+                let inner_quantified = groups.pop().unwrap_or(false);
+                let next = chars.get(i + 1).copied();
+                let repeated = matches!(next, Some('*' | '+' | '{'));
+                if inner_quantified && repeated {
+                    return true;
+                }
+                if inner_quantified || repeated || next == Some('?') {
+                    if let Some(parent) = groups.last_mut() {
+                        *parent = true;
+                    }
+                }
+            }
+            '*' | '+' | '?' | '{' => {
+                if let Some(current) = groups.last_mut() {
+                    *current = true;
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_portable_patterns_and_rejects_redos_prone_ones() {
+        crate::i18n::ErrorMessageI18n::init_i18n();
+        let check = |pattern: &str| regex_pattern("name", &DataValue::String(pattern.to_string())).is_ok();
+
+        for pattern in [r"^user_\d+$", r"(ab)+c", r"(a|b)?c*", r"[(+*)]+", r"\(a+\)+", r"^(foo|bar)$", r"(?:ab)+", r"(?i)^abc$"] {
+            assert!(check(pattern), "{}", pattern);
+        }
+        for pattern in [r"(a+)+$", r"(a*)*", r"((ab)*c)+", r"(a|b+){2,}", r"(\w+\s?)*$", r"(?:a+)+"] {
+            assert!(!check(pattern), "{}", pattern);
+        }
+
+        // 反向引用不属于可移植子集
+        assert!(!check(r"(a)\1"));
+        assert!(!check(&"a".repeat(MAX_REGEX_PATTERN_LEN + 1)));
+        assert!(regex_pattern("name", &DataValue::Int(1)).is_err());
+    }
+}
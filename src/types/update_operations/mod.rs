@@ -18,6 +18,15 @@ pub enum UpdateOperator {
     PercentIncrease,
     /// 百分比减少 (值是百分比，如10表示减少10%)
     PercentDecrease,
+    /// 移除字段（值被忽略）
+    ///
+    /// MongoDB 使用 `$unset`；SQL 数据库把整列置为 NULL，`列.键` 形式则从 JSON 列中删除该键
+    Unset,
+    /// 重命名字段（值为新字段名）
+    ///
+    /// MongoDB 使用 `$rename`；SQL 数据库把值移到目标列并清空原列，
+    /// `列.键` 形式则在同一 JSON 列内移动键
+    Rename,
 }
 
 /// 更新操作定义
@@ -94,4 +103,22 @@ impl UpdateOperation {
             value: DataValue::Float(percentage),
         }
     }
+
+    /// 创建一个移除字段操作
+    pub fn unset(field: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            operation: UpdateOperator::Unset,
+            value: DataValue::Null,
+        }
+    }
+
+    /// 创建一个重命名字段操作
+    pub fn rename(field: impl Into<String>, new_name: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            operation: UpdateOperator::Rename,
+            value: DataValue::String(new_name.into()),
+        }
+    }
 }
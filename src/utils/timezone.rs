@@ -62,7 +62,7 @@ pub fn utc_to_timezone(
     timezone_offset: &str,
 ) -> crate::error::QuickDbResult<DateTime<FixedOffset>> {
     let offset_seconds = parse_timezone_offset_to_seconds(timezone_offset)?;
-    Ok(utc_dt.with_timezone(&FixedOffset::east(offset_seconds)))
+    Ok(utc_dt.with_timezone(&FixedOffset::east_opt(offset_seconds).unwrap()))
 }
 
 pub fn process_data_fields_from_metadata(
@@ -137,7 +137,7 @@ pub fn process_data_fields_from_metadata(
                                 dt
                             );
                             Some(DataValue::DateTime(
-                                dt.with_timezone(&chrono::FixedOffset::east(0)),
+                                dt.with_timezone(&chrono::FixedOffset::east_opt(0).unwrap()),
                             ))
                         }
                         Err(e) => {
@@ -160,7 +160,7 @@ pub fn process_data_fields_from_metadata(
                 {
                     rat_logger::debug!("字段 {} DateTimeUTC转DateTime: {}", field_name, dt);
                     Some(DataValue::DateTime(
-                        dt.with_timezone(&chrono::FixedOffset::east(0)),
+                        dt.with_timezone(&chrono::FixedOffset::east_opt(0).unwrap()),
                     ))
                 }
                 // 处理DateTimeWithTz字段的时区转换
@@ -223,7 +223,7 @@ fn apply_timezone_offset_to_datetime(
     let offset_seconds = crate::utils::timezone::parse_timezone_offset_to_seconds(timezone_offset)?;
 
     // 检查时区偏移是否在有效范围内（-23:59 到 +23:59）
-    if offset_seconds < -86399 || offset_seconds > 86399 {
+    if !(-86399..=86399).contains(&offset_seconds) {
         return Err(QuickDbError::ValidationError {
             field: "timezone_offset".to_string(),
             message: format!(
@@ -235,7 +235,7 @@ fn apply_timezone_offset_to_datetime(
 
     // 将UTC时间转换为本地时间
     let utc_chrono = utc_dt.with_timezone(&chrono::Utc);
-    let local_dt = utc_chrono.with_timezone(&chrono::FixedOffset::east(offset_seconds));
+    let local_dt = utc_chrono.with_timezone(&chrono::FixedOffset::east_opt(offset_seconds).unwrap());
 
     Ok(local_dt)
 }
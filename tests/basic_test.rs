@@ -1,7 +1,5 @@
 #[cfg(test)]
 mod tests {
-    use serde_json;
-
     /// 基础的JSON解析测试
     #[test]
     fn test_basic_json_parsing() {
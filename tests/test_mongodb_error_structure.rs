@@ -8,7 +8,7 @@ async fn test_mongodb_error_structure() {
     rat_logger::LoggerBuilder::new()
         .with_level(rat_logger::LevelFilter::Debug)
         .add_terminal_with_config(rat_logger::handler::term::TermConfig::default())
-        .init()
+        .init_global_logger()
         .expect("日志初始化失败");
 
     println!("🔍 开始探索 MongoDB 错误类型结构...");
@@ -8,7 +8,7 @@ async fn test_sqlite_error_code() {
     rat_logger::LoggerBuilder::new()
         .with_level(rat_logger::LevelFilter::Debug)
         .add_terminal_with_config(rat_logger::handler::term::TermConfig::default())
-        .init()
+        .init_global_logger()
         .expect("日志初始化失败");
 
     println!("🔍 开始测试 SQLite 错误码检测...");